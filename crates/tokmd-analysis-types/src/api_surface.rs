@@ -6,6 +6,7 @@
 use std::collections::BTreeMap;
 
 use serde::{Deserialize, Serialize};
+pub use tokmd_types::{ApiSurfaceDiff, ModuleApiChange};
 
 /// Public API surface analysis report.
 ///
@@ -29,6 +30,25 @@ pub struct ApiSurfaceReport {
     pub by_module: Vec<ModuleApiRow>,
     /// Top exporters (files with most public items).
     pub top_exporters: Vec<ApiExportItem>,
+    /// Public item identifiers (`"<kind> <name>"`) grouped by module.
+    ///
+    /// Lets two reports be diffed for added/removed public items. Capped at
+    /// 10,000 identifiers in total; see `public_symbols_truncated`. Older
+    /// receipts omit this field.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub public_symbols: BTreeMap<String, Vec<String>>,
+    /// Documented-ratio thresholds that were not met.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub doc_violations: Vec<ApiDocViolation>,
+    /// Detailed public item list (opt-in; `None` unless requested).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub items: Option<Vec<ApiItem>>,
+    /// Whether `items` was cut off at the item cap.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub items_truncated: bool,
+    /// Whether `public_symbols` was cut off at the symbol cap.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub public_symbols_truncated: bool,
 }
 
 /// Per-language API surface breakdown.
@@ -42,6 +62,9 @@ pub struct LangApiSurface {
     pub internal_items: usize,
     /// Public ratio for this language.
     pub public_ratio: f64,
+    /// Ratio of documented public items in this language (0.0-1.0).
+    #[serde(default)]
+    pub documented_ratio: f64,
 }
 
 /// Per-module API surface row.
@@ -69,3 +92,49 @@ pub struct ApiExportItem {
     /// Total items in the file.
     pub total_items: usize,
 }
+
+/// A single public API item.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ApiItem {
+    /// Item identifier (`"?"` when the scanner cannot find one).
+    pub name: String,
+    /// Declaration keyword that introduced the item (`fn`, `class`, ...).
+    pub kind: String,
+    /// Module path.
+    pub module: String,
+    /// File path.
+    pub path: String,
+    /// Language of the file.
+    pub lang: String,
+    /// Whether the item carries a doc comment.
+    pub documented: bool,
+}
+
+/// Minimum documented-ratio requirements for the public API surface.
+///
+/// Unset thresholds are not checked.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ApiDocThresholds {
+    /// Minimum overall documented ratio (0.0-1.0).
+    pub min_documented_ratio: Option<f64>,
+    /// Minimum documented ratio per language (0.0-1.0), keyed by language name.
+    pub min_documented_ratio_by_lang: BTreeMap<String, f64>,
+}
+
+impl ApiDocThresholds {
+    /// Whether any threshold is configured.
+    pub fn is_empty(&self) -> bool {
+        self.min_documented_ratio.is_none() && self.min_documented_ratio_by_lang.is_empty()
+    }
+}
+
+/// A documented-ratio threshold that the API surface did not meet.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ApiDocViolation {
+    /// `"overall"` or the language name.
+    pub scope: String,
+    /// Observed documented ratio.
+    pub documented_ratio: f64,
+    /// Required minimum documented ratio.
+    pub threshold: f64,
+}
@@ -19,4 +19,23 @@ pub struct AnalysisArgsMeta {
     pub max_commit_files: Option<usize>,
     pub max_file_bytes: Option<u64>,
     pub import_granularity: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub oversized_file_bytes: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_commit_message_bytes: Option<usize>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub strip_bom: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sample_rate: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_runtime_ms: Option<u64>,
+    /// Sections a configured preset enabled, when `preset` names one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub preset_sections: Option<Vec<String>>,
+    /// Sections computation was restricted to (`--include-sections`).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub include_sections: Vec<String>,
+    /// Sections skipped (`--exclude-sections`).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub exclude_sections: Vec<String>,
 }
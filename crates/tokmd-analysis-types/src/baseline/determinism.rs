@@ -4,6 +4,7 @@
 //! complexity ratchet structures while preserving the crate-root re-export.
 
 use serde::{Deserialize, Serialize};
+use tokmd_types::HashAlgo;
 
 /// Build determinism baseline for reproducibility verification.
 ///
@@ -21,6 +22,10 @@ pub struct DeterminismBaseline {
     pub source_hash: String,
     /// Hash of Cargo.lock if present (Rust projects).
     pub cargo_lock_hash: Option<String>,
+    /// Algorithm behind `source_hash` and `cargo_lock_hash`. Baselines
+    /// written before this field existed are BLAKE3.
+    #[serde(default)]
+    pub algo: HashAlgo,
 }
 
 #[cfg(test)]
@@ -43,6 +48,7 @@ mod tests {
                 build_hash,
                 source_hash,
                 cargo_lock_hash,
+                algo: HashAlgo::Blake3,
             };
 
             let json = serde_json::to_string(&baseline).expect("serialize determinism baseline");
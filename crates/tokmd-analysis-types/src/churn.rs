@@ -10,6 +10,10 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PredictiveChurnReport {
     pub per_module: BTreeMap<String, ChurnTrend>,
+    /// Horizon of each trend's projection, in days. Present only when a
+    /// forecast was requested.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub forecast_days: Option<u32>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -18,6 +22,19 @@ pub struct ChurnTrend {
     pub r2: f64,
     pub recent_change: i64,
     pub classification: TrendClass,
+    /// Commits expected to touch the module within `forecast_days`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub projected_change: Option<f64>,
+    /// Uncertainty band around `projected_change`; wide when the fit is poor.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub projected_interval: Option<ChurnInterval>,
+}
+
+/// Lower and upper bound of a projected commit count.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ChurnInterval {
+    pub low: f64,
+    pub high: f64,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
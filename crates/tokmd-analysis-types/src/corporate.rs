@@ -15,4 +15,28 @@ pub struct DomainStat {
     pub domain: String,
     pub commits: u32,
     pub pct: f32,
+    /// Subdomains counted under this parent by [`DomainGrouping::parents`], sorted.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub members: Vec<String>,
+}
+
+/// How commit author domains are merged before the fingerprint is counted.
+///
+/// Entries match the domain itself and any subdomain of it.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DomainGrouping {
+    /// Organization domains; `eng.acme.com` counts under a listed `acme.com`.
+    pub parents: Vec<String>,
+    /// Domains kept under their own name even when on the built-in
+    /// personal-mail list.
+    pub allow: Vec<String>,
+    /// Domains left out of the fingerprint entirely, e.g. `gmail.com`.
+    pub deny: Vec<String>,
+}
+
+impl DomainGrouping {
+    /// Whether no grouping is configured.
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty() && self.allow.is_empty() && self.deny.is_empty()
+    }
 }
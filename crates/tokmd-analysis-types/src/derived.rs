@@ -29,6 +29,15 @@ pub struct DerivedReport {
     pub cocomo: Option<CocomoReport>,
     pub todo: Option<TodoReport>,
     pub integrity: IntegrityReport,
+    /// Files over the oversized-file cap, largest first. They are left out of
+    /// every other derived metric.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub oversized_files: Vec<OversizedFileRow>,
+    /// Files read through a detected legacy encoding instead of UTF-8,
+    /// sorted by path. Only analyses built with the `encoding` feature
+    /// fill it.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub non_utf8_files: Vec<NonUtf8FileRow>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -85,6 +94,21 @@ pub struct MaxFileRow {
     pub file: FileStatRow,
 }
 
+/// A file excluded from analysis metrics for exceeding the size cap.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OversizedFileRow {
+    pub path: String,
+    pub bytes: usize,
+}
+
+/// A file transcoded to UTF-8 before content analysis.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NonUtf8FileRow {
+    pub path: String,
+    /// WHATWG encoding name, such as `Shift_JIS` or `windows-1252`.
+    pub encoding: String,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FileStatRow {
     pub path: String,
@@ -167,12 +191,19 @@ pub struct DistributionReport {
     pub gini: f64,
 }
 
+/// One file-size bucket. Buckets are contiguous and ordered, so `min`/`max`
+/// are enough to rebuild the histogram without reading `label`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HistogramBucket {
+    /// Display name, such as "Small".
     pub label: String,
+    /// Smallest line count in the bucket, inclusive.
     pub min: usize,
+    /// Largest line count in the bucket, inclusive; `null` for the open-ended
+    /// last bucket.
     pub max: Option<usize>,
     pub files: usize,
+    /// Share of files in the bucket, 0.0 to 1.0.
     pub pct: f64,
 }
 
@@ -213,9 +244,4 @@ pub struct ContextWindowReport {
     pub fits: bool,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct IntegrityReport {
-    pub algo: String,
-    pub hash: String,
-    pub entries: usize,
-}
+pub use tokmd_types::IntegrityReport;
@@ -113,6 +113,26 @@ pub struct CommitIntentReport {
     /// Corrective ratio: (fix + revert) / total. Range [0.0, 1.0].
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub corrective_ratio: Option<f64>,
+    /// Most recent commits with their intent, newest first (bounded).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub recent: Vec<RecentCommitIntent>,
+    /// Commits whose message was cut to the message byte limit before
+    /// classification; absent when none were.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub truncated_messages: Option<usize>,
+}
+
+/// One recent commit and its classified intent.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecentCommitIntent {
+    /// Full commit hash, when git reported one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hash: Option<String>,
+    /// Commit time, Unix seconds.
+    pub timestamp: i64,
+    /// Commit subject line.
+    pub subject: String,
+    pub intent: CommitIntentKind,
 }
 
 /// Counts per intent kind.
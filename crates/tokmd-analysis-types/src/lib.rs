@@ -33,12 +33,18 @@ mod fun;
 mod git;
 mod imports;
 mod license;
+mod line_endings;
 mod receipt;
+mod risk_surface;
+mod sampling;
 mod source;
 mod topics;
 pub mod util;
 
-pub use api_surface::{ApiExportItem, ApiSurfaceReport, LangApiSurface, ModuleApiRow};
+pub use api_surface::{
+    ApiDocThresholds, ApiDocViolation, ApiExportItem, ApiItem, ApiSurfaceDiff, ApiSurfaceReport,
+    LangApiSurface, ModuleApiChange, ModuleApiRow,
+};
 pub use archetype::Archetype;
 pub use args::AnalysisArgsMeta;
 pub use assets::{AssetCategoryRow, AssetFileRow, AssetReport};
@@ -46,19 +52,20 @@ pub use baseline::{
     BASELINE_VERSION, BaselineComplexitySection, BaselineMetrics, ComplexityBaseline,
     DeterminismBaseline, FileBaselineEntry,
 };
-pub use churn::{ChurnTrend, PredictiveChurnReport, TrendClass};
+pub use churn::{ChurnInterval, ChurnTrend, PredictiveChurnReport, TrendClass};
 pub use complexity::{
     ComplexityHistogram, ComplexityReport, ComplexityRisk, FileComplexity,
     FunctionComplexityDetail, HalsteadMetrics, MaintainabilityIndex, TechnicalDebtLevel,
     TechnicalDebtRatio,
 };
-pub use corporate::{CorporateFingerprint, DomainStat};
+pub use corporate::{CorporateFingerprint, DomainGrouping, DomainStat};
 pub use dependencies::{DependencyReport, LockfileReport};
 pub use derived::{
     BoilerplateReport, ContextWindowReport, DerivedReport, DerivedTotals, DistributionReport,
     FileStatRow, HistogramBucket, IntegrityReport, LangPurityReport, LangPurityRow, MaxFileReport,
-    MaxFileRow, NestingReport, NestingRow, PolyglotReport, RateReport, RateRow, RatioReport,
-    RatioRow, ReadingTimeReport, TestDensityReport, TodoReport, TodoTagRow, TopOffenders,
+    MaxFileRow, NestingReport, NestingRow, NonUtf8FileRow, OversizedFileRow, PolyglotReport,
+    RateReport, RateRow, RatioReport, RatioRow, ReadingTimeReport, TestDensityReport, TodoReport,
+    TodoTagRow, TopOffenders,
 };
 pub use duplication::{
     DuplicateGroup, DuplicateReport, DuplicationDensityReport, ModuleDuplicationDensityRow,
@@ -79,16 +86,20 @@ pub use fun::{EcoLabel, FunReport};
 pub use git::{
     BusFactorRow, CodeAgeBucket, CodeAgeDistributionReport, CommitIntentCounts, CommitIntentKind,
     CommitIntentReport, CouplingRow, FreshnessReport, GitReport, HotspotRow, ModuleFreshnessRow,
-    ModuleIntentRow,
+    ModuleIntentRow, RecentCommitIntent,
 };
 pub use imports::{ImportEdge, ImportReport};
-pub use license::{LicenseFinding, LicenseReport, LicenseSourceKind};
+pub use license::{LicenseFinding, LicenseHeaderCompliance, LicenseReport, LicenseSourceKind};
+pub use line_endings::{LineEnding, LineEndingReport, MixedLineEndingFile};
 pub use receipt::AnalysisReceipt;
+pub use risk_surface::RiskSurfaceRow;
+pub use sampling::SamplingReport;
 pub use source::AnalysisSource;
 pub use topics::{TopicClouds, TopicTerm};
 pub use util::{
-    AnalysisLimits, empty_file_row, is_infra_lang, is_test_path, normalize_path, normalize_root,
-    now_ms, path_depth,
+    AnalysisLimits, RuntimeBudget, SOURCE_DATE_EPOCH, empty_file_row, file_role, fixed_now_ms,
+    is_infra_lang, is_test_path, normalize_path, normalize_root, now_ms, path_depth,
+    set_fixed_now_ms,
 };
 
 #[cfg(test)]
@@ -98,7 +109,8 @@ pub use tokmd_scan::{gini_coefficient, percentile, round_f64, safe_ratio};
 /// v7: Added coupling normalization (Jaccard/Lift), commit intent classification, near-duplicate detection.
 /// v8: Near-dup clusters, selection metadata, max_pairs guardrail, runtime stats.
 /// v9: Added effort estimation report.
-pub const ANALYSIS_SCHEMA_VERSION: u32 = 9;
+/// v10: Warnings are structured objects with a code and severity.
+pub const ANALYSIS_SCHEMA_VERSION: u32 = 10;
 
 #[cfg(test)]
 mod tests {
@@ -107,7 +119,7 @@ mod tests {
     // ── Schema version constant ───────────────────────────────────────
     #[test]
     fn analysis_schema_version_constant() -> Result<(), Box<dyn std::error::Error>> {
-        assert_eq!(ANALYSIS_SCHEMA_VERSION, 9);
+        assert_eq!(ANALYSIS_SCHEMA_VERSION, 10);
         Ok(())
     }
 }
@@ -9,6 +9,9 @@ use serde::{Deserialize, Serialize};
 pub struct LicenseReport {
     pub findings: Vec<LicenseFinding>,
     pub effective: Option<String>,
+    /// Per-file header check; present only when an expected header was given.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub header_compliance: Option<LicenseHeaderCompliance>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -19,6 +22,21 @@ pub struct LicenseFinding {
     pub source_kind: LicenseSourceKind,
 }
 
+/// Which source files carry the expected license header.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LicenseHeaderCompliance {
+    /// The header snippet searched for, e.g. `SPDX-License-Identifier: MIT`.
+    pub expected: String,
+    /// Source files checked.
+    pub checked: usize,
+    /// Checked files whose head contains the header.
+    pub compliant: usize,
+    /// `compliant / checked`, or 1.0 when nothing was checked.
+    pub ratio: f64,
+    /// Paths of checked files missing the header, sorted.
+    pub missing: Vec<String>,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum LicenseSourceKind {
@@ -0,0 +1,56 @@
+//! Line-ending hygiene receipt DTOs.
+//!
+//! These contract types are re-exported from the crate root alongside the
+//! other analysis sections.
+
+use serde::{Deserialize, Serialize};
+
+/// Repository-wide line-ending summary.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LineEndingReport {
+    /// Text files whose line breaks are all `\n`.
+    pub lf_files: usize,
+    /// Text files whose line breaks are all `\r\n`.
+    pub crlf_files: usize,
+    /// Text files that use both.
+    pub mixed_files: usize,
+    /// Text files with no line break at all.
+    pub no_line_break_files: usize,
+    /// Every mixed-ending file, sorted by path.
+    pub mixed: Vec<MixedLineEndingFile>,
+}
+
+/// A file that mixes `\n` and `\r\n` line breaks.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MixedLineEndingFile {
+    pub path: String,
+    pub module: String,
+    /// Lines ending in a bare `\n`.
+    pub lf_lines: usize,
+    /// Lines ending in `\r\n`.
+    pub crlf_lines: usize,
+}
+
+/// How one file ends its lines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LineEnding {
+    Lf,
+    Crlf,
+    Mixed,
+    /// The file has no line break to classify.
+    None,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn line_ending_uses_snake_case() -> Result<(), Box<dyn std::error::Error>> {
+        assert_eq!(serde_json::to_string(&LineEnding::Crlf)?, "\"crlf\"");
+        let back: LineEnding = serde_json::from_str("\"mixed\"")?;
+        assert_eq!(back, LineEnding::Mixed);
+        Ok(())
+    }
+}
@@ -3,14 +3,16 @@
 //! This module owns the serde-stable analysis receipt envelope. Public
 //! consumers should keep using the crate-root `AnalysisReceipt` re-export.
 
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
-use tokmd_types::{ScanStatus, ToolInfo};
+use tokmd_types::{ScanStatus, ToolInfo, Warning};
 
 use crate::{
     AnalysisArgsMeta, AnalysisSource, ApiSurfaceReport, Archetype, AssetReport, ComplexityReport,
     CorporateFingerprint, DependencyReport, DerivedReport, DuplicateReport, EffortEstimateReport,
-    EntropyReport, FunReport, GitReport, ImportReport, LicenseReport, PredictiveChurnReport,
-    TopicClouds,
+    EntropyReport, FunReport, GitReport, ImportReport, LicenseReport, LineEndingReport,
+    PredictiveChurnReport, RiskSurfaceRow, SamplingReport, TopicClouds,
 };
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -20,7 +22,7 @@ pub struct AnalysisReceipt {
     pub tool: ToolInfo,
     pub mode: String,
     pub status: ScanStatus,
-    pub warnings: Vec<String>,
+    pub warnings: Vec<Warning>,
     pub source: AnalysisSource,
     pub args: AnalysisArgsMeta,
     pub archetype: Option<Archetype>,
@@ -39,4 +41,24 @@ pub struct AnalysisReceipt {
     pub api_surface: Option<ApiSurfaceReport>,
     pub effort: Option<EffortEstimateReport>,
     pub fun: Option<FunReport>,
+    /// LF/CRLF consistency across text files; absent for presets that do
+    /// not check it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub line_endings: Option<LineEndingReport>,
+    /// Wall-clock milliseconds per analysis phase, recorded only when timing
+    /// was requested.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timings: Option<BTreeMap<String, u64>>,
+    /// Present when only a sample of files was analyzed; records the rate and
+    /// whole-scan totals extrapolated from the sample.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sampling: Option<SamplingReport>,
+    /// Files ranked by combined complexity, churn, and coverage gap; empty
+    /// unless both complexity and git history were analyzed.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub risk_surface: Vec<RiskSurfaceRow>,
+    /// Values attached by embedder-supplied metrics, keyed by metric name;
+    /// tokmd itself never writes here.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub extensions: BTreeMap<String, serde_json::Value>,
 }
@@ -0,0 +1,26 @@
+//! Risk surface receipt DTOs.
+//!
+//! These contract types are re-exported from the crate root alongside the
+//! other analysis sections.
+
+use serde::{Deserialize, Serialize};
+
+/// One file in the risk surface: where complexity, churn, and missing test
+/// coverage meet.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RiskSurfaceRow {
+    pub path: String,
+    /// Composite score from 0.0 to 1.0: the mean of the signals available
+    /// for this file, each scaled against the highest value in the report.
+    pub score: f64,
+    /// File cyclomatic complexity, when the file was in the complexity report.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cyclomatic: Option<usize>,
+    /// Commits touching the file, when git history was scanned.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commits: Option<usize>,
+    /// Fraction of instrumented lines hit, when a coverage report listed the
+    /// file.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub coverage: Option<f64>,
+}
@@ -0,0 +1,23 @@
+//! Sampled-analysis receipt DTOs.
+//!
+//! These contract types are re-exported from the crate root alongside the
+//! other analysis sections.
+
+use serde::{Deserialize, Serialize};
+
+use crate::DerivedTotals;
+
+/// Marks a receipt computed from a deterministic subset of the scanned files.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SamplingReport {
+    /// Always `true`; every other section describes only the sampled files.
+    pub sampled: bool,
+    /// Share of files kept, greater than 0 and at most 1.
+    pub rate: f64,
+    /// Files in the scan before sampling.
+    pub total_files: usize,
+    /// Files kept by the sample.
+    pub sampled_files: usize,
+    /// `derived.totals` scaled by `1 / rate`. Estimates, not counts.
+    pub estimated_totals: DerivedTotals,
+}
@@ -17,4 +17,7 @@ pub struct AnalysisSource {
     pub module_roots: Vec<String>,
     pub module_depth: usize,
     pub children: String,
+    /// Tokenizer model the analyzed token counts assume.
+    #[serde(default = "tokmd_types::default_token_model")]
+    pub token_model: String,
 }
@@ -4,9 +4,13 @@
 //! calculations without adding orchestration or rendering behavior.
 
 use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
 #[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
 use std::time::{SystemTime, UNIX_EPOCH};
 
+use tokmd_types::FileRole;
+
 use crate::FileStatRow;
 
 #[derive(Debug, Clone, Default)]
@@ -16,20 +20,102 @@ pub struct AnalysisLimits {
     pub max_file_bytes: Option<u64>,
     pub max_commits: Option<usize>,
     pub max_commit_files: Option<usize>,
+    /// Commit message bytes kept per commit for intent classification;
+    /// defaults to `tokmd_git::DEFAULT_MAX_COMMIT_MESSAGE_BYTES` when unset.
+    pub max_commit_message_bytes: Option<usize>,
+    /// Files larger than this many bytes are left out of every metric and
+    /// listed in `derived.oversized_files` instead.
+    pub oversized_file_bytes: Option<u64>,
+    /// Strip a leading byte-order mark (transcoding UTF-16 to UTF-8) before
+    /// content analysis reads a file.
+    pub strip_bom: bool,
+    /// Analyze only this share of files, chosen deterministically by path
+    /// hash; the receipt's `sampling` section records it.
+    pub sample_rate: Option<f64>,
+    /// Wall-clock allowance for the run. Once it runs out, phases that have
+    /// not started are skipped, file loops stop early, and the receipt is
+    /// marked partial.
+    pub runtime_budget: Option<RuntimeBudget>,
+}
+
+impl AnalysisLimits {
+    /// Whether the runtime budget, if any, has run out.
+    pub fn out_of_time(&self) -> bool {
+        self.runtime_budget.is_some_and(|budget| budget.exhausted())
+    }
+}
+
+/// A wall-clock allowance that starts counting when created, so a caller can
+/// start it before scanning and let the scan's time count against it.
+///
+/// The clock is only read when a budget is set, which keeps runs without one
+/// usable on targets that have no monotonic clock.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RuntimeBudget {
+    limit: Duration,
+    started: Instant,
+}
+
+impl RuntimeBudget {
+    /// Start a budget of `limit_ms` milliseconds now.
+    pub fn start(limit_ms: u64) -> Self {
+        Self {
+            limit: Duration::from_millis(limit_ms),
+            started: Instant::now(),
+        }
+    }
+
+    pub fn limit_ms(&self) -> u64 {
+        u64::try_from(self.limit.as_millis()).unwrap_or(u64::MAX)
+    }
+
+    pub fn exhausted(&self) -> bool {
+        self.started.elapsed() >= self.limit
+    }
 }
 
+/// Environment variable from the reproducible-builds spec: Unix seconds that
+/// replace the wall clock in receipts.
+pub const SOURCE_DATE_EPOCH: &str = "SOURCE_DATE_EPOCH";
+
+static FIXED_NOW_MS: Mutex<Option<u128>> = Mutex::new(None);
+
+/// Pin [`now_ms`] to `ms` for this process, or restore the wall clock with
+/// `None`. Takes precedence over [`SOURCE_DATE_EPOCH`].
+pub fn set_fixed_now_ms(ms: Option<u128>) {
+    *FIXED_NOW_MS.lock().unwrap_or_else(|e| e.into_inner()) = ms;
+}
+
+/// The pinned timestamp, if any: the explicit override, else
+/// `SOURCE_DATE_EPOCH` (whole seconds). Unparsable values are ignored.
+pub fn fixed_now_ms() -> Option<u128> {
+    let explicit = *FIXED_NOW_MS.lock().unwrap_or_else(|e| e.into_inner());
+    explicit.or_else(|| {
+        std::env::var(SOURCE_DATE_EPOCH)
+            .ok()
+            .and_then(|secs| secs.trim().parse::<u64>().ok())
+            .map(|secs| u128::from(secs) * 1000)
+    })
+}
+
+/// Milliseconds since the Unix epoch, or the [`fixed_now_ms`] value when one
+/// is set so repeated runs produce identical receipts.
 #[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
 pub fn now_ms() -> u128 {
     // Keep wasm receipts from reusing zero as a fake wall-clock sentinel.
-    js_sys::Date::now().max(1.0) as u128
+    fixed_now_ms().unwrap_or_else(|| js_sys::Date::now().max(1.0) as u128)
 }
 
+/// Milliseconds since the Unix epoch, or the [`fixed_now_ms`] value when one
+/// is set so repeated runs produce identical receipts.
 #[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
 pub fn now_ms() -> u128 {
-    SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .unwrap_or_default()
-        .as_millis()
+    fixed_now_ms().unwrap_or_else(|| {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis()
+    })
 }
 
 pub fn normalize_path(path: &str, root: &Path) -> String {
@@ -64,6 +150,47 @@ pub fn is_test_path(path: &str) -> bool {
         || name.ends_with("_test.rs")
 }
 
+/// Name fragments of protobuf, codegen, and bundler output.
+const GENERATED_NAME_PATTERNS: &[&str] = &[
+    ".pb.rs",
+    ".pb.go",
+    "_pb2.py",
+    ".generated.",
+    ".g.dart",
+    ".freezed.dart",
+    ".min.js",
+    ".min.css",
+];
+
+/// Classify a file as source, test, generated, or vendored from its path.
+///
+/// Vendored directories win over generated names, which win over the
+/// [`is_test_path`] rules; everything else is source.
+pub fn file_role(path: &str) -> FileRole {
+    let lower = path.replace('\\', "/").to_lowercase();
+    if lower.split('/').any(|segment| {
+        matches!(
+            segment,
+            "vendor" | "vendored" | "third_party" | "third-party" | "node_modules"
+        )
+    }) {
+        return FileRole::Vendored;
+    }
+    let name = lower.rsplit('/').next().unwrap_or(&lower);
+    if lower.split('/').any(|segment| segment == "generated")
+        || GENERATED_NAME_PATTERNS
+            .iter()
+            .any(|pattern| name.contains(pattern))
+    {
+        return FileRole::Generated;
+    }
+    if is_test_path(&lower) {
+        FileRole::Test
+    } else {
+        FileRole::Source
+    }
+}
+
 pub fn is_infra_lang(lang: &str) -> bool {
     let l = lang.to_lowercase();
     matches!(
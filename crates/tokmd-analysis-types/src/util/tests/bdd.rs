@@ -27,6 +27,11 @@ fn analysis_limits_can_be_constructed_with_values() {
         max_file_bytes: Some(50_000),
         max_commits: Some(500),
         max_commit_files: Some(20),
+        oversized_file_bytes: None,
+        max_commit_message_bytes: None,
+        strip_bom: false,
+        sample_rate: None,
+        runtime_budget: None,
     };
     assert_eq!(limits.max_files, Some(100));
     assert_eq!(limits.max_bytes, Some(1_000_000));
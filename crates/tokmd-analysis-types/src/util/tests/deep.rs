@@ -285,6 +285,11 @@ fn analysis_limits_clone_is_equal_to_original() {
         max_file_bytes: Some(10_000),
         max_commits: Some(200),
         max_commit_files: Some(15),
+        oversized_file_bytes: None,
+        max_commit_message_bytes: None,
+        strip_bom: false,
+        sample_rate: None,
+        runtime_budget: None,
     };
     let cloned = original.clone();
     assert_eq!(cloned.max_files, original.max_files);
@@ -327,6 +332,11 @@ fn analysis_limits_max_values() {
         max_file_bytes: Some(u64::MAX),
         max_commits: Some(usize::MAX),
         max_commit_files: Some(usize::MAX),
+        oversized_file_bytes: None,
+        max_commit_message_bytes: None,
+        strip_bom: false,
+        sample_rate: None,
+        runtime_budget: None,
     };
     assert_eq!(limits.max_files, Some(usize::MAX));
     assert_eq!(limits.max_bytes, Some(u64::MAX));
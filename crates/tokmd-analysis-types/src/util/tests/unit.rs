@@ -3,8 +3,9 @@
 use std::path::{Path, PathBuf};
 
 use crate::{
-    AnalysisLimits, empty_file_row, gini_coefficient, is_infra_lang, is_test_path, normalize_path,
-    normalize_root, now_ms, path_depth, percentile, round_f64, safe_ratio,
+    AnalysisLimits, RuntimeBudget, empty_file_row, file_role, gini_coefficient, is_infra_lang,
+    is_test_path, normalize_path, normalize_root, now_ms, path_depth, percentile, round_f64,
+    safe_ratio,
 };
 
 // ── normalize_path edge cases ───────────────────────────────────────────────
@@ -68,6 +69,30 @@ fn path_depth_three_segments() {
     assert_eq!(path_depth("a/b/c"), 3);
 }
 
+// ── file_role ───────────────────────────────────────────────────────────────
+
+#[test]
+fn file_role_classifies_tests_generated_and_vendored() {
+    use tokmd_types::FileRole;
+
+    assert_eq!(file_role("src/parser_test.rs"), FileRole::Test);
+    assert_eq!(file_role("src/proto/api.pb.rs"), FileRole::Generated);
+    assert_eq!(file_role("web/generated/client.ts"), FileRole::Generated);
+    assert_eq!(file_role("vendor/zlib/inflate.c"), FileRole::Vendored);
+    assert_eq!(file_role("src\\lib.rs"), FileRole::Source);
+}
+
+#[test]
+fn file_role_prefers_vendored_then_generated_over_test() {
+    use tokmd_types::FileRole;
+
+    assert_eq!(
+        file_role("third_party/lib/tests/it_test.rs"),
+        FileRole::Vendored
+    );
+    assert_eq!(file_role("tests/fixtures/api.pb.rs"), FileRole::Generated);
+}
+
 // ── is_test_path edge cases ─────────────────────────────────────────────────
 
 #[test]
@@ -303,3 +328,25 @@ fn gini_coefficient_increasing_sequence() {
     let g = gini_coefficient(&[1, 2, 3, 4, 5]);
     assert!(g > 0.0 && g < 1.0, "expected gini in (0,1), got {}", g);
 }
+
+// ── RuntimeBudget ───────────────────────────────────────────────────────────
+
+#[test]
+fn zero_runtime_budget_is_exhausted_immediately() {
+    let limits = AnalysisLimits {
+        runtime_budget: Some(RuntimeBudget::start(0)),
+        ..Default::default()
+    };
+    assert!(limits.out_of_time());
+    assert_eq!(limits.runtime_budget.unwrap().limit_ms(), 0);
+}
+
+#[test]
+fn generous_runtime_budget_and_no_budget_leave_time() {
+    let limits = AnalysisLimits {
+        runtime_budget: Some(RuntimeBudget::start(3_600_000)),
+        ..Default::default()
+    };
+    assert!(!limits.out_of_time());
+    assert!(!AnalysisLimits::default().out_of_time());
+}
@@ -86,6 +86,11 @@ fn analysis_limits_override_individual_fields() {
         max_file_bytes: None,
         max_commits: Some(500),
         max_commit_files: None,
+        oversized_file_bytes: None,
+        max_commit_message_bytes: None,
+        strip_bom: false,
+        sample_rate: None,
+        runtime_budget: None,
     };
     assert_eq!(limits.max_files, Some(100));
     assert_eq!(limits.max_bytes, Some(1_048_576));
@@ -101,6 +106,11 @@ fn analysis_limits_clone_preserves_values() {
         max_file_bytes: Some(100),
         max_commits: Some(10),
         max_commit_files: Some(5),
+        oversized_file_bytes: None,
+        max_commit_message_bytes: None,
+        strip_bom: false,
+        sample_rate: None,
+        runtime_budget: None,
     };
     let cloned = limits.clone();
     assert_eq!(cloned.max_files, Some(42));
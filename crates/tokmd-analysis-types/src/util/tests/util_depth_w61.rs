@@ -240,6 +240,11 @@ fn analysis_limits_can_be_partially_set() {
         max_file_bytes: Some(4096),
         max_commits: None,
         max_commit_files: None,
+        oversized_file_bytes: None,
+        max_commit_message_bytes: None,
+        strip_bom: false,
+        sample_rate: None,
+        runtime_budget: None,
     };
     assert_eq!(lim.max_files, Some(100));
     assert!(lim.max_bytes.is_none());
@@ -30,6 +30,7 @@ fn minimal_receipt() -> AnalysisReceipt {
             module_roots: vec![],
             module_depth: 2,
             children: "parents-only".to_string(),
+            token_model: "heuristic".to_string(),
         },
         args: AnalysisArgsMeta {
             preset: "receipt".to_string(),
@@ -42,6 +43,14 @@ fn minimal_receipt() -> AnalysisReceipt {
             max_commit_files: None,
             max_file_bytes: None,
             import_granularity: "module".to_string(),
+            oversized_file_bytes: None,
+            max_commit_message_bytes: None,
+            strip_bom: false,
+            preset_sections: None,
+            include_sections: vec![],
+            exclude_sections: vec![],
+            sample_rate: None,
+            max_runtime_ms: None,
         },
         archetype: None,
         topics: None,
@@ -59,6 +68,11 @@ fn minimal_receipt() -> AnalysisReceipt {
         complexity: None,
         api_surface: None,
         fun: None,
+        timings: None,
+        line_endings: None,
+        sampling: None,
+        risk_surface: Vec::new(),
+        extensions: Default::default(),
     }
 }
 
@@ -140,6 +154,8 @@ fn receipt_with_derived() -> AnalysisReceipt {
             hash: "abc123".to_string(),
             entries: 10,
         },
+        oversized_files: vec![],
+        non_utf8_files: vec![],
     });
     r
 }
@@ -203,7 +219,7 @@ fn file_stat_row(path: &str) -> FileStatRow {
 fn minimal_receipt_serializes_to_json() {
     let r = minimal_receipt();
     let json = serde_json::to_string(&r).unwrap();
-    assert!(json.contains("\"schema_version\":9"));
+    assert!(json.contains("\"schema_version\":10"));
     assert!(json.contains("\"mode\":\"receipt\""));
 }
 
@@ -254,8 +270,8 @@ fn receipt_source_fields_preserved() {
 // ──────────────────────────────────────────────────────────────────────
 
 #[test]
-fn analysis_schema_version_is_9() {
-    assert_eq!(ANALYSIS_SCHEMA_VERSION, 9);
+fn analysis_schema_version_is_10() {
+    assert_eq!(ANALYSIS_SCHEMA_VERSION, 10);
 }
 
 #[test]
@@ -513,6 +529,8 @@ fn complexity_baseline_from_receipt_with_complexity() {
             hash: "def456".to_string(),
             entries: 5,
         },
+        oversized_files: vec![],
+        non_utf8_files: vec![],
     });
     r.complexity = Some(ComplexityReport {
         total_functions: 15,
@@ -754,10 +772,16 @@ fn api_surface_report_roundtrip() {
                 public_items: 30,
                 internal_items: 70,
                 public_ratio: 0.3,
+                documented_ratio: 0.0,
             },
         )]),
         by_module: vec![],
         top_exporters: vec![],
+        public_symbols: Default::default(),
+        doc_violations: vec![],
+        items: None,
+        items_truncated: false,
+        public_symbols_truncated: false,
     };
     let json = serde_json::to_string(&report).unwrap();
     let back: ApiSurfaceReport = serde_json::from_str(&json).unwrap();
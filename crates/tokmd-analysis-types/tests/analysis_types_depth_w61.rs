@@ -33,6 +33,7 @@ fn source() -> AnalysisSource {
         module_roots: vec![],
         module_depth: 2,
         children: "parents-only".into(),
+        token_model: "heuristic".to_string(),
     }
 }
 
@@ -48,6 +49,14 @@ fn args() -> AnalysisArgsMeta {
         max_commit_files: None,
         max_file_bytes: None,
         import_granularity: "module".into(),
+        oversized_file_bytes: None,
+        max_commit_message_bytes: None,
+        strip_bom: false,
+        preset_sections: None,
+        include_sections: vec![],
+        exclude_sections: vec![],
+        sample_rate: None,
+        max_runtime_ms: None,
     }
 }
 
@@ -77,6 +86,11 @@ fn minimal_receipt() -> AnalysisReceipt {
         complexity: None,
         api_surface: None,
         fun: None,
+        timings: None,
+        line_endings: None,
+        sampling: None,
+        risk_surface: Vec::new(),
+        extensions: Default::default(),
     }
 }
 
@@ -85,8 +99,8 @@ fn minimal_receipt() -> AnalysisReceipt {
 // ══════════════════════════════════════════════════════════════════════
 
 #[test]
-fn schema_version_is_9() {
-    assert_eq!(ANALYSIS_SCHEMA_VERSION, 9);
+fn schema_version_is_10() {
+    assert_eq!(ANALYSIS_SCHEMA_VERSION, 10);
 }
 
 #[test]
@@ -354,6 +368,7 @@ fn api_surface_report_serde_roundtrip() {
             public_items: 40,
             internal_items: 60,
             public_ratio: 0.4,
+            documented_ratio: 0.0,
         },
     );
     let report = ApiSurfaceReport {
@@ -375,6 +390,11 @@ fn api_surface_report_serde_roundtrip() {
             public_items: 20,
             total_items: 50,
         }],
+        public_symbols: Default::default(),
+        doc_violations: vec![],
+        items: None,
+        items_truncated: false,
+        public_symbols_truncated: false,
     };
     let json = serde_json::to_string(&report).unwrap();
     let back: ApiSurfaceReport = serde_json::from_str(&json).unwrap();
@@ -410,6 +430,8 @@ fn commit_intent_report_serde_roundtrip() {
         by_module: vec![],
         unknown_pct: 0.1,
         corrective_ratio: Some(0.15),
+        recent: vec![],
+        truncated_messages: None,
     };
     let json = serde_json::to_string(&report).unwrap();
     let back: CommitIntentReport = serde_json::from_str(&json).unwrap();
@@ -652,6 +674,8 @@ fn baseline_from_analysis_with_complexity() {
             hash: "abc".into(),
             entries: 5,
         },
+        oversized_files: vec![],
+        non_utf8_files: vec![],
     });
     receipt.complexity = Some(ComplexityReport {
         total_functions: 10,
@@ -793,6 +817,7 @@ fn determinism_baseline_serde_roundtrip() {
         build_hash: "abc".into(),
         source_hash: "def".into(),
         cargo_lock_hash: Some("ghi".into()),
+        algo: tokmd_types::HashAlgo::Blake3,
     };
     let json = serde_json::to_string(&db).unwrap();
     let back: DeterminismBaseline = serde_json::from_str(&json).unwrap();
@@ -807,6 +832,7 @@ fn determinism_baseline_without_cargo_lock() {
         build_hash: "abc".into(),
         source_hash: "def".into(),
         cargo_lock_hash: None,
+        algo: tokmd_types::HashAlgo::Blake3,
     };
     let json = serde_json::to_string(&db).unwrap();
     let back: DeterminismBaseline = serde_json::from_str(&json).unwrap();
@@ -22,6 +22,7 @@ fn base_receipt() -> AnalysisReceipt {
             module_roots: vec!["src".to_string()],
             module_depth: 1,
             children: "separate".to_string(),
+            token_model: "heuristic".to_string(),
         },
         args: AnalysisArgsMeta {
             preset: "receipt".to_string(),
@@ -34,6 +35,14 @@ fn base_receipt() -> AnalysisReceipt {
             max_commit_files: None,
             max_file_bytes: None,
             import_granularity: "module".to_string(),
+            oversized_file_bytes: None,
+            max_commit_message_bytes: None,
+            strip_bom: false,
+            preset_sections: None,
+            include_sections: vec![],
+            exclude_sections: vec![],
+            sample_rate: None,
+            max_runtime_ms: None,
         },
         archetype: None,
         topics: None,
@@ -51,6 +60,11 @@ fn base_receipt() -> AnalysisReceipt {
         complexity: None,
         api_surface: None,
         fun: None,
+        timings: None,
+        line_endings: None,
+        sampling: None,
+        risk_surface: Vec::new(),
+        extensions: Default::default(),
     }
 }
 
@@ -29,6 +29,7 @@ fn sample_source() -> AnalysisSource {
         module_roots: vec!["crates".into()],
         module_depth: 2,
         children: "separate".into(),
+        token_model: "heuristic".to_string(),
     }
 }
 
@@ -44,6 +45,14 @@ fn sample_args() -> AnalysisArgsMeta {
         max_commit_files: None,
         max_file_bytes: None,
         import_granularity: "module".into(),
+        oversized_file_bytes: None,
+        max_commit_message_bytes: None,
+        strip_bom: false,
+        preset_sections: None,
+        include_sections: vec![],
+        exclude_sections: vec![],
+        sample_rate: None,
+        max_runtime_ms: None,
     }
 }
 
@@ -73,6 +82,11 @@ fn minimal_receipt() -> AnalysisReceipt {
         complexity: None,
         api_surface: None,
         fun: None,
+        timings: None,
+        line_endings: None,
+        sampling: None,
+        risk_surface: Vec::new(),
+        extensions: Default::default(),
     }
 }
 
@@ -299,6 +313,7 @@ fn api_surface_report_roundtrip() {
             public_items: 30,
             internal_items: 70,
             public_ratio: 0.3,
+            documented_ratio: 0.0,
         },
     );
 
@@ -321,6 +336,11 @@ fn api_surface_report_roundtrip() {
             public_items: 10,
             total_items: 20,
         }],
+        public_symbols: Default::default(),
+        doc_violations: vec![],
+        items: None,
+        items_truncated: false,
+        public_symbols_truncated: false,
     };
 
     let json = serde_json::to_string(&report).unwrap();
@@ -480,6 +500,7 @@ fn analysis_source_all_fields_roundtrip() {
         module_roots: vec!["crates".into()],
         module_depth: 2,
         children: "collapse".into(),
+        token_model: "heuristic".to_string(),
     };
 
     let json = serde_json::to_string(&source).unwrap();
@@ -507,6 +528,14 @@ fn analysis_args_meta_all_fields_roundtrip() {
         max_commit_files: Some(100),
         max_file_bytes: Some(500_000),
         import_granularity: "file".into(),
+        oversized_file_bytes: None,
+        max_commit_message_bytes: None,
+        strip_bom: false,
+        preset_sections: None,
+        include_sections: vec![],
+        exclude_sections: vec![],
+        sample_rate: None,
+        max_runtime_ms: None,
     };
 
     let json = serde_json::to_string(&args).unwrap();
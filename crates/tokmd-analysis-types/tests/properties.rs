@@ -138,6 +138,8 @@ proptest! {
             r2,
             recent_change,
             classification,
+            projected_change: None,
+            projected_interval: None,
         };
 
         let json = serde_json::to_string(&trend).expect("serialize");
@@ -156,7 +158,7 @@ proptest! {
         commits in 0u32..10000,
         pct in 0.0f32..100.0
     ) {
-        let stat = DomainStat { domain, commits, pct };
+        let stat = DomainStat { domain, commits, pct, members: Vec::new() };
 
         let json = serde_json::to_string(&stat).expect("serialize");
         let parsed: DomainStat = serde_json::from_str(&json).expect("deserialize");
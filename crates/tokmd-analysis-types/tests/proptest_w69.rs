@@ -22,7 +22,7 @@ proptest! {
 
     #[test]
     fn analysis_schema_version_value(_dummy in 0..1u8) {
-        prop_assert_eq!(ANALYSIS_SCHEMA_VERSION, 9u32);
+        prop_assert_eq!(ANALYSIS_SCHEMA_VERSION, 10u32);
     }
 }
 
@@ -317,6 +317,7 @@ proptest! {
             domain: "github.com".into(),
             commits,
             pct,
+            members: Vec::new(),
         };
         let json = serde_json::to_string(&stat).unwrap();
         let parsed: DomainStat = serde_json::from_str(&json).unwrap();
@@ -377,6 +378,7 @@ proptest! {
                 module_roots: vec!["crates".into()],
                 module_depth: 2,
                 children: "collapse".into(),
+                token_model: "heuristic".to_string(),
             },
             args: AnalysisArgsMeta {
                 preset: "receipt".into(),
@@ -389,6 +391,14 @@ proptest! {
                 max_commit_files: None,
                 max_file_bytes: None,
                 import_granularity: "module".into(),
+                oversized_file_bytes: None,
+                max_commit_message_bytes: None,
+                strip_bom: false,
+                preset_sections: None,
+                include_sections: vec![],
+                exclude_sections: vec![],
+                sample_rate: None,
+                max_runtime_ms: None,
             },
             archetype: None,
             topics: None,
@@ -406,6 +416,11 @@ proptest! {
             complexity: None,
             api_surface: None,
             fun: None,
+            timings: None,
+            line_endings: None,
+            sampling: None,
+            risk_surface: Vec::new(),
+            extensions: Default::default(),
         };
         let json = serde_json::to_string(&receipt).unwrap();
         let parsed: AnalysisReceipt = serde_json::from_str(&json).unwrap();
@@ -0,0 +1,32 @@
+//! `now_ms` pinning for reproducible receipts.
+//!
+//! Kept in its own test binary: the override and `SOURCE_DATE_EPOCH` are
+//! process-global and would race with other clock tests.
+
+use tokmd_analysis_types::{SOURCE_DATE_EPOCH, fixed_now_ms, now_ms, set_fixed_now_ms};
+
+#[test]
+fn pinned_clock_makes_repeated_calls_identical() {
+    // SAFETY: this binary has a single test, so no other thread reads the env.
+    unsafe { std::env::remove_var(SOURCE_DATE_EPOCH) };
+    assert_eq!(fixed_now_ms(), None);
+
+    // SOURCE_DATE_EPOCH is whole seconds.
+    unsafe { std::env::set_var(SOURCE_DATE_EPOCH, "1700000000") };
+    assert_eq!(now_ms(), 1_700_000_000_000);
+    assert_eq!(now_ms(), now_ms());
+
+    // The explicit override wins over the environment.
+    set_fixed_now_ms(Some(42));
+    assert_eq!(now_ms(), 42);
+    assert_eq!(now_ms(), now_ms());
+    set_fixed_now_ms(None);
+    assert_eq!(now_ms(), 1_700_000_000_000);
+
+    // Garbage is ignored rather than pinning the clock to zero.
+    unsafe { std::env::set_var(SOURCE_DATE_EPOCH, "yesterday") };
+    assert_eq!(fixed_now_ms(), None);
+    assert!(now_ms() > 1_700_000_000_000);
+
+    unsafe { std::env::remove_var(SOURCE_DATE_EPOCH) };
+}
@@ -35,6 +35,7 @@ fn sample_analysis_receipt() -> AnalysisReceipt {
             module_roots: vec![],
             module_depth: 1,
             children: "separate".into(),
+            token_model: "heuristic".to_string(),
         },
         args: AnalysisArgsMeta {
             preset: "receipt".into(),
@@ -47,6 +48,14 @@ fn sample_analysis_receipt() -> AnalysisReceipt {
             max_commit_files: None,
             max_file_bytes: None,
             import_granularity: "module".into(),
+            oversized_file_bytes: None,
+            max_commit_message_bytes: None,
+            strip_bom: false,
+            preset_sections: None,
+            include_sections: vec![],
+            exclude_sections: vec![],
+            sample_rate: None,
+            max_runtime_ms: None,
         },
         archetype: None,
         topics: None,
@@ -64,6 +73,11 @@ fn sample_analysis_receipt() -> AnalysisReceipt {
         complexity: None,
         api_surface: None,
         fun: None,
+        timings: None,
+        line_endings: None,
+        sampling: None,
+        risk_surface: Vec::new(),
+        extensions: Default::default(),
     }
 }
 
@@ -78,7 +92,7 @@ fn analysis_schema_version_is_positive() {
 
 #[test]
 fn analysis_schema_version_matches_documented_value() {
-    assert_eq!(ANALYSIS_SCHEMA_VERSION, 9);
+    assert_eq!(ANALYSIS_SCHEMA_VERSION, 10);
 }
 
 // ---------------------------------------------------------------------------
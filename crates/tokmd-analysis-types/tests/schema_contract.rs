@@ -30,6 +30,7 @@ fn sample_analysis_receipt() -> AnalysisReceipt {
             module_roots: vec!["crates".into()],
             module_depth: 2,
             children: "collapse".into(),
+            token_model: "heuristic".to_string(),
         },
         args: AnalysisArgsMeta {
             preset: "receipt".into(),
@@ -42,6 +43,14 @@ fn sample_analysis_receipt() -> AnalysisReceipt {
             max_commit_files: None,
             max_file_bytes: None,
             import_granularity: "module".into(),
+            oversized_file_bytes: None,
+            max_commit_message_bytes: None,
+            strip_bom: false,
+            preset_sections: None,
+            include_sections: vec![],
+            exclude_sections: vec![],
+            sample_rate: None,
+            max_runtime_ms: None,
         },
         archetype: None,
         topics: None,
@@ -59,6 +68,11 @@ fn sample_analysis_receipt() -> AnalysisReceipt {
         complexity: None,
         api_surface: None,
         fun: None,
+        timings: None,
+        line_endings: None,
+        sampling: None,
+        risk_surface: Vec::new(),
+        extensions: Default::default(),
     }
 }
 
@@ -72,7 +86,7 @@ fn analysis_schema_version_is_positive() {
 
 #[test]
 fn analysis_schema_version_pinned() {
-    assert_eq!(ANALYSIS_SCHEMA_VERSION, 9);
+    assert_eq!(ANALYSIS_SCHEMA_VERSION, 10);
 }
 
 #[test]
@@ -244,6 +258,7 @@ mod properties {
                 module_roots: vec![],
                 module_depth: 2,
                 children: "collapse".into(),
+                token_model: "heuristic".to_string(),
             },
             args: AnalysisArgsMeta {
                 preset,
@@ -256,6 +271,14 @@ mod properties {
                 max_commit_files: None,
                 max_file_bytes: None,
                 import_granularity: "module".into(),
+                oversized_file_bytes: None,
+                max_commit_message_bytes: None,
+                strip_bom: false,
+                preset_sections: None,
+                include_sections: vec![],
+                exclude_sections: vec![],
+                sample_rate: None,
+                max_runtime_ms: None,
             },
             archetype: None,
             topics: None,
@@ -273,6 +296,11 @@ mod properties {
             complexity: None,
             api_surface: None,
             fun: None,
+            timings: None,
+            line_endings: None,
+            sampling: None,
+            risk_surface: Vec::new(),
+            extensions: Default::default(),
         })
     }
 
@@ -30,6 +30,7 @@ fn make_analysis_receipt() -> AnalysisReceipt {
             module_roots: vec![],
             module_depth: 1,
             children: "collapse".into(),
+            token_model: "heuristic".to_string(),
         },
         args: AnalysisArgsMeta {
             preset: "receipt".into(),
@@ -42,6 +43,14 @@ fn make_analysis_receipt() -> AnalysisReceipt {
             max_commit_files: None,
             max_file_bytes: None,
             import_granularity: "module".into(),
+            oversized_file_bytes: None,
+            max_commit_message_bytes: None,
+            strip_bom: false,
+            preset_sections: None,
+            include_sections: vec![],
+            exclude_sections: vec![],
+            sample_rate: None,
+            max_runtime_ms: None,
         },
         archetype: None,
         topics: None,
@@ -59,6 +68,11 @@ fn make_analysis_receipt() -> AnalysisReceipt {
         complexity: None,
         api_surface: None,
         fun: None,
+        timings: None,
+        line_endings: None,
+        sampling: None,
+        risk_surface: Vec::new(),
+        extensions: Default::default(),
     }
 }
 
@@ -79,7 +93,7 @@ fn analysis_schema_version_is_positive() {
 
 #[test]
 fn analysis_schema_version_value() {
-    assert_eq!(ANALYSIS_SCHEMA_VERSION, 9);
+    assert_eq!(ANALYSIS_SCHEMA_VERSION, 10);
 }
 
 #[test]
@@ -391,6 +405,14 @@ fn analysis_args_meta_field_names_stable() {
         max_commit_files: None,
         max_file_bytes: None,
         import_granularity: "module".into(),
+        oversized_file_bytes: None,
+        max_commit_message_bytes: None,
+        strip_bom: false,
+        preset_sections: None,
+        include_sections: vec![],
+        exclude_sections: vec![],
+        sample_rate: None,
+        max_runtime_ms: None,
     };
     let json = serde_json::to_string(&args).unwrap();
     let val: Value = serde_json::from_str(&json).unwrap();
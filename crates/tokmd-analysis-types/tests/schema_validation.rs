@@ -32,6 +32,7 @@ fn sample_analysis_source() -> AnalysisSource {
         module_roots: vec![],
         module_depth: 1,
         children: "separate".to_string(),
+        token_model: "heuristic".to_string(),
     }
 }
 
@@ -47,6 +48,14 @@ fn sample_analysis_args() -> AnalysisArgsMeta {
         max_commit_files: None,
         max_file_bytes: None,
         import_granularity: "module".to_string(),
+        oversized_file_bytes: None,
+        max_commit_message_bytes: None,
+        strip_bom: false,
+        preset_sections: None,
+        include_sections: vec![],
+        exclude_sections: vec![],
+        sample_rate: None,
+        max_runtime_ms: None,
     }
 }
 
@@ -76,6 +85,11 @@ fn sample_analysis_receipt() -> AnalysisReceipt {
         complexity: None,
         api_surface: None,
         fun: None,
+        timings: None,
+        line_endings: None,
+        sampling: None,
+        risk_surface: Vec::new(),
+        extensions: Default::default(),
     }
 }
 
@@ -86,7 +100,7 @@ fn sample_analysis_receipt() -> AnalysisReceipt {
 #[test]
 fn analysis_schema_version_matches_expected() {
     assert_eq!(
-        ANALYSIS_SCHEMA_VERSION, 9,
+        ANALYSIS_SCHEMA_VERSION, 10,
         "ANALYSIS_SCHEMA_VERSION changed — update docs/SCHEMA.md and docs/schema.json"
     );
 }
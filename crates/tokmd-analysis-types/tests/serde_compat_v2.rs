@@ -159,6 +159,31 @@ fn entropy_finding_roundtrip() {
     assert_eq!(back.path, "secrets.env");
 }
 
+#[test]
+fn analysis_source_roundtrip_preserves_token_model() {
+    let source = AnalysisSource {
+        inputs: vec![".".into()],
+        export_path: None,
+        base_receipt_path: None,
+        export_schema_version: Some(2),
+        export_generated_at_ms: None,
+        base_signature: None,
+        module_roots: vec!["crates".into()],
+        module_depth: 2,
+        children: "separate".into(),
+        token_model: "cl100k_base".into(),
+    };
+    let mut json = serde_json::to_value(source).unwrap();
+    assert_eq!(json["token_model"], "cl100k_base");
+    let back: AnalysisSource = serde_json::from_value(json.clone()).unwrap();
+    assert_eq!(back.token_model, "cl100k_base");
+
+    // Receipts written before the field existed read back as the heuristic.
+    json.as_object_mut().unwrap().remove("token_model");
+    let legacy: AnalysisSource = serde_json::from_value(json).unwrap();
+    assert_eq!(legacy.token_model, "heuristic");
+}
+
 #[test]
 fn churn_trend_roundtrip_with_classification() {
     let trend = ChurnTrend {
@@ -166,6 +191,8 @@ fn churn_trend_roundtrip_with_classification() {
         r2: 0.85,
         recent_change: 42,
         classification: TrendClass::Rising,
+        projected_change: None,
+        projected_interval: None,
     };
     let json = serde_json::to_value(trend).unwrap();
     assert_eq!(json["classification"], "rising");
@@ -4,12 +4,16 @@
 )]
 use std::path::{Path, PathBuf};
 
-use tokmd_analysis_types::AnalysisLimits;
-use tokmd_types::ExportData;
+use tokmd_analysis_types::{AnalysisLimits, ApiDocThresholds};
+use tokmd_types::{ExportData, Warning};
 
 use crate::grid::PresetPlan;
 
+#[cfg(all(feature = "content", feature = "walk"))]
+use super::super::files::enricher_failed;
+
 use super::super::outputs::AnalysisOutputs;
+use super::super::timings::PhaseTimings;
 
 #[cfg_attr(not(all(feature = "content", feature = "walk")), allow(dead_code))]
 pub(in crate::analysis) struct CodeQualityInput<'a> {
@@ -19,73 +23,86 @@ pub(in crate::analysis) struct CodeQualityInput<'a> {
     pub(in crate::analysis) plan: &'a PresetPlan,
     pub(in crate::analysis) limits: &'a AnalysisLimits,
     pub(in crate::analysis) detail_functions: bool,
+    pub(in crate::analysis) detail_api_items: bool,
+    pub(in crate::analysis) api_doc_thresholds: &'a ApiDocThresholds,
+    pub(in crate::analysis) license_header: Option<&'a str>,
 }
 
 pub(in crate::analysis) fn run(
     input: CodeQualityInput<'_>,
     outputs: &mut AnalysisOutputs,
-    warnings: &mut Vec<String>,
+    warnings: &mut Vec<Warning>,
+    timings: &mut PhaseTimings,
 ) {
-    run_entropy(&input, outputs, warnings);
-    run_license(&input, outputs, warnings);
-    run_complexity(&input, outputs, warnings);
-    run_api_surface(&input, outputs, warnings);
-    attach_halstead(&input, outputs, warnings);
+    run_entropy(&input, outputs, warnings, timings);
+    run_license(&input, outputs, warnings, timings);
+    run_complexity(&input, outputs, warnings, timings);
+    run_api_surface(&input, outputs, warnings, timings);
+    attach_halstead(&input, outputs, warnings, timings);
 }
 
 fn run_entropy(
     input: &CodeQualityInput<'_>,
     outputs: &mut AnalysisOutputs,
-    warnings: &mut Vec<String>,
+    warnings: &mut Vec<Warning>,
+    timings: &mut PhaseTimings,
 ) {
-    if input.plan.entropy {
+    if input.plan.entropy && timings.has_time("entropy") {
         #[cfg(all(feature = "content", feature = "walk"))]
         if let Some(list) = input.files {
+            let _phase = timings.phase("entropy");
             match crate::entropy::build_entropy_report(input.root, list, input.export, input.limits)
             {
                 Ok(report) => outputs.entropy = Some(report),
-                Err(err) => warnings.push(format!("entropy scan failed: {}", err)),
+                Err(err) => warnings.push(enricher_failed(format!("entropy scan failed: {}", err))),
             }
         }
         #[cfg(not(all(feature = "content", feature = "walk")))]
-        warnings.push(
-            crate::grid::DisabledFeature::EntropyProfiling
-                .warning()
-                .to_string(),
-        );
+        warnings.push(crate::grid::DisabledFeature::EntropyProfiling.to_warning());
     }
 }
 
 fn run_license(
     input: &CodeQualityInput<'_>,
     outputs: &mut AnalysisOutputs,
-    warnings: &mut Vec<String>,
+    warnings: &mut Vec<Warning>,
+    timings: &mut PhaseTimings,
 ) {
-    if input.plan.license {
+    if input.plan.license && timings.has_time("license") {
         #[cfg(all(feature = "content", feature = "walk"))]
         if let Some(list) = input.files {
+            let _phase = timings.phase("license");
             match crate::license::build_license_report(input.root, list, input.limits) {
-                Ok(report) => outputs.license = Some(report),
-                Err(err) => warnings.push(format!("license scan failed: {}", err)),
+                Ok(mut report) => {
+                    report.header_compliance = input.license_header.map(|expected| {
+                        crate::license::build_header_compliance(
+                            input.root,
+                            list,
+                            input.export,
+                            expected,
+                            input.limits,
+                        )
+                    });
+                    outputs.license = Some(report);
+                }
+                Err(err) => warnings.push(enricher_failed(format!("license scan failed: {}", err))),
             }
         }
         #[cfg(not(all(feature = "content", feature = "walk")))]
-        warnings.push(
-            crate::grid::DisabledFeature::LicenseRadar
-                .warning()
-                .to_string(),
-        );
+        warnings.push(crate::grid::DisabledFeature::LicenseRadar.to_warning());
     }
 }
 
 fn run_complexity(
     input: &CodeQualityInput<'_>,
     outputs: &mut AnalysisOutputs,
-    warnings: &mut Vec<String>,
+    warnings: &mut Vec<Warning>,
+    timings: &mut PhaseTimings,
 ) {
-    if input.plan.complexity {
+    if input.plan.complexity && timings.has_time("complexity") {
         #[cfg(all(feature = "content", feature = "walk"))]
         if let Some(list) = input.files {
+            let _phase = timings.phase("complexity");
             match crate::complexity::build_complexity_report(
                 input.root,
                 list,
@@ -106,63 +123,75 @@ fn run_complexity(
                         }
                     }
                 }
-                Err(err) => warnings.push(format!("complexity scan failed: {}", err)),
+                Err(err) => {
+                    warnings.push(enricher_failed(format!("complexity scan failed: {}", err)))
+                }
             }
         }
         #[cfg(not(all(feature = "content", feature = "walk")))]
-        warnings.push(
-            crate::grid::DisabledFeature::ComplexityAnalysis
-                .warning()
-                .to_string(),
-        );
+        warnings.push(crate::grid::DisabledFeature::ComplexityAnalysis.to_warning());
     }
 }
 
 fn run_api_surface(
     input: &CodeQualityInput<'_>,
     outputs: &mut AnalysisOutputs,
-    warnings: &mut Vec<String>,
+    warnings: &mut Vec<Warning>,
+    timings: &mut PhaseTimings,
 ) {
-    if input.plan.api_surface {
+    if input.plan.api_surface && timings.has_time("api_surface") {
         #[cfg(all(feature = "content", feature = "walk"))]
         if let Some(list) = input.files {
-            match crate::api_surface::build_api_surface_report(
+            let _phase = timings.phase("api_surface");
+            match crate::api_surface::build_api_surface_report_with_items(
                 input.root,
                 list,
                 input.export,
                 input.limits,
+                input.detail_api_items,
             ) {
-                Ok(report) => outputs.api_surface = Some(report),
-                Err(err) => warnings.push(format!("api surface scan failed: {}", err)),
+                Ok(mut report) => {
+                    report.doc_violations =
+                        crate::api_surface::check_doc_thresholds(&report, input.api_doc_thresholds);
+                    warnings.extend(
+                        report
+                            .doc_violations
+                            .iter()
+                            .map(crate::api_surface::violation_warning),
+                    );
+                    outputs.api_surface = Some(report);
+                }
+                Err(err) => {
+                    warnings.push(enricher_failed(format!("api surface scan failed: {}", err)))
+                }
             }
         }
         #[cfg(not(all(feature = "content", feature = "walk")))]
-        warnings.push(
-            crate::grid::DisabledFeature::ApiSurfaceAnalysis
-                .warning()
-                .to_string(),
-        );
+        warnings.push(crate::grid::DisabledFeature::ApiSurfaceAnalysis.to_warning());
     }
 }
 
 fn attach_halstead(
     input: &CodeQualityInput<'_>,
     outputs: &mut AnalysisOutputs,
-    warnings: &mut Vec<String>,
+    warnings: &mut Vec<Warning>,
+    timings: &mut PhaseTimings,
 ) {
     #[cfg(all(feature = "halstead", feature = "content", feature = "walk"))]
     if input.plan.halstead
+        && timings.has_time("halstead")
         && let Some(list) = input.files
     {
+        let _phase = timings.phase("halstead");
         match crate::halstead::build_halstead_report(input.root, list, input.export, input.limits) {
             Ok(halstead_report) => {
                 if let Some(ref mut complexity) = outputs.complexity {
                     crate::maintainability::attach_halstead_metrics(complexity, halstead_report);
                 }
             }
-            Err(err) => warnings.push(format!("halstead scan failed: {}", err)),
+            Err(err) => warnings.push(enricher_failed(format!("halstead scan failed: {}", err))),
         }
     }
     #[cfg(not(all(feature = "halstead", feature = "content", feature = "walk")))]
-    let _ = (input, outputs, warnings);
+    let _ = (input, outputs, warnings, timings);
 }
@@ -2,13 +2,16 @@
 use std::path::{Path, PathBuf};
 
 use tokmd_analysis_types::{AnalysisLimits, DerivedReport, DuplicateReport};
-use tokmd_types::ExportData;
+use tokmd_types::{ExportData, Warning};
 
 use crate::grid::PresetPlan;
 
 #[cfg(feature = "content")]
-use super::super::files::{ROOTLESS_FILE_ANALYSIS_WARNING, push_warning_once};
+use super::super::files::{
+    ROOTLESS_FILE_ANALYSIS_WARNING, enricher_failed, no_host_root, push_warning_once,
+};
 use super::super::outputs::AnalysisOutputs;
+use super::super::timings::PhaseTimings;
 use super::super::{AnalysisRequest, ImportGranularity};
 
 #[cfg_attr(not(feature = "content"), allow(dead_code))]
@@ -26,6 +29,8 @@ fn content_limits(limits: &AnalysisLimits) -> crate::content::ContentLimits {
     crate::content::ContentLimits {
         max_bytes: limits.max_bytes,
         max_file_bytes: limits.max_file_bytes,
+        strip_bom: limits.strip_bom,
+        runtime_budget: limits.runtime_budget,
     }
 }
 
@@ -41,63 +46,120 @@ pub(in crate::analysis) fn run(
     input: ContentInput<'_>,
     derived: &mut DerivedReport,
     outputs: &mut AnalysisOutputs,
-    warnings: &mut Vec<String>,
+    warnings: &mut Vec<Warning>,
+    timings: &mut PhaseTimings,
 ) {
-    run_todo(&input, derived, warnings);
-    run_duplicate(&input, outputs, warnings);
-    run_near_duplicate(&input, outputs, warnings);
-    run_imports(&input, outputs, warnings);
+    run_bom_scan(&input, warnings);
+    run_encoding_scan(&input, derived);
+    run_todo(&input, derived, warnings, timings);
+    run_duplicate(&input, outputs, warnings, timings);
+    run_near_duplicate(&input, outputs, warnings, timings);
+    run_imports(&input, outputs, warnings, timings);
+    run_line_endings(&input, outputs, warnings, timings);
 }
 
-fn run_todo(input: &ContentInput<'_>, derived: &mut DerivedReport, warnings: &mut Vec<String>) {
-    if input.plan.todo {
+/// Files named in the stripped-BOM warning before it is truncated.
+#[cfg(feature = "content")]
+const MAX_BOM_WARNING_PATHS: usize = 10;
+
+fn run_bom_scan(input: &ContentInput<'_>, warnings: &mut Vec<Warning>) {
+    #[cfg(feature = "content")]
+    if input.req.limits.strip_bom
+        && let Some(list) = input.files
+    {
+        let paths = crate::content::find_bom_files(input.root, list);
+        if !paths.is_empty() {
+            warnings.push(bom_warning(&paths));
+        }
+    }
+}
+
+#[cfg(feature = "content")]
+fn bom_warning(paths: &[String]) -> Warning {
+    let mut listed = paths
+        .iter()
+        .take(MAX_BOM_WARNING_PATHS)
+        .map(String::as_str)
+        .collect::<Vec<_>>()
+        .join(", ");
+    if paths.len() > MAX_BOM_WARNING_PATHS {
+        listed.push_str(&format!(" (+{} more)", paths.len() - MAX_BOM_WARNING_PATHS));
+    }
+    Warning::new(
+        tokmd_types::BOM_STRIPPED,
+        tokmd_types::WarningSeverity::Info,
+        format!(
+            "stripped byte-order mark from {} file(s) before content analysis: {}",
+            paths.len(),
+            listed
+        ),
+    )
+}
+
+#[cfg_attr(not(feature = "encoding"), allow(unused_variables))]
+fn run_encoding_scan(input: &ContentInput<'_>, derived: &mut DerivedReport) {
+    #[cfg(feature = "encoding")]
+    if let Some(list) = input.files {
+        let limits = content_limits(&input.req.limits);
+        derived.non_utf8_files = crate::content::find_non_utf8_files(input.root, list, &limits);
+    }
+}
+
+fn run_todo(
+    input: &ContentInput<'_>,
+    derived: &mut DerivedReport,
+    warnings: &mut Vec<Warning>,
+    timings: &mut PhaseTimings,
+) {
+    if input.plan.todo && timings.has_time("todo") {
         #[cfg(feature = "content")]
         if let Some(list) = input.files {
+            let _phase = timings.phase("todo");
             let limits = content_limits(&input.req.limits);
             match crate::content::build_todo_report(input.root, list, &limits, derived.totals.code)
             {
                 Ok(report) => derived.todo = Some(report),
-                Err(err) => warnings.push(format!("todo scan failed: {}", err)),
+                Err(err) => warnings.push(enricher_failed(format!("todo scan failed: {}", err))),
             }
         }
         #[cfg(not(feature = "content"))]
-        warnings.push(crate::grid::DisabledFeature::TodoScan.warning().to_string());
+        warnings.push(crate::grid::DisabledFeature::TodoScan.to_warning());
     }
 }
 
 fn run_duplicate(
     input: &ContentInput<'_>,
     outputs: &mut AnalysisOutputs,
-    warnings: &mut Vec<String>,
+    warnings: &mut Vec<Warning>,
+    timings: &mut PhaseTimings,
 ) {
-    if input.plan.dup {
+    if input.plan.dup && timings.has_time("dup") {
         #[cfg(feature = "content")]
         if let Some(list) = input.files {
+            let _phase = timings.phase("dup");
             let limits = content_limits(&input.req.limits);
             match crate::content::build_duplicate_report(input.root, list, input.export, &limits) {
                 Ok(report) => outputs.dup = Some(report),
-                Err(err) => warnings.push(format!("dup scan failed: {}", err)),
+                Err(err) => warnings.push(enricher_failed(format!("dup scan failed: {}", err))),
             }
         }
         #[cfg(not(feature = "content"))]
-        warnings.push(
-            crate::grid::DisabledFeature::DuplicationScan
-                .warning()
-                .to_string(),
-        );
+        warnings.push(crate::grid::DisabledFeature::DuplicationScan.to_warning());
     }
 }
 
 fn run_near_duplicate(
     input: &ContentInput<'_>,
     outputs: &mut AnalysisOutputs,
-    warnings: &mut Vec<String>,
+    warnings: &mut Vec<Warning>,
+    timings: &mut PhaseTimings,
 ) {
     let req = input.req;
-    if req.near_dup {
+    if req.near_dup && timings.has_time("near_dup") {
         #[cfg(feature = "content")]
         {
             if input.has_host_root {
+                let _phase = timings.phase("near_dup");
                 let near_dup_limits = crate::near_dup::NearDupLimits {
                     max_bytes: req.limits.max_bytes,
                     max_file_bytes: req.limits.max_file_bytes,
@@ -125,29 +187,29 @@ fn run_near_duplicate(
                             });
                         }
                     }
-                    Err(err) => warnings.push(format!("near-dup scan failed: {}", err)),
+                    Err(err) => {
+                        warnings.push(enricher_failed(format!("near-dup scan failed: {}", err)))
+                    }
                 }
             } else {
-                push_warning_once(warnings, ROOTLESS_FILE_ANALYSIS_WARNING);
+                push_warning_once(warnings, no_host_root(ROOTLESS_FILE_ANALYSIS_WARNING));
             }
         }
         #[cfg(not(feature = "content"))]
-        warnings.push(
-            crate::grid::DisabledFeature::NearDuplicateScan
-                .warning()
-                .to_string(),
-        );
+        warnings.push(crate::grid::DisabledFeature::NearDuplicateScan.to_warning());
     }
 }
 
 fn run_imports(
     input: &ContentInput<'_>,
     outputs: &mut AnalysisOutputs,
-    warnings: &mut Vec<String>,
+    warnings: &mut Vec<Warning>,
+    timings: &mut PhaseTimings,
 ) {
-    if input.plan.imports {
+    if input.plan.imports && timings.has_time("imports") {
         #[cfg(feature = "content")]
         if let Some(list) = input.files {
+            let _phase = timings.phase("imports");
             let limits = content_limits(&input.req.limits);
             let granularity = content_import_granularity(input.req.import_granularity);
             match crate::content::build_import_report(
@@ -158,14 +220,37 @@ fn run_imports(
                 &limits,
             ) {
                 Ok(report) => outputs.imports = Some(report),
-                Err(err) => warnings.push(format!("import scan failed: {}", err)),
+                Err(err) => warnings.push(enricher_failed(format!("import scan failed: {}", err))),
+            }
+        }
+        #[cfg(not(feature = "content"))]
+        warnings.push(crate::grid::DisabledFeature::ImportScan.to_warning());
+    }
+}
+
+fn run_line_endings(
+    input: &ContentInput<'_>,
+    outputs: &mut AnalysisOutputs,
+    warnings: &mut Vec<Warning>,
+    timings: &mut PhaseTimings,
+) {
+    if input.plan.line_endings && timings.has_time("line_endings") {
+        #[cfg(feature = "content")]
+        if let Some(list) = input.files {
+            let _phase = timings.phase("line_endings");
+            match crate::line_endings::build_line_ending_report(
+                input.root,
+                list,
+                input.export,
+                &input.req.limits,
+            ) {
+                Ok(report) => outputs.line_endings = Some(report),
+                Err(err) => {
+                    warnings.push(enricher_failed(format!("line-ending scan failed: {}", err)))
+                }
             }
         }
         #[cfg(not(feature = "content"))]
-        warnings.push(
-            crate::grid::DisabledFeature::ImportScan
-                .warning()
-                .to_string(),
-        );
+        warnings.push(crate::grid::DisabledFeature::LineEndingScan.to_warning());
     }
 }
@@ -1,11 +1,13 @@
 use std::path::Path;
 
 use tokmd_analysis_types::{DerivedReport, EffortEstimateReport};
-use tokmd_types::ExportData;
+use tokmd_types::{ExportData, Warning};
 
 use crate::effort::{EffortRequest, build_effort_report};
 
+use super::super::files::enricher_failed;
 use super::super::outputs::AnalysisOutputs;
+use super::super::timings::PhaseTimings;
 
 pub(in crate::analysis) fn run(
     root: &Path,
@@ -13,9 +15,14 @@ pub(in crate::analysis) fn run(
     derived: &DerivedReport,
     outputs: &AnalysisOutputs,
     request: Option<&EffortRequest>,
-    warnings: &mut Vec<String>,
+    warnings: &mut Vec<Warning>,
+    timings: &mut PhaseTimings,
 ) -> Option<EffortEstimateReport> {
     let effort_request = request?;
+    if !timings.has_time("effort") {
+        return None;
+    }
+    let _phase = timings.phase("effort");
     match build_effort_report(
         root,
         export,
@@ -28,7 +35,7 @@ pub(in crate::analysis) fn run(
     ) {
         Ok(report) => Some(report),
         Err(err) => {
-            warnings.push(format!("effort estimate failed: {}", err));
+            warnings.push(enricher_failed(format!("effort estimate failed: {}", err)));
             None
         }
     }
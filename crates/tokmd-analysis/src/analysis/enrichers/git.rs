@@ -1,13 +1,17 @@
 #![cfg_attr(not(feature = "git"), allow(unused_imports))]
 use std::path::Path;
 
-use tokmd_types::ExportData;
+use tokmd_analysis_types::DomainGrouping;
+use tokmd_types::{ExportData, Warning};
 
 use crate::grid::PresetPlan;
 
 #[cfg(feature = "git")]
-use super::super::files::{ROOTLESS_GIT_ANALYSIS_WARNING, push_warning_once};
+use super::super::files::{
+    ROOTLESS_GIT_ANALYSIS_WARNING, enricher_failed, no_host_root, push_warning_once,
+};
 use super::super::outputs::AnalysisOutputs;
+use super::super::timings::PhaseTimings;
 
 #[cfg_attr(not(feature = "git"), allow(dead_code))]
 pub(in crate::analysis) struct GitInput<'a> {
@@ -17,30 +21,42 @@ pub(in crate::analysis) struct GitInput<'a> {
     pub(in crate::analysis) include_git: bool,
     pub(in crate::analysis) max_commits: Option<usize>,
     pub(in crate::analysis) max_commit_files: Option<usize>,
+    pub(in crate::analysis) max_commit_message_bytes: Option<usize>,
     pub(in crate::analysis) has_host_root: bool,
+    pub(in crate::analysis) domain_grouping: &'a DomainGrouping,
+    pub(in crate::analysis) forecast_days: Option<u32>,
 }
 
 pub(in crate::analysis) fn run(
     input: GitInput<'_>,
     outputs: &mut AnalysisOutputs,
-    warnings: &mut Vec<String>,
+    warnings: &mut Vec<Warning>,
+    timings: &mut PhaseTimings,
 ) {
-    if input.include_git {
+    if input.include_git && timings.has_time("git") {
         #[cfg(feature = "git")]
         {
             if input.has_host_root {
+                let _phase = timings.phase("git");
                 let repo_root = match tokmd_git::repo_root(input.root) {
                     Some(root) => root,
                     None => {
-                        warnings.push("git scan failed: not a git repo".to_string());
+                        warnings.push(Warning::new(
+                            tokmd_types::NOT_A_GIT_REPO,
+                            tokmd_types::WarningSeverity::Warning,
+                            "git scan failed: not a git repo",
+                        ));
                         std::path::PathBuf::new()
                     }
                 };
                 if !repo_root.as_os_str().is_empty() {
-                    match tokmd_git::collect_history(
+                    match tokmd_git::collect_history_bounded(
                         &repo_root,
                         input.max_commits,
                         input.max_commit_files,
+                        input
+                            .max_commit_message_bytes
+                            .unwrap_or(tokmd_git::DEFAULT_MAX_COMMIT_MESSAGE_BYTES),
                     ) {
                         Ok(commits) => {
                             if input.plan.git {
@@ -50,36 +66,40 @@ pub(in crate::analysis) fn run(
                                     &commits,
                                 ) {
                                     Ok(report) => outputs.git = Some(report),
-                                    Err(err) => warnings.push(format!("git scan failed: {}", err)),
+                                    Err(err) => warnings
+                                        .push(enricher_failed(format!("git scan failed: {}", err))),
                                 }
                             }
                             if input.plan.churn {
-                                outputs.churn = Some(crate::git::build_predictive_churn_report(
-                                    input.export,
-                                    &commits,
-                                    &repo_root,
-                                ));
+                                outputs.churn =
+                                    Some(crate::git::build_predictive_churn_report_with(
+                                        input.export,
+                                        &commits,
+                                        &repo_root,
+                                        input.forecast_days,
+                                    ));
                             }
                             if input.plan.fingerprint {
                                 outputs.fingerprint =
-                                    Some(crate::fingerprint::build_corporate_fingerprint(&commits));
+                                    Some(crate::fingerprint::build_corporate_fingerprint_with(
+                                        &commits,
+                                        input.domain_grouping,
+                                    ));
                             }
                         }
-                        Err(err) => warnings.push(format!("git scan failed: {}", err)),
+                        Err(err) => {
+                            warnings.push(enricher_failed(format!("git scan failed: {}", err)))
+                        }
                     }
                 }
             } else {
-                push_warning_once(warnings, ROOTLESS_GIT_ANALYSIS_WARNING);
+                push_warning_once(warnings, no_host_root(ROOTLESS_GIT_ANALYSIS_WARNING));
             }
         }
         #[cfg(not(feature = "git"))]
         {
-            let _ = (input, outputs);
-            warnings.push(
-                crate::grid::DisabledFeature::GitMetrics
-                    .warning()
-                    .to_string(),
-            );
+            let _ = (input, outputs, timings);
+            warnings.push(crate::grid::DisabledFeature::GitMetrics.to_warning());
         }
     }
 }
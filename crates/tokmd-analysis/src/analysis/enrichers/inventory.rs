@@ -1,33 +1,44 @@
 #![cfg_attr(not(feature = "walk"), allow(unused_variables, clippy::ptr_arg))]
 use std::path::{Path, PathBuf};
 
+use tokmd_types::Warning;
+
 use crate::grid::PresetPlan;
 
+#[cfg(feature = "walk")]
+use super::super::files::enricher_failed;
+
 use super::super::outputs::AnalysisOutputs;
+use super::super::timings::PhaseTimings;
 
 pub(in crate::analysis) fn run(
     root: &Path,
     files: Option<&[PathBuf]>,
     plan: &PresetPlan,
     outputs: &mut AnalysisOutputs,
-    warnings: &mut Vec<String>,
+    warnings: &mut Vec<Warning>,
+    timings: &mut PhaseTimings,
 ) {
-    if plan.assets {
+    if plan.assets && timings.has_time("assets") {
         #[cfg(feature = "walk")]
         if let Some(list) = files {
+            let _phase = timings.phase("assets");
             match crate::assets::build_assets_report(root, list) {
                 Ok(report) => outputs.assets = Some(report),
-                Err(err) => warnings.push(format!("asset scan failed: {}", err)),
+                Err(err) => warnings.push(enricher_failed(format!("asset scan failed: {}", err))),
             }
         }
     }
 
-    if plan.deps {
+    if plan.deps && timings.has_time("deps") {
         #[cfg(feature = "walk")]
         if let Some(list) = files {
+            let _phase = timings.phase("deps");
             match crate::assets::build_dependency_report(root, list) {
                 Ok(report) => outputs.deps = Some(report),
-                Err(err) => warnings.push(format!("dependency scan failed: {}", err)),
+                Err(err) => {
+                    warnings.push(enricher_failed(format!("dependency scan failed: {}", err)))
+                }
             }
         }
     }
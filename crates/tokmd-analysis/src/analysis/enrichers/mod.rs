@@ -4,4 +4,5 @@ pub(super) mod content;
 pub(super) mod effort;
 pub(super) mod git;
 pub(super) mod inventory;
+pub(super) mod risk_surface;
 pub(super) mod semantic;
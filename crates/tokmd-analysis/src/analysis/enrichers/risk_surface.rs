@@ -0,0 +1,34 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use tokmd_analysis_types::RiskSurfaceRow;
+use tokmd_types::{ENRICHER_FAILED, Warning, WarningSeverity};
+
+use crate::risk_surface::{build_risk_surface, read_coverage};
+
+use super::super::outputs::AnalysisOutputs;
+
+/// Join complexity, churn, and coverage once both complexity and git history
+/// are available.
+pub(in crate::analysis) fn run(
+    root: &Path,
+    coverage_path: Option<&Path>,
+    outputs: &AnalysisOutputs,
+    warnings: &mut Vec<Warning>,
+) -> Vec<RiskSurfaceRow> {
+    let (Some(complexity), Some(git)) = (&outputs.complexity, &outputs.git) else {
+        return Vec::new();
+    };
+    let coverage = match coverage_path {
+        Some(path) => read_coverage(root, path).unwrap_or_else(|err| {
+            warnings.push(Warning::new(
+                ENRICHER_FAILED,
+                WarningSeverity::Warning,
+                format!("coverage report {} unreadable: {}", path.display(), err),
+            ));
+            BTreeMap::new()
+        }),
+        None => BTreeMap::new(),
+    };
+    build_risk_surface(&complexity.files, &git.hotspots, &coverage)
+}
@@ -1,42 +1,42 @@
 use tokmd_analysis_types::DerivedReport;
-use tokmd_types::ExportData;
+use tokmd_types::{ExportData, Warning};
 
 use crate::grid::PresetPlan;
 
 use super::super::outputs::AnalysisOutputs;
+use super::super::timings::PhaseTimings;
 
 pub(in crate::analysis) fn run(
     export: &ExportData,
     derived: &DerivedReport,
     plan: &PresetPlan,
     outputs: &mut AnalysisOutputs,
-    warnings: &mut Vec<String>,
+    warnings: &mut Vec<Warning>,
+    timings: &mut PhaseTimings,
 ) {
-    run_archetype(export, plan, outputs, warnings);
-    run_topics(export, plan, outputs, warnings);
-    run_fun(derived, plan, outputs, warnings);
+    run_archetype(export, plan, outputs, warnings, timings);
+    run_topics(export, plan, outputs, warnings, timings);
+    run_fun(derived, plan, outputs, warnings, timings);
 }
 
 fn run_archetype(
     export: &ExportData,
     plan: &PresetPlan,
     outputs: &mut AnalysisOutputs,
-    warnings: &mut Vec<String>,
+    warnings: &mut Vec<Warning>,
+    timings: &mut PhaseTimings,
 ) {
     let _ = warnings;
-    if plan.archetype {
+    if plan.archetype && timings.has_time("archetype") {
         #[cfg(feature = "archetype")]
         {
+            let _phase = timings.phase("archetype");
             outputs.archetype = crate::archetype::detect_archetype(export);
         }
         #[cfg(not(feature = "archetype"))]
         {
-            let _ = (export, outputs);
-            warnings.push(
-                crate::grid::DisabledFeature::Archetype
-                    .warning()
-                    .to_string(),
-            );
+            let _ = (export, outputs, timings);
+            warnings.push(crate::grid::DisabledFeature::Archetype.to_warning());
         }
     }
 }
@@ -45,18 +45,20 @@ fn run_topics(
     export: &ExportData,
     plan: &PresetPlan,
     outputs: &mut AnalysisOutputs,
-    warnings: &mut Vec<String>,
+    warnings: &mut Vec<Warning>,
+    timings: &mut PhaseTimings,
 ) {
     let _ = warnings;
-    if plan.topics {
+    if plan.topics && timings.has_time("topics") {
         #[cfg(feature = "topics")]
         {
+            let _phase = timings.phase("topics");
             outputs.topics = Some(crate::topics::build_topic_clouds(export));
         }
         #[cfg(not(feature = "topics"))]
         {
-            let _ = (export, outputs);
-            warnings.push(crate::grid::DisabledFeature::Topics.warning().to_string());
+            let _ = (export, outputs, timings);
+            warnings.push(crate::grid::DisabledFeature::Topics.to_warning());
         }
     }
 }
@@ -65,18 +67,20 @@ fn run_fun(
     derived: &DerivedReport,
     plan: &PresetPlan,
     outputs: &mut AnalysisOutputs,
-    warnings: &mut Vec<String>,
+    warnings: &mut Vec<Warning>,
+    timings: &mut PhaseTimings,
 ) {
     let _ = warnings;
-    if plan.fun {
+    if plan.fun && timings.has_time("fun") {
         #[cfg(feature = "fun")]
         {
+            let _phase = timings.phase("fun");
             outputs.fun = Some(crate::fun::build_fun_report(derived));
         }
         #[cfg(not(feature = "fun"))]
         {
-            let _ = (derived, outputs);
-            warnings.push(crate::grid::DisabledFeature::Fun.warning().to_string());
+            let _ = (derived, outputs, timings);
+            warnings.push(crate::grid::DisabledFeature::Fun.to_warning());
         }
     }
 }
@@ -0,0 +1,64 @@
+//! Embedder-supplied metrics attached after the built-in analysis.
+
+use std::fmt;
+use std::sync::Arc;
+
+use anyhow::Result;
+use serde_json::Value;
+use tokmd_analysis_types::AnalysisReceipt;
+use tokmd_types::{ExportData, ScanStatus};
+
+use super::files::enricher_failed;
+
+/// A custom metric computed once the built-in analysis has finished.
+///
+/// The value lands in the receipt's `extensions` map under [`name`], so it is
+/// serialized with the receipt and listed by renderers that show extensions.
+///
+/// [`name`]: AnalysisExtension::name
+pub trait AnalysisExtension: Send + Sync {
+    /// Key of the value in `extensions`.
+    fn name(&self) -> &str;
+
+    /// Compute the value from the analyzed export and the receipt built so
+    /// far. An error becomes a warning and leaves the key out.
+    fn compute(&self, export: &ExportData, receipt: &AnalysisReceipt) -> Result<Value>;
+}
+
+/// A registered [`AnalysisExtension`].
+#[derive(Clone)]
+pub struct ExtensionHook(Arc<dyn AnalysisExtension>);
+
+impl ExtensionHook {
+    pub fn new(extension: impl AnalysisExtension + 'static) -> Self {
+        Self(Arc::new(extension))
+    }
+}
+
+impl fmt::Debug for ExtensionHook {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ExtensionHook({})", self.0.name())
+    }
+}
+
+/// Run each hook in order, attaching its value. Later hooks see the values
+/// earlier ones attached; a failing hook marks the receipt partial.
+pub(super) fn attach(hooks: &[ExtensionHook], export: &ExportData, receipt: &mut AnalysisReceipt) {
+    for ExtensionHook(extension) in hooks {
+        match extension.compute(export, receipt) {
+            Ok(value) => {
+                receipt
+                    .extensions
+                    .insert(extension.name().to_string(), value);
+            }
+            Err(err) => {
+                receipt.warnings.push(enricher_failed(format!(
+                    "extension {} failed: {}",
+                    extension.name(),
+                    err
+                )));
+                receipt.status = ScanStatus::Partial;
+            }
+        }
+    }
+}
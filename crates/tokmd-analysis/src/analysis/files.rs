@@ -7,6 +7,9 @@ use crate::grid::PresetPlan;
 #[cfg(feature = "walk")]
 use anyhow::{Context, Result};
 use tokmd_analysis_types::AnalysisSource;
+#[cfg(any(feature = "walk", feature = "content", feature = "git"))]
+use tokmd_types::NO_HOST_ROOT;
+use tokmd_types::{ENRICHER_FAILED, Warning, WarningSeverity};
 
 #[cfg(any(feature = "walk", feature = "content"))]
 pub(super) const ROOTLESS_FILE_ANALYSIS_WARNING: &str =
@@ -20,12 +23,24 @@ pub(super) fn has_host_root(root: &Path) -> bool {
 }
 
 #[cfg(any(feature = "walk", feature = "content", feature = "git"))]
-pub(super) fn push_warning_once(warnings: &mut Vec<String>, warning: &str) {
-    if warnings.iter().all(|existing| existing != warning) {
-        warnings.push(warning.to_string());
+pub(super) fn push_warning_once(warnings: &mut Vec<Warning>, warning: Warning) {
+    if warnings.iter().all(|existing| *existing != warning) {
+        warnings.push(warning);
     }
 }
 
+/// Info-level warning for enrichers skipped because the input has no host
+/// directory to read from.
+#[cfg(any(feature = "walk", feature = "content", feature = "git"))]
+pub(super) fn no_host_root(message: &str) -> Warning {
+    Warning::new(NO_HOST_ROOT, WarningSeverity::Info, message)
+}
+
+/// Warning for an enricher that failed outright, leaving its section empty.
+pub(super) fn enricher_failed(message: String) -> Warning {
+    Warning::new(ENRICHER_FAILED, WarningSeverity::Error, message)
+}
+
 pub(super) fn analysis_roots(source: &AnalysisSource) -> Vec<PathBuf> {
     if !source_uses_ad_hoc_paths(source) {
         return vec![PathBuf::from(".")];
@@ -58,7 +73,7 @@ pub(super) fn collect_required_files(
     plan: &PresetPlan,
     max_files: Option<usize>,
     has_host_root: bool,
-    warnings: &mut Vec<String>,
+    warnings: &mut Vec<Warning>,
 ) -> Option<Vec<PathBuf>> {
     if !plan.needs_files() {
         return None;
@@ -70,12 +85,12 @@ pub(super) fn collect_required_files(
             match collect_scoped_files(root, analysis_roots, max_files) {
                 Ok(list) => Some(list),
                 Err(err) => {
-                    warnings.push(format!("walk failed: {}", err));
+                    warnings.push(enricher_failed(format!("walk failed: {}", err)));
                     None
                 }
             }
         } else {
-            push_warning_once(warnings, ROOTLESS_FILE_ANALYSIS_WARNING);
+            push_warning_once(warnings, no_host_root(ROOTLESS_FILE_ANALYSIS_WARNING));
             None
         }
     }
@@ -83,11 +98,7 @@ pub(super) fn collect_required_files(
     #[cfg(not(feature = "walk"))]
     {
         let _ = (root, analysis_roots, max_files, has_host_root);
-        warnings.push(
-            crate::grid::DisabledFeature::FileInventory
-                .warning()
-                .to_string(),
-        );
+        warnings.push(crate::grid::DisabledFeature::FileInventory.to_warning());
         None
     }
 }
@@ -204,6 +215,7 @@ mod tests {
             module_roots: vec![],
             module_depth: 1,
             children: "separate".to_string(),
+            token_model: "heuristic".to_string(),
         }
     }
 
@@ -249,6 +261,7 @@ mod tests {
             license: false,
             complexity: false,
             api_surface: false,
+            line_endings: false,
             #[cfg(all(feature = "halstead", feature = "content", feature = "walk"))]
             halstead: false,
             #[cfg(feature = "git")]
@@ -1,20 +1,32 @@
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 
 use anyhow::Result;
 use tokmd_analysis_types::{AnalysisArgsMeta, AnalysisReceipt, AnalysisSource, NearDupScope};
+use tokmd_format::AnalysisTreeOptions;
 use tokmd_types::{ExportData, ScanStatus, ToolInfo};
 
+use crate::derived::export_integrity;
 #[cfg(feature = "effort")]
 use crate::effort::EffortRequest;
 use crate::grid::{PresetKind, PresetPlan, preset_plan_for};
 use crate::util::now_ms;
 
 mod enrichers;
+mod extensions;
 mod files;
 mod outputs;
+mod oversized;
+mod progress;
+mod sampling;
+mod sections;
 mod setup;
+mod timings;
 
+pub use extensions::{AnalysisExtension, ExtensionHook};
 use outputs::AnalysisOutputs;
+pub use progress::{AnalysisProgress, ProgressHook};
+use timings::PhaseTimings;
 
 /// Canonical preset enum for analysis orchestration.
 pub type AnalysisPreset = PresetKind;
@@ -35,6 +47,14 @@ pub struct AnalysisContext {
 #[derive(Debug, Clone)]
 pub struct AnalysisRequest {
     pub preset: AnalysisPreset,
+    /// Sections to compute instead of the preset's grid row, for presets
+    /// defined in configuration.
+    pub plan: Option<PresetPlan>,
+    /// Compute only these sections of the plan (plus `near_dup`); empty
+    /// means no restriction.
+    pub include_sections: Vec<String>,
+    /// Sections to skip even when the plan computes them.
+    pub exclude_sections: Vec<String>,
     pub args: AnalysisArgsMeta,
     pub limits: tokmd_analysis_types::AnalysisLimits,
     #[cfg(feature = "effort")]
@@ -42,7 +62,11 @@ pub struct AnalysisRequest {
     pub window_tokens: Option<usize>,
     pub git: Option<bool>,
     pub import_granularity: ImportGranularity,
+    /// Rendering options for `derived.tree` (built for the `tree` format).
+    pub tree: AnalysisTreeOptions,
     pub detail_functions: bool,
+    /// Include the detailed public item list in the API surface report.
+    pub detail_api_items: bool,
     /// Enable near-duplicate detection.
     pub near_dup: bool,
     /// Near-duplicate similarity threshold (0.0–1.0).
@@ -55,37 +79,99 @@ pub struct AnalysisRequest {
     pub near_dup_max_pairs: Option<usize>,
     /// Glob patterns to exclude from near-duplicate analysis.
     pub near_dup_exclude: Vec<String>,
+    /// Minimum documented ratios for the API surface; unmet ones become warnings.
+    pub api_doc_thresholds: tokmd_analysis_types::ApiDocThresholds,
+    /// Expected per-file license header; when set, the license report lists
+    /// source files whose head lacks it.
+    pub license_header: Option<String>,
+    /// Domain merging applied before the corporate fingerprint is counted.
+    pub domain_grouping: tokmd_analysis_types::DomainGrouping,
+    /// Project predictive churn this many days ahead.
+    pub forecast_days: Option<u32>,
+    /// LCOV report whose per-file line coverage feeds the risk surface.
+    pub coverage: Option<PathBuf>,
+    /// Digest algorithm for the derived integrity hash.
+    pub hash_algo: tokmd_types::HashAlgo,
+    /// Record wall-clock milliseconds per phase in the receipt's `timings`.
+    pub timings: bool,
+    /// Called after each analysis phase, for progress display.
+    pub progress: Option<ProgressHook>,
+    /// Custom metrics attached to the receipt's `extensions` once the
+    /// built-in analysis is done, in order.
+    pub extensions: Vec<ExtensionHook>,
 }
 
 fn preset_plan(preset: AnalysisPreset) -> PresetPlan {
     preset_plan_for(preset)
 }
 
-pub fn analyze(ctx: AnalysisContext, req: AnalysisRequest) -> Result<AnalysisReceipt> {
+pub fn analyze(ctx: AnalysisContext, mut req: AnalysisRequest) -> Result<AnalysisReceipt> {
+    sampling::validate_rate(req.limits.sample_rate)?;
     let mut warnings = Vec::new();
-    let mut derived = setup::build_derived(&ctx.export, &req);
+    let plan = req.plan.unwrap_or_else(|| preset_plan(req.preset));
+    let plan = sections::apply(plan, &mut req)?;
+    let include_git = req.git.unwrap_or(plan.git);
+    let mut timings = PhaseTimings::new(req.timings)
+        .with_progress(
+            req.progress.clone(),
+            progress::planned_phases(&plan, &req, include_git),
+        )
+        .with_budget(req.limits.runtime_budget);
+    let (export, oversized_files) =
+        oversized::split_oversized(&ctx.export, req.limits.oversized_file_bytes);
+    let (export, unsampled_files) =
+        sampling::split_sampled(export.as_ref(), req.limits.sample_rate);
+    let export = export.as_ref();
+    let mut derived = {
+        let _phase = timings.phase("derived");
+        setup::build_derived(export, &req)
+    };
+    if !oversized_files.is_empty() || unsampled_files.is_some() {
+        // Integrity still covers the whole export, so `verify_integrity`
+        // matches against the export the caller actually passed in.
+        derived.integrity = export_integrity(&ctx.export, req.hash_algo);
+        derived.oversized_files = oversized_files;
+    }
+    let sampling = req
+        .limits
+        .sample_rate
+        .zip(unsampled_files)
+        .map(|(rate, total_files)| sampling::report(rate, total_files, &derived.totals));
     let analysis_roots = files::analysis_roots(&ctx.source);
     let source = setup::source_with_signature(ctx.source, derived.integrity.hash.clone());
 
-    let plan = preset_plan(req.preset);
-    let include_git = req.git.unwrap_or(plan.git);
     let has_host_root = files::has_host_root(&ctx.root);
-    let files = files::collect_required_files(
+    let files = if plan.needs_files() && timings.has_time("walk") {
+        let _phase = timings.phase("walk");
+        files::collect_required_files(
+            &ctx.root,
+            &analysis_roots,
+            &plan,
+            req.limits.max_files,
+            has_host_root,
+            &mut warnings,
+        )
+        .map(|files| oversized::retain_files(files, &derived.oversized_files))
+        .map(|files| sampling::retain_files(files, req.limits.sample_rate))
+    } else {
+        None
+    };
+    timings.set_files(files.as_ref().map(Vec::len));
+    let file_slice = files.as_deref();
+
+    let mut outputs = AnalysisOutputs::default();
+    enrichers::inventory::run(
         &ctx.root,
-        &analysis_roots,
+        file_slice,
         &plan,
-        req.limits.max_files,
-        has_host_root,
+        &mut outputs,
         &mut warnings,
+        &mut timings,
     );
-    let file_slice = files.as_deref();
-
-    let mut outputs = AnalysisOutputs::default();
-    enrichers::inventory::run(&ctx.root, file_slice, &plan, &mut outputs, &mut warnings);
     enrichers::content::run(
         enrichers::content::ContentInput {
             root: &ctx.root,
-            export: &ctx.export,
+            export,
             files: file_slice,
             plan: &plan,
             req: &req,
@@ -94,53 +180,75 @@ pub fn analyze(ctx: AnalysisContext, req: AnalysisRequest) -> Result<AnalysisRec
         &mut derived,
         &mut outputs,
         &mut warnings,
+        &mut timings,
     );
     enrichers::git::run(
         enrichers::git::GitInput {
             root: &ctx.root,
-            export: &ctx.export,
+            export,
             plan: &plan,
             include_git,
             max_commits: req.limits.max_commits,
             max_commit_files: req.limits.max_commit_files,
+            max_commit_message_bytes: req.limits.max_commit_message_bytes,
             has_host_root,
+            domain_grouping: &req.domain_grouping,
+            forecast_days: req.forecast_days,
         },
         &mut outputs,
         &mut warnings,
+        &mut timings,
+    );
+    enrichers::semantic::run(
+        export,
+        &derived,
+        &plan,
+        &mut outputs,
+        &mut warnings,
+        &mut timings,
     );
-    enrichers::semantic::run(&ctx.export, &derived, &plan, &mut outputs, &mut warnings);
     enrichers::code_quality::run(
         enrichers::code_quality::CodeQualityInput {
             root: &ctx.root,
-            export: &ctx.export,
+            export,
             files: file_slice,
             plan: &plan,
             limits: &req.limits,
             detail_functions: req.detail_functions,
+            detail_api_items: req.detail_api_items,
+            api_doc_thresholds: &req.api_doc_thresholds,
+            license_header: req.license_header.as_deref(),
         },
         &mut outputs,
         &mut warnings,
+        &mut timings,
     );
 
+    let risk_surface =
+        enrichers::risk_surface::run(&ctx.root, req.coverage.as_deref(), &outputs, &mut warnings);
+
     #[cfg(feature = "effort")]
     let effort = enrichers::effort::run(
         &ctx.root,
-        &ctx.export,
+        export,
         &derived,
         &outputs,
         req.effort.as_ref(),
         &mut warnings,
+        &mut timings,
     );
     #[cfg(not(feature = "effort"))]
     let effort: Option<tokmd_analysis_types::EffortEstimateReport> = None;
 
+    warnings.extend(timings.budget_warning());
+
     let status = if warnings.is_empty() {
         ScanStatus::Complete
     } else {
         ScanStatus::Partial
     };
 
-    Ok(AnalysisReceipt {
+    let mut receipt = AnalysisReceipt {
         schema_version: tokmd_analysis_types::ANALYSIS_SCHEMA_VERSION,
         generated_at_ms: now_ms(),
         tool: ToolInfo::current(),
@@ -165,5 +273,12 @@ pub fn analyze(ctx: AnalysisContext, req: AnalysisRequest) -> Result<AnalysisRec
         api_surface: outputs.api_surface,
         effort,
         fun: outputs.fun,
-    })
+        timings: timings.finish(),
+        line_endings: outputs.line_endings,
+        sampling,
+        risk_surface,
+        extensions: BTreeMap::new(),
+    };
+    extensions::attach(&req.extensions, export, &mut receipt);
+    Ok(receipt)
 }
@@ -1,7 +1,7 @@
 use tokmd_analysis_types::{
     ApiSurfaceReport, Archetype, AssetReport, ComplexityReport, CorporateFingerprint,
     DependencyReport, DuplicateReport, EntropyReport, FunReport, GitReport, ImportReport,
-    LicenseReport, PredictiveChurnReport, TopicClouds,
+    LicenseReport, LineEndingReport, PredictiveChurnReport, TopicClouds,
 };
 
 #[derive(Debug, Default)]
@@ -20,4 +20,5 @@ pub(super) struct AnalysisOutputs {
     pub(super) archetype: Option<Archetype>,
     pub(super) topics: Option<TopicClouds>,
     pub(super) fun: Option<FunReport>,
+    pub(super) line_endings: Option<LineEndingReport>,
 }
@@ -0,0 +1,141 @@
+//! Oversized-file cap (`AnalysisLimits::oversized_file_bytes`).
+//!
+//! Files above the cap are removed from the export before any metric runs and
+//! reported in `derived.oversized_files` instead. Unlike `max_file_bytes`,
+//! which only bounds how much of each file content scans read, the cap keeps
+//! a file out of every total, distribution, and enricher.
+
+use std::borrow::Cow;
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+
+use tokmd_analysis_types::OversizedFileRow;
+use tokmd_types::{ExportData, FileKind};
+
+/// The export with oversized files removed, plus those files largest first.
+///
+/// Child rows (embedded languages) follow their parent out. The export is
+/// borrowed unchanged when there is no cap or nothing exceeds it.
+pub(super) fn split_oversized(
+    export: &ExportData,
+    cap: Option<u64>,
+) -> (Cow<'_, ExportData>, Vec<OversizedFileRow>) {
+    let Some(cap) = cap else {
+        return (Cow::Borrowed(export), Vec::new());
+    };
+    let mut oversized: Vec<OversizedFileRow> = export
+        .rows
+        .iter()
+        .filter(|r| r.kind == FileKind::Parent && r.bytes as u64 > cap)
+        .map(|r| OversizedFileRow {
+            path: r.path.clone(),
+            bytes: r.bytes,
+        })
+        .collect();
+    if oversized.is_empty() {
+        return (Cow::Borrowed(export), oversized);
+    }
+    oversized.sort_by(|a, b| b.bytes.cmp(&a.bytes).then_with(|| a.path.cmp(&b.path)));
+
+    let paths: BTreeSet<&str> = oversized.iter().map(|r| r.path.as_str()).collect();
+    let mut kept = export.clone();
+    kept.rows.retain(|r| !paths.contains(r.path.as_str()));
+    (Cow::Owned(kept), oversized)
+}
+
+/// Drop oversized files from the walked file list so file-backed enrichers
+/// skip them too.
+pub(super) fn retain_files(files: Vec<PathBuf>, oversized: &[OversizedFileRow]) -> Vec<PathBuf> {
+    if oversized.is_empty() {
+        return files;
+    }
+    let paths: BTreeSet<&str> = oversized.iter().map(|r| r.path.as_str()).collect();
+    files
+        .into_iter()
+        .filter(|file| !paths.contains(normalized(file).as_str()))
+        .collect()
+}
+
+fn normalized(path: &Path) -> String {
+    path.to_string_lossy().replace('\\', "/")
+}
+
+#[cfg(test)]
+mod tests {
+    use tokmd_types::{ChildIncludeMode, FileRow};
+
+    use super::*;
+
+    fn row(path: &str, kind: FileKind, bytes: usize) -> FileRow {
+        FileRow {
+            path: path.to_string(),
+            module: "src".to_string(),
+            lang: "Rust".to_string(),
+            kind,
+            code: 10,
+            comments: 1,
+            blanks: 1,
+            lines: 12,
+            bytes,
+            tokens: bytes / 4,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
+        }
+    }
+
+    fn export(rows: Vec<FileRow>) -> ExportData {
+        ExportData {
+            rows,
+            module_roots: vec![],
+            module_depth: 1,
+            children: ChildIncludeMode::Separate,
+        }
+    }
+
+    #[test]
+    fn no_cap_borrows_export() {
+        let data = export(vec![row("src/a.rs", FileKind::Parent, 1_000_000)]);
+        let (kept, oversized) = split_oversized(&data, None);
+        assert!(matches!(kept, Cow::Borrowed(_)));
+        assert!(oversized.is_empty());
+    }
+
+    #[test]
+    fn files_over_cap_are_removed_with_their_children() {
+        let data = export(vec![
+            row("src/a.rs", FileKind::Parent, 100),
+            row("gen/big.html", FileKind::Parent, 5_000),
+            row("gen/big.html", FileKind::Child, 900),
+            row("gen/huge.json", FileKind::Parent, 9_000),
+            row("src/edge.rs", FileKind::Parent, 1_000),
+        ]);
+        let (kept, oversized) = split_oversized(&data, Some(1_000));
+
+        let kept_paths: Vec<&str> = kept.rows.iter().map(|r| r.path.as_str()).collect();
+        assert_eq!(kept_paths, ["src/a.rs", "src/edge.rs"]);
+        assert_eq!(
+            oversized,
+            [
+                OversizedFileRow {
+                    path: "gen/huge.json".to_string(),
+                    bytes: 9_000,
+                },
+                OversizedFileRow {
+                    path: "gen/big.html".to_string(),
+                    bytes: 5_000,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn walked_files_skip_oversized_paths() {
+        let oversized = [OversizedFileRow {
+            path: "gen/huge.json".to_string(),
+            bytes: 9_000,
+        }];
+        let files = vec![PathBuf::from("gen/huge.json"), PathBuf::from("src/a.rs")];
+        assert_eq!(retain_files(files, &oversized), [PathBuf::from("src/a.rs")]);
+    }
+}
@@ -0,0 +1,61 @@
+//! Phase-level progress reporting for long analysis runs.
+
+use std::fmt;
+use std::sync::Arc;
+
+use super::AnalysisRequest;
+use crate::grid::PresetPlan;
+
+/// Where an analysis run is, reported each time a phase finishes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AnalysisProgress {
+    /// The phase that just finished (`derived`, `walk`, `dup`, `git`, ...).
+    pub phase: &'static str,
+    /// Phases finished so far, including this one.
+    pub completed: usize,
+    /// Phases the plan schedules. Phases skipped at runtime (a missing
+    /// feature, no git repository, a spent runtime budget) never report, so
+    /// a run can end with `completed` below `total`.
+    pub total: usize,
+    /// Files the content phases read, once the walk has listed them.
+    pub files: Option<usize>,
+}
+
+/// Callback invoked with [`AnalysisProgress`] after each phase.
+#[derive(Clone)]
+pub struct ProgressHook(Arc<dyn Fn(&AnalysisProgress) + Send + Sync>);
+
+impl ProgressHook {
+    pub fn new(hook: impl Fn(&AnalysisProgress) + Send + Sync + 'static) -> Self {
+        Self(Arc::new(hook))
+    }
+
+    pub(super) fn report(&self, progress: &AnalysisProgress) {
+        (self.0)(progress);
+    }
+}
+
+impl fmt::Debug for ProgressHook {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ProgressHook(..)")
+    }
+}
+
+/// Phases `plan` schedules: `derived`, the walk, one per content section,
+/// one shared `git` phase, near-duplicates and effort.
+pub(super) fn planned_phases(plan: &PresetPlan, req: &AnalysisRequest, include_git: bool) -> usize {
+    let content = plan
+        .sections()
+        .into_iter()
+        .filter(|section| !matches!(*section, "git" | "churn" | "fingerprint"))
+        .count();
+    #[cfg(feature = "effort")]
+    let effort = usize::from(req.effort.is_some());
+    #[cfg(not(feature = "effort"))]
+    let effort = 0;
+    1 + usize::from(plan.needs_files())
+        + content
+        + usize::from(include_git)
+        + usize::from(req.near_dup)
+        + effort
+}
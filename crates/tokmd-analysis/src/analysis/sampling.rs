@@ -0,0 +1,242 @@
+//! Deterministic file sampling (`AnalysisLimits::sample_rate`).
+//!
+//! A file is kept when the BLAKE3 hash of its normalized path falls in the
+//! lowest `rate` share of the hash space, so a path is in or out of the sample
+//! regardless of platform, scan order, or which other files exist. Every
+//! section then describes the sample; the receipt's `sampling` section records
+//! the rate and extrapolates whole-scan totals from it.
+
+use std::borrow::Cow;
+use std::path::PathBuf;
+
+use anyhow::{Result, bail};
+use tokmd_analysis_types::{DerivedTotals, SamplingReport};
+use tokmd_types::{ExportData, FileKind};
+
+/// Reject rates outside `(0, 1]`, which would keep nothing or make no sense.
+pub(super) fn validate_rate(rate: Option<f64>) -> Result<()> {
+    if let Some(rate) = rate
+        && !(rate.is_finite() && rate > 0.0 && rate <= 1.0)
+    {
+        bail!("sample rate {rate} is out of range; expected greater than 0 and at most 1");
+    }
+    Ok(())
+}
+
+/// The export restricted to sampled files (child rows follow their parent),
+/// plus the number of files before sampling. Borrowed unchanged without a rate.
+pub(super) fn split_sampled(
+    export: &ExportData,
+    rate: Option<f64>,
+) -> (Cow<'_, ExportData>, Option<usize>) {
+    let Some(rate) = rate else {
+        return (Cow::Borrowed(export), None);
+    };
+    let total_files = parent_files(export);
+    let mut kept = export.clone();
+    kept.rows.retain(|r| keeps(&r.path, rate));
+    if kept.rows.len() == export.rows.len() {
+        return (Cow::Borrowed(export), Some(total_files));
+    }
+    (Cow::Owned(kept), Some(total_files))
+}
+
+/// Drop unsampled files from the walked file list so file-backed enrichers
+/// read only the sample.
+pub(super) fn retain_files(files: Vec<PathBuf>, rate: Option<f64>) -> Vec<PathBuf> {
+    let Some(rate) = rate else {
+        return files;
+    };
+    files
+        .into_iter()
+        .filter(|file| keeps(&file.to_string_lossy(), rate))
+        .collect()
+}
+
+/// The receipt section for a sampled run: `sampled` totals scaled by
+/// `1 / rate`.
+pub(super) fn report(rate: f64, total_files: usize, sampled: &DerivedTotals) -> SamplingReport {
+    let scale = |value: usize| (value as f64 / rate).round() as usize;
+    SamplingReport {
+        sampled: true,
+        rate,
+        total_files,
+        sampled_files: sampled.files,
+        estimated_totals: DerivedTotals {
+            files: scale(sampled.files),
+            code: scale(sampled.code),
+            comments: scale(sampled.comments),
+            blanks: scale(sampled.blanks),
+            lines: scale(sampled.lines),
+            bytes: scale(sampled.bytes),
+            tokens: scale(sampled.tokens),
+        },
+    }
+}
+
+/// Whether `path` falls in a sample of `rate`.
+fn keeps(path: &str, rate: f64) -> bool {
+    if rate >= 1.0 {
+        return true;
+    }
+    let hash = blake3::hash(path.replace('\\', "/").as_bytes());
+    let Some(&prefix) = hash.as_bytes().first_chunk::<8>() else {
+        return true;
+    };
+    (u64::from_le_bytes(prefix) as f64 / u64::MAX as f64) < rate
+}
+
+fn parent_files(export: &ExportData) -> usize {
+    export
+        .rows
+        .iter()
+        .filter(|r| r.kind == FileKind::Parent)
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use tokmd_types::{ChildIncludeMode, FileRow};
+
+    use super::*;
+
+    fn row(path: &str, kind: FileKind) -> FileRow {
+        FileRow {
+            path: path.to_string(),
+            module: "src".to_string(),
+            lang: "Rust".to_string(),
+            kind,
+            code: 10,
+            comments: 1,
+            blanks: 1,
+            lines: 12,
+            bytes: 400,
+            tokens: 100,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
+        }
+    }
+
+    fn export(rows: Vec<FileRow>) -> ExportData {
+        ExportData {
+            rows,
+            module_roots: vec![],
+            module_depth: 1,
+            children: ChildIncludeMode::Separate,
+        }
+    }
+
+    fn many_files(count: usize) -> ExportData {
+        export(
+            (0..count)
+                .map(|i| row(&format!("src/file_{i}.rs"), FileKind::Parent))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn half_rate_keeps_about_half_the_files_deterministically() {
+        let data = many_files(1_000);
+        let (first, total) = split_sampled(&data, Some(0.5));
+        let (second, _) = split_sampled(&data, Some(0.5));
+
+        assert_eq!(total, Some(1_000));
+        let kept = first.rows.len();
+        assert!((400..=600).contains(&kept), "kept {kept} of 1000");
+        let paths = |e: &ExportData| e.rows.iter().map(|r| r.path.clone()).collect::<Vec<_>>();
+        assert_eq!(paths(&first), paths(&second));
+    }
+
+    #[test]
+    fn selection_depends_only_on_the_path() {
+        let data = many_files(200);
+        let (sampled, _) = split_sampled(&data, Some(0.3));
+        let mut reversed = data.clone();
+        reversed.rows.reverse();
+        let (sampled_reversed, _) = split_sampled(&reversed, Some(0.3));
+
+        let mut forward: Vec<&str> = sampled.rows.iter().map(|r| r.path.as_str()).collect();
+        let mut backward: Vec<&str> = sampled_reversed
+            .rows
+            .iter()
+            .map(|r| r.path.as_str())
+            .collect();
+        forward.sort_unstable();
+        backward.sort_unstable();
+        assert_eq!(forward, backward);
+        assert_eq!(keeps("src\\file_1.rs", 0.3), keeps("src/file_1.rs", 0.3));
+    }
+
+    #[test]
+    fn children_follow_their_parent_and_walked_files_agree() {
+        let mut rows = Vec::new();
+        for i in 0..50 {
+            let path = format!("web/page_{i}.html");
+            rows.push(row(&path, FileKind::Parent));
+            rows.push(row(&path, FileKind::Child));
+        }
+        let data = export(rows);
+        let (sampled, _) = split_sampled(&data, Some(0.5));
+
+        for pair in sampled.rows.chunks(2) {
+            assert_eq!(pair[0].path, pair[1].path);
+        }
+        let walked: Vec<PathBuf> = (0..50)
+            .map(|i| PathBuf::from(format!("web/page_{i}.html")))
+            .collect();
+        let kept: Vec<String> = retain_files(walked, Some(0.5))
+            .iter()
+            .map(|p| p.to_string_lossy().into_owned())
+            .collect();
+        let parents: Vec<&str> = sampled
+            .rows
+            .iter()
+            .filter(|r| r.kind == FileKind::Parent)
+            .map(|r| r.path.as_str())
+            .collect();
+        assert_eq!(kept, parents);
+    }
+
+    #[test]
+    fn no_rate_or_full_rate_borrows_export() {
+        let data = many_files(10);
+        assert!(matches!(
+            split_sampled(&data, None),
+            (Cow::Borrowed(_), None)
+        ));
+        assert!(matches!(
+            split_sampled(&data, Some(1.0)),
+            (Cow::Borrowed(_), Some(10))
+        ));
+    }
+
+    #[test]
+    fn report_extrapolates_totals_by_the_rate() {
+        let sampled = DerivedTotals {
+            files: 5,
+            code: 100,
+            comments: 10,
+            blanks: 5,
+            lines: 115,
+            bytes: 4_000,
+            tokens: 1_001,
+        };
+        let report = report(0.1, 48, &sampled);
+        assert!(report.sampled);
+        assert_eq!((report.total_files, report.sampled_files), (48, 5));
+        assert_eq!(report.estimated_totals.files, 50);
+        assert_eq!(report.estimated_totals.code, 1_000);
+        assert_eq!(report.estimated_totals.tokens, 10_010);
+    }
+
+    #[test]
+    fn out_of_range_rates_are_rejected() {
+        assert!(validate_rate(None).is_ok());
+        assert!(validate_rate(Some(0.25)).is_ok());
+        assert!(validate_rate(Some(1.0)).is_ok());
+        for rate in [0.0, -0.5, 1.5, f64::NAN] {
+            assert!(validate_rate(Some(rate)).is_err(), "{rate}");
+        }
+    }
+}
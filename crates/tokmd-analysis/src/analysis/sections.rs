@@ -0,0 +1,47 @@
+//! Section include/exclude filtering on top of the preset plan.
+
+use anyhow::Result;
+
+use super::AnalysisRequest;
+use crate::grid::PresetPlan;
+
+/// Opt-in near-duplicate detection, filtered by name like the grid sections.
+const NEAR_DUP: &str = "near_dup";
+
+/// Apply the request's section filters to `plan`.
+///
+/// Near-duplicate detection and git history collection are also turned off
+/// when the filters leave nothing that needs them.
+pub(super) fn apply(plan: PresetPlan, req: &mut AnalysisRequest) -> Result<PresetPlan> {
+    if req.include_sections.is_empty() && req.exclude_sections.is_empty() {
+        return Ok(plan);
+    }
+    let include = grid_sections(&req.include_sections);
+    let exclude = grid_sections(&req.exclude_sections);
+    // An include list naming only `near_dup` keeps no grid section.
+    let base = if include.is_empty() && !req.include_sections.is_empty() {
+        PresetPlan::default()
+    } else {
+        plan
+    };
+    let plan = base
+        .filtered(&include, &exclude)
+        .map_err(anyhow::Error::msg)?;
+
+    let keeps_near_dup = (req.include_sections.is_empty()
+        || req.include_sections.iter().any(|s| s == NEAR_DUP))
+        && !req.exclude_sections.iter().any(|s| s == NEAR_DUP);
+    req.near_dup &= keeps_near_dup;
+    if !plan.needs_history() {
+        req.git = Some(false);
+    }
+    Ok(plan)
+}
+
+fn grid_sections(names: &[String]) -> Vec<&str> {
+    names
+        .iter()
+        .map(String::as_str)
+        .filter(|name| *name != NEAR_DUP)
+        .collect()
+}
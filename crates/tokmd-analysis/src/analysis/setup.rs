@@ -1,14 +1,14 @@
 use tokmd_analysis_types::{AnalysisSource, DerivedReport};
 use tokmd_types::ExportData;
 
-use crate::derived::{build_tree, derive_report};
+use crate::derived::{build_tree, derive_report_with_algo};
 
 use super::AnalysisRequest;
 
 pub(super) fn build_derived(export: &ExportData, req: &AnalysisRequest) -> DerivedReport {
-    let mut derived = derive_report(export, req.window_tokens);
+    let mut derived = derive_report_with_algo(export, req.window_tokens, req.hash_algo);
     if req.args.format.contains("tree") {
-        derived.tree = Some(build_tree(export));
+        derived.tree = Some(build_tree(export, &req.tree));
     }
     derived
 }
@@ -0,0 +1,250 @@
+use std::collections::BTreeMap;
+use std::time::Instant;
+
+use tokmd_analysis_types::RuntimeBudget;
+use tokmd_types::{RUNTIME_BUDGET_EXCEEDED, Warning, WarningSeverity};
+
+use super::progress::{AnalysisProgress, ProgressHook};
+
+/// Wall-clock milliseconds per analysis phase, collected only on request so
+/// normal runs never read the clock. Finished phases are also reported to
+/// the progress hook, when there is one, and checked against the runtime
+/// budget, when there is one.
+#[derive(Debug, Default)]
+pub(in crate::analysis) struct PhaseTimings {
+    phases: Option<BTreeMap<String, u64>>,
+    progress: Option<ProgressState>,
+    budget: Option<BudgetState>,
+}
+
+/// What the runtime budget cost the run.
+#[derive(Debug)]
+struct BudgetState {
+    budget: RuntimeBudget,
+    /// The phase that was running when the budget ran out.
+    cut_short: Option<&'static str>,
+    /// Phases not started because the budget had run out, in run order.
+    skipped: Vec<&'static str>,
+}
+
+#[derive(Debug)]
+struct ProgressState {
+    hook: ProgressHook,
+    completed: usize,
+    total: usize,
+    files: Option<usize>,
+}
+
+impl PhaseTimings {
+    pub(super) fn new(enabled: bool) -> Self {
+        Self {
+            phases: enabled.then(BTreeMap::new),
+            progress: None,
+            budget: None,
+        }
+    }
+
+    /// Skip phases once `budget` runs out.
+    pub(super) fn with_budget(mut self, budget: Option<RuntimeBudget>) -> Self {
+        self.budget = budget.map(|budget| BudgetState {
+            budget,
+            cut_short: None,
+            skipped: Vec::new(),
+        });
+        self
+    }
+
+    /// Whether `phase` may start. Once the runtime budget has run out this is
+    /// false, and the phase is recorded as skipped.
+    pub(in crate::analysis) fn has_time(&mut self, phase: &'static str) -> bool {
+        let Some(state) = self.budget.as_mut() else {
+            return true;
+        };
+        if !state.budget.exhausted() {
+            return true;
+        }
+        if !state.skipped.contains(&phase) {
+            state.skipped.push(phase);
+        }
+        false
+    }
+
+    /// Report each finished phase to `hook`, out of `total` planned phases.
+    pub(super) fn with_progress(mut self, hook: Option<ProgressHook>, total: usize) -> Self {
+        self.progress = hook.map(|hook| ProgressState {
+            hook,
+            completed: 0,
+            total,
+            files: None,
+        });
+        self
+    }
+
+    /// Record how many files the content phases will read.
+    pub(super) fn set_files(&mut self, files: Option<usize>) {
+        if let Some(progress) = self.progress.as_mut() {
+            progress.files = files;
+        }
+    }
+
+    /// Starts timing `phase`; the elapsed time is added when the guard drops.
+    ///
+    /// Open the guard inside the branch that does the work, so phases that
+    /// the preset skips leave no entry.
+    pub(in crate::analysis) fn phase(&mut self, phase: &'static str) -> PhaseGuard<'_> {
+        let started = self.phases.is_some().then(Instant::now);
+        let had_time = self
+            .budget
+            .as_ref()
+            .is_some_and(|state| !state.budget.exhausted());
+        PhaseGuard {
+            timings: self,
+            phase,
+            started,
+            had_time,
+        }
+    }
+
+    /// A [`RUNTIME_BUDGET_EXCEEDED`] warning naming the phase the budget ran
+    /// out in and the phases it skipped, when it cost the run anything.
+    pub(super) fn budget_warning(&self) -> Option<Warning> {
+        let state = self.budget.as_ref()?;
+        if state.cut_short.is_none() && state.skipped.is_empty() {
+            return None;
+        }
+        let mut message = format!("runtime budget of {} ms ran out", state.budget.limit_ms());
+        if let Some(phase) = state.cut_short {
+            message.push_str(&format!(" during {phase}"));
+        }
+        if !state.skipped.is_empty() {
+            message.push_str(&format!("; skipped {}", state.skipped.join(", ")));
+        }
+        Some(Warning::new(
+            RUNTIME_BUDGET_EXCEEDED,
+            WarningSeverity::Warning,
+            message,
+        ))
+    }
+
+    pub(super) fn finish(self) -> Option<BTreeMap<String, u64>> {
+        self.phases
+    }
+}
+
+pub(in crate::analysis) struct PhaseGuard<'a> {
+    timings: &'a mut PhaseTimings,
+    phase: &'static str,
+    started: Option<Instant>,
+    /// The runtime budget had time left when the phase started.
+    had_time: bool,
+}
+
+impl Drop for PhaseGuard<'_> {
+    fn drop(&mut self) {
+        if let (Some(phases), Some(started)) = (self.timings.phases.as_mut(), self.started) {
+            let elapsed = u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX);
+            *phases.entry(self.phase.to_string()).or_default() += elapsed;
+        }
+        if let Some(state) = self.timings.budget.as_mut()
+            && self.had_time
+            && state.cut_short.is_none()
+            && state.budget.exhausted()
+        {
+            state.cut_short = Some(self.phase);
+        }
+        if let Some(progress) = self.timings.progress.as_mut() {
+            progress.completed += 1;
+            progress.hook.report(&AnalysisProgress {
+                phase: self.phase,
+                completed: progress.completed,
+                total: progress.total.max(progress.completed),
+                files: progress.files,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use super::*;
+
+    #[test]
+    fn disabled_timings_record_nothing() {
+        let mut timings = PhaseTimings::new(false);
+        drop(timings.phase("derived"));
+        assert_eq!(timings.finish(), None);
+    }
+
+    #[test]
+    fn enabled_timings_record_each_phase_once() {
+        let mut timings = PhaseTimings::new(true);
+        drop(timings.phase("dup"));
+        drop(timings.phase("dup"));
+        drop(timings.phase("git"));
+        let phases = timings.finish().unwrap();
+        assert_eq!(phases.keys().collect::<Vec<_>>(), ["dup", "git"]);
+    }
+
+    #[test]
+    fn progress_hook_sees_increasing_counts() {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&seen);
+        let hook = ProgressHook::new(move |progress: &AnalysisProgress| {
+            sink.lock().unwrap().push((
+                progress.phase,
+                progress.completed,
+                progress.total,
+                progress.files,
+            ));
+        });
+        let mut timings = PhaseTimings::new(false).with_progress(Some(hook), 3);
+        drop(timings.phase("derived"));
+        timings.set_files(Some(12));
+        drop(timings.phase("walk"));
+        drop(timings.phase("dup"));
+        // An unplanned phase stretches the total instead of overshooting it.
+        drop(timings.phase("git"));
+        assert_eq!(timings.finish(), None);
+        assert_eq!(
+            *seen.lock().unwrap(),
+            [
+                ("derived", 1, 3, None),
+                ("walk", 2, 3, Some(12)),
+                ("dup", 3, 3, Some(12)),
+                ("git", 4, 4, Some(12)),
+            ]
+        );
+    }
+
+    #[test]
+    fn spent_budget_skips_phases_and_names_them() {
+        let mut timings = PhaseTimings::new(false).with_budget(Some(RuntimeBudget::start(0)));
+        drop(timings.phase("derived"));
+        assert!(!timings.has_time("dup"));
+        assert!(!timings.has_time("git"));
+        assert!(!timings.has_time("dup"));
+        let warning = timings.budget_warning().unwrap();
+        assert_eq!(warning.code, RUNTIME_BUDGET_EXCEEDED);
+        // The budget was already spent when `derived` started, so nothing
+        // was cut short.
+        assert_eq!(
+            warning.message,
+            "runtime budget of 0 ms ran out; skipped dup, git"
+        );
+    }
+
+    #[test]
+    fn unspent_or_missing_budget_warns_about_nothing() {
+        let mut timings =
+            PhaseTimings::new(false).with_budget(Some(RuntimeBudget::start(3_600_000)));
+        assert!(timings.has_time("dup"));
+        drop(timings.phase("dup"));
+        assert_eq!(timings.budget_warning(), None);
+
+        let mut unbudgeted = PhaseTimings::new(false);
+        assert!(unbudgeted.has_time("dup"));
+        assert_eq!(unbudgeted.budget_warning(), None);
+    }
+}
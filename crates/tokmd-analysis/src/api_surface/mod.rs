@@ -9,5 +9,9 @@ mod moved_tests;
 
 mod report;
 mod symbols;
+mod thresholds;
 
+#[cfg(test)]
 pub(crate) use report::build_api_surface_report;
+pub(crate) use report::build_api_surface_report_with_items;
+pub(crate) use thresholds::{check_doc_thresholds, violation_warning};
@@ -9,7 +9,9 @@ use std::path::{Path, PathBuf};
 
 use anyhow::Result;
 use tokmd_analysis_types::{AnalysisLimits, normalize_path};
-use tokmd_analysis_types::{ApiExportItem, ApiSurfaceReport, LangApiSurface, ModuleApiRow};
+use tokmd_analysis_types::{
+    ApiExportItem, ApiItem, ApiSurfaceReport, LangApiSurface, ModuleApiRow,
+};
 use tokmd_types::{ExportData, FileKind, FileRow};
 
 use super::symbols;
@@ -17,13 +19,31 @@ use super::symbols;
 const DEFAULT_MAX_FILE_BYTES: u64 = 128 * 1024;
 const MAX_TOP_EXPORTERS: usize = 20;
 const MAX_BY_MODULE: usize = 50;
+const MAX_ITEMS: usize = 10_000;
+const MAX_PUBLIC_SYMBOLS: usize = 10_000;
 
 /// Build the API surface report by scanning source files for public/internal symbols.
+#[cfg(test)]
 pub(crate) fn build_api_surface_report(
     root: &Path,
     files: &[PathBuf],
     export: &ExportData,
     limits: &AnalysisLimits,
+) -> Result<ApiSurfaceReport> {
+    build_api_surface_report_with_items(root, files, export, limits, false)
+}
+
+/// Build the API surface report, optionally listing every public item.
+///
+/// The item list is capped at `MAX_ITEMS` and the per-module symbol inventory
+/// at `MAX_PUBLIC_SYMBOLS`; `items_truncated` and `public_symbols_truncated`
+/// record whether each cap was hit.
+pub(crate) fn build_api_surface_report_with_items(
+    root: &Path,
+    files: &[PathBuf],
+    export: &ExportData,
+    limits: &AnalysisLimits,
+    detail_items: bool,
 ) -> Result<ApiSurfaceReport> {
     // Build lookup from normalized path -> FileRow
     let mut row_map: BTreeMap<String, &FileRow> = BTreeMap::new();
@@ -41,7 +61,7 @@ pub(crate) fn build_api_surface_report(
     let mut documented_public = 0usize;
 
     // Per-language accumulators
-    let mut lang_totals: BTreeMap<&str, (usize, usize, usize)> = BTreeMap::new(); // (total, public, internal)
+    let mut lang_totals: BTreeMap<&str, (usize, usize, usize, usize)> = BTreeMap::new(); // (total, public, internal, documented public)
 
     // Per-module accumulators
     let mut module_totals: BTreeMap<&str, (usize, usize)> = BTreeMap::new(); // (total, public)
@@ -49,8 +69,17 @@ pub(crate) fn build_api_surface_report(
     // Top exporters
     let mut exporters: Vec<ApiExportItem> = Vec::new();
 
+    // Public item identifiers per module (for API surface diffs)
+    let mut public_symbols: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    let mut public_symbol_count = 0usize;
+    let mut public_symbols_truncated = false;
+
+    // Detailed public items (opt-in)
+    let mut items: Vec<ApiItem> = Vec::new();
+    let mut items_truncated = false;
+
     for rel in files {
-        if limits.max_bytes.is_some_and(|limit| total_bytes >= limit) {
+        if limits.max_bytes.is_some_and(|limit| total_bytes >= limit) || limits.out_of_time() {
             break;
         }
 
@@ -70,6 +99,7 @@ pub(crate) fn build_api_surface_report(
             Err(_) => continue,
         };
         total_bytes += bytes.len() as u64;
+        let bytes = crate::content::io::prepare_text(bytes, limits.strip_bom);
 
         if !crate::content::io::is_text_like(&bytes) {
             continue;
@@ -96,16 +126,46 @@ pub(crate) fn build_api_surface_report(
         documented_public += file_documented;
 
         // Per-language
-        let entry = lang_totals.entry(row.lang.as_str()).or_insert((0, 0, 0));
+        let entry = lang_totals.entry(row.lang.as_str()).or_insert((0, 0, 0, 0));
         entry.0 += file_total;
         entry.1 += file_public;
         entry.2 += file_internal;
+        entry.3 += file_documented;
 
         // Per-module
         let mod_entry = module_totals.entry(row.module.as_str()).or_insert((0, 0));
         mod_entry.0 += file_total;
         mod_entry.1 += file_public;
 
+        if !public_symbols_truncated {
+            let module_symbols = public_symbols.entry(row.module.clone()).or_default();
+            for symbol in symbols.iter().filter(|s| s.is_public) {
+                if public_symbol_count >= MAX_PUBLIC_SYMBOLS {
+                    public_symbols_truncated = true;
+                    break;
+                }
+                module_symbols.push(format!("{} {}", symbol.kind, symbol.name));
+                public_symbol_count += 1;
+            }
+        }
+
+        if detail_items {
+            for symbol in symbols.iter().filter(|s| s.is_public) {
+                if items.len() >= MAX_ITEMS {
+                    items_truncated = true;
+                    break;
+                }
+                items.push(ApiItem {
+                    name: symbol.name.clone(),
+                    kind: symbol.kind.to_string(),
+                    module: row.module.clone(),
+                    path: rel_str.clone(),
+                    lang: row.lang.clone(),
+                    documented: symbol.is_documented,
+                });
+            }
+        }
+
         // Track top exporters
         if file_public > 0 {
             exporters.push(ApiExportItem {
@@ -120,12 +180,17 @@ pub(crate) fn build_api_surface_report(
     // Build per-language map
     let by_language: BTreeMap<String, LangApiSurface> = lang_totals
         .into_iter()
-        .map(|(lang, (total, public, internal))| {
+        .map(|(lang, (total, public, internal, documented))| {
             let public_ratio = if total == 0 {
                 0.0
             } else {
                 round_f64(public as f64 / total as f64, 4)
             };
+            let documented_ratio = if public == 0 {
+                0.0
+            } else {
+                round_f64(documented as f64 / public as f64, 4)
+            };
             (
                 lang.to_owned(),
                 LangApiSurface {
@@ -133,6 +198,7 @@ pub(crate) fn build_api_surface_report(
                     public_items: public,
                     internal_items: internal,
                     public_ratio,
+                    documented_ratio,
                 },
             )
         })
@@ -170,6 +236,11 @@ pub(crate) fn build_api_surface_report(
     });
     exporters.truncate(MAX_TOP_EXPORTERS);
 
+    public_symbols.retain(|_, items| !items.is_empty());
+    for items in public_symbols.values_mut() {
+        items.sort();
+    }
+
     let public_ratio = if total_items == 0 {
         0.0
     } else {
@@ -191,6 +262,11 @@ pub(crate) fn build_api_surface_report(
         by_language,
         by_module,
         top_exporters: exporters,
+        public_symbols,
+        doc_violations: vec![],
+        items: detail_items.then_some(items),
+        items_truncated,
+        public_symbols_truncated,
     })
 }
 
@@ -23,6 +23,10 @@ pub(super) fn is_api_surface_lang(lang: &str) -> bool {
 /// Represents a single discovered symbol.
 #[derive(Debug)]
 pub(super) struct Symbol {
+    /// Identifier of the item (`"?"` when the heuristic cannot find one).
+    pub(super) name: String,
+    /// Declaration keyword that introduced the item (`fn`, `class`, ...).
+    pub(super) kind: &'static str,
     pub(super) is_public: bool,
     pub(super) is_documented: bool,
 }
@@ -40,6 +44,44 @@ pub(super) fn extract_symbols(lang: &str, text: &str) -> Vec<Symbol> {
     }
 }
 
+/// Find the first declaration keyword in `decl` and the identifier after it.
+///
+/// Returns `(kind, name)` where `kind` is the matched keyword. When no keyword
+/// is present, `fallback` is used as the kind and the name is unknown.
+pub(super) fn split_item(
+    decl: &str,
+    keywords: &[&'static str],
+    fallback: &'static str,
+) -> (&'static str, String) {
+    let mut tokens = decl.split_whitespace();
+    while let Some(token) = tokens.next() {
+        if let Some(kind) = keywords.iter().find(|kw| **kw == token) {
+            let name = tokens
+                .find(|t| *t != "mut")
+                .map(ident_prefix)
+                .unwrap_or_default();
+            return (kind, non_empty_name(name));
+        }
+    }
+    (fallback, non_empty_name(String::new()))
+}
+
+/// Leading identifier characters of a token (`foo(` -> `foo`).
+pub(super) fn ident_prefix(token: &str) -> String {
+    token
+        .chars()
+        .take_while(|c| c.is_alphanumeric() || *c == '_' || *c == '$')
+        .collect()
+}
+
+pub(super) fn non_empty_name(name: String) -> String {
+    if name.is_empty() {
+        "?".to_string()
+    } else {
+        name
+    }
+}
+
 /// Check whether the line preceding a symbol looks like a doc comment.
 pub(super) fn has_doc_comment(lines: &[&str], idx: usize) -> bool {
     if idx == 0 {
@@ -10,11 +10,13 @@ pub(super) fn extract_symbols(lines: &[&str]) -> Vec<Symbol> {
             continue;
         }
 
-        if let Some(name) = extract_item_name(trimmed) {
+        if let Some((kind, name)) = extract_item_name(trimmed) {
             // In Go, items starting with uppercase are public
             let first_char = name.chars().next().unwrap_or('_');
             let is_public = first_char.is_uppercase();
             symbols.push(Symbol {
+                name,
+                kind,
                 is_public,
                 is_documented: has_doc_comment(lines, i),
             });
@@ -24,7 +26,7 @@ pub(super) fn extract_symbols(lines: &[&str]) -> Vec<Symbol> {
     symbols
 }
 
-fn extract_item_name(trimmed: &str) -> Option<String> {
+fn extract_item_name(trimmed: &str) -> Option<(&'static str, String)> {
     // func Name or func (receiver) Name
     if let Some(rest) = trimmed.strip_prefix("func ") {
         let rest = if rest.starts_with('(') {
@@ -42,7 +44,7 @@ fn extract_item_name(trimmed: &str) -> Option<String> {
             .take_while(|c| c.is_alphanumeric() || *c == '_')
             .collect();
         if !name.is_empty() {
-            return Some(name);
+            return Some(("func", name));
         }
     }
 
@@ -53,19 +55,19 @@ fn extract_item_name(trimmed: &str) -> Option<String> {
             .take_while(|c| c.is_alphanumeric() || *c == '_')
             .collect();
         if !name.is_empty() {
-            return Some(name);
+            return Some(("type", name));
         }
     }
 
     // var Name or const Name (top-level)
-    for prefix in &["var ", "const "] {
+    for (prefix, kind) in [("var ", "var"), ("const ", "const")] {
         if let Some(rest) = trimmed.strip_prefix(prefix) {
             let name: String = rest
                 .chars()
                 .take_while(|c| c.is_alphanumeric() || *c == '_')
                 .collect();
             if !name.is_empty() {
-                return Some(name);
+                return Some((kind, name));
             }
         }
     }
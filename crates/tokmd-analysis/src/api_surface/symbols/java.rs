@@ -1,4 +1,6 @@
-use super::{Symbol, has_doc_comment};
+use super::{Symbol, has_doc_comment, ident_prefix, non_empty_name, split_item};
+
+const TYPE_KEYWORDS: &[&str] = &["class", "interface", "enum", "record"];
 
 pub(super) fn extract_symbols(lines: &[&str]) -> Vec<Symbol> {
     let mut symbols = Vec::new();
@@ -14,7 +16,10 @@ pub(super) fn extract_symbols(lines: &[&str]) -> Vec<Symbol> {
         let is_internal = !is_public && is_internal(trimmed);
 
         if is_public || is_internal {
+            let (kind, name) = java_item(trimmed);
             symbols.push(Symbol {
+                name,
+                kind,
                 is_public,
                 is_documented: has_doc_comment(lines, i),
             });
@@ -24,6 +29,27 @@ pub(super) fn extract_symbols(lines: &[&str]) -> Vec<Symbol> {
     symbols
 }
 
+/// Classify a Java declaration as a type, method, or field and name it.
+fn java_item(trimmed: &str) -> (&'static str, String) {
+    let (kind, name) = split_item(trimmed, TYPE_KEYWORDS, "field");
+    if kind != "field" {
+        return (kind, name);
+    }
+    if let Some(open) = trimmed.find('(') {
+        let head = trimmed.get(..open).unwrap_or_default();
+        let name = head.split_whitespace().last().map(ident_prefix);
+        return ("method", non_empty_name(name.unwrap_or_default()));
+    }
+    let head = trimmed
+        .split(['=', ';'])
+        .next()
+        .unwrap_or_default()
+        .split_whitespace()
+        .last()
+        .map(ident_prefix);
+    ("field", non_empty_name(head.unwrap_or_default()))
+}
+
 fn is_public(trimmed: &str) -> bool {
     trimmed.starts_with("public class ")
         || trimmed.starts_with("public interface ")
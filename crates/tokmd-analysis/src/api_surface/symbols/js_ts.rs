@@ -1,4 +1,14 @@
-use super::{Symbol, has_doc_comment};
+use super::{Symbol, has_doc_comment, split_item};
+
+const ITEM_KEYWORDS: &[&str] = &[
+    "function",
+    "class",
+    "const",
+    "let",
+    "interface",
+    "type",
+    "enum",
+];
 
 pub(super) fn extract_symbols(lines: &[&str]) -> Vec<Symbol> {
     let mut symbols = Vec::new();
@@ -14,7 +24,10 @@ pub(super) fn extract_symbols(lines: &[&str]) -> Vec<Symbol> {
         let is_internal = !is_public && is_internal(trimmed);
 
         if is_public || is_internal {
+            let (kind, name) = split_item(trimmed, ITEM_KEYWORDS, "default");
             symbols.push(Symbol {
+                name,
+                kind,
                 is_public,
                 is_documented: has_doc_comment(lines, i),
             });
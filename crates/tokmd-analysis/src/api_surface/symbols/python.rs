@@ -1,4 +1,4 @@
-use super::{Symbol, has_doc_comment};
+use super::{Symbol, has_doc_comment, non_empty_name};
 
 pub(super) fn extract_symbols(lines: &[&str]) -> Vec<Symbol> {
     let mut symbols = Vec::new();
@@ -22,7 +22,14 @@ pub(super) fn extract_symbols(lines: &[&str]) -> Vec<Symbol> {
             let name = extract_name(trimmed);
             let is_public = !name.starts_with('_');
             let documented = has_docstring(lines, i);
+            let kind = if trimmed.starts_with("class ") {
+                "class"
+            } else {
+                "def"
+            };
             symbols.push(Symbol {
+                name: non_empty_name(name),
+                kind,
                 is_public,
                 is_documented: documented || has_doc_comment(lines, i),
             });
@@ -1,4 +1,26 @@
-use super::{Symbol, has_doc_comment};
+use super::{Symbol, has_doc_comment, ident_prefix, non_empty_name, split_item};
+
+const ITEM_KEYWORDS: &[&str] = &[
+    "fn",
+    "struct",
+    "enum",
+    "union",
+    "trait",
+    "type",
+    "const",
+    "static",
+    "mod",
+    "use",
+    "impl",
+    "macro_rules!",
+];
+
+/// Qualifiers that may come between `pub` and the item keyword.
+const ITEM_MODIFIERS: &[&str] = &["async", "unsafe"];
+
+/// Items that only count when public: a private `use` is just an import and
+/// an `impl` block declares no name of its own.
+const PUBLIC_ONLY_KEYWORDS: &[&str] = &["use", "impl"];
 
 pub(super) fn extract_symbols(lines: &[&str]) -> Vec<Symbol> {
     let mut symbols = Vec::new();
@@ -10,69 +32,70 @@ pub(super) fn extract_symbols(lines: &[&str]) -> Vec<Symbol> {
             continue;
         }
 
-        let is_public = is_pub_item(trimmed);
-        let is_internal = is_internal_item(trimmed);
-
-        if is_public || is_internal {
-            symbols.push(Symbol {
-                is_public,
-                is_documented: has_doc_comment(lines, i),
-            });
+        let (decl, is_pub) = match after_pub(trimmed) {
+            Some(decl) => (decl, true),
+            None => (trimmed, false),
+        };
+        let Some(keyword) = item_keyword(decl) else {
+            continue;
+        };
+        let is_public = is_pub || (keyword == "macro_rules!" && is_macro_export(lines, i));
+        if !is_public && PUBLIC_ONLY_KEYWORDS.contains(&keyword) {
+            continue;
         }
+
+        let (kind, name) = if keyword == "use" {
+            (keyword, use_name(decl))
+        } else {
+            split_item(decl, ITEM_KEYWORDS, "item")
+        };
+        symbols.push(Symbol {
+            name,
+            kind,
+            is_public,
+            is_documented: has_doc_comment(lines, i),
+        });
     }
 
     symbols
 }
 
-fn is_pub_item(trimmed: &str) -> bool {
-    // Match pub items, including pub(crate), pub(super), pub(in ...)
-    if !trimmed.starts_with("pub ") && !trimmed.starts_with("pub(") {
-        return false;
+/// The declaration after a `pub` qualifier, including `pub(crate)`,
+/// `pub(super)`, and `pub(in ...)`, or `None` for non-pub lines.
+fn after_pub(trimmed: &str) -> Option<&str> {
+    if let Some(rest) = trimmed.strip_prefix("pub ") {
+        return Some(rest);
     }
+    let (_, rest) = trimmed.strip_prefix("pub(")?.split_once(')')?;
+    Some(rest.trim_start())
+}
 
-    // Find the part after the pub qualifier
-    let after_pub = if trimmed.starts_with("pub(") {
-        // Find matching close paren
-        if let Some(close) = trimmed.find(')') {
-            trimmed[close + 1..].trim_start()
-        } else {
-            return false;
-        }
-    } else {
-        // "pub " prefix
-        &trimmed[4..]
-    };
-
-    // Now check for item keywords
-    after_pub.starts_with("fn ")
-        || after_pub.starts_with("struct ")
-        || after_pub.starts_with("enum ")
-        || after_pub.starts_with("trait ")
-        || after_pub.starts_with("type ")
-        || after_pub.starts_with("const ")
-        || after_pub.starts_with("static ")
-        || after_pub.starts_with("mod ")
-        || after_pub.starts_with("async fn ")
-        || after_pub.starts_with("unsafe fn ")
-        || after_pub.starts_with("unsafe trait ")
+/// The item keyword a declaration starts with, past any `async`/`unsafe`.
+fn item_keyword(decl: &str) -> Option<&'static str> {
+    let first = decl
+        .split_whitespace()
+        .find(|token| !ITEM_MODIFIERS.contains(token))?;
+    ITEM_KEYWORDS.iter().copied().find(|kw| *kw == first)
 }
 
-fn is_internal_item(trimmed: &str) -> bool {
-    // Non-pub items at start of line (no leading whitespace for top-level heuristic
-    // but we keep it simple: any fn/struct/etc. without pub)
-    if trimmed.starts_with("pub ") || trimmed.starts_with("pub(") {
-        return false;
-    }
+/// The name a `use` item exports: its `as` alias, else the last path
+/// segment. Glob and grouped imports have no single name.
+fn use_name(decl: &str) -> String {
+    let path = decl
+        .split_once("use ")
+        .map_or("", |(_, path)| path)
+        .trim_end_matches(';')
+        .trim();
+    let exported = match path.rsplit_once(" as ") {
+        Some((_, alias)) => alias,
+        None => path.rsplit("::").next().unwrap_or(path),
+    };
+    non_empty_name(ident_prefix(exported.trim()))
+}
 
-    trimmed.starts_with("fn ")
-        || trimmed.starts_with("struct ")
-        || trimmed.starts_with("enum ")
-        || trimmed.starts_with("trait ")
-        || trimmed.starts_with("type ")
-        || trimmed.starts_with("const ")
-        || trimmed.starts_with("static ")
-        || trimmed.starts_with("mod ")
-        || trimmed.starts_with("async fn ")
-        || trimmed.starts_with("unsafe fn ")
-        || trimmed.starts_with("unsafe trait ")
+/// Whether a `macro_rules!` is exported by a `#[macro_export]` just above it.
+fn is_macro_export(lines: &[&str], idx: usize) -> bool {
+    idx.checked_sub(1)
+        .and_then(|prev| lines.get(prev))
+        .is_some_and(|prev| prev.trim().starts_with("#[macro_export"))
 }
@@ -87,6 +87,43 @@ fn rust_async_unsafe() {
     assert!(syms.iter().all(|s| s.is_public));
 }
 
+#[test]
+fn rust_pub_use_reports_exported_name() {
+    let code = "pub use crate::inner::Parser as ReceiptParser;\npub use crate::inner::Lexer;\npub use crate::prelude::*;\n";
+    let syms = extract_symbols("rust", code);
+    let names: Vec<(&str, &str)> = syms.iter().map(|s| (s.kind, s.name.as_str())).collect();
+    assert_eq!(
+        names,
+        vec![("use", "ReceiptParser"), ("use", "Lexer"), ("use", "?")]
+    );
+    assert!(syms.iter().all(|s| s.is_public));
+}
+
+#[test]
+fn rust_private_use_and_impl_blocks_are_not_items() {
+    let code = "use std::fmt;\nimpl fmt::Display for Foo {}\nunsafe impl Send for Foo {}\n";
+    assert!(extract_symbols("rust", code).is_empty());
+}
+
+#[test]
+fn rust_union_and_macro_rules() {
+    let code = "pub union Bits { a: u32 }\nunion Raw { b: u8 }\n#[macro_export]\nmacro_rules! exported { () => {} }\nmacro_rules! local { () => {} }\n";
+    let syms = extract_symbols("rust", code);
+    let names: Vec<(&str, &str, bool)> = syms
+        .iter()
+        .map(|s| (s.kind, s.name.as_str(), s.is_public))
+        .collect();
+    assert_eq!(
+        names,
+        vec![
+            ("union", "Bits", true),
+            ("union", "Raw", false),
+            ("macro_rules!", "exported", true),
+            ("macro_rules!", "local", false),
+        ]
+    );
+}
+
 // -------
 // JS/TS symbol extraction
 // -------
@@ -445,3 +482,41 @@ fn rust_pub_unmatched_paren_no_panic() {
     // Unmatched paren should not match as pub item
     assert!(syms.is_empty() || !syms[0].is_public);
 }
+
+// -------
+// Symbol names and kinds
+// -------
+
+#[test]
+fn rust_symbol_names_and_kinds() {
+    let code =
+        "pub async fn fetch() {}\npub(crate) struct Cache;\npub static mut COUNTER: u32 = 0;\n";
+    let syms = extract_symbols("rust", code);
+    let ids: Vec<(&str, &str)> = syms.iter().map(|s| (s.kind, s.name.as_str())).collect();
+    assert_eq!(
+        ids,
+        vec![("fn", "fetch"), ("struct", "Cache"), ("static", "COUNTER")]
+    );
+}
+
+#[test]
+fn js_python_go_java_symbol_names() {
+    let js = extract_symbols("typescript", "export default function render() {}\n");
+    assert_eq!((js[0].kind, js[0].name.as_str()), ("function", "render"));
+
+    let py = extract_symbols("python", "class Parser:\n    pass\n");
+    assert_eq!((py[0].kind, py[0].name.as_str()), ("class", "Parser"));
+
+    let go = extract_symbols("go", "func (s *Server) Serve() {}\n");
+    assert_eq!((go[0].kind, go[0].name.as_str()), ("func", "Serve"));
+
+    let java = extract_symbols("java", "public static void main(String[] args) {\n");
+    assert_eq!((java[0].kind, java[0].name.as_str()), ("method", "main"));
+}
+
+#[test]
+fn anonymous_export_gets_placeholder_name() {
+    let syms = extract_symbols("javascript", "export default 42;\n");
+    assert_eq!(syms.len(), 1);
+    assert_eq!((syms[0].kind, syms[0].name.as_str()), ("default", "?"));
+}
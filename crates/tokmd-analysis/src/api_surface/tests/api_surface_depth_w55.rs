@@ -17,6 +17,11 @@ fn default_limits() -> AnalysisLimits {
         max_file_bytes: None,
         max_commits: None,
         max_commit_files: None,
+        oversized_file_bytes: None,
+        max_commit_message_bytes: None,
+        strip_bom: false,
+        sample_rate: None,
+        runtime_budget: None,
     }
 }
 
@@ -32,6 +37,9 @@ fn make_row(path: &str, module: &str, lang: &str, kind: FileKind) -> FileRow {
         lines: 115,
         bytes: 2000,
         tokens: 500,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
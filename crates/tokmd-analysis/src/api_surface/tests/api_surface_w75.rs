@@ -24,6 +24,9 @@ fn make_row(path: &str, module: &str, lang: &str) -> FileRow {
         lines: 27,
         bytes: 500,
         tokens: 100,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
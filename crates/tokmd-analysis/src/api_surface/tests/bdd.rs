@@ -26,6 +26,9 @@ fn make_row(path: &str, module: &str, lang: &str) -> FileRow {
         lines: 13,
         bytes: 100,
         tokens: 30,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
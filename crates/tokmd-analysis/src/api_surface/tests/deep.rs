@@ -26,6 +26,9 @@ fn make_row(path: &str, module: &str, lang: &str) -> FileRow {
         lines: 13,
         bytes: 100,
         tokens: 30,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
@@ -933,6 +936,7 @@ mod serialization {
             public_items: 3,
             internal_items: 2,
             public_ratio: 0.6,
+            documented_ratio: 0.0,
         };
         let json = serde_json::to_string(&surface).unwrap();
         let deserialized: LangApiSurface = serde_json::from_str(&json).unwrap();
@@ -26,6 +26,9 @@ fn file_row(path: &str, module: &str, lang: &str) -> FileRow {
         lines: 10,
         bytes: 200,
         tokens: 50,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
@@ -7,9 +7,11 @@
 use std::fs;
 use std::path::PathBuf;
 
-use crate::api_surface::build_api_surface_report;
+use crate::api_surface::{build_api_surface_report, build_api_surface_report_with_items};
 use tokmd_analysis_types::AnalysisLimits;
-use tokmd_analysis_types::{ApiExportItem, ApiSurfaceReport, LangApiSurface, ModuleApiRow};
+use tokmd_analysis_types::{
+    ApiExportItem, ApiItem, ApiSurfaceReport, LangApiSurface, ModuleApiRow,
+};
 use tokmd_types::{ChildIncludeMode, ExportData, FileKind, FileRow};
 
 // ---------------------------------------------------------------------------
@@ -28,6 +30,9 @@ fn make_row(path: &str, module: &str, lang: &str) -> FileRow {
         lines: 13,
         bytes: 100,
         tokens: 30,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
@@ -89,6 +94,7 @@ fn lang_api_surface_debug_and_clone() {
         public_items: 3,
         internal_items: 2,
         public_ratio: 0.6,
+        documented_ratio: 0.0,
     };
     let debug = format!("{:?}", surface);
     assert!(debug.contains("LangApiSurface"));
@@ -492,3 +498,75 @@ fn report_serializes_to_json_and_back() {
     assert_eq!(deserialized.by_module.len(), report.by_module.len());
     assert_eq!(deserialized.top_exporters.len(), report.top_exporters.len());
 }
+
+// ---------------------------------------------------------------------------
+// Detailed item list
+// ---------------------------------------------------------------------------
+
+#[test]
+fn detail_items_lists_public_items_of_rust_file() {
+    let code = "/// Parses input.\npub fn parse() {}\nfn helper() {}\npub struct Config;\n";
+    let (dir, paths) = write_temp_files(&[("src/lib.rs", code)]);
+    let export = make_export(vec![make_row("src/lib.rs", "src", "Rust")]);
+    let report =
+        build_api_surface_report_with_items(dir.path(), &paths, &export, &default_limits(), true)
+            .unwrap();
+
+    let items = report.items.expect("items requested");
+    assert!(!report.items_truncated);
+    assert_eq!(
+        items,
+        vec![
+            ApiItem {
+                name: "parse".to_string(),
+                kind: "fn".to_string(),
+                module: "src".to_string(),
+                path: "src/lib.rs".to_string(),
+                lang: "Rust".to_string(),
+                documented: true,
+            },
+            ApiItem {
+                name: "Config".to_string(),
+                kind: "struct".to_string(),
+                module: "src".to_string(),
+                path: "src/lib.rs".to_string(),
+                lang: "Rust".to_string(),
+                documented: false,
+            },
+        ]
+    );
+}
+
+#[test]
+fn items_are_omitted_unless_requested() {
+    let (dir, paths) = write_temp_files(&[("lib.rs", "pub fn a() {}\n")]);
+    let export = make_export(vec![make_row("lib.rs", "src", "Rust")]);
+    let report = build_api_surface_report(dir.path(), &paths, &export, &default_limits()).unwrap();
+
+    assert!(report.items.is_none());
+    let json = serde_json::to_string(&report).unwrap();
+    assert!(!json.contains("\"items\""));
+}
+
+#[test]
+fn public_symbols_are_capped() {
+    let many_fns = |prefix: &str| -> String {
+        (0..6_000)
+            .map(|i| format!("pub fn {prefix}{i}() {{}}\n"))
+            .collect()
+    };
+    let (a, b) = (many_fns("a"), many_fns("b"));
+    let (dir, paths) = write_temp_files(&[("src/a.rs", &a), ("src/b.rs", &b)]);
+    let export = make_export(vec![
+        make_row("src/a.rs", "src", "Rust"),
+        make_row("src/b.rs", "src", "Rust"),
+    ]);
+    let report = build_api_surface_report(dir.path(), &paths, &export, &default_limits()).unwrap();
+
+    assert_eq!(report.public_items, 12_000);
+    assert!(report.public_symbols_truncated);
+    assert_eq!(
+        report.public_symbols.values().map(Vec::len).sum::<usize>(),
+        10_000
+    );
+}
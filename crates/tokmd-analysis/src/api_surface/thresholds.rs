@@ -0,0 +1,168 @@
+//! Documented-ratio threshold checks for the API surface report.
+//!
+//! Thresholds are compared against the already-rounded ratios on the report,
+//! so a violation reads the same numbers a consumer sees in the receipt.
+
+use tokmd_analysis_types::{ApiDocThresholds, ApiDocViolation, ApiSurfaceReport};
+use tokmd_types::{THRESHOLD_UNMET, Warning, WarningSeverity};
+
+const OVERALL_SCOPE: &str = "overall";
+
+/// Compare the report's documented ratios against `thresholds`.
+///
+/// Returns violations in a stable order: the overall check first, then
+/// per-language checks sorted by language. Languages without public items
+/// are not checked, since their documented ratio carries no signal.
+pub(crate) fn check_doc_thresholds(
+    report: &ApiSurfaceReport,
+    thresholds: &ApiDocThresholds,
+) -> Vec<ApiDocViolation> {
+    let mut violations = Vec::new();
+
+    if let Some(threshold) = thresholds.min_documented_ratio
+        && report.public_items > 0
+        && report.documented_ratio < threshold
+    {
+        violations.push(ApiDocViolation {
+            scope: OVERALL_SCOPE.to_string(),
+            documented_ratio: report.documented_ratio,
+            threshold,
+        });
+    }
+
+    for (lang, threshold) in &thresholds.min_documented_ratio_by_lang {
+        let Some(surface) = report
+            .by_language
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(lang))
+            .map(|(_, surface)| surface)
+        else {
+            continue;
+        };
+        if surface.public_items > 0 && surface.documented_ratio < *threshold {
+            violations.push(ApiDocViolation {
+                scope: lang.clone(),
+                documented_ratio: surface.documented_ratio,
+                threshold: *threshold,
+            });
+        }
+    }
+
+    violations
+}
+
+/// Human-readable warning for one violation.
+pub(crate) fn violation_warning(violation: &ApiDocViolation) -> Warning {
+    Warning::new(
+        THRESHOLD_UNMET,
+        WarningSeverity::Warning,
+        format!(
+            "api surface documented ratio {:.4} ({}) is below threshold {:.4}",
+            violation.documented_ratio, violation.scope, violation.threshold
+        ),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use tokmd_analysis_types::LangApiSurface;
+
+    use super::*;
+
+    fn report(documented_ratio: f64, rust_ratio: f64) -> ApiSurfaceReport {
+        let mut by_language = BTreeMap::new();
+        by_language.insert(
+            "Rust".to_string(),
+            LangApiSurface {
+                total_items: 10,
+                public_items: 4,
+                internal_items: 6,
+                public_ratio: 0.4,
+                documented_ratio: rust_ratio,
+            },
+        );
+        ApiSurfaceReport {
+            total_items: 10,
+            public_items: 4,
+            internal_items: 6,
+            public_ratio: 0.4,
+            documented_ratio,
+            by_language,
+            by_module: vec![],
+            top_exporters: vec![],
+            public_symbols: BTreeMap::new(),
+            doc_violations: vec![],
+            items: None,
+            items_truncated: false,
+            public_symbols_truncated: false,
+        }
+    }
+
+    #[test]
+    fn low_ratio_violates_overall_threshold() {
+        let thresholds = ApiDocThresholds {
+            min_documented_ratio: Some(0.8),
+            ..Default::default()
+        };
+
+        let violations = check_doc_thresholds(&report(0.25, 0.25), &thresholds);
+
+        assert_eq!(
+            violations,
+            vec![ApiDocViolation {
+                scope: "overall".to_string(),
+                documented_ratio: 0.25,
+                threshold: 0.8,
+            }]
+        );
+        assert_eq!(
+            violation_warning(&violations[0]).message,
+            "api surface documented ratio 0.2500 (overall) is below threshold 0.8000"
+        );
+    }
+
+    #[test]
+    fn no_thresholds_means_no_violations() {
+        let violations = check_doc_thresholds(&report(0.0, 0.0), &ApiDocThresholds::default());
+        assert!(violations.is_empty());
+    }
+
+    #[test]
+    fn ratio_meeting_threshold_is_not_a_violation() {
+        let thresholds = ApiDocThresholds {
+            min_documented_ratio: Some(0.5),
+            ..Default::default()
+        };
+        assert!(check_doc_thresholds(&report(0.5, 0.5), &thresholds).is_empty());
+    }
+
+    #[test]
+    fn per_language_threshold_matches_case_insensitively() {
+        let mut by_lang = BTreeMap::new();
+        by_lang.insert("rust".to_string(), 0.9);
+        by_lang.insert("Python".to_string(), 0.9);
+        let thresholds = ApiDocThresholds {
+            min_documented_ratio: None,
+            min_documented_ratio_by_lang: by_lang,
+        };
+
+        let violations = check_doc_thresholds(&report(1.0, 0.5), &thresholds);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].scope, "rust");
+        assert_eq!(violations[0].documented_ratio, 0.5);
+    }
+
+    #[test]
+    fn empty_surface_is_not_checked() {
+        let mut empty = report(0.0, 0.0);
+        empty.public_items = 0;
+        let thresholds = ApiDocThresholds {
+            min_documented_ratio: Some(1.0),
+            ..Default::default()
+        };
+        assert!(check_doc_thresholds(&empty, &thresholds).is_empty());
+    }
+}
@@ -38,6 +38,9 @@ mod unit_tests {
                 lines: 1,
                 bytes: 10,
                 tokens: 2,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             })
             .collect();
         ExportData {
@@ -62,6 +65,9 @@ mod unit_tests {
             lines: 1,
             bytes: 10,
             tokens: 2,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         });
 
         let archetype = detect_archetype(&export).unwrap();
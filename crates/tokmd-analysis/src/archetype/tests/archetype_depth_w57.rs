@@ -16,6 +16,9 @@ fn export_with_paths(paths: &[&str]) -> ExportData {
             lines: 1,
             bytes: 10,
             tokens: 2,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         })
         .collect();
     ExportData {
@@ -264,6 +267,9 @@ fn backslash_paths_are_normalised() {
         lines: 1,
         bytes: 10,
         tokens: 2,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     });
     let a = detect_archetype(&e).unwrap();
     assert!(a.kind.contains("Rust workspace"));
@@ -283,6 +289,9 @@ fn child_rows_are_ignored_during_detection() {
         lines: 1,
         bytes: 10,
         tokens: 2,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     });
     e.rows.push(FileRow {
         path: "crates/core/src/lib.rs".to_string(),
@@ -295,6 +304,9 @@ fn child_rows_are_ignored_during_detection() {
         lines: 1,
         bytes: 10,
         tokens: 2,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     });
     assert!(detect_archetype(&e).is_none());
 }
@@ -24,6 +24,9 @@ fn parent_row(path: &str) -> FileRow {
         lines: 1,
         bytes: 10,
         tokens: 2,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
@@ -39,6 +42,9 @@ fn child_row(path: &str) -> FileRow {
         lines: 1,
         bytes: 10,
         tokens: 2,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
@@ -711,6 +717,9 @@ proptest! {
                 lang: "Unknown".to_string(),
                 kind: FileKind::Parent,
                 code: 0, comments: 0, blanks: 0, lines: 0, bytes: 0, tokens: 0,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             }).collect(),
             module_roots: vec![],
             module_depth: 2,
@@ -734,6 +743,9 @@ proptest! {
                 lang: "Unknown".to_string(),
                 kind: FileKind::Parent,
                 code: 0, comments: 0, blanks: 0, lines: 0, bytes: 0, tokens: 0,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             }).collect(),
             module_roots: vec![],
             module_depth: 2,
@@ -762,6 +774,9 @@ proptest! {
                 lang: "Unknown".to_string(),
                 kind: FileKind::Parent,
                 code: 0, comments: 0, blanks: 0, lines: 0, bytes: 0, tokens: 0,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             }).collect(),
             module_roots: vec![],
             module_depth: 2,
@@ -787,6 +802,9 @@ proptest! {
                 lang: "Unknown".to_string(),
                 kind: FileKind::Parent,
                 code: 0, comments: 0, blanks: 0, lines: 0, bytes: 0, tokens: 0,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             }).collect(),
             module_roots: vec![],
             module_depth: 2,
@@ -819,6 +837,9 @@ proptest! {
                 lang: "Unknown".to_string(),
                 kind: FileKind::Child,
                 code: 0, comments: 0, blanks: 0, lines: 0, bytes: 0, tokens: 0,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             }).collect(),
             module_roots: vec![],
             module_depth: 2,
@@ -23,6 +23,9 @@ fn parent_row(path: &str) -> FileRow {
         lines: 1,
         bytes: 10,
         tokens: 2,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
@@ -166,6 +169,9 @@ fn child_rows_ignored_for_detection() {
             lines: 1,
             bytes: 10,
             tokens: 2,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         },
         FileRow {
             path: "crates/core/src/lib.rs".to_string(),
@@ -178,6 +184,9 @@ fn child_rows_ignored_for_detection() {
             lines: 1,
             bytes: 10,
             tokens: 2,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         },
     ];
     let export = ExportData {
@@ -23,6 +23,9 @@ fn export_with_paths(paths: &[&str]) -> ExportData {
             lines: 1,
             bytes: 10,
             tokens: 2,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         })
         .collect();
     ExportData {
@@ -300,6 +303,9 @@ fn given_child_rows_only_then_no_archetype() {
         lines: 10,
         bytes: 100,
         tokens: 20,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }];
     let export = ExportData {
         rows,
@@ -327,6 +333,9 @@ fn given_mix_of_parent_and_child_rows_then_only_parents_considered() {
             lines: 10,
             bytes: 100,
             tokens: 20,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         },
         // Child row for crates/ — should NOT contribute to workspace detection
         FileRow {
@@ -340,6 +349,9 @@ fn given_mix_of_parent_and_child_rows_then_only_parents_considered() {
             lines: 58,
             bytes: 500,
             tokens: 100,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         },
     ];
     let export = ExportData {
@@ -370,6 +382,9 @@ fn given_backslash_paths_then_normalized_to_forward_slash() {
             lines: 1,
             bytes: 10,
             tokens: 2,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         },
         FileRow {
             path: "crates\\core\\src\\lib.rs".to_string(),
@@ -382,6 +397,9 @@ fn given_backslash_paths_then_normalized_to_forward_slash() {
             lines: 1,
             bytes: 10,
             tokens: 2,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         },
     ];
     let export = ExportData {
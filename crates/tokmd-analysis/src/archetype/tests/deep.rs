@@ -34,6 +34,9 @@ fn parent_row(path: &str) -> FileRow {
         lines: 1,
         bytes: 10,
         tokens: 2,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
@@ -49,6 +52,9 @@ fn child_row(path: &str) -> FileRow {
         lines: 1,
         bytes: 10,
         tokens: 2,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
@@ -21,6 +21,9 @@ fn parent_row(path: &str) -> FileRow {
         lines: 1,
         bytes: 10,
         tokens: 2,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
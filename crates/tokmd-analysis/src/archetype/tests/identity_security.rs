@@ -27,6 +27,9 @@ fn parent_row(path: &str) -> FileRow {
         lines: 1,
         bytes: 10,
         tokens: 2,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
@@ -338,6 +341,9 @@ fn parent_markers_with_child_noise_still_detected() {
             lines: 0,
             bytes: 0,
             tokens: 0,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         },
         FileRow {
             path: "more/child/noise.py".to_string(),
@@ -350,6 +356,9 @@ fn parent_markers_with_child_noise_still_detected() {
             lines: 0,
             bytes: 0,
             tokens: 0,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         },
     ];
     let export = ExportData {
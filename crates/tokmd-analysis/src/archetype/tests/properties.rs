@@ -84,6 +84,9 @@ fn export_from_paths(paths: Vec<String>) -> ExportData {
             lines: 0,
             bytes: 0,
             tokens: 0,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         })
         .collect();
     ExportData {
@@ -172,6 +175,9 @@ proptest! {
                 lines: 0,
                 bytes: 0,
                 tokens: 0,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             })
             .collect();
         let export = ExportData {
@@ -4,7 +4,7 @@ mod rust;
 
 pub(super) use rust::{count_rust_functions, is_rust_fn_start};
 
-pub(super) fn count_functions(lang: &str, text: &str) -> (usize, usize) {
+pub(crate) fn count_functions(lang: &str, text: &str) -> (usize, usize) {
     let lines: Vec<&str> = text.lines().collect();
     match lang.to_lowercase().as_str() {
         "rust" => count_rust_functions(&lines),
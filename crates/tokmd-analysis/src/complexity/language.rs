@@ -1,7 +1,7 @@
 //! Language compatibility helpers for complexity analysis.
 
 /// Map language strings to complexity-compatible names.
-pub(super) fn map_language_for_complexity(lang: &str) -> &str {
+pub(crate) fn map_language_for_complexity(lang: &str) -> &str {
     match lang.to_lowercase().as_str() {
         "rust" => "rust",
         "javascript" | "jsx" => "javascript",
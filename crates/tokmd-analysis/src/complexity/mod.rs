@@ -9,7 +9,9 @@ use tokmd_analysis_types::ComplexityRisk;
 #[cfg(test)]
 use tokmd_analysis_types::TechnicalDebtLevel;
 use tokmd_analysis_types::{ComplexityReport, FileComplexity};
-use tokmd_types::{ExportData, FileKind, FileRow};
+use tokmd_types::{
+    BYTE_BUDGET_EXCEEDED, ExportData, FileKind, FileRow, TRUNCATED_FILE, Warning, WarningSeverity,
+};
 
 use tokmd_analysis_types::{AnalysisLimits, normalize_path};
 
@@ -29,14 +31,16 @@ use details::{
     detect_fn_spans_c_style, detect_fn_spans_go, detect_fn_spans_js, detect_fn_spans_python,
     detect_fn_spans_rust,
 };
-use functions::count_functions;
+pub(crate) use functions::count_functions;
 #[cfg(test)]
 use functions::{count_python_functions, count_rust_functions, is_rust_fn_start};
 #[cfg(test)]
 pub(crate) use histogram::generate_complexity_histogram;
 pub(crate) use histogram::generate_complexity_histogram_for_files;
-use language::{is_complexity_lang, map_language_for_complexity};
-use risk::{classify_risk_extended, estimate_cyclomatic};
+use language::is_complexity_lang;
+pub(crate) use language::map_language_for_complexity;
+use risk::classify_risk_extended;
+pub(crate) use risk::estimate_cyclomatic;
 use summary::summarize_file_complexities;
 
 const DEFAULT_MAX_FILE_BYTES: u64 = 128 * 1024;
@@ -69,7 +73,7 @@ pub(crate) fn build_complexity_report(
     let per_file_limit = limits.max_file_bytes.unwrap_or(DEFAULT_MAX_FILE_BYTES) as usize;
 
     for rel in files {
-        if max_total.is_some_and(|limit| total_bytes >= limit) {
+        if max_total.is_some_and(|limit| total_bytes >= limit) || limits.out_of_time() {
             break;
         }
         let rel_str = rel.to_string_lossy().replace('\\', "/");
@@ -87,6 +91,7 @@ pub(crate) fn build_complexity_report(
             Err(_) => continue,
         };
         total_bytes += bytes.len() as u64;
+        let bytes = crate::content::io::prepare_text(bytes, limits.strip_bom);
 
         if !crate::content::io::is_text_like(&bytes) {
             continue;
@@ -201,7 +206,7 @@ pub(crate) fn bounded_complexity_warnings(
     files: &[PathBuf],
     export: &ExportData,
     limits: &AnalysisLimits,
-) -> Vec<String> {
+) -> Vec<Warning> {
     let mut row_map: BTreeMap<String, &FileRow> = BTreeMap::new();
     for row in export.rows.iter().filter(|r| r.kind == FileKind::Parent) {
         row_map.insert(normalize_path(&row.path, root), row);
@@ -241,20 +246,32 @@ pub(crate) fn bounded_complexity_warnings(
 
     let mut warnings = Vec::new();
     if clipped_files > 0 {
-        warnings.push(format!(
-            "complexity scan bounded: {clipped_files} of {eligible_files} eligible file(s) exceed {limit_label}; complexity metrics are partial"
+        warnings.push(Warning::new(
+            TRUNCATED_FILE,
+            WarningSeverity::Warning,
+            format!(
+                "complexity scan bounded: {clipped_files} of {eligible_files} eligible file(s) exceed {limit_label}; complexity metrics are partial"
+            ),
         ));
     }
     if let Some(max_bytes) = limits.max_bytes
         && total_estimated_read_bytes > max_bytes
     {
-        warnings.push(format!(
-            "complexity scan bounded: max_bytes={max_bytes} stops before all eligible complexity files are scanned; complexity metrics are partial"
+        warnings.push(Warning::new(
+            BYTE_BUDGET_EXCEEDED,
+            WarningSeverity::Warning,
+            format!(
+                "complexity scan bounded: max_bytes={max_bytes} stops before all eligible complexity files are scanned; complexity metrics are partial"
+            ),
         ));
     }
     if bounded_bytes > 0 {
-        warnings.push(format!(
-            "complexity scan bounded: at least {bounded_bytes} byte(s) of eligible source were outside the scanned content window"
+        warnings.push(Warning::new(
+            TRUNCATED_FILE,
+            WarningSeverity::Warning,
+            format!(
+                "complexity scan bounded: at least {bounded_bytes} byte(s) of eligible source were outside the scanned content window"
+            ),
         ));
     }
 
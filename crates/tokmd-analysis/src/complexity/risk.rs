@@ -2,7 +2,7 @@
 
 use tokmd_analysis_types::ComplexityRisk;
 
-pub(super) fn estimate_cyclomatic(lang: &str, text: &str) -> usize {
+pub(crate) fn estimate_cyclomatic(lang: &str, text: &str) -> usize {
     let mut complexity = 1usize;
 
     let keywords: &[&str] = match lang.to_lowercase().as_str() {
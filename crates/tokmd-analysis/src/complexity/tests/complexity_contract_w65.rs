@@ -45,6 +45,9 @@ fn make_row(path: &str, lang: &str, code: usize, bytes: usize) -> FileRow {
         lines: code,
         bytes,
         tokens: code * 5,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
@@ -64,6 +67,11 @@ fn default_limits() -> AnalysisLimits {
         max_file_bytes: None,
         max_commits: None,
         max_commit_files: None,
+        oversized_file_bytes: None,
+        max_commit_message_bytes: None,
+        strip_bom: false,
+        sample_rate: None,
+        runtime_budget: None,
     }
 }
 
@@ -427,6 +435,9 @@ mod edge_cases {
             lines: 50,
             bytes: 1000,
             tokens: 250,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         });
         let files = vec![PathBuf::from("src/main.rs")];
         let r =
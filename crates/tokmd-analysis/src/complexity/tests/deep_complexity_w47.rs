@@ -27,6 +27,9 @@ fn make_row(path: &str, lang: &str, code: usize) -> FileRow {
         lines: code,
         bytes: code * 40,
         tokens: code * 8,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
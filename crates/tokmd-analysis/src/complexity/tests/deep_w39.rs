@@ -26,6 +26,9 @@ fn make_row(path: &str, module: &str, lang: &str, code: usize) -> FileRow {
         lines: code,
         bytes: code * 40,
         tokens: code * 8,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
@@ -27,6 +27,9 @@ fn make_row(path: &str, module: &str, lang: &str, code: usize) -> FileRow {
         lines: code,
         bytes: code * 40,
         tokens: code * 8,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
@@ -551,3 +554,69 @@ fn branchy(x: i32, y: bool) -> i32 {
         assert!(false, "build_complexity_report failed");
     }
 }
+
+// ===========================================================================
+// Scenario: Byte-order marks
+// ===========================================================================
+
+#[test]
+fn given_strip_bom_a_bom_prefixed_file_matches_its_bom_less_twin() {
+    let source =
+        "def first(x):\n    if x:\n        return 1\n    return 0\n\ndef second():\n    return 2\n";
+    let (dir, paths) = write_temp_files(&[
+        ("plain.py", source),
+        ("bom.py", &format!("\u{FEFF}{source}")),
+    ]);
+    let export = make_export(vec![
+        make_row("plain.py", "(root)", "Python", 6),
+        make_row("bom.py", "(root)", "Python", 6),
+    ]);
+    let function_counts = |limits: &AnalysisLimits| {
+        let report = build_complexity_report(dir.path(), &paths, &export, limits, false).unwrap();
+        let count = |path: &str| {
+            let file = report.files.iter().find(|f| f.path == path).unwrap();
+            (file.function_count, file.cyclomatic_complexity)
+        };
+        (count("plain.py"), count("bom.py"))
+    };
+
+    let (plain, bom) = function_counts(&default_limits());
+    assert_ne!(plain, bom, "the BOM hides the first `def`");
+
+    let stripping = AnalysisLimits {
+        strip_bom: true,
+        ..default_limits()
+    };
+    let (plain, bom) = function_counts(&stripping);
+    assert_eq!(plain, bom);
+    assert_eq!(plain.0, 2);
+}
+
+#[cfg(feature = "encoding")]
+#[test]
+fn given_encoding_feature_a_latin1_file_matches_its_utf8_twin() {
+    let source = "def caf\u{e9}(x):\n    # cr\u{e8}me br\u{fb}l\u{e9}e\n    if x:\n        return 1\n    return 0\n";
+    let latin1: Vec<u8> = source.chars().map(|c| c as u8).collect();
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join("utf8.py"), source).unwrap();
+    fs::write(dir.path().join("latin1.py"), latin1).unwrap();
+    let paths = vec![PathBuf::from("utf8.py"), PathBuf::from("latin1.py")];
+    let export = make_export(vec![
+        make_row("utf8.py", "(root)", "Python", 4),
+        make_row("latin1.py", "(root)", "Python", 4),
+    ]);
+
+    let report =
+        build_complexity_report(dir.path(), &paths, &export, &default_limits(), false).unwrap();
+    let file = |path: &str| {
+        let file = report.files.iter().find(|f| f.path == path).unwrap();
+        (
+            file.function_count,
+            file.max_function_length,
+            file.cyclomatic_complexity,
+        )
+    };
+
+    assert_eq!(file("latin1.py"), file("utf8.py"));
+    assert_eq!(file("utf8.py").0, 1);
+}
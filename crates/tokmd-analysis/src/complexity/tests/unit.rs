@@ -296,6 +296,9 @@ fn test_compute_technical_debt_ratio() {
             lines: 1000,
             bytes: 1000,
             tokens: 250,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         }],
         module_roots: vec![],
         module_depth: 1,
@@ -335,6 +338,9 @@ fn test_compute_technical_debt_ratio_none_for_zero_code() {
             lines: 0,
             bytes: 0,
             tokens: 0,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         }],
         module_roots: vec![],
         module_depth: 1,
@@ -370,6 +376,9 @@ fn bounded_complexity_warnings_report_default_file_cap() {
             lines: 40_000,
             bytes: DEFAULT_MAX_FILE_BYTES as usize + 4096,
             tokens: 10_000,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         }],
         module_roots: vec![],
         module_depth: 1,
@@ -382,16 +391,15 @@ fn bounded_complexity_warnings_report_default_file_cap() {
         &AnalysisLimits::default(),
     );
 
+    let truncated = &warnings[0];
+    assert_eq!(truncated.code, tokmd_types::TRUNCATED_FILE);
+    assert_eq!(truncated.severity, tokmd_types::WarningSeverity::Warning);
     assert!(
-        warnings
-            .iter()
-            .any(|warning| warning.contains("default max_file_bytes=131072")),
+        truncated.message.contains("default max_file_bytes=131072"),
         "{warnings:?}"
     );
     assert!(
-        warnings
-            .iter()
-            .any(|warning| warning.contains("complexity metrics are partial")),
+        truncated.message.contains("complexity metrics are partial"),
         "{warnings:?}"
     );
 }
@@ -6,6 +6,8 @@
 //! ## What belongs here
 //! * File content reading (head, tail, lines)
 //! * Text detection and byte-level classification
+//! * Byte-order mark detection and stripping
+//! * Legacy-encoding detection (`encoding` feature)
 //! * File integrity hashing (BLAKE3)
 //! * Tag counting (TODOs, FIXMEs)
 //! * Entropy calculation
@@ -26,8 +28,13 @@ use std::path::Path;
 
 use anyhow::Result;
 
+#[path = "io/bom.rs"]
+mod bom;
 #[path = "io/bytes.rs"]
 mod bytes;
+#[cfg(feature = "encoding")]
+#[path = "io/encoding.rs"]
+mod encoding;
 #[path = "io/read.rs"]
 mod read;
 #[path = "io/tags.rs"]
@@ -49,10 +56,37 @@ pub fn read_text_capped(path: &Path, max_bytes: usize) -> Result<String> {
     read::read_text_capped(path, max_bytes)
 }
 
+/// Raw-byte check: NUL-free valid UTF-8. UTF-16 and legacy-encoded files
+/// only pass after [`prepare_text`] has transcoded them.
 pub fn is_text_like(bytes: &[u8]) -> bool {
     bytes::is_text_like(bytes)
 }
 
+pub fn detect_bom(bytes: &[u8]) -> Option<bom::Bom> {
+    bom::detect_bom(bytes)
+}
+
+/// Name of the legacy encoding `bytes` appear to use, such as `Shift_JIS`
+/// or `windows-1252`.
+#[cfg(feature = "encoding")]
+pub fn detect_encoding(bytes: &[u8]) -> Option<&'static str> {
+    encoding::detect_encoding(bytes).map(|encoding| encoding.name())
+}
+
+/// Bytes as content analysis should read them: without a leading BOM when
+/// `strip_bom` is set, and transcoded to UTF-8 when the `encoding` feature
+/// detects a legacy encoding. Undetected bytes are returned as read.
+pub fn prepare_text(mut bytes: Vec<u8>, strip_bom: bool) -> Vec<u8> {
+    if strip_bom {
+        bytes = bom::strip_bom(bytes);
+    }
+    #[cfg(feature = "encoding")]
+    {
+        bytes = encoding::decode_legacy(bytes);
+    }
+    bytes
+}
+
 #[cfg_attr(
     not(any(test, fuzzing)),
     expect(
@@ -0,0 +1,111 @@
+//! Byte-order mark detection and stripping.
+//!
+//! A UTF-8 BOM is plain bytes in front of the text; stripping it keeps the
+//! first line comparable with a BOM-less twin. UTF-16 text carries NUL bytes,
+//! so it only becomes text-like after it is transcoded to UTF-8.
+
+const UTF8_BOM: &[u8] = &[0xEF, 0xBB, 0xBF];
+const UTF16_LE_BOM: &[u8] = &[0xFF, 0xFE];
+const UTF16_BE_BOM: &[u8] = &[0xFE, 0xFF];
+
+/// The byte-order mark a file starts with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Bom {
+    Utf8,
+    Utf16Le,
+    Utf16Be,
+}
+
+pub fn detect_bom(bytes: &[u8]) -> Option<Bom> {
+    if bytes.starts_with(UTF8_BOM) {
+        Some(Bom::Utf8)
+    } else if bytes.starts_with(UTF16_LE_BOM) {
+        Some(Bom::Utf16Le)
+    } else if bytes.starts_with(UTF16_BE_BOM) {
+        Some(Bom::Utf16Be)
+    } else {
+        None
+    }
+}
+
+/// Drop a leading BOM, transcoding UTF-16 to UTF-8. Bytes without a BOM, and
+/// UTF-16 that does not decode, are returned unchanged.
+pub fn strip_bom(bytes: Vec<u8>) -> Vec<u8> {
+    match detect_bom(&bytes) {
+        None => bytes,
+        Some(Bom::Utf8) => match bytes.strip_prefix(UTF8_BOM) {
+            Some(text) => text.to_vec(),
+            None => bytes,
+        },
+        Some(bom) => {
+            let body = bytes.get(UTF16_LE_BOM.len()..).unwrap_or_default();
+            decode_utf16(body, bom == Bom::Utf16Le).unwrap_or(bytes)
+        }
+    }
+}
+
+fn decode_utf16(body: &[u8], little_endian: bool) -> Option<Vec<u8>> {
+    // A capped read can split the last code unit; drop the odd byte.
+    let units = body
+        .chunks_exact(2)
+        .filter_map(|pair| <[u8; 2]>::try_from(pair).ok())
+        .map(|pair| {
+            if little_endian {
+                u16::from_le_bytes(pair)
+            } else {
+                u16::from_be_bytes(pair)
+            }
+        });
+    char::decode_utf16(units)
+        .collect::<Result<String, _>>()
+        .ok()
+        .map(String::into_bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utf16(text: &str, bom: &[u8], little_endian: bool) -> Vec<u8> {
+        let mut bytes = bom.to_vec();
+        for unit in text.encode_utf16() {
+            if little_endian {
+                bytes.extend_from_slice(&unit.to_le_bytes());
+            } else {
+                bytes.extend_from_slice(&unit.to_be_bytes());
+            }
+        }
+        bytes
+    }
+
+    #[test]
+    fn detects_each_bom() {
+        assert_eq!(detect_bom(b"\xEF\xBB\xBFfn a() {}"), Some(Bom::Utf8));
+        assert_eq!(detect_bom(b"\xFF\xFEa\0"), Some(Bom::Utf16Le));
+        assert_eq!(detect_bom(b"\xFE\xFF\0a"), Some(Bom::Utf16Be));
+        assert_eq!(detect_bom(b"fn a() {}"), None);
+        assert_eq!(detect_bom(b""), None);
+    }
+
+    #[test]
+    fn strips_utf8_bom_and_keeps_plain_bytes() {
+        assert_eq!(strip_bom(b"\xEF\xBB\xBFx = 1\n".to_vec()), b"x = 1\n");
+        assert_eq!(strip_bom(b"x = 1\n".to_vec()), b"x = 1\n");
+    }
+
+    #[test]
+    fn transcodes_utf16_to_text_like_utf8() {
+        let le = utf16("x = 1\n", UTF16_LE_BOM, true);
+        let be = utf16("x = 1\n", UTF16_BE_BOM, false);
+        assert!(!super::super::bytes::is_text_like(&le));
+        assert_eq!(strip_bom(le), b"x = 1\n");
+        assert_eq!(strip_bom(be), b"x = 1\n");
+    }
+
+    #[test]
+    fn undecodable_utf16_is_returned_unchanged() {
+        // An unpaired high surrogate.
+        let bytes = vec![0xFF, 0xFE, 0x00, 0xD8, b'a', 0x00];
+        assert_eq!(strip_bom(bytes.clone()), bytes);
+    }
+}
@@ -0,0 +1,118 @@
+//! Legacy-encoding detection for sources that are not UTF-8.
+//!
+//! Detection is deliberately narrow: Shift_JIS is chosen only when it
+//! decodes cleanly and yields kana, which Japanese text almost always
+//! contains; otherwise Windows-1252 (a superset of Latin-1) is chosen when
+//! the bytes look like mostly-ASCII prose. Anything else stays undetected
+//! and is decoded lossily, as before.
+
+use encoding_rs::{Encoding, SHIFT_JIS, WINDOWS_1252};
+
+/// Upper bound on the share of non-ASCII bytes in a Windows-1252 file.
+/// Source files in Western European languages stay far below it.
+const MAX_LEGACY_NON_ASCII_PERCENT: usize = 30;
+
+/// The legacy encoding `bytes` appear to use. `None` for UTF-8 (including
+/// a UTF-8 sequence cut by a capped read), binary data, and text that fits
+/// no candidate.
+pub fn detect_encoding(bytes: &[u8]) -> Option<&'static Encoding> {
+    if bytes.contains(&0) || is_utf8_prefix(bytes) {
+        return None;
+    }
+    if looks_like_shift_jis(bytes) {
+        Some(SHIFT_JIS)
+    } else if looks_like_windows_1252(bytes) {
+        Some(WINDOWS_1252)
+    } else {
+        None
+    }
+}
+
+/// Transcode `bytes` to UTF-8 when a legacy encoding is detected, else
+/// return them unchanged.
+pub fn decode_legacy(bytes: Vec<u8>) -> Vec<u8> {
+    match detect_encoding(&bytes) {
+        Some(encoding) => encoding
+            .decode_without_bom_handling(&bytes)
+            .0
+            .into_owned()
+            .into_bytes(),
+        None => bytes,
+    }
+}
+
+fn is_utf8_prefix(bytes: &[u8]) -> bool {
+    match std::str::from_utf8(bytes) {
+        Ok(_) => true,
+        // `error_len() == None` means the input ended mid-sequence.
+        Err(err) => err.error_len().is_none(),
+    }
+}
+
+fn looks_like_shift_jis(bytes: &[u8]) -> bool {
+    // A capped read can split the last double-byte character.
+    let decoded = SHIFT_JIS
+        .decode_without_bom_handling_and_without_replacement(bytes)
+        .or_else(|| {
+            let init = bytes.split_last().map(|(_, init)| init).unwrap_or_default();
+            SHIFT_JIS.decode_without_bom_handling_and_without_replacement(init)
+        });
+    decoded.is_some_and(|text| text.chars().any(|c| ('\u{3040}'..='\u{30FF}').contains(&c)))
+}
+
+fn looks_like_windows_1252(bytes: &[u8]) -> bool {
+    let mut non_ascii = 0usize;
+    for &byte in bytes {
+        match byte {
+            // Undefined in Windows-1252.
+            0x81 | 0x8D | 0x8F | 0x90 | 0x9D => return false,
+            // Control characters other than tab, newline, form feed and
+            // carriage return point at binary data.
+            0x01..=0x08 | 0x0B | 0x0E..=0x1F | 0x7F => return false,
+            0x80..=0xFF => non_ascii += 1,
+            _ => {}
+        }
+    }
+    non_ascii * 100 <= bytes.len() * MAX_LEGACY_NON_ASCII_PERCENT
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn utf8_and_binary_are_not_legacy() {
+        assert_eq!(detect_encoding("café\n".as_bytes()), None);
+        // "é" cut after its first byte by a capped read.
+        assert_eq!(detect_encoding(b"caf\xC3"), None);
+        assert_eq!(detect_encoding(b"\x89PNG\r\n\x1A\n"), None);
+        assert_eq!(detect_encoding(b"a\0b\xE9"), None);
+    }
+
+    #[test]
+    fn latin1_prose_is_windows_1252() {
+        let bytes = b"# R\xE9sum\xE9 of the caf\xE9 menu\nprice = 3\n".to_vec();
+        assert_eq!(detect_encoding(&bytes), Some(WINDOWS_1252));
+        assert_eq!(
+            decode_legacy(bytes),
+            "# Résumé of the café menu\nprice = 3\n".as_bytes()
+        );
+    }
+
+    #[test]
+    fn kana_text_is_shift_jis() {
+        let (bytes, _, _) = SHIFT_JIS.encode("# こんにちは、世界\nx = 1\n");
+        assert_eq!(detect_encoding(&bytes), Some(SHIFT_JIS));
+        assert_eq!(
+            decode_legacy(bytes.into_owned()),
+            "# こんにちは、世界\nx = 1\n".as_bytes()
+        );
+    }
+
+    #[test]
+    fn dense_high_bytes_stay_undetected() {
+        let bytes = vec![0xE9; 64];
+        assert_eq!(detect_encoding(&bytes), None);
+        assert_eq!(decode_legacy(bytes.clone()), bytes);
+    }
+}
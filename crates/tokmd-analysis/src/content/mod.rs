@@ -8,7 +8,7 @@ use tokmd_analysis_types::{
 };
 use tokmd_types::{ExportData, FileKind, FileRow};
 
-use tokmd_analysis_types::normalize_path;
+use tokmd_analysis_types::{RuntimeBudget, normalize_path};
 use tokmd_scan::round_f64;
 
 pub(crate) mod complexity;
@@ -27,6 +27,14 @@ pub(crate) enum ImportGranularity {
 pub(crate) struct ContentLimits {
     pub max_bytes: Option<u64>,
     pub max_file_bytes: Option<u64>,
+    pub strip_bom: bool,
+    pub runtime_budget: Option<RuntimeBudget>,
+}
+
+impl ContentLimits {
+    fn out_of_time(&self) -> bool {
+        self.runtime_budget.is_some_and(|budget| budget.exhausted())
+    }
 }
 
 pub(crate) fn build_todo_report(
@@ -42,12 +50,13 @@ pub(crate) fn build_todo_report(
     let per_file_limit = limits.max_file_bytes.unwrap_or(DEFAULT_MAX_FILE_BYTES) as usize;
 
     for rel in files {
-        if max_total.is_some_and(|limit| total_bytes >= limit) {
+        if max_total.is_some_and(|limit| total_bytes >= limit) || limits.out_of_time() {
             break;
         }
         let path = root.join(rel);
         let bytes = crate::content::io::read_head(&path, per_file_limit)?;
         total_bytes += bytes.len() as u64;
+        let bytes = crate::content::io::prepare_text(bytes, limits.strip_bom);
         if !crate::content::io::is_text_like(&bytes) {
             continue;
         }
@@ -82,6 +91,43 @@ pub(crate) fn build_todo_report(
     })
 }
 
+/// Paths of the files that start with a byte-order mark, in input order.
+/// Unreadable files are skipped.
+pub(crate) fn find_bom_files(root: &Path, files: &[PathBuf]) -> Vec<String> {
+    files
+        .iter()
+        .filter(|rel| {
+            crate::content::io::read_head(&root.join(rel), 3)
+                .is_ok_and(|head| crate::content::io::detect_bom(&head).is_some())
+        })
+        .map(|rel| rel.to_string_lossy().replace('\\', "/"))
+        .collect()
+}
+
+/// Files whose content window reads as a legacy encoding, sorted by path.
+/// Unreadable files are skipped.
+#[cfg(feature = "encoding")]
+pub(crate) fn find_non_utf8_files(
+    root: &Path,
+    files: &[PathBuf],
+    limits: &ContentLimits,
+) -> Vec<tokmd_analysis_types::NonUtf8FileRow> {
+    let per_file_limit = limits.max_file_bytes.unwrap_or(DEFAULT_MAX_FILE_BYTES) as usize;
+    let mut rows: Vec<_> = files
+        .iter()
+        .filter_map(|rel| {
+            let bytes = crate::content::io::read_head(&root.join(rel), per_file_limit).ok()?;
+            let encoding = crate::content::io::detect_encoding(&bytes)?;
+            Some(tokmd_analysis_types::NonUtf8FileRow {
+                path: rel.to_string_lossy().replace('\\', "/"),
+                encoding: encoding.to_string(),
+            })
+        })
+        .collect();
+    rows.sort_by(|a, b| a.path.cmp(&b.path));
+    rows
+}
+
 pub(crate) fn build_duplicate_report(
     root: &Path,
     files: &[PathBuf],
@@ -92,6 +138,9 @@ pub(crate) fn build_duplicate_report(
     let size_limit = limits.max_file_bytes;
 
     for rel in files {
+        if limits.out_of_time() {
+            break;
+        }
         let size = std::fs::metadata(root.join(rel))
             .map(|m| m.len())
             .unwrap_or(0);
@@ -240,7 +289,7 @@ pub(crate) fn build_import_report(
     let per_file_limit = limits.max_file_bytes.unwrap_or(DEFAULT_MAX_FILE_BYTES) as usize;
 
     for rel in files {
-        if max_total.is_some_and(|limit| total_bytes >= limit) {
+        if max_total.is_some_and(|limit| total_bytes >= limit) || limits.out_of_time() {
             break;
         }
         let rel_str = rel.to_string_lossy().replace('\\', "/");
@@ -25,6 +25,9 @@ fn file_row(path: &str, module: &str, lang: &str, bytes: usize) -> FileRow {
         lines: 13,
         bytes,
         tokens: 80,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
@@ -152,6 +155,8 @@ fn given_max_bytes_limit_when_building_todo_report_then_stops_early() {
     let limits = ContentLimits {
         max_bytes: Some(10), // very small budget
         max_file_bytes: None,
+        strip_bom: false,
+        runtime_budget: None,
     };
     let report = build_todo_report(root, &files, &limits, 1000).unwrap();
 
@@ -324,6 +329,8 @@ fn given_file_exceeds_max_file_bytes_when_building_duplicate_report_then_skipped
     let limits = ContentLimits {
         max_bytes: None,
         max_file_bytes: Some(100), // both files exceed this
+        strip_bom: false,
+        runtime_budget: None,
     };
     let report = build_duplicate_report(root, &files, &export, &limits).unwrap();
 
@@ -485,6 +492,8 @@ fn given_max_bytes_limit_when_building_import_report_then_budget_respected() {
     let limits = ContentLimits {
         max_bytes: Some(5), // very small budget
         max_file_bytes: None,
+        strip_bom: false,
+        runtime_budget: None,
     };
     let report =
         build_import_report(root, &files, &export, ImportGranularity::Module, &limits).unwrap();
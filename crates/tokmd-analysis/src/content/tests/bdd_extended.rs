@@ -25,6 +25,9 @@ fn file_row(path: &str, module: &str, lang: &str, bytes: usize) -> FileRow {
         lines: 13,
         bytes,
         tokens: 80,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
@@ -318,6 +321,8 @@ fn given_per_file_byte_limit_when_building_import_report_then_large_file_truncat
     let limits = ContentLimits {
         max_bytes: None,
         max_file_bytes: Some(256),
+        strip_bom: false,
+        runtime_budget: None,
     };
     let report =
         build_import_report(root, &files, &export, ImportGranularity::Module, &limits).unwrap();
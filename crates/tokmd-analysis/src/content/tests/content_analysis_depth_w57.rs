@@ -29,6 +29,9 @@ fn file_row(path: &str, module: &str, lang: &str, bytes: usize) -> FileRow {
         lines: 13,
         bytes,
         tokens: 80,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
@@ -314,6 +317,8 @@ fn dup_max_file_bytes_excludes_large() {
     let limits = ContentLimits {
         max_bytes: None,
         max_file_bytes: Some(10), // far smaller than content
+        strip_bom: false,
+        runtime_budget: None,
     };
     let report = build_duplicate_report(tmp.path(), &[f1, f2], &exp, &limits).unwrap();
     assert!(report.groups.is_empty());
@@ -646,6 +651,8 @@ fn import_respects_max_bytes() {
     let limits = ContentLimits {
         max_bytes: Some(big_content.len() as u64),
         max_file_bytes: None,
+        strip_bom: false,
+        runtime_budget: None,
     };
     let report = build_import_report(
         tmp.path(),
@@ -27,6 +27,9 @@ fn make_row(path: &str, module: &str, lang: &str, bytes: usize) -> FileRow {
         lines: 115,
         bytes,
         tokens: 200,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
@@ -43,6 +46,8 @@ fn default_limits() -> ContentLimits {
     ContentLimits {
         max_bytes: None,
         max_file_bytes: None,
+        strip_bom: false,
+        runtime_budget: None,
     }
 }
 
@@ -118,6 +123,8 @@ mod todo_w76 {
         let limits = ContentLimits {
             max_bytes: Some(content.len() as u64),
             max_file_bytes: None,
+            strip_bom: false,
+            runtime_budget: None,
         };
         let r = build_todo_report(tmp.path(), &[f1, f2, f3], &limits, 1000).unwrap();
         // Only the first file should be scanned before budget is exhausted
@@ -172,6 +179,8 @@ mod duplicate_w76 {
         let limits = ContentLimits {
             max_bytes: None,
             max_file_bytes: Some(100),
+            strip_bom: false,
+            runtime_budget: None,
         };
         let r = build_duplicate_report(tmp.path(), &[f1, f2, f3, f4], &e, &limits).unwrap();
         // Big files should be excluded; only small files considered
@@ -28,6 +28,9 @@ fn file_row(path: &str, module: &str, lang: &str, bytes: usize) -> FileRow {
         lines: 13,
         bytes,
         tokens: 80,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
@@ -114,6 +117,8 @@ fn todo_max_bytes_limit() {
     let limits = ContentLimits {
         max_bytes: Some(15), // only enough for first file
         max_file_bytes: None,
+        strip_bom: false,
+        runtime_budget: None,
     };
     let report = build_todo_report(tmp.path(), &[rel1, rel2], &limits, 1000).unwrap();
     // At least one TODO found, but not necessarily both due to byte limit
@@ -260,6 +265,8 @@ fn dup_max_file_bytes_limit() {
     let limits = ContentLimits {
         max_bytes: None,
         max_file_bytes: Some(100),
+        strip_bom: false,
+        runtime_budget: None,
     };
     let report = build_duplicate_report(tmp.path(), &files, &exp, &limits).unwrap();
     assert!(report.groups.is_empty());
@@ -380,6 +387,9 @@ fn import_child_rows_excluded() {
             lines: 1,
             bytes: content.len(),
             tokens: 5,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         }],
         module_roots: vec![],
         module_depth: 1,
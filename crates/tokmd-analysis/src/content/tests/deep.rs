@@ -7,7 +7,7 @@ use std::path::PathBuf;
 
 use crate::content::{
     ContentLimits, ImportGranularity, build_duplicate_report, build_import_report,
-    build_todo_report,
+    build_todo_report, find_bom_files,
 };
 use tempfile::TempDir;
 use tokmd_types::{ChildIncludeMode, ExportData, FileKind, FileRow};
@@ -28,6 +28,9 @@ fn file_row(path: &str, module: &str, lang: &str, bytes: usize) -> FileRow {
         lines: 13,
         bytes,
         tokens: 80,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
@@ -432,6 +435,8 @@ fn todo_respects_per_file_limit() {
     let limits = ContentLimits {
         max_bytes: None,
         max_file_bytes: Some(100), // Only read first 100 bytes
+        strip_bom: false,
+        runtime_budget: None,
     };
     let report = build_todo_report(tmp.path(), &[rel], &limits, 1000).unwrap();
     // The TODO is past byte 100, so it should not be found
@@ -449,6 +454,8 @@ fn todo_respects_total_byte_limit() {
     let limits = ContentLimits {
         max_bytes: Some(big_content.len() as u64), // Limit reached after first file
         max_file_bytes: None,
+        strip_bom: false,
+        runtime_budget: None,
     };
     let report = build_todo_report(tmp.path(), &[f1, f2], &limits, 1000).unwrap();
     // Only first file should be scanned (10 TODOs), second file skipped
@@ -491,6 +498,9 @@ fn dup_excludes_child_rows() {
         lines: 10,
         bytes: content.len(),
         tokens: 50,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     });
     let exp = export(rows);
     let report =
@@ -499,3 +509,74 @@ fn dup_excludes_child_rows() {
     // but module attribution uses "(unknown)" for b.rs
     assert_eq!(report.groups.len(), 1);
 }
+
+// 27. A UTF-16 file with a BOM counts like its UTF-8 twin once stripped
+#[test]
+fn todo_strip_bom_counts_utf16_file_like_its_twin() {
+    let tmp = TempDir::new().unwrap();
+    let text = "// TODO: one\n// FIXME: two\n";
+    let mut utf16 = vec![0xFF, 0xFE];
+    for unit in text.encode_utf16() {
+        utf16.extend_from_slice(&unit.to_le_bytes());
+    }
+    let plain = write_file(tmp.path(), "plain.rs", text.as_bytes());
+    let bom = write_file(tmp.path(), "bom.rs", &utf16);
+    let stripping = ContentLimits {
+        strip_bom: true,
+        ..ContentLimits::default()
+    };
+
+    let twin = build_todo_report(tmp.path(), &[plain], &stripping, 1000).unwrap();
+    let raw = build_todo_report(
+        tmp.path(),
+        std::slice::from_ref(&bom),
+        &ContentLimits::default(),
+        1000,
+    )
+    .unwrap();
+    let stripped = build_todo_report(tmp.path(), &[bom], &stripping, 1000).unwrap();
+    assert_eq!(raw.total, 0, "UTF-16 is not text-like without stripping");
+    assert_eq!(stripped.total, twin.total);
+    let counts = |report: &tokmd_analysis_types::TodoReport| {
+        report
+            .tags
+            .iter()
+            .map(|row| (row.tag.clone(), row.count))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(counts(&stripped), counts(&twin));
+    assert_eq!(twin.total, 2);
+}
+
+// 28. Only files that start with a BOM are reported
+#[test]
+fn find_bom_files_lists_bom_prefixed_files() {
+    let tmp = TempDir::new().unwrap();
+    let files = vec![
+        write_file(tmp.path(), "a.rs", b"\xEF\xBB\xBFfn a() {}\n"),
+        write_file(tmp.path(), "b.rs", b"fn b() {}\n"),
+        write_file(tmp.path(), "c/d.txt", b"\xFE\xFF\0x"),
+        PathBuf::from("missing.rs"),
+    ];
+    assert_eq!(find_bom_files(tmp.path(), &files), vec!["a.rs", "c/d.txt"]);
+}
+
+// 29. Legacy-encoded files are listed with their encoding
+#[cfg(feature = "encoding")]
+#[test]
+fn find_non_utf8_files_records_detected_encodings() {
+    let tmp = TempDir::new().unwrap();
+    let files = vec![
+        write_file(tmp.path(), "utf8.py", "caf\u{e9} = 1\n".as_bytes()),
+        write_file(tmp.path(), "src/latin1.py", b"caf\xE9 = 1\n"),
+        write_file(tmp.path(), "logo.png", b"\x89PNG\r\n\x1A\n"),
+    ];
+    let rows = crate::content::find_non_utf8_files(tmp.path(), &files, &ContentLimits::default());
+    assert_eq!(
+        rows,
+        vec![tokmd_analysis_types::NonUtf8FileRow {
+            path: "src/latin1.py".to_string(),
+            encoding: "windows-1252".to_string(),
+        }]
+    );
+}
@@ -22,6 +22,9 @@ fn make_row(path: &str, module: &str, lang: &str, bytes: usize) -> FileRow {
         lines: 115,
         bytes,
         tokens: 200,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
@@ -38,6 +41,8 @@ fn default_limits() -> ContentLimits {
     ContentLimits {
         max_bytes: None,
         max_file_bytes: None,
+        strip_bom: false,
+        runtime_budget: None,
     }
 }
 
@@ -144,6 +149,8 @@ fn todo_report_respects_max_file_bytes() {
     let limits = ContentLimits {
         max_bytes: None,
         max_file_bytes: Some(50), // very small limit
+        strip_bom: false,
+        runtime_budget: None,
     };
     let files = vec![PathBuf::from("big.rs")];
     let report = build_todo_report(root, &files, &limits, 1000).unwrap();
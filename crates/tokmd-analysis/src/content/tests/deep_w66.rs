@@ -22,6 +22,9 @@ fn make_row(path: &str, module: &str, lang: &str, bytes: usize) -> FileRow {
         lines: 115,
         bytes,
         tokens: 200,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
@@ -38,6 +41,8 @@ fn default_limits() -> ContentLimits {
     ContentLimits {
         max_bytes: None,
         max_file_bytes: None,
+        strip_bom: false,
+        runtime_budget: None,
     }
 }
 
@@ -181,6 +186,8 @@ mod content_limits_w66 {
         let limits = ContentLimits {
             max_bytes: Some(50),
             max_file_bytes: None,
+            strip_bom: false,
+            runtime_budget: None,
         };
         let r = build_todo_report(tmp.path(), &files, &limits, 10000).unwrap();
         assert!(
@@ -197,6 +204,8 @@ mod content_limits_w66 {
         let limits = ContentLimits {
             max_bytes: Some(0),
             max_file_bytes: None,
+            strip_bom: false,
+            runtime_budget: None,
         };
         let r = build_todo_report(tmp.path(), &[f], &limits, 1000).unwrap();
         assert_eq!(r.total, 0);
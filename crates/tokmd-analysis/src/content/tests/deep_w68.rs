@@ -23,6 +23,9 @@ fn make_row(path: &str, module: &str, lang: &str, bytes: usize) -> FileRow {
         lines: 115,
         bytes,
         tokens: 200,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
@@ -39,6 +42,8 @@ fn default_limits() -> ContentLimits {
     ContentLimits {
         max_bytes: None,
         max_file_bytes: None,
+        strip_bom: false,
+        runtime_budget: None,
     }
 }
 
@@ -176,6 +181,8 @@ mod limits_w68 {
         let limits = ContentLimits {
             max_bytes: None,
             max_file_bytes: Some(100),
+            strip_bom: false,
+            runtime_budget: None,
         };
         let r = build_todo_report(tmp.path(), &[f], &limits, 1000).unwrap();
         assert_eq!(r.total, 0, "TODO past file byte limit should not be found");
@@ -188,6 +195,8 @@ mod limits_w68 {
         let limits = ContentLimits {
             max_bytes: Some(0),
             max_file_bytes: None,
+            strip_bom: false,
+            runtime_budget: None,
         };
         let r = build_todo_report(tmp.path(), &[f], &limits, 1000).unwrap();
         assert_eq!(r.total, 0);
@@ -14,6 +14,9 @@ fn make_row(path: &str, module: &str, lang: &str) -> FileRow {
         lines: 1,
         bytes: 10,
         tokens: 2,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
@@ -59,6 +62,8 @@ fn import_edges_are_deterministically_sorted_by_destination() {
     let limits = ContentLimits {
         max_bytes: None,
         max_file_bytes: None,
+        strip_bom: false,
+        runtime_budget: None,
     };
 
     let report = build_import_report(
@@ -16,6 +16,9 @@ fn file_row(path: &str, module: &str, lang: &str) -> FileRow {
         lines: 1,
         bytes: 64,
         tokens: 8,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
@@ -107,8 +107,7 @@ pub(super) fn build_top_offenders(rows: &[FileStatRow]) -> TopOffenders {
         let a_doc = a.doc_pct.unwrap_or(0.0);
         let b_doc = b.doc_pct.unwrap_or(0.0);
         a_doc
-            .partial_cmp(&b_doc)
-            .unwrap_or(std::cmp::Ordering::Equal)
+            .total_cmp(&b_doc)
             .then_with(|| b.lines.cmp(&a.lines))
             .then_with(|| a.path.cmp(&b.path))
     });
@@ -117,10 +116,7 @@ pub(super) fn build_top_offenders(rows: &[FileStatRow]) -> TopOffenders {
     dense.sort_by(|a, b| {
         let a_rate = a.bytes_per_line.unwrap_or(0.0);
         let b_rate = b.bytes_per_line.unwrap_or(0.0);
-        b_rate
-            .partial_cmp(&a_rate)
-            .unwrap_or(std::cmp::Ordering::Equal)
-            .then_with(|| a.path.cmp(&b.path))
+        b_rate.total_cmp(&a_rate).then_with(|| a.path.cmp(&b.path))
     });
 
     TopOffenders {
@@ -1,11 +1,37 @@
-use tokmd_analysis_types::IntegrityReport;
-use tokmd_types::FileRow;
+use tokmd_analysis_types::{AnalysisReceipt, IntegrityReport};
+use tokmd_format::{IntegrityHasher, IntegrityMismatch, check_integrity, integrity_algo};
+use tokmd_types::{ExportData, FileKind, FileRow, HashAlgo};
+
+/// Recompute an analysis receipt's `derived.integrity` and compare it with
+/// the recorded block.
+///
+/// Analysis receipts do not embed their file rows, so the caller passes the
+/// export the analysis ran on (e.g. the `export.json` it was given). Only
+/// parent rows are hashed, matching [`crate::derive_report`].
+pub fn verify_integrity(
+    receipt: &AnalysisReceipt,
+    export: &ExportData,
+) -> Result<(), IntegrityMismatch> {
+    let derived = receipt.derived.as_ref().ok_or(IntegrityMismatch::Missing)?;
+    let algo = integrity_algo(&derived.integrity)?;
+    check_integrity(&derived.integrity, &export_integrity(export, algo))
+}
+
+/// Integrity block over every parent row of `export`.
+pub(crate) fn export_integrity(export: &ExportData, algo: HashAlgo) -> IntegrityReport {
+    let parents: Vec<&FileRow> = export
+        .rows
+        .iter()
+        .filter(|r| r.kind == FileKind::Parent)
+        .collect();
+    build_integrity_report(&parents, algo)
+}
 
-pub(super) fn build_integrity_report(rows: &[&FileRow]) -> IntegrityReport {
+pub(super) fn build_integrity_report(rows: &[&FileRow], algo: HashAlgo) -> IntegrityReport {
     let mut sorted_rows = rows.to_vec();
     sorted_rows.sort_unstable_by(|&a, &b| compare_integrity_rows(a, b));
 
-    let mut hasher = blake3::Hasher::new();
+    let mut hasher = IntegrityHasher::new(algo);
     let mut first = true;
     for row in sorted_rows {
         if !first {
@@ -20,8 +46,8 @@ pub(super) fn build_integrity_report(rows: &[&FileRow]) -> IntegrityReport {
     }
 
     IntegrityReport {
-        algo: "blake3".to_string(),
-        hash: hasher.finalize().to_hex().to_string(),
+        algo: algo.as_str().to_string(),
+        hash: hasher.finalize_hex(),
         entries: rows.len(),
     }
 }
@@ -93,7 +119,6 @@ fn write_usize_ascii(buf: &mut [u8], value: usize) -> usize {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use tokmd_types::FileKind;
 
     fn make_row(path: &str, bytes: usize, lines: usize) -> FileRow {
         FileRow {
@@ -107,6 +132,9 @@ mod tests {
             lines,
             bytes,
             tokens: 0,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         }
     }
 
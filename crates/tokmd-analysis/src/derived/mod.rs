@@ -5,9 +5,9 @@ use tokmd_analysis_types::{
     FileStatRow, NestingReport, NestingRow, ReadingTimeReport, TestDensityReport,
 };
 use tokmd_analysis_types::{is_infra_lang, is_test_path};
-use tokmd_format::render_analysis_tree;
+use tokmd_format::{AnalysisTreeOptions, render_analysis_tree_with_options};
 use tokmd_scan::{round_f64, safe_ratio};
-use tokmd_types::{ExportData, FileKind, FileRow};
+use tokmd_types::{ExportData, FileKind, FileRow, HashAlgo};
 
 use crate::cocomo81_core::{COCOMO81_COEFFICIENTS, cocomo81_effort_pm};
 
@@ -19,12 +19,23 @@ mod ratios;
 use distribution::{build_distribution_report, build_histogram};
 use files::{build_file_stats, build_max_file_report, build_top_offenders};
 use integrity::build_integrity_report;
+pub(crate) use integrity::export_integrity;
+pub use integrity::verify_integrity;
 use languages::{build_lang_purity_report, build_polyglot_report};
 use ratios::{build_doc_density_report, build_verbosity_report, build_whitespace_report};
 
 const LINES_PER_MINUTE: usize = 20;
 
 pub fn derive_report(export: &ExportData, window_tokens: Option<usize>) -> DerivedReport {
+    derive_report_with_algo(export, window_tokens, HashAlgo::default())
+}
+
+/// [`derive_report`] with the integrity hash computed using `hash_algo`.
+pub fn derive_report_with_algo(
+    export: &ExportData,
+    window_tokens: Option<usize>,
+    hash_algo: HashAlgo,
+) -> DerivedReport {
     let parents: Vec<&FileRow> = export
         .rows
         .iter()
@@ -117,7 +128,7 @@ pub fn derive_report(export: &ExportData, window_tokens: Option<usize>) -> Deriv
         })
     };
 
-    let integrity = build_integrity_report(&parents);
+    let integrity = build_integrity_report(&parents, hash_algo);
 
     DerivedReport {
         totals,
@@ -139,6 +150,8 @@ pub fn derive_report(export: &ExportData, window_tokens: Option<usize>) -> Deriv
         cocomo,
         todo: None,
         integrity,
+        oversized_files: vec![],
+        non_utf8_files: vec![],
     }
 }
 
@@ -253,8 +266,8 @@ fn build_boilerplate_report(rows: &[&FileRow]) -> BoilerplateReport {
     }
 }
 
-pub fn build_tree(export: &ExportData) -> String {
-    render_analysis_tree(export)
+pub fn build_tree(export: &ExportData, options: &AnalysisTreeOptions) -> String {
+    render_analysis_tree_with_options(export, options)
 }
 
 #[cfg(test)]
@@ -30,6 +30,9 @@ fn make_row(
         lines: code + comments + blanks,
         bytes,
         tokens,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
@@ -438,6 +441,9 @@ mod totals {
             lines: 57,
             bytes: 2000,
             tokens: 500,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         });
         let report = derive_report(&export(rows), None);
         assert_eq!(report.totals.files, 1);
@@ -34,6 +34,9 @@ fn row(
         lines: code + comments + blanks,
         bytes,
         tokens,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
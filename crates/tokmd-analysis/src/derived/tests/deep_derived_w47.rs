@@ -35,6 +35,9 @@ fn row(
         lines: code + comments + blanks,
         bytes,
         tokens,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
@@ -310,6 +313,9 @@ fn arb_file_row() -> impl Strategy<Value = FileRow> {
                 lines: code + comments + blanks,
                 bytes,
                 tokens,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             },
         )
 }
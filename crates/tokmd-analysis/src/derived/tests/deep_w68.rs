@@ -33,6 +33,9 @@ fn single_file_export(code: usize, comments: usize, blanks: usize) -> ExportData
             lines,
             bytes: lines * 25,
             tokens: code * 8,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         }],
         module_roots: vec!["src".to_string()],
         module_depth: 1,
@@ -54,6 +57,9 @@ fn multi_file_export() -> ExportData {
                 lines: 280,
                 bytes: 7_000,
                 tokens: 1_600,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             },
             FileRow {
                 path: "src/lib.rs".to_string(),
@@ -66,6 +72,9 @@ fn multi_file_export() -> ExportData {
                 lines: 130,
                 bytes: 3_250,
                 tokens: 800,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             },
             FileRow {
                 path: "src/util.py".to_string(),
@@ -78,6 +87,9 @@ fn multi_file_export() -> ExportData {
                 lines: 60,
                 bytes: 1_500,
                 tokens: 400,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             },
         ],
         module_roots: vec!["src".to_string()],
@@ -255,6 +267,9 @@ fn distribution_gini_uniform_is_low() {
                 lines: 60,
                 bytes: 1500,
                 tokens: 400,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             },
             FileRow {
                 path: "b.rs".to_string(),
@@ -267,6 +282,9 @@ fn distribution_gini_uniform_is_low() {
                 lines: 60,
                 bytes: 1500,
                 tokens: 400,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             },
         ],
         module_roots: vec!["src".to_string()],
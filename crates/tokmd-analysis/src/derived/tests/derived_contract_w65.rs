@@ -35,6 +35,9 @@ fn row(
         lines: code + comments + blanks,
         bytes,
         tokens,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
@@ -50,6 +53,9 @@ fn child_row(path: &str, lang: &str, code: usize) -> FileRow {
         lines: code,
         bytes: code * 20,
         tokens: code * 5,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
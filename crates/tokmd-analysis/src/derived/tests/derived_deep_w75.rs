@@ -31,6 +31,9 @@ fn single_file(code: usize, comments: usize, blanks: usize) -> ExportData {
             lines,
             bytes: lines * 30,
             tokens: code * 8,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         }],
         module_roots: vec!["src".to_string()],
         module_depth: 1,
@@ -52,6 +55,9 @@ fn multi_lang_export() -> ExportData {
                 lines: 520,
                 bytes: 13_000,
                 tokens: 3_200,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             },
             FileRow {
                 path: "src/lib.rs".to_string(),
@@ -64,6 +70,9 @@ fn multi_lang_export() -> ExportData {
                 lines: 260,
                 bytes: 6_500,
                 tokens: 1_600,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             },
             FileRow {
                 path: "scripts/build.py".to_string(),
@@ -76,6 +85,9 @@ fn multi_lang_export() -> ExportData {
                 lines: 120,
                 bytes: 3_000,
                 tokens: 800,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             },
             FileRow {
                 path: "web/index.js".to_string(),
@@ -88,6 +100,9 @@ fn multi_lang_export() -> ExportData {
                 lines: 60,
                 bytes: 1_500,
                 tokens: 400,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             },
         ],
         module_roots: vec!["src".to_string(), "scripts".to_string(), "web".to_string()],
@@ -218,6 +233,9 @@ fn distribution_gini_zero_for_equal_files() {
                 lines: 100,
                 bytes: 2500,
                 tokens: 800,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             },
             FileRow {
                 path: "b.rs".to_string(),
@@ -230,6 +248,9 @@ fn distribution_gini_zero_for_equal_files() {
                 lines: 100,
                 bytes: 2500,
                 tokens: 800,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             },
         ],
         module_roots: vec!["src".to_string()],
@@ -303,6 +324,9 @@ fn edge_child_rows_excluded() {
                 lines: 115,
                 bytes: 2875,
                 tokens: 800,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             },
             FileRow {
                 path: "src/lib.rs".to_string(),
@@ -315,6 +339,9 @@ fn edge_child_rows_excluded() {
                 lines: 50,
                 bytes: 1250,
                 tokens: 400,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             },
         ],
         module_roots: vec!["src".to_string()],
@@ -23,6 +23,9 @@ fn make_row(path: &str, lang: &str, code: usize, comments: usize, blanks: usize)
         lines,
         bytes: lines * 40,
         tokens: lines * 5,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
@@ -26,6 +26,9 @@ fn row(path: &str, lang: &str, code: usize, comments: usize, blanks: usize) -> F
         lines,
         bytes: lines * 40,
         tokens: lines * 5,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
@@ -52,6 +55,9 @@ fn row_with_bytes(
         lines,
         bytes,
         tokens: lines * 5,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
@@ -485,6 +491,9 @@ fn arb_row() -> impl Strategy<Value = FileRow> {
                 lines,
                 bytes: lines * 40,
                 tokens: lines * 5,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             }
         })
 }
@@ -1,5 +1,5 @@
 use crate::derived::build_tree;
-use tokmd_format::render_analysis_tree;
+use tokmd_format::{AnalysisTreeOptions, render_analysis_tree};
 use tokmd_types::{ChildIncludeMode, ExportData, FileKind, FileRow};
 
 fn row(path: &str, kind: FileKind, lines: usize, tokens: usize) -> FileRow {
@@ -14,6 +14,9 @@ fn row(path: &str, kind: FileKind, lines: usize, tokens: usize) -> FileRow {
         lines,
         bytes: lines * 8,
         tokens,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
@@ -33,7 +36,10 @@ fn derived_build_tree_matches_export_tree_renderer() {
         row("src/lib.rs", FileKind::Parent, 20, 40),
     ]);
 
-    assert_eq!(build_tree(&export), render_analysis_tree(&export));
+    assert_eq!(
+        build_tree(&export, &AnalysisTreeOptions::default()),
+        render_analysis_tree(&export)
+    );
 }
 
 #[test]
@@ -43,7 +49,7 @@ fn derived_build_tree_ignores_child_rows() {
         row("src/main.rs::embedded", FileKind::Child, 99, 199),
     ]);
 
-    let tree = build_tree(&export);
+    let tree = build_tree(&export, &AnalysisTreeOptions::default());
     assert!(tree.contains("main.rs (lines: 10, tokens: 20)"));
     assert!(!tree.contains("embedded"));
 }
@@ -41,6 +41,9 @@ fn arb_file_row() -> impl Strategy<Value = FileRow> {
                 lines: code + comments + blanks,
                 bytes,
                 tokens,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             },
         )
 }
@@ -20,6 +20,9 @@ fn make_row(code: usize, comments: usize, blanks: usize) -> FileRow {
         lines,
         bytes: lines * 40,
         tokens: lines * 4,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
@@ -138,6 +141,9 @@ proptest! {
                 lines: s,
                 bytes: s * 40,
                 tokens: s * 4,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             }
         }).collect();
 
@@ -164,6 +170,9 @@ proptest! {
                 lines: s,
                 bytes: s * 40,
                 tokens: s * 4,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             }
         }).collect();
 
@@ -199,6 +208,9 @@ proptest! {
                 lines: s,
                 bytes: s * 30,
                 tokens: s * 3,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             }
         }).collect();
 
@@ -227,6 +239,9 @@ proptest! {
                 lines: s,
                 bytes: s * 30,
                 tokens: s * 3,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             }
         }).collect();
 
@@ -97,6 +97,9 @@ mod tests {
             lines: 10,
             bytes: 40,
             tokens: 20,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         }
     }
 
@@ -169,6 +169,9 @@ mod tests {
             lines: 1,
             bytes: 1,
             tokens: 1,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         }
     }
 
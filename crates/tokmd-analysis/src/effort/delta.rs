@@ -220,6 +220,7 @@ mod tests {
             module_roots: Vec::new(),
             module_depth: 1,
             children: tokmd_types::ChildIncludeMode::Separate,
+            summary: None,
         };
         let dir = tempfile::tempdir().unwrap();
         let err = build_delta(dir.path(), &export, None, "HEAD~1", "HEAD").unwrap_err();
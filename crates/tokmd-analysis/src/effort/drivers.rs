@@ -1,5 +1,3 @@
-use std::cmp::Ordering;
-
 use tokmd_analysis_types::{
     ApiSurfaceReport, ComplexityReport, DerivedReport, DuplicateReport, EffortDriver,
     EffortDriverDirection, EffortSizeBasis, GitReport,
@@ -145,8 +143,7 @@ pub fn build_drivers(
 
     drivers.sort_by(|a, b| {
         b.weight
-            .partial_cmp(&a.weight)
-            .unwrap_or(Ordering::Equal)
+            .total_cmp(&a.weight)
             .then_with(|| a.key.cmp(&b.key))
     });
     drivers
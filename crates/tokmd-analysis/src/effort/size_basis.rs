@@ -195,6 +195,9 @@ mod tests {
                 lines: 12,
                 bytes: 120,
                 tokens: 30,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             }],
             module_roots: vec!["src".to_string()],
             module_depth: 1,
@@ -224,6 +227,9 @@ mod tests {
                 lines: 40,
                 bytes: 300,
                 tokens: 20,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             }],
             module_roots: vec!["src".to_string()],
             module_depth: 1,
@@ -256,6 +262,9 @@ mod tests {
                 lines: 40,
                 bytes: 300,
                 tokens: 20,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             }],
             module_roots: vec!["src".to_string()],
             module_depth: 1,
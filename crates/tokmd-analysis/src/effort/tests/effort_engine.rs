@@ -97,6 +97,9 @@ fn make_export(code: usize) -> ExportData {
             lines: code,
             bytes: code.saturating_mul(4),
             tokens: code.saturating_mul(2),
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         }],
         module_roots: vec!["src".to_string()],
         module_depth: 1,
@@ -256,6 +259,8 @@ fn make_derived(total_code: usize, test_ratio: f64, polyglot_count: usize) -> De
             hash: "000000".to_string(),
             entries: 1,
         },
+        oversized_files: vec![],
+        non_utf8_files: vec![],
     }
 }
 
@@ -346,6 +351,9 @@ fn build_effort_report_reports_authored_generated_and_vendored_breakdown() {
                 lines: 100,
                 bytes: 1200,
                 tokens: 180,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             },
             FileRow {
                 path: "target/generated/bundle.min.js".to_string(),
@@ -358,6 +366,9 @@ fn build_effort_report_reports_authored_generated_and_vendored_breakdown() {
                 lines: 50,
                 bytes: 600,
                 tokens: 80,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             },
             FileRow {
                 path: "src/vendor/lib/external.rs".to_string(),
@@ -370,6 +381,9 @@ fn build_effort_report_reports_authored_generated_and_vendored_breakdown() {
                 lines: 20,
                 bytes: 240,
                 tokens: 32,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             },
         ],
         module_roots: vec![
@@ -437,6 +451,9 @@ fn effort_driver_extraction_tracks_expected_signal_types() {
                 lines: 400,
                 bytes: 1_600,
                 tokens: 800,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             },
             FileRow {
                 path: "target/generated/bundle.min.js".to_string(),
@@ -449,6 +466,9 @@ fn effort_driver_extraction_tracks_expected_signal_types() {
                 lines: 800,
                 bytes: 2_400,
                 tokens: 1_200,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             },
             FileRow {
                 path: "src/vendor/lib/external.rs".to_string(),
@@ -461,6 +481,9 @@ fn effort_driver_extraction_tracks_expected_signal_types() {
                 lines: 700,
                 bytes: 1_400,
                 tokens: 250,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             },
         ],
         module_roots: vec![
@@ -546,6 +569,11 @@ fn effort_driver_extraction_tracks_expected_signal_types() {
         by_language: BTreeMap::new(),
         by_module: vec![],
         top_exporters: vec![],
+        public_symbols: Default::default(),
+        doc_violations: vec![],
+        items: None,
+        items_truncated: false,
+        public_symbols_truncated: false,
     };
     let dup = DuplicateReport {
         groups: vec![],
@@ -612,6 +640,9 @@ fn effort_confidence_distinguishes_signal_coverage() {
             lines: 100,
             bytes: 400,
             tokens: 200,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         }],
         module_roots: vec!["mystery".to_string()],
         module_depth: 1,
@@ -715,6 +746,11 @@ fn effort_confidence_distinguishes_signal_coverage() {
         by_language: BTreeMap::new(),
         by_module: vec![],
         top_exporters: vec![],
+        public_symbols: Default::default(),
+        doc_violations: vec![],
+        items: None,
+        items_truncated: false,
+        public_symbols_truncated: false,
     };
     let dup = DuplicateReport {
         groups: vec![],
@@ -27,7 +27,7 @@ pub(crate) fn build_entropy_report(
     let per_file_limit = limits.max_file_bytes.unwrap_or(DEFAULT_SAMPLE_BYTES as u64) as usize;
 
     for rel in files {
-        if max_total.is_some_and(|limit| total_bytes >= limit) {
+        if max_total.is_some_and(|limit| total_bytes >= limit) || limits.out_of_time() {
             break;
         }
         let rel_str = rel.to_string_lossy().replace('\\', "/");
@@ -57,8 +57,7 @@ pub(crate) fn build_entropy_report(
 
     suspects.sort_by(|a, b| {
         b.entropy_bits_per_byte
-            .partial_cmp(&a.entropy_bits_per_byte)
-            .unwrap_or(std::cmp::Ordering::Equal)
+            .total_cmp(&a.entropy_bits_per_byte)
             .then_with(|| a.path.cmp(&b.path))
     });
     suspects.truncate(MAX_SUSPECTS);
@@ -103,6 +102,9 @@ mod tests {
                 lines: 1,
                 bytes: 10,
                 tokens: 2,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             })
             .collect();
         ExportData {
@@ -25,6 +25,9 @@ fn export_for_paths(paths: &[&str]) -> ExportData {
             lines: 1,
             bytes: 10,
             tokens: 2,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         })
         .collect();
     ExportData {
@@ -28,6 +28,9 @@ fn export_for_paths(paths: &[&str]) -> ExportData {
             lines: 1,
             bytes: 10,
             tokens: 2,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         })
         .collect();
     ExportData {
@@ -155,6 +158,9 @@ fn exactly_fifty_suspects_not_truncated() {
             lines: 1,
             bytes: 10,
             tokens: 2,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         });
         files.push(PathBuf::from(name));
     }
@@ -198,6 +204,9 @@ fn fifty_one_suspects_truncated_to_fifty() {
             lines: 1,
             bytes: 10,
             tokens: 2,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         });
         files.push(PathBuf::from(name));
     }
@@ -242,6 +251,9 @@ fn different_prng_seeds_all_classify_high() {
             lines: 1,
             bytes: 10,
             tokens: 2,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         });
         files.push(PathBuf::from(name));
     }
@@ -313,6 +325,9 @@ fn subdirectory_file_maps_to_correct_module() {
         lines: 1,
         bytes: 10,
         tokens: 2,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }];
     let export = ExportData {
         rows,
@@ -427,6 +442,9 @@ fn parent_row_preferred_over_child_for_module_lookup() {
             lines: 1,
             bytes: 10,
             tokens: 2,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         },
         FileRow {
             path: "data.bin".to_string(),
@@ -439,6 +457,9 @@ fn parent_row_preferred_over_child_for_module_lookup() {
             lines: 1,
             bytes: 10,
             tokens: 2,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         },
     ];
     let export = ExportData {
@@ -28,6 +28,9 @@ fn export_for_paths(paths: &[&str]) -> ExportData {
             lines: 1,
             bytes: 10,
             tokens: 2,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         })
         .collect();
     ExportData {
@@ -597,6 +600,9 @@ fn path_preserved_in_findings() {
         lines: 1,
         bytes: 10,
         tokens: 2,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }];
     let export = ExportData {
         rows,
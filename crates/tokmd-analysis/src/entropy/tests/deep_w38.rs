@@ -28,6 +28,9 @@ fn make_row(path: &str, module: &str) -> FileRow {
         lines: 1,
         bytes: 10,
         tokens: 2,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
@@ -366,6 +369,9 @@ fn report_includes_module_from_export() {
         lines: 1,
         bytes: 10,
         tokens: 2,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }];
     let export = ExportData {
         rows,
@@ -433,6 +439,11 @@ fn max_bytes_limit_stops_processing() {
         max_files: None,
         max_commits: None,
         max_commit_files: None,
+        oversized_file_bytes: None,
+        max_commit_message_bytes: None,
+        strip_bom: false,
+        sample_rate: None,
+        runtime_budget: None,
     };
 
     let report = build_entropy_report(dir.path(), &files, &export, &limits).unwrap();
@@ -457,6 +468,11 @@ fn max_file_bytes_limits_sample_size() {
         max_files: None,
         max_commits: None,
         max_commit_files: None,
+        oversized_file_bytes: None,
+        max_commit_message_bytes: None,
+        strip_bom: false,
+        sample_rate: None,
+        runtime_budget: None,
     };
 
     let report = build_entropy_report(dir.path(), &files, &export, &limits).unwrap();
@@ -489,6 +505,9 @@ fn child_rows_excluded_from_module_map() {
         lines: 1,
         bytes: 10,
         tokens: 2,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }];
     let export = ExportData {
         rows,
@@ -550,6 +569,9 @@ fn backslash_paths_normalized() {
         lines: 1,
         bytes: 10,
         tokens: 2,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }];
     let export = ExportData {
         rows,
@@ -28,6 +28,9 @@ fn export_for(paths: &[&str]) -> ExportData {
             lines: 1,
             bytes: 10,
             tokens: 2,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         })
         .collect();
     ExportData {
@@ -51,6 +54,9 @@ fn export_with_module(path: &str, module: &str) -> ExportData {
             lines: 1,
             bytes: 10,
             tokens: 2,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         }],
         module_roots: vec![],
         module_depth: 1,
@@ -402,6 +408,9 @@ fn child_rows_ignored_for_module_mapping() {
             lines: 1,
             bytes: 10,
             tokens: 2,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         }],
         module_roots: vec![],
         module_depth: 1,
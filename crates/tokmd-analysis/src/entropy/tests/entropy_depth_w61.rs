@@ -31,6 +31,9 @@ fn make_export(paths: &[&str]) -> ExportData {
             lines: 1,
             bytes: 10,
             tokens: 2,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         })
         .collect();
     ExportData {
@@ -54,6 +57,9 @@ fn make_export_with_module(path: &str, module: &str) -> ExportData {
             lines: 1,
             bytes: 10,
             tokens: 2,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         }],
         module_roots: vec![],
         module_depth: 1,
@@ -436,6 +442,9 @@ fn child_rows_ignored_for_module_lookup() {
                 lines: 1,
                 bytes: 10,
                 tokens: 2,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             },
             FileRow {
                 path: "x.bin".to_string(),
@@ -448,6 +457,9 @@ fn child_rows_ignored_for_module_lookup() {
                 lines: 1,
                 bytes: 10,
                 tokens: 2,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             },
         ],
         module_roots: vec![],
@@ -3,9 +3,9 @@
 //! This module preserves the former `analysis fingerprint module` seam inside
 //! the `tokmd-analysis` owner crate.
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 
-use tokmd_analysis_types::{CorporateFingerprint, DomainStat};
+use tokmd_analysis_types::{CorporateFingerprint, DomainGrouping, DomainStat};
 
 const PUBLIC_DOMAINS: [&str; 7] = [
     "gmail.com",
@@ -17,31 +17,55 @@ const PUBLIC_DOMAINS: [&str; 7] = [
     "protonmail.com",
 ];
 
+#[cfg(test)]
 pub(crate) fn build_corporate_fingerprint(
     commits: &[tokmd_git::GitCommit],
 ) -> CorporateFingerprint {
-    let mut counts: BTreeMap<String, u32> = BTreeMap::new();
+    build_corporate_fingerprint_with(commits, &DomainGrouping::default())
+}
+
+/// Count commits per author domain after applying `grouping`: denied
+/// domains are dropped, subdomains of a listed parent count under it, and
+/// personal-mail domains share one bucket unless allowed.
+pub(crate) fn build_corporate_fingerprint_with(
+    commits: &[tokmd_git::GitCommit],
+    grouping: &DomainGrouping,
+) -> CorporateFingerprint {
+    let parents = normalize_list(&grouping.parents);
+    let allow = normalize_list(&grouping.allow);
+    let deny = normalize_list(&grouping.deny);
+    let mut counts: BTreeMap<String, (u32, BTreeSet<String>)> = BTreeMap::new();
     let mut total = 0u32;
 
     for commit in commits {
         if let Some(domain) = extract_domain(&commit.author) {
             let domain = normalize_domain(&domain);
-            if domain.is_empty() || is_ignored_domain(&domain) {
+            if domain.is_empty() || is_ignored_domain(&domain) || matches_any(&domain, &deny) {
                 continue;
             }
-            let bucket = if is_public_domain(&domain) {
-                "public-email".to_string()
+            let parent = parents
+                .iter()
+                .filter(|parent| within(&domain, parent))
+                .max_by_key(|parent| parent.len());
+            let entry = if let Some(parent) = parent {
+                let entry = counts.entry(parent.clone()).or_default();
+                if domain != *parent {
+                    entry.1.insert(domain);
+                }
+                entry
+            } else if is_public_domain(&domain) && !matches_any(&domain, &allow) {
+                counts.entry("public-email".to_string()).or_default()
             } else {
-                domain
+                counts.entry(domain).or_default()
             };
-            *counts.entry(bucket).or_insert(0) += 1;
+            entry.0 += 1;
             total += 1;
         }
     }
 
     let mut domains: Vec<DomainStat> = counts
         .into_iter()
-        .map(|(domain, commits)| DomainStat {
+        .map(|(domain, (commits, members))| DomainStat {
             domain,
             commits,
             pct: if total == 0 {
@@ -49,6 +73,7 @@ pub(crate) fn build_corporate_fingerprint(
             } else {
                 (commits as f32) / (total as f32)
             },
+            members: members.into_iter().collect(),
         })
         .collect();
     domains.sort_by(|a, b| {
@@ -83,6 +108,29 @@ fn is_public_domain(domain: &str) -> bool {
     PUBLIC_DOMAINS.contains(&domain)
 }
 
+fn normalize_list(domains: &[String]) -> Vec<String> {
+    domains
+        .iter()
+        .map(|d| {
+            normalize_domain(d)
+                .trim_start_matches(['@', '.'])
+                .to_string()
+        })
+        .filter(|d| !d.is_empty())
+        .collect()
+}
+
+/// `domain` is `parent` or one of its subdomains.
+fn within(domain: &str, parent: &str) -> bool {
+    domain
+        .strip_suffix(parent)
+        .is_some_and(|rest| rest.is_empty() || rest.ends_with('.'))
+}
+
+fn matches_any(domain: &str, list: &[String]) -> bool {
+    list.iter().any(|entry| within(domain, entry))
+}
+
 #[cfg(test)]
 mod unit_tests {
     use super::*;
@@ -96,6 +144,7 @@ mod unit_tests {
                 hash: None,
                 subject: String::new(),
                 files: vec![],
+                message_truncated: false,
             },
             tokmd_git::GitCommit {
                 timestamp: 0,
@@ -103,6 +152,7 @@ mod unit_tests {
                 hash: None,
                 subject: String::new(),
                 files: vec![],
+                message_truncated: false,
             },
             tokmd_git::GitCommit {
                 timestamp: 0,
@@ -110,6 +160,7 @@ mod unit_tests {
                 hash: None,
                 subject: String::new(),
                 files: vec![],
+                message_truncated: false,
             },
         ];
 
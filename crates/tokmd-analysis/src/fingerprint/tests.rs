@@ -7,6 +7,7 @@ mod edge_cases;
 mod fingerprint_depth_w56;
 mod fingerprint_depth_w61;
 mod fingerprint_w75;
+mod grouping;
 mod identity_security;
 mod properties;
 mod unit;
@@ -10,6 +10,7 @@ fn commit(author: &str) -> GitCommit {
         hash: None,
         subject: String::new(),
         files: vec![],
+        message_truncated: false,
     }
 }
 
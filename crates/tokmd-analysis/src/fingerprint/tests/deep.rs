@@ -27,6 +27,7 @@ fn commit(author: &str) -> GitCommit {
         hash: None,
         subject: String::new(),
         files: vec![],
+        message_truncated: false,
     }
 }
 
@@ -40,6 +41,7 @@ fn domain_stat_serde_round_trip() {
         domain: "acme.com".to_string(),
         commits: 42,
         pct: 0.75,
+        members: Vec::new(),
     };
     let json = serde_json::to_string(&stat).unwrap();
     let deserialized: DomainStat = serde_json::from_str(&json).unwrap();
@@ -58,6 +60,7 @@ fn domain_stat_json_shape() {
         domain: "example.org".to_string(),
         commits: 10,
         pct: 0.5,
+        members: Vec::new(),
     };
     let v: serde_json::Value = serde_json::to_value(stat).unwrap();
     assert!(v.is_object());
@@ -318,6 +321,7 @@ fn domain_stat_clone_preserves_fields() {
         domain: "test.com".to_string(),
         commits: 5,
         pct: 0.5,
+        members: Vec::new(),
     };
     let cloned = stat.clone();
     assert_eq!(stat.domain, cloned.domain);
@@ -362,6 +366,7 @@ fn full_commit_fields_do_not_affect_extraction() {
         hash: Some("abc123def456".to_string()),
         subject: "feat: add something".to_string(),
         files: vec!["src/main.rs".to_string(), "Cargo.toml".to_string()],
+        message_truncated: false,
     }];
     let fp = build_corporate_fingerprint(&commits);
     assert_eq!(fp.domains.len(), 1);
@@ -16,6 +16,7 @@ fn commit(author: &str) -> GitCommit {
         hash: None,
         subject: String::new(),
         files: vec![],
+        message_truncated: false,
     }
 }
 
@@ -234,6 +235,7 @@ mod properties {
             hash: None,
             subject: String::new(),
             files: vec![],
+            message_truncated: false,
         })
     }
 
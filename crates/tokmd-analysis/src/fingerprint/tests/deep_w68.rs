@@ -17,6 +17,7 @@ fn commit(author: &str) -> GitCommit {
         hash: None,
         subject: String::new(),
         files: vec![],
+        message_truncated: false,
     }
 }
 
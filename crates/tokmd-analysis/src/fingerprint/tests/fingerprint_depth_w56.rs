@@ -16,6 +16,7 @@ fn commit(author: &str) -> GitCommit {
         hash: None,
         subject: String::new(),
         files: vec![],
+        message_truncated: false,
     }
 }
 
@@ -26,6 +27,7 @@ fn commit_with_ts(author: &str, ts: i64) -> GitCommit {
         hash: None,
         subject: String::new(),
         files: vec![],
+        message_truncated: false,
     }
 }
 
@@ -232,6 +234,7 @@ fn commit_files_ignored() {
         hash: None,
         subject: String::new(),
         files: vec!["src/main.rs".to_string(), "README.md".to_string()],
+        message_truncated: false,
     };
     let c2 = GitCommit {
         timestamp: 0,
@@ -239,6 +242,7 @@ fn commit_files_ignored() {
         hash: None,
         subject: String::new(),
         files: vec![],
+        message_truncated: false,
     };
     let r1 = build_corporate_fingerprint(&[c1]);
     let r2 = build_corporate_fingerprint(&[c2]);
@@ -255,6 +259,7 @@ fn hash_and_subject_ignored() {
         hash: Some("abc123".to_string()),
         subject: "fix: something important".to_string(),
         files: vec![],
+        message_truncated: false,
     };
     let report = build_corporate_fingerprint(&[c]);
     assert_eq!(report.domains.len(), 1);
@@ -18,6 +18,7 @@ fn commit(author: &str) -> GitCommit {
         hash: None,
         subject: String::new(),
         files: vec![],
+        message_truncated: false,
     }
 }
 
@@ -383,6 +384,7 @@ fn timestamps_do_not_affect_result() {
             hash: None,
             subject: String::new(),
             files: vec![],
+            message_truncated: false,
         },
         GitCommit {
             timestamp: 2000,
@@ -390,6 +392,7 @@ fn timestamps_do_not_affect_result() {
             hash: None,
             subject: String::new(),
             files: vec![],
+            message_truncated: false,
         },
     ];
     let c2 = vec![
@@ -399,6 +402,7 @@ fn timestamps_do_not_affect_result() {
             hash: None,
             subject: String::new(),
             files: vec![],
+            message_truncated: false,
         },
         GitCommit {
             timestamp: 1,
@@ -406,6 +410,7 @@ fn timestamps_do_not_affect_result() {
             hash: None,
             subject: String::new(),
             files: vec![],
+            message_truncated: false,
         },
     ];
     let r1 = build_corporate_fingerprint(&c1);
@@ -424,6 +429,7 @@ fn hash_subject_files_do_not_affect_result() {
         hash: Some("abc123".to_string()),
         subject: "feat: something".to_string(),
         files: vec!["src/main.rs".to_string()],
+        message_truncated: false,
     }];
     let c2 = vec![GitCommit {
         timestamp: 0,
@@ -431,6 +437,7 @@ fn hash_subject_files_do_not_affect_result() {
         hash: None,
         subject: String::new(),
         files: vec![],
+        message_truncated: false,
     }];
     let r1 = build_corporate_fingerprint(&c1);
     let r2 = build_corporate_fingerprint(&c2);
@@ -470,6 +477,7 @@ fn domain_stat_json_shape() {
         domain: "test.org".to_string(),
         commits: 7,
         pct: 0.25,
+        members: Vec::new(),
     };
     let v: serde_json::Value = serde_json::to_value(stat).unwrap();
     assert!(v.is_object());
@@ -18,6 +18,7 @@ fn commit(author: &str) -> GitCommit {
         hash: None,
         subject: String::new(),
         files: vec![],
+        message_truncated: false,
     }
 }
 
@@ -28,6 +29,7 @@ fn rich_commit(author: &str, hash: &str, subject: &str, files: &[&str]) -> GitCo
         hash: Some(hash.to_string()),
         subject: subject.to_string(),
         files: files.iter().map(|s| s.to_string()).collect(),
+        message_truncated: false,
     }
 }
 
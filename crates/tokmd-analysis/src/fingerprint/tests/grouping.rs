@@ -0,0 +1,124 @@
+//! Tests for configurable domain grouping in the corporate fingerprint.
+
+use crate::fingerprint::build_corporate_fingerprint_with;
+use tokmd_analysis_types::{CorporateFingerprint, DomainGrouping};
+use tokmd_git::GitCommit;
+
+fn commit(author: &str) -> GitCommit {
+    GitCommit {
+        timestamp: 0,
+        author: author.to_string(),
+        hash: None,
+        subject: String::new(),
+        files: vec![],
+        message_truncated: false,
+    }
+}
+
+fn fingerprint(authors: &[&str], grouping: &DomainGrouping) -> CorporateFingerprint {
+    let commits: Vec<GitCommit> = authors.iter().map(|a| commit(a)).collect();
+    build_corporate_fingerprint_with(&commits, grouping)
+}
+
+fn domains(fp: &CorporateFingerprint) -> Vec<(&str, u32)> {
+    fp.domains
+        .iter()
+        .map(|d| (d.domain.as_str(), d.commits))
+        .collect()
+}
+
+#[test]
+fn subdomains_collapse_to_parent() {
+    let grouping = DomainGrouping {
+        parents: vec!["acme.com".to_string()],
+        ..Default::default()
+    };
+    let fp = fingerprint(
+        &[
+            "a@acme.com",
+            "b@eng.acme.com",
+            "c@ops.eu.acme.com",
+            "d@other.org",
+        ],
+        &grouping,
+    );
+
+    assert_eq!(domains(&fp), vec![("acme.com", 3), ("other.org", 1)]);
+    assert_eq!(
+        fp.domains[0].members,
+        vec!["eng.acme.com", "ops.eu.acme.com"]
+    );
+    assert!((fp.domains[0].pct - 0.75).abs() < f32::EPSILON);
+    assert!(fp.domains[1].members.is_empty());
+}
+
+#[test]
+fn without_grouping_subdomains_stay_separate() {
+    let fp = fingerprint(
+        &["a@acme.com", "b@eng.acme.com"],
+        &DomainGrouping::default(),
+    );
+
+    assert_eq!(domains(&fp), vec![("acme.com", 1), ("eng.acme.com", 1)]);
+}
+
+#[test]
+fn parent_does_not_absorb_lookalike_domains() {
+    let grouping = DomainGrouping {
+        parents: vec!["acme.com".to_string()],
+        ..Default::default()
+    };
+    let fp = fingerprint(&["a@notacme.com", "b@acme.com.evil.io"], &grouping);
+
+    assert!(fp.domains.iter().all(|d| d.domain != "acme.com"));
+    assert_eq!(fp.domains.len(), 2);
+}
+
+#[test]
+fn most_specific_parent_wins() {
+    let grouping = DomainGrouping {
+        parents: vec!["acme.com".to_string(), "labs.acme.com".to_string()],
+        ..Default::default()
+    };
+    let fp = fingerprint(&["a@x.labs.acme.com", "b@eng.acme.com"], &grouping);
+
+    assert_eq!(domains(&fp), vec![("acme.com", 1), ("labs.acme.com", 1)]);
+}
+
+#[test]
+fn denylisted_personal_domain_is_excluded() {
+    let grouping = DomainGrouping {
+        deny: vec!["gmail.com".to_string()],
+        ..Default::default()
+    };
+    let fp = fingerprint(
+        &["a@gmail.com", "b@gmail.com", "c@acme.com", "d@yahoo.com"],
+        &grouping,
+    );
+
+    assert_eq!(domains(&fp), vec![("acme.com", 1), ("public-email", 1)]);
+    assert!((fp.domains[0].pct - 0.5).abs() < f32::EPSILON);
+}
+
+#[test]
+fn allowlisted_personal_domain_keeps_its_own_row() {
+    let grouping = DomainGrouping {
+        allow: vec!["proton.me".to_string()],
+        ..Default::default()
+    };
+    let fp = fingerprint(&["a@proton.me", "b@gmail.com"], &grouping);
+
+    assert_eq!(domains(&fp), vec![("proton.me", 1), ("public-email", 1)]);
+}
+
+#[test]
+fn config_entries_are_normalized() {
+    let grouping = DomainGrouping {
+        parents: vec![" @ACME.com ".to_string()],
+        deny: vec![".Gmail.COM".to_string()],
+        ..Default::default()
+    };
+    let fp = fingerprint(&["a@Eng.Acme.com", "b@gmail.com"], &grouping);
+
+    assert_eq!(domains(&fp), vec![("acme.com", 1)]);
+}
@@ -16,6 +16,7 @@ fn commit(author: &str) -> GitCommit {
         hash: None,
         subject: String::new(),
         files: vec![],
+        message_truncated: false,
     }
 }
 
@@ -26,6 +27,7 @@ fn full_commit(author: &str, hash: &str, subject: &str, files: &[&str]) -> GitCo
         hash: Some(hash.to_string()),
         subject: subject.to_string(),
         files: files.iter().map(|f| f.to_string()).collect(),
+        message_truncated: false,
     }
 }
 
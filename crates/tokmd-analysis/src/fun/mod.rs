@@ -175,6 +175,8 @@ mod unit_tests {
                 hash: "placeholder".to_string(),
                 entries: 0,
             },
+            oversized_files: vec![],
+            non_utf8_files: vec![],
         }
     }
 
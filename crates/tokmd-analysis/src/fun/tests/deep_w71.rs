@@ -136,6 +136,8 @@ fn derived_with_bytes(bytes: usize) -> DerivedReport {
             hash: "placeholder".into(),
             entries: 0,
         },
+        oversized_files: vec![],
+        non_utf8_files: vec![],
     }
 }
 
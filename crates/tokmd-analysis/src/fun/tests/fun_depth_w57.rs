@@ -140,6 +140,8 @@ fn derived_with(bytes: usize, files: usize, code: usize) -> DerivedReport {
             hash: "placeholder".to_string(),
             entries: 0,
         },
+        oversized_files: vec![],
+        non_utf8_files: vec![],
     }
 }
 
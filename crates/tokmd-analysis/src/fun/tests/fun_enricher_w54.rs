@@ -148,6 +148,8 @@ fn derived_with(
             hash: "placeholder".to_string(),
             entries: 0,
         },
+        oversized_files: vec![],
+        non_utf8_files: vec![],
     }
 }
 
@@ -135,6 +135,8 @@ fn derived_with_bytes(bytes: usize) -> DerivedReport {
             hash: "placeholder".to_string(),
             entries: 0,
         },
+        oversized_files: vec![],
+        non_utf8_files: vec![],
     }
 }
 
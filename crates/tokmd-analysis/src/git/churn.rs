@@ -1,6 +1,6 @@
 use std::collections::{BTreeMap, BTreeSet};
 
-use tokmd_analysis_types::{ChurnTrend, PredictiveChurnReport, TrendClass};
+use tokmd_analysis_types::{ChurnInterval, ChurnTrend, PredictiveChurnReport, TrendClass};
 use tokmd_types::{ExportData, FileKind, FileRow};
 
 use tokmd_analysis_types::normalize_path;
@@ -8,11 +8,25 @@ use tokmd_analysis_types::normalize_path;
 const SECONDS_PER_WEEK: i64 = 7 * 86_400;
 const RECENT_WEEKS: i64 = 4;
 const SLOPE_EPSILON: f64 = 0.01;
+/// Two-sided 95% normal quantile.
+const Z_95: f64 = 1.96;
 
+#[cfg(test)]
 pub(crate) fn build_predictive_churn_report(
     export: &ExportData,
     commits: &[tokmd_git::GitCommit],
     repo_root: &std::path::Path,
+) -> PredictiveChurnReport {
+    build_predictive_churn_report_with(export, commits, repo_root, None)
+}
+
+/// Fit a weekly commit-count trend per module and, with `forecast_days`,
+/// project it that many days past the newest commit.
+pub(crate) fn build_predictive_churn_report_with(
+    export: &ExportData,
+    commits: &[tokmd_git::GitCommit],
+    repo_root: &std::path::Path,
+    forecast_days: Option<u32>,
 ) -> PredictiveChurnReport {
     let mut row_map: BTreeMap<String, &FileRow> = BTreeMap::new();
     for row in export.rows.iter().filter(|r| r.kind == FileKind::Parent) {
@@ -34,12 +48,18 @@ pub(crate) fn build_predictive_churn_report(
             *entry.entry(week).or_insert(0) += 1;
         }
     }
+    let last_week = commits
+        .iter()
+        .map(|c| c.timestamp / SECONDS_PER_WEEK)
+        .max()
+        .unwrap_or(0);
 
     let mut per_module: BTreeMap<String, ChurnTrend> = BTreeMap::new();
     for (module, points) in series {
         let (slope, r2) = regression(&points);
         let recent_change = recent_delta(&points);
         let classification = classify_trend(slope);
+        let forecast = forecast_days.map(|days| project(&points, last_week, days));
         per_module.insert(
             module.to_string(),
             ChurnTrend {
@@ -47,17 +67,35 @@ pub(crate) fn build_predictive_churn_report(
                 r2,
                 recent_change,
                 classification,
+                projected_change: forecast.map(|(projected, _)| projected),
+                projected_interval: forecast.map(|(_, interval)| interval),
             },
         );
     }
 
-    PredictiveChurnReport { per_module }
+    PredictiveChurnReport {
+        per_module,
+        forecast_days,
+    }
 }
 
-fn regression(points: &BTreeMap<i64, i64>) -> (f64, f64) {
+/// Least-squares line through weekly commit counts.
+struct LineFit {
+    slope: f64,
+    intercept: f64,
+    r2: f64,
+    mean_x: f64,
+    /// Sum of squared deviations of the weeks from their mean.
+    sxx: f64,
+    /// Sum of squared residuals.
+    ss_res: f64,
+}
+
+/// `None` when there are fewer than two distinct weeks.
+fn fit_line(points: &BTreeMap<i64, i64>) -> Option<LineFit> {
     let n = points.len();
     if n < 2 {
-        return (0.0, 0.0);
+        return None;
     }
 
     let xs: Vec<f64> = points.keys().map(|v| *v as f64).collect();
@@ -76,8 +114,8 @@ fn regression(points: &BTreeMap<i64, i64>) -> (f64, f64) {
         var_y += dy * dy;
     }
 
-    if var_x == 0.0 || var_y == 0.0 {
-        return (0.0, 0.0);
+    if var_x == 0.0 {
+        return None;
     }
 
     let slope = cov / var_x;
@@ -89,8 +127,66 @@ fn regression(points: &BTreeMap<i64, i64>) -> (f64, f64) {
         let err = y - pred;
         ss_res += err * err;
     }
-    let r2 = (1.0 - ss_res / var_y).clamp(0.0, 1.0);
-    (slope, r2)
+    let r2 = if var_y == 0.0 {
+        0.0
+    } else {
+        (1.0 - ss_res / var_y).clamp(0.0, 1.0)
+    };
+    Some(LineFit {
+        slope,
+        intercept,
+        r2,
+        mean_x,
+        sxx: var_x,
+        ss_res,
+    })
+}
+
+fn regression(points: &BTreeMap<i64, i64>) -> (f64, f64) {
+    fit_line(points).map_or((0.0, 0.0), |fit| (fit.slope, fit.r2))
+}
+
+/// Commits expected in the `days` after `last_week`, with a band that is
+/// the wider of a ~95% prediction interval and ±(1 − r²) of the
+/// projection, so a poorly fitting trend never looks precise. Modules too
+/// sparse to fit project their mean weekly rate with a 0–2× band.
+fn project(points: &BTreeMap<i64, i64>, last_week: i64, days: u32) -> (f64, ChurnInterval) {
+    let weeks = f64::from(days) / 7.0;
+    let n = points.len();
+    let fit = fit_line(points).filter(|_| n > 2);
+    let Some(fit) = fit else {
+        let mean = points.values().sum::<i64>() as f64 / n.max(1) as f64;
+        let projected = mean * weeks;
+        return (
+            projected,
+            ChurnInterval {
+                low: 0.0,
+                high: 2.0 * projected,
+            },
+        );
+    };
+
+    // Rate at the middle of the horizon, times its length in weeks.
+    let x0 = last_week as f64 + weeks / 2.0;
+    let expected = (fit.intercept + fit.slope * x0) * weeks;
+    let residual_se = (fit.ss_res / (n - 2) as f64).sqrt();
+    let leverage = 1.0 + 1.0 / n as f64 + (x0 - fit.mean_x).powi(2) / fit.sxx;
+    let statistical = Z_95 * residual_se * leverage.sqrt() * weeks;
+    // A constant series has r² = 0 but fits exactly; only penalize misfit.
+    let fit_penalty = if fit.ss_res > 0.0 {
+        (1.0 - fit.r2) * expected.abs()
+    } else {
+        0.0
+    };
+    let half_width = statistical.max(fit_penalty);
+
+    (
+        expected.max(0.0),
+        ChurnInterval {
+            low: (expected - half_width).max(0.0),
+            high: (expected + half_width).max(0.0),
+        },
+    )
 }
 
 fn recent_delta(points: &BTreeMap<i64, i64>) -> i64 {
@@ -151,6 +247,9 @@ mod tests {
                 lines: 1,
                 bytes: 10,
                 tokens: 2,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             })
             .collect();
         ExportData {
@@ -171,6 +270,7 @@ mod tests {
                 hash: None,
                 subject: String::new(),
                 files: vec!["src/lib.rs".to_string()],
+                message_truncated: false,
             },
             tokmd_git::GitCommit {
                 timestamp: 2 * SECONDS_PER_WEEK,
@@ -178,6 +278,7 @@ mod tests {
                 hash: None,
                 subject: String::new(),
                 files: vec!["src/lib.rs".to_string()],
+                message_truncated: false,
             },
             tokmd_git::GitCommit {
                 timestamp: 3 * SECONDS_PER_WEEK,
@@ -185,10 +286,100 @@ mod tests {
                 hash: None,
                 subject: String::new(),
                 files: vec!["src/lib.rs".to_string()],
+                message_truncated: false,
             },
         ];
         let report = build_predictive_churn_report(&export, &commits, std::path::Path::new("."));
         let trend = report.per_module.get("core").unwrap();
         assert!(trend.slope >= 0.0);
     }
+
+    /// One commit per unit of `counts[i]` in week `i + 1`, each touching
+    /// `path`.
+    fn weekly_commits(path: &str, counts: &[usize]) -> Vec<tokmd_git::GitCommit> {
+        counts
+            .iter()
+            .enumerate()
+            .flat_map(|(i, &count)| {
+                (0..count).map(move |_| tokmd_git::GitCommit {
+                    timestamp: (i as i64 + 1) * SECONDS_PER_WEEK,
+                    author: "a@acme.com".to_string(),
+                    hash: None,
+                    subject: String::new(),
+                    files: vec![path.to_string()],
+                    message_truncated: false,
+                })
+            })
+            .collect()
+    }
+
+    fn forecast(counts: &[usize], days: u32) -> ChurnTrend {
+        let export = export_with_paths(&["src/lib.rs"]);
+        let commits = weekly_commits("src/lib.rs", counts);
+        let report = build_predictive_churn_report_with(
+            &export,
+            &commits,
+            std::path::Path::new("."),
+            Some(days),
+        );
+        assert_eq!(report.forecast_days, Some(days));
+        report.per_module["core"].clone()
+    }
+
+    #[test]
+    fn rising_trend_projects_more_than_flat() {
+        let rising = forecast(&[1, 2, 3, 4, 5, 6], 28);
+        let flat = forecast(&[3, 3, 3, 3, 3, 3], 28);
+
+        // Flat: 3 commits/week for 4 weeks, known exactly.
+        assert!((flat.projected_change.unwrap() - 12.0).abs() < 1e-9);
+        let band = flat.projected_interval.unwrap();
+        assert!((band.low - 12.0).abs() < 1e-9 && (band.high - 12.0).abs() < 1e-9);
+
+        let projected = rising.projected_change.unwrap();
+        assert!(projected > flat.projected_change.unwrap());
+        let band = rising.projected_interval.unwrap();
+        assert!(band.low <= projected && projected <= band.high);
+    }
+
+    #[test]
+    fn poor_fit_widens_the_interval() {
+        let clean = forecast(&[2, 3, 4, 5, 6, 7], 28);
+        let noisy = forecast(&[1, 8, 2, 9, 3, 10], 28);
+        assert!(noisy.r2 < clean.r2);
+
+        let width = |t: &ChurnTrend| {
+            let band = t.projected_interval.unwrap();
+            band.high - band.low
+        };
+        assert!(width(&noisy) > width(&clean));
+        // Relative to the projection, a low-r² band spans at least ±(1 − r²).
+        let projected = noisy.projected_change.unwrap();
+        assert!(width(&noisy) >= 2.0 * (1.0 - noisy.r2) * projected - 1e-9);
+    }
+
+    #[test]
+    fn sparse_history_reports_an_uncertain_band() {
+        let trend = forecast(&[4], 14);
+
+        assert!((trend.projected_change.unwrap() - 8.0).abs() < 1e-9);
+        assert_eq!(
+            trend.projected_interval,
+            Some(ChurnInterval {
+                low: 0.0,
+                high: 16.0
+            })
+        );
+    }
+
+    #[test]
+    fn no_forecast_without_horizon() {
+        let export = export_with_paths(&["src/lib.rs"]);
+        let commits = weekly_commits("src/lib.rs", &[1, 2, 3]);
+        let report = build_predictive_churn_report(&export, &commits, std::path::Path::new("."));
+
+        assert_eq!(report.forecast_days, None);
+        assert!(report.per_module["core"].projected_change.is_none());
+        assert!(report.per_module["core"].projected_interval.is_none());
+    }
 }
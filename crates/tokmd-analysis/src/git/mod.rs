@@ -4,7 +4,7 @@ use std::path::Path;
 use anyhow::Result;
 use tokmd_analysis_types::{
     BusFactorRow, CommitIntentCounts, CommitIntentReport, CouplingRow, GitReport, HotspotRow,
-    ModuleIntentRow,
+    ModuleIntentRow, RecentCommitIntent,
 };
 use tokmd_types::{ExportData, FileKind, FileRow};
 
@@ -14,7 +14,12 @@ use tokmd_scan::round_f64;
 mod churn;
 mod freshness;
 
+/// Commits kept in `intent.recent`; enough for a readable Mermaid gitGraph.
+const RECENT_INTENT_COMMITS: usize = 30;
+
+#[cfg(test)]
 pub(crate) use churn::build_predictive_churn_report;
+pub(crate) use churn::build_predictive_churn_report_with;
 use freshness::{build_code_age_distribution, build_freshness_report};
 
 pub(crate) fn build_git_report(
@@ -163,7 +168,12 @@ fn build_coupling(
             }
         })
         .collect();
-    rows.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.left.cmp(&b.left)));
+    rows.sort_by(|a, b| {
+        b.count
+            .cmp(&a.count)
+            .then_with(|| a.left.cmp(&b.left))
+            .then_with(|| a.right.cmp(&b.right))
+    });
     rows
 }
 
@@ -173,10 +183,22 @@ fn build_intent_report(
 ) -> CommitIntentReport {
     let mut overall = CommitIntentCounts::default();
     let mut by_module_counts: BTreeMap<&str, CommitIntentCounts> = BTreeMap::new();
+    let mut recent = Vec::new();
+    let mut truncated_messages = 0;
 
     for commit in commits {
         let kind = tokmd_git::classify_intent(&commit.subject);
+        truncated_messages += usize::from(commit.message_truncated);
         overall.increment(kind);
+        // `git log` lists newest first.
+        if recent.len() < RECENT_INTENT_COMMITS {
+            recent.push(RecentCommitIntent {
+                hash: commit.hash.clone(),
+                timestamp: commit.timestamp,
+                subject: commit.subject.clone(),
+                intent: kind,
+            });
+        }
 
         // Attribute intent to all modules touched by this commit
         let mut modules: BTreeSet<&str> = BTreeSet::new();
@@ -220,6 +242,8 @@ fn build_intent_report(
         by_module,
         unknown_pct,
         corrective_ratio,
+        recent,
+        truncated_messages: (truncated_messages > 0).then_some(truncated_messages),
     }
 }
 
@@ -29,6 +29,9 @@ fn file_row(path: &str, module: &str, lines: usize) -> FileRow {
         lines,
         bytes: lines * 40,
         tokens: lines * 3,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
@@ -48,6 +51,7 @@ fn commit(ts: i64, author: &str, subject: &str, files: &[&str]) -> GitCommit {
         hash: None,
         subject: subject.to_string(),
         files: files.iter().map(|s| s.to_string()).collect(),
+        message_truncated: false,
     }
 }
 
@@ -312,6 +316,53 @@ fn scenario_intent_report_conventional_commits() {
     assert_eq!(intent.overall.total, 4);
 }
 
+// ===========================================================================
+// Scenario: Recent commits keep their intent, newest first, bounded
+// ===========================================================================
+#[test]
+fn scenario_intent_recent_commits_are_tagged_and_bounded() {
+    // Given: more commits than the recent window, newest first like `git log`
+    let exp = export(vec![file_row("src/lib.rs", "src", 100)]);
+    let mut commits = vec![commit(9000, "alice", "feat: add parser", &["src/lib.rs"])];
+    commits.extend((0..40).map(|i| commit(8000 - i, "bob", "chore: tidy", &["src/lib.rs"])));
+
+    // When
+    let report = build_git_report(Path::new("."), &exp, &commits).unwrap();
+
+    // Then: the newest commit leads the bounded list with its intent
+    let recent = &report.intent.as_ref().unwrap().recent;
+    assert_eq!(recent.len(), 30);
+    assert_eq!(recent[0].subject, "feat: add parser");
+    assert_eq!(
+        recent[0].intent,
+        tokmd_analysis_types::CommitIntentKind::Feat
+    );
+    assert_eq!(recent[0].timestamp, 9000);
+}
+
+// ===========================================================================
+// Scenario: Truncated commit messages are counted and still classified
+// ===========================================================================
+#[test]
+fn scenario_intent_counts_truncated_messages() {
+    // Given: one commit whose message was cut by the history walk
+    let exp = export(vec![file_row("src/lib.rs", "src", 100)]);
+    let mut long = commit(2000, "bob", "fix: guard the parser and", &["src/lib.rs"]);
+    long.message_truncated = true;
+    let commits = vec![long, commit(1000, "alice", "feat: init", &["src/lib.rs"])];
+
+    // When
+    let report = build_git_report(Path::new("."), &exp, &commits).unwrap();
+
+    // Then: the cut message keeps its subject intent and is recorded
+    let intent = report.intent.as_ref().unwrap();
+    assert_eq!(intent.overall.fix, 1);
+    assert_eq!(intent.truncated_messages, Some(1));
+
+    let untouched = build_git_report(Path::new("."), &exp, &commits[1..]).unwrap();
+    assert_eq!(untouched.intent.unwrap().truncated_messages, None);
+}
+
 // ===========================================================================
 // Scenario: Corrective ratio reflects fix and revert commits
 // ===========================================================================
@@ -427,6 +478,9 @@ fn scenario_child_file_kind_excluded() {
         lines: 5,
         bytes: 50,
         tokens: 10,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     });
     let exp = export(rows);
     let commits = vec![commit(1000, "alice", "feat: init", &["src/lib.rs"])];
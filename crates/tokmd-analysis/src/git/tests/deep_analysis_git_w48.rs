@@ -29,6 +29,9 @@ fn file_row(path: &str, module: &str, lines: usize) -> FileRow {
         lines,
         bytes: lines * 40,
         tokens: lines * 3,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
@@ -44,6 +47,9 @@ fn child_row(path: &str, module: &str, lines: usize) -> FileRow {
         lines,
         bytes: lines * 40,
         tokens: lines * 3,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
@@ -63,6 +69,7 @@ fn commit(ts: i64, author: &str, subject: &str, files: &[&str]) -> GitCommit {
         hash: None,
         subject: subject.to_string(),
         files: files.iter().map(|s| s.to_string()).collect(),
+        message_truncated: false,
     }
 }
 
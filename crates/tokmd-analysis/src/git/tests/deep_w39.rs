@@ -20,6 +20,9 @@ fn make_row(path: &str, module: &str, lines: usize) -> FileRow {
         lines,
         bytes: lines * 10,
         tokens: lines * 5,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
@@ -39,6 +42,7 @@ fn make_commit(ts: i64, author: &str, subject: &str, files: &[&str]) -> GitCommi
         hash: None,
         subject: subject.to_string(),
         files: files.iter().map(|s| s.to_string()).collect(),
+        message_truncated: false,
     }
 }
 
@@ -379,6 +383,9 @@ fn churn_child_rows_excluded() {
         lines: 50,
         bytes: 500,
         tokens: 250,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     });
     let commits = vec![make_commit(week, "a", "c", &["src/lib.rs"])];
     let report = build_predictive_churn_report(&export, &commits, Path::new("."));
@@ -23,6 +23,9 @@ fn make_row(path: &str, module: &str, lines: usize) -> FileRow {
         lines,
         bytes: lines * 10,
         tokens: lines * 5,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
@@ -42,6 +45,7 @@ fn make_commit(ts: i64, author: &str, subject: &str, files: &[&str]) -> GitCommi
         hash: None,
         subject: subject.to_string(),
         files: files.iter().map(|s| s.to_string()).collect(),
+        message_truncated: false,
     }
 }
 
@@ -437,6 +441,9 @@ fn churn_report_child_rows_excluded() {
         lines: 100,
         bytes: 1000,
         tokens: 500,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }];
     let export = make_export(rows);
     let commits = vec![make_commit(SECONDS_PER_WEEK, "a@x.com", "c", &["f.rs"])];
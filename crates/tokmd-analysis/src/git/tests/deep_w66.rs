@@ -25,6 +25,9 @@ fn make_row(path: &str, module: &str, lines: usize) -> FileRow {
         lines,
         bytes: lines * 10,
         tokens: lines * 5,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
@@ -44,6 +47,7 @@ fn make_commit(ts: i64, author: &str, subject: &str, files: &[&str]) -> GitCommi
         hash: None,
         subject: subject.to_string(),
         files: files.iter().map(|s| s.to_string()).collect(),
+        message_truncated: false,
     }
 }
 
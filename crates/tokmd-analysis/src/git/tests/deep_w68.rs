@@ -28,6 +28,9 @@ fn row(path: &str, module: &str, lines: usize) -> FileRow {
         lines,
         bytes: lines * 10,
         tokens: lines * 5,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
@@ -47,6 +50,7 @@ fn commit(ts: i64, author: &str, subject: &str, files: &[&str]) -> GitCommit {
         hash: None,
         subject: subject.to_string(),
         files: files.iter().map(|s| s.to_string()).collect(),
+        message_truncated: false,
     }
 }
 
@@ -387,6 +391,9 @@ mod edge_cases_w68 {
             lines: 50,
             bytes: 500,
             tokens: 100,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         });
         let commits = vec![commit(DAY, "a", "c", &["src/lib.rs", "src/embedded.html"])];
         let r = build_git_report(Path::new("."), &e, &commits).unwrap();
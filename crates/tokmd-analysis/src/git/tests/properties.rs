@@ -35,6 +35,9 @@ fn arb_file_row() -> impl Strategy<Value = FileRow> {
             lines,
             bytes: lines * 40,
             tokens: lines * 3,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         })
 }
 
@@ -76,6 +79,7 @@ fn arb_commit(paths: Vec<String>) -> impl Strategy<Value = GitCommit> {
                 hash: None,
                 subject,
                 files,
+                message_truncated: false,
             }
         })
 }
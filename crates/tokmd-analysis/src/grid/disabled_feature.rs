@@ -1,5 +1,7 @@
 //! Disabled-feature warning catalog for analysis presets.
 
+use tokmd_types::{FEATURE_DISABLED, Warning, WarningSeverity};
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum DisabledFeature {
     FileInventory,
@@ -7,6 +9,7 @@ pub enum DisabledFeature {
     DuplicationScan,
     NearDuplicateScan,
     ImportScan,
+    LineEndingScan,
     GitMetrics,
     EntropyProfiling,
     LicenseRadar,
@@ -25,6 +28,7 @@ impl DisabledFeature {
             Self::DuplicationScan => "content feature disabled; skipping duplication scan",
             Self::NearDuplicateScan => "content feature disabled; skipping near-dup scan",
             Self::ImportScan => "content feature disabled; skipping import scan",
+            Self::LineEndingScan => "content feature disabled; skipping line-ending scan",
             Self::GitMetrics => "git feature disabled; skipping git metrics",
             Self::EntropyProfiling => "content/walk feature disabled; skipping entropy profiling",
             Self::LicenseRadar => "content/walk feature disabled; skipping license radar",
@@ -45,6 +49,12 @@ impl DisabledFeature {
             }
         }
     }
+
+    /// The receipt warning for this feature: [`FEATURE_DISABLED`], at info
+    /// severity since the build, not the input, left the section out.
+    pub fn to_warning(self) -> Warning {
+        Warning::new(FEATURE_DISABLED, WarningSeverity::Info, self.warning())
+    }
 }
 
 #[cfg(test)]
@@ -58,6 +68,7 @@ mod unit_tests {
         assert!(!DisabledFeature::DuplicationScan.warning().is_empty());
         assert!(!DisabledFeature::NearDuplicateScan.warning().is_empty());
         assert!(!DisabledFeature::ImportScan.warning().is_empty());
+        assert!(!DisabledFeature::LineEndingScan.warning().is_empty());
         assert!(!DisabledFeature::GitMetrics.warning().is_empty());
         assert!(!DisabledFeature::EntropyProfiling.warning().is_empty());
         assert!(!DisabledFeature::LicenseRadar.warning().is_empty());
@@ -67,4 +78,12 @@ mod unit_tests {
         assert!(!DisabledFeature::Topics.warning().is_empty());
         assert!(!DisabledFeature::Fun.warning().is_empty());
     }
+
+    #[test]
+    fn disabled_feature_warning_is_info_with_stable_code() {
+        let warning = DisabledFeature::GitMetrics.to_warning();
+        assert_eq!(warning.code, "feature_disabled");
+        assert_eq!(warning.severity, WarningSeverity::Info);
+        assert_eq!(warning.message, DisabledFeature::GitMetrics.warning());
+    }
 }
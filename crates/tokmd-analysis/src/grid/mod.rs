@@ -7,7 +7,8 @@ pub use disabled_feature::DisabledFeature;
 #[cfg(test)]
 pub use presets::PresetGridRow;
 pub use presets::{
-    PRESET_GRID, PRESET_KINDS, PresetKind, PresetPlan, preset_plan_for, preset_plan_for_name,
+    PRESET_GRID, PRESET_KINDS, PRESET_SECTIONS, PresetKind, PresetPlan, preset_plan_for,
+    preset_plan_for_name,
 };
 
 #[cfg(test)]
@@ -82,7 +82,7 @@ impl PresetKind {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 pub struct PresetPlan {
     pub assets: bool,
     pub deps: bool,
@@ -97,6 +97,7 @@ pub struct PresetPlan {
     pub license: bool,
     pub complexity: bool,
     pub api_surface: bool,
+    pub line_endings: bool,
     #[cfg(all(feature = "halstead", feature = "content", feature = "walk"))]
     pub halstead: bool,
     #[cfg(feature = "git")]
@@ -119,13 +120,153 @@ impl PresetPlan {
             || self.entropy
             || self.license
             || self.complexity
-            || self.api_surface;
+            || self.api_surface
+            || self.line_endings;
         #[cfg(all(feature = "halstead", feature = "content", feature = "walk"))]
         {
             needs = needs || self.halstead;
         }
         needs
     }
+
+    /// Build a plan that enables exactly the named sections.
+    ///
+    /// Names come from [`PRESET_SECTIONS`]. Sections compiled out of this
+    /// build are accepted and stay off, so one config works across builds;
+    /// unknown names are an error.
+    pub fn from_sections<S: AsRef<str>>(sections: &[S]) -> Result<Self, String> {
+        let mut plan = Self::default();
+        for section in sections {
+            let section = section.as_ref();
+            if !plan.set_section(section, true) {
+                return Err(unknown_section(section));
+            }
+        }
+        Ok(plan)
+    }
+
+    /// Narrow the plan to the sections it shares with a non-empty `include`,
+    /// then turn off every section in `exclude`.
+    pub fn filtered<S: AsRef<str>>(self, include: &[S], exclude: &[S]) -> Result<Self, String> {
+        let mut plan = self;
+        if !include.is_empty() {
+            let include = Self::from_sections(include)?;
+            let kept: Vec<&str> = self
+                .sections()
+                .into_iter()
+                .filter(|section| include.section(section))
+                .collect();
+            plan = Self::from_sections(&kept)?;
+        }
+        for section in exclude {
+            let section = section.as_ref();
+            if !plan.set_section(section, false) {
+                return Err(unknown_section(section));
+            }
+        }
+        Ok(plan)
+    }
+
+    /// Whether any enabled section reads git history.
+    #[cfg_attr(not(feature = "git"), allow(unused_mut))]
+    pub fn needs_history(&self) -> bool {
+        let mut needs = self.git;
+        #[cfg(feature = "git")]
+        {
+            needs = needs || self.churn || self.fingerprint;
+        }
+        needs
+    }
+
+    /// The enabled sections, in [`PRESET_SECTIONS`] order.
+    pub fn sections(&self) -> Vec<&'static str> {
+        PRESET_SECTIONS
+            .into_iter()
+            .filter(|section| self.section(section))
+            .collect()
+    }
+
+    /// Turn `section` on or off; `false` if the name is unknown.
+    fn set_section(&mut self, section: &str, on: bool) -> bool {
+        match section {
+            "assets" => self.assets = on,
+            "deps" => self.deps = on,
+            "todo" => self.todo = on,
+            "dup" => self.dup = on,
+            "imports" => self.imports = on,
+            "git" => self.git = on,
+            "fun" => self.fun = on,
+            "archetype" => self.archetype = on,
+            "topics" => self.topics = on,
+            "entropy" => self.entropy = on,
+            "license" => self.license = on,
+            "complexity" => self.complexity = on,
+            "api_surface" => self.api_surface = on,
+            "line_endings" => self.line_endings = on,
+            #[cfg(all(feature = "halstead", feature = "content", feature = "walk"))]
+            "halstead" => self.halstead = on,
+            #[cfg(feature = "git")]
+            "churn" => self.churn = on,
+            #[cfg(feature = "git")]
+            "fingerprint" => self.fingerprint = on,
+            other => return PRESET_SECTIONS.contains(&other),
+        }
+        true
+    }
+
+    fn section(&self, section: &str) -> bool {
+        match section {
+            "assets" => self.assets,
+            "deps" => self.deps,
+            "todo" => self.todo,
+            "dup" => self.dup,
+            "imports" => self.imports,
+            "git" => self.git,
+            "fun" => self.fun,
+            "archetype" => self.archetype,
+            "topics" => self.topics,
+            "entropy" => self.entropy,
+            "license" => self.license,
+            "complexity" => self.complexity,
+            "api_surface" => self.api_surface,
+            "line_endings" => self.line_endings,
+            #[cfg(all(feature = "halstead", feature = "content", feature = "walk"))]
+            "halstead" => self.halstead,
+            #[cfg(feature = "git")]
+            "churn" => self.churn,
+            #[cfg(feature = "git")]
+            "fingerprint" => self.fingerprint,
+            _ => false,
+        }
+    }
+}
+
+/// Section names a custom preset can enable, one per [`PresetPlan`] flag.
+pub const PRESET_SECTIONS: [&str; 17] = [
+    "assets",
+    "deps",
+    "todo",
+    "dup",
+    "imports",
+    "git",
+    "fun",
+    "archetype",
+    "topics",
+    "entropy",
+    "license",
+    "complexity",
+    "api_surface",
+    "line_endings",
+    "halstead",
+    "churn",
+    "fingerprint",
+];
+
+fn unknown_section(section: &str) -> String {
+    format!(
+        "unknown analysis section '{section}' (expected one of: {})",
+        PRESET_SECTIONS.join(", ")
+    )
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -151,6 +292,7 @@ pub const PRESET_GRID: [PresetGridRow; 13] = [
             license: false,
             complexity: true,
             api_surface: true,
+            line_endings: false,
             #[cfg(all(feature = "halstead", feature = "content", feature = "walk"))]
             halstead: false,
             #[cfg(feature = "git")]
@@ -175,6 +317,7 @@ pub const PRESET_GRID: [PresetGridRow; 13] = [
             license: false,
             complexity: true,
             api_surface: true,
+            line_endings: false,
             #[cfg(all(feature = "halstead", feature = "content", feature = "walk"))]
             halstead: false,
             #[cfg(feature = "git")]
@@ -199,6 +342,7 @@ pub const PRESET_GRID: [PresetGridRow; 13] = [
             license: false,
             complexity: true,
             api_surface: true,
+            line_endings: false,
             #[cfg(all(feature = "halstead", feature = "content", feature = "walk"))]
             halstead: false,
             #[cfg(feature = "git")]
@@ -223,6 +367,7 @@ pub const PRESET_GRID: [PresetGridRow; 13] = [
             license: false,
             complexity: true,
             api_surface: false,
+            line_endings: true,
             #[cfg(all(feature = "halstead", feature = "content", feature = "walk"))]
             halstead: true,
             #[cfg(feature = "git")]
@@ -247,6 +392,7 @@ pub const PRESET_GRID: [PresetGridRow; 13] = [
             license: false,
             complexity: true,
             api_surface: false,
+            line_endings: false,
             #[cfg(all(feature = "halstead", feature = "content", feature = "walk"))]
             halstead: true,
             #[cfg(feature = "git")]
@@ -271,6 +417,7 @@ pub const PRESET_GRID: [PresetGridRow; 13] = [
             license: false,
             complexity: false,
             api_surface: false,
+            line_endings: false,
             #[cfg(all(feature = "halstead", feature = "content", feature = "walk"))]
             halstead: false,
             #[cfg(feature = "git")]
@@ -295,6 +442,7 @@ pub const PRESET_GRID: [PresetGridRow; 13] = [
             license: false,
             complexity: false,
             api_surface: true,
+            line_endings: false,
             #[cfg(all(feature = "halstead", feature = "content", feature = "walk"))]
             halstead: false,
             #[cfg(feature = "git")]
@@ -319,6 +467,7 @@ pub const PRESET_GRID: [PresetGridRow; 13] = [
             license: false,
             complexity: false,
             api_surface: false,
+            line_endings: false,
             #[cfg(all(feature = "halstead", feature = "content", feature = "walk"))]
             halstead: false,
             #[cfg(feature = "git")]
@@ -343,6 +492,7 @@ pub const PRESET_GRID: [PresetGridRow; 13] = [
             license: true,
             complexity: false,
             api_surface: false,
+            line_endings: false,
             #[cfg(all(feature = "halstead", feature = "content", feature = "walk"))]
             halstead: false,
             #[cfg(feature = "git")]
@@ -367,6 +517,7 @@ pub const PRESET_GRID: [PresetGridRow; 13] = [
             license: false,
             complexity: false,
             api_surface: false,
+            line_endings: false,
             #[cfg(all(feature = "halstead", feature = "content", feature = "walk"))]
             halstead: false,
             #[cfg(feature = "git")]
@@ -391,6 +542,7 @@ pub const PRESET_GRID: [PresetGridRow; 13] = [
             license: false,
             complexity: false,
             api_surface: false,
+            line_endings: false,
             #[cfg(all(feature = "halstead", feature = "content", feature = "walk"))]
             halstead: false,
             #[cfg(feature = "git")]
@@ -415,6 +567,7 @@ pub const PRESET_GRID: [PresetGridRow; 13] = [
             license: true,
             complexity: true,
             api_surface: true,
+            line_endings: true,
             #[cfg(all(feature = "halstead", feature = "content", feature = "walk"))]
             halstead: true,
             #[cfg(feature = "git")]
@@ -439,6 +592,7 @@ pub const PRESET_GRID: [PresetGridRow; 13] = [
             license: false,
             complexity: false,
             api_surface: false,
+            line_endings: false,
             #[cfg(all(feature = "halstead", feature = "content", feature = "walk"))]
             halstead: false,
             #[cfg(feature = "git")]
@@ -487,4 +641,47 @@ mod unit_tests {
             );
         }
     }
+
+    #[test]
+    fn sections_roundtrip_through_plan() {
+        for preset in PresetKind::all() {
+            let plan = preset_plan_for(*preset);
+            assert_eq!(PresetPlan::from_sections(&plan.sections()), Ok(plan));
+        }
+    }
+
+    #[test]
+    fn from_sections_enables_exactly_the_named_sections() {
+        let plan = PresetPlan::from_sections(&["entropy", "license", "deps"]).unwrap();
+        assert_eq!(plan.sections(), vec!["deps", "entropy", "license"]);
+        assert!(!plan.git);
+        assert!(!plan.complexity);
+        assert_eq!(
+            PresetPlan::from_sections::<&str>(&[]),
+            Ok(PresetPlan::default())
+        );
+    }
+
+    #[test]
+    fn filtered_narrows_then_excludes() {
+        let deep = preset_plan_for(PresetKind::Deep);
+        let plan = deep.filtered(&["dup", "git", "topics"], &["git"]).unwrap();
+        assert_eq!(plan.sections(), vec!["dup", "topics"]);
+        // Include narrows; it never adds a section the preset lacks.
+        let receipt = preset_plan_for(PresetKind::Receipt);
+        let plan = receipt.filtered(&["dup", "topics"], &[]).unwrap();
+        assert_eq!(plan.sections(), vec!["dup"]);
+        let plan = receipt.filtered(&[], &["dup"]).unwrap();
+        assert!(!plan.dup);
+        assert!(plan.complexity);
+        assert!(receipt.filtered(&[], &["secrets"]).is_err());
+        assert!(receipt.filtered(&["secrets"], &[]).is_err());
+    }
+
+    #[test]
+    fn from_sections_rejects_unknown_names() {
+        let err = PresetPlan::from_sections(&["entropy", "secrets"]).unwrap_err();
+        assert!(err.contains("'secrets'"), "{err}");
+        assert!(err.contains("api_surface"), "{err}");
+    }
 }
@@ -660,7 +660,8 @@ mod needs_files_deep {
                 || plan.entropy
                 || plan.license
                 || plan.complexity
-                || plan.api_surface;
+                || plan.api_surface
+                || plan.line_endings;
             assert_eq!(
                 plan.needs_files(),
                 expected,
@@ -54,6 +54,14 @@ fn preset_plan_for_name_rejects_invalid_inputs() {
 
 // ── Enricher inclusion per preset ───────────────────────────────────────────
 
+#[test]
+fn line_endings_run_only_for_health_and_deep() {
+    for row in &PRESET_GRID {
+        let expected = matches!(row.preset, PresetKind::Health | PresetKind::Deep);
+        assert_eq!(row.plan.line_endings, expected, "{:?}", row.preset);
+    }
+}
+
 #[test]
 fn health_enables_todo_complexity_only() {
     let plan = preset_plan_for(PresetKind::Health);
@@ -182,7 +190,8 @@ fn needs_files_true_iff_any_file_flag_set() {
             || plan.entropy
             || plan.license
             || plan.complexity
-            || plan.api_surface;
+            || plan.api_surface
+            || plan.line_endings;
         assert_eq!(
             plan.needs_files(),
             any_file_flag,
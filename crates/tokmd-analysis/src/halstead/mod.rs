@@ -44,7 +44,7 @@ pub(crate) fn build_halstead_report(
     let per_file_limit = limits.max_file_bytes.unwrap_or(DEFAULT_MAX_FILE_BYTES) as usize;
 
     for rel in files {
-        if max_total.is_some_and(|limit| total_bytes >= limit) {
+        if max_total.is_some_and(|limit| total_bytes >= limit) || limits.out_of_time() {
             break;
         }
         let rel_str = rel.to_string_lossy().replace('\\', "/");
@@ -62,6 +62,7 @@ pub(crate) fn build_halstead_report(
             Err(_) => continue,
         };
         total_bytes += bytes.len() as u64;
+        let bytes = crate::content::io::prepare_text(bytes, limits.strip_bom);
 
         if !crate::content::io::is_text_like(&bytes) {
             continue;
@@ -20,6 +20,11 @@ fn no_limits() -> AnalysisLimits {
         max_file_bytes: None,
         max_commits: None,
         max_commit_files: None,
+        oversized_file_bytes: None,
+        max_commit_message_bytes: None,
+        strip_bom: false,
+        sample_rate: None,
+        runtime_budget: None,
     }
 }
 
@@ -35,6 +40,9 @@ fn make_row(path: &str, lang: &str) -> FileRow {
         lines: 10,
         bytes: 100,
         tokens: 50,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
@@ -17,6 +17,11 @@ fn no_limits() -> AnalysisLimits {
         max_file_bytes: None,
         max_commits: None,
         max_commit_files: None,
+        oversized_file_bytes: None,
+        max_commit_message_bytes: None,
+        strip_bom: false,
+        sample_rate: None,
+        runtime_budget: None,
     }
 }
 
@@ -32,6 +37,9 @@ fn make_row(path: &str, lang: &str) -> FileRow {
         lines: 10,
         bytes: 100,
         tokens: 50,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
@@ -333,3 +341,32 @@ fn c_family_shares_operator_table() {
     assert_eq!(c_ops, csharp_ops);
     assert_eq!(c_ops, php_ops);
 }
+
+// ── 9. Legacy encodings ──────────────────────────────────────────────
+
+#[cfg(feature = "encoding")]
+#[test]
+fn latin1_file_counts_like_its_utf8_twin() {
+    let dir = tempfile::tempdir().unwrap();
+    let utf8 = "caf\u{e9} = 'cr\u{e8}me'\nprint(caf\u{e9} + '\u{e0} la carte')\n";
+    let latin1: Vec<u8> = utf8.chars().map(|c| c as u8).collect();
+    std::fs::write(dir.path().join("utf8.py"), utf8).unwrap();
+    std::fs::write(dir.path().join("latin1.py"), &latin1).unwrap();
+
+    let report = |path: &str| {
+        let export = make_export(vec![make_row(path, "Python")]);
+        let files = vec![PathBuf::from(path)];
+        build_halstead_report(dir.path(), &files, &export, &no_limits()).unwrap()
+    };
+    let (twin, legacy) = (report("utf8.py"), report("latin1.py"));
+
+    assert!(twin.length > 0);
+    assert_eq!(
+        (legacy.total_operators, legacy.total_operands),
+        (twin.total_operators, twin.total_operands)
+    );
+    assert_eq!(
+        (legacy.distinct_operators, legacy.distinct_operands),
+        (twin.distinct_operators, twin.distinct_operands)
+    );
+}
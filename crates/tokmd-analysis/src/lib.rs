@@ -48,25 +48,33 @@ mod halstead;
 mod imports;
 #[cfg(all(feature = "content", feature = "walk"))]
 mod license;
+#[cfg(feature = "content")]
+mod line_endings;
 #[cfg(all(feature = "content", feature = "walk"))]
 mod maintainability;
 #[cfg(feature = "content")]
 mod near_dup;
+mod risk_surface;
 pub mod source_complexity;
 #[cfg(feature = "topics")]
 mod topics;
 mod util;
 
-pub use analysis::{AnalysisContext, AnalysisPreset, AnalysisRequest, ImportGranularity, analyze};
-pub use derived::{build_tree, derive_report};
+pub use analysis::{
+    AnalysisContext, AnalysisExtension, AnalysisPreset, AnalysisProgress, AnalysisRequest,
+    ExtensionHook, ImportGranularity, ProgressHook, analyze,
+};
+pub use derived::{build_tree, derive_report, derive_report_with_algo, verify_integrity};
 #[cfg(feature = "effort")]
 pub use effort::{EffortLayer, EffortModelKind, EffortRequest};
 pub use grid::{
-    DisabledFeature, PRESET_GRID, PRESET_KINDS, PresetKind, PresetPlan, preset_plan_for,
-    preset_plan_for_name,
+    DisabledFeature, PRESET_GRID, PRESET_KINDS, PRESET_SECTIONS, PresetKind, PresetPlan,
+    preset_plan_for, preset_plan_for_name,
 };
-pub use tokmd_analysis_types::AnalysisLimits;
+pub use tokmd_analysis_types::ApiDocThresholds;
 pub use tokmd_analysis_types::NearDupScope;
+pub use tokmd_analysis_types::{AnalysisLimits, RuntimeBudget};
+pub use tokmd_format::{AnalysisTreeOptions, IntegrityMismatch};
 pub use util::normalize_root;
 
 #[cfg(doctest)]
@@ -0,0 +1,216 @@
+//! Per-file license header compliance.
+//!
+//! Checks the head of every source file for an expected header snippet (an
+//! SPDX line or a multi-line notice). Comment markers differ between
+//! languages, so the snippet matches when each of its non-blank lines,
+//! trimmed, appears somewhere in the file head.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use tokmd_analysis_types::{AnalysisLimits, LicenseHeaderCompliance, normalize_path};
+use tokmd_types::{ExportData, FileKind, FileRow};
+
+/// Headers are expected near the top; later mentions do not count.
+const HEADER_SCAN_LINES: usize = 50;
+const HEADER_SCAN_BYTES: usize = 16 * 1024;
+
+/// Languages without comment syntax, which cannot carry a header.
+const EXEMPT_LANGS: &[&str] = &[
+    "json",
+    "markdown",
+    "plain text",
+    "text",
+    "restructuredtext",
+    "asciidoc",
+    "svg",
+];
+
+pub(crate) fn build_header_compliance(
+    root: &Path,
+    files: &[PathBuf],
+    export: &ExportData,
+    expected: &str,
+    limits: &AnalysisLimits,
+) -> LicenseHeaderCompliance {
+    let rows: BTreeMap<String, &FileRow> = export
+        .rows
+        .iter()
+        .filter(|r| r.kind == FileKind::Parent)
+        .map(|r| (normalize_path(&r.path, root), r))
+        .collect();
+    let needles: Vec<&str> = expected
+        .lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty())
+        .collect();
+    let max_bytes = limits
+        .max_file_bytes
+        .map_or(HEADER_SCAN_BYTES, |b| (b as usize).min(HEADER_SCAN_BYTES));
+
+    let mut checked = 0usize;
+    let mut missing = Vec::new();
+    for rel in files {
+        let rel_str = rel.to_string_lossy().replace('\\', "/");
+        let Some(row) = rows.get(&rel_str) else {
+            continue;
+        };
+        if EXEMPT_LANGS.contains(&row.lang.to_lowercase().as_str()) {
+            continue;
+        }
+        let Ok(head) =
+            crate::content::io::read_lines(&root.join(rel), HEADER_SCAN_LINES, max_bytes)
+        else {
+            continue;
+        };
+        checked += 1;
+        if !has_header(&head, &needles) {
+            missing.push(rel_str);
+        }
+    }
+    missing.sort();
+
+    let compliant = checked - missing.len();
+    let ratio = if checked == 0 {
+        1.0
+    } else {
+        compliant as f64 / checked as f64
+    };
+    LicenseHeaderCompliance {
+        expected: expected.to_string(),
+        checked,
+        compliant,
+        ratio,
+        missing,
+    }
+}
+
+fn has_header(head: &[String], needles: &[&str]) -> bool {
+    needles
+        .iter()
+        .all(|needle| head.iter().any(|line| line.contains(needle)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::tempdir;
+    use tokmd_types::ChildIncludeMode;
+
+    fn row(path: &str, lang: &str) -> FileRow {
+        FileRow {
+            path: path.to_string(),
+            module: "(root)".to_string(),
+            lang: lang.to_string(),
+            kind: FileKind::Parent,
+            code: 1,
+            comments: 1,
+            blanks: 0,
+            lines: 2,
+            bytes: 10,
+            tokens: 3,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
+        }
+    }
+
+    fn check(files: &[(&str, &str, &str)], expected: &str) -> LicenseHeaderCompliance {
+        let dir = tempdir().unwrap();
+        let mut rows = Vec::new();
+        let mut paths = Vec::new();
+        for (path, lang, content) in files {
+            fs::write(dir.path().join(path), content).unwrap();
+            rows.push(row(path, lang));
+            paths.push(PathBuf::from(path));
+        }
+        let export = ExportData {
+            rows,
+            module_roots: vec![],
+            module_depth: 1,
+            children: ChildIncludeMode::Separate,
+        };
+        build_header_compliance(
+            dir.path(),
+            &paths,
+            &export,
+            expected,
+            &AnalysisLimits::default(),
+        )
+    }
+
+    #[test]
+    fn lists_files_missing_the_spdx_line() {
+        let report = check(
+            &[
+                (
+                    "a.rs",
+                    "Rust",
+                    "// SPDX-License-Identifier: MIT\nfn a() {}\n",
+                ),
+                ("b.rs", "Rust", "fn b() {}\n"),
+                ("c.py", "Python", "# SPDX-License-Identifier: MIT\nx = 1\n"),
+                ("d.py", "Python", "x = 2\n"),
+            ],
+            "SPDX-License-Identifier: MIT",
+        );
+
+        assert_eq!(report.checked, 4);
+        assert_eq!(report.compliant, 2);
+        assert_eq!(report.missing, vec!["b.rs", "d.py"]);
+        assert!((report.ratio - 0.5).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn multi_line_header_matches_across_comment_styles() {
+        let expected =
+            "Copyright 2026 Example Corp\nLicensed under the Apache License, Version 2.0";
+        let report = check(
+            &[
+                (
+                    "a.rs",
+                    "Rust",
+                    "// Copyright 2026 Example Corp\n// Licensed under the Apache License, Version 2.0\n",
+                ),
+                (
+                    "b.py",
+                    "Python",
+                    "# Copyright 2026 Example Corp\n# Licensed under the Apache License, Version 2.0\n",
+                ),
+                ("c.rs", "Rust", "// Copyright 2026 Example Corp\n"),
+            ],
+            expected,
+        );
+
+        assert_eq!(report.missing, vec!["c.rs"]);
+        assert_eq!(report.compliant, 2);
+    }
+
+    #[test]
+    fn header_below_the_scan_window_does_not_count() {
+        let mut late = "fn f() {}\n".repeat(HEADER_SCAN_LINES);
+        late.push_str("// SPDX-License-Identifier: MIT\n");
+        let report = check(
+            &[("late.rs", "Rust", &late)],
+            "SPDX-License-Identifier: MIT",
+        );
+
+        assert_eq!(report.missing, vec!["late.rs"]);
+    }
+
+    #[test]
+    fn prose_and_data_files_are_not_checked() {
+        let report = check(
+            &[
+                ("README.md", "Markdown", "# Readme\n"),
+                ("data.json", "JSON", "{}\n"),
+            ],
+            "SPDX-License-Identifier: MIT",
+        );
+
+        assert_eq!(report.checked, 0);
+        assert!(report.missing.is_empty());
+        assert!((report.ratio - 1.0).abs() < f64::EPSILON);
+    }
+}
@@ -6,6 +6,10 @@ use tokmd_analysis_types::{LicenseFinding, LicenseReport, LicenseSourceKind};
 
 use tokmd_analysis_types::AnalysisLimits;
 
+mod header;
+
+pub(crate) use header::build_header_compliance;
+
 const DEFAULT_MAX_LICENSE_BYTES: u64 = 256 * 1024;
 
 pub(crate) fn build_license_report(
@@ -57,8 +61,7 @@ pub(crate) fn build_license_report(
 
     findings.sort_by(|a, b| {
         b.confidence
-            .partial_cmp(&a.confidence)
-            .unwrap_or(std::cmp::Ordering::Equal)
+            .total_cmp(&a.confidence)
             .then_with(|| a.spdx.cmp(&b.spdx))
             .then_with(|| a.source_path.cmp(&b.source_path))
     });
@@ -68,6 +71,7 @@ pub(crate) fn build_license_report(
     Ok(LicenseReport {
         findings,
         effective,
+        header_compliance: None,
     })
 }
 
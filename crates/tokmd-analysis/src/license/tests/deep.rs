@@ -382,6 +382,7 @@ fn license_report_debug_contains_findings() {
             source_kind: LicenseSourceKind::Metadata,
         }],
         effective: Some("MIT".to_string()),
+        header_compliance: None,
     };
     let dbg = format!("{:?}", report);
     assert!(dbg.contains("MIT"));
@@ -501,6 +502,7 @@ fn license_report_json_shape() {
     let report = LicenseReport {
         findings: vec![],
         effective: None,
+        header_compliance: None,
     };
     let v: serde_json::Value = serde_json::to_value(report).unwrap();
     assert!(v.is_object());
@@ -524,6 +526,7 @@ fn license_report_with_effective_serializes_correctly() {
             source_kind: LicenseSourceKind::Metadata,
         }],
         effective: Some("MIT".to_string()),
+        header_compliance: None,
     };
     let v: serde_json::Value = serde_json::to_value(report).unwrap();
     assert_eq!(v["effective"], "MIT");
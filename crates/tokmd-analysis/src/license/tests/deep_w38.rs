@@ -409,6 +409,7 @@ fn license_report_default_is_empty() {
     let report = LicenseReport {
         findings: vec![],
         effective: None,
+        header_compliance: None,
     };
     assert!(report.findings.is_empty());
     assert!(report.effective.is_none());
@@ -0,0 +1,168 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use tokmd_analysis_types::{LineEnding, LineEndingReport, MixedLineEndingFile};
+use tokmd_types::{ExportData, FileKind};
+
+use tokmd_analysis_types::{AnalysisLimits, normalize_path};
+
+const DEFAULT_MAX_FILE_BYTES: u64 = 128 * 1024;
+
+pub(crate) fn build_line_ending_report(
+    root: &Path,
+    files: &[PathBuf],
+    export: &ExportData,
+    limits: &AnalysisLimits,
+) -> Result<LineEndingReport> {
+    let modules: BTreeMap<String, &str> = export
+        .rows
+        .iter()
+        .filter(|r| r.kind == FileKind::Parent)
+        .map(|r| (normalize_path(&r.path, root), r.module.as_str()))
+        .collect();
+
+    let mut report = LineEndingReport::default();
+    let mut total_bytes = 0u64;
+    let max_total = limits.max_bytes;
+    let per_file_limit = limits.max_file_bytes.unwrap_or(DEFAULT_MAX_FILE_BYTES) as usize;
+
+    for rel in files {
+        if max_total.is_some_and(|limit| total_bytes >= limit) || limits.out_of_time() {
+            break;
+        }
+        let bytes = crate::content::io::read_head(&root.join(rel), per_file_limit)?;
+        total_bytes += bytes.len() as u64;
+        let bytes = crate::content::io::prepare_text(bytes, limits.strip_bom);
+        if !crate::content::io::is_text_like(&bytes) {
+            continue;
+        }
+        let (lf_lines, crlf_lines) = count_line_breaks(&bytes);
+        match classify(lf_lines, crlf_lines) {
+            LineEnding::Lf => report.lf_files += 1,
+            LineEnding::Crlf => report.crlf_files += 1,
+            LineEnding::None => report.no_line_break_files += 1,
+            LineEnding::Mixed => {
+                report.mixed_files += 1;
+                let path = rel.to_string_lossy().replace('\\', "/");
+                let module = modules.get(&path).copied().unwrap_or("(unknown)");
+                report.mixed.push(MixedLineEndingFile {
+                    module: module.to_string(),
+                    path,
+                    lf_lines,
+                    crlf_lines,
+                });
+            }
+        }
+    }
+
+    report.mixed.sort_by(|a, b| a.path.cmp(&b.path));
+    Ok(report)
+}
+
+/// Count bare `\n` and `\r\n` line breaks.
+fn count_line_breaks(bytes: &[u8]) -> (usize, usize) {
+    let mut lf = 0;
+    let mut crlf = 0;
+    let mut prev = None;
+    for &byte in bytes {
+        if byte == b'\n' {
+            if prev == Some(b'\r') {
+                crlf += 1;
+            } else {
+                lf += 1;
+            }
+        }
+        prev = Some(byte);
+    }
+    (lf, crlf)
+}
+
+fn classify(lf_lines: usize, crlf_lines: usize) -> LineEnding {
+    match (lf_lines > 0, crlf_lines > 0) {
+        (true, true) => LineEnding::Mixed,
+        (true, false) => LineEnding::Lf,
+        (false, true) => LineEnding::Crlf,
+        (false, false) => LineEnding::None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::tempdir;
+    use tokmd_types::{ChildIncludeMode, FileRow};
+
+    fn classify_bytes(bytes: &[u8]) -> LineEnding {
+        let (lf, crlf) = count_line_breaks(bytes);
+        classify(lf, crlf)
+    }
+
+    #[test]
+    fn classifies_lf_crlf_mixed_and_unbroken_files() {
+        assert_eq!(classify_bytes(b"a\nb\n"), LineEnding::Lf);
+        assert_eq!(classify_bytes(b"a\r\nb\r\n"), LineEnding::Crlf);
+        assert_eq!(classify_bytes(b"a\r\nb\nc\r\n"), LineEnding::Mixed);
+        assert_eq!(classify_bytes(b"no break"), LineEnding::None);
+        // A lone carriage return is not a line break.
+        assert_eq!(classify_bytes(b"a\rb\n"), LineEnding::Lf);
+    }
+
+    #[test]
+    fn report_counts_each_class_and_lists_mixed_files() {
+        let dir = tempdir().unwrap();
+        fs::write(dir.path().join("lf.rs"), "fn a() {}\nfn b() {}\n").unwrap();
+        fs::write(dir.path().join("crlf.rs"), "fn a() {}\r\nfn b() {}\r\n").unwrap();
+        fs::write(
+            dir.path().join("mixed.rs"),
+            "fn a() {}\r\nfn b() {}\nfn c() {}\n",
+        )
+        .unwrap();
+        fs::write(dir.path().join("logo.bin"), [0u8, 159, 146, 150, b'\n']).unwrap();
+
+        let export = ExportData {
+            rows: vec![FileRow {
+                path: "mixed.rs".to_string(),
+                module: "(root)".to_string(),
+                lang: "Rust".to_string(),
+                kind: FileKind::Parent,
+                code: 3,
+                comments: 0,
+                blanks: 0,
+                lines: 3,
+                bytes: 32,
+                tokens: 8,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
+            }],
+            module_roots: vec![],
+            module_depth: 1,
+            children: ChildIncludeMode::Separate,
+        };
+        let files: Vec<PathBuf> = ["crlf.rs", "lf.rs", "logo.bin", "mixed.rs"]
+            .into_iter()
+            .map(PathBuf::from)
+            .collect();
+
+        let report =
+            build_line_ending_report(dir.path(), &files, &export, &AnalysisLimits::default())
+                .unwrap();
+
+        assert_eq!(
+            (report.lf_files, report.crlf_files, report.mixed_files),
+            (1, 1, 1)
+        );
+        assert_eq!(report.no_line_break_files, 0);
+        assert_eq!(
+            report.mixed,
+            vec![MixedLineEndingFile {
+                path: "mixed.rs".to_string(),
+                module: "(root)".to_string(),
+                lf_lines: 2,
+                crlf_lines: 1,
+            }]
+        );
+    }
+}
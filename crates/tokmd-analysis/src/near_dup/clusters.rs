@@ -115,8 +115,7 @@ pub(super) fn build_clusters(pairs: &[NearDupPairRow]) -> Vec<NearDupCluster> {
 
     clusters.sort_by(|a, b| {
         b.max_similarity
-            .partial_cmp(&a.max_similarity)
-            .unwrap_or(std::cmp::Ordering::Equal)
+            .total_cmp(&a.max_similarity)
             .then_with(|| a.representative.cmp(&b.representative))
     });
 
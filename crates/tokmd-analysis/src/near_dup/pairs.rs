@@ -97,8 +97,7 @@ fn shared_fingerprint_counts(
 fn sort_pairs(pairs: &mut [NearDupPairRow]) {
     pairs.sort_by(|a, b| {
         b.similarity
-            .partial_cmp(&a.similarity)
-            .unwrap_or(std::cmp::Ordering::Equal)
+            .total_cmp(&a.similarity)
             .then_with(|| a.left.cmp(&b.left))
             .then_with(|| a.right.cmp(&b.right))
     });
@@ -125,6 +124,9 @@ mod tests {
             lines: 10,
             bytes: 100,
             tokens: 10,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         }
     }
 
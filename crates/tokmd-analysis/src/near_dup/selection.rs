@@ -111,6 +111,9 @@ mod tests {
             bytes,
             tokens: code,
             kind: FileKind::Parent,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         }
     }
 
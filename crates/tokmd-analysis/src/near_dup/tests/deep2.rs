@@ -31,6 +31,9 @@ fn make_row(path: &str, module: &str, lang: &str, code: usize, bytes: usize) ->
         lines: code,
         bytes,
         tokens: code * 5,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
@@ -342,6 +345,9 @@ fn child_rows_excluded_from_analysis() {
             lines: 100,
             bytes: 5000,
             tokens: 500,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         },
     ];
     let export = make_export(rows);
@@ -27,6 +27,9 @@ fn make_row(path: &str, module: &str, lang: &str, code: usize, bytes: usize) ->
         lines: code,
         bytes,
         tokens: code * 5,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
@@ -23,6 +23,9 @@ fn make_row(path: &str, module: &str, lang: &str, code: usize, bytes: usize) ->
         lines: code,
         bytes,
         tokens: code * 5,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
@@ -389,6 +392,9 @@ fn child_rows_excluded() {
             lines: 100,
             bytes: content.len(),
             tokens: 500,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         },
         FileRow {
             path: "b.rs".to_string(),
@@ -401,6 +407,9 @@ fn child_rows_excluded() {
             lines: 100,
             bytes: content.len(),
             tokens: 500,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         },
     ];
     let export = make_export(rows);
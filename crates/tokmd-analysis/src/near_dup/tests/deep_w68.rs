@@ -18,6 +18,9 @@ fn make_row(path: &str, lang: &str, module: &str, code: usize, bytes: usize) ->
         lines: code,
         bytes,
         tokens: code * 5,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
@@ -448,6 +451,9 @@ fn child_rows_excluded() {
             lines: 100,
             bytes: body.len(),
             tokens: 500,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         },
     ]);
     let report = build_near_dup_report(
@@ -39,6 +39,9 @@ fn make_row(path: &str, module: &str, lang: &str, code: usize, bytes: usize) ->
         lines: code,
         bytes,
         tokens: code * 5,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
@@ -571,6 +574,9 @@ mod edge_cases {
             lines: 50,
             bytes: 1000,
             tokens: 250,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         });
         let r = run_report(&dir, &data, NearDupScope::Global, 0.5);
         assert_eq!(r.files_analyzed, 1);
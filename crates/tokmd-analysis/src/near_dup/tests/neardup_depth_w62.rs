@@ -26,6 +26,9 @@ fn frow(path: &str, module: &str, lang: &str, code: usize, bytes: usize) -> File
         lines: code,
         bytes,
         tokens: code * 5,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
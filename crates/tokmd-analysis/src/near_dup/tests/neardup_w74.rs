@@ -23,6 +23,9 @@ fn make_export(files: &[(&str, &str, usize)]) -> ExportData {
             lines: 60,
             bytes: *bytes,
             tokens: 200,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         })
         .collect();
     ExportData {
@@ -22,6 +22,9 @@ fn make_file_row(path: &str, code: usize, bytes: usize) -> FileRow {
         lines: code,
         bytes,
         tokens: code * 5,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
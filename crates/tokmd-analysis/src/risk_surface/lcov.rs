@@ -0,0 +1,85 @@
+//! Per-file line coverage from an LCOV report.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// Fraction of instrumented (`DA:`) lines with a non-zero hit count, per
+/// `SF:` file. Records for the same file are merged, later hits winning.
+pub(super) fn parse_lcov_coverage(root: &Path, content: &str) -> BTreeMap<String, f64> {
+    let mut lines_by_file: BTreeMap<String, BTreeMap<usize, usize>> = BTreeMap::new();
+    let mut current: Option<String> = None;
+    for line in content.lines() {
+        let line = line.trim();
+        if let Some(sf) = line.strip_prefix("SF:") {
+            current = Some(relative_path(root, sf));
+        } else if let Some(da) = line.strip_prefix("DA:") {
+            if let Some(file) = &current
+                && let Some((line_no, hits)) = parse_da(da)
+            {
+                lines_by_file
+                    .entry(file.clone())
+                    .or_default()
+                    .insert(line_no, hits);
+            }
+        } else if line == "end_of_record" {
+            current = None;
+        }
+    }
+
+    lines_by_file
+        .into_iter()
+        .filter(|(_, lines)| !lines.is_empty())
+        .map(|(file, lines)| {
+            let hit = lines.values().filter(|&&hits| hits > 0).count();
+            (file, hit as f64 / lines.len() as f64)
+        })
+        .collect()
+}
+
+fn parse_da(da: &str) -> Option<(usize, usize)> {
+    let mut parts = da.split(',');
+    let line_no = parts.next()?.parse().ok()?;
+    let hits = parts.next()?.parse().ok()?;
+    Some((line_no, hits))
+}
+
+/// Strip `root` (or its canonical form) from absolute `SF:` paths.
+fn relative_path(root: &Path, raw: &str) -> String {
+    let path = Path::new(raw);
+    let relative = path
+        .strip_prefix(root)
+        .ok()
+        .map(Path::to_path_buf)
+        .or_else(|| {
+            let canonical = root.canonicalize().ok()?;
+            path.strip_prefix(&canonical).ok().map(Path::to_path_buf)
+        })
+        .map_or_else(|| raw.to_string(), |rel| rel.to_string_lossy().into_owned());
+    let relative = relative.replace('\\', "/");
+    relative.strip_prefix("./").unwrap_or(&relative).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ratio_counts_hit_lines_per_file() {
+        let content = "SF:src/a.rs\nDA:1,3\nDA:2,0\nDA:3,1\nDA:4,0\nend_of_record\n\
+                       SF:src/b.rs\nDA:1,0\nend_of_record\n";
+        let coverage = parse_lcov_coverage(Path::new("/repo"), content);
+
+        assert_eq!(coverage.get("src/a.rs"), Some(&0.5));
+        assert_eq!(coverage.get("src/b.rs"), Some(&0.0));
+    }
+
+    #[test]
+    fn absolute_paths_under_root_become_relative() {
+        let content = "SF:/repo/src/lib.rs\nDA:1,1\nend_of_record\n\
+                       SF:./src/main.rs\nDA:1,1\nend_of_record\n";
+        let coverage = parse_lcov_coverage(Path::new("/repo"), content);
+
+        assert!(coverage.contains_key("src/lib.rs"));
+        assert!(coverage.contains_key("src/main.rs"));
+    }
+}
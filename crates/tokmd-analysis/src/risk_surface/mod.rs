@@ -0,0 +1,190 @@
+//! Risk surface: files that are complex, frequently changed, and untested.
+//!
+//! Joins the per-file signals other sections already computed (cyclomatic
+//! complexity, git commit counts, and optionally LCOV line coverage) into one
+//! ranked list. Each signal is scaled to 0.0–1.0 against the report maximum;
+//! a file's score is the mean of the signals it has, so a file missing from
+//! one source still ranks on the others.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use tokmd_analysis_types::{FileComplexity, HotspotRow, RiskSurfaceRow};
+use tokmd_scan::round_f64;
+
+mod lcov;
+
+/// Rows kept in the receipt; matches the complexity report's file cap.
+const MAX_RISK_SURFACE_ROWS: usize = 100;
+
+/// Rank files by combined complexity, churn, and coverage gap.
+///
+/// Only files with a complexity or churn signal are ranked; coverage refines
+/// their score but never adds a file on its own, since an untested file that
+/// is simple and never changes is not a refactoring priority.
+pub(crate) fn build_risk_surface(
+    complexity: &[FileComplexity],
+    hotspots: &[HotspotRow],
+    coverage: &BTreeMap<String, f64>,
+) -> Vec<RiskSurfaceRow> {
+    let mut rows: BTreeMap<&str, RiskSurfaceRow> = BTreeMap::new();
+    for file in complexity {
+        rows.entry(file.path.as_str())
+            .or_insert_with(|| empty_row(&file.path))
+            .cyclomatic = Some(file.cyclomatic_complexity);
+    }
+    for hotspot in hotspots {
+        rows.entry(hotspot.path.as_str())
+            .or_insert_with(|| empty_row(&hotspot.path))
+            .commits = Some(hotspot.commits);
+    }
+
+    let max_cyclomatic = rows.values().filter_map(|r| r.cyclomatic).max();
+    let max_commits = rows.values().filter_map(|r| r.commits).max();
+    for row in rows.values_mut() {
+        row.coverage = coverage.get(&row.path).copied();
+        let signals = [
+            scaled(row.cyclomatic, max_cyclomatic),
+            scaled(row.commits, max_commits),
+            row.coverage.map(|covered| 1.0 - covered.clamp(0.0, 1.0)),
+        ];
+        let present: Vec<f64> = signals.into_iter().flatten().collect();
+        row.score = if present.is_empty() {
+            0.0
+        } else {
+            round_f64(present.iter().sum::<f64>() / present.len() as f64, 4)
+        };
+    }
+
+    let mut ranked: Vec<RiskSurfaceRow> = rows.into_values().collect();
+    ranked.sort_by(|a, b| {
+        b.score
+            .total_cmp(&a.score)
+            .then_with(|| a.path.cmp(&b.path))
+    });
+    ranked.truncate(MAX_RISK_SURFACE_ROWS);
+    ranked
+}
+
+/// Read an LCOV report into per-file line coverage, keyed like the other
+/// sections (repo-relative, forward slashes).
+pub(crate) fn read_coverage(
+    root: &Path,
+    lcov_path: &Path,
+) -> anyhow::Result<BTreeMap<String, f64>> {
+    let content = std::fs::read_to_string(lcov_path)?;
+    Ok(lcov::parse_lcov_coverage(root, &content))
+}
+
+fn empty_row(path: &str) -> RiskSurfaceRow {
+    RiskSurfaceRow {
+        path: path.to_string(),
+        score: 0.0,
+        cyclomatic: None,
+        commits: None,
+        coverage: None,
+    }
+}
+
+fn scaled(value: Option<usize>, max: Option<usize>) -> Option<f64> {
+    let value = value?;
+    match max {
+        Some(max) if max > 0 => Some(value as f64 / max as f64),
+        _ => Some(0.0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokmd_analysis_types::ComplexityRisk;
+
+    fn file(path: &str, cyclomatic: usize) -> FileComplexity {
+        FileComplexity {
+            path: path.to_string(),
+            module: "src".to_string(),
+            function_count: 1,
+            max_function_length: 10,
+            cyclomatic_complexity: cyclomatic,
+            cognitive_complexity: None,
+            max_nesting: None,
+            risk_level: ComplexityRisk::Low,
+            functions: None,
+        }
+    }
+
+    fn hotspot(path: &str, commits: usize) -> HotspotRow {
+        HotspotRow {
+            path: path.to_string(),
+            commits,
+            lines: 100,
+            score: 100 * commits,
+        }
+    }
+
+    #[test]
+    fn complex_churning_file_outranks_simple_stable_one() {
+        let rows = build_risk_surface(
+            &[file("src/simple.rs", 2), file("src/tangled.rs", 40)],
+            &[hotspot("src/simple.rs", 1), hotspot("src/tangled.rs", 25)],
+            &BTreeMap::new(),
+        );
+
+        assert_eq!(rows[0].path, "src/tangled.rs");
+        assert_eq!(rows[1].path, "src/simple.rs");
+        assert!(rows[0].score > rows[1].score);
+        assert!((rows[0].score - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn file_missing_a_signal_still_ranks() {
+        let rows = build_risk_surface(
+            &[file("src/only_complex.rs", 30)],
+            &[hotspot("src/only_churn.rs", 8)],
+            &BTreeMap::new(),
+        );
+
+        assert_eq!(rows.len(), 2);
+        let complex = rows
+            .iter()
+            .find(|r| r.path == "src/only_complex.rs")
+            .unwrap();
+        assert_eq!(complex.cyclomatic, Some(30));
+        assert_eq!(complex.commits, None);
+        assert!(complex.score > 0.0);
+        let churn = rows.iter().find(|r| r.path == "src/only_churn.rs").unwrap();
+        assert_eq!(churn.cyclomatic, None);
+        assert_eq!(churn.commits, Some(8));
+    }
+
+    #[test]
+    fn coverage_gap_raises_the_score() {
+        let coverage = BTreeMap::from([
+            ("src/tested.rs".to_string(), 0.95),
+            ("src/untested.rs".to_string(), 0.0),
+            ("src/unrelated.rs".to_string(), 0.0),
+        ]);
+        let rows = build_risk_surface(
+            &[file("src/tested.rs", 10), file("src/untested.rs", 10)],
+            &[hotspot("src/tested.rs", 5), hotspot("src/untested.rs", 5)],
+            &coverage,
+        );
+
+        assert_eq!(rows.len(), 2, "coverage alone never adds a file");
+        assert_eq!(rows[0].path, "src/untested.rs");
+        assert_eq!(rows[0].coverage, Some(0.0));
+        assert!(rows[0].score > rows[1].score);
+    }
+
+    #[test]
+    fn ties_break_on_path() {
+        let rows = build_risk_surface(
+            &[file("src/b.rs", 5), file("src/a.rs", 5)],
+            &[],
+            &BTreeMap::new(),
+        );
+
+        let paths: Vec<&str> = rows.iter().map(|r| r.path.as_str()).collect();
+        assert_eq!(paths, vec!["src/a.rs", "src/b.rs"]);
+    }
+}
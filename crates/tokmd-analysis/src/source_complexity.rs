@@ -2,11 +2,14 @@
 //!
 //! These helpers are intentionally lightweight and heuristic. They preserve
 //! function-scoped Rust complexity for cockpit review gates without pulling in
-//! the full analysis preset pipeline or changing receipt schemas.
+//! the full analysis preset pipeline or changing receipt schemas, and expose
+//! the complexity report's multi-language estimators for other languages.
 
 #[path = "source_complexity/mask.rs"]
 mod mask;
 
+use std::path::Path;
+
 use mask::RustCodeMask;
 
 /// Summary of function-scoped Rust complexity for one source file.
@@ -22,6 +25,31 @@ pub struct RustFunctionComplexitySummary {
     pub max_function_length: usize,
 }
 
+/// Summary of function-scoped complexity for one source file in any supported
+/// language.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FunctionComplexitySummary {
+    /// Total cyclomatic complexity across all detected functions.
+    pub total_complexity: u32,
+    /// Maximum complexity of any single detected function.
+    pub max_complexity: u32,
+    /// Number of functions detected in the source file.
+    pub function_count: usize,
+    /// Maximum detected function length in lines.
+    pub max_function_length: usize,
+}
+
+impl From<RustFunctionComplexitySummary> for FunctionComplexitySummary {
+    fn from(rust: RustFunctionComplexitySummary) -> Self {
+        Self {
+            total_complexity: rust.total_complexity,
+            max_complexity: rust.max_complexity,
+            function_count: rust.function_count,
+            max_function_length: rust.max_function_length,
+        }
+    }
+}
+
 /// Testable source analyzer seam for review and gate callers.
 pub trait SourceAnalyzer {
     /// Analyze function-scoped Rust complexity for one source file.
@@ -116,6 +144,65 @@ pub fn analyze_rust_function_complexity(content: &str) -> RustFunctionComplexity
     }
 }
 
+/// Complexity language of a source path, from its extension, or `None` when the
+/// complexity estimators do not support it.
+pub fn complexity_language(path: &str) -> Option<&'static str> {
+    let ext = Path::new(path).extension()?.to_str()?.to_ascii_lowercase();
+    Some(match ext.as_str() {
+        "rs" => "rust",
+        "js" | "jsx" | "mjs" | "cjs" => "javascript",
+        "ts" | "tsx" | "mts" | "cts" => "typescript",
+        "py" | "pyi" => "python",
+        "go" => "go",
+        "c" | "h" => "c",
+        "cc" | "cpp" | "cxx" | "hh" | "hpp" | "hxx" => "c++",
+        "java" => "java",
+        "cs" => "c#",
+        "php" => "php",
+        "rb" => "ruby",
+        _ => return None,
+    })
+}
+
+/// Analyze function-scoped cyclomatic complexity of source code in `language`
+/// (a name returned by [`complexity_language`]).
+///
+/// Rust keeps the comment- and string-aware analyzer above. Other languages
+/// use the complexity report's estimators: per-function scores where function
+/// bodies can be delimited, otherwise a single file-level score.
+#[cfg(all(feature = "content", feature = "walk"))]
+pub fn analyze_function_complexity(content: &str, language: &str) -> FunctionComplexitySummary {
+    use crate::complexity::{count_functions, estimate_cyclomatic, map_language_for_complexity};
+    use crate::content::complexity::estimate_cyclomatic_complexity;
+
+    if language.eq_ignore_ascii_case("rust") {
+        return analyze_rust_function_complexity(content).into();
+    }
+
+    let (function_count, max_function_length) = count_functions(language, content);
+    let per_function =
+        estimate_cyclomatic_complexity(content, map_language_for_complexity(language));
+    let (total, max, function_count) = if per_function.function_count > 0 {
+        (
+            per_function.total_cc,
+            per_function.max_cc,
+            per_function.function_count,
+        )
+    } else if function_count > 0 {
+        let file_level = estimate_cyclomatic(language, content);
+        (file_level, file_level, function_count)
+    } else {
+        (0, 0, 0)
+    };
+
+    FunctionComplexitySummary {
+        total_complexity: u32::try_from(total).unwrap_or(u32::MAX),
+        max_complexity: u32::try_from(max).unwrap_or(u32::MAX),
+        function_count,
+        max_function_length,
+    }
+}
+
 fn is_rust_fn_start(trimmed: &str) -> bool {
     let Some(fn_pos) = trimmed.find("fn ") else {
         return false;
@@ -163,6 +250,8 @@ fn is_rust_fn_start(trimmed: &str) -> bool {
 #[cfg(test)]
 mod tests {
     use super::{RustAnalyzer, SourceAnalyzer, analyze_rust_function_complexity};
+    #[cfg(all(feature = "content", feature = "walk"))]
+    use super::{analyze_function_complexity, complexity_language};
 
     #[test]
     fn rust_complexity_counts_else_if_once() {
@@ -261,4 +350,39 @@ fn second() {
         assert_eq!(analysis.total_complexity, 5);
         assert_eq!(analysis.max_complexity, 3);
     }
+
+    #[cfg(all(feature = "content", feature = "walk"))]
+    #[test]
+    fn python_branches_are_scored_per_function() {
+        let analysis = analyze_function_complexity(
+            r#"
+def simple():
+    return 1
+
+def branchy(x, y):
+    if x > 0 and y > 0:
+        return 1
+    elif x < 0 or y < 0:
+        return -1
+    for i in range(x):
+        if i == y:
+            return i
+    return 0
+"#,
+            complexity_language("pkg/branchy.py").unwrap(),
+        );
+
+        assert_eq!(analysis.function_count, 2);
+        assert_eq!(analysis.max_complexity, 7);
+        assert_eq!(analysis.total_complexity, 8);
+    }
+
+    #[cfg(all(feature = "content", feature = "walk"))]
+    #[test]
+    fn unsupported_extensions_have_no_complexity_language() {
+        assert_eq!(complexity_language("src/lib.rs"), Some("rust"));
+        assert_eq!(complexity_language("web/App.TSX"), Some("typescript"));
+        assert_eq!(complexity_language("README.md"), None);
+        assert_eq!(complexity_language("Makefile"), None);
+    }
 }
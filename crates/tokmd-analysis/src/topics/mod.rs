@@ -70,8 +70,7 @@ pub(crate) fn build_topic_clouds(export: &ExportData) -> TopicClouds {
             .collect();
         rows.sort_by(|a, b| {
             b.score
-                .partial_cmp(&a.score)
-                .unwrap_or(std::cmp::Ordering::Equal)
+                .total_cmp(&a.score)
                 .then_with(|| a.term.cmp(&b.term))
         });
         rows.truncate(TOP_K);
@@ -97,8 +96,7 @@ pub(crate) fn build_topic_clouds(export: &ExportData) -> TopicClouds {
         .collect();
     overall.sort_by(|a, b| {
         b.score
-            .partial_cmp(&a.score)
-            .unwrap_or(std::cmp::Ordering::Equal)
+            .total_cmp(&a.score)
             .then_with(|| a.term.cmp(&b.term))
     });
     overall.truncate(TOP_K);
@@ -171,7 +169,9 @@ fn build_stopwords(export: &ExportData) -> BTreeSet<String> {
         stop.insert(ext.to_string());
     }
     for root in &export.module_roots {
-        stop.insert(root.to_lowercase());
+        // Roots may carry a `name=depth` override; only the name is a path token.
+        let name = root.split_once('=').map_or(root.as_str(), |(name, _)| name);
+        stop.insert(name.to_lowercase());
     }
     stop
 }
@@ -195,6 +195,9 @@ mod unit_tests {
                 lines: 10,
                 bytes: 100,
                 tokens: 50,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             },
             FileRow {
                 path: "crates/auth/src/token.rs".to_string(),
@@ -207,6 +210,9 @@ mod unit_tests {
                 lines: 10,
                 bytes: 100,
                 tokens: 50,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             },
             FileRow {
                 path: "crates/payments/src/stripe_api.rs".to_string(),
@@ -219,6 +225,9 @@ mod unit_tests {
                 lines: 10,
                 bytes: 100,
                 tokens: 50,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             },
             FileRow {
                 path: "crates/payments/src/refund.rs".to_string(),
@@ -231,6 +240,9 @@ mod unit_tests {
                 lines: 10,
                 bytes: 100,
                 tokens: 50,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             },
         ];
         let export = ExportData {
@@ -17,6 +17,9 @@ fn make_row(path: &str, module: &str, code: usize, tokens: usize) -> FileRow {
         lines: code,
         bytes: code * 10,
         tokens,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
@@ -21,6 +21,9 @@ fn row(path: &str, module: &str, lang: &str, tokens: usize) -> FileRow {
         lines: 10,
         bytes: 100,
         tokens,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
@@ -151,6 +154,9 @@ fn child_rows_excluded() {
             lines: 10,
             bytes: 100,
             tokens: 50,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         },
     ];
     let data = export(rows, &[]);
@@ -24,6 +24,9 @@ fn make_row(path: &str, module: &str, lang: &str, tokens: usize) -> FileRow {
         lines: 10,
         bytes: 100,
         tokens,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
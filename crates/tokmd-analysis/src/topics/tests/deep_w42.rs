@@ -20,6 +20,9 @@ fn row(path: &str, module: &str, tokens: usize) -> FileRow {
         lines: 10,
         bytes: 100,
         tokens,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
@@ -21,6 +21,9 @@ fn row(path: &str, module: &str, lang: &str, tokens: usize) -> FileRow {
         lines: 10,
         bytes: 100,
         tokens,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
@@ -36,6 +39,9 @@ fn child_row(path: &str, module: &str) -> FileRow {
         lines: 5,
         bytes: 50,
         tokens: 25,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
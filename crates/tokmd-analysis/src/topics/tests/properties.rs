@@ -38,6 +38,9 @@ fn arb_file_row() -> impl Strategy<Value = FileRow> {
             lines: code,
             bytes: code * 10,
             tokens,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         }
     })
 }
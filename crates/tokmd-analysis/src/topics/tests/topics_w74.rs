@@ -20,6 +20,9 @@ fn make_export(files: &[(&str, &str, &str, usize)]) -> ExportData {
             lines: 60,
             bytes: 500,
             tokens: *tokens,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         })
         .collect();
     ExportData {
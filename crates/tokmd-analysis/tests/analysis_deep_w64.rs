@@ -50,6 +50,7 @@ fn make_source() -> AnalysisSource {
         module_roots: vec!["src".to_string()],
         module_depth: 2,
         children: "separate".to_string(),
+        token_model: "heuristic".to_string(),
     }
 }
 
@@ -75,6 +76,14 @@ fn make_req(preset: AnalysisPreset) -> AnalysisRequest {
             max_commits: None,
             max_commit_files: None,
             import_granularity: "module".to_string(),
+            oversized_file_bytes: None,
+            max_commit_message_bytes: None,
+            strip_bom: false,
+            preset_sections: None,
+            include_sections: vec![],
+            exclude_sections: vec![],
+            sample_rate: None,
+            max_runtime_ms: None,
         },
         limits: AnalysisLimits::default(),
         #[cfg(feature = "effort")]
@@ -82,13 +91,27 @@ fn make_req(preset: AnalysisPreset) -> AnalysisRequest {
         window_tokens: None,
         git: None,
         import_granularity: ImportGranularity::Module,
+        tree: Default::default(),
         detail_functions: false,
+        detail_api_items: false,
         near_dup: false,
         near_dup_threshold: 0.80,
         near_dup_max_files: 2000,
         near_dup_scope: NearDupScope::Module,
         near_dup_max_pairs: None,
         near_dup_exclude: Vec::new(),
+        api_doc_thresholds: Default::default(),
+        license_header: None,
+        domain_grouping: Default::default(),
+        forecast_days: None,
+        coverage: None,
+        hash_algo: tokmd_types::HashAlgo::Blake3,
+        timings: false,
+        plan: None,
+        include_sections: vec![],
+        exclude_sections: vec![],
+        progress: None,
+        extensions: Vec::new(),
     }
 }
 
@@ -104,6 +127,9 @@ fn file_row(path: &str, module: &str, lang: &str, code: usize) -> FileRow {
         lines: code + code.checked_div(5).unwrap_or(0) + code.checked_div(10).unwrap_or(0),
         bytes: code * 10,
         tokens: code * 2,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
@@ -290,6 +316,11 @@ fn limits_custom_values_preserved() {
         max_file_bytes: Some(50_000),
         max_commits: Some(500),
         max_commit_files: Some(50),
+        oversized_file_bytes: None,
+        max_commit_message_bytes: None,
+        strip_bom: false,
+        sample_rate: None,
+        runtime_budget: None,
     };
     assert_eq!(limits.max_files, Some(100));
     assert_eq!(limits.max_bytes, Some(1_000_000));
@@ -362,7 +393,7 @@ fn near_dup_scope_serde_roundtrip() {
 
 #[test]
 fn analysis_schema_version_is_current() {
-    assert_eq!(ANALYSIS_SCHEMA_VERSION, 9);
+    assert_eq!(ANALYSIS_SCHEMA_VERSION, 10);
 }
 
 #[test]
@@ -581,6 +612,14 @@ fn args_meta_serde_roundtrip() {
         max_commits: Some(1000),
         max_commit_files: Some(100),
         import_granularity: "file".to_string(),
+        oversized_file_bytes: None,
+        max_commit_message_bytes: None,
+        strip_bom: false,
+        preset_sections: None,
+        include_sections: vec![],
+        exclude_sections: vec![],
+        sample_rate: None,
+        max_runtime_ms: None,
     };
     let json = serde_json::to_string(&args).unwrap();
     let back: AnalysisArgsMeta = serde_json::from_str(&json).unwrap();
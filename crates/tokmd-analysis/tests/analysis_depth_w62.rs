@@ -28,6 +28,9 @@ fn file_row(path: &str, lang: &str, code: usize) -> FileRow {
         lines: code + code / 5 + code / 10,
         bytes: code * 40,
         tokens: code * 3,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
@@ -75,6 +78,7 @@ fn source() -> AnalysisSource {
         module_roots: vec![],
         module_depth: 1,
         children: "separate".into(),
+        token_model: "heuristic".to_string(),
     }
 }
 
@@ -90,6 +94,14 @@ fn args(preset: &str) -> AnalysisArgsMeta {
         max_commit_files: None,
         max_file_bytes: None,
         import_granularity: "module".into(),
+        oversized_file_bytes: None,
+        max_commit_message_bytes: None,
+        strip_bom: false,
+        preset_sections: None,
+        include_sections: vec![],
+        exclude_sections: vec![],
+        sample_rate: None,
+        max_runtime_ms: None,
     }
 }
 
@@ -103,13 +115,27 @@ fn request(preset: AnalysisPreset) -> AnalysisRequest {
         window_tokens: None,
         git: Some(false),
         import_granularity: ImportGranularity::Module,
+        tree: Default::default(),
         detail_functions: false,
+        detail_api_items: false,
         near_dup: false,
         near_dup_threshold: 0.8,
         near_dup_max_files: 500,
         near_dup_scope: NearDupScope::default(),
         near_dup_max_pairs: None,
         near_dup_exclude: vec![],
+        api_doc_thresholds: Default::default(),
+        license_header: None,
+        domain_grouping: Default::default(),
+        forecast_days: None,
+        coverage: None,
+        hash_algo: tokmd_types::HashAlgo::Blake3,
+        timings: false,
+        plan: None,
+        include_sections: vec![],
+        exclude_sections: vec![],
+        progress: None,
+        extensions: Vec::new(),
     }
 }
 
@@ -574,7 +600,11 @@ fn deterministic_json_serialization() {
 fn git_explicitly_disabled_no_warning() {
     // git=Some(false) should not produce a git warning
     let r = run(AnalysisPreset::Risk);
-    let git_warnings: Vec<_> = r.warnings.iter().filter(|w| w.contains("git")).collect();
+    let git_warnings: Vec<_> = r
+        .warnings
+        .iter()
+        .filter(|w| w.message.contains("git"))
+        .collect();
     assert!(
         git_warnings.is_empty(),
         "explicitly disabling git should produce no git warnings"
@@ -799,6 +829,9 @@ fn child_rows_handled_without_panic() {
                 lines: 13,
                 bytes: 0,
                 tokens: 0,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             },
         ],
         module_roots: vec![],
@@ -26,6 +26,9 @@ fn sample_row(path: &str, module: &str, lang: &str, code: usize) -> FileRow {
         lines: code + 3,
         bytes: code * 30,
         tokens: code * 5,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
@@ -63,6 +66,7 @@ fn make_source() -> AnalysisSource {
         module_roots: vec!["src".to_string()],
         module_depth: 2,
         children: "separate".to_string(),
+        token_model: "heuristic".to_string(),
     }
 }
 
@@ -88,6 +92,14 @@ fn make_req(preset: PresetKind) -> AnalysisRequest {
             max_commit_files: None,
             max_file_bytes: None,
             import_granularity: "module".to_string(),
+            oversized_file_bytes: None,
+            max_commit_message_bytes: None,
+            strip_bom: false,
+            preset_sections: None,
+            include_sections: vec![],
+            exclude_sections: vec![],
+            sample_rate: None,
+            max_runtime_ms: None,
         },
         limits: Default::default(),
         #[cfg(feature = "effort")]
@@ -95,13 +107,27 @@ fn make_req(preset: PresetKind) -> AnalysisRequest {
         window_tokens: None,
         git: None,
         import_granularity: ImportGranularity::Module,
+        tree: Default::default(),
         detail_functions: false,
+        detail_api_items: false,
         near_dup: false,
         near_dup_threshold: 0.8,
         near_dup_max_files: 500,
         near_dup_scope: NearDupScope::Module,
         near_dup_max_pairs: None,
         near_dup_exclude: vec![],
+        api_doc_thresholds: Default::default(),
+        license_header: None,
+        domain_grouping: Default::default(),
+        forecast_days: None,
+        coverage: None,
+        hash_algo: tokmd_types::HashAlgo::Blake3,
+        timings: false,
+        plan: None,
+        include_sections: vec![],
+        exclude_sections: vec![],
+        progress: None,
+        extensions: Vec::new(),
     }
 }
 
@@ -324,7 +350,8 @@ fn estimate_with_rootless_context_emits_host_root_warnings() {
         receipt
             .warnings
             .iter()
-            .any(|warning| warning.contains("no host root") && warning.contains("file-backed")),
+            .any(|warning| warning.message.contains("no host root")
+                && warning.message.contains("file-backed")),
         "expected file-backed rootless warning, got {:?}",
         receipt.warnings
     );
@@ -332,7 +359,8 @@ fn estimate_with_rootless_context_emits_host_root_warnings() {
         receipt
             .warnings
             .iter()
-            .any(|warning| warning.contains("no host root") && warning.contains("git")),
+            .any(|warning| warning.message.contains("no host root")
+                && warning.message.contains("git")),
         "expected git rootless warning, got {:?}",
         receipt.warnings
     );
@@ -37,6 +37,7 @@ fn make_source() -> AnalysisSource {
         module_roots: vec!["crates".to_string()],
         module_depth: 2,
         children: "separate".to_string(),
+        token_model: "heuristic".to_string(),
     }
 }
 
@@ -62,6 +63,14 @@ fn make_req(preset: AnalysisPreset) -> AnalysisRequest {
             max_commits: None,
             max_commit_files: None,
             import_granularity: "module".to_string(),
+            oversized_file_bytes: None,
+            max_commit_message_bytes: None,
+            strip_bom: false,
+            preset_sections: None,
+            include_sections: vec![],
+            exclude_sections: vec![],
+            sample_rate: None,
+            max_runtime_ms: None,
         },
         limits: AnalysisLimits::default(),
         #[cfg(feature = "effort")]
@@ -69,13 +78,27 @@ fn make_req(preset: AnalysisPreset) -> AnalysisRequest {
         window_tokens: None,
         git: None,
         import_granularity: ImportGranularity::Module,
+        tree: Default::default(),
         detail_functions: false,
+        detail_api_items: false,
         near_dup: false,
         near_dup_threshold: 0.80,
         near_dup_max_files: 2000,
         near_dup_scope: NearDupScope::Module,
         near_dup_max_pairs: None,
         near_dup_exclude: Vec::new(),
+        api_doc_thresholds: Default::default(),
+        license_header: None,
+        domain_grouping: Default::default(),
+        forecast_days: None,
+        coverage: None,
+        hash_algo: tokmd_types::HashAlgo::Blake3,
+        timings: false,
+        plan: None,
+        include_sections: vec![],
+        exclude_sections: vec![],
+        progress: None,
+        extensions: Vec::new(),
     }
 }
 
@@ -91,6 +114,9 @@ fn row(path: &str, module: &str, lang: &str, code: usize) -> FileRow {
         lines: code + code / 5 + code / 10,
         bytes: code * 10,
         tokens: code * 2,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
@@ -212,11 +238,11 @@ fn warnings_from_disabled_features_match_catalog() {
     // Every warning in the receipt should either be a known catalog message
     // or a runtime error message (starts with a known prefix).
     for warning in &receipt.warnings {
-        let is_catalog = known_warnings.iter().any(|k| warning == k);
-        let is_runtime = warning.contains("failed:")
-            || warning.contains("not a git repo")
-            || warning.contains("feature disabled")
-            || warning.contains("feature is disabled");
+        let is_catalog = known_warnings.iter().any(|k| warning.message == *k);
+        let is_runtime = warning.message.contains("failed:")
+            || warning.message.contains("not a git repo")
+            || warning.message.contains("feature disabled")
+            || warning.message.contains("feature is disabled");
         assert!(
             is_catalog || is_runtime,
             "Unexpected warning not in catalog: {:?}",
@@ -395,6 +421,11 @@ fn custom_limits_do_not_crash() {
         max_file_bytes: Some(256),
         max_commits: Some(10),
         max_commit_files: Some(5),
+        oversized_file_bytes: None,
+        max_commit_message_bytes: None,
+        strip_bom: false,
+        sample_rate: None,
+        runtime_budget: None,
     };
     let receipt = analyze(make_ctx(sample_export()), req).unwrap();
     assert!(receipt.derived.is_some());
@@ -410,6 +441,11 @@ fn zero_max_files_limit_does_not_panic() {
         max_file_bytes: Some(0),
         max_commits: Some(0),
         max_commit_files: Some(0),
+        oversized_file_bytes: None,
+        max_commit_message_bytes: None,
+        strip_bom: false,
+        sample_rate: None,
+        runtime_budget: None,
     };
     let receipt = analyze(make_ctx(sample_export()), req).unwrap();
     assert!(receipt.derived.is_some());
@@ -484,6 +520,9 @@ fn context_window_fits_when_tokens_within_budget() {
             lines: 10,
             bytes: 100,
             tokens: 20,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         }],
         module_roots: vec![],
         module_depth: 1,
@@ -513,6 +552,9 @@ fn context_window_exactly_at_boundary() {
             lines: 500,
             bytes: 5000,
             tokens: 1000,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         }],
         module_roots: vec![],
         module_depth: 1,
@@ -586,6 +628,14 @@ fn args_meta_json_roundtrip() {
         max_commits: Some(500),
         max_commit_files: Some(200),
         import_granularity: "file".to_string(),
+        oversized_file_bytes: None,
+        max_commit_message_bytes: None,
+        strip_bom: false,
+        preset_sections: None,
+        include_sections: vec![],
+        exclude_sections: vec![],
+        sample_rate: None,
+        max_runtime_ms: None,
     };
 
     let json = serde_json::to_string(&args).unwrap();
@@ -615,6 +665,7 @@ fn source_json_roundtrip() {
         module_roots: vec!["crates".to_string(), "packages".to_string()],
         module_depth: 3,
         children: "collapse".to_string(),
+        token_model: "heuristic".to_string(),
     };
 
     let json = serde_json::to_string(&source).unwrap();
@@ -788,7 +839,7 @@ fn git_unavailable_reported_in_warnings() {
         matches!(receipt.status, ScanStatus::Partial),
         "git preset on non-repo should be Partial"
     );
-    let has_git_warning = receipt.warnings.iter().any(|w| w.contains("git"));
+    let has_git_warning = receipt.warnings.iter().any(|w| w.message.contains("git"));
     assert!(
         has_git_warning,
         "should mention git in warnings: {:?}",
@@ -874,6 +925,9 @@ fn doc_density_ratio_is_comments_over_code_plus_comments() {
             lines: 1000,
             bytes: 10000,
             tokens: 1600,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         }],
         module_roots: vec![],
         module_depth: 1,
@@ -30,6 +30,7 @@ fn make_source() -> AnalysisSource {
         module_roots: vec![],
         module_depth: 2,
         children: "separate".to_string(),
+        token_model: "heuristic".to_string(),
     }
 }
 
@@ -55,6 +56,14 @@ fn make_req(preset: AnalysisPreset) -> AnalysisRequest {
             max_commits: None,
             max_commit_files: None,
             import_granularity: "module".to_string(),
+            oversized_file_bytes: None,
+            max_commit_message_bytes: None,
+            strip_bom: false,
+            preset_sections: None,
+            include_sections: vec![],
+            exclude_sections: vec![],
+            sample_rate: None,
+            max_runtime_ms: None,
         },
         limits: AnalysisLimits::default(),
         #[cfg(feature = "effort")]
@@ -62,13 +71,27 @@ fn make_req(preset: AnalysisPreset) -> AnalysisRequest {
         window_tokens: None,
         git: Some(false),
         import_granularity: ImportGranularity::Module,
+        tree: Default::default(),
         detail_functions: false,
+        detail_api_items: false,
         near_dup: false,
         near_dup_threshold: 0.80,
         near_dup_max_files: 2000,
         near_dup_scope: NearDupScope::Module,
         near_dup_max_pairs: None,
         near_dup_exclude: Vec::new(),
+        api_doc_thresholds: Default::default(),
+        license_header: None,
+        domain_grouping: Default::default(),
+        forecast_days: None,
+        coverage: None,
+        hash_algo: tokmd_types::HashAlgo::Blake3,
+        timings: false,
+        plan: None,
+        include_sections: vec![],
+        exclude_sections: vec![],
+        progress: None,
+        extensions: Vec::new(),
     }
 }
 
@@ -84,6 +107,9 @@ fn row(path: &str, module: &str, lang: &str, code: usize) -> FileRow {
         lines: code + code / 5 + code / 10,
         bytes: code * 10,
         tokens: code * 2,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
@@ -379,7 +405,7 @@ fn fun_preset_emits_fun_report_or_warning() {
     let has_warning = receipt
         .warnings
         .iter()
-        .any(|w| w.contains("fun") || w.contains("eco-label"));
+        .any(|w| w.message.contains("fun") || w.message.contains("eco-label"));
     assert!(
         has_fun || has_warning,
         "Fun preset should produce fun report or warning"
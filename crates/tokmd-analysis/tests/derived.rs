@@ -1,9 +1,9 @@
 use tokmd_analysis::{
-    AnalysisContext, AnalysisLimits, AnalysisPreset, AnalysisRequest, ImportGranularity,
-    NearDupScope, analyze,
+    AnalysisContext, AnalysisLimits, AnalysisPreset, AnalysisRequest, AnalysisTreeOptions,
+    ImportGranularity, IntegrityMismatch, NearDupScope, analyze, verify_integrity,
 };
-use tokmd_analysis_types::{AnalysisArgsMeta, AnalysisSource};
-use tokmd_types::{ChildIncludeMode, ExportData, FileKind, FileRow};
+use tokmd_analysis_types::{AnalysisArgsMeta, AnalysisReceipt, AnalysisSource};
+use tokmd_types::{ChildIncludeMode, ExportData, FileKind, FileRow, HashAlgo};
 
 fn sample_export() -> ExportData {
     let rows = vec![
@@ -18,6 +18,9 @@ fn sample_export() -> ExportData {
             lines: 130,
             bytes: 1000,
             tokens: 250,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         },
         FileRow {
             path: "tests/lib_test.rs".to_string(),
@@ -30,6 +33,9 @@ fn sample_export() -> ExportData {
             lines: 65,
             bytes: 500,
             tokens: 125,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         },
         FileRow {
             path: "Cargo.toml".to_string(),
@@ -42,6 +48,9 @@ fn sample_export() -> ExportData {
             lines: 25,
             bytes: 200,
             tokens: 50,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         },
         FileRow {
             path: "src/lib.rs".to_string(),
@@ -54,6 +63,9 @@ fn sample_export() -> ExportData {
             lines: 13,
             bytes: 0,
             tokens: 0,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         },
     ];
 
@@ -65,9 +77,16 @@ fn sample_export() -> ExportData {
     }
 }
 
-#[test]
-fn derived_metrics_basic() {
-    let export = sample_export();
+fn analyze_export(export: ExportData, hash_algo: HashAlgo) -> AnalysisReceipt {
+    analyze_export_with(export, hash_algo, "md", AnalysisTreeOptions::default())
+}
+
+fn analyze_export_with(
+    export: ExportData,
+    hash_algo: HashAlgo,
+    format: &str,
+    tree: AnalysisTreeOptions,
+) -> AnalysisReceipt {
     let ctx = AnalysisContext {
         export,
         root: std::path::PathBuf::from("."),
@@ -81,13 +100,14 @@ fn derived_metrics_basic() {
             module_roots: vec!["crates".to_string(), "packages".to_string()],
             module_depth: 2,
             children: "separate".to_string(),
+            token_model: "heuristic".to_string(),
         },
     };
     let request = AnalysisRequest {
         preset: AnalysisPreset::Receipt,
         args: AnalysisArgsMeta {
             preset: "receipt".to_string(),
-            format: "md".to_string(),
+            format: format.to_string(),
             window_tokens: None,
             git: None,
             max_files: None,
@@ -96,6 +116,14 @@ fn derived_metrics_basic() {
             max_commits: None,
             max_commit_files: None,
             import_granularity: "module".to_string(),
+            oversized_file_bytes: None,
+            max_commit_message_bytes: None,
+            strip_bom: false,
+            preset_sections: None,
+            include_sections: vec![],
+            exclude_sections: vec![],
+            sample_rate: None,
+            max_runtime_ms: None,
         },
         limits: AnalysisLimits::default(),
         #[cfg(feature = "effort")]
@@ -103,16 +131,35 @@ fn derived_metrics_basic() {
         window_tokens: None,
         git: None,
         import_granularity: ImportGranularity::Module,
+        tree,
         detail_functions: false,
+        detail_api_items: false,
         near_dup: false,
         near_dup_threshold: 0.80,
         near_dup_max_files: 2000,
         near_dup_scope: NearDupScope::Module,
         near_dup_max_pairs: None,
         near_dup_exclude: Vec::new(),
+        api_doc_thresholds: Default::default(),
+        license_header: None,
+        domain_grouping: Default::default(),
+        forecast_days: None,
+        coverage: None,
+        hash_algo,
+        timings: false,
+        plan: None,
+        include_sections: vec![],
+        exclude_sections: vec![],
+        progress: None,
+        extensions: Vec::new(),
     };
 
-    let receipt = analyze(ctx, request).expect("analysis");
+    analyze(ctx, request).expect("analysis")
+}
+
+#[test]
+fn derived_metrics_basic() {
+    let receipt = analyze_export(sample_export(), HashAlgo::Blake3);
     let derived = receipt.derived.expect("derived report");
 
     assert_eq!(derived.totals.files, 3);
@@ -161,3 +208,123 @@ fn derived_metrics_basic() {
     assert_eq!(derived.top.largest_lines[0].path, "src/lib.rs");
     assert_eq!(derived.top.largest_lines[2].path, "Cargo.toml");
 }
+
+#[test]
+fn histogram_buckets_serialize_numeric_bounds() {
+    let receipt = analyze_export(sample_export(), HashAlgo::Blake3);
+    let json = serde_json::to_value(&receipt).expect("serialize receipt");
+    let buckets = json["derived"]["histogram"]
+        .as_array()
+        .expect("histogram array");
+
+    let (last, rest) = buckets.split_last().expect("buckets");
+    assert!(last["max"].is_null(), "last bucket is open-ended: {last}");
+    let mut next_min = 0;
+    for bucket in rest {
+        assert_eq!(bucket["min"].as_u64(), Some(next_min), "{bucket}");
+        let max = bucket["max"].as_u64().expect("numeric max");
+        assert!(max >= next_min, "{bucket}");
+        next_min = max + 1;
+    }
+    assert_eq!(last["min"].as_u64(), Some(next_min));
+
+    // Sizes 25, 65, and 130 land by bounds alone, without reading labels.
+    let sizes = [25, 65, 130];
+    for bucket in buckets {
+        let min = bucket["min"].as_u64().unwrap();
+        let max = bucket["max"].as_u64().unwrap_or(u64::MAX);
+        let expected = sizes
+            .iter()
+            .filter(|&&size| (min..=max).contains(&size))
+            .count();
+        assert_eq!(bucket["files"].as_u64(), Some(expected as u64), "{bucket}");
+    }
+}
+
+#[test]
+fn verify_integrity_accepts_untouched_receipt() {
+    for algo in [HashAlgo::Blake3, HashAlgo::Sha256] {
+        let receipt = analyze_export(sample_export(), algo);
+        assert_eq!(verify_integrity(&receipt, &sample_export()), Ok(()));
+    }
+}
+
+#[test]
+fn verify_integrity_reports_tampered_receipt_and_rows() {
+    let mut receipt = analyze_export(sample_export(), HashAlgo::Sha256);
+
+    let mut edited = sample_export();
+    edited.rows[0].bytes += 1;
+    assert!(matches!(
+        verify_integrity(&receipt, &edited),
+        Err(IntegrityMismatch::Hash { .. })
+    ));
+
+    let derived = receipt.derived.as_mut().expect("derived report");
+    derived.integrity.hash = "0".repeat(64);
+    let err = verify_integrity(&receipt, &sample_export()).unwrap_err();
+    assert!(err.to_string().contains("recorded 0000"), "{err}");
+}
+
+fn nested_export() -> Result<ExportData, Box<dyn std::error::Error>> {
+    let mut export = sample_export();
+    let mut nested = export.rows.first().cloned().ok_or("sample export rows")?;
+    nested.path = "crates/core/src/main.rs".to_string();
+    export.rows.push(nested);
+    Ok(export)
+}
+
+#[test]
+fn tree_format_collapses_single_child_directories_when_requested()
+-> Result<(), Box<dyn std::error::Error>> {
+    let tree_options = AnalysisTreeOptions {
+        collapse_single_child: true,
+        ..AnalysisTreeOptions::default()
+    };
+    let receipt = analyze_export_with(nested_export()?, HashAlgo::Blake3, "tree", tree_options);
+    let tree = receipt.derived.and_then(|d| d.tree).ok_or("derived tree")?;
+
+    assert!(
+        tree.contains("crates/core/src (lines: 130, tokens: 250)"),
+        "{tree}"
+    );
+    assert!(!tree.contains("\n  core "), "{tree}");
+    Ok(())
+}
+
+#[test]
+fn tree_format_keeps_every_level_by_default() -> Result<(), Box<dyn std::error::Error>> {
+    let receipt = analyze_export_with(
+        nested_export()?,
+        HashAlgo::Blake3,
+        "tree",
+        AnalysisTreeOptions::default(),
+    );
+    let tree = receipt.derived.and_then(|d| d.tree).ok_or("derived tree")?;
+
+    assert!(
+        tree.contains("crates (lines: 130, tokens: 250)\n  core "),
+        "{tree}"
+    );
+    Ok(())
+}
+
+#[test]
+fn tree_format_appends_line_breakdown_when_requested() -> Result<(), Box<dyn std::error::Error>> {
+    let tree_options = AnalysisTreeOptions {
+        line_breakdown: true,
+        ..AnalysisTreeOptions::default()
+    };
+    let receipt = analyze_export_with(sample_export(), HashAlgo::Blake3, "tree", tree_options);
+    let tree = receipt.derived.and_then(|d| d.tree).ok_or("derived tree")?;
+
+    assert!(
+        tree.contains("src (lines: 130, tokens: 250, code/comment/blank: 100/20/10)"),
+        "{tree}"
+    );
+    assert!(
+        tree.contains("Cargo.toml (lines: 25, tokens: 50, code/comment/blank: 20/0/5)"),
+        "{tree}"
+    );
+    Ok(())
+}
@@ -41,6 +41,9 @@ fn create_dummy_row(i: usize) -> FileRow {
         lines: 0, // will be calc
         bytes: (i * 100) % 1000 + 10,
         tokens: (i * 25) % 250 + 2,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
@@ -61,6 +64,7 @@ fn test_derive_report_determinism() {
         module_roots: vec!["src".to_string()],
         module_depth: 1,
         children: "separate".to_string(),
+        token_model: "heuristic".to_string(),
     };
 
     let args_meta = AnalysisArgsMeta {
@@ -74,6 +78,14 @@ fn test_derive_report_determinism() {
         max_commit_files: None,
         max_file_bytes: None,
         import_granularity: "module".to_string(),
+        oversized_file_bytes: None,
+        max_commit_message_bytes: None,
+        strip_bom: false,
+        preset_sections: None,
+        include_sections: vec![],
+        exclude_sections: vec![],
+        sample_rate: None,
+        max_runtime_ms: None,
     };
 
     let limits = AnalysisLimits::default();
@@ -87,13 +99,27 @@ fn test_derive_report_determinism() {
         window_tokens: None,
         git: Some(false),
         import_granularity: ImportGranularity::Module,
+        tree: Default::default(),
         detail_functions: false,
+        detail_api_items: false,
         near_dup: false,
         near_dup_threshold: 0.80,
         near_dup_max_files: 2000,
         near_dup_scope: NearDupScope::Module,
         near_dup_max_pairs: None,
         near_dup_exclude: Vec::new(),
+        api_doc_thresholds: Default::default(),
+        license_header: None,
+        domain_grouping: Default::default(),
+        forecast_days: None,
+        coverage: None,
+        hash_algo: tokmd_types::HashAlgo::Blake3,
+        timings: false,
+        plan: None,
+        include_sections: vec![],
+        exclude_sections: vec![],
+        progress: None,
+        extensions: Vec::new(),
     };
 
     let base_export = ExportData {
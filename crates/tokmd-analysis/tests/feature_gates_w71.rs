@@ -29,6 +29,9 @@ fn sample_row(path: &str, module: &str, lang: &str, code: usize) -> FileRow {
         lines: code + 3,
         bytes: code * 30,
         tokens: code * 5,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
@@ -57,6 +60,7 @@ fn make_source() -> AnalysisSource {
         module_roots: vec!["src".to_string()],
         module_depth: 2,
         children: "separate".to_string(),
+        token_model: "heuristic".to_string(),
     }
 }
 
@@ -82,6 +86,14 @@ fn make_req(preset: PresetKind) -> AnalysisRequest {
             max_commit_files: None,
             max_file_bytes: None,
             import_granularity: "module".to_string(),
+            oversized_file_bytes: None,
+            max_commit_message_bytes: None,
+            strip_bom: false,
+            preset_sections: None,
+            include_sections: vec![],
+            exclude_sections: vec![],
+            sample_rate: None,
+            max_runtime_ms: None,
         },
         limits: Default::default(),
         #[cfg(feature = "effort")]
@@ -89,13 +101,27 @@ fn make_req(preset: PresetKind) -> AnalysisRequest {
         window_tokens: None,
         git: None,
         import_granularity: ImportGranularity::Module,
+        tree: Default::default(),
         detail_functions: false,
+        detail_api_items: false,
         near_dup: false,
         near_dup_threshold: 0.8,
         near_dup_max_files: 500,
         near_dup_scope: NearDupScope::Module,
         near_dup_max_pairs: None,
         near_dup_exclude: vec![],
+        api_doc_thresholds: Default::default(),
+        license_header: None,
+        domain_grouping: Default::default(),
+        forecast_days: None,
+        coverage: None,
+        hash_algo: tokmd_types::HashAlgo::Blake3,
+        timings: false,
+        plan: None,
+        include_sections: vec![],
+        exclude_sections: vec![],
+        progress: None,
+        extensions: Vec::new(),
     }
 }
 
@@ -119,7 +145,7 @@ fn risk_preset_git_gate_no_silent_success() {
             receipt
                 .warnings
                 .iter()
-                .any(|w| w.contains(DisabledFeature::GitMetrics.warning())),
+                .any(|w| w.message.contains(DisabledFeature::GitMetrics.warning())),
             "must warn about disabled git: {:?}",
             receipt.warnings
         );
@@ -128,7 +154,7 @@ fn risk_preset_git_gate_no_silent_success() {
     #[cfg(feature = "git")]
     {
         let has_git = receipt.git.is_some();
-        let has_git_warning = receipt.warnings.iter().any(|w| w.contains("git"));
+        let has_git_warning = receipt.warnings.iter().any(|w| w.message.contains("git"));
         assert!(
             has_git || has_git_warning,
             "git feature compiled in but no git report and no git warning"
@@ -187,15 +213,14 @@ fn health_preset_content_gate_no_silent_success() {
             receipt
                 .warnings
                 .iter()
-                .any(|w| w.contains(DisabledFeature::TodoScan.warning())),
+                .any(|w| w.message.contains(DisabledFeature::TodoScan.warning())),
             "must warn about disabled TODO scan: {:?}",
             receipt.warnings
         );
         assert!(
-            receipt
-                .warnings
-                .iter()
-                .any(|w| w.contains(DisabledFeature::ComplexityAnalysis.warning())),
+            receipt.warnings.iter().any(|w| w
+                .message
+                .contains(DisabledFeature::ComplexityAnalysis.warning())),
             "must warn about disabled complexity: {:?}",
             receipt.warnings
         );
@@ -220,7 +245,7 @@ fn architecture_preset_content_gate() {
             receipt
                 .warnings
                 .iter()
-                .any(|w| w.contains(DisabledFeature::ImportScan.warning())),
+                .any(|w| w.message.contains(DisabledFeature::ImportScan.warning())),
             "must warn about disabled import scan"
         );
     }
@@ -255,7 +280,7 @@ fn supply_preset_walk_gate_no_silent_success() {
             receipt
                 .warnings
                 .iter()
-                .any(|w| w.contains(DisabledFeature::FileInventory.warning())),
+                .any(|w| w.message.contains(DisabledFeature::FileInventory.warning())),
             "must warn about disabled file inventory: {:?}",
             receipt.warnings
         );
@@ -21,6 +21,7 @@ fn make_context(export: ExportData) -> AnalysisContext {
             module_roots: vec!["crates".to_string()],
             module_depth: 2,
             children: "separate".to_string(),
+            token_model: "heuristic".to_string(),
         },
     }
 }
@@ -105,6 +106,7 @@ fn make_git_context(export: ExportData) -> (AnalysisContext, tempfile::TempDir)
             module_roots: vec!["(root)".to_string()],
             module_depth: 1,
             children: "separate".to_string(),
+            token_model: "heuristic".to_string(),
         },
     };
 
@@ -125,6 +127,14 @@ fn make_request(preset: AnalysisPreset) -> AnalysisRequest {
             max_commits: None,
             max_commit_files: None,
             import_granularity: "module".to_string(),
+            oversized_file_bytes: None,
+            max_commit_message_bytes: None,
+            strip_bom: false,
+            preset_sections: None,
+            include_sections: vec![],
+            exclude_sections: vec![],
+            sample_rate: None,
+            max_runtime_ms: None,
         },
         limits: AnalysisLimits::default(),
         #[cfg(feature = "effort")]
@@ -132,13 +142,27 @@ fn make_request(preset: AnalysisPreset) -> AnalysisRequest {
         window_tokens: None,
         git: None,
         import_granularity: ImportGranularity::Module,
+        tree: Default::default(),
         detail_functions: false,
+        detail_api_items: false,
         near_dup: false,
         near_dup_threshold: 0.80,
         near_dup_max_files: 1_000,
         near_dup_scope: NearDupScope::Module,
         near_dup_max_pairs: None,
         near_dup_exclude: Vec::new(),
+        api_doc_thresholds: Default::default(),
+        license_header: None,
+        domain_grouping: Default::default(),
+        forecast_days: None,
+        coverage: None,
+        hash_algo: tokmd_types::HashAlgo::Blake3,
+        timings: false,
+        plan: None,
+        include_sections: vec![],
+        exclude_sections: vec![],
+        progress: None,
+        extensions: Vec::new(),
     }
 }
 
@@ -156,6 +180,9 @@ fn export_with_paths(rows: Vec<(&str, &str, &str, usize)>) -> ExportData {
             lines,
             bytes: 1024 + lines,
             tokens: lines * 2,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         })
         .collect();
 
@@ -202,11 +229,11 @@ fn archetype_feature_is_reported_when_disabled() {
         analyze(make_context(export), make_request(AnalysisPreset::Identity)).expect("analyze");
 
     assert!(receipt.archetype.is_none());
-    assert!(
-        receipt.warnings.iter().any(|warning| {
-            warning.contains(tokmd_analysis::DisabledFeature::Archetype.warning())
-        }),
-    );
+    assert!(receipt.warnings.iter().any(|warning| {
+        warning
+            .message
+            .contains(tokmd_analysis::DisabledFeature::Archetype.warning())
+    }),);
 }
 
 #[cfg(feature = "topics")]
@@ -265,12 +292,11 @@ fn fun_feature_is_reported_when_disabled() {
     let receipt = analyze(make_context(export), request).expect("analyze");
 
     assert!(receipt.fun.is_none());
-    assert!(
-        receipt
-            .warnings
-            .iter()
-            .any(|warning| warning.contains(tokmd_analysis::DisabledFeature::Fun.warning())),
-    );
+    assert!(receipt.warnings.iter().any(|warning| {
+        warning
+            .message
+            .contains(tokmd_analysis::DisabledFeature::Fun.warning())
+    }),);
 }
 
 #[cfg(feature = "git")]
@@ -312,7 +338,9 @@ fn fingerprint_feature_is_reported_when_disabled() {
     let receipt = analyze(make_context(export), request).expect("analyze");
     assert!(receipt.corporate_fingerprint.is_none());
     assert!(receipt.warnings.iter().any(|warning| {
-        warning.contains(tokmd_analysis::DisabledFeature::GitMetrics.warning())
+        warning
+            .message
+            .contains(tokmd_analysis::DisabledFeature::GitMetrics.warning())
     }),);
 }
 
@@ -336,10 +364,9 @@ fn topics_feature_is_reported_when_disabled() {
         analyze(make_context(export), make_request(AnalysisPreset::Topics)).expect("analyze");
 
     assert!(receipt.topics.is_none());
-    assert!(
-        receipt
-            .warnings
-            .iter()
-            .any(|warning| warning.contains(tokmd_analysis::DisabledFeature::Topics.warning())),
-    );
+    assert!(receipt.warnings.iter().any(|warning| {
+        warning
+            .message
+            .contains(tokmd_analysis::DisabledFeature::Topics.warning())
+    }),);
 }
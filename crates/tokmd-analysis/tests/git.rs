@@ -87,6 +87,9 @@ mod git_tests {
                     lines: 2,
                     bytes: 20,
                     tokens: 5,
+                    last_modified_ms: None,
+                    age_days: None,
+                    role: None,
                 },
                 FileRow {
                     path: "README.md".to_string(),
@@ -99,6 +102,9 @@ mod git_tests {
                     lines: 1,
                     bytes: 8,
                     tokens: 2,
+                    last_modified_ms: None,
+                    age_days: None,
+                    role: None,
                 },
             ],
             module_roots: vec!["crates".to_string(), "packages".to_string()],
@@ -119,6 +125,7 @@ mod git_tests {
                 module_roots: vec!["crates".to_string(), "packages".to_string()],
                 module_depth: 2,
                 children: "separate".to_string(),
+                token_model: "heuristic".to_string(),
             },
         };
 
@@ -135,12 +142,22 @@ mod git_tests {
                 max_commits: None,
                 max_commit_files: None,
                 import_granularity: "module".to_string(),
+                oversized_file_bytes: None,
+                max_commit_message_bytes: None,
+                strip_bom: false,
+                preset_sections: None,
+                include_sections: vec![],
+                exclude_sections: vec![],
+                sample_rate: None,
+                max_runtime_ms: None,
             },
             limits: AnalysisLimits::default(),
             window_tokens: None,
             git: Some(true),
             import_granularity: ImportGranularity::Module,
+            tree: Default::default(),
             detail_functions: false,
+            detail_api_items: false,
             near_dup: false,
             near_dup_threshold: 0.80,
             near_dup_max_files: 2000,
@@ -149,6 +166,18 @@ mod git_tests {
             near_dup_exclude: Vec::new(),
             #[cfg(feature = "effort")]
             effort: None,
+            api_doc_thresholds: Default::default(),
+            license_header: None,
+            domain_grouping: Default::default(),
+            forecast_days: None,
+            coverage: None,
+            hash_algo: tokmd_types::HashAlgo::Blake3,
+            timings: false,
+            plan: None,
+            include_sections: vec![],
+            exclude_sections: vec![],
+            progress: None,
+            extensions: Vec::new(),
         };
 
         let receipt = analyze(ctx, request).expect("analysis");
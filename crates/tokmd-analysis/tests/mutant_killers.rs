@@ -27,6 +27,7 @@ fn make_context(export: ExportData) -> AnalysisContext {
             module_roots: vec!["crates".to_string()],
             module_depth: 2,
             children: "separate".to_string(),
+            token_model: "heuristic".to_string(),
         },
     }
 }
@@ -45,6 +46,14 @@ fn make_request(preset: AnalysisPreset) -> AnalysisRequest {
             max_commits: None,
             max_commit_files: None,
             import_granularity: "module".to_string(),
+            oversized_file_bytes: None,
+            max_commit_message_bytes: None,
+            strip_bom: false,
+            preset_sections: None,
+            include_sections: vec![],
+            exclude_sections: vec![],
+            sample_rate: None,
+            max_runtime_ms: None,
         },
         limits: AnalysisLimits::default(),
         #[cfg(feature = "effort")]
@@ -52,13 +61,27 @@ fn make_request(preset: AnalysisPreset) -> AnalysisRequest {
         window_tokens: None,
         git: None,
         import_granularity: ImportGranularity::Module,
+        tree: Default::default(),
         detail_functions: false,
+        detail_api_items: false,
         near_dup: false,
         near_dup_threshold: 0.80,
         near_dup_max_files: 2000,
         near_dup_scope: NearDupScope::Module,
         near_dup_max_pairs: None,
         near_dup_exclude: Vec::new(),
+        api_doc_thresholds: Default::default(),
+        license_header: None,
+        domain_grouping: Default::default(),
+        forecast_days: None,
+        coverage: None,
+        hash_algo: tokmd_types::HashAlgo::Blake3,
+        timings: false,
+        plan: None,
+        include_sections: vec![],
+        exclude_sections: vec![],
+        progress: None,
+        extensions: Vec::new(),
     }
 }
 
@@ -74,6 +97,9 @@ fn file_row(path: &str, module: &str, lang: &str, code: usize, lines: usize) ->
         lines,
         bytes: lines * 20,
         tokens: code * 2,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
@@ -104,6 +130,9 @@ fn cocomo_returns_none_for_zero_code() {
         lines: 15,
         bytes: 100,
         tokens: 0,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }]);
 
     let ctx = make_context(export);
@@ -350,6 +379,9 @@ fn context_window_calculates_pct_correctly() {
         lines: 100,
         bytes: 1000,
         tokens: 500, // 500 tokens
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }]);
 
     let ctx = make_context(export);
@@ -601,6 +633,9 @@ fn doc_density_calculates_ratio() {
         lines: 110,
         bytes: 1000,
         tokens: 100,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }]);
 
     let ctx = make_context(export);
@@ -674,6 +709,9 @@ mod fun_preset_tests {
             lines: 100,
             bytes: 500_000, // 500KB - should be grade A
             tokens: 200,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         }]);
 
         let ctx = make_context(export);
@@ -704,6 +742,9 @@ mod fun_preset_tests {
             lines: 100,
             bytes: 5_000_000, // 5MB - should be grade B
             tokens: 200,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         }]);
 
         let ctx = make_context(export);
@@ -734,6 +775,9 @@ mod fun_preset_tests {
             lines: 100,
             bytes: 30_000_000, // 30MB - should be grade C
             tokens: 200,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         }]);
 
         let ctx = make_context(export);
@@ -764,6 +808,9 @@ mod fun_preset_tests {
             lines: 100,
             bytes: 100_000_000, // 100MB - should be grade D
             tokens: 200,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         }]);
 
         let ctx = make_context(export);
@@ -794,6 +841,9 @@ mod fun_preset_tests {
             lines: 100,
             bytes: 300_000_000, // 300MB - should be grade E
             tokens: 200,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         }]);
 
         let ctx = make_context(export);
@@ -5,6 +5,8 @@
 //! - base_signature backfill logic
 //! - ScanStatus Complete vs Partial
 //! - Git override flag (`req.git`)
+//! - Runtime budget cutting a run short
+//! - Embedder extensions attached after analysis
 //! - Tree building via format string
 //! - Child row filtering
 //! - context_window absent when no window_tokens
@@ -12,12 +14,14 @@
 //! - Property-based invariants on `analyze`
 
 use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
 
 use proptest::prelude::*;
 use tokmd_analysis::PresetKind;
 use tokmd_analysis::{
-    AnalysisContext, AnalysisLimits, AnalysisPreset, AnalysisRequest, ImportGranularity,
-    NearDupScope, analyze,
+    AnalysisContext, AnalysisExtension, AnalysisLimits, AnalysisPreset, AnalysisProgress,
+    AnalysisRequest, ExtensionHook, ImportGranularity, NearDupScope, ProgressHook, RuntimeBudget,
+    analyze,
 };
 use tokmd_analysis_types::{ANALYSIS_SCHEMA_VERSION, AnalysisArgsMeta, AnalysisSource};
 use tokmd_types::{ChildIncludeMode, ExportData, FileKind, FileRow, ScanStatus};
@@ -35,6 +39,7 @@ fn make_source() -> AnalysisSource {
         module_roots: vec!["crates".to_string()],
         module_depth: 2,
         children: "separate".to_string(),
+        token_model: "heuristic".to_string(),
     }
 }
 
@@ -60,6 +65,14 @@ fn make_req(preset: AnalysisPreset) -> AnalysisRequest {
             max_commits: None,
             max_commit_files: None,
             import_granularity: "module".to_string(),
+            oversized_file_bytes: None,
+            max_commit_message_bytes: None,
+            strip_bom: false,
+            preset_sections: None,
+            include_sections: vec![],
+            exclude_sections: vec![],
+            sample_rate: None,
+            max_runtime_ms: None,
         },
         limits: AnalysisLimits::default(),
         #[cfg(feature = "effort")]
@@ -67,13 +80,27 @@ fn make_req(preset: AnalysisPreset) -> AnalysisRequest {
         window_tokens: None,
         git: Some(false),
         import_granularity: ImportGranularity::Module,
+        tree: Default::default(),
         detail_functions: false,
+        detail_api_items: false,
         near_dup: false,
         near_dup_threshold: 0.80,
         near_dup_max_files: 2000,
         near_dup_scope: NearDupScope::Module,
         near_dup_max_pairs: None,
         near_dup_exclude: Vec::new(),
+        api_doc_thresholds: Default::default(),
+        license_header: None,
+        domain_grouping: Default::default(),
+        forecast_days: None,
+        coverage: None,
+        hash_algo: tokmd_types::HashAlgo::Blake3,
+        timings: false,
+        plan: None,
+        include_sections: vec![],
+        exclude_sections: vec![],
+        progress: None,
+        extensions: Vec::new(),
     }
 }
 
@@ -89,6 +116,9 @@ fn row(path: &str, module: &str, lang: &str, code: usize) -> FileRow {
         lines: code + code / 5 + code / 10,
         bytes: code * 10,
         tokens: code * 2,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
@@ -104,6 +134,9 @@ fn child_row(path: &str, module: &str, lang: &str, code: usize) -> FileRow {
         lines: code,
         bytes: 0,
         tokens: 0,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
@@ -289,6 +322,317 @@ fn tree_absent_when_format_is_json() {
     assert!(derived.tree.is_none(), "tree should not be built for json");
 }
 
+// ═══════════════════════════════════════════════════════════════════════════
+// Scenario: Phase timings
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn timings_absent_unless_requested() {
+    let receipt = analyze(make_ctx(sample_export()), make_req(AnalysisPreset::Receipt)).unwrap();
+    assert!(receipt.timings.is_none());
+}
+
+#[cfg(feature = "fun")]
+#[test]
+fn timings_record_only_phases_that_ran() {
+    // Given: the Fun preset with git disabled and timings requested
+    // When: analyze runs
+    // Then: derived and fun are timed; the skipped git and dup phases are not
+    let mut req = make_req(AnalysisPreset::Fun);
+    req.timings = true;
+
+    let receipt = analyze(make_ctx(sample_export()), req).unwrap();
+    let timings = receipt.timings.expect("timings requested");
+    assert!(timings.contains_key("derived"));
+    assert!(timings.contains_key("fun"));
+    assert!(!timings.contains_key("git"));
+    assert!(!timings.contains_key("dup"));
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Scenario: Deterministic sampling
+// ═══════════════════════════════════════════════════════════════════════════
+
+fn wide_export(files: usize) -> ExportData {
+    ExportData {
+        rows: (0..files)
+            .map(|i| row(&format!("src/file_{i}.rs"), "src", "Rust", 10))
+            .collect(),
+        module_roots: vec![],
+        module_depth: 1,
+        children: ChildIncludeMode::Separate,
+    }
+}
+
+#[test]
+fn half_sample_analyzes_about_half_the_files_and_records_the_rate() {
+    // Given: 200 files and a 50% sample
+    let mut req = make_req(AnalysisPreset::Receipt);
+    req.git = Some(false);
+    req.limits.sample_rate = Some(0.5);
+
+    // When: analyze runs twice
+    let first = analyze(make_ctx(wide_export(200)), req.clone()).unwrap();
+    let second = analyze(make_ctx(wide_export(200)), req).unwrap();
+
+    // Then: about half the files are kept, the same ones each time
+    let sampling = first.sampling.expect("sampled receipt");
+    assert!(sampling.sampled);
+    assert_eq!(sampling.rate, 0.5);
+    assert_eq!(sampling.total_files, 200);
+    assert!(
+        (70..=130).contains(&sampling.sampled_files),
+        "{}",
+        sampling.sampled_files
+    );
+    let derived = first.derived.expect("derived");
+    assert_eq!(derived.totals.files, sampling.sampled_files);
+    assert_eq!(sampling.estimated_totals.files, sampling.sampled_files * 2);
+    assert_eq!(sampling.estimated_totals.code, derived.totals.code * 2);
+    assert_eq!(
+        second.sampling.expect("sampled receipt").sampled_files,
+        sampling.sampled_files
+    );
+}
+
+#[test]
+fn unsampled_receipt_has_no_sampling_section() {
+    let receipt = analyze(make_ctx(sample_export()), make_req(AnalysisPreset::Receipt)).unwrap();
+    assert!(receipt.sampling.is_none());
+}
+
+#[test]
+fn out_of_range_sample_rate_is_rejected() {
+    let mut req = make_req(AnalysisPreset::Receipt);
+    req.limits.sample_rate = Some(0.0);
+    let err = analyze(make_ctx(sample_export()), req).unwrap_err();
+    assert!(err.to_string().contains("sample rate"), "{err}");
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Scenario: Runtime budget
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[cfg(feature = "fun")]
+#[test]
+fn spent_runtime_budget_yields_partial_receipt_with_derived_intact() {
+    // Given: the Fun preset and a budget that is already spent
+    let mut req = make_req(AnalysisPreset::Fun);
+    req.limits.runtime_budget = Some(RuntimeBudget::start(0));
+    let unbudgeted = analyze(make_ctx(sample_export()), make_req(AnalysisPreset::Fun)).unwrap();
+
+    // When: analyze runs
+    let receipt = analyze(make_ctx(sample_export()), req).unwrap();
+
+    // Then: the receipt is partial, fun was skipped and says so, and the
+    // derived report that ran first matches an unbudgeted run
+    assert_eq!(receipt.status, ScanStatus::Partial);
+    assert!(receipt.fun.is_none());
+    let warning = receipt
+        .warnings
+        .iter()
+        .find(|warning| warning.code == tokmd_types::RUNTIME_BUDGET_EXCEEDED)
+        .expect("runtime budget warning");
+    assert!(
+        warning.message.contains("skipped fun"),
+        "{}",
+        warning.message
+    );
+    let derived = receipt.derived.expect("derived");
+    let expected = unbudgeted.derived.expect("derived");
+    assert_eq!(derived.totals.files, expected.totals.files);
+    assert_eq!(derived.totals.code, expected.totals.code);
+    assert_eq!(derived.integrity.hash, expected.integrity.hash);
+}
+
+#[test]
+fn ample_runtime_budget_leaves_receipt_complete() {
+    let mut req = make_req(AnalysisPreset::Receipt);
+    req.limits.runtime_budget = Some(RuntimeBudget::start(3_600_000));
+    let receipt = analyze(make_ctx(sample_export()), req).unwrap();
+    assert_eq!(receipt.status, ScanStatus::Complete);
+    assert!(receipt.warnings.is_empty());
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Scenario: Embedder extensions
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Counts Rust files in the export and echoes the receipt's preset.
+struct RustFileCount;
+
+impl AnalysisExtension for RustFileCount {
+    fn name(&self) -> &str {
+        "rust_files"
+    }
+
+    fn compute(
+        &self,
+        export: &ExportData,
+        receipt: &tokmd_analysis_types::AnalysisReceipt,
+    ) -> anyhow::Result<serde_json::Value> {
+        let count = export.rows.iter().filter(|row| row.lang == "Rust").count();
+        Ok(serde_json::json!({ "count": count, "preset": receipt.args.preset }))
+    }
+}
+
+struct Failing;
+
+impl AnalysisExtension for Failing {
+    fn name(&self) -> &str {
+        "failing"
+    }
+
+    fn compute(
+        &self,
+        _export: &ExportData,
+        _receipt: &tokmd_analysis_types::AnalysisReceipt,
+    ) -> anyhow::Result<serde_json::Value> {
+        anyhow::bail!("no data")
+    }
+}
+
+#[test]
+fn registered_extension_appears_in_json_and_markdown() {
+    // Given: a hook counting Rust files
+    let mut req = make_req(AnalysisPreset::Receipt);
+    req.extensions = vec![ExtensionHook::new(RustFileCount)];
+
+    // When: analyze runs and the receipt is rendered
+    let receipt = analyze(make_ctx(sample_export()), req).unwrap();
+    let rust_files = sample_export()
+        .rows
+        .iter()
+        .filter(|row| row.lang == "Rust")
+        .count();
+
+    // Then: the value is serialized under `extensions` and listed in Markdown
+    assert_eq!(receipt.status, ScanStatus::Complete);
+    let json = serde_json::to_value(&receipt).unwrap();
+    assert_eq!(json["extensions"]["rust_files"]["count"], rust_files);
+    assert_eq!(json["extensions"]["rust_files"]["preset"], "receipt");
+    let md =
+        match tokmd_format::analysis::render(&receipt, tokmd_types::AnalysisFormat::Md).unwrap() {
+            tokmd_format::analysis::RenderedOutput::Text(md) => md,
+            tokmd_format::analysis::RenderedOutput::Binary(_) => panic!("markdown is text"),
+        };
+    assert!(md.contains("## Extensions\n\n### rust_files\n"), "{md}");
+    assert!(md.contains(&format!("\"count\": {rust_files}")), "{md}");
+}
+
+#[test]
+fn failing_extension_becomes_a_warning_and_later_ones_still_run() {
+    let mut req = make_req(AnalysisPreset::Receipt);
+    req.extensions = vec![
+        ExtensionHook::new(Failing),
+        ExtensionHook::new(RustFileCount),
+    ];
+    let receipt = analyze(make_ctx(sample_export()), req).unwrap();
+    assert_eq!(receipt.status, ScanStatus::Partial);
+    assert!(!receipt.extensions.contains_key("failing"));
+    assert!(receipt.extensions.contains_key("rust_files"));
+    assert!(
+        receipt
+            .warnings
+            .iter()
+            .any(|warning| warning.code == tokmd_types::ENRICHER_FAILED
+                && warning.message == "extension failing failed: no data")
+    );
+}
+
+#[test]
+fn receipt_without_extensions_omits_the_key() {
+    let receipt = analyze(make_ctx(sample_export()), make_req(AnalysisPreset::Receipt)).unwrap();
+    let json = serde_json::to_value(&receipt).unwrap();
+    assert!(json.get("extensions").is_none());
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Scenario: Progress reporting
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn progress_hook_reports_each_phase_with_increasing_counts() {
+    // Given: a progress hook collecting every report
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let sink = Arc::clone(&seen);
+    let mut req = make_req(AnalysisPreset::Receipt);
+    req.progress = Some(ProgressHook::new(move |progress: &AnalysisProgress| {
+        sink.lock().unwrap().push(*progress);
+    }));
+
+    // When: analyze runs
+    analyze(make_ctx(sample_export()), req).unwrap();
+
+    // Then: derived reports first and the count climbs by one per phase
+    let seen = seen.lock().unwrap();
+    assert_eq!(seen.first().map(|progress| progress.phase), Some("derived"));
+    assert!(
+        seen.windows(2)
+            .all(|pair| pair[1].completed == pair[0].completed + 1)
+    );
+    assert!(
+        seen.iter()
+            .all(|progress| progress.completed <= progress.total)
+    );
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Scenario: Section include/exclude filters
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[cfg(all(feature = "content", feature = "walk"))]
+#[test]
+fn excluding_dup_skips_duplicate_detection() {
+    // Given: two identical files and the Receipt preset, which computes dup
+    let tmp = tempfile::tempdir().unwrap();
+    std::fs::write(tmp.path().join("a.rs"), "fn main() {}\n").unwrap();
+    std::fs::write(tmp.path().join("b.rs"), "fn main() {}\n").unwrap();
+    let ctx = || AnalysisContext {
+        export: sample_export(),
+        root: tmp.path().to_path_buf(),
+        source: make_source(),
+    };
+    let mut req = make_req(AnalysisPreset::Receipt);
+    req.timings = true;
+    assert!(analyze(ctx(), req.clone()).unwrap().dup.is_some());
+
+    // When: dup is excluded
+    req.exclude_sections = vec!["dup".to_string()];
+    let receipt = analyze(ctx(), req).unwrap();
+
+    // Then: duplicate detection never ran and the field is absent
+    assert!(receipt.dup.is_none());
+    let timings = receipt.timings.expect("timings requested");
+    assert!(!timings.contains_key("dup"));
+}
+
+#[test]
+fn include_sections_narrow_the_preset() {
+    // Given: the Deep preset narrowed to topics
+    let mut req = make_req(AnalysisPreset::Deep);
+    req.include_sections = vec!["topics".to_string()];
+
+    // When: analyze runs
+    let receipt = analyze(make_ctx(sample_export()), req).unwrap();
+
+    // Then: only the included section is computed
+    assert!(receipt.dup.is_none());
+    assert!(receipt.complexity.is_none());
+    assert!(receipt.archetype.is_none());
+    assert!(receipt.git.is_none());
+    #[cfg(feature = "topics")]
+    assert!(receipt.topics.is_some());
+}
+
+#[test]
+fn unknown_section_names_are_rejected() {
+    let mut req = make_req(AnalysisPreset::Receipt);
+    req.exclude_sections = vec!["secrets".to_string()];
+    let err = analyze(make_ctx(sample_export()), req).unwrap_err();
+    assert!(err.to_string().contains("'secrets'"), "{err}");
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // Scenario: Child row exclusion from derived totals
 // ═══════════════════════════════════════════════════════════════════════════
@@ -626,6 +970,9 @@ fn context_window_does_not_fit_when_tokens_exceed_window() {
             lines: 1000,
             bytes: 10000,
             tokens: 5000,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         }],
         module_roots: vec![],
         module_depth: 1,
@@ -746,6 +1093,9 @@ fn arb_file_row() -> impl Strategy<Value = FileRow> {
         lines: code + comments + blanks,
         bytes: (code + comments + blanks) * 10,
         tokens: code * 2,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     })
 }
 
@@ -771,6 +1121,9 @@ proptest! {
                 lines,
                 bytes: lines * 10,
                 tokens: code * 2,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             }],
             module_roots: vec![],
             module_depth: 1,
@@ -815,6 +1168,9 @@ proptest! {
                 lines: code,
                 bytes: code * 10,
                 tokens: code * 2,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             }],
             module_roots: vec![],
             module_depth: 1,
@@ -853,6 +1209,9 @@ proptest! {
                 lines: code,
                 bytes: code * 10,
                 tokens: code * 2,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             }],
             module_roots: vec![],
             module_depth: 1,
@@ -892,6 +1251,9 @@ proptest! {
                 lines: code + comments,
                 bytes: (code + comments) * 10,
                 tokens: code * 2,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             }],
             module_roots: vec![],
             module_depth: 1,
@@ -1191,6 +1553,9 @@ fn zero_code_files_produce_valid_receipt() {
             lines: 0,
             bytes: 0,
             tokens: 0,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         }],
         module_roots: vec![],
         module_depth: 1,
@@ -38,6 +38,9 @@ fn minimal_export() -> ExportData {
             lines: 130,
             bytes: 3_200,
             tokens: 800,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         }],
         module_roots: vec!["src".to_string()],
         module_depth: 1,
@@ -59,6 +62,9 @@ fn multi_lang_export() -> ExportData {
                 lines: 260,
                 bytes: 6_400,
                 tokens: 1_600,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             },
             FileRow {
                 path: "src/util.py".to_string(),
@@ -71,6 +77,9 @@ fn multi_lang_export() -> ExportData {
                 lines: 95,
                 bytes: 2_000,
                 tokens: 500,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             },
             FileRow {
                 path: "tests/test_main.rs".to_string(),
@@ -83,6 +92,9 @@ fn multi_lang_export() -> ExportData {
                 lines: 60,
                 bytes: 1_200,
                 tokens: 300,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             },
         ],
         module_roots: vec!["src".to_string(), "tests".to_string()],
@@ -102,6 +114,7 @@ fn default_source() -> AnalysisSource {
         module_roots: vec![],
         module_depth: 1,
         children: "parents_only".to_string(),
+        token_model: "heuristic".to_string(),
     }
 }
 
@@ -117,6 +130,14 @@ fn default_args(preset: &str) -> AnalysisArgsMeta {
         max_commit_files: None,
         max_file_bytes: None,
         import_granularity: "module".to_string(),
+        oversized_file_bytes: None,
+        max_commit_message_bytes: None,
+        strip_bom: false,
+        preset_sections: None,
+        include_sections: vec![],
+        exclude_sections: vec![],
+        sample_rate: None,
+        max_runtime_ms: None,
     }
 }
 
@@ -130,13 +151,27 @@ fn make_request(preset: AnalysisPreset) -> AnalysisRequest {
         window_tokens: None,
         git: Some(false),
         import_granularity: ImportGranularity::Module,
+        tree: Default::default(),
         detail_functions: false,
+        detail_api_items: false,
         near_dup: false,
         near_dup_threshold: 0.8,
         near_dup_max_files: 500,
         near_dup_scope: tokmd_analysis::NearDupScope::Module,
         near_dup_max_pairs: None,
         near_dup_exclude: vec![],
+        api_doc_thresholds: Default::default(),
+        license_header: None,
+        domain_grouping: Default::default(),
+        forecast_days: None,
+        coverage: None,
+        hash_algo: tokmd_types::HashAlgo::Blake3,
+        timings: false,
+        plan: None,
+        include_sections: vec![],
+        exclude_sections: vec![],
+        progress: None,
+        extensions: Vec::new(),
     }
 }
 
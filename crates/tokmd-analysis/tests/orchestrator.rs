@@ -23,6 +23,9 @@ fn sample_file_row(path: &str, lang: &str, code: usize) -> FileRow {
         lines: code + code / 5 + code / 10,
         bytes: code * 40,
         tokens: code * 3,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
@@ -51,6 +54,7 @@ fn sample_source() -> AnalysisSource {
         module_roots: vec![],
         module_depth: 1,
         children: "separate".to_string(),
+        token_model: "heuristic".to_string(),
     }
 }
 
@@ -66,6 +70,14 @@ fn sample_args(preset: &str) -> AnalysisArgsMeta {
         max_commit_files: None,
         max_file_bytes: None,
         import_granularity: "module".to_string(),
+        oversized_file_bytes: None,
+        max_commit_message_bytes: None,
+        strip_bom: false,
+        preset_sections: None,
+        include_sections: vec![],
+        exclude_sections: vec![],
+        sample_rate: None,
+        max_runtime_ms: None,
     }
 }
 
@@ -79,13 +91,27 @@ fn sample_request(preset: AnalysisPreset) -> AnalysisRequest {
         window_tokens: None,
         git: Some(false), // disable git for unit tests (no repo)
         import_granularity: ImportGranularity::Module,
+        tree: Default::default(),
         detail_functions: false,
+        detail_api_items: false,
         near_dup: false,
         near_dup_threshold: 0.8,
         near_dup_max_files: 500,
         near_dup_scope: NearDupScope::default(),
         near_dup_max_pairs: None,
         near_dup_exclude: vec![],
+        api_doc_thresholds: Default::default(),
+        license_header: None,
+        domain_grouping: Default::default(),
+        forecast_days: None,
+        coverage: None,
+        hash_algo: tokmd_types::HashAlgo::Blake3,
+        timings: false,
+        plan: None,
+        include_sections: vec![],
+        exclude_sections: vec![],
+        progress: None,
+        extensions: Vec::new(),
     }
 }
 
@@ -164,6 +190,32 @@ fn derived_report_has_totals_and_integrity() {
     );
 }
 
+// ── Scenario: Oversized files are listed but excluded from totals ──────
+
+#[test]
+fn oversized_file_is_excluded_from_totals_but_listed() {
+    let mut req = sample_request(AnalysisPreset::Receipt);
+    // src/main.rs is 8000 bytes; every other file is at most 6000.
+    req.limits.oversized_file_bytes = Some(7_000);
+    let export = sample_export();
+    let ctx = AnalysisContext {
+        export: export.clone(),
+        root: PathBuf::from("."),
+        source: sample_source(),
+    };
+    let receipt = analyze(ctx, req).expect("analyze should not fail");
+    let derived = receipt.derived.as_ref().expect("derived should exist");
+
+    assert_eq!(derived.totals.files, 3);
+    assert_eq!(derived.totals.code, 150 + 80 + 60);
+    assert_eq!(derived.oversized_files.len(), 1);
+    assert_eq!(derived.oversized_files[0].path, "src/main.rs");
+    assert_eq!(derived.oversized_files[0].bytes, 8_000);
+    // Integrity still covers the export as given.
+    assert_eq!(derived.integrity.entries, 4);
+    assert!(tokmd_analysis::verify_integrity(&receipt, &export).is_ok());
+}
+
 // ── Scenario: Git disabled via flag produces no git report ─────────────
 
 #[test]
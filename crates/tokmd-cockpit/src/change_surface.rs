@@ -2,72 +2,48 @@
 
 use std::path::Path;
 
-use anyhow::{Context, Result, bail};
+use anyhow::Result;
 use tokmd_types::cockpit::ChangeSurface;
 
 use crate::FileStat;
+use crate::host::{GitProvider, HostGit};
 
 /// Get file stats for changed files.
+///
+/// One batched `git diff --numstat` covers every changed file.
 pub fn get_file_stats(
     repo_root: &Path,
     base: &str,
     head: &str,
     range_mode: tokmd_git::GitRangeMode,
 ) -> Result<Vec<FileStat>> {
-    let range = range_mode.format(base, head);
-    let output = tokmd_git::git_cmd()
-        .arg("-C")
-        .arg(repo_root)
-        .args(["diff", "--numstat", &range])
-        .output()
-        .context("Failed to run git diff --numstat")?;
-
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        bail!("git diff --numstat failed: {}", stderr.trim());
-    }
-
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    let mut stats = Vec::new();
-
-    for line in stdout.lines() {
-        let parts: Vec<&str> = line.split('\t').collect();
-        if parts.len() == 3 {
-            let insertions = parts[0].parse().unwrap_or(0);
-            let deletions = parts[1].parse().unwrap_or(0);
-            let path = parts[2].to_string();
-            stats.push(FileStat {
-                path,
-                insertions,
-                deletions,
-            });
-        }
-    }
+    collect_file_stats(&HostGit, repo_root, base, head, range_mode)
+}
 
-    Ok(stats)
+/// [`get_file_stats`] against any git backend.
+pub(crate) fn collect_file_stats(
+    git: &dyn GitProvider,
+    repo_root: &Path,
+    base: &str,
+    head: &str,
+    range_mode: tokmd_git::GitRangeMode,
+) -> Result<Vec<FileStat>> {
+    git.diff_numstat(repo_root, &range_mode.format(base, head))
 }
 
-/// Compute change surface metrics.
-pub(crate) fn compute_change_surface(
+/// Count the commits between `base` and `head`.
+pub(crate) fn count_commits(
+    git: &dyn GitProvider,
     repo_root: &Path,
     base: &str,
     head: &str,
-    file_stats: &[FileStat],
     range_mode: tokmd_git::GitRangeMode,
-) -> Result<ChangeSurface> {
-    let range = range_mode.format(base, head);
-    let output = tokmd_git::git_cmd()
-        .arg("-C")
-        .arg(repo_root)
-        .args(["rev-list", "--count", &range])
-        .output()
-        .context("Failed to run git rev-list --count")?;
-
-    let commits = String::from_utf8_lossy(&output.stdout)
-        .trim()
-        .parse()
-        .unwrap_or(0);
+) -> Result<usize> {
+    git.commit_count(repo_root, &range_mode.format(base, head))
+}
 
+/// Compute change surface metrics.
+pub(crate) fn compute_change_surface(commits: usize, file_stats: &[FileStat]) -> ChangeSurface {
     let files_changed = file_stats.len();
     let insertions = file_stats.iter().map(|s| s.insertions).sum();
     let deletions = file_stats.iter().map(|s| s.deletions).sum();
@@ -96,7 +72,7 @@ pub(crate) fn compute_change_surface(
         0.0
     };
 
-    Ok(ChangeSurface {
+    ChangeSurface {
         commits,
         files_changed,
         insertions,
@@ -104,7 +80,7 @@ pub(crate) fn compute_change_surface(
         net_lines,
         churn_velocity,
         change_concentration,
-    })
+    }
 }
 
 #[cfg(test)]
@@ -166,13 +142,16 @@ mod tests {
     fn compute_change_surface_empty_stats() {
         let dir = init_repo_with_two_commits(&[("src/lib.rs", "fn a() {}\n", "fn a() {}\n")]);
         let surface = compute_change_surface(
-            dir.path(),
-            "HEAD~1",
-            "HEAD",
+            count_commits(
+                &HostGit,
+                dir.path(),
+                "HEAD~1",
+                "HEAD",
+                tokmd_git::GitRangeMode::TwoDot,
+            )
+            .unwrap(),
             &[],
-            tokmd_git::GitRangeMode::TwoDot,
-        )
-        .unwrap();
+        );
 
         assert_eq!(surface.files_changed, 0);
         assert_eq!(surface.insertions, 0);
@@ -190,13 +169,16 @@ mod tests {
         let stats = vec![make_stat("src/lib.rs", 10, 4)];
 
         let surface = compute_change_surface(
-            dir.path(),
-            "HEAD~1",
-            "HEAD",
+            count_commits(
+                &HostGit,
+                dir.path(),
+                "HEAD~1",
+                "HEAD",
+                tokmd_git::GitRangeMode::TwoDot,
+            )
+            .unwrap(),
             &stats,
-            tokmd_git::GitRangeMode::TwoDot,
-        )
-        .unwrap();
+        );
 
         assert_eq!(surface.commits, 1, "HEAD~1..HEAD spans one commit");
         assert_eq!(surface.files_changed, 1);
@@ -215,13 +197,16 @@ mod tests {
         let stats = vec![make_stat("src/lib.rs", 3, 20)];
 
         let surface = compute_change_surface(
-            dir.path(),
-            "HEAD~1",
-            "HEAD",
+            count_commits(
+                &HostGit,
+                dir.path(),
+                "HEAD~1",
+                "HEAD",
+                tokmd_git::GitRangeMode::TwoDot,
+            )
+            .unwrap(),
             &stats,
-            tokmd_git::GitRangeMode::TwoDot,
-        )
-        .unwrap();
+        );
 
         assert_eq!(surface.insertions, 3);
         assert_eq!(surface.deletions, 20);
@@ -242,13 +227,16 @@ mod tests {
         ];
 
         let surface = compute_change_surface(
-            dir.path(),
-            "HEAD~1",
-            "HEAD",
+            count_commits(
+                &HostGit,
+                dir.path(),
+                "HEAD~1",
+                "HEAD",
+                tokmd_git::GitRangeMode::TwoDot,
+            )
+            .unwrap(),
             &stats,
-            tokmd_git::GitRangeMode::TwoDot,
-        )
-        .unwrap();
+        );
 
         assert_eq!(surface.files_changed, 5);
         let expected = 50.0_f64 / 70.0;
@@ -268,13 +256,16 @@ mod tests {
         let stats = vec![make_stat("a.rs", 0, 0), make_stat("b.rs", 0, 0)];
 
         let surface = compute_change_surface(
-            dir.path(),
-            "HEAD~1",
-            "HEAD",
+            count_commits(
+                &HostGit,
+                dir.path(),
+                "HEAD~1",
+                "HEAD",
+                tokmd_git::GitRangeMode::TwoDot,
+            )
+            .unwrap(),
             &stats,
-            tokmd_git::GitRangeMode::TwoDot,
-        )
-        .unwrap();
+        );
 
         assert_eq!(surface.files_changed, 2);
         assert_eq!(surface.insertions, 0);
@@ -291,13 +282,16 @@ mod tests {
         let stats = vec![make_stat("src/lib.rs", 10, 5)];
 
         let surface = compute_change_surface(
-            dir.path(),
-            "HEAD",
-            "HEAD",
+            count_commits(
+                &HostGit,
+                dir.path(),
+                "HEAD",
+                "HEAD",
+                tokmd_git::GitRangeMode::TwoDot,
+            )
+            .unwrap(),
             &stats,
-            tokmd_git::GitRangeMode::TwoDot,
-        )
-        .unwrap();
+        );
 
         assert_eq!(surface.commits, 0);
         assert_eq!(surface.insertions, 10);
@@ -375,13 +369,16 @@ mod tests {
         ];
 
         let surface = compute_change_surface(
-            dir.path(),
-            "HEAD~1",
-            "HEAD",
+            count_commits(
+                &HostGit,
+                dir.path(),
+                "HEAD~1",
+                "HEAD",
+                tokmd_git::GitRangeMode::TwoDot,
+            )
+            .unwrap(),
             &stats,
-            tokmd_git::GitRangeMode::TwoDot,
-        )
-        .unwrap();
+        );
 
         let expected = 100.0_f64 / 102.0;
         assert!(
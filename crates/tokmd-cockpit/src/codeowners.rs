@@ -0,0 +1,250 @@
+//! CODEOWNERS parsing and ownership annotation for cockpit receipts.
+//!
+//! Follows GitHub's rules: the last matching line wins, a pattern without a
+//! slash matches at any depth, a leading slash or an inner slash anchors it to
+//! the repository root, and a pattern naming a directory owns everything
+//! beneath it. A matching line with no owners leaves the file unowned.
+
+use std::collections::BTreeSet;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
+use tokmd_types::cockpit::{ReviewItem, Risk};
+
+/// Where GitHub looks for a CODEOWNERS file, in lookup order.
+pub const CODEOWNERS_LOCATIONS: [&str; 3] = [".github/CODEOWNERS", "CODEOWNERS", "docs/CODEOWNERS"];
+
+/// Risk points added for each owner beyond the first that a change touches.
+const RISK_PER_EXTRA_OWNER: u32 = 5;
+
+/// Parsed CODEOWNERS rules.
+#[derive(Debug, Clone, Default)]
+pub struct CodeOwners {
+    rules: Vec<Rule>,
+}
+
+#[derive(Debug, Clone)]
+struct Rule {
+    matcher: GlobSet,
+    owners: Vec<String>,
+}
+
+impl CodeOwners {
+    /// Parse CODEOWNERS content. Lines whose pattern is not a valid glob are
+    /// skipped, as GitHub does.
+    pub fn parse(content: &str) -> Self {
+        let rules = content
+            .lines()
+            .filter_map(|line| {
+                let line = line.trim();
+                if line.is_empty() || line.starts_with('#') {
+                    return None;
+                }
+                let mut fields = line.split_whitespace();
+                let matcher = pattern_matcher(fields.next()?)?;
+                let owners = fields
+                    .take_while(|field| !field.starts_with('#'))
+                    .map(str::to_string)
+                    .collect();
+                Some(Rule { matcher, owners })
+            })
+            .collect();
+        Self { rules }
+    }
+
+    /// Load the first CODEOWNERS file found under `repo_root`, if any.
+    pub fn discover(repo_root: &Path) -> Result<Option<Self>> {
+        for location in CODEOWNERS_LOCATIONS {
+            let path = repo_root.join(location);
+            if path.is_file() {
+                let content = std::fs::read_to_string(&path)
+                    .with_context(|| format!("Failed to read {}", path.display()))?;
+                return Ok(Some(Self::parse(&content)));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Owners of `path` (repo-relative); empty when the file is unowned.
+    pub fn owners_of(&self, path: &str) -> Vec<String> {
+        let path = path.replace('\\', "/");
+        let path = path.trim_start_matches("./").trim_start_matches('/');
+        self.rules
+            .iter()
+            .rev()
+            .find(|rule| rule.matcher.is_match(path))
+            .map(|rule| rule.owners.clone())
+            .unwrap_or_default()
+    }
+}
+
+/// Turn one CODEOWNERS pattern into the globs it stands for: the path itself
+/// and everything below it. A wildcard in the last segment (`docs/*`) matches
+/// direct children only.
+fn pattern_matcher(pattern: &str) -> Option<GlobSet> {
+    let dir_only = pattern.ends_with('/');
+    let trimmed = pattern.trim_start_matches('/').trim_end_matches('/');
+    if trimmed.is_empty() {
+        return None;
+    }
+    let anchored = pattern.starts_with('/') || trimmed.contains('/');
+    let base = if anchored {
+        trimmed.to_string()
+    } else {
+        format!("**/{trimmed}")
+    };
+
+    let last_segment = trimmed.rsplit('/').next().unwrap_or(trimmed);
+    let mut globs = Vec::new();
+    if dir_only || !last_segment.contains('*') {
+        globs.push(format!("{base}/**"));
+    }
+    if !dir_only {
+        globs.push(base);
+    }
+    let mut builder = GlobSetBuilder::new();
+    for glob in globs {
+        builder.add(
+            GlobBuilder::new(&glob)
+                .literal_separator(true)
+                .build()
+                .ok()?,
+        );
+    }
+    builder.build().ok()
+}
+
+/// Annotate review items and risk with CODEOWNERS ownership.
+///
+/// Every review item gets its owners (an empty list marks it unowned), risk
+/// gains the owners of each touched hotspot, the unowned changed files, and
+/// the number of distinct owners the change touches. Each owner beyond the
+/// first adds to the risk score, since the change needs more reviewers to
+/// agree.
+pub fn annotate_owners(owners: &CodeOwners, review_plan: &mut [ReviewItem], risk: &mut Risk) {
+    let mut touched = BTreeSet::new();
+    let mut unowned = Vec::new();
+    for item in review_plan.iter_mut() {
+        let item_owners = owners.owners_of(&item.path);
+        if item_owners.is_empty() {
+            unowned.push(item.path.clone());
+        }
+        touched.extend(item_owners.iter().cloned());
+        item.owners = Some(item_owners);
+    }
+    unowned.sort();
+
+    risk.hotspot_owners = risk
+        .hotspots_touched
+        .iter()
+        .map(|path| (path.clone(), owners.owners_of(path)))
+        .collect();
+    risk.unowned_files = unowned;
+    risk.owners_touched = Some(touched.len());
+
+    let extra = (touched.len() as u32).saturating_sub(1);
+    risk.score = risk
+        .score
+        .saturating_add(extra.saturating_mul(RISK_PER_EXTRA_OWNER))
+        .min(100);
+    risk.level = crate::risk::risk_level(risk.score);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+    use tokmd_types::cockpit::RiskLevel;
+
+    const CODEOWNERS: &str = "\
+# Default owners
+*                       @org/maintainers
+*.md                    @org/docs
+/crates/                @org/rust
+crates/tokmd-cockpit/   @alice @bob   # review tooling
+docs/generated/
+";
+
+    #[test]
+    fn nested_path_matches_the_last_matching_rule() {
+        let owners = CodeOwners::parse(CODEOWNERS);
+
+        assert_eq!(
+            owners.owners_of("crates/tokmd-cockpit/src/render/markdown/risk.rs"),
+            vec!["@alice", "@bob"]
+        );
+        assert_eq!(
+            owners.owners_of("crates/tokmd-git/src/lib.rs"),
+            vec!["@org/rust"]
+        );
+        assert_eq!(owners.owners_of("docs/guide/intro.md"), vec!["@org/docs"]);
+        assert_eq!(owners.owners_of("build.rs"), vec!["@org/maintainers"]);
+    }
+
+    #[test]
+    fn rule_without_owners_leaves_file_unowned() {
+        let owners = CodeOwners::parse(CODEOWNERS);
+        assert!(owners.owners_of("docs/generated/api.json").is_empty());
+        assert!(
+            CodeOwners::parse("/src/ @core\n")
+                .owners_of("lib/a.rs")
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn anchoring_and_wildcards_follow_github_rules() {
+        let owners = CodeOwners::parse("/build/ @ci\ndocs/* @docs\nlogs @ops\n");
+
+        assert_eq!(owners.owners_of("build/out.txt"), vec!["@ci"]);
+        assert!(owners.owners_of("src/build/out.txt").is_empty());
+        assert_eq!(owners.owners_of("docs/a.md"), vec!["@docs"]);
+        assert!(owners.owners_of("docs/nested/a.md").is_empty());
+        assert_eq!(owners.owners_of("deep/logs/today.log"), vec!["@ops"]);
+    }
+
+    fn item(path: &str) -> ReviewItem {
+        ReviewItem {
+            path: path.to_string(),
+            reason: String::new(),
+            priority: 3,
+            complexity: None,
+            lines_changed: None,
+            review_minutes: None,
+            owners: None,
+        }
+    }
+
+    #[test]
+    fn annotation_flags_unowned_files_and_counts_owners() {
+        let owners = CodeOwners::parse("/crates/a/ @a\n/crates/b/ @b @shared\n");
+        let mut plan = vec![
+            item("crates/a/lib.rs"),
+            item("crates/b/lib.rs"),
+            item("scripts/release.sh"),
+        ];
+        let mut risk = Risk {
+            hotspots_touched: vec!["crates/b/lib.rs".to_string()],
+            bus_factor_warnings: vec![],
+            level: RiskLevel::Low,
+            score: 15,
+            owners_touched: None,
+            unowned_files: vec![],
+            hotspot_owners: BTreeMap::new(),
+        };
+
+        annotate_owners(&owners, &mut plan, &mut risk);
+
+        assert_eq!(plan[0].owners, Some(vec!["@a".to_string()]));
+        assert_eq!(plan[2].owners, Some(vec![]));
+        assert_eq!(risk.unowned_files, vec!["scripts/release.sh"]);
+        assert_eq!(
+            risk.hotspot_owners["crates/b/lib.rs"],
+            vec!["@b", "@shared"]
+        );
+        assert_eq!(risk.owners_touched, Some(3));
+        assert_eq!(risk.score, 25);
+        assert_eq!(risk.level, RiskLevel::Medium);
+    }
+}
@@ -1,13 +1,20 @@
-//! Shared BLAKE3 hashing helpers for determinism verification.
+//! Shared hashing helpers for determinism verification.
 //!
 //! Used by both the `baseline` command (to capture source hashes) and the
 //! `cockpit` command (to verify them). Both paths use the same incremental
-//! BLAKE3 protocol so that identical source trees produce identical hashes.
+//! protocol so that identical source trees produce identical hashes. BLAKE3
+//! is the default; the `_with` variants take the baseline's [`HashAlgo`].
 
 use std::io;
 use std::path::Path;
 
 use anyhow::{Context, Result};
+use tokmd_format::IntegrityHasher;
+use tokmd_types::HashAlgo;
+
+/// Glob patterns that are never part of the source hash: VCS metadata,
+/// build output, and tokmd's own artifacts (including baselines).
+pub const DEFAULT_DETERMINISM_EXCLUDES: &[&str] = &[".git/**", ".tokmd/**", "target/**"];
 
 /// Hash a set of files given their relative paths (from export rows).
 ///
@@ -15,11 +22,16 @@ use anyhow::{Context, Result};
 /// Each file contributes `(normalized_path, file_length_le_bytes, file_bytes)`
 /// to an incremental BLAKE3 hasher.
 pub fn hash_files_from_paths(root: &Path, paths: &[&str]) -> Result<String> {
+    hash_files_from_paths_with(root, paths, HashAlgo::Blake3)
+}
+
+/// [`hash_files_from_paths`] with an explicit hash algorithm.
+pub fn hash_files_from_paths_with(root: &Path, paths: &[&str], algo: HashAlgo) -> Result<String> {
     let mut sorted: Vec<&str> = paths.to_vec();
     sorted.sort();
     sorted.dedup();
 
-    let mut hasher = blake3::Hasher::new();
+    let mut hasher = IntegrityHasher::new(algo);
 
     for rel_path in &sorted {
         let normalized = normalize(rel_path);
@@ -45,7 +57,7 @@ pub fn hash_files_from_paths(root: &Path, paths: &[&str]) -> Result<String> {
         }
     }
 
-    Ok(hasher.finalize().to_hex().to_string())
+    Ok(hasher.finalize_hex())
 }
 
 /// Hash all tracked files under `root` by walking the directory tree.
@@ -53,8 +65,28 @@ pub fn hash_files_from_paths(root: &Path, paths: &[&str]) -> Result<String> {
 /// Uses the `ignore` crate to respect `.gitignore` rules, then sorts
 /// the discovered paths and hashes them with the same protocol as
 /// [`hash_files_from_paths`].
+///
+/// `exclude` holds glob patterns matched against `/`-separated paths
+/// relative to `root` (a plain path matches only itself). They apply on top
+/// of [`DEFAULT_DETERMINISM_EXCLUDES`].
+#[cfg(feature = "git")]
+pub fn hash_files_from_walk(root: &Path, exclude: &[&str]) -> Result<String> {
+    hash_files_from_walk_with(root, exclude, HashAlgo::Blake3)
+}
+
+/// [`hash_files_from_walk`] with an explicit hash algorithm.
 #[cfg(feature = "git")]
-pub fn hash_files_from_walk(root: &Path, exclude_rel: &[&str]) -> Result<String> {
+pub fn hash_files_from_walk_with(root: &Path, exclude: &[&str], algo: HashAlgo) -> Result<String> {
+    let mut builder = globset::GlobSetBuilder::new();
+    for pattern in DEFAULT_DETERMINISM_EXCLUDES.iter().chain(exclude) {
+        let glob = globset::Glob::new(pattern)
+            .with_context(|| format!("invalid determinism exclude pattern '{pattern}'"))?;
+        builder.add(glob);
+    }
+    let excluded = builder
+        .build()
+        .context("failed to build determinism exclude set")?;
+
     let mut paths: Vec<String> = Vec::new();
 
     let walker = ignore::WalkBuilder::new(root)
@@ -72,12 +104,7 @@ pub fn hash_files_from_walk(root: &Path, exclude_rel: &[&str]) -> Result<String>
         if let Ok(rel) = entry.path().strip_prefix(root) {
             let normalized = normalize(&rel.to_string_lossy());
 
-            // Hard-skip generated directories + explicit exclusions
-            if normalized.starts_with(".tokmd/")
-                || normalized.starts_with("target/")
-                || normalized.starts_with(".git/")
-                || exclude_rel.iter().any(|ex| normalized == *ex)
-            {
+            if excluded.is_match(&normalized) {
                 continue;
             }
 
@@ -88,7 +115,7 @@ pub fn hash_files_from_walk(root: &Path, exclude_rel: &[&str]) -> Result<String>
     paths.sort();
     paths.dedup();
 
-    let mut hasher = blake3::Hasher::new();
+    let mut hasher = IntegrityHasher::new(algo);
 
     for rel_path in &paths {
         let full = root.join(rel_path);
@@ -102,13 +129,18 @@ pub fn hash_files_from_walk(root: &Path, exclude_rel: &[&str]) -> Result<String>
         }
     }
 
-    Ok(hasher.finalize().to_hex().to_string())
+    Ok(hasher.finalize_hex())
 }
 
-/// Hash the `Cargo.lock` file at `root`, if present.
+/// Hash the `Cargo.lock` file at `root` with BLAKE3, if present.
 ///
 /// Returns `None` if no `Cargo.lock` exists.
 pub fn hash_cargo_lock(root: &Path) -> Result<Option<String>> {
+    hash_cargo_lock_with(root, HashAlgo::Blake3)
+}
+
+/// [`hash_cargo_lock`] with an explicit hash algorithm.
+pub fn hash_cargo_lock_with(root: &Path, algo: HashAlgo) -> Result<Option<String>> {
     let lock_path = root.join("Cargo.lock");
     if !lock_path.exists() {
         return Ok(None);
@@ -117,15 +149,16 @@ pub fn hash_cargo_lock(root: &Path) -> Result<Option<String>> {
     let content = std::fs::read(&lock_path)
         .with_context(|| format!("failed to read {}", lock_path.display()))?;
 
-    let hash = blake3::hash(&content);
-    Ok(Some(hash.to_hex().to_string()))
+    let mut hasher = IntegrityHasher::new(algo);
+    hasher.update(&content);
+    Ok(Some(hasher.finalize_hex()))
 }
 
 /// Feed a single file into the incremental hasher using streaming I/O (O(1) memory).
 ///
 /// Protocol: `hasher.update(path_bytes); hasher.update(len_le_bytes); hasher.update(content)`.
 fn feed_file_streaming(
-    hasher: &mut blake3::Hasher,
+    hasher: &mut IntegrityHasher,
     normalized_path: &str,
     full_path: &Path,
 ) -> std::io::Result<()> {
@@ -273,6 +306,39 @@ mod tests {
         Ok(())
     }
 
+    #[cfg(feature = "git")]
+    #[test]
+    fn test_walk_excludes_glob_patterns() -> anyhow::Result<()> {
+        let dir = tempfile::tempdir()?;
+        fs::create_dir_all(dir.path().join(".git"))?;
+        fs::create_dir_all(dir.path().join("src/generated"))?;
+        fs::write(dir.path().join("a.rs"), "fn main() {}")?;
+        fs::write(dir.path().join("src/generated/schema.rs"), "// v1")?;
+        fs::write(dir.path().join("deps.lock"), "v1")?;
+
+        let excludes = ["**/generated/**", "*.lock"];
+        let walk_excluded = hash_files_from_walk(dir.path(), &excludes)?;
+        let paths_only = hash_files_from_paths(dir.path(), &["a.rs"])?;
+        assert_eq!(walk_excluded, paths_only);
+
+        fs::write(dir.path().join("src/generated/schema.rs"), "// v2")?;
+        fs::write(dir.path().join("deps.lock"), "v2")?;
+        assert_eq!(hash_files_from_walk(dir.path(), &excludes)?, walk_excluded);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "git")]
+    #[test]
+    fn test_walk_rejects_invalid_exclude_pattern() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let err = hash_files_from_walk(dir.path(), &["src/[oops"]).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("invalid determinism exclude pattern")
+        );
+    }
+
     #[cfg(feature = "git")]
     #[test]
     fn test_walk_excludes_tokmd_directory() -> anyhow::Result<()> {
@@ -294,4 +360,23 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_sha256_hashes_are_64_hex_chars_and_differ_from_blake3() -> anyhow::Result<()> {
+        let dir = tempfile::tempdir()?;
+        fs::write(dir.path().join("a.rs"), "fn a() {}")?;
+        fs::write(dir.path().join("Cargo.lock"), "# lock")?;
+
+        let sha = hash_files_from_paths_with(dir.path(), &["a.rs"], HashAlgo::Sha256)?;
+        assert_eq!(sha.len(), 64);
+        assert!(sha.chars().all(|c| c.is_ascii_hexdigit()));
+        assert_ne!(sha, hash_files_from_paths(dir.path(), &["a.rs"])?);
+
+        let lock = hash_cargo_lock_with(dir.path(), HashAlgo::Sha256)?.expect("lock hash");
+        assert_eq!(
+            lock,
+            "ba661c38ea21e3029278dc52ec830a6ab47a9fd2c86b4541d3458f2fe72e0266"
+        );
+        Ok(())
+    }
 }
@@ -1,11 +1,13 @@
+use tokmd_format::analysis::fixed;
+use tokmd_format::{TimestampFormat, TimestampStyle};
 use tokmd_types::cockpit::TrendDirection;
 
 /// Format a float with a sign prefix.
 pub fn format_signed_f64(value: f64) -> String {
     if value > 0.0 {
-        format!("+{value:.2}")
+        format!("+{}", fixed(value, 2))
     } else {
-        format!("{value:.2}")
+        fixed(value, 2)
     }
 }
 
@@ -58,16 +60,12 @@ pub fn sparkline(values: &[f64]) -> String {
 
 /// Return the current time as an ISO 8601 string.
 pub fn now_iso8601() -> String {
-    let now = time::OffsetDateTime::now_utc();
-    format!(
-        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
-        now.year(),
-        now.month() as u8,
-        now.day(),
-        now.hour(),
-        now.minute(),
-        now.second(),
-    )
+    now_iso8601_with(&TimestampFormat::default())
+}
+
+/// [`now_iso8601`] in the offset (and optional custom pattern) of `tf`.
+pub fn now_iso8601_with(tf: &TimestampFormat) -> String {
+    tf.now(TimestampStyle::Iso8601)
 }
 
 /// Round a float to two decimal places.
@@ -148,4 +146,15 @@ mod tests {
         assert!(ts.contains('T'));
         assert_eq!(ts.len(), 20);
     }
+
+    #[test]
+    fn now_iso8601_with_custom_pattern() {
+        let tf = TimestampFormat {
+            pattern: Some("[year]".to_string()),
+            ..TimestampFormat::default()
+        };
+        let ts = now_iso8601_with(&tf);
+        assert_eq!(ts.len(), 4);
+        assert!(ts.bytes().all(|b| b.is_ascii_digit()));
+    }
 }
@@ -1,4 +1,4 @@
-use std::path::{Path, PathBuf};
+use std::path::Path;
 
 use anyhow::Result;
 use tokmd_types::cockpit::*;
@@ -8,39 +8,96 @@ mod contracts;
 mod determinism_gate;
 mod diff_coverage;
 mod mutation;
+mod project_coverage;
 mod rust_source;
 
 use complexity::compute_complexity_gate;
 use contracts::compute_contract_gate;
 use diff_coverage::compute_diff_coverage_gate;
 use mutation::compute_mutation_gate;
+use project_coverage::compute_project_coverage_gate;
+use rust_source::RustSourceFilter;
 
 use crate::FileStat;
-use crate::supply_chain::compute_supply_chain_gate;
+use crate::host::CockpitHost;
+use crate::supply_chain::{AdvisoryAllowlist, AdvisorySeverity, compute_supply_chain_gate};
 
 pub use determinism_gate::compute_determinism_gate;
+pub use project_coverage::ProjectCoverageThresholds;
+pub use rust_source::RustSourceScope;
 
 // =============================================================================
 // Evidence computation
 // =============================================================================
 
+/// The change under review, shared by the gates that look at the diff.
+#[cfg(feature = "git")]
+#[derive(Clone, Copy)]
+pub(crate) struct GateContext<'a> {
+    pub(crate) host: CockpitHost<'a>,
+    pub(crate) repo_root: &'a Path,
+    pub(crate) base: &'a str,
+    pub(crate) head: &'a str,
+    pub(crate) range_mode: tokmd_git::GitRangeMode,
+    pub(crate) changed_files: &'a [FileStat],
+}
+
+/// Per-gate configuration for a cockpit run; the default matches a plain
+/// `tokmd cockpit` invocation.
+#[derive(Debug, Clone, Default)]
+pub struct GateOptions {
+    /// Glob patterns left out of the determinism gate's source hash (see
+    /// [`compute_determinism_gate`]).
+    pub determinism_excludes: Vec<String>,
+    /// Coverage report paths or globs the coverage gates try before their
+    /// built-in locations.
+    pub coverage_artifacts: Vec<String>,
+    /// Pass/warn bands of the project coverage gate.
+    pub project_coverage: ProjectCoverageThresholds,
+    /// Which changed files the mutation and complexity gates consider.
+    pub rust_source: RustSourceScope,
+    /// How many commits behind HEAD an ancestor's mutation CI artifact may be
+    /// and still be used.
+    pub artifact_staleness_window: usize,
+    /// Supply-chain advisories below this severity are dropped.
+    pub supply_chain_min_severity: Option<AdvisorySeverity>,
+    /// Accepted advisories, acknowledged instead of failing the gate.
+    pub advisory_allowlist: AdvisoryAllowlist,
+}
+
 /// Compute evidence section with all gates.
 #[cfg(feature = "git")]
 pub(crate) fn compute_evidence(
-    repo_root: &PathBuf,
-    base: &str,
-    head: &str,
-    changed_files: &[FileStat],
+    ctx: &GateContext<'_>,
     contracts_info: &Contracts,
-    range_mode: tokmd_git::GitRangeMode,
     baseline_path: Option<&Path>,
+    options: &GateOptions,
 ) -> Result<Evidence> {
-    let mutation = compute_mutation_gate(repo_root, base, head, changed_files, range_mode)?;
-    let diff_coverage = compute_diff_coverage_gate(repo_root, base, head, range_mode)?;
-    let contracts = compute_contract_gate(repo_root, base, head, changed_files, contracts_info)?;
-    let supply_chain = compute_supply_chain_gate(repo_root, changed_files)?;
-    let determinism = compute_determinism_gate(repo_root, baseline_path)?;
-    let complexity = compute_complexity_gate(repo_root, changed_files)?;
+    let GateContext {
+        host,
+        repo_root,
+        changed_files,
+        ..
+    } = *ctx;
+    let rust_source = RustSourceFilter::new(&options.rust_source)?;
+    let mutation = compute_mutation_gate(ctx, &rust_source, options.artifact_staleness_window)?;
+    let diff_coverage = compute_diff_coverage_gate(ctx, &options.coverage_artifacts)?;
+    let contracts = compute_contract_gate(ctx, contracts_info)?;
+    let supply_chain = compute_supply_chain_gate(
+        host.commands,
+        repo_root,
+        changed_files,
+        options.supply_chain_min_severity,
+        &options.advisory_allowlist,
+    )?;
+    let determinism =
+        compute_determinism_gate(repo_root, baseline_path, &options.determinism_excludes)?;
+    let complexity = compute_complexity_gate(repo_root, changed_files, &rust_source)?;
+    let project_coverage = compute_project_coverage_gate(
+        repo_root,
+        &options.coverage_artifacts,
+        &options.project_coverage,
+    )?;
 
     // Compute overall status: any Fail -> Fail, all Pass -> Pass, otherwise Pending/Skipped
     let overall_status = compute_overall_status(
@@ -50,6 +107,7 @@ pub(crate) fn compute_evidence(
         &supply_chain,
         &determinism,
         &complexity,
+        &project_coverage,
     );
 
     Ok(Evidence {
@@ -60,6 +118,7 @@ pub(crate) fn compute_evidence(
         supply_chain,
         determinism,
         complexity,
+        project_coverage,
     })
 }
 
@@ -72,6 +131,7 @@ fn compute_overall_status(
     supply_chain: &Option<SupplyChainGate>,
     determinism: &Option<DeterminismGate>,
     complexity: &Option<ComplexityGate>,
+    project_coverage: &Option<ProjectCoverageGate>,
 ) -> GateStatus {
     let statuses: Vec<GateStatus> = [
         Some(mutation.meta.status),
@@ -80,6 +140,7 @@ fn compute_overall_status(
         supply_chain.as_ref().map(|g| g.meta.status),
         determinism.as_ref().map(|g| g.meta.status),
         complexity.as_ref().map(|g| g.meta.status),
+        project_coverage.as_ref().map(|g| g.meta.status),
     ]
     .into_iter()
     .flatten()
@@ -1,23 +1,25 @@
 use std::path::Path;
 
 use anyhow::Result;
-use tokmd_analysis::source_complexity::analyze_rust_function_complexity;
+use tokmd_analysis::source_complexity::{analyze_function_complexity, complexity_language};
 use tokmd_types::cockpit::*;
 
-use super::rust_source::is_relevant_rust_source;
+use super::rust_source::RustSourceFilter;
 use crate::{COMPLEXITY_THRESHOLD, FileStat, round_pct};
 
 /// Compute complexity gate.
-/// Analyzes cyclomatic complexity of changed Rust source files.
+/// Analyzes cyclomatic complexity of changed source files in every language
+/// the complexity estimators support.
 #[cfg(feature = "git")]
 pub(super) fn compute_complexity_gate(
     repo_root: &Path,
     changed_files: &[FileStat],
+    rust_source: &RustSourceFilter,
 ) -> Result<Option<ComplexityGate>> {
-    // Filter to relevant Rust source files
+    // Filter to in-scope source files in a supported language
     let relevant_files: Vec<String> = changed_files
         .iter()
-        .filter(|f| is_relevant_rust_source(&f.path))
+        .filter(|f| complexity_language(&f.path).is_some() && rust_source.is_in_scope(&f.path))
         .map(|f| f.path.clone())
         .collect();
 
@@ -38,8 +40,11 @@ pub(super) fn compute_complexity_gate(
             continue;
         }
 
+        let Some(language) = complexity_language(file_path) else {
+            continue;
+        };
         if let Ok(content) = std::fs::read_to_string(&full_path) {
-            let analysis = analyze_rust_function_complexity(&content);
+            let analysis = analyze_function_complexity(&content, language);
             files_analyzed += 1;
             total_complexity += analysis.total_complexity as u64;
             total_functions += analysis.function_count;
@@ -93,12 +98,7 @@ pub(super) fn compute_complexity_gate(
                 lines_tested: None,
             },
             evidence_commit: None,
-            evidence_generated_at_ms: Some(
-                std::time::SystemTime::now()
-                    .duration_since(std::time::UNIX_EPOCH)
-                    .unwrap_or_default()
-                    .as_millis() as u64,
-            ),
+            evidence_generated_at_ms: Some(tokmd_analysis_types::now_ms() as u64),
         },
         files_analyzed,
         high_complexity_files,
@@ -111,6 +111,11 @@ pub(super) fn compute_complexity_gate(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::gates::RustSourceScope;
+
+    fn default_scope() -> RustSourceFilter {
+        RustSourceFilter::new(&RustSourceScope::default()).unwrap()
+    }
 
     fn stat(path: &str) -> FileStat {
         FileStat {
@@ -124,7 +129,8 @@ mod tests {
     fn skips_when_no_relevant_rust_sources_changed() {
         let changed_files = vec![stat("README.md"), stat("tests/cockpit.rs")];
 
-        let gate = compute_complexity_gate(Path::new("."), &changed_files).unwrap();
+        let gate =
+            compute_complexity_gate(Path::new("."), &changed_files, &default_scope()).unwrap();
 
         assert!(gate.is_none());
     }
@@ -147,7 +153,7 @@ fn maybe(flag: bool) {
 
         let changed_files = vec![stat("src/lib.rs")];
 
-        let gate = compute_complexity_gate(dir.path(), &changed_files)
+        let gate = compute_complexity_gate(dir.path(), &changed_files, &default_scope())
             .unwrap()
             .expect("changed Rust source should produce complexity gate");
 
@@ -158,6 +164,30 @@ fn maybe(flag: bool) {
         assert!(!gate.threshold_exceeded);
     }
 
+    #[test]
+    fn include_pattern_brings_integration_tests_into_scope() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join("tests")).unwrap();
+        std::fs::write(
+            dir.path().join("tests/cockpit.rs"),
+            "#[test]\nfn smoke() {\n    assert!(true);\n}\n",
+        )
+        .unwrap();
+        let changed_files = vec![stat("tests/cockpit.rs")];
+        let scope = RustSourceFilter::new(&RustSourceScope {
+            include: vec!["tests/**".to_string()],
+            exclude: vec![],
+        })
+        .unwrap();
+
+        let gate = compute_complexity_gate(dir.path(), &changed_files, &scope)
+            .unwrap()
+            .expect("included test file should produce complexity gate");
+
+        assert_eq!(gate.files_analyzed, 1);
+        assert_eq!(gate.meta.scope.relevant, vec!["tests/cockpit.rs"]);
+    }
+
     #[test]
     fn avg_cyclomatic_uses_function_count_not_file_count() {
         let dir = tempfile::tempdir().unwrap();
@@ -182,12 +212,12 @@ fn branchy(a: bool, b: bool, n: i32) {
 "#;
         std::fs::write(dir.path().join("src/lib.rs"), code).unwrap();
 
-        let analysis = analyze_rust_function_complexity(code);
+        let analysis = analyze_function_complexity(code, "rust");
         assert!(analysis.function_count > 1);
         assert!(analysis.total_complexity > analysis.max_complexity);
 
         let changed_files = vec![stat("src/lib.rs")];
-        let gate = compute_complexity_gate(dir.path(), &changed_files)
+        let gate = compute_complexity_gate(dir.path(), &changed_files, &default_scope())
             .unwrap()
             .expect("changed Rust source should produce complexity gate");
 
@@ -196,4 +226,29 @@ fn branchy(a: bool, b: bool, n: i32) {
         assert_eq!(gate.avg_cyclomatic, expected_avg);
         assert!(gate.avg_cyclomatic <= f64::from(gate.max_cyclomatic));
     }
+
+    #[test]
+    fn high_complexity_python_file_triggers_the_gate() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join("app")).unwrap();
+        let mut code = String::from("def route(kind):\n");
+        for i in 0..20 {
+            code.push_str(&format!("    if kind == {i}:\n        return {i}\n"));
+        }
+        code.push_str("    return -1\n");
+        std::fs::write(dir.path().join("app/routes.py"), &code).unwrap();
+        std::fs::write(dir.path().join("app/util.py"), "def ok():\n    return 1\n").unwrap();
+
+        let changed_files = vec![stat("app/routes.py"), stat("app/util.py")];
+        let gate = compute_complexity_gate(dir.path(), &changed_files, &default_scope())
+            .unwrap()
+            .expect("changed Python sources should produce complexity gate");
+
+        assert_eq!(gate.files_analyzed, 2);
+        assert_eq!(gate.max_cyclomatic, 21);
+        assert!(gate.threshold_exceeded);
+        assert_eq!(gate.meta.status, GateStatus::Warn);
+        assert_eq!(gate.high_complexity_files.len(), 1);
+        assert_eq!(gate.high_complexity_files[0].path, "app/routes.py");
+    }
 }
@@ -1,20 +1,28 @@
 use std::path::Path;
-use std::process::Command;
 
 use anyhow::Result;
 use tokmd_types::cockpit::*;
 
-use crate::FileStat;
+#[cfg(feature = "git")]
+use super::GateContext;
+#[cfg(feature = "git")]
+use crate::host::CockpitHost;
 
 /// Compute contract diff gate (semver, CLI, schema).
 #[cfg(feature = "git")]
 pub(super) fn compute_contract_gate(
-    repo_root: &Path,
-    base: &str,
-    head: &str,
-    changed_files: &[FileStat],
+    ctx: &GateContext<'_>,
     contracts_info: &Contracts,
 ) -> Result<Option<ContractDiffGate>> {
+    let GateContext {
+        ref host,
+        repo_root,
+        base,
+        head,
+        changed_files,
+        ..
+    } = *ctx;
+
     // Only compute if any contract-relevant files changed
     if !contracts_info.api_changed && !contracts_info.cli_changed && !contracts_info.schema_changed
     {
@@ -28,7 +36,7 @@ pub(super) fn compute_contract_gate(
 
     // Check for semver changes (API files)
     if contracts_info.api_changed {
-        semver = Some(run_semver_check(repo_root));
+        semver = Some(run_semver_check(host, repo_root));
     }
 
     // Check for CLI changes
@@ -84,7 +92,7 @@ pub(super) fn compute_contract_gate(
 
     // Check for schema changes
     if contracts_info.schema_changed {
-        schema = Some(run_schema_diff(repo_root, base, head));
+        schema = Some(run_schema_diff(host, repo_root, base, head));
     }
 
     // Count failures from sub-gates
@@ -164,12 +172,12 @@ pub(super) fn compute_contract_gate(
 /// Run cargo-semver-checks if available.
 /// Returns a SemverSubGate with the result.
 #[cfg(feature = "git")]
-fn run_semver_check(repo_root: &Path) -> SemverSubGate {
+fn run_semver_check(host: &CockpitHost<'_>, repo_root: &Path) -> SemverSubGate {
     // Check if cargo-semver-checks is available
-    let available = Command::new("cargo")
-        .args(["semver-checks", "--version"])
-        .output()
-        .map(|o| o.status.success())
+    let available = host
+        .commands
+        .run("cargo", &["semver-checks", "--version"], repo_root)
+        .map(|o| o.success)
         .unwrap_or(false);
 
     if !available {
@@ -180,10 +188,9 @@ fn run_semver_check(repo_root: &Path) -> SemverSubGate {
     }
 
     // Run cargo semver-checks
-    let output = match Command::new("cargo")
-        .args(["semver-checks", "check-release"])
-        .current_dir(repo_root)
-        .output()
+    let output = match host
+        .commands
+        .run("cargo", &["semver-checks", "check-release"], repo_root)
     {
         Ok(o) => o,
         Err(_) => {
@@ -194,7 +201,7 @@ fn run_semver_check(repo_root: &Path) -> SemverSubGate {
         }
     };
 
-    if output.status.success() {
+    if output.success {
         // Exit 0 = no breaking changes
         return SemverSubGate {
             status: GateStatus::Pass,
@@ -203,9 +210,7 @@ fn run_semver_check(repo_root: &Path) -> SemverSubGate {
     }
 
     // Non-zero exit = breaking changes found
-    let stderr = String::from_utf8_lossy(&output.stderr);
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    let combined = format!("{}{}", stdout, stderr);
+    let combined = format!("{}{}", output.stdout, output.stderr);
 
     // Parse breaking changes from output lines
     // cargo-semver-checks output format: "--- failure[kind]: message ---" or similar
@@ -242,16 +247,16 @@ fn run_semver_check(repo_root: &Path) -> SemverSubGate {
 /// Run git diff on docs/schema.json to detect schema changes.
 /// Returns a SchemaSubGate with the result.
 #[cfg(feature = "git")]
-fn run_schema_diff(repo_root: &Path, base: &str, head: &str) -> SchemaSubGate {
+fn run_schema_diff(
+    host: &CockpitHost<'_>,
+    repo_root: &Path,
+    base: &str,
+    head: &str,
+) -> SchemaSubGate {
     // Use two-dot syntax for comparing refs directly (per project convention)
     let range = format!("{}..{}", base, head);
-    let output = match tokmd_git::git_cmd()
-        .arg("-C")
-        .arg(repo_root)
-        .args(["diff", &range, "--", "docs/schema.json"])
-        .output()
-    {
-        Ok(o) => o,
+    let diff = match host.git.diff_path(repo_root, &range, "docs/schema.json") {
+        Ok(diff) => diff,
         Err(_) => {
             return SchemaSubGate {
                 status: GateStatus::Pending,
@@ -260,14 +265,6 @@ fn run_schema_diff(repo_root: &Path, base: &str, head: &str) -> SchemaSubGate {
         }
     };
 
-    if !output.status.success() {
-        return SchemaSubGate {
-            status: GateStatus::Pending,
-            diff_summary: None,
-        };
-    }
-
-    let diff = String::from_utf8_lossy(&output.stdout);
     if diff.trim().is_empty() {
         // No diff means schema.json didn't change between these refs
         return SchemaSubGate {
@@ -335,3 +332,123 @@ fn run_schema_diff(repo_root: &Path, base: &str, head: &str) -> SchemaSubGate {
         diff_summary: summary,
     }
 }
+
+#[cfg(all(test, feature = "git"))]
+mod tests {
+    use super::*;
+    use crate::FileStat;
+    use crate::host::mock::{MockCommands, MockGit};
+
+    fn stat(path: &str) -> FileStat {
+        FileStat {
+            path: path.to_string(),
+            insertions: 1,
+            deletions: 1,
+        }
+    }
+
+    fn ctx<'a>(host: CockpitHost<'a>, changed_files: &'a [FileStat]) -> GateContext<'a> {
+        GateContext {
+            host,
+            repo_root: Path::new("."),
+            base: "main",
+            head: "HEAD",
+            range_mode: tokmd_git::GitRangeMode::TwoDot,
+            changed_files,
+        }
+    }
+
+    fn contracts(api_changed: bool, schema_changed: bool) -> Contracts {
+        Contracts {
+            api_changed,
+            cli_changed: false,
+            schema_changed,
+            breaking_indicators: 0,
+        }
+    }
+
+    #[test]
+    fn semver_breakage_reported_by_the_tool_fails_the_gate() {
+        let commands = MockCommands::default()
+            .reply(
+                "cargo semver-checks --version",
+                true,
+                "cargo-semver-checks 0.40.0",
+            )
+            .reply(
+                "cargo semver-checks check-release",
+                false,
+                "--- failure function_missing: pub fn removed ---",
+            );
+        let host = CockpitHost {
+            git: &MockGit::default(),
+            commands: &commands,
+        };
+
+        let gate = compute_contract_gate(
+            &ctx(host, &[stat("crates/a/src/lib.rs")]),
+            &contracts(true, false),
+        )
+        .unwrap()
+        .expect("API change should produce a contract gate");
+
+        assert_eq!(gate.meta.status, GateStatus::Fail);
+        assert_eq!(gate.failures, 1);
+        let semver = gate.semver.unwrap();
+        assert_eq!(
+            semver.breaking_changes[0].message,
+            "--- failure function_missing: pub fn removed ---"
+        );
+    }
+
+    #[test]
+    fn missing_semver_tool_leaves_the_gate_pending() {
+        let host = CockpitHost {
+            git: &MockGit::default(),
+            commands: &MockCommands::default(),
+        };
+
+        let gate = compute_contract_gate(
+            &ctx(host, &[stat("crates/a/src/lib.rs")]),
+            &contracts(true, false),
+        )
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(gate.meta.status, GateStatus::Pending);
+        assert_eq!(gate.semver.unwrap().status, GateStatus::Pending);
+    }
+
+    #[test]
+    fn schema_type_change_in_the_diff_fails_the_gate() {
+        let git = MockGit {
+            path_diffs: [(
+                "docs/schema.json".to_string(),
+                "--- a/docs/schema.json\n+++ b/docs/schema.json\n\
+                 -      \"type\": \"integer\"\n+      \"type\": \"string\"\n"
+                    .to_string(),
+            )]
+            .into(),
+            ..MockGit::default()
+        };
+        let host = CockpitHost {
+            git: &git,
+            commands: &MockCommands::default(),
+        };
+
+        let gate = compute_contract_gate(
+            &ctx(host, &[stat("docs/schema.json")]),
+            &contracts(false, true),
+        )
+        .unwrap()
+        .unwrap();
+
+        let schema = gate.schema.unwrap();
+        assert_eq!(schema.status, GateStatus::Fail);
+        assert_eq!(
+            schema.diff_summary.as_deref(),
+            Some("schema.json: 1 addition, 1 removal (potential breaking change)")
+        );
+        assert_eq!(gate.meta.status, GateStatus::Fail);
+    }
+}
@@ -4,13 +4,21 @@ use anyhow::{Context, Result, bail};
 use tokmd_types::cockpit::*;
 
 use crate::determinism;
+use crate::input::read_input_to_string;
 
 /// Compute determinism gate.
 /// Compares expected source hash (from baseline) with a fresh hash of the repo.
+/// Baselines ending in `.gz` or `.zst` are decompressed transparently.
+///
+/// `excludes` are extra glob patterns (relative to `repo_root`) left out of
+/// the hash walk, for generated files that legitimately change. They apply
+/// on top of [`determinism::DEFAULT_DETERMINISM_EXCLUDES`] and the baseline
+/// file itself; the effective list is recorded in the gate output.
 #[cfg(feature = "git")]
 pub fn compute_determinism_gate(
     repo_root: &Path,
     baseline_path: Option<&Path>,
+    excludes: &[String],
 ) -> Result<Option<DeterminismGate>> {
     use tokmd_analysis_types::ComplexityBaseline;
 
@@ -30,7 +38,7 @@ pub fn compute_determinism_gate(
     }
 
     // Parse baseline
-    let content = std::fs::read_to_string(&resolved_path)
+    let content = read_input_to_string(&resolved_path)
         .with_context(|| format!("failed to read baseline at {}", resolved_path.display()))?;
     let json: serde_json::Value = serde_json::from_str(&content).with_context(|| {
         format!(
@@ -66,9 +74,13 @@ pub fn compute_determinism_gate(
     let baseline_rel = resolved_path
         .strip_prefix(repo_root)
         .ok()
-        .map(|p| p.to_string_lossy().replace('\\', "/"));
-    let exclude: Vec<&str> = baseline_rel.as_deref().into_iter().collect();
-    let actual_hash = determinism::hash_files_from_walk(repo_root, &exclude)?;
+        .map(|p| globset::escape(&p.to_string_lossy().replace('\\', "/")));
+    let exclude: Vec<&str> = excludes
+        .iter()
+        .map(String::as_str)
+        .chain(baseline_rel.as_deref())
+        .collect();
+    let actual_hash = determinism::hash_files_from_walk_with(repo_root, &exclude, det.algo)?;
     let expected_hash = &det.source_hash;
 
     let mut differences = Vec::new();
@@ -83,7 +95,7 @@ pub fn compute_determinism_gate(
 
     // Check Cargo.lock hash if baseline had one
     if let Some(expected_lock) = &det.cargo_lock_hash {
-        let actual_lock = determinism::hash_cargo_lock(repo_root)?;
+        let actual_lock = determinism::hash_cargo_lock_with(repo_root, det.algo)?;
         match actual_lock {
             Some(ref actual) if actual != expected_lock => {
                 differences.push(format!(
@@ -122,7 +134,105 @@ pub fn compute_determinism_gate(
         },
         expected_hash: Some(expected_hash.clone()),
         actual_hash: Some(actual_hash),
-        algo: "blake3".to_string(),
+        algo: det.algo.as_str().to_string(),
         differences,
+        excludes: determinism::DEFAULT_DETERMINISM_EXCLUDES
+            .iter()
+            .copied()
+            .chain(exclude)
+            .map(String::from)
+            .collect(),
     }))
 }
+
+#[cfg(all(test, feature = "git"))]
+mod tests {
+    use std::fs;
+
+    use tokmd_analysis_types::{ComplexityBaseline, DeterminismBaseline};
+    use tokmd_types::HashAlgo;
+
+    use super::*;
+
+    /// Repo with one generated file and a baseline captured with `excludes`.
+    fn repo_with_baseline(excludes: &[&str]) -> Result<tempfile::TempDir> {
+        repo_with_baseline_algo(excludes, HashAlgo::Blake3)
+    }
+
+    fn repo_with_baseline_algo(excludes: &[&str], algo: HashAlgo) -> Result<tempfile::TempDir> {
+        let dir = tempfile::tempdir()?;
+        let root = dir.path();
+        fs::create_dir_all(root.join(".git"))?;
+        fs::create_dir_all(root.join("src/generated"))?;
+        fs::write(root.join("src/lib.rs"), "pub fn f() {}")?;
+        fs::write(root.join("src/generated/build_info.rs"), "// built at 1")?;
+
+        let mut baseline = ComplexityBaseline::new();
+        baseline.determinism = Some(DeterminismBaseline {
+            baseline_version: 1,
+            generated_at: String::new(),
+            build_hash: String::new(),
+            source_hash: determinism::hash_files_from_walk_with(root, excludes, algo)?,
+            cargo_lock_hash: None,
+            algo,
+        });
+        fs::create_dir_all(root.join(".tokmd"))?;
+        fs::write(
+            root.join(".tokmd/baseline.json"),
+            serde_json::to_string(&baseline)?,
+        )?;
+        Ok(dir)
+    }
+
+    #[test]
+    fn excluded_generated_file_does_not_trigger_mismatch() -> Result<()> {
+        let dir = repo_with_baseline(&["**/generated/**"])?;
+        fs::write(
+            dir.path().join("src/generated/build_info.rs"),
+            "// built at 2",
+        )?;
+
+        let gate = compute_determinism_gate(dir.path(), None, &["**/generated/**".to_string()])?
+            .expect("baseline has a determinism section");
+
+        assert_eq!(gate.meta.status, GateStatus::Pass);
+        assert!(gate.differences.is_empty());
+        assert_eq!(
+            gate.excludes,
+            vec![
+                ".git/**",
+                ".tokmd/**",
+                "target/**",
+                "**/generated/**",
+                ".tokmd/baseline.json",
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn changed_file_outside_excludes_still_warns() -> Result<()> {
+        let dir = repo_with_baseline(&["**/generated/**"])?;
+        fs::write(dir.path().join("src/lib.rs"), "pub fn g() {}")?;
+
+        let gate = compute_determinism_gate(dir.path(), None, &["**/generated/**".to_string()])?
+            .expect("baseline has a determinism section");
+
+        assert_eq!(gate.meta.status, GateStatus::Warn);
+        assert!(gate.differences[0].starts_with("source hash mismatch"));
+        Ok(())
+    }
+
+    #[test]
+    fn sha256_baseline_is_verified_with_sha256() -> Result<()> {
+        let dir = repo_with_baseline_algo(&[], HashAlgo::Sha256)?;
+
+        let gate = compute_determinism_gate(dir.path(), None, &[])?
+            .expect("baseline has a determinism section");
+
+        assert_eq!(gate.meta.status, GateStatus::Pass);
+        assert_eq!(gate.algo, "sha256");
+        assert_eq!(gate.actual_hash.as_deref().map(str::len), Some(64));
+        Ok(())
+    }
+}
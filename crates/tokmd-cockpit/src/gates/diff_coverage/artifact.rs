@@ -1,9 +1,11 @@
 //! Coverage artifact discovery.
 //!
-//! Locates a coverage report file on disk by checking the common conventional
-//! locations. Today only LCOV (`lcov.info`) is recognized; other formats are
+//! Locates a coverage report file on disk: first any caller-supplied paths or
+//! globs, then the common conventional locations. Among the conventional
+//! locations only LCOV (`lcov.info`) is recognized; other formats are
 //! discovered but not yet parsed, so they are rejected here to keep the
-//! contract simple ("returned path is parseable").
+//! contract simple ("returned path is parseable"). Caller-supplied matches
+//! are read as LCOV whatever their name.
 
 use std::path::{Path, PathBuf};
 
@@ -22,11 +24,21 @@ const SEARCH_PATHS: &[&str] = &[
 
 /// Locate an LCOV coverage artifact under `repo_root`, if one exists.
 ///
-/// Returns `Some(path)` only when a `lcov.info` file is found. Non-LCOV
-/// artifacts (cobertura.xml, coverage.json) cause the search to stop with
-/// `None` because they cannot yet be parsed, matching the prior behaviour.
+/// Each entry of `custom` (a repo-relative path or glob) is tried in order
+/// before the built-in locations; a glob that matches several files picks
+/// the first in path order. Otherwise returns `Some(path)` only when a
+/// `lcov.info` file is found. Non-LCOV artifacts (cobertura.xml,
+/// coverage.json) cause the search to stop with `None` because they cannot
+/// yet be parsed, matching the prior behaviour.
 #[cfg(feature = "git")]
-pub(super) fn find_lcov_artifact(repo_root: &Path) -> Option<PathBuf> {
+pub(in crate::gates) fn find_lcov_artifact(repo_root: &Path, custom: &[String]) -> Option<PathBuf> {
+    if let Some(path) = custom
+        .iter()
+        .find_map(|pattern| find_custom_artifact(repo_root, pattern))
+    {
+        return Some(path);
+    }
+
     for candidate in SEARCH_PATHS {
         let path = repo_root.join(candidate);
         if path.exists() {
@@ -39,6 +51,44 @@ pub(super) fn find_lcov_artifact(repo_root: &Path) -> Option<PathBuf> {
     None
 }
 
+/// First file under `repo_root` matching `pattern`. Only the directory below
+/// the pattern's literal prefix is walked, and ignore files are not honoured
+/// because coverage usually lands in ignored build directories.
+#[cfg(feature = "git")]
+fn find_custom_artifact(repo_root: &Path, pattern: &str) -> Option<PathBuf> {
+    let pattern = pattern.trim().trim_start_matches("./");
+    if pattern.is_empty() {
+        return None;
+    }
+    if !pattern.contains(['*', '?', '[', '{']) {
+        let path = repo_root.join(pattern);
+        return path.is_file().then_some(path);
+    }
+
+    let matcher = globset::GlobBuilder::new(pattern)
+        .literal_separator(true)
+        .build()
+        .ok()?
+        .compile_matcher();
+    let prefix: PathBuf = pattern
+        .split('/')
+        .take_while(|segment| !segment.contains(['*', '?', '[', '{']))
+        .collect();
+    let mut matches: Vec<PathBuf> = ignore::WalkBuilder::new(repo_root.join(&prefix))
+        .standard_filters(false)
+        .build()
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_type().is_some_and(|ft| ft.is_file()))
+        .filter_map(|entry| {
+            let rel = entry.path().strip_prefix(repo_root).ok()?;
+            let rel = rel.to_string_lossy().replace('\\', "/");
+            matcher.is_match(&rel).then(|| entry.into_path())
+        })
+        .collect();
+    matches.sort();
+    matches.into_iter().next()
+}
+
 #[cfg(all(test, feature = "git"))]
 mod tests {
     use super::*;
@@ -46,14 +96,14 @@ mod tests {
     #[test]
     fn returns_none_when_no_artifact_exists() {
         let dir = tempfile::tempdir().unwrap();
-        assert!(find_lcov_artifact(dir.path()).is_none());
+        assert!(find_lcov_artifact(dir.path(), &[]).is_none());
     }
 
     #[test]
     fn finds_lcov_at_repo_root() {
         let dir = tempfile::tempdir().unwrap();
         std::fs::write(dir.path().join("lcov.info"), "SF:src/lib.rs\n").unwrap();
-        let found = find_lcov_artifact(dir.path()).expect("should find lcov.info");
+        let found = find_lcov_artifact(dir.path(), &[]).expect("should find lcov.info");
         assert!(found.ends_with("lcov.info"));
     }
 
@@ -62,7 +112,7 @@ mod tests {
         let dir = tempfile::tempdir().unwrap();
         std::fs::create_dir_all(dir.path().join("coverage")).unwrap();
         std::fs::write(dir.path().join("coverage/lcov.info"), "SF:src/lib.rs\n").unwrap();
-        let found = find_lcov_artifact(dir.path()).expect("should find coverage/lcov.info");
+        let found = find_lcov_artifact(dir.path(), &[]).expect("should find coverage/lcov.info");
         assert!(found.ends_with("lcov.info"));
     }
 
@@ -74,6 +124,45 @@ mod tests {
         let dir = tempfile::tempdir().unwrap();
         std::fs::create_dir_all(dir.path().join("coverage")).unwrap();
         std::fs::write(dir.path().join("coverage/cobertura.xml"), "<x/>").unwrap();
-        assert!(find_lcov_artifact(dir.path()).is_none());
+        assert!(find_lcov_artifact(dir.path(), &[]).is_none());
+    }
+
+    #[test]
+    fn custom_path_is_found_ahead_of_defaults() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("lcov.info"), "SF:src/lib.rs\n").unwrap();
+        let nextest = dir.path().join("target/nextest/coverage");
+        std::fs::create_dir_all(&nextest).unwrap();
+        std::fs::write(nextest.join("lcov.info"), "SF:src/lib.rs\n").unwrap();
+
+        let found = find_lcov_artifact(
+            dir.path(),
+            &["target/nextest/coverage/lcov.info".to_string()],
+        )
+        .expect("custom artifact should be found");
+        assert_eq!(found, nextest.join("lcov.info"));
+    }
+
+    #[test]
+    fn custom_glob_picks_first_match_in_path_order() {
+        let dir = tempfile::tempdir().unwrap();
+        for krate in ["beta", "alpha"] {
+            let cov = dir.path().join("crates").join(krate).join("coverage");
+            std::fs::create_dir_all(&cov).unwrap();
+            std::fs::write(cov.join("report.lcov"), "SF:src/lib.rs\n").unwrap();
+        }
+
+        let found = find_lcov_artifact(dir.path(), &["crates/*/coverage/*.lcov".to_string()])
+            .expect("glob should match");
+        assert!(found.ends_with("crates/alpha/coverage/report.lcov"));
+    }
+
+    #[test]
+    fn missing_custom_path_falls_back_to_defaults() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("lcov.info"), "SF:src/lib.rs\n").unwrap();
+        let found = find_lcov_artifact(dir.path(), &["nowhere/lcov.info".to_string()])
+            .expect("defaults still apply");
+        assert_eq!(found, dir.path().join("lcov.info"));
     }
 }
@@ -2,7 +2,7 @@
 //!
 //! Given the set of lines a diff introduced and a parsed LCOV map, produces
 //! the totals plus the contiguous runs of *uncovered* lines (hunks) we want
-//! to report.
+//! to report, and the branch totals on those lines.
 
 use std::collections::{BTreeMap, BTreeSet};
 use std::path::PathBuf;
@@ -10,7 +10,7 @@ use std::path::PathBuf;
 use tokmd_types::cockpit::UncoveredHunk;
 
 #[cfg(feature = "git")]
-use super::lcov::LcovData;
+use super::lcov::{BranchData, LcovData};
 
 /// Per-file totals and uncovered hunks for the diff under inspection.
 #[cfg(feature = "git")]
@@ -55,6 +55,34 @@ pub(super) fn intersect(
     }
 }
 
+/// Count the branches on added lines and how many of them were taken.
+///
+/// Returns `(branches, taken)`; lines without `BRDA` records contribute
+/// nothing, so a report with no branch data yields `(0, 0)`.
+#[cfg(feature = "git")]
+pub(super) fn intersect_branches(
+    added_lines: &BTreeMap<PathBuf, BTreeSet<usize>>,
+    branch_data: &BranchData,
+) -> (usize, usize) {
+    let mut total = 0usize;
+    let mut taken = 0usize;
+    for (file_path, lines) in added_lines {
+        let file_path_str = file_path.to_string_lossy().replace('\\', "/");
+        let Some(file_branches) = branch_data.get(&file_path_str) else {
+            continue;
+        };
+        for line in lines {
+            for &count in file_branches.get(line).into_iter().flat_map(|b| b.values()) {
+                total += 1;
+                if count > 0 {
+                    taken += 1;
+                }
+            }
+        }
+    }
+    (total, taken)
+}
+
 /// Split a file's added lines into covered vs. uncovered.
 ///
 /// Increments `total_covered` for each line with a hit > 0; returns the
@@ -102,6 +130,7 @@ pub(super) fn flush_uncovered_hunks(
                 file: file.to_string(),
                 start_line: start,
                 end_line: end,
+                snippet: None,
             });
             start = line;
             end = line;
@@ -111,6 +140,7 @@ pub(super) fn flush_uncovered_hunks(
         file: file.to_string(),
         start_line: start,
         end_line: end,
+        snippet: None,
     });
 }
 
@@ -192,4 +222,27 @@ mod tests {
         assert_eq!(result.uncovered_hunks[1].start_line, 4);
         assert_eq!(result.uncovered_hunks[1].end_line, 4);
     }
+
+    #[test]
+    fn intersect_branches_counts_only_added_lines() {
+        let mut added = BTreeMap::new();
+        added.insert(PathBuf::from("src/lib.rs"), BTreeSet::from([4, 5]));
+        added.insert(PathBuf::from("src/other.rs"), BTreeSet::from([1]));
+        let mut branches: BranchData = BTreeMap::new();
+        branches.insert(
+            "src/lib.rs".to_string(),
+            BTreeMap::from([
+                (
+                    4,
+                    BTreeMap::from([("0,0".to_string(), 3), ("0,1".to_string(), 0)]),
+                ),
+                (5, BTreeMap::from([("1,0".to_string(), 1)])),
+                // Line 20 was not added by the diff.
+                (20, BTreeMap::from([("2,0".to_string(), 0)])),
+            ]),
+        );
+
+        assert_eq!(intersect_branches(&added, &branches), (3, 2));
+        assert_eq!(intersect_branches(&added, &BTreeMap::new()), (0, 0));
+    }
 }
@@ -1,51 +1,93 @@
 //! LCOV report parsing.
 //!
-//! Parses the `lcov.info` line-coverage format into a per-file lookup of
-//! `line number → hit count`. Records are normalised to repo-relative paths
-//! and merged when the same `SF:` reappears (a quirk some tools emit).
+//! Parses the `lcov.info` format into per-file lookups of `line number → hit
+//! count` (`DA:` records) and `line number → branch → taken count` (`BRDA:`
+//! records). Records are normalised to repo-relative paths and merged when
+//! the same `SF:` reappears (a quirk some tools emit).
 
 use std::collections::BTreeMap;
 use std::path::Path;
 
 /// `file path → { line number → hit count }`.
 #[cfg(feature = "git")]
-pub(super) type LcovData = BTreeMap<String, BTreeMap<usize, usize>>;
+pub(in crate::gates) type LcovData = BTreeMap<String, BTreeMap<usize, usize>>;
+
+/// `file path → { line number → { "block,branch" → taken count } }`.
+#[cfg(feature = "git")]
+pub(in crate::gates) type BranchData = BTreeMap<String, BTreeMap<usize, BTreeMap<String, usize>>>;
+
+/// Line and branch coverage parsed from one LCOV report.
+#[cfg(feature = "git")]
+#[derive(Debug, Default)]
+pub(in crate::gates) struct LcovReport {
+    pub lines: LcovData,
+    pub branches: BranchData,
+}
 
 /// Parse the textual contents of an `lcov.info` file.
 ///
 /// `repo_root` is used to make absolute `SF:` paths repo-relative.
 #[cfg(feature = "git")]
-pub(super) fn parse_lcov(repo_root: &Path, content: &str) -> LcovData {
-    let mut lcov_data: LcovData = BTreeMap::new();
+pub(in crate::gates) fn parse_lcov(repo_root: &Path, content: &str) -> LcovReport {
+    let mut report = LcovReport::default();
     let mut current_file: Option<String> = None;
     let mut current_lines: BTreeMap<usize, usize> = BTreeMap::new();
+    let mut current_branches: BTreeMap<usize, BTreeMap<String, usize>> = BTreeMap::new();
 
     for line in content.lines() {
         if let Some(sf) = line.strip_prefix("SF:") {
             current_file = Some(normalize_source_path(repo_root, sf));
             current_lines.clear();
+            current_branches.clear();
         } else if let Some(da) = line.strip_prefix("DA:") {
             if current_file.is_some()
                 && let Some((line_no, count)) = parse_da_record(da)
             {
                 current_lines.insert(line_no, count);
             }
+        } else if let Some(brda) = line.strip_prefix("BRDA:") {
+            if current_file.is_some()
+                && let Some((line_no, branch, taken)) = parse_brda_record(brda)
+            {
+                current_branches
+                    .entry(line_no)
+                    .or_default()
+                    .insert(branch, taken);
+            }
         } else if line == "end_of_record"
             && let Some(file) = current_file.take()
         {
-            let lines = std::mem::take(&mut current_lines);
-            merge_record(&mut lcov_data, file, lines);
+            flush_record(&mut report, file, &mut current_lines, &mut current_branches);
         }
     }
 
     // Some generators omit the trailing `end_of_record`; flush any pending
     // record so its data is not silently dropped.
     if let Some(file) = current_file.take() {
-        let lines = std::mem::take(&mut current_lines);
-        merge_record(&mut lcov_data, file, lines);
+        flush_record(&mut report, file, &mut current_lines, &mut current_branches);
     }
 
-    lcov_data
+    report
+}
+
+#[cfg(feature = "git")]
+fn flush_record(
+    report: &mut LcovReport,
+    file: String,
+    lines: &mut BTreeMap<usize, usize>,
+    branches: &mut BTreeMap<usize, BTreeMap<String, usize>>,
+) {
+    let branches = std::mem::take(branches);
+    if !branches.is_empty() {
+        let file_branches = report.branches.entry(file.clone()).or_default();
+        for (line_no, line_branches) in branches {
+            file_branches
+                .entry(line_no)
+                .or_default()
+                .extend(line_branches);
+        }
+    }
+    merge_record(&mut report.lines, file, std::mem::take(lines));
 }
 
 #[cfg(feature = "git")]
@@ -65,6 +107,21 @@ fn parse_da_record(da: &str) -> Option<(usize, usize)> {
     Some((line_no.parse().ok()?, count.parse().ok()?))
 }
 
+/// Parse `line,block,branch,taken`; a `-` taken count (block never ran)
+/// counts as zero.
+#[cfg(feature = "git")]
+fn parse_brda_record(brda: &str) -> Option<(usize, String, usize)> {
+    let mut fields = brda.splitn(4, ',');
+    let line_no = fields.next()?.parse().ok()?;
+    let block = fields.next()?;
+    let branch = fields.next()?;
+    let taken = match fields.next()? {
+        "-" => 0,
+        count => count.parse().ok()?,
+    };
+    Some((line_no, format!("{block},{branch}"), taken))
+}
+
 #[cfg(feature = "git")]
 fn merge_record(lcov_data: &mut LcovData, file: String, lines: BTreeMap<usize, usize>) {
     match lcov_data.entry(file) {
@@ -96,7 +153,7 @@ mod tests {
     fn parses_single_record() {
         let content = format!("SF:{FAKE_PATH}\nDA:1,1\nDA:2,0\nend_of_record\n");
         let data = parse_lcov(&root(), &content);
-        let file = data.get(FAKE_PATH).expect("file present");
+        let file = data.lines.get(FAKE_PATH).expect("file present");
         assert_eq!(file.get(&1), Some(&1));
         assert_eq!(file.get(&2), Some(&0));
     }
@@ -105,7 +162,7 @@ mod tests {
     fn flushes_unterminated_final_record() {
         let content = format!("SF:{FAKE_PATH}\nDA:2,1\n");
         let data = parse_lcov(&root(), &content);
-        assert_eq!(data.get(FAKE_PATH).and_then(|m| m.get(&2)), Some(&1));
+        assert_eq!(data.lines.get(FAKE_PATH).and_then(|m| m.get(&2)), Some(&1));
     }
 
     #[test]
@@ -114,7 +171,7 @@ mod tests {
             "SF:{FAKE_PATH}\nDA:1,1\nend_of_record\nSF:{FAKE_PATH}\nDA:2,3\nend_of_record\n"
         );
         let data = parse_lcov(&root(), &content);
-        let file = data.get(FAKE_PATH).expect("file present");
+        let file = data.lines.get(FAKE_PATH).expect("file present");
         assert_eq!(file.get(&1), Some(&1));
         assert_eq!(file.get(&2), Some(&3));
     }
@@ -123,7 +180,7 @@ mod tests {
     fn skips_malformed_da_lines() {
         let content = format!("SF:{FAKE_PATH}\nDA:abc\nDA:1,xyz\nDA:3,7\nend_of_record\n");
         let data = parse_lcov(&root(), &content);
-        let file = data.get(FAKE_PATH).expect("file present");
+        let file = data.lines.get(FAKE_PATH).expect("file present");
         assert_eq!(file.len(), 1);
         assert_eq!(file.get(&3), Some(&7));
     }
@@ -132,8 +189,28 @@ mod tests {
     fn ignores_da_before_sf() {
         let content = format!("DA:1,1\nSF:{FAKE_PATH}\nDA:2,1\nend_of_record\n");
         let data = parse_lcov(&root(), &content);
-        let file = data.get(FAKE_PATH).expect("file present");
+        let file = data.lines.get(FAKE_PATH).expect("file present");
         assert_eq!(file.len(), 1);
         assert_eq!(file.get(&2), Some(&1));
     }
+
+    #[test]
+    fn parses_branch_records() {
+        let content = format!(
+            "SF:{FAKE_PATH}\nDA:4,2\nBRDA:4,0,0,2\nBRDA:4,0,1,0\nBRDA:9,1,0,-\nBRDA:bad\nend_of_record\n"
+        );
+        let data = parse_lcov(&root(), &content);
+        let file = data.branches.get(FAKE_PATH).expect("branch data present");
+        assert_eq!(file.len(), 2);
+        assert_eq!(file[&4]["0,0"], 2);
+        assert_eq!(file[&4]["0,1"], 0);
+        assert_eq!(file[&9]["1,0"], 0, "`-` means the block never ran");
+        assert_eq!(data.lines.get(FAKE_PATH).and_then(|m| m.get(&4)), Some(&2));
+    }
+
+    #[test]
+    fn report_without_brda_has_no_branch_data() {
+        let content = format!("SF:{FAKE_PATH}\nDA:1,1\nend_of_record\n");
+        assert!(parse_lcov(&root(), &content).branches.is_empty());
+    }
 }
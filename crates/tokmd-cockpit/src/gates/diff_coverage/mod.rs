@@ -10,16 +10,16 @@
 //! The orchestrator is the only place that talks to git, the filesystem, and
 //! the rolled-up [`DiffCoverageGate`] result type.
 
-use std::path::Path;
-
 use anyhow::Result;
 use tokmd_types::cockpit::*;
 
+#[cfg(feature = "git")]
+use super::GateContext;
 use crate::round_pct;
 
-mod artifact;
+pub(super) mod artifact;
 mod intersect;
-mod lcov;
+pub(super) mod lcov;
 
 #[cfg(feature = "git")]
 const MAX_UNCOVERED_HUNKS: usize = 20;
@@ -27,26 +27,37 @@ const MAX_UNCOVERED_HUNKS: usize = 20;
 const COVERAGE_PASS_THRESHOLD: f64 = 0.80;
 #[cfg(feature = "git")]
 const COVERAGE_WARN_THRESHOLD: f64 = 0.50;
+/// Branch bands sit lower than line bands: error paths and defensive arms
+/// are rarely all exercised even in well-tested code.
+#[cfg(feature = "git")]
+const BRANCH_PASS_THRESHOLD: f64 = 0.70;
+#[cfg(feature = "git")]
+const BRANCH_WARN_THRESHOLD: f64 = 0.40;
 
 /// Compute diff coverage gate.
 ///
-/// Looks for coverage artifacts (lcov.info, coverage.json, cobertura.xml) and
-/// parses them. Only LCOV is currently parsed; other formats short-circuit
-/// to `Ok(None)`.
+/// Looks for coverage artifacts (`coverage_artifacts` first, then lcov.info,
+/// coverage.json, cobertura.xml) and parses them. Only LCOV is currently
+/// parsed; other formats short-circuit to `Ok(None)`. The gate records which
+/// artifact it read, and reports branch coverage of the added lines when the
+/// report carries `BRDA` records.
 #[cfg(feature = "git")]
 pub(in crate::gates) fn compute_diff_coverage_gate(
-    repo_root: &Path,
-    base: &str,
-    head: &str,
-    range_mode: tokmd_git::GitRangeMode,
+    ctx: &GateContext<'_>,
+    coverage_artifacts: &[String],
 ) -> Result<Option<DiffCoverageGate>> {
-    let added_lines = match tokmd_git::get_added_lines(repo_root, base, head, range_mode) {
+    let repo_root = ctx.repo_root;
+    let added_lines = match ctx
+        .host
+        .git
+        .added_lines(repo_root, ctx.base, ctx.head, ctx.range_mode)
+    {
         Ok(lines) if !lines.is_empty() => lines,
         Ok(_) => return Ok(None),
         Err(_) => return Ok(None),
     };
 
-    let Some(lcov_path) = artifact::find_lcov_artifact(repo_root) else {
+    let Some(lcov_path) = artifact::find_lcov_artifact(repo_root, coverage_artifacts) else {
         return Ok(None);
     };
 
@@ -57,7 +68,11 @@ pub(in crate::gates) fn compute_diff_coverage_gate(
 
     let lcov_data = lcov::parse_lcov(repo_root, &content);
 
-    let mut summary = intersect::intersect(&added_lines, &lcov_data);
+    let mut summary = intersect::intersect(&added_lines, &lcov_data.lines);
+    let branch_coverage = branch_coverage(intersect::intersect_branches(
+        &added_lines,
+        &lcov_data.branches,
+    ));
 
     if summary.total_added == 0 {
         return Ok(None);
@@ -75,7 +90,7 @@ pub(in crate::gates) fn compute_diff_coverage_gate(
             source: EvidenceSource::CiArtifact,
             commit_match: CommitMatch::Unknown,
             scope: ScopeCoverage {
-                relevant: lcov_data.keys().cloned().collect(),
+                relevant: lcov_data.lines.keys().cloned().collect(),
                 tested: summary.tested_files.into_iter().collect(),
                 ratio: coverage_pct,
                 lines_relevant: Some(summary.total_added),
@@ -87,10 +102,37 @@ pub(in crate::gates) fn compute_diff_coverage_gate(
         lines_added: summary.total_added,
         lines_covered: summary.total_covered,
         coverage_pct,
+        artifact: lcov_path
+            .strip_prefix(repo_root)
+            .ok()
+            .map(|rel| rel.to_string_lossy().replace('\\', "/")),
+        branch_coverage,
         uncovered_hunks: summary.uncovered_hunks,
     }))
 }
 
+/// Branch coverage from `(branches, taken)`; `None` without branch data.
+#[cfg(feature = "git")]
+fn branch_coverage((branches, taken): (usize, usize)) -> Option<BranchCoverage> {
+    if branches == 0 {
+        return None;
+    }
+    let coverage_pct = round_pct(taken as f64 / branches as f64);
+    let status = if coverage_pct >= BRANCH_PASS_THRESHOLD {
+        GateStatus::Pass
+    } else if coverage_pct >= BRANCH_WARN_THRESHOLD {
+        GateStatus::Warn
+    } else {
+        GateStatus::Fail
+    };
+    Some(BranchCoverage {
+        status,
+        branches_added: branches,
+        branches_covered: taken,
+        coverage_pct,
+    })
+}
+
 #[cfg(feature = "git")]
 fn coverage_status(pct: f64) -> GateStatus {
     if pct >= COVERAGE_PASS_THRESHOLD {
@@ -104,7 +146,11 @@ fn coverage_status(pct: f64) -> GateStatus {
 
 #[cfg(all(test, feature = "git"))]
 mod tests {
+    use std::path::Path;
+
     use super::*;
+    use crate::host::mock::MockGit;
+    use crate::host::{CockpitHost, GitProvider, HostCommands, HostGit};
 
     #[test]
     fn coverage_status_thresholds() {
@@ -116,8 +162,9 @@ mod tests {
         assert_eq!(coverage_status(0.0), GateStatus::Fail);
     }
 
-    #[test]
-    fn diff_coverage_gate_flushes_unterminated_final_lcov_record() {
+    /// A repo whose last commit adds line 2 of `src/lib.rs`, with `lcov`
+    /// written as `lcov.info`.
+    fn repo_adding_line_two(lcov: &str) -> tempfile::TempDir {
         let dir = tempfile::tempdir().unwrap();
         std::fs::create_dir_all(dir.path().join("src")).unwrap();
         std::fs::write(dir.path().join("src/lib.rs"), "fn a() {}\n").unwrap();
@@ -144,19 +191,95 @@ mod tests {
         git(&["add", "."]);
         git(&["commit", "-m", "head"]);
 
-        std::fs::write(dir.path().join("lcov.info"), "SF:src/lib.rs\nDA:2,1\n").unwrap();
+        std::fs::write(dir.path().join("lcov.info"), lcov).unwrap();
+        dir
+    }
 
-        let gate = compute_diff_coverage_gate(
-            dir.path(),
-            "HEAD~1",
-            "HEAD",
-            tokmd_git::GitRangeMode::TwoDot,
-        )
-        .unwrap()
-        .expect("diff coverage gate should exist");
+    fn ctx<'a>(git: &'a dyn GitProvider, repo_root: &'a Path, base: &'a str) -> GateContext<'a> {
+        GateContext {
+            host: CockpitHost {
+                git,
+                commands: &HostCommands,
+            },
+            repo_root,
+            base,
+            head: "HEAD",
+            range_mode: tokmd_git::GitRangeMode::TwoDot,
+            changed_files: &[],
+        }
+    }
+
+    fn gate_for(dir: &tempfile::TempDir) -> DiffCoverageGate {
+        compute_diff_coverage_gate(&ctx(&HostGit, dir.path(), "HEAD~1"), &[])
+            .unwrap()
+            .expect("diff coverage gate should exist")
+    }
+
+    #[test]
+    fn diff_coverage_gate_flushes_unterminated_final_lcov_record() {
+        let dir = repo_adding_line_two("SF:src/lib.rs\nDA:2,1\n");
+        let gate = gate_for(&dir);
 
         assert_eq!(gate.coverage_pct, 1.0);
+        assert_eq!(gate.artifact.as_deref(), Some("lcov.info"));
+        assert!(gate.branch_coverage.is_none(), "no BRDA records");
         assert_eq!(gate.meta.scope.lines_relevant, Some(1));
         assert_eq!(gate.meta.scope.lines_tested, Some(1));
     }
+
+    #[test]
+    fn diff_coverage_gate_reports_branch_coverage_of_added_lines() {
+        let dir = repo_adding_line_two(
+            "SF:src/lib.rs\nDA:1,1\nDA:2,1\n\
+             BRDA:1,0,0,1\nBRDA:1,0,1,1\n\
+             BRDA:2,0,0,4\nBRDA:2,0,1,0\nBRDA:2,1,0,-\n\
+             end_of_record\n",
+        );
+        let gate = gate_for(&dir);
+
+        assert_eq!(gate.coverage_pct, 1.0);
+        assert_eq!(gate.meta.status, GateStatus::Pass);
+        let branches = gate.branch_coverage.expect("branch data present");
+        assert_eq!(branches.branches_added, 3, "line 1 was not added");
+        assert_eq!(branches.branches_covered, 1);
+        assert_eq!(branches.coverage_pct, 0.33);
+        assert_eq!(branches.status, GateStatus::Fail);
+    }
+
+    #[test]
+    fn branch_coverage_bands() {
+        assert_eq!(branch_coverage((0, 0)), None);
+        let half = branch_coverage((4, 2)).unwrap();
+        assert_eq!(half.coverage_pct, 0.5);
+        assert_eq!(half.status, GateStatus::Warn);
+        assert_eq!(branch_coverage((10, 7)).unwrap().status, GateStatus::Pass);
+        assert_eq!(branch_coverage((10, 3)).unwrap().status, GateStatus::Fail);
+    }
+
+    #[test]
+    fn added_lines_come_from_the_git_provider() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("lcov.info"),
+            "SF:src/lib.rs\nDA:2,1\nDA:3,0\nend_of_record\n",
+        )
+        .unwrap();
+        let git = MockGit {
+            added: [("src/lib.rs".into(), [2, 3].into())].into(),
+            ..MockGit::default()
+        };
+
+        let gate = compute_diff_coverage_gate(&ctx(&git, dir.path(), "main"), &[])
+            .unwrap()
+            .expect("diff coverage gate should exist");
+
+        assert_eq!(gate.lines_added, 2);
+        assert_eq!(gate.lines_covered, 1);
+        assert_eq!(gate.meta.status, GateStatus::Warn);
+        assert_eq!(gate.uncovered_hunks[0].start_line, 3);
+
+        let no_diff =
+            compute_diff_coverage_gate(&ctx(&MockGit::default(), dir.path(), "main"), &[]).unwrap();
+        assert!(no_diff.is_none());
+    }
 }
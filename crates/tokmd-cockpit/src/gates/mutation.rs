@@ -1,30 +1,12 @@
-use std::path::{Path, PathBuf};
+use std::path::Path;
 
-use anyhow::{Context, Result, bail};
+use anyhow::Result;
 use serde::Deserialize;
 use tokmd_types::cockpit::*;
 
-use super::rust_source::is_relevant_rust_source;
-use crate::FileStat;
-
-/// Get the current HEAD commit hash.
-#[cfg(feature = "git")]
-fn get_head_commit(repo_root: &PathBuf) -> Result<String> {
-    let output = tokmd_git::git_cmd()
-        .arg("-C")
-        .arg(repo_root)
-        .arg("rev-parse")
-        .arg("HEAD")
-        .output()
-        .context("Failed to run git rev-parse HEAD")?;
-
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        bail!("git rev-parse HEAD failed: {}", stderr.trim());
-    }
-
-    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
-}
+use super::GateContext;
+use super::rust_source::RustSourceFilter;
+use crate::host::GitProvider;
 
 /// CI workflow summary format (mutants-summary.json).
 #[derive(Debug, Clone, Deserialize)]
@@ -48,18 +30,23 @@ struct CiSurvivor {
 }
 
 /// Compute the mutation gate status.
+///
+/// Changed files outside `rust_source` are ignored; a CI artifact from an
+/// ancestor up to `artifact_staleness_window` commits back still counts.
 #[cfg(feature = "git")]
 pub(super) fn compute_mutation_gate(
-    repo_root: &PathBuf,
-    _base: &str,
-    _head: &str,
-    changed_files: &[FileStat],
-    _range_mode: tokmd_git::GitRangeMode,
+    ctx: &GateContext<'_>,
+    rust_source: &RustSourceFilter,
+    artifact_staleness_window: usize,
 ) -> Result<MutationGate> {
+    let git = ctx.host.git;
+    let repo_root = ctx.repo_root;
+
     // Filter to relevant Rust source files
-    let relevant_files: Vec<String> = changed_files
+    let relevant_files: Vec<String> = ctx
+        .changed_files
         .iter()
-        .filter(|f| is_relevant_rust_source(&f.path))
+        .filter(|f| rust_source.is_relevant(&f.path))
         .map(|f| f.path.clone())
         .collect();
 
@@ -87,10 +74,16 @@ pub(super) fn compute_mutation_gate(
         });
     }
 
-    let head_commit = get_head_commit(repo_root)?;
+    let head_commit = git.head_commit(repo_root)?;
 
     // Try to find cached results
-    if let Some(gate) = try_load_ci_artifact(repo_root, &head_commit, &relevant_files)? {
+    if let Some(gate) = try_load_ci_artifact(
+        git,
+        repo_root,
+        &head_commit,
+        &relevant_files,
+        artifact_staleness_window,
+    )? {
         return Ok(gate);
     }
 
@@ -104,11 +97,17 @@ pub(super) fn compute_mutation_gate(
 
 /// Try to load mutation results from CI artifact.
 /// Checks for mutants-summary.json (our format) first, then falls back to mutants.out/outcomes.json.
+///
+/// An artifact from another commit is dropped unless that commit is an
+/// ancestor of HEAD at most `staleness_window` commits back, in which case it
+/// is used and marked [`CommitMatch::Stale`].
 #[cfg(feature = "git")]
 fn try_load_ci_artifact(
+    git: &dyn GitProvider,
     repo_root: &Path,
     head_commit: &str,
     relevant_files: &[String],
+    staleness_window: usize,
 ) -> Result<Option<MutationGate>> {
     // First, check for our summary format (mutants-summary.json)
     let summary_path = repo_root.join("mutants-summary.json");
@@ -116,19 +115,22 @@ fn try_load_ci_artifact(
         && let Ok(content) = std::fs::read_to_string(&summary_path)
         && let Ok(summary) = serde_json::from_str::<CiMutantsSummary>(&content)
     {
-        // Determine commit match quality
+        // Determine commit match quality; skip stale artifacts outside the window
         let commit_match = if summary.commit.starts_with(head_commit)
             || head_commit.starts_with(&summary.commit)
         {
             CommitMatch::Exact
-        } else {
+        } else if within_staleness_window(
+            git,
+            repo_root,
+            &summary.commit,
+            head_commit,
+            staleness_window,
+        ) {
             CommitMatch::Stale
-        };
-
-        // Skip stale artifacts
-        if commit_match == CommitMatch::Stale {
+        } else {
             return Ok(None);
-        }
+        };
 
         let status = match summary.status.as_str() {
             "pass" => GateStatus::Pass,
@@ -181,6 +183,27 @@ fn try_load_ci_artifact(
     }
 }
 
+/// Whether `artifact_commit` is an ancestor of `head_commit` no more than
+/// `window` commits back.
+#[cfg(feature = "git")]
+fn within_staleness_window(
+    git: &dyn GitProvider,
+    repo_root: &Path,
+    artifact_commit: &str,
+    head_commit: &str,
+    window: usize,
+) -> bool {
+    if window == 0
+        || artifact_commit.is_empty()
+        || !artifact_commit.chars().all(|c| c.is_ascii_hexdigit())
+        || !git.is_ancestor(repo_root, artifact_commit, head_commit)
+    {
+        return false;
+    }
+    git.commit_count(repo_root, &format!("{artifact_commit}..{head_commit}"))
+        .is_ok_and(|behind| behind <= window)
+}
+
 /// Try to load cached mutation results.
 #[cfg(feature = "git")]
 fn try_load_cached(
@@ -263,6 +286,9 @@ fn run_mutations(_repo_root: &Path, relevant_files: &[String]) -> Result<Mutatio
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::FileStat;
+    use crate::host::mock::{MockCommands, MockGit};
+    use crate::host::{CockpitHost, HostGit};
 
     fn cached_mutation_gate(tested: Vec<String>, evidence_commit: Option<&str>) -> MutationGate {
         MutationGate {
@@ -337,6 +363,77 @@ mod tests {
         assert!(loaded.is_none());
     }
 
+    /// Repository with two commits; returns the dir and the (base, head) SHAs.
+    fn repo_with_two_commits() -> (tempfile::TempDir, String, String) {
+        let dir = tempfile::tempdir().unwrap();
+        let git = |args: &[&str]| {
+            let output = tokmd_git::git_cmd()
+                .args(args)
+                .current_dir(dir.path())
+                .output()
+                .unwrap();
+            assert!(output.status.success(), "git {:?} failed", args);
+            String::from_utf8_lossy(&output.stdout).trim().to_string()
+        };
+        git(&["init", "-b", "main"]);
+        git(&["config", "user.email", "tokmd@example.com"]);
+        git(&["config", "user.name", "tokmd"]);
+        git(&["config", "commit.gpgsign", "false"]);
+        git(&["commit", "--allow-empty", "-m", "base"]);
+        let base = git(&["rev-parse", "HEAD"]);
+        git(&["commit", "--allow-empty", "-m", "rebased"]);
+        let head = git(&["rev-parse", "HEAD"]);
+        (dir, base, head)
+    }
+
+    fn write_ci_summary(repo_root: &Path, commit: &str) {
+        let summary = serde_json::json!({
+            "commit": commit,
+            "status": "pass",
+            "scope": ["src/lib.rs"],
+            "survivors": [],
+            "killed": 7,
+            "timeout": 0,
+            "unviable": 0,
+        });
+        std::fs::write(
+            repo_root.join("mutants-summary.json"),
+            serde_json::to_string(&summary).unwrap(),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn ancestor_artifact_is_used_only_within_the_staleness_window() {
+        let (dir, base, head) = repo_with_two_commits();
+        write_ci_summary(dir.path(), &base);
+        let relevant = ["src/lib.rs".to_string()];
+
+        let strict = try_load_ci_artifact(&HostGit, dir.path(), &head, &relevant, 0).unwrap();
+        assert!(strict.is_none());
+
+        let permissive = try_load_ci_artifact(&HostGit, dir.path(), &head, &relevant, 1)
+            .unwrap()
+            .expect("ancestor artifact within the window should load");
+        assert_eq!(permissive.meta.commit_match, CommitMatch::Stale);
+        assert_eq!(
+            permissive.meta.evidence_commit.as_deref(),
+            Some(base.as_str())
+        );
+        assert_eq!(permissive.killed, 7);
+    }
+
+    #[test]
+    fn descendant_artifact_is_never_used() {
+        let (dir, base, head) = repo_with_two_commits();
+        write_ci_summary(dir.path(), &head);
+
+        let loaded =
+            try_load_ci_artifact(&HostGit, dir.path(), &base, &["src/lib.rs".to_string()], 10)
+                .unwrap();
+        assert!(loaded.is_none());
+    }
+
     #[test]
     fn cache_misses_for_invalid_json() {
         let dir = tempfile::tempdir().unwrap();
@@ -347,4 +444,43 @@ mod tests {
 
         assert!(loaded.is_none());
     }
+
+    #[test]
+    fn staleness_window_is_judged_by_the_git_provider() {
+        let dir = tempfile::tempdir().unwrap();
+        write_ci_summary(dir.path(), "aaaa");
+        let git = MockGit {
+            head: "cccc".to_string(),
+            ancestry: vec![("aaaa".to_string(), "cccc".to_string())],
+            commits: [("aaaa..cccc".to_string(), 2)].into(),
+            ..MockGit::default()
+        };
+        let changed = [FileStat {
+            path: "src/lib.rs".to_string(),
+            insertions: 1,
+            deletions: 0,
+        }];
+        let ctx = GateContext {
+            host: CockpitHost {
+                git: &git,
+                commands: &MockCommands::default(),
+            },
+            repo_root: dir.path(),
+            base: "main",
+            head: "HEAD",
+            range_mode: tokmd_git::GitRangeMode::TwoDot,
+            changed_files: &changed,
+        };
+        let filter = RustSourceFilter::new(&Default::default()).unwrap();
+        let gate = |window| compute_mutation_gate(&ctx, &filter, window).unwrap();
+
+        let stale = gate(2);
+        assert_eq!(stale.meta.source, EvidenceSource::CiArtifact);
+        assert_eq!(stale.meta.commit_match, CommitMatch::Stale);
+        assert_eq!(stale.killed, 7);
+
+        let too_old = gate(1);
+        assert_eq!(too_old.meta.status, GateStatus::Pending);
+        assert_eq!(too_old.meta.source, EvidenceSource::RanLocal);
+    }
 }
@@ -0,0 +1,259 @@
+//! Whole-project coverage gate.
+//!
+//! Reads the same coverage report as the diff-coverage gate (same search
+//! order, same LCOV parser) but totals every file in it, so the gate tracks
+//! overall coverage independent of what the diff touched.
+
+#[cfg(feature = "git")]
+use std::path::Path;
+
+#[cfg(feature = "git")]
+use anyhow::Result;
+#[cfg(feature = "git")]
+use tokmd_types::cockpit::*;
+
+#[cfg(feature = "git")]
+use super::diff_coverage::{artifact, lcov};
+#[cfg(feature = "git")]
+use crate::round_pct;
+
+/// Pass/warn bands for the project coverage gate, as ratios (0.0-1.0).
+///
+/// Coverage at or above `*_pass` passes, at or above `*_warn` warns, and
+/// anything lower fails. The gate status is the worse of the line and branch
+/// statuses; branch bands apply only when the report has branch data.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ProjectCoverageThresholds {
+    pub line_pass: f64,
+    pub line_warn: f64,
+    pub branch_pass: f64,
+    pub branch_warn: f64,
+}
+
+impl Default for ProjectCoverageThresholds {
+    fn default() -> Self {
+        Self {
+            line_pass: 0.80,
+            line_warn: 0.50,
+            branch_pass: 0.70,
+            branch_warn: 0.40,
+        }
+    }
+}
+
+/// Compute the project coverage gate.
+///
+/// Returns `Ok(None)` when no LCOV report is found or it records no lines.
+#[cfg(feature = "git")]
+pub(in crate::gates) fn compute_project_coverage_gate(
+    repo_root: &Path,
+    coverage_artifacts: &[String],
+    thresholds: &ProjectCoverageThresholds,
+) -> Result<Option<ProjectCoverageGate>> {
+    let Some(lcov_path) = artifact::find_lcov_artifact(repo_root, coverage_artifacts) else {
+        return Ok(None);
+    };
+    let Ok(content) = std::fs::read_to_string(&lcov_path) else {
+        return Ok(None);
+    };
+    let report = lcov::parse_lcov(repo_root, &content);
+
+    let mut gate = match project_coverage(&report, thresholds) {
+        Some(gate) => gate,
+        None => return Ok(None),
+    };
+    gate.artifact = lcov_path
+        .strip_prefix(repo_root)
+        .ok()
+        .map(|rel| rel.to_string_lossy().replace('\\', "/"));
+    Ok(Some(gate))
+}
+
+/// Total a parsed report into a gate; `None` when it records no lines.
+#[cfg(feature = "git")]
+fn project_coverage(
+    report: &lcov::LcovReport,
+    thresholds: &ProjectCoverageThresholds,
+) -> Option<ProjectCoverageGate> {
+    let lines_found: usize = report.lines.values().map(|lines| lines.len()).sum();
+    if lines_found == 0 {
+        return None;
+    }
+    let lines_hit = report
+        .lines
+        .values()
+        .flat_map(|lines| lines.values())
+        .filter(|&&count| count > 0)
+        .count();
+    let coverage_pct = round_pct(lines_hit as f64 / lines_found as f64);
+    let mut status = band(coverage_pct, thresholds.line_pass, thresholds.line_warn);
+
+    let branch_counts: Vec<usize> = report
+        .branches
+        .values()
+        .flat_map(|lines| lines.values())
+        .flat_map(|branches| branches.values().copied())
+        .collect();
+    let (branches_found, branches_hit, branch_coverage_pct) = if branch_counts.is_empty() {
+        (None, None, None)
+    } else {
+        let found = branch_counts.len();
+        let hit = branch_counts.iter().filter(|&&count| count > 0).count();
+        let pct = round_pct(hit as f64 / found as f64);
+        status = worse(
+            status,
+            band(pct, thresholds.branch_pass, thresholds.branch_warn),
+        );
+        (Some(found), Some(hit), Some(pct))
+    };
+
+    let tested = report
+        .lines
+        .iter()
+        .filter(|(_, lines)| lines.values().any(|&count| count > 0))
+        .map(|(path, _)| path.clone())
+        .collect();
+
+    Some(ProjectCoverageGate {
+        meta: GateMeta {
+            status,
+            source: EvidenceSource::CiArtifact,
+            commit_match: CommitMatch::Unknown,
+            scope: ScopeCoverage {
+                relevant: report.lines.keys().cloned().collect(),
+                tested,
+                ratio: coverage_pct,
+                lines_relevant: Some(lines_found),
+                lines_tested: Some(lines_hit),
+            },
+            evidence_commit: None,
+            evidence_generated_at_ms: None,
+        },
+        files: report.lines.len(),
+        lines_found,
+        lines_hit,
+        coverage_pct,
+        branches_found,
+        branches_hit,
+        branch_coverage_pct,
+        artifact: None,
+    })
+}
+
+#[cfg(feature = "git")]
+fn band(pct: f64, pass: f64, warn: f64) -> GateStatus {
+    if pct >= pass {
+        GateStatus::Pass
+    } else if pct >= warn {
+        GateStatus::Warn
+    } else {
+        GateStatus::Fail
+    }
+}
+
+#[cfg(feature = "git")]
+fn worse(a: GateStatus, b: GateStatus) -> GateStatus {
+    let rank = |status: GateStatus| match status {
+        GateStatus::Fail => 2,
+        GateStatus::Warn => 1,
+        _ => 0,
+    };
+    if rank(b) > rank(a) { b } else { a }
+}
+
+#[cfg(all(test, feature = "git"))]
+mod tests {
+    use super::*;
+
+    const LCOV: &str = "\
+SF:src/lib.rs
+DA:1,3
+DA:2,0
+DA:3,1
+end_of_record
+SF:src/untouched.rs
+DA:1,0
+DA:2,0
+DA:3,5
+DA:4,5
+end_of_record
+";
+
+    #[test]
+    fn totals_every_file_in_the_artifact_without_a_diff() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("lcov.info"), LCOV).unwrap();
+
+        let gate =
+            compute_project_coverage_gate(dir.path(), &[], &ProjectCoverageThresholds::default())
+                .unwrap()
+                .expect("project coverage gate should exist");
+
+        assert_eq!(gate.files, 2);
+        assert_eq!(gate.lines_found, 7);
+        assert_eq!(gate.lines_hit, 4);
+        assert_eq!(gate.coverage_pct, 0.57);
+        assert_eq!(gate.meta.status, GateStatus::Warn);
+        assert_eq!(gate.meta.scope.tested.len(), 2);
+        assert_eq!(gate.artifact.as_deref(), Some("lcov.info"));
+        assert_eq!(gate.branches_found, None);
+    }
+
+    #[test]
+    fn thresholds_are_configurable() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("lcov.info"), LCOV).unwrap();
+        let lenient = ProjectCoverageThresholds {
+            line_pass: 0.55,
+            ..ProjectCoverageThresholds::default()
+        };
+
+        let gate = compute_project_coverage_gate(dir.path(), &[], &lenient)
+            .unwrap()
+            .unwrap();
+        assert_eq!(gate.meta.status, GateStatus::Pass);
+    }
+
+    #[test]
+    fn weak_branch_coverage_lowers_the_status() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("lcov.info"),
+            "SF:src/lib.rs\nDA:1,1\nDA:2,1\nBRDA:1,0,0,1\nBRDA:1,0,1,0\nBRDA:2,0,0,-\nend_of_record\n",
+        )
+        .unwrap();
+
+        let gate =
+            compute_project_coverage_gate(dir.path(), &[], &ProjectCoverageThresholds::default())
+                .unwrap()
+                .unwrap();
+
+        assert_eq!(gate.coverage_pct, 1.0);
+        assert_eq!(gate.branches_found, Some(3));
+        assert_eq!(gate.branches_hit, Some(1));
+        assert_eq!(gate.branch_coverage_pct, Some(0.33));
+        assert_eq!(gate.meta.status, GateStatus::Fail);
+    }
+
+    #[test]
+    fn missing_or_empty_report_yields_no_gate() {
+        let dir = tempfile::tempdir().unwrap();
+        let thresholds = ProjectCoverageThresholds::default();
+        assert!(
+            compute_project_coverage_gate(dir.path(), &[], &thresholds)
+                .unwrap()
+                .is_none()
+        );
+
+        std::fs::write(
+            dir.path().join("lcov.info"),
+            "SF:src/lib.rs\nend_of_record\n",
+        )
+        .unwrap();
+        assert!(
+            compute_project_coverage_gate(dir.path(), &[], &thresholds)
+                .unwrap()
+                .is_none()
+        );
+    }
+}
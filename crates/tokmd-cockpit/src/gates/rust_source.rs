@@ -1,28 +1,135 @@
-/// Check if a file is a relevant Rust source file for mutation testing.
-/// Excludes test files, fuzz targets, etc.
-#[cfg(feature = "git")]
-pub(super) fn is_relevant_rust_source(path: &str) -> bool {
-    let path_lower = path.to_lowercase();
+//! Which changed files the source gates (mutation, complexity) consider.
+
+use anyhow::{Context, Result};
+use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
+
+/// Include/exclude globs adjusting which changed files the mutation and
+/// complexity gates consider.
+///
+/// Patterns are repo-relative. A file matching `include` is in scope even
+/// where the built-in rules would skip it (e.g. `tests/**`); a file matching
+/// `exclude` is out of scope, and exclusion wins. Files matching neither fall
+/// back to the built-in rules, which skip test directories, `*_test.*` and
+/// `*_tests.*` files, and fuzz targets. The mutation gate only ever considers
+/// `.rs` files; the complexity gate takes any language it can score.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RustSourceScope {
+    pub include: Vec<String>,
+    pub exclude: Vec<String>,
+}
+
+/// Compiled form of a [`RustSourceScope`].
+pub(super) struct RustSourceFilter {
+    include: GlobSet,
+    exclude: GlobSet,
+}
+
+impl RustSourceFilter {
+    pub(super) fn new(scope: &RustSourceScope) -> Result<Self> {
+        Ok(Self {
+            include: glob_set(&scope.include)?,
+            exclude: glob_set(&scope.exclude)?,
+        })
+    }
+
+    /// Whether `path` is a Rust source file in scope.
+    pub(super) fn is_relevant(&self, path: &str) -> bool {
+        path.to_lowercase().ends_with(".rs") && self.is_in_scope(path)
+    }
+
+    /// Whether `path` is in scope, whatever its language.
+    pub(super) fn is_in_scope(&self, path: &str) -> bool {
+        let path = path.replace('\\', "/");
+        if self.exclude.is_match(&path) {
+            return false;
+        }
+        self.include.is_match(&path) || !is_test_or_fuzz_path(&path)
+    }
+}
 
-    // Must be a .rs file
-    if !path_lower.ends_with(".rs") {
-        return false;
+fn glob_set(patterns: &[String]) -> Result<GlobSet> {
+    let mut builder = GlobSetBuilder::new();
+    for pattern in patterns {
+        let glob = GlobBuilder::new(pattern)
+            .literal_separator(true)
+            .build()
+            .with_context(|| format!("invalid Rust source glob: {pattern}"))?;
+        builder.add(glob);
     }
+    builder
+        .build()
+        .context("failed to compile Rust source globs")
+}
+
+/// Built-in rules: test directories, test files, and fuzz targets are skipped.
+fn is_test_or_fuzz_path(path: &str) -> bool {
+    let path_lower = path.to_lowercase();
 
-    // Exclude test directories
+    // Test directories
     if path_lower.contains("/tests/") || path_lower.starts_with("tests/") {
-        return false;
+        return true;
     }
 
-    // Exclude test files
-    if path_lower.ends_with("_test.rs") || path_lower.ends_with("_tests.rs") {
-        return false;
+    // Test files (`parser_test.rs`, `handler_test.go`, ...)
+    let stem = std::path::Path::new(&path_lower)
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .unwrap_or_default();
+    if stem.ends_with("_test") || stem.ends_with("_tests") {
+        return true;
     }
 
-    // Exclude fuzz targets
-    if path_lower.contains("/fuzz/") || path_lower.starts_with("fuzz/") {
-        return false;
+    // Fuzz targets
+    path_lower.contains("/fuzz/") || path_lower.starts_with("fuzz/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn filter(include: &[&str], exclude: &[&str]) -> RustSourceFilter {
+        RustSourceFilter::new(&RustSourceScope {
+            include: include.iter().map(|p| p.to_string()).collect(),
+            exclude: exclude.iter().map(|p| p.to_string()).collect(),
+        })
+        .unwrap()
     }
 
-    true
+    #[test]
+    fn default_scope_keeps_the_built_in_rules() {
+        let scope = filter(&[], &[]);
+        assert!(scope.is_relevant("src/lib.rs"));
+        assert!(!scope.is_relevant("tests/integration.rs"));
+        assert!(!scope.is_relevant("src/parser_test.rs"));
+        assert!(!scope.is_relevant("fuzz/fuzz_targets/parse.rs"));
+        assert!(!scope.is_relevant("README.md"));
+        assert!(scope.is_in_scope("web/app.ts"));
+        assert!(!scope.is_in_scope("pkg/handler_test.go"));
+    }
+
+    #[test]
+    fn include_pattern_brings_tests_into_scope() {
+        let scope = filter(&["tests/**", "crates/*/tests/**"], &[]);
+        assert!(scope.is_relevant("tests/integration.rs"));
+        assert!(scope.is_relevant("crates/core/tests/api.rs"));
+        assert!(!scope.is_relevant("fuzz/fuzz_targets/parse.rs"));
+        assert!(!scope.is_relevant("tests/fixtures/data.json"));
+    }
+
+    #[test]
+    fn exclude_pattern_wins_over_include_and_defaults() {
+        let scope = filter(&["tests/**"], &["tests/fixtures/**", "spec/**"]);
+        assert!(!scope.is_relevant("tests/fixtures/sample.rs"));
+        assert!(!scope.is_relevant("spec/parser.rs"));
+        assert!(scope.is_relevant("tests/smoke.rs"));
+    }
+
+    #[test]
+    fn invalid_glob_is_an_error() {
+        let scope = RustSourceScope {
+            include: vec!["src/[".to_string()],
+            exclude: vec![],
+        };
+        assert!(RustSourceFilter::new(&scope).is_err());
+    }
 }
@@ -5,17 +5,48 @@ use tokmd_types::cockpit::{
     CodeHealth, ComplexityIndicator, Contracts, HealthWarning, WarningType,
 };
 
-/// Compute code health metrics.
+/// Thresholds that decide when a change counts as large and how many large
+/// changes raise the complexity indicator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HealthThresholds {
+    /// Changed lines (insertions plus deletions) above which a file is large.
+    pub large_file_lines: usize,
+    /// More large files than this makes the indicator `High`.
+    pub high_large_files: usize,
+    /// More large files than this makes the indicator `Critical`.
+    pub critical_large_files: usize,
+}
+
+impl Default for HealthThresholds {
+    fn default() -> Self {
+        Self {
+            large_file_lines: 500,
+            high_large_files: 2,
+            critical_large_files: 5,
+        }
+    }
+}
+
+/// Compute code health metrics with the default [`HealthThresholds`].
 pub fn compute_code_health(file_stats: &[FileStat], contracts: &Contracts) -> CodeHealth {
+    compute_code_health_with(file_stats, contracts, &HealthThresholds::default())
+}
+
+/// Compute code health metrics, judging file size by `thresholds`.
+pub fn compute_code_health_with(
+    file_stats: &[FileStat],
+    contracts: &Contracts,
+    thresholds: &HealthThresholds,
+) -> CodeHealth {
+    let is_large = |stat: &FileStat| stat.insertions + stat.deletions > thresholds.large_file_lines;
     let mut large_files_touched = 0;
     let mut total_lines = 0;
 
     for stat in file_stats {
-        let lines = stat.insertions + stat.deletions;
-        if lines > 500 {
+        if is_large(stat) {
             large_files_touched += 1;
         }
-        total_lines += lines;
+        total_lines += stat.insertions + stat.deletions;
     }
 
     let avg_file_size = if !file_stats.is_empty() {
@@ -24,9 +55,9 @@ pub fn compute_code_health(file_stats: &[FileStat], contracts: &Contracts) -> Co
         0
     };
 
-    let complexity_indicator = if large_files_touched > 5 {
+    let complexity_indicator = if large_files_touched > thresholds.critical_large_files {
         ComplexityIndicator::Critical
-    } else if large_files_touched > 2 {
+    } else if large_files_touched > thresholds.high_large_files {
         ComplexityIndicator::High
     } else if large_files_touched > 0 {
         ComplexityIndicator::Medium
@@ -34,16 +65,15 @@ pub fn compute_code_health(file_stats: &[FileStat], contracts: &Contracts) -> Co
         ComplexityIndicator::Low
     };
 
-    let mut warnings = Vec::new();
-    for stat in file_stats {
-        if stat.insertions + stat.deletions > 500 {
-            warnings.push(HealthWarning {
-                path: stat.path.clone(),
-                warning_type: WarningType::LargeFile,
-                message: "Large file touched".to_string(),
-            });
-        }
-    }
+    let warnings: Vec<HealthWarning> = file_stats
+        .iter()
+        .filter(|stat| is_large(stat))
+        .map(|stat| HealthWarning {
+            path: stat.path.clone(),
+            warning_type: WarningType::LargeFile,
+            message: "Large file touched".to_string(),
+        })
+        .collect();
 
     let mut score: u32 = 100;
     score = score.saturating_sub((large_files_touched * 10) as u32);
@@ -154,4 +184,55 @@ mod tests {
         let health = compute_code_health(&stats, &contracts);
         assert_eq!(health.complexity_indicator, ComplexityIndicator::Medium);
     }
+
+    #[test]
+    fn lower_threshold_flags_a_300_line_change_as_large() {
+        let contracts = Contracts {
+            api_changed: false,
+            cli_changed: false,
+            schema_changed: false,
+            breaking_indicators: 0,
+        };
+        let stats = vec![make_stat("src/mid.rs", 200, 100)];
+
+        let default = compute_code_health(&stats, &contracts);
+        assert_eq!(default.large_files_touched, 0);
+        assert!(default.warnings.is_empty());
+
+        let strict = HealthThresholds {
+            large_file_lines: 250,
+            ..HealthThresholds::default()
+        };
+        let health = compute_code_health_with(&stats, &contracts, &strict);
+        assert_eq!(health.large_files_touched, 1);
+        assert_eq!(health.warnings.len(), 1);
+        assert_eq!(health.warnings[0].path, "src/mid.rs");
+        assert_eq!(health.complexity_indicator, ComplexityIndicator::Medium);
+        assert_eq!(health.score, 90);
+    }
+
+    #[test]
+    fn indicator_bands_follow_configured_counts() {
+        let contracts = Contracts {
+            api_changed: false,
+            cli_changed: false,
+            schema_changed: false,
+            breaking_indicators: 0,
+        };
+        let stats: Vec<FileStat> = (0..2)
+            .map(|i| make_stat(&format!("src/f{i}.rs"), 600, 0))
+            .collect();
+        let thresholds = HealthThresholds {
+            high_large_files: 0,
+            critical_large_files: 1,
+            ..HealthThresholds::default()
+        };
+
+        let health = compute_code_health_with(&stats, &contracts, &thresholds);
+        assert_eq!(health.complexity_indicator, ComplexityIndicator::Critical);
+        assert_eq!(
+            compute_code_health(&stats, &contracts).complexity_indicator,
+            ComplexityIndicator::Medium
+        );
+    }
 }
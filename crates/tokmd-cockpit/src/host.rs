@@ -0,0 +1,260 @@
+//! Host ports for the cockpit's git and tool subprocesses.
+//!
+//! Cockpit computation reaches git and external tools (`cargo audit`,
+//! `cargo semver-checks`) only through [`GitProvider`] and [`CommandRunner`],
+//! so embedders without those tools can supply their own backends and tests
+//! can drive every gate without spawning a process. [`HostGit`] and
+//! [`HostCommands`] are the real implementations and the defaults.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use anyhow::{Context, Result, bail};
+
+use crate::FileStat;
+
+// ---------------------------------------------------------------------------
+// Traits
+// ---------------------------------------------------------------------------
+
+/// Git queries the cockpit needs.
+pub trait GitProvider: Sync {
+    /// Insertions and deletions per file changed in `range`.
+    fn diff_numstat(&self, repo_root: &Path, range: &str) -> Result<Vec<FileStat>>;
+
+    /// Number of commits in `range`.
+    fn commit_count(&self, repo_root: &Path, range: &str) -> Result<usize>;
+
+    /// Line numbers added between `base` and `head`, keyed by repo-relative path.
+    fn added_lines(
+        &self,
+        repo_root: &Path,
+        base: &str,
+        head: &str,
+        range_mode: tokmd_git::GitRangeMode,
+    ) -> Result<BTreeMap<PathBuf, BTreeSet<usize>>>;
+
+    /// Full hash of the commit checked out at HEAD.
+    fn head_commit(&self, repo_root: &Path) -> Result<String>;
+
+    /// Whether `ancestor` is reachable from `descendant`.
+    fn is_ancestor(&self, repo_root: &Path, ancestor: &str, descendant: &str) -> bool;
+
+    /// Unified diff of one repo-relative `path` over `range`; empty when the
+    /// file did not change.
+    fn diff_path(&self, repo_root: &Path, range: &str, path: &str) -> Result<String>;
+}
+
+/// Captured result of an external command.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CommandOutput {
+    /// Whether the command exited with status 0.
+    pub success: bool,
+    pub stdout: String,
+    pub stderr: String,
+}
+
+/// Runs external tools such as `cargo audit`.
+pub trait CommandRunner: Sync {
+    /// Run `program` with `args` in `cwd`. Returns `Err` only when the command
+    /// cannot be started; a non-zero exit is reported through
+    /// [`CommandOutput::success`].
+    fn run(&self, program: &str, args: &[&str], cwd: &Path) -> std::io::Result<CommandOutput>;
+}
+
+/// Git and command backends for one cockpit computation.
+#[derive(Clone, Copy)]
+pub struct CockpitHost<'a> {
+    pub git: &'a dyn GitProvider,
+    pub commands: &'a dyn CommandRunner,
+}
+
+impl Default for CockpitHost<'static> {
+    fn default() -> Self {
+        Self {
+            git: &HostGit,
+            commands: &HostCommands,
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Host implementations
+// ---------------------------------------------------------------------------
+
+/// [`GitProvider`] backed by the `git` binary (through `tokmd-git`).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HostGit;
+
+impl GitProvider for HostGit {
+    fn diff_numstat(&self, repo_root: &Path, range: &str) -> Result<Vec<FileStat>> {
+        Ok(tokmd_git::diff_numstat(repo_root, range, &[])?
+            .into_iter()
+            .map(|stat| FileStat {
+                path: stat.path,
+                insertions: stat.insertions,
+                deletions: stat.deletions,
+            })
+            .collect())
+    }
+
+    fn commit_count(&self, repo_root: &Path, range: &str) -> Result<usize> {
+        tokmd_git::commit_count(repo_root, range)
+    }
+
+    fn added_lines(
+        &self,
+        repo_root: &Path,
+        base: &str,
+        head: &str,
+        range_mode: tokmd_git::GitRangeMode,
+    ) -> Result<BTreeMap<PathBuf, BTreeSet<usize>>> {
+        tokmd_git::get_added_lines(repo_root, base, head, range_mode)
+    }
+
+    fn head_commit(&self, repo_root: &Path) -> Result<String> {
+        let output = tokmd_git::git_cmd()
+            .arg("-C")
+            .arg(repo_root)
+            .arg("rev-parse")
+            .arg("HEAD")
+            .output()
+            .context("Failed to run git rev-parse HEAD")?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            bail!("git rev-parse HEAD failed: {}", stderr.trim());
+        }
+
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    }
+
+    fn is_ancestor(&self, repo_root: &Path, ancestor: &str, descendant: &str) -> bool {
+        tokmd_git::is_ancestor(repo_root, ancestor, descendant)
+    }
+
+    fn diff_path(&self, repo_root: &Path, range: &str, path: &str) -> Result<String> {
+        let output = tokmd_git::git_cmd()
+            .arg("-C")
+            .arg(repo_root)
+            .args(["diff", range, "--", path])
+            .output()
+            .context("Failed to run git diff")?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            bail!("git diff {range} -- {path} failed: {}", stderr.trim());
+        }
+
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    }
+}
+
+/// [`CommandRunner`] that spawns real processes.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HostCommands;
+
+impl CommandRunner for HostCommands {
+    fn run(&self, program: &str, args: &[&str], cwd: &Path) -> std::io::Result<CommandOutput> {
+        let output = Command::new(program).args(args).current_dir(cwd).output()?;
+        Ok(CommandOutput {
+            success: output.status.success(),
+            stdout: String::from_utf8_lossy(&output.stdout).into_owned(),
+            stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
+        })
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Test doubles
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+pub(crate) mod mock {
+    //! Canned [`GitProvider`] and [`CommandRunner`] for unit tests.
+
+    use std::io;
+
+    use super::*;
+
+    /// Git answers fixed up front; ranges and paths not listed are empty.
+    #[derive(Debug, Default)]
+    pub(crate) struct MockGit {
+        pub numstat: Vec<FileStat>,
+        pub commits: BTreeMap<String, usize>,
+        pub added: BTreeMap<PathBuf, BTreeSet<usize>>,
+        pub head: String,
+        /// `(ancestor, descendant)` pairs that are related.
+        pub ancestry: Vec<(String, String)>,
+        pub path_diffs: BTreeMap<String, String>,
+    }
+
+    impl GitProvider for MockGit {
+        fn diff_numstat(&self, _repo_root: &Path, _range: &str) -> Result<Vec<FileStat>> {
+            Ok(self.numstat.clone())
+        }
+
+        fn commit_count(&self, _repo_root: &Path, range: &str) -> Result<usize> {
+            Ok(self.commits.get(range).copied().unwrap_or(0))
+        }
+
+        fn added_lines(
+            &self,
+            _repo_root: &Path,
+            _base: &str,
+            _head: &str,
+            _range_mode: tokmd_git::GitRangeMode,
+        ) -> Result<BTreeMap<PathBuf, BTreeSet<usize>>> {
+            Ok(self.added.clone())
+        }
+
+        fn head_commit(&self, _repo_root: &Path) -> Result<String> {
+            Ok(self.head.clone())
+        }
+
+        fn is_ancestor(&self, _repo_root: &Path, ancestor: &str, descendant: &str) -> bool {
+            self.ancestry
+                .iter()
+                .any(|(a, d)| a == ancestor && d == descendant)
+        }
+
+        fn diff_path(&self, _repo_root: &Path, _range: &str, path: &str) -> Result<String> {
+            Ok(self.path_diffs.get(path).cloned().unwrap_or_default())
+        }
+    }
+
+    /// Replies keyed by `"program arg arg…"`; anything else fails to start,
+    /// as a missing tool would.
+    #[derive(Debug, Default)]
+    pub(crate) struct MockCommands {
+        pub replies: BTreeMap<String, CommandOutput>,
+    }
+
+    impl MockCommands {
+        pub(crate) fn reply(mut self, command: &str, success: bool, stdout: &str) -> Self {
+            self.replies.insert(
+                command.to_string(),
+                CommandOutput {
+                    success,
+                    stdout: stdout.to_string(),
+                    stderr: String::new(),
+                },
+            );
+            self
+        }
+    }
+
+    impl CommandRunner for MockCommands {
+        fn run(&self, program: &str, args: &[&str], _cwd: &Path) -> io::Result<CommandOutput> {
+            let command = std::iter::once(program)
+                .chain(args.iter().copied())
+                .collect::<Vec<_>>()
+                .join(" ");
+            self.replies
+                .get(&command)
+                .cloned()
+                .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, command))
+        }
+    }
+}
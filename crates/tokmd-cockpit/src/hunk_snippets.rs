@@ -0,0 +1,194 @@
+//! Source-context snippets for uncovered diff-coverage hunks.
+//!
+//! Reads each uncovered hunk's lines from the working tree so the cockpit
+//! comment shows the untested code itself, not just a line range. Snippets are
+//! capped in hunk count, lines per hunk, and characters per line.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use tokmd_types::RedactMode;
+use tokmd_types::cockpit::DiffCoverageGate;
+
+/// Caps on the snippets attached to uncovered hunks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HunkSnippetLimits {
+    /// Hunks that get a snippet; later hunks keep only their line range.
+    pub max_hunks: usize,
+    /// Lines kept per hunk, counted from its first line.
+    pub max_lines: usize,
+    /// Characters kept per line; longer lines end in `…`.
+    pub max_line_chars: usize,
+}
+
+impl Default for HunkSnippetLimits {
+    fn default() -> Self {
+        Self {
+            max_hunks: 5,
+            max_lines: 8,
+            max_line_chars: 160,
+        }
+    }
+}
+
+/// Attach working-tree source lines to the gate's uncovered hunks.
+///
+/// Does nothing unless `redact` is [`RedactMode::None`]: a snippet would
+/// expose the file contents that redacted paths are meant to hide. Hunks whose
+/// file cannot be read as text keep `snippet: None`.
+pub fn attach_hunk_snippets(
+    repo_root: &Path,
+    gate: &mut DiffCoverageGate,
+    limits: &HunkSnippetLimits,
+    redact: RedactMode,
+) {
+    if redact != RedactMode::None {
+        return;
+    }
+
+    let mut sources: BTreeMap<String, Option<Vec<String>>> = BTreeMap::new();
+    for hunk in gate.uncovered_hunks.iter_mut().take(limits.max_hunks) {
+        let source = sources.entry(hunk.file.clone()).or_insert_with(|| {
+            std::fs::read_to_string(repo_root.join(&hunk.file))
+                .ok()
+                .map(|content| content.lines().map(str::to_string).collect())
+        });
+        let Some(lines) = source else {
+            continue;
+        };
+
+        let start = hunk.start_line.saturating_sub(1);
+        let end = hunk.end_line.min(lines.len());
+        let Some(hunk_lines @ [_, ..]) = lines.get(start..end) else {
+            continue;
+        };
+        let snippet = hunk_lines
+            .iter()
+            .take(limits.max_lines)
+            .map(|line| truncate_line(line, limits.max_line_chars))
+            .collect();
+        hunk.snippet = Some(snippet);
+    }
+}
+
+fn truncate_line(line: &str, max_chars: usize) -> String {
+    match line.char_indices().nth(max_chars) {
+        Some((cut, _)) => format!("{}…", line.get(..cut).unwrap_or(line)),
+        None => line.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokmd_types::cockpit::{
+        CommitMatch, EvidenceSource, GateMeta, GateStatus, ScopeCoverage, UncoveredHunk,
+    };
+
+    fn gate(hunks: &[(&str, usize, usize)]) -> DiffCoverageGate {
+        DiffCoverageGate {
+            meta: GateMeta {
+                status: GateStatus::Fail,
+                source: EvidenceSource::CiArtifact,
+                commit_match: CommitMatch::Unknown,
+                scope: ScopeCoverage {
+                    relevant: vec![],
+                    tested: vec![],
+                    ratio: 0.0,
+                    lines_relevant: None,
+                    lines_tested: None,
+                },
+                evidence_commit: None,
+                evidence_generated_at_ms: None,
+            },
+            lines_added: 10,
+            lines_covered: 0,
+            coverage_pct: 0.0,
+            artifact: None,
+            branch_coverage: None,
+            uncovered_hunks: hunks
+                .iter()
+                .map(|&(file, start_line, end_line)| UncoveredHunk {
+                    file: file.to_string(),
+                    start_line,
+                    end_line,
+                    snippet: None,
+                })
+                .collect(),
+        }
+    }
+
+    fn repo() -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir(dir.path().join("src")).unwrap();
+        std::fs::write(
+            dir.path().join("src/lib.rs"),
+            "fn covered() {}\n\nfn untested(x: u32) -> u32 {\n    x * 2\n}\n",
+        )
+        .unwrap();
+        dir
+    }
+
+    #[test]
+    fn hunk_includes_its_source_lines() {
+        let dir = repo();
+        let mut gate = gate(&[("src/lib.rs", 3, 5)]);
+
+        attach_hunk_snippets(
+            dir.path(),
+            &mut gate,
+            &HunkSnippetLimits::default(),
+            RedactMode::None,
+        );
+
+        assert_eq!(
+            gate.uncovered_hunks[0].snippet,
+            Some(vec![
+                "fn untested(x: u32) -> u32 {".to_string(),
+                "    x * 2".to_string(),
+                "}".to_string(),
+            ])
+        );
+    }
+
+    #[test]
+    fn snippets_respect_the_caps() {
+        let dir = repo();
+        let mut gate = gate(&[("src/lib.rs", 3, 5), ("src/lib.rs", 1, 1)]);
+        let limits = HunkSnippetLimits {
+            max_hunks: 1,
+            max_lines: 2,
+            max_line_chars: 6,
+        };
+
+        attach_hunk_snippets(dir.path(), &mut gate, &limits, RedactMode::None);
+
+        assert_eq!(
+            gate.uncovered_hunks[0].snippet,
+            Some(vec!["fn unt…".to_string(), "    x …".to_string()])
+        );
+        assert_eq!(gate.uncovered_hunks[1].snippet, None);
+    }
+
+    #[test]
+    fn redacted_paths_and_unreadable_files_get_no_snippet() {
+        let dir = repo();
+        let mut redacted = gate(&[("src/lib.rs", 3, 5)]);
+        attach_hunk_snippets(
+            dir.path(),
+            &mut redacted,
+            &HunkSnippetLimits::default(),
+            RedactMode::Paths,
+        );
+        assert_eq!(redacted.uncovered_hunks[0].snippet, None);
+
+        let mut missing = gate(&[("src/gone.rs", 1, 2), ("src/lib.rs", 40, 42)]);
+        attach_hunk_snippets(
+            dir.path(),
+            &mut missing,
+            &HunkSnippetLimits::default(),
+            RedactMode::None,
+        );
+        assert!(missing.uncovered_hunks.iter().all(|h| h.snippet.is_none()));
+    }
+}
@@ -0,0 +1,81 @@
+//! Reading baseline and receipt inputs.
+//!
+//! Baselines can be large, so they may be stored compressed. The codec is
+//! chosen from the file extension: `.gz` is gzip and `.zst` is zstd; any
+//! other path is read as plain UTF-8.
+
+use std::fs::File;
+use std::io::{self, BufReader, Read};
+use std::path::Path;
+
+/// Read `path` to a string, decompressing `.gz` and `.zst` files.
+pub(crate) fn read_input_to_string(path: &Path) -> io::Result<String> {
+    let extension = path.extension().and_then(|ext| ext.to_str());
+    let reader: Box<dyn Read> = match extension {
+        Some("gz") => Box::new(flate2::read::MultiGzDecoder::new(BufReader::new(
+            File::open(path)?,
+        ))),
+        Some("zst") => Box::new(
+            ruzstd::decoding::StreamingDecoder::new(BufReader::new(File::open(path)?))
+                .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err.to_string()))?,
+        ),
+        _ => return std::fs::read_to_string(path),
+    };
+
+    let mut content = String::new();
+    BufReader::new(reader).read_to_string(&mut content)?;
+    Ok(content)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use super::*;
+
+    const JSON: &str = r#"{"mode":"cockpit"}"#;
+
+    #[test]
+    fn plain_files_are_read_unchanged() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("baseline.json");
+        std::fs::write(&path, JSON).unwrap();
+
+        assert_eq!(read_input_to_string(&path).unwrap(), JSON);
+    }
+
+    #[test]
+    fn gzip_files_are_decompressed() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("baseline.json.gz");
+        let mut encoder =
+            flate2::write::GzEncoder::new(File::create(&path).unwrap(), Default::default());
+        encoder.write_all(JSON.as_bytes()).unwrap();
+        encoder.finish().unwrap();
+
+        assert_eq!(read_input_to_string(&path).unwrap(), JSON);
+    }
+
+    #[test]
+    fn zstd_files_are_decompressed() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("baseline.json.zst");
+        let compressed = ruzstd::encoding::compress_to_vec(
+            JSON.as_bytes(),
+            ruzstd::encoding::CompressionLevel::Fastest,
+        );
+        std::fs::write(&path, compressed).unwrap();
+
+        assert_eq!(read_input_to_string(&path).unwrap(), JSON);
+    }
+
+    #[test]
+    fn corrupt_zstd_is_invalid_data() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("baseline.json.zst");
+        std::fs::write(&path, JSON).unwrap();
+
+        let err = read_input_to_string(&path).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+}
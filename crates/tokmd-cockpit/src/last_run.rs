@@ -0,0 +1,57 @@
+//! Last-run cache for automatic cockpit trends.
+//!
+//! Each cockpit run stores its receipt inside the repository's git directory,
+//! out of the working tree, so the next run over the same refs can report what
+//! changed since then without an explicit `--baseline`. A missing or
+//! unreadable cache, or one recorded for other refs, means no trend, not an
+//! error.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use tokmd_types::cockpit::{CockpitReceipt, TrendComparison};
+
+use crate::trend::compute_trend;
+
+/// Cache location, relative to the repository's git directory.
+pub const LAST_RUN_CACHE_PATH: &str = "tokmd/cockpit-last.json";
+
+/// Path of the last-run cache for the repository whose git directory
+/// (e.g. `.git`) is `git_dir`.
+pub fn last_run_cache_path(git_dir: &Path) -> PathBuf {
+    git_dir.join(LAST_RUN_CACHE_PATH)
+}
+
+/// Trend of `current` against the receipt cached by the previous run.
+///
+/// Returns `Ok(None)` when no run has been cached yet, the cache cannot be
+/// read as a cockpit receipt, or the cached run compared a different
+/// `base_ref`/`head_ref` pair.
+pub fn trend_since_last_run(
+    git_dir: &Path,
+    current: &CockpitReceipt,
+) -> Result<Option<TrendComparison>> {
+    let path = last_run_cache_path(git_dir);
+    let Ok(content) = std::fs::read_to_string(&path) else {
+        return Ok(None);
+    };
+    let Ok(previous) = serde_json::from_str::<CockpitReceipt>(&content) else {
+        return Ok(None);
+    };
+    if previous.base_ref != current.base_ref || previous.head_ref != current.head_ref {
+        return Ok(None);
+    }
+    Ok(Some(compute_trend(&path, &previous, current)))
+}
+
+/// Cache `receipt` as the baseline for the next run, replacing any previous one.
+pub fn store_last_run(git_dir: &Path, receipt: &CockpitReceipt) -> Result<()> {
+    let path = last_run_cache_path(git_dir);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create {}", parent.display()))?;
+    }
+    let json = serde_json::to_string(receipt)?;
+    std::fs::write(&path, json)
+        .with_context(|| format!("Failed to write cockpit cache {}", path.display()))
+}
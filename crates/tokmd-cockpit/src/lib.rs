@@ -18,6 +18,7 @@
 
 #[cfg(feature = "git")]
 mod change_surface;
+mod codeowners;
 mod composition;
 mod contracts;
 pub mod determinism;
@@ -27,6 +28,11 @@ mod file_stat;
 #[cfg(feature = "git")]
 mod gates;
 mod health;
+#[cfg(feature = "git")]
+mod host;
+mod hunk_snippets;
+mod input;
+mod last_run;
 mod proof_evidence;
 pub mod render;
 mod review_plan;
@@ -40,24 +46,40 @@ use std::path::{Path, PathBuf};
 
 use anyhow::Result;
 #[cfg(feature = "git")]
-use change_surface::compute_change_surface;
-#[cfg(feature = "git")]
 pub use change_surface::get_file_stats;
+#[cfg(feature = "git")]
+use change_surface::{collect_file_stats, compute_change_surface, count_commits};
+pub use codeowners::{CODEOWNERS_LOCATIONS, CodeOwners, annotate_owners};
 pub use composition::compute_composition;
 pub use contracts::detect_contracts;
-pub use display::{format_signed_f64, now_iso8601, round_pct, sparkline, trend_direction_label};
+pub use display::{
+    format_signed_f64, now_iso8601, now_iso8601_with, round_pct, sparkline, trend_direction_label,
+};
 pub use doc_artifacts_evidence::{DocArtifactsEvidenceInput, parse_doc_artifacts_evidence_input};
 pub use file_stat::FileStat;
 #[cfg(feature = "git")]
-pub use gates::compute_determinism_gate;
+use gates::{GateContext, compute_evidence};
 #[cfg(feature = "git")]
-use gates::compute_evidence;
-pub use health::compute_code_health;
+pub use gates::{
+    GateOptions, ProjectCoverageThresholds, RustSourceScope, compute_determinism_gate,
+};
+pub use health::{HealthThresholds, compute_code_health, compute_code_health_with};
+#[cfg(feature = "git")]
+pub use host::{CockpitHost, CommandOutput, CommandRunner, GitProvider, HostCommands, HostGit};
+pub use hunk_snippets::{HunkSnippetLimits, attach_hunk_snippets};
+pub use last_run::{
+    LAST_RUN_CACHE_PATH, last_run_cache_path, store_last_run, trend_since_last_run,
+};
 pub use proof_evidence::{ProofEvidenceInput, ProofEvidenceKind};
-pub use review_plan::generate_review_plan;
+pub use review_plan::{
+    ReviewGroup, ReviewPlanGrouping, ReviewTimeModel, generate_review_plan,
+    generate_review_plan_with, group_review_plan, review_plan_minutes,
+};
 pub use risk::compute_risk;
 #[cfg(feature = "git")]
 use risk::compute_risk_owned;
+#[cfg(feature = "git")]
+pub use supply_chain::{AdvisoryAllowlist, AdvisorySeverity, AllowlistEntry};
 pub use trend::{compute_complexity_trend, compute_metric_trend, load_and_compute_trend};
 // Re-export types from tokmd_types::cockpit for convenience
 pub use tokmd_types::cockpit::*;
@@ -86,6 +108,12 @@ pub fn parse_proof_evidence_input(
 // =============================================================================
 
 /// Compute the full cockpit receipt for a PR.
+///
+/// `baseline_path` feeds the determinism gate; `options` tunes the other
+/// gates (see [`GateOptions`]).
+///
+/// Runs git and external tools on the host; see [`compute_cockpit_with`] to
+/// supply other backends.
 #[cfg(feature = "git")]
 pub fn compute_cockpit(
     repo_root: &PathBuf,
@@ -93,17 +121,44 @@ pub fn compute_cockpit(
     head: &str,
     range_mode: tokmd_git::GitRangeMode,
     baseline_path: Option<&Path>,
+    options: &GateOptions,
 ) -> Result<CockpitReceipt> {
-    let generated_at_ms = std::time::SystemTime::now()
-        .duration_since(std::time::UNIX_EPOCH)
-        .unwrap_or_default()
-        .as_millis() as u64;
+    compute_cockpit_with(
+        &CockpitHost::default(),
+        repo_root,
+        base,
+        head,
+        range_mode,
+        baseline_path,
+        options,
+    )
+}
 
-    // Get changed files with their stats
-    let file_stats = get_file_stats(repo_root, base, head, range_mode)?;
+/// [`compute_cockpit`] with git queries and tool runs routed through `host`,
+/// so no subprocess is spawned unless the host's backends spawn one.
+#[cfg(feature = "git")]
+pub fn compute_cockpit_with(
+    host: &CockpitHost<'_>,
+    repo_root: &PathBuf,
+    base: &str,
+    head: &str,
+    range_mode: tokmd_git::GitRangeMode,
+    baseline_path: Option<&Path>,
+    options: &GateOptions,
+) -> Result<CockpitReceipt> {
+    let generated_at_ms = tokmd_analysis_types::now_ms() as u64;
+
+    // Get changed files with their stats and the commit count; the two git
+    // calls are independent, so run them side by side within the job limit
+    let (file_stats, commits) = tokmd_git::join(
+        tokmd_git::git_jobs(),
+        || collect_file_stats(host.git, repo_root, base, head, range_mode),
+        || count_commits(host.git, repo_root, base, head, range_mode),
+    );
+    let file_stats = file_stats?;
 
     // Get change surface from git
-    let change_surface = compute_change_surface(repo_root, base, head, &file_stats, range_mode)?;
+    let change_surface = compute_change_surface(commits?, &file_stats);
 
     // Compute composition with test ratio
     let composition = compute_composition(&file_stats);
@@ -115,21 +170,26 @@ pub fn compute_cockpit(
     let code_health = compute_code_health(&file_stats, &contracts);
 
     // Compute all gate evidence
-    let evidence = compute_evidence(
+    let gate_ctx = GateContext {
+        host: *host,
         repo_root,
         base,
         head,
-        &file_stats,
-        &contracts,
         range_mode,
-        baseline_path,
-    )?;
+        changed_files: &file_stats,
+    };
+    let evidence = compute_evidence(&gate_ctx, &contracts, baseline_path, options)?;
 
     // Generate review plan with complexity scores
-    let review_plan = generate_review_plan(&file_stats, &contracts);
+    let mut review_plan = generate_review_plan(&file_stats, &contracts);
 
     // Compute risk based on various factors
-    let risk = compute_risk_owned(file_stats, &contracts, &code_health);
+    let mut risk = compute_risk_owned(file_stats, &contracts, &code_health);
+
+    // Attach CODEOWNERS ownership when the repository declares it
+    if let Some(owners) = CodeOwners::discover(repo_root)? {
+        annotate_owners(&owners, &mut review_plan, &mut risk);
+    }
 
     Ok(CockpitReceipt {
         schema_version: COCKPIT_SCHEMA_VERSION,
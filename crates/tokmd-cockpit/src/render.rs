@@ -1,15 +1,19 @@
 //! Rendering functions for cockpit receipts.
 //!
-//! Provides JSON, Markdown, sections, comment, and review packet output formats.
+//! Provides JSON, JSON Lines, Markdown, sections, comment, and review packet
+//! output formats.
+
+use std::fmt::Write;
 
 use anyhow::{Context, Result};
 
-use crate::CockpitReceipt;
+use crate::{AcknowledgedVulnerability, CockpitReceipt, Vulnerability};
 
 mod artifacts;
 mod bun_ub_sensor;
 mod comment;
 mod evidence;
+mod jsonl;
 mod manifest;
 mod markdown;
 mod proof_summary;
@@ -18,18 +22,55 @@ mod review_map_proof;
 mod review_packet;
 mod sections;
 
-pub use artifacts::write_artifacts;
+pub use artifacts::{write_artifacts, write_artifacts_with};
 #[cfg(feature = "git")]
-pub use artifacts::write_sensor_artifacts;
+pub use artifacts::{write_sensor_artifacts, write_sensor_artifacts_with};
 pub use bun_ub_sensor::BunUbSensorEvidence;
-pub use comment::render_comment_md;
-pub use markdown::render_markdown;
+pub use comment::{render_comment_md, render_comment_md_with};
+pub use jsonl::render_cockpit_jsonl;
+pub use markdown::{render_markdown, render_markdown_grouped, render_markdown_with};
 pub use review_packet::{
     write_review_packet, write_review_packet_with_imported_evidence,
     write_review_packet_with_imported_evidence_and_bun_ub_sensor,
     write_review_packet_with_proof_evidence,
 };
-pub use sections::render_sections;
+pub use sections::{render_sections, render_sections_with};
+
+/// One-line fix for a vulnerability, e.g.
+/// "upgrade foo to >=1.2.3 ([RUSTSEC-2024-0001](https://...))".
+fn vulnerability_remediation(vuln: &Vulnerability) -> String {
+    let advisory = match vuln.url {
+        Some(ref url) => format!("[{}]({url})", vuln.id),
+        None => vuln.id.clone(),
+    };
+    if vuln.patched.is_empty() {
+        format!("{} has no patched version ({advisory})", vuln.package)
+    } else {
+        format!(
+            "upgrade {} to {} ({advisory})",
+            vuln.package,
+            vuln.patched.join(" or ")
+        )
+    }
+}
+
+/// One-line note for an allowlisted advisory, e.g.
+/// "RUSTSEC-2024-0001 in foo acknowledged until 2026-12-31: not reachable".
+fn acknowledged_advisory(ack: &AcknowledgedVulnerability) -> String {
+    let vuln = &ack.vulnerability;
+    let advisory = match vuln.url {
+        Some(ref url) => format!("[{}]({url})", vuln.id),
+        None => vuln.id.clone(),
+    };
+    let mut note = format!("{advisory} in {} acknowledged", vuln.package);
+    if let Some(ref expires) = ack.expires {
+        let _ = write!(note, " until {expires}");
+    }
+    if let Some(ref reason) = ack.reason {
+        let _ = write!(note, ": {reason}");
+    }
+    note
+}
 
 /// Render receipt as JSON.
 pub fn render_json(receipt: &CockpitReceipt) -> Result<String> {
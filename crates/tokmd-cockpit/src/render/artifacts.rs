@@ -5,12 +5,23 @@ use std::path::Path;
 use anyhow::Result;
 use tokmd_envelope::{SensorReport, ToolMeta, Verdict};
 
-use crate::{CockpitReceipt, GateStatus, now_iso8601};
+use tokmd_format::TimestampFormat;
+
+use crate::{CockpitReceipt, GateStatus, now_iso8601_with};
 
 use super::{render_comment_md, render_json};
 
 /// Write artifacts to directory.
 pub fn write_artifacts(dir: &Path, receipt: &CockpitReceipt) -> Result<()> {
+    write_artifacts_with(dir, receipt, &TimestampFormat::default())
+}
+
+/// [`write_artifacts`] with the report timestamp formatted by `timestamps`.
+pub fn write_artifacts_with(
+    dir: &Path,
+    receipt: &CockpitReceipt,
+    timestamps: &TimestampFormat,
+) -> Result<()> {
     std::fs::create_dir_all(dir)?;
 
     // Write cockpit.json (full receipt)
@@ -28,7 +39,7 @@ pub fn write_artifacts(dir: &Path, receipt: &CockpitReceipt) -> Result<()> {
 
     let report = SensorReport::new(
         ToolMeta::tokmd(env!("CARGO_PKG_VERSION"), "cockpit"),
-        now_iso8601(),
+        now_iso8601_with(timestamps),
         verdict,
         format!(
             "{} files changed, +{}/-{}, health {}/100, risk {} in {}..{}",
@@ -59,6 +70,19 @@ pub fn write_sensor_artifacts(
     receipt: &CockpitReceipt,
     base: &str,
     head: &str,
+) -> Result<()> {
+    write_sensor_artifacts_with(dir, receipt, base, head, &TimestampFormat::default())
+}
+
+/// [`write_sensor_artifacts`] with the report timestamp formatted by
+/// `timestamps`.
+#[cfg(feature = "git")]
+pub fn write_sensor_artifacts_with(
+    dir: &Path,
+    receipt: &CockpitReceipt,
+    base: &str,
+    head: &str,
+    timestamps: &TimestampFormat,
 ) -> Result<()> {
     std::fs::create_dir_all(dir)?;
 
@@ -73,7 +97,7 @@ pub fn write_sensor_artifacts(
 
     let report = SensorReport::new(
         ToolMeta::tokmd(env!("CARGO_PKG_VERSION"), "cockpit"),
-        now_iso8601(),
+        now_iso8601_with(timestamps),
         verdict,
         format!("Cockpit run for {}..{}", base, head),
     );
@@ -2,7 +2,10 @@
 
 use crate::doc_artifacts_evidence::DocArtifactsEvidenceInput;
 use crate::proof_evidence::ProofEvidenceInput;
-use crate::{CockpitReceipt, GateStatus, RiskLevel};
+use tokmd_format::StatusStyle;
+use tokmd_format::analysis::fixed;
+
+use crate::{CockpitReceipt, GateStatus, RiskLevel, review_plan_minutes};
 
 use super::bun_ub_sensor::{BunUbSensorEvidence, bun_ub_sensor_refs, receipt_has_bun_ub_scope};
 use super::evidence::{doc_artifacts_expected, evidence_counts};
@@ -10,6 +13,12 @@ use super::proof_summary::proof_evidence_summary;
 
 /// Render comment.md for PR comments.
 pub fn render_comment_md(receipt: &CockpitReceipt) -> String {
+    render_comment_md_with(receipt, &StatusStyle::default())
+}
+
+/// [`render_comment_md`] with the risk level and overall gate status
+/// labelled by `style`.
+pub fn render_comment_md_with(receipt: &CockpitReceipt, style: &StatusStyle) -> String {
     use std::fmt::Write;
     let mut s = String::new();
 
@@ -31,8 +40,13 @@ pub fn render_comment_md(receipt: &CockpitReceipt) -> String {
     let _ = writeln!(
         s,
         "- **Risk**: {} ({}/100)",
-        receipt.risk.level, receipt.risk.score
+        style.label(receipt.risk.level, receipt.risk.level),
+        receipt.risk.score
     );
+    let minutes = review_plan_minutes(&receipt.review_plan);
+    if minutes > 0 {
+        let _ = writeln!(s, "- **Review time**: ~{minutes} min");
+    }
     let _ = writeln!(s);
 
     // Contract changes
@@ -63,8 +77,11 @@ pub fn render_comment_md(receipt: &CockpitReceipt) -> String {
     // Evidence gates
     let _ = writeln!(
         s,
-        "**Evidence gates**: {:?}",
-        receipt.evidence.overall_status
+        "**Evidence gates**: {}",
+        style.label(
+            receipt.evidence.overall_status,
+            format_args!("{:?}", receipt.evidence.overall_status)
+        )
     );
     let availability = evidence_counts(receipt);
     let _ = writeln!(
@@ -85,7 +102,44 @@ pub fn render_comment_md(receipt: &CockpitReceipt) -> String {
         );
     }
     if let Some(ref dc) = receipt.evidence.diff_coverage {
-        let _ = writeln!(s, "- Diff coverage: {:.1}%", dc.coverage_pct * 100.0);
+        match dc.branch_coverage {
+            Some(ref branches) => {
+                let _ = writeln!(
+                    s,
+                    "- Diff coverage: {}% (branches {}%)",
+                    fixed(dc.coverage_pct * 100.0, 1),
+                    fixed(branches.coverage_pct * 100.0, 1)
+                );
+            }
+            None => {
+                let _ = writeln!(s, "- Diff coverage: {}%", fixed(dc.coverage_pct * 100.0, 1));
+            }
+        }
+        for hunk in &dc.uncovered_hunks {
+            let Some(ref snippet) = hunk.snippet else {
+                continue;
+            };
+            let _ = writeln!(
+                s,
+                "  - `{}` lines {}-{}:",
+                hunk.file, hunk.start_line, hunk.end_line
+            );
+            let _ = writeln!(s, "    ```");
+            for line in snippet {
+                let _ = writeln!(s, "    {line}");
+            }
+            if snippet.len() < hunk.end_line.saturating_sub(hunk.start_line) + 1 {
+                let _ = writeln!(s, "    …");
+            }
+            let _ = writeln!(s, "    ```");
+        }
+    }
+    if let Some(ref pc) = receipt.evidence.project_coverage {
+        let _ = writeln!(
+            s,
+            "- Project coverage: {}%",
+            fixed(pc.coverage_pct * 100.0, 1)
+        );
     }
     if let Some(ref contracts) = receipt.evidence.contracts
         && contracts.failures > 0
@@ -100,6 +154,9 @@ pub fn render_comment_md(receipt: &CockpitReceipt) -> String {
             "- Supply chain: {} vulnerability/vulnerabilities",
             sc.vulnerabilities.len()
         );
+        for vuln in &sc.vulnerabilities {
+            let _ = writeln!(s, "  - {}", super::vulnerability_remediation(vuln));
+        }
     }
     if let Some(ref cx) = receipt.evidence.complexity
         && cx.threshold_exceeded
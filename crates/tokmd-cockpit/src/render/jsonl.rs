@@ -0,0 +1,53 @@
+//! JSON Lines rendering of cockpit gate results for log ingestion.
+//!
+//! One compact record per present gate, then one summary record, so log
+//! pipelines (Loki, Elastic) can index each gate result on its own.
+
+use serde_json::json;
+
+use super::evidence::review_packet_evidence_gate_specs;
+use crate::CockpitReceipt;
+
+/// Render the receipt's gate results as JSON Lines.
+///
+/// Each present gate becomes a `"record": "gate"` line carrying its name,
+/// status, source, and scope ratio; absent optional gates are skipped. A final
+/// `"record": "summary"` line carries the overall status and gate count. Every
+/// line repeats the refs and the receipt timestamp so records stand alone.
+pub fn render_cockpit_jsonl(receipt: &CockpitReceipt) -> String {
+    let mut out = String::new();
+    let mut gates = 0usize;
+    for (gate, meta) in review_packet_evidence_gate_specs(receipt) {
+        let Some(meta) = meta else {
+            continue;
+        };
+        gates += 1;
+        let record = json!({
+            "record": "gate",
+            "gate": gate,
+            "status": meta.status,
+            "source": meta.source,
+            "commit_match": meta.commit_match,
+            "scope_ratio": meta.scope.ratio,
+            "evidence_generated_at_ms": meta.evidence_generated_at_ms,
+            "timestamp_ms": receipt.generated_at_ms,
+            "base_ref": receipt.base_ref,
+            "head_ref": receipt.head_ref,
+        });
+        out.push_str(&record.to_string());
+        out.push('\n');
+    }
+    let summary = json!({
+        "record": "summary",
+        "overall_status": receipt.evidence.overall_status,
+        "gates": gates,
+        "health_score": receipt.code_health.score,
+        "risk_level": receipt.risk.level,
+        "timestamp_ms": receipt.generated_at_ms,
+        "base_ref": receipt.base_ref,
+        "head_ref": receipt.head_ref,
+    });
+    out.push_str(&summary.to_string());
+    out.push('\n');
+    out
+}
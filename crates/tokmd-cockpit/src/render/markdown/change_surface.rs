@@ -1,5 +1,7 @@
 use std::fmt::Write;
 
+use tokmd_format::analysis::fixed;
+
 use crate::CockpitReceipt;
 
 pub(super) fn render(s: &mut String, receipt: &CockpitReceipt) {
@@ -15,8 +17,8 @@ pub(super) fn render(s: &mut String, receipt: &CockpitReceipt) {
     let _ = writeln!(s, "- **Net lines**: {}", receipt.change_surface.net_lines);
     let _ = writeln!(
         s,
-        "- **Churn velocity**: {:.1}",
-        receipt.change_surface.churn_velocity
+        "- **Churn velocity**: {}",
+        fixed(receipt.change_surface.churn_velocity, 1)
     );
     let _ = writeln!(s);
 }
@@ -1,5 +1,7 @@
 use std::fmt::Write;
 
+use tokmd_format::analysis::fixed;
+
 use crate::CockpitReceipt;
 
 pub(super) fn render(s: &mut String, receipt: &CockpitReceipt) {
@@ -7,24 +9,28 @@ pub(super) fn render(s: &mut String, receipt: &CockpitReceipt) {
     let _ = writeln!(s);
     let _ = writeln!(
         s,
-        "- **Code**: {:.1}%",
-        receipt.composition.code_pct * 100.0
+        "- **Code**: {}%",
+        fixed(receipt.composition.code_pct * 100.0, 1)
+    );
+    let _ = writeln!(
+        s,
+        "- **Test**: {}%",
+        fixed(receipt.composition.test_pct * 100.0, 1)
     );
     let _ = writeln!(
         s,
-        "- **Test**: {:.1}%",
-        receipt.composition.test_pct * 100.0
+        "- **Docs**: {}%",
+        fixed(receipt.composition.docs_pct * 100.0, 1)
     );
     let _ = writeln!(
         s,
-        "- **Docs**: {:.1}%",
-        receipt.composition.docs_pct * 100.0
+        "- **Config**: {}%",
+        fixed(receipt.composition.config_pct * 100.0, 1)
     );
     let _ = writeln!(
         s,
-        "- **Config**: {:.1}%",
-        receipt.composition.config_pct * 100.0
+        "- **Test ratio**: {}",
+        fixed(receipt.composition.test_ratio, 2)
     );
-    let _ = writeln!(s, "- **Test ratio**: {:.2}", receipt.composition.test_ratio);
     let _ = writeln!(s);
 }
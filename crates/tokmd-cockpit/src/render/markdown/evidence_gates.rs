@@ -1,58 +1,109 @@
 use std::fmt::Write;
 
-use crate::CockpitReceipt;
+use tokmd_format::StatusStyle;
+use tokmd_format::analysis::fixed;
 
-pub(super) fn render(s: &mut String, receipt: &CockpitReceipt) {
+use crate::render::{acknowledged_advisory, vulnerability_remediation};
+use crate::{CockpitReceipt, GateStatus};
+
+pub(super) fn render(s: &mut String, receipt: &CockpitReceipt, style: &StatusStyle) {
+    let status = |value: GateStatus| style.label(value, format_args!("{value:?}"));
     let _ = writeln!(s, "### Evidence Gates");
     let _ = writeln!(s);
     let _ = writeln!(
         s,
-        "- **Overall status**: {:?}",
-        receipt.evidence.overall_status
+        "- **Overall status**: {}",
+        status(receipt.evidence.overall_status)
     );
     let _ = writeln!(
         s,
-        "- **Mutation**: {:?} (killed: {}, survivors: {})",
-        receipt.evidence.mutation.meta.status,
+        "- **Mutation**: {} (killed: {}, survivors: {})",
+        status(receipt.evidence.mutation.meta.status),
         receipt.evidence.mutation.killed,
         receipt.evidence.mutation.survivors.len()
     );
     if let Some(ref dc) = receipt.evidence.diff_coverage {
         let _ = writeln!(
             s,
-            "- **Diff coverage**: {:?} ({:.1}%)",
-            dc.meta.status,
-            dc.coverage_pct * 100.0
+            "- **Diff coverage**: {} ({}%)",
+            status(dc.meta.status),
+            fixed(dc.coverage_pct * 100.0, 1)
+        );
+        if let Some(ref branches) = dc.branch_coverage {
+            let _ = writeln!(
+                s,
+                "  - Branches: {} ({}%, {}/{} taken)",
+                status(branches.status),
+                fixed(branches.coverage_pct * 100.0, 1),
+                branches.branches_covered,
+                branches.branches_added
+            );
+        }
+    }
+    if let Some(ref pc) = receipt.evidence.project_coverage {
+        let _ = writeln!(
+            s,
+            "- **Project coverage**: {} ({}%, {}/{} lines in {} files)",
+            status(pc.meta.status),
+            fixed(pc.coverage_pct * 100.0, 1),
+            pc.lines_hit,
+            pc.lines_found,
+            pc.files
         );
+        if let (Some(pct), Some(hit), Some(found)) =
+            (pc.branch_coverage_pct, pc.branches_hit, pc.branches_found)
+        {
+            let _ = writeln!(
+                s,
+                "  - Branches: {}%, {}/{} taken",
+                fixed(pct * 100.0, 1),
+                hit,
+                found
+            );
+        }
     }
     if let Some(ref contracts) = receipt.evidence.contracts {
         let _ = writeln!(
             s,
-            "- **Contracts**: {:?} (failures: {})",
-            contracts.meta.status, contracts.failures
+            "- **Contracts**: {} (failures: {})",
+            status(contracts.meta.status),
+            contracts.failures
         );
     }
     if let Some(ref sc) = receipt.evidence.supply_chain {
+        let acknowledged = if sc.acknowledged.is_empty() {
+            String::new()
+        } else {
+            format!(", acknowledged: {}", sc.acknowledged.len())
+        };
         let _ = writeln!(
             s,
-            "- **Supply chain**: {:?} (vulnerabilities: {})",
-            sc.meta.status,
+            "- **Supply chain**: {} (vulnerabilities: {}{acknowledged})",
+            status(sc.meta.status),
             sc.vulnerabilities.len()
         );
+        for vuln in &sc.vulnerabilities {
+            let _ = writeln!(s, "  - {}", vulnerability_remediation(vuln));
+        }
+        for ack in &sc.acknowledged {
+            let _ = writeln!(s, "  - {}", acknowledged_advisory(ack));
+        }
     }
     if let Some(ref det) = receipt.evidence.determinism {
         let _ = writeln!(
             s,
-            "- **Determinism**: {:?} (differences: {})",
-            det.meta.status,
+            "- **Determinism**: {} (differences: {})",
+            status(det.meta.status),
             det.differences.len()
         );
     }
     if let Some(ref cx) = receipt.evidence.complexity {
         let _ = writeln!(
             s,
-            "- **Complexity**: {:?} (avg cyclomatic: {:.1}, max: {})",
-            cx.meta.status, cx.avg_cyclomatic, cx.max_cyclomatic
+            "- **Complexity**: {} (avg cyclomatic: {}, max: {})",
+            status(cx.meta.status),
+            fixed(cx.avg_cyclomatic, 1),
+            cx.max_cyclomatic
         );
     }
     let _ = writeln!(s);
@@ -2,7 +2,9 @@
 
 use std::fmt::Write;
 
-use crate::CockpitReceipt;
+use tokmd_format::StatusStyle;
+
+use crate::{CockpitReceipt, ReviewPlanGrouping};
 
 mod change_surface;
 mod code_health;
@@ -16,6 +18,29 @@ mod trend;
 
 /// Render receipt as Markdown summary.
 pub fn render_markdown(receipt: &CockpitReceipt) -> String {
+    render_markdown_with(receipt, &StatusStyle::default())
+}
+
+/// [`render_markdown`] with risk levels and gate statuses labelled by `style`.
+pub fn render_markdown_with(receipt: &CockpitReceipt, style: &StatusStyle) -> String {
+    render(receipt, style, None)
+}
+
+/// [`render_markdown_with`] with the review plan split into one section per
+/// module, each with its own line and review-time subtotals.
+pub fn render_markdown_grouped(
+    receipt: &CockpitReceipt,
+    style: &StatusStyle,
+    grouping: &ReviewPlanGrouping,
+) -> String {
+    render(receipt, style, Some(grouping))
+}
+
+fn render(
+    receipt: &CockpitReceipt,
+    style: &StatusStyle,
+    grouping: Option<&ReviewPlanGrouping>,
+) -> String {
     let mut s = String::new();
 
     let _ = writeln!(s, "## Glass Cockpit");
@@ -27,9 +52,9 @@ pub fn render_markdown(receipt: &CockpitReceipt) -> String {
     composition::render(&mut s, receipt);
     contracts::render(&mut s, receipt);
     code_health::render(&mut s, receipt);
-    risk::render(&mut s, receipt);
-    evidence_gates::render(&mut s, receipt);
-    review_plan::render(&mut s, receipt);
+    risk::render(&mut s, receipt, style);
+    evidence_gates::render(&mut s, receipt, style);
+    review_plan::render(&mut s, receipt, grouping);
     trend::render(&mut s, receipt);
 
     s
@@ -1,23 +1,79 @@
 use std::fmt::Write;
 
-use crate::CockpitReceipt;
+use tokmd_types::cockpit::ReviewItem;
 
-pub(super) fn render(s: &mut String, receipt: &CockpitReceipt) {
+use crate::{CockpitReceipt, ReviewPlanGrouping, group_review_plan, review_plan_minutes};
+
+pub(super) fn render(
+    s: &mut String,
+    receipt: &CockpitReceipt,
+    grouping: Option<&ReviewPlanGrouping>,
+) {
     let _ = writeln!(s, "### Review Plan");
     let _ = writeln!(s);
     if receipt.review_plan.is_empty() {
         let _ = writeln!(s, "No review items.");
     } else {
-        for item in &receipt.review_plan {
-            let _ = writeln!(s, "- **{}** (priority: {})", item.path, item.priority);
-            let _ = writeln!(s, "  - Reason: {}", item.reason);
-            if let Some(complexity) = item.complexity {
-                let _ = writeln!(s, "  - Complexity: {}", complexity);
-            }
-            if let Some(lines) = item.lines_changed {
-                let _ = writeln!(s, "  - Lines changed: {}", lines);
+        let minutes = review_plan_minutes(&receipt.review_plan);
+        if minutes > 0 {
+            let _ = writeln!(s, "Estimated review time: ~{minutes} min");
+            let _ = writeln!(s);
+        }
+        match grouping {
+            None => render_items(s, &receipt.review_plan),
+            Some(grouping) => {
+                let groups = group_review_plan(&receipt.review_plan, grouping);
+                for (idx, group) in groups.iter().enumerate() {
+                    if idx > 0 {
+                        let _ = writeln!(s);
+                    }
+                    let _ = writeln!(s, "#### `{}`", group.module);
+                    let _ = writeln!(s);
+                    let noun = if group.items.len() == 1 {
+                        "file"
+                    } else {
+                        "files"
+                    };
+                    let _ = write!(
+                        s,
+                        "{} {noun}, {} lines changed",
+                        group.items.len(),
+                        group.lines_changed
+                    );
+                    if group.review_minutes > 0 {
+                        let _ = write!(s, ", ~{} min", group.review_minutes);
+                    }
+                    let _ = writeln!(s);
+                    let _ = writeln!(s);
+                    render_items(s, &group.items);
+                }
             }
         }
     }
     let _ = writeln!(s);
 }
+
+fn render_items(s: &mut String, items: &[ReviewItem]) {
+    for item in items {
+        let _ = writeln!(s, "- **{}** (priority: {})", item.path, item.priority);
+        let _ = writeln!(s, "  - Reason: {}", item.reason);
+        if let Some(complexity) = item.complexity {
+            let _ = writeln!(s, "  - Complexity: {}", complexity);
+        }
+        if let Some(lines) = item.lines_changed {
+            let _ = writeln!(s, "  - Lines changed: {}", lines);
+        }
+        if let Some(minutes) = item.review_minutes {
+            let _ = writeln!(s, "  - Review time: ~{minutes} min");
+        }
+        match item.owners.as_deref() {
+            Some([]) => {
+                let _ = writeln!(s, "  - Owners: unowned");
+            }
+            Some(owners) => {
+                let _ = writeln!(s, "  - Owners: {}", owners.join(", "));
+            }
+            None => {}
+        }
+    }
+}
@@ -1,16 +1,38 @@
 use std::fmt::Write;
 
+use tokmd_format::StatusStyle;
+
 use crate::CockpitReceipt;
 
-pub(super) fn render(s: &mut String, receipt: &CockpitReceipt) {
+pub(super) fn render(s: &mut String, receipt: &CockpitReceipt, style: &StatusStyle) {
     let _ = writeln!(s, "### Risk");
     let _ = writeln!(s);
-    let _ = writeln!(s, "- **Level**: {}", receipt.risk.level);
+    let _ = writeln!(
+        s,
+        "- **Level**: {}",
+        style.label(receipt.risk.level, receipt.risk.level)
+    );
     let _ = writeln!(s, "- **Score**: {}/100", receipt.risk.score);
     if !receipt.risk.hotspots_touched.is_empty() {
         let _ = writeln!(s, "- **Hotspots touched**:");
         for hotspot in &receipt.risk.hotspots_touched {
-            let _ = writeln!(s, "  - {}", hotspot);
+            match receipt.risk.hotspot_owners.get(hotspot) {
+                Some(owners) if !owners.is_empty() => {
+                    let _ = writeln!(s, "  - {} ({})", hotspot, owners.join(", "));
+                }
+                _ => {
+                    let _ = writeln!(s, "  - {}", hotspot);
+                }
+            }
+        }
+    }
+    if let Some(owners) = receipt.risk.owners_touched {
+        let _ = writeln!(s, "- **Owners touched**: {}", owners);
+    }
+    if !receipt.risk.unowned_files.is_empty() {
+        let _ = writeln!(s, "- **Unowned files**:");
+        for path in &receipt.risk.unowned_files {
+            let _ = writeln!(s, "  - {}", path);
         }
     }
     if !receipt.risk.bus_factor_warnings.is_empty() {
@@ -1,5 +1,7 @@
 use std::fmt::Write;
 
+use tokmd_format::analysis::fixed;
+
 use crate::{CockpitReceipt, format_signed_f64, trend_direction_label};
 
 pub(super) fn render(s: &mut String, receipt: &CockpitReceipt) {
@@ -16,7 +18,11 @@ pub(super) fn render(s: &mut String, receipt: &CockpitReceipt) {
     let _ = writeln!(s, "|Net Lines|{}|", receipt.change_surface.net_lines);
     let _ = writeln!(s, "|Code Health Score|{}/100|", receipt.code_health.score);
     let _ = writeln!(s, "|Risk Score|{}/100|", receipt.risk.score);
-    let _ = writeln!(s, "|Test Ratio|{:.2}|", receipt.composition.test_ratio);
+    let _ = writeln!(
+        s,
+        "|Test Ratio|{}|",
+        fixed(receipt.composition.test_ratio, 2)
+    );
     s.push('\n');
 }
 
@@ -32,9 +38,9 @@ pub(super) fn render_comparison(s: &mut String, receipt: &CockpitReceipt) {
     if let Some(health) = &trend.health {
         let _ = writeln!(
             s,
-            "|Health Score|{:.1}|{:.1}|{}|{}|",
-            health.previous,
-            health.current,
+            "|Health Score|{}|{}|{}|{}|",
+            fixed(health.previous, 1),
+            fixed(health.current, 1),
             format_signed_f64(health.delta),
             trend_direction_label(health.direction)
         );
@@ -42,9 +48,9 @@ pub(super) fn render_comparison(s: &mut String, receipt: &CockpitReceipt) {
     if let Some(risk) = &trend.risk {
         let _ = writeln!(
             s,
-            "|Risk Score|{:.1}|{:.1}|{}|{}|",
-            risk.previous,
-            risk.current,
+            "|Risk Score|{}|{}|{}|{}|",
+            fixed(risk.previous, 1),
+            fixed(risk.current, 1),
             format_signed_f64(risk.delta),
             trend_direction_label(risk.direction)
         );
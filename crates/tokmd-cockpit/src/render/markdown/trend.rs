@@ -1,5 +1,7 @@
 use std::fmt::Write;
 
+use tokmd_format::analysis::fixed;
+
 use crate::{CockpitReceipt, sparkline};
 
 pub(super) fn render(s: &mut String, receipt: &CockpitReceipt) {
@@ -18,22 +20,22 @@ pub(super) fn render(s: &mut String, receipt: &CockpitReceipt) {
         if let Some(ref health) = trend.health {
             let _ = writeln!(
                 s,
-                "- **Health**: {:.1} -> {:.1} {} ({:.1}%, {:?})",
-                health.previous,
-                health.current,
+                "- **Health**: {} -> {} {} ({}%, {:?})",
+                fixed(health.previous, 1),
+                fixed(health.current, 1),
                 sparkline(&[health.previous, health.current]),
-                health.delta_pct,
+                fixed(health.delta_pct, 1),
                 health.direction
             );
         }
         if let Some(ref risk) = trend.risk {
             let _ = writeln!(
                 s,
-                "- **Risk**: {:.1} -> {:.1} {} ({:.1}%, {:?})",
-                risk.previous,
-                risk.current,
+                "- **Risk**: {} -> {} {} ({}%, {:?})",
+                fixed(risk.previous, 1),
+                fixed(risk.current, 1),
                 sparkline(&[risk.previous, risk.current]),
-                risk.delta_pct,
+                fixed(risk.delta_pct, 1),
                 risk.direction
             );
         }
@@ -660,6 +660,8 @@ mod tests {
             priority,
             complexity: None,
             lines_changed: None,
+            review_minutes: None,
+            owners: None,
         }
     }
 
@@ -2,10 +2,18 @@
 
 use std::fmt::Write;
 
+use tokmd_format::StatusStyle;
+use tokmd_format::analysis::fixed;
+
 use crate::CockpitReceipt;
 
 /// Render receipt as sectioned output.
 pub fn render_sections(receipt: &CockpitReceipt) -> String {
+    render_sections_with(receipt, &StatusStyle::default())
+}
+
+/// [`render_sections`] with the risk level labelled by `style`.
+pub fn render_sections_with(receipt: &CockpitReceipt, style: &StatusStyle) -> String {
     let mut s = String::new();
 
     let _ = writeln!(s, "<!-- SECTION:COCKPIT -->");
@@ -21,13 +29,25 @@ pub fn render_sections(receipt: &CockpitReceipt) -> String {
     let _ = writeln!(s, "- Deletions: {}", receipt.change_surface.deletions);
     let _ = writeln!(s);
     let _ = writeln!(s, "**Composition**:");
-    let _ = writeln!(s, "- Code: {:.1}%", receipt.composition.code_pct * 100.0);
-    let _ = writeln!(s, "- Test: {:.1}%", receipt.composition.test_pct * 100.0);
-    let _ = writeln!(s, "- Docs: {:.1}%", receipt.composition.docs_pct * 100.0);
     let _ = writeln!(
         s,
-        "- Config: {:.1}%",
-        receipt.composition.config_pct * 100.0
+        "- Code: {}%",
+        fixed(receipt.composition.code_pct * 100.0, 1)
+    );
+    let _ = writeln!(
+        s,
+        "- Test: {}%",
+        fixed(receipt.composition.test_pct * 100.0, 1)
+    );
+    let _ = writeln!(
+        s,
+        "- Docs: {}%",
+        fixed(receipt.composition.docs_pct * 100.0, 1)
+    );
+    let _ = writeln!(
+        s,
+        "- Config: {}%",
+        fixed(receipt.composition.config_pct * 100.0, 1)
     );
     let _ = writeln!(s);
     let _ = writeln!(s, "**Contracts**:");
@@ -67,7 +87,8 @@ pub fn render_sections(receipt: &CockpitReceipt) -> String {
     let _ = writeln!(
         s,
         "**Risk**: {} ({}/100)",
-        receipt.risk.level, receipt.risk.score
+        style.label(receipt.risk.level, receipt.risk.level),
+        receipt.risk.score
     );
     let _ = writeln!(s);
     let _ = writeln!(s, "<!-- SECTION:REVIEW_PLAN -->");
@@ -1,10 +1,60 @@
+use std::collections::BTreeMap;
+
+use tokmd_model::module_key;
 use tokmd_types::cockpit::{Contracts, ReviewItem};
 
 use crate::FileStat;
 use crate::doc_artifacts_evidence::source_of_truth_path;
 
-/// Generate review plan.
+/// Review-time estimate for one review-plan item.
+///
+/// An item costs `base_minutes` to open and orient, plus reading time of
+/// `lines_changed / lines_per_minute`. Reading time is stretched by
+/// `complexity_weight` for each complexity step above 1, so with the defaults
+/// a complexity-5 file reads at half speed. The result rounds up to whole
+/// minutes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ReviewTimeModel {
+    pub base_minutes: f64,
+    pub lines_per_minute: f64,
+    pub complexity_weight: f64,
+}
+
+impl Default for ReviewTimeModel {
+    fn default() -> Self {
+        Self {
+            base_minutes: 2.0,
+            lines_per_minute: 20.0,
+            complexity_weight: 0.25,
+        }
+    }
+}
+
+impl ReviewTimeModel {
+    /// Estimated minutes to review `lines` changed lines at `complexity`
+    /// (1-5).
+    pub fn minutes(&self, lines: usize, complexity: u8) -> u32 {
+        let reading = if self.lines_per_minute > 0.0 {
+            lines as f64 / self.lines_per_minute
+        } else {
+            0.0
+        };
+        let stretch = 1.0 + self.complexity_weight * f64::from(complexity.saturating_sub(1));
+        (self.base_minutes + reading * stretch).max(0.0).ceil() as u32
+    }
+}
+
+/// Generate review plan with the default [`ReviewTimeModel`].
 pub fn generate_review_plan(file_stats: &[FileStat], contracts: &Contracts) -> Vec<ReviewItem> {
+    generate_review_plan_with(file_stats, contracts, &ReviewTimeModel::default())
+}
+
+/// Generate review plan, estimating each item's review time with `model`.
+pub fn generate_review_plan_with(
+    file_stats: &[FileStat],
+    contracts: &Contracts,
+    model: &ReviewTimeModel,
+) -> Vec<ReviewItem> {
     let mut items = Vec::new();
 
     for stat in file_stats {
@@ -26,6 +76,8 @@ pub fn generate_review_plan(file_stats: &[FileStat], contracts: &Contracts) -> V
             priority,
             complexity: Some(complexity),
             lines_changed: Some(lines),
+            review_minutes: Some(model.minutes(lines, complexity)),
+            owners: None,
         });
     }
 
@@ -38,6 +90,70 @@ pub fn generate_review_plan(file_stats: &[FileStat], contracts: &Contracts) -> V
     items
 }
 
+/// Total estimated review minutes across `items`; items without an estimate
+/// count as zero.
+pub fn review_plan_minutes(items: &[ReviewItem]) -> u32 {
+    items.iter().filter_map(|item| item.review_minutes).sum()
+}
+
+/// How [`group_review_plan`] maps paths to modules; same semantics as the
+/// `module` command's roots and depth.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReviewPlanGrouping {
+    pub module_roots: Vec<String>,
+    pub module_depth: usize,
+}
+
+impl Default for ReviewPlanGrouping {
+    fn default() -> Self {
+        Self {
+            module_roots: vec!["crates".to_string(), "packages".to_string()],
+            module_depth: 2,
+        }
+    }
+}
+
+/// Review-plan items that fall under one module, with subtotals.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReviewGroup {
+    pub module: String,
+    pub items: Vec<ReviewItem>,
+    pub lines_changed: usize,
+    pub review_minutes: u32,
+}
+
+/// Group review-plan items by module.
+///
+/// Items keep their plan order within a group. Groups are ordered by their
+/// most urgent item, so the module holding the first item of the plan comes
+/// first; ties break on module name.
+pub fn group_review_plan(items: &[ReviewItem], grouping: &ReviewPlanGrouping) -> Vec<ReviewGroup> {
+    let mut by_module: BTreeMap<String, ReviewGroup> = BTreeMap::new();
+    for item in items {
+        let module = module_key(&item.path, &grouping.module_roots, grouping.module_depth);
+        let group = by_module
+            .entry(module)
+            .or_insert_with_key(|module| ReviewGroup {
+                module: module.clone(),
+                items: Vec::new(),
+                lines_changed: 0,
+                review_minutes: 0,
+            });
+        group.lines_changed += item.lines_changed.unwrap_or(0);
+        group.review_minutes += item.review_minutes.unwrap_or(0);
+        group.items.push(item.clone());
+    }
+
+    let mut groups: Vec<ReviewGroup> = by_module.into_values().collect();
+    groups.sort_by(|a, b| {
+        let urgency = |g: &ReviewGroup| g.items.iter().map(|i| i.priority).min();
+        urgency(a)
+            .cmp(&urgency(b))
+            .then_with(|| a.module.cmp(&b.module))
+    });
+    groups
+}
+
 fn review_priority_for_lines(lines: usize) -> u32 {
     if lines > 200 {
         1
@@ -285,4 +401,120 @@ mod tests {
         assert_eq!(med.complexity, Some(3));
         assert_eq!(small.complexity, Some(1));
     }
+
+    #[test]
+    fn large_complex_file_takes_longer_to_review_than_a_trivial_one() {
+        let stats = vec![make_stat("engine.rs", 300, 100), make_stat("typo.rs", 1, 1)];
+        let contracts = Contracts {
+            api_changed: false,
+            cli_changed: false,
+            schema_changed: false,
+            breaking_indicators: 0,
+        };
+        let plan = generate_review_plan(&stats, &contracts);
+        let engine = plan.iter().find(|i| i.path == "engine.rs").unwrap();
+        let typo = plan.iter().find(|i| i.path == "typo.rs").unwrap();
+
+        // 2 + 400 / 20 * (1 + 0.25 * 4) = 42; 2 + 2 / 20 = 2.1, rounded up.
+        assert_eq!(engine.review_minutes, Some(42));
+        assert_eq!(typo.review_minutes, Some(3));
+        assert_eq!(review_plan_minutes(&plan), 45);
+    }
+
+    #[test]
+    fn review_time_model_is_configurable() {
+        let stats = vec![make_stat("a.rs", 50, 50)];
+        let contracts = Contracts {
+            api_changed: false,
+            cli_changed: false,
+            schema_changed: false,
+            breaking_indicators: 0,
+        };
+        let slow = ReviewTimeModel {
+            base_minutes: 5.0,
+            lines_per_minute: 10.0,
+            complexity_weight: 0.0,
+        };
+        let plan = generate_review_plan_with(&stats, &contracts, &slow);
+        assert_eq!(plan[0].review_minutes, Some(15));
+    }
+
+    #[test]
+    fn total_skips_items_without_an_estimate() {
+        let item = |minutes| ReviewItem {
+            path: "x.rs".to_string(),
+            reason: String::new(),
+            priority: 3,
+            complexity: None,
+            lines_changed: None,
+            review_minutes: minutes,
+            owners: None,
+        };
+        assert_eq!(
+            review_plan_minutes(&[item(Some(4)), item(None), item(Some(6))]),
+            10
+        );
+        assert_eq!(review_plan_minutes(&[]), 0);
+    }
+
+    fn plan_item(path: &str, priority: u32, lines: usize, minutes: u32) -> ReviewItem {
+        ReviewItem {
+            path: path.to_string(),
+            reason: format!("{lines} lines changed"),
+            priority,
+            complexity: Some(1),
+            lines_changed: Some(lines),
+            review_minutes: Some(minutes),
+            owners: None,
+        }
+    }
+
+    #[test]
+    fn items_group_by_module_prefix() {
+        let plan = vec![
+            plan_item("crates/core/src/lib.rs", 1, 250, 14),
+            plan_item("crates/cli/src/main.rs", 2, 80, 6),
+            plan_item("crates/core/src/parse.rs", 3, 10, 3),
+            plan_item("README.md", 3, 4, 3),
+            plan_item("crates/cli/tests/run.rs", 3, 20, 3),
+        ];
+        let groups = group_review_plan(&plan, &ReviewPlanGrouping::default());
+
+        let modules: Vec<&str> = groups.iter().map(|g| g.module.as_str()).collect();
+        assert_eq!(modules, vec!["crates/core", "crates/cli", "(root)"]);
+
+        let core = &groups[0];
+        let core_paths: Vec<&str> = core.items.iter().map(|i| i.path.as_str()).collect();
+        assert_eq!(
+            core_paths,
+            vec!["crates/core/src/lib.rs", "crates/core/src/parse.rs"]
+        );
+        assert_eq!(core.lines_changed, 260);
+        assert_eq!(core.review_minutes, 17);
+
+        let cli = &groups[1];
+        assert_eq!(cli.items.len(), 2);
+        assert_eq!(cli.lines_changed, 100);
+        assert_eq!(cli.review_minutes, 9);
+
+        assert_eq!(groups[2].items[0].path, "README.md");
+    }
+
+    #[test]
+    fn grouping_honours_module_roots_and_depth() {
+        let plan = vec![
+            plan_item("src/a.rs", 3, 5, 3),
+            plan_item("src/b/c.rs", 3, 5, 3),
+            plan_item("libs/x/src/y.rs", 3, 5, 3),
+        ];
+        let grouping = ReviewPlanGrouping {
+            module_roots: vec!["libs".to_string()],
+            module_depth: 1,
+        };
+        let groups = group_review_plan(&plan, &grouping);
+
+        let modules: Vec<&str> = groups.iter().map(|g| g.module.as_str()).collect();
+        assert_eq!(modules, vec!["libs", "src"]);
+        assert_eq!(groups[1].items.len(), 2);
+    }
 }
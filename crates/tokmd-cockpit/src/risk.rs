@@ -1,5 +1,7 @@
 //! Risk metric computation for cockpit receipts.
 
+use std::collections::BTreeMap;
+
 use crate::FileStat;
 use tokmd_types::cockpit::{CodeHealth, Contracts, Risk, RiskLevel};
 
@@ -16,18 +18,24 @@ where
 
     let score = (hotspots_touched.len() * 15 + (100 - health.score) as usize).min(100) as u32;
 
-    let level = match score {
-        0..=20 => RiskLevel::Low,
-        21..=50 => RiskLevel::Medium,
-        51..=80 => RiskLevel::High,
-        _ => RiskLevel::Critical,
-    };
-
     Risk {
         hotspots_touched,
         bus_factor_warnings,
-        level,
+        level: risk_level(score),
         score,
+        owners_touched: None,
+        unowned_files: Vec::new(),
+        hotspot_owners: BTreeMap::new(),
+    }
+}
+
+/// Risk level band for a 0-100 risk score.
+pub(crate) fn risk_level(score: u32) -> RiskLevel {
+    match score {
+        0..=20 => RiskLevel::Low,
+        21..=50 => RiskLevel::Medium,
+        51..=80 => RiskLevel::High,
+        _ => RiskLevel::Critical,
     }
 }
 
@@ -1,50 +1,156 @@
 //! Supply-chain evidence gate for cockpit receipts.
 
 use std::path::Path;
-use std::process::Command;
 
-use anyhow::Result;
+use anyhow::{Context, Result, bail};
 use serde::Deserialize;
+use time::Date;
+use time::macros::format_description;
 use tokmd_types::cockpit::{
-    CommitMatch, EvidenceSource, GateMeta, GateStatus, ScopeCoverage, SupplyChainGate,
-    Vulnerability,
+    AcknowledgedVulnerability, CommitMatch, EvidenceSource, GateMeta, GateStatus, ScopeCoverage,
+    SupplyChainGate, Vulnerability,
 };
 
 use crate::FileStat;
+#[cfg(feature = "git")]
+use crate::host::CommandRunner;
+
+/// Severity of a supply-chain advisory, ordered from least to most severe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum AdvisorySeverity {
+    None,
+    Low,
+    Medium,
+    High,
+    Critical,
+}
+
+impl AdvisorySeverity {
+    /// Parse a `cargo audit` severity (`none`, `low`, `medium`, `high`,
+    /// `critical`), ignoring case.
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "none" => Some(Self::None),
+            "low" => Some(Self::Low),
+            "medium" => Some(Self::Medium),
+            "high" => Some(Self::High),
+            "critical" => Some(Self::Critical),
+            _ => None,
+        }
+    }
+}
+
+/// Advisories accepted with justification, typically because no fix exists.
+///
+/// Loaded from a TOML file of `[[advisory]]` tables:
+///
+/// ```toml
+/// [[advisory]]
+/// id = "RUSTSEC-2024-0001"
+/// reason = "No patched release; the affected API is never called"
+/// expires = "2026-12-31"
+/// ```
+///
+/// An entry holds through its `expires` day (UTC); without one it never
+/// expires. Expired entries are ignored, so the advisory fails the gate again.
+/// Today's date comes from [`tokmd_format::now_utc`], so `SOURCE_DATE_EPOCH`
+/// pins it.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AdvisoryAllowlist {
+    #[serde(default, rename = "advisory")]
+    pub entries: Vec<AllowlistEntry>,
+}
+
+/// One accepted advisory.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AllowlistEntry {
+    /// Advisory ID as reported by `cargo audit`, e.g. `RUSTSEC-2024-0001`.
+    pub id: String,
+    /// Why the advisory is accepted.
+    #[serde(default)]
+    pub reason: Option<String>,
+    /// Last day (`YYYY-MM-DD`) the entry applies.
+    #[serde(default)]
+    pub expires: Option<String>,
+}
+
+impl AdvisoryAllowlist {
+    /// Parse allowlist TOML, rejecting malformed expiry dates.
+    pub fn parse(content: &str) -> Result<Self> {
+        let allowlist: Self = toml::from_str(content).context("Invalid advisory allowlist")?;
+        for entry in &allowlist.entries {
+            if let Some(expires) = &entry.expires
+                && parse_iso_date(expires).is_none()
+            {
+                bail!(
+                    "advisory allowlist entry {}: expires {expires:?} is not a YYYY-MM-DD date",
+                    entry.id
+                );
+            }
+        }
+        Ok(allowlist)
+    }
+
+    /// Read and parse the allowlist at `path`.
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read advisory allowlist {}", path.display()))?;
+        Self::parse(&content).with_context(|| format!("in {}", path.display()))
+    }
+
+    /// The entry accepting advisory `id` on `today`, if any. An expiry that
+    /// is not a valid date never accepts.
+    fn accepting(&self, id: &str, today: Date) -> Option<&AllowlistEntry> {
+        self.entries.iter().find(|entry| {
+            entry.id.eq_ignore_ascii_case(id)
+                && entry.expires.as_deref().is_none_or(|expires| {
+                    parse_iso_date(expires).is_some_and(|expires| expires >= today)
+                })
+        })
+    }
+}
 
 /// Compute supply-chain gate evidence.
 ///
 /// The gate is scoped to `Cargo.lock` changes. It runs `cargo audit --json`
 /// when the tool is available; otherwise it records pending local evidence.
+/// Advisories below `min_severity` are dropped before the status is set;
+/// advisories without a recognised severity are always kept. Advisories with
+/// an unexpired `allowlist` entry move to `acknowledged` and do not count
+/// toward the status.
 #[cfg(feature = "git")]
 pub(crate) fn compute_supply_chain_gate(
+    commands: &dyn CommandRunner,
     repo_root: &Path,
     changed_files: &[FileStat],
+    min_severity: Option<AdvisorySeverity>,
+    allowlist: &AdvisoryAllowlist,
 ) -> Result<Option<SupplyChainGate>> {
     let lock_changed = changed_files.iter().any(|f| f.path.ends_with("Cargo.lock"));
     if !lock_changed {
         return Ok(None);
     }
 
-    let check = Command::new("cargo").arg("audit").arg("--version").output();
-    let audit_available = check.as_ref().map(|o| o.status.success()).unwrap_or(false);
+    let check = commands.run("cargo", &["audit", "--version"], repo_root);
+    let audit_available = check.as_ref().map(|o| o.success).unwrap_or(false);
 
     if !audit_available {
         return Ok(Some(pending_supply_chain_gate()));
     }
 
-    let audit_output = Command::new("cargo")
-        .args(["audit", "--json"])
-        .current_dir(repo_root)
-        .output();
-
-    let output = match audit_output {
+    let output = match commands.run("cargo", &["audit", "--json"], repo_root) {
         Ok(o) => o,
         Err(_) => return Ok(Some(pending_supply_chain_gate())),
     };
 
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    let (vulnerabilities, advisory_db_version, status) = parse_audit_output(&stdout);
+    let (vulnerabilities, acknowledged, advisory_db_version, status) = parse_audit_output(
+        &output.stdout,
+        min_severity,
+        allowlist,
+        tokmd_format::now_utc().date(),
+    );
 
     Ok(Some(SupplyChainGate {
         meta: GateMeta {
@@ -63,6 +169,7 @@ pub(crate) fn compute_supply_chain_gate(
         },
         vulnerabilities,
         denied: Vec::new(),
+        acknowledged,
         advisory_db_version,
     }))
 }
@@ -85,11 +192,22 @@ fn pending_supply_chain_gate() -> SupplyChainGate {
         },
         vulnerabilities: Vec::new(),
         denied: Vec::new(),
+        acknowledged: Vec::new(),
         advisory_db_version: None,
     }
 }
 
-fn parse_audit_output(stdout: &str) -> (Vec<Vulnerability>, Option<String>, GateStatus) {
+fn parse_audit_output(
+    stdout: &str,
+    min_severity: Option<AdvisorySeverity>,
+    allowlist: &AdvisoryAllowlist,
+    today: Date,
+) -> (
+    Vec<Vulnerability>,
+    Vec<AcknowledgedVulnerability>,
+    Option<String>,
+    GateStatus,
+) {
     let parsed: Result<AuditOutput, _> = serde_json::from_str(stdout);
 
     match parsed {
@@ -111,8 +229,30 @@ fn parse_audit_output(stdout: &str) -> (Vec<Vulnerability>, Option<String>, Gate
                             .clone()
                             .unwrap_or_else(|| "unknown".to_string()),
                         title: advisory.title.unwrap_or_default(),
+                        url: advisory.url,
+                        patched: entry
+                            .versions
+                            .and_then(|versions| versions.patched)
+                            .unwrap_or_default(),
                     })
                 })
+                .filter(|vuln| meets_floor(&vuln.severity, min_severity))
+                .collect();
+
+            let mut acknowledged = Vec::new();
+            let vulns: Vec<Vulnerability> = vulns
+                .into_iter()
+                .filter_map(|vuln| match allowlist.accepting(&vuln.id, today) {
+                    Some(entry) => {
+                        acknowledged.push(AcknowledgedVulnerability {
+                            vulnerability: vuln,
+                            reason: entry.reason.clone(),
+                            expires: entry.expires.clone(),
+                        });
+                        None
+                    }
+                    None => Some(vuln),
+                })
                 .collect();
 
             let has_critical_or_high = vulns.iter().any(|v| {
@@ -129,12 +269,25 @@ fn parse_audit_output(stdout: &str) -> (Vec<Vulnerability>, Option<String>, Gate
                 GateStatus::Pass
             };
 
-            (vulns, db_version, status)
+            (vulns, acknowledged, db_version, status)
         }
-        Err(_) => (Vec::new(), None, GateStatus::Pending),
+        Err(_) => (Vec::new(), Vec::new(), None, GateStatus::Pending),
     }
 }
 
+fn meets_floor(severity: &str, min_severity: Option<AdvisorySeverity>) -> bool {
+    match (min_severity, AdvisorySeverity::parse(severity)) {
+        (Some(floor), Some(severity)) => severity >= floor,
+        _ => true,
+    }
+}
+
+/// A `YYYY-MM-DD` calendar date, or `None` for any other shape or an
+/// impossible day such as `2026-13-45`.
+fn parse_iso_date(value: &str) -> Option<Date> {
+    Date::parse(value, format_description!("[year]-[month]-[day]")).ok()
+}
+
 #[derive(Deserialize)]
 struct AuditOutput {
     database: Option<AuditDatabase>,
@@ -155,6 +308,7 @@ struct AuditVulnerabilities {
 struct AuditVulnEntry {
     advisory: Option<AuditAdvisory>,
     package: Option<AuditPackage>,
+    versions: Option<AuditVersions>,
 }
 
 #[derive(Deserialize)]
@@ -162,6 +316,12 @@ struct AuditAdvisory {
     id: Option<String>,
     severity: Option<String>,
     title: Option<String>,
+    url: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct AuditVersions {
+    patched: Option<Vec<String>>,
 }
 
 #[derive(Deserialize)]
@@ -171,9 +331,18 @@ struct AuditPackage {
 
 #[cfg(test)]
 mod tests {
-    use super::{parse_audit_output, pending_supply_chain_gate};
+    use std::path::Path;
+
+    use super::{
+        AdvisoryAllowlist, AdvisorySeverity, compute_supply_chain_gate, parse_audit_output,
+        parse_iso_date, pending_supply_chain_gate,
+    };
+    use crate::FileStat;
+    use crate::host::mock::MockCommands;
     use tokmd_types::cockpit::GateStatus;
 
+    const TODAY: time::Date = time::macros::date!(2026 - 10 - 16);
+
     #[test]
     fn pending_gate_records_cargo_lock_as_untested() {
         let gate = pending_supply_chain_gate();
@@ -186,7 +355,7 @@ mod tests {
 
     #[test]
     fn parse_audit_output_fails_for_high_or_critical_vulnerabilities() {
-        let (vulns, db_version, status) = parse_audit_output(
+        let (vulns, _, db_version, status) = parse_audit_output(
             r#"{
   "database": { "advisory-count": 1, "version": "2026-05-08" },
   "vulnerabilities": {
@@ -200,6 +369,9 @@ mod tests {
     ]
   }
 }"#,
+            None,
+            &AdvisoryAllowlist::default(),
+            TODAY,
         );
 
         assert_eq!(status, GateStatus::Fail);
@@ -210,7 +382,7 @@ mod tests {
 
     #[test]
     fn parse_audit_output_warns_for_medium_vulnerabilities() {
-        let (_vulns, _db_version, status) = parse_audit_output(
+        let (_vulns, _, _db_version, status) = parse_audit_output(
             r#"{
   "vulnerabilities": {
     "list": [
@@ -221,6 +393,9 @@ mod tests {
     ]
   }
 }"#,
+            None,
+            &AdvisoryAllowlist::default(),
+            TODAY,
         );
 
         assert_eq!(status, GateStatus::Warn);
@@ -228,11 +403,14 @@ mod tests {
 
     #[test]
     fn parse_audit_output_passes_when_no_vulnerabilities_are_listed() {
-        let (vulns, db_version, status) = parse_audit_output(
+        let (vulns, _, db_version, status) = parse_audit_output(
             r#"{
   "database": { "version": "2026-05-08" },
   "vulnerabilities": { "found": false, "count": 0, "list": [] }
 }"#,
+            None,
+            &AdvisoryAllowlist::default(),
+            TODAY,
         );
 
         assert_eq!(status, GateStatus::Pass);
@@ -242,10 +420,222 @@ mod tests {
 
     #[test]
     fn parse_audit_output_marks_malformed_json_pending() {
-        let (vulns, db_version, status) = parse_audit_output("not json");
+        let (vulns, _, db_version, status) =
+            parse_audit_output("not json", None, &AdvisoryAllowlist::default(), TODAY);
 
         assert_eq!(status, GateStatus::Pending);
         assert!(db_version.is_none());
         assert!(vulns.is_empty());
     }
+
+    const LOW_AND_MEDIUM: &str = r#"{
+  "vulnerabilities": {
+    "list": [
+      {
+        "advisory": { "id": "RUSTSEC-0000-0002", "severity": "low", "title": "minor" },
+        "package": { "name": "noisy" }
+      },
+      {
+        "advisory": { "id": "RUSTSEC-0000-0003", "severity": "medium", "title": "watch" },
+        "package": { "name": "example" }
+      }
+    ]
+  }
+}"#;
+
+    #[test]
+    fn min_severity_drops_advisories_below_the_floor() {
+        let (all, _, _, _) =
+            parse_audit_output(LOW_AND_MEDIUM, None, &AdvisoryAllowlist::default(), TODAY);
+        assert_eq!(all.len(), 2);
+
+        let (vulns, _, _db_version, status) = parse_audit_output(
+            LOW_AND_MEDIUM,
+            Some(AdvisorySeverity::Medium),
+            &AdvisoryAllowlist::default(),
+            TODAY,
+        );
+        assert_eq!(vulns.len(), 1);
+        assert_eq!(vulns[0].id, "RUSTSEC-0000-0003");
+        assert_eq!(status, GateStatus::Warn);
+    }
+
+    #[test]
+    fn filtered_low_advisory_leaves_the_gate_passing() {
+        let (vulns, _, _db_version, status) = parse_audit_output(
+            r#"{
+  "vulnerabilities": {
+    "list": [
+      {
+        "advisory": { "id": "RUSTSEC-0000-0002", "severity": "low", "title": "minor" },
+        "package": { "name": "noisy" }
+      },
+      {
+        "advisory": { "id": "RUSTSEC-0000-0004", "title": "unscored" },
+        "package": { "name": "other" }
+      }
+    ]
+  }
+}"#,
+            Some(AdvisorySeverity::Medium),
+            &AdvisoryAllowlist::default(),
+            TODAY,
+        );
+
+        assert_eq!(status, GateStatus::Pass);
+        assert_eq!(vulns.len(), 1, "advisories without a severity are kept");
+        assert_eq!(vulns[0].severity, "unknown");
+    }
+
+    #[test]
+    fn parse_audit_output_reads_advisory_url_and_patched_versions() {
+        let (vulns, _, _db_version, _status) = parse_audit_output(
+            r#"{
+  "vulnerabilities": {
+    "list": [
+      {
+        "advisory": {
+          "id": "RUSTSEC-2024-0003",
+          "severity": "high",
+          "title": "bad",
+          "url": "https://rustsec.org/advisories/RUSTSEC-2024-0003"
+        },
+        "versions": { "patched": [">=1.2.3"], "unaffected": ["<0.5.0"] },
+        "package": { "name": "foo" }
+      }
+    ]
+  }
+}"#,
+            None,
+            &AdvisoryAllowlist::default(),
+            TODAY,
+        );
+
+        assert_eq!(
+            vulns[0].url.as_deref(),
+            Some("https://rustsec.org/advisories/RUSTSEC-2024-0003")
+        );
+        assert_eq!(vulns[0].patched, vec![">=1.2.3"]);
+    }
+
+    #[test]
+    fn severity_parsing_ignores_case() {
+        assert_eq!(
+            AdvisorySeverity::parse("HIGH"),
+            Some(AdvisorySeverity::High)
+        );
+        assert_eq!(AdvisorySeverity::parse("unknown"), None);
+        assert!(AdvisorySeverity::Critical > AdvisorySeverity::Low);
+    }
+
+    const HIGH_WITHOUT_FIX: &str = r#"{
+  "vulnerabilities": {
+    "list": [
+      {
+        "advisory": { "id": "RUSTSEC-2024-0005", "severity": "high", "title": "no fix yet" },
+        "package": { "name": "stuck" }
+      }
+    ]
+  }
+}"#;
+
+    fn allowlist(expires: Option<&str>) -> AdvisoryAllowlist {
+        let expires = expires.map_or(String::new(), |date| format!("expires = \"{date}\"\n"));
+        AdvisoryAllowlist::parse(&format!(
+            "[[advisory]]\nid = \"RUSTSEC-2024-0005\"\nreason = \"not reachable\"\n{expires}"
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn allowlisted_advisory_is_acknowledged_and_does_not_fail() {
+        for expires in [None, Some("2026-12-31"), Some("2026-10-16")] {
+            let (vulns, acknowledged, _db_version, status) =
+                parse_audit_output(HIGH_WITHOUT_FIX, None, &allowlist(expires), TODAY);
+
+            assert_eq!(status, GateStatus::Pass, "expires {expires:?}");
+            assert!(vulns.is_empty());
+            assert_eq!(acknowledged.len(), 1);
+            assert_eq!(acknowledged[0].vulnerability.id, "RUSTSEC-2024-0005");
+            assert_eq!(acknowledged[0].reason.as_deref(), Some("not reachable"));
+            assert_eq!(acknowledged[0].expires.as_deref(), expires);
+        }
+    }
+
+    #[test]
+    fn expired_allowlist_entry_fails_the_gate_again() {
+        let (vulns, acknowledged, _db_version, status) = parse_audit_output(
+            HIGH_WITHOUT_FIX,
+            None,
+            &allowlist(Some("2026-10-15")),
+            TODAY,
+        );
+
+        assert_eq!(status, GateStatus::Fail);
+        assert_eq!(vulns.len(), 1);
+        assert!(acknowledged.is_empty());
+    }
+
+    #[test]
+    fn allowlist_rejects_malformed_entries() {
+        assert!(
+            AdvisoryAllowlist::parse("[[advisory]]\nid = \"X\"\nexpires = \"soon\"\n").is_err()
+        );
+        assert!(AdvisoryAllowlist::parse("[[advisory]]\nreason = \"no id\"\n").is_err());
+        assert!(AdvisoryAllowlist::parse("[[advisories]]\nid = \"X\"\n").is_err());
+        assert_eq!(
+            AdvisoryAllowlist::parse("").unwrap(),
+            AdvisoryAllowlist::default()
+        );
+    }
+
+    #[test]
+    fn expiry_dates_must_be_real_calendar_days() {
+        assert_eq!(parse_iso_date("2026-10-16"), Some(TODAY));
+        assert!(parse_iso_date("2024-02-29").is_some());
+        for invalid in ["2026-13-45", "2025-02-29", "2026-1-16", "2026/10/16", ""] {
+            assert_eq!(parse_iso_date(invalid), None, "{invalid}");
+        }
+        assert!(
+            AdvisoryAllowlist::parse("[[advisory]]\nid = \"X\"\nexpires = \"2026-13-45\"\n")
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn gate_runs_cargo_audit_through_the_command_runner() {
+        let lock = [FileStat {
+            path: "Cargo.lock".to_string(),
+            insertions: 3,
+            deletions: 1,
+        }];
+        let audit = MockCommands::default()
+            .reply("cargo audit --version", true, "cargo-audit 0.21.0")
+            .reply("cargo audit --json", false, HIGH_WITHOUT_FIX);
+        let none = AdvisoryAllowlist::default();
+
+        let gate = compute_supply_chain_gate(&audit, Path::new("."), &lock, None, &none)
+            .unwrap()
+            .expect("Cargo.lock change should produce a gate");
+        assert_eq!(gate.meta.status, GateStatus::Fail);
+        assert_eq!(gate.vulnerabilities[0].package, "stuck");
+
+        let gate = compute_supply_chain_gate(&audit, Path::new("."), &lock, None, &allowlist(None))
+            .unwrap()
+            .unwrap();
+        assert_eq!(gate.meta.status, GateStatus::Pass);
+        assert_eq!(gate.acknowledged.len(), 1);
+
+        let missing_tool = MockCommands::default();
+        let gate = compute_supply_chain_gate(&missing_tool, Path::new("."), &lock, None, &none)
+            .unwrap()
+            .unwrap();
+        assert_eq!(gate.meta.status, GateStatus::Pending);
+
+        assert!(
+            compute_supply_chain_gate(&audit, Path::new("."), &[], None, &none)
+                .unwrap()
+                .is_none()
+        );
+    }
 }
@@ -6,14 +6,17 @@ use tokmd_types::cockpit::{
 };
 
 use crate::display::round_pct;
+use crate::input::read_input_to_string;
 
 /// Load baseline receipt and compute trend comparison.
+///
+/// Baselines ending in `.gz` or `.zst` are decompressed transparently.
 pub fn load_and_compute_trend(
     baseline_path: &Path,
     current: &CockpitReceipt,
 ) -> Result<TrendComparison> {
     // Try to load baseline
-    let content = match std::fs::read_to_string(baseline_path) {
+    let content = match read_input_to_string(baseline_path) {
         Ok(c) => c,
         Err(_) => {
             return Ok(TrendComparison {
@@ -35,6 +38,15 @@ pub fn load_and_compute_trend(
         }
     };
 
+    Ok(compute_trend(baseline_path, &baseline, current))
+}
+
+/// Trend of `current` against an already loaded `baseline` receipt.
+pub(crate) fn compute_trend(
+    baseline_path: &Path,
+    baseline: &CockpitReceipt,
+    current: &CockpitReceipt,
+) -> TrendComparison {
     // Compute health trend
     let health = compute_metric_trend(
         current.code_health.score as f64,
@@ -50,16 +62,16 @@ pub fn load_and_compute_trend(
     );
 
     // Compute complexity trend indicator
-    let complexity = compute_complexity_trend(current, &baseline);
+    let complexity = compute_complexity_trend(current, baseline);
 
-    Ok(TrendComparison {
+    TrendComparison {
         baseline_available: true,
         baseline_path: Some(baseline_path.to_string_lossy().to_string()),
         baseline_generated_at_ms: Some(baseline.generated_at_ms),
         health: Some(health),
         risk: Some(risk),
         complexity: Some(complexity),
-    })
+    }
 }
 
 /// Compute trend metric with direction.
@@ -63,6 +63,7 @@ fn make_evidence(
 ) -> Evidence {
     Evidence {
         overall_status: overall,
+        project_coverage: None,
         mutation: make_mutation_gate(mutation_status),
         diff_coverage: None,
         contracts: None,
@@ -250,6 +251,8 @@ fn evidence_diff_coverage_gate_pass_high_coverage() {
         lines_added: 100,
         lines_covered: 90,
         coverage_pct: 0.9,
+        artifact: None,
+        branch_coverage: None,
         uncovered_hunks: vec![],
     };
     assert_eq!(gate.meta.status, GateStatus::Pass);
@@ -263,10 +266,13 @@ fn evidence_diff_coverage_gate_warn_medium_coverage() {
         lines_added: 100,
         lines_covered: 60,
         coverage_pct: 0.6,
+        artifact: None,
+        branch_coverage: None,
         uncovered_hunks: vec![UncoveredHunk {
             file: "src/new.rs".into(),
             start_line: 10,
             end_line: 30,
+            snippet: None,
         }],
     };
     assert_eq!(gate.meta.status, GateStatus::Warn);
@@ -281,16 +287,20 @@ fn evidence_diff_coverage_gate_fail_low_coverage() {
         lines_added: 200,
         lines_covered: 40,
         coverage_pct: 0.2,
+        artifact: None,
+        branch_coverage: None,
         uncovered_hunks: vec![
             UncoveredHunk {
                 file: "src/a.rs".into(),
                 start_line: 1,
                 end_line: 50,
+                snippet: None,
             },
             UncoveredHunk {
                 file: "src/b.rs".into(),
                 start_line: 10,
                 end_line: 80,
+                snippet: None,
             },
         ],
     };
@@ -360,6 +370,7 @@ fn evidence_supply_chain_gate_pass_no_vulns() {
         meta: make_gate_meta(GateStatus::Pass),
         vulnerabilities: vec![],
         denied: vec![],
+        acknowledged: vec![],
         advisory_db_version: Some("2024-01-01".into()),
     };
     assert_eq!(gate.meta.status, GateStatus::Pass);
@@ -375,8 +386,11 @@ fn evidence_supply_chain_gate_fail_critical_vuln() {
             package: "unsafe-crate".into(),
             severity: "critical".into(),
             title: "Remote code execution".into(),
+            url: None,
+            patched: vec![],
         }],
         denied: vec![],
+        acknowledged: vec![],
         advisory_db_version: Some("2024-06-01".into()),
     };
     assert_eq!(gate.meta.status, GateStatus::Fail);
@@ -393,8 +407,11 @@ fn evidence_supply_chain_gate_warn_medium_vuln() {
             package: "minor-risk".into(),
             severity: "medium".into(),
             title: "Denial of service".into(),
+            url: None,
+            patched: vec![],
         }],
         denied: vec![],
+        acknowledged: vec![],
         advisory_db_version: None,
     };
     assert_eq!(gate.meta.status, GateStatus::Warn);
@@ -408,6 +425,7 @@ fn evidence_determinism_gate_pass_hashes_match() {
         actual_hash: Some("abc123".into()),
         algo: "blake3".into(),
         differences: vec![],
+        excludes: vec![],
     };
     assert_eq!(gate.meta.status, GateStatus::Pass);
     assert!(gate.differences.is_empty());
@@ -422,6 +440,7 @@ fn evidence_determinism_gate_warn_hash_mismatch() {
         actual_hash: Some("def456".into()),
         algo: "blake3".into(),
         differences: vec!["source hash mismatch: expected abc123, got def456".into()],
+        excludes: vec![],
     };
     assert_eq!(gate.meta.status, GateStatus::Warn);
     assert_eq!(gate.differences.len(), 1);
@@ -432,12 +451,15 @@ fn evidence_determinism_gate_warn_hash_mismatch() {
 fn evidence_overall_all_pass_serde_roundtrip() {
     let evidence = Evidence {
         overall_status: GateStatus::Pass,
+        project_coverage: None,
         mutation: make_mutation_gate(GateStatus::Pass),
         diff_coverage: Some(DiffCoverageGate {
             meta: make_gate_meta(GateStatus::Pass),
             lines_added: 50,
             lines_covered: 45,
             coverage_pct: 0.9,
+            artifact: None,
+            branch_coverage: None,
             uncovered_hunks: vec![],
         }),
         contracts: None,
@@ -463,12 +485,15 @@ fn evidence_overall_all_pass_serde_roundtrip() {
 fn evidence_overall_some_fail_serde_roundtrip() {
     let evidence = Evidence {
         overall_status: GateStatus::Fail,
+        project_coverage: None,
         mutation: make_mutation_gate(GateStatus::Fail),
         diff_coverage: Some(DiffCoverageGate {
             meta: make_gate_meta(GateStatus::Pass),
             lines_added: 50,
             lines_covered: 45,
             coverage_pct: 0.9,
+            artifact: None,
+            branch_coverage: None,
             uncovered_hunks: vec![],
         }),
         contracts: None,
@@ -486,12 +511,15 @@ fn evidence_overall_some_fail_serde_roundtrip() {
 fn evidence_all_fail_overall_fail() {
     let evidence = Evidence {
         overall_status: GateStatus::Fail,
+        project_coverage: None,
         mutation: make_mutation_gate(GateStatus::Fail),
         diff_coverage: Some(DiffCoverageGate {
             meta: make_gate_meta(GateStatus::Fail),
             lines_added: 100,
             lines_covered: 10,
             coverage_pct: 0.1,
+            artifact: None,
+            branch_coverage: None,
             uncovered_hunks: vec![],
         }),
         contracts: Some(ContractDiffGate {
@@ -520,6 +548,7 @@ fn evidence_all_fail_overall_fail() {
 fn evidence_missing_optional_gates_skipped() {
     let evidence = Evidence {
         overall_status: GateStatus::Pass,
+        project_coverage: None,
         mutation: make_mutation_gate(GateStatus::Pass),
         diff_coverage: None,
         contracts: None,
@@ -1020,6 +1049,28 @@ fn trend_load_cockpit_receipt_as_baseline() {
     assert!(trend.risk.is_some());
 }
 
+#[test]
+fn trend_load_zstd_compressed_baseline() {
+    let stats = vec![stat("src/main.rs", 10, 5)];
+    let receipt = make_receipt_with_evidence(
+        &stats,
+        make_evidence(GateStatus::Pass, GateStatus::Pass, None),
+    );
+    let tmp = tempfile::Builder::new()
+        .suffix(".json.zst")
+        .tempfile()
+        .unwrap();
+    let json = serde_json::to_string_pretty(&receipt).unwrap();
+    let compressed = ruzstd::encoding::compress_to_vec(
+        json.as_bytes(),
+        ruzstd::encoding::CompressionLevel::Fastest,
+    );
+    std::fs::write(tmp.path(), compressed).unwrap();
+    let trend = load_and_compute_trend(tmp.path(), &receipt).unwrap();
+    assert!(trend.baseline_available);
+    assert!(trend.health.is_some());
+}
+
 #[test]
 fn trend_load_nonexistent_baseline_graceful() {
     let stats = vec![stat("src/main.rs", 10, 5)];
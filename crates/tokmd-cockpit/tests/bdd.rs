@@ -52,6 +52,9 @@ fn minimal_receipt() -> CockpitReceipt {
             bus_factor_warnings: Vec::new(),
             level: RiskLevel::Low,
             score: 0,
+            owners_touched: None,
+            unowned_files: vec![],
+            hotspot_owners: Default::default(),
         },
         contracts: Contracts {
             api_changed: false,
@@ -61,6 +64,7 @@ fn minimal_receipt() -> CockpitReceipt {
         },
         evidence: Evidence {
             overall_status: GateStatus::Pass,
+            project_coverage: None,
             mutation: MutationGate {
                 meta: GateMeta {
                     status: GateStatus::Skipped,
@@ -779,10 +783,13 @@ fn scenario_write_review_packet_creates_contract_files() {
         lines_added: 42,
         lines_covered: 0,
         coverage_pct: 0.0,
+        artifact: None,
+        branch_coverage: None,
         uncovered_hunks: vec![UncoveredHunk {
             file: "src/lib.rs".to_string(),
             start_line: 1,
             end_line: 42,
+            snippet: None,
         }],
     });
     receipt.review_plan = vec![ReviewItem {
@@ -791,6 +798,8 @@ fn scenario_write_review_packet_creates_contract_files() {
         priority: 1,
         complexity: Some(4),
         lines_changed: Some(240),
+        review_minutes: None,
+        owners: None,
     }];
     let out = dir.path().join("review");
 
@@ -952,10 +961,13 @@ fn scenario_review_map_orders_review_first_by_evidence_risk() {
         lines_added: 12,
         lines_covered: 0,
         coverage_pct: 0.0,
+        artifact: None,
+        branch_coverage: None,
         uncovered_hunks: vec![UncoveredHunk {
             file: "src/missing.rs".to_string(),
             start_line: 1,
             end_line: 12,
+            snippet: None,
         }],
     });
     receipt.review_plan = vec![
@@ -965,6 +977,8 @@ fn scenario_review_map_orders_review_first_by_evidence_risk() {
             priority: 1,
             complexity: Some(5),
             lines_changed: Some(400),
+            review_minutes: None,
+            owners: None,
         },
         ReviewItem {
             path: "src/missing.rs".to_string(),
@@ -972,6 +986,8 @@ fn scenario_review_map_orders_review_first_by_evidence_risk() {
             priority: 2,
             complexity: Some(1),
             lines_changed: Some(12),
+            review_minutes: None,
+            owners: None,
         },
     ];
     let out = dir.path().join("review");
@@ -1025,6 +1041,8 @@ fn scenario_review_map_orders_contract_paths_before_ordinary_low_priority_items(
             priority: 3,
             complexity: Some(1),
             lines_changed: Some(4),
+            review_minutes: None,
+            owners: None,
         },
         ReviewItem {
             path: "crates/tokmd/schemas/review-map.schema.json".to_string(),
@@ -1032,6 +1050,8 @@ fn scenario_review_map_orders_contract_paths_before_ordinary_low_priority_items(
             priority: 3,
             complexity: Some(1),
             lines_changed: Some(1),
+            review_minutes: None,
+            owners: None,
         },
     ];
     let out = dir.path().join("review");
@@ -1080,6 +1100,8 @@ fn scenario_write_review_packet_includes_imported_proof_evidence() {
             priority: 1,
             complexity: Some(3),
             lines_changed: Some(12),
+            review_minutes: None,
+            owners: None,
         },
         ReviewItem {
             path: "unrelated.rs".to_string(),
@@ -1087,6 +1109,8 @@ fn scenario_write_review_packet_includes_imported_proof_evidence() {
             priority: 3,
             complexity: None,
             lines_changed: Some(1),
+            review_minutes: None,
+            owners: None,
         },
     ];
     let out = dir.path().join("review");
@@ -1243,6 +1267,8 @@ fn scenario_write_review_packet_keeps_ambiguous_multiscope_proof_packet_level()
             priority: 1,
             complexity: Some(3),
             lines_changed: Some(12),
+            review_minutes: None,
+            owners: None,
         },
         ReviewItem {
             path: "crates/tokmd/src/commands/cockpit.rs".to_string(),
@@ -1250,6 +1276,8 @@ fn scenario_write_review_packet_keeps_ambiguous_multiscope_proof_packet_level()
             priority: 1,
             complexity: Some(2),
             lines_changed: Some(8),
+            review_minutes: None,
+            owners: None,
         },
     ];
     let out = dir.path().join("review");
@@ -1354,6 +1382,8 @@ fn scenario_write_review_packet_includes_imported_doc_artifacts_evidence() {
             priority: 1,
             complexity: None,
             lines_changed: Some(24),
+            review_minutes: None,
+            owners: None,
         },
         ReviewItem {
             path: "docs/review-packet.md".to_string(),
@@ -1361,6 +1391,8 @@ fn scenario_write_review_packet_includes_imported_doc_artifacts_evidence() {
             priority: 1,
             complexity: None,
             lines_changed: Some(6),
+            review_minutes: None,
+            owners: None,
         },
     ];
     let out = dir.path().join("review");
@@ -1512,6 +1544,8 @@ fn scenario_write_review_packet_marks_missing_doc_artifacts_for_source_of_truth_
         priority: 1,
         complexity: None,
         lines_changed: Some(8),
+        review_minutes: None,
+        owners: None,
     }];
     let out = dir.path().join("review");
 
@@ -1554,6 +1588,8 @@ fn scenario_review_map_md_includes_packet_level_proof_overview() {
         priority: 1,
         complexity: Some(2),
         lines_changed: Some(8),
+        review_minutes: None,
+        owners: None,
     }];
     let out = dir.path().join("review");
     let proof = tokmd_cockpit::parse_proof_evidence_input(
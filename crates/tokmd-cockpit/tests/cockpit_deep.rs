@@ -36,6 +36,7 @@ fn minimal_gate_meta(status: GateStatus) -> GateMeta {
 fn minimal_evidence() -> Evidence {
     Evidence {
         overall_status: GateStatus::Pass,
+        project_coverage: None,
         mutation: MutationGate {
             meta: minimal_gate_meta(GateStatus::Pass),
             survivors: vec![],
@@ -365,6 +366,8 @@ fn evidence_diff_coverage_roundtrip() {
         lines_added: 100,
         lines_covered: 95,
         coverage_pct: 95.0,
+        artifact: None,
+        branch_coverage: None,
         uncovered_hunks: vec![],
     };
     let json = serde_json::to_string(&gate).unwrap();
@@ -396,6 +399,7 @@ fn evidence_complexity_gate_threshold() {
 fn evidence_all_optional_gates_serialized_when_present() {
     let evidence = Evidence {
         overall_status: GateStatus::Warn,
+        project_coverage: None,
         mutation: MutationGate {
             meta: minimal_gate_meta(GateStatus::Pass),
             survivors: vec![],
@@ -408,6 +412,8 @@ fn evidence_all_optional_gates_serialized_when_present() {
             lines_added: 50,
             lines_covered: 50,
             coverage_pct: 100.0,
+            artifact: None,
+            branch_coverage: None,
             uncovered_hunks: vec![],
         }),
         contracts: Some(ContractDiffGate {
@@ -421,6 +427,7 @@ fn evidence_all_optional_gates_serialized_when_present() {
             meta: minimal_gate_meta(GateStatus::Pass),
             vulnerabilities: vec![],
             denied: vec![],
+            acknowledged: vec![],
             advisory_db_version: None,
         }),
         determinism: Some(DeterminismGate {
@@ -429,6 +436,7 @@ fn evidence_all_optional_gates_serialized_when_present() {
             actual_hash: None,
             algo: "blake3".to_string(),
             differences: vec![],
+            excludes: vec![],
         }),
         complexity: Some(ComplexityGate {
             meta: minimal_gate_meta(GateStatus::Pass),
@@ -39,6 +39,7 @@ fn make_mutation_gate(status: GateStatus) -> MutationGate {
 fn make_evidence_all_pass() -> Evidence {
     Evidence {
         overall_status: GateStatus::Pass,
+        project_coverage: None,
         mutation: make_mutation_gate(GateStatus::Pass),
         diff_coverage: None,
         contracts: None,
@@ -84,6 +85,9 @@ fn make_minimal_receipt() -> CockpitReceipt {
             bus_factor_warnings: vec![],
             level: RiskLevel::Low,
             score: 10,
+            owners_touched: None,
+            unowned_files: vec![],
+            hotspot_owners: Default::default(),
         },
         contracts: Contracts {
             api_changed: false,
@@ -561,6 +565,7 @@ fn receipt_with_all_optional_evidence_roundtrips() {
         actual_hash: Some("abc".into()),
         algo: "blake3".into(),
         differences: vec![],
+        excludes: vec![],
     });
 
     let json = serde_json::to_string(&receipt).unwrap();
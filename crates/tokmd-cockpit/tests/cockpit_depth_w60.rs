@@ -59,6 +59,7 @@ fn mutation_gate(status: GateStatus) -> MutationGate {
 fn evidence_all_skipped() -> Evidence {
     Evidence {
         overall_status: GateStatus::Skipped,
+        project_coverage: None,
         mutation: mutation_gate(GateStatus::Skipped),
         diff_coverage: None,
         contracts: None,
@@ -71,6 +72,7 @@ fn evidence_all_skipped() -> Evidence {
 fn evidence_pass() -> Evidence {
     Evidence {
         overall_status: GateStatus::Pass,
+        project_coverage: None,
         mutation: mutation_gate(GateStatus::Pass),
         diff_coverage: None,
         contracts: None,
@@ -116,6 +118,9 @@ fn minimal_receipt() -> CockpitReceipt {
             bus_factor_warnings: Vec::new(),
             level: RiskLevel::Low,
             score: 0,
+            owners_touched: None,
+            unowned_files: vec![],
+            hotspot_owners: Default::default(),
         },
         contracts: no_contracts(),
         evidence: evidence_all_skipped(),
@@ -213,6 +218,8 @@ fn gate_diff_coverage_pass_at_80_pct() {
         lines_added: 100,
         lines_covered: 80,
         coverage_pct: 0.80,
+        artifact: None,
+        branch_coverage: None,
         uncovered_hunks: Vec::new(),
     });
     let r = receipt_with_evidence(ev);
@@ -229,10 +236,13 @@ fn gate_diff_coverage_warn_at_50_pct() {
         lines_added: 100,
         lines_covered: 50,
         coverage_pct: 0.50,
+        artifact: None,
+        branch_coverage: None,
         uncovered_hunks: vec![UncoveredHunk {
             file: "src/main.rs".into(),
             start_line: 10,
             end_line: 20,
+            snippet: None,
         }],
     });
     let r = receipt_with_evidence(ev);
@@ -249,6 +259,8 @@ fn gate_diff_coverage_fail_below_50_pct() {
         lines_added: 100,
         lines_covered: 10,
         coverage_pct: 0.10,
+        artifact: None,
+        branch_coverage: None,
         uncovered_hunks: Vec::new(),
     });
     ev.overall_status = GateStatus::Fail;
@@ -306,8 +318,11 @@ fn gate_supply_chain_with_vulnerabilities() {
             package: "unsafe-crate".into(),
             severity: "high".into(),
             title: "Memory safety issue".into(),
+            url: None,
+            patched: vec![],
         }],
         denied: Vec::new(),
+        acknowledged: Vec::new(),
         advisory_db_version: Some("2024-01-01".into()),
     });
     ev.overall_status = GateStatus::Fail;
@@ -350,6 +365,7 @@ fn gate_determinism_pass_hashes_match() {
         actual_hash: Some("aabbccdd".into()),
         algo: "blake3".into(),
         differences: Vec::new(),
+        excludes: vec![],
     });
     let r = receipt_with_evidence(ev);
     let det = r.evidence.determinism.as_ref().unwrap();
@@ -366,6 +382,7 @@ fn gate_determinism_warn_hashes_differ() {
         actual_hash: Some("eeff0011".into()),
         algo: "blake3".into(),
         differences: vec!["source hash mismatch".into()],
+        excludes: vec![],
     });
     ev.overall_status = GateStatus::Warn;
     let r = receipt_with_evidence(ev);
@@ -637,6 +654,8 @@ fn determinism_json_roundtrip_with_all_gates() {
         lines_added: 50,
         lines_covered: 45,
         coverage_pct: 0.90,
+        artifact: None,
+        branch_coverage: None,
         uncovered_hunks: Vec::new(),
     });
     r.evidence.complexity = Some(ComplexityGate {
@@ -1044,6 +1063,8 @@ fn render_comment_md_shows_priority_review_items() {
             priority: 1,
             complexity: Some(5),
             lines_changed: Some(300),
+            review_minutes: None,
+            owners: None,
         },
         ReviewItem {
             path: "src/trivial.rs".into(),
@@ -1051,6 +1072,8 @@ fn render_comment_md_shows_priority_review_items() {
             priority: 3,
             complexity: Some(1),
             lines_changed: Some(5),
+            review_minutes: None,
+            owners: None,
         },
     ];
     let c = render::render_comment_md(&r);
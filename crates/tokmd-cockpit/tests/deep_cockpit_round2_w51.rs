@@ -44,6 +44,7 @@ fn make_gate_meta(status: GateStatus) -> GateMeta {
 fn make_evidence_all_gates() -> Evidence {
     Evidence {
         overall_status: GateStatus::Pass,
+        project_coverage: None,
         mutation: MutationGate {
             meta: make_gate_meta(GateStatus::Pass),
             survivors: vec![MutationSurvivor {
@@ -60,10 +61,13 @@ fn make_evidence_all_gates() -> Evidence {
             lines_added: 100,
             lines_covered: 85,
             coverage_pct: 0.85,
+            artifact: None,
+            branch_coverage: None,
             uncovered_hunks: vec![UncoveredHunk {
                 file: "src/lib.rs".into(),
                 start_line: 50,
                 end_line: 65,
+                snippet: None,
             }],
         }),
         contracts: Some(ContractDiffGate {
@@ -93,8 +97,11 @@ fn make_evidence_all_gates() -> Evidence {
                 package: "some-crate".into(),
                 severity: "low".into(),
                 title: "Minor issue".into(),
+                url: None,
+                patched: vec![],
             }],
             denied: vec![],
+            acknowledged: vec![],
             advisory_db_version: Some("2024-01-01".into()),
         }),
         determinism: Some(DeterminismGate {
@@ -103,6 +110,7 @@ fn make_evidence_all_gates() -> Evidence {
             actual_hash: Some("abc123".into()),
             algo: "blake3".into(),
             differences: vec![],
+            excludes: vec![],
         }),
         complexity: Some(ComplexityGate {
             meta: make_gate_meta(GateStatus::Pass),
@@ -514,6 +522,7 @@ fn receipt_without_trend_serializes_cleanly() {
 fn evidence_optional_gates_none_serialize_cleanly() {
     let evidence = Evidence {
         overall_status: GateStatus::Skipped,
+        project_coverage: None,
         mutation: MutationGate {
             meta: make_gate_meta(GateStatus::Skipped),
             survivors: vec![],
@@ -485,6 +485,9 @@ fn cockpit_receipt_json_roundtrip() {
             bus_factor_warnings: vec![],
             level: RiskLevel::Low,
             score: 0,
+            owners_touched: None,
+            unowned_files: vec![],
+            hotspot_owners: Default::default(),
         },
         contracts: Contracts {
             api_changed: false,
@@ -494,6 +497,7 @@ fn cockpit_receipt_json_roundtrip() {
         },
         evidence: Evidence {
             overall_status: GateStatus::Skipped,
+            project_coverage: None,
             mutation: make_mutation_gate(GateStatus::Skipped),
             diff_coverage: None,
             contracts: None,
@@ -551,10 +555,14 @@ fn cockpit_receipt_trend_serializes_when_present() {
             bus_factor_warnings: vec![],
             level: RiskLevel::Low,
             score: 0,
+            owners_touched: None,
+            unowned_files: vec![],
+            hotspot_owners: Default::default(),
         },
         contracts: no_contracts(),
         evidence: Evidence {
             overall_status: GateStatus::Skipped,
+            project_coverage: None,
             mutation: make_mutation_gate(GateStatus::Skipped),
             diff_coverage: None,
             contracts: None,
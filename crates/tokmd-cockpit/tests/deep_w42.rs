@@ -375,10 +375,14 @@ fn make_receipt_with_complexity(avg_cyc: f64) -> CockpitReceipt {
             bus_factor_warnings: vec![],
             level: RiskLevel::Low,
             score: 0,
+            owners_touched: None,
+            unowned_files: vec![],
+            hotspot_owners: Default::default(),
         },
         contracts: no_contracts(),
         evidence: Evidence {
             overall_status: GateStatus::Pass,
+            project_coverage: None,
             mutation: MutationGate {
                 meta: GateMeta {
                     status: GateStatus::Skipped,
@@ -0,0 +1,182 @@
+//! Cockpit computation against caller-supplied git and command backends.
+//!
+//! The repository here is a plain directory, not a git checkout, and every
+//! git answer and tool run comes from the fakes below, so the receipt is fully
+//! determined by the fixture and no subprocess is spawned.
+
+#![cfg(feature = "git")]
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use anyhow::Result;
+use tokmd_cockpit::*;
+
+struct FakeGit;
+
+impl GitProvider for FakeGit {
+    fn diff_numstat(&self, _repo_root: &Path, range: &str) -> Result<Vec<FileStat>> {
+        assert_eq!(range, "main..feature");
+        Ok(vec![
+            FileStat {
+                path: "src/lib.rs".to_string(),
+                insertions: 10,
+                deletions: 2,
+            },
+            FileStat {
+                path: "Cargo.lock".to_string(),
+                insertions: 3,
+                deletions: 1,
+            },
+        ])
+    }
+
+    fn commit_count(&self, _repo_root: &Path, _range: &str) -> Result<usize> {
+        Ok(2)
+    }
+
+    fn added_lines(
+        &self,
+        _repo_root: &Path,
+        _base: &str,
+        _head: &str,
+        _range_mode: tokmd_git::GitRangeMode,
+    ) -> Result<BTreeMap<PathBuf, BTreeSet<usize>>> {
+        Ok(BTreeMap::from([(
+            PathBuf::from("src/lib.rs"),
+            BTreeSet::from([1, 2]),
+        )]))
+    }
+
+    fn head_commit(&self, _repo_root: &Path) -> Result<String> {
+        Ok("abc123".to_string())
+    }
+
+    fn is_ancestor(&self, _repo_root: &Path, _ancestor: &str, _descendant: &str) -> bool {
+        false
+    }
+
+    fn diff_path(&self, _repo_root: &Path, _range: &str, _path: &str) -> Result<String> {
+        Ok(String::new())
+    }
+}
+
+/// Answers `cargo audit` and records every command it is asked to run.
+#[derive(Default)]
+struct FakeCommands {
+    calls: Mutex<Vec<String>>,
+}
+
+impl CommandRunner for FakeCommands {
+    fn run(&self, program: &str, args: &[&str], _cwd: &Path) -> std::io::Result<CommandOutput> {
+        let command = format!("{program} {}", args.join(" "));
+        self.calls.lock().unwrap().push(command.clone());
+        let stdout = match command.as_str() {
+            "cargo audit --version" => "cargo-audit 0.21.0",
+            "cargo audit --json" => {
+                r#"{"vulnerabilities":{"list":[{"advisory":{"id":"RUSTSEC-2024-0007","severity":"medium","title":"watch"},"package":{"name":"dep"}}]}}"#
+            }
+            _ => {
+                return Err(std::io::Error::new(std::io::ErrorKind::NotFound, command));
+            }
+        };
+        Ok(CommandOutput {
+            success: true,
+            stdout: stdout.to_string(),
+            stderr: String::new(),
+        })
+    }
+}
+
+fn fixture() -> tempfile::TempDir {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::create_dir(dir.path().join("src")).unwrap();
+    std::fs::write(
+        dir.path().join("src/lib.rs"),
+        "pub fn add(a: u32, b: u32) -> u32 {\n    a + b\n}\n",
+    )
+    .unwrap();
+    std::fs::write(
+        dir.path().join("lcov.info"),
+        "SF:src/lib.rs\nDA:1,1\nDA:2,1\nend_of_record\n",
+    )
+    .unwrap();
+    std::fs::write(
+        dir.path().join("mutants-summary.json"),
+        r#"{"commit":"abc123","status":"pass","scope":["src/lib.rs"],"survivors":[],"killed":4,"timeout":0,"unviable":0}"#,
+    )
+    .unwrap();
+    dir
+}
+
+fn compute(dir: &Path, commands: &FakeCommands) -> CockpitReceipt {
+    let host = CockpitHost {
+        git: &FakeGit,
+        commands,
+    };
+    compute_cockpit_with(
+        &host,
+        &dir.to_path_buf(),
+        "main",
+        "feature",
+        tokmd_git::GitRangeMode::TwoDot,
+        None,
+        &GateOptions::default(),
+    )
+    .unwrap()
+}
+
+#[test]
+fn change_surface_comes_from_the_git_provider() {
+    let dir = fixture();
+    let receipt = compute(dir.path(), &FakeCommands::default());
+
+    assert_eq!(receipt.change_surface.commits, 2);
+    assert_eq!(receipt.change_surface.files_changed, 2);
+    assert_eq!(receipt.change_surface.insertions, 13);
+    assert_eq!(receipt.change_surface.deletions, 3);
+}
+
+#[test]
+fn every_gate_is_driven_by_the_fakes() {
+    let dir = fixture();
+    let commands = FakeCommands::default();
+    let receipt = compute(dir.path(), &commands);
+    let evidence = &receipt.evidence;
+
+    assert_eq!(evidence.mutation.meta.source, EvidenceSource::CiArtifact);
+    assert_eq!(evidence.mutation.meta.commit_match, CommitMatch::Exact);
+    assert_eq!(evidence.mutation.killed, 4);
+
+    let diff_coverage = evidence.diff_coverage.as_ref().unwrap();
+    assert_eq!(diff_coverage.lines_added, 2);
+    assert_eq!(diff_coverage.meta.status, GateStatus::Pass);
+
+    let supply_chain = evidence.supply_chain.as_ref().unwrap();
+    assert_eq!(supply_chain.meta.status, GateStatus::Warn);
+    assert_eq!(supply_chain.vulnerabilities[0].id, "RUSTSEC-2024-0007");
+
+    let calls = commands.calls.lock().unwrap();
+    assert!(
+        calls.contains(&"cargo audit --json".to_string()),
+        "{calls:?}"
+    );
+}
+
+#[test]
+fn identical_inputs_give_identical_evidence() {
+    let dir = fixture();
+    // The complexity gate stamps when it ran; everything else must match.
+    let evidence = || {
+        let receipt = compute(dir.path(), &FakeCommands::default());
+        let mut value = serde_json::to_value(&receipt.evidence).unwrap();
+        value["complexity"]
+            .as_object_mut()
+            .unwrap()
+            .remove("evidence_generated_at_ms");
+        value
+    };
+
+    assert_eq!(evidence(), evidence());
+}
@@ -48,6 +48,7 @@ fn make_receipt(stats: &[FileStat]) -> CockpitReceipt {
         contracts,
         evidence: Evidence {
             overall_status: GateStatus::Pass,
+            project_coverage: None,
             mutation: MutationGate {
                 meta: GateMeta {
                     status: GateStatus::Skipped,
@@ -301,6 +302,62 @@ fn integration_trend_comparison_e2e() {
     assert!(trend.complexity.is_some());
 }
 
+#[test]
+fn integration_second_run_trends_against_the_cached_first_run()
+-> Result<(), Box<dyn std::error::Error>> {
+    let git_dir = tempfile::tempdir()?;
+
+    // First run: nothing cached yet, so no trend
+    let mut first = make_receipt(&[make_file_stat("src/lib.rs", 100, 50)]);
+    first.code_health.score = 70;
+    assert!(trend_since_last_run(git_dir.path(), &first)?.is_none());
+    store_last_run(git_dir.path(), &first)?;
+    assert!(git_dir.path().join(LAST_RUN_CACHE_PATH).is_file());
+
+    // Second run picks up the first as its baseline without --baseline
+    let mut second = make_receipt(&[make_file_stat("src/lib.rs", 10, 2)]);
+    second.code_health.score = 90;
+    let trend = trend_since_last_run(git_dir.path(), &second)?
+        .ok_or("second run should trend against the first")?;
+    assert!(trend.baseline_available);
+    assert_eq!(trend.baseline_generated_at_ms, Some(first.generated_at_ms));
+    let health = trend.health.ok_or("health trend")?;
+    assert_eq!(health.previous, 70.0);
+    assert_eq!(health.current, 90.0);
+    assert_eq!(health.direction, TrendDirection::Improving);
+    Ok(())
+}
+
+#[test]
+fn integration_last_run_over_other_refs_yields_no_trend() -> Result<(), Box<dyn std::error::Error>>
+{
+    let git_dir = tempfile::tempdir()?;
+    let first = make_receipt(&[make_file_stat("src/lib.rs", 100, 50)]);
+    store_last_run(git_dir.path(), &first)?;
+
+    let mut other_head = make_receipt(&[make_file_stat("src/lib.rs", 10, 2)]);
+    other_head.head_ref = "other-branch".to_string();
+    assert!(trend_since_last_run(git_dir.path(), &other_head)?.is_none());
+
+    let mut other_base = make_receipt(&[make_file_stat("src/lib.rs", 10, 2)]);
+    other_base.base_ref = "release".to_string();
+    assert!(trend_since_last_run(git_dir.path(), &other_base)?.is_none());
+    Ok(())
+}
+
+#[test]
+fn integration_unreadable_last_run_cache_yields_no_trend() -> Result<(), Box<dyn std::error::Error>>
+{
+    let git_dir = tempfile::tempdir()?;
+    let cache = git_dir.path().join(LAST_RUN_CACHE_PATH);
+    std::fs::create_dir_all(cache.parent().ok_or("cache parent")?)?;
+    std::fs::write(&cache, "{not json")?;
+
+    let current = make_receipt(&[make_file_stat("src/lib.rs", 1, 0)]);
+    assert!(trend_since_last_run(git_dir.path(), &current)?.is_none());
+    Ok(())
+}
+
 // ===========================================================================
 // Integration: Determinism hashing workflow
 // ===========================================================================
@@ -49,6 +49,7 @@ fn make_receipt(stats: &[FileStat]) -> CockpitReceipt {
         contracts,
         evidence: Evidence {
             overall_status: GateStatus::Pass,
+            project_coverage: None,
             mutation: MutationGate {
                 meta: GateMeta {
                     status: GateStatus::Skipped,
@@ -87,6 +87,9 @@ fn receipt_with_status(status: GateStatus) -> CockpitReceipt {
             bus_factor_warnings: vec![],
             level: RiskLevel::Medium,
             score: 25,
+            owners_touched: None,
+            unowned_files: vec![],
+            hotspot_owners: Default::default(),
         },
         contracts: Contracts {
             api_changed: false,
@@ -96,6 +99,7 @@ fn receipt_with_status(status: GateStatus) -> CockpitReceipt {
         },
         evidence: Evidence {
             overall_status: status,
+            project_coverage: None,
             mutation: base_mutation(),
             diff_coverage: None,
             contracts: None,
@@ -75,6 +75,9 @@ fn base_receipt() -> CockpitReceipt {
             bus_factor_warnings: vec![],
             level: RiskLevel::Low,
             score: 10,
+            owners_touched: None,
+            unowned_files: vec![],
+            hotspot_owners: Default::default(),
         },
         contracts: Contracts {
             api_changed: false,
@@ -84,6 +87,7 @@ fn base_receipt() -> CockpitReceipt {
         },
         evidence: Evidence {
             overall_status: GateStatus::Pass,
+            project_coverage: None,
             mutation: base_mutation(),
             diff_coverage: None,
             contracts: None,
@@ -260,6 +264,8 @@ fn comment_emits_diff_coverage_percent_with_one_decimal() {
         lines_added: 10,
         lines_covered: 7,
         coverage_pct: 0.732,
+        artifact: None,
+        branch_coverage: None,
         uncovered_hunks: vec![],
     });
 
@@ -271,6 +277,46 @@ fn comment_emits_diff_coverage_percent_with_one_decimal() {
     );
 }
 
+#[test]
+fn comment_emits_uncovered_hunk_snippets() {
+    let mut r = base_receipt();
+    r.evidence.diff_coverage = Some(DiffCoverageGate {
+        meta: base_meta(),
+        lines_added: 10,
+        lines_covered: 5,
+        coverage_pct: 0.5,
+        artifact: None,
+        branch_coverage: None,
+        uncovered_hunks: vec![
+            UncoveredHunk {
+                file: "src/lib.rs".to_string(),
+                start_line: 3,
+                end_line: 12,
+                snippet: Some(vec![
+                    "fn untested() {".to_string(),
+                    "    todo!()".to_string(),
+                ]),
+            },
+            UncoveredHunk {
+                file: "src/other.rs".to_string(),
+                start_line: 1,
+                end_line: 1,
+                snippet: None,
+            },
+        ],
+    });
+
+    let md = render_comment_md(&r);
+
+    assert!(
+        md.contains(
+            "- Diff coverage: 50.0%\n  - `src/lib.rs` lines 3-12:\n    ```\n    fn untested() {\n        todo!()\n    …\n    ```\n"
+        ),
+        "unexpected snippet block in: {md}"
+    );
+    assert!(!md.contains("src/other.rs"));
+}
+
 #[test]
 fn comment_omits_contract_failure_line_when_no_failures() {
     let mut r = base_receipt();
@@ -311,6 +357,7 @@ fn comment_omits_supply_chain_line_when_no_vulnerabilities() {
         meta: base_meta(),
         vulnerabilities: vec![],
         denied: vec![],
+        acknowledged: vec![],
         advisory_db_version: None,
     });
 
@@ -329,8 +376,11 @@ fn comment_emits_supply_chain_vuln_count() {
             package: "openssl".to_string(),
             severity: "high".to_string(),
             title: "buffer overflow".to_string(),
+            url: None,
+            patched: vec![],
         }],
         denied: vec![],
+        acknowledged: vec![],
         advisory_db_version: None,
     });
 
@@ -482,6 +532,8 @@ fn priority_items_section_omitted_when_no_high_priority_items() {
         priority: 3,
         complexity: None,
         lines_changed: None,
+        review_minutes: None,
+        owners: None,
     }];
 
     let md = render_comment_md(&r);
@@ -500,6 +552,8 @@ fn priority_items_section_includes_priority_one_and_two() {
             priority: 1,
             complexity: None,
             lines_changed: None,
+            review_minutes: None,
+            owners: None,
         },
         ReviewItem {
             path: "src/p2.rs".to_string(),
@@ -507,6 +561,8 @@ fn priority_items_section_includes_priority_one_and_two() {
             priority: 2,
             complexity: None,
             lines_changed: None,
+            review_minutes: None,
+            owners: None,
         },
         ReviewItem {
             path: "src/p3.rs".to_string(),
@@ -514,6 +570,8 @@ fn priority_items_section_includes_priority_one_and_two() {
             priority: 3,
             complexity: None,
             lines_changed: None,
+            review_minutes: None,
+            owners: None,
         },
     ];
 
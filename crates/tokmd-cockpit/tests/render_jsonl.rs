@@ -0,0 +1,168 @@
+//! Tests for `render::render_cockpit_jsonl`.
+
+use serde_json::Value;
+use tokmd_cockpit::render::render_cockpit_jsonl;
+use tokmd_cockpit::*;
+use tokmd_types::cockpit::COCKPIT_SCHEMA_VERSION;
+
+fn base_meta() -> GateMeta {
+    GateMeta {
+        status: GateStatus::Pass,
+        source: EvidenceSource::RanLocal,
+        commit_match: CommitMatch::Exact,
+        scope: ScopeCoverage {
+            relevant: vec![],
+            tested: vec![],
+            ratio: 1.0,
+            lines_relevant: None,
+            lines_tested: None,
+        },
+        evidence_commit: None,
+        evidence_generated_at_ms: None,
+    }
+}
+
+fn base_receipt() -> CockpitReceipt {
+    CockpitReceipt {
+        schema_version: COCKPIT_SCHEMA_VERSION,
+        mode: "cockpit".to_string(),
+        generated_at_ms: 1_700_000_000_000,
+        base_ref: "main".to_string(),
+        head_ref: "HEAD".to_string(),
+        change_surface: ChangeSurface {
+            commits: 1,
+            files_changed: 1,
+            insertions: 10,
+            deletions: 5,
+            net_lines: 5,
+            churn_velocity: 0.0,
+            change_concentration: 0.0,
+        },
+        composition: Composition {
+            code_pct: 1.0,
+            test_pct: 0.0,
+            docs_pct: 0.0,
+            config_pct: 0.0,
+            test_ratio: 0.0,
+        },
+        code_health: CodeHealth {
+            score: 95,
+            grade: "A".to_string(),
+            large_files_touched: 0,
+            avg_file_size: 100,
+            complexity_indicator: ComplexityIndicator::Low,
+            warnings: vec![],
+        },
+        risk: Risk {
+            hotspots_touched: vec![],
+            bus_factor_warnings: vec![],
+            level: RiskLevel::Low,
+            score: 10,
+            owners_touched: None,
+            unowned_files: vec![],
+            hotspot_owners: Default::default(),
+        },
+        contracts: Contracts {
+            api_changed: false,
+            cli_changed: false,
+            schema_changed: false,
+            breaking_indicators: 0,
+        },
+        evidence: Evidence {
+            overall_status: GateStatus::Warn,
+            project_coverage: None,
+            mutation: MutationGate {
+                meta: GateMeta {
+                    status: GateStatus::Skipped,
+                    ..base_meta()
+                },
+                survivors: vec![],
+                killed: 0,
+                timeout: 0,
+                unviable: 0,
+            },
+            diff_coverage: None,
+            contracts: None,
+            supply_chain: None,
+            determinism: None,
+            complexity: None,
+        },
+        review_plan: vec![],
+        trend: None,
+    }
+}
+
+fn records(receipt: &CockpitReceipt) -> Vec<Value> {
+    render_cockpit_jsonl(receipt)
+        .lines()
+        .map(|line| serde_json::from_str(line).expect("each line is JSON"))
+        .collect()
+}
+
+#[test]
+fn one_line_per_present_gate_plus_summary() {
+    let mut receipt = base_receipt();
+    receipt.evidence.diff_coverage = Some(DiffCoverageGate {
+        meta: GateMeta {
+            status: GateStatus::Warn,
+            scope: ScopeCoverage {
+                relevant: vec!["src/lib.rs".to_string()],
+                tested: vec![],
+                ratio: 0.625,
+                lines_relevant: Some(8),
+                lines_tested: Some(5),
+            },
+            ..base_meta()
+        },
+        lines_added: 8,
+        lines_covered: 5,
+        coverage_pct: 0.625,
+        artifact: None,
+        branch_coverage: None,
+        uncovered_hunks: vec![],
+    });
+    receipt.evidence.determinism = Some(DeterminismGate {
+        meta: base_meta(),
+        expected_hash: None,
+        actual_hash: None,
+        algo: "blake3".to_string(),
+        differences: vec![],
+        excludes: vec![],
+    });
+
+    let out = render_cockpit_jsonl(&receipt);
+    let records = records(&receipt);
+
+    // mutation (always present) + diff_coverage + determinism + summary.
+    assert_eq!(out.lines().count(), 3 + 1);
+    assert!(out.ends_with('\n'));
+    let gates: Vec<&str> = records[..3]
+        .iter()
+        .map(|r| r["gate"].as_str().unwrap())
+        .collect();
+    assert_eq!(gates, vec!["mutation", "diff_coverage", "determinism"]);
+
+    let coverage = &records[1];
+    assert_eq!(coverage["record"], "gate");
+    assert_eq!(coverage["status"], "warn");
+    assert_eq!(coverage["scope_ratio"], 0.625);
+    assert_eq!(coverage["timestamp_ms"], 1_700_000_000_000u64);
+    assert_eq!(coverage["head_ref"], "HEAD");
+
+    let summary = &records[3];
+    assert_eq!(summary["record"], "summary");
+    assert_eq!(summary["gates"], 3);
+    assert_eq!(summary["overall_status"], "warn");
+    assert_eq!(summary["risk_level"], "low");
+}
+
+#[test]
+fn absent_optional_gates_emit_no_lines() {
+    let records = records(&base_receipt());
+
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[0]["gate"], "mutation");
+    assert_eq!(records[0]["status"], "skipped");
+    assert_eq!(records[1]["record"], "summary");
+    assert_eq!(records[1]["gates"], 1);
+}
@@ -12,8 +12,9 @@
 //! The sub-renderers are private (`pub(super)`); they are exercised
 //! through the public `render_markdown` entrypoint.
 
-use tokmd_cockpit::render::render_markdown;
+use tokmd_cockpit::render::{render_markdown, render_markdown_grouped, render_markdown_with};
 use tokmd_cockpit::*;
+use tokmd_format::StatusStyle;
 use tokmd_types::cockpit::COCKPIT_SCHEMA_VERSION;
 
 fn base_meta() -> GateMeta {
@@ -82,6 +83,9 @@ fn base_receipt() -> CockpitReceipt {
             bus_factor_warnings: vec![],
             level: RiskLevel::Low,
             score: 10,
+            owners_touched: None,
+            unowned_files: vec![],
+            hotspot_owners: Default::default(),
         },
         contracts: Contracts {
             api_changed: false,
@@ -91,6 +95,7 @@ fn base_receipt() -> CockpitReceipt {
         },
         evidence: Evidence {
             overall_status: GateStatus::Pass,
+            project_coverage: None,
             mutation: base_mutation(),
             diff_coverage: None,
             contracts: None,
@@ -116,6 +121,23 @@ fn markdown_emits_glass_cockpit_header_and_evidence_gates_section() {
     assert!(md.contains("- **Mutation**:"));
 }
 
+#[test]
+fn markdown_labels_risk_and_gates_with_status_style() {
+    let mut r = base_receipt();
+    r.risk.level = RiskLevel::Critical;
+    r.evidence.overall_status = GateStatus::Fail;
+
+    let plain = render_markdown(&r);
+    assert!(plain.contains("- **Level**: critical\n"));
+    assert!(plain.contains("- **Overall status**: Fail\n"));
+
+    let style = StatusStyle::traffic_light().with_label("critical", "🚨 CRITICAL");
+    let md = render_markdown_with(&r, &style);
+    assert!(md.contains("- **Level**: 🚨 CRITICAL\n"));
+    assert!(md.contains("- **Overall status**: 🔴 fail\n"));
+    assert!(md.contains("- **Mutation**: ⚪ skipped ("));
+}
+
 #[test]
 fn markdown_omits_optional_gate_lines_when_none() {
     let md = render_markdown(&base_receipt());
@@ -124,6 +146,7 @@ fn markdown_omits_optional_gate_lines_when_none() {
     assert!(!md.contains("- **Supply chain**"));
     assert!(!md.contains("- **Determinism**"));
     assert!(!md.contains("- **Complexity**:"));
+    assert!(!md.contains("- **Project coverage**"));
 }
 
 // ---------------------------------------------------------------------------
@@ -138,6 +161,8 @@ fn markdown_emits_diff_coverage_gate_line_when_present() {
         lines_added: 100,
         lines_covered: 84,
         coverage_pct: 0.842,
+        artifact: None,
+        branch_coverage: None,
         uncovered_hunks: vec![],
     });
 
@@ -149,6 +174,60 @@ fn markdown_emits_diff_coverage_gate_line_when_present() {
     );
 }
 
+#[test]
+fn markdown_emits_branch_coverage_under_diff_coverage() {
+    let mut r = base_receipt();
+    r.evidence.diff_coverage = Some(DiffCoverageGate {
+        meta: base_meta(),
+        lines_added: 100,
+        lines_covered: 84,
+        coverage_pct: 0.842,
+        artifact: Some("coverage/lcov.info".to_string()),
+        branch_coverage: Some(BranchCoverage {
+            status: GateStatus::Warn,
+            branches_added: 8,
+            branches_covered: 4,
+            coverage_pct: 0.5,
+        }),
+        uncovered_hunks: vec![],
+    });
+
+    let md = render_markdown(&r);
+
+    assert!(
+        md.contains("- **Diff coverage**: Pass (84.2%)\n  - Branches: Warn (50.0%, 4/8 taken)\n"),
+        "unexpected branch coverage line in: {md}"
+    );
+}
+
+#[test]
+fn markdown_emits_project_coverage_gate_line_when_present() {
+    let mut r = base_receipt();
+    r.evidence.project_coverage = Some(ProjectCoverageGate {
+        meta: GateMeta {
+            status: GateStatus::Warn,
+            ..base_meta()
+        },
+        files: 12,
+        lines_found: 400,
+        lines_hit: 250,
+        coverage_pct: 0.625,
+        branches_found: Some(40),
+        branches_hit: Some(30),
+        branch_coverage_pct: Some(0.75),
+        artifact: Some("lcov.info".to_string()),
+    });
+
+    let md = render_markdown(&r);
+
+    assert!(
+        md.contains(
+            "- **Project coverage**: Warn (62.5%, 250/400 lines in 12 files)\n  - Branches: 75.0%, 30/40 taken\n"
+        ),
+        "unexpected project coverage line in: {md}"
+    );
+}
+
 #[test]
 fn markdown_emits_contracts_gate_line_when_present_with_failure_count() {
     let mut r = base_receipt();
@@ -185,15 +264,20 @@ fn markdown_emits_supply_chain_gate_line_when_present_with_vuln_count() {
                 package: "x".to_string(),
                 severity: "low".to_string(),
                 title: "advisory".to_string(),
+                url: None,
+                patched: vec![],
             },
             Vulnerability {
                 id: "RUSTSEC-2024-0002".to_string(),
                 package: "y".to_string(),
                 severity: "high".to_string(),
                 title: "advisory".to_string(),
+                url: None,
+                patched: vec![],
             },
         ],
         denied: vec![],
+        acknowledged: vec![],
         advisory_db_version: None,
     });
 
@@ -205,6 +289,86 @@ fn markdown_emits_supply_chain_gate_line_when_present_with_vuln_count() {
     );
 }
 
+#[test]
+fn markdown_lists_remediation_for_each_vulnerability() {
+    let mut r = base_receipt();
+    r.evidence.supply_chain = Some(SupplyChainGate {
+        meta: GateMeta {
+            status: GateStatus::Fail,
+            ..base_meta()
+        },
+        vulnerabilities: vec![
+            Vulnerability {
+                id: "RUSTSEC-2024-0003".to_string(),
+                package: "foo".to_string(),
+                severity: "high".to_string(),
+                title: "advisory".to_string(),
+                url: Some("https://rustsec.org/advisories/RUSTSEC-2024-0003".to_string()),
+                patched: vec![">=1.2.3".to_string(), "^0.9.8".to_string()],
+            },
+            Vulnerability {
+                id: "RUSTSEC-2024-0004".to_string(),
+                package: "bar".to_string(),
+                severity: "medium".to_string(),
+                title: "advisory".to_string(),
+                url: None,
+                patched: vec![],
+            },
+        ],
+        denied: vec![],
+        acknowledged: vec![],
+        advisory_db_version: None,
+    });
+
+    let md = render_markdown(&r);
+
+    assert!(
+        md.contains(
+            "  - upgrade foo to >=1.2.3 or ^0.9.8 \
+             ([RUSTSEC-2024-0003](https://rustsec.org/advisories/RUSTSEC-2024-0003))"
+        ),
+        "{md}"
+    );
+    assert!(
+        md.contains("  - bar has no patched version (RUSTSEC-2024-0004)"),
+        "{md}"
+    );
+}
+
+#[test]
+fn markdown_lists_acknowledged_advisories_separately() {
+    let mut r = base_receipt();
+    r.evidence.supply_chain = Some(SupplyChainGate {
+        meta: base_meta(),
+        vulnerabilities: vec![],
+        denied: vec![],
+        acknowledged: vec![AcknowledgedVulnerability {
+            vulnerability: Vulnerability {
+                id: "RUSTSEC-2024-0005".to_string(),
+                package: "stuck".to_string(),
+                severity: "high".to_string(),
+                title: "advisory".to_string(),
+                url: None,
+                patched: vec![],
+            },
+            reason: Some("not reachable".to_string()),
+            expires: Some("2026-12-31".to_string()),
+        }],
+        advisory_db_version: None,
+    });
+
+    let md = render_markdown(&r);
+
+    assert!(
+        md.contains("- **Supply chain**: Pass (vulnerabilities: 0, acknowledged: 1)"),
+        "{md}"
+    );
+    assert!(
+        md.contains("  - RUSTSEC-2024-0005 in stuck acknowledged until 2026-12-31: not reachable"),
+        "{md}"
+    );
+}
+
 #[test]
 fn markdown_emits_determinism_gate_line_when_present_with_diff_count() {
     let mut r = base_receipt();
@@ -214,6 +378,7 @@ fn markdown_emits_determinism_gate_line_when_present_with_diff_count() {
         actual_hash: None,
         algo: "blake3".to_string(),
         differences: vec!["a.json".to_string(), "b.json".to_string()],
+        excludes: vec![],
     });
 
     let md = render_markdown(&r);
@@ -255,6 +420,8 @@ fn markdown_emits_all_optional_gate_lines_when_all_present() {
         lines_added: 1,
         lines_covered: 1,
         coverage_pct: 1.0,
+        artifact: None,
+        branch_coverage: None,
         uncovered_hunks: vec![],
     });
     r.evidence.contracts = Some(ContractDiffGate {
@@ -268,6 +435,7 @@ fn markdown_emits_all_optional_gate_lines_when_all_present() {
         meta: base_meta(),
         vulnerabilities: vec![],
         denied: vec![],
+        acknowledged: vec![],
         advisory_db_version: None,
     });
     r.evidence.determinism = Some(DeterminismGate {
@@ -276,6 +444,7 @@ fn markdown_emits_all_optional_gate_lines_when_all_present() {
         actual_hash: None,
         algo: "blake3".to_string(),
         differences: vec![],
+        excludes: vec![],
     });
     r.evidence.complexity = Some(ComplexityGate {
         meta: base_meta(),
@@ -448,3 +617,89 @@ fn markdown_summary_comparison_avg_cyclomatic_falls_back_when_delta_missing() {
     assert!(md.contains("### Summary Comparison"));
     assert!(md.contains("|Avg Cyclomatic|n/a|n/a|n/a|stable|"));
 }
+
+// ---------------------------------------------------------------------------
+// Review plan grouped by module
+// ---------------------------------------------------------------------------
+
+fn review_item(path: &str, priority: u32, lines: usize, minutes: u32) -> ReviewItem {
+    ReviewItem {
+        path: path.to_string(),
+        reason: format!("{lines} lines changed"),
+        priority,
+        complexity: Some(1),
+        lines_changed: Some(lines),
+        review_minutes: Some(minutes),
+        owners: None,
+    }
+}
+
+#[test]
+fn markdown_groups_review_plan_by_module_with_subtotals() {
+    let mut r = base_receipt();
+    r.review_plan = vec![
+        review_item("crates/core/src/lib.rs", 1, 250, 14),
+        review_item("crates/cli/src/main.rs", 2, 80, 6),
+        review_item("crates/core/src/parse.rs", 3, 10, 3),
+    ];
+    let md = render_markdown_grouped(&r, &StatusStyle::default(), &ReviewPlanGrouping::default());
+
+    assert!(md.contains("Estimated review time: ~23 min"));
+    assert!(md.contains("#### `crates/core`\n\n2 files, 260 lines changed, ~17 min\n"));
+    assert!(md.contains("#### `crates/cli`\n\n1 file, 80 lines changed, ~6 min\n"));
+    let core = md.find("#### `crates/core`").unwrap();
+    let cli = md.find("#### `crates/cli`").unwrap();
+    let parse = md.find("crates/core/src/parse.rs").unwrap();
+    assert!(core < parse && parse < cli, "items stay under their module");
+}
+
+#[test]
+fn markdown_review_plan_is_flat_without_grouping() {
+    let mut r = base_receipt();
+    r.review_plan = vec![review_item("crates/core/src/lib.rs", 1, 250, 14)];
+    let md = render_markdown(&r);
+    assert!(!md.contains("#### `crates/core`"));
+    assert!(md.contains("- **crates/core/src/lib.rs** (priority: 1)"));
+}
+
+// ---------------------------------------------------------------------------
+// CODEOWNERS ownership
+// ---------------------------------------------------------------------------
+
+#[test]
+fn markdown_emits_owners_and_unowned_files() {
+    let mut r = base_receipt();
+    r.review_plan = vec![
+        ReviewItem {
+            owners: Some(vec!["@alice".to_string(), "@bob".to_string()]),
+            ..review_item("crates/core/src/lib.rs", 1, 400, 42)
+        },
+        ReviewItem {
+            owners: Some(vec![]),
+            ..review_item("scripts/release.sh", 3, 4, 3)
+        },
+    ];
+    r.risk.hotspots_touched = vec!["crates/core/src/lib.rs".to_string()];
+    r.risk.hotspot_owners = std::collections::BTreeMap::from([(
+        "crates/core/src/lib.rs".to_string(),
+        vec!["@alice".to_string(), "@bob".to_string()],
+    )]);
+    r.risk.owners_touched = Some(2);
+    r.risk.unowned_files = vec!["scripts/release.sh".to_string()];
+    let md = render_markdown(&r);
+
+    assert!(md.contains("  - crates/core/src/lib.rs (@alice, @bob)\n"));
+    assert!(md.contains("- **Owners touched**: 2\n"));
+    assert!(md.contains("- **Unowned files**:\n  - scripts/release.sh\n"));
+    assert!(md.contains("  - Owners: @alice, @bob\n"));
+    assert!(md.contains("  - Owners: unowned\n"));
+}
+
+#[test]
+fn markdown_omits_ownership_without_codeowners() {
+    let mut r = base_receipt();
+    r.review_plan = vec![review_item("crates/core/src/lib.rs", 1, 250, 14)];
+    let md = render_markdown(&r);
+    assert!(!md.contains("Owners"));
+    assert!(!md.contains("Unowned"));
+}
@@ -28,6 +28,7 @@ fn sample_gate_meta(status: GateStatus) -> GateMeta {
 fn sample_evidence() -> Evidence {
     Evidence {
         overall_status: GateStatus::Pass,
+        project_coverage: None,
         mutation: MutationGate {
             meta: sample_gate_meta(GateStatus::Pass),
             survivors: vec![],
@@ -83,6 +84,9 @@ fn sample_cockpit_receipt() -> CockpitReceipt {
             bus_factor_warnings: vec![],
             level: RiskLevel::Medium,
             score: 35,
+            owners_touched: None,
+            unowned_files: vec![],
+            hotspot_owners: Default::default(),
         },
         contracts: Contracts {
             api_changed: true,
@@ -97,6 +101,8 @@ fn sample_cockpit_receipt() -> CockpitReceipt {
             priority: 1,
             complexity: Some(3),
             lines_changed: Some(80),
+            review_minutes: None,
+            owners: None,
         }],
         trend: None,
     }
@@ -244,6 +250,7 @@ fn commit_match_all_variants_roundtrip() {
 fn evidence_full_roundtrip() {
     let evidence = Evidence {
         overall_status: GateStatus::Warn,
+        project_coverage: None,
         mutation: MutationGate {
             meta: sample_gate_meta(GateStatus::Fail),
             survivors: vec![MutationSurvivor {
@@ -260,10 +267,13 @@ fn evidence_full_roundtrip() {
             lines_added: 50,
             lines_covered: 45,
             coverage_pct: 90.0,
+            artifact: None,
+            branch_coverage: None,
             uncovered_hunks: vec![UncoveredHunk {
                 file: "src/new.rs".to_string(),
                 start_line: 10,
                 end_line: 14,
+                snippet: None,
             }],
         }),
         contracts: Some(ContractDiffGate {
@@ -293,8 +303,11 @@ fn evidence_full_roundtrip() {
                 package: "serde".to_string(),
                 severity: "low".to_string(),
                 title: "Test vuln".to_string(),
+                url: None,
+                patched: vec![],
             }],
             denied: vec!["evil-crate".to_string()],
+            acknowledged: vec![],
             advisory_db_version: Some("2024-01-01".to_string()),
         }),
         determinism: Some(DeterminismGate {
@@ -303,6 +316,7 @@ fn evidence_full_roundtrip() {
             actual_hash: Some("abc123".to_string()),
             algo: "blake3".to_string(),
             differences: vec![],
+            excludes: vec![],
         }),
         complexity: Some(ComplexityGate {
             meta: sample_gate_meta(GateStatus::Warn),
@@ -433,6 +447,8 @@ fn review_item_roundtrip() {
             priority: 1,
             complexity: Some(4),
             lines_changed: Some(120),
+            review_minutes: None,
+            owners: None,
         },
         ReviewItem {
             path: "tests/test.rs".to_string(),
@@ -440,6 +456,8 @@ fn review_item_roundtrip() {
             priority: 3,
             complexity: None,
             lines_changed: None,
+            review_minutes: None,
+            owners: None,
         },
     ];
 
@@ -478,6 +496,9 @@ proptest! {
             bus_factor_warnings: vec![],
             level: RiskLevel::Low,
             score,
+            owners_touched: None,
+            unowned_files: vec![],
+            hotspot_owners: Default::default(),
         };
         let json = serde_json::to_string(&risk).unwrap();
         let back: Risk = serde_json::from_str(&json).unwrap();
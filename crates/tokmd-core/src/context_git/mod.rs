@@ -118,6 +118,9 @@ mod tests {
             lines,
             bytes: lines * 10,
             tokens: lines * 5,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         }
     }
 
@@ -248,6 +251,9 @@ mod tests {
             lines: 4,
             bytes: 40,
             tokens: 20,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         }];
         let scores = compute_git_scores(repo.path(), &rows, 100, 100);
 
@@ -390,6 +396,9 @@ mod tests {
                 lines: 5,
                 bytes: 50,
                 tokens: 25,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             },
         ];
         let scores = compute_git_scores(repo.path(), &rows, 100, 100);
@@ -422,6 +431,9 @@ mod tests {
             lines: 4,
             bytes: 40,
             tokens: 20,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         }];
         let Some(scores) = compute_git_scores(repo.path(), &rows, 100, 100) else {
             return;
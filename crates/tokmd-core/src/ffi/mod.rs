@@ -30,7 +30,7 @@ use crate::error::TokmdError;
 pub use byte_mode::run_json_bytes;
 use envelope::json_response;
 use inputs::parse_in_memory_inputs;
-use modes::run_mode;
+use modes::{capabilities_info, run_mode};
 use settings_parse::parse_scan_settings;
 
 /// Run a tokmd operation with JSON arguments, returning JSON output.
@@ -40,7 +40,8 @@ use settings_parse::parse_scan_settings;
 ///
 /// # Arguments
 ///
-/// * `mode` - The operation mode: "lang", "module", "export", "analyze", "diff"
+/// * `mode` - The operation mode: "lang", "module", "export", "analyze", "diff", "verify",
+///   "validate", "formats", "capabilities", "version"
 /// * `args_json` - JSON string containing the arguments
 ///
 /// # Returns
@@ -98,5 +99,37 @@ pub fn schema_version() -> u32 {
     tokmd_types::SCHEMA_VERSION
 }
 
+/// Get the oldest receipt schema version this build can still read.
+pub fn min_schema_version() -> u32 {
+    tokmd_format::MIN_SUPPORTED_SCHEMA_VERSION
+}
+
+/// Whether a receipt recorded with `schema_version` can be read by this
+/// build, migrating it first when it is older than [`schema_version`].
+pub fn is_compatible(schema_version: u64) -> bool {
+    tokmd_format::is_supported_schema_version(schema_version)
+}
+
+/// Describe what this build supports, as a JSON object.
+///
+/// Lists the modes [`run_json`] accepts, the analysis formats it can render,
+/// the receipt schema versions, and which Cargo features were enabled, so
+/// embedders can check support up front instead of by trial and error. The
+/// same object is the `data` of `run_json("capabilities", "{}")`; this
+/// function returns it without the envelope.
+///
+/// # Example
+///
+/// ```rust
+/// use tokmd_core::ffi::capabilities;
+///
+/// let caps: serde_json::Value = serde_json::from_str(&capabilities()).unwrap();
+/// assert!(caps["modes"].as_array().unwrap().contains(&"lang".into()));
+/// assert_eq!(caps["schema_versions"]["core"], tokmd_types::SCHEMA_VERSION);
+/// ```
+pub fn capabilities() -> String {
+    capabilities_info().to_string()
+}
+
 #[cfg(test)]
 mod tests;
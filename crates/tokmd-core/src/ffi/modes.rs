@@ -5,6 +5,7 @@
 
 use serde_json::Value;
 
+use super::parse::parse_bool;
 #[cfg(feature = "analysis")]
 use super::settings_parse::parse_analyze_settings;
 #[cfg(feature = "cockpit")]
@@ -36,6 +37,10 @@ pub(super) fn run_mode(
         "analyze" => run_analyze(args, scan, inputs),
         "cockpit" => run_cockpit(args),
         "diff" => run_diff(args),
+        "verify" => run_verify(args),
+        "validate" => run_validate(args),
+        "formats" => Ok(formats_info()),
+        "capabilities" => Ok(capabilities_info()),
         "version" => Ok(version_info()),
         _ => Err(TokmdError::unknown_mode(mode)),
     }
@@ -129,6 +134,104 @@ fn run_diff(args: &Value) -> Result<Value, TokmdError> {
     Ok(serde_json::to_value(receipt)?)
 }
 
+/// Verify a receipt's `integrity` block. A mismatch is a successful result
+/// with `valid: false`; only unreadable input is an error.
+fn run_verify(args: &Value) -> Result<Value, TokmdError> {
+    let receipt = parse_json_arg(args, "receipt")?
+        .ok_or_else(|| TokmdError::invalid_field("receipt", "required but missing"))?;
+    let export = parse_json_arg(args, "export")?;
+    let verdict = crate::verify_workflow(&receipt, export.as_ref())?;
+    let integrity = receipt
+        .get("derived")
+        .unwrap_or(&receipt)
+        .get("integrity")
+        .cloned()
+        .unwrap_or(Value::Null);
+    Ok(serde_json::json!({
+        "mode": receipt["mode"],
+        "valid": verdict.is_ok(),
+        "algo": integrity["algo"],
+        "entries": integrity["entries"],
+        "mismatch": verdict.err().map(|m| m.to_string()),
+    }))
+}
+
+/// Check that a receipt parses as its mode's type. Unknown keys are only
+/// looked for with `strict: true`, and are a successful result with
+/// `valid: false`; input that does not parse is an error.
+fn run_validate(args: &Value) -> Result<Value, TokmdError> {
+    let receipt = parse_json_arg(args, "receipt")?
+        .ok_or_else(|| TokmdError::invalid_field("receipt", "required but missing"))?;
+    let strict = parse_bool(args, "strict", false)?;
+    let unknown = crate::validate_workflow(&receipt, strict)?;
+    Ok(serde_json::json!({
+        "mode": receipt["mode"],
+        "strict": strict,
+        "valid": unknown.is_empty(),
+        "unknown_fields": unknown,
+    }))
+}
+
+/// A receipt argument given either as a JSON object or as a JSON string.
+fn parse_json_arg(args: &Value, field: &str) -> Result<Option<Value>, TokmdError> {
+    match args.get(field) {
+        None | Some(Value::Null) => Ok(None),
+        Some(Value::String(text)) => serde_json::from_str(text)
+            .map(Some)
+            .map_err(|err| TokmdError::invalid_json(format!("{field}: {err}"))),
+        Some(v) if v.is_object() => Ok(Some(v.clone())),
+        Some(_) => Err(TokmdError::invalid_field(
+            field,
+            "a JSON object or JSON string",
+        )),
+    }
+}
+
+/// Output formats usable in this build, e.g. `{"analysis": ["md", ...]}`.
+fn formats_info() -> Value {
+    serde_json::json!({
+        "analysis": tokmd_format::analysis::available_formats(),
+    })
+}
+
+/// Modes, formats, schema versions, and Cargo features of this build.
+pub(super) fn capabilities_info() -> Value {
+    let mut modes = vec!["lang", "module", "export"];
+    if cfg!(feature = "analysis") {
+        modes.push("analyze");
+    }
+    if cfg!(feature = "cockpit") {
+        modes.push("cockpit");
+    }
+    modes.extend([
+        "diff",
+        "verify",
+        "validate",
+        "formats",
+        "capabilities",
+        "version",
+    ]);
+
+    let version = version_info();
+    serde_json::json!({
+        "version": version["version"],
+        "modes": modes,
+        "formats": formats_info(),
+        "schema_versions": {
+            "core": version["schema_version"],
+            "core_min": tokmd_format::MIN_SUPPORTED_SCHEMA_VERSION,
+            "analysis": version["analysis_schema_version"],
+        },
+        "features": {
+            "analysis": cfg!(feature = "analysis"),
+            "cockpit": cfg!(feature = "cockpit"),
+            "git": cfg!(feature = "git"),
+            "fun": cfg!(feature = "fun"),
+            "archive-zip": cfg!(feature = "archive-zip"),
+        },
+    })
+}
+
 fn version_info() -> Value {
     #[cfg(feature = "analysis")]
     {
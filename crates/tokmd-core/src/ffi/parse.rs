@@ -6,7 +6,10 @@
 use serde_json::Value;
 
 use crate::error::TokmdError;
-use crate::settings::{ChildIncludeMode, ChildrenMode, ConfigMode, ExportFormat, RedactMode};
+use crate::settings::{
+    ChildIncludeMode, ChildrenMode, ConfigMode, ExportFormat, ExportSortKey, HashAlgo,
+    LineCountMode, RedactMode, SortDirection, SymlinkPolicy,
+};
 
 pub(super) fn nested_arg_object<'a>(args: &'a Value, field: &str) -> Result<&'a Value, TokmdError> {
     match args.get(field) {
@@ -63,6 +66,18 @@ pub(super) fn parse_optional_usize(args: &Value, field: &str) -> Result<Option<u
     }
 }
 
+/// Parse an optional f64 field strictly: missing/null -> None, non-number -> error.
+#[cfg(feature = "cockpit")]
+pub(super) fn parse_optional_f64(args: &Value, field: &str) -> Result<Option<f64>, TokmdError> {
+    match args.get(field) {
+        None | Some(Value::Null) => Ok(None),
+        Some(v) => v
+            .as_f64()
+            .map(Some)
+            .ok_or_else(|| TokmdError::invalid_field(field, "a number")),
+    }
+}
+
 /// Parse an optional bool field strictly: missing/null -> None, non-bool -> error.
 pub(super) fn parse_optional_bool(args: &Value, field: &str) -> Result<Option<bool>, TokmdError> {
     match args.get(field) {
@@ -150,8 +165,9 @@ pub(super) fn parse_child_include_mode(
 ) -> Result<ChildIncludeMode, TokmdError> {
     match args.get("children") {
         None => Ok(default),
-        Some(v) => serde_json::from_value::<ChildIncludeMode>(v.clone())
-            .map_err(|_| TokmdError::invalid_field("children", "'separate' or 'parents-only'")),
+        Some(v) => serde_json::from_value::<ChildIncludeMode>(v.clone()).map_err(|_| {
+            TokmdError::invalid_field("children", "'separate', 'parents-only', or 'nested'")
+        }),
     }
 }
 
@@ -167,6 +183,34 @@ pub(super) fn parse_redact_mode(
     }
 }
 
+/// Parse a HashAlgo field strictly.
+pub(super) fn parse_hash_algo(args: &Value) -> Result<HashAlgo, TokmdError> {
+    match args.get("hash_algo") {
+        None | Some(Value::Null) => Ok(HashAlgo::default()),
+        Some(v) => serde_json::from_value::<HashAlgo>(v.clone())
+            .map_err(|_| TokmdError::invalid_field("hash_algo", "'blake3' or 'sha256'")),
+    }
+}
+
+/// Parse a SymlinkPolicy field strictly.
+pub(super) fn parse_symlink_policy(args: &Value) -> Result<SymlinkPolicy, TokmdError> {
+    match args.get("symlink_policy") {
+        None | Some(Value::Null) => Ok(SymlinkPolicy::default()),
+        Some(v) => serde_json::from_value::<SymlinkPolicy>(v.clone()).map_err(|_| {
+            TokmdError::invalid_field("symlink_policy", "'ignore', 'follow', or 'follow-no-loop'")
+        }),
+    }
+}
+
+/// Parse a LineCountMode field strictly.
+pub(super) fn parse_line_count_mode(args: &Value) -> Result<LineCountMode, TokmdError> {
+    match args.get("line_count_mode") {
+        None | Some(Value::Null) => Ok(LineCountMode::default()),
+        Some(v) => serde_json::from_value::<LineCountMode>(v.clone())
+            .map_err(|_| TokmdError::invalid_field("line_count_mode", "'physical' or 'logical'")),
+    }
+}
+
 /// Parse an effort model from a string: missing/null -> None, unsupported values -> error.
 pub(super) fn parse_effort_model(args: &Value, field: &str) -> Result<Option<String>, TokmdError> {
     match parse_optional_string(args, field)? {
@@ -232,11 +276,43 @@ pub(super) fn parse_export_format(
     match args.get("format") {
         None => Ok(default),
         Some(v) => serde_json::from_value::<ExportFormat>(v.clone()).map_err(|_| {
-            TokmdError::invalid_field("format", "'csv', 'jsonl', 'json', or 'cyclonedx'")
+            TokmdError::invalid_field(
+                "format",
+                "'csv', 'jsonl', 'json', 'cyclonedx', 'paths', or 'paths0'",
+            )
         }),
     }
 }
 
+/// Parse an optional ExportSortKey field strictly.
+pub(super) fn parse_optional_export_sort_key(
+    args: &Value,
+) -> Result<Option<ExportSortKey>, TokmdError> {
+    match args.get("sort_by") {
+        None => Ok(None),
+        Some(v) => serde_json::from_value::<ExportSortKey>(v.clone())
+            .map(Some)
+            .map_err(|_| {
+                TokmdError::invalid_field(
+                    "sort_by",
+                    "'code', 'lines', 'comments', 'blanks', 'bytes', 'tokens', 'path', 'module', or 'lang'",
+                )
+            }),
+    }
+}
+
+/// Parse an optional SortDirection field strictly.
+pub(super) fn parse_optional_sort_direction(
+    args: &Value,
+) -> Result<Option<SortDirection>, TokmdError> {
+    match args.get("sort_dir") {
+        None => Ok(None),
+        Some(v) => serde_json::from_value::<SortDirection>(v.clone())
+            .map(Some)
+            .map_err(|_| TokmdError::invalid_field("sort_dir", "'asc' or 'desc'")),
+    }
+}
+
 /// Parse and validate analyze preset names.
 pub(super) fn parse_analyze_preset(args: &Value, default: &str) -> Result<String, TokmdError> {
     let preset = parse_string(args, "preset", default)?;
@@ -756,6 +832,8 @@ mod tests {
             ("jsonl", ExportFormat::Jsonl),
             ("json", ExportFormat::Json),
             ("cyclonedx", ExportFormat::Cyclonedx),
+            ("paths", ExportFormat::Paths),
+            ("paths0", ExportFormat::Paths0),
         ];
         for (input, expected) in cases {
             let args = json!({ "format": input });
@@ -782,6 +860,25 @@ mod tests {
         assert_eq!(err.code, ErrorCode::InvalidSettings);
     }
 
+    // ---- parse_optional_export_sort_key / parse_optional_sort_direction ---
+
+    #[test]
+    fn parse_export_sort_fields() {
+        let args = json!({"sort_by": "tokens", "sort_dir": "asc"});
+        assert_eq!(
+            parse_optional_export_sort_key(&args).unwrap(),
+            Some(ExportSortKey::Tokens)
+        );
+        assert_eq!(
+            parse_optional_sort_direction(&args).unwrap(),
+            Some(SortDirection::Asc)
+        );
+        assert_eq!(parse_optional_export_sort_key(&json!({})).unwrap(), None);
+
+        let err = parse_optional_sort_direction(&json!({"sort_dir": "up"})).unwrap_err();
+        assert_eq!(err.code, ErrorCode::InvalidSettings);
+    }
+
     // ---- parse_analyze_preset ---------------------------------------------
 
     #[test]
@@ -5,19 +5,21 @@
 
 use serde_json::Value;
 
-#[cfg(feature = "cockpit")]
-use super::parse::parse_string;
 use super::parse::{
     nested_arg_object, parse_analyze_preset, parse_bool, parse_child_include_mode,
     parse_children_mode, parse_config_mode, parse_effort_layer, parse_effort_model,
-    parse_export_format, parse_import_granularity, parse_optional_bool, parse_optional_redact_mode,
-    parse_optional_string, parse_optional_u64, parse_optional_usize, parse_redact_mode,
-    parse_required_string, parse_string_array, parse_usize, scan_arg_object,
+    parse_export_format, parse_hash_algo, parse_import_granularity, parse_line_count_mode,
+    parse_optional_bool, parse_optional_export_sort_key, parse_optional_redact_mode,
+    parse_optional_sort_direction, parse_optional_string, parse_optional_u64, parse_optional_usize,
+    parse_redact_mode, parse_required_string, parse_string_array, parse_symlink_policy,
+    parse_usize, scan_arg_object,
 };
+#[cfg(feature = "cockpit")]
+use super::parse::{parse_optional_f64, parse_string};
 use crate::error::TokmdError;
 use crate::settings::{
-    AnalyzeSettings, ChildIncludeMode, ChildrenMode, ConfigMode, DiffSettings, ExportFormat,
-    ExportSettings, LangSettings, ModuleSettings, RedactMode, ScanSettings,
+    AnalyzeSettings, ChildIncludeMode, ChildrenMode, ConfigMode, DiffSettings, ExportFilters,
+    ExportFormat, ExportSettings, LangSettings, ModuleSettings, RedactMode, ScanSettings,
 };
 
 pub(super) fn parse_scan_settings(args: &Value) -> Result<ScanSettings, TokmdError> {
@@ -37,6 +39,14 @@ pub(super) fn parse_scan_settings(args: &Value) -> Result<ScanSettings, TokmdErr
             no_ignore_dot: parse_bool(obj, "no_ignore_dot", false)?,
             no_ignore_vcs: parse_bool(obj, "no_ignore_vcs", false)?,
             treat_doc_strings_as_comments: parse_bool(obj, "treat_doc_strings_as_comments", false)?,
+            token_model: parse_optional_string(obj, "token_model")?,
+            hash_algo: parse_hash_algo(obj)?,
+            symlink_policy: parse_symlink_policy(obj)?,
+            line_count_mode: parse_line_count_mode(obj)?,
+            code_comment_markers: parse_string_array(obj, "code_comment_markers", vec![])?,
+            ignore_file: parse_optional_string(obj, "ignore_file")?,
+            include: parse_string_array(obj, "include", vec![])?,
+            scan_cache: parse_optional_string(obj, "scan_cache")?,
         },
     })
 }
@@ -65,6 +75,7 @@ pub(super) fn parse_module_settings(args: &Value) -> Result<ModuleSettings, Tokm
         module_depth: parse_usize(obj, "module_depth", 2)?,
         children: parse_child_include_mode(obj, ChildIncludeMode::Separate)?,
         redact: parse_optional_redact_mode(obj)?,
+        collapse_single_child: parse_bool(obj, "collapse_single_child", false)?,
     })
 }
 
@@ -81,10 +92,22 @@ pub(super) fn parse_export_settings(args: &Value) -> Result<ExportSettings, Tokm
         module_depth: parse_usize(obj, "module_depth", 2)?,
         children: parse_child_include_mode(obj, ChildIncludeMode::Separate)?,
         min_code: parse_usize(obj, "min_code", 0)?,
+        filters: ExportFilters {
+            max_code: parse_optional_usize(obj, "max_code")?,
+            min_lines: parse_optional_usize(obj, "min_lines")?,
+            max_lines: parse_optional_usize(obj, "max_lines")?,
+            min_bytes: parse_optional_usize(obj, "min_bytes")?,
+            max_bytes: parse_optional_usize(obj, "max_bytes")?,
+            min_tokens: parse_optional_usize(obj, "min_tokens")?,
+            max_tokens: parse_optional_usize(obj, "max_tokens")?,
+        },
+        sort_by: parse_optional_export_sort_key(obj)?,
+        sort_dir: parse_optional_sort_direction(obj)?,
         max_rows: parse_usize(obj, "max_rows", 0)?,
         redact: parse_redact_mode(obj, RedactMode::None)?,
         meta: parse_bool(obj, "meta", true)?,
         strip_prefix: parse_optional_string(obj, "strip_prefix")?,
+        summary: parse_bool(obj, "summary", false)?,
     })
 }
 
@@ -118,8 +141,11 @@ pub(super) fn parse_analyze_settings(args: &Value) -> Result<AnalyzeSettings, To
         max_files: parse_optional_usize(obj, "max_files")?,
         max_bytes: parse_optional_u64(obj, "max_bytes")?,
         max_file_bytes: parse_optional_u64(obj, "max_file_bytes")?,
+        oversized_file_bytes: parse_optional_u64(obj, "oversized_file_bytes")?,
         max_commits: parse_optional_usize(obj, "max_commits")?,
         max_commit_files: parse_optional_usize(obj, "max_commit_files")?,
+        max_commit_message_bytes: parse_optional_usize(obj, "max_commit_message_bytes")?,
+        strip_bom: parse_bool(obj, "strip_bom", false)?,
         granularity: parse_import_granularity(obj, "module")?,
         effort_base_ref,
         effort_head_ref,
@@ -142,6 +168,15 @@ pub(super) fn parse_cockpit_settings(
         head: parse_string(obj, "head", "HEAD")?,
         range_mode: parse_string(obj, "range_mode", "two-dot")?,
         baseline: parse_optional_string(obj, "baseline")?,
+        determinism_exclude: parse_string_array(obj, "determinism_exclude", Vec::new())?,
+        coverage_artifacts: parse_string_array(obj, "coverage_artifacts", Vec::new())?,
+        project_coverage_pass: parse_optional_f64(obj, "project_coverage_pass")?,
+        project_coverage_warn: parse_optional_f64(obj, "project_coverage_warn")?,
+        rust_source_include: parse_string_array(obj, "rust_source_include", Vec::new())?,
+        rust_source_exclude: parse_string_array(obj, "rust_source_exclude", Vec::new())?,
+        artifact_staleness_window: parse_usize(obj, "artifact_staleness_window", 0)?,
+        supply_chain_min_severity: parse_optional_string(obj, "supply_chain_min_severity")?,
+        advisory_allowlist: parse_optional_string(obj, "advisory_allowlist")?,
     })
 }
 
@@ -24,6 +24,57 @@ fn run_json_version() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+#[test]
+fn run_json_formats_reflect_fun_feature() -> Result<(), Box<dyn std::error::Error>> {
+    let parsed: Value = serde_json::from_str(&run_json("formats", "{}"))?;
+    assert_eq!(parsed["ok"], true);
+    let formats: Vec<&str> = parsed
+        .pointer("/data/analysis")
+        .and_then(Value::as_array)
+        .ok_or_else(|| std::io::Error::other("not an array"))?
+        .iter()
+        .filter_map(Value::as_str)
+        .collect();
+    assert!(formats.contains(&"md"));
+    assert!(formats.contains(&"html"));
+    assert_eq!(formats.contains(&"obj"), cfg!(feature = "fun"));
+    assert_eq!(formats.contains(&"midi"), cfg!(feature = "fun"));
+    Ok(())
+}
+
+#[test]
+fn capabilities_list_core_modes_and_fun_feature() -> Result<(), Box<dyn std::error::Error>> {
+    let caps: Value = serde_json::from_str(&capabilities())?;
+    let modes: Vec<&str> = caps
+        .get("modes")
+        .and_then(Value::as_array)
+        .ok_or_else(|| std::io::Error::other("not an array"))?
+        .iter()
+        .filter_map(Value::as_str)
+        .collect();
+    for mode in ["lang", "module", "export", "diff", "verify", "version"] {
+        assert!(modes.contains(&mode), "missing {mode} in {modes:?}");
+    }
+    assert_eq!(modes.contains(&"analyze"), cfg!(feature = "analysis"));
+    assert_eq!(caps["features"]["fun"], cfg!(feature = "fun"));
+    let analysis_formats = caps
+        .pointer("/formats/analysis")
+        .and_then(Value::as_array)
+        .ok_or_else(|| std::io::Error::other("not an array"))?;
+    assert_eq!(
+        analysis_formats.contains(&Value::from("obj")),
+        cfg!(feature = "fun")
+    );
+    assert_eq!(caps["schema_versions"]["core"], tokmd_types::SCHEMA_VERSION);
+    assert_eq!(caps["schema_versions"]["core_min"], min_schema_version());
+    assert_eq!(caps["version"], version());
+
+    let enveloped: Value = serde_json::from_str(&run_json("capabilities", "{}"))?;
+    assert_eq!(enveloped["ok"], true);
+    assert_eq!(enveloped["data"], caps);
+    Ok(())
+}
+
 #[test]
 fn run_json_unknown_mode() -> Result<(), Box<dyn std::error::Error>> {
     let result = run_json("unknown", "{}");
@@ -112,6 +163,15 @@ fn schema_version_returns_current() {
     assert_eq!(sv, tokmd_types::SCHEMA_VERSION);
 }
 
+#[test]
+fn is_compatible_covers_min_through_current() {
+    assert!(min_schema_version() <= schema_version());
+    assert!(is_compatible(u64::from(min_schema_version())));
+    assert!(is_compatible(u64::from(schema_version())));
+    assert!(!is_compatible(0));
+    assert!(!is_compatible(u64::from(schema_version()) + 1));
+}
+
 // ========================================================================
 // Strict parsing tests
 // ========================================================================
@@ -371,6 +431,112 @@ fn diff_wrong_type_from_returns_error() -> Result<(), Box<dyn std::error::Error>
     Ok(())
 }
 
+// ========================================================================
+// Verify mode tests
+// ========================================================================
+
+fn verify_export_receipt(receipt: &Value) -> Result<Value, Box<dyn std::error::Error>> {
+    let args = serde_json::json!({ "receipt": receipt.to_string() });
+    let parsed: Value = serde_json::from_str(&run_json("verify", &args.to_string()))?;
+    assert_eq!(parsed["ok"], true, "{parsed}");
+    parsed
+        .get("data")
+        .cloned()
+        .ok_or_else(|| std::io::Error::other("no data").into())
+}
+
+#[test]
+fn verify_accepts_untouched_export_receipt() -> Result<(), Box<dyn std::error::Error>> {
+    let exported: Value = serde_json::from_str(&run_json("export", r#"{"hash_algo": "sha256"}"#))?;
+    let data = verify_export_receipt(
+        exported
+            .get("data")
+            .ok_or_else(|| std::io::Error::other("no data"))?,
+    )?;
+    assert_eq!(data["valid"], true);
+    assert_eq!(data["mode"], "export");
+    assert_eq!(data["algo"], "sha256");
+    assert!(data["mismatch"].is_null());
+    Ok(())
+}
+
+#[test]
+fn verify_reports_tampered_export_receipt() -> Result<(), Box<dyn std::error::Error>> {
+    let exported: Value = serde_json::from_str(&run_json("export", "{}"))?;
+    let mut receipt = exported
+        .get("data")
+        .ok_or_else(|| std::io::Error::other("no data"))?
+        .clone();
+    *receipt
+        .pointer_mut("/rows/0/code")
+        .ok_or_else(|| std::io::Error::other("no exported row"))? = serde_json::json!(999_999);
+
+    let data = verify_export_receipt(&receipt)?;
+    assert_eq!(data["valid"], false);
+    assert!(
+        data["mismatch"]
+            .as_str()
+            .ok_or_else(|| std::io::Error::other("not a string"))?
+            .starts_with("integrity hash mismatch")
+    );
+    Ok(())
+}
+
+#[test]
+fn verify_missing_receipt_returns_error() -> Result<(), Box<dyn std::error::Error>> {
+    let parsed: Value = serde_json::from_str(&run_json("verify", "{}"))?;
+    assert_eq!(parsed["ok"], false);
+    assert!(
+        parsed["error"]["message"]
+            .as_str()
+            .ok_or_else(|| std::io::Error::other("not a string"))?
+            .contains("receipt")
+    );
+    Ok(())
+}
+
+// ========================================================================
+// Validate mode tests
+// ========================================================================
+
+#[test]
+fn validate_reports_unknown_keys_only_when_strict() -> Result<(), Box<dyn std::error::Error>> {
+    let scanned: Value = serde_json::from_str(&run_json("lang", "{}"))?;
+    let mut receipt = scanned
+        .get("data")
+        .ok_or_else(|| std::io::Error::other("no data"))?
+        .clone();
+    receipt
+        .as_object_mut()
+        .ok_or_else(|| std::io::Error::other("not an object"))?
+        .insert("totl".to_string(), serde_json::json!(1));
+
+    let lenient = serde_json::json!({ "receipt": receipt });
+    let parsed: Value = serde_json::from_str(&run_json("validate", &lenient.to_string()))?;
+    assert_eq!(parsed["ok"], true, "{parsed}");
+    assert_eq!(parsed["data"]["valid"], true);
+    assert_eq!(parsed["data"]["unknown_fields"], serde_json::json!([]));
+
+    let strict = serde_json::json!({ "receipt": receipt, "strict": true });
+    let parsed: Value = serde_json::from_str(&run_json("validate", &strict.to_string()))?;
+    assert_eq!(parsed["ok"], true, "{parsed}");
+    assert_eq!(parsed["data"]["mode"], "lang");
+    assert_eq!(parsed["data"]["valid"], false);
+    assert_eq!(
+        parsed["data"]["unknown_fields"],
+        serde_json::json!(["/totl"])
+    );
+    Ok(())
+}
+
+#[test]
+fn validate_rejects_receipt_missing_required_fields() -> Result<(), Box<dyn std::error::Error>> {
+    let args = serde_json::json!({ "receipt": { "mode": "lang" } });
+    let parsed: Value = serde_json::from_str(&run_json("validate", &args.to_string()))?;
+    assert_eq!(parsed["ok"], false);
+    Ok(())
+}
+
 #[test]
 #[cfg(feature = "analysis")]
 fn invalid_analyze_preset_returns_error() -> Result<(), Box<dyn std::error::Error>> {
@@ -64,17 +64,19 @@ pub use tokmd_scan::InMemoryFile;
 pub use tokmd_types as types;
 #[cfg(feature = "cockpit")]
 pub use workflows::cockpit_workflow;
-#[cfg(all(test, feature = "cockpit"))]
-use workflows::parse_cockpit_range_mode;
 pub use workflows::{
     TimedWorkflow, WorkflowTiming, diff_workflow, export_workflow, export_workflow_from_inputs,
     lang_workflow, lang_workflow_from_inputs, module_workflow, module_workflow_from_inputs,
-    timed_export_workflow, timed_lang_workflow, timed_module_workflow,
+    timed_export_workflow, timed_lang_workflow, timed_module_workflow, validate_workflow,
+    verify_workflow,
 };
 #[cfg(feature = "analysis")]
 pub use workflows::{
-    analyze_workflow, analyze_workflow_from_inputs, supports_rootless_in_memory_analyze_preset,
+    analyze_workflow, analyze_workflow_from_inputs, analyze_workflow_with_extensions,
+    supports_rootless_in_memory_analyze_preset,
 };
+#[cfg(all(test, feature = "cockpit"))]
+use workflows::{parse_advisory_severity, parse_cockpit_range_mode};
 #[cfg(all(test, feature = "analysis"))]
 use workflows::{parse_analysis_preset, parse_effort_request};
 
@@ -126,12 +128,42 @@ pub mod analysis_facade {
     /// - `fun` feature is disabled but OBJ/MIDI format requested
     pub use tokmd_format::analysis::render;
 
+    /// Analysis formats [`render`] supports in this build (OBJ/MIDI need `fun`).
+    pub use tokmd_format::analysis::available_formats;
+
+    /// Render an analysis receipt with explicit presentation options.
+    ///
+    /// Identical to [`render`] except that Markdown and HTML reports use the
+    /// given number, timestamp, and status formatting; other formats ignore it.
+    pub use tokmd_format::analysis::render_with;
+
+    /// Presentation options for [`render_with`]: numbers, timestamps, and
+    /// status labels.
+    pub use tokmd_format::analysis::{
+        AnalysisRenderOptions, DecimalMetric, MetricPrecision, NumberFormat, SuffixStyle,
+        SvgBadgeMetric, TreemapMetric,
+    };
+
+    /// Labels for risk, gate, and trend values, e.g. 🟢/🟡/🔴.
+    pub use tokmd_format::{StatusStyle, StatusValue};
+
+    /// Timezone offset and pattern for rendered timestamps.
+    pub use tokmd_format::{TimestampFormat, TimestampStyle};
+
     /// Output container for rendered analysis.
     ///
     /// ## Variants
     /// - `Text(String)` — Textual formats: Markdown, JSON, XML, SVG, Mermaid, Tree, HTML
     /// - `Binary(Vec<u8>)` — Binary formats: MIDI (requires `fun` feature)
     pub use tokmd_format::analysis::RenderedOutput;
+
+    /// Write the detailed API surface item list (`api_surface.items`) as JSON Lines.
+    pub use tokmd_format::analysis::write_api_items_jsonl;
+
+    /// Custom metrics for [`analyze_workflow_with_extensions`].
+    ///
+    /// [`analyze_workflow_with_extensions`]: crate::analyze_workflow_with_extensions
+    pub use tokmd_analysis::{AnalysisExtension, ExtensionHook};
 }
 
 // =============================================================================
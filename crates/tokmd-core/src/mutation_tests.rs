@@ -26,6 +26,14 @@ fn minimal_scan_opts() -> ScanOptions {
         no_ignore_dot: false,
         no_ignore_vcs: false,
         treat_doc_strings_as_comments: false,
+        token_model: None,
+        hash_algo: tokmd_types::HashAlgo::Blake3,
+        symlink_policy: Default::default(),
+        line_count_mode: Default::default(),
+        code_comment_markers: Vec::new(),
+        ignore_file: None,
+        include: Vec::new(),
+        scan_cache: None,
     }
 }
 
@@ -41,6 +49,10 @@ fn export_settings(redact: RedactMode, strip_prefix: Option<String>) -> ExportSe
         redact,
         meta: true,
         strip_prefix,
+        filters: Default::default(),
+        sort_by: None,
+        sort_dir: None,
+        summary: false,
     }
 }
 
@@ -328,3 +340,19 @@ fn cockpit_workflow_range_mode_invalid_rejected() {
         "Error should reference range_mode field; got: {msg}"
     );
 }
+
+#[cfg(feature = "cockpit")]
+#[test]
+fn cockpit_workflow_min_severity_parsing() {
+    assert_eq!(
+        parse_advisory_severity(" Medium ").expect("medium should parse"),
+        tokmd_cockpit::AdvisorySeverity::Medium
+    );
+    for invalid in ["none", "severe"] {
+        let err = parse_advisory_severity(invalid).expect_err("invalid floor should fail");
+        assert!(
+            err.to_string().contains("supply_chain_min_severity"),
+            "Error should reference supply_chain_min_severity; got: {err}"
+        );
+    }
+}
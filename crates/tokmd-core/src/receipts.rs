@@ -1,10 +1,8 @@
 //! Receipt construction helpers for core workflows.
 
 use std::path::PathBuf;
-#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
-use std::time::{SystemTime, UNIX_EPOCH};
 
-use tokmd_format::scan_args;
+use tokmd_format::{path_portability_warnings, rows_integrity_with, scan_args};
 use tokmd_settings::ScanOptions;
 use tokmd_types::{
     ExportArgsMeta, ExportData, ExportReceipt, LangArgsMeta, LangReceipt, LangReport,
@@ -13,20 +11,11 @@ use tokmd_types::{
 
 use crate::settings::{ExportSettings, LangSettings, ModuleSettings};
 
-#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+/// Shares tokmd-format's clock, which handles wasm and honors
+/// `SOURCE_DATE_EPOCH` for reproducible receipts.
 #[inline]
 fn now_ms() -> u128 {
-    // Keep wasm receipts from reusing zero as a fake wall-clock sentinel.
-    js_sys::Date::now().max(1.0) as u128
-}
-
-#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
-#[inline]
-fn now_ms() -> u128 {
-    SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .unwrap_or_default()
-        .as_millis()
+    (tokmd_format::now_utc().unix_timestamp_nanos() / 1_000_000).max(0) as u128
 }
 
 pub(crate) fn build_lang_receipt(
@@ -49,6 +38,7 @@ pub(crate) fn build_lang_receipt(
             with_files: lang.files,
             children: lang.children,
         },
+        integrity: Some(rows_integrity_with(&report.rows, scan_opts.hash_algo)),
         report,
     }
 }
@@ -73,7 +63,9 @@ pub(crate) fn build_module_receipt(
             module_roots: module.module_roots.clone(),
             module_depth: module.module_depth,
             children: module.children,
+            collapse_single_child: module.collapse_single_child,
         },
+        integrity: Some(rows_integrity_with(&report.rows, scan_opts.hash_algo)),
         report,
     }
 }
@@ -87,13 +79,18 @@ pub(crate) fn build_export_receipt(
     let should_redact = export.redact == RedactMode::Paths || export.redact == RedactMode::All;
     let strip_prefix_redacted = should_redact && export.strip_prefix.is_some();
 
+    let warnings = path_portability_warnings(&data.rows, export.redact);
+    let summary =
+        (export.meta && export.summary).then(|| tokmd_model::create_export_summary(&data.rows));
+    let data = redact_export_data(data, export.redact);
+    let integrity = rows_integrity_with(&data.rows, scan_opts.hash_algo);
     ExportReceipt {
         schema_version: SCHEMA_VERSION,
         generated_at_ms: now_ms(),
         tool: ToolInfo::current(),
         mode: "export".to_string(),
         status: ScanStatus::Complete,
-        warnings: vec![],
+        warnings,
         scan: scan_args(paths, scan_opts, Some(export.redact)),
         args: ExportArgsMeta {
             format: export.format,
@@ -101,6 +98,9 @@ pub(crate) fn build_export_receipt(
             module_depth: export.module_depth,
             children: export.children,
             min_code: export.min_code,
+            filters: export.filters,
+            sort_by: export.sort_by,
+            sort_dir: export.sort_dir,
             max_rows: export.max_rows,
             redact: export.redact,
             strip_prefix: if should_redact {
@@ -113,7 +113,9 @@ pub(crate) fn build_export_receipt(
             },
             strip_prefix_redacted,
         },
-        data: redact_export_data(data, export.redact),
+        data,
+        integrity: Some(integrity),
+        summary,
     }
 }
 
@@ -44,12 +44,30 @@ pub(crate) use request::{parse_analysis_preset, parse_effort_request};
 /// assert!(receipt.derived.is_some());
 /// ```
 pub fn analyze_workflow(scan: &ScanSettings, analyze: &AnalyzeSettings) -> Result<AnalysisReceipt> {
+    analyze_workflow_with_extensions(scan, analyze, Vec::new())
+}
+
+/// Like [`analyze_workflow`], but runs `extensions` after the built-in
+/// analysis and attaches their values to the receipt's `extensions` map.
+///
+/// A failing extension becomes a warning and marks the receipt partial.
+pub fn analyze_workflow_with_extensions(
+    scan: &ScanSettings,
+    analyze: &AnalyzeSettings,
+    extensions: Vec<analysis::ExtensionHook>,
+) -> Result<AnalysisReceipt> {
     let export_receipt = export_workflow(scan, &ExportSettings::default())?;
     let root = derive_analysis_root(scan)
         .or_else(|| std::env::current_dir().ok())
         .unwrap_or_else(|| PathBuf::from("."));
 
-    analyze_with_export_receipt(export_receipt, scan.paths.clone(), root, analyze)
+    analyze_with_export_receipt(
+        export_receipt,
+        scan.paths.clone(),
+        root,
+        analyze,
+        extensions,
+    )
 }
 
 /// Analyze workflow for ordered in-memory inputs (requires `analysis` feature).
@@ -110,8 +128,9 @@ fn analyze_with_export_receipt(
     inputs: Vec<String>,
     root: PathBuf,
     analyze: &AnalyzeSettings,
+    extensions: Vec<analysis::ExtensionHook>,
 ) -> Result<AnalysisReceipt> {
-    let request = build_analysis_request(analyze)?;
+    let request = build_analysis_request(analyze, export_receipt.scan.hash_algo, extensions)?;
     let source = AnalysisSource {
         inputs,
         export_path: None,
@@ -122,6 +141,7 @@ fn analyze_with_export_receipt(
         module_roots: export_receipt.data.module_roots.clone(),
         module_depth: export_receipt.data.module_depth,
         children: child_include_mode_to_string(export_receipt.data.children),
+        token_model: export_receipt.scan.token_model.clone(),
     };
 
     let ctx = analysis::AnalysisContext {
@@ -143,13 +163,14 @@ fn analyze_prepared_input(
         root,
         materialized_scan: _materialized_scan,
     } = prepared;
-    analyze_with_export_receipt(export_receipt, logical_inputs, root, analyze)
+    analyze_with_export_receipt(export_receipt, logical_inputs, root, analyze, Vec::new())
 }
 
 fn child_include_mode_to_string(mode: ChildIncludeMode) -> String {
     match mode {
         ChildIncludeMode::Separate => "separate".to_string(),
         ChildIncludeMode::ParentsOnly => "parents-only".to_string(),
+        ChildIncludeMode::Nested => "nested".to_string(),
     }
 }
 
@@ -9,6 +9,8 @@ use crate::settings::AnalyzeSettings;
 
 pub(super) fn build_analysis_request(
     analyze: &AnalyzeSettings,
+    hash_algo: tokmd_types::HashAlgo,
+    extensions: Vec<analysis::ExtensionHook>,
 ) -> Result<analysis::AnalysisRequest> {
     let (preset, preset_meta) = parse_analysis_preset(&analyze.preset)?;
     let (granularity, granularity_meta) = parse_import_granularity(&analyze.granularity)?;
@@ -27,6 +29,14 @@ pub(super) fn build_analysis_request(
             max_commits: analyze.max_commits,
             max_commit_files: analyze.max_commit_files,
             import_granularity: granularity_meta,
+            oversized_file_bytes: analyze.oversized_file_bytes,
+            max_commit_message_bytes: analyze.max_commit_message_bytes,
+            strip_bom: analyze.strip_bom,
+            preset_sections: None,
+            include_sections: vec![],
+            exclude_sections: vec![],
+            sample_rate: None,
+            max_runtime_ms: None,
         },
         limits: analysis::AnalysisLimits {
             max_files: analyze.max_files,
@@ -34,11 +44,18 @@ pub(super) fn build_analysis_request(
             max_file_bytes: analyze.max_file_bytes,
             max_commits: analyze.max_commits,
             max_commit_files: analyze.max_commit_files,
+            oversized_file_bytes: analyze.oversized_file_bytes,
+            max_commit_message_bytes: analyze.max_commit_message_bytes,
+            strip_bom: analyze.strip_bom,
+            sample_rate: None,
+            runtime_budget: None,
         },
         window_tokens: analyze.window,
         git: analyze.git,
         import_granularity: granularity,
+        tree: Default::default(),
         detail_functions: false,
+        detail_api_items: false,
         near_dup: false,
         near_dup_threshold: 0.80,
         near_dup_max_files: 2000,
@@ -46,6 +63,18 @@ pub(super) fn build_analysis_request(
         near_dup_max_pairs: None,
         near_dup_exclude: Vec::new(),
         effort,
+        api_doc_thresholds: Default::default(),
+        license_header: None,
+        domain_grouping: Default::default(),
+        forecast_days: None,
+        coverage: None,
+        hash_algo,
+        timings: false,
+        plan: None,
+        include_sections: vec![],
+        exclude_sections: vec![],
+        progress: None,
+        extensions,
     })
 }
 
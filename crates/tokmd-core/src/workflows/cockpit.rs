@@ -82,6 +82,30 @@ pub fn cockpit_workflow(
         })?;
 
     let baseline_path = settings.baseline.as_deref();
+    let defaults = tokmd_cockpit::ProjectCoverageThresholds::default();
+    let options = tokmd_cockpit::GateOptions {
+        determinism_excludes: settings.determinism_exclude.clone(),
+        coverage_artifacts: settings.coverage_artifacts.clone(),
+        project_coverage: tokmd_cockpit::ProjectCoverageThresholds {
+            line_pass: settings.project_coverage_pass.unwrap_or(defaults.line_pass),
+            line_warn: settings.project_coverage_warn.unwrap_or(defaults.line_warn),
+            ..defaults
+        },
+        rust_source: tokmd_cockpit::RustSourceScope {
+            include: settings.rust_source_include.clone(),
+            exclude: settings.rust_source_exclude.clone(),
+        },
+        artifact_staleness_window: settings.artifact_staleness_window,
+        supply_chain_min_severity: settings
+            .supply_chain_min_severity
+            .as_deref()
+            .map(parse_advisory_severity)
+            .transpose()?,
+        advisory_allowlist: match settings.advisory_allowlist.as_deref() {
+            Some(path) => tokmd_cockpit::AdvisoryAllowlist::load(std::path::Path::new(path))?,
+            None => tokmd_cockpit::AdvisoryAllowlist::default(),
+        },
+    };
 
     let mut receipt: CockpitReceipt = tokmd_cockpit::compute_cockpit(
         &repo_root,
@@ -89,6 +113,7 @@ pub fn cockpit_workflow(
         &settings.head,
         range_mode,
         baseline_path.map(std::path::Path::new),
+        &options,
     )?;
 
     // Load baseline and compute trend if provided.
@@ -102,6 +127,19 @@ pub fn cockpit_workflow(
     Ok(receipt)
 }
 
+pub(crate) fn parse_advisory_severity(value: &str) -> Result<tokmd_cockpit::AdvisorySeverity> {
+    match tokmd_cockpit::AdvisorySeverity::parse(value) {
+        Some(tokmd_cockpit::AdvisorySeverity::None) | None => {
+            Err(error::TokmdError::invalid_field(
+                "supply_chain_min_severity",
+                "'low', 'medium', 'high', or 'critical'",
+            )
+            .into())
+        }
+        Some(severity) => Ok(severity),
+    }
+}
+
 pub(crate) fn parse_cockpit_range_mode(value: &str) -> Result<tokmd_git::GitRangeMode> {
     let normalized = value.trim().to_ascii_lowercase();
     match normalized.as_str() {
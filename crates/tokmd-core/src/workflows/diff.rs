@@ -1,6 +1,7 @@
 //! Diff workflow facade.
 
 use anyhow::Result;
+use tokmd_format::UnsupportedSchemaVersion;
 use tokmd_types::{DiffReceipt, LangReceipt, LangReport};
 
 use crate::settings::{DiffSettings, LangSettings, ScanSettings};
@@ -52,8 +53,11 @@ fn load_lang_report(source: &str) -> Result<LangReport> {
 
     if path.exists() && path.is_file() {
         let content = std::fs::read_to_string(path)?;
-        if let Ok(receipt) = serde_json::from_str::<LangReceipt>(&content) {
-            return Ok(receipt.report);
+        match tokmd_format::parse_receipt::<LangReceipt>(&content) {
+            Ok(receipt) => return Ok(receipt.report),
+            // A receipt from a newer tokmd should not be rescanned as a path.
+            Err(err) if err.is::<UnsupportedSchemaVersion>() => return Err(err),
+            Err(_) => {}
         }
     }
 
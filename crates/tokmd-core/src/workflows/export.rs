@@ -42,14 +42,13 @@ pub fn export_workflow(scan: &ScanSettings, export: &ExportSettings) -> Result<E
     let strip_prefix = export.strip_prefix.as_deref();
 
     let languages = tokmd_scan::scan(&paths, &scan_opts)?;
-    let data = tokmd_model::create_export_data(
+    let data = tokmd_model::create_export_data_with_options(
         &languages,
         &export.module_roots,
         export.module_depth,
         export.children,
         strip_prefix.map(Path::new),
-        export.min_code,
-        export.max_rows,
+        &export_row_options(export),
     );
 
     Ok(build_export_receipt(&paths, &scan_opts, export, data))
@@ -94,14 +93,23 @@ pub fn export_workflow_from_inputs(
             export.module_depth,
         );
     }
-    let data = tokmd_model::create_export_data_from_rows(
+    let data = tokmd_model::create_export_data_from_rows_with_options(
         rows,
         &export.module_roots,
         export.module_depth,
         export.children,
-        export.min_code,
-        export.max_rows,
+        &export_row_options(export),
     );
 
     Ok(build_export_receipt(&paths, &scan_opts, export, data))
 }
+
+fn export_row_options(export: &ExportSettings) -> tokmd_model::ExportRowOptions {
+    tokmd_model::ExportRowOptions {
+        min_code: export.min_code,
+        filters: export.filters,
+        sort_by: export.sort_by,
+        sort_dir: export.sort_dir,
+        max_rows: export.max_rows,
+    }
+}
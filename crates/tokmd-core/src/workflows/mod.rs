@@ -10,17 +10,20 @@ mod lang;
 mod module;
 mod support;
 mod timing;
+mod validate;
+mod verify;
 
 #[cfg(feature = "analysis")]
 pub use analyze::{
-    analyze_workflow, analyze_workflow_from_inputs, supports_rootless_in_memory_analyze_preset,
+    analyze_workflow, analyze_workflow_from_inputs, analyze_workflow_with_extensions,
+    supports_rootless_in_memory_analyze_preset,
 };
 #[cfg(all(test, feature = "analysis"))]
 pub(crate) use analyze::{parse_analysis_preset, parse_effort_request};
 #[cfg(feature = "cockpit")]
 pub use cockpit::cockpit_workflow;
 #[cfg(all(test, feature = "cockpit"))]
-pub(crate) use cockpit::parse_cockpit_range_mode;
+pub(crate) use cockpit::{parse_advisory_severity, parse_cockpit_range_mode};
 pub use diff::diff_workflow;
 pub use export::{export_workflow, export_workflow_from_inputs};
 pub use lang::{lang_workflow, lang_workflow_from_inputs};
@@ -29,6 +32,8 @@ pub use timing::{
     TimedWorkflow, WorkflowTiming, timed_export_workflow, timed_lang_workflow,
     timed_module_workflow,
 };
+pub use validate::validate_workflow;
+pub use verify::verify_workflow;
 
 pub(crate) use support::{
     collect_pure_in_memory_rows, deterministic_in_memory_scan_options, scan_paths_or_current_dir,
@@ -43,13 +43,16 @@ pub fn module_workflow(scan: &ScanSettings, module: &ModuleSettings) -> Result<M
     let strip_prefix = single_scan_root_strip_prefix(&paths);
 
     let languages = tokmd_scan::scan(&paths, &scan_opts)?;
-    let file_rows = tokmd_model::collect_file_rows(
+    let mut file_rows = tokmd_model::collect_file_rows(
         &languages,
         &module.module_roots,
         module.module_depth,
         module.children,
         strip_prefix,
     );
+    if module.collapse_single_child {
+        tokmd_model::collapse_single_child_modules(&mut file_rows);
+    }
     let report = tokmd_model::create_module_report_from_rows(
         &file_rows,
         &module.module_roots,
@@ -85,13 +88,16 @@ pub fn module_workflow_from_inputs(
     module: &ModuleSettings,
 ) -> Result<ModuleReceipt> {
     let scan_opts = deterministic_in_memory_scan_options(scan_opts);
-    let (paths, rows) = collect_pure_in_memory_rows(
+    let (paths, mut rows) = collect_pure_in_memory_rows(
         inputs,
         &scan_opts,
         &module.module_roots,
         module.module_depth,
         module.children,
     )?;
+    if module.collapse_single_child {
+        tokmd_model::collapse_single_child_modules(&mut rows);
+    }
     let report = tokmd_model::create_module_report_from_rows(
         &rows,
         &module.module_roots,
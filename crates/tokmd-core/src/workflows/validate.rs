@@ -0,0 +1,86 @@
+//! Receipt shape validation facade.
+
+use anyhow::{Context, Result, bail};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use tokmd_format::{migrate_receipt, unknown_fields};
+use tokmd_types::cockpit::CockpitReceipt;
+use tokmd_types::{ContextReceipt, DiffReceipt, ExportReceipt, LangReceipt, ModuleReceipt};
+
+/// Checks that a stored receipt deserializes as the type its `mode` names.
+///
+/// Parsing is lenient by default, matching every other tokmd reader: unknown
+/// keys are ignored and the result is empty. With `strict`, the result lists
+/// each key the receipt type does not read, as a JSON Pointer. Core receipts
+/// from an older schema are upgraded first, so their missing fields are not
+/// errors.
+///
+/// # Returns
+///
+/// The outer error is for input that does not parse as its receipt type.
+///
+/// # Example
+///
+/// ```rust
+/// use tokmd_core::{lang_workflow, validate_workflow, settings::{ScanSettings, LangSettings}};
+///
+/// let receipt = lang_workflow(&ScanSettings::current_dir(), &LangSettings::default())
+///     .expect("Lang scan failed");
+/// let mut json = serde_json::to_value(&receipt).unwrap();
+/// assert!(validate_workflow(&json, true).unwrap().is_empty());
+///
+/// json["totl"] = serde_json::json!(1);
+/// assert!(validate_workflow(&json, false).unwrap().is_empty());
+/// assert_eq!(validate_workflow(&json, true).unwrap(), ["/totl"]);
+/// ```
+pub fn validate_workflow(receipt: &Value, strict: bool) -> Result<Vec<String>> {
+    let mode = receipt
+        .get("mode")
+        .and_then(Value::as_str)
+        .context("receipt has no 'mode' field")?;
+    match mode {
+        "lang" => check_core::<LangReceipt>(receipt, mode, strict),
+        "module" => check_core::<ModuleReceipt>(receipt, mode, strict),
+        "export" => check_core::<ExportReceipt>(receipt, mode, strict),
+        "diff" => check_core::<DiffReceipt>(receipt, mode, strict),
+        "context" => check::<ContextReceipt>(receipt, mode, strict),
+        "cockpit" => check::<CockpitReceipt>(receipt, mode, strict),
+        "analysis" => check_analysis(receipt, strict),
+        other => bail!("cannot validate a '{other}' receipt"),
+    }
+}
+
+fn check_core<T: DeserializeOwned + Serialize>(
+    receipt: &Value,
+    mode: &str,
+    strict: bool,
+) -> Result<Vec<String>> {
+    let mut receipt = receipt.clone();
+    migrate_receipt(&mut receipt)?;
+    check::<T>(&receipt, mode, strict)
+}
+
+fn check<T: DeserializeOwned + Serialize>(
+    receipt: &Value,
+    mode: &str,
+    strict: bool,
+) -> Result<Vec<String>> {
+    if strict {
+        unknown_fields::<T>(receipt).with_context(|| format!("invalid {mode} receipt"))
+    } else {
+        serde_json::from_value::<T>(receipt.clone())
+            .with_context(|| format!("invalid {mode} receipt"))?;
+        Ok(Vec::new())
+    }
+}
+
+#[cfg(feature = "analysis")]
+fn check_analysis(receipt: &Value, strict: bool) -> Result<Vec<String>> {
+    check::<tokmd_analysis_types::AnalysisReceipt>(receipt, "analysis", strict)
+}
+
+#[cfg(not(feature = "analysis"))]
+fn check_analysis(_receipt: &Value, _strict: bool) -> Result<Vec<String>> {
+    bail!("validating analysis receipts requires the 'analysis' feature")
+}
@@ -0,0 +1,93 @@
+//! Receipt integrity verification facade.
+
+use anyhow::{Context, Result, bail};
+use serde::Serialize;
+use serde_json::Value;
+use tokmd_format::{IntegrityMismatch, verify_rows_integrity};
+use tokmd_types::{ExportReceipt, IntegrityReport, LangReceipt, ModuleReceipt};
+
+/// Recomputes a stored receipt's `integrity` hash and compares it with the
+/// recorded value.
+///
+/// Lang, module, and export receipts carry their own rows. Analysis receipts
+/// do not, so `export` must hold the export receipt (or export data) the
+/// analysis ran on.
+///
+/// # Returns
+///
+/// The outer error is for input that is not a verifiable receipt; the inner
+/// result is the verdict.
+///
+/// # Example
+///
+/// ```rust
+/// use tokmd_core::{export_workflow, verify_workflow, settings::{ScanSettings, ExportSettings}};
+///
+/// let receipt = export_workflow(&ScanSettings::current_dir(), &ExportSettings::default())
+///     .expect("Export scan failed");
+/// let mut json = serde_json::to_value(&receipt).unwrap();
+/// assert!(verify_workflow(&json, None).unwrap().is_ok());
+///
+/// json["rows"][0]["code"] = serde_json::json!(999_999);
+/// assert!(verify_workflow(&json, None).unwrap().is_err());
+/// ```
+pub fn verify_workflow(
+    receipt: &Value,
+    export: Option<&Value>,
+) -> Result<Result<(), IntegrityMismatch>> {
+    let mode = receipt
+        .get("mode")
+        .and_then(Value::as_str)
+        .context("receipt has no 'mode' field")?;
+    match mode {
+        "lang" => {
+            let r: LangReceipt = parse_receipt(receipt, mode)?;
+            Ok(verify_rows(r.integrity.as_ref(), &r.report.rows))
+        }
+        "module" => {
+            let r: ModuleReceipt = parse_receipt(receipt, mode)?;
+            Ok(verify_rows(r.integrity.as_ref(), &r.report.rows))
+        }
+        "export" => {
+            let r: ExportReceipt = parse_receipt(receipt, mode)?;
+            Ok(verify_rows(r.integrity.as_ref(), &r.data.rows))
+        }
+        "analysis" => verify_analysis(receipt, export),
+        other => bail!("cannot verify integrity of a '{other}' receipt"),
+    }
+}
+
+fn parse_receipt<T: serde::de::DeserializeOwned>(receipt: &Value, mode: &str) -> Result<T> {
+    serde_json::from_value(receipt.clone()).with_context(|| format!("invalid {mode} receipt"))
+}
+
+fn verify_rows<T: Serialize>(
+    integrity: Option<&IntegrityReport>,
+    rows: &[T],
+) -> Result<(), IntegrityMismatch> {
+    verify_rows_integrity(integrity.ok_or(IntegrityMismatch::Missing)?, rows)
+}
+
+#[cfg(feature = "analysis")]
+fn verify_analysis(
+    receipt: &Value,
+    export: Option<&Value>,
+) -> Result<Result<(), IntegrityMismatch>> {
+    let Some(export) = export else {
+        bail!(
+            "analysis receipts do not embed their file rows; pass the export they were built from"
+        );
+    };
+    let receipt: tokmd_analysis_types::AnalysisReceipt = parse_receipt(receipt, "analysis")?;
+    let export: tokmd_types::ExportData =
+        serde_json::from_value(export.clone()).context("invalid export data")?;
+    Ok(tokmd_analysis::verify_integrity(&receipt, &export))
+}
+
+#[cfg(not(feature = "analysis"))]
+fn verify_analysis(
+    _receipt: &Value,
+    _export: Option<&Value>,
+) -> Result<Result<(), IntegrityMismatch>> {
+    bail!("verifying analysis receipts requires the 'analysis' feature")
+}
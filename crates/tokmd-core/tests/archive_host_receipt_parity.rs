@@ -68,6 +68,14 @@ fn parity_scan_options() -> ScanOptions {
         no_ignore_dot: true,
         no_ignore_vcs: true,
         treat_doc_strings_as_comments: false,
+        token_model: None,
+        hash_algo: tokmd_types::HashAlgo::Blake3,
+        symlink_policy: Default::default(),
+        line_count_mode: Default::default(),
+        code_comment_markers: Vec::new(),
+        ignore_file: None,
+        include: Vec::new(),
+        scan_cache: None,
     }
 }
 
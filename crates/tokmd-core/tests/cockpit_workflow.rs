@@ -82,6 +82,15 @@ fn cockpit_workflow_computes_receipt_from_settings() {
         head: "HEAD".to_string(),
         range_mode: "2dot".to_string(),
         baseline: None,
+        determinism_exclude: vec![],
+        coverage_artifacts: vec![],
+        project_coverage_pass: None,
+        project_coverage_warn: None,
+        rust_source_include: vec![],
+        rust_source_exclude: vec![],
+        artifact_staleness_window: 0,
+        supply_chain_min_severity: None,
+        advisory_allowlist: None,
     })
     .expect("cockpit workflow should compute from settings");
 
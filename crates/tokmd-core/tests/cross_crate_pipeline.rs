@@ -352,6 +352,10 @@ fn export_as_csv_via_format_should_have_header_and_data_rows() {
         redact: tokmd_types::RedactMode::None,
         meta: false,
         strip_prefix: None,
+        filters: Default::default(),
+        sort_by: None,
+        sort_dir: None,
+        summary: false,
     };
 
     let mut buf = Cursor::new(Vec::new());
@@ -33,6 +33,7 @@ mod facade_tests {
                 module_roots: vec![],
                 module_depth: 1,
                 children: "separate".into(),
+                token_model: "heuristic".to_string(),
             },
             args: AnalysisArgsMeta {
                 preset: "receipt".into(),
@@ -45,6 +46,14 @@ mod facade_tests {
                 max_commit_files: None,
                 max_file_bytes: None,
                 import_granularity: "module".into(),
+                oversized_file_bytes: None,
+                max_commit_message_bytes: None,
+                strip_bom: false,
+                preset_sections: None,
+                include_sections: vec![],
+                exclude_sections: vec![],
+                sample_rate: None,
+                max_runtime_ms: None,
             },
             archetype: None,
             topics: None,
@@ -62,6 +71,11 @@ mod facade_tests {
             api_surface: None,
             fun: None,
             effort: None,
+            timings: None,
+            line_endings: None,
+            sampling: None,
+            risk_surface: Vec::new(),
+            extensions: Default::default(),
         }
     }
 
@@ -28,6 +28,14 @@ fn scan_options() -> ScanOptions {
         no_ignore_dot: false,
         no_ignore_vcs: false,
         treat_doc_strings_as_comments: false,
+        token_model: None,
+        hash_algo: tokmd_types::HashAlgo::Blake3,
+        symlink_policy: Default::default(),
+        line_count_mode: Default::default(),
+        code_comment_markers: Vec::new(),
+        ignore_file: None,
+        include: Vec::new(),
+        scan_cache: None,
     }
 }
 
@@ -253,7 +261,8 @@ fn analyze_workflow_from_inputs_uses_logical_inputs_and_populates_estimate_recei
         actual
             .warnings
             .iter()
-            .any(|warning| warning.contains("no host root") && warning.contains("file-backed")),
+            .any(|warning| warning.message.contains("no host root")
+                && warning.message.contains("file-backed")),
         "expected file-backed rootless warning, got {:?}",
         actual.warnings
     );
@@ -261,7 +270,8 @@ fn analyze_workflow_from_inputs_uses_logical_inputs_and_populates_estimate_recei
         actual
             .warnings
             .iter()
-            .any(|warning| warning.contains("no host root") && warning.contains("git")),
+            .any(|warning| warning.message.contains("no host root")
+                && warning.message.contains("git")),
         "expected git rootless warning, got {:?}",
         actual.warnings
     );
@@ -320,7 +330,8 @@ fn analyze_workflow_from_inputs_uses_logical_inputs_and_populates_rootless_recei
         actual
             .warnings
             .iter()
-            .any(|warning| warning.contains("no host root") && warning.contains("file-backed")),
+            .any(|warning| warning.message.contains("no host root")
+                && warning.message.contains("file-backed")),
         "expected file-backed rootless warning, got {:?}",
         actual.warnings
     );
@@ -328,7 +339,8 @@ fn analyze_workflow_from_inputs_uses_logical_inputs_and_populates_rootless_recei
         actual
             .warnings
             .iter()
-            .any(|warning| warning.contains("no host root") && warning.contains("git")),
+            .any(|warning| warning.message.contains("no host root")
+                && warning.message.contains("git")),
         "expected git rootless warning, got {:?}",
         actual.warnings
     );
@@ -123,6 +123,10 @@ fn module_markdown_pipeline_produces_valid_table() {
         module_roots: vec![],
         module_depth: 2,
         children: tokmd_types::ChildIncludeMode::Separate,
+        collapse_single_child: false,
+        avg: tokmd_types::AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     };
     let global = tokmd_settings::ScanOptions::default();
 
@@ -169,6 +173,10 @@ fn export_csv_pipeline_produces_valid_csv() {
         redact: tokmd_types::RedactMode::None,
         meta: false,
         strip_prefix: None,
+        filters: Default::default(),
+        sort_by: None,
+        sort_dir: None,
+        summary: false,
     };
 
     let mut buf = Cursor::new(Vec::new());
@@ -227,6 +235,10 @@ fn export_jsonl_pipeline_each_line_is_valid_json() {
         redact: tokmd_types::RedactMode::None,
         meta: false,
         strip_prefix: None,
+        filters: Default::default(),
+        sort_by: None,
+        sort_dir: None,
+        summary: false,
     };
     let global = tokmd_settings::ScanOptions::default();
 
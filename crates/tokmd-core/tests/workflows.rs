@@ -1,7 +1,11 @@
 //! Integration tests for tokmd-core workflows.
 
 #[cfg(feature = "analysis")]
-use tokmd_core::{analyze_workflow, settings::AnalyzeSettings};
+use tokmd_core::{
+    analysis_facade::{AnalysisExtension, ExtensionHook},
+    analyze_workflow, analyze_workflow_with_extensions,
+    settings::AnalyzeSettings,
+};
 use tokmd_core::{
     export_workflow, lang_workflow, module_workflow,
     settings::{ExportSettings, LangSettings, ModuleSettings, ScanSettings},
@@ -434,6 +438,38 @@ fn analyze_workflow_runs_with_receipt_preset() {
     );
 }
 
+#[cfg(feature = "analysis")]
+struct RowCount;
+
+#[cfg(feature = "analysis")]
+impl AnalysisExtension for RowCount {
+    fn name(&self) -> &str {
+        "row_count"
+    }
+
+    fn compute(
+        &self,
+        export: &tokmd_types::ExportData,
+        _receipt: &tokmd_analysis_types::AnalysisReceipt,
+    ) -> anyhow::Result<serde_json::Value> {
+        Ok(serde_json::json!(export.rows.len()))
+    }
+}
+
+#[test]
+#[cfg(feature = "analysis")]
+fn analyze_workflow_with_extensions_attaches_their_values() -> anyhow::Result<()> {
+    let scan = ScanSettings::for_paths(vec!["src".to_string()]);
+    let analyze = AnalyzeSettings::default();
+
+    let receipt =
+        analyze_workflow_with_extensions(&scan, &analyze, vec![ExtensionHook::new(RowCount)])?;
+
+    let rows = receipt.extensions.get("row_count").and_then(|v| v.as_u64());
+    assert!(rows.is_some_and(|n| n > 0), "row_count: {rows:?}");
+    Ok(())
+}
+
 // ============================================================================
 // Property tests
 // ============================================================================
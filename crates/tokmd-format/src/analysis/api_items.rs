@@ -0,0 +1,73 @@
+//! JSONL export of the detailed API surface item list.
+//!
+//! One public item per line, in report order. Reports built without the
+//! item list produce no output.
+
+use std::io::Write;
+
+use anyhow::Result;
+use tokmd_analysis_types::ApiSurfaceReport;
+
+/// Write `api_surface.items` as JSON Lines.
+pub fn write_api_items_jsonl<W: Write>(out: &mut W, report: &ApiSurfaceReport) -> Result<()> {
+    for item in report.items.iter().flatten() {
+        writeln!(out, "{}", serde_json::to_string(item)?)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use tokmd_analysis_types::ApiItem;
+
+    use super::*;
+
+    fn report(items: Option<Vec<ApiItem>>) -> ApiSurfaceReport {
+        ApiSurfaceReport {
+            total_items: 1,
+            public_items: 1,
+            internal_items: 0,
+            public_ratio: 1.0,
+            documented_ratio: 1.0,
+            by_language: BTreeMap::new(),
+            by_module: vec![],
+            top_exporters: vec![],
+            public_symbols: BTreeMap::new(),
+            doc_violations: vec![],
+            items,
+            items_truncated: false,
+            public_symbols_truncated: false,
+        }
+    }
+
+    #[test]
+    fn writes_one_line_per_item() {
+        let item = ApiItem {
+            name: "parse".to_string(),
+            kind: "fn".to_string(),
+            module: "src".to_string(),
+            path: "src/lib.rs".to_string(),
+            lang: "Rust".to_string(),
+            documented: true,
+        };
+        let mut out = Vec::new();
+        write_api_items_jsonl(&mut out, &report(Some(vec![item.clone(), item]))).unwrap();
+
+        let text = String::from_utf8(out).unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(
+            lines[0],
+            r#"{"name":"parse","kind":"fn","module":"src","path":"src/lib.rs","lang":"Rust","documented":true}"#
+        );
+    }
+
+    #[test]
+    fn missing_item_list_writes_nothing() {
+        let mut out = Vec::new();
+        write_api_items_jsonl(&mut out, &report(None)).unwrap();
+        assert!(out.is_empty());
+    }
+}
@@ -0,0 +1,302 @@
+//! API surface diffing between two analysis runs.
+//!
+//! This module owns the multiset comparison of `api_surface.public_symbols`
+//! and its Markdown projection. It is language-agnostic: whatever symbol
+//! identifiers the analysis recorded are compared verbatim per module.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+
+use tokmd_analysis_types::{ApiSurfaceDiff, ApiSurfaceReport, ModuleApiChange};
+
+/// Compare the public item inventories of two API surface reports.
+///
+/// Items are matched per module by their `"<kind> <name>"` identifier.
+/// Repeated identifiers are counted, so removing one of two same-named items
+/// in a module still surfaces as a removal. The diff is marked `truncated`
+/// when either inventory hit its cap.
+///
+/// A capped inventory is missing every item past the cut, in modules it
+/// cannot name. Removals are therefore only reported when the head inventory
+/// is complete, and additions only when the base inventory is, so the cap
+/// never shows up as a (breaking) change.
+pub fn diff_api_surface(base: &ApiSurfaceReport, head: &ApiSurfaceReport) -> ApiSurfaceDiff {
+    let modules: BTreeSet<&String> = base
+        .public_symbols
+        .keys()
+        .chain(head.public_symbols.keys())
+        .collect();
+
+    let mut added = Vec::new();
+    let mut removed = Vec::new();
+    for module in modules {
+        let base_counts = item_counts(base.public_symbols.get(module));
+        let head_counts = item_counts(head.public_symbols.get(module));
+
+        let module_added = if base.public_symbols_truncated {
+            Vec::new()
+        } else {
+            surplus(&head_counts, &base_counts)
+        };
+        if !module_added.is_empty() {
+            added.push(ModuleApiChange {
+                module: module.clone(),
+                items: module_added,
+            });
+        }
+
+        let module_removed = if head.public_symbols_truncated {
+            Vec::new()
+        } else {
+            surplus(&base_counts, &head_counts)
+        };
+        if !module_removed.is_empty() {
+            removed.push(ModuleApiChange {
+                module: module.clone(),
+                items: module_removed,
+            });
+        }
+    }
+
+    ApiSurfaceDiff {
+        base_public_items: base.public_items,
+        head_public_items: head.public_items,
+        added,
+        removed,
+        truncated: base.public_symbols_truncated || head.public_symbols_truncated,
+    }
+}
+
+fn item_counts(items: Option<&Vec<String>>) -> BTreeMap<&str, usize> {
+    let mut counts = BTreeMap::new();
+    for item in items.into_iter().flatten() {
+        *counts.entry(item.as_str()).or_insert(0) += 1;
+    }
+    counts
+}
+
+/// Items (with multiplicity) in `left` beyond what `right` holds, sorted.
+fn surplus(left: &BTreeMap<&str, usize>, right: &BTreeMap<&str, usize>) -> Vec<String> {
+    let mut out = Vec::new();
+    for (item, count) in left {
+        let extra = count.saturating_sub(right.get(item).copied().unwrap_or(0));
+        out.extend(std::iter::repeat_n((*item).to_string(), extra));
+    }
+    out
+}
+
+/// Render an [`ApiSurfaceDiff`] as a Markdown section.
+pub fn render_api_surface_diff_md(diff: &ApiSurfaceDiff) -> String {
+    let mut out = String::new();
+    out.push_str("## API surface diff\n\n");
+    out.push_str("|Metric|Value|\n");
+    out.push_str("|---|---:|\n");
+    let _ = writeln!(out, "|Base public items|{}|", diff.base_public_items);
+    let _ = writeln!(out, "|Head public items|{}|", diff.head_public_items);
+    let _ = writeln!(out, "|Added|{}|", change_count(&diff.added));
+    let _ = writeln!(out, "|Removed|{}|\n", change_count(&diff.removed));
+
+    if diff.truncated {
+        out.push_str(
+            "> Symbol inventory was truncated, so this diff is incomplete: removals are \
+             not reported when the head inventory was capped, nor additions when the \
+             base one was.\n\n",
+        );
+    }
+
+    if diff.added.is_empty() && diff.removed.is_empty() {
+        out.push_str(if diff.truncated {
+            "No public API changes among the compared items.\n"
+        } else {
+            "No public API changes.\n"
+        });
+        return out;
+    }
+
+    if !diff.removed.is_empty() {
+        out.push_str("### Removed public items (breaking)\n\n");
+        render_changes(&mut out, &diff.removed, '-');
+    }
+
+    if !diff.added.is_empty() {
+        out.push_str("### Added public items\n\n");
+        render_changes(&mut out, &diff.added, '+');
+    }
+
+    out
+}
+
+fn change_count(changes: &[ModuleApiChange]) -> usize {
+    changes.iter().map(|change| change.items.len()).sum()
+}
+
+fn render_changes(out: &mut String, changes: &[ModuleApiChange], marker: char) {
+    out.push_str("|Module|Item|\n");
+    out.push_str("|---|---|\n");
+    for change in changes {
+        for item in &change.items {
+            let _ = writeln!(out, "|{}|{} `{}`|", change.module, marker, item);
+        }
+    }
+    out.push('\n');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report(symbols: &[(&str, &[&str])]) -> ApiSurfaceReport {
+        let public_symbols: BTreeMap<String, Vec<String>> = symbols
+            .iter()
+            .map(|(module, items)| {
+                (
+                    module.to_string(),
+                    items.iter().map(|item| item.to_string()).collect(),
+                )
+            })
+            .collect();
+        let public_items = public_symbols.values().map(Vec::len).sum();
+        ApiSurfaceReport {
+            total_items: public_items,
+            public_items,
+            internal_items: 0,
+            public_ratio: 1.0,
+            documented_ratio: 0.0,
+            by_language: BTreeMap::new(),
+            by_module: vec![],
+            top_exporters: vec![],
+            public_symbols,
+            doc_violations: vec![],
+            items: None,
+            items_truncated: false,
+            public_symbols_truncated: false,
+        }
+    }
+
+    #[test]
+    fn removed_item_surfaces_as_removed() {
+        let base = report(&[("src", &["fn parse", "struct Config"])]);
+        let head = report(&[("src", &["struct Config"])]);
+
+        let diff = diff_api_surface(&base, &head);
+
+        assert!(diff.added.is_empty());
+        assert_eq!(
+            diff.removed,
+            vec![ModuleApiChange {
+                module: "src".to_string(),
+                items: vec!["fn parse".to_string()],
+            }]
+        );
+        assert!(diff.has_breaking_changes());
+        assert_eq!(diff.base_public_items, 2);
+        assert_eq!(diff.head_public_items, 1);
+    }
+
+    #[test]
+    fn added_items_and_new_modules_are_grouped_by_module() {
+        let base = report(&[("src", &["fn parse"])]);
+        let head = report(&[("src", &["fn parse", "fn render"]), ("cli", &["fn main"])]);
+
+        let diff = diff_api_surface(&base, &head);
+
+        assert!(diff.removed.is_empty());
+        let modules: Vec<&str> = diff.added.iter().map(|c| c.module.as_str()).collect();
+        assert_eq!(modules, vec!["cli", "src"]);
+        assert_eq!(diff.added[1].items, vec!["fn render".to_string()]);
+        assert!(!diff.has_breaking_changes());
+    }
+
+    #[test]
+    fn duplicate_identifiers_are_counted() {
+        let base = report(&[("src", &["fn new", "fn new"])]);
+        let head = report(&[("src", &["fn new"])]);
+
+        let diff = diff_api_surface(&base, &head);
+
+        assert_eq!(diff.removed.len(), 1);
+        assert_eq!(diff.removed[0].items, vec!["fn new".to_string()]);
+    }
+
+    #[test]
+    fn identical_reports_have_no_changes() {
+        let base = report(&[("src", &["fn parse"])]);
+        let diff = diff_api_surface(&base, &base);
+
+        assert_eq!(diff.added, Vec::new());
+        assert_eq!(diff.removed, Vec::new());
+        assert!(render_api_surface_diff_md(&diff).contains("No public API changes."));
+    }
+
+    #[test]
+    fn render_lists_removed_before_added() {
+        let base = report(&[("src", &["fn parse"])]);
+        let head = report(&[("src", &["fn render"])]);
+
+        let md = render_api_surface_diff_md(&diff_api_surface(&base, &head));
+
+        assert!(md.starts_with("## API surface diff\n"));
+        assert!(md.contains("|Removed|1|"));
+        assert!(md.contains("|src|- `fn parse`|"));
+        assert!(md.contains("|src|+ `fn render`|"));
+        let removed_at = md.find("### Removed public items").unwrap();
+        let added_at = md.find("### Added public items").unwrap();
+        assert!(removed_at < added_at);
+    }
+
+    #[test]
+    fn truncated_inventory_is_flagged() {
+        let base = report(&[("src", &["fn parse"])]);
+        let mut head = report(&[("src", &["fn parse"])]);
+        head.public_symbols_truncated = true;
+
+        let diff = diff_api_surface(&base, &head);
+
+        assert!(diff.truncated);
+        assert!(render_api_surface_diff_md(&diff).contains("was truncated"));
+        assert!(!diff_api_surface(&base, &base).truncated);
+    }
+
+    #[test]
+    fn capped_head_reports_no_false_removals() {
+        let base = report(&[("cli", &["fn main"]), ("src", &["fn parse", "fn render"])]);
+        // The head hit the cap after `src::fn parse`; the rest was never seen.
+        let mut head = report(&[("src", &["fn parse", "fn added"])]);
+        head.public_symbols_truncated = true;
+
+        let diff = diff_api_surface(&base, &head);
+
+        assert!(diff.truncated);
+        assert_eq!(diff.removed, Vec::new());
+        assert!(!diff.has_breaking_changes());
+        assert_eq!(
+            diff.added,
+            vec![ModuleApiChange {
+                module: "src".to_string(),
+                items: vec!["fn added".to_string()],
+            }]
+        );
+        let md = render_api_surface_diff_md(&diff);
+        assert!(md.contains("|Removed|0|"));
+        assert!(md.contains("diff is incomplete"));
+        assert!(!md.contains("### Removed public items"));
+    }
+
+    #[test]
+    fn capped_base_reports_no_false_additions() {
+        let mut base = report(&[("src", &["fn parse"])]);
+        base.public_symbols_truncated = true;
+        let head = report(&[("cli", &["fn main"]), ("src", &["fn render"])]);
+
+        let diff = diff_api_surface(&base, &head);
+
+        assert_eq!(diff.added, Vec::new());
+        assert_eq!(
+            diff.removed,
+            vec![ModuleApiChange {
+                module: "src".to_string(),
+                items: vec!["fn parse".to_string()],
+            }]
+        );
+    }
+}
@@ -0,0 +1,48 @@
+//! CSV rendering for analysis receipts.
+//!
+//! This module owns the per-file table used by `AnalysisFormat::Csv`: one row
+//! per file in `derived.top.largest_lines`, quoted per RFC 4180.
+
+use anyhow::Result;
+use tokmd_analysis_types::AnalysisReceipt;
+
+const HEADER: [&str; 9] = [
+    "path",
+    "module",
+    "lang",
+    "code",
+    "lines",
+    "bytes",
+    "tokens",
+    "doc_pct",
+    "bytes_per_line",
+];
+
+/// Render the largest files as CSV. Without derived metrics only the header
+/// row is written; missing ratios are empty cells.
+pub(super) fn render(receipt: &AnalysisReceipt) -> Result<String> {
+    let mut wtr = ::csv::Writer::from_writer(Vec::new());
+    wtr.write_record(HEADER)?;
+
+    let rows = receipt
+        .derived
+        .as_ref()
+        .map(|d| d.top.largest_lines.as_slice())
+        .unwrap_or_default();
+    let optional = |value: Option<f64>| value.map(|v| v.to_string()).unwrap_or_default();
+    for row in rows {
+        wtr.write_record([
+            row.path.clone(),
+            row.module.clone(),
+            row.lang.clone(),
+            row.code.to_string(),
+            row.lines.to_string(),
+            row.bytes.to_string(),
+            row.tokens.to_string(),
+            optional(row.doc_pct),
+            optional(row.bytes_per_line),
+        ])?;
+    }
+
+    Ok(String::from_utf8(wtr.into_inner()?)?)
+}
@@ -56,17 +56,30 @@ fn render_midi_fun(receipt: &AnalysisReceipt) -> Result<Vec<u8>> {
 #[cfg(not(feature = "fun"))]
 fn render_obj_disabled(_receipt: &AnalysisReceipt) -> Result<String> {
     anyhow::bail!(
-        "OBJ format requires the `fun` feature: tokmd-format = {{ version = \"1.9\", features = [\"fun\"] }}"
+        "OBJ format requires the `fun` feature: tokmd-format = {{ version = \"1.9\", features = [\"fun\"] }}; available formats: {}",
+        available_format_names()
     )
 }
 
 #[cfg(not(feature = "fun"))]
 fn render_midi_disabled(_receipt: &AnalysisReceipt) -> Result<Vec<u8>> {
     anyhow::bail!(
-        "MIDI format requires the `fun` feature: tokmd-format = {{ version = \"1.9\", features = [\"fun\"] }}"
+        "MIDI format requires the `fun` feature: tokmd-format = {{ version = \"1.9\", features = [\"fun\"] }}; available formats: {}",
+        available_format_names()
     )
 }
 
+/// Comma-separated CLI names of [`super::available_formats`].
+#[cfg(not(feature = "fun"))]
+fn available_format_names() -> String {
+    super::available_formats()
+        .into_iter()
+        .filter_map(|format| serde_json::to_value(format).ok())
+        .filter_map(|name| name.as_str().map(str::to_string))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
 pub(super) fn render_obj(receipt: &AnalysisReceipt) -> Result<String> {
     #[cfg(feature = "fun")]
     {
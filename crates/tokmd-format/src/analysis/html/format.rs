@@ -1,18 +1,6 @@
 //! Shared HTML formatting helpers.
 
-pub(super) fn format_number(n: usize) -> String {
-    if n >= 1_000_000 {
-        format!("{:.1}M", n as f64 / 1_000_000.0)
-    } else if n >= 1_000 {
-        format!("{:.1}K", n as f64 / 1_000.0)
-    } else {
-        n.to_string()
-    }
-}
-
-pub(super) fn format_pct(ratio: f64) -> String {
-    format!("{:.1}%", ratio * 100.0)
-}
+use std::collections::BTreeMap;
 
 pub(super) fn escape_html(value: &str) -> String {
     value
@@ -22,3 +10,56 @@ pub(super) fn escape_html(value: &str) -> String {
         .replace('"', "&quot;")
         .replace('\'', "&#x27;")
 }
+
+/// Replace each `{{KEY}}` in `template` with its value in one pass.
+///
+/// Inserted values are never rescanned, so a path that happens to contain
+/// `{{REPORT_JSON}}` stays literal text. Unknown keys are left as-is.
+pub(super) fn fill_template(template: &str, values: &[(&str, &str)]) -> String {
+    let mut out =
+        String::with_capacity(template.len() + values.iter().map(|(_, v)| v.len()).sum::<usize>());
+    let mut rest = template;
+    while let Some((before, after)) = rest.split_once("{{") {
+        out.push_str(before);
+        let filled = after.split_once("}}").and_then(|(key, tail)| {
+            values
+                .iter()
+                .find(|(k, _)| *k == key)
+                .map(|(_, value)| (*value, tail))
+        });
+        match filled {
+            Some((value, tail)) => {
+                out.push_str(value);
+                rest = tail;
+            }
+            None => {
+                out.push_str("{{");
+                rest = after;
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Whether `value` is a color safe to place in a style attribute or script:
+/// `#` with 3, 4, 6, or 8 hex digits, or an ASCII color name like `teal`.
+pub(super) fn is_css_color(value: &str) -> bool {
+    match value.strip_prefix('#') {
+        Some(hex) => {
+            matches!(hex.len(), 3 | 4 | 6 | 8) && hex.chars().all(|c| c.is_ascii_hexdigit())
+        }
+        None => (1..=32).contains(&value.len()) && value.chars().all(|c| c.is_ascii_alphabetic()),
+    }
+}
+
+/// The configured color for `lang`, if it is a valid CSS color.
+pub(super) fn lang_color<'a>(
+    lang_colors: &'a BTreeMap<String, String>,
+    lang: &str,
+) -> Option<&'a str> {
+    lang_colors
+        .get(lang)
+        .map(String::as_str)
+        .filter(|color| is_css_color(color))
+}
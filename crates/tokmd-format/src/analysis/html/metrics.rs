@@ -1,18 +1,19 @@
 //! Metric-card rendering for analysis HTML reports.
 
-use super::format::{format_number, format_pct};
 use tokmd_analysis_types::AnalysisReceipt;
 
-pub(super) fn build_metrics_cards(receipt: &AnalysisReceipt) -> String {
+use crate::analysis::NumberFormat;
+
+pub(super) fn build_metrics_cards(receipt: &AnalysisReceipt, nf: &NumberFormat) -> String {
     let mut cards = String::new();
 
     if let Some(derived) = &receipt.derived {
         let metrics = [
-            ("Files", derived.totals.files.to_string()),
-            ("Lines", format_number(derived.totals.lines)),
-            ("Code", format_number(derived.totals.code)),
-            ("Tokens", format_number(derived.totals.tokens)),
-            ("Doc%", format_pct(derived.doc_density.total.ratio)),
+            ("Files", nf.integer(derived.totals.files)),
+            ("Lines", nf.count(derived.totals.lines)),
+            ("Code", nf.count(derived.totals.code)),
+            ("Tokens", nf.count(derived.totals.tokens)),
+            ("Doc%", nf.pct(derived.doc_density.total.ratio)),
         ];
 
         for (label, value) in metrics {
@@ -25,7 +26,7 @@ pub(super) fn build_metrics_cards(receipt: &AnalysisReceipt) -> String {
         if let Some(ctx) = &derived.context_window {
             cards.push_str(&format!(
                 r#"<div class="metric-card"><span class="value">{}</span><span class="label">Context Fit</span></div>"#,
-                format_pct(ctx.pct)
+                nf.pct(ctx.pct)
             ));
         }
     }
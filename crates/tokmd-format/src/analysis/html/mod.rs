@@ -2,9 +2,47 @@
 //!
 //! Single-responsibility HTML renderer for `AnalysisReceipt`.
 
-use time::OffsetDateTime;
-use time::macros::format_description;
-use tokmd_analysis_types::AnalysisReceipt;
+use tokmd_analysis_types::{AnalysisReceipt, FileStatRow};
+
+use super::{AnalysisRenderOptions, ReportSection, TimestampStyle};
+
+/// Files listed when [`AnalysisRenderOptions::html_max_rows`] is unset.
+const DEFAULT_MAX_ROWS: usize = 100;
+
+/// Per-file count that sizes the HTML report's treemap cells.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TreemapMetric {
+    /// Total lines.
+    #[default]
+    Lines,
+    /// Lines of code.
+    Code,
+    /// Estimated tokens, for LLM-cost views.
+    Tokens,
+    /// File size in bytes.
+    Bytes,
+}
+
+impl TreemapMetric {
+    /// The metric's name, as shown in treemap tooltips.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Lines => "lines",
+            Self::Code => "code",
+            Self::Tokens => "tokens",
+            Self::Bytes => "bytes",
+        }
+    }
+
+    fn of(self, row: &FileStatRow) -> usize {
+        match self {
+            Self::Lines => row.lines,
+            Self::Code => row.code,
+            Self::Tokens => row.tokens,
+            Self::Bytes => row.bytes,
+        }
+    }
+}
 
 mod format;
 mod metrics;
@@ -13,30 +51,97 @@ mod table;
 
 /// Render a self-contained HTML report for an analysis receipt.
 pub fn render(receipt: &AnalysisReceipt) -> String {
+    render_with(receipt, &AnalysisRenderOptions::default())
+}
+
+/// [`render`] with displayed counts, percentages, and the header timestamp
+/// formatted per `options`, plus the raw receipt when
+/// [`AnalysisRenderOptions::embed_receipt`] is set and the print stylesheet
+/// applied on screen when [`AnalysisRenderOptions::print_layout`] is set.
+///
+/// `data-*` attributes keep raw integers so table sorting is unaffected.
+pub fn render_with(receipt: &AnalysisReceipt, options: &AnalysisRenderOptions) -> String {
     const TEMPLATE: &str = include_str!("../templates/report.html");
 
-    let timestamp = timestamp_utc();
-    let metrics_cards = metrics::build_metrics_cards(receipt);
-    let table_rows = table::build_table_rows(receipt);
-    let report_json = report_json::build_report_json(receipt);
+    let nf = &options.numbers;
+    let timestamp = options.timestamps.now(TimestampStyle::Display);
+    let metrics_cards = metrics::build_metrics_cards(receipt, nf);
+    let max_rows = options.html_max_rows.unwrap_or(DEFAULT_MAX_ROWS);
+    let table_rows = table::build_table_rows(receipt, nf, max_rows, &options.lang_colors);
+    let lang_colors = report_json::build_lang_colors_json(&options.lang_colors);
+    let report_json = report_json::build_report_json(receipt, max_rows, options.treemap_metric);
+    let receipt_script = if options.embed_receipt {
+        format!("{}\n    ", report_json::build_receipt_script(receipt))
+    } else {
+        String::new()
+    };
+
+    let print_media = if options.print_layout { "all" } else { "print" };
 
-    TEMPLATE
-        .replace("{{TIMESTAMP}}", &timestamp)
-        .replace("{{METRICS_CARDS}}", &metrics_cards)
-        .replace("{{TABLE_ROWS}}", &table_rows)
-        .replace("{{REPORT_JSON}}", &report_json)
+    format::fill_template(
+        TEMPLATE,
+        &[
+            ("PRINT_MEDIA", print_media),
+            ("TIMESTAMP", &format::escape_html(&timestamp)),
+            ("METRICS_CARDS", &metrics_cards),
+            ("TABLE_HEAD", table::FILES_TABLE_HEAD),
+            ("TABLE_ROWS", &table_rows),
+            ("REPORT_JSON", &report_json),
+            ("SIZE_BY", options.treemap_metric.as_str()),
+            ("LANG_COLORS", &lang_colors),
+            ("RECEIPT_SCRIPT", &receipt_script),
+        ],
+    )
 }
 
-fn timestamp_utc() -> String {
-    let format = format_description!("[year]-[month]-[day] [hour]:[minute]:[second] UTC");
-    OffsetDateTime::now_utc()
-        .format(&format)
-        .unwrap_or_else(|_| "1970-01-01 00:00:00 UTC".to_string())
+/// One section of the report on its own: the metric cards for
+/// [`ReportSection::Totals`] and the file table for
+/// [`ReportSection::TopOffenders`]. Other sections have no HTML form.
+pub(super) fn render_fragment(
+    receipt: &AnalysisReceipt,
+    section: ReportSection,
+    options: &AnalysisRenderOptions,
+) -> Option<String> {
+    receipt.derived.as_ref()?;
+    let nf = &options.numbers;
+    match section {
+        ReportSection::Totals => Some(format!(
+            "<div class=\"metrics-grid\">{}</div>",
+            metrics::build_metrics_cards(receipt, nf)
+        )),
+        ReportSection::TopOffenders => {
+            let max_rows = options.html_max_rows.unwrap_or(DEFAULT_MAX_ROWS);
+            Some(format!(
+                "<table id=\"files-table\">{}<tbody>{}</tbody></table>",
+                table::FILES_TABLE_HEAD,
+                table::build_table_rows(receipt, nf, max_rows, &options.lang_colors)
+            ))
+        }
+        _ => None,
+    }
+}
+
+/// The largest files the table and treemap show: the first `max_rows`, or
+/// all of them when `max_rows` is 0.
+fn listed_files(receipt: &AnalysisReceipt, max_rows: usize) -> &[FileStatRow] {
+    let Some(derived) = &receipt.derived else {
+        return &[];
+    };
+    let rows = &derived.top.largest_lines;
+    if max_rows == 0 {
+        rows
+    } else {
+        rows.get(..max_rows).unwrap_or(rows)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::collections::BTreeMap;
+
+    use crate::TimestampFormat;
+    use crate::analysis::NumberFormat;
     use tokmd_analysis_types::*;
 
     fn minimal_receipt() -> AnalysisReceipt {
@@ -60,6 +165,7 @@ mod tests {
                 module_roots: vec![],
                 module_depth: 1,
                 children: "collapse".to_string(),
+                token_model: "heuristic".to_string(),
             },
             args: AnalysisArgsMeta {
                 preset: "receipt".to_string(),
@@ -72,6 +178,14 @@ mod tests {
                 max_commit_files: None,
                 max_file_bytes: None,
                 import_granularity: "module".to_string(),
+                oversized_file_bytes: None,
+                max_commit_message_bytes: None,
+                strip_bom: false,
+                preset_sections: None,
+                include_sections: vec![],
+                exclude_sections: vec![],
+                sample_rate: None,
+                max_runtime_ms: None,
             },
             archetype: None,
             topics: None,
@@ -89,6 +203,11 @@ mod tests {
             api_surface: None,
             effort: None,
             fun: None,
+            timings: None,
+            line_endings: None,
+            sampling: None,
+            risk_surface: Vec::new(),
+            extensions: Default::default(),
         }
     }
 
@@ -250,16 +369,18 @@ mod tests {
                 hash: "abc123".to_string(),
                 entries: 10,
             },
+            oversized_files: vec![],
+            non_utf8_files: vec![],
         }
     }
 
     #[test]
     fn format_number_thresholds() {
-        assert_eq!(format::format_number(500), "500");
-        assert_eq!(format::format_number(1_000), "1.0K");
-        assert_eq!(format::format_number(1_500), "1.5K");
-        assert_eq!(format::format_number(1_000_000), "1.0M");
-        assert_eq!(format::format_number(2_500_000), "2.5M");
+        assert_eq!(NumberFormat::default().count(500), "500");
+        assert_eq!(NumberFormat::default().count(1_000), "1.0K");
+        assert_eq!(NumberFormat::default().count(1_500), "1.5K");
+        assert_eq!(NumberFormat::default().count(1_000_000), "1.0M");
+        assert_eq!(NumberFormat::default().count(2_500_000), "2.5M");
     }
 
     #[test]
@@ -277,22 +398,36 @@ mod tests {
 
     #[test]
     fn timestamp_has_expected_shape() {
-        let ts = timestamp_utc();
-        assert!(ts.contains("UTC"));
-        assert!(ts.len() > 10);
+        let html = render(&minimal_receipt());
+        assert!(html.contains(" UTC"));
+        assert!(!html.contains("{{TIMESTAMP}}"));
+    }
+
+    #[test]
+    fn custom_timestamp_pattern_is_rendered() {
+        let options = AnalysisRenderOptions {
+            timestamps: TimestampFormat {
+                pattern: Some("generated [year]/[month]/[day]".to_string()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let html = render_with(&minimal_receipt(), &options);
+        assert!(html.contains("generated 20"), "custom pattern not applied");
+        assert!(!html.contains(" UTC"));
     }
 
     #[test]
     fn metrics_cards_empty_without_derived() {
         let receipt = minimal_receipt();
-        assert!(metrics::build_metrics_cards(&receipt).is_empty());
+        assert!(metrics::build_metrics_cards(&receipt, &NumberFormat::default()).is_empty());
     }
 
     #[test]
     fn metrics_cards_include_context_fit_when_available() {
         let mut receipt = minimal_receipt();
         receipt.derived = Some(sample_derived());
-        let cards = metrics::build_metrics_cards(&receipt);
+        let cards = metrics::build_metrics_cards(&receipt, &NumberFormat::default());
         assert!(cards.contains("class=\"metric-card\""));
         assert!(cards.contains("Context Fit"));
     }
@@ -306,12 +441,44 @@ mod tests {
         derived.top.largest_lines[0].lang = "Ru\"st".to_string();
         receipt.derived = Some(derived);
 
-        let rows = table::build_table_rows(&receipt);
+        let rows = table::build_table_rows(
+            &receipt,
+            &NumberFormat::default(),
+            DEFAULT_MAX_ROWS,
+            &BTreeMap::new(),
+        );
         assert!(rows.contains("src/&lt;script&gt;.rs"));
         assert!(rows.contains("mod&amp;name"));
         assert!(rows.contains("Ru&quot;st"));
     }
 
+    #[test]
+    fn table_rows_use_number_format_but_keep_raw_data_attributes() {
+        let mut receipt = minimal_receipt();
+        let mut derived = sample_derived();
+        derived.top.largest_lines[0].lines = 1_234_567;
+        receipt.derived = Some(derived);
+
+        let grouped = NumberFormat {
+            group_separator: Some(','),
+            suffix: crate::analysis::SuffixStyle::Full,
+            ..NumberFormat::default()
+        };
+        let rows = table::build_table_rows(&receipt, &grouped, DEFAULT_MAX_ROWS, &BTreeMap::new());
+        assert!(
+            rows.contains(r#"data-lines="1234567">1,234,567<"#),
+            "{rows}"
+        );
+
+        let rows = table::build_table_rows(
+            &receipt,
+            &NumberFormat::default(),
+            DEFAULT_MAX_ROWS,
+            &BTreeMap::new(),
+        );
+        assert!(rows.contains(r#"data-lines="1234567">1.2M<"#), "{rows}");
+    }
+
     #[test]
     fn report_json_escapes_angle_brackets() {
         let mut receipt = minimal_receipt();
@@ -319,7 +486,7 @@ mod tests {
         derived.top.largest_lines[0].path = "</script><script>alert(1)</script>".to_string();
         receipt.derived = Some(derived);
 
-        let json = report_json::build_report_json(&receipt);
+        let json = report_json::build_report_json(&receipt, DEFAULT_MAX_ROWS, TreemapMetric::Lines);
         assert!(
             json.contains("\\u003c/script\\u003e\\u003cscript\\u003ealert(1)\\u003c/script\\u003e")
         );
@@ -330,7 +497,10 @@ mod tests {
     #[test]
     fn report_json_without_derived_is_empty_files_array() {
         let receipt = minimal_receipt();
-        assert_eq!(report_json::build_report_json(&receipt), "{\"files\":[]}");
+        assert_eq!(
+            report_json::build_report_json(&receipt, DEFAULT_MAX_ROWS, TreemapMetric::Lines),
+            "{\"files\":[]}"
+        );
     }
 
     #[test]
@@ -344,4 +514,220 @@ mod tests {
         assert!(html.contains("src/lib.rs"));
         assert!(html.contains("const REPORT_DATA ="));
     }
+
+    #[test]
+    fn receipt_is_not_embedded_by_default() {
+        let html = render(&minimal_receipt());
+        assert!(!html.contains("id=\"tokmd-receipt\""));
+        assert!(!html.contains("{{RECEIPT_SCRIPT}}"));
+    }
+
+    #[test]
+    fn embedded_receipt_is_escaped_valid_json() {
+        const OPEN: &str = "<script type=\"application/json\" id=\"tokmd-receipt\">";
+        let mut receipt = minimal_receipt();
+        receipt.source.inputs = vec!["</script><script>alert(1)</script>".to_string()];
+        receipt.derived = Some(sample_derived());
+        let options = AnalysisRenderOptions {
+            embed_receipt: true,
+            ..Default::default()
+        };
+
+        let html = render_with(&receipt, &options);
+        let start = html.find(OPEN).expect("receipt script tag") + OPEN.len();
+        let end = start + html[start..].find("</script>").unwrap();
+        let embedded = &html[start..end];
+
+        assert!(!embedded.contains('<'));
+        assert!(!embedded.contains('>'));
+        let parsed: AnalysisReceipt = serde_json::from_str(embedded).unwrap();
+        assert_eq!(parsed.source.inputs, receipt.source.inputs);
+        assert_eq!(
+            serde_json::to_value(&parsed).unwrap(),
+            serde_json::to_value(&receipt).unwrap()
+        );
+    }
+
+    #[test]
+    fn print_media_query_is_rendered() {
+        let html = render(&minimal_receipt());
+        assert!(html.contains("@media print {"));
+        assert!(html.contains("break-inside: avoid"));
+        assert!(!html.contains("{{PRINT_MEDIA}}"));
+    }
+
+    #[test]
+    fn print_layout_applies_print_rules_on_screen() {
+        let options = AnalysisRenderOptions {
+            print_layout: true,
+            ..Default::default()
+        };
+        let html = render_with(&minimal_receipt(), &options);
+        assert!(html.contains("@media all {"));
+        assert!(!html.contains("@media print"));
+    }
+
+    #[test]
+    fn html_max_rows_limits_table_and_treemap() {
+        let mut receipt = minimal_receipt();
+        let mut derived = sample_derived();
+        let row = derived.top.largest_lines[0].clone();
+        derived.top.largest_lines = (0..8)
+            .map(|i| FileStatRow {
+                path: format!("src/file{i}.rs"),
+                ..row.clone()
+            })
+            .collect();
+        receipt.derived = Some(derived);
+        let options = |max| AnalysisRenderOptions {
+            html_max_rows: Some(max),
+            ..Default::default()
+        };
+
+        let html = render_with(&receipt, &options(5));
+        assert_eq!(html.matches("<tr><td").count(), 5);
+        assert!(html.contains("src/file4.rs"));
+        assert!(!html.contains("src/file5.rs"));
+        let files = report_json::build_report_json(&receipt, 5, TreemapMetric::Lines);
+        assert_eq!(files.matches("\"path\"").count(), 5);
+
+        let html = render_with(&receipt, &options(0));
+        assert_eq!(html.matches("<tr><td").count(), 8);
+    }
+
+    #[test]
+    fn placeholders_in_file_names_stay_literal() {
+        let mut receipt = minimal_receipt();
+        let mut derived = sample_derived();
+        derived.top.largest_lines[0].path = "{{REPORT_JSON}}".to_string();
+        derived.top.largest_lines[0].module = "{{RECEIPT_SCRIPT}}".to_string();
+        receipt.derived = Some(derived);
+        let options = AnalysisRenderOptions {
+            embed_receipt: true,
+            ..Default::default()
+        };
+
+        let html = render_with(&receipt, &options);
+        assert!(html.contains(r#"data-path="{{REPORT_JSON}}">{{REPORT_JSON}}</td>"#));
+        assert!(html.contains(r#"data-module="{{RECEIPT_SCRIPT}}">"#));
+        assert_eq!(html.matches("id=\"tokmd-receipt\"").count(), 1);
+    }
+
+    #[test]
+    fn timestamp_pattern_is_html_escaped() {
+        let options = AnalysisRenderOptions {
+            timestamps: TimestampFormat {
+                pattern: Some("<b>[year]</b>".to_string()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let html = render_with(&minimal_receipt(), &options);
+        assert!(html.contains("Generated: &lt;b&gt;20"));
+        assert!(!html.contains("<b>"));
+    }
+
+    #[test]
+    fn fill_template_substitutes_in_one_pass() {
+        let filled = format::fill_template(
+            "a {{X}} b {{Y}} {{UNKNOWN}} {{",
+            &[("X", "{{Y}}"), ("Y", "y")],
+        );
+        assert_eq!(filled, "a {{Y}} b y {{UNKNOWN}} {{");
+    }
+
+    #[test]
+    fn report_json_escapes_js_line_terminators() {
+        let mut receipt = minimal_receipt();
+        let mut derived = sample_derived();
+        derived.top.largest_lines[0].path = "a\u{2028}b\u{2029}c".to_string();
+        receipt.derived = Some(derived);
+
+        let json = report_json::build_report_json(&receipt, DEFAULT_MAX_ROWS, TreemapMetric::Lines);
+        assert!(json.contains("a\\u2028b\\u2029c"));
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed["files"][0]["path"], "a\u{2028}b\u{2029}c");
+    }
+
+    #[test]
+    fn treemap_metric_sets_the_sizing_field() {
+        let mut receipt = minimal_receipt();
+        receipt.derived = Some(sample_derived());
+        let row = &receipt.derived.as_ref().unwrap().top.largest_lines[0];
+
+        for (metric, expected) in [
+            (TreemapMetric::Lines, row.lines),
+            (TreemapMetric::Code, row.code),
+            (TreemapMetric::Tokens, row.tokens),
+            (TreemapMetric::Bytes, row.bytes),
+        ] {
+            let json = report_json::build_report_json(&receipt, DEFAULT_MAX_ROWS, metric);
+            let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+            assert_eq!(parsed["files"][0]["size"], expected, "{metric:?}");
+        }
+
+        let options = AnalysisRenderOptions {
+            treemap_metric: TreemapMetric::Tokens,
+            ..Default::default()
+        };
+        let html = render_with(&receipt, &options);
+        assert!(html.contains("const SIZE_BY = 'tokens';"));
+        assert!(html.contains(r#""size":1250"#));
+    }
+
+    #[test]
+    fn configured_lang_color_styles_badges_and_treemap() {
+        let mut receipt = minimal_receipt();
+        receipt.derived = Some(sample_derived());
+        let options = AnalysisRenderOptions {
+            lang_colors: BTreeMap::from([
+                ("Rust".to_string(), "#123abc".to_string()),
+                ("TOML".to_string(), "red;}</style>".to_string()),
+            ]),
+            ..Default::default()
+        };
+
+        let html = render_with(&receipt, &options);
+        assert!(html.contains(
+            r##"<td data-lang="Rust"><span class="lang-badge" style="background: #123abc">Rust</span>"##
+        ));
+        assert!(html.contains(r##"Object.assign(LANG_COLORS, {"Rust":"#123abc"});"##));
+        assert!(!html.contains("red;}"));
+    }
+
+    #[test]
+    fn lang_badges_are_unstyled_without_colors() {
+        let mut receipt = minimal_receipt();
+        receipt.derived = Some(sample_derived());
+        let html = render(&receipt);
+        assert!(html.contains(r#"<span class="lang-badge">Rust</span>"#));
+        assert!(html.contains("Object.assign(LANG_COLORS, {});"));
+    }
+
+    #[test]
+    fn css_colors_are_hex_or_named() {
+        for ok in [
+            "#abc",
+            "#abcd",
+            "#a1b2c3",
+            "#a1b2c3d4",
+            "teal",
+            "RebeccaPurple",
+        ] {
+            assert!(format::is_css_color(ok), "{ok}");
+        }
+        for bad in [
+            "",
+            "#",
+            "#ab",
+            "#abcde",
+            "#ggg",
+            "red;",
+            "rgb(1,2,3)",
+            "a b",
+            "\"x",
+        ] {
+            assert!(!format::is_css_color(bad), "{bad}");
+        }
+    }
 }
@@ -1,27 +1,61 @@
 //! Embedded report JSON rendering for analysis HTML reports.
 
+use std::collections::BTreeMap;
+
 use tokmd_analysis_types::AnalysisReceipt;
 
-pub(super) fn build_report_json(receipt: &AnalysisReceipt) -> String {
-    let mut files = Vec::new();
+use super::format::is_css_color;
+use super::{TreemapMetric, listed_files};
 
-    if let Some(derived) = &receipt.derived {
-        for row in &derived.top.largest_lines {
-            files.push(serde_json::json!({
+/// Treemap data: the listed files, each with a `size` taken from `metric`.
+pub(super) fn build_report_json(
+    receipt: &AnalysisReceipt,
+    max_rows: usize,
+    metric: TreemapMetric,
+) -> String {
+    let files: Vec<_> = listed_files(receipt, max_rows)
+        .iter()
+        .map(|row| {
+            serde_json::json!({
                 "path": row.path,
                 "module": row.module,
                 "lang": row.lang,
                 "code": row.code,
                 "lines": row.lines,
                 "tokens": row.tokens,
-            }));
-        }
-    }
-
-    // Escape < and > to prevent </script> breakout XSS attacks.
-    // JSON remains valid because \u003c and \u003e are valid JSON string escapes.
-    serde_json::json!({ "files": files })
-        .to_string()
-        .replace('<', "\\u003c")
+                "bytes": row.bytes,
+                "size": metric.of(row),
+            })
+        })
+        .collect();
+
+    escape_script_json(&serde_json::json!({ "files": files }).to_string())
+}
+
+/// Configured language colors as a JSON object, skipping invalid colors.
+pub(super) fn build_lang_colors_json(lang_colors: &BTreeMap<String, String>) -> String {
+    let valid: BTreeMap<_, _> = lang_colors
+        .iter()
+        .filter(|(_, color)| is_css_color(color))
+        .collect();
+    escape_script_json(&serde_json::to_string(&valid).unwrap_or_else(|_| "{}".to_string()))
+}
+
+/// The full receipt as a `<script type="application/json">` element.
+pub(super) fn build_receipt_script(receipt: &AnalysisReceipt) -> String {
+    let json = serde_json::to_string(receipt).unwrap_or_else(|_| "null".to_string());
+    format!(
+        "<script type=\"application/json\" id=\"tokmd-receipt\">{}</script>",
+        escape_script_json(&json)
+    )
+}
+
+// Escape < and > to prevent </script> and <!-- breakout XSS attacks, and
+// U+2028/U+2029, which end a line in pre-ES2019 JavaScript string literals.
+// JSON remains valid because these are all valid JSON string escapes.
+fn escape_script_json(json: &str) -> String {
+    json.replace('<', "\\u003c")
         .replace('>', "\\u003e")
+        .replace('\u{2028}', "\\u2028")
+        .replace('\u{2029}', "\\u2029")
 }
@@ -1,28 +1,56 @@
 //! Table-row rendering for analysis HTML reports.
 
-use super::format::{escape_html, format_number};
+use std::collections::BTreeMap;
+
+use super::format::{escape_html, lang_color};
+use super::listed_files;
 use tokmd_analysis_types::AnalysisReceipt;
 
-pub(super) fn build_table_rows(receipt: &AnalysisReceipt) -> String {
+use crate::analysis::NumberFormat;
+
+/// Header of the files table, shared by the full report and the
+/// [`ReportSection::TopOffenders`] fragment. The `data-sort` keys name the
+/// `data-*` attribute each column's cells carry.
+///
+/// [`ReportSection::TopOffenders`]: crate::analysis::ReportSection::TopOffenders
+pub(super) const FILES_TABLE_HEAD: &str = concat!(
+    "<thead><tr>",
+    "<th data-sort=\"path\">Path</th>",
+    "<th data-sort=\"module\">Module</th>",
+    "<th data-sort=\"lang\">Lang</th>",
+    "<th data-sort=\"lines\" class=\"num\">Lines</th>",
+    "<th data-sort=\"code\" class=\"num\">Code</th>",
+    "<th data-sort=\"tokens\" class=\"num\">Tokens</th>",
+    "<th data-sort=\"bytes\" class=\"num\">Bytes</th>",
+    "</tr></thead>",
+);
+
+pub(super) fn build_table_rows(
+    receipt: &AnalysisReceipt,
+    nf: &NumberFormat,
+    max_rows: usize,
+    lang_colors: &BTreeMap<String, String>,
+) -> String {
     let mut rows = String::new();
 
-    if let Some(derived) = &receipt.derived {
-        for row in derived.top.largest_lines.iter().take(100) {
-            rows.push_str(&format!(
-                r#"<tr><td class="path" data-path="{path}">{path}</td><td data-module="{module}">{module}</td><td data-lang="{lang}"><span class="lang-badge">{lang}</span></td><td class="num" data-lines="{lines}">{lines_fmt}</td><td class="num" data-code="{code}">{code_fmt}</td><td class="num" data-tokens="{tokens}">{tokens_fmt}</td><td class="num" data-bytes="{bytes}">{bytes_fmt}</td></tr>"#,
-                path = escape_html(&row.path),
-                module = escape_html(&row.module),
-                lang = escape_html(&row.lang),
-                lines = row.lines,
-                lines_fmt = format_number(row.lines),
-                code = row.code,
-                code_fmt = format_number(row.code),
-                tokens = row.tokens,
-                tokens_fmt = format_number(row.tokens),
-                bytes = row.bytes,
-                bytes_fmt = format_number(row.bytes),
-            ));
-        }
+    for row in listed_files(receipt, max_rows) {
+        let badge_style = lang_color(lang_colors, &row.lang)
+            .map(|color| format!(r#" style="background: {color}""#))
+            .unwrap_or_default();
+        rows.push_str(&format!(
+            r#"<tr><td class="path" data-path="{path}">{path}</td><td data-module="{module}">{module}</td><td data-lang="{lang}"><span class="lang-badge"{badge_style}>{lang}</span></td><td class="num" data-lines="{lines}">{lines_fmt}</td><td class="num" data-code="{code}">{code_fmt}</td><td class="num" data-tokens="{tokens}">{tokens_fmt}</td><td class="num" data-bytes="{bytes}">{bytes_fmt}</td></tr>"#,
+            path = escape_html(&row.path),
+            module = escape_html(&row.module),
+            lang = escape_html(&row.lang),
+            lines = row.lines,
+            lines_fmt = nf.count(row.lines),
+            code = row.code,
+            code_fmt = nf.count(row.code),
+            tokens = row.tokens,
+            tokens_fmt = nf.count(row.tokens),
+            bytes = row.bytes,
+            bytes_fmt = nf.count(row.bytes),
+        ));
     }
 
     rows
@@ -15,10 +15,19 @@
 //!
 //! The formatter intentionally renders whatever the receipt contains without
 //! inferring missing estimate data.
+//!
+//! ## Table order
+//!
+//! Most tables render rows in receipt order; analysis sorts them with a name
+//! tiebreaker before they get here. The few tables ranked at render time
+//! (predictive churn, maintenance hotspots) use a total order on the value,
+//! then the module name, so tied rows come out the same on every run.
 
 use std::fmt::Write;
 use tokmd_analysis_types::AnalysisReceipt;
 
+use super::{AnalysisRenderOptions, DecimalMetric, NumberFormat, ReportSection};
+
 mod api_surface;
 mod archetype;
 mod assets;
@@ -30,97 +39,228 @@ mod duplicates;
 mod eco_label;
 mod effort;
 mod entropy;
+mod explain;
+mod extensions;
 mod git;
 mod imports;
 mod inputs;
 mod license;
+mod line_endings;
 mod predictive_churn;
+mod risk_surface;
+mod sampling;
+mod timings;
 mod topics;
+mod warnings;
 
 /// Render an [`AnalysisReceipt`] to a Markdown string.
 ///
-/// This is the sole public entry point. All subsections (derived metrics,
-/// effort, duplicates, complexity, etc.) are rendered internally.
-pub fn render_md(receipt: &AnalysisReceipt) -> String {
+/// This is the sole public entry point for whole reports. All subsections
+/// (derived metrics, effort, duplicates, complexity, etc.) are rendered
+/// internally, in [`ReportSection::ALL`] order, with numbers, risk and trend
+/// labels, and derived-metric notes controlled by `options`.
+pub fn render_md(receipt: &AnalysisReceipt, options: &AnalysisRenderOptions) -> String {
     let mut out = String::new();
     out.push_str("# tokmd analysis\n\n");
     let _ = writeln!(out, "Preset: `{}`\n", receipt.args.preset);
 
-    if !receipt.source.inputs.is_empty() {
-        inputs::render_inputs(&mut out, &receipt.source.inputs);
-    }
-
-    if let Some(archetype) = &receipt.archetype {
-        archetype::render_archetype(&mut out, archetype);
-    }
-
-    if let Some(topics) = &receipt.topics {
-        topics::render_topic_clouds(&mut out, topics);
-    }
-
-    if let Some(entropy) = &receipt.entropy {
-        entropy::render_entropy_report(&mut out, entropy);
-    }
-
-    if let Some(license) = &receipt.license {
-        license::render_license_report(&mut out, license);
-    }
-
-    if let Some(fingerprint) = &receipt.corporate_fingerprint {
-        corporate_fingerprint::render_corporate_fingerprint(&mut out, fingerprint);
-    }
-
-    if let Some(churn) = &receipt.predictive_churn {
-        predictive_churn::render_predictive_churn(&mut out, churn);
-    }
-
-    if let Some(derived) = &receipt.derived {
-        derived::render_derived_report(&mut out, derived, receipt.effort.as_ref());
-    }
-
-    if let Some(assets) = &receipt.assets {
-        assets::render_asset_report(&mut out, assets);
-    }
-
-    if let Some(deps) = &receipt.deps {
-        dependencies::render_dependency_report(&mut out, deps);
-    }
-
-    if let Some(git) = &receipt.git {
-        git::render_git_report(&mut out, git);
-    }
-
-    if let Some(imports) = &receipt.imports {
-        imports::render_import_report(&mut out, imports);
-    }
-
-    if let Some(dup) = &receipt.dup {
-        duplicates::render_duplicate_report(&mut out, dup);
-    }
-
-    if let Some(cx) = &receipt.complexity {
-        complexity::render_complexity_report(&mut out, cx);
-    }
-
-    if let Some(api) = &receipt.api_surface {
-        api_surface::render_api_surface_report(&mut out, api);
-    }
-
-    if let Some(fun) = &receipt.fun
-        && let Some(label) = &fun.eco_label
-    {
-        eco_label::render_eco_label(&mut out, label);
+    for section in ReportSection::ALL {
+        render_section(&mut out, receipt, section, options);
     }
 
     out
 }
 
-fn fmt_pct(ratio: f64) -> String {
-    format!("{:.1}%", ratio * 100.0)
+/// Render one section exactly as it appears in the full report, or `None`
+/// when the receipt has nothing for it.
+pub(super) fn render_fragment(
+    receipt: &AnalysisReceipt,
+    section: ReportSection,
+    options: &AnalysisRenderOptions,
+) -> Option<String> {
+    let mut out = String::new();
+    render_section(&mut out, receipt, section, options).then_some(out)
 }
 
-fn fmt_f64(value: f64, decimals: usize) -> String {
-    format!("{value:.decimals$}")
+/// Append `section` to `out`. Returns `false`, leaving `out` untouched, when
+/// the receipt has nothing to show for it.
+fn render_section(
+    out: &mut String,
+    receipt: &AnalysisReceipt,
+    section: ReportSection,
+    options: &AnalysisRenderOptions,
+) -> bool {
+    let nf = &options.numbers;
+    let status = &options.status;
+    let explain = options.explain;
+    let derived = receipt.derived.as_ref();
+
+    match section {
+        ReportSection::Sampling => match &receipt.sampling {
+            Some(sampling) => sampling::render_sampling(out, sampling, nf),
+            None => return false,
+        },
+        ReportSection::Warnings => {
+            if receipt.warnings.is_empty() {
+                return false;
+            }
+            warnings::render_warnings(out, &receipt.warnings);
+        }
+        ReportSection::Inputs => {
+            if receipt.source.inputs.is_empty() {
+                return false;
+            }
+            inputs::render_inputs(out, &receipt.source.inputs);
+        }
+        ReportSection::Archetype => match &receipt.archetype {
+            Some(archetype) => archetype::render_archetype(out, archetype),
+            None => return false,
+        },
+        ReportSection::Topics => match &receipt.topics {
+            Some(topics) => topics::render_topic_clouds(out, topics),
+            None => return false,
+        },
+        ReportSection::Entropy => match &receipt.entropy {
+            Some(entropy) => entropy::render_entropy_report(out, entropy, nf),
+            None => return false,
+        },
+        ReportSection::License => match &receipt.license {
+            Some(license) => license::render_license_report(out, license, nf),
+            None => return false,
+        },
+        ReportSection::CorporateFingerprint => match &receipt.corporate_fingerprint {
+            Some(fingerprint) => {
+                corporate_fingerprint::render_corporate_fingerprint(out, fingerprint, nf)
+            }
+            None => return false,
+        },
+        ReportSection::PredictiveChurn => match &receipt.predictive_churn {
+            Some(churn) => predictive_churn::render_predictive_churn(out, churn, nf, status),
+            None => return false,
+        },
+        ReportSection::Totals => match derived {
+            Some(derived) => derived::render_totals(out, derived),
+            None => return false,
+        },
+        ReportSection::Ratios => match derived {
+            Some(derived) => derived::render_ratios(out, derived, nf, explain),
+            None => return false,
+        },
+        ReportSection::Distribution => match derived {
+            Some(derived) => derived::render_distribution(out, derived, nf, explain),
+            None => return false,
+        },
+        ReportSection::Histogram => match derived {
+            Some(derived) => derived::render_histogram(out, derived, nf),
+            None => return false,
+        },
+        ReportSection::TopOffenders => match derived {
+            Some(derived) => derived::render_top_offenders(out, derived, nf),
+            None => return false,
+        },
+        ReportSection::OversizedFiles => match derived {
+            Some(derived) if !derived.oversized_files.is_empty() => {
+                derived::render_oversized_files(out, derived)
+            }
+            _ => return false,
+        },
+        ReportSection::Structure => match derived {
+            Some(derived) => derived::render_structure(out, derived, nf),
+            None => return false,
+        },
+        ReportSection::TestDensity => match derived {
+            Some(derived) => derived::render_test_density(out, derived, nf, explain),
+            None => return false,
+        },
+        ReportSection::Todo => match derived.and_then(|d| d.todo.as_ref()) {
+            Some(todo) => derived::render_todos(out, todo, nf),
+            None => return false,
+        },
+        ReportSection::Boilerplate => match derived {
+            Some(derived) => derived::render_boilerplate(out, derived, nf, explain),
+            None => return false,
+        },
+        ReportSection::Polyglot => match derived {
+            Some(derived) => derived::render_polyglot(out, derived, nf, explain),
+            None => return false,
+        },
+        ReportSection::ReadingTime => match derived {
+            Some(derived) => derived::render_reading_time(out, derived, nf),
+            None => return false,
+        },
+        ReportSection::ContextWindow => match derived.and_then(|d| d.context_window.as_ref()) {
+            Some(context) => derived::render_context_window(out, context, nf),
+            None => return false,
+        },
+        // Prefer the richer top-level effort contract when present; fall back
+        // to legacy derived COCOMO output for older receipts. Both sit inside
+        // the derived report, so neither renders without it.
+        ReportSection::Effort => match (derived, &receipt.effort) {
+            (Some(_), Some(effort_report)) => effort::render_effort_report(out, effort_report, nf),
+            (Some(derived), None) => match &derived.cocomo {
+                Some(cocomo) => effort::render_legacy_cocomo_report(out, derived, cocomo, nf),
+                None => return false,
+            },
+            (None, _) => return false,
+        },
+        ReportSection::Integrity => match derived {
+            Some(derived) => derived::render_integrity(out, derived),
+            None => return false,
+        },
+        ReportSection::Assets => match &receipt.assets {
+            Some(assets) => assets::render_asset_report(out, assets),
+            None => return false,
+        },
+        ReportSection::Deps => match &receipt.deps {
+            Some(deps) => dependencies::render_dependency_report(out, deps),
+            None => return false,
+        },
+        ReportSection::Git => match &receipt.git {
+            Some(git) => git::render_git_report(out, git, nf, status),
+            None => return false,
+        },
+        ReportSection::Imports => match &receipt.imports {
+            Some(imports) => imports::render_import_report(out, imports),
+            None => return false,
+        },
+        ReportSection::Dup => match &receipt.dup {
+            Some(dup) => duplicates::render_duplicate_report(out, dup, nf),
+            None => return false,
+        },
+        ReportSection::Complexity => match &receipt.complexity {
+            Some(cx) => complexity::render_complexity_report(out, cx, nf, status),
+            None => return false,
+        },
+        ReportSection::RiskSurface => {
+            if receipt.risk_surface.is_empty() {
+                return false;
+            }
+            risk_surface::render_risk_surface(out, &receipt.risk_surface, nf);
+        }
+        ReportSection::ApiSurface => match &receipt.api_surface {
+            Some(api) => api_surface::render_api_surface_report(out, api, nf),
+            None => return false,
+        },
+        ReportSection::LineEndings => match &receipt.line_endings {
+            Some(line_endings) => line_endings::render_line_ending_report(out, line_endings),
+            None => return false,
+        },
+        ReportSection::EcoLabel => match receipt.fun.as_ref().and_then(|f| f.eco_label.as_ref()) {
+            Some(label) => eco_label::render_eco_label(out, label, nf),
+            None => return false,
+        },
+        ReportSection::Extensions => {
+            if receipt.extensions.is_empty() {
+                return false;
+            }
+            extensions::render_extensions(out, &receipt.extensions);
+        }
+        ReportSection::Timings => match &receipt.timings {
+            Some(timings) => timings::render_timings(out, timings),
+            None => return false,
+        },
+    }
+    true
 }
 
 #[cfg(test)]
@@ -149,6 +289,7 @@ mod tests {
                 module_roots: vec![],
                 module_depth: 1,
                 children: "collapse".to_string(),
+                token_model: "heuristic".to_string(),
             },
             args: AnalysisArgsMeta {
                 preset: "receipt".to_string(),
@@ -161,6 +302,14 @@ mod tests {
                 max_commit_files: None,
                 max_file_bytes: None,
                 import_granularity: "module".to_string(),
+                oversized_file_bytes: None,
+                max_commit_message_bytes: None,
+                strip_bom: false,
+                preset_sections: None,
+                include_sections: vec![],
+                exclude_sections: vec![],
+                sample_rate: None,
+                max_runtime_ms: None,
             },
             archetype: None,
             topics: None,
@@ -178,28 +327,69 @@ mod tests {
             api_surface: None,
             fun: None,
             effort: None,
+            timings: None,
+            line_endings: None,
+            sampling: None,
+            risk_surface: Vec::new(),
+            extensions: Default::default(),
         }
     }
 
     #[test]
     fn minimal_receipt_renders_without_panic() {
         let receipt = minimal_receipt();
-        let md = render_md(&receipt);
+        let md = render_md(&receipt, &AnalysisRenderOptions::default());
         assert!(md.starts_with("# tokmd analysis\n"));
         assert!(md.contains("Preset: `receipt`"));
         assert!(md.contains("## Inputs\n"));
     }
 
+    #[test]
+    fn truncation_warning_renders_code_and_severity() {
+        let mut receipt = minimal_receipt();
+        receipt.warnings.push(
+            tokmd_types::Warning::new(
+                tokmd_types::TRUNCATED_FILE,
+                tokmd_types::WarningSeverity::Warning,
+                "read the first 128 bytes only",
+            )
+            .with_path("src/big.rs"),
+        );
+        let md = render_md(&receipt, &AnalysisRenderOptions::default());
+        assert!(md.contains(
+            "## Warnings\n\n- **warning** `truncated_file`: read the first 128 bytes only (src/big.rs)\n"
+        ));
+    }
+
+    #[test]
+    fn extensions_render_as_json_under_their_key() {
+        let mut receipt = minimal_receipt();
+        receipt.extensions.insert(
+            "team_owners".to_string(),
+            serde_json::json!({ "src": "core-team" }),
+        );
+        let md = render_md(&receipt, &AnalysisRenderOptions::default());
+        assert!(md.contains(
+            "## Extensions\n\n### team_owners\n\n```json\n{\n  \"src\": \"core-team\"\n}\n```\n"
+        ));
+        assert!(
+            !render_md(&minimal_receipt(), &AnalysisRenderOptions::default())
+                .contains("## Extensions")
+        );
+    }
+
     #[test]
     fn fmt_pct_output_format() {
-        assert_eq!(fmt_pct(0.456), "45.6%");
-        assert_eq!(fmt_pct(0.0), "0.0%");
-        assert_eq!(fmt_pct(1.0), "100.0%");
+        let nf = NumberFormat::default();
+        assert_eq!(nf.pct(0.456), "45.6%");
+        assert_eq!(nf.pct(0.0), "0.0%");
+        assert_eq!(nf.pct(1.0), "100.0%");
     }
 
     #[test]
     fn fmt_f64_output_format() {
-        assert_eq!(fmt_f64(std::f64::consts::PI, 2), "3.14");
-        assert_eq!(fmt_f64(1.0, 4), "1.0000");
+        let nf = NumberFormat::default();
+        assert_eq!(nf.decimal(std::f64::consts::PI, 2), "3.14");
+        assert_eq!(nf.decimal(1.0, 4), "1.0000");
     }
 }
@@ -5,22 +5,22 @@
 
 use std::fmt::Write;
 
-use super::fmt_pct;
+use super::NumberFormat;
 use tokmd_analysis_types::ApiSurfaceReport;
 
-pub(super) fn render_api_surface_report(out: &mut String, api: &ApiSurfaceReport) {
+pub(super) fn render_api_surface_report(
+    out: &mut String,
+    api: &ApiSurfaceReport,
+    nf: &NumberFormat,
+) {
     out.push_str("## API surface\n\n");
     out.push_str("|Metric|Value|\n");
     out.push_str("|---|---:|\n");
     let _ = writeln!(out, "|Total items|{}|", api.total_items);
     let _ = writeln!(out, "|Public items|{}|", api.public_items);
     let _ = writeln!(out, "|Internal items|{}|", api.internal_items);
-    let _ = writeln!(out, "|Public ratio|{}|", fmt_pct(api.public_ratio));
-    let _ = writeln!(
-        out,
-        "|Documented ratio|{}|\n",
-        fmt_pct(api.documented_ratio)
-    );
+    let _ = writeln!(out, "|Public ratio|{}|", nf.pct(api.public_ratio));
+    let _ = writeln!(out, "|Documented ratio|{}|\n", nf.pct(api.documented_ratio));
 
     if !api.by_language.is_empty() {
         out.push_str("### By language\n\n");
@@ -34,7 +34,7 @@ pub(super) fn render_api_surface_report(out: &mut String, api: &ApiSurfaceReport
                 data.total_items,
                 data.public_items,
                 data.internal_items,
-                fmt_pct(data.public_ratio)
+                nf.pct(data.public_ratio)
             );
         }
         out.push('\n');
@@ -51,7 +51,7 @@ pub(super) fn render_api_surface_report(out: &mut String, api: &ApiSurfaceReport
                 row.module,
                 row.total_items,
                 row.public_items,
-                fmt_pct(row.public_ratio)
+                nf.pct(row.public_ratio)
             );
         }
         out.push('\n');
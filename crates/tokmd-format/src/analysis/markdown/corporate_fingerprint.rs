@@ -4,10 +4,14 @@
 
 use std::fmt::Write;
 
-use super::fmt_pct;
+use super::NumberFormat;
 use tokmd_analysis_types::CorporateFingerprint;
 
-pub(super) fn render_corporate_fingerprint(out: &mut String, fingerprint: &CorporateFingerprint) {
+pub(super) fn render_corporate_fingerprint(
+    out: &mut String,
+    fingerprint: &CorporateFingerprint,
+    nf: &NumberFormat,
+) {
     out.push_str("## Corporate fingerprint\n\n");
     if fingerprint.domains.is_empty() {
         out.push_str("- No commit domains detected.\n\n");
@@ -15,13 +19,11 @@ pub(super) fn render_corporate_fingerprint(out: &mut String, fingerprint: &Corpo
         out.push_str("|Domain|Commits|Pct|\n");
         out.push_str("|---|---:|---:|\n");
         for row in fingerprint.domains.iter().take(10) {
-            let _ = writeln!(
-                out,
-                "|{}|{}|{}|",
-                row.domain,
-                row.commits,
-                fmt_pct(row.pct as f64)
-            );
+            let _ = write!(out, "|{}", row.domain);
+            if !row.members.is_empty() {
+                let _ = write!(out, " (incl. {})", row.members.join(", "));
+            }
+            let _ = writeln!(out, "|{}|{}|", row.commits, nf.pct(row.pct as f64));
         }
         out.push('\n');
     }
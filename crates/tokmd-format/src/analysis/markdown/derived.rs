@@ -1,18 +1,15 @@
 //! Derived analysis Markdown rendering.
 //!
 //! This module owns totals, ratios, distribution, top-offender tables, density,
-//! context-window, legacy COCOMO fallback, and integrity sections.
+//! context-window, and integrity sections, one function per `##` heading so
+//! each can also be rendered as a standalone fragment.
 
 use std::fmt::Write;
 
-use super::{effort, fmt_f64, fmt_pct};
-use tokmd_analysis_types::{DerivedReport, EffortEstimateReport, FileStatRow};
+use super::{DecimalMetric, NumberFormat, explain};
+use tokmd_analysis_types::{ContextWindowReport, DerivedReport, FileStatRow, TodoReport};
 
-pub(super) fn render_derived_report(
-    out: &mut String,
-    derived: &DerivedReport,
-    effort_report: Option<&EffortEstimateReport>,
-) {
+pub(super) fn render_totals(out: &mut String, derived: &DerivedReport) {
     out.push_str("## Totals\n\n");
     out.push_str("|Files|Code|Comments|Blanks|Lines|Bytes|Tokens|\n");
     out.push_str("|---:|---:|---:|---:|---:|---:|---:|\n");
@@ -27,25 +24,41 @@ pub(super) fn render_derived_report(
         derived.totals.bytes,
         derived.totals.tokens
     );
+}
 
+pub(super) fn render_ratios(
+    out: &mut String,
+    derived: &DerivedReport,
+    nf: &NumberFormat,
+    explain: bool,
+) {
     out.push_str("## Ratios\n\n");
     out.push_str("|Metric|Value|\n");
     out.push_str("|---|---:|\n");
     let _ = writeln!(
         out,
         "|Doc density|{}|",
-        fmt_pct(derived.doc_density.total.ratio)
+        nf.pct(derived.doc_density.total.ratio)
     );
     let _ = writeln!(
         out,
         "|Whitespace ratio|{}|",
-        fmt_pct(derived.whitespace.total.ratio)
+        nf.pct(derived.whitespace.total.ratio)
     );
     let _ = writeln!(
         out,
         "|Bytes per line|{}|\n",
-        fmt_f64(derived.verbosity.total.rate, 2)
+        nf.metric(DecimalMetric::Verbosity, derived.verbosity.total.rate)
     );
+    if explain {
+        let ratio = derived.doc_density.total.ratio;
+        explain::note(
+            out,
+            "Doc density",
+            &nf.pct(ratio),
+            explain::doc_density(ratio),
+        );
+    }
 
     out.push_str("### Doc density by language\n\n");
     out.push_str("|Lang|Doc%|Comments|Code|\n");
@@ -55,7 +68,7 @@ pub(super) fn render_derived_report(
             out,
             "|{}|{}|{}|{}|",
             row.key,
-            fmt_pct(row.ratio),
+            nf.pct(row.ratio),
             row.numerator,
             row.denominator.saturating_sub(row.numerator)
         );
@@ -70,7 +83,7 @@ pub(super) fn render_derived_report(
             out,
             "|{}|{}|{}|{}|",
             row.key,
-            fmt_pct(row.ratio),
+            nf.pct(row.ratio),
             row.numerator,
             row.denominator
         );
@@ -85,13 +98,20 @@ pub(super) fn render_derived_report(
             out,
             "|{}|{}|{}|{}|",
             row.key,
-            fmt_f64(row.rate, 2),
+            nf.metric(DecimalMetric::Verbosity, row.rate),
             row.numerator,
             row.denominator
         );
     }
     out.push('\n');
+}
 
+pub(super) fn render_distribution(
+    out: &mut String,
+    derived: &DerivedReport,
+    nf: &NumberFormat,
+    explain: bool,
+) {
     out.push_str("## Distribution\n\n");
     out.push_str("|Count|Min|Max|Mean|Median|P90|P99|Gini|\n");
     out.push_str("|---:|---:|---:|---:|---:|---:|---:|---:|\n");
@@ -101,13 +121,19 @@ pub(super) fn render_derived_report(
         derived.distribution.count,
         derived.distribution.min,
         derived.distribution.max,
-        fmt_f64(derived.distribution.mean, 2),
-        fmt_f64(derived.distribution.median, 2),
-        fmt_f64(derived.distribution.p90, 2),
-        fmt_f64(derived.distribution.p99, 2),
-        fmt_f64(derived.distribution.gini, 4)
+        nf.metric(DecimalMetric::Distribution, derived.distribution.mean),
+        nf.metric(DecimalMetric::Distribution, derived.distribution.median),
+        nf.metric(DecimalMetric::Distribution, derived.distribution.p90),
+        nf.metric(DecimalMetric::Distribution, derived.distribution.p99),
+        nf.metric(DecimalMetric::Gini, derived.distribution.gini)
     );
+    if explain {
+        let gini = derived.distribution.gini;
+        explain::note(out, "Gini", &nf.decimal(gini, 2), explain::gini(gini));
+    }
+}
 
+pub(super) fn render_histogram(out: &mut String, derived: &DerivedReport, nf: &NumberFormat) {
     out.push_str("## File size histogram\n\n");
     out.push_str("|Bucket|Min|Max|Files|Pct|\n");
     out.push_str("|---|---:|---:|---:|---:|\n");
@@ -123,113 +149,176 @@ pub(super) fn render_derived_report(
             bucket.min,
             max,
             bucket.files,
-            fmt_pct(bucket.pct)
+            nf.pct(bucket.pct)
         );
     }
     out.push('\n');
+}
 
+pub(super) fn render_top_offenders(out: &mut String, derived: &DerivedReport, nf: &NumberFormat) {
     out.push_str("## Top offenders\n\n");
 
     out.push_str("### Largest files by lines\n\n");
-    out.push_str(&render_file_table(&derived.top.largest_lines));
+    out.push_str(&render_file_table(&derived.top.largest_lines, nf));
     out.push('\n');
 
     out.push_str("### Largest files by tokens\n\n");
-    out.push_str(&render_file_table(&derived.top.largest_tokens));
+    out.push_str(&render_file_table(&derived.top.largest_tokens, nf));
     out.push('\n');
 
     out.push_str("### Largest files by bytes\n\n");
-    out.push_str(&render_file_table(&derived.top.largest_bytes));
+    out.push_str(&render_file_table(&derived.top.largest_bytes, nf));
     out.push('\n');
 
     out.push_str("### Least documented (min LOC)\n\n");
-    out.push_str(&render_file_table(&derived.top.least_documented));
+    out.push_str(&render_file_table(&derived.top.least_documented, nf));
     out.push('\n');
 
     out.push_str("### Most dense (bytes/line)\n\n");
-    out.push_str(&render_file_table(&derived.top.most_dense));
+    out.push_str(&render_file_table(&derived.top.most_dense, nf));
     out.push('\n');
+}
+
+pub(super) fn render_oversized_files(out: &mut String, derived: &DerivedReport) {
+    out.push_str("## Oversized files\n\n");
+    out.push_str("Excluded from every metric in this report.\n\n");
+    out.push_str("|Path|Bytes|\n");
+    out.push_str("|---|---:|\n");
+    for row in &derived.oversized_files {
+        let _ = writeln!(out, "|{}|{}|", row.path, row.bytes);
+    }
+    out.push('\n');
+}
 
+pub(super) fn render_structure(out: &mut String, derived: &DerivedReport, nf: &NumberFormat) {
     out.push_str("## Structure\n\n");
     let _ = writeln!(
         out,
         "- Max depth: `{}`\n- Avg depth: `{}`\n",
         derived.nesting.max,
-        fmt_f64(derived.nesting.avg, 2)
+        nf.metric(DecimalMetric::Nesting, derived.nesting.avg)
     );
+}
 
+pub(super) fn render_test_density(
+    out: &mut String,
+    derived: &DerivedReport,
+    nf: &NumberFormat,
+    explain: bool,
+) {
     out.push_str("## Test density\n\n");
     let _ = writeln!(
         out,
         "- Test lines: `{}`\n- Prod lines: `{}`\n- Test ratio: `{}`\n",
         derived.test_density.test_lines,
         derived.test_density.prod_lines,
-        fmt_pct(derived.test_density.ratio)
+        nf.pct(derived.test_density.ratio)
     );
-
-    if let Some(todo) = &derived.todo {
-        out.push_str("## TODOs\n\n");
-        let _ = writeln!(
+    if explain {
+        let ratio = derived.test_density.ratio;
+        explain::note(
             out,
-            "- Total: `{}`\n- Density (per KLOC): `{}`\n",
-            todo.total,
-            fmt_f64(todo.density_per_kloc, 2)
+            "Test ratio",
+            &nf.pct(ratio),
+            explain::test_ratio(ratio),
         );
-        out.push_str("|Tag|Count|\n");
-        out.push_str("|---|---:|\n");
-        for tag in &todo.tags {
-            let _ = writeln!(out, "|{}|{}|", tag.tag, tag.count);
-        }
-        out.push('\n');
     }
+}
 
+pub(super) fn render_todos(out: &mut String, todo: &TodoReport, nf: &NumberFormat) {
+    out.push_str("## TODOs\n\n");
+    let _ = writeln!(
+        out,
+        "- Total: `{}`\n- Density (per KLOC): `{}`\n",
+        todo.total,
+        nf.metric(DecimalMetric::TodoDensity, todo.density_per_kloc)
+    );
+    out.push_str("|Tag|Count|\n");
+    out.push_str("|---|---:|\n");
+    for tag in &todo.tags {
+        let _ = writeln!(out, "|{}|{}|", tag.tag, tag.count);
+    }
+    out.push('\n');
+}
+
+pub(super) fn render_boilerplate(
+    out: &mut String,
+    derived: &DerivedReport,
+    nf: &NumberFormat,
+    explain: bool,
+) {
     out.push_str("## Boilerplate ratio\n\n");
     let _ = writeln!(
         out,
         "- Infra lines: `{}`\n- Logic lines: `{}`\n- Infra ratio: `{}`\n",
         derived.boilerplate.infra_lines,
         derived.boilerplate.logic_lines,
-        fmt_pct(derived.boilerplate.ratio)
+        nf.pct(derived.boilerplate.ratio)
     );
+    if explain {
+        let ratio = derived.boilerplate.ratio;
+        explain::note(
+            out,
+            "Infra ratio",
+            &nf.pct(ratio),
+            explain::infra_ratio(ratio),
+        );
+    }
+}
 
+pub(super) fn render_polyglot(
+    out: &mut String,
+    derived: &DerivedReport,
+    nf: &NumberFormat,
+    explain: bool,
+) {
     out.push_str("## Polyglot\n\n");
     let _ = writeln!(
         out,
         "- Languages: `{}`\n- Dominant: `{}` ({})\n- Entropy: `{}`\n",
         derived.polyglot.lang_count,
         derived.polyglot.dominant_lang,
-        fmt_pct(derived.polyglot.dominant_pct),
-        fmt_f64(derived.polyglot.entropy, 4)
+        nf.pct(derived.polyglot.dominant_pct),
+        nf.metric(DecimalMetric::Entropy, derived.polyglot.entropy)
     );
+    if explain {
+        let polyglot = &derived.polyglot;
+        explain::note(
+            out,
+            "Entropy",
+            &nf.decimal(polyglot.entropy, 2),
+            explain::polyglot(polyglot),
+        );
+    }
+}
 
+pub(super) fn render_reading_time(out: &mut String, derived: &DerivedReport, nf: &NumberFormat) {
     out.push_str("## Reading time\n\n");
     let _ = writeln!(
         out,
         "- Minutes: `{}` ({} lines/min)\n",
-        fmt_f64(derived.reading_time.minutes, 2),
+        nf.metric(DecimalMetric::ReadingTime, derived.reading_time.minutes),
         derived.reading_time.lines_per_minute
     );
+}
 
-    if let Some(context) = &derived.context_window {
-        out.push_str("## Context window\n\n");
-        let _ = writeln!(
-            out,
-            "- Window tokens: `{}`\n- Total tokens: `{}`\n- Utilization: `{}`\n- Fits: `{}`\n",
-            context.window_tokens,
-            context.total_tokens,
-            fmt_pct(context.pct),
-            context.fits
-        );
-    }
-
-    // Prefer the richer top-level effort contract when present; fall back to
-    // legacy derived COCOMO output for older receipts.
-    if let Some(effort_report) = effort_report {
-        effort::render_effort_report(out, effort_report);
-    } else if let Some(cocomo) = &derived.cocomo {
-        effort::render_legacy_cocomo_report(out, derived, cocomo);
-    }
+pub(super) fn render_context_window(
+    out: &mut String,
+    context: &ContextWindowReport,
+    nf: &NumberFormat,
+) {
+    out.push_str("## Context window\n\n");
+    let _ = writeln!(
+        out,
+        "- Window tokens: `{}`\n- Total tokens: `{}`\n- Utilization: `{}`\n- Fits: `{}`\n",
+        context.window_tokens,
+        context.total_tokens,
+        nf.pct(context.pct),
+        context.fits
+    );
+}
 
+pub(super) fn render_integrity(out: &mut String, derived: &DerivedReport) {
     out.push_str("## Integrity\n\n");
     let _ = writeln!(
         out,
@@ -238,7 +327,7 @@ pub(super) fn render_derived_report(
     );
 }
 
-fn render_file_table(rows: &[FileStatRow]) -> String {
+fn render_file_table(rows: &[FileStatRow], nf: &NumberFormat) -> String {
     let mut out = String::with_capacity((rows.len() + 3) * 80);
     out.push_str("|Path|Lang|Lines|Code|Bytes|Tokens|Doc%|B/Line|\n");
     out.push_str("|---|---|---:|---:|---:|---:|---:|---:|\n");
@@ -252,9 +341,11 @@ fn render_file_table(rows: &[FileStatRow]) -> String {
             row.code,
             row.bytes,
             row.tokens,
-            row.doc_pct.map(fmt_pct).unwrap_or_else(|| "-".to_string()),
+            row.doc_pct
+                .map(|v| nf.pct(v))
+                .unwrap_or_else(|| "-".to_string()),
             row.bytes_per_line
-                .map(|v| fmt_f64(v, 2))
+                .map(|v| nf.metric(DecimalMetric::BytesPerLine, v))
                 .unwrap_or_else(|| "-".to_string())
         );
     }
@@ -5,10 +5,10 @@
 
 use std::fmt::Write;
 
-use super::{fmt_f64, fmt_pct};
+use super::{DecimalMetric, NumberFormat};
 use tokmd_analysis_types::DuplicateReport;
 
-pub(super) fn render_duplicate_report(out: &mut String, dup: &DuplicateReport) {
+pub(super) fn render_duplicate_report(out: &mut String, dup: &DuplicateReport, nf: &NumberFormat) {
     out.push_str("## Duplicates\n\n");
     let _ = writeln!(
         out,
@@ -23,7 +23,7 @@ pub(super) fn render_duplicate_report(out: &mut String, dup: &DuplicateReport) {
             density.duplicate_groups,
             density.duplicate_files,
             density.duplicated_bytes,
-            fmt_pct(density.wasted_pct_of_codebase)
+            nf.pct(density.wasted_pct_of_codebase)
         );
         if !density.by_module.is_empty() {
             out.push_str(
@@ -40,7 +40,7 @@ pub(super) fn render_duplicate_report(out: &mut String, dup: &DuplicateReport) {
                     row.duplicated_bytes,
                     row.wasted_bytes,
                     row.module_bytes,
-                    fmt_pct(row.density)
+                    nf.pct(row.density)
                 );
             }
             out.push('\n');
@@ -62,7 +62,7 @@ pub(super) fn render_duplicate_report(out: &mut String, dup: &DuplicateReport) {
             "- Files analyzed: `{}`\n- Files skipped: `{}`\n- Threshold: `{}`\n- Scope: `{:?}`",
             near.files_analyzed,
             near.files_skipped,
-            fmt_f64(near.params.threshold, 2),
+            nf.metric(DecimalMetric::NearDupThreshold, near.params.threshold),
             near.params.scope
         );
         if let Some(eligible) = near.eligible_files {
@@ -86,7 +86,7 @@ pub(super) fn render_duplicate_report(out: &mut String, dup: &DuplicateReport) {
                     "|{}|{}|{}|{}|{}|",
                     i + 1,
                     cluster.files.len(),
-                    fmt_pct(cluster.max_similarity),
+                    nf.pct(cluster.max_similarity),
                     cluster.representative,
                     cluster.pair_count
                 );
@@ -107,7 +107,7 @@ pub(super) fn render_duplicate_report(out: &mut String, dup: &DuplicateReport) {
                     "|{}|{}|{}|{}|",
                     pair.left,
                     pair.right,
-                    fmt_pct(pair.similarity),
+                    nf.pct(pair.similarity),
                     pair.shared_fingerprints
                 );
             }
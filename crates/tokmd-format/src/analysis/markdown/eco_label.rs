@@ -4,16 +4,16 @@
 
 use std::fmt::Write;
 
-use super::fmt_f64;
+use super::{DecimalMetric, NumberFormat};
 use tokmd_analysis_types::EcoLabel;
 
-pub(super) fn render_eco_label(out: &mut String, label: &EcoLabel) {
+pub(super) fn render_eco_label(out: &mut String, label: &EcoLabel, nf: &NumberFormat) {
     out.push_str("## Eco label\n\n");
     let _ = writeln!(
         out,
         "- Label: `{}`\n- Score: `{}`\n- Bytes: `{}`\n- Notes: `{}`\n",
         label.label,
-        fmt_f64(label.score, 1),
+        nf.metric(DecimalMetric::EcoScore, label.score),
         label.bytes,
         label.notes
     );
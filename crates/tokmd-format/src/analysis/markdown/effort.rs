@@ -5,7 +5,7 @@
 
 use std::fmt::Write;
 
-use super::{fmt_f64, fmt_pct};
+use super::{DecimalMetric, NumberFormat};
 use tokmd_analysis_types::{DerivedReport, EffortDriverDirection, EffortEstimateReport};
 
 /// Render the richer `receipt.effort` section to Markdown.
@@ -21,7 +21,11 @@ use tokmd_analysis_types::{DerivedReport, EffortDriverDirection, EffortEstimateR
 ///
 /// That keeps formatting honest when the upstream effort engine is only
 /// partially implemented.
-pub(super) fn render_effort_report(out: &mut String, effort: &EffortEstimateReport) {
+pub(super) fn render_effort_report(
+    out: &mut String,
+    effort: &EffortEstimateReport,
+    nf: &NumberFormat,
+) {
     out.push_str("## Effort estimate\n\n");
 
     out.push_str("### Size basis\n\n");
@@ -33,10 +37,10 @@ pub(super) fn render_effort_report(out: &mut String, effort: &EffortEstimateRepo
         effort.size_basis.authored_lines,
         effort.size_basis.generated_lines,
         effort.size_basis.vendored_lines,
-        fmt_f64(effort.size_basis.kloc_authored, 4),
-        fmt_f64(effort.size_basis.kloc_total, 4),
-        fmt_pct(effort.size_basis.generated_pct),
-        fmt_pct(effort.size_basis.vendored_pct),
+        nf.metric(DecimalMetric::Kloc, effort.size_basis.kloc_authored),
+        nf.metric(DecimalMetric::Kloc, effort.size_basis.kloc_total),
+        nf.pct(effort.size_basis.generated_pct),
+        nf.pct(effort.size_basis.vendored_pct),
         effort.size_basis.classification_confidence
     );
 
@@ -51,7 +55,7 @@ pub(super) fn render_effort_report(out: &mut String, effort: &EffortEstimateRepo
                 row.tag,
                 row.lines,
                 row.authored_lines,
-                fmt_pct(row.pct_of_total)
+                nf.pct(row.pct_of_total)
             );
         }
         out.push('\n');
@@ -61,21 +65,21 @@ pub(super) fn render_effort_report(out: &mut String, effort: &EffortEstimateRepo
     let _ = writeln!(
         out,
         "- Effort p50: `{}` person-months (low `{}` / p80 `{}`)\n- Schedule p50: `{}` months (low `{}` / p80 `{}`)\n- Staff p50: `{}` FTE (low `{}` / p80 `{}`)\n",
-        fmt_f64(effort.results.effort_pm_p50, 4),
-        fmt_f64(effort.results.effort_pm_low, 4),
-        fmt_f64(effort.results.effort_pm_p80, 4),
-        fmt_f64(effort.results.schedule_months_p50, 4),
-        fmt_f64(effort.results.schedule_months_low, 4),
-        fmt_f64(effort.results.schedule_months_p80, 4),
-        fmt_f64(effort.results.staff_p50, 4),
-        fmt_f64(effort.results.staff_low, 4),
-        fmt_f64(effort.results.staff_p80, 4),
+        nf.metric(DecimalMetric::Effort, effort.results.effort_pm_p50),
+        nf.metric(DecimalMetric::Effort, effort.results.effort_pm_low),
+        nf.metric(DecimalMetric::Effort, effort.results.effort_pm_p80),
+        nf.metric(DecimalMetric::Effort, effort.results.schedule_months_p50),
+        nf.metric(DecimalMetric::Effort, effort.results.schedule_months_low),
+        nf.metric(DecimalMetric::Effort, effort.results.schedule_months_p80),
+        nf.metric(DecimalMetric::Effort, effort.results.staff_p50),
+        nf.metric(DecimalMetric::Effort, effort.results.staff_low),
+        nf.metric(DecimalMetric::Effort, effort.results.staff_p80),
     );
 
     out.push_str("### Why\n\n");
     let _ = writeln!(out, "- Confidence level: `{}`", effort.confidence.level);
     if let Some(coverage) = effort.confidence.data_coverage_pct {
-        let _ = writeln!(out, "- Data coverage: `{}`", fmt_pct(coverage));
+        let _ = writeln!(out, "- Data coverage: `{}`", nf.pct(coverage));
     }
     if !effort.confidence.reasons.is_empty() {
         out.push_str("- Reasons:\n");
@@ -102,7 +106,7 @@ pub(super) fn render_effort_report(out: &mut String, effort: &EffortEstimateRepo
                 "|{}|{}|{}|{}|",
                 row.label,
                 direction,
-                fmt_f64(row.weight, 4),
+                nf.metric(DecimalMetric::Effort, row.weight),
                 row.evidence
             );
         }
@@ -139,15 +143,15 @@ pub(super) fn render_effort_report(out: &mut String, effort: &EffortEstimateRepo
             delta.langs_changed,
             delta.hotspot_files_touched,
             delta.coupled_neighbors_touched,
-            fmt_f64(delta.blast_radius, 4),
+            nf.metric(DecimalMetric::Effort, delta.blast_radius),
             delta.classification,
-            fmt_f64(delta.effort_pm_est, 4),
-            fmt_f64(delta.effort_pm_high, 4)
+            nf.metric(DecimalMetric::Effort, delta.effort_pm_est),
+            nf.metric(DecimalMetric::Effort, delta.effort_pm_high)
         );
         let _ = writeln!(
             out,
             "- Effort low bound (delta): `{}`\n",
-            fmt_f64(delta.effort_pm_low, 4),
+            nf.metric(DecimalMetric::Effort, delta.effort_pm_low),
         );
     } else {
         out.push_str("- Baseline comparison is not available for this receipt.\n\n");
@@ -167,6 +171,7 @@ pub(super) fn render_legacy_cocomo_report(
     out: &mut String,
     derived: &DerivedReport,
     cocomo: &tokmd_analysis_types::CocomoReport,
+    nf: &NumberFormat,
 ) {
     out.push_str("## Effort estimate\n\n");
 
@@ -176,16 +181,16 @@ pub(super) fn render_legacy_cocomo_report(
         "- Source lines: `{}`\n- Total lines: `{}`\n- KLOC: `{}`\n",
         derived.totals.code,
         derived.totals.lines,
-        fmt_f64(cocomo.kloc, 4)
+        nf.metric(DecimalMetric::Kloc, cocomo.kloc)
     );
 
     out.push_str("### Headline\n\n");
     let _ = writeln!(
         out,
         "- Effort: `{}` person-months\n- Duration: `{}` months\n- Staff: `{}`\n",
-        fmt_f64(cocomo.effort_pm, 2),
-        fmt_f64(cocomo.duration_months, 2),
-        fmt_f64(cocomo.staff, 2)
+        nf.metric(DecimalMetric::Cocomo, cocomo.effort_pm),
+        nf.metric(DecimalMetric::Cocomo, cocomo.duration_months),
+        nf.metric(DecimalMetric::Cocomo, cocomo.staff)
     );
 
     out.push_str("### Why\n\n");
@@ -193,10 +198,10 @@ pub(super) fn render_legacy_cocomo_report(
         out,
         "- Model: `COCOMO` (`{}` mode)\n- Formula: `E = a * KLOC^b`\n- Coefficients: `a={}`, `b={}`, `c={}`, `d={}`\n",
         cocomo.mode,
-        fmt_f64(cocomo.a, 2),
-        fmt_f64(cocomo.b, 2),
-        fmt_f64(cocomo.c, 2),
-        fmt_f64(cocomo.d, 2)
+        nf.metric(DecimalMetric::Cocomo, cocomo.a),
+        nf.metric(DecimalMetric::Cocomo, cocomo.b),
+        nf.metric(DecimalMetric::Cocomo, cocomo.c),
+        nf.metric(DecimalMetric::Cocomo, cocomo.d)
     );
 
     out.push_str("### Delta\n\n");
@@ -4,10 +4,10 @@
 
 use std::fmt::Write;
 
-use super::fmt_f64;
+use super::{DecimalMetric, NumberFormat};
 use tokmd_analysis_types::EntropyReport;
 
-pub(super) fn render_entropy_report(out: &mut String, entropy: &EntropyReport) {
+pub(super) fn render_entropy_report(out: &mut String, entropy: &EntropyReport, nf: &NumberFormat) {
     out.push_str("## Entropy profiling\n\n");
     if entropy.suspects.is_empty() {
         out.push_str("- No entropy outliers detected.\n\n");
@@ -20,7 +20,7 @@ pub(super) fn render_entropy_report(out: &mut String, entropy: &EntropyReport) {
                 "|{}|{}|{}|{}|{:?}|",
                 row.path,
                 row.module,
-                fmt_f64(row.entropy_bits_per_byte as f64, 2),
+                nf.metric(DecimalMetric::ByteEntropy, row.entropy_bits_per_byte as f64),
                 row.sample_bytes,
                 row.class
             );
@@ -0,0 +1,110 @@
+//! Plain-language notes for derived metrics.
+//!
+//! With [`AnalysisRenderOptions::explain`](crate::analysis::AnalysisRenderOptions)
+//! set, the derived report follows each headline metric with a one-line
+//! reading such as `> Gini 0.30 — moderately uneven file-size distribution`.
+//! Each reading comes from fixed threshold bands, so the same value always
+//! gets the same note.
+
+use std::fmt::Write;
+
+use tokmd_analysis_types::PolyglotReport;
+
+/// Appends `> {metric} {value} — {meaning}` as its own paragraph.
+pub(super) fn note(out: &mut String, metric: &str, value: &str, meaning: &str) {
+    let _ = writeln!(out, "> {metric} {value} — {meaning}\n");
+}
+
+/// Reads the Gini coefficient of file sizes.
+pub(super) fn gini(value: f64) -> &'static str {
+    match value {
+        v if v < 0.2 => "files are close to the same size",
+        v if v < 0.4 => "moderately uneven file-size distribution",
+        v if v < 0.6 => "uneven file sizes; a minority of files hold most lines",
+        _ => "highly concentrated; a few files hold most lines",
+    }
+}
+
+/// Reads the comment share of code plus comments.
+pub(super) fn doc_density(ratio: f64) -> &'static str {
+    match ratio {
+        r if r < 0.05 => "sparsely commented",
+        r if r < 0.2 => "moderately commented",
+        r if r < 0.4 => "well commented",
+        _ => "comment-heavy; check for commented-out code",
+    }
+}
+
+/// Reads the test share of code lines.
+pub(super) fn test_ratio(ratio: f64) -> &'static str {
+    match ratio {
+        r if r < 0.1 => "little test code next to production code",
+        r if r < 0.4 => "a moderate share of test code",
+        _ => "test-heavy codebase",
+    }
+}
+
+/// Reads the infrastructure share of all lines.
+pub(super) fn infra_ratio(ratio: f64) -> &'static str {
+    match ratio {
+        r if r < 0.1 => "mostly application logic",
+        r if r < 0.3 => "a moderate amount of configuration and build files",
+        _ => "infrastructure-heavy; configuration rivals logic",
+    }
+}
+
+/// Reads language entropy relative to the most even mix of
+/// `lang_count` languages, so the bands hold for any number of languages.
+pub(super) fn polyglot(polyglot: &PolyglotReport) -> &'static str {
+    if polyglot.lang_count <= 1 || polyglot.entropy <= 0.0 {
+        return "single-language codebase";
+    }
+    match polyglot.entropy / (polyglot.lang_count as f64).log2() {
+        e if e < 0.4 => "one language dominates",
+        e if e < 0.75 => "a main language with significant secondary ones",
+        _ => "languages are evenly mixed",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn polyglot_report(lang_count: usize, entropy: f64) -> PolyglotReport {
+        PolyglotReport {
+            lang_count,
+            entropy,
+            dominant_lang: "Rust".to_string(),
+            dominant_lines: 100,
+            dominant_pct: 0.9,
+        }
+    }
+
+    #[test]
+    fn gini_bands_are_half_open() {
+        assert_eq!(gini(0.0), "files are close to the same size");
+        assert_eq!(gini(0.3), "moderately uneven file-size distribution");
+        assert_eq!(
+            gini(0.4),
+            "uneven file sizes; a minority of files hold most lines"
+        );
+        assert_eq!(
+            gini(0.95),
+            "highly concentrated; a few files hold most lines"
+        );
+    }
+
+    #[test]
+    fn polyglot_entropy_is_read_relative_to_language_count() {
+        assert_eq!(
+            polyglot(&polyglot_report(1, 0.0)),
+            "single-language codebase"
+        );
+        // 1.0 bit is an even two-language split but lopsided across eight.
+        assert_eq!(
+            polyglot(&polyglot_report(2, 1.0)),
+            "languages are evenly mixed"
+        );
+        assert_eq!(polyglot(&polyglot_report(8, 1.0)), "one language dominates");
+    }
+}
@@ -0,0 +1,18 @@
+//! Extensions Markdown rendering.
+//!
+//! This module owns the generic section for values attached by
+//! embedder-supplied metrics. tokmd knows nothing about their shape, so each
+//! key is shown as pretty-printed JSON.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use serde_json::Value;
+
+pub(super) fn render_extensions(out: &mut String, extensions: &BTreeMap<String, Value>) {
+    out.push_str("## Extensions\n\n");
+    for (name, value) in extensions {
+        let json = serde_json::to_string_pretty(value).unwrap_or_else(|_| value.to_string());
+        let _ = writeln!(out, "### {}\n\n```json\n{}\n```\n", name, json);
+    }
+}
@@ -5,10 +5,16 @@
 
 use std::fmt::Write;
 
-use super::{fmt_f64, fmt_pct};
+use super::{DecimalMetric, NumberFormat};
+use crate::StatusStyle;
 use tokmd_analysis_types::GitReport;
 
-pub(super) fn render_git_report(out: &mut String, git: &GitReport) {
+pub(super) fn render_git_report(
+    out: &mut String,
+    git: &GitReport,
+    nf: &NumberFormat,
+    status: &StatusStyle,
+) {
     out.push_str("## Git metrics\n\n");
     let _ = writeln!(
         out,
@@ -44,7 +50,7 @@ pub(super) fn render_git_report(out: &mut String, git: &GitReport) {
         git.freshness.threshold_days,
         git.freshness.stale_files,
         git.freshness.total_files,
-        fmt_pct(git.freshness.stale_pct)
+        nf.pct(git.freshness.stale_pct)
     );
     if !git.freshness.by_module.is_empty() {
         out.push_str("|Module|Avg days|P90 days|Stale%|\n");
@@ -54,9 +60,9 @@ pub(super) fn render_git_report(out: &mut String, git: &GitReport) {
                 out,
                 "|{}|{}|{}|{}|",
                 row.module,
-                fmt_f64(row.avg_days, 2),
-                fmt_f64(row.p90_days, 2),
-                fmt_pct(row.stale_pct)
+                nf.metric(DecimalMetric::AgeDays, row.avg_days),
+                nf.metric(DecimalMetric::AgeDays, row.p90_days),
+                nf.pct(row.stale_pct)
             );
         }
         out.push('\n');
@@ -65,8 +71,10 @@ pub(super) fn render_git_report(out: &mut String, git: &GitReport) {
         out.push_str("### Code age\n\n");
         let _ = writeln!(
             out,
-            "- Refresh trend: `{:?}` (recent: `{}`, prior: `{}`)\n",
-            age.refresh_trend, age.recent_refreshes, age.prior_refreshes
+            "- Refresh trend: `{}` (recent: `{}`, prior: `{}`)\n",
+            status.label(age.refresh_trend, format_args!("{:?}", age.refresh_trend)),
+            age.recent_refreshes,
+            age.prior_refreshes
         );
         if !age.buckets.is_empty() {
             out.push_str("|Bucket|Min days|Max days|Files|Pct|\n");
@@ -83,7 +91,7 @@ pub(super) fn render_git_report(out: &mut String, git: &GitReport) {
                     bucket.min_days,
                     max,
                     bucket.files,
-                    fmt_pct(bucket.pct)
+                    nf.pct(bucket.pct)
                 );
             }
             out.push('\n');
@@ -100,11 +108,11 @@ pub(super) fn render_git_report(out: &mut String, git: &GitReport) {
             for row in filtered.iter().take(10) {
                 let jaccard = row
                     .jaccard
-                    .map(|v| fmt_f64(v, 4))
+                    .map(|v| nf.metric(DecimalMetric::Coupling, v))
                     .unwrap_or_else(|| "-".to_string());
                 let lift = row
                     .lift
-                    .map(|v| fmt_f64(v, 4))
+                    .map(|v| nf.metric(DecimalMetric::Coupling, v))
                     .unwrap_or_else(|| "-".to_string());
                 let _ = writeln!(
                     out,
@@ -141,12 +149,12 @@ pub(super) fn render_git_report(out: &mut String, git: &GitReport) {
             }
         }
         let _ = writeln!(out, "|**total**|{}|", o.total);
-        let _ = writeln!(out, "\n- Unknown: `{}`", fmt_pct(intent.unknown_pct));
+        let _ = writeln!(out, "\n- Unknown: `{}`", nf.pct(intent.unknown_pct));
         if let Some(cr) = intent.corrective_ratio {
             let _ = writeln!(
                 out,
                 "- Corrective ratio (fix+revert/total): `{}`",
-                fmt_pct(cr)
+                nf.pct(cr)
             );
         }
         out.push('\n');
@@ -164,8 +172,7 @@ pub(super) fn render_git_report(out: &mut String, git: &GitReport) {
             .filter(|(_, share)| *share > 0.0)
             .collect();
         maintenance.sort_by(|a, b| {
-            b.1.partial_cmp(&a.1)
-                .unwrap_or(std::cmp::Ordering::Equal)
+            b.1.total_cmp(&a.1)
                 .then_with(|| a.0.module.cmp(&b.0.module))
         });
 
@@ -180,7 +187,7 @@ pub(super) fn render_git_report(out: &mut String, git: &GitReport) {
                     m.module,
                     m.counts.fix + m.counts.revert,
                     m.counts.total,
-                    fmt_pct(*share)
+                    nf.pct(*share)
                 );
             }
             out.push('\n');
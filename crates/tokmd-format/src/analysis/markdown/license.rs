@@ -5,10 +5,12 @@
 
 use std::fmt::Write;
 
-use super::fmt_f64;
-use tokmd_analysis_types::LicenseReport;
+use super::{DecimalMetric, NumberFormat};
+use tokmd_analysis_types::{LicenseHeaderCompliance, LicenseReport};
 
-pub(super) fn render_license_report(out: &mut String, license: &LicenseReport) {
+const MAX_MISSING_ROWS: usize = 20;
+
+pub(super) fn render_license_report(out: &mut String, license: &LicenseReport, nf: &NumberFormat) {
     out.push_str("## License radar\n\n");
     if let Some(effective) = &license.effective {
         let _ = writeln!(out, "- Effective: `{}`", effective);
@@ -22,11 +24,52 @@ pub(super) fn render_license_report(out: &mut String, license: &LicenseReport) {
                 out,
                 "|{}|{}|{}|{:?}|",
                 row.spdx,
-                fmt_f64(row.confidence as f64, 2),
+                nf.metric(DecimalMetric::LicenseConfidence, row.confidence as f64),
                 row.source_path,
                 row.source_kind
             );
         }
         out.push('\n');
     }
+    if let Some(headers) = &license.header_compliance {
+        render_header_compliance(out, headers, nf);
+    }
+}
+
+fn render_header_compliance(
+    out: &mut String,
+    headers: &LicenseHeaderCompliance,
+    nf: &NumberFormat,
+) {
+    out.push_str("### License header compliance\n\n");
+    let expected: Vec<&str> = headers
+        .expected
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect();
+    let _ = writeln!(out, "- Expected: `{}`", expected.join(" / "));
+    let _ = writeln!(
+        out,
+        "- Compliant: {} of {} source files ({})",
+        nf.count(headers.compliant),
+        nf.count(headers.checked),
+        nf.pct(headers.ratio)
+    );
+    out.push('\n');
+    if !headers.missing.is_empty() {
+        out.push_str("|Missing header|\n");
+        out.push_str("|---|\n");
+        for path in headers.missing.iter().take(MAX_MISSING_ROWS) {
+            let _ = writeln!(out, "|{}|", path);
+        }
+        if headers.missing.len() > MAX_MISSING_ROWS {
+            let _ = writeln!(
+                out,
+                "|… {} more|",
+                nf.count(headers.missing.len() - MAX_MISSING_ROWS)
+            );
+        }
+        out.push('\n');
+    }
 }
@@ -0,0 +1,31 @@
+//! Line-ending Markdown rendering.
+//!
+//! This module owns the LF/CRLF summary and mixed-file table for analysis
+//! Markdown output.
+
+use std::fmt::Write;
+
+use tokmd_analysis_types::LineEndingReport;
+
+pub(super) fn render_line_ending_report(out: &mut String, report: &LineEndingReport) {
+    out.push_str("## Line endings\n\n");
+    let _ = writeln!(
+        out,
+        "- LF: {}, CRLF: {}, mixed: {}, no line break: {}\n",
+        report.lf_files, report.crlf_files, report.mixed_files, report.no_line_break_files
+    );
+    if report.mixed.is_empty() {
+        out.push_str("- No files mix LF and CRLF.\n\n");
+    } else {
+        out.push_str("|Path|Module|LF lines|CRLF lines|\n");
+        out.push_str("|---|---|---:|---:|\n");
+        for row in &report.mixed {
+            let _ = writeln!(
+                out,
+                "|{}|{}|{}|{}|",
+                row.path, row.module, row.lf_lines, row.crlf_lines
+            );
+        }
+        out.push('\n');
+    }
+}
@@ -4,33 +4,66 @@
 
 use std::fmt::Write;
 
-use super::fmt_f64;
+use super::{DecimalMetric, NumberFormat};
+use crate::StatusStyle;
 use tokmd_analysis_types::PredictiveChurnReport;
 
-pub(super) fn render_predictive_churn(out: &mut String, churn: &PredictiveChurnReport) {
+pub(super) fn render_predictive_churn(
+    out: &mut String,
+    churn: &PredictiveChurnReport,
+    nf: &NumberFormat,
+    status: &StatusStyle,
+) {
     out.push_str("## Predictive churn\n\n");
     let mut rows: Vec<_> = churn.per_module.iter().collect();
-    rows.sort_by(|a, b| {
-        b.1.slope
-            .partial_cmp(&a.1.slope)
-            .unwrap_or(std::cmp::Ordering::Equal)
-            .then_with(|| a.0.cmp(b.0))
-    });
+    rows.sort_by(|a, b| b.1.slope.total_cmp(&a.1.slope).then_with(|| a.0.cmp(b.0)));
     if rows.is_empty() {
         out.push_str("- No churn signals detected.\n\n");
     } else {
-        out.push_str("|Module|Slope|R²|Recent change|Class|\n");
-        out.push_str("|---|---:|---:|---:|---|\n");
+        match churn.forecast_days {
+            Some(days) => {
+                let _ = writeln!(
+                    out,
+                    "|Module|Slope|R²|Recent change|Class|Next {days}d|Range|"
+                );
+                out.push_str("|---|---:|---:|---:|---|---:|---:|\n");
+            }
+            None => {
+                out.push_str("|Module|Slope|R²|Recent change|Class|\n");
+                out.push_str("|---|---:|---:|---:|---|\n");
+            }
+        }
         for (module, trend) in rows.into_iter().take(10) {
-            let _ = writeln!(
+            let _ = write!(
                 out,
-                "|{}|{}|{}|{}|{:?}|",
+                "|{}|{}|{}|{}|{}|",
                 module,
-                fmt_f64(trend.slope, 4),
-                fmt_f64(trend.r2, 2),
+                nf.metric(DecimalMetric::ChurnSlope, trend.slope),
+                nf.metric(DecimalMetric::ChurnR2, trend.r2),
                 trend.recent_change,
-                trend.classification
+                status.label(
+                    trend.classification,
+                    format_args!("{:?}", trend.classification)
+                )
             );
+            if churn.forecast_days.is_some() {
+                let projected = trend.projected_change.map_or_else(
+                    || "-".to_string(),
+                    |v| nf.metric(DecimalMetric::ChurnForecast, v),
+                );
+                let range = trend.projected_interval.map_or_else(
+                    || "-".to_string(),
+                    |band| {
+                        format!(
+                            "{}–{}",
+                            nf.metric(DecimalMetric::ChurnForecast, band.low),
+                            nf.metric(DecimalMetric::ChurnForecast, band.high)
+                        )
+                    },
+                );
+                let _ = write!(out, "{projected}|{range}|");
+            }
+            out.push('\n');
         }
         out.push('\n');
     }
@@ -0,0 +1,36 @@
+//! Risk surface Markdown rendering.
+//!
+//! This module owns the ranked table of files where complexity, churn, and
+//! coverage gaps combine.
+
+use std::fmt::Write;
+
+use super::{DecimalMetric, NumberFormat};
+use tokmd_analysis_types::RiskSurfaceRow;
+
+/// Rows shown in Markdown; the receipt keeps the full ranking.
+const MAX_ROWS: usize = 20;
+
+pub(super) fn render_risk_surface(out: &mut String, rows: &[RiskSurfaceRow], nf: &NumberFormat) {
+    out.push_str("## Risk surface\n\n");
+    out.push_str("|Rank|File|Score|Cyclomatic|Commits|Coverage|\n");
+    out.push_str("|---:|---|---:|---:|---:|---:|\n");
+    for (rank, row) in rows.iter().take(MAX_ROWS).enumerate() {
+        let _ = writeln!(
+            out,
+            "|{}|{}|{}|{}|{}|{}|",
+            rank + 1,
+            row.path,
+            nf.metric(DecimalMetric::RiskScore, row.score),
+            row.cyclomatic
+                .map_or_else(|| "-".to_string(), |v| nf.integer(v)),
+            row.commits
+                .map_or_else(|| "-".to_string(), |v| nf.integer(v)),
+            row.coverage.map_or_else(|| "-".to_string(), |v| nf.pct(v)),
+        );
+    }
+    if rows.len() > MAX_ROWS {
+        let _ = writeln!(out, "||… {} more|", nf.count(rows.len() - MAX_ROWS));
+    }
+    out.push('\n');
+}
@@ -0,0 +1,35 @@
+//! Sampling Markdown rendering.
+//!
+//! This module owns the notice and extrapolated totals shown when analysis
+//! ran on a sample of files.
+
+use std::fmt::Write;
+
+use tokmd_analysis_types::SamplingReport;
+
+use super::NumberFormat;
+
+pub(super) fn render_sampling(out: &mut String, sampling: &SamplingReport, nf: &NumberFormat) {
+    out.push_str("## Sampling\n\n");
+    let _ = writeln!(
+        out,
+        "Sampled {} of files ({} of {}); every other section describes only the sample.\n",
+        nf.pct(sampling.rate),
+        sampling.sampled_files,
+        sampling.total_files
+    );
+    let totals = &sampling.estimated_totals;
+    out.push_str("|Estimated|Files|Code|Comments|Blanks|Lines|Bytes|Tokens|\n");
+    out.push_str("|---|---:|---:|---:|---:|---:|---:|---:|\n");
+    let _ = writeln!(
+        out,
+        "|~|{}|{}|{}|{}|{}|{}|{}|\n",
+        totals.files,
+        totals.code,
+        totals.comments,
+        totals.blanks,
+        totals.lines,
+        totals.bytes,
+        totals.tokens
+    );
+}
@@ -0,0 +1,18 @@
+//! Phase timing Markdown rendering.
+//!
+//! This module owns the per-phase wall-clock table recorded when analysis
+//! runs with timings enabled.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+pub(super) fn render_timings(out: &mut String, timings: &BTreeMap<String, u64>) {
+    out.push_str("## Timings\n\n");
+    out.push_str("|Phase|ms|\n");
+    out.push_str("|---|---:|\n");
+    for (phase, ms) in timings {
+        let _ = writeln!(out, "|{}|{}|", phase, ms);
+    }
+    let _ = writeln!(out, "|**Total**|{}|", timings.values().sum::<u64>());
+    out.push('\n');
+}
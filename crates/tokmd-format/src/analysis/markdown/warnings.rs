@@ -0,0 +1,23 @@
+//! Warnings Markdown rendering.
+//!
+//! This module owns the list of non-fatal problems recorded while the
+//! receipt was produced. Each entry shows the severity and stable code, then
+//! the warning's plain-text form.
+
+use std::fmt::Write;
+
+use tokmd_types::Warning;
+
+pub(super) fn render_warnings(out: &mut String, warnings: &[Warning]) {
+    out.push_str("## Warnings\n\n");
+    for warning in warnings {
+        let _ = writeln!(
+            out,
+            "- **{}** `{}`: {}",
+            warning.severity.as_str(),
+            warning.code,
+            warning
+        );
+    }
+    out.push('\n');
+}
@@ -1,12 +1,16 @@
 //! Mermaid rendering for analysis receipts.
 //!
-//! This module owns the small graph projection used by
-//! `AnalysisFormat::Mermaid`; analysis computation and import discovery stay
-//! in the analysis crates.
+//! This module owns the small graph projections used by
+//! `AnalysisFormat::Mermaid` (import flow) and `AnalysisFormat::Gitgraph`
+//! (recent commits by intent); analysis computation, import discovery, and
+//! intent classification stay in the analysis crates.
 
 use std::fmt::Write;
 
-use tokmd_analysis_types::AnalysisReceipt;
+use tokmd_analysis_types::{AnalysisReceipt, CommitIntentKind};
+
+/// Subject characters kept in a gitGraph commit label.
+const GITGRAPH_SUBJECT_CHARS: usize = 48;
 
 pub(super) fn render(receipt: &AnalysisReceipt) -> String {
     let mut out = String::from("graph TD\n");
@@ -20,6 +24,61 @@ pub(super) fn render(receipt: &AnalysisReceipt) -> String {
     out
 }
 
+/// Mermaid `gitGraph` of the receipt's recent commits, oldest first.
+///
+/// Each commit is tagged with its intent; features are highlighted and
+/// reverts drawn reversed. Empty when the receipt has no git intent data.
+pub(super) fn render_gitgraph(receipt: &AnalysisReceipt) -> String {
+    let mut out = String::from("gitGraph\n");
+    let recent = receipt
+        .git
+        .as_ref()
+        .and_then(|git| git.intent.as_ref())
+        .map(|intent| intent.recent.as_slice())
+        .unwrap_or_default();
+    for (idx, commit) in recent.iter().rev().enumerate() {
+        let short = commit
+            .hash
+            .as_deref()
+            .map(|hash| hash.chars().take(7).collect::<String>())
+            .unwrap_or_else(|| format!("c{}", idx + 1));
+        let subject: String = commit
+            .subject
+            .chars()
+            .take(GITGRAPH_SUBJECT_CHARS)
+            .map(|c| if c == '"' { '\'' } else { c })
+            .collect();
+        let kind = match commit.intent {
+            CommitIntentKind::Feat => "HIGHLIGHT",
+            CommitIntentKind::Revert => "REVERSE",
+            _ => "NORMAL",
+        };
+        let _ = writeln!(
+            out,
+            "  commit id: \"{short} {subject}\" tag: \"{}\" type: {kind}",
+            intent_label(commit.intent)
+        );
+    }
+    out
+}
+
+fn intent_label(kind: CommitIntentKind) -> &'static str {
+    match kind {
+        CommitIntentKind::Feat => "feat",
+        CommitIntentKind::Fix => "fix",
+        CommitIntentKind::Refactor => "refactor",
+        CommitIntentKind::Docs => "docs",
+        CommitIntentKind::Test => "test",
+        CommitIntentKind::Chore => "chore",
+        CommitIntentKind::Ci => "ci",
+        CommitIntentKind::Build => "build",
+        CommitIntentKind::Perf => "perf",
+        CommitIntentKind::Style => "style",
+        CommitIntentKind::Revert => "revert",
+        CommitIntentKind::Other => "other",
+    }
+}
+
 fn sanitize_node_name(name: &str) -> String {
     name.chars()
         .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
@@ -2,7 +2,7 @@
 //!
 //! This module owns analysis-specific formatting under the durable
 //! `tokmd-format` capability crate. It supports Markdown, JSON, JSON-LD, XML,
-//! SVG, Mermaid, HTML, and optional fun outputs.
+//! CSV, SVG (badge and scorecard), Mermaid, HTML, and optional fun outputs.
 //!
 //! ## Effort rendering
 //!
@@ -29,43 +29,185 @@
 //! * CLI argument parsing
 //! * Analysis computation (use tokmd-analysis)
 
+use std::collections::BTreeMap;
+
 use anyhow::Result;
 use tokmd_analysis_types::AnalysisReceipt;
 use tokmd_types::AnalysisFormat;
 
+mod api_items;
+mod api_surface_diff;
+mod csv;
 mod fun_outputs;
 pub mod html;
 mod jsonld;
 mod markdown;
 mod mermaid;
+mod number_format;
+mod precision;
+mod scorecard;
+mod section;
 mod svg;
 mod tree;
 mod xml;
 
+pub use api_items::write_api_items_jsonl;
+pub use api_surface_diff::{diff_api_surface, render_api_surface_diff_md};
+pub use html::TreemapMetric;
+pub use number_format::{NumberFormat, SuffixStyle, fixed};
+pub use precision::{DecimalMetric, MetricPrecision};
+pub use section::ReportSection;
+pub use svg::SvgBadgeMetric;
+
+use crate::{StatusStyle, TimestampFormat, TimestampStyle};
+
+/// Presentation settings for human-readable analysis reports.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AnalysisRenderOptions {
+    /// Number formatting for Markdown and HTML.
+    pub numbers: NumberFormat,
+    /// Timezone and layout of the HTML report timestamp.
+    pub timestamps: TimestampFormat,
+    /// Labels for risk and trend values in Markdown.
+    pub status: StatusStyle,
+    /// Follow derived metrics in Markdown with a one-line reading, e.g.
+    /// `> Gini 0.30 — moderately uneven file-size distribution`. Off by
+    /// default.
+    pub explain: bool,
+    /// Embed the full receipt JSON in the HTML report as
+    /// `<script type="application/json" id="tokmd-receipt">` so it can be
+    /// extracted later. Off by default to keep reports small.
+    pub embed_receipt: bool,
+    /// Apply the HTML report's print stylesheet on screen as well, for
+    /// previewing or printing from tools that ignore `@media print`. Off by
+    /// default.
+    pub print_layout: bool,
+    /// Most files listed in the HTML report's table and treemap; `Some(0)`
+    /// lists all of them. `None` uses the default of 100.
+    pub html_max_rows: Option<usize>,
+    /// Per-file count that sizes the HTML treemap's cells. Defaults to lines.
+    pub treemap_metric: TreemapMetric,
+    /// Language name → CSS color (`#rgb`, `#rrggbb`, `#rrggbbaa`, or a named
+    /// color) for HTML treemap cells and language badges. Other colors are
+    /// ignored; unlisted languages keep the built-in palette.
+    pub lang_colors: BTreeMap<String, String>,
+    /// Value shown on the `svg` badge. Defaults to context-window fit, which
+    /// falls back to tokens when the receipt has no context window.
+    pub svg_badge_metric: SvgBadgeMetric,
+}
+
+/// Every analysis format, in declaration order.
+const ALL_FORMATS: [AnalysisFormat; 13] = [
+    AnalysisFormat::Md,
+    AnalysisFormat::Json,
+    AnalysisFormat::Jsonld,
+    AnalysisFormat::Xml,
+    AnalysisFormat::Csv,
+    AnalysisFormat::Svg,
+    AnalysisFormat::Scorecard,
+    AnalysisFormat::Mermaid,
+    AnalysisFormat::Gitgraph,
+    AnalysisFormat::Obj,
+    AnalysisFormat::Midi,
+    AnalysisFormat::Tree,
+    AnalysisFormat::Html,
+];
+
+/// Analysis formats [`render`] supports in this build.
+///
+/// OBJ and MIDI need the `fun` feature and are omitted without it, so
+/// bindings and UIs can hide options that would only return an error.
+pub fn available_formats() -> Vec<AnalysisFormat> {
+    ALL_FORMATS
+        .into_iter()
+        .filter(|format| {
+            cfg!(feature = "fun") || !matches!(format, AnalysisFormat::Obj | AnalysisFormat::Midi)
+        })
+        .collect()
+}
+
 pub enum RenderedOutput {
     Text(String),
     Binary(Vec<u8>),
 }
 
 pub fn render(receipt: &AnalysisReceipt, format: AnalysisFormat) -> Result<RenderedOutput> {
+    render_with(receipt, format, &AnalysisRenderOptions::default())
+}
+
+/// [`render`] with Markdown and HTML presentation, and the SVG badge's
+/// metric, controlled by `options`.
+///
+/// Machine-readable formats ignore `options`.
+pub fn render_with(
+    receipt: &AnalysisReceipt,
+    format: AnalysisFormat,
+    options: &AnalysisRenderOptions,
+) -> Result<RenderedOutput> {
     match format {
-        AnalysisFormat::Md => Ok(RenderedOutput::Text(render_md(receipt))),
+        AnalysisFormat::Md => Ok(RenderedOutput::Text(markdown::render_md(receipt, options))),
         AnalysisFormat::Json => Ok(RenderedOutput::Text(serde_json::to_string_pretty(receipt)?)),
         AnalysisFormat::Jsonld => Ok(RenderedOutput::Text(jsonld::render(receipt))),
         AnalysisFormat::Xml => Ok(RenderedOutput::Text(xml::render(receipt))),
-        AnalysisFormat::Svg => Ok(RenderedOutput::Text(svg::render(receipt))),
+        AnalysisFormat::Csv => Ok(RenderedOutput::Text(csv::render(receipt)?)),
+        AnalysisFormat::Svg => Ok(RenderedOutput::Text(svg::render(
+            receipt,
+            options.svg_badge_metric,
+            &options.numbers.precision,
+        ))),
+        AnalysisFormat::Scorecard => Ok(RenderedOutput::Text(scorecard::render(receipt))),
         AnalysisFormat::Mermaid => Ok(RenderedOutput::Text(mermaid::render(receipt))),
+        AnalysisFormat::Gitgraph => Ok(RenderedOutput::Text(mermaid::render_gitgraph(receipt))),
         AnalysisFormat::Obj => Ok(RenderedOutput::Text(fun_outputs::render_obj(receipt)?)),
         AnalysisFormat::Midi => Ok(RenderedOutput::Binary(fun_outputs::render_midi(receipt)?)),
         AnalysisFormat::Tree => Ok(RenderedOutput::Text(tree::render(receipt))),
-        AnalysisFormat::Html => Ok(RenderedOutput::Text(render_html(receipt))),
+        AnalysisFormat::Html => Ok(RenderedOutput::Text(html::render_with(receipt, options))),
     }
 }
 
+/// Render one [`ReportSection`] as a standalone fragment, exactly as it
+/// appears in the full report but without the document header or any other
+/// section.
+///
+/// Returns `None` when the receipt has nothing for the section, or when
+/// `format` has no per-section form. Markdown covers every section; HTML
+/// covers [`ReportSection::Totals`] (metric cards) and
+/// [`ReportSection::TopOffenders`] (file table).
+pub fn render_section(
+    receipt: &AnalysisReceipt,
+    section: ReportSection,
+    format: AnalysisFormat,
+) -> Option<RenderedOutput> {
+    render_section_with(receipt, section, format, &AnalysisRenderOptions::default())
+}
+
+/// [`render_section`] with presentation controlled by `options`.
+pub fn render_section_with(
+    receipt: &AnalysisReceipt,
+    section: ReportSection,
+    format: AnalysisFormat,
+    options: &AnalysisRenderOptions,
+) -> Option<RenderedOutput> {
+    let fragment = match format {
+        AnalysisFormat::Md => markdown::render_fragment(receipt, section, options),
+        AnalysisFormat::Html => html::render_fragment(receipt, section, options),
+        _ => None,
+    };
+    fragment.map(RenderedOutput::Text)
+}
+
+/// SVG doc-density badge, green when `derived.doc_density.total.ratio` meets
+/// `target_pct` percent and red below it.
+pub fn render_doc_density_svg(receipt: &AnalysisReceipt, target_pct: f64) -> String {
+    svg::render_doc_density(receipt, target_pct)
+}
+
+#[cfg(test)]
 fn render_md(receipt: &AnalysisReceipt) -> String {
-    markdown::render_md(receipt)
+    markdown::render_md(receipt, &AnalysisRenderOptions::default())
 }
 
+#[cfg(test)]
 fn render_html(receipt: &AnalysisReceipt) -> String {
     html::render(receipt)
 }
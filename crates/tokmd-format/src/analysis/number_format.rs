@@ -0,0 +1,311 @@
+//! Number formatting for human-readable analysis reports.
+//!
+//! The default reproduces tokmd's long-standing output: `.` decimals, no
+//! thousands grouping, and `K`/`M` suffixes for large HTML counts. Setting a
+//! group separator or a `,` decimal mark adapts Markdown and HTML reports
+//! for non-English readers; machine formats (JSON, XML, ...) are unaffected.
+//!
+//! Fixed-point values go through [`fixed`], which rounds the shortest decimal
+//! form of the value half-to-even instead of trusting `{:.N}`, so a tie such
+//! as `2.675` renders the same on every platform.
+
+use super::precision::{DecimalMetric, MetricPrecision};
+
+/// How large counts are abbreviated.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SuffixStyle {
+    /// `1.5K`, `2.5M`.
+    #[default]
+    Short,
+    /// Every digit, e.g. `1500` (grouped when a separator is set).
+    Full,
+}
+
+/// Separators, suffix style, and metric precision used when rendering
+/// numbers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NumberFormat {
+    /// Thousands separator; `None` disables grouping.
+    pub group_separator: Option<char>,
+    /// Decimal mark.
+    pub decimal_separator: char,
+    /// Abbreviation style for counts.
+    pub suffix: SuffixStyle,
+    /// Decimals shown per metric by [`NumberFormat::metric`] and
+    /// [`NumberFormat::pct`].
+    pub precision: MetricPrecision,
+}
+
+impl Default for NumberFormat {
+    fn default() -> Self {
+        Self {
+            group_separator: None,
+            decimal_separator: '.',
+            suffix: SuffixStyle::Short,
+            precision: MetricPrecision::default(),
+        }
+    }
+}
+
+impl NumberFormat {
+    /// A count, abbreviated per [`SuffixStyle`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tokmd_format::analysis::{NumberFormat, SuffixStyle};
+    ///
+    /// assert_eq!(NumberFormat::default().count(1_500), "1.5K");
+    ///
+    /// let full = NumberFormat {
+    ///     group_separator: Some(','),
+    ///     suffix: SuffixStyle::Full,
+    ///     ..NumberFormat::default()
+    /// };
+    /// assert_eq!(full.count(1_234_567), "1,234,567");
+    /// ```
+    pub fn count(&self, n: usize) -> String {
+        match self.suffix {
+            SuffixStyle::Short if n >= 1_000_000 => {
+                format!("{}M", self.decimal(n as f64 / 1_000_000.0, 1))
+            }
+            SuffixStyle::Short if n >= 1_000 => {
+                format!("{}K", self.decimal(n as f64 / 1_000.0, 1))
+            }
+            _ => self.integer(n),
+        }
+    }
+
+    /// An integer with every digit, grouped when a separator is set.
+    pub fn integer(&self, n: usize) -> String {
+        self.localize(n.to_string())
+    }
+
+    /// A fixed-point value with `decimals` fractional digits.
+    pub fn decimal(&self, value: f64, decimals: usize) -> String {
+        self.localize(fixed(value, decimals))
+    }
+
+    /// `value` with the decimals configured for `metric`.
+    pub fn metric(&self, metric: DecimalMetric, value: f64) -> String {
+        self.decimal(value, self.precision.decimals(metric))
+    }
+
+    /// A ratio in `[0, 1]` as a percentage, e.g. `45.6%`, with one decimal
+    /// unless [`DecimalMetric::Percent`] is overridden.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tokmd_format::analysis::NumberFormat;
+    ///
+    /// let comma = NumberFormat {
+    ///     decimal_separator: ',',
+    ///     ..NumberFormat::default()
+    /// };
+    /// assert_eq!(comma.pct(0.123), "12,3%");
+    /// ```
+    pub fn pct(&self, ratio: f64) -> String {
+        format!("{}%", self.metric(DecimalMetric::Percent, ratio * 100.0))
+    }
+
+    /// Apply grouping and the decimal mark to Rust's `.`-decimal rendering.
+    fn localize(&self, plain: String) -> String {
+        if self.group_separator.is_none() && self.decimal_separator == '.' {
+            return plain;
+        }
+        let (sign, unsigned) = match plain.strip_prefix('-') {
+            Some(rest) => ("-", rest),
+            None => ("", plain.as_str()),
+        };
+        let (int_part, frac_part) = match unsigned.split_once('.') {
+            Some((int_part, frac_part)) => (int_part, Some(frac_part)),
+            None => (unsigned, None),
+        };
+        // NaN / inf have no digits to group.
+        if !int_part.bytes().all(|b| b.is_ascii_digit()) {
+            return plain;
+        }
+
+        let mut out = String::with_capacity(plain.len() + int_part.len() / 3);
+        out.push_str(sign);
+        for (idx, digit) in int_part.chars().enumerate() {
+            if let Some(sep) = self.group_separator
+                && idx > 0
+                && (int_part.len() - idx) % 3 == 0
+            {
+                out.push(sep);
+            }
+            out.push(digit);
+        }
+        if let Some(frac_part) = frac_part {
+            out.push(self.decimal_separator);
+            out.push_str(frac_part);
+        }
+        out
+    }
+}
+
+/// `value` with exactly `decimals` fractional digits, rounded half-to-even.
+///
+/// Rounding works on the shortest string that round-trips to `value` (what
+/// `{}` prints), so it is pure digit arithmetic: `2.675` becomes `2.68` and
+/// `2.665` becomes `2.66` regardless of how either is stored in binary.
+/// Non-finite values print as `{}` does.
+///
+/// # Examples
+///
+/// ```
+/// use tokmd_format::analysis::fixed;
+///
+/// assert_eq!(fixed(2.675, 2), "2.68");
+/// assert_eq!(fixed(6.45, 1), "6.4");
+/// assert_eq!(fixed(3.0, 1), "3.0");
+/// ```
+pub fn fixed(value: f64, decimals: usize) -> String {
+    if !value.is_finite() {
+        return value.to_string();
+    }
+    let shortest = value.abs().to_string();
+    let (int_part, frac_part) = shortest.split_once('.').unwrap_or((&shortest, ""));
+
+    let mut digits: Vec<u8> = int_part.bytes().map(|b| b - b'0').collect();
+    let mut frac = frac_part.bytes().map(|b| b - b'0');
+    digits.extend(frac.by_ref().take(decimals));
+    digits.resize(int_part.len() + decimals, 0);
+
+    let rest: Vec<u8> = frac.collect();
+    let round_up = match rest.split_first() {
+        // An exact tie goes to the even neighbour.
+        Some((5, tail)) if tail.iter().all(|&d| d == 0) => {
+            digits.last().is_some_and(|d| d % 2 == 1)
+        }
+        Some((&first, _)) => first >= 5,
+        None => false,
+    };
+    if round_up {
+        let mut carry = true;
+        for digit in digits.iter_mut().rev() {
+            if *digit == 9 {
+                *digit = 0;
+            } else {
+                *digit += 1;
+                carry = false;
+                break;
+            }
+        }
+        if carry {
+            digits.insert(0, 1);
+        }
+    }
+
+    let split = digits
+        .len()
+        .checked_sub(decimals)
+        .and_then(|int_len| digits.split_at_checked(int_len));
+    let Some((int_digits, frac_digits)) = split else {
+        return format!("{value:.decimals$}");
+    };
+    let mut out = String::with_capacity(digits.len() + 2);
+    if value.is_sign_negative() {
+        out.push('-');
+    }
+    out.extend(int_digits.iter().map(|d| char::from(b'0' + d)));
+    if decimals > 0 {
+        out.push('.');
+        out.extend(frac_digits.iter().map(|d| char::from(b'0' + d)));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn grouped() -> NumberFormat {
+        NumberFormat {
+            group_separator: Some(','),
+            suffix: SuffixStyle::Full,
+            ..NumberFormat::default()
+        }
+    }
+
+    #[test]
+    fn default_matches_legacy_output() {
+        let nf = NumberFormat::default();
+        assert_eq!(nf.count(500), "500");
+        assert_eq!(nf.count(1_500), "1.5K");
+        assert_eq!(nf.count(2_500_000), "2.5M");
+        assert_eq!(nf.integer(1_234_567), "1234567");
+        assert_eq!(nf.decimal(1234.5678, 2), "1234.57");
+        assert_eq!(nf.pct(0.456), "45.6%");
+    }
+
+    #[test]
+    fn grouping_separates_thousands() {
+        let nf = grouped();
+        assert_eq!(nf.count(1_234_567), "1,234,567");
+        assert_eq!(nf.count(999), "999");
+        assert_eq!(nf.integer(1_000), "1,000");
+        assert_eq!(nf.decimal(-1234567.25, 1), "-1,234,567.2");
+    }
+
+    #[test]
+    fn comma_decimal_locale() {
+        let nf = NumberFormat {
+            group_separator: Some('.'),
+            decimal_separator: ',',
+            suffix: SuffixStyle::Short,
+            ..NumberFormat::default()
+        };
+        assert_eq!(nf.pct(0.123), "12,3%");
+        assert_eq!(nf.count(1_500), "1,5K");
+        assert_eq!(nf.decimal(12345.5, 1), "12.345,5");
+    }
+
+    #[test]
+    fn ties_round_half_to_even_on_the_decimal_value() {
+        assert_eq!(fixed(2.675, 2), "2.68");
+        assert_eq!(fixed(2.665, 2), "2.66");
+        assert_eq!(fixed(1.005, 2), "1.00");
+        assert_eq!(fixed(0.125, 2), "0.12");
+        assert_eq!(fixed(0.375, 2), "0.38");
+        assert_eq!(fixed(2.5, 0), "2");
+        assert_eq!(fixed(3.5, 0), "4");
+        assert_eq!(fixed(-2.675, 2), "-2.68");
+    }
+
+    #[test]
+    fn rounding_carries_and_pads() {
+        assert_eq!(fixed(9.995, 2), "10.00");
+        assert_eq!(fixed(0.96, 1), "1.0");
+        assert_eq!(fixed(1.0, 4), "1.0000");
+        assert_eq!(fixed(0.0, 2), "0.00");
+        assert_eq!(fixed(0.123456, 4), "0.1235");
+        assert_eq!(fixed(1e21, 1), "1000000000000000000000.0");
+        assert_eq!(NumberFormat::default().decimal(2.675, 2), "2.68");
+        assert_eq!(NumberFormat::default().pct(0.00125), "0.1%");
+    }
+
+    proptest! {
+        #[test]
+        fn repeated_formats_agree(value in -1.0e9f64..1.0e9, decimals in 0usize..8) {
+            let first = fixed(value, decimals);
+            prop_assert_eq!(&first, &fixed(value, decimals));
+            // Formatting the printed value again is a no-op, and it is
+            // within half a unit of the last digit.
+            let shown: f64 = first.parse().unwrap();
+            prop_assert_eq!(&first, &fixed(shown, decimals));
+            let half_unit = 0.5 * 10f64.powi(-(decimals as i32));
+            prop_assert!((shown - value).abs() <= half_unit + value.abs() * 1e-15);
+        }
+    }
+
+    #[test]
+    fn non_finite_values_pass_through() {
+        let nf = grouped();
+        assert_eq!(nf.decimal(f64::NAN, 2), "NaN");
+        assert_eq!(nf.decimal(f64::INFINITY, 2), "inf");
+    }
+}
@@ -0,0 +1,232 @@
+//! Display precision for fixed-point metrics.
+//!
+//! Every non-integer value in the Markdown report belongs to a
+//! [`DecimalMetric`] with a built-in number of decimals. A [`MetricPrecision`]
+//! overrides some of them; the rest keep their default.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+/// A fixed-point metric whose displayed precision can be tuned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum DecimalMetric {
+    /// Percentages, e.g. doc density and ratios (`45.6%`).
+    Percent,
+    /// Gini coefficient of the file-size distribution.
+    Gini,
+    /// Mean, median, p90, and p99 of the file-size distribution.
+    Distribution,
+    /// Bytes per line, overall and by language.
+    Verbosity,
+    /// Average directory nesting.
+    Nesting,
+    /// TODO density per KLOC.
+    TodoDensity,
+    /// Language-mix entropy in the polyglot section.
+    Entropy,
+    /// Bits per byte of high-entropy files.
+    ByteEntropy,
+    /// Estimated reading time in minutes.
+    ReadingTime,
+    /// Bytes per line in top-offender tables.
+    BytesPerLine,
+    /// Average and p90 file age in days.
+    AgeDays,
+    /// Coupling Jaccard and lift.
+    Coupling,
+    /// Near-duplicate similarity threshold.
+    NearDupThreshold,
+    /// Risk-surface score.
+    RiskScore,
+    /// License detection confidence.
+    LicenseConfidence,
+    /// Predictive churn trend slope.
+    ChurnSlope,
+    /// Predictive churn trend R².
+    ChurnR2,
+    /// Predictive churn forecast and its band.
+    ChurnForecast,
+    /// KLOC in the effort size basis and legacy COCOMO.
+    Kloc,
+    /// Effort estimate results, driver weights, and delta figures.
+    Effort,
+    /// Legacy COCOMO results and coefficients.
+    Cocomo,
+    /// Eco-label score.
+    EcoScore,
+    /// Average function length.
+    FunctionLength,
+    /// Average cyclomatic, cognitive, and nesting complexity.
+    Complexity,
+}
+
+impl DecimalMetric {
+    /// Every metric, in declaration order.
+    pub const ALL: [DecimalMetric; 24] = [
+        Self::Percent,
+        Self::Gini,
+        Self::Distribution,
+        Self::Verbosity,
+        Self::Nesting,
+        Self::TodoDensity,
+        Self::Entropy,
+        Self::ByteEntropy,
+        Self::ReadingTime,
+        Self::BytesPerLine,
+        Self::AgeDays,
+        Self::Coupling,
+        Self::NearDupThreshold,
+        Self::RiskScore,
+        Self::LicenseConfidence,
+        Self::ChurnSlope,
+        Self::ChurnR2,
+        Self::ChurnForecast,
+        Self::Kloc,
+        Self::Effort,
+        Self::Cocomo,
+        Self::EcoScore,
+        Self::FunctionLength,
+        Self::Complexity,
+    ];
+
+    /// The metric's name, as accepted by [`FromStr`].
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Percent => "percent",
+            Self::Gini => "gini",
+            Self::Distribution => "distribution",
+            Self::Verbosity => "verbosity",
+            Self::Nesting => "nesting",
+            Self::TodoDensity => "todo_density",
+            Self::Entropy => "entropy",
+            Self::ByteEntropy => "byte_entropy",
+            Self::ReadingTime => "reading_time",
+            Self::BytesPerLine => "bytes_per_line",
+            Self::AgeDays => "age_days",
+            Self::Coupling => "coupling",
+            Self::NearDupThreshold => "near_dup_threshold",
+            Self::RiskScore => "risk_score",
+            Self::LicenseConfidence => "license_confidence",
+            Self::ChurnSlope => "churn_slope",
+            Self::ChurnR2 => "churn_r2",
+            Self::ChurnForecast => "churn_forecast",
+            Self::Kloc => "kloc",
+            Self::Effort => "effort",
+            Self::Cocomo => "cocomo",
+            Self::EcoScore => "eco_score",
+            Self::FunctionLength => "function_length",
+            Self::Complexity => "complexity",
+        }
+    }
+
+    /// Decimals shown when no override is set.
+    pub fn default_decimals(self) -> usize {
+        match self {
+            Self::Percent | Self::ChurnForecast | Self::EcoScore | Self::FunctionLength => 1,
+            Self::Gini
+            | Self::Entropy
+            | Self::Coupling
+            | Self::ChurnSlope
+            | Self::Kloc
+            | Self::Effort => 4,
+            Self::Distribution
+            | Self::Verbosity
+            | Self::Nesting
+            | Self::TodoDensity
+            | Self::ByteEntropy
+            | Self::ReadingTime
+            | Self::BytesPerLine
+            | Self::AgeDays
+            | Self::NearDupThreshold
+            | Self::RiskScore
+            | Self::LicenseConfidence
+            | Self::ChurnR2
+            | Self::Cocomo
+            | Self::Complexity => 2,
+        }
+    }
+}
+
+impl fmt::Display for DecimalMetric {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for DecimalMetric {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|metric| metric.as_str() == s)
+            .ok_or_else(|| format!("unknown metric '{s}'"))
+    }
+}
+
+/// Per-metric decimal overrides; metrics without one use
+/// [`DecimalMetric::default_decimals`].
+///
+/// # Examples
+///
+/// ```
+/// use tokmd_format::analysis::{DecimalMetric, MetricPrecision};
+///
+/// let precision = MetricPrecision::default().with(DecimalMetric::Gini, 6);
+/// assert_eq!(precision.decimals(DecimalMetric::Gini), 6);
+/// assert_eq!(precision.decimals(DecimalMetric::Distribution), 2);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MetricPrecision {
+    overrides: BTreeMap<DecimalMetric, usize>,
+}
+
+impl MetricPrecision {
+    /// Show `metric` with `decimals` fractional digits.
+    pub fn with(mut self, metric: DecimalMetric, decimals: usize) -> Self {
+        self.overrides.insert(metric, decimals);
+        self
+    }
+
+    /// Decimals shown for `metric`.
+    pub fn decimals(&self, metric: DecimalMetric) -> usize {
+        self.overrides
+            .get(&metric)
+            .copied()
+            .unwrap_or_else(|| metric.default_decimals())
+    }
+}
+
+impl FromIterator<(DecimalMetric, usize)> for MetricPrecision {
+    fn from_iter<I: IntoIterator<Item = (DecimalMetric, usize)>>(iter: I) -> Self {
+        Self {
+            overrides: iter.into_iter().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names_round_trip_and_are_unique() {
+        for metric in DecimalMetric::ALL {
+            assert_eq!(metric.as_str().parse::<DecimalMetric>(), Ok(metric));
+        }
+        let mut names: Vec<_> = DecimalMetric::ALL.iter().map(|m| m.as_str()).collect();
+        names.sort_unstable();
+        names.dedup();
+        assert_eq!(names.len(), DecimalMetric::ALL.len());
+        assert!("median".parse::<DecimalMetric>().is_err());
+    }
+
+    #[test]
+    fn overrides_replace_only_their_metric() {
+        let precision: MetricPrecision = [(DecimalMetric::Entropy, 1)].into_iter().collect();
+        assert_eq!(precision.decimals(DecimalMetric::Entropy), 1);
+        assert_eq!(precision.decimals(DecimalMetric::Gini), 4);
+        assert_eq!(precision.decimals(DecimalMetric::Percent), 1);
+    }
+}
@@ -0,0 +1,130 @@
+//! Multi-metric SVG scorecard for README embedding.
+//!
+//! Renders up to six headline metrics as a grid of labeled cells. A cell is
+//! omitted when the receipt lacks the section it reads, and cells with a
+//! threshold are colored green, yellow, or red.
+
+use tokmd_analysis_types::AnalysisReceipt;
+
+use super::NumberFormat;
+use crate::badge::{BADGE_DEFAULT_COLOR, BADGE_OK_COLOR, BADGE_WARN_COLOR};
+
+const CAUTION_COLOR: &str = "#dfb317";
+const COLUMNS: usize = 3;
+const CELL_WIDTH: usize = 120;
+const CELL_HEIGHT: usize = 52;
+const GAP: usize = 4;
+
+struct Cell {
+    label: &'static str,
+    value: String,
+    color: &'static str,
+}
+
+pub(super) fn render(receipt: &AnalysisReceipt) -> String {
+    let cells = cells(receipt);
+    let columns = cells.len().clamp(1, COLUMNS);
+    let rows = cells.len().div_ceil(COLUMNS).max(1);
+    let width = columns * (CELL_WIDTH + GAP) + GAP;
+    let height = rows * (CELL_HEIGHT + GAP) + GAP;
+
+    let mut out = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" role=\"img\" aria-label=\"tokmd scorecard\"><rect width=\"{width}\" height=\"{height}\" fill=\"#fff\"/>"
+    );
+    if cells.is_empty() {
+        out.push_str(&format!(
+            "<text x=\"{}\" y=\"{}\" fill=\"#555\" font-family=\"Verdana\" font-size=\"11\" text-anchor=\"middle\">no metrics</text>",
+            width / 2,
+            height / 2 + 4
+        ));
+    }
+    for (i, cell) in cells.iter().enumerate() {
+        let x = GAP + (i % COLUMNS) * (CELL_WIDTH + GAP);
+        let y = GAP + (i / COLUMNS) * (CELL_HEIGHT + GAP);
+        let cx = x + CELL_WIDTH / 2;
+        out.push_str(&format!(
+            "<g class=\"cell\"><rect x=\"{x}\" y=\"{y}\" width=\"{CELL_WIDTH}\" height=\"{CELL_HEIGHT}\" rx=\"4\" fill=\"{color}\"/><text x=\"{cx}\" y=\"{label_y}\" fill=\"#fff\" font-family=\"Verdana\" font-size=\"10\" text-anchor=\"middle\">{label}</text><text x=\"{cx}\" y=\"{value_y}\" fill=\"#fff\" font-family=\"Verdana\" font-size=\"16\" font-weight=\"bold\" text-anchor=\"middle\">{value}</text></g>",
+            color = cell.color,
+            label = cell.label,
+            value = cell.value,
+            label_y = y + 18,
+            value_y = y + 40,
+        ));
+    }
+    out.push_str("</svg>");
+    out
+}
+
+/// The metrics the receipt has data for, in display order.
+fn cells(receipt: &AnalysisReceipt) -> Vec<Cell> {
+    let nf = NumberFormat::default();
+    let mut cells = Vec::new();
+    if let Some(derived) = &receipt.derived {
+        cells.push(Cell {
+            label: "files",
+            value: nf.count(derived.totals.files),
+            color: BADGE_DEFAULT_COLOR,
+        });
+        cells.push(Cell {
+            label: "code lines",
+            value: nf.count(derived.totals.code),
+            color: BADGE_DEFAULT_COLOR,
+        });
+        let doc = derived.doc_density.total.ratio;
+        cells.push(Cell {
+            label: "doc",
+            value: nf.pct(doc),
+            color: at_least(doc, 0.10, 0.05),
+        });
+    }
+    if let Some(complexity) = &receipt.complexity {
+        let avg = complexity.avg_cyclomatic;
+        cells.push(Cell {
+            label: "avg complexity",
+            value: nf.decimal(avg, 1),
+            color: at_most(avg, 5.0, 10.0),
+        });
+    }
+    if let Some(derived) = &receipt.derived {
+        let tests = derived.test_density.ratio;
+        cells.push(Cell {
+            label: "tests",
+            value: nf.pct(tests),
+            color: at_least(tests, 0.20, 0.10),
+        });
+        if let Some(context) = &derived.context_window {
+            cells.push(Cell {
+                label: "context fit",
+                value: nf.pct(context.pct),
+                color: if context.fits {
+                    BADGE_OK_COLOR
+                } else {
+                    BADGE_WARN_COLOR
+                },
+            });
+        }
+    }
+    cells
+}
+
+/// Green at or above `good`, yellow at or above `fair`, red below.
+fn at_least(value: f64, good: f64, fair: f64) -> &'static str {
+    if value >= good {
+        BADGE_OK_COLOR
+    } else if value >= fair {
+        CAUTION_COLOR
+    } else {
+        BADGE_WARN_COLOR
+    }
+}
+
+/// Green at or below `good`, yellow at or below `fair`, red above.
+fn at_most(value: f64, good: f64, fair: f64) -> &'static str {
+    if value <= good {
+        BADGE_OK_COLOR
+    } else if value <= fair {
+        CAUTION_COLOR
+    } else {
+        BADGE_WARN_COLOR
+    }
+}
@@ -0,0 +1,172 @@
+//! Report section identifiers for standalone fragments.
+
+use std::fmt;
+use std::str::FromStr;
+
+/// One section of the analysis report, renderable on its own with
+/// [`render_section`](super::render_section).
+///
+/// Ids are the snake_case names used in the Markdown report's order; most
+/// match the receipt field they render. The derived report is split into
+/// its headed parts (`totals`, `ratios`, `distribution`, ...).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ReportSection {
+    Sampling,
+    Warnings,
+    Inputs,
+    Archetype,
+    Topics,
+    Entropy,
+    License,
+    CorporateFingerprint,
+    PredictiveChurn,
+    Totals,
+    Ratios,
+    Distribution,
+    Histogram,
+    TopOffenders,
+    OversizedFiles,
+    Structure,
+    TestDensity,
+    Todo,
+    Boilerplate,
+    Polyglot,
+    ReadingTime,
+    ContextWindow,
+    Effort,
+    Integrity,
+    Assets,
+    Deps,
+    Git,
+    Imports,
+    Dup,
+    Complexity,
+    RiskSurface,
+    ApiSurface,
+    LineEndings,
+    EcoLabel,
+    Extensions,
+    Timings,
+}
+
+impl ReportSection {
+    /// Every section, in the order the Markdown report renders them.
+    pub const ALL: [ReportSection; 36] = [
+        ReportSection::Sampling,
+        ReportSection::Warnings,
+        ReportSection::Inputs,
+        ReportSection::Archetype,
+        ReportSection::Topics,
+        ReportSection::Entropy,
+        ReportSection::License,
+        ReportSection::CorporateFingerprint,
+        ReportSection::PredictiveChurn,
+        ReportSection::Totals,
+        ReportSection::Ratios,
+        ReportSection::Distribution,
+        ReportSection::Histogram,
+        ReportSection::TopOffenders,
+        ReportSection::OversizedFiles,
+        ReportSection::Structure,
+        ReportSection::TestDensity,
+        ReportSection::Todo,
+        ReportSection::Boilerplate,
+        ReportSection::Polyglot,
+        ReportSection::ReadingTime,
+        ReportSection::ContextWindow,
+        ReportSection::Effort,
+        ReportSection::Integrity,
+        ReportSection::Assets,
+        ReportSection::Deps,
+        ReportSection::Git,
+        ReportSection::Imports,
+        ReportSection::Dup,
+        ReportSection::Complexity,
+        ReportSection::RiskSurface,
+        ReportSection::ApiSurface,
+        ReportSection::LineEndings,
+        ReportSection::EcoLabel,
+        ReportSection::Extensions,
+        ReportSection::Timings,
+    ];
+
+    /// The section id, as accepted by [`FromStr`].
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Sampling => "sampling",
+            Self::Warnings => "warnings",
+            Self::Inputs => "inputs",
+            Self::Archetype => "archetype",
+            Self::Topics => "topics",
+            Self::Entropy => "entropy",
+            Self::License => "license",
+            Self::CorporateFingerprint => "corporate_fingerprint",
+            Self::PredictiveChurn => "predictive_churn",
+            Self::Totals => "totals",
+            Self::Ratios => "ratios",
+            Self::Distribution => "distribution",
+            Self::Histogram => "histogram",
+            Self::TopOffenders => "top_offenders",
+            Self::OversizedFiles => "oversized_files",
+            Self::Structure => "structure",
+            Self::TestDensity => "test_density",
+            Self::Todo => "todo",
+            Self::Boilerplate => "boilerplate",
+            Self::Polyglot => "polyglot",
+            Self::ReadingTime => "reading_time",
+            Self::ContextWindow => "context_window",
+            Self::Effort => "effort",
+            Self::Integrity => "integrity",
+            Self::Assets => "assets",
+            Self::Deps => "deps",
+            Self::Git => "git",
+            Self::Imports => "imports",
+            Self::Dup => "dup",
+            Self::Complexity => "complexity",
+            Self::RiskSurface => "risk_surface",
+            Self::ApiSurface => "api_surface",
+            Self::LineEndings => "line_endings",
+            Self::EcoLabel => "eco_label",
+            Self::Extensions => "extensions",
+            Self::Timings => "timings",
+        }
+    }
+}
+
+impl fmt::Display for ReportSection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for ReportSection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ReportSection::ALL
+            .into_iter()
+            .find(|section| section.as_str() == s)
+            .ok_or_else(|| format!("unknown report section '{s}'"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ids_round_trip_and_are_unique() {
+        for section in ReportSection::ALL {
+            assert_eq!(section.as_str().parse::<ReportSection>(), Ok(section));
+        }
+        let mut ids: Vec<_> = ReportSection::ALL.iter().map(|s| s.as_str()).collect();
+        ids.sort_unstable();
+        ids.dedup();
+        assert_eq!(ids.len(), ReportSection::ALL.len());
+    }
+
+    #[test]
+    fn unknown_id_is_rejected() {
+        assert!("summary".parse::<ReportSection>().is_err());
+    }
+}
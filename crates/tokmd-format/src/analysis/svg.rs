@@ -5,15 +5,69 @@
 
 use tokmd_analysis_types::AnalysisReceipt;
 
-pub(super) fn render(receipt: &AnalysisReceipt) -> String {
-    let (label, value) = if let Some(derived) = &receipt.derived {
-        if let Some(ctx) = &derived.context_window {
-            ("context".to_string(), format!("{:.1}%", ctx.pct * 100.0))
-        } else {
-            ("tokens".to_string(), derived.totals.tokens.to_string())
+use super::{DecimalMetric, MetricPrecision, fixed};
+use crate::badge::{BADGE_OK_COLOR, BADGE_WARN_COLOR, badge_svg_with_color};
+
+/// Headline value shown on the analysis SVG badge.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SvgBadgeMetric {
+    /// Files scanned.
+    Files,
+    /// Total lines.
+    Lines,
+    /// Lines of code.
+    Code,
+    /// Estimated tokens.
+    Tokens,
+    /// Documentation density, as a percentage.
+    DocDensity,
+    /// Share of the context window the repo fills.
+    #[default]
+    ContextFit,
+}
+
+impl SvgBadgeMetric {
+    /// The metric's name, as shown on the badge.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Files => "files",
+            Self::Lines => "lines",
+            Self::Code => "code",
+            Self::Tokens => "tokens",
+            Self::DocDensity => "doc",
+            Self::ContextFit => "context",
+        }
+    }
+}
+
+/// Badge for `metric`, or for total tokens when the receipt lacks it (e.g.
+/// context fit without a window). A receipt without derived metrics shows
+/// `tokens` `0`. Percentages use `precision`'s [`DecimalMetric::Percent`].
+pub(super) fn render(
+    receipt: &AnalysisReceipt,
+    metric: SvgBadgeMetric,
+    precision: &MetricPrecision,
+) -> String {
+    let pct = |ratio: f64| {
+        format!(
+            "{}%",
+            fixed(ratio * 100.0, precision.decimals(DecimalMetric::Percent))
+        )
+    };
+    let value = receipt.derived.as_ref().and_then(|derived| match metric {
+        SvgBadgeMetric::Files => Some(derived.totals.files.to_string()),
+        SvgBadgeMetric::Lines => Some(derived.totals.lines.to_string()),
+        SvgBadgeMetric::Code => Some(derived.totals.code.to_string()),
+        SvgBadgeMetric::Tokens => Some(derived.totals.tokens.to_string()),
+        SvgBadgeMetric::DocDensity => Some(pct(derived.doc_density.total.ratio)),
+        SvgBadgeMetric::ContextFit => derived.context_window.as_ref().map(|ctx| pct(ctx.pct)),
+    });
+    let (label, value) = match value {
+        Some(value) => (metric.as_str(), value),
+        None => {
+            let tokens = receipt.derived.as_ref().map_or(0, |d| d.totals.tokens);
+            (SvgBadgeMetric::Tokens.as_str(), tokens.to_string())
         }
-    } else {
-        ("tokens".to_string(), "0".to_string())
     };
 
     let width = 240;
@@ -33,3 +87,20 @@ pub(super) fn render(receipt: &AnalysisReceipt) -> String {
         value = value
     )
 }
+
+/// Doc-density badge from `derived.doc_density.total.ratio`: green at or
+/// above `target_pct` percent, red below. The comparison uses the rounded
+/// percentage shown on the badge.
+pub(super) fn render_doc_density(receipt: &AnalysisReceipt, target_pct: f64) -> String {
+    let pct = receipt
+        .derived
+        .as_ref()
+        .map_or(0.0, |derived| derived.doc_density.total.ratio * 100.0);
+    let shown = fixed(pct, 1);
+    let color = if shown.parse::<f64>().is_ok_and(|shown| shown >= target_pct) {
+        BADGE_OK_COLOR
+    } else {
+        BADGE_WARN_COLOR
+    };
+    badge_svg_with_color("doc", &format!("{shown}%"), color)
+}
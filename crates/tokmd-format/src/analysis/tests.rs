@@ -2,6 +2,15 @@
 use super::*;
 use tokmd_analysis_types::*;
 
+type TestResult<T = ()> = Result<T, Box<dyn std::error::Error>>;
+
+fn text(output: RenderedOutput) -> TestResult<String> {
+    match output {
+        RenderedOutput::Text(s) => Ok(s),
+        RenderedOutput::Binary(_) => Err("expected text output".into()),
+    }
+}
+
 fn minimal_receipt() -> AnalysisReceipt {
     AnalysisReceipt {
         schema_version: 2,
@@ -23,6 +32,7 @@ fn minimal_receipt() -> AnalysisReceipt {
             module_roots: vec![],
             module_depth: 1,
             children: "collapse".to_string(),
+            token_model: "heuristic".to_string(),
         },
         args: AnalysisArgsMeta {
             preset: "receipt".to_string(),
@@ -35,6 +45,14 @@ fn minimal_receipt() -> AnalysisReceipt {
             max_commit_files: None,
             max_file_bytes: None,
             import_granularity: "module".to_string(),
+            oversized_file_bytes: None,
+            max_commit_message_bytes: None,
+            strip_bom: false,
+            preset_sections: None,
+            include_sections: vec![],
+            exclude_sections: vec![],
+            sample_rate: None,
+            max_runtime_ms: None,
         },
         archetype: None,
         topics: None,
@@ -52,6 +70,11 @@ fn minimal_receipt() -> AnalysisReceipt {
         api_surface: None,
         fun: None,
         effort: None,
+        timings: None,
+        line_endings: None,
+        sampling: None,
+        risk_surface: Vec::new(),
+        extensions: Default::default(),
     }
 }
 
@@ -213,6 +236,8 @@ fn sample_derived() -> DerivedReport {
             hash: "abc123".to_string(),
             entries: 10,
         },
+        oversized_files: vec![],
+        non_utf8_files: vec![],
     }
 }
 
@@ -236,6 +261,49 @@ fn test_render_xml_no_derived() {
     assert_eq!(result, "<analysis></analysis>");
 }
 
+// Test render_csv
+#[test]
+fn test_render_csv() -> TestResult {
+    let mut receipt = minimal_receipt();
+    let mut derived = sample_derived();
+    let mut quoted = derived
+        .top
+        .largest_lines
+        .first()
+        .ok_or("sample has a largest file")?
+        .clone();
+    quoted.path = "src/a,\"b\".rs".to_string();
+    quoted.doc_pct = None;
+    quoted.bytes_per_line = Some(12.5);
+    derived.top.largest_lines.push(quoted);
+    let files = derived.top.largest_lines.len();
+    receipt.derived = Some(derived);
+
+    let result = csv::render(&receipt)?;
+    let lines: Vec<&str> = result.lines().collect();
+    assert_eq!(
+        lines.first(),
+        Some(&"path,module,lang,code,lines,bytes,tokens,doc_pct,bytes_per_line")
+    );
+    assert_eq!(lines.len(), files + 1);
+    let last = lines.last().ok_or("csv has rows")?;
+    assert!(last.starts_with("\"src/a,\"\"b\"\".rs\","), "{last}");
+    assert!(last.ends_with(",,12.5"), "{last}");
+    Ok(())
+}
+
+// Test render_csv without derived
+#[test]
+fn test_render_csv_no_derived() -> TestResult {
+    let receipt = minimal_receipt();
+    let result = text(render(&receipt, AnalysisFormat::Csv)?)?;
+    assert_eq!(
+        result,
+        "path,module,lang,code,lines,bytes,tokens,doc_pct,bytes_per_line\n"
+    );
+    Ok(())
+}
+
 // Test render_jsonld
 #[test]
 fn test_render_jsonld() {
@@ -262,7 +330,11 @@ fn test_render_jsonld_empty_inputs() {
 fn test_render_svg() {
     let mut receipt = minimal_receipt();
     receipt.derived = Some(sample_derived());
-    let result = svg::render(&receipt);
+    let result = svg::render(
+        &receipt,
+        SvgBadgeMetric::default(),
+        &MetricPrecision::default(),
+    );
     assert!(result.contains("<svg"));
     assert!(result.contains("</svg>"));
     assert!(result.contains("context")); // has context_window
@@ -276,7 +348,11 @@ fn test_render_svg_no_context() {
     let mut derived = sample_derived();
     derived.context_window = None;
     receipt.derived = Some(derived);
-    let result = svg::render(&receipt);
+    let result = svg::render(
+        &receipt,
+        SvgBadgeMetric::default(),
+        &MetricPrecision::default(),
+    );
     assert!(result.contains("tokens"));
     assert!(result.contains("2500")); // total tokens
 }
@@ -285,7 +361,11 @@ fn test_render_svg_no_context() {
 #[test]
 fn test_render_svg_no_derived() {
     let receipt = minimal_receipt();
-    let result = svg::render(&receipt);
+    let result = svg::render(
+        &receipt,
+        SvgBadgeMetric::default(),
+        &MetricPrecision::default(),
+    );
     assert!(result.contains("tokens"));
     assert!(result.contains(">0<")); // default 0 value
 }
@@ -294,11 +374,207 @@ fn test_render_svg_no_derived() {
 #[test]
 fn test_render_svg_dimensions() {
     let receipt = minimal_receipt();
-    let result = svg::render(&receipt);
+    let result = svg::render(
+        &receipt,
+        SvgBadgeMetric::default(),
+        &MetricPrecision::default(),
+    );
     // width=240, label_width=80, value_width should be 160
     assert!(result.contains("width=\"160\"")); // value_width = 240 - 80
 }
 
+// Test render_svg shows the requested badge metric
+#[test]
+fn test_render_svg_badge_metrics() {
+    let mut receipt = minimal_receipt();
+    receipt.derived = Some(sample_derived());
+    for (metric, label, value) in [
+        (SvgBadgeMetric::Files, "files", "10"),
+        (SvgBadgeMetric::Lines, "lines", "1300"),
+        (SvgBadgeMetric::Code, "code", "1000"),
+        (SvgBadgeMetric::Tokens, "tokens", "2500"),
+        (SvgBadgeMetric::DocDensity, "doc", "16.7%"),
+        (SvgBadgeMetric::ContextFit, "context", "2.5%"),
+    ] {
+        let result = svg::render(&receipt, metric, &MetricPrecision::default());
+        assert!(
+            result.contains(&format!(">{label}</text>")),
+            "{metric:?}: {result}"
+        );
+        assert!(
+            result.contains(&format!(">{value}</text>")),
+            "{metric:?}: {result}"
+        );
+        assert!(result.contains("width=\"240\" height=\"32\""));
+        assert!(result.contains("fill=\"#4c9aff\""));
+    }
+}
+
+// Test render_svg falls back to tokens when the metric is unavailable
+#[test]
+fn test_render_svg_badge_metric_fallbacks() {
+    let mut receipt = minimal_receipt();
+    let mut derived = sample_derived();
+    derived.context_window = None;
+    receipt.derived = Some(derived);
+    let result = svg::render(
+        &receipt,
+        SvgBadgeMetric::ContextFit,
+        &MetricPrecision::default(),
+    );
+    assert!(result.contains(">tokens</text>") && result.contains(">2500</text>"));
+
+    let receipt = minimal_receipt();
+    for metric in [SvgBadgeMetric::Files, SvgBadgeMetric::DocDensity] {
+        let result = svg::render(&receipt, metric, &MetricPrecision::default());
+        assert!(result.contains(">tokens</text>") && result.contains(">0</text>"));
+    }
+}
+
+// Test render_with threads the badge metric to the svg format
+#[test]
+fn test_render_with_svg_badge_metric() -> TestResult {
+    let mut receipt = minimal_receipt();
+    receipt.derived = Some(sample_derived());
+    let options = AnalysisRenderOptions {
+        svg_badge_metric: SvgBadgeMetric::Code,
+        ..AnalysisRenderOptions::default()
+    };
+    let result = text(render_with(&receipt, AnalysisFormat::Svg, &options)?)?;
+    assert!(result.contains(">code</text>") && result.contains(">1000</text>"));
+    Ok(())
+}
+
+// Test render_with applies the configured percent precision to the badge
+#[test]
+fn test_render_with_svg_badge_percent_precision() -> TestResult {
+    let mut receipt = minimal_receipt();
+    receipt.derived = Some(sample_derived());
+    let options = AnalysisRenderOptions {
+        numbers: NumberFormat {
+            precision: MetricPrecision::default().with(DecimalMetric::Percent, 3),
+            ..NumberFormat::default()
+        },
+        svg_badge_metric: SvgBadgeMetric::DocDensity,
+        ..AnalysisRenderOptions::default()
+    };
+    let result = text(render_with(&receipt, AnalysisFormat::Svg, &options)?)?;
+    assert!(result.contains(">16.670%</text>"), "{result}");
+    Ok(())
+}
+
+#[test]
+fn doc_density_svg_below_target_uses_warn_color_and_percentage() {
+    let mut receipt = minimal_receipt();
+    let mut derived = sample_derived();
+    derived.doc_density.total.ratio = 0.084;
+    receipt.derived = Some(derived);
+
+    let result = render_doc_density_svg(&receipt, 10.0);
+    assert!(result.contains(">doc<"));
+    assert!(result.contains(">8.4%<"));
+    assert!(result.contains(&format!("fill=\"{}\"", crate::BADGE_WARN_COLOR)));
+    assert!(!result.contains(crate::BADGE_OK_COLOR));
+}
+
+#[test]
+fn doc_density_svg_at_or_above_target_uses_ok_color() -> TestResult {
+    let mut receipt = minimal_receipt();
+    receipt.derived = Some(sample_derived());
+    let result = render_doc_density_svg(&receipt, 10.0);
+    assert!(result.contains(">16.7%<"));
+    assert!(result.contains(&format!("fill=\"{}\"", crate::BADGE_OK_COLOR)));
+
+    // The rounded percentage on the badge decides the color.
+    let derived = receipt.derived.as_mut().ok_or("derived was set")?;
+    derived.doc_density.total.ratio = 0.09996;
+    let result = render_doc_density_svg(&receipt, 10.0);
+    assert!(result.contains(">10.0%<"));
+    assert!(result.contains(crate::BADGE_OK_COLOR));
+    Ok(())
+}
+
+#[test]
+fn doc_density_svg_without_derived_is_zero_and_warns() {
+    let result = render_doc_density_svg(&minimal_receipt(), 10.0);
+    assert!(result.contains(">0.0%<"));
+    assert!(result.contains(crate::BADGE_WARN_COLOR));
+}
+
+fn sample_complexity() -> ComplexityReport {
+    ComplexityReport {
+        total_functions: 10,
+        avg_function_length: 12.0,
+        max_function_length: 40,
+        avg_cyclomatic: 4.0,
+        max_cyclomatic: 9,
+        avg_cognitive: None,
+        max_cognitive: None,
+        avg_nesting_depth: None,
+        max_nesting_depth: None,
+        high_risk_files: 0,
+        histogram: None,
+        halstead: None,
+        maintainability_index: None,
+        technical_debt: None,
+        files: vec![],
+    }
+}
+
+fn scorecard_cells(receipt: &AnalysisReceipt) -> TestResult<usize> {
+    let svg = text(render(receipt, AnalysisFormat::Scorecard)?)?;
+    assert!(svg.starts_with("<svg") && svg.ends_with("</svg>"));
+    Ok(svg.matches("<g class=\"cell\">").count())
+}
+
+#[test]
+fn scorecard_renders_one_cell_per_available_metric() -> TestResult {
+    let mut receipt = minimal_receipt();
+    assert_eq!(scorecard_cells(&receipt)?, 0);
+
+    // files, code lines, doc, tests, and context fit come from `derived`.
+    receipt.derived = Some(sample_derived());
+    assert_eq!(scorecard_cells(&receipt)?, 5);
+
+    receipt
+        .derived
+        .as_mut()
+        .ok_or("derived was set")?
+        .context_window = None;
+    assert_eq!(scorecard_cells(&receipt)?, 4);
+
+    receipt.complexity = Some(ComplexityReport {
+        avg_cyclomatic: 12.0,
+        ..sample_complexity()
+    });
+    assert_eq!(scorecard_cells(&receipt)?, 5);
+    Ok(())
+}
+
+#[test]
+fn scorecard_colors_cells_by_threshold() -> TestResult {
+    let mut receipt = minimal_receipt();
+    let mut derived = sample_derived();
+    derived.doc_density.total.ratio = 0.02;
+    receipt.derived = Some(derived);
+    receipt.complexity = Some(ComplexityReport {
+        avg_cyclomatic: 3.0,
+        ..sample_complexity()
+    });
+    let svg = text(render(&receipt, AnalysisFormat::Scorecard)?)?;
+
+    let cell = |label: &str| {
+        svg.split("<g class=\"cell\">")
+            .find(|cell| cell.contains(&format!(">{label}<")))
+            .ok_or_else(|| format!("no {label} cell in {svg}"))
+    };
+    assert!(cell("doc")?.contains(crate::BADGE_WARN_COLOR));
+    assert!(cell("doc")?.contains(">2.0%<"));
+    assert!(cell("avg complexity")?.contains(crate::BADGE_OK_COLOR));
+    assert!(cell("avg complexity")?.contains(">3.0<"));
+    Ok(())
+}
+
 // Test render_mermaid
 #[test]
 fn test_render_mermaid() {
@@ -372,6 +648,42 @@ fn test_render_midi_no_fun() {
     assert!(result.unwrap_err().to_string().contains("fun"));
 }
 
+// available_formats hides OBJ/MIDI without the fun feature
+#[cfg(not(feature = "fun"))]
+#[test]
+fn test_available_formats_exclude_fun_formats() -> TestResult {
+    let formats = available_formats();
+    assert!(!formats.contains(&AnalysisFormat::Obj));
+    assert!(!formats.contains(&AnalysisFormat::Midi));
+    assert!(formats.contains(&AnalysisFormat::Md));
+    assert!(formats.contains(&AnalysisFormat::Html));
+    assert_eq!(formats.len(), 11);
+
+    let Err(err) = render(&minimal_receipt(), AnalysisFormat::Obj) else {
+        return Err("OBJ should be unavailable without fun".into());
+    };
+    assert!(
+        err.to_string().ends_with(
+            "available formats: md, json, jsonld, xml, csv, svg, scorecard, mermaid, gitgraph, tree, html"
+        ),
+        "{err}"
+    );
+    Ok(())
+}
+
+// available_formats lists every format with the fun feature
+#[cfg(feature = "fun")]
+#[test]
+fn test_available_formats_include_fun_formats() {
+    let formats = available_formats();
+    assert!(formats.contains(&AnalysisFormat::Obj));
+    assert!(formats.contains(&AnalysisFormat::Midi));
+    assert_eq!(formats.len(), 13);
+    for format in formats {
+        assert!(render(&minimal_receipt(), format).is_ok(), "{format:?}");
+    }
+}
+
 // Test render_obj with fun feature - verify coordinate calculations
 // This test uses precise vertex extraction to catch arithmetic mutants:
 // - idx % 5 vs idx / 5 (grid position)
@@ -983,6 +1295,7 @@ fn test_render_md_license() {
             source_path: "LICENSE".to_string(),
             source_kind: LicenseSourceKind::Text,
         }],
+        header_compliance: None,
     });
     let result = render_md(&receipt);
     assert!(result.contains("## License radar"));
@@ -997,6 +1310,7 @@ fn test_render_md_license_no_findings() {
     receipt.license = Some(LicenseReport {
         effective: None,
         findings: vec![],
+        header_compliance: None,
     });
     let result = render_md(&receipt);
     assert!(result.contains("## License radar"));
@@ -1004,6 +1318,47 @@ fn test_render_md_license_no_findings() {
     assert!(!result.contains("|SPDX|")); // No table header
 }
 
+#[test]
+fn test_render_md_license_header_compliance() {
+    let mut receipt = minimal_receipt();
+    receipt.license = Some(LicenseReport {
+        effective: None,
+        findings: vec![],
+        header_compliance: Some(LicenseHeaderCompliance {
+            expected: "Copyright Example\nSPDX-License-Identifier: MIT\n".to_string(),
+            checked: 4,
+            compliant: 3,
+            ratio: 0.75,
+            missing: vec!["src/lib.rs".to_string()],
+        }),
+    });
+    let result = render_md(&receipt);
+    assert!(result.contains("### License header compliance"));
+    assert!(result.contains("- Expected: `Copyright Example / SPDX-License-Identifier: MIT`"));
+    assert!(result.contains("- Compliant: 3 of 4 source files (75.0%)"));
+    assert!(result.contains("|Missing header|\n|---|\n|src/lib.rs|\n"));
+}
+
+#[test]
+fn test_render_md_license_header_compliance_truncates_missing() {
+    let mut receipt = minimal_receipt();
+    receipt.license = Some(LicenseReport {
+        effective: None,
+        findings: vec![],
+        header_compliance: Some(LicenseHeaderCompliance {
+            expected: "SPDX-License-Identifier: MIT".to_string(),
+            checked: 25,
+            compliant: 0,
+            ratio: 0.0,
+            missing: (0..25).map(|i| format!("src/f{i:02}.rs")).collect(),
+        }),
+    });
+    let result = render_md(&receipt);
+    assert!(result.contains("|src/f19.rs|"));
+    assert!(!result.contains("|src/f20.rs|"));
+    assert!(result.contains("|… 5 more|"));
+}
+
 // Test render_md with corporate fingerprint
 #[test]
 fn test_render_md_corporate_fingerprint() {
@@ -1013,6 +1368,7 @@ fn test_render_md_corporate_fingerprint() {
             domain: "example.com".to_string(),
             commits: 50,
             pct: 0.75,
+            members: Vec::new(),
         }],
     });
     let result = render_md(&receipt);
@@ -1020,6 +1376,21 @@ fn test_render_md_corporate_fingerprint() {
     assert!(result.contains("|example.com|50|75.0%|"));
 }
 
+#[test]
+fn test_render_md_corporate_fingerprint_grouped_members() {
+    let mut receipt = minimal_receipt();
+    receipt.corporate_fingerprint = Some(CorporateFingerprint {
+        domains: vec![DomainStat {
+            domain: "acme.com".to_string(),
+            commits: 3,
+            pct: 1.0,
+            members: vec!["eng.acme.com".to_string(), "ops.acme.com".to_string()],
+        }],
+    });
+    let result = render_md(&receipt);
+    assert!(result.contains("|acme.com (incl. eng.acme.com, ops.acme.com)|3|100.0%|"));
+}
+
 // Test render_md with corporate fingerprint no domains
 #[test]
 fn test_render_md_corporate_fingerprint_no_domains() {
@@ -1042,15 +1413,228 @@ fn test_render_md_churn() {
             r2: 0.8,
             recent_change: 5,
             classification: TrendClass::Rising,
+            projected_change: None,
+            projected_interval: None,
         },
     );
     let mut receipt = minimal_receipt();
-    receipt.predictive_churn = Some(PredictiveChurnReport { per_module });
+    receipt.predictive_churn = Some(PredictiveChurnReport {
+        per_module,
+        forecast_days: None,
+    });
     let result = render_md(&receipt);
     assert!(result.contains("## Predictive churn"));
     assert!(result.contains("|src|0.5000|0.80|5|Rising|"));
 }
 
+#[test]
+fn test_render_md_churn_forecast() {
+    use std::collections::BTreeMap;
+    let mut per_module = BTreeMap::new();
+    per_module.insert(
+        "src".to_string(),
+        ChurnTrend {
+            slope: 0.5,
+            r2: 0.8,
+            recent_change: 5,
+            classification: TrendClass::Rising,
+            projected_change: Some(12.4),
+            projected_interval: Some(ChurnInterval {
+                low: 9.5,
+                high: 15.0,
+            }),
+        },
+    );
+    let mut receipt = minimal_receipt();
+    receipt.predictive_churn = Some(PredictiveChurnReport {
+        per_module,
+        forecast_days: Some(30),
+    });
+    let result = render_md(&receipt);
+    assert!(result.contains("|Module|Slope|R²|Recent change|Class|Next 30d|Range|"));
+    assert!(result.contains("|src|0.5000|0.80|5|Rising|12.4|9.5–15.0|"));
+}
+
+#[test]
+fn test_render_md_risk_surface() {
+    let mut receipt = minimal_receipt();
+    receipt.risk_surface = vec![
+        RiskSurfaceRow {
+            path: "src/engine.rs".to_string(),
+            score: 0.9167,
+            cyclomatic: Some(40),
+            commits: Some(25),
+            coverage: Some(0.25),
+        },
+        RiskSurfaceRow {
+            path: "src/util.rs".to_string(),
+            score: 0.2,
+            cyclomatic: None,
+            commits: Some(5),
+            coverage: None,
+        },
+    ];
+    let result = render_md(&receipt);
+    assert!(result.contains("## Risk surface"));
+    assert!(result.contains("|1|src/engine.rs|0.92|40|25|25.0%|"));
+    assert!(result.contains("|2|src/util.rs|0.20|-|5|-|"));
+}
+
+#[test]
+fn test_render_md_omits_empty_risk_surface() {
+    let result = render_md(&minimal_receipt());
+    assert!(!result.contains("## Risk surface"));
+}
+
+#[test]
+fn test_render_md_labels_risk_and_trend_with_status_style() -> TestResult {
+    let mut per_module = std::collections::BTreeMap::new();
+    per_module.insert(
+        "src".to_string(),
+        ChurnTrend {
+            slope: 0.5,
+            r2: 0.8,
+            recent_change: 5,
+            classification: TrendClass::Rising,
+            projected_change: None,
+            projected_interval: None,
+        },
+    );
+    let mut receipt = minimal_receipt();
+    receipt.predictive_churn = Some(PredictiveChurnReport {
+        per_module,
+        forecast_days: None,
+    });
+    receipt.complexity = Some(ComplexityReport {
+        total_functions: 80,
+        avg_function_length: 40.0,
+        max_function_length: 300,
+        avg_cyclomatic: 12.0,
+        max_cyclomatic: 90,
+        avg_cognitive: None,
+        max_cognitive: None,
+        avg_nesting_depth: None,
+        max_nesting_depth: None,
+        high_risk_files: 1,
+        histogram: None,
+        halstead: None,
+        maintainability_index: None,
+        technical_debt: None,
+        files: vec![FileComplexity {
+            path: "src/engine.rs".to_string(),
+            module: "src".to_string(),
+            function_count: 80,
+            max_function_length: 300,
+            cyclomatic_complexity: 90,
+            cognitive_complexity: None,
+            max_nesting: None,
+            risk_level: ComplexityRisk::Critical,
+            functions: None,
+        }],
+    });
+
+    let plain = render_md(&receipt);
+    assert!(plain.contains("|src/engine.rs|90|80|300|Critical|"));
+    assert!(plain.contains("|src|0.5000|0.80|5|Rising|"));
+
+    let options = AnalysisRenderOptions {
+        status: crate::StatusStyle::traffic_light().with_label("critical", "🚨 CRITICAL"),
+        ..AnalysisRenderOptions::default()
+    };
+    let styled = text(render_with(&receipt, AnalysisFormat::Md, &options)?)?;
+    assert!(styled.contains("|src/engine.rs|90|80|300|🚨 CRITICAL|"));
+    assert!(styled.contains("|src|0.5000|0.80|5|🟡 rising|"));
+    Ok(())
+}
+
+#[test]
+fn test_render_md_timings_section_only_when_recorded() {
+    let mut receipt = minimal_receipt();
+    assert!(!render_md(&receipt).contains("## Timings"));
+
+    receipt.timings = Some(
+        [("complexity".to_string(), 40), ("derived".to_string(), 2)]
+            .into_iter()
+            .collect(),
+    );
+    let md = render_md(&receipt);
+    assert!(md.contains(
+        "## Timings\n\n|Phase|ms|\n|---|---:|\n|complexity|40|\n|derived|2|\n|**Total**|42|\n"
+    ));
+}
+
+#[test]
+fn test_render_md_line_endings_lists_mixed_files() {
+    let mut receipt = minimal_receipt();
+    assert!(!render_md(&receipt).contains("## Line endings"));
+
+    receipt.line_endings = Some(LineEndingReport {
+        lf_files: 3,
+        crlf_files: 1,
+        mixed_files: 1,
+        no_line_break_files: 0,
+        mixed: vec![MixedLineEndingFile {
+            path: "src/win.rs".to_string(),
+            module: "src".to_string(),
+            lf_lines: 4,
+            crlf_lines: 2,
+        }],
+    });
+    let md = render_md(&receipt);
+    assert!(md.contains("## Line endings\n\n- LF: 3, CRLF: 1, mixed: 1, no line break: 0\n"));
+    assert!(md.contains("|src/win.rs|src|4|2|"));
+}
+
+#[test]
+fn test_render_md_sampling_flags_estimates() {
+    let mut receipt = minimal_receipt();
+    assert!(!render_md(&receipt).contains("## Sampling"));
+
+    receipt.sampling = Some(SamplingReport {
+        sampled: true,
+        rate: 0.1,
+        total_files: 1_000,
+        sampled_files: 98,
+        estimated_totals: DerivedTotals {
+            files: 980,
+            code: 50_000,
+            comments: 4_000,
+            blanks: 6_000,
+            lines: 60_000,
+            bytes: 2_000_000,
+            tokens: 500_000,
+        },
+    });
+    let md = render_md(&receipt);
+    assert!(md.contains(
+        "## Sampling\n\nSampled 10.0% of files (98 of 1000); every other section describes only the sample.\n"
+    ));
+    assert!(md.contains("|~|980|50000|4000|6000|60000|2000000|500000|"));
+    assert!(md.find("## Sampling") < md.find("## Inputs"));
+}
+
+#[test]
+fn test_render_md_explain_annotates_distribution_and_polyglot() -> TestResult {
+    let mut receipt = minimal_receipt();
+    receipt.derived = Some(sample_derived());
+
+    let plain = render_md(&receipt);
+    assert!(!plain.contains("> Gini"));
+    assert!(!plain.contains("> Entropy"));
+
+    let options = AnalysisRenderOptions {
+        explain: true,
+        ..AnalysisRenderOptions::default()
+    };
+    let explained = text(render_with(&receipt, AnalysisFormat::Md, &options)?)?;
+    assert!(explained.contains("|0.3000|\n\n> Gini 0.30 — moderately uneven file-size distribution\n\n## File size histogram"));
+    assert!(explained.contains(
+        "- Entropy: `0.5000`\n\n> Entropy 0.50 — a main language with significant secondary ones\n\n"
+    ));
+    assert!(explained.contains("> Doc density "));
+    Ok(())
+}
+
 // Test render_md with predictive churn empty
 #[test]
 fn test_render_md_churn_deterministic_tiebreak() {
@@ -1065,6 +1649,8 @@ fn test_render_md_churn_deterministic_tiebreak() {
             r2: 0.8,
             recent_change: 5,
             classification: tokmd_analysis_types::TrendClass::Rising,
+            projected_change: None,
+            projected_interval: None,
         },
     );
     per_module.insert(
@@ -1074,9 +1660,14 @@ fn test_render_md_churn_deterministic_tiebreak() {
             r2: 0.8,
             recent_change: 5,
             classification: tokmd_analysis_types::TrendClass::Rising,
+            projected_change: None,
+            projected_interval: None,
         },
     );
-    receipt.predictive_churn = Some(tokmd_analysis_types::PredictiveChurnReport { per_module });
+    receipt.predictive_churn = Some(tokmd_analysis_types::PredictiveChurnReport {
+        per_module,
+        forecast_days: None,
+    });
 
     let result = render_md(&receipt);
     let a_idx = result.find("|a_module|-0.5000|0.80|5|Rising|").unwrap();
@@ -1146,6 +1737,8 @@ fn test_render_md_maintenance_deterministic_tiebreak() {
             ],
             unknown_pct: 0.0,
             corrective_ratio: Some(0.0),
+            recent: vec![],
+            truncated_messages: None,
         }),
     });
 
@@ -1164,6 +1757,7 @@ fn test_render_md_churn_empty() {
     let mut receipt = minimal_receipt();
     receipt.predictive_churn = Some(PredictiveChurnReport {
         per_module: BTreeMap::new(),
+        forecast_days: None,
     });
     let result = render_md(&receipt);
     assert!(result.contains("## Predictive churn"));
@@ -1450,6 +2044,58 @@ fn test_render_md_fun_no_label() {
     assert!(!result.contains("## Eco label"));
 }
 
+// Test render_with applies the number format to Markdown
+#[test]
+fn test_render_md_comma_decimal_number_format() -> TestResult {
+    let mut receipt = minimal_receipt();
+    receipt.derived = Some(sample_derived());
+    let options = AnalysisRenderOptions {
+        numbers: NumberFormat {
+            decimal_separator: ',',
+            ..NumberFormat::default()
+        },
+        ..AnalysisRenderOptions::default()
+    };
+    let result = text(render_with(&receipt, AnalysisFormat::Md, &options)?)?;
+    assert!(result.contains("16,7%"), "{result}");
+    assert!(!result.contains("16.7%"));
+    // Integer table cells are unchanged.
+    assert!(result.contains("|10|1000|200|100|1300|50000|2500|"));
+    Ok(())
+}
+
+// Test render_with applies per-metric precision overrides to Markdown
+#[test]
+fn test_render_md_metric_precision_override() -> TestResult {
+    let mut receipt = minimal_receipt();
+    let mut derived = sample_derived();
+    derived.distribution.gini = 0.123456789;
+    receipt.derived = Some(derived);
+
+    let default = text(render(&receipt, AnalysisFormat::Md)?)?;
+    assert!(default.contains("|10|50|650|130.00|100.00|400.00|650.00|0.1235|"));
+
+    let options = AnalysisRenderOptions {
+        numbers: NumberFormat {
+            precision: MetricPrecision::default().with(DecimalMetric::Gini, 6),
+            ..NumberFormat::default()
+        },
+        ..AnalysisRenderOptions::default()
+    };
+    let tuned = text(render_with(&receipt, AnalysisFormat::Md, &options)?)?;
+    assert!(
+        tuned.contains("|10|50|650|130.00|100.00|400.00|650.00|0.123457|"),
+        "{tuned}"
+    );
+    // Only the gini cell differs.
+    assert_eq!(
+        tuned.replace("0.123457", "0.1235"),
+        default,
+        "other metrics keep their defaults"
+    );
+    Ok(())
+}
+
 // Test render_md with derived
 #[test]
 fn test_render_md_derived() {
@@ -1538,6 +2184,157 @@ fn test_render_dispatch_mermaid() {
     }
 }
 
+#[test]
+fn test_render_dispatch_gitgraph_without_git() -> TestResult {
+    let receipt = minimal_receipt();
+    assert_eq!(
+        text(render(&receipt, AnalysisFormat::Gitgraph)?)?,
+        "gitGraph\n"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_render_gitgraph_tags_commits_by_intent() -> TestResult {
+    let recent = |hash: &str, subject: &str, intent| RecentCommitIntent {
+        hash: Some(hash.to_string()),
+        timestamp: 0,
+        subject: subject.to_string(),
+        intent,
+    };
+    let mut receipt = minimal_receipt();
+    receipt.git = Some(GitReport {
+        commits_scanned: 2,
+        files_seen: 0,
+        hotspots: vec![],
+        bus_factor: vec![],
+        freshness: FreshnessReport {
+            threshold_days: 90,
+            stale_files: 0,
+            total_files: 0,
+            stale_pct: 0.0,
+            by_module: vec![],
+        },
+        coupling: vec![],
+        age_distribution: None,
+        intent: Some(CommitIntentReport {
+            overall: CommitIntentCounts::default(),
+            by_module: vec![],
+            unknown_pct: 0.0,
+            corrective_ratio: None,
+            // Newest first, as analysis records them.
+            recent: vec![
+                recent(
+                    "bbbbbbbbbb",
+                    "fix: handle \"quoted\" paths",
+                    CommitIntentKind::Fix,
+                ),
+                recent("aaaaaaaaaa", "feat: add gitgraph", CommitIntentKind::Feat),
+            ],
+            truncated_messages: None,
+        }),
+    });
+
+    let out = text(render(&receipt, AnalysisFormat::Gitgraph)?)?;
+    assert!(out.starts_with("gitGraph\n"));
+    let lines: Vec<&str> = out.lines().skip(1).collect();
+    assert_eq!(
+        lines,
+        vec![
+            "  commit id: \"aaaaaaa feat: add gitgraph\" tag: \"feat\" type: HIGHLIGHT",
+            "  commit id: \"bbbbbbb fix: handle 'quoted' paths\" tag: \"fix\" type: NORMAL",
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_render_md_ranked_tables_ignore_input_order_on_ties() -> TestResult {
+    use std::collections::BTreeMap;
+
+    let intent_row = |module: &str, fix: usize, total: usize| ModuleIntentRow {
+        module: module.to_string(),
+        counts: CommitIntentCounts {
+            fix,
+            feat: total - fix,
+            total,
+            ..CommitIntentCounts::default()
+        },
+    };
+    let trend = |slope: f64| ChurnTrend {
+        slope,
+        r2: 0.5,
+        recent_change: 1,
+        classification: TrendClass::Rising,
+        projected_change: None,
+        projected_interval: None,
+    };
+    let receipt_with = |by_module: Vec<ModuleIntentRow>| {
+        let mut receipt = minimal_receipt();
+        receipt.git = Some(GitReport {
+            commits_scanned: 12,
+            files_seen: 0,
+            hotspots: vec![],
+            bus_factor: vec![],
+            freshness: FreshnessReport {
+                threshold_days: 90,
+                stale_files: 0,
+                total_files: 0,
+                stale_pct: 0.0,
+                by_module: vec![],
+            },
+            coupling: vec![],
+            age_distribution: None,
+            intent: Some(CommitIntentReport {
+                overall: CommitIntentCounts::default(),
+                by_module,
+                unknown_pct: 0.0,
+                corrective_ratio: None,
+                recent: vec![],
+                truncated_messages: None,
+            }),
+        });
+        let mut per_module = BTreeMap::new();
+        per_module.insert("zeta".to_string(), trend(0.25));
+        per_module.insert("alpha".to_string(), trend(0.25));
+        per_module.insert("mid".to_string(), trend(0.75));
+        receipt.predictive_churn = Some(PredictiveChurnReport {
+            per_module,
+            forecast_days: None,
+        });
+        receipt
+    };
+
+    // Same rows, opposite order; every share is tied at 50%.
+    let rows = vec![
+        intent_row("zeta", 2, 4),
+        intent_row("alpha", 1, 2),
+        intent_row("mid", 3, 6),
+    ];
+    let forward = render_md(&receipt_with(rows.clone()));
+    let reversed = render_md(&receipt_with(rows.into_iter().rev().collect()));
+    assert_eq!(forward, reversed);
+    assert_eq!(
+        forward,
+        render_md(&receipt_with(vec![
+            intent_row("mid", 3, 6),
+            intent_row("zeta", 2, 4),
+            intent_row("alpha", 1, 2),
+        ]))
+    );
+
+    let position = |needle: &str| {
+        forward
+            .find(needle)
+            .ok_or_else(|| format!("missing {needle:?} in:\n{forward}"))
+    };
+    assert!(position("|alpha|1|2|50.0%|")? < position("|mid|3|6|50.0%|")?);
+    assert!(position("|mid|3|6|50.0%|")? < position("|zeta|2|4|50.0%|")?);
+    assert!(position("|mid|0.7500|")? < position("|alpha|0.2500|")?);
+    assert!(position("|alpha|0.2500|")? < position("|zeta|0.2500|")?);
+    Ok(())
+}
+
 #[test]
 fn test_render_dispatch_jsonld() {
     let receipt = minimal_receipt();
@@ -1556,3 +2353,113 @@ fn test_render_html() {
     let result = render_html(&receipt);
     assert!(result.contains("<!DOCTYPE html>") || result.contains("<html"));
 }
+
+fn section_text(
+    receipt: &AnalysisReceipt,
+    section: ReportSection,
+    format: AnalysisFormat,
+) -> TestResult<String> {
+    let output = render_section(receipt, section, format)
+        .ok_or_else(|| format!("expected a {section} fragment"))?;
+    text(output)
+}
+
+#[test]
+fn totals_fragment_is_the_totals_table_and_nothing_else() -> TestResult {
+    let mut receipt = minimal_receipt();
+    receipt.derived = Some(sample_derived());
+    let fragment = section_text(&receipt, ReportSection::Totals, AnalysisFormat::Md)?;
+
+    assert!(fragment.starts_with("## Totals\n\n|Files|Code|Comments|Blanks|Lines|Bytes|Tokens|\n"));
+    assert_eq!(fragment.matches("## ").count(), 1, "{fragment}");
+    assert!(!fragment.contains("# tokmd analysis"));
+    assert!(!fragment.contains("Preset:"));
+    assert!(render_md(&receipt).contains(&fragment));
+    Ok(())
+}
+
+#[test]
+fn fragments_concatenate_to_the_full_report_body() -> TestResult {
+    let mut receipt = minimal_receipt();
+    receipt.derived = Some(sample_derived());
+    receipt.warnings = vec!["careful".into()];
+    let body: String = ReportSection::ALL
+        .into_iter()
+        .filter_map(|section| render_section(&receipt, section, AnalysisFormat::Md))
+        .map(text)
+        .collect::<TestResult<_>>()?;
+    assert_eq!(
+        render_md(&receipt),
+        format!("# tokmd analysis\n\nPreset: `receipt`\n\n{body}")
+    );
+    Ok(())
+}
+
+#[test]
+fn absent_sections_render_no_fragment() {
+    let receipt = minimal_receipt();
+    for section in [
+        ReportSection::Totals,
+        ReportSection::Effort,
+        ReportSection::Git,
+        ReportSection::Warnings,
+        ReportSection::Extensions,
+        ReportSection::OversizedFiles,
+    ] {
+        assert!(
+            render_section(&receipt, section, AnalysisFormat::Md).is_none(),
+            "{section}"
+        );
+        assert!(
+            render_section(&receipt, section, AnalysisFormat::Html).is_none(),
+            "{section}"
+        );
+    }
+}
+
+#[test]
+fn html_fragments_have_no_document_shell() -> TestResult {
+    let mut receipt = minimal_receipt();
+    receipt.derived = Some(sample_derived());
+
+    let totals = section_text(&receipt, ReportSection::Totals, AnalysisFormat::Html)?;
+    assert!(totals.starts_with(r#"<div class="metrics-grid">"#));
+    assert!(totals.contains(r#"<span class="label">Files</span>"#));
+    assert!(!totals.contains("<html") && !totals.contains("<table"));
+
+    let table = section_text(&receipt, ReportSection::TopOffenders, AnalysisFormat::Html)?;
+    assert!(table.starts_with(r#"<table id="files-table">"#));
+    assert!(!table.contains("<html") && !table.contains("metric-card"));
+
+    assert!(render_section(&receipt, ReportSection::Ratios, AnalysisFormat::Html).is_none());
+    Ok(())
+}
+
+#[test]
+fn html_fragment_and_report_share_the_files_table_head() -> TestResult {
+    let mut receipt = minimal_receipt();
+    receipt.derived = Some(sample_derived());
+
+    let table = section_text(&receipt, ReportSection::TopOffenders, AnalysisFormat::Html)?;
+    let head = table
+        .split_once("<thead>")
+        .and_then(|(_, rest)| rest.split_once("</thead>"))
+        .map(|(head, _)| format!("<thead>{head}</thead>"))
+        .ok_or("fragment has no <thead>")?;
+    let report = text(render(&receipt, AnalysisFormat::Html)?)?;
+    assert!(report.contains(&head), "report lacks {head}");
+    Ok(())
+}
+
+#[test]
+fn formats_without_sections_render_no_fragment() {
+    let mut receipt = minimal_receipt();
+    receipt.derived = Some(sample_derived());
+    for format in [
+        AnalysisFormat::Json,
+        AnalysisFormat::Xml,
+        AnalysisFormat::Svg,
+    ] {
+        assert!(render_section(&receipt, ReportSection::Totals, format).is_none());
+    }
+}
@@ -16,8 +16,19 @@ fn escape_xml_text(s: &str) -> String {
     out
 }
 
+/// Value-segment fill for a badge that meets its target.
+pub const BADGE_OK_COLOR: &str = "#4c1";
+/// Value-segment fill for a badge below its target.
+pub const BADGE_WARN_COLOR: &str = "#e05d44";
+pub(crate) const BADGE_DEFAULT_COLOR: &str = "#4c9aff";
+
 /// Build a compact two-segment SVG badge.
 pub fn badge_svg(label: &str, value: &str) -> String {
+    badge_svg_with_color(label, value, BADGE_DEFAULT_COLOR)
+}
+
+/// [`badge_svg`] with the value segment filled with `color`.
+pub fn badge_svg_with_color(label: &str, value: &str, color: &str) -> String {
     // Width is heuristic; char count avoids UTF-8 byte-length drift.
     let label_chars = label.chars().count() as i32;
     let value_chars = value.chars().count() as i32;
@@ -29,8 +40,9 @@ pub fn badge_svg(label: &str, value: &str) -> String {
     let value_x = label_width + value_width / 2;
     let label_escaped = escape_xml_text(label);
     let value_escaped = escape_xml_text(value);
+    let color = escape_xml_text(color);
     format!(
-        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" role=\"img\"><rect width=\"{label_width}\" height=\"{height}\" fill=\"#555\"/><rect x=\"{label_width}\" width=\"{value_width}\" height=\"{height}\" fill=\"#4c9aff\"/><text x=\"{label_x}\" y=\"16\" fill=\"#fff\" font-family=\"Verdana\" font-size=\"11\" text-anchor=\"middle\">{label}</text><text x=\"{value_x}\" y=\"16\" fill=\"#fff\" font-family=\"Verdana\" font-size=\"11\" text-anchor=\"middle\">{value}</text></svg>",
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" role=\"img\"><rect width=\"{label_width}\" height=\"{height}\" fill=\"#555\"/><rect x=\"{label_width}\" width=\"{value_width}\" height=\"{height}\" fill=\"{color}\"/><text x=\"{label_x}\" y=\"16\" fill=\"#fff\" font-family=\"Verdana\" font-size=\"11\" text-anchor=\"middle\">{label}</text><text x=\"{value_x}\" y=\"16\" fill=\"#fff\" font-family=\"Verdana\" font-size=\"11\" text-anchor=\"middle\">{value}</text></svg>",
         width = width,
         height = height,
         label_width = label_width,
@@ -38,13 +50,14 @@ pub fn badge_svg(label: &str, value: &str) -> String {
         label_x = label_x,
         value_x = value_x,
         label = label_escaped,
-        value = value_escaped
+        value = value_escaped,
+        color = color
     )
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{badge_svg, escape_xml_text};
+    use super::{BADGE_WARN_COLOR, badge_svg, badge_svg_with_color, escape_xml_text};
 
     #[test]
     fn badge_svg_contains_label_and_value() {
@@ -96,6 +109,18 @@ mod tests {
         assert!(!svg.contains(value));
     }
 
+    #[test]
+    fn badge_svg_with_color_fills_only_the_value_segment() {
+        let svg = badge_svg_with_color("doc", "8.0%", BADGE_WARN_COLOR);
+        assert!(svg.contains("fill=\"#555\""));
+        assert!(svg.contains(&format!("fill=\"{BADGE_WARN_COLOR}\"")));
+        assert!(!svg.contains("#4c9aff"));
+        assert_eq!(
+            badge_svg("doc", "8.0%"),
+            badge_svg_with_color("doc", "8.0%", "#4c9aff")
+        );
+    }
+
     #[test]
     fn extract_svg_width_reports_malformed_svg() {
         assert!(extract_svg_width("<svg></svg>").is_err());
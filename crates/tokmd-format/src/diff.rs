@@ -4,9 +4,11 @@ use crate::now_ms;
 // -----------------
 
 mod compute;
+mod files;
 mod render;
 
 pub use compute::{compute_diff_rows, compute_diff_totals};
+pub use files::{FileDelta, FileDeltaSummary, compute_file_deltas, render_file_delta_summary};
 pub use render::{DiffColorMode, DiffRenderOptions, render_diff_md, render_diff_md_with_options};
 use tokmd_types::{DiffReceipt, DiffRow, DiffTotals, ToolInfo};
 
@@ -26,6 +28,7 @@ pub fn create_diff_receipt(
         to_source: to_source.to_string(),
         diff_rows: rows,
         totals,
+        api_surface: None,
     }
 }
 
@@ -46,6 +49,10 @@ mod tests {
                 bytes: 100,
                 tokens: 20,
                 avg_lines: 10,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             }],
             total: Totals {
                 code: 10,
@@ -54,6 +61,9 @@ mod tests {
                 bytes: 100,
                 tokens: 20,
                 avg_lines: 10,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
             },
             with_files: false,
             children: ChildrenMode::Collapse,
@@ -69,6 +79,10 @@ mod tests {
                 bytes: 120,
                 tokens: 24,
                 avg_lines: 12,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             }],
             total: Totals {
                 code: 12,
@@ -77,6 +91,9 @@ mod tests {
                 bytes: 120,
                 tokens: 24,
                 avg_lines: 12,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
             },
             with_files: false,
             children: ChildrenMode::Collapse,
@@ -116,6 +133,10 @@ mod tests {
                 bytes: 100,
                 tokens: 20,
                 avg_lines: 10,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             }],
             total: Totals {
                 code: 10,
@@ -124,6 +145,9 @@ mod tests {
                 bytes: 100,
                 tokens: 20,
                 avg_lines: 10,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
             },
             with_files: false,
             children: ChildrenMode::Collapse,
@@ -139,6 +163,10 @@ mod tests {
                     bytes: 120,
                     tokens: 24,
                     avg_lines: 12,
+                    median_lines: None,
+                    p50_lines: None,
+                    p90_lines: None,
+                    file_rows: Vec::new(),
                 },
                 LangRow {
                     lang: "Python".to_string(),
@@ -148,6 +176,10 @@ mod tests {
                     bytes: 80,
                     tokens: 16,
                     avg_lines: 8,
+                    median_lines: None,
+                    p50_lines: None,
+                    p90_lines: None,
+                    file_rows: Vec::new(),
                 },
             ],
             total: Totals {
@@ -157,6 +189,9 @@ mod tests {
                 bytes: 200,
                 tokens: 40,
                 avg_lines: 10,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
             },
             with_files: false,
             children: ChildrenMode::Collapse,
@@ -195,6 +230,9 @@ mod tests {
                 bytes: 0,
                 tokens: 0,
                 avg_lines: 0,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
             },
             with_files: false,
             children: ChildrenMode::Collapse,
@@ -210,6 +248,10 @@ mod tests {
                 bytes: 5000,
                 tokens: 250,
                 avg_lines: 24,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             }],
             total: Totals {
                 code: 100,
@@ -218,6 +260,9 @@ mod tests {
                 bytes: 5000,
                 tokens: 250,
                 avg_lines: 24,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
             },
             with_files: false,
             children: ChildrenMode::Collapse,
@@ -244,6 +289,10 @@ mod tests {
                 bytes: 2000,
                 tokens: 125,
                 avg_lines: 30,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             }],
             total: Totals {
                 code: 50,
@@ -252,6 +301,9 @@ mod tests {
                 bytes: 2000,
                 tokens: 125,
                 avg_lines: 30,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
             },
             with_files: false,
             children: ChildrenMode::Collapse,
@@ -267,6 +319,9 @@ mod tests {
                 bytes: 0,
                 tokens: 0,
                 avg_lines: 0,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
             },
             with_files: false,
             children: ChildrenMode::Collapse,
@@ -293,6 +348,10 @@ mod tests {
                 bytes: 1000,
                 tokens: 250,
                 avg_lines: 100,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             }],
             total: Totals {
                 code: 100,
@@ -301,6 +360,9 @@ mod tests {
                 bytes: 1000,
                 tokens: 250,
                 avg_lines: 100,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
             },
             with_files: false,
             children: ChildrenMode::Collapse,
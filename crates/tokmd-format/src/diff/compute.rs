@@ -20,17 +20,19 @@ use tokmd_types::{DiffRow, DiffTotals, LangReport, LangRow};
 /// let from = LangReport {
 ///     rows: vec![LangRow {
 ///         lang: "Rust".into(), code: 100, lines: 150,
-///         files: 5, bytes: 4000, tokens: 1000, avg_lines: 30,
+///         files: 5, bytes: 4000, tokens: 1000, avg_lines: 30, median_lines: None, p50_lines: None, p90_lines: None,
+///         file_rows: vec![],
 ///     }],
-///     total: Totals { code: 100, lines: 150, files: 5, bytes: 4000, tokens: 1000, avg_lines: 30 },
+///     total: Totals { code: 100, lines: 150, files: 5, bytes: 4000, tokens: 1000, avg_lines: 30, median_lines: None, p50_lines: None, p90_lines: None },
 ///     with_files: true, children: ChildrenMode::Collapse, top: 0,
 /// };
 /// let to = LangReport {
 ///     rows: vec![LangRow {
 ///         lang: "Rust".into(), code: 200, lines: 300,
-///         files: 8, bytes: 8000, tokens: 2000, avg_lines: 38,
+///         files: 8, bytes: 8000, tokens: 2000, avg_lines: 38, median_lines: None, p50_lines: None, p90_lines: None,
+///         file_rows: vec![],
 ///     }],
-///     total: Totals { code: 200, lines: 300, files: 8, bytes: 8000, tokens: 2000, avg_lines: 38 },
+///     total: Totals { code: 200, lines: 300, files: 8, bytes: 8000, tokens: 2000, avg_lines: 38, median_lines: None, p50_lines: None, p90_lines: None },
 ///     with_files: true, children: ChildrenMode::Collapse, top: 0,
 /// };
 ///
@@ -63,6 +65,10 @@ pub fn compute_diff_rows(from_report: &LangReport, to_report: &LangReport) -> Ve
                 bytes: 0,
                 tokens: 0,
                 avg_lines: 0,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             });
             let new = new_row.cloned().unwrap_or_else(|| LangRow {
                 lang: lang_name.clone(),
@@ -72,6 +78,10 @@ pub fn compute_diff_rows(from_report: &LangReport, to_report: &LangReport) -> Ve
                 bytes: 0,
                 tokens: 0,
                 avg_lines: 0,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             });
 
             // Skip if no change
@@ -0,0 +1,269 @@
+//! File-level deltas between two export row sets.
+//!
+//! This module owns the per-path comparison behind `tokmd analyze --delta`:
+//! which files were added, removed, grown, or shrunk (by code lines) since a
+//! previous run, plus a short plain-text summary.
+
+use std::collections::BTreeMap;
+use std::fmt::Write as FmtWrite;
+
+use tokmd_types::{FileKind, FileRow};
+
+/// Code-line change for one file path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileDelta {
+    pub path: String,
+    pub old_code: usize,
+    pub new_code: usize,
+}
+
+impl FileDelta {
+    /// Signed change in code lines.
+    pub fn delta_code(&self) -> i64 {
+        self.new_code as i64 - self.old_code as i64
+    }
+}
+
+/// Files that changed between two row sets, each list sorted by path.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FileDeltaSummary {
+    pub added: Vec<FileDelta>,
+    pub removed: Vec<FileDelta>,
+    pub grown: Vec<FileDelta>,
+    pub shrunk: Vec<FileDelta>,
+}
+
+impl FileDeltaSummary {
+    /// True when no file was added, removed, or resized.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty()
+            && self.removed.is_empty()
+            && self.grown.is_empty()
+            && self.shrunk.is_empty()
+    }
+
+    /// Net change in code lines across every listed file.
+    pub fn delta_code(&self) -> i64 {
+        [&self.added, &self.removed, &self.grown, &self.shrunk]
+            .into_iter()
+            .flatten()
+            .map(FileDelta::delta_code)
+            .sum()
+    }
+}
+
+/// Compare two export row sets by path.
+///
+/// Only parent rows are compared; embedded-language child rows repeat their
+/// parent's path. Files whose code count is unchanged are omitted.
+///
+/// # Examples
+///
+/// ```
+/// use tokmd_types::{FileKind, FileRow};
+/// use tokmd_format::compute_file_deltas;
+///
+/// let row = |path: &str, code: usize| FileRow {
+///     path: path.into(), module: "src".into(), lang: "Rust".into(),
+///     kind: FileKind::Parent, code, comments: 0, blanks: 0,
+///     lines: code, bytes: code * 10, tokens: code * 3,
+///     last_modified_ms: None,
+///     age_days: None,
+///     role: None,
+/// };
+///
+/// let before = vec![row("src/lib.rs", 10), row("src/old.rs", 5)];
+/// let after = vec![row("src/lib.rs", 14), row("src/new.rs", 3)];
+///
+/// let delta = compute_file_deltas(&before, &after);
+/// assert_eq!(delta.added[0].path, "src/new.rs");
+/// assert_eq!(delta.removed[0].path, "src/old.rs");
+/// assert_eq!(delta.grown[0].delta_code(), 4);
+/// assert_eq!(delta.delta_code(), 2);
+/// ```
+pub fn compute_file_deltas(old: &[FileRow], new: &[FileRow]) -> FileDeltaSummary {
+    let old_code = code_by_path(old);
+    let new_code = code_by_path(new);
+    let mut summary = FileDeltaSummary::default();
+
+    for (path, &old) in &old_code {
+        match new_code.get(path) {
+            None => summary.removed.push(delta(path, old, 0)),
+            Some(&new) if new > old => summary.grown.push(delta(path, old, new)),
+            Some(&new) if new < old => summary.shrunk.push(delta(path, old, new)),
+            Some(_) => {}
+        }
+    }
+    for (path, &new) in &new_code {
+        if !old_code.contains_key(path) {
+            summary.added.push(delta(path, 0, new));
+        }
+    }
+
+    summary
+}
+
+fn code_by_path(rows: &[FileRow]) -> BTreeMap<&str, usize> {
+    rows.iter()
+        .filter(|row| row.kind == FileKind::Parent)
+        .map(|row| (row.path.as_str(), row.code))
+        .collect()
+}
+
+fn delta(path: &str, old_code: usize, new_code: usize) -> FileDelta {
+    FileDelta {
+        path: path.to_string(),
+        old_code,
+        new_code,
+    }
+}
+
+/// Render a file delta summary as plain text, one line per changed file.
+pub fn render_file_delta_summary(summary: &FileDeltaSummary) -> String {
+    if summary.is_empty() {
+        return "Delta since last analysis: no file changes\n".to_string();
+    }
+
+    let mut out = String::new();
+    let _ = writeln!(
+        out,
+        "Delta since last analysis: {} added, {} removed, {} grown, {} shrunk (code {})",
+        summary.added.len(),
+        summary.removed.len(),
+        summary.grown.len(),
+        summary.shrunk.len(),
+        format_delta(summary.delta_code())
+    );
+    for d in &summary.added {
+        let _ = writeln!(out, "  + {} ({} code)", d.path, d.new_code);
+    }
+    for d in &summary.removed {
+        let _ = writeln!(out, "  - {} ({} code)", d.path, d.old_code);
+    }
+    for d in summary.grown.iter().chain(&summary.shrunk) {
+        let _ = writeln!(
+            out,
+            "  ~ {} {} -> {} ({})",
+            d.path,
+            d.old_code,
+            d.new_code,
+            format_delta(d.delta_code())
+        );
+    }
+    out
+}
+
+fn format_delta(delta: i64) -> String {
+    if delta > 0 {
+        format!("+{delta}")
+    } else {
+        delta.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(path: &str, kind: FileKind, code: usize) -> FileRow {
+        FileRow {
+            path: path.to_string(),
+            module: "src".to_string(),
+            lang: "Rust".to_string(),
+            kind,
+            code,
+            comments: 0,
+            blanks: 0,
+            lines: code,
+            bytes: code * 10,
+            tokens: code * 3,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
+        }
+    }
+
+    #[test]
+    fn classifies_added_removed_grown_and_shrunk() {
+        let old = vec![
+            row("src/a.rs", FileKind::Parent, 10),
+            row("src/b.rs", FileKind::Parent, 20),
+            row("src/c.rs", FileKind::Parent, 30),
+            row("src/same.rs", FileKind::Parent, 7),
+        ];
+        let new = vec![
+            row("src/b.rs", FileKind::Parent, 25),
+            row("src/c.rs", FileKind::Parent, 12),
+            row("src/d.rs", FileKind::Parent, 4),
+            row("src/same.rs", FileKind::Parent, 7),
+        ];
+
+        let delta = compute_file_deltas(&old, &new);
+        assert_eq!(
+            delta.added,
+            vec![FileDelta {
+                path: "src/d.rs".into(),
+                old_code: 0,
+                new_code: 4
+            }]
+        );
+        assert_eq!(
+            delta.removed,
+            vec![FileDelta {
+                path: "src/a.rs".into(),
+                old_code: 10,
+                new_code: 0
+            }]
+        );
+        assert_eq!(
+            delta.grown,
+            vec![FileDelta {
+                path: "src/b.rs".into(),
+                old_code: 20,
+                new_code: 25
+            }]
+        );
+        assert_eq!(
+            delta.shrunk,
+            vec![FileDelta {
+                path: "src/c.rs".into(),
+                old_code: 30,
+                new_code: 12
+            }]
+        );
+        assert_eq!(delta.delta_code(), 4 - 10 + 5 - 18);
+    }
+
+    #[test]
+    fn child_rows_are_ignored() {
+        let old = vec![row("index.html", FileKind::Parent, 10)];
+        let new = vec![
+            row("index.html", FileKind::Parent, 10),
+            row("index.html", FileKind::Child, 6),
+        ];
+        assert!(compute_file_deltas(&old, &new).is_empty());
+    }
+
+    #[test]
+    fn render_lists_each_changed_file() {
+        let old = vec![row("src/a.rs", FileKind::Parent, 10)];
+        let new = vec![
+            row("src/a.rs", FileKind::Parent, 12),
+            row("src/b.rs", FileKind::Parent, 3),
+        ];
+        let text = render_file_delta_summary(&compute_file_deltas(&old, &new));
+        assert_eq!(
+            text,
+            "Delta since last analysis: 1 added, 0 removed, 1 grown, 0 shrunk (code +5)\n\
+             \x20 + src/b.rs (3 code)\n\
+             \x20 ~ src/a.rs 10 -> 12 (+2)\n"
+        );
+    }
+
+    #[test]
+    fn render_reports_no_changes() {
+        let rows = vec![row("src/a.rs", FileKind::Parent, 10)];
+        let text = render_file_delta_summary(&compute_file_deltas(&rows, &rows));
+        assert_eq!(text, "Delta since last analysis: no file changes\n");
+    }
+}
@@ -7,6 +7,8 @@ use std::fmt::Write as FmtWrite;
 
 use tokmd_types::{DiffRow, DiffTotals};
 
+use crate::analysis::fixed;
+
 fn format_delta(delta: i64) -> String {
     if delta > 0 {
         format!("+{}", delta)
@@ -51,7 +53,12 @@ fn format_delta_colored(delta: i64, mode: DiffColorMode) -> String {
 }
 
 fn format_pct_delta_colored(delta_pct: f64, mode: DiffColorMode) -> String {
-    let raw = format!("{:+.1}%", delta_pct);
+    let shown = fixed(delta_pct, 1);
+    let raw = if shown.starts_with('-') {
+        format!("{shown}%")
+    } else {
+        format!("+{shown}%")
+    };
     if mode == DiffColorMode::Off {
         return raw;
     }
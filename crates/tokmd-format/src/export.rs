@@ -1,11 +1,14 @@
-use std::fs::File;
 use std::io::{self, BufWriter, Write};
+use std::path::Path;
 
 use anyhow::Result;
 
 use tokmd_settings::ScanOptions;
 use tokmd_types::{ExportArgs, ExportData, ExportFormat, RedactMode};
 
+use crate::output_path::create_output_file;
+use crate::{OutputPathVars, expand_output_path, repo_name};
+
 // -----------------
 // Export (datasets)
 // -----------------
@@ -14,23 +17,26 @@ mod csv;
 mod cyclonedx;
 mod json;
 mod jsonl;
+mod paths;
 mod redact;
 
 use csv::write_export_csv;
 use cyclonedx::{write_export_cyclonedx, write_export_cyclonedx_impl};
 use json::write_export_json;
 use jsonl::write_export_jsonl;
+use paths::{write_export_paths, write_export_paths0};
 use redact::redact_rows;
 
 pub use jsonl::write_export_jsonl_to_file;
 
 pub fn write_export(export: &ExportData, global: &ScanOptions, args: &ExportArgs) -> Result<()> {
     match &args.output {
-        Some(path) => {
-            let file = File::create(path)?;
+        Some(template) => {
+            let path = expand_output_path(template, &export_path_vars(args));
+            let file = create_output_file(&path)?;
             let mut out = BufWriter::new(file);
             write_export_to(&mut out, export, global, args)?;
-            out.flush()?;
+            out.into_inner().map_err(|e| e.into_error())?.commit()?;
         }
         None => {
             let stdout = io::stdout();
@@ -42,6 +48,16 @@ pub fn write_export(export: &ExportData, global: &ScanOptions, args: &ExportArgs
     Ok(())
 }
 
+/// Template variables for an export output path.
+fn export_path_vars(args: &ExportArgs) -> OutputPathVars {
+    let format = serde_json::to_value(args.format)
+        .ok()
+        .and_then(|v| v.as_str().map(str::to_string))
+        .unwrap_or_default();
+    let root = args.paths.first().map_or(Path::new("."), |p| p.as_path());
+    OutputPathVars::now(&format, &repo_name(root))
+}
+
 fn write_export_to<W: Write>(
     out: &mut W,
     export: &ExportData,
@@ -53,6 +69,8 @@ fn write_export_to<W: Write>(
         ExportFormat::Jsonl => write_export_jsonl(out, export, global, args),
         ExportFormat::Json => write_export_json(out, export, global, args),
         ExportFormat::Cyclonedx => write_export_cyclonedx(out, export, args.redact),
+        ExportFormat::Paths => write_export_paths(out, export, args.redact),
+        ExportFormat::Paths0 => write_export_paths0(out, export, args.redact),
     }
 }
 
@@ -102,6 +120,26 @@ pub fn write_export_cyclonedx_to<W: Write>(
     write_export_cyclonedx(out, export, redact)
 }
 
+/// Write newline-terminated export paths to a writer (exposed for testing).
+#[doc(hidden)]
+pub fn write_export_paths_to<W: Write>(
+    out: &mut W,
+    export: &ExportData,
+    redact: RedactMode,
+) -> Result<()> {
+    write_export_paths(out, export, redact)
+}
+
+/// Write NUL-terminated export paths to a writer (exposed for testing).
+#[doc(hidden)]
+pub fn write_export_paths0_to<W: Write>(
+    out: &mut W,
+    export: &ExportData,
+    redact: RedactMode,
+) -> Result<()> {
+    write_export_paths0(out, export, redact)
+}
+
 /// Write CycloneDX export to a writer with explicit options (exposed for testing).
 #[doc(hidden)]
 pub fn write_export_cyclonedx_with_options<W: Write>(
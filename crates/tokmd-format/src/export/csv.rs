@@ -20,9 +20,20 @@ pub(super) fn write_export_csv<W: Write>(
     let mut wtr = ::csv::WriterBuilder::new()
         .has_headers(true)
         .from_writer(out);
-    wtr.write_record([
+    // Role and age columns appear only for `--classify` / `--git-age` exports,
+    // so other CSV is unchanged.
+    let with_role = export.rows.iter().any(|r| r.role.is_some());
+    let with_age = export.rows.iter().any(|r| r.last_modified_ms.is_some());
+    let mut header = vec![
         "path", "module", "lang", "kind", "code", "comments", "blanks", "lines", "bytes", "tokens",
-    ])?;
+    ];
+    if with_role {
+        header.push("role");
+    }
+    if with_age {
+        header.extend(["last_modified_ms", "age_days"]);
+    }
+    wtr.write_record(&header)?;
 
     for r in redact_rows(&export.rows, args.redact) {
         let code = r.code.to_string();
@@ -36,18 +47,31 @@ pub(super) fn write_export_csv<W: Write>(
             FileKind::Child => "child",
         };
 
-        wtr.write_record([
-            r.path.as_str(),
-            r.module.as_str(),
-            r.lang.as_str(),
-            kind,
-            &code,
-            &comments,
-            &blanks,
-            &lines,
-            &bytes,
-            &tokens,
-        ])?;
+        let mut record = vec![
+            r.path.to_string(),
+            r.module.to_string(),
+            r.lang.to_string(),
+            kind.to_string(),
+            code,
+            comments,
+            blanks,
+            lines,
+            bytes,
+            tokens,
+        ];
+        if with_role {
+            record.push(
+                r.role
+                    .map(|role| role.as_str().to_string())
+                    .unwrap_or_default(),
+            );
+        }
+        if with_age {
+            let optional = |value: Option<u64>| value.map(|v| v.to_string()).unwrap_or_default();
+            record.push(optional(r.last_modified_ms));
+            record.push(optional(r.age_days));
+        }
+        wtr.write_record(&record)?;
     }
 
     wtr.flush()?;
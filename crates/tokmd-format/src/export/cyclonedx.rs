@@ -8,7 +8,6 @@ use std::io::Write;
 
 use anyhow::Result;
 use serde::Serialize;
-use time::OffsetDateTime;
 use time::format_description::well_known::Rfc3339;
 
 use tokmd_types::{ExportData, FileKind, RedactMode};
@@ -72,7 +71,7 @@ pub(super) fn write_export_cyclonedx_impl<W: Write>(
     timestamp: Option<String>,
 ) -> Result<()> {
     let timestamp = timestamp.unwrap_or_else(|| {
-        OffsetDateTime::now_utc()
+        crate::now_utc()
             .format(&Rfc3339)
             .unwrap_or_else(|_| "1970-01-01T00:00:00Z".to_string())
     });
@@ -116,6 +115,12 @@ pub(super) fn write_export_cyclonedx_impl<W: Write>(
                     value: "child".to_string(),
                 });
             }
+            if let Some(role) = row.role {
+                properties.push(CycloneDxProperty {
+                    name: "tokmd:role".to_string(),
+                    value: role.as_str().to_string(),
+                });
+            }
 
             CycloneDxComponent {
                 ty: "file",
@@ -13,7 +13,10 @@ use tokmd_types::{
     ExportArgs, ExportArgsMeta, ExportData, ExportReceipt, RedactMode, ScanStatus, ToolInfo,
 };
 
-use crate::{now_ms, redact_module_roots, redact_path, scan_args};
+use crate::{
+    now_ms, path_portability_warnings, redact_module_roots, redact_path, rows_integrity_with,
+    scan_args,
+};
 
 use super::redact_rows;
 
@@ -29,13 +32,17 @@ pub(super) fn write_export_json<W: Write>(
         let should_redact = args.redact == RedactMode::Paths || args.redact == RedactMode::All;
         let strip_prefix_redacted = should_redact && args.strip_prefix.is_some();
 
+        let rows: Vec<_> = redact_rows(&export.rows, args.redact)
+            .map(|c| c.into_owned())
+            .collect();
+        let integrity = rows_integrity_with(&rows, global.hash_algo);
         let receipt = ExportReceipt {
             schema_version: tokmd_types::SCHEMA_VERSION,
             generated_at_ms: now_ms(),
             tool: ToolInfo::current(),
             mode: "export".to_string(),
             status: ScanStatus::Complete,
-            warnings: vec![],
+            warnings: path_portability_warnings(&export.rows, args.redact),
             scan: scan_args(&args.paths, global, Some(args.redact)),
             args: ExportArgsMeta {
                 format: args.format,
@@ -43,6 +50,9 @@ pub(super) fn write_export_json<W: Write>(
                 module_depth: export.module_depth,
                 children: export.children,
                 min_code: args.min_code,
+                filters: args.filters,
+                sort_by: args.sort_by,
+                sort_dir: args.sort_dir,
                 max_rows: args.max_rows,
                 redact: args.redact,
                 strip_prefix: if should_redact {
@@ -57,13 +67,15 @@ pub(super) fn write_export_json<W: Write>(
                 strip_prefix_redacted,
             },
             data: ExportData {
-                rows: redact_rows(&export.rows, args.redact)
-                    .map(|c| c.into_owned())
-                    .collect(),
+                rows,
                 module_roots: module_roots.clone(),
                 module_depth: export.module_depth,
                 children: export.children,
             },
+            integrity: Some(integrity),
+            summary: args
+                .summary
+                .then(|| tokmd_model::create_export_summary(&export.rows)),
         };
         writeln!(out, "{}", serde_json::to_string(&receipt)?)?;
     } else {
@@ -4,7 +4,6 @@
 //! by the `run` command. The parent export module keeps format dispatch and the
 //! public test-helper facade stable.
 
-use std::fs::File;
 use std::io::{BufWriter, Write};
 use std::path::Path;
 
@@ -13,10 +12,12 @@ use serde::Serialize;
 
 use tokmd_settings::ScanOptions;
 use tokmd_types::{
-    ExportArgs, ExportArgsMeta, ExportData, FileRow, RedactMode, ScanArgs, ScanStatus, ToolInfo,
+    ExportArgs, ExportArgsMeta, ExportData, ExportSummary, FileRow, RedactMode, ScanArgs,
+    ScanStatus, ToolInfo, Warning,
 };
 
-use crate::{now_ms, redact_module_roots, redact_path, scan_args};
+use crate::output_path::create_output_file;
+use crate::{now_ms, path_portability_warnings, redact_module_roots, redact_path, scan_args};
 
 use super::redact_rows;
 
@@ -29,9 +30,11 @@ struct ExportMeta {
     tool: ToolInfo,
     mode: String,
     status: ScanStatus,
-    warnings: Vec<String>,
+    warnings: Vec<Warning>,
     scan: ScanArgs,
     args: ExportArgsMeta,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    summary: Option<ExportSummary>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -61,7 +64,7 @@ pub(super) fn write_export_jsonl<W: Write>(
             tool: ToolInfo::current(),
             mode: "export".to_string(),
             status: ScanStatus::Complete,
-            warnings: vec![],
+            warnings: path_portability_warnings(&export.rows, args.redact),
             scan: scan_args(&args.paths, global, Some(args.redact)),
             args: ExportArgsMeta {
                 format: args.format,
@@ -69,6 +72,9 @@ pub(super) fn write_export_jsonl<W: Write>(
                 module_depth: export.module_depth,
                 children: export.children,
                 min_code: args.min_code,
+                filters: args.filters,
+                sort_by: args.sort_by,
+                sort_dir: args.sort_dir,
                 max_rows: args.max_rows,
                 redact: args.redact,
                 strip_prefix: if should_redact {
@@ -82,6 +88,9 @@ pub(super) fn write_export_jsonl<W: Write>(
                 },
                 strip_prefix_redacted,
             },
+            summary: args
+                .summary
+                .then(|| tokmd_model::create_export_summary(&export.rows)),
         };
         writeln!(out, "{}", serde_json::to_string(&meta)?)?;
     }
@@ -100,7 +109,7 @@ pub fn write_export_jsonl_to_file(
     scan: &ScanArgs,
     args_meta: &ExportArgsMeta,
 ) -> Result<()> {
-    let file = File::create(path)?;
+    let file = create_output_file(path)?;
     let mut out = BufWriter::new(file);
 
     let mut final_args = args_meta.clone();
@@ -113,15 +122,15 @@ pub fn write_export_jsonl_to_file(
         tool: ToolInfo::current(),
         mode: "export".to_string(),
         status: ScanStatus::Complete,
-        warnings: vec![],
+        warnings: path_portability_warnings(&export.rows, args_meta.redact),
         scan: scan.clone(),
         args: final_args,
+        summary: None,
     };
     writeln!(out, "{}", serde_json::to_string(&meta)?)?;
 
     write_rows(&mut out, export, args_meta.redact)?;
-    out.flush()?;
-    Ok(())
+    out.into_inner().map_err(|e| e.into_error())?.commit()
 }
 
 fn write_rows<W: Write>(out: &mut W, export: &ExportData, redact: RedactMode) -> Result<()> {
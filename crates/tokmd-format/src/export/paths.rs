@@ -0,0 +1,129 @@
+//! Path-list export rendering.
+//!
+//! This module owns the `paths` and `paths0` formats: each exported file path
+//! followed by a newline or a NUL byte. The NUL framing matches
+//! `find -print0`, so paths containing spaces or newlines survive `xargs -0`.
+
+use std::collections::BTreeSet;
+use std::io::Write;
+
+use anyhow::Result;
+
+use tokmd_types::{ExportData, RedactMode};
+
+use super::redact_rows;
+
+pub(super) fn write_export_paths<W: Write>(
+    out: &mut W,
+    export: &ExportData,
+    redact: RedactMode,
+) -> Result<()> {
+    write_paths(out, export, redact, b'\n')
+}
+
+pub(super) fn write_export_paths0<W: Write>(
+    out: &mut W,
+    export: &ExportData,
+    redact: RedactMode,
+) -> Result<()> {
+    write_paths(out, export, redact, b'\0')
+}
+
+fn write_paths<W: Write>(
+    out: &mut W,
+    export: &ExportData,
+    redact: RedactMode,
+    terminator: u8,
+) -> Result<()> {
+    // Embedded-language child rows repeat their parent's path; list each file once.
+    let mut seen = BTreeSet::new();
+    for row in redact_rows(&export.rows, redact) {
+        if seen.insert(row.path.clone()) {
+            out.write_all(row.path.as_bytes())?;
+            out.write_all(&[terminator])?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use tokmd_types::{ChildIncludeMode, FileKind, FileRow};
+
+    use super::*;
+
+    fn row(path: &str, lang: &str, kind: FileKind) -> FileRow {
+        FileRow {
+            path: path.to_string(),
+            module: "src".to_string(),
+            lang: lang.to_string(),
+            kind,
+            code: 1,
+            comments: 0,
+            blanks: 0,
+            lines: 1,
+            bytes: 10,
+            tokens: 3,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
+        }
+    }
+
+    fn export(rows: Vec<FileRow>) -> ExportData {
+        ExportData {
+            rows,
+            module_roots: vec![],
+            module_depth: 1,
+            children: ChildIncludeMode::Separate,
+        }
+    }
+
+    #[test]
+    fn records_are_nul_terminated_without_newlines() {
+        let data = export(vec![
+            row("src/my file.rs", "Rust", FileKind::Parent),
+            row("src/lib.rs", "Rust", FileKind::Parent),
+        ]);
+        let mut out = Vec::new();
+        write_export_paths0(&mut out, &data, RedactMode::None).unwrap();
+
+        assert_eq!(out, b"src/my file.rs\0src/lib.rs\0");
+        assert!(!out.contains(&b'\n'));
+    }
+
+    #[test]
+    fn child_rows_do_not_repeat_paths() {
+        let data = export(vec![
+            row("index.html", "HTML", FileKind::Parent),
+            row("index.html", "JavaScript", FileKind::Child),
+        ]);
+        let mut out = Vec::new();
+        write_export_paths0(&mut out, &data, RedactMode::None).unwrap();
+
+        assert_eq!(out, b"index.html\0");
+    }
+
+    #[test]
+    fn redacted_paths_are_written() {
+        let data = export(vec![row("src/lib.rs", "Rust", FileKind::Parent)]);
+        let mut out = Vec::new();
+        write_export_paths0(&mut out, &data, RedactMode::Paths).unwrap();
+
+        let text = String::from_utf8(out).unwrap();
+        assert!(text.ends_with(".rs\0"));
+        assert!(!text.contains("src/lib.rs"));
+    }
+
+    #[test]
+    fn paths_are_newline_terminated_without_metrics() {
+        let data = export(vec![
+            row("src/main.rs", "Rust", FileKind::Parent),
+            row("src/lib.rs", "Rust", FileKind::Parent),
+        ]);
+        let mut out = Vec::new();
+        write_export_paths(&mut out, &data, RedactMode::None).unwrap();
+
+        assert_eq!(String::from_utf8(out).unwrap(), "src/main.rs\nsrc/lib.rs\n");
+    }
+}
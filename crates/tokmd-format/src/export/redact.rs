@@ -1,7 +1,8 @@
 //! Export row redaction shared by dataset renderers.
 //!
 //! This module owns path/module redaction for file-level export rows. Format
-//! writers consume the iterator so CSV, JSON, JSONL, and CycloneDX stay aligned.
+//! writers consume the iterator so CSV, JSON, JSONL, CycloneDX, and path lists
+//! stay aligned.
 
 use std::borrow::Cow;
 
@@ -26,6 +27,9 @@ pub(super) fn redact_rows(
             lines: r.lines,
             bytes: r.bytes,
             tokens: r.tokens,
+            last_modified_ms: r.last_modified_ms,
+            age_days: r.age_days,
+            role: r.role,
         }),
         RedactMode::All => Cow::Owned(FileRow {
             path: redact_path(&r.path),
@@ -38,6 +42,9 @@ pub(super) fn redact_rows(
             lines: r.lines,
             bytes: r.bytes,
             tokens: r.tokens,
+            last_modified_ms: r.last_modified_ms,
+            age_days: r.age_days,
+            role: r.role,
         }),
     })
 }
@@ -61,6 +68,9 @@ mod tests {
                 lines: 130,
                 bytes: 1000,
                 tokens: 250,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             },
             FileRow {
                 path: "tests/test.rs".to_string(),
@@ -73,6 +83,9 @@ mod tests {
                 lines: 60,
                 bytes: 500,
                 tokens: 125,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             },
         ]
     }
@@ -144,6 +157,9 @@ mod tests {
                 lines: code + comments + blanks,
                 bytes: 1000,
                 tokens: 250,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             }];
 
             for mode in [RedactMode::None, RedactMode::Paths, RedactMode::All] {
@@ -169,6 +185,9 @@ mod tests {
                 lines: 115,
                 bytes: 1000,
                 tokens: 250,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             }];
 
             let redacted: Vec<_> = redact_rows(&rows, RedactMode::Paths).collect();
@@ -190,6 +209,9 @@ mod tests {
                 lines: 115,
                 bytes: 1000,
                 tokens: 250,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             }];
 
             let redacted: Vec<_> = redact_rows(&rows, RedactMode::Paths).collect();
@@ -6,31 +6,68 @@ use std::collections::BTreeMap;
 
 use tokmd_types::{ExportData, FileKind, FileRow};
 
+/// Options for [`render_analysis_tree_with_options`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AnalysisTreeOptions {
+    /// Fold chains of single-subdirectory directories into one node.
+    pub collapse_single_child: bool,
+    /// Append `code/comment/blank` line counts to every node.
+    pub line_breakdown: bool,
+}
+
 #[derive(Default)]
 struct AnalysisNode {
     children: BTreeMap<String, AnalysisNode>,
     lines: usize,
     tokens: usize,
+    code: usize,
+    comments: usize,
+    blanks: usize,
     is_file: bool,
 }
 
-fn insert_analysis(node: &mut AnalysisNode, parts: &[&str], lines: usize, tokens: usize) {
-    node.lines += lines;
-    node.tokens += tokens;
+fn insert_analysis(node: &mut AnalysisNode, parts: &[&str], row: &FileRow) {
+    node.lines += row.lines;
+    node.tokens += row.tokens;
+    node.code += row.code;
+    node.comments += row.comments;
+    node.blanks += row.blanks;
     if let Some((head, tail)) = parts.split_first() {
         let child = node.children.entry((*head).to_string()).or_default();
-        insert_analysis(child, tail, lines, tokens);
+        insert_analysis(child, tail, row);
     } else {
         node.is_file = true;
     }
 }
 
-fn render_analysis(node: &AnalysisNode, name: &str, indent: &str, out: &mut String) {
+fn render_analysis(
+    node: &AnalysisNode,
+    name: &str,
+    indent: &str,
+    options: &AnalysisTreeOptions,
+    out: &mut String,
+) {
+    let mut node = node;
+    let mut name = name.to_string();
+    if options.collapse_single_child && !name.is_empty() {
+        while let Some((child_name, child)) = single_dir_child(node) {
+            name = format!("{name}/{child_name}");
+            node = child;
+        }
+    }
+
     if !name.is_empty() {
         out.push_str(&format!(
-            "{}{} (lines: {}, tokens: {})\n",
+            "{}{} (lines: {}, tokens: {}",
             indent, name, node.lines, node.tokens
         ));
+        if options.line_breakdown {
+            out.push_str(&format!(
+                ", code/comment/blank: {}/{}/{}",
+                node.code, node.comments, node.blanks
+            ));
+        }
+        out.push_str(")\n");
     }
     let next_indent = if name.is_empty() {
         indent.to_string()
@@ -38,8 +75,19 @@ fn render_analysis(node: &AnalysisNode, name: &str, indent: &str, out: &mut Stri
         format!("{indent}  ")
     };
     for (child_name, child) in &node.children {
-        render_analysis(child, child_name, &next_indent, out);
+        render_analysis(child, child_name, &next_indent, options, out);
+    }
+}
+
+/// The only child of a directory node, when that child is itself a directory.
+fn single_dir_child(node: &AnalysisNode) -> Option<(&String, &AnalysisNode)> {
+    if node.is_file || node.children.len() != 1 {
+        return None;
     }
+    node.children
+        .iter()
+        .next()
+        .filter(|(_, child)| !child.is_file && !child.children.is_empty())
 }
 
 /// Render the analysis tree used by `analysis.tree`.
@@ -51,14 +99,43 @@ fn render_analysis(node: &AnalysisNode, name: &str, indent: &str, out: &mut Stri
 /// - Orders siblings lexicographically for deterministic output.
 #[must_use]
 pub fn render_analysis_tree(export: &ExportData) -> String {
+    render_analysis_tree_with(export, false)
+}
+
+/// Render the analysis tree, optionally collapsing single-child directories.
+///
+/// With `collapse_single_child`, a chain of directories that each hold exactly
+/// one subdirectory (and no files) renders as one node named by the joined
+/// path, e.g. `src/a/b/c`. Branching directories are left as-is.
+#[must_use]
+pub fn render_analysis_tree_with(export: &ExportData, collapse_single_child: bool) -> String {
+    render_analysis_tree_with_options(
+        export,
+        &AnalysisTreeOptions {
+            collapse_single_child,
+            ..AnalysisTreeOptions::default()
+        },
+    )
+}
+
+/// Render the analysis tree with the given [`AnalysisTreeOptions`].
+///
+/// With `line_breakdown`, each node also shows the summed code, comment, and
+/// blank lines of the files beneath it, e.g.
+/// `src (lines: 12, tokens: 24, code/comment/blank: 9/2/1)`.
+#[must_use]
+pub fn render_analysis_tree_with_options(
+    export: &ExportData,
+    options: &AnalysisTreeOptions,
+) -> String {
     let mut root = AnalysisNode::default();
     for row in export.rows.iter().filter(|r| r.kind == FileKind::Parent) {
         let parts: Vec<&str> = row.path.split('/').filter(|seg| !seg.is_empty()).collect();
-        insert_analysis(&mut root, &parts, row.lines, row.tokens);
+        insert_analysis(&mut root, &parts, row);
     }
 
     let mut out = String::new();
-    render_analysis(&root, "", "", &mut out);
+    render_analysis(&root, "", "", options, &mut out);
     out
 }
 
@@ -163,6 +240,9 @@ mod tests {
             lines,
             bytes: lines * 10,
             tokens,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         }
     }
 
@@ -198,6 +278,62 @@ mod tests {
         assert!(!out.contains("embedded"));
     }
 
+    #[test]
+    fn analysis_tree_collapses_single_child_chain() {
+        let out = render_analysis_tree_with(
+            &export(vec![row("src/a/b/c/file.rs", FileKind::Parent, 10, 20)]),
+            true,
+        );
+        assert_eq!(
+            out,
+            "src/a/b/c (lines: 10, tokens: 20)\n  file.rs (lines: 10, tokens: 20)\n"
+        );
+    }
+
+    #[test]
+    fn analysis_tree_does_not_collapse_branching_directory() {
+        let out = render_analysis_tree_with(
+            &export(vec![
+                row("src/a/one.rs", FileKind::Parent, 1, 2),
+                row("src/b/two.rs", FileKind::Parent, 3, 4),
+            ]),
+            true,
+        );
+        assert!(out.starts_with("src (lines: 4, tokens: 6)\n"));
+        assert!(out.contains("  a (lines: 1, tokens: 2)\n"));
+        assert!(out.contains("  b (lines: 3, tokens: 4)\n"));
+    }
+
+    #[test]
+    fn analysis_tree_without_collapse_keeps_every_level() {
+        let out = render_analysis_tree(&export(vec![row("src/a/file.rs", FileKind::Parent, 5, 6)]));
+        assert!(out.contains("src (lines: 5, tokens: 6)"));
+        assert!(out.contains("  a (lines: 5, tokens: 6)"));
+    }
+
+    #[test]
+    fn analysis_tree_line_breakdown_aggregates_directories() {
+        let mut a = row("src/a.rs", FileKind::Parent, 10, 20);
+        (a.code, a.comments, a.blanks) = (7, 2, 1);
+        let mut b = row("src/lib/b.rs", FileKind::Parent, 6, 12);
+        (b.code, b.comments, b.blanks) = (3, 1, 2);
+        let options = AnalysisTreeOptions {
+            line_breakdown: true,
+            ..AnalysisTreeOptions::default()
+        };
+
+        let out = render_analysis_tree_with_options(&export(vec![a, b]), &options);
+        assert!(out.starts_with("src (lines: 16, tokens: 32, code/comment/blank: 10/3/3)\n"));
+        assert!(out.contains("  a.rs (lines: 10, tokens: 20, code/comment/blank: 7/2/1)\n"));
+        assert!(out.contains("  lib (lines: 6, tokens: 12, code/comment/blank: 3/1/2)\n"));
+    }
+
+    #[test]
+    fn analysis_tree_omits_line_breakdown_by_default() {
+        let out = render_analysis_tree(&export(vec![row("src/a.rs", FileKind::Parent, 4, 8)]));
+        assert!(!out.contains("code/comment/blank"));
+    }
+
     #[test]
     fn handoff_tree_empty_export_returns_empty() {
         let out = render_handoff_tree(&export(vec![]), 3);
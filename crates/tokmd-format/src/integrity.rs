@@ -0,0 +1,336 @@
+//! Receipt integrity hashing.
+//!
+//! Lang, module, and export receipts carry the same `integrity` block shape
+//! as the analysis receipt: a hex digest plus the number of hashed entries.
+//! Here the entries are the receipt's rows, each hashed as its compact JSON
+//! serialization and separated by `\n`.
+//!
+//! [`IntegrityHasher`] is the shared digest front end, so every integrity
+//! and determinism hash honours the selected [`HashAlgo`] (blake3 by
+//! default, SHA-256 when compliance requires it).
+//!
+//! [`verify_rows_integrity`] recomputes a stored block to detect receipts
+//! whose rows were edited after they were written.
+
+use std::fmt;
+use std::io;
+
+use serde::Serialize;
+use sha2::Digest;
+use tokmd_types::{HashAlgo, IntegrityReport};
+
+/// Incremental hasher for a [`HashAlgo`], producing lowercase hex digests.
+///
+/// # Examples
+///
+/// ```
+/// use tokmd_format::IntegrityHasher;
+/// use tokmd_types::HashAlgo;
+///
+/// let mut hasher = IntegrityHasher::new(HashAlgo::Sha256);
+/// hasher.update(b"abc");
+/// assert_eq!(
+///     hasher.finalize_hex(),
+///     "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+/// );
+/// ```
+pub struct IntegrityHasher {
+    state: HasherState,
+}
+
+enum HasherState {
+    Blake3(Box<blake3::Hasher>),
+    Sha256(sha2::Sha256),
+}
+
+impl IntegrityHasher {
+    pub fn new(algo: HashAlgo) -> Self {
+        let state = match algo {
+            HashAlgo::Blake3 => HasherState::Blake3(Box::default()),
+            HashAlgo::Sha256 => HasherState::Sha256(sha2::Sha256::new()),
+        };
+        Self { state }
+    }
+
+    pub fn algo(&self) -> HashAlgo {
+        match self.state {
+            HasherState::Blake3(_) => HashAlgo::Blake3,
+            HasherState::Sha256(_) => HashAlgo::Sha256,
+        }
+    }
+
+    pub fn update(&mut self, bytes: &[u8]) -> &mut Self {
+        match &mut self.state {
+            HasherState::Blake3(hasher) => {
+                hasher.update(bytes);
+            }
+            HasherState::Sha256(hasher) => hasher.update(bytes),
+        }
+        self
+    }
+
+    /// Lowercase hex digest (64 characters for both algorithms).
+    pub fn finalize_hex(self) -> String {
+        match self.state {
+            HasherState::Blake3(hasher) => hasher.finalize().to_hex().to_string(),
+            HasherState::Sha256(hasher) => format!("{:x}", hasher.finalize()),
+        }
+    }
+}
+
+impl io::Write for IntegrityHasher {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.update(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Hash `rows` in their given (already deterministic) order with blake3.
+///
+/// See [`rows_integrity_with`] to pick the algorithm.
+///
+/// # Examples
+///
+/// ```
+/// use tokmd_format::rows_integrity;
+///
+/// let a = rows_integrity(&["x", "y"]);
+/// assert_eq!(a.algo, "blake3");
+/// assert_eq!(a.entries, 2);
+/// assert_eq!(a, rows_integrity(&["x", "y"]));
+/// assert_ne!(a.hash, rows_integrity(&["x", "z"]).hash);
+/// ```
+pub fn rows_integrity<T: Serialize>(rows: &[T]) -> IntegrityReport {
+    rows_integrity_with(rows, HashAlgo::default())
+}
+
+/// Hash `rows` in their given order with `algo`; `algo` is recorded in the
+/// report.
+pub fn rows_integrity_with<T: Serialize>(rows: &[T], algo: HashAlgo) -> IntegrityReport {
+    let mut hasher = IntegrityHasher::new(algo);
+    for (idx, row) in rows.iter().enumerate() {
+        if idx > 0 {
+            hasher.update(b"\n");
+        }
+        // Plain data rows always serialize; an empty entry keeps the count honest.
+        let bytes = serde_json::to_vec(row).unwrap_or_default();
+        hasher.update(&bytes);
+    }
+
+    IntegrityReport {
+        algo: algo.as_str().to_string(),
+        hash: hasher.finalize_hex(),
+        entries: rows.len(),
+    }
+}
+
+/// Why a recorded integrity block does not match its entries.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IntegrityMismatch {
+    /// The receipt carries no integrity block.
+    Missing,
+    /// `algo` names a hash tokmd cannot compute.
+    UnknownAlgo(String),
+    /// The number of entries differs from the recorded `entries`.
+    Entries { expected: usize, actual: usize },
+    /// The recomputed digest differs from the recorded `hash`.
+    Hash { expected: String, actual: String },
+}
+
+impl fmt::Display for IntegrityMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Missing => write!(f, "receipt has no integrity block"),
+            Self::UnknownAlgo(algo) => write!(f, "unsupported integrity algorithm '{algo}'"),
+            Self::Entries { expected, actual } => write!(
+                f,
+                "integrity entry count mismatch: recorded {expected}, found {actual}"
+            ),
+            Self::Hash { expected, actual } => write!(
+                f,
+                "integrity hash mismatch: recorded {expected}, recomputed {actual}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for IntegrityMismatch {}
+
+/// Algorithm named by a recorded integrity block.
+pub fn integrity_algo(report: &IntegrityReport) -> Result<HashAlgo, IntegrityMismatch> {
+    match report.algo.as_str() {
+        "blake3" => Ok(HashAlgo::Blake3),
+        "sha256" => Ok(HashAlgo::Sha256),
+        other => Err(IntegrityMismatch::UnknownAlgo(other.to_string())),
+    }
+}
+
+/// Compare a recorded integrity block with one recomputed from the entries.
+///
+/// The entry count is checked first since it explains most mismatches.
+pub fn check_integrity(
+    recorded: &IntegrityReport,
+    recomputed: &IntegrityReport,
+) -> Result<(), IntegrityMismatch> {
+    if recorded.entries != recomputed.entries {
+        return Err(IntegrityMismatch::Entries {
+            expected: recorded.entries,
+            actual: recomputed.entries,
+        });
+    }
+    if !recorded.hash.eq_ignore_ascii_case(&recomputed.hash) {
+        return Err(IntegrityMismatch::Hash {
+            expected: recorded.hash.clone(),
+            actual: recomputed.hash.clone(),
+        });
+    }
+    Ok(())
+}
+
+/// Recompute a lang, module, or export receipt's `integrity` over its rows
+/// with the recorded algorithm and compare.
+///
+/// # Examples
+///
+/// ```
+/// use tokmd_format::{IntegrityMismatch, rows_integrity, verify_rows_integrity};
+///
+/// let report = rows_integrity(&["x", "y"]);
+/// assert_eq!(verify_rows_integrity(&report, &["x", "y"]), Ok(()));
+/// assert!(matches!(
+///     verify_rows_integrity(&report, &["x", "z"]),
+///     Err(IntegrityMismatch::Hash { .. })
+/// ));
+/// ```
+pub fn verify_rows_integrity<T: Serialize>(
+    recorded: &IntegrityReport,
+    rows: &[T],
+) -> Result<(), IntegrityMismatch> {
+    let algo = integrity_algo(recorded)?;
+    check_integrity(recorded, &rows_integrity_with(rows, algo))
+}
+
+#[cfg(test)]
+mod tests {
+    use tokmd_types::{FileKind, FileRow};
+
+    use super::*;
+
+    fn row(path: &str, code: usize) -> FileRow {
+        FileRow {
+            path: path.to_string(),
+            module: "src".to_string(),
+            lang: "Rust".to_string(),
+            kind: FileKind::Parent,
+            code,
+            comments: 0,
+            blanks: 0,
+            lines: code,
+            bytes: code * 10,
+            tokens: code * 3,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
+        }
+    }
+
+    #[test]
+    fn identical_rows_hash_identically() {
+        let rows = vec![row("src/a.rs", 10), row("src/b.rs", 20)];
+        let first = rows_integrity(&rows);
+        let second = rows_integrity(&rows.clone());
+
+        assert_eq!(first, second);
+        assert_eq!(first.entries, 2);
+        assert_eq!(first.hash.len(), 64);
+    }
+
+    #[test]
+    fn changed_row_changes_hash() {
+        let before = rows_integrity(&[row("src/a.rs", 10), row("src/b.rs", 20)]);
+        let after = rows_integrity(&[row("src/a.rs", 10), row("src/b.rs", 21)]);
+        assert_ne!(before.hash, after.hash);
+    }
+
+    #[test]
+    fn row_boundaries_are_part_of_the_hash() {
+        let one = rows_integrity(&[row("src/a.rs", 10)]);
+        let two = rows_integrity(&[row("src/a.rs", 10), row("src/a.rs", 10)]);
+        assert_ne!(one.hash, two.hash);
+        assert_eq!(rows_integrity::<FileRow>(&[]).entries, 0);
+    }
+
+    #[test]
+    fn sha256_is_labelled_and_hex_encoded() {
+        let rows = vec![row("src/a.rs", 10)];
+        let report = rows_integrity_with(&rows, HashAlgo::Sha256);
+
+        assert_eq!(report.algo, "sha256");
+        assert_eq!(report.hash.len(), 64);
+        assert!(report.hash.bytes().all(|b| b.is_ascii_hexdigit()));
+        assert!(!report.hash.bytes().any(|b| b.is_ascii_uppercase()));
+        assert_ne!(report.hash, rows_integrity(&rows).hash);
+    }
+
+    #[test]
+    fn hasher_matches_one_shot_digests() {
+        let mut blake = IntegrityHasher::new(HashAlgo::Blake3);
+        blake.update(b"ab").update(b"c");
+        assert_eq!(blake.algo(), HashAlgo::Blake3);
+        assert_eq!(
+            blake.finalize_hex(),
+            blake3::hash(b"abc").to_hex().to_string()
+        );
+
+        let mut sha = IntegrityHasher::new(HashAlgo::Sha256);
+        std::io::copy(&mut &b"abc"[..], &mut sha).unwrap();
+        assert_eq!(
+            sha.finalize_hex(),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[test]
+    fn verify_accepts_matching_rows_for_each_algo() {
+        let rows = vec![row("src/a.rs", 10), row("src/b.rs", 20)];
+        for algo in [HashAlgo::Blake3, HashAlgo::Sha256] {
+            let report = rows_integrity_with(&rows, algo);
+            assert_eq!(verify_rows_integrity(&report, &rows), Ok(()));
+        }
+    }
+
+    #[test]
+    fn verify_reports_tampered_rows() {
+        let rows = vec![row("src/a.rs", 10), row("src/b.rs", 20)];
+        let report = rows_integrity_with(&rows, HashAlgo::Sha256);
+
+        let edited = vec![row("src/a.rs", 10), row("src/b.rs", 2)];
+        let err = verify_rows_integrity(&report, &edited).unwrap_err();
+        assert!(
+            matches!(&err, IntegrityMismatch::Hash { expected, .. } if *expected == report.hash)
+        );
+        assert!(err.to_string().starts_with("integrity hash mismatch"));
+
+        assert_eq!(
+            verify_rows_integrity(&report, &rows[..1]),
+            Err(IntegrityMismatch::Entries {
+                expected: 2,
+                actual: 1
+            })
+        );
+    }
+
+    #[test]
+    fn verify_rejects_unknown_algo() {
+        let mut report = rows_integrity(&["x"]);
+        report.algo = "md5".to_string();
+        assert_eq!(
+            verify_rows_integrity(&report, &["x"]),
+            Err(IntegrityMismatch::UnknownAlgo("md5".to_string()))
+        );
+    }
+}
@@ -9,6 +9,7 @@
 //! * Serialization logic (JSON/CSV/CycloneDX)
 //! * Markdown and TSV table rendering
 //! * Output file writing
+//! * Upgrading older core receipts to the current schema
 //! * Redaction integration (via internal `redact` module)
 //! * ScanArgs integration (via internal `scan_args` module)
 //! * Analysis receipt rendering under [`analysis`]
@@ -18,8 +19,7 @@
 //! * CLI argument parsing
 //! * Analysis computation (use tokmd-analysis)
 
-use std::time::{SystemTime, UNIX_EPOCH};
-
+use tokmd_analysis_types::now_ms;
 use tokmd_types::RedactMode;
 
 pub mod analysis;
@@ -29,30 +29,55 @@ mod export;
 pub mod export_tree;
 #[cfg(feature = "fun")]
 pub mod fun;
+mod integrity;
+mod migrate;
+mod output_path;
 mod packet_siblings;
+mod portability;
 pub mod redact;
 pub mod scan_args;
+mod status_style;
+mod strict;
 mod summary;
+mod timestamp;
 pub mod tokmd_packets;
 
-pub use badge::badge_svg;
+pub use badge::{BADGE_OK_COLOR, BADGE_WARN_COLOR, badge_svg, badge_svg_with_color};
 pub use diff::{
-    DiffColorMode, DiffRenderOptions, compute_diff_rows, compute_diff_totals, create_diff_receipt,
-    render_diff_md, render_diff_md_with_options,
+    DiffColorMode, DiffRenderOptions, FileDelta, FileDeltaSummary, compute_diff_rows,
+    compute_diff_totals, compute_file_deltas, create_diff_receipt, render_diff_md,
+    render_diff_md_with_options, render_file_delta_summary,
 };
 pub use export::{
     write_export, write_export_csv_to, write_export_cyclonedx_to,
     write_export_cyclonedx_with_options, write_export_json_to, write_export_jsonl_to,
-    write_export_jsonl_to_file,
+    write_export_jsonl_to_file, write_export_paths_to, write_export_paths0_to,
+};
+pub use export_tree::{
+    AnalysisTreeOptions, render_analysis_tree, render_analysis_tree_with,
+    render_analysis_tree_with_options, render_handoff_tree,
+};
+pub use integrity::{
+    IntegrityHasher, IntegrityMismatch, check_integrity, integrity_algo, rows_integrity,
+    rows_integrity_with, verify_rows_integrity,
 };
-pub use export_tree::{render_analysis_tree, render_handoff_tree};
+pub use migrate::{
+    MIN_SUPPORTED_SCHEMA_VERSION, UnsupportedSchemaVersion, is_supported_schema_version,
+    migrate_receipt, parse_receipt,
+};
+pub use output_path::{OutputPathVars, expand_output_path, repo_name};
 pub use packet_siblings::resolve_preset_input;
+pub use portability::{WINDOWS_MAX_PATH, path_portability_warnings};
 pub use redact::{redact_path, short_hash};
 pub use scan_args::{normalize_scan_input, scan_args};
+pub use status_style::{StatusStyle, StatusValue};
+pub use strict::{UnknownFields, parse_receipt_strict, unknown_fields};
 pub use summary::{
-    print_lang_report, print_module_report, write_lang_json_to_file, write_lang_report_to,
-    write_module_json_to_file, write_module_report_to,
+    SummarySnapshot, append_summary_row, print_lang_report, print_module_report,
+    write_lang_json_to_file, write_lang_report_to, write_module_json_to_file,
+    write_module_report_to,
 };
+pub use timestamp::{TimestampFormat, TimestampStyle, now_utc};
 pub use tokmd_packets::{
     preset_title, render_packet_bundle_markdown, render_packet_preset_markdown, validate_manifest,
 };
@@ -65,13 +90,6 @@ fn redact_module_roots(roots: &[String], redact: RedactMode) -> Vec<String> {
     }
 }
 
-fn now_ms() -> u128 {
-    SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .unwrap_or_default()
-        .as_millis()
-}
-
 #[cfg(test)]
 mod tests {
     use std::path::Path;
@@ -0,0 +1,220 @@
+//! Upgrading older core receipts to the current [`SCHEMA_VERSION`].
+//!
+//! Migrations run on the raw JSON before it is deserialized, one version step
+//! at a time, filling fields the older schema did not record with the values
+//! an empty scan would produce. Receipts from a newer tokmd are rejected with
+//! [`UnsupportedSchemaVersion`] rather than being read half-understood.
+
+use std::fmt;
+
+use anyhow::{Context, Result};
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+use tokmd_types::{SCHEMA_VERSION, Warning};
+
+/// Oldest core receipt `schema_version` that [`migrate_receipt`] can upgrade.
+pub const MIN_SUPPORTED_SCHEMA_VERSION: u32 = 1;
+
+/// Whether a core receipt recorded with `version` can be read by this build,
+/// after migration if it is older than [`SCHEMA_VERSION`].
+pub fn is_supported_schema_version(version: u64) -> bool {
+    (u64::from(MIN_SUPPORTED_SCHEMA_VERSION)..=u64::from(SCHEMA_VERSION)).contains(&version)
+}
+
+/// A receipt's `schema_version` is one this tokmd cannot read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnsupportedSchemaVersion {
+    /// Version recorded in the receipt.
+    pub found: u64,
+    /// Newest version this build understands.
+    pub supported: u32,
+}
+
+impl fmt::Display for UnsupportedSchemaVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.found == 0 {
+            write!(f, "receipt schema_version 0 is not a valid tokmd schema")
+        } else {
+            write!(
+                f,
+                "receipt schema_version {} is newer than this tokmd supports ({}); upgrade tokmd to read it",
+                self.found, self.supported
+            )
+        }
+    }
+}
+
+impl std::error::Error for UnsupportedSchemaVersion {}
+
+/// Upgrade a core receipt (`lang`, `module`, or `export`) in place.
+///
+/// Values without a numeric top-level `schema_version`, such as a bare list of
+/// export rows, are left untouched. Returns the version the receipt was
+/// recorded with.
+pub fn migrate_receipt(value: &mut Value) -> Result<Option<u64>, UnsupportedSchemaVersion> {
+    let Some(found) = value.get("schema_version").and_then(Value::as_u64) else {
+        return Ok(None);
+    };
+    if !is_supported_schema_version(found) {
+        return Err(UnsupportedSchemaVersion {
+            found,
+            supported: SCHEMA_VERSION,
+        });
+    }
+    let Some(receipt) = value.as_object_mut() else {
+        return Ok(Some(found));
+    };
+
+    if found < 2 {
+        migrate_v1_to_v2(receipt);
+    }
+    if found < 3 {
+        migrate_v2_to_v3(receipt);
+    }
+    receipt.insert("schema_version".to_string(), Value::from(SCHEMA_VERSION));
+    Ok(Some(found))
+}
+
+/// Parse a core receipt, upgrading it first if it predates [`SCHEMA_VERSION`].
+///
+/// A too-new receipt fails with an error that downcasts to
+/// [`UnsupportedSchemaVersion`].
+pub fn parse_receipt<T: DeserializeOwned>(content: &str) -> Result<T> {
+    let mut value: Value = serde_json::from_str(content).context("receipt is not valid JSON")?;
+    migrate_receipt(&mut value)?;
+    Ok(serde_json::from_value(value)?)
+}
+
+/// v2 added `bytes` and `tokens` to every row, and `bytes`, `tokens`, and
+/// `avg_lines` to totals. v1 never measured either, so both become zero.
+fn migrate_v1_to_v2(receipt: &mut Map<String, Value>) {
+    if let Some(Value::Array(rows)) = receipt.get_mut("rows") {
+        for row in rows.iter_mut().filter_map(Value::as_object_mut) {
+            fill_size_fields(row);
+            if let Some(Value::Array(files)) = row.get_mut("file_rows") {
+                for file in files.iter_mut().filter_map(Value::as_object_mut) {
+                    fill_size_fields(file);
+                }
+            }
+        }
+    }
+    if let Some(Value::Object(total)) = receipt.get_mut("total") {
+        fill_size_fields(total);
+    }
+}
+
+/// v3 replaced plain warning strings with structured warnings. Old messages
+/// carry no code, so they become `unclassified` warnings.
+fn migrate_v2_to_v3(receipt: &mut Map<String, Value>) {
+    if let Some(Value::Array(warnings)) = receipt.get_mut("warnings") {
+        for warning in warnings.iter_mut() {
+            if let Value::String(message) = warning {
+                let structured = Warning::from(std::mem::take(message));
+                *warning = serde_json::to_value(structured).unwrap_or_default();
+            }
+        }
+    }
+}
+
+fn fill_size_fields(row: &mut Map<String, Value>) {
+    row.entry("bytes").or_insert(Value::from(0));
+    row.entry("tokens").or_insert(Value::from(0));
+    // File rows carry no `files` count and never had an average.
+    if let Some(files) = row.get("files").and_then(Value::as_u64) {
+        let lines = row.get("lines").and_then(Value::as_u64).unwrap_or(0);
+        row.entry("avg_lines")
+            .or_insert(Value::from(avg_lines(lines, files)));
+    }
+}
+
+/// Mean lines per file, rounded half up, as tokmd-model computes it.
+fn avg_lines(lines: u64, files: u64) -> u64 {
+    if files == 0 {
+        return 0;
+    }
+    let (quotient, remainder) = (lines / files, lines % files);
+    if remainder >= files - remainder {
+        quotient + 1
+    } else {
+        quotient
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn current_receipts_pass_through() {
+        let mut value = json!({"schema_version": SCHEMA_VERSION, "rows": [{"code": 1}]});
+        let before = value.clone();
+        assert_eq!(
+            migrate_receipt(&mut value),
+            Ok(Some(u64::from(SCHEMA_VERSION)))
+        );
+        assert_eq!(value, before);
+    }
+
+    #[test]
+    fn supported_range_runs_from_oldest_migration_to_current() {
+        assert!(!is_supported_schema_version(0));
+        assert!(is_supported_schema_version(u64::from(
+            MIN_SUPPORTED_SCHEMA_VERSION
+        )));
+        assert!(is_supported_schema_version(u64::from(SCHEMA_VERSION)));
+        assert!(!is_supported_schema_version(u64::from(SCHEMA_VERSION) + 1));
+    }
+
+    #[test]
+    fn bare_values_are_left_alone() {
+        let mut value = json!([{"path": "src/lib.rs"}]);
+        assert_eq!(migrate_receipt(&mut value), Ok(None));
+    }
+
+    #[test]
+    fn v1_rows_and_totals_gain_size_fields() {
+        let mut value = json!({
+            "schema_version": 1,
+            "rows": [{"lang": "Rust", "code": 10, "lines": 7, "files": 2,
+                      "file_rows": [{"path": "a.rs", "lines": 4}]}],
+            "total": {"code": 10, "lines": 7, "files": 2, "avg_lines": 9},
+        });
+        migrate_receipt(&mut value).unwrap();
+
+        assert_eq!(value["schema_version"], json!(SCHEMA_VERSION));
+        let row = &value["rows"][0];
+        assert_eq!((&row["bytes"], &row["tokens"]), (&json!(0), &json!(0)));
+        assert_eq!(row["avg_lines"], json!(4));
+        assert_eq!(row["file_rows"][0]["bytes"], json!(0));
+        assert!(row["file_rows"][0].get("avg_lines").is_none());
+        // A recorded value is never overwritten.
+        assert_eq!(value["total"]["avg_lines"], json!(9));
+    }
+
+    #[test]
+    fn v2_warning_strings_become_unclassified_warnings() {
+        let mut value = json!({"schema_version": 2, "warnings": ["git scan failed"]});
+        migrate_receipt(&mut value).unwrap();
+
+        assert_eq!(
+            value["warnings"],
+            json!([{"code": "unclassified", "severity": "warning", "message": "git scan failed"}])
+        );
+    }
+
+    #[test]
+    fn future_and_zero_versions_are_rejected() {
+        for found in [0, u64::from(SCHEMA_VERSION) + 1] {
+            let mut value = json!({"schema_version": found});
+            assert_eq!(
+                migrate_receipt(&mut value),
+                Err(UnsupportedSchemaVersion {
+                    found,
+                    supported: SCHEMA_VERSION,
+                })
+            );
+        }
+    }
+}
@@ -0,0 +1,274 @@
+//! Output path handling.
+//!
+//! Output paths may contain `{date}`, `{time}`, `{format}`, and `{repo}` so
+//! scheduled runs write a distinct file each time, e.g.
+//! `reports/{date}-{repo}.json`. Unknown `{...}` segments are left as-is.
+//! Every file writer in this crate opens its output through
+//! [`create_output_file`], which creates missing parent directories and
+//! writes atomically: bytes go to a temp file beside the target, which is
+//! renamed over it only on [`OutputFile::commit`]. Readers therefore see
+//! either the previous file or the complete new one, never a partial write.
+
+use std::fs::File;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use anyhow::{Context, Result};
+use time::OffsetDateTime;
+use time::macros::format_description;
+
+/// Values substituted into an output path template.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutputPathVars {
+    /// UTC date, `YYYY-MM-DD`.
+    pub date: String,
+    /// UTC time, `HHMMSS` (no colons, so it is safe in file names).
+    pub time: String,
+    /// Output format name, e.g. `json` or `jsonl`.
+    pub format: String,
+    /// Repository (or scan root) directory name.
+    pub repo: String,
+}
+
+impl OutputPathVars {
+    /// Variables for the current UTC time.
+    pub fn now(format: &str, repo: &str) -> Self {
+        Self::at(crate::now_utc(), format, repo)
+    }
+
+    fn at(when: OffsetDateTime, format: &str, repo: &str) -> Self {
+        let date = when
+            .format(format_description!("[year]-[month]-[day]"))
+            .unwrap_or_else(|_| "1970-01-01".to_string());
+        let time = when
+            .format(format_description!("[hour][minute][second]"))
+            .unwrap_or_else(|_| "000000".to_string());
+        Self {
+            date,
+            time,
+            format: format.to_string(),
+            repo: repo.replace(['/', '\\'], "_"),
+        }
+    }
+}
+
+/// Expand template variables in `template`.
+///
+/// Paths without a `{` are returned unchanged (including non-UTF-8 paths).
+///
+/// # Examples
+///
+/// ```
+/// use std::path::{Path, PathBuf};
+/// use tokmd_format::{OutputPathVars, expand_output_path};
+///
+/// let vars = OutputPathVars {
+///     date: "2026-01-31".into(),
+///     time: "235959".into(),
+///     format: "json".into(),
+///     repo: "tokmd".into(),
+/// };
+/// assert_eq!(
+///     expand_output_path(Path::new("reports/{date}-{repo}.{format}"), &vars),
+///     PathBuf::from("reports/2026-01-31-tokmd.json"),
+/// );
+/// ```
+pub fn expand_output_path(template: &Path, vars: &OutputPathVars) -> PathBuf {
+    let Some(text) = template.to_str().filter(|t| t.contains('{')) else {
+        return template.to_path_buf();
+    };
+    PathBuf::from(
+        text.replace("{date}", &vars.date)
+            .replace("{time}", &vars.time)
+            .replace("{format}", &vars.format)
+            .replace("{repo}", &vars.repo),
+    )
+}
+
+/// Open an atomic writer for `path`, creating its parent directories first.
+///
+/// Nothing appears at `path` until [`OutputFile::commit`] succeeds; dropping
+/// the writer without committing removes the temp file.
+pub(crate) fn create_output_file(path: &Path) -> Result<OutputFile> {
+    let parent = path.parent().filter(|p| !p.as_os_str().is_empty());
+    if let Some(parent) = parent {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create {}", parent.display()))?;
+    }
+
+    let temp = temp_path_for(path);
+    let file = File::create(&temp)
+        .with_context(|| format!("Failed to create temp file {}", temp.display()))?;
+    Ok(OutputFile {
+        file,
+        temp,
+        target: path.to_path_buf(),
+        committed: false,
+    })
+}
+
+/// Temp file in the target's directory, so the final rename never crosses
+/// filesystems. The pid and a counter keep concurrent writers apart.
+fn temp_path_for(target: &Path) -> PathBuf {
+    static NEXT: AtomicU64 = AtomicU64::new(0);
+    let name = target
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "output".to_string());
+    let unique = NEXT.fetch_add(1, Ordering::Relaxed);
+    target.with_file_name(format!(".{name}.{}.{unique}.tmp", std::process::id()))
+}
+
+/// Atomic file writer returned by [`create_output_file`].
+#[derive(Debug)]
+pub(crate) struct OutputFile {
+    file: File,
+    temp: PathBuf,
+    target: PathBuf,
+    committed: bool,
+}
+
+impl OutputFile {
+    /// Flush the temp file, sync it to disk, and rename it over the target,
+    /// so a crash never leaves a partly written target behind.
+    pub(crate) fn commit(mut self) -> Result<()> {
+        self.file.flush()?;
+        self.file
+            .sync_all()
+            .with_context(|| format!("Failed to sync {}", self.temp.display()))?;
+        std::fs::rename(&self.temp, &self.target)
+            .with_context(|| format!("Failed to write {}", self.target.display()))?;
+        self.committed = true;
+        Ok(())
+    }
+}
+
+impl Write for OutputFile {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.file.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.file.flush()
+    }
+}
+
+impl Drop for OutputFile {
+    fn drop(&mut self) {
+        if !self.committed {
+            let _ = std::fs::remove_file(&self.temp);
+        }
+    }
+}
+
+/// Name used for `{repo}`: the enclosing git repository's directory name, or
+/// the scan root's own name when it is not inside a repository.
+pub fn repo_name(scan_root: &Path) -> String {
+    let root = std::fs::canonicalize(scan_root).unwrap_or_else(|_| scan_root.to_path_buf());
+    let repo_root = root
+        .ancestors()
+        .find(|dir| dir.join(".git").exists())
+        .unwrap_or(&root);
+    repo_root
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "repo".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use time::macros::datetime;
+
+    use super::*;
+
+    #[test]
+    fn vars_format_date_and_time_in_utc() {
+        let vars = OutputPathVars::at(datetime!(2026-03-04 05:06:07 UTC), "jsonl", "tokmd");
+        assert_eq!(vars.date, "2026-03-04");
+        assert_eq!(vars.time, "050607");
+        assert_eq!(vars.format, "jsonl");
+    }
+
+    #[test]
+    fn repo_separators_cannot_add_directories() {
+        let vars = OutputPathVars::at(datetime!(2026-03-04 05:06:07 UTC), "json", "a/b\\c");
+        assert_eq!(vars.repo, "a_b_c");
+    }
+
+    #[test]
+    fn unknown_placeholders_are_kept() {
+        let vars = OutputPathVars::at(datetime!(2026-03-04 05:06:07 UTC), "json", "r");
+        assert_eq!(
+            expand_output_path(Path::new("out/{branch}-{time}.json"), &vars),
+            PathBuf::from("out/{branch}-050607.json")
+        );
+    }
+
+    fn dir_entries(dir: &Path) -> Vec<String> {
+        let mut names: Vec<String> = std::fs::read_dir(dir)
+            .unwrap()
+            .map(|e| e.unwrap().file_name().to_string_lossy().into_owned())
+            .collect();
+        names.sort();
+        names
+    }
+
+    #[test]
+    fn create_output_file_creates_missing_parents() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("a/b/c/out.json");
+
+        let mut file = create_output_file(&path).unwrap();
+        file.write_all(b"{}").unwrap();
+        file.commit().unwrap();
+
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "{}");
+    }
+
+    #[test]
+    fn target_appears_only_after_commit() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("report.json");
+        std::fs::write(&path, "old").unwrap();
+
+        let mut file = create_output_file(&path).unwrap();
+        file.write_all(b"{\"partial\":").unwrap();
+        file.flush().unwrap();
+        // Mid-write, readers still see the previous complete file.
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "old");
+        assert_eq!(dir_entries(dir.path()).len(), 2);
+
+        file.write_all(b"true}").unwrap();
+        file.commit().unwrap();
+
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "{\"partial\":true}"
+        );
+        assert_eq!(dir_entries(dir.path()), vec!["report.json".to_string()]);
+    }
+
+    #[test]
+    fn dropping_without_commit_removes_temp_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("report.json");
+
+        let mut file = create_output_file(&path).unwrap();
+        file.write_all(b"half").unwrap();
+        drop(file);
+
+        assert!(dir_entries(dir.path()).is_empty());
+    }
+
+    #[test]
+    fn repo_name_prefers_enclosing_git_root() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = dir.path().join("myrepo");
+        std::fs::create_dir_all(repo.join(".git")).unwrap();
+        std::fs::create_dir_all(repo.join("src")).unwrap();
+
+        assert_eq!(repo_name(&repo.join("src")), "myrepo");
+        assert_eq!(repo_name(&repo), "myrepo");
+    }
+}
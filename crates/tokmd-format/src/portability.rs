@@ -0,0 +1,206 @@
+//! Windows path portability checks for export receipts.
+//!
+//! A tree scanned on Linux or macOS can hold paths that fail to check out on
+//! Windows: anything longer than `MAX_PATH`, segments named after reserved
+//! devices (`CON`, `NUL`, `COM1`, ...), and segments ending in a dot or space.
+//! Export receipts list such paths in `warnings`, under the
+//! [`NON_PORTABLE_PATH`] code; they never fail the scan.
+
+use tokmd_types::{FileKind, FileRow, NON_PORTABLE_PATH, RedactMode, Warning, WarningSeverity};
+
+use crate::redact_path;
+
+/// Windows `MAX_PATH`, in characters.
+pub const WINDOWS_MAX_PATH: usize = 260;
+
+/// Device names Windows reserves in every directory, with or without an
+/// extension (`nul.txt` is as unusable as `NUL`).
+const RESERVED_NAMES: [&str; 22] = [
+    "CON", "PRN", "AUX", "NUL", "COM1", "COM2", "COM3", "COM4", "COM5", "COM6", "COM7", "COM8",
+    "COM9", "LPT1", "LPT2", "LPT3", "LPT4", "LPT5", "LPT6", "LPT7", "LPT8", "LPT9",
+];
+
+/// Warnings for file rows whose paths would break a Windows checkout.
+///
+/// Only `FileKind::Parent` rows are checked. When `redact` hides paths, the
+/// path is shown redacted and offending segments are not named, so warnings
+/// never leak what the rows conceal.
+///
+/// # Examples
+///
+/// ```
+/// use tokmd_format::path_portability_warnings;
+/// use tokmd_types::{FileKind, FileRow, RedactMode};
+///
+/// let row = FileRow {
+///     path: "docs/con.md".into(),
+///     module: "docs".into(),
+///     lang: "Markdown".into(),
+///     kind: FileKind::Parent,
+///     code: 1,
+///     comments: 0,
+///     blanks: 0,
+///     lines: 1,
+///     bytes: 10,
+///     tokens: 3,
+///     last_modified_ms: None,
+///     age_days: None,
+///     role: None,
+/// };
+/// let warnings = path_portability_warnings(&[row], RedactMode::None);
+/// assert_eq!(warnings[0].code, "non_portable_path");
+/// assert_eq!(
+///     warnings[0].to_string(),
+///     "'con.md' is a reserved name on Windows (docs/con.md)"
+/// );
+/// ```
+pub fn path_portability_warnings(rows: &[FileRow], redact: RedactMode) -> Vec<Warning> {
+    let mut warnings = Vec::new();
+    for row in rows.iter().filter(|r| r.kind == FileKind::Parent) {
+        let redacted = redact != RedactMode::None;
+        let problems = path_problems(&row.path, redacted);
+        if problems.is_empty() {
+            continue;
+        }
+        let shown = if redacted {
+            redact_path(&row.path)
+        } else {
+            row.path.clone()
+        };
+        warnings.extend(problems.into_iter().map(|problem| {
+            Warning::new(NON_PORTABLE_PATH, WarningSeverity::Warning, problem).with_path(&shown)
+        }));
+    }
+    warnings
+}
+
+fn path_problems(path: &str, redacted: bool) -> Vec<String> {
+    let mut problems = Vec::new();
+    let len = path.chars().count();
+    if len > WINDOWS_MAX_PATH {
+        problems.push(format!(
+            "path is {len} characters, over the Windows limit of {WINDOWS_MAX_PATH}"
+        ));
+    }
+    for segment in path.split('/').filter(|s| !s.is_empty() && *s != ".") {
+        if segment == ".." {
+            continue;
+        }
+        let name = if redacted {
+            "a path segment".to_string()
+        } else {
+            format!("'{segment}'")
+        };
+        if is_reserved_name(segment) {
+            problems.push(format!("{name} is a reserved name on Windows"));
+        } else if segment.ends_with(['.', ' ']) {
+            problems.push(format!(
+                "{name} ends with a dot or space, which Windows strips"
+            ));
+        }
+    }
+    problems
+}
+
+fn is_reserved_name(segment: &str) -> bool {
+    let stem = segment.split('.').next().unwrap_or(segment).trim_end();
+    RESERVED_NAMES
+        .iter()
+        .any(|name| stem.eq_ignore_ascii_case(name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(path: &str) -> FileRow {
+        FileRow {
+            path: path.to_string(),
+            module: "src".to_string(),
+            lang: "Rust".to_string(),
+            kind: FileKind::Parent,
+            code: 1,
+            comments: 0,
+            blanks: 0,
+            lines: 1,
+            bytes: 10,
+            tokens: 3,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
+        }
+    }
+
+    #[test]
+    fn portable_paths_have_no_warnings() {
+        let rows = [
+            row("src/lib.rs"),
+            row("docs/console.md"),
+            row("src/com10.rs"),
+        ];
+        assert!(path_portability_warnings(&rows, RedactMode::None).is_empty());
+    }
+
+    #[test]
+    fn over_length_path_is_flagged() {
+        let long = format!("src/{}.rs", "a".repeat(WINDOWS_MAX_PATH));
+        let warnings = path_portability_warnings(&[row(&long)], RedactMode::None);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(
+            warnings[0].message,
+            "path is 267 characters, over the Windows limit of 260"
+        );
+        assert_eq!(warnings[0].path.as_deref(), Some(long.as_str()));
+    }
+
+    #[test]
+    fn reserved_names_are_flagged_case_insensitively() {
+        let rows = [row("src/CON"), row("src/aux.rs"), row("Lpt1/x.rs")];
+        let warnings = path_portability_warnings(&rows, RedactMode::None);
+        let shown: Vec<String> = warnings.iter().map(ToString::to_string).collect();
+        assert_eq!(
+            shown,
+            [
+                "'CON' is a reserved name on Windows (src/CON)",
+                "'aux.rs' is a reserved name on Windows (src/aux.rs)",
+                "'Lpt1' is a reserved name on Windows (Lpt1/x.rs)",
+            ]
+        );
+        assert!(warnings.iter().all(|w| w.code == NON_PORTABLE_PATH));
+    }
+
+    #[test]
+    fn trailing_dot_or_space_is_flagged() {
+        let rows = [row("src/notes./a.md"), row("src/b.md ")];
+        let warnings = path_portability_warnings(&rows, RedactMode::None);
+        assert_eq!(warnings.len(), 2);
+        assert!(
+            warnings[0]
+                .message
+                .contains("'notes.' ends with a dot or space")
+        );
+        assert!(
+            warnings[1]
+                .message
+                .contains("'b.md ' ends with a dot or space")
+        );
+    }
+
+    #[test]
+    fn child_rows_are_skipped_and_redaction_hides_paths() {
+        let mut child = row("src/nul.rs");
+        child.kind = FileKind::Child;
+        assert!(path_portability_warnings(&[child], RedactMode::None).is_empty());
+
+        let warnings = path_portability_warnings(&[row("secret/nul.rs")], RedactMode::Paths);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(
+            warnings[0].message,
+            "a path segment is a reserved name on Windows"
+        );
+        assert_eq!(
+            warnings[0].path.as_deref(),
+            Some(redact_path("secret/nul.rs").as_str())
+        );
+    }
+}
@@ -62,6 +62,20 @@ pub fn scan_args(paths: &[PathBuf], global: &ScanOptions, redact: Option<RedactM
         no_ignore_dot: global.no_ignore || global.no_ignore_dot,
         no_ignore_vcs: global.no_ignore || global.no_ignore_vcs,
         treat_doc_strings_as_comments: global.treat_doc_strings_as_comments,
+        token_model: global
+            .token_model
+            .clone()
+            .unwrap_or_else(tokmd_types::default_token_model),
+        hash_algo: global.hash_algo,
+        symlink_policy: global.symlink_policy,
+        line_count_mode: global.line_count_mode,
+        code_comment_markers: global.code_comment_markers.clone(),
+        ignore_file: global.ignore_file.clone(),
+        include: if should_redact {
+            global.include.iter().map(|p| short_hash(p)).collect()
+        } else {
+            global.include.clone()
+        },
     };
 
     if should_redact {
@@ -102,6 +116,21 @@ mod tests {
         assert!(args.excluded_redacted);
     }
 
+    #[test]
+    fn scan_args_records_include_globs_and_hashes_them_when_redacting() {
+        let paths = vec![PathBuf::from(".")];
+        let scan_options = ScanOptions {
+            include: vec!["src/**/*.rs".to_string()],
+            ..Default::default()
+        };
+
+        let args = scan_args(&paths, &scan_options, None);
+        assert_eq!(args.include, vec!["src/**/*.rs"]);
+
+        let args = scan_args(&paths, &scan_options, Some(RedactMode::Paths));
+        assert_eq!(args.include, vec![short_hash("src/**/*.rs")]);
+    }
+
     #[test]
     fn scan_args_no_ignore_enables_sub_flags() {
         let paths = vec![PathBuf::from(".")];
@@ -119,6 +148,20 @@ mod tests {
         assert!(args.no_ignore_vcs);
     }
 
+    #[test]
+    fn scan_args_records_token_model() {
+        let paths = vec![PathBuf::from(".")];
+        let args = scan_args(&paths, &ScanOptions::default(), None);
+        assert_eq!(args.token_model, "heuristic");
+
+        let scan_options = ScanOptions {
+            token_model: Some("o200k_base".to_string()),
+            ..Default::default()
+        };
+        let args = scan_args(&paths, &scan_options, None);
+        assert_eq!(args.token_model, "o200k_base");
+    }
+
     proptest! {
         #[test]
         fn scan_args_preserves_redaction_and_ignore_invariants(
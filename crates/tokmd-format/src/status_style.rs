@@ -0,0 +1,144 @@
+//! Labels for status, risk, and trend values in human-readable reports.
+//!
+//! Analysis Markdown and cockpit renderers pass every [`ComplexityRisk`],
+//! [`RiskLevel`], [`GateStatus`], and [`TrendClass`] they print through a
+//! [`StatusStyle`]. The default style has no labels, so each report keeps
+//! its plain wording; [`StatusStyle::traffic_light`] prefixes every value
+//! with 🟢, 🟡, 🔴, or ⚪ for dashboards.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use tokmd_analysis_types::{ComplexityRisk, TrendClass};
+use tokmd_types::cockpit::{GateStatus, RiskLevel};
+
+/// A value a [`StatusStyle`] can label.
+pub trait StatusValue: Copy {
+    /// The value's serialized (snake_case) name, used as its style key.
+    fn key(self) -> &'static str;
+}
+
+impl StatusValue for ComplexityRisk {
+    fn key(self) -> &'static str {
+        match self {
+            Self::Low => "low",
+            Self::Moderate => "moderate",
+            Self::High => "high",
+            Self::Critical => "critical",
+        }
+    }
+}
+
+impl StatusValue for RiskLevel {
+    fn key(self) -> &'static str {
+        match self {
+            Self::Low => "low",
+            Self::Medium => "medium",
+            Self::High => "high",
+            Self::Critical => "critical",
+        }
+    }
+}
+
+impl StatusValue for GateStatus {
+    fn key(self) -> &'static str {
+        match self {
+            Self::Pass => "pass",
+            Self::Warn => "warn",
+            Self::Fail => "fail",
+            Self::Skipped => "skipped",
+            Self::Pending => "pending",
+        }
+    }
+}
+
+impl StatusValue for TrendClass {
+    fn key(self) -> &'static str {
+        match self {
+            Self::Rising => "rising",
+            Self::Flat => "flat",
+            Self::Falling => "falling",
+        }
+    }
+}
+
+/// Label overrides for status-like values, keyed by [`StatusValue::key`].
+///
+/// Keys are shared across enums: `"high"` styles both a high complexity risk
+/// and a high cockpit risk level.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StatusStyle {
+    /// Replacement label per value key, e.g. `"critical" => "🔴 critical"`.
+    pub labels: BTreeMap<String, String>,
+}
+
+impl StatusStyle {
+    /// Every value as a colored circle followed by its key: 🟢 for passing
+    /// and low-risk values, 🟡 for warnings, 🔴 for failures and high risk,
+    /// and ⚪ for values that carry no verdict.
+    pub fn traffic_light() -> Self {
+        const TONES: [(&str, &str); 13] = [
+            ("low", "🟢"),
+            ("pass", "🟢"),
+            ("falling", "🟢"),
+            ("moderate", "🟡"),
+            ("medium", "🟡"),
+            ("warn", "🟡"),
+            ("rising", "🟡"),
+            ("high", "🔴"),
+            ("critical", "🔴"),
+            ("fail", "🔴"),
+            ("flat", "⚪"),
+            ("skipped", "⚪"),
+            ("pending", "⚪"),
+        ];
+        Self {
+            labels: TONES
+                .into_iter()
+                .map(|(key, tone)| (key.to_string(), format!("{tone} {key}")))
+                .collect(),
+        }
+    }
+
+    /// This style with `key` labelled as `label`.
+    pub fn with_label(mut self, key: &str, label: impl Into<String>) -> Self {
+        self.labels.insert(key.to_string(), label.into());
+        self
+    }
+
+    /// The configured label for `value`, or `plain` when it has none.
+    pub fn label(&self, value: impl StatusValue, plain: impl fmt::Display) -> String {
+        match self.labels.get(value.key()) {
+            Some(label) => label.clone(),
+            None => plain.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_style_keeps_plain_labels() {
+        let style = StatusStyle::default();
+        assert_eq!(style.label(GateStatus::Pass, "Pass"), "Pass");
+        assert_eq!(style.label(RiskLevel::High, RiskLevel::High), "high");
+    }
+
+    #[test]
+    fn traffic_light_covers_every_value() {
+        let style = StatusStyle::traffic_light();
+        assert_eq!(style.label(ComplexityRisk::Critical, ""), "🔴 critical");
+        assert_eq!(style.label(TrendClass::Falling, ""), "🟢 falling");
+        assert_eq!(style.label(GateStatus::Pending, ""), "⚪ pending");
+        assert_eq!(style.label(RiskLevel::Medium, ""), "🟡 medium");
+    }
+
+    #[test]
+    fn overrides_replace_one_key() {
+        let style = StatusStyle::traffic_light().with_label("critical", "🚨 CRITICAL");
+        assert_eq!(style.label(RiskLevel::Critical, ""), "🚨 CRITICAL");
+        assert_eq!(style.label(RiskLevel::High, ""), "🔴 high");
+    }
+}
@@ -0,0 +1,217 @@
+//! Strict receipt parsing: report keys a receipt type never reads.
+//!
+//! Receipt types stay lenient so newer tokmd versions can add fields, and
+//! `#[serde(deny_unknown_fields)]` does not combine with the `flatten` every
+//! receipt uses. Instead the receipt is deserialized once through
+//! `serde_ignored`, which reports the path of every key the type skips.
+//!
+//! serde buffers the keys of a `flatten`ed struct and drops the leftovers
+//! without telling `serde_ignored`, so the parsed receipt is also serialized
+//! back and compared with the input. A key missing from that round trip and
+//! not already reported is either unknown or a known field that serializes
+//! only when non-default, so each such key is probed with values of the wrong
+//! type: only an unknown key is still accepted. Fields typed as free-form
+//! JSON accept every probe and are reported when they are omitted from the
+//! round trip.
+
+use std::fmt;
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::migrate::migrate_receipt;
+
+/// A receipt contains keys its type does not read.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownFields(
+    /// JSON Pointers to each unknown key, e.g. `/rows/0/cod`.
+    pub Vec<String>,
+);
+
+impl fmt::Display for UnknownFields {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "receipt has unknown fields: {}", self.0.join(", "))
+    }
+}
+
+impl std::error::Error for UnknownFields {}
+
+/// Keys in `value` that `T` ignores when deserializing, as JSON Pointers
+/// sorted by path.
+pub fn unknown_fields<T: DeserializeOwned + Serialize>(value: &Value) -> Result<Vec<String>> {
+    Ok(parse_tracking_unknown::<T>(value)?.1)
+}
+
+/// Like [`crate::parse_receipt`], but fails with [`UnknownFields`] when the
+/// receipt carries keys its type does not read.
+pub fn parse_receipt_strict<T: DeserializeOwned + Serialize>(content: &str) -> Result<T> {
+    let mut value: Value = serde_json::from_str(content).context("receipt is not valid JSON")?;
+    migrate_receipt(&mut value)?;
+    let (parsed, unknown) = parse_tracking_unknown::<T>(&value)?;
+    if !unknown.is_empty() {
+        return Err(UnknownFields(unknown).into());
+    }
+    Ok(parsed)
+}
+
+/// Deserialize `value` once, collecting a pointer to each key `T` ignores.
+fn parse_tracking_unknown<T: DeserializeOwned + Serialize>(
+    value: &Value,
+) -> Result<(T, Vec<String>)> {
+    let mut unknown = Vec::new();
+    let parsed: T = serde_ignored::deserialize(value, |path| unknown.push(segments(&path)))?;
+
+    // Keys dropped from inside a `flatten` never reach the callback.
+    let round_trip = serde_json::to_value(&parsed)?;
+    let mut candidates = Vec::new();
+    missing_keys(value, &round_trip, &mut Vec::new(), &mut candidates);
+    for path in candidates {
+        if !unknown.contains(&path) && !is_read::<T>(value, &path) {
+            unknown.push(path);
+        }
+    }
+    unknown.sort();
+    unknown.dedup();
+    Ok((parsed, unknown.iter().map(|path| pointer(path)).collect()))
+}
+
+/// The path of a key `serde_ignored` reports.
+fn segments(path: &serde_ignored::Path<'_>) -> Vec<Segment> {
+    use serde_ignored::Path;
+
+    match path {
+        Path::Root => Vec::new(),
+        Path::Seq { parent, index } => {
+            let mut segments = segments(parent);
+            segments.push(Segment::Index(*index));
+            segments
+        }
+        Path::Map { parent, key } => {
+            let mut segments = segments(parent);
+            segments.push(Segment::Key(key.clone()));
+            segments
+        }
+        Path::Some { parent }
+        | Path::NewtypeStruct { parent }
+        | Path::NewtypeVariant { parent } => segments(parent),
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum Segment {
+    Key(String),
+    Index(usize),
+}
+
+fn missing_keys(
+    input: &Value,
+    known: &Value,
+    path: &mut Vec<Segment>,
+    out: &mut Vec<Vec<Segment>>,
+) {
+    match (input, known) {
+        (Value::Object(input), Value::Object(known)) => {
+            for (key, value) in input {
+                path.push(Segment::Key(key.clone()));
+                match known.get(key) {
+                    Some(known) => missing_keys(value, known, path, out),
+                    None => out.push(path.clone()),
+                }
+                path.pop();
+            }
+        }
+        (Value::Array(input), Value::Array(known)) => {
+            for (index, (value, known)) in input.iter().zip(known).enumerate() {
+                path.push(Segment::Index(index));
+                missing_keys(value, known, path, out);
+                path.pop();
+            }
+        }
+        _ => {}
+    }
+}
+
+/// True when `T` rejects a wrong-typed value at `path`, i.e. it reads the key.
+fn is_read<T: DeserializeOwned>(value: &Value, path: &[Segment]) -> bool {
+    [Value::from(-0.5), Value::from("\u{0}")]
+        .into_iter()
+        .any(|probe| {
+            let mut probed = value.clone();
+            if let Some(slot) = lookup_mut(&mut probed, path) {
+                *slot = probe;
+            }
+            serde_json::from_value::<T>(probed).is_err()
+        })
+}
+
+fn lookup_mut<'a>(value: &'a mut Value, path: &[Segment]) -> Option<&'a mut Value> {
+    path.iter().try_fold(value, |value, segment| match segment {
+        Segment::Key(key) => value.get_mut(key.as_str()),
+        Segment::Index(index) => value.get_mut(*index),
+    })
+}
+
+/// RFC 6901 JSON Pointer for `path`.
+fn pointer(path: &[Segment]) -> String {
+    path.iter()
+        .map(|segment| match segment {
+            Segment::Key(key) => format!("/{}", key.replace('~', "~0").replace('/', "~1")),
+            Segment::Index(index) => format!("/{index}"),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+    use serde_json::json;
+
+    use super::*;
+
+    #[derive(Serialize, Deserialize)]
+    struct Row {
+        code: usize,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        note: Option<String>,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct Doc {
+        rows: Vec<Row>,
+        #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+        redacted: bool,
+        #[serde(flatten)]
+        extra: Inner,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct Inner {
+        ratio: f64,
+    }
+
+    #[test]
+    fn default_valued_known_keys_are_not_reported() {
+        let value = json!({"rows": [{"code": 1, "note": null}], "redacted": false, "ratio": 0.5});
+        assert!(unknown_fields::<Doc>(&value).unwrap().is_empty());
+    }
+
+    #[test]
+    fn unknown_keys_are_reported_at_any_depth() {
+        let value = json!({
+            "rows": [{"code": 1}, {"code": 2, "cod/e": 3}],
+            "ratio": 0.5,
+            "bogus": true,
+        });
+        assert_eq!(
+            unknown_fields::<Doc>(&value).unwrap(),
+            ["/bogus", "/rows/1/cod~1e"]
+        );
+    }
+
+    #[test]
+    fn unparseable_values_are_an_error() {
+        assert!(unknown_fields::<Doc>(&json!({"rows": "nope"})).is_err());
+    }
+}
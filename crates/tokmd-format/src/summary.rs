@@ -5,18 +5,80 @@ use anyhow::Result;
 
 use tokmd_settings::ScanOptions;
 use tokmd_types::{
-    LangArgs, LangArgsMeta, LangReport, ModuleArgs, ModuleArgsMeta, ModuleReport, RedactMode,
-    ScanArgs, TableFormat,
+    AvgMeasure, LangArgs, LangArgsMeta, LangReport, ModuleArgs, ModuleArgsMeta, ModuleReport,
+    RedactMode, ScanArgs, TableFormat,
 };
 
+mod history;
 mod json;
 mod lang;
 mod module;
 
+pub use history::{SummarySnapshot, append_summary_row};
 use json::{write_lang_json, write_module_json};
 use lang::{render_lang_md, render_lang_tsv};
 use module::{render_module_md, render_module_tsv};
 
+// -------------------------
+// Per-file size columns
+// -------------------------
+
+/// Which per-file size columns the lang/module tables show.
+#[derive(Debug, Clone, Copy, Default)]
+struct SizeColumns {
+    avg: AvgMeasure,
+    percentiles: bool,
+}
+
+fn size_columns(avg: AvgMeasure, percentiles: bool) -> SizeColumns {
+    SizeColumns { avg, percentiles }
+}
+
+impl SizeColumns {
+    fn headers(self) -> Vec<&'static str> {
+        let mut headers = Vec::with_capacity(4);
+        if self.avg.includes_mean() {
+            headers.push("Avg");
+        }
+        if self.avg.includes_median() {
+            headers.push("Median");
+        }
+        if self.percentiles {
+            headers.extend(["P50", "P90"]);
+        }
+        headers
+    }
+
+    /// Append the size cells, each preceded by `sep`. Missing optional
+    /// figures render as `-`.
+    fn push_cells(
+        self,
+        s: &mut String,
+        sep: char,
+        avg_lines: usize,
+        median_lines: Option<usize>,
+        percentiles: (Option<usize>, Option<usize>),
+    ) {
+        let mut push = |value: Option<usize>| {
+            s.push(sep);
+            match value {
+                Some(v) => s.push_str(&v.to_string()),
+                None => s.push('-'),
+            }
+        };
+        if self.avg.includes_mean() {
+            push(Some(avg_lines));
+        }
+        if self.avg.includes_median() {
+            push(median_lines);
+        }
+        if self.percentiles {
+            push(percentiles.0);
+            push(percentiles.1);
+        }
+    }
+}
+
 // -----------------------
 // Language summary output
 // -----------------------
@@ -32,10 +94,14 @@ pub fn write_lang_report_to<W: Write>(
 ) -> Result<()> {
     match args.format {
         TableFormat::Md => {
-            out.write_all(render_lang_md(report).as_bytes())?;
+            out.write_all(
+                render_lang_md(report, size_columns(args.avg, args.percentiles)).as_bytes(),
+            )?;
         }
         TableFormat::Tsv => {
-            out.write_all(render_lang_tsv(report).as_bytes())?;
+            out.write_all(
+                render_lang_tsv(report, size_columns(args.avg, args.percentiles)).as_bytes(),
+            )?;
         }
         TableFormat::Json => {
             write_lang_json(out, report, global, args)?;
@@ -68,10 +134,14 @@ pub fn write_module_report_to<W: Write>(
 ) -> Result<()> {
     match args.format {
         TableFormat::Md => {
-            out.write_all(render_module_md(report).as_bytes())?;
+            out.write_all(
+                render_module_md(report, size_columns(args.avg, args.percentiles)).as_bytes(),
+            )?;
         }
         TableFormat::Tsv => {
-            out.write_all(render_module_tsv(report).as_bytes())?;
+            out.write_all(
+                render_module_tsv(report, size_columns(args.avg, args.percentiles)).as_bytes(),
+            )?;
         }
         TableFormat::Json => {
             write_module_json(out, report, global, args)?;
@@ -131,7 +201,7 @@ mod tests {
     use super::*;
     use std::path::PathBuf;
     use tokmd_settings::ChildrenMode;
-    use tokmd_types::{LangReceipt, LangRow, ModuleReceipt, ModuleRow, Totals};
+    use tokmd_types::{LangReceipt, LangRow, ModuleChildRow, ModuleReceipt, ModuleRow, Totals};
 
     fn sample_lang_report(with_files: bool) -> LangReport {
         LangReport {
@@ -144,6 +214,10 @@ mod tests {
                     bytes: 50000,
                     tokens: 2500,
                     avg_lines: 120,
+                    median_lines: None,
+                    p50_lines: None,
+                    p90_lines: None,
+                    file_rows: Vec::new(),
                 },
                 LangRow {
                     lang: "TOML".to_string(),
@@ -153,6 +227,10 @@ mod tests {
                     bytes: 1000,
                     tokens: 125,
                     avg_lines: 30,
+                    median_lines: None,
+                    p50_lines: None,
+                    p90_lines: None,
+                    file_rows: Vec::new(),
                 },
             ],
             total: Totals {
@@ -162,6 +240,9 @@ mod tests {
                 bytes: 51000,
                 tokens: 2625,
                 avg_lines: 105,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
             },
             with_files,
             children: ChildrenMode::Collapse,
@@ -180,6 +261,11 @@ mod tests {
                     bytes: 40000,
                     tokens: 2000,
                     avg_lines: 119,
+                    median_lines: None,
+                    p50_lines: None,
+                    p90_lines: None,
+                    file_rows: Vec::new(),
+                    children: Vec::new(),
                 },
                 ModuleRow {
                     module: "crates/bar".to_string(),
@@ -189,6 +275,11 @@ mod tests {
                     bytes: 10000,
                     tokens: 500,
                     avg_lines: 125,
+                    median_lines: None,
+                    p50_lines: None,
+                    p90_lines: None,
+                    file_rows: Vec::new(),
+                    children: Vec::new(),
                 },
             ],
             total: Totals {
@@ -198,6 +289,9 @@ mod tests {
                 bytes: 50000,
                 tokens: 2500,
                 avg_lines: 120,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
             },
             module_roots: vec!["crates".to_string()],
             module_depth: 2,
@@ -209,7 +303,7 @@ mod tests {
     #[test]
     fn render_lang_md_without_files() {
         let report = sample_lang_report(false);
-        let output = render_lang_md(&report);
+        let output = render_lang_md(&report, SizeColumns::default());
 
         assert!(output.contains("|Lang|Code|Lines|Bytes|Tokens|"));
         assert!(!output.contains("|Files|"));
@@ -222,7 +316,7 @@ mod tests {
     #[test]
     fn render_lang_md_with_files() {
         let report = sample_lang_report(true);
-        let output = render_lang_md(&report);
+        let output = render_lang_md(&report, SizeColumns::default());
 
         assert!(output.contains("|Lang|Code|Lines|Files|Bytes|Tokens|Avg|"));
         assert!(output.contains("|Rust|1000|1200|10|50000|2500|120|"));
@@ -233,7 +327,7 @@ mod tests {
     #[test]
     fn render_lang_md_table_structure() {
         let report = sample_lang_report(true);
-        let output = render_lang_md(&report);
+        let output = render_lang_md(&report, SizeColumns::default());
 
         let lines: Vec<&str> = output.lines().collect();
         assert!(lines.len() >= 4);
@@ -244,7 +338,7 @@ mod tests {
     #[test]
     fn render_lang_tsv_without_files() {
         let report = sample_lang_report(false);
-        let output = render_lang_tsv(&report);
+        let output = render_lang_tsv(&report, SizeColumns::default());
 
         assert!(output.starts_with("Lang\tCode\tLines\tBytes\tTokens\n"));
         assert!(!output.contains("\tFiles\t"));
@@ -257,7 +351,7 @@ mod tests {
     #[test]
     fn render_lang_tsv_with_files() {
         let report = sample_lang_report(true);
-        let output = render_lang_tsv(&report);
+        let output = render_lang_tsv(&report, SizeColumns::default());
 
         assert!(output.starts_with("Lang\tCode\tLines\tFiles\tBytes\tTokens\tAvg\n"));
         assert!(output.contains("Rust\t1000\t1200\t10\t50000\t2500\t120"));
@@ -267,7 +361,7 @@ mod tests {
     #[test]
     fn render_lang_tsv_tab_separated() {
         let report = sample_lang_report(false);
-        let output = render_lang_tsv(&report);
+        let output = render_lang_tsv(&report, SizeColumns::default());
 
         for line in output.lines().skip(1) {
             if line.starts_with("Total") || line.starts_with("Rust") || line.starts_with("TOML") {
@@ -279,7 +373,7 @@ mod tests {
     #[test]
     fn render_module_md_structure() {
         let report = sample_module_report();
-        let output = render_module_md(&report);
+        let output = render_module_md(&report, SizeColumns::default());
 
         assert!(output.contains("|Module|Code|Lines|Files|Bytes|Tokens|Avg|"));
         assert!(output.contains("|crates/foo|800|950|8|40000|2000|119|"));
@@ -290,7 +384,7 @@ mod tests {
     #[test]
     fn render_module_md_table_format() {
         let report = sample_module_report();
-        let output = render_module_md(&report);
+        let output = render_module_md(&report, SizeColumns::default());
 
         let lines: Vec<&str> = output.lines().collect();
         assert_eq!(lines.len(), 5);
@@ -300,7 +394,7 @@ mod tests {
     #[test]
     fn render_module_tsv_structure() {
         let report = sample_module_report();
-        let output = render_module_tsv(&report);
+        let output = render_module_tsv(&report, SizeColumns::default());
 
         assert!(output.starts_with("Module\tCode\tLines\tFiles\tBytes\tTokens\tAvg\n"));
         assert!(output.contains("crates/foo\t800\t950\t8\t40000\t2000\t119"));
@@ -311,17 +405,133 @@ mod tests {
     #[test]
     fn render_module_tsv_tab_count() {
         let report = sample_module_report();
-        let output = render_module_tsv(&report);
+        let output = render_module_tsv(&report, SizeColumns::default());
 
         for line in output.lines() {
             assert_eq!(line.matches('\t').count(), 6);
         }
     }
 
+    fn with_medians(mut report: LangReport) -> LangReport {
+        report.rows[0].median_lines = Some(95);
+        report.total.median_lines = Some(90);
+        report
+    }
+
+    #[test]
+    fn render_lang_md_median_replaces_avg() {
+        let report = with_medians(sample_lang_report(true));
+        let output = render_lang_md(&report, size_columns(AvgMeasure::Median, false));
+
+        assert!(output.contains("|Lang|Code|Lines|Files|Bytes|Tokens|Median|\n"));
+        assert!(!output.contains("|Avg|"));
+        assert!(output.contains("|Rust|1000|1200|10|50000|2500|95|"));
+        assert!(output.contains("|TOML|50|60|2|1000|125|-|"));
+        assert!(output.contains("|**Total**|1050|1260|12|51000|2625|90|"));
+    }
+
+    #[test]
+    fn render_lang_tsv_both_shows_avg_and_median() {
+        let report = with_medians(sample_lang_report(true));
+        let output = render_lang_tsv(&report, size_columns(AvgMeasure::Both, false));
+
+        assert!(output.starts_with("Lang\tCode\tLines\tFiles\tBytes\tTokens\tAvg\tMedian\n"));
+        assert!(output.contains("Rust\t1000\t1200\t10\t50000\t2500\t120\t95\n"));
+        assert!(output.contains("Total\t1050\t1260\t12\t51000\t2625\t105\t90\n"));
+    }
+
+    #[test]
+    fn render_lang_without_files_ignores_measure() {
+        let report = with_medians(sample_lang_report(false));
+        assert_eq!(
+            render_lang_md(&report, size_columns(AvgMeasure::Both, true)),
+            render_lang_md(&report, SizeColumns::default())
+        );
+    }
+
+    #[test]
+    fn render_module_md_both_columns() {
+        let mut report = sample_module_report();
+        report.rows[0].median_lines = Some(40);
+        let output = render_module_md(&report, size_columns(AvgMeasure::Both, false));
+
+        assert!(output.contains("|Module|Code|Lines|Files|Bytes|Tokens|Avg|Median|\n"));
+        assert!(output.contains("|---|---:|---:|---:|---:|---:|---:|---:|\n"));
+        assert!(output.contains("|crates/foo|800|950|8|40000|2000|119|40|"));
+        assert!(output.contains("|**Total**|1000|1200|10|50000|2500|120|-|"));
+    }
+
+    fn child(lang: &str, code: usize, lines: usize, files: usize) -> ModuleChildRow {
+        ModuleChildRow {
+            lang: lang.to_string(),
+            code,
+            lines,
+            files,
+            bytes: 0,
+            tokens: 0,
+        }
+    }
+
+    #[test]
+    fn render_module_md_nests_children_under_their_module() {
+        let mut report = sample_module_report();
+        report.children = tokmd_settings::ChildIncludeMode::Nested;
+        report.rows[0].children = vec![child("Rust", 60, 70, 3), child("TOML", 5, 6, 1)];
+        let output = render_module_md(&report, size_columns(AvgMeasure::Both, false));
+
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(
+            &lines[2..6],
+            [
+                "|crates/foo|800|950|8|40000|2000|119|-|",
+                "|└─ Rust (embedded)|60|70|3|0|0|-|-|",
+                "|└─ TOML (embedded)|5|6|1|0|0|-|-|",
+                "|crates/bar|200|250|2|10000|500|125|-|",
+            ]
+        );
+        // Children are part of their module's row, not extra contributions.
+        assert_eq!(lines[6], "|**Total**|1000|1200|10|50000|2500|120|-|");
+    }
+
+    #[test]
+    fn render_lang_md_percentile_columns() {
+        let mut report = sample_lang_report(true);
+        report.rows[0].p50_lines = Some(110);
+        report.rows[0].p90_lines = Some(400);
+        let output = render_lang_md(&report, size_columns(AvgMeasure::Mean, true));
+
+        assert!(output.contains("|Lang|Code|Lines|Files|Bytes|Tokens|Avg|P50|P90|\n"));
+        assert!(output.contains("|Rust|1000|1200|10|50000|2500|120|110|400|"));
+        assert!(output.contains("|TOML|50|60|2|1000|125|30|-|-|"));
+    }
+
+    #[test]
+    fn render_module_tsv_percentile_columns() {
+        let mut report = sample_module_report();
+        report.total.p50_lines = Some(100);
+        report.total.p90_lines = Some(300);
+        let output = render_module_tsv(&report, size_columns(AvgMeasure::Median, true));
+
+        assert!(
+            output.starts_with("Module\tCode\tLines\tFiles\tBytes\tTokens\tMedian\tP50\tP90\n")
+        );
+        assert!(output.contains("Total\t1000\t1200\t10\t50000\t2500\t-\t100\t300\n"));
+    }
+
+    #[test]
+    fn render_module_tsv_median_only() {
+        let mut report = sample_module_report();
+        report.rows[1].median_lines = Some(125);
+        let output = render_module_tsv(&report, size_columns(AvgMeasure::Median, false));
+
+        assert!(output.starts_with("Module\tCode\tLines\tFiles\tBytes\tTokens\tMedian\n"));
+        assert!(output.contains("crates/bar\t200\t250\t2\t10000\t500\t125\n"));
+    }
+
     #[test]
     fn snapshot_lang_md_with_files() {
         let report = sample_lang_report(true);
-        let output = render_lang_md(&report);
+        let output = render_lang_md(&report, SizeColumns::default());
         insta::with_settings!({ prepend_module_to_snapshot => false }, {
             insta::assert_snapshot!("tokmd_format__tests__snapshot_lang_md_with_files", output);
         });
@@ -330,7 +540,7 @@ mod tests {
     #[test]
     fn snapshot_lang_md_without_files() {
         let report = sample_lang_report(false);
-        let output = render_lang_md(&report);
+        let output = render_lang_md(&report, SizeColumns::default());
         insta::with_settings!({ prepend_module_to_snapshot => false }, {
             insta::assert_snapshot!("tokmd_format__tests__snapshot_lang_md_without_files", output);
         });
@@ -339,7 +549,7 @@ mod tests {
     #[test]
     fn snapshot_lang_tsv_with_files() {
         let report = sample_lang_report(true);
-        let output = render_lang_tsv(&report);
+        let output = render_lang_tsv(&report, SizeColumns::default());
         insta::with_settings!({ prepend_module_to_snapshot => false }, {
             insta::assert_snapshot!("tokmd_format__tests__snapshot_lang_tsv_with_files", output);
         });
@@ -348,7 +558,7 @@ mod tests {
     #[test]
     fn snapshot_module_md() {
         let report = sample_module_report();
-        let output = render_module_md(&report);
+        let output = render_module_md(&report, SizeColumns::default());
         insta::with_settings!({ prepend_module_to_snapshot => false }, {
             insta::assert_snapshot!("tokmd_format__tests__snapshot_module_md", output);
         });
@@ -357,7 +567,7 @@ mod tests {
     #[test]
     fn snapshot_module_tsv() {
         let report = sample_module_report();
-        let output = render_module_tsv(&report);
+        let output = render_module_tsv(&report, SizeColumns::default());
         insta::with_settings!({ prepend_module_to_snapshot => false }, {
             insta::assert_snapshot!("tokmd_format__tests__snapshot_module_tsv", output);
         });
@@ -374,6 +584,9 @@ mod tests {
             top: 0,
             files: false,
             children: ChildrenMode::Collapse,
+            avg: AvgMeasure::Mean,
+            percentiles: false,
+            with_files_detail: false,
         }
     }
 
@@ -385,6 +598,10 @@ mod tests {
             module_roots: vec!["crates".to_string()],
             module_depth: 2,
             children: tokmd_settings::ChildIncludeMode::Separate,
+            collapse_single_child: false,
+            avg: AvgMeasure::Mean,
+            percentiles: false,
+            with_files_detail: false,
         }
     }
 
@@ -468,6 +685,49 @@ mod tests {
         assert!(output.contains("Total\t"), "must contain total row");
     }
 
+    #[test]
+    fn write_module_report_to_json_embeds_file_rows() {
+        let mut report = sample_module_report();
+        report.rows[1].file_rows = vec![tokmd_types::FileRow {
+            path: "crates/bar/src/lib.rs".to_string(),
+            module: "crates/bar".to_string(),
+            lang: "Rust".to_string(),
+            kind: tokmd_types::FileKind::Parent,
+            code: 200,
+            comments: 30,
+            blanks: 20,
+            lines: 250,
+            bytes: 10000,
+            tokens: 500,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
+        }];
+        let global = sample_global_args();
+        let mut buf = Vec::new();
+
+        write_module_report_to(
+            &mut buf,
+            &report,
+            &global,
+            &sample_module_args(TableFormat::Json),
+        )
+        .unwrap();
+        let receipt: ModuleReceipt = serde_json::from_slice(&buf).unwrap();
+        assert!(receipt.report.rows[0].file_rows.is_empty());
+        assert_eq!(receipt.report.rows[1].file_rows, report.rows[1].file_rows);
+
+        let mut md = Vec::new();
+        write_module_report_to(
+            &mut md,
+            &report,
+            &global,
+            &sample_module_args(TableFormat::Md),
+        )
+        .unwrap();
+        assert!(!String::from_utf8(md).unwrap().contains("lib.rs"));
+    }
+
     #[test]
     fn write_module_report_to_json_writes_receipt() {
         let report = sample_module_report();
@@ -0,0 +1,198 @@
+//! Append-only summary history for trend charting.
+//!
+//! Each call adds one dated totals row to a long-lived TSV (or CSV) file, so
+//! a nightly job builds up a time series without rewriting earlier rows.
+
+use std::fs::OpenOptions;
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::Path;
+
+use anyhow::{Context, Result};
+
+use crate::analysis::fixed;
+
+const HEADERS: [&str; 8] = [
+    "Date", "Files", "Code", "Comments", "Lines", "Tokens", "Bytes", "Doc%",
+];
+
+/// Repository totals for one row of a summary history file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SummarySnapshot {
+    /// UTC date of the scan, `YYYY-MM-DD`.
+    pub date: String,
+    pub files: usize,
+    pub code: usize,
+    pub comments: usize,
+    pub lines: usize,
+    pub tokens: usize,
+    pub bytes: usize,
+}
+
+impl SummarySnapshot {
+    /// Comment lines as a percentage of code plus comment lines.
+    fn doc_pct(&self) -> f64 {
+        let denom = self.code + self.comments;
+        if denom == 0 {
+            0.0
+        } else {
+            self.comments as f64 * 100.0 / denom as f64
+        }
+    }
+
+    fn row(&self, sep: char) -> String {
+        let cells = [
+            self.date.clone(),
+            self.files.to_string(),
+            self.code.to_string(),
+            self.comments.to_string(),
+            self.lines.to_string(),
+            self.tokens.to_string(),
+            self.bytes.to_string(),
+            fixed(self.doc_pct(), 1),
+        ];
+        cells.join(&sep.to_string())
+    }
+}
+
+/// Append `snapshot` as one row to the history file at `path`.
+///
+/// The file is comma-separated when its extension is `csv` and tab-separated
+/// otherwise. A missing or empty file first gets a header row; existing rows
+/// are never rewritten, only appended to. Returns whether the header was
+/// written.
+pub fn append_summary_row(path: &Path, snapshot: &SummarySnapshot) -> Result<bool> {
+    let sep = if path
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("csv"))
+    {
+        ','
+    } else {
+        '\t'
+    };
+
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create {}", parent.display()))?;
+    }
+    let mut file = OpenOptions::new()
+        .read(true)
+        .append(true)
+        .create(true)
+        .open(path)
+        .with_context(|| format!("Failed to open {}", path.display()))?;
+
+    let new_file = file.metadata()?.len() == 0;
+    let mut content = String::new();
+    if new_file {
+        content.push_str(&HEADERS.join(&sep.to_string()));
+        content.push('\n');
+    } else {
+        let mut last = [0u8; 1];
+        file.seek(SeekFrom::End(-1))?;
+        file.read_exact(&mut last)?;
+        let [last] = last;
+        if last != b'\n' {
+            content.push('\n');
+        }
+    }
+    content.push_str(&snapshot.row(sep));
+    content.push('\n');
+
+    file.write_all(content.as_bytes())
+        .with_context(|| format!("Failed to append to {}", path.display()))?;
+    Ok(new_file)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(date: &str, code: usize) -> SummarySnapshot {
+        SummarySnapshot {
+            date: date.to_string(),
+            files: 12,
+            code,
+            comments: 250,
+            lines: 1500,
+            tokens: 4000,
+            bytes: 60000,
+        }
+    }
+
+    #[test]
+    fn new_file_gets_header_and_row() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("history.tsv");
+
+        let wrote_header = append_summary_row(&path, &snapshot("2026-01-01", 1000)).unwrap();
+
+        assert!(wrote_header);
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "Date\tFiles\tCode\tComments\tLines\tTokens\tBytes\tDoc%\n\
+             2026-01-01\t12\t1000\t250\t1500\t4000\t60000\t20.0\n"
+        );
+    }
+
+    #[test]
+    fn existing_file_keeps_prior_rows_and_gains_one() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("history.tsv");
+        append_summary_row(&path, &snapshot("2026-01-01", 1000)).unwrap();
+        append_summary_row(&path, &snapshot("2026-01-02", 1100)).unwrap();
+        let before = std::fs::read_to_string(&path).unwrap();
+
+        let wrote_header = append_summary_row(&path, &snapshot("2026-01-03", 1200)).unwrap();
+
+        assert!(!wrote_header);
+        let after = std::fs::read_to_string(&path).unwrap();
+        assert!(after.starts_with(&before));
+        assert_eq!(after.lines().count(), before.lines().count() + 1);
+        assert_eq!(after.matches("Date\t").count(), 1);
+        assert!(after.ends_with("2026-01-03\t12\t1200\t250\t1500\t4000\t60000\t17.2\n"));
+    }
+
+    #[test]
+    fn missing_trailing_newline_is_repaired() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("history.tsv");
+        std::fs::write(&path, "Date\tFiles\n2026-01-01\t3").unwrap();
+
+        append_summary_row(&path, &snapshot("2026-01-02", 1000)).unwrap();
+
+        let lines: Vec<String> = std::fs::read_to_string(&path)
+            .unwrap()
+            .lines()
+            .map(str::to_string)
+            .collect();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[1], "2026-01-01\t3");
+        assert!(lines[2].starts_with("2026-01-02\t"));
+    }
+
+    #[test]
+    fn csv_extension_uses_commas() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("nested").join("history.csv");
+
+        append_summary_row(&path, &snapshot("2026-01-01", 1000)).unwrap();
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert!(content.starts_with("Date,Files,Code,Comments,Lines,Tokens,Bytes,Doc%\n"));
+        assert!(content.contains("2026-01-01,12,1000,250,1500,4000,60000,20.0\n"));
+    }
+
+    #[test]
+    fn doc_pct_is_zero_without_code_or_comments() {
+        let empty = SummarySnapshot {
+            date: "2026-01-01".to_string(),
+            files: 0,
+            code: 0,
+            comments: 0,
+            lines: 0,
+            tokens: 0,
+            bytes: 0,
+        };
+        assert!(empty.row('\t').ends_with("\t0.0"));
+    }
+}
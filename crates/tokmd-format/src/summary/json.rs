@@ -3,7 +3,6 @@
 //! This module owns JSON receipt construction for direct command output and
 //! run-command artifact files. The parent summary module keeps public dispatch.
 
-use std::fs::File;
 use std::io::Write;
 use std::path::Path;
 
@@ -14,7 +13,8 @@ use tokmd_types::{
     ModuleReport, RedactMode, ScanArgs, ScanStatus, ToolInfo,
 };
 
-use crate::{now_ms, redact_module_roots, scan_args, short_hash};
+use crate::output_path::create_output_file;
+use crate::{now_ms, redact_module_roots, rows_integrity_with, scan_args, short_hash};
 
 pub(super) fn write_lang_json<W: Write>(
     mut out: W,
@@ -37,6 +37,7 @@ pub(super) fn write_lang_json<W: Write>(
             children: report.children,
         },
         report: report.clone(),
+        integrity: Some(rows_integrity_with(&report.rows, global.hash_algo)),
     };
     writeln!(out, "{}", serde_json::to_string(&receipt)?)?;
     Ok(())
@@ -62,8 +63,10 @@ pub(super) fn write_module_json<W: Write>(
             module_roots: report.module_roots.clone(),
             module_depth: report.module_depth,
             children: report.children,
+            collapse_single_child: args.collapse_single_child,
         },
         report: report.clone(),
+        integrity: Some(rows_integrity_with(&report.rows, global.hash_algo)),
     };
     writeln!(out, "{}", serde_json::to_string(&receipt)?)?;
     Ok(())
@@ -85,10 +88,11 @@ pub(super) fn write_lang_json_to_file(
         scan: scan.clone(),
         args: args_meta.clone(),
         report: report.clone(),
+        integrity: Some(rows_integrity_with(&report.rows, scan.hash_algo)),
     };
-    let file = File::create(path)?;
-    serde_json::to_writer(file, &receipt)?;
-    Ok(())
+    let mut file = create_output_file(path)?;
+    serde_json::to_writer(&mut file, &receipt)?;
+    file.commit()
 }
 
 pub(super) fn write_module_json_to_file(
@@ -109,6 +113,7 @@ pub(super) fn write_module_json_to_file(
         }
     }
 
+    let integrity = rows_integrity_with(&final_report.rows, scan.hash_algo);
     let receipt = ModuleReceipt {
         schema_version: tokmd_types::SCHEMA_VERSION,
         generated_at_ms: now_ms(),
@@ -119,8 +124,9 @@ pub(super) fn write_module_json_to_file(
         scan: scan.clone(),
         args: final_args,
         report: final_report,
+        integrity: Some(integrity),
     };
-    let file = File::create(path)?;
-    serde_json::to_writer(file, &receipt)?;
-    Ok(())
+    let mut file = create_output_file(path)?;
+    serde_json::to_writer(&mut file, &receipt)?;
+    file.commit()
 }
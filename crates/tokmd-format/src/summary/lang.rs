@@ -7,30 +7,54 @@ use std::fmt::Write as FmtWrite;
 
 use tokmd_types::LangReport;
 
-pub(super) fn render_lang_md(report: &LangReport) -> String {
+use super::SizeColumns;
+
+pub(super) fn render_lang_md(report: &LangReport, columns: SizeColumns) -> String {
     // Heuristic: (rows + 3) * 80 chars per row
     let mut s = String::with_capacity((report.rows.len() + 3) * 80);
 
     if report.with_files {
-        s.push_str("|Lang|Code|Lines|Files|Bytes|Tokens|Avg|\n");
-        s.push_str("|---|---:|---:|---:|---:|---:|---:|\n");
+        s.push_str("|Lang|Code|Lines|Files|Bytes|Tokens");
+        for header in columns.headers() {
+            let _ = write!(s, "|{header}");
+        }
+        s.push_str("|\n|---|---:|---:|---:|---:|---:");
+        for _ in columns.headers() {
+            s.push_str("|---:");
+        }
+        s.push_str("|\n");
         for r in &report.rows {
-            let _ = writeln!(
+            let _ = write!(
                 s,
-                "|{}|{}|{}|{}|{}|{}|{}|",
-                r.lang, r.code, r.lines, r.files, r.bytes, r.tokens, r.avg_lines
+                "|{}|{}|{}|{}|{}|{}",
+                r.lang, r.code, r.lines, r.files, r.bytes, r.tokens
+            );
+            columns.push_cells(
+                &mut s,
+                '|',
+                r.avg_lines,
+                r.median_lines,
+                (r.p50_lines, r.p90_lines),
             );
+            s.push_str("|\n");
         }
-        let _ = writeln!(
+        let _ = write!(
             s,
-            "|**Total**|{}|{}|{}|{}|{}|{}|",
+            "|**Total**|{}|{}|{}|{}|{}",
             report.total.code,
             report.total.lines,
             report.total.files,
             report.total.bytes,
-            report.total.tokens,
-            report.total.avg_lines
+            report.total.tokens
         );
+        columns.push_cells(
+            &mut s,
+            '|',
+            report.total.avg_lines,
+            report.total.median_lines,
+            (report.total.p50_lines, report.total.p90_lines),
+        );
+        s.push_str("|\n");
     } else {
         s.push_str("|Lang|Code|Lines|Bytes|Tokens|\n");
         s.push_str("|---|---:|---:|---:|---:|\n");
@@ -51,29 +75,48 @@ pub(super) fn render_lang_md(report: &LangReport) -> String {
     s
 }
 
-pub(super) fn render_lang_tsv(report: &LangReport) -> String {
+pub(super) fn render_lang_tsv(report: &LangReport, columns: SizeColumns) -> String {
     // Heuristic: (rows + 2) * 64 chars per row
     let mut s = String::with_capacity((report.rows.len() + 2) * 64);
 
     if report.with_files {
-        s.push_str("Lang\tCode\tLines\tFiles\tBytes\tTokens\tAvg\n");
+        s.push_str("Lang\tCode\tLines\tFiles\tBytes\tTokens");
+        for header in columns.headers() {
+            let _ = write!(s, "\t{header}");
+        }
+        s.push('\n');
         for r in &report.rows {
-            let _ = writeln!(
+            let _ = write!(
                 s,
-                "{}\t{}\t{}\t{}\t{}\t{}\t{}",
-                r.lang, r.code, r.lines, r.files, r.bytes, r.tokens, r.avg_lines
+                "{}\t{}\t{}\t{}\t{}\t{}",
+                r.lang, r.code, r.lines, r.files, r.bytes, r.tokens
             );
+            columns.push_cells(
+                &mut s,
+                '\t',
+                r.avg_lines,
+                r.median_lines,
+                (r.p50_lines, r.p90_lines),
+            );
+            s.push('\n');
         }
-        let _ = writeln!(
+        let _ = write!(
             s,
-            "Total\t{}\t{}\t{}\t{}\t{}\t{}",
+            "Total\t{}\t{}\t{}\t{}\t{}",
             report.total.code,
             report.total.lines,
             report.total.files,
             report.total.bytes,
-            report.total.tokens,
-            report.total.avg_lines
+            report.total.tokens
+        );
+        columns.push_cells(
+            &mut s,
+            '\t',
+            report.total.avg_lines,
+            report.total.median_lines,
+            (report.total.p50_lines, report.total.p90_lines),
         );
+        s.push('\n');
     } else {
         s.push_str("Lang\tCode\tLines\tBytes\tTokens\n");
         for r in &report.rows {
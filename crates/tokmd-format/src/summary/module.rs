@@ -7,51 +7,107 @@ use std::fmt::Write as FmtWrite;
 
 use tokmd_types::ModuleReport;
 
-pub(super) fn render_module_md(report: &ModuleReport) -> String {
+use super::SizeColumns;
+
+pub(super) fn render_module_md(report: &ModuleReport, columns: SizeColumns) -> String {
     // Heuristic: (rows + 3) * 80 chars per row
     let mut s = String::with_capacity((report.rows.len() + 3) * 80);
-    s.push_str("|Module|Code|Lines|Files|Bytes|Tokens|Avg|\n");
-    s.push_str("|---|---:|---:|---:|---:|---:|---:|\n");
+    s.push_str("|Module|Code|Lines|Files|Bytes|Tokens");
+    for header in columns.headers() {
+        let _ = write!(s, "|{header}");
+    }
+    s.push_str("|\n|---|---:|---:|---:|---:|---:");
+    for _ in columns.headers() {
+        s.push_str("|---:");
+    }
+    s.push_str("|\n");
     for r in &report.rows {
-        let _ = writeln!(
+        let _ = write!(
             s,
-            "|{}|{}|{}|{}|{}|{}|{}|",
-            r.module, r.code, r.lines, r.files, r.bytes, r.tokens, r.avg_lines
+            "|{}|{}|{}|{}|{}|{}",
+            r.module, r.code, r.lines, r.files, r.bytes, r.tokens
+        );
+        columns.push_cells(
+            &mut s,
+            '|',
+            r.avg_lines,
+            r.median_lines,
+            (r.p50_lines, r.p90_lines),
         );
+        s.push_str("|\n");
+        // Nested embedded languages: drawn as branches of their module, with no
+        // per-file size figures of their own.
+        for c in &r.children {
+            let _ = write!(
+                s,
+                "|└─ {} (embedded)|{}|{}|{}|{}|{}",
+                c.lang, c.code, c.lines, c.files, c.bytes, c.tokens
+            );
+            for _ in columns.headers() {
+                s.push_str("|-");
+            }
+            s.push_str("|\n");
+        }
     }
-    let _ = writeln!(
+    let _ = write!(
         s,
-        "|**Total**|{}|{}|{}|{}|{}|{}|",
+        "|**Total**|{}|{}|{}|{}|{}",
         report.total.code,
         report.total.lines,
         report.total.files,
         report.total.bytes,
-        report.total.tokens,
-        report.total.avg_lines
+        report.total.tokens
     );
+    columns.push_cells(
+        &mut s,
+        '|',
+        report.total.avg_lines,
+        report.total.median_lines,
+        (report.total.p50_lines, report.total.p90_lines),
+    );
+    s.push_str("|\n");
     s
 }
 
-pub(super) fn render_module_tsv(report: &ModuleReport) -> String {
+pub(super) fn render_module_tsv(report: &ModuleReport, columns: SizeColumns) -> String {
     // Heuristic: (rows + 2) * 64 chars per row
     let mut s = String::with_capacity((report.rows.len() + 2) * 64);
-    s.push_str("Module\tCode\tLines\tFiles\tBytes\tTokens\tAvg\n");
+    s.push_str("Module\tCode\tLines\tFiles\tBytes\tTokens");
+    for header in columns.headers() {
+        let _ = write!(s, "\t{header}");
+    }
+    s.push('\n');
     for r in &report.rows {
-        let _ = writeln!(
+        let _ = write!(
             s,
-            "{}\t{}\t{}\t{}\t{}\t{}\t{}",
-            r.module, r.code, r.lines, r.files, r.bytes, r.tokens, r.avg_lines
+            "{}\t{}\t{}\t{}\t{}\t{}",
+            r.module, r.code, r.lines, r.files, r.bytes, r.tokens
         );
+        columns.push_cells(
+            &mut s,
+            '\t',
+            r.avg_lines,
+            r.median_lines,
+            (r.p50_lines, r.p90_lines),
+        );
+        s.push('\n');
     }
-    let _ = writeln!(
+    let _ = write!(
         s,
-        "Total\t{}\t{}\t{}\t{}\t{}\t{}",
+        "Total\t{}\t{}\t{}\t{}\t{}",
         report.total.code,
         report.total.lines,
         report.total.files,
         report.total.bytes,
-        report.total.tokens,
-        report.total.avg_lines
+        report.total.tokens
+    );
+    columns.push_cells(
+        &mut s,
+        '\t',
+        report.total.avg_lines,
+        report.total.median_lines,
+        (report.total.p50_lines, report.total.p90_lines),
     );
+    s.push('\n');
     s
 }
@@ -0,0 +1,168 @@
+//! Timestamp display for rendered reports.
+//!
+//! Human-facing timestamps (the HTML report header, cockpit artifacts) default
+//! to UTC in a fixed layout. [`TimestampFormat`] lets callers pick another UTC
+//! offset and a custom [`time` format description] instead. A pattern that
+//! does not parse, or cannot be applied, falls back to the default layout for
+//! the chosen offset rather than failing the render.
+//!
+//! "Now" always comes from [`now_utc`], which honors `SOURCE_DATE_EPOCH` and
+//! [`tokmd_analysis_types::set_fixed_now_ms`] for reproducible output.
+//!
+//! [`time` format description]: https://time-rs.github.io/book/api/format-description.html
+
+use time::format_description::{self, OwnedFormatItem};
+use time::macros::format_description;
+use time::{OffsetDateTime, UtcOffset};
+
+/// Built-in timestamp layouts used when no custom pattern applies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimestampStyle {
+    /// `2026-01-31 23:59:59 UTC`, or `... +02:00` for other offsets.
+    Display,
+    /// `2026-01-31T23:59:59Z`, or `...+02:00` for other offsets.
+    Iso8601,
+}
+
+/// Timezone offset and optional pattern for rendered timestamps.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimestampFormat {
+    /// Offset timestamps are shown in. Defaults to UTC.
+    pub offset: UtcOffset,
+    /// `time` format description, e.g. `"[day].[month].[year] [hour]:[minute]"`.
+    /// `None` uses the renderer's [`TimestampStyle`].
+    pub pattern: Option<String>,
+}
+
+/// The current time, pinned by `SOURCE_DATE_EPOCH` or
+/// [`tokmd_analysis_types::set_fixed_now_ms`] when either is set.
+pub fn now_utc() -> OffsetDateTime {
+    let nanos = i128::try_from(crate::now_ms()).unwrap_or(0) * 1_000_000;
+    OffsetDateTime::from_unix_timestamp_nanos(nanos).unwrap_or(OffsetDateTime::UNIX_EPOCH)
+}
+
+impl Default for TimestampFormat {
+    fn default() -> Self {
+        Self {
+            offset: UtcOffset::UTC,
+            pattern: None,
+        }
+    }
+}
+
+impl TimestampFormat {
+    /// Format the current time.
+    pub fn now(&self, style: TimestampStyle) -> String {
+        self.format(now_utc(), style)
+    }
+
+    /// Format `when`, shifted to [`Self::offset`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use time::macros::{datetime, offset};
+    /// use tokmd_format::{TimestampFormat, TimestampStyle};
+    ///
+    /// let when = datetime!(2026-01-31 22:30:00 UTC);
+    /// assert_eq!(
+    ///     TimestampFormat::default().format(when, TimestampStyle::Iso8601),
+    ///     "2026-01-31T22:30:00Z"
+    /// );
+    ///
+    /// let berlin = TimestampFormat {
+    ///     offset: offset!(+1),
+    ///     pattern: Some("[day].[month].[year] [hour]:[minute]".into()),
+    /// };
+    /// assert_eq!(berlin.format(when, TimestampStyle::Display), "31.01.2026 23:30");
+    /// ```
+    pub fn format(&self, when: OffsetDateTime, style: TimestampStyle) -> String {
+        let when = when.to_offset(self.offset);
+        self.pattern
+            .as_deref()
+            .and_then(|pattern| format_description::parse_owned::<2>(pattern).ok())
+            .and_then(|items: OwnedFormatItem| when.format(&items).ok())
+            .unwrap_or_else(|| default_layout(when, style))
+    }
+}
+
+fn default_layout(when: OffsetDateTime, style: TimestampStyle) -> String {
+    let utc = when.offset().is_utc();
+    let formatted = match (style, utc) {
+        (TimestampStyle::Display, true) => when.format(format_description!(
+            "[year]-[month]-[day] [hour]:[minute]:[second] UTC"
+        )),
+        (TimestampStyle::Display, false) => when.format(format_description!(
+            "[year]-[month]-[day] [hour]:[minute]:[second] [offset_hour sign:mandatory]:[offset_minute]"
+        )),
+        (TimestampStyle::Iso8601, true) => when.format(format_description!(
+            "[year]-[month]-[day]T[hour]:[minute]:[second]Z"
+        )),
+        (TimestampStyle::Iso8601, false) => when.format(format_description!(
+            "[year]-[month]-[day]T[hour]:[minute]:[second][offset_hour sign:mandatory]:[offset_minute]"
+        )),
+    };
+    formatted.unwrap_or_else(|_| match style {
+        TimestampStyle::Display => "1970-01-01 00:00:00 UTC".to_string(),
+        TimestampStyle::Iso8601 => "1970-01-01T00:00:00Z".to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use time::macros::{datetime, offset};
+
+    use super::*;
+
+    const WHEN: OffsetDateTime = datetime!(2026-03-04 05:06:07 UTC);
+
+    #[test]
+    fn default_matches_utc_layouts() {
+        let tf = TimestampFormat::default();
+        assert_eq!(
+            tf.format(WHEN, TimestampStyle::Display),
+            "2026-03-04 05:06:07 UTC"
+        );
+        assert_eq!(
+            tf.format(WHEN, TimestampStyle::Iso8601),
+            "2026-03-04T05:06:07Z"
+        );
+    }
+
+    #[test]
+    fn offset_shifts_time_and_shows_offset() {
+        let tf = TimestampFormat {
+            offset: offset!(-5:30),
+            pattern: None,
+        };
+        assert_eq!(
+            tf.format(WHEN, TimestampStyle::Display),
+            "2026-03-03 23:36:07 -05:30"
+        );
+        assert_eq!(
+            tf.format(WHEN, TimestampStyle::Iso8601),
+            "2026-03-03T23:36:07-05:30"
+        );
+    }
+
+    #[test]
+    fn custom_pattern_changes_output() {
+        let tf = TimestampFormat {
+            offset: UtcOffset::UTC,
+            pattern: Some("[month]/[day]/[year]".to_string()),
+        };
+        assert_eq!(tf.format(WHEN, TimestampStyle::Display), "03/04/2026");
+    }
+
+    #[test]
+    fn invalid_pattern_falls_back_to_default_layout() {
+        let tf = TimestampFormat {
+            offset: offset!(+2),
+            pattern: Some("[not_a_component".to_string()),
+        };
+        assert_eq!(
+            tf.format(WHEN, TimestampStyle::Iso8601),
+            "2026-03-04T07:06:07+02:00"
+        );
+    }
+}
@@ -35,6 +35,7 @@ fn source(inputs: Vec<&str>) -> AnalysisSource {
         module_roots: vec![],
         module_depth: 1,
         children: "collapse".into(),
+        token_model: "heuristic".to_string(),
     }
 }
 
@@ -50,6 +51,14 @@ fn args(preset: &str, format: &str) -> AnalysisArgsMeta {
         max_commit_files: None,
         max_file_bytes: None,
         import_granularity: "module".into(),
+        oversized_file_bytes: None,
+        max_commit_message_bytes: None,
+        strip_bom: false,
+        preset_sections: None,
+        include_sections: vec![],
+        exclude_sections: vec![],
+        sample_rate: None,
+        max_runtime_ms: None,
     }
 }
 
@@ -79,6 +88,11 @@ fn empty_receipt() -> AnalysisReceipt {
         complexity: None,
         api_surface: None,
         fun: None,
+        timings: None,
+        line_endings: None,
+        sampling: None,
+        risk_surface: Vec::new(),
+        extensions: Default::default(),
     }
 }
 
@@ -268,6 +282,8 @@ fn sample_derived() -> DerivedReport {
             hash: "abcdef1234567890".into(),
             entries: 10,
         },
+        oversized_files: vec![],
+        non_utf8_files: vec![],
     }
 }
 
@@ -511,7 +527,7 @@ fn json_roundtrip_preserves_warnings() {
     r.status = ScanStatus::Partial;
     let json = render_text(&r, AnalysisFormat::Json);
     let roundtrip: AnalysisReceipt = serde_json::from_str(&json).unwrap();
-    assert_eq!(roundtrip.warnings, vec!["warn1", "warn2"]);
+    assert_eq!(roundtrip.warnings, r.warnings);
     assert!(matches!(roundtrip.status, ScanStatus::Partial));
 }
 
@@ -896,7 +912,8 @@ fn json_warnings_reflected_in_output() {
     r.status = ScanStatus::Partial;
     let json = render_text(&r, AnalysisFormat::Json);
     let v: serde_json::Value = serde_json::from_str(&json).unwrap();
-    assert_eq!(v["warnings"][0], "something went wrong");
+    assert_eq!(v["warnings"][0]["message"], "something went wrong");
+    assert_eq!(v["warnings"][0]["code"], "unclassified");
     assert_eq!(v["status"], "partial");
 }
 
@@ -32,6 +32,7 @@ fn base_receipt() -> AnalysisReceipt {
             module_roots: vec!["src".into()],
             module_depth: 2,
             children: "collapse".into(),
+            token_model: "heuristic".to_string(),
         },
         args: AnalysisArgsMeta {
             preset: "receipt".into(),
@@ -44,6 +45,14 @@ fn base_receipt() -> AnalysisReceipt {
             max_commits: None,
             max_commit_files: None,
             import_granularity: "module".into(),
+            oversized_file_bytes: None,
+            max_commit_message_bytes: None,
+            strip_bom: false,
+            preset_sections: None,
+            include_sections: vec![],
+            exclude_sections: vec![],
+            sample_rate: None,
+            max_runtime_ms: None,
         },
         archetype: None,
         topics: None,
@@ -60,6 +69,11 @@ fn base_receipt() -> AnalysisReceipt {
         complexity: None,
         api_surface: None,
         fun: None,
+        timings: None,
+        line_endings: None,
+        sampling: None,
+        risk_surface: Vec::new(),
+        extensions: Default::default(),
     }
 }
 
@@ -263,6 +277,8 @@ fn make_derived() -> DerivedReport {
             hash: "b".repeat(64),
             entries: 12,
         },
+        oversized_files: vec![],
+        non_utf8_files: vec![],
     }
 }
 
@@ -475,6 +491,7 @@ fn w54_analysis_md_full() {
             source_path: "LICENSE".into(),
             source_kind: LicenseSourceKind::Text,
         }],
+        header_compliance: None,
     });
     insta::assert_snapshot!(
         "w54_analysis_md_full",
@@ -33,6 +33,7 @@ fn base_receipt() -> AnalysisReceipt {
             module_roots: vec!["src".into()],
             module_depth: 2,
             children: "collapse".into(),
+            token_model: "heuristic".to_string(),
         },
         args: AnalysisArgsMeta {
             preset: "receipt".into(),
@@ -45,6 +46,14 @@ fn base_receipt() -> AnalysisReceipt {
             max_commits: None,
             max_commit_files: None,
             import_granularity: "module".into(),
+            oversized_file_bytes: None,
+            max_commit_message_bytes: None,
+            strip_bom: false,
+            preset_sections: None,
+            include_sections: vec![],
+            exclude_sections: vec![],
+            sample_rate: None,
+            max_runtime_ms: None,
         },
         archetype: None,
         topics: None,
@@ -61,6 +70,11 @@ fn base_receipt() -> AnalysisReceipt {
         complexity: None,
         api_surface: None,
         fun: None,
+        timings: None,
+        line_endings: None,
+        sampling: None,
+        risk_surface: Vec::new(),
+        extensions: Default::default(),
     }
 }
 
@@ -268,6 +282,8 @@ fn make_derived() -> DerivedReport {
             hash: "a".repeat(64),
             entries: 20,
         },
+        oversized_files: vec![],
+        non_utf8_files: vec![],
     }
 }
 
@@ -517,6 +533,7 @@ fn w58_analysis_md_license_section() {
                 source_kind: LicenseSourceKind::Text,
             },
         ],
+        header_compliance: None,
     });
     insta::assert_snapshot!(
         "w58_analysis_md_license_section",
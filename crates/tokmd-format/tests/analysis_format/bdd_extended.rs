@@ -33,6 +33,7 @@ fn minimal_receipt() -> AnalysisReceipt {
             module_roots: vec![],
             module_depth: 1,
             children: "collapse".into(),
+            token_model: "heuristic".to_string(),
         },
         args: AnalysisArgsMeta {
             preset: "receipt".into(),
@@ -45,6 +46,14 @@ fn minimal_receipt() -> AnalysisReceipt {
             max_commits: None,
             max_commit_files: None,
             import_granularity: "module".into(),
+            oversized_file_bytes: None,
+            max_commit_message_bytes: None,
+            strip_bom: false,
+            preset_sections: None,
+            include_sections: vec![],
+            exclude_sections: vec![],
+            sample_rate: None,
+            max_runtime_ms: None,
         },
         archetype: None,
         topics: None,
@@ -61,6 +70,11 @@ fn minimal_receipt() -> AnalysisReceipt {
         complexity: None,
         api_surface: None,
         fun: None,
+        timings: None,
+        line_endings: None,
+        sampling: None,
+        risk_surface: Vec::new(),
+        extensions: Default::default(),
     }
 }
 
@@ -504,5 +518,7 @@ fn sample_derived() -> DerivedReport {
             hash: "a".repeat(64),
             entries: 5,
         },
+        oversized_files: vec![],
+        non_utf8_files: vec![],
     }
 }
@@ -35,6 +35,7 @@ fn minimal_receipt() -> AnalysisReceipt {
             module_roots: vec![],
             module_depth: 1,
             children: "collapse".to_string(),
+            token_model: "heuristic".to_string(),
         },
         args: AnalysisArgsMeta {
             preset: "receipt".to_string(),
@@ -47,6 +48,14 @@ fn minimal_receipt() -> AnalysisReceipt {
             max_commit_files: None,
             max_file_bytes: None,
             import_granularity: "module".to_string(),
+            oversized_file_bytes: None,
+            max_commit_message_bytes: None,
+            strip_bom: false,
+            preset_sections: None,
+            include_sections: vec![],
+            exclude_sections: vec![],
+            sample_rate: None,
+            max_runtime_ms: None,
         },
         archetype: None,
         topics: None,
@@ -63,6 +72,11 @@ fn minimal_receipt() -> AnalysisReceipt {
         complexity: None,
         api_surface: None,
         fun: None,
+        timings: None,
+        line_endings: None,
+        sampling: None,
+        risk_surface: Vec::new(),
+        extensions: Default::default(),
     }
 }
 
@@ -224,6 +238,8 @@ fn sample_derived() -> DerivedReport {
             hash: "abc123".to_string(),
             entries: 10,
         },
+        oversized_files: vec![],
+        non_utf8_files: vec![],
     }
 }
 
@@ -268,12 +284,14 @@ fn fully_populated_receipt() -> AnalysisReceipt {
             source_path: "LICENSE".to_string(),
             source_kind: LicenseSourceKind::Text,
         }],
+        header_compliance: None,
     });
     r.corporate_fingerprint = Some(CorporateFingerprint {
         domains: vec![DomainStat {
             domain: "example.com".to_string(),
             commits: 50,
             pct: 0.75,
+            members: Vec::new(),
         }],
     });
     r.assets = Some(AssetReport {
@@ -379,6 +397,11 @@ fn fully_populated_receipt() -> AnalysisReceipt {
         by_language: BTreeMap::new(),
         by_module: vec![],
         top_exporters: vec![],
+        public_symbols: Default::default(),
+        doc_violations: vec![],
+        items: None,
+        items_truncated: false,
+        public_symbols_truncated: false,
     });
     r.fun = Some(FunReport {
         eco_label: Some(EcoLabel {
@@ -854,6 +877,7 @@ fn md_api_surface_with_language_breakdown() {
             public_items: 30,
             internal_items: 50,
             public_ratio: 0.375,
+            documented_ratio: 0.0,
         },
     );
     r.api_surface = Some(ApiSurfaceReport {
@@ -875,6 +899,11 @@ fn md_api_surface_with_language_breakdown() {
             public_items: 15,
             total_items: 30,
         }],
+        public_symbols: Default::default(),
+        doc_violations: vec![],
+        items: None,
+        items_truncated: false,
+        public_symbols_truncated: false,
     });
     let md = extract_text(render(&r, AnalysisFormat::Md).unwrap());
     assert!(md.contains("## API surface"));
@@ -1025,6 +1054,8 @@ fn md_git_intent_section() {
             }],
             unknown_pct: 0.067,
             corrective_ratio: Some(0.227),
+            recent: vec![],
+            truncated_messages: None,
         }),
     });
     let md = extract_text(render(&r, AnalysisFormat::Md).unwrap());
@@ -1076,6 +1107,8 @@ fn md_git_intent_skips_zero_counts() {
             by_module: vec![],
             unknown_pct: 0.0,
             corrective_ratio: None,
+            recent: vec![],
+            truncated_messages: None,
         }),
     });
     let md = extract_text(render(&r, AnalysisFormat::Md).unwrap());
@@ -1583,6 +1616,8 @@ fn md_churn_sorted_descending_by_slope() {
             r2: 0.5,
             recent_change: 1,
             classification: TrendClass::Flat,
+            projected_change: None,
+            projected_interval: None,
         },
     );
     per_module.insert(
@@ -1592,6 +1627,8 @@ fn md_churn_sorted_descending_by_slope() {
             r2: 0.9,
             recent_change: 10,
             classification: TrendClass::Rising,
+            projected_change: None,
+            projected_interval: None,
         },
     );
     per_module.insert(
@@ -1601,9 +1638,14 @@ fn md_churn_sorted_descending_by_slope() {
             r2: 0.7,
             recent_change: 5,
             classification: TrendClass::Rising,
+            projected_change: None,
+            projected_interval: None,
         },
     );
-    r.predictive_churn = Some(PredictiveChurnReport { per_module });
+    r.predictive_churn = Some(PredictiveChurnReport {
+        per_module,
+        forecast_days: None,
+    });
     let md = extract_text(render(&r, AnalysisFormat::Md).unwrap());
 
     let high_pos = md.find("|high_slope|").unwrap();
@@ -1639,6 +1681,7 @@ fn md_license_multiple_findings() {
                 source_kind: LicenseSourceKind::Text,
             },
         ],
+        header_compliance: None,
     });
     let md = extract_text(render(&r, AnalysisFormat::Md).unwrap());
     assert!(md.contains("Effective: `MIT OR Apache-2.0`"));
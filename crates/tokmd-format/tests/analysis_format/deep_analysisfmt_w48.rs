@@ -34,6 +34,7 @@ fn minimal_receipt() -> AnalysisReceipt {
             module_roots: vec![],
             module_depth: 1,
             children: "collapse".to_string(),
+            token_model: "heuristic".to_string(),
         },
         args: AnalysisArgsMeta {
             preset: "receipt".to_string(),
@@ -46,6 +47,14 @@ fn minimal_receipt() -> AnalysisReceipt {
             max_commits: None,
             max_commit_files: None,
             import_granularity: "module".to_string(),
+            oversized_file_bytes: None,
+            max_commit_message_bytes: None,
+            strip_bom: false,
+            preset_sections: None,
+            include_sections: vec![],
+            exclude_sections: vec![],
+            sample_rate: None,
+            max_runtime_ms: None,
         },
         archetype: None,
         topics: None,
@@ -62,6 +71,11 @@ fn minimal_receipt() -> AnalysisReceipt {
         complexity: None,
         api_surface: None,
         fun: None,
+        timings: None,
+        line_endings: None,
+        sampling: None,
+        risk_surface: Vec::new(),
+        extensions: Default::default(),
     }
 }
 
@@ -183,6 +197,8 @@ fn receipt_with_derived() -> AnalysisReceipt {
             entries: 3,
         },
         todo: None,
+        oversized_files: vec![],
+        non_utf8_files: vec![],
     });
     r
 }
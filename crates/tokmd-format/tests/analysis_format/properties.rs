@@ -211,6 +211,8 @@ fn arb_derived() -> impl Strategy<Value = DerivedReport> {
                     hash: "0".repeat(64),
                     entries: files,
                 },
+                oversized_files: vec![],
+                non_utf8_files: vec![],
             }
         })
 }
@@ -238,6 +240,7 @@ fn arb_receipt() -> impl Strategy<Value = AnalysisReceipt> {
                 module_roots: vec![],
                 module_depth: 1,
                 children: "collapse".into(),
+                token_model: "heuristic".to_string(),
             },
             args: AnalysisArgsMeta {
                 preset,
@@ -250,6 +253,14 @@ fn arb_receipt() -> impl Strategy<Value = AnalysisReceipt> {
                 max_commits: None,
                 max_commit_files: None,
                 import_granularity: "module".into(),
+                oversized_file_bytes: None,
+                max_commit_message_bytes: None,
+                strip_bom: false,
+                preset_sections: None,
+                include_sections: vec![],
+                exclude_sections: vec![],
+                sample_rate: None,
+                max_runtime_ms: None,
             },
             archetype: None,
             topics: None,
@@ -266,6 +277,11 @@ fn arb_receipt() -> impl Strategy<Value = AnalysisReceipt> {
             complexity: None,
             api_surface: None,
             fun: None,
+            timings: None,
+            line_endings: None,
+            sampling: None,
+            risk_surface: Vec::new(),
+            extensions: Default::default(),
         }
     })
 }
@@ -429,6 +445,7 @@ proptest! {
                 module_roots: vec![],
                 module_depth: 1,
                 children: "collapse".into(),
+                token_model: "heuristic".to_string(),
             },
             args: AnalysisArgsMeta {
                 preset: "receipt".into(),
@@ -441,6 +458,14 @@ proptest! {
                 max_commits: None,
                 max_commit_files: None,
                 import_granularity: "module".into(),
+                oversized_file_bytes: None,
+                max_commit_message_bytes: None,
+                strip_bom: false,
+                preset_sections: None,
+                include_sections: vec![],
+                exclude_sections: vec![],
+                sample_rate: None,
+                max_runtime_ms: None,
             },
             archetype: None,
             topics: None,
@@ -457,6 +482,11 @@ proptest! {
             complexity: None,
             api_surface: None,
             fun: None,
+            timings: None,
+            line_endings: None,
+            sampling: None,
+            risk_surface: Vec::new(),
+            extensions: Default::default(),
         };
         let rendered = render(&receipt, AnalysisFormat::Md).unwrap();
         let text = match rendered {
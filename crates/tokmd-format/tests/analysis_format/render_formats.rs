@@ -36,6 +36,7 @@ fn minimal_receipt() -> AnalysisReceipt {
             module_roots: vec![],
             module_depth: 1,
             children: "collapse".into(),
+            token_model: "heuristic".to_string(),
         },
         args: AnalysisArgsMeta {
             preset: "receipt".into(),
@@ -48,6 +49,14 @@ fn minimal_receipt() -> AnalysisReceipt {
             max_commits: None,
             max_commit_files: None,
             import_granularity: "module".into(),
+            oversized_file_bytes: None,
+            max_commit_message_bytes: None,
+            strip_bom: false,
+            preset_sections: None,
+            include_sections: vec![],
+            exclude_sections: vec![],
+            sample_rate: None,
+            max_runtime_ms: None,
         },
         archetype: None,
         topics: None,
@@ -64,6 +73,11 @@ fn minimal_receipt() -> AnalysisReceipt {
         complexity: None,
         api_surface: None,
         fun: None,
+        timings: None,
+        line_endings: None,
+        sampling: None,
+        risk_surface: Vec::new(),
+        extensions: Default::default(),
     }
 }
 
@@ -230,6 +244,8 @@ fn sample_derived() -> DerivedReport {
             hash: "a".repeat(64),
             entries: 5,
         },
+        oversized_files: vec![],
+        non_utf8_files: vec![],
     }
 }
 
@@ -587,6 +603,7 @@ fn md_renders_license_section() {
             source_path: "LICENSE".into(),
             source_kind: LicenseSourceKind::Text,
         }],
+        header_compliance: None,
     });
     let output = render(&receipt, AnalysisFormat::Md).unwrap();
     let text = match output {
@@ -607,11 +624,13 @@ fn md_renders_corporate_fingerprint() {
                 domain: "example.com".into(),
                 commits: 100,
                 pct: 0.8,
+                members: Vec::new(),
             },
             DomainStat {
                 domain: "other.org".into(),
                 commits: 25,
                 pct: 0.2,
+                members: Vec::new(),
             },
         ],
     });
@@ -648,9 +667,14 @@ fn md_renders_predictive_churn() {
             r2: 0.85,
             recent_change: 3,
             classification: TrendClass::Rising,
+            projected_change: None,
+            projected_interval: None,
         },
     );
-    receipt.predictive_churn = Some(PredictiveChurnReport { per_module });
+    receipt.predictive_churn = Some(PredictiveChurnReport {
+        per_module,
+        forecast_days: None,
+    });
     let output = render(&receipt, AnalysisFormat::Md).unwrap();
     let text = match output {
         RenderedOutput::Text(s) => s,
@@ -800,6 +824,7 @@ fn md_renders_api_surface() {
             public_items: 40,
             internal_items: 60,
             public_ratio: 0.4,
+            documented_ratio: 0.0,
         },
     );
     receipt.api_surface = Some(ApiSurfaceReport {
@@ -821,6 +846,11 @@ fn md_renders_api_surface() {
             public_items: 20,
             total_items: 40,
         }],
+        public_symbols: Default::default(),
+        doc_violations: vec![],
+        items: None,
+        items_truncated: false,
+        public_symbols_truncated: false,
     });
     let output = render(&receipt, AnalysisFormat::Md).unwrap();
     let text = match output {
@@ -139,6 +139,8 @@ fn make_derived_report(largest_lines: Vec<FileStatRow>) -> DerivedReport {
             hash: "0".repeat(64),
             entries: 1,
         },
+        oversized_files: vec![],
+        non_utf8_files: vec![],
     }
 }
 
@@ -165,6 +167,7 @@ fn make_receipt(derived: DerivedReport) -> AnalysisReceipt {
             module_roots: vec!["crates".into()],
             module_depth: 2,
             children: "separate".into(),
+            token_model: "heuristic".to_string(),
         },
         args: AnalysisArgsMeta {
             preset: "receipt".into(),
@@ -177,6 +180,14 @@ fn make_receipt(derived: DerivedReport) -> AnalysisReceipt {
             max_commits: None,
             max_commit_files: None,
             import_granularity: "module".into(),
+            oversized_file_bytes: None,
+            max_commit_message_bytes: None,
+            strip_bom: false,
+            preset_sections: None,
+            include_sections: vec![],
+            exclude_sections: vec![],
+            sample_rate: None,
+            max_runtime_ms: None,
         },
         archetype: None,
         topics: None,
@@ -193,6 +204,11 @@ fn make_receipt(derived: DerivedReport) -> AnalysisReceipt {
         complexity: None,
         api_surface: None,
         fun: None,
+        timings: None,
+        line_endings: None,
+        sampling: None,
+        risk_surface: Vec::new(),
+        extensions: Default::default(),
     }
 }
 
@@ -40,6 +40,7 @@ fn minimal_source() -> AnalysisSource {
         module_roots: vec![],
         module_depth: 1,
         children: "collapse".to_string(),
+        token_model: "heuristic".to_string(),
     }
 }
 
@@ -55,6 +56,14 @@ fn minimal_args() -> AnalysisArgsMeta {
         max_commit_files: None,
         max_file_bytes: None,
         import_granularity: "module".to_string(),
+        oversized_file_bytes: None,
+        max_commit_message_bytes: None,
+        strip_bom: false,
+        preset_sections: None,
+        include_sections: vec![],
+        exclude_sections: vec![],
+        sample_rate: None,
+        max_runtime_ms: None,
     }
 }
 
@@ -84,6 +93,11 @@ fn empty_receipt() -> AnalysisReceipt {
         complexity: None,
         api_surface: None,
         fun: None,
+        timings: None,
+        line_endings: None,
+        sampling: None,
+        risk_surface: Vec::new(),
+        extensions: Default::default(),
     }
 }
 
@@ -228,6 +242,8 @@ fn sample_derived() -> DerivedReport {
             hash: "abc123def456".into(),
             entries: 10,
         },
+        oversized_files: vec![],
+        non_utf8_files: vec![],
     }
 }
 
@@ -349,6 +365,7 @@ fn snapshot_md_multi_enrichment() {
             source_kind: LicenseSourceKind::Text,
         }],
         effective: Some("MIT".into()),
+        header_compliance: None,
     });
     let text = extract_text(render(&receipt, AnalysisFormat::Md).unwrap());
     insta::assert_snapshot!(text);
@@ -482,6 +499,7 @@ fn snapshot_xml_archetype_license() {
             },
         ],
         effective: Some("Apache-2.0 OR MIT".into()),
+        header_compliance: None,
     });
     let text = extract_text(render(&receipt, AnalysisFormat::Xml).unwrap());
     insta::assert_snapshot!(text);
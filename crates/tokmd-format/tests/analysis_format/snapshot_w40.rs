@@ -28,6 +28,7 @@ fn minimal_source() -> AnalysisSource {
         module_roots: vec![],
         module_depth: 1,
         children: "collapse".to_string(),
+        token_model: "heuristic".to_string(),
     }
 }
 
@@ -43,6 +44,14 @@ fn minimal_args() -> AnalysisArgsMeta {
         max_commit_files: None,
         max_file_bytes: None,
         import_granularity: "module".to_string(),
+        oversized_file_bytes: None,
+        max_commit_message_bytes: None,
+        strip_bom: false,
+        preset_sections: None,
+        include_sections: vec![],
+        exclude_sections: vec![],
+        sample_rate: None,
+        max_runtime_ms: None,
     }
 }
 
@@ -72,6 +81,11 @@ fn minimal_receipt() -> AnalysisReceipt {
         complexity: None,
         api_surface: None,
         fun: None,
+        timings: None,
+        line_endings: None,
+        sampling: None,
+        risk_surface: Vec::new(),
+        extensions: Default::default(),
     }
 }
 
@@ -226,6 +240,8 @@ fn sample_derived() -> DerivedReport {
             hash: "abc123def456".into(),
             entries: 10,
         },
+        oversized_files: vec![],
+        non_utf8_files: vec![],
     }
 }
 
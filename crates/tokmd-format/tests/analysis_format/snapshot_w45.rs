@@ -35,6 +35,7 @@ fn minimal_source() -> AnalysisSource {
         module_roots: vec![],
         module_depth: 1,
         children: "collapse".to_string(),
+        token_model: "heuristic".to_string(),
     }
 }
 
@@ -50,6 +51,14 @@ fn minimal_args() -> AnalysisArgsMeta {
         max_commit_files: None,
         max_file_bytes: None,
         import_granularity: "module".to_string(),
+        oversized_file_bytes: None,
+        max_commit_message_bytes: None,
+        strip_bom: false,
+        preset_sections: None,
+        include_sections: vec![],
+        exclude_sections: vec![],
+        sample_rate: None,
+        max_runtime_ms: None,
     }
 }
 
@@ -79,6 +88,11 @@ fn minimal_receipt() -> AnalysisReceipt {
         complexity: None,
         api_surface: None,
         fun: None,
+        timings: None,
+        line_endings: None,
+        sampling: None,
+        risk_surface: Vec::new(),
+        extensions: Default::default(),
     }
 }
 
@@ -233,6 +247,8 @@ fn sample_derived() -> DerivedReport {
             hash: "abc123def456".into(),
             entries: 10,
         },
+        oversized_files: vec![],
+        non_utf8_files: vec![],
     }
 }
 
@@ -26,6 +26,7 @@ fn minimal_receipt() -> AnalysisReceipt {
             module_roots: vec![],
             module_depth: 1,
             children: "collapse".into(),
+            token_model: "heuristic".to_string(),
         },
         args: AnalysisArgsMeta {
             preset: "receipt".into(),
@@ -38,6 +39,14 @@ fn minimal_receipt() -> AnalysisReceipt {
             max_commit_files: None,
             max_file_bytes: None,
             import_granularity: "module".into(),
+            oversized_file_bytes: None,
+            max_commit_message_bytes: None,
+            strip_bom: false,
+            preset_sections: None,
+            include_sections: vec![],
+            exclude_sections: vec![],
+            sample_rate: None,
+            max_runtime_ms: None,
         },
         archetype: None,
         topics: None,
@@ -55,6 +64,11 @@ fn minimal_receipt() -> AnalysisReceipt {
         api_surface: None,
         effort: None,
         fun: None,
+        timings: None,
+        line_endings: None,
+        sampling: None,
+        risk_surface: Vec::new(),
+        extensions: Default::default(),
     }
 }
 
@@ -191,6 +205,8 @@ fn derived_with_files(files: Vec<FileStatRow>) -> DerivedReport {
             hash: "test".into(),
             entries: files.len(),
         },
+        oversized_files: vec![],
+        non_utf8_files: vec![],
     }
 }
 
@@ -1,7 +1,8 @@
 //! Depth tests for tokmd-format analysis HTML rendering.
 
 use tokmd_analysis_types::*;
-use tokmd_format::analysis::html::render;
+use tokmd_format::analysis::AnalysisRenderOptions;
+use tokmd_format::analysis::html::{render, render_with};
 
 // ── helpers ────────────────────────────────────────────────────────
 
@@ -26,6 +27,7 @@ fn minimal_receipt() -> AnalysisReceipt {
             module_roots: vec![],
             module_depth: 1,
             children: "collapse".into(),
+            token_model: "heuristic".to_string(),
         },
         args: AnalysisArgsMeta {
             preset: "receipt".into(),
@@ -38,6 +40,14 @@ fn minimal_receipt() -> AnalysisReceipt {
             max_commit_files: None,
             max_file_bytes: None,
             import_granularity: "module".into(),
+            oversized_file_bytes: None,
+            max_commit_message_bytes: None,
+            strip_bom: false,
+            preset_sections: None,
+            include_sections: vec![],
+            exclude_sections: vec![],
+            sample_rate: None,
+            max_runtime_ms: None,
         },
         archetype: None,
         topics: None,
@@ -55,6 +65,11 @@ fn minimal_receipt() -> AnalysisReceipt {
         api_surface: None,
         effort: None,
         fun: None,
+        timings: None,
+        line_endings: None,
+        sampling: None,
+        risk_surface: Vec::new(),
+        extensions: Default::default(),
     }
 }
 
@@ -207,6 +222,8 @@ fn sample_derived() -> DerivedReport {
             hash: "abc123".into(),
             entries: 10,
         },
+        oversized_files: vec![],
+        non_utf8_files: vec![],
     }
 }
 
@@ -484,20 +501,30 @@ fn table_rows_capped_at_100() {
 }
 
 #[test]
-fn large_dataset_report_json_has_all_files() {
+fn large_dataset_report_json_follows_row_limit() {
     let mut r = receipt_with_derived();
     let d = r.derived.as_mut().unwrap();
     d.top.largest_lines = (0..200)
         .map(|i| make_file_row(&format!("src/file_{i}.rs"), "Rust", 100))
         .collect();
-    let html = render(&r);
-    // JSON section contains all files (no cap)
-    let json_start = html.find("const REPORT_DATA = ").unwrap() + "const REPORT_DATA = ".len();
-    let json_end = html[json_start..].find(";\n").unwrap() + json_start;
-    let json_str = &html[json_start..json_end];
-    let json_str = json_str.replace("\\u003c", "<").replace("\\u003e", ">");
-    let parsed: serde_json::Value = serde_json::from_str(&json_str).unwrap();
-    assert_eq!(parsed["files"].as_array().unwrap().len(), 200);
+    let report_files = |html: &str| {
+        let json_start = html.find("const REPORT_DATA = ").unwrap() + "const REPORT_DATA = ".len();
+        let json_end = html[json_start..].find(";\n").unwrap() + json_start;
+        let json_str = html[json_start..json_end]
+            .replace("\\u003c", "<")
+            .replace("\\u003e", ">");
+        let parsed: serde_json::Value = serde_json::from_str(&json_str).unwrap();
+        parsed["files"].as_array().unwrap().len()
+    };
+
+    // JSON section is capped with the table by default
+    assert_eq!(report_files(&render(&r)), 100);
+    // and lists every file when the limit is 0
+    let all = AnalysisRenderOptions {
+        html_max_rows: Some(0),
+        ..Default::default()
+    };
+    assert_eq!(report_files(&render_with(&r, &all)), 200);
 }
 
 // ── 8. Number formatting ──────────────────────────────────────────
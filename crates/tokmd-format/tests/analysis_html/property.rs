@@ -5,7 +5,8 @@
 
 use proptest::prelude::*;
 use tokmd_analysis_types::*;
-use tokmd_format::analysis::html::render;
+use tokmd_format::analysis::AnalysisRenderOptions;
+use tokmd_format::analysis::html::{render, render_with};
 
 // ── Strategies ──────────────────────────────────────────────────────
 
@@ -21,6 +22,26 @@ fn html_nasty_string() -> impl Strategy<Value = String> {
         )
 }
 
+/// Concatenated fragments aimed at the renderer's escaping seams: script and
+/// comment terminators, attribute breakouts, JS line terminators, and the
+/// template's own placeholders.
+fn adversarial_string() -> impl Strategy<Value = String> {
+    let fragment = prop_oneof![
+        prop::string::string_regex("[a-zA-Z0-9 _/.\\-<>&\"'=!{}]{0,8}").unwrap(),
+        Just("</script>".to_string()),
+        Just("</SCRIPT ".to_string()),
+        Just("<!--".to_string()),
+        Just("-->".to_string()),
+        Just("\" onmouseover=\"x".to_string()),
+        Just("&amp;".to_string()),
+        Just("\u{2028}\u{2029}".to_string()),
+        Just("{{REPORT_JSON}}".to_string()),
+        Just("{{RECEIPT_SCRIPT}}".to_string()),
+        Just("{{TABLE_ROWS}}".to_string()),
+    ];
+    prop::collection::vec(fragment, 0..6).prop_map(|parts| parts.concat())
+}
+
 fn arb_file_row() -> impl Strategy<Value = FileStatRow> {
     (
         html_nasty_string(),
@@ -65,6 +86,7 @@ fn minimal_receipt() -> AnalysisReceipt {
             module_roots: vec![],
             module_depth: 1,
             children: "collapse".into(),
+            token_model: "heuristic".to_string(),
         },
         args: AnalysisArgsMeta {
             preset: "receipt".into(),
@@ -77,6 +99,14 @@ fn minimal_receipt() -> AnalysisReceipt {
             max_commit_files: None,
             max_file_bytes: None,
             import_granularity: "module".into(),
+            oversized_file_bytes: None,
+            max_commit_message_bytes: None,
+            strip_bom: false,
+            preset_sections: None,
+            include_sections: vec![],
+            exclude_sections: vec![],
+            sample_rate: None,
+            max_runtime_ms: None,
         },
         archetype: None,
         topics: None,
@@ -94,6 +124,11 @@ fn minimal_receipt() -> AnalysisReceipt {
         api_surface: None,
         effort: None,
         fun: None,
+        timings: None,
+        line_endings: None,
+        sampling: None,
+        risk_surface: Vec::new(),
+        extensions: Default::default(),
     }
 }
 
@@ -223,6 +258,8 @@ fn derived_with_files(files: Vec<FileStatRow>) -> DerivedReport {
             hash: "test".into(),
             entries: files.len(),
         },
+        oversized_files: vec![],
+        non_utf8_files: vec![],
     }
 }
 
@@ -458,3 +495,131 @@ proptest! {
         }
     }
 }
+
+// ── Property: adversarial names never change the document structure ──
+
+/// Tag names of `html` in document order, walking it like a tokenizer.
+///
+/// `script` and `style` bodies are skipped up to their end tag (and must not
+/// hold `<!--`); any tag containing `<` or an odd number of `"`, raw `>` in
+/// text, or a bare `&` is an error.
+fn tag_names(html: &str) -> Result<Vec<String>, String> {
+    let lower = html.to_ascii_lowercase();
+    let mut names = Vec::new();
+    let mut i = 0;
+    loop {
+        let text_end = html[i..].find('<').map_or(html.len(), |lt| i + lt);
+        let text = &html[i..text_end];
+        if text.contains('>') {
+            return Err(format!("raw '>' in text {text:?}"));
+        }
+        for (amp, _) in text.match_indices('&') {
+            let entity = text[amp + 1..].split(';').next().unwrap_or("");
+            if !text[amp + 1..].contains(';')
+                || entity.is_empty()
+                || !entity
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '#')
+            {
+                return Err(format!("bare '&' in text {text:?}"));
+            }
+        }
+        if text_end == html.len() {
+            return Ok(names);
+        }
+
+        let gt = html[text_end..]
+            .find('>')
+            .ok_or_else(|| "unterminated tag".to_string())?;
+        let tag = &html[text_end + 1..text_end + gt];
+        if tag.contains('<') || !tag.matches('"').count().is_multiple_of(2) {
+            return Err(format!("malformed tag <{tag}>"));
+        }
+        let name = tag
+            .split(|c: char| c.is_whitespace() || c == '>')
+            .next()
+            .unwrap_or("")
+            .to_ascii_lowercase();
+        i = text_end + gt + 1;
+
+        if name == "script" || name == "style" {
+            let close = format!("</{name}");
+            let end = lower[i..]
+                .find(&close)
+                .ok_or_else(|| format!("unclosed <{name}>"))?;
+            if name == "script" && lower[i..i + end].contains("<!--") {
+                return Err("'<!--' inside <script>".to_string());
+            }
+            i += end;
+        }
+        names.push(name);
+    }
+}
+
+fn named_row(path: String, module: String, lang: String) -> FileStatRow {
+    FileStatRow {
+        path,
+        module,
+        lang,
+        code: 42,
+        comments: 8,
+        blanks: 4,
+        lines: 54,
+        bytes: 2100,
+        tokens: 126,
+        doc_pct: Some(0.15),
+        bytes_per_line: Some(40.0),
+        depth: 1,
+    }
+}
+
+proptest! {
+    #[test]
+    fn adversarial_names_render_with_the_same_tags_as_plain_ones(
+        names in prop::collection::vec(
+            (adversarial_string(), adversarial_string(), adversarial_string()),
+            1..6,
+        ),
+        input in adversarial_string(),
+    ) {
+        let options = AnalysisRenderOptions {
+            embed_receipt: true,
+            ..Default::default()
+        };
+        let mut receipt = minimal_receipt();
+        receipt.source.inputs = vec![input];
+        let rows: Vec<_> = names
+            .iter()
+            .map(|(p, m, l)| named_row(p.clone(), m.clone(), l.clone()))
+            .collect();
+        receipt.derived = Some(derived_with_files(rows));
+        let mut plain = minimal_receipt();
+        let plain_rows = (0..names.len())
+            .map(|_| named_row("a".into(), "m".into(), "Rust".into()))
+            .collect();
+        plain.derived = Some(derived_with_files(plain_rows));
+
+        let html = render_with(&receipt, &options);
+        let tags = tag_names(&html).map_err(TestCaseError::fail)?;
+        prop_assert_eq!(tags, tag_names(&render_with(&plain, &options)).unwrap());
+
+        // The treemap data and embedded receipt still round-trip.
+        let start = html.find("const REPORT_DATA = ").unwrap() + "const REPORT_DATA = ".len();
+        let end = start + html[start..].find(";\n").unwrap();
+        let data: serde_json::Value = serde_json::from_str(&html[start..end]).unwrap();
+        let paths: Vec<_> = data["files"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|f| f["path"].as_str().unwrap().to_string())
+            .collect();
+        let expected: Vec<_> = names.iter().map(|(p, _, _)| p.clone()).collect();
+        prop_assert_eq!(paths, expected);
+
+        const OPEN: &str = "<script type=\"application/json\" id=\"tokmd-receipt\">";
+        let start = html.find(OPEN).unwrap() + OPEN.len();
+        let end = start + html[start..].find("</script>").unwrap();
+        let embedded: AnalysisReceipt = serde_json::from_str(&html[start..end]).unwrap();
+        prop_assert_eq!(embedded.source.inputs, receipt.source.inputs);
+    }
+}
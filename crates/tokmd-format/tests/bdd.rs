@@ -12,7 +12,7 @@ use tokmd_format::{
     compute_diff_rows, compute_diff_totals, render_diff_md, write_export_csv_to,
     write_lang_report_to, write_module_report_to,
 };
-use tokmd_settings::{ChildIncludeMode, ChildrenMode, ScanOptions};
+use tokmd_settings::{AvgMeasure, ChildIncludeMode, ChildrenMode, ScanOptions};
 use tokmd_types::{
     ExportArgs, ExportData, ExportFormat, FileKind, FileRow, LangArgs, LangReport, LangRow,
     ModuleArgs, ModuleReport, ModuleRow, RedactMode, TableFormat, Totals,
@@ -37,6 +37,10 @@ fn sample_lang_report(with_files: bool) -> LangReport {
                 bytes: 25000,
                 tokens: 1250,
                 avg_lines: 120,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
             LangRow {
                 lang: "Python".to_string(),
@@ -46,6 +50,10 @@ fn sample_lang_report(with_files: bool) -> LangReport {
                 bytes: 10000,
                 tokens: 500,
                 avg_lines: 83,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
         ],
         total: Totals {
@@ -55,6 +63,9 @@ fn sample_lang_report(with_files: bool) -> LangReport {
             bytes: 35000,
             tokens: 1750,
             avg_lines: 106,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files,
         children: ChildrenMode::Collapse,
@@ -72,6 +83,11 @@ fn sample_module_report() -> ModuleReport {
             bytes: 20000,
             tokens: 1000,
             avg_lines: 125,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
+            children: Vec::new(),
         }],
         total: Totals {
             code: 400,
@@ -80,6 +96,9 @@ fn sample_module_report() -> ModuleReport {
             bytes: 20000,
             tokens: 1000,
             avg_lines: 125,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         module_roots: vec!["src".to_string()],
         module_depth: 2,
@@ -102,6 +121,9 @@ fn sample_export_data() -> ExportData {
                 lines: 130,
                 bytes: 5000,
                 tokens: 250,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             },
             FileRow {
                 path: "tests/test.rs".to_string(),
@@ -114,6 +136,9 @@ fn sample_export_data() -> ExportData {
                 lines: 60,
                 bytes: 2000,
                 tokens: 100,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             },
         ],
         module_roots: vec!["src".to_string()],
@@ -135,6 +160,10 @@ fn default_export_args() -> ExportArgs {
         redact: RedactMode::None,
         meta: false,
         strip_prefix: None,
+        filters: Default::default(),
+        sort_by: None,
+        sort_dir: None,
+        summary: false,
     }
 }
 
@@ -146,6 +175,9 @@ fn zero_totals() -> Totals {
         bytes: 0,
         tokens: 0,
         avg_lines: 0,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
     }
 }
 
@@ -165,6 +197,9 @@ mod given_lang_report_without_files {
             top: 0,
             files: false,
             children: ChildrenMode::Collapse,
+            avg: AvgMeasure::Mean,
+            percentiles: false,
+            with_files_detail: false,
         };
         let mut buf = Vec::new();
         write_lang_report_to(&mut buf, &report, &default_scan_options(), &args)
@@ -191,6 +226,9 @@ mod given_lang_report_with_files {
             top: 0,
             files: true,
             children: ChildrenMode::Collapse,
+            avg: AvgMeasure::Mean,
+            percentiles: false,
+            with_files_detail: false,
         };
         let mut buf = Vec::new();
         write_lang_report_to(&mut buf, &report, &default_scan_options(), &args)
@@ -210,6 +248,9 @@ mod given_lang_report_with_files {
             top: 0,
             files: true,
             children: ChildrenMode::Collapse,
+            avg: AvgMeasure::Mean,
+            percentiles: false,
+            with_files_detail: false,
         };
         let mut buf = Vec::new();
         write_lang_report_to(&mut buf, &report, &default_scan_options(), &args)
@@ -229,6 +270,9 @@ mod given_lang_report_with_files {
             top: 0,
             files: true,
             children: ChildrenMode::Collapse,
+            avg: AvgMeasure::Mean,
+            percentiles: false,
+            with_files_detail: false,
         };
         let mut buf = Vec::new();
         write_lang_report_to(&mut buf, &report, &default_scan_options(), &args)
@@ -264,6 +308,10 @@ mod given_module_report {
             module_roots: vec!["src".to_string()],
             module_depth: 2,
             children: ChildIncludeMode::Separate,
+            collapse_single_child: false,
+            avg: AvgMeasure::Mean,
+            percentiles: false,
+            with_files_detail: false,
         };
         let mut buf = Vec::new();
         write_module_report_to(&mut buf, &report, &default_scan_options(), &args)
@@ -285,6 +333,10 @@ mod given_module_report {
             module_roots: vec!["src".to_string()],
             module_depth: 2,
             children: ChildIncludeMode::Separate,
+            collapse_single_child: false,
+            avg: AvgMeasure::Mean,
+            percentiles: false,
+            with_files_detail: false,
         };
         let mut buf = Vec::new();
         write_module_report_to(&mut buf, &report, &default_scan_options(), &args)
@@ -315,6 +367,10 @@ mod given_two_lang_reports {
                 bytes: 5000,
                 tokens: 250,
                 avg_lines: 60,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             }],
             total: Totals {
                 code: 100,
@@ -323,6 +379,9 @@ mod given_two_lang_reports {
                 bytes: 5000,
                 tokens: 250,
                 avg_lines: 60,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
             },
             with_files: false,
             children: ChildrenMode::Collapse,
@@ -338,6 +397,10 @@ mod given_two_lang_reports {
                 bytes: 7500,
                 tokens: 375,
                 avg_lines: 60,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             }],
             total: Totals {
                 code: 150,
@@ -346,6 +409,9 @@ mod given_two_lang_reports {
                 bytes: 7500,
                 tokens: 375,
                 avg_lines: 60,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
             },
             with_files: false,
             children: ChildrenMode::Collapse,
@@ -378,6 +444,10 @@ mod given_two_lang_reports {
                 bytes: 2000,
                 tokens: 100,
                 avg_lines: 60,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             }],
             total: Totals {
                 code: 50,
@@ -386,6 +456,9 @@ mod given_two_lang_reports {
                 bytes: 2000,
                 tokens: 100,
                 avg_lines: 60,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
             },
             with_files: false,
             children: ChildrenMode::Collapse,
@@ -410,6 +483,10 @@ mod given_two_lang_reports {
                 bytes: 4000,
                 tokens: 200,
                 avg_lines: 50,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             }],
             total: Totals {
                 code: 80,
@@ -418,6 +495,9 @@ mod given_two_lang_reports {
                 bytes: 4000,
                 tokens: 200,
                 avg_lines: 50,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
             },
             with_files: false,
             children: ChildrenMode::Collapse,
@@ -8,7 +8,7 @@ use tokmd_format::{
     write_export_csv_to, write_export_json_to, write_export_jsonl_to, write_lang_report_to,
     write_module_report_to,
 };
-use tokmd_settings::{ChildIncludeMode, ChildrenMode, ScanOptions};
+use tokmd_settings::{AvgMeasure, ChildIncludeMode, ChildrenMode, ScanOptions};
 use tokmd_types::{
     ExportArgs, ExportData, ExportFormat, FileKind, FileRow, LangArgs, LangReport, LangRow,
     ModuleArgs, ModuleReport, ModuleRow, RedactMode, TableFormat, Totals,
@@ -29,6 +29,9 @@ fn lang_args(format: TableFormat) -> LangArgs {
         top: 0,
         files: false,
         children: ChildrenMode::Collapse,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     }
 }
 
@@ -40,6 +43,10 @@ fn module_args(format: TableFormat) -> ModuleArgs {
         module_roots: vec![],
         module_depth: 2,
         children: ChildIncludeMode::Separate,
+        collapse_single_child: false,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     }
 }
 
@@ -56,6 +63,10 @@ fn export_args(format: ExportFormat) -> ExportArgs {
         redact: RedactMode::None,
         meta: true,
         strip_prefix: None,
+        filters: Default::default(),
+        sort_by: None,
+        sort_dir: None,
+        summary: false,
     }
 }
 
@@ -70,6 +81,10 @@ fn sample_lang_report() -> LangReport {
                 bytes: 180000,
                 tokens: 12500,
                 avg_lines: 148,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
             LangRow {
                 lang: "TOML".to_string(),
@@ -79,6 +94,10 @@ fn sample_lang_report() -> LangReport {
                 bytes: 8000,
                 tokens: 500,
                 avg_lines: 52,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
         ],
         total: Totals {
@@ -88,6 +107,9 @@ fn sample_lang_report() -> LangReport {
             bytes: 188000,
             tokens: 13000,
             avg_lines: 137,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: true,
         children: ChildrenMode::Collapse,
@@ -105,6 +127,10 @@ fn single_lang_report() -> LangReport {
             bytes: 12000,
             tokens: 750,
             avg_lines: 133,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         }],
         total: Totals {
             code: 300,
@@ -113,6 +139,9 @@ fn single_lang_report() -> LangReport {
             bytes: 12000,
             tokens: 750,
             avg_lines: 133,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: true,
         children: ChildrenMode::Collapse,
@@ -130,6 +159,9 @@ fn empty_lang_report() -> LangReport {
             bytes: 0,
             tokens: 0,
             avg_lines: 0,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: false,
         children: ChildrenMode::Collapse,
@@ -148,6 +180,10 @@ fn embedded_lang_report() -> LangReport {
                 bytes: 32000,
                 tokens: 2000,
                 avg_lines: 100,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
             LangRow {
                 lang: "JavaScript (embedded)".to_string(),
@@ -157,6 +193,10 @@ fn embedded_lang_report() -> LangReport {
                 bytes: 8000,
                 tokens: 500,
                 avg_lines: 50,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
             LangRow {
                 lang: "CSS (embedded)".to_string(),
@@ -166,6 +206,10 @@ fn embedded_lang_report() -> LangReport {
                 bytes: 4000,
                 tokens: 250,
                 avg_lines: 40,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
         ],
         total: Totals {
@@ -175,6 +219,9 @@ fn embedded_lang_report() -> LangReport {
             bytes: 44000,
             tokens: 2750,
             avg_lines: 76,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: true,
         children: ChildrenMode::Separate,
@@ -193,6 +240,11 @@ fn sample_module_report() -> ModuleReport {
                 bytes: 120000,
                 tokens: 7500,
                 avg_lines: 152,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
+                children: Vec::new(),
             },
             ModuleRow {
                 module: "crates/format".to_string(),
@@ -202,6 +254,11 @@ fn sample_module_report() -> ModuleReport {
                 bytes: 60000,
                 tokens: 3750,
                 avg_lines: 158,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
+                children: Vec::new(),
             },
             ModuleRow {
                 module: "crates/types".to_string(),
@@ -211,6 +268,11 @@ fn sample_module_report() -> ModuleReport {
                 bytes: 20000,
                 tokens: 1250,
                 avg_lines: 150,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
+                children: Vec::new(),
             },
         ],
         total: Totals {
@@ -220,6 +282,9 @@ fn sample_module_report() -> ModuleReport {
             bytes: 200000,
             tokens: 12500,
             avg_lines: 154,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         module_roots: vec!["crates".to_string()],
         module_depth: 2,
@@ -239,6 +304,11 @@ fn nested_module_report() -> ModuleReport {
                 bytes: 80000,
                 tokens: 5000,
                 avg_lines: 167,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
+                children: Vec::new(),
             },
             ModuleRow {
                 module: "src/api".to_string(),
@@ -248,6 +318,11 @@ fn nested_module_report() -> ModuleReport {
                 bytes: 32000,
                 tokens: 2000,
                 avg_lines: 167,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
+                children: Vec::new(),
             },
             ModuleRow {
                 module: "src/api/v2".to_string(),
@@ -257,6 +332,11 @@ fn nested_module_report() -> ModuleReport {
                 bytes: 12000,
                 tokens: 750,
                 avg_lines: 127,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
+                children: Vec::new(),
             },
         ],
         total: Totals {
@@ -266,6 +346,9 @@ fn nested_module_report() -> ModuleReport {
             bytes: 124000,
             tokens: 7750,
             avg_lines: 162,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         module_roots: vec!["src".to_string()],
         module_depth: 3,
@@ -287,6 +370,9 @@ fn sample_file_rows() -> Vec<FileRow> {
             lines: 200,
             bytes: 6000,
             tokens: 375,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         },
         FileRow {
             path: "src/lib.rs".to_string(),
@@ -299,6 +385,9 @@ fn sample_file_rows() -> Vec<FileRow> {
             lines: 380,
             bytes: 12000,
             tokens: 750,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         },
         FileRow {
             path: "tests/integration.rs".to_string(),
@@ -311,6 +400,9 @@ fn sample_file_rows() -> Vec<FileRow> {
             lines: 98,
             bytes: 3200,
             tokens: 200,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         },
     ]
 }
@@ -962,6 +1054,42 @@ fn given_export_json_with_meta_then_has_envelope() {
     assert!(parsed.get("scan").is_some(), "should have scan metadata");
 }
 
+#[test]
+fn given_export_json_with_windows_unsafe_paths_then_warnings_list_them() {
+    let mut data = sample_export_data();
+    data.rows[0].path = "src/aux.rs".to_string();
+    data.rows[1].path = format!("src/{}.rs", "x".repeat(300));
+    let args = export_args(ExportFormat::Json);
+    let output =
+        render_to_string(|buf| write_export_json_to(buf, &data, &default_scan_options(), &args));
+
+    let parsed: serde_json::Value =
+        serde_json::from_str(output.trim()).expect("must parse valid JSON");
+    let warnings = parsed["warnings"]
+        .as_array()
+        .expect("warnings should be an array");
+    assert_eq!(warnings.len(), 2, "{warnings:?}");
+    assert_eq!(
+        warnings[0],
+        serde_json::json!({
+            "code": "non_portable_path",
+            "severity": "warning",
+            "message": "'aux.rs' is a reserved name on Windows",
+            "path": "src/aux.rs",
+        })
+    );
+    assert!(
+        warnings[1]["message"]
+            .as_str()
+            .is_some_and(|w| w.ends_with("over the Windows limit of 260")),
+        "{warnings:?}"
+    );
+    assert_eq!(
+        parsed["status"], "complete",
+        "warnings must not fail the scan"
+    );
+}
+
 #[test]
 fn given_export_json_then_data_rows_are_array() {
     let data = sample_export_data();
@@ -14,7 +14,7 @@ use tokmd_format::{
     write_export_cyclonedx_with_options, write_export_json_to, write_export_jsonl_to,
     write_lang_report_to, write_module_report_to,
 };
-use tokmd_settings::{ChildIncludeMode, ChildrenMode, ScanOptions};
+use tokmd_settings::{AvgMeasure, ChildIncludeMode, ChildrenMode, ScanOptions};
 use tokmd_types::{
     ExportArgs, ExportData, ExportFormat, FileKind, FileRow, LangArgs, LangReport, LangRow,
     ModuleArgs, ModuleReport, ModuleRow, RedactMode, TableFormat, Totals,
@@ -36,6 +36,9 @@ fn lang_report(rows: Vec<LangRow>, with_files: bool) -> LangReport {
         } else {
             rows.iter().map(|r| r.lines).sum::<usize>() / rows.len()
         },
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
     };
     LangReport {
         rows,
@@ -54,6 +57,9 @@ fn module_report(rows: Vec<ModuleRow>) -> ModuleReport {
         bytes: rows.iter().map(|r| r.bytes).sum(),
         tokens: rows.iter().map(|r| r.tokens).sum(),
         avg_lines: 0,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
     };
     ModuleReport {
         rows,
@@ -81,6 +87,9 @@ fn lang_args(format: TableFormat) -> LangArgs {
         top: 0,
         files: true,
         children: ChildrenMode::Collapse,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     }
 }
 
@@ -92,6 +101,10 @@ fn module_args(format: TableFormat) -> ModuleArgs {
         module_roots: vec!["src".into()],
         module_depth: 1,
         children: ChildIncludeMode::Separate,
+        collapse_single_child: false,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     }
 }
 
@@ -108,6 +121,10 @@ fn export_args(format: ExportFormat) -> ExportArgs {
         redact: RedactMode::None,
         meta: true,
         strip_prefix: None,
+        filters: Default::default(),
+        sort_by: None,
+        sort_dir: None,
+        summary: false,
     }
 }
 
@@ -124,6 +141,10 @@ fn rust_row(code: usize) -> LangRow {
         bytes: code * 40,
         tokens: code * 2,
         avg_lines: 120,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
+        file_rows: Vec::new(),
     }
 }
 
@@ -136,6 +157,10 @@ fn python_row(code: usize) -> LangRow {
         bytes: code * 36,
         tokens: code * 2,
         avg_lines: 100,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
+        file_rows: Vec::new(),
     }
 }
 
@@ -148,6 +173,10 @@ fn toml_row(code: usize) -> LangRow {
         bytes: code * 30,
         tokens: code,
         avg_lines: 50,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
+        file_rows: Vec::new(),
     }
 }
 
@@ -160,6 +189,11 @@ fn sample_module_row(name: &str, code: usize) -> ModuleRow {
         bytes: code * 40,
         tokens: code * 2,
         avg_lines: 100,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
+        file_rows: Vec::new(),
+        children: Vec::new(),
     }
 }
 
@@ -175,6 +209,9 @@ fn sample_file_row(path: &str, lang: &str, code: usize) -> FileRow {
         lines: code + code / 10 + code / 20,
         bytes: code * 30,
         tokens: code * 2,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
@@ -635,6 +672,10 @@ fn special_chars_lang_name_md() {
         bytes: 4000,
         tokens: 200,
         avg_lines: 24,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
+        file_rows: Vec::new(),
     };
     let report = lang_report(vec![row], true);
     let output = render_lang(&report, TableFormat::Md);
@@ -651,6 +692,10 @@ fn special_chars_lang_name_tsv() {
         bytes: 2310,
         tokens: 154,
         avg_lines: 30,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
+        file_rows: Vec::new(),
     };
     let report = lang_report(vec![row], false);
     let output = render_lang(&report, TableFormat::Tsv);
@@ -670,6 +715,9 @@ fn special_chars_path_csv() {
         lines: 12,
         bytes: 300,
         tokens: 20,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     };
     let data = export_data(vec![row]);
     let output = render_export_csv(&data);
@@ -686,6 +734,10 @@ fn special_chars_unicode_lang_json() {
         bytes: 2000,
         tokens: 100,
         avg_lines: 60,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
+        file_rows: Vec::new(),
     };
     let report = lang_report(vec![row], true);
     let output = render_lang(&report, TableFormat::Json);
@@ -831,6 +883,9 @@ fn export_csv_child_file_kind() {
         lines: 23,
         bytes: 600,
         tokens: 40,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     };
     let data = export_data(vec![row]);
     let output = render_export_csv(&data);
@@ -13,9 +13,9 @@ use tokmd_format::{
 };
 use tokmd_settings::ScanOptions;
 use tokmd_types::{
-    ChildIncludeMode, ChildrenMode, DiffRow, ExportArgs, ExportData, ExportFormat, FileKind,
-    FileRow, LangArgs, LangReport, LangRow, ModuleArgs, ModuleReport, ModuleRow, RedactMode,
-    TableFormat, Totals,
+    AvgMeasure, ChildIncludeMode, ChildrenMode, DiffRow, ExportArgs, ExportData, ExportFormat,
+    FileKind, FileRow, LangArgs, LangReport, LangRow, ModuleArgs, ModuleReport, ModuleRow,
+    RedactMode, TableFormat, Totals,
 };
 
 // ============================================================================
@@ -33,6 +33,10 @@ fn sample_lang_report(with_files: bool) -> LangReport {
                 bytes: 50000,
                 tokens: 2500,
                 avg_lines: 120,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
             LangRow {
                 lang: "TOML".into(),
@@ -42,6 +46,10 @@ fn sample_lang_report(with_files: bool) -> LangReport {
                 bytes: 1000,
                 tokens: 125,
                 avg_lines: 30,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
         ],
         total: Totals {
@@ -51,6 +59,9 @@ fn sample_lang_report(with_files: bool) -> LangReport {
             bytes: 51000,
             tokens: 2625,
             avg_lines: 105,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files,
         children: ChildrenMode::Collapse,
@@ -69,6 +80,11 @@ fn sample_module_report() -> ModuleReport {
                 bytes: 40000,
                 tokens: 2000,
                 avg_lines: 119,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
+                children: Vec::new(),
             },
             ModuleRow {
                 module: "crates/bar".into(),
@@ -78,6 +94,11 @@ fn sample_module_report() -> ModuleReport {
                 bytes: 10000,
                 tokens: 500,
                 avg_lines: 125,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
+                children: Vec::new(),
             },
         ],
         total: Totals {
@@ -87,6 +108,9 @@ fn sample_module_report() -> ModuleReport {
             bytes: 50000,
             tokens: 2500,
             avg_lines: 120,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         module_roots: vec!["crates".into()],
         module_depth: 2,
@@ -108,6 +132,9 @@ fn sample_file_rows() -> Vec<FileRow> {
             lines: 130,
             bytes: 1000,
             tokens: 250,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         },
         FileRow {
             path: "tests/test.rs".into(),
@@ -120,6 +147,9 @@ fn sample_file_rows() -> Vec<FileRow> {
             lines: 60,
             bytes: 500,
             tokens: 125,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         },
     ]
 }
@@ -144,6 +174,9 @@ fn default_lang_args(format: TableFormat) -> LangArgs {
         top: 0,
         files: false,
         children: ChildrenMode::Collapse,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     }
 }
 
@@ -155,6 +188,10 @@ fn default_module_args(format: TableFormat) -> ModuleArgs {
         module_roots: vec!["crates".into()],
         module_depth: 2,
         children: ChildIncludeMode::Separate,
+        collapse_single_child: false,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     }
 }
 
@@ -171,6 +208,10 @@ fn default_export_args(format: ExportFormat) -> ExportArgs {
         redact: RedactMode::None,
         meta: true,
         strip_prefix: None,
+        filters: Default::default(),
+        sort_by: None,
+        sort_dir: None,
+        summary: false,
     }
 }
 
@@ -299,7 +340,7 @@ fn lang_json_has_schema_version() {
     let output = String::from_utf8(buf).expect("output must be valid UTF-8");
     let json: serde_json::Value =
         serde_json::from_str(output.trim()).expect("must parse valid JSON");
-    assert_eq!(json["schema_version"], 2);
+    assert_eq!(json["schema_version"], 3);
 }
 
 #[test]
@@ -520,7 +561,7 @@ fn json_export_has_schema_version() {
     let output = String::from_utf8(buf).expect("output must be valid UTF-8");
     let json: serde_json::Value =
         serde_json::from_str(output.trim()).expect("must parse valid JSON");
-    assert_eq!(json["schema_version"], 2);
+    assert_eq!(json["schema_version"], 3);
 }
 
 #[test]
@@ -556,6 +597,9 @@ fn empty_lang_report_md_still_has_header() {
             bytes: 0,
             tokens: 0,
             avg_lines: 0,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: false,
         children: ChildrenMode::Collapse,
@@ -581,6 +625,9 @@ fn empty_module_report_md_has_header() {
             bytes: 0,
             tokens: 0,
             avg_lines: 0,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         module_roots: vec![],
         module_depth: 1,
@@ -640,6 +687,10 @@ fn lang_md_special_chars_in_name() {
             bytes: 20000,
             tokens: 1000,
             avg_lines: 120,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         }],
         total: Totals {
             code: 500,
@@ -648,6 +699,9 @@ fn lang_md_special_chars_in_name() {
             bytes: 20000,
             tokens: 1000,
             avg_lines: 120,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: false,
         children: ChildrenMode::Collapse,
@@ -672,6 +726,10 @@ fn lang_md_embedded_suffix() {
             bytes: 5000,
             tokens: 250,
             avg_lines: 40,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         }],
         total: Totals {
             code: 100,
@@ -680,6 +738,9 @@ fn lang_md_embedded_suffix() {
             bytes: 5000,
             tokens: 250,
             avg_lines: 40,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: false,
         children: ChildrenMode::Separate,
@@ -739,6 +800,9 @@ fn make_lang_report(rows: Vec<LangRow>) -> LangReport {
             bytes,
             tokens,
             avg_lines: lines.checked_div(files).unwrap_or(0),
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: true,
         children: ChildrenMode::Collapse,
@@ -755,6 +819,10 @@ fn rust_row(code: usize) -> LangRow {
         bytes: code * 10,
         tokens: code * 2,
         avg_lines: (code + 50) / 5,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
+        file_rows: Vec::new(),
     }
 }
 
@@ -797,6 +865,10 @@ fn diff_rows_new_language_added() {
             bytes: 500,
             tokens: 100,
             avg_lines: 30,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         },
     ]);
     let rows = compute_diff_rows(&from, &to);
@@ -821,6 +893,10 @@ fn diff_rows_language_removed() {
             bytes: 500,
             tokens: 100,
             avg_lines: 30,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         },
     ]);
     let to = make_lang_report(vec![rust_row(100)]);
@@ -961,7 +1037,7 @@ fn diff_receipt_has_correct_mode() {
     assert_eq!(receipt.mode, "diff");
     assert_eq!(receipt.from_source, "v1");
     assert_eq!(receipt.to_source, "v2");
-    assert_eq!(receipt.schema_version, 2);
+    assert_eq!(receipt.schema_version, 3);
 }
 
 // ============================================================================
@@ -1054,6 +1130,9 @@ fn csv_child_kind_rendered() {
             lines: 12,
             bytes: 100,
             tokens: 25,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         }],
         module_roots: vec![],
         module_depth: 1,
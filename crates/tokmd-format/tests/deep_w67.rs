@@ -11,9 +11,9 @@ use tokmd_format::{
 };
 use tokmd_settings::ScanOptions;
 use tokmd_types::{
-    ChildIncludeMode, ChildrenMode, ExportArgs, ExportData, ExportFormat, FileKind, FileRow,
-    LangArgs, LangReport, LangRow, ModuleArgs, ModuleReport, ModuleRow, RedactMode, TableFormat,
-    Totals,
+    AvgMeasure, ChildIncludeMode, ChildrenMode, ExportArgs, ExportData, ExportFormat, FileKind,
+    FileRow, LangArgs, LangReport, LangRow, ModuleArgs, ModuleReport, ModuleRow, RedactMode,
+    TableFormat, Totals,
 };
 
 // ---------------------------------------------------------------------------
@@ -31,6 +31,10 @@ fn sample_lang_report(with_files: bool) -> LangReport {
                 bytes: 50000,
                 tokens: 2500,
                 avg_lines: 120,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
             LangRow {
                 lang: "TOML".into(),
@@ -40,6 +44,10 @@ fn sample_lang_report(with_files: bool) -> LangReport {
                 bytes: 1000,
                 tokens: 125,
                 avg_lines: 30,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
         ],
         total: Totals {
@@ -49,6 +57,9 @@ fn sample_lang_report(with_files: bool) -> LangReport {
             bytes: 51000,
             tokens: 2625,
             avg_lines: 105,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files,
         children: ChildrenMode::Collapse,
@@ -67,6 +78,11 @@ fn sample_module_report() -> ModuleReport {
                 bytes: 40000,
                 tokens: 2000,
                 avg_lines: 119,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
+                children: Vec::new(),
             },
             ModuleRow {
                 module: "crates/bar".into(),
@@ -76,6 +92,11 @@ fn sample_module_report() -> ModuleReport {
                 bytes: 10000,
                 tokens: 500,
                 avg_lines: 125,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
+                children: Vec::new(),
             },
         ],
         total: Totals {
@@ -85,6 +106,9 @@ fn sample_module_report() -> ModuleReport {
             bytes: 50000,
             tokens: 2500,
             avg_lines: 120,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         module_roots: vec!["crates".into()],
         module_depth: 2,
@@ -106,6 +130,9 @@ fn sample_file_rows() -> Vec<FileRow> {
             lines: 130,
             bytes: 1000,
             tokens: 250,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         },
         FileRow {
             path: "tests/test.rs".into(),
@@ -118,6 +145,9 @@ fn sample_file_rows() -> Vec<FileRow> {
             lines: 60,
             bytes: 500,
             tokens: 125,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         },
     ]
 }
@@ -142,6 +172,9 @@ fn default_lang_args(format: TableFormat) -> LangArgs {
         top: 0,
         files: false,
         children: ChildrenMode::Collapse,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     }
 }
 
@@ -153,6 +186,10 @@ fn default_module_args(format: TableFormat) -> ModuleArgs {
         module_roots: vec!["crates".into()],
         module_depth: 2,
         children: ChildIncludeMode::Separate,
+        collapse_single_child: false,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     }
 }
 
@@ -169,6 +206,10 @@ fn default_export_args(format: ExportFormat) -> ExportArgs {
         redact: RedactMode::None,
         meta: true,
         strip_prefix: None,
+        filters: Default::default(),
+        sort_by: None,
+        sort_dir: None,
+        summary: false,
     }
 }
 
@@ -489,6 +530,9 @@ fn empty_lang_report_renders_without_panic() {
             bytes: 0,
             tokens: 0,
             avg_lines: 0,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: false,
         children: ChildrenMode::Collapse,
@@ -513,6 +557,10 @@ fn single_row_lang_report() {
             bytes: 200,
             tokens: 50,
             avg_lines: 50,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         }],
         total: Totals {
             code: 42,
@@ -521,6 +569,9 @@ fn single_row_lang_report() {
             bytes: 200,
             tokens: 50,
             avg_lines: 50,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: false,
         children: ChildrenMode::Collapse,
@@ -561,6 +612,9 @@ fn empty_module_report_renders() {
             bytes: 0,
             tokens: 0,
             avg_lines: 0,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         module_roots: vec![],
         module_depth: 1,
@@ -590,6 +644,10 @@ fn unicode_lang_name_in_md() {
             bytes: 5000,
             tokens: 250,
             avg_lines: 40,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         }],
         total: Totals {
             code: 100,
@@ -598,6 +656,9 @@ fn unicode_lang_name_in_md() {
             bytes: 5000,
             tokens: 250,
             avg_lines: 40,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: false,
         children: ChildrenMode::Collapse,
@@ -622,6 +683,11 @@ fn special_chars_in_module_name() {
             bytes: 2000,
             tokens: 100,
             avg_lines: 60,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
+            children: Vec::new(),
         }],
         total: Totals {
             code: 50,
@@ -630,6 +696,9 @@ fn special_chars_in_module_name() {
             bytes: 2000,
             tokens: 100,
             avg_lines: 60,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         module_roots: vec!["crates".into()],
         module_depth: 2,
@@ -10,9 +10,9 @@ use std::path::PathBuf;
 use tokmd_format::{write_export_csv_to, write_lang_report_to, write_module_report_to};
 use tokmd_settings::ScanOptions;
 use tokmd_types::{
-    ChildIncludeMode, ChildrenMode, ExportArgs, ExportData, ExportFormat, FileKind, FileRow,
-    LangArgs, LangReport, LangRow, ModuleArgs, ModuleReport, ModuleRow, RedactMode, TableFormat,
-    Totals,
+    AvgMeasure, ChildIncludeMode, ChildrenMode, ExportArgs, ExportData, ExportFormat, FileKind,
+    FileRow, LangArgs, LangReport, LangRow, ModuleArgs, ModuleReport, ModuleRow, RedactMode,
+    TableFormat, Totals,
 };
 
 /// Build a synthetic LangReport with deterministic data.
@@ -27,6 +27,10 @@ fn sample_lang_report() -> LangReport {
                 bytes: 20000,
                 tokens: 5000,
                 avg_lines: 70,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
             LangRow {
                 lang: "Python".to_string(),
@@ -36,6 +40,10 @@ fn sample_lang_report() -> LangReport {
                 bytes: 12000,
                 tokens: 3000,
                 avg_lines: 90,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
             LangRow {
                 lang: "JavaScript".to_string(),
@@ -45,6 +53,10 @@ fn sample_lang_report() -> LangReport {
                 bytes: 15000,
                 tokens: 3750,
                 avg_lines: 50,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
         ],
         total: Totals {
@@ -54,6 +66,9 @@ fn sample_lang_report() -> LangReport {
             bytes: 47000,
             tokens: 11750,
             avg_lines: 67,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: true,
         children: ChildrenMode::Collapse,
@@ -73,6 +88,11 @@ fn sample_module_report() -> ModuleReport {
                 bytes: 16000,
                 tokens: 4000,
                 avg_lines: 100,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
+                children: Vec::new(),
             },
             ModuleRow {
                 module: "src/utils".to_string(),
@@ -82,6 +102,11 @@ fn sample_module_report() -> ModuleReport {
                 bytes: 8000,
                 tokens: 2000,
                 avg_lines: 75,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
+                children: Vec::new(),
             },
         ],
         total: Totals {
@@ -91,6 +116,9 @@ fn sample_module_report() -> ModuleReport {
             bytes: 24000,
             tokens: 6000,
             avg_lines: 90,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         module_roots: vec![],
         module_depth: 2,
@@ -114,6 +142,9 @@ fn sample_export_data() -> ExportData {
                 lines: 250,
                 bytes: 8000,
                 tokens: 2000,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             },
             FileRow {
                 path: "src/core/lib.rs".to_string(),
@@ -126,6 +157,9 @@ fn sample_export_data() -> ExportData {
                 lines: 250,
                 bytes: 8000,
                 tokens: 2000,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             },
             FileRow {
                 path: "src/utils/helper.py".to_string(),
@@ -138,6 +172,9 @@ fn sample_export_data() -> ExportData {
                 lines: 125,
                 bytes: 4000,
                 tokens: 1000,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             },
         ],
         module_roots: vec![],
@@ -157,6 +194,9 @@ fn lang_args(format: TableFormat) -> LangArgs {
         top: 0,
         files: true,
         children: ChildrenMode::Collapse,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     }
 }
 
@@ -168,6 +208,10 @@ fn module_args(format: TableFormat) -> ModuleArgs {
         module_roots: vec![],
         module_depth: 2,
         children: ChildIncludeMode::ParentsOnly,
+        collapse_single_child: false,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     }
 }
 
@@ -184,6 +228,10 @@ fn export_args_csv() -> ExportArgs {
         redact: RedactMode::None,
         meta: false,
         strip_prefix: None,
+        filters: Default::default(),
+        sort_by: None,
+        sort_dir: None,
+        summary: false,
     }
 }
 
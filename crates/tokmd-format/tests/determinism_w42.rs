@@ -13,9 +13,9 @@ use tokmd_format::{
 };
 use tokmd_settings::ScanOptions;
 use tokmd_types::{
-    ChildIncludeMode, ChildrenMode, DiffRow, ExportArgs, ExportData, ExportFormat, FileKind,
-    FileRow, LangArgs, LangReport, LangRow, ModuleArgs, ModuleReport, ModuleRow, RedactMode,
-    TableFormat, Totals,
+    AvgMeasure, ChildIncludeMode, ChildrenMode, DiffRow, ExportArgs, ExportData, ExportFormat,
+    FileKind, FileRow, LangArgs, LangReport, LangRow, ModuleArgs, ModuleReport, ModuleRow,
+    RedactMode, TableFormat, Totals,
 };
 
 // =========================================================================
@@ -30,6 +30,9 @@ fn lang_report_with_rows(rows: Vec<LangRow>, with_files: bool) -> LangReport {
         bytes: rows.iter().map(|r| r.bytes).sum(),
         tokens: rows.iter().map(|r| r.tokens).sum(),
         avg_lines: 0,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
     };
     LangReport {
         rows,
@@ -48,6 +51,9 @@ fn module_report_with_rows(rows: Vec<ModuleRow>) -> ModuleReport {
         bytes: rows.iter().map(|r| r.bytes).sum(),
         tokens: rows.iter().map(|r| r.tokens).sum(),
         avg_lines: 0,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
     };
     ModuleReport {
         rows,
@@ -70,6 +76,9 @@ fn lang_args(format: TableFormat) -> LangArgs {
         top: 0,
         files: true,
         children: ChildrenMode::Collapse,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     }
 }
 
@@ -81,6 +90,10 @@ fn module_args(format: TableFormat) -> ModuleArgs {
         module_roots: vec![],
         module_depth: 2,
         children: ChildIncludeMode::ParentsOnly,
+        collapse_single_child: false,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     }
 }
 
@@ -97,6 +110,10 @@ fn export_args_csv() -> ExportArgs {
         redact: RedactMode::None,
         meta: false,
         strip_prefix: None,
+        filters: Default::default(),
+        sort_by: None,
+        sort_dir: None,
+        summary: false,
     }
 }
 
@@ -133,6 +150,10 @@ fn two_lang_rows() -> Vec<LangRow> {
             bytes: 20000,
             tokens: 5000,
             avg_lines: 70,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         },
         LangRow {
             lang: "Python".into(),
@@ -142,6 +163,10 @@ fn two_lang_rows() -> Vec<LangRow> {
             bytes: 12000,
             tokens: 3000,
             avg_lines: 90,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         },
     ]
 }
@@ -178,6 +203,10 @@ fn lang_md_single_row_deterministic() {
         bytes: 1000,
         tokens: 250,
         avg_lines: 55,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
+        file_rows: Vec::new(),
     }];
     let report = lang_report_with_rows(rows, true);
     let a = render_lang(&report, TableFormat::Md);
@@ -201,6 +230,11 @@ fn module_tsv_repeated_calls_identical() {
             bytes: 16000,
             tokens: 4000,
             avg_lines: 100,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
+            children: Vec::new(),
         },
         ModuleRow {
             module: "src/utils".into(),
@@ -210,6 +244,11 @@ fn module_tsv_repeated_calls_identical() {
             bytes: 8000,
             tokens: 2000,
             avg_lines: 75,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
+            children: Vec::new(),
         },
     ];
     let report = module_report_with_rows(rows);
@@ -230,6 +269,11 @@ fn module_tsv_single_row_deterministic() {
         bytes: 500,
         tokens: 120,
         avg_lines: 15,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
+        file_rows: Vec::new(),
+        children: Vec::new(),
     }];
     let report = module_report_with_rows(rows);
     let a = render_module(&report, TableFormat::Tsv);
@@ -249,6 +293,11 @@ fn module_md_repeated_calls_identical() {
             bytes: 4000,
             tokens: 1000,
             avg_lines: 50,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
+            children: Vec::new(),
         },
         ModuleRow {
             module: "src/b".into(),
@@ -258,6 +307,11 @@ fn module_md_repeated_calls_identical() {
             bytes: 4000,
             tokens: 1000,
             avg_lines: 50,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
+            children: Vec::new(),
         },
     ];
     let report = module_report_with_rows(rows);
@@ -282,6 +336,10 @@ fn lang_md_tie_break_preserves_input_order() {
             bytes: 4000,
             tokens: 1000,
             avg_lines: 100,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         },
         LangRow {
             lang: "Beta".into(),
@@ -291,6 +349,10 @@ fn lang_md_tie_break_preserves_input_order() {
             bytes: 4000,
             tokens: 1000,
             avg_lines: 100,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         },
     ];
     let report = lang_report_with_rows(rows, true);
@@ -311,6 +373,10 @@ fn lang_tsv_tie_break_preserves_input_order() {
             bytes: 1500,
             tokens: 400,
             avg_lines: 80,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         },
         LangRow {
             lang: "Apple".into(),
@@ -320,6 +386,10 @@ fn lang_tsv_tie_break_preserves_input_order() {
             bytes: 1500,
             tokens: 400,
             avg_lines: 80,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         },
     ];
     let report = lang_report_with_rows(rows, true);
@@ -340,6 +410,11 @@ fn module_md_tie_break_preserves_input_order() {
             bytes: 8000,
             tokens: 2000,
             avg_lines: 100,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
+            children: Vec::new(),
         },
         ModuleRow {
             module: "a_mod".into(),
@@ -349,6 +424,11 @@ fn module_md_tie_break_preserves_input_order() {
             bytes: 8000,
             tokens: 2000,
             avg_lines: 100,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
+            children: Vec::new(),
         },
     ];
     let report = module_report_with_rows(rows);
@@ -389,6 +469,11 @@ fn module_json_top_level_keys_sorted() {
         bytes: 4000,
         tokens: 1000,
         avg_lines: 50,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
+        file_rows: Vec::new(),
+        children: Vec::new(),
     }];
     let report = module_report_with_rows(rows);
     let global = default_global();
@@ -448,6 +533,9 @@ fn csv_paths_use_forward_slashes() {
             lines: 115,
             bytes: 4000,
             tokens: 1000,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         }],
         module_roots: vec![],
         module_depth: 2,
@@ -471,6 +559,11 @@ fn module_md_paths_use_forward_slashes() {
         bytes: 2000,
         tokens: 500,
         avg_lines: 35,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
+        file_rows: Vec::new(),
+        children: Vec::new(),
     }];
     let report = module_report_with_rows(rows);
     let output = render_module(&report, TableFormat::Md);
@@ -496,6 +589,9 @@ fn csv_repeated_calls_identical() {
                 lines: 13,
                 bytes: 500,
                 tokens: 120,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             },
             FileRow {
                 path: "b.py".into(),
@@ -508,6 +604,9 @@ fn csv_repeated_calls_identical() {
                 lines: 26,
                 bytes: 1000,
                 tokens: 240,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             },
         ],
         module_roots: vec![],
@@ -563,6 +662,10 @@ fn compute_diff_rows_deterministic_ordering() {
                 bytes: 4000,
                 tokens: 1000,
                 avg_lines: 50,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
             LangRow {
                 lang: "Rust".into(),
@@ -572,6 +675,10 @@ fn compute_diff_rows_deterministic_ordering() {
                 bytes: 8000,
                 tokens: 2000,
                 avg_lines: 60,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
         ],
         true,
@@ -586,6 +693,10 @@ fn compute_diff_rows_deterministic_ordering() {
                 bytes: 12000,
                 tokens: 3000,
                 avg_lines: 80,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
             LangRow {
                 lang: "C".into(),
@@ -595,6 +706,10 @@ fn compute_diff_rows_deterministic_ordering() {
                 bytes: 6000,
                 tokens: 1500,
                 avg_lines: 67,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
         ],
         true,
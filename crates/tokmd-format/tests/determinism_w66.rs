@@ -18,6 +18,10 @@ fn sample_lang_row(lang: &str, code: usize) -> LangRow {
         bytes: code * 4,
         tokens: code,
         avg_lines: if code > 0 { (code + 50) / 3 } else { 0 },
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
+        file_rows: Vec::new(),
     }
 }
 
@@ -35,6 +39,9 @@ fn sample_lang_report() -> LangReport {
             bytes: 3600,
             tokens: 900,
             avg_lines: 116,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: true,
         children: ChildrenMode::Collapse,
@@ -52,6 +59,9 @@ fn sample_lang_report_no_files() -> LangReport {
             bytes: 3200,
             tokens: 800,
             avg_lines: 150,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: false,
         children: ChildrenMode::Collapse,
@@ -70,6 +80,11 @@ fn sample_module_report() -> ModuleReport {
                 bytes: 3200,
                 tokens: 800,
                 avg_lines: 110,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
+                children: Vec::new(),
             },
             ModuleRow {
                 module: "src".to_string(),
@@ -79,6 +94,11 @@ fn sample_module_report() -> ModuleReport {
                 bytes: 800,
                 tokens: 200,
                 avg_lines: 60,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
+                children: Vec::new(),
             },
         ],
         total: Totals {
@@ -88,6 +108,9 @@ fn sample_module_report() -> ModuleReport {
             bytes: 4000,
             tokens: 1000,
             avg_lines: 93,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         module_roots: vec!["crates".to_string()],
         module_depth: 2,
@@ -110,6 +133,9 @@ fn sample_export_data() -> ExportData {
                 lines: 170,
                 bytes: 4800,
                 tokens: 1200,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             },
             FileRow {
                 path: "src/lib.rs".to_string(),
@@ -122,6 +148,9 @@ fn sample_export_data() -> ExportData {
                 lines: 100,
                 bytes: 3200,
                 tokens: 800,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             },
         ],
         module_roots: vec!["crates".to_string()],
@@ -141,6 +170,9 @@ fn lang_args_md() -> LangArgs {
         top: 0,
         files: true,
         children: ChildrenMode::Collapse,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     }
 }
 
@@ -151,6 +183,9 @@ fn lang_args_tsv() -> LangArgs {
         top: 0,
         files: true,
         children: ChildrenMode::Collapse,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     }
 }
 
@@ -162,6 +197,10 @@ fn module_args_md() -> ModuleArgs {
         module_roots: vec!["crates".to_string()],
         module_depth: 2,
         children: ChildIncludeMode::Separate,
+        collapse_single_child: false,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     }
 }
 
@@ -173,6 +212,10 @@ fn module_args_tsv() -> ModuleArgs {
         module_roots: vec!["crates".to_string()],
         module_depth: 2,
         children: ChildIncludeMode::Separate,
+        collapse_single_child: false,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     }
 }
 
@@ -189,6 +232,10 @@ fn export_args_csv() -> ExportArgs {
         redact: RedactMode::None,
         meta: false,
         strip_prefix: None,
+        filters: Default::default(),
+        sort_by: None,
+        sort_dir: None,
+        summary: false,
     }
 }
 
@@ -331,6 +378,9 @@ fn diff_rows_are_deterministic() {
             bytes: 4000,
             tokens: 1000,
             avg_lines: 127,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: true,
         children: ChildrenMode::Collapse,
@@ -433,6 +483,9 @@ fn empty_lang_report_md_is_byte_stable() {
             bytes: 0,
             tokens: 0,
             avg_lines: 0,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: true,
         children: ChildrenMode::Collapse,
@@ -462,6 +515,9 @@ fn single_row_lang_report_is_stable() {
             bytes: 4000,
             tokens: 1000,
             avg_lines: 350,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: true,
         children: ChildrenMode::Collapse,
@@ -546,6 +602,9 @@ fn markdown_rows_maintain_input_order() {
             bytes: 3600,
             tokens: 900,
             avg_lines: 116,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: true,
         children: ChildrenMode::Collapse,
@@ -617,6 +676,9 @@ proptest! {
                 bytes: (code_a + code_b) * 4,
                 tokens: code_a + code_b,
                 avg_lines: if (code_a + code_b + 100) > 0 { (code_a + code_b + 100) / 6 } else { 0 },
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
             },
             with_files: true,
             children: ChildrenMode::Collapse,
@@ -641,6 +703,9 @@ proptest! {
             total: Totals {
                 code: from_code, lines: from_code + 50, files: 3,
                 bytes: from_code * 4, tokens: from_code, avg_lines: 0,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
             },
             with_files: true,
             children: ChildrenMode::Collapse,
@@ -651,6 +716,9 @@ proptest! {
             total: Totals {
                 code: to_code, lines: to_code + 50, files: 3,
                 bytes: to_code * 4, tokens: to_code, avg_lines: 0,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
             },
             with_files: true,
             children: ChildrenMode::Collapse,
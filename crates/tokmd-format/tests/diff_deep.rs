@@ -21,6 +21,10 @@ fn make_lang_row(lang: &str, code: usize, lines: usize, files: usize) -> LangRow
         bytes: code * 40,
         tokens: code * 3,
         avg_lines: lines.checked_div(files).unwrap_or(0),
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
+        file_rows: Vec::new(),
     }
 }
 
@@ -32,6 +36,9 @@ fn make_report(rows: Vec<LangRow>) -> LangReport {
         bytes: rows.iter().map(|r| r.bytes).sum(),
         tokens: rows.iter().map(|r| r.tokens).sum(),
         avg_lines: 0,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
     };
     LangReport {
         rows,
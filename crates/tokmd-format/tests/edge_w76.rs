@@ -12,7 +12,7 @@ use tokmd_format::{
     write_export_cyclonedx_with_options, write_export_json_to, write_export_jsonl_to,
     write_lang_report_to, write_module_report_to,
 };
-use tokmd_settings::{ChildIncludeMode, ChildrenMode, ScanOptions};
+use tokmd_settings::{AvgMeasure, ChildIncludeMode, ChildrenMode, ScanOptions};
 use tokmd_types::{
     ConfigMode, DiffTotals, ExportArgs, ExportData, ExportFormat, FileKind, FileRow, LangArgs,
     LangReport, LangRow, ModuleArgs, ModuleReport, ModuleRow, RedactMode, TableFormat, Totals,
@@ -32,6 +32,14 @@ fn default_scan_options() -> ScanOptions {
         no_ignore_dot: false,
         no_ignore_vcs: false,
         treat_doc_strings_as_comments: false,
+        token_model: None,
+        hash_algo: tokmd_types::HashAlgo::Blake3,
+        symlink_policy: Default::default(),
+        line_count_mode: Default::default(),
+        code_comment_markers: Vec::new(),
+        ignore_file: None,
+        include: Vec::new(),
+        scan_cache: None,
     }
 }
 
@@ -42,6 +50,9 @@ fn default_lang_args() -> LangArgs {
         top: 0,
         files: false,
         children: ChildrenMode::Collapse,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     }
 }
 
@@ -53,6 +64,10 @@ fn default_module_args() -> ModuleArgs {
         module_roots: vec![],
         module_depth: 1,
         children: ChildIncludeMode::Separate,
+        collapse_single_child: false,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     }
 }
 
@@ -69,6 +84,10 @@ fn default_export_args() -> ExportArgs {
         redact: RedactMode::None,
         meta: false,
         strip_prefix: None,
+        filters: Default::default(),
+        sort_by: None,
+        sort_dir: None,
+        summary: false,
     }
 }
 
@@ -80,6 +99,9 @@ fn zero_totals() -> Totals {
         bytes: 0,
         tokens: 0,
         avg_lines: 0,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
     }
 }
 
@@ -92,6 +114,10 @@ fn make_lang_row(lang: &str, code: usize, lines: usize, files: usize) -> LangRow
         bytes: code * 30,
         tokens: code * 4,
         avg_lines: lines.checked_div(files).unwrap_or(0),
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
+        file_rows: Vec::new(),
     }
 }
 
@@ -104,6 +130,11 @@ fn make_module_row(module: &str, code: usize, lines: usize, files: usize) -> Mod
         bytes: code * 30,
         tokens: code * 4,
         avg_lines: lines.checked_div(files).unwrap_or(0),
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
+        file_rows: Vec::new(),
+        children: Vec::new(),
     }
 }
 
@@ -119,6 +150,9 @@ fn make_file_row(path: &str, lang: &str, code: usize) -> FileRow {
         lines: code,
         bytes: code * 30,
         tokens: code * 4,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
@@ -225,6 +259,9 @@ fn lang_report_single_row_md() {
             bytes: 30000,
             tokens: 4000,
             avg_lines: 150,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: false,
         children: ChildrenMode::Collapse,
@@ -257,6 +294,9 @@ fn module_report_single_row_md() {
             bytes: 15000,
             tokens: 2000,
             avg_lines: 160,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         module_roots: vec![],
         module_depth: 1,
@@ -290,6 +330,9 @@ fn lang_report_unicode_language_name_md() {
             bytes: 3000,
             tokens: 400,
             avg_lines: 66,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: false,
         children: ChildrenMode::Collapse,
@@ -321,6 +364,9 @@ fn lang_report_unicode_language_name_tsv() {
             bytes: 1500,
             tokens: 200,
             avg_lines: 40,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: false,
         children: ChildrenMode::Collapse,
@@ -349,6 +395,9 @@ fn module_report_unicode_module_name() {
             bytes: 6000,
             tokens: 800,
             avg_lines: 75,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         module_roots: vec![],
         module_depth: 1,
@@ -445,6 +494,9 @@ fn lang_report_large_counts_md() {
             bytes: big * 30,
             tokens: big * 4,
             avg_lines: big * 2,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: false,
         children: ChildrenMode::Collapse,
@@ -474,6 +526,9 @@ fn lang_report_large_counts_json() {
             bytes: big * 30,
             tokens: big * 4,
             avg_lines: big * 2,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: false,
         children: ChildrenMode::Collapse,
@@ -504,6 +559,9 @@ fn lang_md_table_has_separator_row() {
             bytes: 3000,
             tokens: 400,
             avg_lines: 40,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: false,
         children: ChildrenMode::Collapse,
@@ -533,6 +591,9 @@ fn module_md_table_has_pipe_columns() {
             bytes: 3000,
             tokens: 400,
             avg_lines: 40,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         module_roots: vec![],
         module_depth: 1,
@@ -573,6 +634,9 @@ fn lang_tsv_tab_separated_columns() {
             bytes: 3000,
             tokens: 400,
             avg_lines: 40,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: false,
         children: ChildrenMode::Collapse,
@@ -602,6 +666,9 @@ fn lang_tsv_no_pipe_separators() {
             bytes: 3000,
             tokens: 400,
             avg_lines: 40,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: false,
         children: ChildrenMode::Collapse,
@@ -634,6 +701,9 @@ fn lang_json_rows_are_array() {
             bytes: 24000,
             tokens: 3200,
             avg_lines: 86,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: false,
         children: ChildrenMode::Collapse,
@@ -661,6 +731,9 @@ fn lang_json_row_has_expected_keys() {
             bytes: 3000,
             tokens: 400,
             avg_lines: 50,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: false,
         children: ChildrenMode::Collapse,
@@ -921,6 +994,9 @@ fn diff_identical_reports_filtered_out() {
             bytes: 15000,
             tokens: 2000,
             avg_lines: 80,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: false,
         children: ChildrenMode::Collapse,
@@ -952,6 +1028,9 @@ fn diff_new_language_appears() {
             bytes: 6000,
             tokens: 800,
             avg_lines: 60,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: false,
         children: ChildrenMode::Collapse,
@@ -976,6 +1055,9 @@ fn diff_language_removed() {
             bytes: 3000,
             tokens: 400,
             avg_lines: 66,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: false,
         children: ChildrenMode::Collapse,
@@ -1021,6 +1103,9 @@ fn render_diff_md_with_arrow_separator() {
             bytes: 3000,
             tokens: 400,
             avg_lines: 40,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: false,
         children: ChildrenMode::Collapse,
@@ -1035,6 +1120,9 @@ fn render_diff_md_with_arrow_separator() {
             bytes: 4500,
             tokens: 600,
             avg_lines: 41,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: false,
         children: ChildrenMode::Collapse,
@@ -1061,6 +1149,9 @@ fn render_diff_md_with_options_compact() {
                 bytes: 3000,
                 tokens: 400,
                 avg_lines: 40,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
             },
             with_files: false,
             children: ChildrenMode::Collapse,
@@ -1075,6 +1166,9 @@ fn render_diff_md_with_options_compact() {
                 bytes: 6000,
                 tokens: 800,
                 avg_lines: 50,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
             },
             with_files: false,
             children: ChildrenMode::Collapse,
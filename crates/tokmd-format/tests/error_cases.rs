@@ -5,7 +5,7 @@ use tokmd_format::{
     compute_diff_rows, compute_diff_totals, create_diff_receipt, render_diff_md,
     write_lang_report_to, write_module_report_to,
 };
-use tokmd_settings::{ChildIncludeMode, ChildrenMode, ScanOptions};
+use tokmd_settings::{AvgMeasure, ChildIncludeMode, ChildrenMode, ScanOptions};
 use tokmd_types::{
     ConfigMode, DiffRow, LangArgs, LangReport, LangRow, ModuleArgs, ModuleReport, ModuleRow,
     TableFormat, Totals,
@@ -21,6 +21,14 @@ fn default_scan_options() -> ScanOptions {
         no_ignore_dot: false,
         no_ignore_vcs: false,
         treat_doc_strings_as_comments: false,
+        token_model: None,
+        hash_algo: tokmd_types::HashAlgo::Blake3,
+        symlink_policy: Default::default(),
+        line_count_mode: Default::default(),
+        code_comment_markers: Vec::new(),
+        ignore_file: None,
+        include: Vec::new(),
+        scan_cache: None,
     }
 }
 
@@ -31,6 +39,9 @@ fn default_lang_args() -> LangArgs {
         top: 0,
         files: false,
         children: ChildrenMode::Collapse,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     }
 }
 
@@ -42,6 +53,10 @@ fn default_module_args() -> ModuleArgs {
         module_roots: vec![],
         module_depth: 1,
         children: ChildIncludeMode::Separate,
+        collapse_single_child: false,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     }
 }
 
@@ -53,6 +68,9 @@ fn zero_totals() -> Totals {
         bytes: 0,
         tokens: 0,
         avg_lines: 0,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
     }
 }
 
@@ -186,6 +204,10 @@ fn format_lang_report_with_very_long_name() {
             bytes: 5000,
             tokens: 400,
             avg_lines: 40,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         }],
         total: Totals {
             code: 100,
@@ -194,6 +216,9 @@ fn format_lang_report_with_very_long_name() {
             bytes: 5000,
             tokens: 400,
             avg_lines: 40,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: false,
         children: ChildrenMode::Collapse,
@@ -223,6 +248,11 @@ fn format_module_report_with_very_long_module_name() {
             bytes: 2000,
             tokens: 200,
             avg_lines: 50,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
+            children: Vec::new(),
         }],
         total: Totals {
             code: 50,
@@ -231,6 +261,9 @@ fn format_module_report_with_very_long_module_name() {
             bytes: 2000,
             tokens: 200,
             avg_lines: 50,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         module_roots: vec![],
         module_depth: 1,
@@ -260,6 +293,10 @@ fn format_lang_report_with_unicode_name() {
             bytes: 500,
             tokens: 40,
             avg_lines: 20,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         }],
         total: Totals {
             code: 10,
@@ -268,6 +305,9 @@ fn format_lang_report_with_unicode_name() {
             bytes: 500,
             tokens: 40,
             avg_lines: 20,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: false,
         children: ChildrenMode::Collapse,
@@ -296,6 +336,10 @@ fn format_lang_report_with_emoji_name() {
             bytes: 2000,
             tokens: 168,
             avg_lines: 28,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         }],
         total: Totals {
             code: 42,
@@ -304,6 +348,9 @@ fn format_lang_report_with_emoji_name() {
             bytes: 2000,
             tokens: 168,
             avg_lines: 28,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: false,
         children: ChildrenMode::Collapse,
@@ -332,6 +379,10 @@ fn diff_with_unicode_language_names() {
             bytes: 5000,
             tokens: 400,
             avg_lines: 40,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         }],
         total: Totals {
             code: 100,
@@ -340,6 +391,9 @@ fn diff_with_unicode_language_names() {
             bytes: 5000,
             tokens: 400,
             avg_lines: 40,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: false,
         children: ChildrenMode::Collapse,
@@ -354,6 +408,10 @@ fn diff_with_unicode_language_names() {
             bytes: 7500,
             tokens: 600,
             avg_lines: 43,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         }],
         total: Totals {
             code: 150,
@@ -362,6 +420,9 @@ fn diff_with_unicode_language_names() {
             bytes: 7500,
             tokens: 600,
             avg_lines: 43,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: false,
         children: ChildrenMode::Collapse,
@@ -387,6 +448,10 @@ fn format_lang_report_with_large_values() {
             bytes: big,
             tokens: big,
             avg_lines: big,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         }],
         total: Totals {
             code: big,
@@ -395,6 +460,9 @@ fn format_lang_report_with_large_values() {
             bytes: big,
             tokens: big,
             avg_lines: big,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: false,
         children: ChildrenMode::Collapse,
@@ -422,6 +490,10 @@ fn format_lang_report_json_with_large_values() {
             bytes: big,
             tokens: big,
             avg_lines: big,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         }],
         total: Totals {
             code: big,
@@ -430,6 +502,9 @@ fn format_lang_report_json_with_large_values() {
             bytes: big,
             tokens: big,
             avg_lines: big,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: false,
         children: ChildrenMode::Collapse,
@@ -467,6 +542,10 @@ fn diff_new_language_added() {
             bytes: 5000,
             tokens: 400,
             avg_lines: 40,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         }],
         total: Totals {
             code: 100,
@@ -475,6 +554,9 @@ fn diff_new_language_added() {
             bytes: 5000,
             tokens: 400,
             avg_lines: 40,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: false,
         children: ChildrenMode::Collapse,
@@ -498,6 +580,10 @@ fn diff_language_removed() {
             bytes: 10000,
             tokens: 800,
             avg_lines: 40,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         }],
         total: Totals {
             code: 200,
@@ -506,6 +592,9 @@ fn diff_language_removed() {
             bytes: 10000,
             tokens: 800,
             avg_lines: 40,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: false,
         children: ChildrenMode::Collapse,
@@ -536,6 +625,10 @@ fn diff_identical_reports_yields_no_rows() {
             bytes: 2500,
             tokens: 200,
             avg_lines: 33,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         }],
         total: Totals {
             code: 50,
@@ -544,6 +637,9 @@ fn diff_identical_reports_yields_no_rows() {
             bytes: 2500,
             tokens: 200,
             avg_lines: 33,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: false,
         children: ChildrenMode::Collapse,
@@ -577,6 +673,10 @@ fn format_single_language_report() {
             bytes: 10,
             tokens: 4,
             avg_lines: 1,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         }],
         total: Totals {
             code: 1,
@@ -585,6 +685,9 @@ fn format_single_language_report() {
             bytes: 10,
             tokens: 4,
             avg_lines: 1,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: false,
         children: ChildrenMode::Collapse,
@@ -614,6 +717,10 @@ fn format_multiple_languages_report() {
                 bytes: 25000,
                 tokens: 2000,
                 avg_lines: 100,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
             LangRow {
                 lang: "Python".to_string(),
@@ -623,6 +730,10 @@ fn format_multiple_languages_report() {
                 bytes: 15000,
                 tokens: 1200,
                 avg_lines: 120,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
             LangRow {
                 lang: "TOML".to_string(),
@@ -632,6 +743,10 @@ fn format_multiple_languages_report() {
                 bytes: 2500,
                 tokens: 200,
                 avg_lines: 33,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
         ],
         total: Totals {
@@ -641,6 +756,9 @@ fn format_multiple_languages_report() {
             bytes: 42500,
             tokens: 3400,
             avg_lines: 94,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: false,
         children: ChildrenMode::Collapse,
@@ -674,6 +792,10 @@ fn format_lang_report_with_special_chars() {
                 bytes: 5000,
                 tokens: 400,
                 avg_lines: 40,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
             LangRow {
                 lang: "Objective-C++".to_string(),
@@ -683,6 +805,10 @@ fn format_lang_report_with_special_chars() {
                 bytes: 2500,
                 tokens: 200,
                 avg_lines: 50,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
         ],
         total: Totals {
@@ -692,6 +818,9 @@ fn format_lang_report_with_special_chars() {
             bytes: 7500,
             tokens: 600,
             avg_lines: 43,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: false,
         children: ChildrenMode::Collapse,
@@ -29,6 +29,9 @@ fn file_row(path: &str, module: &str, lang: &str, kind: FileKind, code: usize) -
         lines: code + code / 5 + code / 10,
         bytes: code * 10,
         tokens: code * 3,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
@@ -62,6 +65,10 @@ fn default_args(format: ExportFormat) -> ExportArgs {
         redact: RedactMode::None,
         meta: true,
         strip_prefix: None,
+        filters: Default::default(),
+        sort_by: None,
+        sort_dir: None,
+        summary: false,
     }
 }
 
@@ -259,6 +266,28 @@ fn csv_includes_child_file_kind() {
     assert!(output.contains(",child,"));
 }
 
+#[test]
+fn csv_adds_age_columns_only_when_rows_carry_git_age() {
+    let mut aged = file_row("src/lib.rs", "src", "Rust", FileKind::Parent, 100);
+    aged.last_modified_ms = Some(1_700_000_000_000);
+    aged.age_days = Some(12);
+    let unseen = file_row("src/new.rs", "src", "Rust", FileKind::Parent, 5);
+    let data = export_data(vec![aged, unseen]);
+    let args = default_args(ExportFormat::Csv);
+    let mut buf = Vec::new();
+
+    write_export_csv_to(&mut buf, &data, &args).expect("operation must succeed");
+    let output = String::from_utf8(buf).expect("output must be valid UTF-8");
+    let lines: Vec<&str> = output.lines().collect();
+
+    assert_eq!(
+        lines[0],
+        "path,module,lang,kind,code,comments,blanks,lines,bytes,tokens,last_modified_ms,age_days"
+    );
+    assert!(lines[1].ends_with(",1700000000000,12"), "{}", lines[1]);
+    assert!(lines[2].ends_with(",,"), "{}", lines[2]);
+}
+
 // ===========================================================================
 // 3. CycloneDX: structural validity
 // ===========================================================================
@@ -355,6 +384,53 @@ fn cyclonedx_child_kind_property_only_on_children() {
     assert_eq!(kind_prop["value"], "child");
 }
 
+#[test]
+fn classified_rows_carry_role_in_jsonl_csv_and_cyclonedx() {
+    let mut rows = vec![
+        file_row("src/parser_test.rs", "src", "Rust", FileKind::Parent, 40),
+        file_row("src/api.pb.rs", "src", "Rust", FileKind::Parent, 30),
+        file_row("src/lib.rs", "src", "Rust", FileKind::Parent, 20),
+    ];
+    for row in &mut rows {
+        row.role = Some(tokmd_analysis_types::file_role(&row.path));
+    }
+    let data = export_data(rows);
+
+    let mut args = default_args(ExportFormat::Jsonl);
+    args.meta = false;
+    let mut buf = Vec::new();
+    write_export_jsonl_to(&mut buf, &data, &default_scan(), &args).expect("operation must succeed");
+    let roles: Vec<String> = String::from_utf8(buf)
+        .expect("output must be valid UTF-8")
+        .lines()
+        .map(|line| {
+            let v: serde_json::Value = serde_json::from_str(line).expect("must parse valid JSON");
+            v["role"].as_str().expect("role must be set").to_string()
+        })
+        .collect();
+    assert_eq!(roles, ["test", "generated", "source"]);
+
+    let mut buf = Vec::new();
+    write_export_csv_to(&mut buf, &data, &default_args(ExportFormat::Csv))
+        .expect("operation must succeed");
+    let output = String::from_utf8(buf).expect("output must be valid UTF-8");
+    let lines: Vec<&str> = output.lines().collect();
+    assert!(lines[0].ends_with(",tokens,role"), "{}", lines[0]);
+    assert!(lines[1].ends_with(",test"), "{}", lines[1]);
+    assert!(lines[2].ends_with(",generated"), "{}", lines[2]);
+
+    let mut buf = Vec::new();
+    write_export_cyclonedx_to(&mut buf, &data, RedactMode::None).expect("operation must succeed");
+    let v: serde_json::Value = serde_json::from_slice(&buf).expect("must parse valid JSON");
+    let role = v["components"][0]["properties"]
+        .as_array()
+        .expect("must be a JSON array")
+        .iter()
+        .find(|p| p["name"] == "tokmd:role")
+        .expect("classified rows carry tokmd:role");
+    assert_eq!(role["value"], "test");
+}
+
 #[test]
 fn cyclonedx_with_fixed_serial_and_timestamp_is_deterministic() {
     let data = export_data(sample_rows());
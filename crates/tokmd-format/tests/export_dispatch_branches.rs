@@ -13,7 +13,9 @@ use std::path::PathBuf;
 
 use tempfile::TempDir;
 
-use tokmd_format::{short_hash, write_export, write_lang_json_to_file, write_module_json_to_file};
+use tokmd_format::{
+    OutputPathVars, short_hash, write_export, write_lang_json_to_file, write_module_json_to_file,
+};
 use tokmd_settings::{ChildIncludeMode, ChildrenMode, ScanOptions};
 use tokmd_types::{
     ConfigMode, ExportArgs, ExportData, ExportFormat, FileKind, FileRow, LangArgsMeta, LangReport,
@@ -37,6 +39,9 @@ fn sample_export_data() -> ExportData {
             lines: 130,
             bytes: 5000,
             tokens: 250,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         }],
         module_roots: vec!["src".to_string()],
         module_depth: 2,
@@ -57,6 +62,10 @@ fn export_args(format: ExportFormat, output: Option<PathBuf>) -> ExportArgs {
         redact: RedactMode::None,
         meta: false,
         strip_prefix: None,
+        filters: Default::default(),
+        sort_by: None,
+        sort_dir: None,
+        summary: false,
     }
 }
 
@@ -72,6 +81,13 @@ fn scan_args_stub() -> ScanArgs {
         no_ignore_dot: false,
         no_ignore_vcs: false,
         treat_doc_strings_as_comments: false,
+        token_model: "heuristic".to_string(),
+        hash_algo: tokmd_types::HashAlgo::Blake3,
+        symlink_policy: Default::default(),
+        line_count_mode: Default::default(),
+        code_comment_markers: Vec::new(),
+        ignore_file: None,
+        include: Vec::new(),
     }
 }
 
@@ -86,6 +102,11 @@ fn sample_module_report() -> ModuleReport {
                 bytes: 4000,
                 tokens: 200,
                 avg_lines: 120,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
+                children: Vec::new(),
             },
             ModuleRow {
                 module: "crates/bar".to_string(),
@@ -95,6 +116,11 @@ fn sample_module_report() -> ModuleReport {
                 bytes: 3000,
                 tokens: 150,
                 avg_lines: 100,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
+                children: Vec::new(),
             },
         ],
         total: Totals {
@@ -104,6 +130,9 @@ fn sample_module_report() -> ModuleReport {
             bytes: 7000,
             tokens: 350,
             avg_lines: 110,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         module_roots: vec!["crates/foo".to_string(), "crates/bar".to_string()],
         module_depth: 2,
@@ -122,6 +151,10 @@ fn sample_lang_report() -> LangReport {
             bytes: 5000,
             tokens: 250,
             avg_lines: 130,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         }],
         total: Totals {
             code: 100,
@@ -130,6 +163,9 @@ fn sample_lang_report() -> LangReport {
             bytes: 5000,
             tokens: 250,
             avg_lines: 130,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: true,
         children: ChildrenMode::Collapse,
@@ -179,6 +215,73 @@ fn write_export_dispatches_jsonl_format_to_file() {
     assert!(out.contains("src/lib.rs"));
 }
 
+#[test]
+fn write_export_expands_date_template_and_creates_directory() {
+    let tmp = TempDir::new().unwrap();
+    let template = tmp.path().join("reports/{date}/out.{format}");
+    let args = export_args(ExportFormat::Csv, Some(template));
+
+    let today = OutputPathVars::now("csv", "r").date;
+    write_export(&sample_export_data(), &ScanOptions::default(), &args)
+        .expect("write_export creates the templated path");
+
+    let mut written = tmp.path().join("reports").join(&today).join("out.csv");
+    if !written.exists() {
+        // The UTC date rolled over mid-test.
+        let tomorrow = OutputPathVars::now("csv", "r").date;
+        written = tmp.path().join("reports").join(tomorrow).join("out.csv");
+    }
+    let out = fs::read_to_string(&written).expect("templated csv file readable");
+    assert!(out.contains("src/lib.rs"));
+    assert!(!tmp.path().join("reports/{date}").exists());
+}
+
+#[test]
+fn write_export_jsonl_meta_carries_summary() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp = TempDir::new()?;
+    let path = tmp.path().join("out.jsonl");
+    let mut args = export_args(ExportFormat::Jsonl, Some(path.clone()));
+    args.meta = true;
+    args.summary = true;
+
+    write_export(&sample_export_data(), &ScanOptions::default(), &args)?;
+
+    let out = fs::read_to_string(&path)?;
+    let mut lines = out.lines();
+    let meta: serde_json::Value = serde_json::from_str(lines.next().ok_or("no meta line")?)?;
+    assert_eq!(meta["type"], "meta");
+    assert_eq!(meta["summary"]["total"]["code"], 100);
+    assert_eq!(meta["summary"]["by_lang"][0]["lang"], "Rust");
+    assert_eq!(meta["summary"]["by_ext"][0]["ext"], "rs");
+    assert_eq!(meta["summary"]["by_ext"][0]["tokens"], 250);
+
+    let rows: Vec<_> = lines.collect();
+    assert_eq!(rows.len(), 1);
+    assert!(!rows.iter().any(|row| row.contains("summary")));
+    Ok(())
+}
+
+#[test]
+fn write_export_json_receipt_carries_summary_only_when_requested()
+-> Result<(), Box<dyn std::error::Error>> {
+    let tmp = TempDir::new()?;
+    let path = tmp.path().join("out.json");
+    let mut args = export_args(ExportFormat::Json, Some(path.clone()));
+    args.meta = true;
+
+    write_export(&sample_export_data(), &ScanOptions::default(), &args)?;
+    let plain: serde_json::Value = serde_json::from_str(&fs::read_to_string(&path)?)?;
+    assert!(plain.get("summary").is_none());
+
+    args.summary = true;
+    write_export(&sample_export_data(), &ScanOptions::default(), &args)?;
+    let receipt: serde_json::Value = serde_json::from_str(&fs::read_to_string(&path)?)?;
+    assert_eq!(receipt["summary"]["total"]["files"], 1);
+    assert_eq!(receipt["summary"]["total"]["tokens"], 250);
+    assert_eq!(receipt["summary"]["by_lang"][0]["lang"], "Rust");
+    Ok(())
+}
+
 #[test]
 fn write_export_dispatches_json_format_to_file() {
     let tmp = TempDir::new().unwrap();
@@ -243,6 +346,33 @@ fn write_lang_json_to_file_emits_valid_receipt_json() {
     assert!(v["rows"].is_array());
 }
 
+#[test]
+fn file_writers_create_missing_parent_directories() {
+    let tmp = TempDir::new().unwrap();
+    let lang_path = tmp.path().join("a/b/c/out.json");
+    let export_path = tmp.path().join("x/y/out.jsonl");
+    let args_meta = LangArgsMeta {
+        format: "json".to_string(),
+        top: 0,
+        with_files: false,
+        children: ChildrenMode::Collapse,
+    };
+
+    write_lang_json_to_file(
+        &lang_path,
+        &sample_lang_report(),
+        &scan_args_stub(),
+        &args_meta,
+    )
+    .expect("lang json write creates a/b/c");
+    let args = export_args(ExportFormat::Jsonl, Some(export_path.clone()));
+    write_export(&sample_export_data(), &ScanOptions::default(), &args)
+        .expect("export write creates x/y");
+
+    assert!(lang_path.is_file());
+    assert!(export_path.is_file());
+}
+
 // ---------------------------------------------------------------------------
 // write_module_json_to_file — RedactMode::All hashes module names
 // ---------------------------------------------------------------------------
@@ -258,6 +388,7 @@ fn write_module_json_to_file_with_redact_all_hashes_module_rows_and_roots() {
         module_depth: 2,
         children: ChildIncludeMode::Separate,
         top: 0,
+        collapse_single_child: false,
     };
 
     write_module_json_to_file(
@@ -324,6 +455,7 @@ fn write_module_json_to_file_without_redaction_preserves_module_names() {
         module_depth: 2,
         children: ChildIncludeMode::Separate,
         top: 0,
+        collapse_single_child: false,
     };
 
     write_module_json_to_file(
@@ -361,6 +493,7 @@ fn write_module_json_to_file_with_redact_paths_does_not_hash_module_names() {
         module_depth: 2,
         children: ChildIncludeMode::Separate,
         top: 0,
+        collapse_single_child: false,
     };
 
     write_module_json_to_file(
@@ -19,6 +19,9 @@ fn empty_lang_report() -> LangReport {
             bytes: 0,
             tokens: 0,
             avg_lines: 0,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: false,
         children: ChildrenMode::Collapse,
@@ -36,6 +39,10 @@ fn sample_lang_report() -> LangReport {
             bytes: 5000,
             tokens: 1200,
             avg_lines: 50,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         }],
         total: Totals {
             code: 100,
@@ -44,6 +51,9 @@ fn sample_lang_report() -> LangReport {
             bytes: 5000,
             tokens: 1200,
             avg_lines: 50,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: false,
         children: ChildrenMode::Collapse,
@@ -61,6 +71,9 @@ fn empty_module_report() -> ModuleReport {
             bytes: 0,
             tokens: 0,
             avg_lines: 0,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         module_roots: vec![],
         module_depth: 2,
@@ -85,6 +98,9 @@ fn md_lang_args() -> LangArgs {
         top: 0,
         files: false,
         children: ChildrenMode::Collapse,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     }
 }
 
@@ -95,6 +111,9 @@ fn tsv_lang_args() -> LangArgs {
         top: 0,
         files: false,
         children: ChildrenMode::Collapse,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     }
 }
 
@@ -105,6 +124,9 @@ fn json_lang_args() -> LangArgs {
         top: 0,
         files: false,
         children: ChildrenMode::Collapse,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     }
 }
 
@@ -116,6 +138,10 @@ fn md_module_args() -> ModuleArgs {
         module_roots: vec![],
         module_depth: 2,
         children: ChildIncludeMode::Separate,
+        collapse_single_child: false,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     }
 }
 
@@ -136,6 +162,10 @@ fn csv_export_args() -> ExportArgs {
         redact: RedactMode::None,
         meta: false,
         strip_prefix: None,
+        filters: Default::default(),
+        sort_by: None,
+        sort_dir: None,
+        summary: false,
     }
 }
 
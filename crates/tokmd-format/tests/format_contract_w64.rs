@@ -21,9 +21,9 @@ use tokmd_format::{
 };
 use tokmd_settings::ScanOptions;
 use tokmd_types::{
-    ChildIncludeMode, ChildrenMode, DiffRow, DiffTotals, ExportArgs, ExportData, ExportFormat,
-    FileKind, FileRow, LangArgs, LangReceipt, LangReport, LangRow, ModuleArgs, ModuleReport,
-    ModuleRow, RedactMode, TableFormat, Totals,
+    AvgMeasure, ChildIncludeMode, ChildrenMode, DiffRow, DiffTotals, ExportArgs, ExportData,
+    ExportFormat, FileKind, FileRow, LangArgs, LangReceipt, LangReport, LangRow, ModuleArgs,
+    ModuleReport, ModuleRow, RedactMode, TableFormat, Totals,
 };
 
 // ============================================================================
@@ -45,6 +45,10 @@ fn lang_row(lang: &str, code: usize) -> LangRow {
         avg_lines: code
             .checked_div(code.checked_div(100).unwrap_or(1).max(1))
             .unwrap_or(0),
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
+        file_rows: Vec::new(),
     }
 }
 
@@ -59,6 +63,11 @@ fn module_row(module: &str, code: usize) -> ModuleRow {
         avg_lines: code
             .checked_div(code.checked_div(80).unwrap_or(1).max(1))
             .unwrap_or(0),
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
+        file_rows: Vec::new(),
+        children: Vec::new(),
     }
 }
 
@@ -75,6 +84,9 @@ fn totals_from_lang_rows(rows: &[LangRow]) -> Totals {
         bytes,
         tokens,
         avg_lines: code.checked_div(files).unwrap_or(0),
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
     }
 }
 
@@ -91,6 +103,9 @@ fn totals_from_module_rows(rows: &[ModuleRow]) -> Totals {
         bytes,
         tokens,
         avg_lines: code.checked_div(files).unwrap_or(0),
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
     }
 }
 
@@ -124,6 +139,9 @@ fn lang_args(format: TableFormat) -> LangArgs {
         top: 0,
         files: false,
         children: ChildrenMode::Collapse,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     }
 }
 
@@ -135,6 +153,10 @@ fn module_args(format: TableFormat) -> ModuleArgs {
         module_roots: vec![],
         module_depth: 1,
         children: ChildIncludeMode::Separate,
+        collapse_single_child: false,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     }
 }
 
@@ -150,6 +172,9 @@ fn file_row(path: &str, lang: &str, code: usize) -> FileRow {
         lines: code + code / 5 + code / 10,
         bytes: code * 40,
         tokens: code * 3,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
@@ -175,6 +200,10 @@ fn export_args_csv() -> ExportArgs {
         redact: RedactMode::None,
         meta: false,
         strip_prefix: None,
+        filters: Default::default(),
+        sort_by: None,
+        sort_dir: None,
+        summary: false,
     }
 }
 
@@ -191,6 +220,10 @@ fn export_args_jsonl() -> ExportArgs {
         redact: RedactMode::None,
         meta: true,
         strip_prefix: None,
+        filters: Default::default(),
+        sort_by: None,
+        sort_dir: None,
+        summary: false,
     }
 }
 
@@ -207,6 +240,10 @@ fn export_args_json() -> ExportArgs {
         redact: RedactMode::None,
         meta: true,
         strip_prefix: None,
+        filters: Default::default(),
+        sort_by: None,
+        sort_dir: None,
+        summary: false,
     }
 }
 
@@ -371,7 +408,7 @@ fn lang_json_has_schema_version() {
         v["schema_version"]
             .as_u64()
             .expect("must be a JSON integer"),
-        2
+        3
     );
 }
 
@@ -487,7 +524,7 @@ fn module_json_has_schema_version() {
         v["schema_version"]
             .as_u64()
             .expect("must be a JSON integer"),
-        2
+        3
     );
 }
 
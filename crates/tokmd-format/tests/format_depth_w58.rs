@@ -13,9 +13,9 @@ use tokmd_format::{
 };
 use tokmd_settings::ScanOptions;
 use tokmd_types::{
-    ChildIncludeMode, ChildrenMode, ExportArgs, ExportData, ExportFormat, FileKind, FileRow,
-    LangArgs, LangReport, LangRow, ModuleArgs, ModuleReport, ModuleRow, RedactMode, TableFormat,
-    Totals,
+    AvgMeasure, ChildIncludeMode, ChildrenMode, ExportArgs, ExportData, ExportFormat, FileKind,
+    FileRow, LangArgs, LangReport, LangRow, ModuleArgs, ModuleReport, ModuleRow, RedactMode,
+    TableFormat, Totals,
 };
 
 // ---------------------------------------------------------------------------
@@ -31,6 +31,10 @@ fn lang_row(lang: &str, code: usize) -> LangRow {
         bytes: code * 10,
         tokens: code * 3,
         avg_lines: if code > 0 { code + code / 5 } else { 0 },
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
+        file_rows: Vec::new(),
     }
 }
 
@@ -42,6 +46,9 @@ fn totals_from_lang_rows(rows: &[LangRow]) -> Totals {
         bytes: rows.iter().map(|r| r.bytes).sum(),
         tokens: rows.iter().map(|r| r.tokens).sum(),
         avg_lines: 0,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
     }
 }
 
@@ -54,6 +61,11 @@ fn module_row(module: &str, code: usize) -> ModuleRow {
         bytes: code * 10,
         tokens: code * 3,
         avg_lines: if code > 0 { code + code / 5 } else { 0 },
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
+        file_rows: Vec::new(),
+        children: Vec::new(),
     }
 }
 
@@ -65,6 +77,9 @@ fn totals_from_module_rows(rows: &[ModuleRow]) -> Totals {
         bytes: rows.iter().map(|r| r.bytes).sum(),
         tokens: rows.iter().map(|r| r.tokens).sum(),
         avg_lines: 0,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
     }
 }
 
@@ -98,6 +113,9 @@ fn lang_args(format: TableFormat) -> LangArgs {
         top: 0,
         files: false,
         children: ChildrenMode::Collapse,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     }
 }
 
@@ -109,6 +127,10 @@ fn module_args(format: TableFormat) -> ModuleArgs {
         module_roots: vec![],
         module_depth: 1,
         children: ChildIncludeMode::Separate,
+        collapse_single_child: false,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     }
 }
 
@@ -128,6 +150,9 @@ fn file_row(path: &str, module: &str, lang: &str, kind: FileKind, code: usize) -
         lines: code + code / 5 + code / 10,
         bytes: code * 10,
         tokens: code * 3,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
@@ -153,6 +178,10 @@ fn export_args(format: ExportFormat) -> ExportArgs {
         redact: RedactMode::None,
         meta: false,
         strip_prefix: None,
+        filters: Default::default(),
+        sort_by: None,
+        sort_dir: None,
+        summary: false,
     }
 }
 
@@ -27,7 +27,7 @@ use tokmd_format::{
     write_export_cyclonedx_with_options, write_export_json_to, write_export_jsonl_to,
     write_lang_report_to, write_module_report_to,
 };
-use tokmd_settings::{ChildIncludeMode, ChildrenMode, ScanOptions};
+use tokmd_settings::{AvgMeasure, ChildIncludeMode, ChildrenMode, ScanOptions};
 use tokmd_types::{
     ExportArgs, ExportData, ExportFormat, FileKind, FileRow, LangArgs, LangReport, LangRow,
     ModuleArgs, ModuleReport, ModuleRow, RedactMode, TableFormat, Totals,
@@ -49,6 +49,9 @@ fn lang_report(rows: Vec<LangRow>, with_files: bool) -> LangReport {
         } else {
             rows.iter().map(|r| r.lines).sum::<usize>() / rows.len()
         },
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
     };
     LangReport {
         rows,
@@ -67,6 +70,9 @@ fn module_report(rows: Vec<ModuleRow>) -> ModuleReport {
         bytes: rows.iter().map(|r| r.bytes).sum(),
         tokens: rows.iter().map(|r| r.tokens).sum(),
         avg_lines: 0,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
     };
     ModuleReport {
         rows,
@@ -94,6 +100,9 @@ fn lang_args(format: TableFormat) -> LangArgs {
         top: 0,
         files: true,
         children: ChildrenMode::Collapse,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     }
 }
 
@@ -105,6 +114,10 @@ fn module_args(format: TableFormat) -> ModuleArgs {
         module_roots: vec!["src".into()],
         module_depth: 1,
         children: ChildIncludeMode::Separate,
+        collapse_single_child: false,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     }
 }
 
@@ -121,6 +134,10 @@ fn export_args(format: ExportFormat) -> ExportArgs {
         redact: RedactMode::None,
         meta: true,
         strip_prefix: None,
+        filters: Default::default(),
+        sort_by: None,
+        sort_dir: None,
+        summary: false,
     }
 }
 
@@ -144,6 +161,10 @@ fn make_lang_row(lang: &str, code: usize) -> LangRow {
         bytes: code * 40,
         tokens: code * 2,
         avg_lines: if code > 0 { 120 } else { 0 },
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
+        file_rows: Vec::new(),
     }
 }
 
@@ -159,6 +180,9 @@ fn make_file_row(path: &str, lang: &str, code: usize) -> FileRow {
         lines: code + code / 5 + code / 10,
         bytes: code * 40,
         tokens: code * 2,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
@@ -171,6 +195,11 @@ fn make_module_row(module: &str, code: usize) -> ModuleRow {
         bytes: code * 40,
         tokens: code * 2,
         avg_lines: if code > 0 { 120 } else { 0 },
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
+        file_rows: Vec::new(),
+        children: Vec::new(),
     }
 }
 
@@ -498,6 +527,9 @@ fn csv_commas_in_path_are_quoted() {
         lines: 13,
         bytes: 400,
         tokens: 20,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     };
     let data = export_data(vec![row]);
     let out =
@@ -524,6 +556,9 @@ fn csv_quotes_in_path_are_escaped() {
         lines: 6,
         bytes: 200,
         tokens: 10,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     };
     let data = export_data(vec![row]);
     let out =
@@ -550,6 +585,9 @@ fn csv_child_kind_column() {
         lines: 13,
         bytes: 400,
         tokens: 20,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     };
     let data = export_data(vec![row]);
     let out =
@@ -1117,6 +1155,9 @@ fn unicode_file_path_csv() {
         lines: 13,
         bytes: 400,
         tokens: 20,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     };
     let data = export_data(vec![row]);
     let out =
@@ -1147,6 +1188,9 @@ fn unicode_path_cyclonedx() {
         lines: 13,
         bytes: 400,
         tokens: 20,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     };
     let data = export_data(vec![row]);
     let out = render_to_string(|buf| {
@@ -1331,6 +1375,10 @@ proptest! {
             bytes: code * 40,
             tokens: code * 2,
             avg_lines: lines,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         };
         let report = lang_report(vec![row], true);
         let out = render_to_string(|buf| {
@@ -1565,6 +1613,9 @@ fn cyclonedx_empty_module_omits_group() {
         lines: 13,
         bytes: 400,
         tokens: 20,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     };
     let data = export_data(vec![row]);
     let out = render_to_string(|buf| {
@@ -1610,6 +1661,9 @@ fn csv_newline_in_lang_name_quoted() {
         lines: 13,
         bytes: 400,
         tokens: 20,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     };
     let data = export_data(vec![row]);
     let out =
@@ -13,9 +13,10 @@ use tokmd_format::{
 };
 use tokmd_settings::ScanOptions;
 use tokmd_types::{
-    ChildIncludeMode, ChildrenMode, ExportArgs, ExportData, ExportFormat, ExportReceipt, FileKind,
-    FileRow, LangArgs, LangReceipt, LangReport, LangRow, ModuleArgs, ModuleReceipt, ModuleReport,
-    ModuleRow, RedactMode, SCHEMA_VERSION, ScanStatus, TableFormat, ToolInfo, Totals,
+    AvgMeasure, ChildIncludeMode, ChildrenMode, ExportArgs, ExportData, ExportFormat,
+    ExportReceipt, FileKind, FileRow, LangArgs, LangReceipt, LangReport, LangRow, ModuleArgs,
+    ModuleReceipt, ModuleReport, ModuleRow, RedactMode, SCHEMA_VERSION, ScanStatus, TableFormat,
+    ToolInfo, Totals,
 };
 
 // ---------------------------------------------------------------------------
@@ -33,6 +34,10 @@ fn sample_lang_report() -> LangReport {
                 bytes: 50000,
                 tokens: 2500,
                 avg_lines: 120,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
             LangRow {
                 lang: "TOML".into(),
@@ -42,6 +47,10 @@ fn sample_lang_report() -> LangReport {
                 bytes: 1000,
                 tokens: 125,
                 avg_lines: 30,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
         ],
         total: Totals {
@@ -51,6 +60,9 @@ fn sample_lang_report() -> LangReport {
             bytes: 51000,
             tokens: 2625,
             avg_lines: 105,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: true,
         children: ChildrenMode::Collapse,
@@ -69,6 +81,11 @@ fn sample_module_report() -> ModuleReport {
                 bytes: 40000,
                 tokens: 2000,
                 avg_lines: 119,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
+                children: Vec::new(),
             },
             ModuleRow {
                 module: "crates/bar".into(),
@@ -78,6 +95,11 @@ fn sample_module_report() -> ModuleReport {
                 bytes: 10000,
                 tokens: 500,
                 avg_lines: 125,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
+                children: Vec::new(),
             },
         ],
         total: Totals {
@@ -87,6 +109,9 @@ fn sample_module_report() -> ModuleReport {
             bytes: 50000,
             tokens: 2500,
             avg_lines: 120,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         module_roots: vec!["crates".into()],
         module_depth: 2,
@@ -108,6 +133,9 @@ fn sample_file_rows() -> Vec<FileRow> {
             lines: 130,
             bytes: 1000,
             tokens: 250,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         },
         FileRow {
             path: "tests/test.rs".into(),
@@ -120,6 +148,9 @@ fn sample_file_rows() -> Vec<FileRow> {
             lines: 60,
             bytes: 500,
             tokens: 125,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         },
     ]
 }
@@ -156,6 +187,7 @@ fn make_lang_receipt(report: &LangReport) -> LangReceipt {
             children: report.children,
         },
         report: report.clone(),
+        integrity: None,
     }
 }
 
@@ -177,8 +209,10 @@ fn make_module_receipt(report: &ModuleReport) -> ModuleReceipt {
             module_roots: report.module_roots.clone(),
             module_depth: report.module_depth,
             children: report.children,
+            collapse_single_child: false,
         },
         report: report.clone(),
+        integrity: None,
     }
 }
 
@@ -204,8 +238,13 @@ fn make_export_receipt(data: &ExportData) -> ExportReceipt {
             redact: RedactMode::None,
             strip_prefix: None,
             strip_prefix_redacted: false,
+            filters: Default::default(),
+            sort_by: None,
+            sort_dir: None,
         },
         data: data.clone(),
+        integrity: None,
+        summary: None,
     }
 }
 
@@ -216,6 +255,9 @@ fn lang_args(fmt: TableFormat) -> LangArgs {
         top: 0,
         files: true,
         children: ChildrenMode::Collapse,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     }
 }
 
@@ -227,6 +269,10 @@ fn module_args(fmt: TableFormat) -> ModuleArgs {
         module_roots: vec!["crates".into()],
         module_depth: 2,
         children: ChildIncludeMode::Separate,
+        collapse_single_child: false,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     }
 }
 
@@ -243,6 +289,10 @@ fn export_args(fmt: ExportFormat) -> ExportArgs {
         redact: RedactMode::None,
         meta: true,
         strip_prefix: None,
+        filters: Default::default(),
+        sort_by: None,
+        sort_dir: None,
+        summary: false,
     }
 }
 
@@ -320,6 +370,9 @@ fn json_roundtrip_empty_rows() {
             bytes: 0,
             tokens: 0,
             avg_lines: 0,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: false,
         children: ChildrenMode::Collapse,
@@ -343,6 +396,10 @@ fn json_roundtrip_max_field_values() {
             bytes: usize::MAX,
             tokens: usize::MAX,
             avg_lines: usize::MAX,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         }],
         total: Totals {
             code: usize::MAX,
@@ -351,6 +408,9 @@ fn json_roundtrip_max_field_values() {
             bytes: usize::MAX,
             tokens: usize::MAX,
             avg_lines: usize::MAX,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: true,
         children: ChildrenMode::Collapse,
@@ -374,6 +434,10 @@ fn json_roundtrip_special_characters_in_lang() {
             bytes: 200,
             tokens: 100,
             avg_lines: 50,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         }],
         total: Totals {
             code: 42,
@@ -382,6 +446,9 @@ fn json_roundtrip_special_characters_in_lang() {
             bytes: 200,
             tokens: 100,
             avg_lines: 50,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: true,
         children: ChildrenMode::Separate,
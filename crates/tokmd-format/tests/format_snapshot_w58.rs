@@ -10,7 +10,7 @@ use tokmd_format::{
     render_diff_md_with_options, write_export_csv_to, write_export_json_to, write_export_jsonl_to,
     write_lang_report_to, write_module_report_to,
 };
-use tokmd_settings::{ChildIncludeMode, ChildrenMode, ScanOptions};
+use tokmd_settings::{AvgMeasure, ChildIncludeMode, ChildrenMode, ScanOptions};
 use tokmd_types::{
     ExportArgs, ExportData, ExportFormat, FileKind, FileRow, LangArgs, LangReport, LangRow,
     ModuleArgs, ModuleReport, ModuleRow, RedactMode, TableFormat, Totals,
@@ -29,6 +29,10 @@ fn lang_row(name: &str, code: usize) -> LangRow {
         bytes: code * 35,
         tokens: code * 9,
         avg_lines: if code > 0 { 130 } else { 0 },
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
+        file_rows: Vec::new(),
     }
 }
 
@@ -46,6 +50,9 @@ fn totals_from_rows(rows: &[LangRow]) -> Totals {
             let total_files: usize = rows.iter().map(|r| r.files).sum();
             total_lines.checked_div(total_files).unwrap_or(0)
         },
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
     }
 }
 
@@ -99,6 +106,9 @@ fn lang_args(format: TableFormat) -> LangArgs {
         top: 0,
         files: true,
         children: ChildrenMode::Collapse,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     }
 }
 
@@ -110,6 +120,10 @@ fn module_args(format: TableFormat) -> ModuleArgs {
         module_roots: vec![],
         module_depth: 2,
         children: ChildIncludeMode::Separate,
+        collapse_single_child: false,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     }
 }
 
@@ -126,6 +140,10 @@ fn export_args(format: ExportFormat) -> ExportArgs {
         redact: RedactMode::None,
         meta: false,
         strip_prefix: None,
+        filters: Default::default(),
+        sort_by: None,
+        sort_dir: None,
+        summary: false,
     }
 }
 
@@ -251,6 +269,10 @@ fn w58_lang_md_embedded_separate() {
                 bytes: 18_000,
                 tokens: 6_000,
                 avg_lines: 93,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
             LangRow {
                 lang: "JavaScript (embedded)".into(),
@@ -260,6 +282,10 @@ fn w58_lang_md_embedded_separate() {
                 bytes: 9_000,
                 tokens: 3_000,
                 avg_lines: 46,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
             LangRow {
                 lang: "CSS (embedded)".into(),
@@ -269,6 +295,10 @@ fn w58_lang_md_embedded_separate() {
                 bytes: 4_500,
                 tokens: 1_500,
                 avg_lines: 45,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
         ],
         total: Totals {
@@ -278,6 +308,9 @@ fn w58_lang_md_embedded_separate() {
             bytes: 31_500,
             tokens: 10_500,
             avg_lines: 65,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: true,
         children: ChildrenMode::Separate,
@@ -300,6 +333,10 @@ fn w58_lang_md_embedded_collapse() {
             bytes: 31_500,
             tokens: 10_500,
             avg_lines: 162,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         }],
         total: Totals {
             code: 1050,
@@ -308,6 +345,9 @@ fn w58_lang_md_embedded_collapse() {
             bytes: 31_500,
             tokens: 10_500,
             avg_lines: 162,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: true,
         children: ChildrenMode::Collapse,
@@ -401,6 +441,11 @@ fn deep_module_report() -> ModuleReport {
                 bytes: 75_000,
                 tokens: 20_000,
                 avg_lines: 266,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
+                children: Vec::new(),
             },
             ModuleRow {
                 module: "crates/tokmd-scan".into(),
@@ -410,6 +455,11 @@ fn deep_module_report() -> ModuleReport {
                 bytes: 54_000,
                 tokens: 14_400,
                 avg_lines: 287,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
+                children: Vec::new(),
             },
             ModuleRow {
                 module: "crates/tokmd-format".into(),
@@ -419,6 +469,11 @@ fn deep_module_report() -> ModuleReport {
                 bytes: 36_000,
                 tokens: 9_600,
                 avg_lines: 300,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
+                children: Vec::new(),
             },
             ModuleRow {
                 module: "crates/tokmd-badge".into(),
@@ -428,6 +483,11 @@ fn deep_module_report() -> ModuleReport {
                 bytes: 6_000,
                 tokens: 1_600,
                 avg_lines: 130,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
+                children: Vec::new(),
             },
             ModuleRow {
                 module: "tests".into(),
@@ -437,6 +497,11 @@ fn deep_module_report() -> ModuleReport {
                 bytes: 18_000,
                 tokens: 4_800,
                 avg_lines: 187,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
+                children: Vec::new(),
             },
         ],
         total: Totals {
@@ -446,6 +511,9 @@ fn deep_module_report() -> ModuleReport {
             bytes: 189_000,
             tokens: 50_400,
             avg_lines: 258,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         module_roots: vec!["crates".into()],
         module_depth: 2,
@@ -494,6 +562,9 @@ fn rich_export_data() -> ExportData {
                 lines: 600,
                 bytes: 15_000,
                 tokens: 5_000,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             },
             FileRow {
                 path: "src/lib.rs".into(),
@@ -506,6 +577,9 @@ fn rich_export_data() -> ExportData {
                 lines: 305,
                 bytes: 7_500,
                 tokens: 2_500,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             },
             FileRow {
                 path: "src/template.html".into(),
@@ -518,6 +592,9 @@ fn rich_export_data() -> ExportData {
                 lines: 95,
                 bytes: 2_400,
                 tokens: 800,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             },
             FileRow {
                 path: "src/template.html".into(),
@@ -530,6 +607,9 @@ fn rich_export_data() -> ExportData {
                 lines: 35,
                 bytes: 900,
                 tokens: 300,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             },
         ],
         module_roots: vec!["src".into()],
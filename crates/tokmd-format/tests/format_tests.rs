@@ -226,6 +226,10 @@ fn make_lang_row(lang: &str, code: usize, lines: usize, files: usize) -> LangRow
         bytes: code * 10,
         tokens: code / 4,
         avg_lines: lines.checked_div(files).unwrap_or(0),
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
+        file_rows: Vec::new(),
     }
 }
 
@@ -237,6 +241,9 @@ fn make_totals(code: usize, lines: usize, files: usize) -> Totals {
         bytes: code * 10,
         tokens: code / 4,
         avg_lines: lines.checked_div(files).unwrap_or(0),
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
     }
 }
 
@@ -409,6 +416,10 @@ fn test_diff_row_only_bytes_changed() {
             bytes: 1000, // different bytes
             tokens: 25,
             avg_lines: 24,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         }],
         make_totals(100, 120, 5),
     );
@@ -422,6 +433,10 @@ fn test_diff_row_only_bytes_changed() {
             bytes: 2000, // changed bytes
             tokens: 25,
             avg_lines: 24,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         }],
         make_totals(100, 120, 5),
     );
@@ -444,6 +459,10 @@ fn test_diff_row_only_tokens_changed() {
             bytes: 1000,
             tokens: 100, // different tokens
             avg_lines: 24,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         }],
         make_totals(100, 120, 5),
     );
@@ -457,6 +476,10 @@ fn test_diff_row_only_tokens_changed() {
             bytes: 1000,
             tokens: 200, // changed tokens
             avg_lines: 24,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         }],
         make_totals(100, 120, 5),
     );
@@ -488,6 +511,9 @@ fn test_write_export_csv_format() {
                 lines: 130,
                 bytes: 1000,
                 tokens: 250,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             },
             FileRow {
                 path: "src/main.rs".to_string(),
@@ -500,6 +526,9 @@ fn test_write_export_csv_format() {
                 lines: 60,
                 bytes: 500,
                 tokens: 125,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             },
         ],
         module_roots: vec!["src".to_string()],
@@ -520,6 +549,10 @@ fn test_write_export_csv_format() {
         meta: true,
         redact: RedactMode::None,
         strip_prefix: None,
+        filters: Default::default(),
+        sort_by: None,
+        sort_dir: None,
+        summary: false,
     };
 
     let mut buffer = Cursor::new(Vec::new());
@@ -554,6 +587,9 @@ fn test_write_export_jsonl_with_meta() {
             lines: 13,
             bytes: 100,
             tokens: 25,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         }],
         module_roots: vec![],
         module_depth: 1,
@@ -573,6 +609,10 @@ fn test_write_export_jsonl_with_meta() {
         meta: true,
         redact: RedactMode::None,
         strip_prefix: None,
+        filters: Default::default(),
+        sort_by: None,
+        sort_dir: None,
+        summary: false,
     };
 
     let mut buffer = Cursor::new(Vec::new());
@@ -605,6 +645,9 @@ fn test_write_export_jsonl_without_meta() {
             lines: 13,
             bytes: 100,
             tokens: 25,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         }],
         module_roots: vec![],
         module_depth: 1,
@@ -624,6 +667,10 @@ fn test_write_export_jsonl_without_meta() {
         meta: false, // No meta
         redact: RedactMode::None,
         strip_prefix: None,
+        filters: Default::default(),
+        sort_by: None,
+        sort_dir: None,
+        summary: false,
     };
 
     let mut buffer = Cursor::new(Vec::new());
@@ -659,6 +706,9 @@ fn test_write_export_json_with_redaction() {
             lines: 13,
             bytes: 100,
             tokens: 25,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         }],
         module_roots: vec!["src".to_string()],
         module_depth: 1,
@@ -678,6 +728,10 @@ fn test_write_export_json_with_redaction() {
         meta: true,
         redact: RedactMode::All, // Full redaction
         strip_prefix: None,
+        filters: Default::default(),
+        sort_by: None,
+        sort_dir: None,
+        summary: false,
     };
 
     let mut buffer = Cursor::new(Vec::new());
@@ -717,6 +771,9 @@ fn test_write_export_cyclonedx_structure() {
                 lines: 130,
                 bytes: 1000,
                 tokens: 250,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             },
             FileRow {
                 path: "src/main.rs".to_string(),
@@ -729,6 +786,9 @@ fn test_write_export_cyclonedx_structure() {
                 lines: 60,
                 bytes: 500,
                 tokens: 125,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             },
         ],
         module_roots: vec!["src".to_string()],
@@ -770,6 +830,9 @@ fn test_cyclonedx_empty_module_no_group() {
             lines: 13,
             bytes: 100,
             tokens: 25,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         }],
         module_roots: vec![],
         module_depth: 1,
@@ -817,6 +880,9 @@ fn test_jsonl_generated_at_ms_is_reasonable() {
             lines: 13,
             bytes: 100,
             tokens: 25,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         }],
         module_roots: vec![],
         module_depth: 1,
@@ -836,6 +902,10 @@ fn test_jsonl_generated_at_ms_is_reasonable() {
         meta: true,
         redact: RedactMode::None,
         strip_prefix: None,
+        filters: Default::default(),
+        sort_by: None,
+        sort_dir: None,
+        summary: false,
     };
 
     let mut buffer = Cursor::new(Vec::new());
@@ -882,6 +952,9 @@ fn test_json_generated_at_ms_is_reasonable() {
             lines: 13,
             bytes: 100,
             tokens: 25,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         }],
         module_roots: vec![],
         module_depth: 1,
@@ -901,6 +974,10 @@ fn test_json_generated_at_ms_is_reasonable() {
         meta: true,
         redact: RedactMode::None,
         strip_prefix: None,
+        filters: Default::default(),
+        sort_by: None,
+        sort_dir: None,
+        summary: false,
     };
 
     let mut buffer = Cursor::new(Vec::new());
@@ -973,6 +1050,9 @@ fn test_write_export_writes_to_file() {
             lines: 115,
             bytes: 1000,
             tokens: 250,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         }],
         module_roots: vec!["src".to_string()],
         module_depth: 1,
@@ -995,6 +1075,10 @@ fn test_write_export_writes_to_file() {
         meta: true,
         redact: RedactMode::None,
         strip_prefix: None,
+        filters: Default::default(),
+        sort_by: None,
+        sort_dir: None,
+        summary: false,
     };
 
     write_export(&export, &global, &args).expect("write_export should succeed");
@@ -1037,6 +1121,9 @@ fn test_jsonl_strip_prefix_redacted_with_paths_mode() {
             lines: 12,
             bytes: 100,
             tokens: 20,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         }],
         module_roots: vec!["src".to_string()],
         module_depth: 1,
@@ -1056,6 +1143,10 @@ fn test_jsonl_strip_prefix_redacted_with_paths_mode() {
         meta: true,
         redact: RedactMode::Paths, // Paths mode should trigger redaction
         strip_prefix: Some(PathBuf::from("src")),
+        filters: Default::default(),
+        sort_by: None,
+        sort_dir: None,
+        summary: false,
     };
 
     let mut buffer = Cursor::new(Vec::new());
@@ -1101,6 +1192,9 @@ fn test_jsonl_strip_prefix_redacted_with_all_mode() {
             lines: 12,
             bytes: 100,
             tokens: 20,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         }],
         module_roots: vec!["src".to_string()],
         module_depth: 1,
@@ -1120,6 +1214,10 @@ fn test_jsonl_strip_prefix_redacted_with_all_mode() {
         meta: true,
         redact: RedactMode::All, // All mode should also trigger redaction
         strip_prefix: Some(PathBuf::from("prefix")),
+        filters: Default::default(),
+        sort_by: None,
+        sort_dir: None,
+        summary: false,
     };
 
     let mut buffer = Cursor::new(Vec::new());
@@ -1165,6 +1263,9 @@ fn test_jsonl_strip_prefix_redacted_false_when_no_strip_prefix() {
             lines: 12,
             bytes: 100,
             tokens: 20,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         }],
         module_roots: vec!["src".to_string()],
         module_depth: 1,
@@ -1183,7 +1284,11 @@ fn test_jsonl_strip_prefix_redacted_false_when_no_strip_prefix() {
         max_rows: 0,
         meta: true,
         redact: RedactMode::Paths, // Redaction enabled but no strip_prefix
-        strip_prefix: None,        // No strip_prefix
+        strip_prefix: None,        // No strip_prefix,
+        filters: Default::default(),
+        sort_by: None,
+        sort_dir: None,
+        summary: false,
     };
 
     let mut buffer = Cursor::new(Vec::new());
@@ -1231,6 +1336,9 @@ fn test_jsonl_no_redaction_with_none_mode() {
             lines: 12,
             bytes: 100,
             tokens: 20,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         }],
         module_roots: vec!["src".to_string()],
         module_depth: 1,
@@ -1250,6 +1358,10 @@ fn test_jsonl_no_redaction_with_none_mode() {
         meta: true,
         redact: RedactMode::None, // None mode should NOT trigger redaction
         strip_prefix: Some(PathBuf::from("src")),
+        filters: Default::default(),
+        sort_by: None,
+        sort_dir: None,
+        summary: false,
     };
 
     let mut buffer = Cursor::new(Vec::new());
@@ -1299,6 +1411,9 @@ fn test_json_strip_prefix_redacted_with_paths_mode() {
             lines: 12,
             bytes: 100,
             tokens: 20,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         }],
         module_roots: vec!["src".to_string()],
         module_depth: 1,
@@ -1318,6 +1433,10 @@ fn test_json_strip_prefix_redacted_with_paths_mode() {
         meta: true,
         redact: RedactMode::Paths,
         strip_prefix: Some(PathBuf::from("src")),
+        filters: Default::default(),
+        sort_by: None,
+        sort_dir: None,
+        summary: false,
     };
 
     let mut buffer = Cursor::new(Vec::new());
@@ -1356,6 +1475,9 @@ fn test_json_no_redaction_with_none_mode() {
             lines: 12,
             bytes: 100,
             tokens: 20,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         }],
         module_roots: vec!["src".to_string()],
         module_depth: 1,
@@ -1375,6 +1497,10 @@ fn test_json_no_redaction_with_none_mode() {
         meta: true,
         redact: RedactMode::None,
         strip_prefix: Some(PathBuf::from("myprefix")),
+        filters: Default::default(),
+        sort_by: None,
+        sort_dir: None,
+        summary: false,
     };
 
     let mut buffer = Cursor::new(Vec::new());
@@ -1424,6 +1550,10 @@ fn test_write_lang_json_to_file_writes_valid_json() {
             bytes: 5000,
             tokens: 250,
             avg_lines: 24,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         }],
         total: Totals {
             code: 100,
@@ -1432,6 +1562,9 @@ fn test_write_lang_json_to_file_writes_valid_json() {
             bytes: 5000,
             tokens: 250,
             avg_lines: 24,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: true,
         children: ChildrenMode::Collapse,
@@ -1449,6 +1582,13 @@ fn test_write_lang_json_to_file_writes_valid_json() {
         no_ignore_dot: false,
         no_ignore_vcs: false,
         treat_doc_strings_as_comments: false,
+        token_model: "heuristic".to_string(),
+        hash_algo: tokmd_types::HashAlgo::Blake3,
+        symlink_policy: Default::default(),
+        line_count_mode: Default::default(),
+        code_comment_markers: Vec::new(),
+        ignore_file: None,
+        include: Vec::new(),
     };
 
     let args_meta = LangArgsMeta {
@@ -1491,6 +1631,11 @@ fn test_write_module_json_to_file_writes_valid_json() {
             bytes: 10000,
             tokens: 500,
             avg_lines: 24,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
+            children: Vec::new(),
         }],
         total: Totals {
             code: 200,
@@ -1499,6 +1644,9 @@ fn test_write_module_json_to_file_writes_valid_json() {
             bytes: 10000,
             tokens: 500,
             avg_lines: 24,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         module_roots: vec!["src".to_string()],
         module_depth: 1,
@@ -1517,6 +1665,13 @@ fn test_write_module_json_to_file_writes_valid_json() {
         no_ignore_dot: false,
         no_ignore_vcs: false,
         treat_doc_strings_as_comments: false,
+        token_model: "heuristic".to_string(),
+        hash_algo: tokmd_types::HashAlgo::Blake3,
+        symlink_policy: Default::default(),
+        line_count_mode: Default::default(),
+        code_comment_markers: Vec::new(),
+        ignore_file: None,
+        include: Vec::new(),
     };
 
     let args_meta = ModuleArgsMeta {
@@ -1525,6 +1680,7 @@ fn test_write_module_json_to_file_writes_valid_json() {
         module_roots: vec!["src".to_string()],
         module_depth: 1,
         children: ChildIncludeMode::Separate,
+        collapse_single_child: false,
     };
 
     let temp_dir = tempfile::tempdir().expect("create temp dir");
@@ -1563,6 +1719,9 @@ fn test_write_export_jsonl_to_file_writes_valid_jsonl() {
             lines: 65,
             bytes: 500,
             tokens: 125,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         }],
         module_roots: vec!["src".to_string()],
         module_depth: 1,
@@ -1580,6 +1739,13 @@ fn test_write_export_jsonl_to_file_writes_valid_jsonl() {
         no_ignore_dot: false,
         no_ignore_vcs: false,
         treat_doc_strings_as_comments: false,
+        token_model: "heuristic".to_string(),
+        hash_algo: tokmd_types::HashAlgo::Blake3,
+        symlink_policy: Default::default(),
+        line_count_mode: Default::default(),
+        code_comment_markers: Vec::new(),
+        ignore_file: None,
+        include: Vec::new(),
     };
 
     let args_meta = ExportArgsMeta {
@@ -1592,6 +1758,9 @@ fn test_write_export_jsonl_to_file_writes_valid_jsonl() {
         redact: RedactMode::None,
         strip_prefix: None,
         strip_prefix_redacted: false,
+        filters: Default::default(),
+        sort_by: None,
+        sort_dir: None,
     };
 
     let temp_dir = tempfile::tempdir().expect("create temp dir");
@@ -1641,6 +1810,9 @@ fn test_cyclonedx_parent_has_no_kind_property() {
             lines: 130,
             bytes: 1000,
             tokens: 250,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         }],
         module_roots: vec!["src".to_string()],
         module_depth: 1,
@@ -1687,6 +1859,9 @@ fn test_cyclonedx_child_has_kind_property() {
             lines: 130,
             bytes: 1000,
             tokens: 250,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         }],
         module_roots: vec!["src".to_string()],
         module_depth: 1,
@@ -1830,6 +2005,9 @@ fn test_cyclonedx_snapshot_deterministic() {
             lines: 130,
             bytes: 1000,
             tokens: 250,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         }],
         module_roots: vec!["src".to_string()],
         module_depth: 1,
@@ -12,7 +12,7 @@ use tokmd_format::{
     render_diff_md_with_options, write_export_csv_to, write_export_json_to, write_export_jsonl_to,
     write_lang_report_to, write_module_report_to,
 };
-use tokmd_settings::{ChildIncludeMode, ChildrenMode, ScanOptions};
+use tokmd_settings::{AvgMeasure, ChildIncludeMode, ChildrenMode, ScanOptions};
 use tokmd_types::{
     DiffRow, DiffTotals, ExportArgs, ExportData, ExportFormat, FileKind, FileRow, LangArgs,
     LangReport, LangRow, ModuleArgs, ModuleReport, ModuleRow, RedactMode, TableFormat, Totals,
@@ -43,6 +43,10 @@ fn arb_lang_row_unicode() -> impl Strategy<Value = LangRow> {
                 bytes: code.saturating_mul(10),
                 tokens: code / 4,
                 avg_lines: lines.checked_div(files).unwrap_or(0),
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             }
         })
 }
@@ -56,6 +60,9 @@ fn arb_lang_report_unicode() -> impl Strategy<Value = LangReport> {
             bytes: rows.iter().map(|r| r.bytes).sum(),
             tokens: rows.iter().map(|r| r.tokens).sum(),
             avg_lines: 0,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         };
         LangReport {
             rows,
@@ -84,6 +91,11 @@ fn arb_module_row_unicode() -> impl Strategy<Value = ModuleRow> {
                 bytes: code.saturating_mul(10),
                 tokens: code / 4,
                 avg_lines: lines.checked_div(files).unwrap_or(0),
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
+                children: Vec::new(),
             }
         })
 }
@@ -97,6 +109,9 @@ fn arb_module_report_unicode() -> impl Strategy<Value = ModuleReport> {
             bytes: rows.iter().map(|r| r.bytes).sum(),
             tokens: rows.iter().map(|r| r.tokens).sum(),
             avg_lines: 0,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         };
         ModuleReport {
             rows,
@@ -129,6 +144,9 @@ fn arb_file_row_unicode() -> impl Strategy<Value = FileRow> {
             lines: code + comments + blanks,
             bytes: code.saturating_mul(10),
             tokens: code / 4,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         })
 }
 
@@ -203,6 +221,9 @@ proptest! {
             top: 0,
             files: false,
             children: ChildrenMode::Collapse,
+            avg: AvgMeasure::Mean,
+            percentiles: false,
+            with_files_detail: false,
         };
         let mut buf = Vec::new();
         let _ = write_lang_report_to(&mut buf, &report, &default_global(), &args);
@@ -216,6 +237,9 @@ proptest! {
             top: 0,
             files: false,
             children: ChildrenMode::Collapse,
+            avg: AvgMeasure::Mean,
+            percentiles: false,
+            with_files_detail: false,
         };
         let mut buf = Vec::new();
         let _ = write_lang_report_to(&mut buf, &report, &default_global(), &args);
@@ -229,6 +253,9 @@ proptest! {
             top: 0,
             files: false,
             children: ChildrenMode::Collapse,
+            avg: AvgMeasure::Mean,
+            percentiles: false,
+            with_files_detail: false,
         };
         let mut buf = Vec::new();
         let _ = write_lang_report_to(&mut buf, &report, &default_global(), &args);
@@ -243,6 +270,10 @@ proptest! {
             module_roots: vec!["crates".into()],
             module_depth: 2,
             children: ChildIncludeMode::Separate,
+            collapse_single_child: false,
+            avg: AvgMeasure::Mean,
+            percentiles: false,
+            with_files_detail: false,
         };
         let mut buf = Vec::new();
         let _ = write_module_report_to(&mut buf, &report, &default_global(), &args);
@@ -257,6 +288,10 @@ proptest! {
             module_roots: vec!["crates".into()],
             module_depth: 2,
             children: ChildIncludeMode::Separate,
+            collapse_single_child: false,
+            avg: AvgMeasure::Mean,
+            percentiles: false,
+            with_files_detail: false,
         };
         let mut buf = Vec::new();
         let _ = write_module_report_to(&mut buf, &report, &default_global(), &args);
@@ -271,6 +306,10 @@ proptest! {
             module_roots: vec!["crates".into()],
             module_depth: 2,
             children: ChildIncludeMode::Separate,
+            collapse_single_child: false,
+            avg: AvgMeasure::Mean,
+            percentiles: false,
+            with_files_detail: false,
         };
         let mut buf = Vec::new();
         let _ = write_module_report_to(&mut buf, &report, &default_global(), &args);
@@ -298,6 +337,10 @@ proptest! {
                 bytes: code * 10,
                 tokens: code / 4,
                 avg_lines: code + 10,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             }],
             total: Totals {
                 code,
@@ -306,6 +349,9 @@ proptest! {
                 bytes: code * 10,
                 tokens: code / 4,
                 avg_lines: code + 10,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
             },
             with_files: false,
             children: ChildrenMode::Collapse,
@@ -317,6 +363,9 @@ proptest! {
             top: 0,
             files: false,
             children: ChildrenMode::Collapse,
+            avg: AvgMeasure::Mean,
+            percentiles: false,
+            with_files_detail: false,
         };
         let mut buf = Vec::new();
         let _ = write_lang_report_to(&mut buf, &report, &default_global(), &args);
@@ -350,6 +399,10 @@ proptest! {
             redact: RedactMode::None,
             meta: false,
             strip_prefix: None,
+            filters: Default::default(),
+            sort_by: None,
+            sort_dir: None,
+            summary: false,
         };
         let mut buf = Vec::new();
         let _ = write_export_csv_to(&mut buf, &data, &args);
@@ -375,6 +428,10 @@ proptest! {
             redact: RedactMode::None,
             meta: false,
             strip_prefix: None,
+            filters: Default::default(),
+            sort_by: None,
+            sort_dir: None,
+            summary: false,
         };
         let mut buf = Vec::new();
         let _ = write_export_json_to(&mut buf, &data, &default_global(), &args);
@@ -400,6 +457,10 @@ proptest! {
             redact: RedactMode::None,
             meta: false,
             strip_prefix: None,
+            filters: Default::default(),
+            sort_by: None,
+            sort_dir: None,
+            summary: false,
         };
         let mut buf = Vec::new();
         let _ = write_export_jsonl_to(&mut buf, &data, &default_global(), &args);
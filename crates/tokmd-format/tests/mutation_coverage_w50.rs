@@ -7,7 +7,7 @@ use std::path::PathBuf;
 
 use tokmd_format::write_lang_report_to;
 use tokmd_settings::ScanOptions;
-use tokmd_types::{ChildrenMode, LangArgs, LangReport, LangRow, TableFormat, Totals};
+use tokmd_types::{AvgMeasure, ChildrenMode, LangArgs, LangReport, LangRow, TableFormat, Totals};
 
 fn default_scan_options() -> ScanOptions {
     ScanOptions::default()
@@ -24,6 +24,10 @@ fn sample_lang_report(with_files: bool) -> LangReport {
                 bytes: 20000,
                 tokens: 5000,
                 avg_lines: 140,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
             LangRow {
                 lang: "Python".to_string(),
@@ -33,6 +37,10 @@ fn sample_lang_report(with_files: bool) -> LangReport {
                 bytes: 8000,
                 tokens: 2000,
                 avg_lines: 100,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
         ],
         total: Totals {
@@ -42,6 +50,9 @@ fn sample_lang_report(with_files: bool) -> LangReport {
             bytes: 28000,
             tokens: 7000,
             avg_lines: 125,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files,
         children: ChildrenMode::Collapse,
@@ -56,6 +67,9 @@ fn lang_args(fmt: TableFormat) -> LangArgs {
         top: 0,
         files: true,
         children: ChildrenMode::Collapse,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     }
 }
 
@@ -147,7 +161,7 @@ fn json_contains_schema_version() {
     // Parse and verify the value
     let json: serde_json::Value =
         serde_json::from_str(output.trim()).expect("must parse valid JSON");
-    assert_eq!(json["schema_version"], 2);
+    assert_eq!(json["schema_version"], 3);
 }
 
 // ---------------------------------------------------------------------------
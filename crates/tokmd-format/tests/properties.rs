@@ -9,7 +9,7 @@ use tokmd_format::{
     compute_diff_rows, compute_diff_totals, write_export_csv_to, write_export_json_to,
     write_lang_report_to, write_module_report_to,
 };
-use tokmd_settings::{ChildIncludeMode, ChildrenMode, ScanOptions};
+use tokmd_settings::{AvgMeasure, ChildIncludeMode, ChildrenMode, ScanOptions};
 use tokmd_types::{
     ExportArgs, ExportData, ExportFormat, FileKind, FileRow, LangArgs, LangReport, LangRow,
     ModuleArgs, ModuleReport, ModuleRow, RedactMode, TableFormat, Totals,
@@ -36,6 +36,10 @@ fn arb_lang_row() -> impl Strategy<Value = LangRow> {
             bytes: code * 10,
             tokens: code / 4,
             avg_lines: lines.checked_div(files).unwrap_or(0),
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         })
 }
 
@@ -55,6 +59,9 @@ fn arb_lang_report() -> impl Strategy<Value = LangReport> {
             bytes: rows.iter().map(|r| r.bytes).sum(),
             tokens: rows.iter().map(|r| r.tokens).sum(),
             avg_lines: 0,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         };
         LangReport {
             rows,
@@ -81,6 +88,11 @@ fn arb_module_row() -> impl Strategy<Value = ModuleRow> {
             bytes: code * 10,
             tokens: code / 4,
             avg_lines: lines.checked_div(files).unwrap_or(0),
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
+            children: Vec::new(),
         })
 }
 
@@ -98,6 +110,9 @@ fn arb_module_report() -> impl Strategy<Value = ModuleReport> {
             bytes: rows.iter().map(|r| r.bytes).sum(),
             tokens: rows.iter().map(|r| r.tokens).sum(),
             avg_lines: 0,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         };
         ModuleReport {
             rows,
@@ -134,6 +149,9 @@ fn arb_file_row() -> impl Strategy<Value = FileRow> {
             lines: code + comments + blanks,
             bytes: code * 10,
             tokens: code / 4,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         })
 }
 
@@ -156,6 +174,9 @@ proptest! {
             top: 0,
             files: false,
             children: ChildrenMode::Collapse,
+            avg: AvgMeasure::Mean,
+            percentiles: false,
+            with_files_detail: false,
         };
         let render = |r: &LangReport| -> String {
             let mut buf = Vec::new();
@@ -173,6 +194,9 @@ proptest! {
             top: 0,
             files: false,
             children: ChildrenMode::Collapse,
+            avg: AvgMeasure::Mean,
+            percentiles: false,
+            with_files_detail: false,
         };
         let render = |r: &LangReport| -> String {
             let mut buf = Vec::new();
@@ -191,6 +215,10 @@ proptest! {
             module_roots: vec!["crates".into()],
             module_depth: 2,
             children: ChildIncludeMode::Separate,
+            collapse_single_child: false,
+            avg: AvgMeasure::Mean,
+            percentiles: false,
+            with_files_detail: false,
         };
         let render = |r: &ModuleReport| -> String {
             let mut buf = Vec::new();
@@ -209,6 +237,10 @@ proptest! {
             module_roots: vec!["crates".into()],
             module_depth: 2,
             children: ChildIncludeMode::Separate,
+            collapse_single_child: false,
+            avg: AvgMeasure::Mean,
+            percentiles: false,
+            with_files_detail: false,
         };
         let render = |r: &ModuleReport| -> String {
             let mut buf = Vec::new();
@@ -238,6 +270,10 @@ proptest! {
             redact: RedactMode::None,
             meta: false,
             strip_prefix: None,
+            filters: Default::default(),
+            sort_by: None,
+            sort_dir: None,
+            summary: false,
         };
         let render = |d: &ExportData| -> String {
             let mut buf = Vec::new();
@@ -265,6 +301,9 @@ proptest! {
             top: 0,
             files: false,
             children: ChildrenMode::Collapse,
+            avg: AvgMeasure::Mean,
+            percentiles: false,
+            with_files_detail: false,
         };
         let mut buf = Vec::new();
         write_lang_report_to(&mut buf, &report, &default_global(), &args).expect("operation must succeed");
@@ -289,6 +328,9 @@ proptest! {
             top: 0,
             files: false,
             children: ChildrenMode::Collapse,
+            avg: AvgMeasure::Mean,
+            percentiles: false,
+            with_files_detail: false,
         };
         let mut buf = Vec::new();
         write_lang_report_to(&mut buf, &report, &default_global(), &args).expect("operation must succeed");
@@ -313,6 +355,10 @@ proptest! {
             module_roots: vec!["crates".into()],
             module_depth: 2,
             children: ChildIncludeMode::Separate,
+            collapse_single_child: false,
+            avg: AvgMeasure::Mean,
+            percentiles: false,
+            with_files_detail: false,
         };
         let mut buf = Vec::new();
         write_module_report_to(&mut buf, &report, &default_global(), &args).expect("operation must succeed");
@@ -346,6 +392,10 @@ proptest! {
             redact: RedactMode::None,
             meta: false,
             strip_prefix: None,
+            filters: Default::default(),
+            sort_by: None,
+            sort_dir: None,
+            summary: false,
         };
         let mut buf = Vec::new();
         write_export_csv_to(&mut buf, &data, &args).expect("operation must succeed");
@@ -371,6 +421,9 @@ proptest! {
             top: 0,
             files: false,
             children: ChildrenMode::Collapse,
+            avg: AvgMeasure::Mean,
+            percentiles: false,
+            with_files_detail: false,
         };
         let mut buf = Vec::new();
         write_lang_report_to(&mut buf, &report, &default_global(), &args).expect("operation must succeed");
@@ -390,6 +443,10 @@ proptest! {
             module_roots: vec!["crates".into()],
             module_depth: 2,
             children: ChildIncludeMode::Separate,
+            collapse_single_child: false,
+            avg: AvgMeasure::Mean,
+            percentiles: false,
+            with_files_detail: false,
         };
         let mut buf = Vec::new();
         write_module_report_to(&mut buf, &report, &default_global(), &args).expect("operation must succeed");
@@ -422,6 +479,10 @@ proptest! {
             redact: RedactMode::None,
             meta: false,
             strip_prefix: None,
+            filters: Default::default(),
+            sort_by: None,
+            sort_dir: None,
+            summary: false,
         };
         let mut buf = Vec::new();
         write_export_json_to(&mut buf, &data, &default_global(), &args).expect("operation must succeed");
@@ -467,6 +528,9 @@ proptest! {
             top: 0,
             files: false,
             children: ChildrenMode::Collapse,
+            avg: AvgMeasure::Mean,
+            percentiles: false,
+            with_files_detail: false,
         };
         let mut buf = Vec::new();
         write_lang_report_to(&mut buf, &report, &default_global(), &args).expect("operation must succeed");
@@ -483,6 +547,10 @@ proptest! {
             module_roots: vec![],
             module_depth: 1,
             children: ChildIncludeMode::Separate,
+            collapse_single_child: false,
+            avg: AvgMeasure::Mean,
+            percentiles: false,
+            with_files_detail: false,
         };
         let mut buf1 = Vec::new();
         let mut buf2 = Vec::new();
@@ -499,6 +567,9 @@ proptest! {
             top: 0,
             files: false,
             children: ChildrenMode::Collapse,
+            avg: AvgMeasure::Mean,
+            percentiles: false,
+            with_files_detail: false,
         };
         let mut buf = Vec::new();
         write_lang_report_to(&mut buf, &report, &default_global(), &args).expect("operation must succeed");
@@ -534,6 +605,10 @@ proptest! {
             redact: RedactMode::None,
             meta: false,
             strip_prefix: None,
+            filters: Default::default(),
+            sort_by: None,
+            sort_dir: None,
+            summary: false,
         };
         let mut buf = Vec::new();
         write_export_json_to(&mut buf, &export, &default_global(), &args).expect("operation must succeed");
@@ -11,7 +11,7 @@ use tokmd_format::{
     compute_diff_rows, compute_diff_totals, write_export_csv_to, write_export_json_to,
     write_export_jsonl_to, write_lang_report_to, write_module_report_to,
 };
-use tokmd_settings::{ChildIncludeMode, ChildrenMode, ScanOptions};
+use tokmd_settings::{AvgMeasure, ChildIncludeMode, ChildrenMode, ScanOptions};
 use tokmd_types::{
     ExportArgs, ExportData, ExportFormat, FileKind, FileRow, LangArgs, LangReport, LangRow,
     ModuleArgs, ModuleReport, ModuleRow, RedactMode, TableFormat, Totals,
@@ -53,6 +53,10 @@ fn arb_lang_row() -> impl Strategy<Value = LangRow> {
             bytes: code * 10,
             tokens: code / 4,
             avg_lines: lines.checked_div(files).unwrap_or(0),
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         })
 }
 
@@ -70,6 +74,9 @@ fn arb_lang_report() -> impl Strategy<Value = LangReport> {
             bytes: rows.iter().map(|r| r.bytes).sum(),
             tokens: rows.iter().map(|r| r.tokens).sum(),
             avg_lines: 0,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         };
         LangReport {
             rows,
@@ -96,6 +103,11 @@ fn arb_module_row() -> impl Strategy<Value = ModuleRow> {
             bytes: code * 10,
             tokens: code / 4,
             avg_lines: lines.checked_div(files).unwrap_or(0),
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
+            children: Vec::new(),
         })
 }
 
@@ -113,6 +125,9 @@ fn arb_module_report() -> impl Strategy<Value = ModuleReport> {
             bytes: rows.iter().map(|r| r.bytes).sum(),
             tokens: rows.iter().map(|r| r.tokens).sum(),
             avg_lines: 0,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         };
         ModuleReport {
             rows,
@@ -149,6 +164,9 @@ fn arb_file_row() -> impl Strategy<Value = FileRow> {
             lines: code + comments + blanks,
             bytes: code * 10,
             tokens: code / 4,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         })
 }
 
@@ -174,6 +192,9 @@ proptest! {
             top: 0,
             files: false,
             children: ChildrenMode::Collapse,
+            avg: AvgMeasure::Mean,
+            percentiles: false,
+            with_files_detail: false,
         };
         let mut buf = Vec::new();
         write_lang_report_to(&mut buf, &report, &default_global(), &args).expect("operation must succeed");
@@ -202,6 +223,10 @@ proptest! {
             module_roots: vec!["crates".into()],
             module_depth: 2,
             children: ChildIncludeMode::Separate,
+            collapse_single_child: false,
+            avg: AvgMeasure::Mean,
+            percentiles: false,
+            with_files_detail: false,
         };
         let mut buf = Vec::new();
         write_module_report_to(&mut buf, &report, &default_global(), &args).expect("operation must succeed");
@@ -265,6 +290,10 @@ proptest! {
             redact: RedactMode::None,
             meta: false,
             strip_prefix: None,
+            filters: Default::default(),
+            sort_by: None,
+            sort_dir: None,
+            summary: false,
         }).expect("operation must succeed");
         let output = String::from_utf8(buf).expect("output must be valid UTF-8");
 
@@ -288,6 +317,9 @@ proptest! {
             top: 0,
             files: false,
             children: ChildrenMode::Collapse,
+            avg: AvgMeasure::Mean,
+            percentiles: false,
+            with_files_detail: false,
         };
         let mut buf = Vec::new();
         write_lang_report_to(&mut buf, &report, &default_global(), &args).expect("operation must succeed");
@@ -316,6 +348,10 @@ proptest! {
             module_roots: vec!["crates".into()],
             module_depth: 2,
             children: ChildIncludeMode::Separate,
+            collapse_single_child: false,
+            avg: AvgMeasure::Mean,
+            percentiles: false,
+            with_files_detail: false,
         };
         let mut buf = Vec::new();
         write_module_report_to(&mut buf, &report, &default_global(), &args).expect("operation must succeed");
@@ -439,6 +475,10 @@ proptest! {
             redact: RedactMode::None,
             meta: false,
             strip_prefix: None,
+            filters: Default::default(),
+            sort_by: None,
+            sort_dir: None,
+            summary: false,
         };
         let mut buf = Vec::new();
         write_export_csv_to(&mut buf, &data, &args).expect("operation must succeed");
@@ -507,6 +547,10 @@ proptest! {
             redact: RedactMode::None,
             meta: false,
             strip_prefix: None,
+            filters: Default::default(),
+            sort_by: None,
+            sort_dir: None,
+            summary: false,
         };
         let mut buf = Vec::new();
         write_export_jsonl_to(&mut buf, &data, &default_global(), &args).expect("operation must succeed");
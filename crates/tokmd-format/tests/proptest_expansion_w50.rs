@@ -5,8 +5,8 @@
 
 use proptest::prelude::*;
 use tokmd_types::{
-    ChildIncludeMode, ChildrenMode, ExportData, FileKind, FileRow, LangArgs, LangReport, LangRow,
-    ModuleArgs, ModuleReport, ModuleRow, TableFormat, Totals,
+    AvgMeasure, ChildIncludeMode, ChildrenMode, ExportData, FileKind, FileRow, LangArgs,
+    LangReport, LangRow, ModuleArgs, ModuleReport, ModuleRow, TableFormat, Totals,
 };
 
 // ── Strategies ───────────────────────────────────────────────────────────────
@@ -27,6 +27,9 @@ fn arb_totals() -> impl Strategy<Value = Totals> {
             bytes,
             tokens,
             avg_lines,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         })
 }
 
@@ -49,6 +52,10 @@ fn arb_lang_row() -> impl Strategy<Value = LangRow> {
                 bytes,
                 tokens,
                 avg_lines,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
         )
 }
@@ -72,6 +79,11 @@ fn arb_module_row() -> impl Strategy<Value = ModuleRow> {
                 bytes,
                 tokens,
                 avg_lines,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
+                children: Vec::new(),
             },
         )
 }
@@ -105,6 +117,9 @@ fn arb_file_row() -> impl Strategy<Value = FileRow> {
                 lines,
                 bytes,
                 tokens,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             },
         )
 }
@@ -184,6 +199,9 @@ fn default_lang_args(format: TableFormat) -> LangArgs {
         top: 0,
         files: false,
         children: ChildrenMode::Collapse,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     }
 }
 
@@ -195,6 +213,10 @@ fn default_module_args(format: TableFormat) -> ModuleArgs {
         module_roots: vec![],
         module_depth: 1,
         children: ChildIncludeMode::Separate,
+        collapse_single_child: false,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     }
 }
 
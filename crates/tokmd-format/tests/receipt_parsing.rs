@@ -0,0 +1,135 @@
+//! Receipt parsing: upgrading older schemas and strict unknown-field checks.
+
+use std::path::PathBuf;
+
+use tokmd_format::{
+    UnknownFields, UnsupportedSchemaVersion, compute_diff_rows, compute_diff_totals, parse_receipt,
+    parse_receipt_strict, render_diff_md, write_lang_report_to,
+};
+use tokmd_settings::{AvgMeasure, ChildrenMode, ScanOptions};
+use tokmd_types::{LangArgs, LangReceipt, SCHEMA_VERSION, TableFormat};
+
+/// A `tokmd lang --format json` receipt as tokmd wrote it under schema v1:
+/// no `bytes`/`tokens` anywhere, no `avg_lines` on totals.
+const V1_LANG_RECEIPT: &str = r#"{
+  "schema_version": 1,
+  "generated_at_ms": 1700000000000,
+  "tool": {"name": "tokmd", "version": "0.1.0"},
+  "mode": "lang",
+  "status": "complete",
+  "warnings": [],
+  "scan": {
+    "paths": ["."],
+    "excluded": [],
+    "config": "auto",
+    "hidden": false,
+    "no_ignore": false,
+    "no_ignore_parent": false,
+    "no_ignore_dot": false,
+    "no_ignore_vcs": false,
+    "treat_doc_strings_as_comments": false
+  },
+  "args": {"format": "json", "top": 0, "with_files": true, "children": "collapse"},
+  "rows": [
+    {"lang": "Rust", "code": 900, "lines": 1100, "files": 4, "avg_lines": 275},
+    {"lang": "TOML", "code": 40, "lines": 45, "files": 1, "avg_lines": 45}
+  ],
+  "total": {"code": 940, "lines": 1145, "files": 5},
+  "with_files": true,
+  "children": "collapse",
+  "top": 0
+}"#;
+
+fn lang_args() -> LangArgs {
+    LangArgs {
+        paths: vec![PathBuf::from(".")],
+        format: TableFormat::Md,
+        top: 0,
+        files: true,
+        children: ChildrenMode::Collapse,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
+    }
+}
+
+#[test]
+fn v1_lang_receipt_migrates_and_renders() {
+    let receipt: LangReceipt = parse_receipt(V1_LANG_RECEIPT).expect("v1 receipt should migrate");
+    assert_eq!(receipt.schema_version, SCHEMA_VERSION);
+    assert_eq!(receipt.report.total.avg_lines, 229);
+    assert_eq!(receipt.report.total.tokens, 0);
+
+    let mut buf = Vec::new();
+    write_lang_report_to(
+        &mut buf,
+        &receipt.report,
+        &ScanOptions::default(),
+        &lang_args(),
+    )
+    .expect("render should succeed");
+    let output = String::from_utf8(buf).unwrap();
+    assert!(output.contains("|Rust|900|1100|4|0|0|275|"), "{output}");
+    assert!(
+        output.contains("|**Total**|940|1145|5|0|0|229|"),
+        "{output}"
+    );
+}
+
+#[test]
+fn v1_lang_receipt_diffs_against_a_newer_one() {
+    let from: LangReceipt = parse_receipt(V1_LANG_RECEIPT).unwrap();
+    let to: LangReceipt =
+        parse_receipt(&V1_LANG_RECEIPT.replace("\"code\": 900", "\"code\": 950")).unwrap();
+    let rows = compute_diff_rows(&from.report, &to.report);
+    let totals = compute_diff_totals(&rows);
+    assert_eq!(totals.delta_code, 50);
+
+    let md = render_diff_md("v1", "v1-later", &rows, &totals);
+    assert!(md.contains("|Rust|900|950|+50|"), "{md}");
+}
+
+#[test]
+fn future_schema_version_fails_clearly() {
+    let future = V1_LANG_RECEIPT.replacen(
+        "\"schema_version\": 1",
+        &format!("\"schema_version\": {}", SCHEMA_VERSION + 1),
+        1,
+    );
+    let err = parse_receipt::<LangReceipt>(&future).unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<UnsupportedSchemaVersion>(),
+        Some(&UnsupportedSchemaVersion {
+            found: u64::from(SCHEMA_VERSION) + 1,
+            supported: SCHEMA_VERSION,
+        })
+    );
+    assert!(err.to_string().contains("newer than this tokmd supports"));
+}
+
+#[test]
+fn extra_keys_are_accepted_leniently_but_reported_strictly() {
+    let mut value: serde_json::Value = serde_json::from_str(V1_LANG_RECEIPT).unwrap();
+    value["bogus"] = true.into();
+    value["rows"][1]["cod"] = 40.into();
+    let content = value.to_string();
+
+    let receipt: LangReceipt = parse_receipt(&content).expect("lenient parse ignores extras");
+    assert_eq!(receipt.report.rows.len(), 2);
+
+    let err = parse_receipt_strict::<LangReceipt>(&content).unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<UnknownFields>(),
+        Some(&UnknownFields(vec![
+            "/bogus".to_string(),
+            "/rows/1/cod".to_string(),
+        ]))
+    );
+}
+
+#[test]
+fn clean_receipt_parses_strictly() {
+    let receipt: LangReceipt =
+        parse_receipt_strict(V1_LANG_RECEIPT).expect("no unknown fields after migration");
+    assert_eq!(receipt.report.total.code, 940);
+}
@@ -10,8 +10,8 @@ use tokmd_analysis_types::{
     ANALYSIS_SCHEMA_VERSION, AnalysisArgsMeta, AnalysisReceipt, AnalysisSource, BoilerplateReport,
     CocomoReport, ContextWindowReport, DerivedReport, DerivedTotals, DistributionReport,
     FileStatRow, HistogramBucket, IntegrityReport, LangPurityReport, MaxFileReport, NestingReport,
-    PolyglotReport, RateReport, RateRow, RatioReport, RatioRow, ReadingTimeReport,
-    TestDensityReport, TodoReport, TodoTagRow, TopOffenders,
+    OversizedFileRow, PolyglotReport, RateReport, RateRow, RatioReport, RatioRow,
+    ReadingTimeReport, TestDensityReport, TodoReport, TodoTagRow, TopOffenders,
 };
 use tokmd_format::analysis::{RenderedOutput, render};
 use tokmd_types::{AnalysisFormat, ScanStatus, ToolInfo};
@@ -38,6 +38,7 @@ fn minimal_source() -> AnalysisSource {
         module_roots: vec![],
         module_depth: 1,
         children: "collapse".to_string(),
+        token_model: "heuristic".to_string(),
     }
 }
 
@@ -53,6 +54,14 @@ fn minimal_args() -> AnalysisArgsMeta {
         max_commit_files: None,
         max_file_bytes: None,
         import_granularity: "module".to_string(),
+        oversized_file_bytes: None,
+        max_commit_message_bytes: None,
+        strip_bom: false,
+        preset_sections: None,
+        include_sections: vec![],
+        exclude_sections: vec![],
+        sample_rate: None,
+        max_runtime_ms: None,
     }
 }
 
@@ -82,6 +91,11 @@ fn minimal_receipt() -> AnalysisReceipt {
         api_surface: None,
         fun: None,
         effort: None,
+        timings: None,
+        line_endings: None,
+        sampling: None,
+        risk_surface: Vec::new(),
+        extensions: Default::default(),
     }
 }
 
@@ -240,6 +254,8 @@ fn sample_derived() -> DerivedReport {
             hash: "deadbeefcafebabe".into(),
             entries: 12,
         },
+        oversized_files: vec![],
+        non_utf8_files: vec![],
     }
 }
 
@@ -381,6 +397,30 @@ fn derived_omits_context_window_when_absent() {
     assert!(md.contains("## Integrity"));
 }
 
+/// Oversized files get their own section, listed between the top offenders
+/// and the structure summary; it is absent when nothing exceeded the cap.
+#[test]
+fn derived_renders_oversized_files_when_present() {
+    let mut receipt = minimal_receipt();
+    receipt.derived = Some(sample_derived());
+    assert!(!render_md(&receipt).contains("## Oversized files"));
+
+    let mut derived = sample_derived();
+    derived.oversized_files = vec![OversizedFileRow {
+        path: "assets/blob.min.js".to_string(),
+        bytes: 2_500_000,
+    }];
+    receipt.derived = Some(derived);
+    let md = render_md(&receipt);
+
+    assert!(md.contains("## Oversized files"));
+    assert!(md.contains("|Path|Bytes|"));
+    assert!(md.contains("|assets/blob.min.js|2500000|"));
+    let section = md.find("## Oversized files").unwrap();
+    assert!(md.find("## Top offenders").unwrap() < section);
+    assert!(section < md.find("## Structure").unwrap());
+}
+
 /// The legacy `derived.cocomo` fallback section is rendered only when
 /// `receipt.effort` is `None` but `derived.cocomo` is populated.
 #[test]
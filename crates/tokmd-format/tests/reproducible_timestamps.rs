@@ -0,0 +1,30 @@
+//! Pinned clock makes receipt timestamps reproducible.
+//!
+//! Own test binary: the clock override is process-global.
+
+use tokmd_analysis_types::set_fixed_now_ms;
+use tokmd_format::{TimestampFormat, TimestampStyle, create_diff_receipt, now_utc};
+use tokmd_types::DiffTotals;
+
+#[test]
+fn pinned_clock_gives_identical_receipts_and_timestamps() {
+    set_fixed_now_ms(Some(1_700_000_000_000));
+
+    let first = create_diff_receipt("a", "b", vec![], DiffTotals::default());
+    let second = create_diff_receipt("a", "b", vec![], DiffTotals::default());
+    assert_eq!(first.generated_at_ms, 1_700_000_000_000);
+    assert_eq!(
+        serde_json::to_string(&first).unwrap(),
+        serde_json::to_string(&second).unwrap()
+    );
+
+    assert_eq!(now_utc().unix_timestamp(), 1_700_000_000);
+    let tf = TimestampFormat::default();
+    assert_eq!(tf.now(TimestampStyle::Iso8601), "2023-11-14T22:13:20Z");
+    assert_eq!(
+        tf.now(TimestampStyle::Display),
+        tf.now(TimestampStyle::Display)
+    );
+
+    set_fixed_now_ms(None);
+}
@@ -9,8 +9,8 @@ use tokmd_format::write_lang_report_to;
 use tokmd_format::write_module_report_to;
 use tokmd_settings::ScanOptions;
 use tokmd_types::{
-    ChildIncludeMode, ChildrenMode, LangArgs, LangReport, LangRow, ModuleArgs, ModuleReport,
-    ModuleRow, SCHEMA_VERSION, TableFormat, Totals,
+    AvgMeasure, ChildIncludeMode, ChildrenMode, LangArgs, LangReport, LangRow, ModuleArgs,
+    ModuleReport, ModuleRow, SCHEMA_VERSION, TableFormat, Totals,
 };
 
 // ---------------------------------------------------------------------------
@@ -25,6 +25,9 @@ fn sample_totals() -> Totals {
         bytes: 5000,
         tokens: 1000,
         avg_lines: 30,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
     }
 }
 
@@ -39,6 +42,10 @@ fn sample_lang_report() -> LangReport {
                 bytes: 4000,
                 tokens: 800,
                 avg_lines: 40,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
             LangRow {
                 lang: "TOML".into(),
@@ -48,6 +55,10 @@ fn sample_lang_report() -> LangReport {
                 bytes: 1000,
                 tokens: 200,
                 avg_lines: 15,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
         ],
         total: sample_totals(),
@@ -67,6 +78,11 @@ fn sample_module_report() -> ModuleReport {
             bytes: 5000,
             tokens: 1000,
             avg_lines: 30,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
+            children: Vec::new(),
         }],
         total: sample_totals(),
         module_roots: vec![],
@@ -87,6 +103,9 @@ fn lang_args(format: TableFormat) -> LangArgs {
         top: 0,
         files: true,
         children: ChildrenMode::Collapse,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     }
 }
 
@@ -98,6 +117,10 @@ fn module_args(format: TableFormat) -> ModuleArgs {
         module_roots: vec![],
         module_depth: 1,
         children: ChildIncludeMode::Separate,
+        collapse_single_child: false,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     }
 }
 
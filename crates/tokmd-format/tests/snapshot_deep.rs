@@ -20,7 +20,7 @@ use tokmd_format::{
     write_export_cyclonedx_with_options, write_export_json_to, write_export_jsonl_to,
     write_lang_report_to, write_module_report_to,
 };
-use tokmd_settings::{ChildIncludeMode, ChildrenMode, ScanOptions};
+use tokmd_settings::{AvgMeasure, ChildIncludeMode, ChildrenMode, ScanOptions};
 use tokmd_types::{
     ExportArgs, ExportData, ExportFormat, FileKind, FileRow, LangArgs, LangReport, LangRow,
     ModuleArgs, ModuleReport, ModuleRow, RedactMode, TableFormat, Totals,
@@ -41,6 +41,9 @@ fn lang_args(format: TableFormat) -> LangArgs {
         top: 0,
         files: false,
         children: ChildrenMode::Collapse,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     }
 }
 
@@ -52,6 +55,10 @@ fn module_args(format: TableFormat) -> ModuleArgs {
         module_roots: vec![],
         module_depth: 1,
         children: ChildIncludeMode::Separate,
+        collapse_single_child: false,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     }
 }
 
@@ -68,6 +75,10 @@ fn export_args(format: ExportFormat) -> ExportArgs {
         redact: RedactMode::None,
         meta: false,
         strip_prefix: None,
+        filters: Default::default(),
+        sort_by: None,
+        sort_dir: None,
+        summary: false,
     }
 }
 
@@ -112,6 +123,10 @@ fn embedded_lang_report() -> LangReport {
                 bytes: 20000,
                 tokens: 1000,
                 avg_lines: 100,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
             LangRow {
                 lang: "JavaScript (embedded)".into(),
@@ -121,6 +136,10 @@ fn embedded_lang_report() -> LangReport {
                 bytes: 6000,
                 tokens: 300,
                 avg_lines: 30,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
             LangRow {
                 lang: "CSS (embedded)".into(),
@@ -130,6 +149,10 @@ fn embedded_lang_report() -> LangReport {
                 bytes: 4000,
                 tokens: 200,
                 avg_lines: 33,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
         ],
         total: Totals {
@@ -139,6 +162,9 @@ fn embedded_lang_report() -> LangReport {
             bytes: 30000,
             tokens: 1500,
             avg_lines: 58,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: true,
         children: ChildrenMode::Separate,
@@ -167,6 +193,9 @@ fn snapshot_lang_json_embedded_rows() {
         top: 0,
         files: true,
         children: ChildrenMode::Separate,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     };
     write_lang_report_to(&mut buf, &embedded_lang_report(), &global(), &args)
         .expect("operation must succeed");
@@ -189,6 +218,10 @@ fn unicode_lang_report() -> LangReport {
                 bytes: 15000,
                 tokens: 750,
                 avg_lines: 133,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
             LangRow {
                 lang: "Ελληνικά".into(),
@@ -198,6 +231,10 @@ fn unicode_lang_report() -> LangReport {
                 bytes: 10000,
                 tokens: 500,
                 avg_lines: 125,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
             LangRow {
                 lang: "Ру́сский".into(),
@@ -207,6 +244,10 @@ fn unicode_lang_report() -> LangReport {
                 bytes: 5000,
                 tokens: 250,
                 avg_lines: 120,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
         ],
         total: Totals {
@@ -216,6 +257,9 @@ fn unicode_lang_report() -> LangReport {
             bytes: 30000,
             tokens: 1500,
             avg_lines: 128,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: false,
         children: ChildrenMode::Collapse,
@@ -246,6 +290,11 @@ fn unicode_module_report() -> ModuleReport {
                 bytes: 12500,
                 tokens: 625,
                 avg_lines: 100,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
+                children: Vec::new(),
             },
             ModuleRow {
                 module: "src/données".into(),
@@ -255,6 +304,11 @@ fn unicode_module_report() -> ModuleReport {
                 bytes: 7500,
                 tokens: 375,
                 avg_lines: 90,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
+                children: Vec::new(),
             },
         ],
         total: Totals {
@@ -264,6 +318,9 @@ fn unicode_module_report() -> ModuleReport {
             bytes: 20000,
             tokens: 1000,
             avg_lines: 96,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         module_roots: vec!["src".into()],
         module_depth: 2,
@@ -299,6 +356,10 @@ fn zero_value_lang_report() -> LangReport {
                 bytes: 0,
                 tokens: 0,
                 avg_lines: 0,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
             LangRow {
                 lang: "Python".into(),
@@ -308,6 +369,10 @@ fn zero_value_lang_report() -> LangReport {
                 bytes: 0,
                 tokens: 0,
                 avg_lines: 0,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
         ],
         total: Totals {
@@ -317,6 +382,9 @@ fn zero_value_lang_report() -> LangReport {
             bytes: 0,
             tokens: 0,
             avg_lines: 0,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: true,
         children: ChildrenMode::Collapse,
@@ -346,6 +414,11 @@ fn zero_value_module_report() -> ModuleReport {
             bytes: 0,
             tokens: 0,
             avg_lines: 0,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
+            children: Vec::new(),
         }],
         total: Totals {
             code: 0,
@@ -354,6 +427,9 @@ fn zero_value_module_report() -> ModuleReport {
             bytes: 0,
             tokens: 0,
             avg_lines: 0,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         module_roots: vec![],
         module_depth: 1,
@@ -389,6 +465,10 @@ fn multi_lang_report() -> LangReport {
                 bytes: 25000,
                 tokens: 1250,
                 avg_lines: 120,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
             LangRow {
                 lang: "Go".into(),
@@ -398,6 +478,10 @@ fn multi_lang_report() -> LangReport {
                 bytes: 15000,
                 tokens: 750,
                 avg_lines: 120,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
             LangRow {
                 lang: "Python".into(),
@@ -407,6 +491,10 @@ fn multi_lang_report() -> LangReport {
                 bytes: 10000,
                 tokens: 500,
                 avg_lines: 120,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
         ],
         total: Totals {
@@ -416,6 +504,9 @@ fn multi_lang_report() -> LangReport {
             bytes: 50000,
             tokens: 2500,
             avg_lines: 120,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: true,
         children: ChildrenMode::Collapse,
@@ -492,6 +583,11 @@ fn snapshot_module_md_forward_slash_paths() {
                 bytes: 20000,
                 tokens: 1000,
                 avg_lines: 125,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
+                children: Vec::new(),
             },
             ModuleRow {
                 module: "crates/tokmd-types/src".into(),
@@ -501,6 +597,11 @@ fn snapshot_module_md_forward_slash_paths() {
                 bytes: 15000,
                 tokens: 750,
                 avg_lines: 120,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
+                children: Vec::new(),
             },
         ],
         total: Totals {
@@ -510,6 +611,9 @@ fn snapshot_module_md_forward_slash_paths() {
             bytes: 35000,
             tokens: 1750,
             avg_lines: 123,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         module_roots: vec!["crates".into()],
         module_depth: 3,
@@ -539,6 +643,11 @@ fn many_module_report() -> ModuleReport {
             bytes: (10 - i) * 5000,
             tokens: (10 - i) * 250,
             avg_lines: 120,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
+            children: Vec::new(),
         })
         .collect();
     let total = Totals {
@@ -548,6 +657,9 @@ fn many_module_report() -> ModuleReport {
         bytes: rows.iter().map(|r| r.bytes).sum(),
         tokens: rows.iter().map(|r| r.tokens).sum(),
         avg_lines: 120,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
     };
     ModuleReport {
         rows,
@@ -582,6 +694,10 @@ fn snapshot_module_json_many() {
         module_roots: vec!["crates".into()],
         module_depth: 2,
         children: ChildIncludeMode::Separate,
+        collapse_single_child: false,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     };
     write_module_report_to(&mut buf, &report, &global(), &args).expect("operation must succeed");
     let pretty = normalise_json(&String::from_utf8(buf).expect("output must be valid UTF-8"));
@@ -605,6 +721,9 @@ fn sample_file_rows() -> Vec<FileRow> {
             lines: 260,
             bytes: 10000,
             tokens: 500,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         },
         FileRow {
             path: "templates/index.html".into(),
@@ -617,6 +736,9 @@ fn sample_file_rows() -> Vec<FileRow> {
             lines: 95,
             bytes: 4000,
             tokens: 200,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         },
         FileRow {
             path: "templates/index.html".into(),
@@ -629,6 +751,9 @@ fn sample_file_rows() -> Vec<FileRow> {
             lines: 35,
             bytes: 1500,
             tokens: 75,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         },
         FileRow {
             path: "templates/index.html".into(),
@@ -641,6 +766,9 @@ fn sample_file_rows() -> Vec<FileRow> {
             lines: 23,
             bytes: 1000,
             tokens: 50,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         },
     ]
 }
@@ -716,6 +844,9 @@ fn snapshot_export_csv_special_chars() {
                 lines: 58,
                 bytes: 2500,
                 tokens: 125,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             },
             FileRow {
                 path: r#"src/has "quotes".rs"#.into(),
@@ -728,6 +859,9 @@ fn snapshot_export_csv_special_chars() {
                 lines: 33,
                 bytes: 1500,
                 tokens: 75,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             },
         ],
         module_roots: vec!["src".into()],
@@ -759,6 +893,9 @@ fn snapshot_cyclonedx_redacted() {
             lines: 115,
             bytes: 5000,
             tokens: 250,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         }],
         module_roots: vec!["src".into()],
         module_depth: 2,
@@ -820,6 +957,10 @@ fn snapshot_diff_many_languages() {
                 bytes: 50000,
                 tokens: 2500,
                 avg_lines: 120,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
             LangRow {
                 lang: "C++".into(),
@@ -829,6 +970,10 @@ fn snapshot_diff_many_languages() {
                 bytes: 40000,
                 tokens: 2000,
                 avg_lines: 120,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
             LangRow {
                 lang: "Makefile".into(),
@@ -838,6 +983,10 @@ fn snapshot_diff_many_languages() {
                 bytes: 2500,
                 tokens: 125,
                 avg_lines: 30,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
             LangRow {
                 lang: "Shell".into(),
@@ -847,6 +996,10 @@ fn snapshot_diff_many_languages() {
                 bytes: 1500,
                 tokens: 75,
                 avg_lines: 36,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
         ],
         total: Totals {
@@ -856,6 +1009,9 @@ fn snapshot_diff_many_languages() {
             bytes: 94000,
             tokens: 4700,
             avg_lines: 107,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: false,
         children: ChildrenMode::Collapse,
@@ -871,6 +1027,10 @@ fn snapshot_diff_many_languages() {
                 bytes: 60000,
                 tokens: 3000,
                 avg_lines: 120,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
             LangRow {
                 lang: "Rust".into(),
@@ -880,6 +1040,10 @@ fn snapshot_diff_many_languages() {
                 bytes: 25000,
                 tokens: 1250,
                 avg_lines: 120,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
             LangRow {
                 lang: "Makefile".into(),
@@ -889,6 +1053,10 @@ fn snapshot_diff_many_languages() {
                 bytes: 2500,
                 tokens: 125,
                 avg_lines: 30,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
             LangRow {
                 lang: "CMake".into(),
@@ -898,6 +1066,10 @@ fn snapshot_diff_many_languages() {
                 bytes: 2000,
                 tokens: 100,
                 avg_lines: 48,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
         ],
         total: Totals {
@@ -907,6 +1079,9 @@ fn snapshot_diff_many_languages() {
             bytes: 89500,
             tokens: 4475,
             avg_lines: 107,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: false,
         children: ChildrenMode::Collapse,
@@ -1011,6 +1186,9 @@ fn snapshot_diff_all_removed() {
             bytes: 0,
             tokens: 0,
             avg_lines: 0,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: false,
         children: ChildrenMode::Collapse,
@@ -1033,6 +1211,9 @@ fn snapshot_diff_all_added() {
             bytes: 0,
             tokens: 0,
             avg_lines: 0,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: false,
         children: ChildrenMode::Collapse,
@@ -1091,6 +1272,10 @@ fn snapshot_lang_md_many_with_files() {
             bytes: (5 - i) * 10000,
             tokens: (5 - i) * 500,
             avg_lines: 120,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         })
         .collect();
     let total = Totals {
@@ -1100,6 +1285,9 @@ fn snapshot_lang_md_many_with_files() {
         bytes: rows.iter().map(|r| r.bytes).sum(),
         tokens: rows.iter().map(|r| r.tokens).sum(),
         avg_lines: 120,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
     };
     let report = LangReport {
         rows,
@@ -10,7 +10,7 @@ use tokmd_format::{
     compute_diff_rows, compute_diff_totals, render_diff_md, write_export_csv_to,
     write_export_json_to, write_export_jsonl_to, write_lang_report_to, write_module_report_to,
 };
-use tokmd_settings::{ChildIncludeMode, ChildrenMode, ScanOptions};
+use tokmd_settings::{AvgMeasure, ChildIncludeMode, ChildrenMode, ScanOptions};
 use tokmd_types::{
     ExportArgs, ExportData, ExportFormat, FileKind, FileRow, LangArgs, LangReport, LangRow,
     ModuleArgs, ModuleReport, ModuleRow, RedactMode, TableFormat, Totals,
@@ -31,6 +31,10 @@ fn five_lang_report() -> LangReport {
                 bytes: 200000,
                 tokens: 12500,
                 avg_lines: 150,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
             LangRow {
                 lang: "Python".into(),
@@ -40,6 +44,10 @@ fn five_lang_report() -> LangReport {
                 bytes: 72000,
                 tokens: 5000,
                 avg_lines: 167,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
             LangRow {
                 lang: "JavaScript".into(),
@@ -49,6 +57,10 @@ fn five_lang_report() -> LangReport {
                 bytes: 54000,
                 tokens: 3750,
                 avg_lines: 150,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
             LangRow {
                 lang: "TOML".into(),
@@ -58,6 +70,10 @@ fn five_lang_report() -> LangReport {
                 bytes: 12000,
                 tokens: 1000,
                 avg_lines: 63,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
             LangRow {
                 lang: "Markdown".into(),
@@ -67,6 +83,10 @@ fn five_lang_report() -> LangReport {
                 bytes: 12000,
                 tokens: 750,
                 avg_lines: 67,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
             LangRow {
                 lang: "YAML".into(),
@@ -76,6 +96,10 @@ fn five_lang_report() -> LangReport {
                 bytes: 3600,
                 tokens: 250,
                 avg_lines: 40,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
         ],
         total: Totals {
@@ -85,6 +109,9 @@ fn five_lang_report() -> LangReport {
             bytes: 353600,
             tokens: 23250,
             avg_lines: 135,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: true,
         children: ChildrenMode::Collapse,
@@ -103,6 +130,11 @@ fn nested_module_report() -> ModuleReport {
                 bytes: 120000,
                 tokens: 7500,
                 avg_lines: 180,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
+                children: Vec::new(),
             },
             ModuleRow {
                 module: "src/parser".into(),
@@ -112,6 +144,11 @@ fn nested_module_report() -> ModuleReport {
                 bytes: 48000,
                 tokens: 3000,
                 avg_lines: 180,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
+                children: Vec::new(),
             },
             ModuleRow {
                 module: "src/format".into(),
@@ -121,6 +158,11 @@ fn nested_module_report() -> ModuleReport {
                 bytes: 32000,
                 tokens: 2000,
                 avg_lines: 192,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
+                children: Vec::new(),
             },
             ModuleRow {
                 module: "tests".into(),
@@ -130,6 +172,11 @@ fn nested_module_report() -> ModuleReport {
                 bytes: 24000,
                 tokens: 1500,
                 avg_lines: 180,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
+                children: Vec::new(),
             },
         ],
         total: Totals {
@@ -139,6 +186,9 @@ fn nested_module_report() -> ModuleReport {
             bytes: 224000,
             tokens: 14000,
             avg_lines: 182,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         module_roots: vec!["src".into()],
         module_depth: 2,
@@ -161,6 +211,9 @@ fn multi_file_export() -> ExportData {
                 lines: 620,
                 bytes: 18600,
                 tokens: 1250,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             },
             FileRow {
                 path: "src/parser.rs".into(),
@@ -173,6 +226,9 @@ fn multi_file_export() -> ExportData {
                 lines: 430,
                 bytes: 12900,
                 tokens: 875,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             },
             FileRow {
                 path: "src/format.rs".into(),
@@ -185,6 +241,9 @@ fn multi_file_export() -> ExportData {
                 lines: 250,
                 bytes: 7500,
                 tokens: 500,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             },
             FileRow {
                 path: "tests/integration.rs".into(),
@@ -197,6 +256,9 @@ fn multi_file_export() -> ExportData {
                 lines: 175,
                 bytes: 5250,
                 tokens: 375,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             },
         ],
         module_roots: vec!["src".into()],
@@ -220,6 +282,9 @@ fn snapshot_lang_md_five_languages() {
         top: 0,
         files: true,
         children: ChildrenMode::Collapse,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     };
     let mut buf = Vec::new();
     write_lang_report_to(&mut buf, &report, &default_scan_options(), &args)
@@ -239,6 +304,10 @@ fn snapshot_module_md_nested() {
         module_roots: vec!["src".into()],
         module_depth: 2,
         children: ChildIncludeMode::Separate,
+        collapse_single_child: false,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     };
     let mut buf = Vec::new();
     write_module_report_to(&mut buf, &report, &default_scan_options(), &args)
@@ -257,6 +326,9 @@ fn snapshot_lang_tsv_five_languages() {
         top: 0,
         files: true,
         children: ChildrenMode::Collapse,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     };
     let mut buf = Vec::new();
     write_lang_report_to(&mut buf, &report, &default_scan_options(), &args)
@@ -281,6 +353,10 @@ fn snapshot_export_csv_header() {
         redact: RedactMode::None,
         meta: false,
         strip_prefix: None,
+        filters: Default::default(),
+        sort_by: None,
+        sort_dir: None,
+        summary: false,
     };
     let mut buf = Vec::new();
     write_export_csv_to(&mut buf, &data, &args).expect("operation must succeed");
@@ -304,6 +380,10 @@ fn snapshot_export_json_envelope() {
         redact: RedactMode::None,
         meta: false,
         strip_prefix: None,
+        filters: Default::default(),
+        sort_by: None,
+        sort_dir: None,
+        summary: false,
     };
     let mut buf = Vec::new();
     write_export_json_to(&mut buf, &data, &default_scan_options(), &args)
@@ -324,6 +404,9 @@ fn snapshot_lang_md_top3_with_other() {
         top: 3,
         files: true,
         children: ChildrenMode::Collapse,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     };
     let mut buf = Vec::new();
     write_lang_report_to(&mut buf, &report, &default_scan_options(), &args)
@@ -348,6 +431,10 @@ fn snapshot_export_jsonl() {
         redact: RedactMode::None,
         meta: false,
         strip_prefix: None,
+        filters: Default::default(),
+        sort_by: None,
+        sort_dir: None,
+        summary: false,
     };
     let mut buf = Vec::new();
     write_export_jsonl_to(&mut buf, &data, &default_scan_options(), &args)
@@ -367,6 +454,10 @@ fn snapshot_module_tsv_nested() {
         module_roots: vec!["src".into()],
         module_depth: 2,
         children: ChildIncludeMode::Separate,
+        collapse_single_child: false,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     };
     let mut buf = Vec::new();
     write_module_report_to(&mut buf, &report, &default_scan_options(), &args)
@@ -386,6 +477,10 @@ fn snapshot_module_json_nested() {
         module_roots: vec!["src".into()],
         module_depth: 2,
         children: ChildIncludeMode::Separate,
+        collapse_single_child: false,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     };
     let mut buf = Vec::new();
     write_module_report_to(&mut buf, &report, &default_scan_options(), &args)
@@ -411,6 +506,10 @@ fn snapshot_diff_md_removed_language() {
                 bytes: 80000,
                 tokens: 5000,
                 avg_lines: 120,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
             LangRow {
                 lang: "Python".into(),
@@ -420,6 +519,10 @@ fn snapshot_diff_md_removed_language() {
                 bytes: 18000,
                 tokens: 1250,
                 avg_lines: 120,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
         ],
         total: Totals {
@@ -429,6 +532,9 @@ fn snapshot_diff_md_removed_language() {
             bytes: 98000,
             tokens: 6250,
             avg_lines: 120,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: false,
         children: ChildrenMode::Collapse,
@@ -443,6 +549,10 @@ fn snapshot_diff_md_removed_language() {
             bytes: 100000,
             tokens: 6250,
             avg_lines: 120,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         }],
         total: Totals {
             code: 2500,
@@ -451,6 +561,9 @@ fn snapshot_diff_md_removed_language() {
             bytes: 100000,
             tokens: 6250,
             avg_lines: 120,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: false,
         children: ChildrenMode::Collapse,
@@ -474,6 +587,9 @@ fn snapshot_lang_md_no_files() {
         top: 0,
         files: false,
         children: ChildrenMode::Collapse,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     };
     let mut buf = Vec::new();
     write_lang_report_to(&mut buf, &report, &default_scan_options(), &args)
@@ -498,6 +614,10 @@ fn snapshot_export_csv_min_code() {
         redact: RedactMode::None,
         meta: false,
         strip_prefix: None,
+        filters: Default::default(),
+        sort_by: None,
+        sort_dir: None,
+        summary: false,
     };
     let mut buf = Vec::new();
     write_export_csv_to(&mut buf, &data, &args).expect("operation must succeed");
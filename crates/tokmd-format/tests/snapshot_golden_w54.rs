@@ -9,7 +9,7 @@ use tokmd_format::{
     write_export_csv_to, write_export_json_to, write_export_jsonl_to, write_lang_report_to,
     write_module_report_to,
 };
-use tokmd_settings::{ChildIncludeMode, ChildrenMode, ScanOptions};
+use tokmd_settings::{AvgMeasure, ChildIncludeMode, ChildrenMode, ScanOptions};
 use tokmd_types::{
     ExportArgs, ExportData, ExportFormat, FileKind, FileRow, LangArgs, LangReport, LangRow,
     ModuleArgs, ModuleReport, ModuleRow, RedactMode, TableFormat, Totals,
@@ -30,6 +30,10 @@ fn two_lang_report(with_files: bool) -> LangReport {
                 bytes: 180_000,
                 tokens: 45_000,
                 avg_lines: 154,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
             LangRow {
                 lang: "Python".into(),
@@ -39,6 +43,10 @@ fn two_lang_report(with_files: bool) -> LangReport {
                 bytes: 36_000,
                 tokens: 12_000,
                 avg_lines: 187,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
         ],
         total: Totals {
@@ -48,6 +56,9 @@ fn two_lang_report(with_files: bool) -> LangReport {
             bytes: 216_000,
             tokens: 57_000,
             avg_lines: 160,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files,
         children: ChildrenMode::Collapse,
@@ -65,6 +76,10 @@ fn single_lang_report() -> LangReport {
             bytes: 9000,
             tokens: 3000,
             avg_lines: 133,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         }],
         total: Totals {
             code: 300,
@@ -73,6 +88,9 @@ fn single_lang_report() -> LangReport {
             bytes: 9000,
             tokens: 3000,
             avg_lines: 133,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: true,
         children: ChildrenMode::Collapse,
@@ -100,6 +118,10 @@ fn many_lang_report() -> LangReport {
             bytes: code * 30,
             tokens: code * 8,
             avg_lines: 125,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         })
         .collect();
     let total = Totals {
@@ -109,6 +131,9 @@ fn many_lang_report() -> LangReport {
         bytes: rows.iter().map(|r| r.bytes).sum(),
         tokens: rows.iter().map(|r| r.tokens).sum(),
         avg_lines: 125,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
     };
     LangReport {
         rows,
@@ -129,6 +154,9 @@ fn empty_lang_report() -> LangReport {
             bytes: 0,
             tokens: 0,
             avg_lines: 0,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: false,
         children: ChildrenMode::Collapse,
@@ -147,6 +175,10 @@ fn embedded_lang_report_separate() -> LangReport {
                 bytes: 12_000,
                 tokens: 4_000,
                 avg_lines: 100,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
             LangRow {
                 lang: "JavaScript (embedded)".into(),
@@ -156,6 +188,10 @@ fn embedded_lang_report_separate() -> LangReport {
                 bytes: 6_000,
                 tokens: 2_000,
                 avg_lines: 50,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
             LangRow {
                 lang: "CSS (embedded)".into(),
@@ -165,6 +201,10 @@ fn embedded_lang_report_separate() -> LangReport {
                 bytes: 3_000,
                 tokens: 1_000,
                 avg_lines: 40,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
         ],
         total: Totals {
@@ -174,6 +214,9 @@ fn embedded_lang_report_separate() -> LangReport {
             bytes: 21_000,
             tokens: 7_000,
             avg_lines: 66,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: true,
         children: ChildrenMode::Separate,
@@ -191,6 +234,10 @@ fn embedded_lang_report_collapse() -> LangReport {
             bytes: 21_000,
             tokens: 7_000,
             avg_lines: 174,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         }],
         total: Totals {
             code: 700,
@@ -199,6 +246,9 @@ fn embedded_lang_report_collapse() -> LangReport {
             bytes: 21_000,
             tokens: 7_000,
             avg_lines: 174,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: true,
         children: ChildrenMode::Collapse,
@@ -217,6 +267,11 @@ fn sample_module_report() -> ModuleReport {
                 bytes: 90_000,
                 tokens: 24_000,
                 avg_lines: 253,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
+                children: Vec::new(),
             },
             ModuleRow {
                 module: "crates/cli".into(),
@@ -226,6 +281,11 @@ fn sample_module_report() -> ModuleReport {
                 bytes: 45_000,
                 tokens: 12_000,
                 avg_lines: 271,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
+                children: Vec::new(),
             },
             ModuleRow {
                 module: "tests".into(),
@@ -235,6 +295,11 @@ fn sample_module_report() -> ModuleReport {
                 bytes: 24_000,
                 tokens: 6_400,
                 avg_lines: 250,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
+                children: Vec::new(),
             },
         ],
         total: Totals {
@@ -244,6 +309,9 @@ fn sample_module_report() -> ModuleReport {
             bytes: 159_000,
             tokens: 42_400,
             avg_lines: 257,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         module_roots: vec!["crates".into()],
         module_depth: 2,
@@ -262,6 +330,9 @@ fn empty_module_report() -> ModuleReport {
             bytes: 0,
             tokens: 0,
             avg_lines: 0,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         module_roots: vec![],
         module_depth: 1,
@@ -283,6 +354,9 @@ fn sample_file_rows() -> Vec<FileRow> {
             lines: 320,
             bytes: 8000,
             tokens: 2500,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         },
         FileRow {
             path: "src/lib.rs".into(),
@@ -295,6 +369,9 @@ fn sample_file_rows() -> Vec<FileRow> {
             lines: 225,
             bytes: 5400,
             tokens: 1800,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         },
         FileRow {
             path: "tests/smoke.rs".into(),
@@ -307,6 +384,9 @@ fn sample_file_rows() -> Vec<FileRow> {
             lines: 73,
             bytes: 1800,
             tokens: 600,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         },
     ]
 }
@@ -331,6 +411,9 @@ fn lang_args(format: TableFormat) -> LangArgs {
         top: 0,
         files: true,
         children: ChildrenMode::Collapse,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     }
 }
 
@@ -342,6 +425,10 @@ fn module_args(format: TableFormat) -> ModuleArgs {
         module_roots: vec![],
         module_depth: 2,
         children: ChildIncludeMode::Separate,
+        collapse_single_child: false,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     }
 }
 
@@ -358,6 +445,10 @@ fn export_args(format: ExportFormat) -> ExportArgs {
         redact: RedactMode::None,
         meta: false,
         strip_prefix: None,
+        filters: Default::default(),
+        sort_by: None,
+        sort_dir: None,
+        summary: false,
     }
 }
 
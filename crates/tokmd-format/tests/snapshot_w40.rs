@@ -1,8 +1,8 @@
 use tokmd_format::{write_lang_report_to, write_module_report_to};
 use tokmd_settings::ScanOptions;
 use tokmd_types::{
-    ChildIncludeMode, ChildrenMode, LangArgs, LangReport, LangRow, ModuleArgs, ModuleReport,
-    ModuleRow, TableFormat, Totals,
+    AvgMeasure, ChildIncludeMode, ChildrenMode, LangArgs, LangReport, LangRow, ModuleArgs,
+    ModuleReport, ModuleRow, TableFormat, Totals,
 };
 
 fn sample_lang_report() -> LangReport {
@@ -16,6 +16,10 @@ fn sample_lang_report() -> LangReport {
                 bytes: 180000,
                 tokens: 12500,
                 avg_lines: 148,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
             LangRow {
                 lang: "Python".to_string(),
@@ -25,6 +29,10 @@ fn sample_lang_report() -> LangReport {
                 bytes: 36000,
                 tokens: 3000,
                 avg_lines: 188,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
             LangRow {
                 lang: "TOML".to_string(),
@@ -34,6 +42,10 @@ fn sample_lang_report() -> LangReport {
                 bytes: 2400,
                 tokens: 200,
                 avg_lines: 33,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
         ],
         total: Totals {
@@ -43,6 +55,9 @@ fn sample_lang_report() -> LangReport {
             bytes: 218400,
             tokens: 15700,
             avg_lines: 147,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: true,
         children: ChildrenMode::Collapse,
@@ -61,6 +76,11 @@ fn sample_module_report() -> ModuleReport {
                 bytes: 120000,
                 tokens: 7500,
                 avg_lines: 152,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
+                children: Vec::new(),
             },
             ModuleRow {
                 module: "crates/cli".to_string(),
@@ -70,6 +90,11 @@ fn sample_module_report() -> ModuleReport {
                 bytes: 60000,
                 tokens: 5000,
                 avg_lines: 160,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
+                children: Vec::new(),
             },
             ModuleRow {
                 module: "tests".to_string(),
@@ -79,6 +104,11 @@ fn sample_module_report() -> ModuleReport {
                 bytes: 38400,
                 tokens: 3200,
                 avg_lines: 123,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
+                children: Vec::new(),
             },
         ],
         total: Totals {
@@ -88,6 +118,9 @@ fn sample_module_report() -> ModuleReport {
             bytes: 218400,
             tokens: 15700,
             avg_lines: 147,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         module_roots: vec!["crates".to_string()],
         module_depth: 2,
@@ -107,6 +140,9 @@ fn snapshot_lang_md_table() {
         top: 0,
         files: true,
         children: ChildrenMode::Collapse,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     };
     let mut buf = Vec::new();
     write_lang_report_to(&mut buf, &report, &ScanOptions::default(), &args)
@@ -125,6 +161,9 @@ fn snapshot_lang_tsv() {
         top: 0,
         files: true,
         children: ChildrenMode::Collapse,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     };
     let mut buf = Vec::new();
     write_lang_report_to(&mut buf, &report, &ScanOptions::default(), &args)
@@ -143,6 +182,9 @@ fn snapshot_lang_json_envelope() {
         top: 0,
         files: true,
         children: ChildrenMode::Collapse,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     };
     let mut buf = Vec::new();
     write_lang_report_to(&mut buf, &report, &ScanOptions::default(), &args)
@@ -167,6 +209,10 @@ fn snapshot_module_md_breakdown() {
         module_roots: vec!["crates".into()],
         module_depth: 2,
         children: ChildIncludeMode::Separate,
+        collapse_single_child: false,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     };
     let mut buf = Vec::new();
     write_module_report_to(&mut buf, &report, &ScanOptions::default(), &args)
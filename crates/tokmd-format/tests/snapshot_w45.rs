@@ -10,7 +10,7 @@ use tokmd_format::{
     write_export_csv_to, write_export_cyclonedx_with_options, write_lang_report_to,
     write_module_report_to,
 };
-use tokmd_settings::{ChildIncludeMode, ChildrenMode, ScanOptions};
+use tokmd_settings::{AvgMeasure, ChildIncludeMode, ChildrenMode, ScanOptions};
 use tokmd_types::{
     ExportArgs, ExportData, ExportFormat, FileKind, FileRow, LangArgs, LangReport, LangRow,
     ModuleArgs, ModuleReport, ModuleRow, RedactMode, TableFormat, Totals,
@@ -30,6 +30,11 @@ fn single_module_report() -> ModuleReport {
             bytes: 32000,
             tokens: 2000,
             avg_lines: 125,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
+            children: Vec::new(),
         }],
         total: Totals {
             code: 800,
@@ -38,6 +43,9 @@ fn single_module_report() -> ModuleReport {
             bytes: 32000,
             tokens: 2000,
             avg_lines: 125,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         module_roots: vec!["src".into()],
         module_depth: 1,
@@ -57,6 +65,10 @@ fn multi_lang_report() -> LangReport {
                 bytes: 120000,
                 tokens: 7500,
                 avg_lines: 120,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
             LangRow {
                 lang: "Python".into(),
@@ -66,6 +78,10 @@ fn multi_lang_report() -> LangReport {
                 bytes: 54000,
                 tokens: 3750,
                 avg_lines: 150,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
             LangRow {
                 lang: "TOML".into(),
@@ -75,6 +91,10 @@ fn multi_lang_report() -> LangReport {
                 bytes: 6000,
                 tokens: 500,
                 avg_lines: 50,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
             LangRow {
                 lang: "Markdown".into(),
@@ -84,6 +104,10 @@ fn multi_lang_report() -> LangReport {
                 bytes: 4500,
                 tokens: 250,
                 avg_lines: 50,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
         ],
         total: Totals {
@@ -93,6 +117,9 @@ fn multi_lang_report() -> LangReport {
             bytes: 184500,
             tokens: 12000,
             avg_lines: 116,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: true,
         children: ChildrenMode::Collapse,
@@ -113,6 +140,9 @@ fn single_file_export() -> ExportData {
             lines: 145,
             bytes: 4350,
             tokens: 300,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         }],
         module_roots: vec!["src".into()],
         module_depth: 1,
@@ -134,6 +164,9 @@ fn multi_file_export() -> ExportData {
                 lines: 620,
                 bytes: 18600,
                 tokens: 1250,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             },
             FileRow {
                 path: "src/utils.rs".into(),
@@ -146,6 +179,9 @@ fn multi_file_export() -> ExportData {
                 lines: 250,
                 bytes: 7500,
                 tokens: 500,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             },
             FileRow {
                 path: "tests/integration.rs".into(),
@@ -158,6 +194,9 @@ fn multi_file_export() -> ExportData {
                 lines: 175,
                 bytes: 5250,
                 tokens: 375,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             },
         ],
         module_roots: vec!["src".into()],
@@ -178,6 +217,10 @@ fn snapshot_module_tsv_single() {
         module_roots: vec!["src".into()],
         module_depth: 1,
         children: ChildIncludeMode::Separate,
+        collapse_single_child: false,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     };
     let mut buf = Vec::new();
     write_module_report_to(&mut buf, &report, &ScanOptions::default(), &args)
@@ -197,6 +240,10 @@ fn snapshot_module_json_single() {
         module_roots: vec!["src".into()],
         module_depth: 1,
         children: ChildIncludeMode::Separate,
+        collapse_single_child: false,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     };
     let mut buf = Vec::new();
     write_module_report_to(&mut buf, &report, &ScanOptions::default(), &args)
@@ -225,6 +272,10 @@ fn snapshot_export_csv_single_file() {
         redact: RedactMode::None,
         meta: false,
         strip_prefix: None,
+        filters: Default::default(),
+        sort_by: None,
+        sort_dir: None,
+        summary: false,
     };
     let mut buf = Vec::new();
     write_export_csv_to(&mut buf, &data, &args).expect("operation must succeed");
@@ -248,6 +299,10 @@ fn snapshot_export_csv_multi_file() {
         redact: RedactMode::None,
         meta: false,
         strip_prefix: None,
+        filters: Default::default(),
+        sort_by: None,
+        sort_dir: None,
+        summary: false,
     };
     let mut buf = Vec::new();
     write_export_csv_to(&mut buf, &data, &args).expect("operation must succeed");
@@ -284,6 +339,10 @@ fn snapshot_diff_json_no_changes() {
             bytes: 25000,
             tokens: 1250,
             avg_lines: 120,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         }],
         total: Totals {
             code: 500,
@@ -292,6 +351,9 @@ fn snapshot_diff_json_no_changes() {
             bytes: 25000,
             tokens: 1250,
             avg_lines: 120,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: false,
         children: ChildrenMode::Collapse,
@@ -319,6 +381,9 @@ fn snapshot_lang_md_top2() {
         top: 2,
         files: true,
         children: ChildrenMode::Collapse,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     };
     let mut buf = Vec::new();
     write_lang_report_to(&mut buf, &report, &ScanOptions::default(), &args)
@@ -339,6 +404,10 @@ fn snapshot_diff_md_new_language() {
             bytes: 40000,
             tokens: 2500,
             avg_lines: 120,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         }],
         total: Totals {
             code: 1000,
@@ -347,6 +416,9 @@ fn snapshot_diff_md_new_language() {
             bytes: 40000,
             tokens: 2500,
             avg_lines: 120,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: false,
         children: ChildrenMode::Collapse,
@@ -362,6 +434,10 @@ fn snapshot_diff_md_new_language() {
                 bytes: 48000,
                 tokens: 3000,
                 avg_lines: 120,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
             LangRow {
                 lang: "Python".into(),
@@ -371,6 +447,10 @@ fn snapshot_diff_md_new_language() {
                 bytes: 10800,
                 tokens: 750,
                 avg_lines: 120,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
         ],
         total: Totals {
@@ -380,6 +460,9 @@ fn snapshot_diff_md_new_language() {
             bytes: 58800,
             tokens: 3750,
             avg_lines: 120,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: false,
         children: ChildrenMode::Collapse,
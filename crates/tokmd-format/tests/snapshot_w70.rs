@@ -8,7 +8,7 @@ use std::path::PathBuf;
 use tokmd_format::{
     write_export_csv_to, write_export_jsonl_to, write_lang_report_to, write_module_report_to,
 };
-use tokmd_settings::{ChildIncludeMode, ChildrenMode, ScanOptions};
+use tokmd_settings::{AvgMeasure, ChildIncludeMode, ChildrenMode, ScanOptions};
 use tokmd_types::{
     ExportArgs, ExportData, ExportFormat, FileKind, FileRow, LangArgs, LangReport, LangRow,
     ModuleArgs, ModuleReport, ModuleRow, RedactMode, TableFormat, Totals,
@@ -29,6 +29,10 @@ fn two_lang_report(with_files: bool) -> LangReport {
                 bytes: 150_000,
                 tokens: 38_000,
                 avg_lines: 157,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
             LangRow {
                 lang: "Python".into(),
@@ -38,6 +42,10 @@ fn two_lang_report(with_files: bool) -> LangReport {
                 bytes: 54_000,
                 tokens: 18_000,
                 avg_lines: 191,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
         ],
         total: Totals {
@@ -47,6 +55,9 @@ fn two_lang_report(with_files: bool) -> LangReport {
             bytes: 204_000,
             tokens: 56_000,
             avg_lines: 165,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files,
         children: ChildrenMode::Collapse,
@@ -64,6 +75,10 @@ fn single_lang_report() -> LangReport {
             bytes: 22_500,
             tokens: 7_500,
             avg_lines: 158,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         }],
         total: Totals {
             code: 750,
@@ -72,6 +87,9 @@ fn single_lang_report() -> LangReport {
             bytes: 22_500,
             tokens: 7_500,
             avg_lines: 158,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: true,
         children: ChildrenMode::Collapse,
@@ -96,6 +114,10 @@ fn four_lang_report() -> LangReport {
             bytes: code * 25,
             tokens: code * 7,
             avg_lines: 140,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         })
         .collect();
     let total = Totals {
@@ -105,6 +127,9 @@ fn four_lang_report() -> LangReport {
         bytes: rows.iter().map(|r| r.bytes).sum(),
         tokens: rows.iter().map(|r| r.tokens).sum(),
         avg_lines: 140,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
     };
     LangReport {
         rows,
@@ -125,6 +150,9 @@ fn empty_lang_report() -> LangReport {
             bytes: 0,
             tokens: 0,
             avg_lines: 0,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: false,
         children: ChildrenMode::Collapse,
@@ -143,6 +171,10 @@ fn embedded_lang_report_separate() -> LangReport {
                 bytes: 10_500,
                 tokens: 3_500,
                 avg_lines: 112,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
             LangRow {
                 lang: "JavaScript (embedded)".into(),
@@ -152,6 +184,10 @@ fn embedded_lang_report_separate() -> LangReport {
                 bytes: 5_400,
                 tokens: 1_800,
                 avg_lines: 55,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
             LangRow {
                 lang: "CSS (embedded)".into(),
@@ -161,6 +197,10 @@ fn embedded_lang_report_separate() -> LangReport {
                 bytes: 2_700,
                 tokens: 900,
                 avg_lines: 36,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
         ],
         total: Totals {
@@ -170,6 +210,9 @@ fn embedded_lang_report_separate() -> LangReport {
             bytes: 18_600,
             tokens: 6_200,
             avg_lines: 70,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: true,
         children: ChildrenMode::Separate,
@@ -194,6 +237,11 @@ fn three_module_report() -> ModuleReport {
                 bytes: 84_000,
                 tokens: 22_400,
                 avg_lines: 250,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
+                children: Vec::new(),
             },
             ModuleRow {
                 module: "crates/api".into(),
@@ -203,6 +251,11 @@ fn three_module_report() -> ModuleReport {
                 bytes: 42_000,
                 tokens: 11_200,
                 avg_lines: 218,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
+                children: Vec::new(),
             },
             ModuleRow {
                 module: "tests".into(),
@@ -212,6 +265,11 @@ fn three_module_report() -> ModuleReport {
                 bytes: 27_000,
                 tokens: 7_200,
                 avg_lines: 220,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
+                children: Vec::new(),
             },
         ],
         total: Totals {
@@ -221,6 +279,9 @@ fn three_module_report() -> ModuleReport {
             bytes: 153_000,
             tokens: 40_800,
             avg_lines: 235,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         module_roots: vec!["crates".into()],
         module_depth: 2,
@@ -239,6 +300,9 @@ fn empty_module_report() -> ModuleReport {
             bytes: 0,
             tokens: 0,
             avg_lines: 0,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         module_roots: vec![],
         module_depth: 1,
@@ -260,6 +324,9 @@ fn sample_file_rows() -> Vec<FileRow> {
             lines: 400,
             bytes: 9_600,
             tokens: 3_200,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         },
         FileRow {
             path: "src/api.rs".into(),
@@ -272,6 +339,9 @@ fn sample_file_rows() -> Vec<FileRow> {
             lines: 265,
             bytes: 6_300,
             tokens: 2_100,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         },
         FileRow {
             path: "tests/integration.rs".into(),
@@ -284,6 +354,9 @@ fn sample_file_rows() -> Vec<FileRow> {
             lines: 107,
             bytes: 2_550,
             tokens: 850,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         },
     ]
 }
@@ -308,6 +381,9 @@ fn lang_args(format: TableFormat) -> LangArgs {
         top: 0,
         files: true,
         children: ChildrenMode::Collapse,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     }
 }
 
@@ -319,6 +395,10 @@ fn module_args(format: TableFormat) -> ModuleArgs {
         module_roots: vec![],
         module_depth: 2,
         children: ChildIncludeMode::Separate,
+        collapse_single_child: false,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     }
 }
 
@@ -335,6 +415,10 @@ fn export_args(format: ExportFormat) -> ExportArgs {
         redact: RedactMode::None,
         meta: false,
         strip_prefix: None,
+        filters: Default::default(),
+        sort_by: None,
+        sort_dir: None,
+        summary: false,
     }
 }
 
@@ -9,7 +9,7 @@ use tokmd_format::{
     write_export_csv_to, write_export_cyclonedx_with_options, write_export_json_to,
     write_export_jsonl_to, write_lang_report_to, write_module_report_to,
 };
-use tokmd_settings::{ChildIncludeMode, ChildrenMode, ScanOptions};
+use tokmd_settings::{AvgMeasure, ChildIncludeMode, ChildrenMode, ScanOptions};
 use tokmd_types::{
     ExportArgs, ExportData, ExportFormat, FileKind, FileRow, LangArgs, LangReport, LangRow,
     ModuleArgs, ModuleReport, ModuleRow, RedactMode, TableFormat, Totals,
@@ -30,6 +30,10 @@ fn three_lang_report(with_files: bool) -> LangReport {
                 bytes: 182_000,
                 tokens: 46_000,
                 avg_lines: 170,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
             LangRow {
                 lang: "TypeScript".into(),
@@ -39,6 +43,10 @@ fn three_lang_report(with_files: bool) -> LangReport {
                 bytes: 93_000,
                 tokens: 28_000,
                 avg_lines: 160,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
             LangRow {
                 lang: "TOML".into(),
@@ -48,6 +56,10 @@ fn three_lang_report(with_files: bool) -> LangReport {
                 bytes: 12_000,
                 tokens: 3_200,
                 avg_lines: 65,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
         ],
         total: Totals {
@@ -57,6 +69,9 @@ fn three_lang_report(with_files: bool) -> LangReport {
             bytes: 287_000,
             tokens: 77_200,
             avg_lines: 155,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files,
         children: ChildrenMode::Collapse,
@@ -74,6 +89,10 @@ fn single_lang_report() -> LangReport {
             bytes: 27_600,
             tokens: 9_200,
             avg_lines: 168,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         }],
         total: Totals {
             code: 920,
@@ -82,6 +101,9 @@ fn single_lang_report() -> LangReport {
             bytes: 27_600,
             tokens: 9_200,
             avg_lines: 168,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: true,
         children: ChildrenMode::Collapse,
@@ -99,6 +121,9 @@ fn empty_lang_report() -> LangReport {
             bytes: 0,
             tokens: 0,
             avg_lines: 0,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: false,
         children: ChildrenMode::Collapse,
@@ -117,6 +142,10 @@ fn embedded_lang_report() -> LangReport {
                 bytes: 12_600,
                 tokens: 4_200,
                 avg_lines: 108,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
             LangRow {
                 lang: "JavaScript (embedded)".into(),
@@ -126,6 +155,10 @@ fn embedded_lang_report() -> LangReport {
                 bytes: 6_300,
                 tokens: 2_100,
                 avg_lines: 52,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
             LangRow {
                 lang: "CSS (embedded)".into(),
@@ -135,6 +168,10 @@ fn embedded_lang_report() -> LangReport {
                 bytes: 3_900,
                 tokens: 1_300,
                 avg_lines: 40,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
         ],
         total: Totals {
@@ -144,6 +181,9 @@ fn embedded_lang_report() -> LangReport {
             bytes: 22_800,
             tokens: 7_600,
             avg_lines: 68,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: true,
         children: ChildrenMode::Separate,
@@ -162,6 +202,11 @@ fn three_module_report() -> ModuleReport {
                 bytes: 96_000,
                 tokens: 25_600,
                 avg_lines: 256,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
+                children: Vec::new(),
             },
             ModuleRow {
                 module: "crates/api".into(),
@@ -171,6 +216,11 @@ fn three_module_report() -> ModuleReport {
                 bytes: 54_000,
                 tokens: 14_400,
                 avg_lines: 225,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
+                children: Vec::new(),
             },
             ModuleRow {
                 module: "tests".into(),
@@ -180,6 +230,11 @@ fn three_module_report() -> ModuleReport {
                 bytes: 33_000,
                 tokens: 8_800,
                 avg_lines: 225,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
+                children: Vec::new(),
             },
         ],
         total: Totals {
@@ -189,6 +244,9 @@ fn three_module_report() -> ModuleReport {
             bytes: 183_000,
             tokens: 48_800,
             avg_lines: 240,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         module_roots: vec!["crates".into()],
         module_depth: 2,
@@ -210,6 +268,9 @@ fn sample_file_rows() -> Vec<FileRow> {
             lines: 350,
             bytes: 8_400,
             tokens: 2_800,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         },
         FileRow {
             path: "src/lib.rs".into(),
@@ -222,6 +283,9 @@ fn sample_file_rows() -> Vec<FileRow> {
             lines: 560,
             bytes: 13_500,
             tokens: 4_500,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         },
         FileRow {
             path: "tests/smoke.rs".into(),
@@ -234,6 +298,9 @@ fn sample_file_rows() -> Vec<FileRow> {
             lines: 122,
             bytes: 2_850,
             tokens: 950,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         },
     ]
 }
@@ -262,6 +329,9 @@ fn lang_args(format: TableFormat) -> LangArgs {
         top: 0,
         files: true,
         children: ChildrenMode::Collapse,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     }
 }
 
@@ -273,6 +343,10 @@ fn module_args(format: TableFormat) -> ModuleArgs {
         module_roots: vec![],
         module_depth: 2,
         children: ChildIncludeMode::Separate,
+        collapse_single_child: false,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     }
 }
 
@@ -289,6 +363,10 @@ fn export_args(format: ExportFormat) -> ExportArgs {
         redact: RedactMode::None,
         meta: false,
         strip_prefix: None,
+        filters: Default::default(),
+        sort_by: None,
+        sort_dir: None,
+        summary: false,
     }
 }
 
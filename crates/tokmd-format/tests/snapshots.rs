@@ -11,7 +11,7 @@ use tokmd_format::{
     write_export_cyclonedx_with_options, write_export_json_to, write_export_jsonl_to,
     write_lang_report_to, write_module_report_to,
 };
-use tokmd_settings::{ChildIncludeMode, ChildrenMode, ScanOptions};
+use tokmd_settings::{AvgMeasure, ChildIncludeMode, ChildrenMode, ScanOptions};
 use tokmd_types::{
     ExportArgs, ExportData, ExportFormat, FileKind, FileRow, LangArgs, LangReport, LangRow,
     ModuleArgs, ModuleReport, ModuleRow, RedactMode, TableFormat, Totals,
@@ -32,6 +32,10 @@ fn lang_report(with_files: bool) -> LangReport {
                 bytes: 50000,
                 tokens: 2500,
                 avg_lines: 120,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
             LangRow {
                 lang: "TOML".into(),
@@ -41,6 +45,10 @@ fn lang_report(with_files: bool) -> LangReport {
                 bytes: 1000,
                 tokens: 125,
                 avg_lines: 30,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
         ],
         total: Totals {
@@ -50,6 +58,9 @@ fn lang_report(with_files: bool) -> LangReport {
             bytes: 51000,
             tokens: 2625,
             avg_lines: 105,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files,
         children: ChildrenMode::Collapse,
@@ -68,6 +79,11 @@ fn module_report() -> ModuleReport {
                 bytes: 40000,
                 tokens: 2000,
                 avg_lines: 119,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
+                children: Vec::new(),
             },
             ModuleRow {
                 module: "crates/beta".into(),
@@ -77,6 +93,11 @@ fn module_report() -> ModuleReport {
                 bytes: 10000,
                 tokens: 500,
                 avg_lines: 125,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
+                children: Vec::new(),
             },
         ],
         total: Totals {
@@ -86,6 +107,9 @@ fn module_report() -> ModuleReport {
             bytes: 50000,
             tokens: 2500,
             avg_lines: 120,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         module_roots: vec!["crates".into()],
         module_depth: 2,
@@ -107,6 +131,9 @@ fn file_rows() -> Vec<FileRow> {
             lines: 130,
             bytes: 1000,
             tokens: 250,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         },
         FileRow {
             path: "src/util.rs".into(),
@@ -119,6 +146,9 @@ fn file_rows() -> Vec<FileRow> {
             lines: 70,
             bytes: 600,
             tokens: 150,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         },
         FileRow {
             path: "tests/smoke.rs".into(),
@@ -131,6 +161,9 @@ fn file_rows() -> Vec<FileRow> {
             lines: 45,
             bytes: 400,
             tokens: 100,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         },
     ]
 }
@@ -161,6 +194,9 @@ fn snapshot_lang_md_without_files() {
         top: 0,
         files: false,
         children: ChildrenMode::Collapse,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     };
     write_lang_report_to(&mut buf, &lang_report(false), &global(), &args)
         .expect("operation must succeed");
@@ -177,6 +213,9 @@ fn snapshot_lang_md_with_files() {
         top: 0,
         files: true,
         children: ChildrenMode::Collapse,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     };
     write_lang_report_to(&mut buf, &lang_report(true), &global(), &args)
         .expect("operation must succeed");
@@ -197,6 +236,9 @@ fn snapshot_lang_tsv_without_files() {
         top: 0,
         files: false,
         children: ChildrenMode::Collapse,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     };
     write_lang_report_to(&mut buf, &lang_report(false), &global(), &args)
         .expect("operation must succeed");
@@ -213,6 +255,9 @@ fn snapshot_lang_tsv_with_files() {
         top: 0,
         files: true,
         children: ChildrenMode::Collapse,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     };
     write_lang_report_to(&mut buf, &lang_report(true), &global(), &args)
         .expect("operation must succeed");
@@ -233,6 +278,9 @@ fn snapshot_lang_json() {
         top: 0,
         files: false,
         children: ChildrenMode::Collapse,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     };
     write_lang_report_to(&mut buf, &lang_report(false), &global(), &args)
         .expect("operation must succeed");
@@ -259,6 +307,10 @@ fn snapshot_module_md() {
         module_roots: vec!["crates".into()],
         module_depth: 2,
         children: ChildIncludeMode::Separate,
+        collapse_single_child: false,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     };
     write_module_report_to(&mut buf, &module_report(), &global(), &args)
         .expect("operation must succeed");
@@ -280,6 +332,10 @@ fn snapshot_module_tsv() {
         module_roots: vec!["crates".into()],
         module_depth: 2,
         children: ChildIncludeMode::Separate,
+        collapse_single_child: false,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     };
     write_module_report_to(&mut buf, &module_report(), &global(), &args)
         .expect("operation must succeed");
@@ -301,6 +357,10 @@ fn snapshot_module_json() {
         module_roots: vec!["crates".into()],
         module_depth: 2,
         children: ChildIncludeMode::Separate,
+        collapse_single_child: false,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     };
     write_module_report_to(&mut buf, &module_report(), &global(), &args)
         .expect("operation must succeed");
@@ -331,6 +391,10 @@ fn snapshot_export_csv() {
         redact: RedactMode::None,
         meta: false,
         strip_prefix: None,
+        filters: Default::default(),
+        sort_by: None,
+        sort_dir: None,
+        summary: false,
     };
     write_export_csv_to(&mut buf, &export_data(), &args).expect("operation must succeed");
     let output = String::from_utf8(buf).expect("output must be valid UTF-8");
@@ -356,6 +420,10 @@ fn snapshot_export_jsonl_no_meta() {
         redact: RedactMode::None,
         meta: false,
         strip_prefix: None,
+        filters: Default::default(),
+        sort_by: None,
+        sort_dir: None,
+        summary: false,
     };
     write_export_jsonl_to(&mut buf, &export_data(), &global(), &args)
         .expect("operation must succeed");
@@ -382,6 +450,10 @@ fn snapshot_export_json_no_meta() {
         redact: RedactMode::None,
         meta: false,
         strip_prefix: None,
+        filters: Default::default(),
+        sort_by: None,
+        sort_dir: None,
+        summary: false,
     };
     write_export_json_to(&mut buf, &export_data(), &global(), &args)
         .expect("operation must succeed");
@@ -429,6 +501,10 @@ fn diff_reports() -> (LangReport, LangReport) {
                 bytes: 25000,
                 tokens: 1250,
                 avg_lines: 120,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
             LangRow {
                 lang: "Go".into(),
@@ -438,6 +514,10 @@ fn diff_reports() -> (LangReport, LangReport) {
                 bytes: 8000,
                 tokens: 500,
                 avg_lines: 80,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
         ],
         total: Totals {
@@ -447,6 +527,9 @@ fn diff_reports() -> (LangReport, LangReport) {
             bytes: 33000,
             tokens: 1750,
             avg_lines: 105,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: false,
         children: ChildrenMode::Collapse,
@@ -462,6 +545,10 @@ fn diff_reports() -> (LangReport, LangReport) {
                 bytes: 30000,
                 tokens: 1500,
                 avg_lines: 120,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
             LangRow {
                 lang: "Python".into(),
@@ -471,6 +558,10 @@ fn diff_reports() -> (LangReport, LangReport) {
                 bytes: 7500,
                 tokens: 375,
                 avg_lines: 45,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
         ],
         total: Totals {
@@ -480,6 +571,9 @@ fn diff_reports() -> (LangReport, LangReport) {
             bytes: 37500,
             tokens: 1875,
             avg_lines: 90,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: false,
         children: ChildrenMode::Collapse,
@@ -551,6 +645,9 @@ fn empty_lang_report() -> LangReport {
             bytes: 0,
             tokens: 0,
             avg_lines: 0,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: false,
         children: ChildrenMode::Collapse,
@@ -568,6 +665,9 @@ fn empty_module_report() -> ModuleReport {
             bytes: 0,
             tokens: 0,
             avg_lines: 0,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         module_roots: vec![],
         module_depth: 1,
@@ -585,6 +685,9 @@ fn snapshot_lang_md_empty() {
         top: 0,
         files: false,
         children: ChildrenMode::Collapse,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     };
     write_lang_report_to(&mut buf, &empty_lang_report(), &global(), &args)
         .expect("operation must succeed");
@@ -601,6 +704,9 @@ fn snapshot_lang_tsv_empty() {
         top: 0,
         files: false,
         children: ChildrenMode::Collapse,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     };
     write_lang_report_to(&mut buf, &empty_lang_report(), &global(), &args)
         .expect("operation must succeed");
@@ -617,6 +723,9 @@ fn snapshot_lang_json_empty() {
         top: 0,
         files: false,
         children: ChildrenMode::Collapse,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     };
     write_lang_report_to(&mut buf, &empty_lang_report(), &global(), &args)
         .expect("operation must succeed");
@@ -638,6 +747,10 @@ fn snapshot_module_md_empty() {
         module_roots: vec![],
         module_depth: 1,
         children: ChildIncludeMode::Separate,
+        collapse_single_child: false,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     };
     write_module_report_to(&mut buf, &empty_module_report(), &global(), &args)
         .expect("operation must succeed");
@@ -655,6 +768,10 @@ fn snapshot_module_tsv_empty() {
         module_roots: vec![],
         module_depth: 1,
         children: ChildIncludeMode::Separate,
+        collapse_single_child: false,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     };
     write_module_report_to(&mut buf, &empty_module_report(), &global(), &args)
         .expect("operation must succeed");
@@ -672,6 +789,10 @@ fn snapshot_module_json_empty() {
         module_roots: vec![],
         module_depth: 1,
         children: ChildIncludeMode::Separate,
+        collapse_single_child: false,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     };
     write_module_report_to(&mut buf, &empty_module_report(), &global(), &args)
         .expect("operation must succeed");
@@ -704,6 +825,10 @@ fn snapshot_export_csv_empty() {
         redact: RedactMode::None,
         meta: false,
         strip_prefix: None,
+        filters: Default::default(),
+        sort_by: None,
+        sort_dir: None,
+        summary: false,
     };
     write_export_csv_to(&mut buf, &data, &args).expect("operation must succeed");
     let output = String::from_utf8(buf).expect("output must be valid UTF-8");
@@ -724,6 +849,10 @@ fn single_lang_report() -> LangReport {
             bytes: 25000,
             tokens: 1250,
             avg_lines: 120,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         }],
         total: Totals {
             code: 500,
@@ -732,6 +861,9 @@ fn single_lang_report() -> LangReport {
             bytes: 25000,
             tokens: 1250,
             avg_lines: 120,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: false,
         children: ChildrenMode::Collapse,
@@ -748,6 +880,9 @@ fn snapshot_lang_md_single() {
         top: 0,
         files: false,
         children: ChildrenMode::Collapse,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     };
     write_lang_report_to(&mut buf, &single_lang_report(), &global(), &args)
         .expect("operation must succeed");
@@ -764,6 +899,9 @@ fn snapshot_lang_tsv_single() {
         top: 0,
         files: false,
         children: ChildrenMode::Collapse,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     };
     write_lang_report_to(&mut buf, &single_lang_report(), &global(), &args)
         .expect("operation must succeed");
@@ -780,6 +918,9 @@ fn snapshot_lang_json_single() {
         top: 0,
         files: false,
         children: ChildrenMode::Collapse,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     };
     write_lang_report_to(&mut buf, &single_lang_report(), &global(), &args)
         .expect("operation must succeed");
@@ -805,6 +946,10 @@ fn many_lang_report() -> LangReport {
             bytes: 250000,
             tokens: 12500,
             avg_lines: 120,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         },
         LangRow {
             lang: "Python".into(),
@@ -814,6 +959,10 @@ fn many_lang_report() -> LangReport {
             bytes: 150000,
             tokens: 7500,
             avg_lines: 120,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         },
         LangRow {
             lang: "JavaScript".into(),
@@ -823,6 +972,10 @@ fn many_lang_report() -> LangReport {
             bytes: 100000,
             tokens: 5000,
             avg_lines: 120,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         },
         LangRow {
             lang: "TypeScript".into(),
@@ -832,6 +985,10 @@ fn many_lang_report() -> LangReport {
             bytes: 75000,
             tokens: 3750,
             avg_lines: 120,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         },
         LangRow {
             lang: "Go".into(),
@@ -841,6 +998,10 @@ fn many_lang_report() -> LangReport {
             bytes: 50000,
             tokens: 2500,
             avg_lines: 120,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         },
         LangRow {
             lang: "TOML".into(),
@@ -850,6 +1011,10 @@ fn many_lang_report() -> LangReport {
             bytes: 10000,
             tokens: 500,
             avg_lines: 30,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         },
         LangRow {
             lang: "YAML".into(),
@@ -859,6 +1024,10 @@ fn many_lang_report() -> LangReport {
             bytes: 7500,
             tokens: 375,
             avg_lines: 36,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         },
         LangRow {
             lang: "Markdown".into(),
@@ -868,6 +1037,10 @@ fn many_lang_report() -> LangReport {
             bytes: 5000,
             tokens: 250,
             avg_lines: 30,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         },
     ];
     let total = Totals {
@@ -877,6 +1050,9 @@ fn many_lang_report() -> LangReport {
         bytes: 647500,
         tokens: 32375,
         avg_lines: 109,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
     };
     LangReport {
         rows,
@@ -896,6 +1072,9 @@ fn snapshot_lang_md_many() {
         top: 0,
         files: false,
         children: ChildrenMode::Collapse,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     };
     write_lang_report_to(&mut buf, &many_lang_report(), &global(), &args)
         .expect("operation must succeed");
@@ -912,6 +1091,9 @@ fn snapshot_lang_tsv_many() {
         top: 0,
         files: false,
         children: ChildrenMode::Collapse,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     };
     write_lang_report_to(&mut buf, &many_lang_report(), &global(), &args)
         .expect("operation must succeed");
@@ -928,6 +1110,9 @@ fn snapshot_lang_json_many() {
         top: 0,
         files: false,
         children: ChildrenMode::Collapse,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     };
     write_lang_report_to(&mut buf, &many_lang_report(), &global(), &args)
         .expect("operation must succeed");
@@ -970,6 +1155,9 @@ fn snapshot_export_jsonl_single_file() {
             lines: 50,
             bytes: 500,
             tokens: 100,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         }],
         module_roots: vec![],
         module_depth: 1,
@@ -988,6 +1176,10 @@ fn snapshot_export_jsonl_single_file() {
         redact: RedactMode::None,
         meta: false,
         strip_prefix: None,
+        filters: Default::default(),
+        sort_by: None,
+        sort_dir: None,
+        summary: false,
     };
     write_export_jsonl_to(&mut buf, &data, &global(), &args).expect("operation must succeed");
     let output = String::from_utf8(buf).expect("output must be valid UTF-8");
@@ -1013,6 +1205,10 @@ fn snapshot_export_jsonl_with_meta() {
         redact: RedactMode::None,
         meta: true,
         strip_prefix: None,
+        filters: Default::default(),
+        sort_by: None,
+        sort_dir: None,
+        summary: false,
     };
     write_export_jsonl_to(&mut buf, &export_data(), &global(), &args)
         .expect("operation must succeed");
@@ -1057,6 +1253,10 @@ fn snapshot_export_json_with_meta() {
         redact: RedactMode::None,
         meta: true,
         strip_prefix: None,
+        filters: Default::default(),
+        sort_by: None,
+        sort_dir: None,
+        summary: false,
     };
     write_export_json_to(&mut buf, &export_data(), &global(), &args)
         .expect("operation must succeed");
@@ -1087,6 +1287,11 @@ fn snapshot_module_md_single() {
             bytes: 25000,
             tokens: 1250,
             avg_lines: 120,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
+            children: Vec::new(),
         }],
         total: Totals {
             code: 500,
@@ -1095,6 +1300,9 @@ fn snapshot_module_md_single() {
             bytes: 25000,
             tokens: 1250,
             avg_lines: 120,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         module_roots: vec!["src".into()],
         module_depth: 1,
@@ -1109,6 +1317,10 @@ fn snapshot_module_md_single() {
         module_roots: vec!["src".into()],
         module_depth: 1,
         children: ChildIncludeMode::Separate,
+        collapse_single_child: false,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     };
     write_module_report_to(&mut buf, &report, &global(), &args).expect("operation must succeed");
     let output = String::from_utf8(buf).expect("output must be valid UTF-8");
@@ -16,6 +16,9 @@ fn test_write_export_cyclonedx_honors_redact_mode() {
             lines: 115,
             bytes: 2000,
             tokens: 500,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         }],
         module_roots: vec![],
         module_depth: 2,
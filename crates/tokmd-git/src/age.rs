@@ -0,0 +1,291 @@
+//! Per-file last-modified times for export rows, from one history pass.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::{BufRead, BufReader};
+use std::path::{Component, Path, PathBuf};
+use std::process::Stdio;
+
+use anyhow::{Context, Result};
+use tokmd_types::FileRow;
+
+use crate::git_cmd;
+
+const MS_PER_DAY: u128 = 86_400_000;
+
+/// Unix seconds of the newest commit touching each of `paths` (relative to
+/// `repo_root`), streamed from one `git log --format=%ct --name-only`.
+///
+/// The log is newest first, so the first commit naming a path wins, and the
+/// walk stops as soon as every path has a timestamp.
+pub fn last_modified(repo_root: &Path, paths: &BTreeSet<String>) -> Result<BTreeMap<String, i64>> {
+    let mut newest: BTreeMap<String, i64> = BTreeMap::new();
+    if paths.is_empty() {
+        return Ok(newest);
+    }
+
+    #[cfg(feature = "gix")]
+    if crate::use_gix_fallback() {
+        for commit in crate::gix_backend::collect_history(repo_root, None, None, 0)? {
+            for file in commit.files {
+                if paths.contains(&file) {
+                    newest.entry(file).or_insert(commit.timestamp);
+                }
+            }
+            if newest.len() == paths.len() {
+                break;
+            }
+        }
+        return Ok(newest);
+    }
+
+    let mut child = git_cmd()
+        .arg("-C")
+        .arg(repo_root)
+        .arg("log")
+        .arg("--format=%ct")
+        .arg("--name-only")
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .context("Failed to spawn git log")?;
+    let stdout = child.stdout.take().context("Missing git log stdout")?;
+
+    // Each commit is its `%ct` line, a blank line, then its files; merges
+    // without files are a bare `%ct` line. A numeric line is a header only
+    // when a blank line, another header, or the end follows it, so an
+    // all-digit file name is only misread as the last file of a commit.
+    let mut lines = BufReader::new(stdout).lines().peekable();
+    let mut timestamp = 0;
+    while let Some(line) = lines.next() {
+        let line = line?;
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        if let Ok(ts) = line.parse::<i64>() {
+            let next_is_header = match lines.peek() {
+                None => true,
+                Some(Ok(next)) => next.trim().is_empty() || next.trim().parse::<i64>().is_ok(),
+                Some(Err(_)) => false,
+            };
+            if next_is_header {
+                timestamp = ts;
+                continue;
+            }
+        }
+        if paths.contains(line) && !newest.contains_key(line) {
+            newest.insert(line.to_string(), timestamp);
+            if newest.len() == paths.len() {
+                // The rest of the history cannot change any answer.
+                let _ = child.kill();
+                let _ = child.wait();
+                return Ok(newest);
+            }
+        }
+    }
+
+    let status = child.wait()?;
+    if !status.success() {
+        return Err(anyhow::anyhow!("git log failed"));
+    }
+    Ok(newest)
+}
+
+/// The repository-relative keys [`last_modified`] needs for `rows`.
+///
+/// Row paths resolve against `base` as in [`annotate_ages`]; rows outside
+/// the repository are left out.
+pub fn row_repo_paths(rows: &[FileRow], repo_root: &Path, base: &Path) -> BTreeSet<String> {
+    rows.iter()
+        .filter_map(|row| repo_relative(repo_root, base, &row.path))
+        .collect()
+}
+
+/// Fill `last_modified_ms` and `age_days` on the rows git has seen.
+///
+/// Row paths resolve against `base`, a directory inside the repository at
+/// `repo_root`; rows outside the repository or unknown to git keep `None`.
+/// Returns the number of rows annotated.
+pub fn annotate_ages(
+    rows: &mut [FileRow],
+    repo_root: &Path,
+    base: &Path,
+    last_modified: &BTreeMap<String, i64>,
+    now_ms: u128,
+) -> usize {
+    let mut annotated = 0;
+    for row in rows.iter_mut() {
+        let Some(key) = repo_relative(repo_root, base, &row.path) else {
+            continue;
+        };
+        let Some(&ts) = last_modified.get(&key) else {
+            continue;
+        };
+        let modified_ms = (ts.max(0) as u128) * 1000;
+        row.last_modified_ms = Some(modified_ms as u64);
+        row.age_days = Some((now_ms.saturating_sub(modified_ms) / MS_PER_DAY) as u64);
+        annotated += 1;
+    }
+    annotated
+}
+
+/// `path` (relative to `base`, or absolute) as a `/`-separated path relative
+/// to `repo_root`.
+fn repo_relative(repo_root: &Path, base: &Path, path: &str) -> Option<String> {
+    let joined = base.join(path.replace('\\', "/"));
+    let mut resolved = PathBuf::new();
+    for component in joined.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                resolved.pop();
+            }
+            other => resolved.push(other),
+        }
+    }
+    let relative = resolved.strip_prefix(repo_root).ok()?;
+    Some(relative.to_string_lossy().replace('\\', "/"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{git_available, git_cmd};
+    use tokmd_types::FileKind;
+
+    const DAY_S: i64 = 86_400;
+    const COMMIT_S: i64 = 1_700_000_000;
+
+    fn git(dir: &Path, args: &[&str], date: i64) {
+        let date = format!("{date} +0000");
+        let output = git_cmd()
+            .arg("-C")
+            .arg(dir)
+            .args(args)
+            .env("GIT_AUTHOR_DATE", &date)
+            .env("GIT_COMMITTER_DATE", &date)
+            .output()
+            .unwrap();
+        assert!(
+            output.status.success(),
+            "git {args:?} failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    fn row(path: &str) -> FileRow {
+        FileRow {
+            path: path.to_string(),
+            module: "src".to_string(),
+            lang: "Rust".to_string(),
+            kind: FileKind::Parent,
+            code: 1,
+            comments: 0,
+            blanks: 0,
+            lines: 1,
+            bytes: 10,
+            tokens: 3,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
+        }
+    }
+
+    #[test]
+    fn rows_get_the_newest_commit_touching_them() -> Result<()> {
+        if !git_available() {
+            return Ok(());
+        }
+        let dir = tempfile::tempdir()?;
+        let root = dir.path();
+        git(root, &["init", "-b", "main"], COMMIT_S);
+        git(root, &["config", "user.email", "test@test.com"], COMMIT_S);
+        git(root, &["config", "user.name", "Test"], COMMIT_S);
+        std::fs::create_dir(root.join("src"))?;
+        std::fs::write(root.join("src/old.rs"), "fn old() {}\n")?;
+        std::fs::write(root.join("src/new.rs"), "fn new() {}\n")?;
+        git(root, &["add", "."], COMMIT_S);
+        git(root, &["commit", "-m", "initial"], COMMIT_S);
+        std::fs::write(root.join("src/new.rs"), "fn new() { }\n")?;
+        git(root, &["commit", "-am", "touch new"], COMMIT_S + 10 * DAY_S);
+        std::fs::write(root.join("src/untracked.rs"), "fn u() {}\n")?;
+
+        let repo_root = crate::repo_root(root).context("repo root")?;
+        let mut rows = vec![
+            row("src/old.rs"),
+            row("./src/new.rs"),
+            row("src/untracked.rs"),
+        ];
+        let paths = row_repo_paths(&rows, &repo_root, &repo_root);
+        let ages = last_modified(&repo_root, &paths)?;
+        assert_eq!(ages.get("src/old.rs"), Some(&COMMIT_S));
+        assert_eq!(ages.get("src/new.rs"), Some(&(COMMIT_S + 10 * DAY_S)));
+        assert_eq!(ages.get("src/untracked.rs"), None);
+
+        let now_ms = ((COMMIT_S + 30 * DAY_S) as u128) * 1000 + 5;
+        let annotated = annotate_ages(&mut rows, &repo_root, &repo_root, &ages, now_ms);
+
+        assert_eq!(annotated, 2);
+        assert_eq!(rows[0].last_modified_ms, Some(COMMIT_S as u64 * 1000));
+        assert_eq!(rows[0].age_days, Some(30));
+        assert_eq!(rows[1].age_days, Some(20));
+        assert_eq!(rows[2].last_modified_ms, None);
+        assert_eq!(rows[2].age_days, None);
+        Ok(())
+    }
+
+    #[test]
+    fn only_requested_paths_are_looked_up() -> Result<()> {
+        if !git_available() {
+            return Ok(());
+        }
+        let dir = tempfile::tempdir()?;
+        let root = dir.path();
+        git(root, &["init", "-b", "main"], COMMIT_S);
+        git(root, &["config", "user.email", "test@test.com"], COMMIT_S);
+        git(root, &["config", "user.name", "Test"], COMMIT_S);
+        std::fs::write(root.join("a.rs"), "fn a() {}\n")?;
+        std::fs::write(root.join("b.rs"), "fn b() {}\n")?;
+        git(root, &["add", "."], COMMIT_S);
+        git(root, &["commit", "-m", "initial"], COMMIT_S);
+        std::fs::write(root.join("a.rs"), "fn a() { }\n")?;
+        git(root, &["commit", "-am", "touch a"], COMMIT_S + DAY_S);
+
+        let repo_root = crate::repo_root(root).context("repo root")?;
+        let paths = BTreeSet::from(["a.rs".to_string()]);
+        let ages = last_modified(&repo_root, &paths)?;
+
+        assert_eq!(
+            ages,
+            BTreeMap::from([("a.rs".to_string(), COMMIT_S + DAY_S)])
+        );
+        assert!(last_modified(&repo_root, &BTreeSet::new())?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn row_paths_resolve_against_a_subdirectory_base() {
+        let ages = BTreeMap::from([("crates/a/src/lib.rs".to_string(), 100)]);
+        let root = Path::new("/repo");
+        let mut rows = vec![row("src/lib.rs"), row("../b/src/lib.rs")];
+
+        let annotated = annotate_ages(&mut rows, root, &root.join("crates/a"), &ages, 100_000);
+
+        assert_eq!(annotated, 1);
+        assert_eq!(rows[0].last_modified_ms, Some(100_000));
+        assert_eq!(rows[0].age_days, Some(0));
+        assert_eq!(rows[1].age_days, None);
+    }
+
+    #[test]
+    fn paths_outside_the_repository_are_skipped() {
+        assert_eq!(
+            repo_relative(Path::new("/repo"), Path::new("/repo"), "./src\\lib.rs"),
+            Some("src/lib.rs".to_string())
+        );
+        assert_eq!(
+            repo_relative(Path::new("/repo"), Path::new("/elsewhere"), "lib.rs"),
+            None
+        );
+    }
+}
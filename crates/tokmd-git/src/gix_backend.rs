@@ -0,0 +1,462 @@
+//! Pure-Rust fallback for environments without a `git` binary.
+//!
+//! Covers repository discovery, history, diff stats, and commit counts, and
+//! mirrors the output of the CLI-backed functions for each of them. Only the
+//! object database is read, so worktree state and attributes that change how
+//! git renders a diff (textconv, `-diff`) are not consulted.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result, bail};
+use gix::ObjectId;
+use gix::bstr::ByteSlice;
+use gix::diff::blob::{Algorithm, intern::InternedInput, sink::Counter};
+use gix::object::tree::diff::ChangeDetached;
+use gix::revision::walk::Sorting;
+use gix::traverse::commit::simple::CommitTimeOrder;
+
+use crate::{GitCommit, NumStat, truncate_message};
+
+/// Bytes git inspects when deciding whether a blob is binary.
+const BINARY_SNIFF_LEN: usize = 8000;
+
+pub(crate) fn repo_root(path: &Path) -> Option<PathBuf> {
+    let repo = gix::discover(path).ok()?;
+    let workdir = repo.workdir()?;
+    Some(std::fs::canonicalize(workdir).unwrap_or_else(|_| workdir.to_path_buf()))
+}
+
+pub(crate) fn git_dir(path: &Path) -> Option<PathBuf> {
+    let repo = gix::discover(path).ok()?;
+    let dir = repo.git_dir();
+    Some(std::fs::canonicalize(dir).unwrap_or_else(|_| dir.to_path_buf()))
+}
+
+pub(crate) fn collect_history(
+    repo_root: &Path,
+    max_commits: Option<usize>,
+    max_commit_files: Option<usize>,
+    max_message_bytes: usize,
+) -> Result<Vec<GitCommit>> {
+    let repo = open(repo_root)?;
+    let head = repo.head_id().context("Failed to resolve HEAD")?;
+    let walk = repo
+        .rev_walk([head])
+        .sorting(Sorting::ByCommitTime(CommitTimeOrder::NewestFirst))
+        .all()
+        .context("Failed to walk history")?;
+
+    let mut commits = Vec::new();
+    for info in walk {
+        if max_commits.is_some_and(|limit| commits.len() >= limit) {
+            break;
+        }
+        let commit = info.context("Failed to walk history")?.object()?;
+        let parents: Vec<ObjectId> = commit.parent_ids().map(|id| id.detach()).collect();
+
+        // Like `git log`, merges list no files and the root commit lists all.
+        let mut files = match parents.as_slice() {
+            [] => changed_paths(&repo, None, commit.id)?,
+            [parent] => changed_paths(&repo, Some(*parent), commit.id)?,
+            _ => Vec::new(),
+        };
+        if let Some(limit) = max_commit_files {
+            files.truncate(limit);
+        }
+
+        let message = commit.message()?;
+        let (subject, message_truncated) =
+            truncate_message(&message.summary().to_str_lossy(), max_message_bytes);
+        commits.push(GitCommit {
+            timestamp: commit.time()?.seconds,
+            author: commit.author()?.email.to_str_lossy().into_owned(),
+            hash: Some(commit.id.to_string()),
+            subject,
+            files,
+            message_truncated,
+        });
+    }
+    Ok(commits)
+}
+
+pub(crate) fn diff_numstat(repo_root: &Path, range: &str, paths: &[&str]) -> Result<Vec<NumStat>> {
+    let repo = open(repo_root)?;
+    let (base, head) = diff_endpoints(&repo, range)?;
+
+    let mut stats = Vec::new();
+    for change in tree_changes(&repo, Some(base), head)? {
+        let (path, old, new) = match &change {
+            ChangeDetached::Addition { location, id, .. } => {
+                (location.to_string(), None, Some(*id))
+            }
+            ChangeDetached::Deletion { location, id, .. } => {
+                (location.to_string(), Some(*id), None)
+            }
+            ChangeDetached::Modification {
+                location,
+                previous_id,
+                id,
+                ..
+            } => (location.to_string(), Some(*previous_id), Some(*id)),
+            ChangeDetached::Rewrite {
+                source_location,
+                source_id,
+                location,
+                id,
+                ..
+            } => (
+                rename_path(&source_location.to_string(), &location.to_string()),
+                Some(*source_id),
+                Some(*id),
+            ),
+        };
+        if !paths.is_empty() && !change_in_paths(&change, paths) {
+            continue;
+        }
+        let (insertions, deletions) = line_counts(&repo, old, new)?;
+        stats.push(NumStat {
+            path,
+            insertions,
+            deletions,
+        });
+    }
+    Ok(stats)
+}
+
+pub(crate) fn commit_count(repo_root: &Path, range: &str) -> Result<usize> {
+    let repo = open(repo_root)?;
+    let Some((base, head, symmetric)) = split_range(range) else {
+        return Ok(0);
+    };
+    let (Ok(base), Ok(head)) = (resolve_commit(&repo, base), resolve_commit(&repo, head)) else {
+        return Ok(0);
+    };
+
+    let (tips, hidden) = if symmetric {
+        let bases: Vec<ObjectId> = repo
+            .merge_bases_many(base, &[head])
+            .map(|ids| ids.into_iter().map(|id| id.detach()).collect())
+            .unwrap_or_default();
+        (vec![base, head], bases)
+    } else {
+        (vec![head], vec![base])
+    };
+    let walk = repo
+        .rev_walk(tips)
+        .with_hidden(hidden)
+        .all()
+        .context("Failed to walk history")?;
+    Ok(walk.filter(Result::is_ok).count())
+}
+
+fn open(repo_root: &Path) -> Result<gix::Repository> {
+    gix::open(repo_root)
+        .with_context(|| format!("Failed to open git repository at {}", repo_root.display()))
+}
+
+/// Split `A..B` or `A...B` into its ends, defaulting an empty end to `HEAD`.
+fn split_range(range: &str) -> Option<(&str, &str, bool)> {
+    let (base, head, symmetric) = if let Some((base, head)) = range.split_once("...") {
+        (base, head, true)
+    } else {
+        let (base, head) = range.split_once("..")?;
+        (base, head, false)
+    };
+    Some((
+        if base.is_empty() { "HEAD" } else { base },
+        if head.is_empty() { "HEAD" } else { head },
+        symmetric,
+    ))
+}
+
+/// The commits `git diff <range>` compares: `A..B` diffs A against B, and
+/// `A...B` diffs the merge base of A and B against B.
+fn diff_endpoints(repo: &gix::Repository, range: &str) -> Result<(ObjectId, ObjectId)> {
+    let Some((base, head, symmetric)) = split_range(range) else {
+        bail!("git diff --numstat failed: unsupported range '{range}'");
+    };
+    let base = resolve_commit(repo, base)?;
+    let head = resolve_commit(repo, head)?;
+    if symmetric {
+        let merge_base = repo
+            .merge_base(base, head)
+            .with_context(|| format!("git diff --numstat failed: no merge base in '{range}'"))?;
+        return Ok((merge_base.detach(), head));
+    }
+    Ok((base, head))
+}
+
+fn resolve_commit(repo: &gix::Repository, rev: &str) -> Result<ObjectId> {
+    let commit = repo
+        .rev_parse_single(rev)
+        .with_context(|| format!("git diff --numstat failed: unknown revision '{rev}'"))?
+        .object()?
+        .peel_to_commit()?;
+    Ok(commit.id)
+}
+
+/// File-level changes between two commits, in git's path order. A missing
+/// `old` compares against the empty tree.
+fn tree_changes(
+    repo: &gix::Repository,
+    old: Option<ObjectId>,
+    new: ObjectId,
+) -> Result<Vec<ChangeDetached>> {
+    let old_tree = match old {
+        Some(id) => Some(repo.find_commit(id)?.tree()?),
+        None => None,
+    };
+    let new_tree = repo.find_commit(new)?.tree()?;
+    let mut changes: Vec<ChangeDetached> = repo
+        .diff_tree_to_tree(old_tree.as_ref(), &new_tree, None)?
+        .into_iter()
+        .filter(|change| !change.entry_mode().is_tree() && !change.entry_mode().is_commit())
+        .collect();
+    changes.sort_by(|a, b| a.location().cmp(b.location()));
+    Ok(changes)
+}
+
+fn changed_paths(
+    repo: &gix::Repository,
+    old: Option<ObjectId>,
+    new: ObjectId,
+) -> Result<Vec<String>> {
+    Ok(tree_changes(repo, old, new)?
+        .iter()
+        .map(|change| change.location().to_string())
+        .collect())
+}
+
+/// Whether either side of `change` falls under one of `paths`, matching
+/// whole path components like a git pathspec.
+fn change_in_paths(change: &ChangeDetached, paths: &[&str]) -> bool {
+    let mut locations = vec![change.location()];
+    if let ChangeDetached::Rewrite {
+        source_location, ..
+    } = change
+    {
+        locations.push(source_location.as_ref());
+    }
+    locations.iter().any(|location| {
+        paths.iter().any(|path| {
+            let path = path.trim_end_matches('/').as_bytes();
+            location.as_bytes() == path
+                || (location.starts_with(path) && location.get(path.len()) == Some(&b'/'))
+        })
+    })
+}
+
+/// Lines added and removed between two blobs; binary blobs count as zero,
+/// matching the `-` markers git prints for them.
+fn line_counts(
+    repo: &gix::Repository,
+    old: Option<ObjectId>,
+    new: Option<ObjectId>,
+) -> Result<(usize, usize)> {
+    let read = |id: Option<ObjectId>| -> Result<Vec<u8>> {
+        Ok(match id {
+            Some(id) => repo.find_blob(id)?.detach().data,
+            None => Vec::new(),
+        })
+    };
+    let (old, new) = (read(old)?, read(new)?);
+    if is_binary(&old) || is_binary(&new) {
+        return Ok((0, 0));
+    }
+    let input = InternedInput::new(old.as_slice(), new.as_slice());
+    let counter = gix::diff::blob::diff(Algorithm::Myers, &input, Counter::default());
+    Ok((counter.insertions as usize, counter.removals as usize))
+}
+
+fn is_binary(data: &[u8]) -> bool {
+    data.get(..BINARY_SNIFF_LEN).unwrap_or(data).contains(&0)
+}
+
+/// Render a rename the way `git diff --numstat` does, folding the shared
+/// directory prefix and suffix into `pfx/{old => new}/sfx`.
+fn rename_path(old: &str, new: &str) -> String {
+    let (a, b) = (old.as_bytes(), new.as_bytes());
+
+    let mut prefix = 0;
+    for (i, (x, y)) in a.iter().zip(b).enumerate() {
+        if x != y {
+            break;
+        }
+        if *x == b'/' {
+            prefix = i + 1;
+        }
+    }
+
+    // The suffix must start at a slash and may reuse the prefix's slash.
+    let floor = prefix.saturating_sub(1);
+    let a_tail = a.get(floor..).unwrap_or_default().iter().rev();
+    let b_tail = b.get(floor..).unwrap_or_default().iter().rev();
+    let mut suffix = 0;
+    for (offset, (x, y)) in a_tail.zip(b_tail).enumerate() {
+        if x != y {
+            break;
+        }
+        if *x == b'/' {
+            suffix = offset + 1;
+        }
+    }
+
+    let mid_end = |len: usize| len.saturating_sub(suffix).max(prefix);
+    let parts = (
+        old.get(..prefix),
+        old.get(prefix..mid_end(old.len())),
+        new.get(prefix..mid_end(new.len())),
+        old.get(old.len().saturating_sub(suffix)..),
+    );
+    match parts {
+        (Some(head), Some(a_mid), Some(b_mid), Some(tail)) if prefix + suffix > 0 => {
+            format!("{head}{{{a_mid} => {b_mid}}}{tail}")
+        }
+        _ => format!("{old} => {new}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{git_available, git_cmd};
+
+    fn run_git(dir: &Path, args: &[&str]) {
+        let status = git_cmd().arg("-C").arg(dir).args(args).status().unwrap();
+        assert!(status.success(), "git {args:?} failed");
+    }
+
+    fn fixture_repo() -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        run_git(dir.path(), &["init", "-b", "main"]);
+        run_git(dir.path(), &["config", "user.email", "test@test.com"]);
+        run_git(dir.path(), &["config", "user.name", "Test"]);
+        run_git(dir.path(), &["config", "commit.gpgsign", "false"]);
+        let write = |path: &str, contents: &[u8]| {
+            let path = dir.path().join(path);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, contents).unwrap();
+        };
+        let commit = |message: &str| {
+            run_git(dir.path(), &["add", "-A"]);
+            run_git(dir.path(), &["commit", "-q", "-m", message]);
+        };
+
+        write("src/lib.rs", b"fn a() {}\nfn b() {}\nfn c() {}\n");
+        write(
+            "src/util/mod.rs",
+            b"pub mod io;\npub mod fmt;\npub mod net;\n",
+        );
+        write("docs/guide.md", b"# Guide\n\nIntro\n");
+        write("logo.bin", b"\x00\x01\x02");
+        write("gone.txt", b"bye\n");
+        commit("feat: initial layout");
+        run_git(dir.path(), &["tag", "base"]);
+
+        write(
+            "src/lib.rs",
+            b"fn a() {}\nfn b2() {}\nfn c() {}\nfn d() {}\n",
+        );
+        write("docs/guide.md", b"# Guide\n\nIntro\nMore\n");
+        commit("docs: extend guide\n\nWith a body paragraph.");
+
+        std::fs::create_dir_all(dir.path().join("src/helpers")).unwrap();
+        std::fs::rename(
+            dir.path().join("src/util/mod.rs"),
+            dir.path().join("src/helpers/mod.rs"),
+        )
+        .unwrap();
+        std::fs::remove_file(dir.path().join("gone.txt")).unwrap();
+        write("logo.bin", b"\x00\x03");
+        write("noeol.txt", b"no newline");
+        commit("refactor: move helpers");
+        dir
+    }
+
+    #[test]
+    fn rename_paths_fold_shared_directories_like_git() {
+        assert_eq!(rename_path("a.txt", "b.txt"), "a.txt => b.txt");
+        assert_eq!(
+            rename_path("src/util/mod.rs", "src/helpers/mod.rs"),
+            "src/{util => helpers}/mod.rs"
+        );
+        assert_eq!(rename_path("src/a.rs", "src/b.rs"), "src/{a.rs => b.rs}");
+        assert_eq!(rename_path("a/x.rs", "b/x.rs"), "{a => b}/x.rs");
+        assert_eq!(rename_path("x.rs", "sub/x.rs"), "x.rs => sub/x.rs");
+    }
+
+    #[test]
+    fn ranges_split_into_ends_with_head_default() {
+        assert_eq!(split_range("main..HEAD"), Some(("main", "HEAD", false)));
+        assert_eq!(split_range("main...topic"), Some(("main", "topic", true)));
+        assert_eq!(split_range("base.."), Some(("base", "HEAD", false)));
+        assert_eq!(split_range("HEAD"), None);
+    }
+
+    #[test]
+    fn gix_numstat_matches_cli_numstat() {
+        if !git_available() {
+            return;
+        }
+        let dir = fixture_repo();
+        for range in [
+            "base..HEAD",
+            "HEAD~1..HEAD",
+            "base...HEAD",
+            "HEAD~2..HEAD~1",
+        ] {
+            let cli = crate::diff_numstat(dir.path(), range, &[]).unwrap();
+            let gix = diff_numstat(dir.path(), range, &[]).unwrap();
+            assert_eq!(gix, cli, "range {range}");
+        }
+        let cli = crate::diff_numstat(dir.path(), "base..HEAD", &["src", "logo.bin"]).unwrap();
+        let gix = diff_numstat(dir.path(), "base..HEAD", &["src", "logo.bin"]).unwrap();
+        assert_eq!(gix, cli);
+    }
+
+    #[test]
+    fn gix_history_and_counts_match_cli() {
+        if !git_available() {
+            return;
+        }
+        let dir = fixture_repo();
+
+        let cli = crate::collect_history(dir.path(), None, None).unwrap();
+        let gix = collect_history(dir.path(), None, None, usize::MAX).unwrap();
+        assert_eq!(gix.len(), cli.len());
+        for (gix, cli) in gix.iter().zip(&cli) {
+            assert_eq!(
+                (
+                    gix.timestamp,
+                    &gix.author,
+                    &gix.hash,
+                    &gix.subject,
+                    &gix.files
+                ),
+                (
+                    cli.timestamp,
+                    &cli.author,
+                    &cli.hash,
+                    &cli.subject,
+                    &cli.files
+                )
+            );
+        }
+        let limited = collect_history(dir.path(), Some(2), Some(1), 4).unwrap();
+        assert_eq!(limited.len(), 2);
+        assert!(limited.iter().all(|commit| commit.files.len() <= 1));
+        assert!(limited.iter().all(|commit| commit.message_truncated));
+
+        for range in ["base..HEAD", "HEAD..base", "base...HEAD", "nope..HEAD"] {
+            assert_eq!(
+                commit_count(dir.path(), range).unwrap(),
+                crate::commit_count(dir.path(), range).unwrap(),
+                "range {range}"
+            );
+        }
+        assert_eq!(
+            repo_root(&dir.path().join("src")),
+            crate::repo_root(&dir.path().join("src"))
+        );
+    }
+}
@@ -0,0 +1,78 @@
+//! Concurrency limit for independent git subprocesses.
+//!
+//! Callers that need two unrelated git answers (a numstat and a commit
+//! count, say) run them through [`join`] instead of one after another. The
+//! limit comes from `TOKMD_GIT_JOBS`, so CI runners with few cores can cap
+//! how many git processes tokmd starts at once; `1` restores fully serial
+//! execution.
+
+use std::thread;
+
+/// Environment variable holding the maximum number of concurrent git calls.
+pub const GIT_JOBS_ENV: &str = "TOKMD_GIT_JOBS";
+
+/// Upper bound on the default limit; git calls are I/O heavy, so more
+/// processes than this rarely help.
+const DEFAULT_MAX_JOBS: usize = 4;
+
+/// The configured git concurrency limit.
+///
+/// Reads [`GIT_JOBS_ENV`]; when it is unset or not a positive integer, uses
+/// the available parallelism capped at four.
+pub fn git_jobs() -> usize {
+    std::env::var(GIT_JOBS_ENV)
+        .ok()
+        .and_then(|value| parse_jobs(&value))
+        .unwrap_or_else(default_jobs)
+}
+
+fn parse_jobs(value: &str) -> Option<usize> {
+    value.trim().parse().ok().filter(|&jobs| jobs > 0)
+}
+
+fn default_jobs() -> usize {
+    thread::available_parallelism()
+        .map(|n| n.get().min(DEFAULT_MAX_JOBS))
+        .unwrap_or(1)
+}
+
+/// Run two independent tasks, concurrently when `limit` allows it.
+pub fn join<A, B, FA, FB>(limit: usize, a: FA, b: FB) -> (A, B)
+where
+    A: Send,
+    B: Send,
+    FA: FnOnce() -> A + Send,
+    FB: FnOnce() -> B + Send,
+{
+    if limit <= 1 {
+        return (a(), b());
+    }
+    thread::scope(|scope| {
+        let b = scope.spawn(b);
+        let a = a();
+        match b.join() {
+            Ok(b) => (a, b),
+            Err(panic) => std::panic::resume_unwind(panic),
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn jobs_parse_positive_integers_only() {
+        assert_eq!(parse_jobs(" 3 "), Some(3));
+        assert_eq!(parse_jobs("0"), None);
+        assert_eq!(parse_jobs("many"), None);
+        assert!((1..=DEFAULT_MAX_JOBS).contains(&default_jobs()));
+    }
+
+    #[test]
+    fn join_returns_both_results_at_any_limit() {
+        for limit in [0, 1, 2] {
+            assert_eq!(join(limit, || 1, || "two"), (1, "two"));
+        }
+    }
+}
@@ -9,6 +9,9 @@
 //! * Git history collection
 //! * Commit parsing (timestamp, author, affected files)
 //! * Streaming interface
+//! * Batched diff stats and the concurrency limit for git subprocesses
+//! * Per-file last-modified times for export rows
+//! * A pure-Rust fallback (`gix` feature) for hosts without a `git` binary
 //!
 //! ## What does NOT belong here
 //! * Analysis computation (use tokmd-analysis)
@@ -22,13 +25,25 @@ use std::process::Stdio;
 use anyhow::{Context, Result};
 pub use tokmd_types::CommitIntentKind;
 
+mod age;
 mod command;
+#[cfg(feature = "gix")]
+mod gix_backend;
 mod intent;
+mod jobs;
+mod numstat;
 mod refs;
 
+pub use age::{annotate_ages, last_modified, row_repo_paths};
 pub use command::git_cmd;
 pub use intent::classify_intent;
-pub use refs::{resolve_base_ref, rev_exists};
+pub use jobs::{GIT_JOBS_ENV, git_jobs, join};
+pub use numstat::{NumStat, commit_count, diff_numstat};
+pub use refs::{is_ancestor, resolve_base_ref, rev_exists};
+
+/// Default cap on the commit message bytes kept per commit by
+/// [`collect_history`].
+pub const DEFAULT_MAX_COMMIT_MESSAGE_BYTES: usize = 4096;
 
 #[derive(Debug, Clone)]
 pub struct GitCommit {
@@ -37,6 +52,8 @@ pub struct GitCommit {
     pub hash: Option<String>,
     pub subject: String,
     pub files: Vec<String>,
+    /// Whether `subject` was cut to the message byte limit.
+    pub message_truncated: bool,
 }
 
 /// Git range syntax for comparing commits.
@@ -69,7 +86,19 @@ pub fn git_available() -> bool {
         .unwrap_or(false)
 }
 
+/// Whether repository reads should go through the `gix` fallback because
+/// the `git` binary cannot be run. Checked once per process.
+#[cfg(feature = "gix")]
+pub(crate) fn use_gix_fallback() -> bool {
+    static CLI_MISSING: std::sync::OnceLock<bool> = std::sync::OnceLock::new();
+    *CLI_MISSING.get_or_init(|| !git_available())
+}
+
 pub fn repo_root(path: &Path) -> Option<PathBuf> {
+    #[cfg(feature = "gix")]
+    if use_gix_fallback() {
+        return gix_backend::repo_root(path);
+    }
     let output = git_cmd()
         .arg("-C")
         .arg(path)
@@ -88,11 +117,67 @@ pub fn repo_root(path: &Path) -> Option<PathBuf> {
     }
 }
 
+/// Absolute path of the git directory (usually `<root>/.git`) of the
+/// repository containing `path`.
+pub fn git_dir(path: &Path) -> Option<PathBuf> {
+    #[cfg(feature = "gix")]
+    if use_gix_fallback() {
+        return gix_backend::git_dir(path);
+    }
+    let output = git_cmd()
+        .arg("-C")
+        .arg(path)
+        .arg("rev-parse")
+        .arg("--absolute-git-dir")
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let dir = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    if dir.is_empty() {
+        None
+    } else {
+        Some(PathBuf::from(dir))
+    }
+}
+
+/// Collect history newest first, keeping at most
+/// [`DEFAULT_MAX_COMMIT_MESSAGE_BYTES`] of each commit message.
 pub fn collect_history(
     repo_root: &Path,
     max_commits: Option<usize>,
     max_commit_files: Option<usize>,
 ) -> Result<Vec<GitCommit>> {
+    collect_history_bounded(
+        repo_root,
+        max_commits,
+        max_commit_files,
+        DEFAULT_MAX_COMMIT_MESSAGE_BYTES,
+    )
+}
+
+/// Like [`collect_history`], but keeping at most `max_message_bytes` of each
+/// commit message.
+///
+/// Intent classification only needs the start of a subject, so a repository
+/// with giant commit messages cannot grow the collected history without
+/// bound. Cut messages set [`GitCommit::message_truncated`].
+pub fn collect_history_bounded(
+    repo_root: &Path,
+    max_commits: Option<usize>,
+    max_commit_files: Option<usize>,
+    max_message_bytes: usize,
+) -> Result<Vec<GitCommit>> {
+    #[cfg(feature = "gix")]
+    if use_gix_fallback() {
+        return gix_backend::collect_history(
+            repo_root,
+            max_commits,
+            max_commit_files,
+            max_message_bytes,
+        );
+    }
     let mut child = git_cmd()
         .arg("-C")
         .arg(repo_root)
@@ -127,7 +212,8 @@ pub fn collect_history(
             let ts = parts.next().unwrap_or("0").parse::<i64>().unwrap_or(0);
             let author = parts.next().unwrap_or("").to_string();
             let hash_str = parts.next().unwrap_or("").to_string();
-            let subject = parts.next().unwrap_or("").to_string();
+            let (subject, message_truncated) =
+                truncate_message(parts.next().unwrap_or(""), max_message_bytes);
             let hash = if hash_str.is_empty() {
                 None
             } else {
@@ -139,6 +225,7 @@ pub fn collect_history(
                 hash,
                 subject,
                 files: Vec::new(),
+                message_truncated,
             });
             continue;
         }
@@ -164,6 +251,18 @@ pub fn collect_history(
     Ok(commits)
 }
 
+/// Cut `message` to at most `max_bytes`, backing off to a character boundary.
+fn truncate_message(message: &str, max_bytes: usize) -> (String, bool) {
+    if message.len() <= max_bytes {
+        return (message.to_string(), false);
+    }
+    let kept = (0..=max_bytes)
+        .rev()
+        .find_map(|end| message.get(..end))
+        .unwrap_or_default();
+    (kept.to_string(), true)
+}
+
 /// Get the set of added line numbers per file between two refs.
 pub fn get_added_lines(
     repo_root: &Path,
@@ -323,6 +422,44 @@ mod tests {
         assert!(commits[0].files.is_empty());
     }
 
+    #[test]
+    fn collect_history_truncates_oversized_messages_but_keeps_intent() {
+        if !git_available() {
+            return;
+        }
+        let dir = init_repo();
+
+        // One long paragraph, so the whole message is the subject.
+        let message = format!("fix: guard the parser {}", "and more ".repeat(2_000));
+        std::fs::write(dir.path().join("parser.rs"), "fn parse() {}\n").unwrap();
+        commit_all(dir.path(), &message);
+
+        let commits = collect_history_bounded(dir.path(), None, None, 64).unwrap();
+
+        let commit = &commits[0];
+        assert!(commit.message_truncated);
+        assert_eq!(commit.subject.len(), 64);
+        assert!(message.starts_with(&commit.subject));
+        assert_eq!(classify_intent(&commit.subject), CommitIntentKind::Fix);
+        assert_eq!(commit.files, ["parser.rs"]);
+
+        let full = collect_history_bounded(dir.path(), None, None, usize::MAX).unwrap();
+        assert!(!full[0].message_truncated);
+        assert_eq!(full[0].subject, message.trim_end());
+    }
+
+    #[test]
+    fn message_truncation_backs_off_to_a_char_boundary() {
+        assert_eq!(
+            truncate_message("feat: café", 10),
+            ("feat: caf".to_string(), true)
+        );
+        assert_eq!(
+            truncate_message("feat: x", 7),
+            ("feat: x".to_string(), false)
+        );
+    }
+
     #[test]
     fn get_added_lines_reports_new_line_numbers_per_file() {
         if !git_available() {
@@ -0,0 +1,138 @@
+//! Batched per-file line counts and commit counts for a revision range.
+
+use std::path::Path;
+
+use anyhow::{Context, Result, bail};
+
+use crate::git_cmd;
+
+/// Lines added and removed in one file, as reported by `git diff --numstat`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NumStat {
+    pub path: String,
+    pub insertions: usize,
+    pub deletions: usize,
+}
+
+/// Line counts for the files changed in `range`, from a single
+/// `git diff --numstat` call.
+///
+/// With `paths`, only those paths are diffed, still in one call; prefer this
+/// over calling once per file. Binary files report zero lines.
+pub fn diff_numstat(repo_root: &Path, range: &str, paths: &[&str]) -> Result<Vec<NumStat>> {
+    #[cfg(feature = "gix")]
+    if crate::use_gix_fallback() {
+        return crate::gix_backend::diff_numstat(repo_root, range, paths);
+    }
+    let mut cmd = git_cmd();
+    cmd.arg("-C")
+        .arg(repo_root)
+        .args(["diff", "--numstat", range]);
+    if !paths.is_empty() {
+        cmd.arg("--").args(paths);
+    }
+    let output = cmd.output().context("Failed to run git diff --numstat")?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        bail!("git diff --numstat failed: {}", stderr.trim());
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    Ok(stdout.lines().filter_map(parse_numstat_line).collect())
+}
+
+fn parse_numstat_line(line: &str) -> Option<NumStat> {
+    let mut parts = line.split('\t');
+    let (insertions, deletions, path) = (parts.next()?, parts.next()?, parts.next()?);
+    if parts.next().is_some() {
+        return None;
+    }
+    Some(NumStat {
+        path: path.to_string(),
+        insertions: insertions.parse().unwrap_or(0),
+        deletions: deletions.parse().unwrap_or(0),
+    })
+}
+
+/// Number of commits in `range`, or 0 when git cannot count them.
+pub fn commit_count(repo_root: &Path, range: &str) -> Result<usize> {
+    #[cfg(feature = "gix")]
+    if crate::use_gix_fallback() {
+        return crate::gix_backend::commit_count(repo_root, range);
+    }
+    let output = git_cmd()
+        .arg("-C")
+        .arg(repo_root)
+        .args(["rev-list", "--count", range])
+        .output()
+        .context("Failed to run git rev-list --count")?;
+
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .trim()
+        .parse()
+        .unwrap_or(0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::git_available;
+
+    fn run_git(dir: &Path, args: &[&str]) {
+        let status = git_cmd().arg("-C").arg(dir).args(args).status().unwrap();
+        assert!(status.success(), "git {args:?} failed");
+    }
+
+    #[test]
+    fn numstat_lines_parse_counts_and_binary_markers() {
+        assert_eq!(
+            parse_numstat_line("3\t1\tsrc/lib.rs"),
+            Some(NumStat {
+                path: "src/lib.rs".to_string(),
+                insertions: 3,
+                deletions: 1,
+            })
+        );
+        let binary = parse_numstat_line("-\t-\tlogo.png").unwrap();
+        assert_eq!((binary.insertions, binary.deletions), (0, 0));
+        assert_eq!(parse_numstat_line("garbage"), None);
+    }
+
+    #[test]
+    fn batched_numstat_matches_per_file_calls() {
+        if !git_available() {
+            return;
+        }
+        let dir = tempfile::tempdir().unwrap();
+        run_git(dir.path(), &["init", "-b", "main"]);
+        run_git(dir.path(), &["config", "user.email", "test@test.com"]);
+        run_git(dir.path(), &["config", "user.name", "Test"]);
+        run_git(dir.path(), &["config", "commit.gpgsign", "false"]);
+        std::fs::create_dir_all(dir.path().join("src")).unwrap();
+        std::fs::write(dir.path().join("a.txt"), "one\n").unwrap();
+        std::fs::write(dir.path().join("src/b.rs"), "x\ny\nz\n").unwrap();
+        std::fs::write(dir.path().join("c.md"), "keep\n").unwrap();
+        run_git(dir.path(), &["add", "."]);
+        run_git(dir.path(), &["commit", "-m", "base"]);
+        std::fs::write(dir.path().join("a.txt"), "one\ntwo\nthree\n").unwrap();
+        std::fs::write(dir.path().join("src/b.rs"), "x\n").unwrap();
+        std::fs::write(dir.path().join("d.txt"), "new\n").unwrap();
+        run_git(dir.path(), &["add", "."]);
+        run_git(dir.path(), &["commit", "-m", "head"]);
+
+        let batched = diff_numstat(dir.path(), "HEAD~1..HEAD", &[]).unwrap();
+        let paths: Vec<&str> = batched.iter().map(|stat| stat.path.as_str()).collect();
+        assert_eq!(paths, ["a.txt", "d.txt", "src/b.rs"]);
+
+        let mut per_file: Vec<NumStat> = Vec::new();
+        for path in &paths {
+            per_file.extend(diff_numstat(dir.path(), "HEAD~1..HEAD", &[path]).unwrap());
+        }
+        assert_eq!(per_file, batched);
+
+        let subset = diff_numstat(dir.path(), "HEAD~1..HEAD", &["a.txt", "src/b.rs"]).unwrap();
+        assert_eq!(subset, [batched[0].clone(), batched[2].clone()]);
+        assert_eq!(commit_count(dir.path(), "HEAD~1..HEAD").unwrap(), 1);
+    }
+}
@@ -19,6 +19,22 @@ pub fn rev_exists(repo_root: &Path, rev: &str) -> bool {
         .unwrap_or(false)
 }
 
+/// Check whether commit `ancestor` is an ancestor of (or the same commit as)
+/// `descendant`. Returns `false` when either revision does not resolve.
+pub fn is_ancestor(repo_root: &Path, ancestor: &str, descendant: &str) -> bool {
+    git_cmd()
+        .arg("-C")
+        .arg(repo_root)
+        .args(["merge-base", "--is-ancestor", "--end-of-options"])
+        .arg(ancestor)
+        .arg(descendant)
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .map(|s| s.success())
+        .unwrap_or(false)
+}
+
 /// Resolve a base ref with a fallback chain for CI environments.
 ///
 /// Fallback order:
@@ -155,6 +171,40 @@ mod tests {
         assert!(!rev_exists(dir.path(), "--help"));
     }
 
+    #[test]
+    fn is_ancestor_follows_commit_history() {
+        if !git_available() {
+            return;
+        }
+        let dir = tempfile::tempdir().unwrap();
+
+        test_git(dir.path())
+            .args(["init", "-b", "main"])
+            .output()
+            .unwrap();
+        test_git(dir.path())
+            .args(["config", "user.email", "test@test.com"])
+            .output()
+            .unwrap();
+        test_git(dir.path())
+            .args(["config", "user.name", "Test"])
+            .output()
+            .unwrap();
+        for content in ["one", "two"] {
+            std::fs::write(dir.path().join("f.txt"), content).unwrap();
+            test_git(dir.path()).args(["add", "."]).output().unwrap();
+            test_git(dir.path())
+                .args(["commit", "-m", content])
+                .output()
+                .unwrap();
+        }
+
+        assert!(is_ancestor(dir.path(), "HEAD~1", "HEAD"));
+        assert!(is_ancestor(dir.path(), "HEAD", "HEAD"));
+        assert!(!is_ancestor(dir.path(), "HEAD", "HEAD~1"));
+        assert!(!is_ancestor(dir.path(), "no-such-rev", "HEAD"));
+    }
+
     #[test]
     fn resolve_base_ref_returns_requested_when_valid() {
         if !git_available() {
@@ -676,6 +676,7 @@ fn scenario_git_commit_is_debug_printable() {
         hash: Some("abc123".to_string()),
         subject: "feat: hello".to_string(),
         files: vec!["src/main.rs".to_string()],
+        message_truncated: false,
     };
     let debug = format!("{:?}", commit);
     assert!(debug.contains("1700000000"));
@@ -696,6 +697,7 @@ fn scenario_git_commit_clone_is_independent() {
         hash: Some("deadbeef".to_string()),
         subject: "init".to_string(),
         files: vec!["f.rs".to_string()],
+        message_truncated: false,
     };
     let mut cloned = original.clone();
     cloned.timestamp = 200;
@@ -717,6 +719,7 @@ fn scenario_git_commit_with_none_hash() {
         hash: None,
         subject: "initial".to_string(),
         files: vec![],
+        message_truncated: false,
     };
     // Then the hash field is None
     assert!(commit.hash.is_none());
@@ -734,6 +737,7 @@ fn scenario_git_commit_with_empty_files_list() {
         hash: Some("abc".to_string()),
         subject: "Merge branch".to_string(),
         files: Vec::new(),
+        message_truncated: false,
     };
     // Then files is empty
     assert!(commit.files.is_empty());
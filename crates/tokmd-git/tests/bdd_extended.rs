@@ -246,6 +246,7 @@ fn test_given_git_commit_with_no_hash_when_accessed_then_none() {
         hash: None,
         subject: String::new(),
         files: vec![],
+        message_truncated: false,
     };
     assert!(commit.hash.is_none());
 }
@@ -258,6 +259,7 @@ fn test_given_git_commit_with_empty_files_when_accessed_then_empty() {
         hash: Some("abc".to_string()),
         subject: "init".to_string(),
         files: vec![],
+        message_truncated: false,
     };
     assert!(commit.files.is_empty());
 }
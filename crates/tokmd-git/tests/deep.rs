@@ -663,6 +663,7 @@ fn test_git_commit_clone() {
         hash: Some("abc123".to_string()),
         subject: "hello".to_string(),
         files: vec!["a.rs".to_string(), "b.rs".to_string()],
+        message_truncated: false,
     };
     let c2 = c.clone();
     assert_eq!(c.timestamp, c2.timestamp);
@@ -680,6 +681,7 @@ fn test_git_commit_debug() {
         hash: None,
         subject: "test".to_string(),
         files: vec![],
+        message_truncated: false,
     };
     let debug = format!("{:?}", c);
     assert!(debug.contains("42"));
@@ -603,6 +603,7 @@ fn git_commit_clone_preserves_fields() {
         hash: Some("abc".to_string()),
         subject: "test".to_string(),
         files: vec!["a.rs".to_string(), "b.rs".to_string()],
+        message_truncated: false,
     };
     let c2 = c.clone();
     assert_eq!(c.timestamp, c2.timestamp);
@@ -620,6 +621,7 @@ fn git_commit_debug_format() {
         hash: None,
         subject: String::new(),
         files: vec![],
+        message_truncated: false,
     };
     let debug = format!("{c:?}");
     assert!(debug.contains("GitCommit"));
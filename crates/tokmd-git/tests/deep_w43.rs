@@ -16,6 +16,7 @@ fn git_commit_with_all_fields() {
         hash: Some("abc123def456".to_string()),
         subject: "feat: add parser".to_string(),
         files: vec!["src/parser.rs".to_string()],
+        message_truncated: false,
     };
     assert_eq!(c.timestamp, 1_700_000_000);
     assert_eq!(c.author, "dev@example.com");
@@ -31,6 +32,7 @@ fn git_commit_with_no_hash() {
         hash: None,
         subject: String::new(),
         files: vec![],
+        message_truncated: false,
     };
     assert!(c.hash.is_none());
     assert!(c.files.is_empty());
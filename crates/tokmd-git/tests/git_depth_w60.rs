@@ -118,6 +118,7 @@ fn git_commit_struct_fields_are_accessible() {
         hash: Some("abc123".to_string()),
         subject: "feat: add feature".to_string(),
         files: vec!["src/lib.rs".to_string()],
+        message_truncated: false,
     };
     assert_eq!(c.timestamp, 1_700_000_000);
     assert_eq!(c.author, "dev@example.com");
@@ -134,6 +135,7 @@ fn git_commit_clone_is_equal() {
         hash: None,
         subject: "init".to_string(),
         files: vec!["f.txt".to_string()],
+        message_truncated: false,
     };
     let c2 = c.clone();
     assert_eq!(c.timestamp, c2.timestamp);
@@ -151,6 +153,7 @@ fn git_commit_debug_format_not_empty() {
         hash: None,
         subject: String::new(),
         files: vec![],
+        message_truncated: false,
     };
     let dbg = format!("{c:?}");
     assert!(!dbg.is_empty());
@@ -165,6 +168,7 @@ fn git_commit_with_no_files_has_empty_vec() {
         hash: Some("deadbeef".to_string()),
         subject: "empty commit".to_string(),
         files: vec![],
+        message_truncated: false,
     };
     assert!(c.files.is_empty());
 }
@@ -178,6 +182,7 @@ fn git_commit_with_many_files() {
         hash: Some("ffff".to_string()),
         subject: "bulk change".to_string(),
         files,
+        message_truncated: false,
     };
     assert_eq!(c.files.len(), 100);
 }
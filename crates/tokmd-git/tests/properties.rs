@@ -285,6 +285,7 @@ proptest! {
             hash: None,
             subject: String::new(),
             files: files.clone(),
+            message_truncated: false,
         };
 
         prop_assert_eq!(commit.timestamp, ts);
@@ -378,6 +379,7 @@ proptest! {
             hash: None,
             subject: String::new(),
             files: vec![path.clone()],
+            message_truncated: false,
         };
 
         prop_assert_eq!(&commit.files[0], &path);
@@ -576,6 +578,7 @@ proptest! {
                 hash: None,
                 subject: String::new(),
                 files: vec![format!("file{}.rs", i)],
+                message_truncated: false,
             })
             .collect();
 
@@ -599,6 +602,7 @@ proptest! {
                 hash: None,
                 subject: String::new(),
                 files: vec![format!("file{}.rs", i)],
+                message_truncated: false,
             })
             .collect();
 
@@ -4,14 +4,55 @@ use std::collections::{BTreeMap, BTreeSet};
 use std::path::Path;
 
 use tokei::Languages;
+use tokmd_scan::percentile;
 use tokmd_types::{
-    ChildIncludeMode, ChildrenMode, ExportData, FileKind, FileRow, LangReport, LangRow,
-    ModuleReport, ModuleRow, Totals,
+    AvgMeasure, ChildIncludeMode, ChildrenMode, ExportData, ExportFilters, ExportSortKey,
+    ExportSummary, ExtensionRow, FileKind, FileRow, LangReport, LangRow, ModuleChildRow,
+    ModuleReport, ModuleRow, SortDirection, Totals,
 };
 
-use crate::children::aggregate_lang_rows;
-use crate::sorting::{sort_file_rows, sort_lang_rows, sort_module_rows};
-use crate::{avg, collect_file_rows, unique_parent_file_count_from_rows};
+use crate::children::aggregate_lang_groups;
+use crate::sorting::{sort_file_rows_by, sort_lang_rows, sort_module_rows};
+use crate::{avg, collect_file_rows, median, unique_parent_file_count_from_rows};
+
+/// Optional per-row detail to compute beyond the always-present totals and mean.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RowDetail {
+    /// Mean, median, or both; the median fills `median_lines`.
+    pub avg: AvgMeasure,
+    /// Fill `p50_lines` and `p90_lines`.
+    pub percentiles: bool,
+    /// Embed the contributing file rows in `file_rows`.
+    pub file_rows: bool,
+}
+
+impl RowDetail {
+    fn needs_sizes(self) -> bool {
+        self.avg.includes_median() || self.percentiles
+    }
+}
+
+/// Optional size figures for one row, computed from its sorted file sizes.
+#[derive(Default)]
+struct SizeFigures {
+    median: Option<usize>,
+    p50: Option<usize>,
+    p90: Option<usize>,
+}
+
+impl SizeFigures {
+    fn of(sorted: &[usize], detail: RowDetail) -> Self {
+        Self {
+            median: detail.avg.includes_median().then(|| median(sorted)),
+            p50: detail
+                .percentiles
+                .then(|| percentile(sorted, 0.50) as usize),
+            p90: detail
+                .percentiles
+                .then(|| percentile(sorted, 0.90) as usize),
+        }
+    }
+}
 
 pub fn create_lang_report(
     languages: &Languages,
@@ -29,7 +70,44 @@ pub fn create_lang_report_from_rows(
     with_files: bool,
     children: ChildrenMode,
 ) -> LangReport {
-    let mut rows = aggregate_lang_rows(file_rows, children);
+    create_lang_report_from_rows_with_detail(
+        file_rows,
+        top,
+        with_files,
+        children,
+        RowDetail::default(),
+    )
+}
+
+/// Build a language report, also filling the optional per-file size figures
+/// (`median_lines`, `p50_lines`, `p90_lines`) that `stats` asks for on every
+/// row and the totals.
+pub fn create_lang_report_from_rows_with_detail(
+    file_rows: &[FileRow],
+    top: usize,
+    with_files: bool,
+    children: ChildrenMode,
+    detail: RowDetail,
+) -> LangReport {
+    let with_sizes = detail.needs_sizes();
+    let mut sizes_by_lang: BTreeMap<String, Vec<usize>> = BTreeMap::new();
+    let mut rows: Vec<LangRow> = aggregate_lang_groups(file_rows, children)
+        .into_iter()
+        .map(|group| {
+            let mut row = group.row;
+            if with_sizes {
+                let figures = SizeFigures::of(&group.sizes, detail);
+                row.median_lines = figures.median;
+                row.p50_lines = figures.p50;
+                row.p90_lines = figures.p90;
+                sizes_by_lang.insert(row.lang.clone(), group.sizes);
+            }
+            if detail.file_rows {
+                row.file_rows = group.sources.into_iter().cloned().collect();
+            }
+            row
+        })
+        .collect();
     sort_lang_rows(&mut rows);
 
     let total_code: usize = rows.iter().map(|r| r.code).sum();
@@ -37,6 +115,11 @@ pub fn create_lang_report_from_rows(
     let total_bytes: usize = rows.iter().map(|r| r.bytes).sum();
     let total_tokens: usize = rows.iter().map(|r| r.tokens).sum();
     let total_files = unique_parent_file_count_from_rows(file_rows);
+    let total_figures = if with_sizes {
+        SizeFigures::of(&parent_file_sizes(file_rows), detail)
+    } else {
+        SizeFigures::default()
+    };
 
     let total = Totals {
         code: total_code,
@@ -45,10 +128,20 @@ pub fn create_lang_report_from_rows(
         bytes: total_bytes,
         tokens: total_tokens,
         avg_lines: avg(total_lines, total_files),
+        median_lines: total_figures.median,
+        p50_lines: total_figures.p50,
+        p90_lines: total_figures.p90,
     };
 
     if top > 0 && rows.len() > top {
-        let other = fold_other_lang(&rows[top..]);
+        let mut other = fold_other_lang(&rows[top..]);
+        if with_sizes {
+            let sizes = folded_sizes(&rows[top..], |r| sizes_by_lang.get(&r.lang));
+            let figures = SizeFigures::of(&sizes, detail);
+            other.median_lines = figures.median;
+            other.p50_lines = figures.p50;
+            other.p90_lines = figures.p90;
+        }
         rows.truncate(top);
         rows.push(other);
     }
@@ -85,6 +178,10 @@ fn fold_other_lang(rows: &[LangRow]) -> LangRow {
         bytes,
         tokens,
         avg_lines: avg(lines, files),
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
+        file_rows: rows.iter().flat_map(|r| r.file_rows.clone()).collect(),
     }
 }
 
@@ -105,6 +202,27 @@ pub fn create_module_report_from_rows(
     module_depth: usize,
     children: ChildIncludeMode,
     top: usize,
+) -> ModuleReport {
+    create_module_report_from_rows_with_detail(
+        file_rows,
+        module_roots,
+        module_depth,
+        children,
+        top,
+        RowDetail::default(),
+    )
+}
+
+/// Build a module report, also filling the optional per-file size figures
+/// (`median_lines`, `p50_lines`, `p90_lines`) that `stats` asks for on every
+/// row and the totals.
+pub fn create_module_report_from_rows_with_detail(
+    file_rows: &[FileRow],
+    module_roots: &[String],
+    module_depth: usize,
+    children: ChildIncludeMode,
+    top: usize,
+    detail: RowDetail,
 ) -> ModuleReport {
     #[derive(Default)]
     struct Agg {
@@ -114,7 +232,9 @@ pub fn create_module_report_from_rows(
         tokens: usize,
     }
 
-    let mut by_module: BTreeMap<&str, (Agg, BTreeSet<&str>)> = BTreeMap::new();
+    let with_sizes = detail.needs_sizes();
+    let mut by_module: BTreeMap<&str, (Agg, BTreeMap<&str, usize>)> = BTreeMap::new();
+    let mut sources_by_module: BTreeMap<&str, Vec<&FileRow>> = BTreeMap::new();
     let mut total_code = 0;
     let mut total_lines = 0;
     let mut total_bytes = 0;
@@ -128,20 +248,39 @@ pub fn create_module_report_from_rows(
 
         let entry = by_module
             .entry(r.module.as_str())
-            .or_insert_with(|| (Agg::default(), BTreeSet::new()));
+            .or_insert_with(|| (Agg::default(), BTreeMap::new()));
         entry.0.code += r.code;
         entry.0.lines += r.lines;
         entry.0.bytes += r.bytes;
         entry.0.tokens += r.tokens;
 
         if r.kind == FileKind::Parent {
-            entry.1.insert(r.path.as_str());
+            entry.1.insert(r.path.as_str(), r.lines);
+        }
+        if detail.file_rows {
+            sources_by_module
+                .entry(r.module.as_str())
+                .or_default()
+                .push(r);
         }
     }
 
+    let mut children_by_module = if children == ChildIncludeMode::Nested {
+        module_child_rows(file_rows)
+    } else {
+        BTreeMap::new()
+    };
+    let mut sizes_by_module: BTreeMap<String, Vec<usize>> = BTreeMap::new();
     let mut rows: Vec<ModuleRow> = Vec::with_capacity(by_module.len());
-    for (module, (agg, files_set)) in by_module {
-        let files = files_set.len();
+    for (module, (agg, file_lines)) in by_module {
+        let files = file_lines.len();
+        let mut figures = SizeFigures::default();
+        if with_sizes {
+            let mut sizes: Vec<usize> = file_lines.into_values().collect();
+            sizes.sort_unstable();
+            figures = SizeFigures::of(&sizes, detail);
+            sizes_by_module.insert(module.to_string(), sizes);
+        }
         rows.push(ModuleRow {
             module: module.to_string(),
             code: agg.code,
@@ -150,18 +289,38 @@ pub fn create_module_report_from_rows(
             bytes: agg.bytes,
             tokens: agg.tokens,
             avg_lines: avg(agg.lines, files),
+            median_lines: figures.median,
+            p50_lines: figures.p50,
+            p90_lines: figures.p90,
+            file_rows: sources_by_module
+                .remove(module)
+                .map(|sources| sources.into_iter().cloned().collect())
+                .unwrap_or_default(),
+            children: children_by_module.remove(module).unwrap_or_default(),
         });
     }
 
     sort_module_rows(&mut rows);
 
     if top > 0 && rows.len() > top {
-        let other = fold_other_module(&rows[top..]);
+        let mut other = fold_other_module(&rows[top..]);
+        if with_sizes {
+            let sizes = folded_sizes(&rows[top..], |r| sizes_by_module.get(&r.module));
+            let figures = SizeFigures::of(&sizes, detail);
+            other.median_lines = figures.median;
+            other.p50_lines = figures.p50;
+            other.p90_lines = figures.p90;
+        }
         rows.truncate(top);
         rows.push(other);
     }
 
     let total_files = unique_parent_file_count_from_rows(file_rows);
+    let total_figures = if with_sizes {
+        SizeFigures::of(&parent_file_sizes(file_rows), detail)
+    } else {
+        SizeFigures::default()
+    };
 
     let total = Totals {
         code: total_code,
@@ -170,6 +329,9 @@ pub fn create_module_report_from_rows(
         bytes: total_bytes,
         tokens: total_tokens,
         avg_lines: avg(total_lines, total_files),
+        median_lines: total_figures.median,
+        p50_lines: total_figures.p50,
+        p90_lines: total_figures.p90,
     };
 
     ModuleReport {
@@ -205,7 +367,117 @@ fn fold_other_module(rows: &[ModuleRow]) -> ModuleRow {
         bytes,
         tokens,
         avg_lines: avg(lines, files),
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
+        file_rows: rows.iter().flat_map(|r| r.file_rows.clone()).collect(),
+        children: merge_child_rows(rows.iter().flat_map(|r| &r.children)),
+    }
+}
+
+/// Per-module embedded-language rows built from the child file rows.
+fn module_child_rows(file_rows: &[FileRow]) -> BTreeMap<&str, Vec<ModuleChildRow>> {
+    let mut by_module: BTreeMap<&str, BTreeMap<&str, (ModuleChildRow, BTreeSet<&str>)>> =
+        BTreeMap::new();
+    for r in file_rows.iter().filter(|r| r.kind == FileKind::Child) {
+        let (child, paths) = by_module
+            .entry(r.module.as_str())
+            .or_default()
+            .entry(r.lang.as_str())
+            .or_insert_with(|| (empty_child_row(&r.lang), BTreeSet::new()));
+        child.code += r.code;
+        child.lines += r.lines;
+        child.bytes += r.bytes;
+        child.tokens += r.tokens;
+        paths.insert(r.path.as_str());
+    }
+
+    by_module
+        .into_iter()
+        .map(|(module, langs)| {
+            let mut rows: Vec<ModuleChildRow> = langs
+                .into_values()
+                .map(|(mut child, paths)| {
+                    child.files = paths.len();
+                    child
+                })
+                .collect();
+            sort_child_rows(&mut rows);
+            (module, rows)
+        })
+        .collect()
+}
+
+/// Sum child rows of the same language, for the folded "Other" module.
+fn merge_child_rows<'a>(children: impl Iterator<Item = &'a ModuleChildRow>) -> Vec<ModuleChildRow> {
+    let mut by_lang: BTreeMap<&str, ModuleChildRow> = BTreeMap::new();
+    for c in children {
+        let merged = by_lang
+            .entry(c.lang.as_str())
+            .or_insert_with(|| empty_child_row(&c.lang));
+        merged.code += c.code;
+        merged.lines += c.lines;
+        merged.files += c.files;
+        merged.bytes += c.bytes;
+        merged.tokens += c.tokens;
     }
+    let mut rows: Vec<ModuleChildRow> = by_lang.into_values().collect();
+    sort_child_rows(&mut rows);
+    rows
+}
+
+fn empty_child_row(lang: &str) -> ModuleChildRow {
+    ModuleChildRow {
+        lang: lang.to_string(),
+        code: 0,
+        lines: 0,
+        files: 0,
+        bytes: 0,
+        tokens: 0,
+    }
+}
+
+fn sort_child_rows(rows: &mut [ModuleChildRow]) {
+    rows.sort_by(|a, b| b.code.cmp(&a.code).then_with(|| a.lang.cmp(&b.lang)));
+}
+
+/// Sorted line counts of unique parent files.
+fn parent_file_sizes(file_rows: &[FileRow]) -> Vec<usize> {
+    let by_path: BTreeMap<&str, usize> = file_rows
+        .iter()
+        .filter(|r| r.kind == FileKind::Parent)
+        .map(|r| (r.path.as_str(), r.lines))
+        .collect();
+    let mut sizes: Vec<usize> = by_path.into_values().collect();
+    sizes.sort_unstable();
+    sizes
+}
+
+/// Sorted per-file sizes of every row folded into "Other".
+fn folded_sizes<'a, R>(rows: &[R], sizes_of: impl Fn(&R) -> Option<&'a Vec<usize>>) -> Vec<usize> {
+    let mut sizes: Vec<usize> = rows
+        .iter()
+        .filter_map(sizes_of)
+        .flatten()
+        .copied()
+        .collect();
+    sizes.sort_unstable();
+    sizes
+}
+
+/// Row selection and ordering applied when building export data.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ExportRowOptions {
+    /// Drop rows with fewer than this many code lines (0 = keep all).
+    pub min_code: usize,
+    /// Per-metric bounds; every set bound must hold.
+    pub filters: ExportFilters,
+    /// Sort column; `None` sorts by code.
+    pub sort_by: Option<ExportSortKey>,
+    /// Sort direction; `None` uses the key's default direction.
+    pub sort_dir: Option<SortDirection>,
+    /// Keep at most this many rows after sorting (0 = unlimited).
+    pub max_rows: usize,
 }
 
 pub fn create_export_data(
@@ -216,6 +488,30 @@ pub fn create_export_data(
     strip_prefix: Option<&Path>,
     min_code: usize,
     max_rows: usize,
+) -> ExportData {
+    create_export_data_with_options(
+        languages,
+        module_roots,
+        module_depth,
+        children,
+        strip_prefix,
+        &ExportRowOptions {
+            min_code,
+            max_rows,
+            ..ExportRowOptions::default()
+        },
+    )
+}
+
+/// Like [`create_export_data`], with filtering and sorting taken from
+/// `options`.
+pub fn create_export_data_with_options(
+    languages: &Languages,
+    module_roots: &[String],
+    module_depth: usize,
+    children: ChildIncludeMode,
+    strip_prefix: Option<&Path>,
+    options: &ExportRowOptions,
 ) -> ExportData {
     let rows = collect_file_rows(
         languages,
@@ -224,31 +520,54 @@ pub fn create_export_data(
         children,
         strip_prefix,
     );
-    create_export_data_from_rows(
+    create_export_data_from_rows_with_options(rows, module_roots, module_depth, children, options)
+}
+
+pub fn create_export_data_from_rows(
+    rows: Vec<FileRow>,
+    module_roots: &[String],
+    module_depth: usize,
+    children: ChildIncludeMode,
+    min_code: usize,
+    max_rows: usize,
+) -> ExportData {
+    create_export_data_from_rows_with_options(
         rows,
         module_roots,
         module_depth,
         children,
-        min_code,
-        max_rows,
+        &ExportRowOptions {
+            min_code,
+            max_rows,
+            ..ExportRowOptions::default()
+        },
     )
 }
 
-pub fn create_export_data_from_rows(
+/// Like [`create_export_data_from_rows`], with filtering and sorting taken
+/// from `options`. Filters and sorting are applied before `max_rows`
+/// truncation, so `max_rows` keeps the top rows of the chosen order.
+pub fn create_export_data_from_rows_with_options(
     mut rows: Vec<FileRow>,
     module_roots: &[String],
     module_depth: usize,
     children: ChildIncludeMode,
-    min_code: usize,
-    max_rows: usize,
+    options: &ExportRowOptions,
 ) -> ExportData {
-    if min_code > 0 {
-        rows.retain(|r| r.code >= min_code);
+    if options.min_code > 0 {
+        rows.retain(|r| r.code >= options.min_code);
+    }
+    if !options.filters.is_empty() {
+        rows.retain(|r| options.filters.matches(r));
     }
-    sort_file_rows(&mut rows);
+    let sort_by = options.sort_by.unwrap_or_default();
+    let sort_dir = options
+        .sort_dir
+        .unwrap_or_else(|| sort_by.default_direction());
+    sort_file_rows_by(&mut rows, sort_by, sort_dir);
 
-    if max_rows > 0 && rows.len() > max_rows {
-        rows.truncate(max_rows);
+    if options.max_rows > 0 && rows.len() > options.max_rows {
+        rows.truncate(options.max_rows);
     }
 
     ExportData {
@@ -259,6 +578,56 @@ pub fn create_export_data_from_rows(
     }
 }
 
+/// Summarize export rows per language, per extension, and overall, with
+/// mean, median, and p50/p90 file sizes.
+pub fn create_export_summary(rows: &[FileRow]) -> ExportSummary {
+    let report = create_lang_report_from_rows_with_detail(
+        rows,
+        0,
+        true,
+        ChildrenMode::Separate,
+        RowDetail {
+            avg: AvgMeasure::Both,
+            percentiles: true,
+            file_rows: false,
+        },
+    );
+    ExportSummary {
+        by_lang: report.rows,
+        by_ext: extension_rows(rows),
+        total: report.total,
+    }
+}
+
+/// Per-extension counts over parent rows, most tokens first.
+fn extension_rows(rows: &[FileRow]) -> Vec<ExtensionRow> {
+    let mut by_ext: BTreeMap<String, ExtensionRow> = BTreeMap::new();
+    for row in rows.iter().filter(|r| r.kind == FileKind::Parent) {
+        let ext = file_extension(&row.path);
+        let entry = by_ext.entry(ext.clone()).or_insert_with(|| ExtensionRow {
+            ext,
+            files: 0,
+            bytes: 0,
+            tokens: 0,
+        });
+        entry.files += 1;
+        entry.bytes += row.bytes;
+        entry.tokens += row.tokens;
+    }
+    let mut out: Vec<ExtensionRow> = by_ext.into_values().collect();
+    out.sort_by(|a, b| b.tokens.cmp(&a.tokens).then_with(|| a.ext.cmp(&b.ext)));
+    out
+}
+
+/// Lowercased extension of a normalized path, or `(none)`. Dotfiles such as
+/// `.gitignore` have no extension.
+fn file_extension(path: &str) -> String {
+    Path::new(path)
+        .extension()
+        .map(|ext| ext.to_string_lossy().to_ascii_lowercase())
+        .unwrap_or_else(|| "(none)".to_string())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -283,6 +652,10 @@ mod tests {
                     bytes,
                     tokens,
                     avg_lines,
+                    median_lines: None,
+                    p50_lines: None,
+                    p90_lines: None,
+                    file_rows: Vec::new(),
                 }
             })
     }
@@ -306,10 +679,466 @@ mod tests {
                     bytes,
                     tokens,
                     avg_lines,
+                    median_lines: None,
+                    p50_lines: None,
+                    p90_lines: None,
+                    file_rows: Vec::new(),
+                    children: Vec::new(),
                 }
             })
     }
 
+    fn file(path: &str, module: &str, lines: usize) -> FileRow {
+        FileRow {
+            path: path.to_string(),
+            module: module.to_string(),
+            lang: "Rust".to_string(),
+            kind: FileKind::Parent,
+            code: lines,
+            comments: 0,
+            blanks: 0,
+            lines,
+            bytes: lines * 10,
+            tokens: lines * 3,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
+        }
+    }
+
+    /// Four small files and one generated giant: the mean is dragged up by
+    /// the outlier while the median stays with the typical file.
+    fn outlier_rows() -> Vec<FileRow> {
+        vec![
+            file("src/a.rs", "src", 10),
+            file("src/b.rs", "src", 12),
+            file("src/c.rs", "src", 14),
+            file("src/d.rs", "src", 16),
+            file("src/generated.rs", "src", 5000),
+        ]
+    }
+
+    #[test]
+    fn lang_median_resists_outlier() {
+        let report = create_lang_report_from_rows_with_detail(
+            &outlier_rows(),
+            0,
+            true,
+            ChildrenMode::Collapse,
+            RowDetail {
+                avg: AvgMeasure::Both,
+                percentiles: false,
+                file_rows: false,
+            },
+        );
+        let rust = &report.rows[0];
+        assert_eq!(rust.avg_lines, 1010);
+        assert_eq!(rust.median_lines, Some(14));
+        assert_eq!(report.total.median_lines, Some(14));
+    }
+
+    #[test]
+    fn module_median_resists_outlier() {
+        let report = create_module_report_from_rows_with_detail(
+            &outlier_rows(),
+            &[],
+            1,
+            ChildIncludeMode::ParentsOnly,
+            0,
+            RowDetail {
+                avg: AvgMeasure::Median,
+                percentiles: false,
+                file_rows: false,
+            },
+        );
+        assert_eq!(report.rows[0].avg_lines, 1010);
+        assert_eq!(report.rows[0].median_lines, Some(14));
+        assert_eq!(report.total.median_lines, Some(14));
+    }
+
+    #[test]
+    fn mean_measure_leaves_median_unset() {
+        let report = create_lang_report_from_rows(&outlier_rows(), 0, true, ChildrenMode::Collapse);
+        assert_eq!(report.rows[0].median_lines, None);
+        assert_eq!(report.total.median_lines, None);
+    }
+
+    #[test]
+    fn lang_percentiles_use_nearest_rank() {
+        let rows: Vec<FileRow> = (1..=10)
+            .map(|i| file(&format!("src/f{i}.rs"), "src", i * 10))
+            .collect();
+        let report = create_lang_report_from_rows_with_detail(
+            &rows,
+            0,
+            true,
+            ChildrenMode::Collapse,
+            RowDetail {
+                avg: AvgMeasure::Mean,
+                percentiles: true,
+                file_rows: false,
+            },
+        );
+        let rust = &report.rows[0];
+        assert_eq!(rust.p50_lines, Some(60));
+        assert_eq!(rust.p90_lines, Some(100));
+        assert_eq!(rust.median_lines, None);
+        assert_eq!(report.total.p90_lines, Some(100));
+    }
+
+    #[test]
+    fn module_percentiles_are_per_module() {
+        let mut rows = outlier_rows();
+        rows.push(file("tools/a.rs", "tools", 7));
+        let report = create_module_report_from_rows_with_detail(
+            &rows,
+            &[],
+            1,
+            ChildIncludeMode::ParentsOnly,
+            0,
+            RowDetail {
+                avg: AvgMeasure::Mean,
+                percentiles: true,
+                file_rows: false,
+            },
+        );
+        let src = report.rows.iter().find(|r| r.module == "src").unwrap();
+        assert_eq!(src.p50_lines, Some(14));
+        assert_eq!(src.p90_lines, Some(5000));
+        let tools = report.rows.iter().find(|r| r.module == "tools").unwrap();
+        assert_eq!(tools.p50_lines, Some(7));
+        assert_eq!(tools.p90_lines, Some(7));
+    }
+
+    #[test]
+    fn files_detail_embeds_rows_per_group() {
+        let mut rows = outlier_rows();
+        rows.push(file("tools/a.rs", "tools", 7));
+        let detail = RowDetail {
+            file_rows: true,
+            ..RowDetail::default()
+        };
+
+        let lang = create_lang_report_from_rows_with_detail(
+            &rows,
+            0,
+            true,
+            ChildrenMode::Collapse,
+            detail,
+        );
+        assert_eq!(lang.rows[0].file_rows.len(), 6);
+
+        let module = create_module_report_from_rows_with_detail(
+            &rows,
+            &[],
+            1,
+            ChildIncludeMode::ParentsOnly,
+            1,
+            detail,
+        );
+        assert_eq!(module.rows[0].module, "src");
+        assert_eq!(module.rows[0].file_rows.len(), 5);
+        let other = &module.rows[1];
+        assert_eq!(other.module, "Other");
+        assert_eq!(other.file_rows.len(), 1);
+        assert_eq!(other.file_rows[0].path, "tools/a.rs");
+    }
+
+    #[test]
+    fn files_detail_off_keeps_rows_small() {
+        let report = create_module_report_from_rows(
+            &outlier_rows(),
+            &[],
+            1,
+            ChildIncludeMode::ParentsOnly,
+            0,
+        );
+        assert!(report.rows[0].file_rows.is_empty());
+        let json = serde_json::to_string(&report).unwrap();
+        assert!(!json.contains("file_rows"));
+    }
+
+    /// Two Markdown files in `docs`, each embedding Rust, plus one embedding
+    /// TOML, and a plain Rust file in `src`.
+    fn embedded_rows() -> Vec<FileRow> {
+        let doc = |path: &str, lines| FileRow {
+            lang: "Markdown".to_string(),
+            ..file(path, "docs", lines)
+        };
+        let child = |path: &str, lang: &str, lines| FileRow {
+            lang: lang.to_string(),
+            kind: FileKind::Child,
+            bytes: 0,
+            tokens: 0,
+            ..file(path, "docs", lines)
+        };
+        vec![
+            doc("docs/a.md", 20),
+            child("docs/a.md", "Rust", 6),
+            doc("docs/b.md", 30),
+            child("docs/b.md", "Rust", 4),
+            child("docs/b.md", "TOML", 3),
+            file("src/lib.rs", "src", 100),
+        ]
+    }
+
+    #[test]
+    fn nested_children_list_embedded_languages_under_their_module() {
+        let report =
+            create_module_report_from_rows(&embedded_rows(), &[], 1, ChildIncludeMode::Nested, 0);
+
+        let src = &report.rows[0];
+        assert_eq!(src.module, "src");
+        assert!(src.children.is_empty());
+
+        let docs = &report.rows[1];
+        assert_eq!((docs.code, docs.files), (63, 2));
+        let langs: Vec<_> = docs
+            .children
+            .iter()
+            .map(|c| (c.lang.as_str(), c.code, c.files))
+            .collect();
+        assert_eq!(langs, vec![("Rust", 10, 2), ("TOML", 3, 1)]);
+    }
+
+    #[test]
+    fn nested_children_count_like_separate() {
+        let nested =
+            create_module_report_from_rows(&embedded_rows(), &[], 1, ChildIncludeMode::Nested, 0);
+        let separate =
+            create_module_report_from_rows(&embedded_rows(), &[], 1, ChildIncludeMode::Separate, 0);
+
+        assert_eq!(nested.total, separate.total);
+        for (n, s) in nested.rows.iter().zip(&separate.rows) {
+            assert_eq!((&n.module, n.code, n.lines), (&s.module, s.code, s.lines));
+            assert!(s.children.is_empty());
+        }
+    }
+
+    #[test]
+    fn nested_children_merge_into_other_row() {
+        let mut rows = embedded_rows();
+        rows.push(FileRow {
+            lang: "Markdown".to_string(),
+            ..file("guide/c.md", "guide", 10)
+        });
+        rows.push(FileRow {
+            lang: "Rust".to_string(),
+            kind: FileKind::Child,
+            ..file("guide/c.md", "guide", 2)
+        });
+        let report = create_module_report_from_rows(&rows, &[], 1, ChildIncludeMode::Nested, 1);
+
+        let other = report.rows.last().unwrap();
+        assert_eq!(other.module, "Other");
+        assert_eq!(other.children.len(), 2);
+        assert_eq!(other.children[0].lang, "Rust");
+        assert_eq!((other.children[0].code, other.children[0].files), (12, 3));
+    }
+
+    #[test]
+    fn other_row_median_covers_folded_files() {
+        let mut rows = outlier_rows();
+        rows.push(FileRow {
+            lang: "Python".to_string(),
+            ..file("tools/x.py", "tools", 2)
+        });
+        rows.push(FileRow {
+            lang: "TOML".to_string(),
+            ..file("Cargo.toml", "(root)", 40)
+        });
+        let report = create_lang_report_from_rows_with_detail(
+            &rows,
+            1,
+            false,
+            ChildrenMode::Collapse,
+            RowDetail {
+                avg: AvgMeasure::Median,
+                percentiles: false,
+                file_rows: false,
+            },
+        );
+        let other = report.rows.last().unwrap();
+        assert_eq!(other.lang, "Other");
+        assert_eq!(other.median_lines, Some(21));
+    }
+
+    #[test]
+    fn metric_filters_keep_the_intersection() {
+        // tokens = 3 * lines, bytes = 10 * lines.
+        let rows = vec![
+            file("src/tiny.rs", "src", 5),
+            file("src/mid.rs", "src", 50),
+            file("src/big.rs", "src", 500),
+        ];
+        let options = ExportRowOptions {
+            filters: ExportFilters {
+                min_tokens: Some(100),
+                max_bytes: Some(1000),
+                ..ExportFilters::default()
+            },
+            ..ExportRowOptions::default()
+        };
+
+        let data = create_export_data_from_rows_with_options(
+            rows.clone(),
+            &[],
+            1,
+            ChildIncludeMode::Separate,
+            &options,
+        );
+        let paths: Vec<&str> = data.rows.iter().map(|r| r.path.as_str()).collect();
+        assert_eq!(paths, vec!["src/mid.rs"]);
+
+        let min_only = ExportRowOptions {
+            filters: ExportFilters {
+                min_tokens: Some(100),
+                ..ExportFilters::default()
+            },
+            max_rows: 1,
+            ..ExportRowOptions::default()
+        };
+        let data = create_export_data_from_rows_with_options(
+            rows,
+            &[],
+            1,
+            ChildIncludeMode::Separate,
+            &min_only,
+        );
+        assert_eq!(data.rows.len(), 1);
+        assert_eq!(data.rows[0].path, "src/big.rs");
+    }
+
+    #[test]
+    fn sort_by_tokens_then_max_rows_keeps_largest() {
+        let rows = vec![
+            FileRow {
+                tokens: 900,
+                ..file("src/a.rs", "src", 1)
+            },
+            FileRow {
+                tokens: 50,
+                ..file("src/b.rs", "src", 400)
+            },
+            FileRow {
+                tokens: 700,
+                ..file("src/c.rs", "src", 2)
+            },
+            FileRow {
+                tokens: 700,
+                ..file("src/d.rs", "src", 3)
+            },
+            FileRow {
+                tokens: 10,
+                ..file("src/e.rs", "src", 4)
+            },
+        ];
+        let options = ExportRowOptions {
+            sort_by: Some(ExportSortKey::Tokens),
+            sort_dir: Some(SortDirection::Desc),
+            max_rows: 3,
+            ..ExportRowOptions::default()
+        };
+
+        let data = create_export_data_from_rows_with_options(
+            rows,
+            &[],
+            1,
+            ChildIncludeMode::Separate,
+            &options,
+        );
+        let paths: Vec<&str> = data.rows.iter().map(|r| r.path.as_str()).collect();
+        // Ties on tokens fall back to path order.
+        assert_eq!(paths, vec!["src/a.rs", "src/c.rs", "src/d.rs"]);
+    }
+
+    #[test]
+    fn export_summary_totals_match_kept_rows() {
+        let mut rows = outlier_rows();
+        rows.push(FileRow {
+            lang: "TOML".to_string(),
+            ..file("Cargo.toml", "(root)", 20)
+        });
+        let options = ExportRowOptions {
+            min_code: 11,
+            ..ExportRowOptions::default()
+        };
+
+        let data = create_export_data_from_rows_with_options(
+            rows,
+            &[],
+            1,
+            ChildIncludeMode::Separate,
+            &options,
+        );
+        let summary = create_export_summary(&data.rows);
+
+        assert_eq!(summary.total.files, data.rows.len());
+        assert_eq!(
+            summary.total.code,
+            data.rows.iter().map(|r| r.code).sum::<usize>()
+        );
+        assert_eq!(
+            summary.total.tokens,
+            data.rows.iter().map(|r| r.tokens).sum::<usize>()
+        );
+        let langs: Vec<(&str, usize)> = summary
+            .by_lang
+            .iter()
+            .map(|r| (r.lang.as_str(), r.files))
+            .collect();
+        assert_eq!(langs, vec![("Rust", 4), ("TOML", 1)]);
+        assert!(summary.total.median_lines.is_some());
+        assert!(summary.total.p90_lines.is_some());
+    }
+
+    #[test]
+    fn export_summary_sums_tokens_per_extension() {
+        let rows = vec![
+            file("src/lib.rs", "src", 100),
+            file("src/main.rs", "src", 40),
+            FileRow {
+                lang: "TOML".to_string(),
+                ..file("Cargo.toml", "(root)", 20)
+            },
+            FileRow {
+                lang: "TOML".to_string(),
+                ..file("crates/a/CARGO.TOML", "crates/a", 10)
+            },
+            FileRow {
+                lang: "Shell".to_string(),
+                ..file("scripts/bootstrap", "scripts", 5)
+            },
+            // Embedded child rows must not add files to the extension.
+            FileRow {
+                kind: FileKind::Child,
+                lang: "Markdown".to_string(),
+                bytes: 0,
+                tokens: 0,
+                ..file("src/lib.rs", "src", 7)
+            },
+        ];
+
+        let summary = create_export_summary(&rows);
+        let by_ext: Vec<(&str, usize, usize, usize)> = summary
+            .by_ext
+            .iter()
+            .map(|r| (r.ext.as_str(), r.files, r.bytes, r.tokens))
+            .collect();
+        assert_eq!(
+            by_ext,
+            vec![
+                ("rs", 2, 1_400, 420),
+                ("toml", 2, 300, 90),
+                ("(none)", 1, 50, 15),
+            ]
+        );
+        assert_eq!(
+            summary.by_ext.iter().map(|r| r.tokens).sum::<usize>(),
+            summary.total.tokens
+        );
+    }
+
     proptest! {
         #[test]
         fn fold_lang_preserves_totals(rows in prop::collection::vec(arb_lang_row(), 0..10)) {
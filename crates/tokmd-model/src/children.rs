@@ -1,20 +1,53 @@
 //! Child and embedded-language aggregation for model receipts.
 
-use std::collections::{BTreeMap, BTreeSet};
+use std::collections::BTreeMap;
 
 use tokmd_types::{ChildrenMode, FileKind, FileRow, LangRow};
 
 use crate::avg;
 
 #[derive(Default)]
-struct LangAgg {
+struct LangAgg<'a> {
     code: usize,
     lines: usize,
     bytes: usize,
     tokens: usize,
+    /// Lines contributed by each file path to this group.
+    file_lines: BTreeMap<&'a str, usize>,
+    /// File rows that contributed to this group, in input order.
+    sources: Vec<&'a FileRow>,
 }
 
+impl<'a> LangAgg<'a> {
+    fn add_file(&mut self, row: &'a FileRow, lines: usize) {
+        *self.file_lines.entry(row.path.as_str()).or_default() += lines;
+        self.sources.push(row);
+    }
+}
+
+/// One aggregated language row plus what it was built from.
+pub(crate) struct LangGroup<'a> {
+    pub(crate) row: LangRow,
+    /// Per-file line counts, sorted ascending.
+    pub(crate) sizes: Vec<usize>,
+    /// Contributing file rows, in input order.
+    pub(crate) sources: Vec<&'a FileRow>,
+}
+
+#[cfg(test)]
 pub(crate) fn aggregate_lang_rows(file_rows: &[FileRow], children: ChildrenMode) -> Vec<LangRow> {
+    aggregate_lang_groups(file_rows, children)
+        .into_iter()
+        .map(|group| group.row)
+        .collect()
+}
+
+/// Aggregate file rows into language rows, keeping the per-file sizes and
+/// contributing rows of each group.
+pub(crate) fn aggregate_lang_groups(
+    file_rows: &[FileRow],
+    children: ChildrenMode,
+) -> Vec<LangGroup<'_>> {
     let parent_lang_by_path: BTreeMap<&str, &str> = file_rows
         .iter()
         .filter(|row| row.kind == FileKind::Parent)
@@ -27,30 +60,26 @@ pub(crate) fn aggregate_lang_rows(file_rows: &[FileRow], children: ChildrenMode)
         entry.1 += row.lines;
     }
 
-    let mut by_lang: BTreeMap<(&str, bool), (LangAgg, BTreeSet<&str>)> = BTreeMap::new();
+    let mut by_lang: BTreeMap<(&str, bool), LangAgg<'_>> = BTreeMap::new();
 
     for row in file_rows {
         match (children, row.kind) {
             (ChildrenMode::Collapse, FileKind::Parent) => {
-                let entry = by_lang
-                    .entry((row.lang.as_str(), false))
-                    .or_insert_with(|| (LangAgg::default(), BTreeSet::new()));
-                entry.0.code += row.code;
-                entry.0.lines += row.lines;
-                entry.0.bytes += row.bytes;
-                entry.0.tokens += row.tokens;
-                entry.1.insert(row.path.as_str());
+                let entry = by_lang.entry((row.lang.as_str(), false)).or_default();
+                entry.code += row.code;
+                entry.lines += row.lines;
+                entry.bytes += row.bytes;
+                entry.tokens += row.tokens;
+                entry.add_file(row, row.lines);
             }
             (ChildrenMode::Collapse, FileKind::Child) => {
                 if !parent_lang_by_path.contains_key(row.path.as_str()) {
-                    let entry = by_lang
-                        .entry((row.lang.as_str(), false))
-                        .or_insert_with(|| (LangAgg::default(), BTreeSet::new()));
-                    entry.0.code += row.code;
-                    entry.0.lines += row.lines;
-                    entry.0.bytes += row.bytes;
-                    entry.0.tokens += row.tokens;
-                    entry.1.insert(row.path.as_str());
+                    let entry = by_lang.entry((row.lang.as_str(), false)).or_default();
+                    entry.code += row.code;
+                    entry.lines += row.lines;
+                    entry.bytes += row.bytes;
+                    entry.tokens += row.tokens;
+                    entry.add_file(row, row.lines);
                 }
             }
             (ChildrenMode::Separate, FileKind::Parent) => {
@@ -59,34 +88,33 @@ pub(crate) fn aggregate_lang_rows(file_rows: &[FileRow], children: ChildrenMode)
                     .copied()
                     .unwrap_or((0, 0));
 
-                let entry = by_lang
-                    .entry((row.lang.as_str(), false))
-                    .or_insert_with(|| (LangAgg::default(), BTreeSet::new()));
-                entry.0.code += row.code.saturating_sub(child_code);
-                entry.0.lines += row.lines.saturating_sub(child_lines);
-                entry.0.bytes += row.bytes;
-                entry.0.tokens += row.tokens;
-                entry.1.insert(row.path.as_str());
+                let entry = by_lang.entry((row.lang.as_str(), false)).or_default();
+                entry.code += row.code.saturating_sub(child_code);
+                let lines = row.lines.saturating_sub(child_lines);
+                entry.lines += lines;
+                entry.bytes += row.bytes;
+                entry.tokens += row.tokens;
+                entry.add_file(row, lines);
             }
             (ChildrenMode::Separate, FileKind::Child) => {
-                let entry = by_lang
-                    .entry((row.lang.as_str(), true))
-                    .or_insert_with(|| (LangAgg::default(), BTreeSet::new()));
-                entry.0.code += row.code;
-                entry.0.lines += row.lines;
-                entry.1.insert(row.path.as_str());
+                let entry = by_lang.entry((row.lang.as_str(), true)).or_default();
+                entry.code += row.code;
+                entry.lines += row.lines;
+                entry.add_file(row, row.lines);
             }
         }
     }
 
     by_lang
         .into_iter()
-        .filter_map(|((lang, is_embedded), (agg, files_set))| {
+        .filter_map(|((lang, is_embedded), agg)| {
             if agg.code == 0 {
                 return None;
             }
-            let files = files_set.len();
-            Some(LangRow {
+            let files = agg.file_lines.len();
+            let mut sizes: Vec<usize> = agg.file_lines.into_values().collect();
+            sizes.sort_unstable();
+            let row = LangRow {
                 lang: if is_embedded {
                     format!("{lang} (embedded)")
                 } else {
@@ -98,6 +126,15 @@ pub(crate) fn aggregate_lang_rows(file_rows: &[FileRow], children: ChildrenMode)
                 bytes: agg.bytes,
                 tokens: agg.tokens,
                 avg_lines: avg(agg.lines, files),
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
+            };
+            Some(LangGroup {
+                row,
+                sizes,
+                sources: agg.sources,
             })
         })
         .collect()
@@ -119,6 +156,9 @@ mod tests {
             lines,
             bytes: if kind == FileKind::Parent { 120 } else { 0 },
             tokens: if kind == FileKind::Parent { 30 } else { 0 },
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         }
     }
 
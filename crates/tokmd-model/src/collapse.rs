@@ -0,0 +1,154 @@
+//! Single-child directory collapsing for module keys.
+//!
+//! A module whose files all sit below one chain of single-child directories
+//! (`src/a/b/c/*.rs` and nothing else under `src`) is relabelled with the
+//! deepest directory of that chain, mirroring how file explorers fold such
+//! chains into one node.
+
+use std::collections::BTreeMap;
+
+use tokmd_types::FileRow;
+
+/// Relabel each module with the deepest directory shared by all of its files.
+///
+/// Only extends a module key downwards: a module with a file directly in it,
+/// or with files in two sibling directories, keeps its key. `"(root)"` is
+/// never changed.
+///
+/// # Examples
+///
+/// ```
+/// use tokmd_model::collapse_single_child_modules;
+/// use tokmd_types::{FileKind, FileRow};
+///
+/// let mut rows = vec![FileRow {
+///     path: "src/a/b/c/file.rs".into(),
+///     module: "src".into(),
+///     lang: "Rust".into(),
+///     kind: FileKind::Parent,
+///     code: 1,
+///     comments: 0,
+///     blanks: 0,
+///     lines: 1,
+///     bytes: 10,
+///     tokens: 3,
+///     last_modified_ms: None,
+///     age_days: None,
+///     role: None,
+/// }];
+/// collapse_single_child_modules(&mut rows);
+/// assert_eq!(rows[0].module, "src/a/b/c");
+/// ```
+pub fn collapse_single_child_modules(rows: &mut [FileRow]) {
+    let mut common: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for row in rows.iter() {
+        if row.module == "(root)" {
+            continue;
+        }
+        let dirs = parent_dirs(&row.path);
+        common
+            .entry(row.module.clone())
+            .and_modify(|prefix| {
+                let shared = prefix
+                    .iter()
+                    .zip(&dirs)
+                    .take_while(|(a, b)| a == *b)
+                    .count();
+                prefix.truncate(shared);
+            })
+            .or_insert_with(|| dirs.iter().map(|d| (*d).to_string()).collect());
+    }
+
+    let renamed: BTreeMap<String, String> = common
+        .into_iter()
+        .filter_map(|(module, prefix)| {
+            let module_segments: Vec<&str> = module.split('/').collect();
+            let extends = prefix.len() > module_segments.len()
+                && prefix.iter().zip(&module_segments).all(|(a, b)| a == b);
+            extends.then(|| (module, prefix.join("/")))
+        })
+        .collect();
+
+    for row in rows.iter_mut() {
+        if let Some(collapsed) = renamed.get(&row.module) {
+            row.module = collapsed.clone();
+        }
+    }
+}
+
+fn parent_dirs(path: &str) -> Vec<&str> {
+    match path.rsplit_once('/') {
+        Some((dir, _file)) => dir
+            .split('/')
+            .filter(|s| !s.is_empty() && *s != ".")
+            .collect(),
+        None => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tokmd_types::FileKind;
+
+    use super::*;
+
+    fn row(path: &str, module: &str) -> FileRow {
+        FileRow {
+            path: path.to_string(),
+            module: module.to_string(),
+            lang: "Rust".to_string(),
+            kind: FileKind::Parent,
+            code: 1,
+            comments: 0,
+            blanks: 0,
+            lines: 1,
+            bytes: 10,
+            tokens: 3,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
+        }
+    }
+
+    fn modules(rows: &[FileRow]) -> Vec<&str> {
+        rows.iter().map(|r| r.module.as_str()).collect()
+    }
+
+    #[test]
+    fn single_child_chain_collapses() {
+        let mut rows = vec![
+            row("src/a/b/c/file.rs", "src"),
+            row("src/a/b/c/other.rs", "src"),
+        ];
+        collapse_single_child_modules(&mut rows);
+        assert_eq!(modules(&rows), vec!["src/a/b/c", "src/a/b/c"]);
+    }
+
+    #[test]
+    fn branching_directory_does_not_collapse_past_branch() {
+        let mut rows = vec![row("src/a/x/one.rs", "src"), row("src/a/y/two.rs", "src")];
+        collapse_single_child_modules(&mut rows);
+        assert_eq!(modules(&rows), vec!["src/a", "src/a"]);
+
+        let mut rows = vec![row("src/a/one.rs", "src"), row("src/b/two.rs", "src")];
+        collapse_single_child_modules(&mut rows);
+        assert_eq!(modules(&rows), vec!["src", "src"]);
+    }
+
+    #[test]
+    fn file_directly_in_module_keeps_key() {
+        let mut rows = vec![row("src/lib.rs", "src"), row("src/a/b/deep.rs", "src")];
+        collapse_single_child_modules(&mut rows);
+        assert_eq!(modules(&rows), vec!["src", "src"]);
+    }
+
+    #[test]
+    fn root_module_is_untouched() {
+        let mut rows = vec![
+            row("Cargo.toml", "(root)"),
+            row("crates/foo/src/lib.rs", "crates/foo"),
+        ];
+        collapse_single_child_modules(&mut rows);
+        assert_eq!(modules(&rows), vec!["(root)", "crates/foo/src"]);
+    }
+}
@@ -21,14 +21,19 @@ use std::path::Path;
 
 mod aggregate;
 mod children;
+mod collapse;
 pub mod module_key;
 mod rows;
 mod sorting;
 
 pub use aggregate::{
-    create_export_data, create_export_data_from_rows, create_lang_report,
-    create_lang_report_from_rows, create_module_report, create_module_report_from_rows,
+    ExportRowOptions, RowDetail, create_export_data, create_export_data_from_rows,
+    create_export_data_from_rows_with_options, create_export_data_with_options,
+    create_export_summary, create_lang_report, create_lang_report_from_rows,
+    create_lang_report_from_rows_with_detail, create_module_report, create_module_report_from_rows,
+    create_module_report_from_rows_with_detail,
 };
+pub use collapse::collapse_single_child_modules;
 pub use rows::{
     InMemoryRowInput, collect_file_rows, collect_in_memory_file_rows, unique_parent_file_count,
     unique_parent_file_count_from_rows,
@@ -68,6 +73,33 @@ pub fn avg(lines: usize, files: usize) -> usize {
     }
 }
 
+/// Compute the median of ascending-sorted `sorted` values.
+///
+/// Even-length inputs take the midpoint of the two middle values, rounding
+/// half up like [`avg`]. Returns 0 for an empty slice.
+///
+/// # Examples
+///
+/// ```
+/// use tokmd_model::median;
+///
+/// assert_eq!(median(&[10, 20, 5000]), 20);
+/// assert_eq!(median(&[10, 21]), 16);
+/// assert_eq!(median(&[]), 0);
+/// ```
+#[inline]
+pub fn median(sorted: &[usize]) -> usize {
+    let mid = sorted.len() / 2;
+    let hi = sorted.get(mid).copied().unwrap_or(0);
+    if sorted.len() % 2 == 1 {
+        return hi;
+    }
+    match mid.checked_sub(1).and_then(|i| sorted.get(i)) {
+        Some(&lo) => lo + (hi - lo).div_ceil(2),
+        None => hi,
+    }
+}
+
 /// Normalize a path for portable output.
 ///
 /// - Uses `/` separators
@@ -5,7 +5,11 @@
 /// Rules:
 /// - Root-level files become `"(root)"`.
 /// - If the first directory segment is in `module_roots`, include up to
-///   `module_depth` directory segments.
+///   `module_depth` directory segments. A root written as `name=depth`
+///   (e.g. `apps=1`) uses its own depth instead of `module_depth`.
+/// - Roots may also be glob patterns over leading directories
+///   (`packages/*/src`, with `*`/`?` matching within one segment). The
+///   deepest matching root wins.
 /// - Otherwise, the module key is the first directory segment.
 ///
 /// # Examples
@@ -37,6 +41,26 @@
 /// // With no module roots every path uses the first directory segment
 /// assert_eq!(module_key("crates/foo/src/lib.rs", &[], 2), "crates");
 /// ```
+///
+/// Per-root depth overrides:
+///
+/// ```
+/// use tokmd_model::module_key::module_key;
+///
+/// let roots = vec!["crates=2".into(), "apps=1".into()];
+/// assert_eq!(module_key("crates/foo/src/lib.rs", &roots, 3), "crates/foo");
+/// assert_eq!(module_key("apps/web/src/main.ts", &roots, 3), "apps");
+/// ```
+///
+/// Glob roots:
+///
+/// ```
+/// use tokmd_model::module_key::module_key;
+///
+/// let roots = vec!["packages/*/src".into()];
+/// assert_eq!(module_key("packages/foo/src/a.rs", &roots, 2), "packages/foo");
+/// assert_eq!(module_key("packages/foo/docs/a.md", &roots, 2), "packages");
+/// ```
 #[must_use]
 pub fn module_key(path: &str, module_roots: &[String], module_depth: usize) -> String {
     let mut p = path.replace('\\', "/");
@@ -99,30 +123,103 @@ pub fn module_key_from_normalized(
         return "(root)".to_string();
     };
 
-    let mut dirs = dir_part.split('/').filter(|s| !s.is_empty() && *s != ".");
-    let first = match dirs.next() {
-        Some(s) => s,
-        None => return "(root)".to_string(),
+    let dirs: Vec<&str> = dir_part
+        .split('/')
+        .filter(|s| !s.is_empty() && *s != ".")
+        .collect();
+    let Some(first) = dirs.first() else {
+        return "(root)".to_string();
     };
 
-    if !module_roots.iter().any(|r| r == first) {
-        return first.to_string();
-    }
+    let Some(root_depth) = matching_root_depth(&dirs, module_roots) else {
+        return (*first).to_string();
+    };
 
-    let depth_needed = module_depth.max(1);
-    let mut key = String::with_capacity(dir_part.len());
-    key.push_str(first);
+    let depth_needed = root_depth.unwrap_or(module_depth).max(1);
+    dirs.get(..depth_needed).unwrap_or(&dirs).join("/")
+}
 
-    for _ in 1..depth_needed {
-        if let Some(seg) = dirs.next() {
-            key.push('/');
-            key.push_str(seg);
-        } else {
-            break;
+/// Find the deepest module root matching the leading directories.
+///
+/// Returns the matched root's depth override (`None` when it has none), or
+/// `None` overall when no root matches. Roots are `/`-separated patterns where
+/// `*` and `?` wildcards match within a single segment, so a literal root such
+/// as `crates` is simply a one-segment pattern. Ties keep the first root listed.
+fn matching_root_depth(dirs: &[&str], module_roots: &[String]) -> Option<Option<usize>> {
+    let mut best: Option<(usize, Option<usize>)> = None;
+    for root in module_roots {
+        let (pattern, depth) = split_root_depth(root);
+        let segments: Vec<&str> = pattern
+            .trim_matches('/')
+            .split('/')
+            .filter(|s| !s.is_empty())
+            .collect();
+        if segments.is_empty() || segments.len() > dirs.len() {
+            continue;
+        }
+        let matched = segments
+            .iter()
+            .zip(dirs)
+            .all(|(pattern, dir)| segment_matches(pattern, dir));
+        if matched && best.is_none_or(|(len, _)| segments.len() > len) {
+            best = Some((segments.len(), depth));
         }
     }
+    best.map(|(_, depth)| depth)
+}
 
-    key
+/// Match one path segment against a pattern with `*` and `?` wildcards.
+fn segment_matches(pattern: &str, segment: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let segment: Vec<char> = segment.chars().collect();
+    let (mut p, mut s) = (pattern.as_slice(), segment.as_slice());
+    // Pattern after the last `*`, and the segment position that star resumes from.
+    let mut star: Option<(&[char], &[char])> = None;
+    while let Some((&c, s_rest)) = s.split_first() {
+        match p.split_first() {
+            Some((&'*', p_rest)) => {
+                star = Some((p_rest, s));
+                p = p_rest;
+            }
+            Some((&pc, p_rest)) if pc == '?' || pc == c => {
+                p = p_rest;
+                s = s_rest;
+            }
+            _ => {
+                let Some((star_p, [_, star_s @ ..])) = star else {
+                    return false;
+                };
+                star = Some((star_p, star_s));
+                p = star_p;
+                s = star_s;
+            }
+        }
+    }
+    p.iter().all(|c| *c == '*')
+}
+
+/// Split a module root entry into its name and optional depth override.
+///
+/// `"apps=1"` yields `("apps", Some(1))`; entries without a numeric
+/// `=depth` suffix are returned whole with no override.
+///
+/// # Examples
+///
+/// ```
+/// use tokmd_model::module_key::split_root_depth;
+///
+/// assert_eq!(split_root_depth("apps=1"), ("apps", Some(1)));
+/// assert_eq!(split_root_depth("crates"), ("crates", None));
+/// ```
+#[must_use]
+pub fn split_root_depth(root: &str) -> (&str, Option<usize>) {
+    match root.rsplit_once('=') {
+        Some((name, depth)) => match depth.trim().parse() {
+            Ok(depth) => (name.trim(), Some(depth)),
+            Err(_) => (root, None),
+        },
+        None => (root, None),
+    }
 }
 
 #[cfg(test)]
@@ -186,4 +283,64 @@ mod tests {
         let roots = vec!["crates".into()];
         assert_eq!(module_key_from_normalized("./lib.rs", &roots, 2), "(root)");
     }
+
+    #[test]
+    fn module_key_applies_per_root_depth_overrides() {
+        let roots = vec!["crates=2".into(), "apps=1".into(), "packages".into()];
+        assert_eq!(module_key("crates/foo/src/lib.rs", &roots, 3), "crates/foo");
+        assert_eq!(module_key("apps/web/src/main.ts", &roots, 3), "apps");
+        assert_eq!(
+            module_key("packages/ui/src/index.ts", &roots, 3),
+            "packages/ui/src"
+        );
+        assert_eq!(module_key("tools/gen.rs", &roots, 3), "tools");
+    }
+
+    #[test]
+    fn split_root_depth_keeps_non_numeric_suffix() {
+        assert_eq!(split_root_depth("crates=2"), ("crates", Some(2)));
+        assert_eq!(split_root_depth("a=b"), ("a=b", None));
+        assert_eq!(split_root_depth("crates"), ("crates", None));
+    }
+
+    #[test]
+    fn module_key_matches_glob_roots() {
+        let roots = vec!["packages/*/src".into()];
+        assert_eq!(
+            module_key("packages/foo/src/a.rs", &roots, 2),
+            "packages/foo"
+        );
+        assert_eq!(
+            module_key("packages/foo/src/nested/b.rs", &roots, 3),
+            "packages/foo/src"
+        );
+        // The glob must match every one of its segments.
+        assert_eq!(module_key("packages/foo/lib/a.rs", &roots, 2), "packages");
+        assert_eq!(module_key("packages/foo/a.rs", &roots, 2), "packages");
+    }
+
+    #[test]
+    fn module_key_prefers_deepest_matching_root() {
+        let roots = vec!["packages=1".into(), "packages/*/src=3".into()];
+        assert_eq!(
+            module_key("packages/foo/src/a.rs", &roots, 2),
+            "packages/foo/src"
+        );
+        assert_eq!(module_key("packages/foo/README.md", &roots, 2), "packages");
+    }
+
+    #[test]
+    fn segment_matches_wildcards() {
+        assert!(segment_matches("*", "anything"));
+        assert!(segment_matches("app-*", "app-web"));
+        assert!(segment_matches("v?", "v1"));
+        assert!(segment_matches("crates", "crates"));
+        assert!(!segment_matches("app-*", "lib-web"));
+        assert!(!segment_matches("v?", "v10"));
+        assert!(segment_matches("*-web", "app-admin-web"));
+        assert!(segment_matches("a*b*c", "aXbYbZc"));
+        assert!(segment_matches("**", ""));
+        assert!(!segment_matches("a*c", "abcd"));
+        assert!(!segment_matches("?", ""));
+    }
 }
@@ -223,6 +223,9 @@ fn rows_from_map<'a>(map: BTreeMap<Key<'a>, (String, Agg)>) -> Vec<FileRow> {
                 lines,
                 bytes: agg.bytes,
                 tokens: agg.tokens,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             }
         })
         .collect()
@@ -253,7 +256,7 @@ pub fn collect_in_memory_file_rows(
         let summary = stats.summarise();
         let (bytes, tokens) = metrics_from_bytes(input.bytes);
 
-        if children == ChildIncludeMode::Separate {
+        if children != ChildIncludeMode::ParentsOnly {
             for (child_type, child_stats) in &stats.blobs {
                 let child_summary = child_stats.summarise();
                 insert_row(
@@ -325,7 +328,7 @@ pub fn collect_file_rows(
         }
     }
 
-    if children == ChildIncludeMode::Separate {
+    if children != ChildIncludeMode::ParentsOnly {
         for (_lang_type, lang) in languages.iter() {
             for (child_type, reports) in &lang.children {
                 for report in reports {
@@ -1,6 +1,8 @@
 //! Deterministic row sorting helpers for model receipts.
 
-use tokmd_types::{FileRow, LangRow, ModuleRow};
+use std::cmp::Ordering;
+
+use tokmd_types::{ExportSortKey, FileRow, LangRow, ModuleRow, SortDirection};
 
 pub(crate) fn sort_lang_rows(rows: &mut [LangRow]) {
     rows.sort_by(|a, b| b.code.cmp(&a.code).then_with(|| a.lang.cmp(&b.lang)));
@@ -11,7 +13,33 @@ pub(crate) fn sort_module_rows(rows: &mut [ModuleRow]) {
 }
 
 pub(crate) fn sort_file_rows(rows: &mut [FileRow]) {
-    rows.sort_by(|a, b| b.code.cmp(&a.code).then_with(|| a.path.cmp(&b.path)));
+    sort_file_rows_by(rows, ExportSortKey::Code, SortDirection::Desc);
+}
+
+/// Sort by `key` in `dir`; ties always fall back to ascending path order.
+pub(crate) fn sort_file_rows_by(rows: &mut [FileRow], key: ExportSortKey, dir: SortDirection) {
+    rows.sort_by(|a, b| {
+        let ord = compare_file_rows(a, b, key);
+        let ord = match dir {
+            SortDirection::Asc => ord,
+            SortDirection::Desc => ord.reverse(),
+        };
+        ord.then_with(|| a.path.cmp(&b.path))
+    });
+}
+
+fn compare_file_rows(a: &FileRow, b: &FileRow, key: ExportSortKey) -> Ordering {
+    match key {
+        ExportSortKey::Code => a.code.cmp(&b.code),
+        ExportSortKey::Lines => a.lines.cmp(&b.lines),
+        ExportSortKey::Comments => a.comments.cmp(&b.comments),
+        ExportSortKey::Blanks => a.blanks.cmp(&b.blanks),
+        ExportSortKey::Bytes => a.bytes.cmp(&b.bytes),
+        ExportSortKey::Tokens => a.tokens.cmp(&b.tokens),
+        ExportSortKey::Path => a.path.cmp(&b.path),
+        ExportSortKey::Module => a.module.cmp(&b.module),
+        ExportSortKey::Lang => a.lang.cmp(&b.lang),
+    }
 }
 
 #[cfg(test)]
@@ -28,6 +56,10 @@ mod tests {
             bytes: 0,
             tokens: 0,
             avg_lines: code,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         }
     }
 
@@ -40,6 +72,11 @@ mod tests {
             bytes: 0,
             tokens: 0,
             avg_lines: code,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
+            children: Vec::new(),
         }
     }
 
@@ -55,6 +92,9 @@ mod tests {
             lines: code,
             bytes: 0,
             tokens: 0,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         }
     }
 
@@ -105,4 +145,25 @@ mod tests {
             ["src/lib.rs", "src/a.rs", "src/z.rs"]
         );
     }
+
+    #[test]
+    fn file_rows_sort_by_key_and_direction() {
+        let mut rows = vec![
+            file_row("src/b.rs", 5),
+            file_row("src/c.rs", 1),
+            file_row("src/a.rs", 5),
+        ];
+
+        sort_file_rows_by(&mut rows, ExportSortKey::Code, SortDirection::Asc);
+        assert_eq!(
+            rows.iter().map(|row| row.path.as_str()).collect::<Vec<_>>(),
+            ["src/c.rs", "src/a.rs", "src/b.rs"]
+        );
+
+        sort_file_rows_by(&mut rows, ExportSortKey::Path, SortDirection::Desc);
+        assert_eq!(
+            rows.iter().map(|row| row.path.as_str()).collect::<Vec<_>>(),
+            ["src/c.rs", "src/b.rs", "src/a.rs"]
+        );
+    }
 }
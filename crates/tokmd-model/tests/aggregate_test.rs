@@ -13,6 +13,9 @@ fn file_row(kind: FileKind, lang: &str, bytes: usize, tokens: usize) -> FileRow
         tokens,
         module: "docs".to_string(),
         kind,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
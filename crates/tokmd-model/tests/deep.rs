@@ -194,6 +194,10 @@ fn sorting_tiebreak_by_name_lang() {
             bytes: 1000,
             tokens: 250,
             avg_lines: 40,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         },
         LangRow {
             lang: "Alpha".into(),
@@ -203,6 +207,10 @@ fn sorting_tiebreak_by_name_lang() {
             bytes: 1000,
             tokens: 250,
             avg_lines: 40,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         },
         LangRow {
             lang: "Middle".into(),
@@ -212,6 +220,10 @@ fn sorting_tiebreak_by_name_lang() {
             bytes: 1000,
             tokens: 250,
             avg_lines: 40,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         },
     ];
 
@@ -233,6 +245,11 @@ fn sorting_tiebreak_by_name_module() {
             bytes: 500,
             tokens: 125,
             avg_lines: 50,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
+            children: Vec::new(),
         },
         ModuleRow {
             module: "aaa".into(),
@@ -242,6 +259,11 @@ fn sorting_tiebreak_by_name_module() {
             bytes: 500,
             tokens: 125,
             avg_lines: 50,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
+            children: Vec::new(),
         },
     ];
 
@@ -401,6 +423,10 @@ fn arb_lang_row() -> impl Strategy<Value = LangRow> {
             bytes,
             tokens,
             avg_lines: avg(lines, files),
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         })
 }
 
@@ -421,6 +447,11 @@ fn arb_module_row() -> impl Strategy<Value = ModuleRow> {
             bytes,
             tokens,
             avg_lines: avg(lines, files),
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
+            children: Vec::new(),
         })
 }
 
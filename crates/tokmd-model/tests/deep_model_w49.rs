@@ -279,6 +279,10 @@ fn lang_rows_tiebreak_ascending_by_name() {
             bytes: 0,
             tokens: 0,
             avg_lines: 20,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         },
         LangRow {
             lang: "Alpha".into(),
@@ -288,6 +292,10 @@ fn lang_rows_tiebreak_ascending_by_name() {
             bytes: 0,
             tokens: 0,
             avg_lines: 20,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         },
         LangRow {
             lang: "Mid".into(),
@@ -297,6 +305,10 @@ fn lang_rows_tiebreak_ascending_by_name() {
             bytes: 0,
             tokens: 0,
             avg_lines: 20,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         },
     ];
     rows.sort_by(|a, b| b.code.cmp(&a.code).then_with(|| a.lang.cmp(&b.lang)));
@@ -332,6 +344,11 @@ fn module_rows_tiebreak_ascending_by_name() {
             bytes: 0,
             tokens: 0,
             avg_lines: 10,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
+            children: Vec::new(),
         },
         ModuleRow {
             module: "aaa".into(),
@@ -341,6 +358,11 @@ fn module_rows_tiebreak_ascending_by_name() {
             bytes: 0,
             tokens: 0,
             avg_lines: 10,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
+            children: Vec::new(),
         },
     ];
     rows.sort_by(|a, b| b.code.cmp(&a.code).then_with(|| a.module.cmp(&b.module)));
@@ -369,6 +391,10 @@ fn arb_lang_row() -> impl Strategy<Value = LangRow> {
             bytes,
             tokens,
             avg_lines: avg(lines, files),
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         })
 }
 
@@ -389,6 +415,11 @@ fn arb_module_row() -> impl Strategy<Value = ModuleRow> {
             bytes,
             tokens,
             avg_lines: avg(lines, files),
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
+            children: Vec::new(),
         })
 }
 
@@ -17,6 +17,10 @@ fn make_lang_row(lang: &str, code: usize) -> LangRow {
         bytes: code * 4,
         tokens: code,
         avg_lines: if code + 50 > 0 { (code + 50) / 3 } else { 0 },
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
+        file_rows: Vec::new(),
     }
 }
 
@@ -29,6 +33,11 @@ fn make_module_row(module: &str, code: usize) -> ModuleRow {
         bytes: code * 4,
         tokens: code,
         avg_lines: if code + 30 > 0 { (code + 30) / 2 } else { 0 },
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
+        file_rows: Vec::new(),
+        children: Vec::new(),
     }
 }
 
@@ -44,6 +53,9 @@ fn make_file_row(path: &str, lang: &str, module: &str, code: usize) -> FileRow {
         lines: code + 15,
         bytes: code * 4,
         tokens: code,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
@@ -60,6 +72,9 @@ fn totals_from_rows(rows: &[LangRow]) -> Totals {
         bytes,
         tokens,
         avg_lines: lines.checked_div(files).unwrap_or(0),
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
     }
 }
 
@@ -343,6 +358,10 @@ fn top_n_folding_is_deterministic() {
         bytes: rows[top..].iter().map(|r| r.bytes).sum(),
         tokens: rows[top..].iter().map(|r| r.tokens).sum(),
         avg_lines: tokmd_model::avg(other_lines, other_files),
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
+        file_rows: Vec::new(),
     };
     rows.truncate(top);
     rows.push(other);
@@ -365,6 +384,10 @@ fn top_n_folding_is_deterministic() {
         bytes: rows2[top..].iter().map(|r| r.bytes).sum(),
         tokens: rows2[top..].iter().map(|r| r.tokens).sum(),
         avg_lines: tokmd_model::avg(other_lines2, other_files2),
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
+        file_rows: Vec::new(),
     };
     rows2.truncate(top);
     rows2.push(other2);
@@ -388,6 +411,9 @@ fn module_report_child_include_mode_serialization_stable() {
                 bytes: 400,
                 tokens: 100,
                 avg_lines: 65,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
             },
             module_roots: vec![],
             module_depth: 1,
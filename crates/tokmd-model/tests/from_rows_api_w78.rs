@@ -44,6 +44,9 @@ fn fixture_rows() -> Vec<FileRow> {
             lines: 115,
             bytes: 400,
             tokens: 100,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         },
         FileRow {
             path: "web/page.html".to_string(),
@@ -56,6 +59,9 @@ fn fixture_rows() -> Vec<FileRow> {
             lines: 62,
             bytes: 300,
             tokens: 75,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         },
         FileRow {
             path: "web/page.html".to_string(),
@@ -68,6 +74,9 @@ fn fixture_rows() -> Vec<FileRow> {
             lines: 12,
             bytes: 0,
             tokens: 0,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         },
     ]
 }
@@ -505,6 +514,9 @@ fn unique_parent_file_count_from_rows_ignores_children_and_duplicates() {
             lines: 13,
             bytes: 40,
             tokens: 10,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         },
         FileRow {
             path: "src/lib.rs".to_string(),
@@ -517,6 +529,9 @@ fn unique_parent_file_count_from_rows_ignores_children_and_duplicates() {
             lines: 13,
             bytes: 40,
             tokens: 10,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         },
         FileRow {
             path: "src/lib.rs".to_string(),
@@ -529,6 +544,9 @@ fn unique_parent_file_count_from_rows_ignores_children_and_duplicates() {
             lines: 3,
             bytes: 0,
             tokens: 0,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         },
         FileRow {
             path: "tests/test.rs".to_string(),
@@ -541,6 +559,9 @@ fn unique_parent_file_count_from_rows_ignores_children_and_duplicates() {
             lines: 10,
             bytes: 32,
             tokens: 8,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         },
     ];
 
@@ -578,6 +599,9 @@ fn create_lang_report_from_rows_distinguishes_children_modes() {
             lines: 13,
             bytes: 40,
             tokens: 10,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         },
         FileRow {
             path: "src/lib.rs".to_string(),
@@ -590,6 +614,9 @@ fn create_lang_report_from_rows_distinguishes_children_modes() {
             lines: 3,
             bytes: 0,
             tokens: 0,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         },
         FileRow {
             path: "src/test.rs".to_string(),
@@ -602,6 +629,9 @@ fn create_lang_report_from_rows_distinguishes_children_modes() {
             lines: 10,
             bytes: 32,
             tokens: 8,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         },
     ];
 
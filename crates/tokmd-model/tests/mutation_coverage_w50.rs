@@ -31,6 +31,10 @@ fn lang_row(name: &str, code: usize, lines: usize, files: usize) -> LangRow {
         bytes: code * 40,
         tokens: code * 10,
         avg_lines: lines.checked_div(files).unwrap_or(0),
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
+        file_rows: Vec::new(),
     }
 }
 
@@ -47,6 +51,9 @@ fn totals_from_rows(rows: &[LangRow]) -> Totals {
         bytes,
         tokens,
         avg_lines: avg(lines, files),
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
     }
 }
 
@@ -241,6 +241,10 @@ fn arb_lang_row() -> impl Strategy<Value = tokmd_types::LangRow> {
                 bytes,
                 tokens,
                 avg_lines,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             }
         })
 }
@@ -265,6 +269,11 @@ fn arb_module_row() -> impl Strategy<Value = tokmd_types::ModuleRow> {
                 bytes,
                 tokens,
                 avg_lines,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
+                children: Vec::new(),
             }
         })
 }
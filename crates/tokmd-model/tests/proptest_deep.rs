@@ -38,6 +38,10 @@ fn arb_lang_row() -> impl Strategy<Value = LangRow> {
                 bytes,
                 tokens,
                 avg_lines,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             }
         })
 }
@@ -67,6 +71,11 @@ fn arb_module_row() -> impl Strategy<Value = ModuleRow> {
                 bytes,
                 tokens,
                 avg_lines,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
+                children: Vec::new(),
             }
         })
 }
@@ -87,6 +96,9 @@ fn arb_totals() -> impl Strategy<Value = Totals> {
             bytes,
             tokens,
             avg_lines,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         })
 }
 
@@ -367,7 +379,7 @@ proptest! {
 
     #[test]
     fn totals_zero_construction(_dummy in 0..1u8) {
-        let t = Totals { code: 0, lines: 0, files: 0, bytes: 0, tokens: 0, avg_lines: 0 };
+        let t = Totals { code: 0, lines: 0, files: 0, bytes: 0, tokens: 0, avg_lines: 0, median_lines: None, p50_lines: None, p90_lines: None };
         prop_assert_eq!(t.code, 0);
         prop_assert_eq!(t.lines, 0);
         prop_assert_eq!(t.files, 0);
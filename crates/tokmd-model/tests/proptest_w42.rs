@@ -33,6 +33,10 @@ fn arb_lang_row() -> impl Strategy<Value = LangRow> {
                 bytes,
                 tokens,
                 avg_lines,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             }
         })
 }
@@ -58,6 +62,11 @@ fn arb_module_row() -> impl Strategy<Value = ModuleRow> {
                 bytes,
                 tokens,
                 avg_lines,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
+                children: Vec::new(),
             }
         })
 }
@@ -34,6 +34,10 @@ fn arb_lang_row() -> impl Strategy<Value = LangRow> {
                 bytes,
                 tokens,
                 avg_lines,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             }
         })
 }
@@ -59,6 +63,11 @@ fn arb_module_row() -> impl Strategy<Value = ModuleRow> {
                 bytes,
                 tokens,
                 avg_lines,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
+                children: Vec::new(),
             }
         })
 }
@@ -90,13 +90,20 @@ export interface Totals {
   avg_lines: number
 }
 
+export interface Warning {
+  code: string
+  severity: 'info' | 'warning' | 'error'
+  message: string
+  path?: string
+}
+
 export interface LangReceipt {
   schema_version: number
   generated_at_ms: number
   tool: { name: string; version: string }
   mode: 'lang'
   status: 'complete' | 'partial'
-  warnings: string[]
+  warnings: Warning[]
   scan: object
   args: object
   rows: LangRow[]
@@ -112,7 +119,7 @@ export interface ModuleReceipt {
   tool: { name: string; version: string }
   mode: 'module'
   status: 'complete' | 'partial'
-  warnings: string[]
+  warnings: Warning[]
   scan: object
   args: object
   rows: ModuleRow[]
@@ -129,7 +136,7 @@ export interface ExportReceipt {
   tool: { name: string; version: string }
   mode: 'export'
   status: 'complete' | 'partial'
-  warnings: string[]
+  warnings: Warning[]
   scan: object
   args: object
   rows: FileRow[]
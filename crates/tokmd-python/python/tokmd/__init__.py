@@ -27,6 +27,7 @@ Features:
     - File-level export (CSV, JSONL, JSON, CycloneDX SBOM)
     - Analysis with multiple presets (health, risk, supply, etc.)
     - Diff comparison between receipts
+    - Integrity verification of stored receipts
     - Path redaction for safe LLM sharing
 
 For more information, see https://github.com/EffortlessMetrics/tokmd
@@ -36,8 +37,11 @@ from tokmd._tokmd import (
     TokmdError,
     __version__,
     SCHEMA_VERSION,
+    MIN_SCHEMA_VERSION,
     version,
     schema_version,
+    min_schema_version,
+    is_compatible,
     run_json,
     run,
     lang,
@@ -45,14 +49,21 @@ from tokmd._tokmd import (
     export,
     analyze,
     diff,
+    verify,
+    validate,
+    available_formats,
+    capabilities,
 )
 
 __all__ = [
     "TokmdError",
     "__version__",
     "SCHEMA_VERSION",
+    "MIN_SCHEMA_VERSION",
     "version",
     "schema_version",
+    "min_schema_version",
+    "is_compatible",
     "run_json",
     "run",
     "lang",
@@ -60,4 +71,8 @@ __all__ = [
     "export",
     "analyze",
     "diff",
+    "verify",
+    "validate",
+    "available_formats",
+    "capabilities",
 ]
@@ -43,7 +43,7 @@ use args::build_args;
 use envelope::{extract_envelope, map_envelope_error};
 #[cfg(test)]
 use runtime::run_with_json_module;
-use runtime::{run, run_json, schema_version, version};
+use runtime::{is_compatible, min_schema_version, run, run_json, schema_version, version};
 
 // Custom exception for tokmd errors.
 //
@@ -129,7 +129,7 @@ fn lang(
 ///     top: Show only top N modules (0 = all, default: 0)
 ///     module_roots: Top-level directories as module roots (default: ["crates", "packages"])
 ///     module_depth: Path segments to include for module roots (default: 2)
-///     children: How to handle embedded languages ("separate" or "parents-only", default: "separate")
+///     children: How to handle embedded languages ("separate", "parents-only", or "nested", default: "separate")
 ///     redact: Redaction mode ("none", "paths", "all", default: None)
 ///     excluded: List of glob patterns to exclude (default: [])
 ///     hidden: Include hidden files (default: False)
@@ -386,6 +386,104 @@ fn diff(py: Python<'_>, from_path: Option<&str>, to_path: Option<&str>) -> PyRes
     run(py, "diff", &args)
 }
 
+/// Verify that a stored receipt's rows still hash to its recorded `integrity`.
+///
+/// # FFI Safety
+///
+/// Uses the standard error propagation pattern with `PyResult` returns and `?` operator.
+/// See `lang()` for detailed rationale.
+///
+/// Args:
+///     receipt_json: A lang, module, export, or analysis receipt as a JSON string
+///     export_json: For analysis receipts, the export receipt the analysis ran on
+///
+/// Returns:
+///     dict: `valid` (bool), `mode`, `algo`, `entries`, and `mismatch`
+///     (a description, or None when valid)
+///
+/// Example:
+///     >>> import tokmd
+///     >>> result = tokmd.verify(open("export.json").read())
+///     >>> if not result["valid"]:
+///     ...     print(result["mismatch"])
+#[cfg_attr(not(test), pyfunction(signature = (receipt_json, export_json=None)))]
+fn verify(py: Python<'_>, receipt_json: &str, export_json: Option<&str>) -> PyResult<Py<PyAny>> {
+    let args = PyDict::new(py);
+    args.set_item("receipt", receipt_json)?;
+    if let Some(e) = export_json {
+        args.set_item("export", e)?;
+    }
+    run(py, "verify", &args)
+}
+
+/// Check that a stored receipt parses, optionally listing unknown keys.
+///
+/// # FFI Safety
+///
+/// Uses the standard error propagation pattern with `PyResult` returns and `?` operator.
+/// See `lang()` for detailed rationale.
+///
+/// Args:
+///     receipt_json: A lang, module, export, diff, context, cockpit, or
+///         analysis receipt as a JSON string
+///     strict: Also report keys the receipt type does not read (default: False)
+///
+/// Returns:
+///     dict: `valid` (bool), `mode`, `strict`, and `unknown_fields`
+///     (JSON Pointers such as "/rows/0/cod"; always empty unless strict)
+///
+/// Raises:
+///     TokmdError: If the receipt does not parse as its mode's type
+///
+/// Example:
+///     >>> import tokmd
+///     >>> result = tokmd.validate(open("baseline.json").read(), strict=True)
+///     >>> for key in result["unknown_fields"]:
+///     ...     print("unexpected", key)
+#[cfg_attr(not(test), pyfunction(signature = (receipt_json, strict=false)))]
+fn validate(py: Python<'_>, receipt_json: &str, strict: bool) -> PyResult<Py<PyAny>> {
+    let args = PyDict::new(py);
+    args.set_item("receipt", receipt_json)?;
+    args.set_item("strict", strict)?;
+    run(py, "validate", &args)
+}
+
+/// List the output formats supported by this build.
+///
+/// OBJ and MIDI analysis formats are only present when tokmd was built with
+/// the `fun` feature, so UIs can hide options that would fail.
+///
+/// Returns:
+///     list[str]: Analysis format names, as accepted by `tokmd analyze --format`
+///
+/// Example:
+///     >>> import tokmd
+///     >>> "md" in tokmd.available_formats()
+///     True
+#[cfg_attr(not(test), pyfunction)]
+fn available_formats(py: Python<'_>) -> PyResult<Py<PyAny>> {
+    let result = run(py, "formats", &PyDict::new(py))?;
+    Ok(result.bind(py).get_item("analysis")?.unbind())
+}
+
+/// Describe what this build of tokmd supports.
+///
+/// Returns the modes accepted by `run`, the analysis formats it can render,
+/// the receipt schema versions, and which optional features were compiled in.
+///
+/// Returns:
+///     dict: Keys `version`, `modes`, `formats`, `schema_versions`, and `features`
+///
+/// Example:
+///     >>> import tokmd
+///     >>> caps = tokmd.capabilities()
+///     >>> "lang" in caps["modes"]
+///     True
+#[cfg_attr(not(test), pyfunction)]
+fn capabilities(py: Python<'_>) -> PyResult<Py<PyAny>> {
+    run(py, "capabilities", &PyDict::new(py))
+}
+
 /// Run cockpit PR metrics analysis.
 ///
 /// # FFI Safety
@@ -462,9 +560,12 @@ fn _tokmd(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add("TokmdError", m.py().get_type::<TokmdError>())?;
     m.add("__version__", version())?;
     m.add("SCHEMA_VERSION", schema_version())?;
+    m.add("MIN_SCHEMA_VERSION", min_schema_version())?;
 
     m.add_function(wrap_pyfunction!(version, m)?)?;
     m.add_function(wrap_pyfunction!(schema_version, m)?)?;
+    m.add_function(wrap_pyfunction!(min_schema_version, m)?)?;
+    m.add_function(wrap_pyfunction!(is_compatible, m)?)?;
     m.add_function(wrap_pyfunction!(run_json, m)?)?;
     m.add_function(wrap_pyfunction!(run, m)?)?;
     m.add_function(wrap_pyfunction!(lang, m)?)?;
@@ -473,6 +574,10 @@ fn _tokmd(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(analyze, m)?)?;
     m.add_function(wrap_pyfunction!(cockpit, m)?)?;
     m.add_function(wrap_pyfunction!(diff, m)?)?;
+    m.add_function(wrap_pyfunction!(verify, m)?)?;
+    m.add_function(wrap_pyfunction!(validate, m)?)?;
+    m.add_function(wrap_pyfunction!(available_formats, m)?)?;
+    m.add_function(wrap_pyfunction!(capabilities, m)?)?;
 
     Ok(())
 }
@@ -32,6 +32,47 @@ pub(crate) fn schema_version() -> u32 {
     tokmd_core::ffi::schema_version()
 }
 
+/// Get the oldest receipt schema version this build can still read.
+///
+/// Receipts between this and `schema_version()` are migrated when read;
+/// older or newer ones are rejected.
+///
+/// Returns:
+///     int: The minimum supported receipt schema version
+///
+/// Example:
+///     >>> import tokmd
+///     >>> tokmd.min_schema_version()
+///     1
+#[cfg_attr(not(test), pyfunction)]
+pub(crate) fn min_schema_version() -> u32 {
+    tokmd_core::ffi::min_schema_version()
+}
+
+/// Check whether a stored receipt's schema version can be read.
+///
+/// Use this on a receipt's `schema_version` field before handing it to
+/// `diff`, `verify`, or `validate`, to decide whether to migrate it with a
+/// newer tokmd or reject it up front.
+///
+/// Args:
+///     receipt_schema_version: The `schema_version` recorded in the receipt
+///
+/// Returns:
+///     bool: True if it is between `min_schema_version()` and
+///     `schema_version()`, inclusive
+///
+/// Example:
+///     >>> import tokmd
+///     >>> tokmd.is_compatible(tokmd.SCHEMA_VERSION)
+///     True
+///     >>> tokmd.is_compatible(999)
+///     False
+#[cfg_attr(not(test), pyfunction)]
+pub(crate) fn is_compatible(receipt_schema_version: i64) -> bool {
+    u64::try_from(receipt_schema_version).is_ok_and(tokmd_core::ffi::is_compatible)
+}
+
 /// Run a tokmd operation with JSON arguments, returning a JSON string.
 ///
 /// This is the low-level API that accepts and returns JSON strings.
@@ -56,7 +97,7 @@ pub(crate) fn schema_version() -> u32 {
 /// the GIL.
 ///
 /// Args:
-///     mode: The operation mode ("lang", "module", "export", "analyze", "diff", "version")
+///     mode: The operation mode ("lang", "module", "export", "analyze", "diff", "verify", "validate", "formats", "capabilities", "version")
 ///     args_json: JSON string containing the arguments
 ///
 /// Returns:
@@ -117,7 +158,7 @@ pub(crate) fn run_json(py: Python<'_>, mode: &str, args_json: &str) -> PyResult<
 /// exceptions that can be caught and handled by the caller.
 ///
 /// Args:
-///     mode: The operation mode ("lang", "module", "export", "analyze", "diff", "version")
+///     mode: The operation mode ("lang", "module", "export", "analyze", "diff", "verify", "validate", "formats", "capabilities", "version")
 ///     args: Python dict containing the arguments (will be converted to JSON)
 ///
 /// Returns:
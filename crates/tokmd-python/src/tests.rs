@@ -515,6 +515,96 @@ fn diff_compares_two_paths() {
     });
 }
 
+#[test]
+fn verify_flags_tampered_export_receipt() {
+    with_py(|py| {
+        let repo = make_repo("fn main() {}\n");
+        let path = repo.path().to_string_lossy().to_string();
+        let args = serde_json::json!({ "paths": [path] }).to_string();
+        let exported: serde_json::Value =
+            serde_json::from_str(&tokmd_core::ffi::run_json("export", &args)).unwrap();
+        let mut receipt = exported["data"].clone();
+
+        let valid = |receipt: &serde_json::Value| -> bool {
+            let result = verify(py, &receipt.to_string(), None).expect("verify should succeed");
+            let dict = result.cast_bound::<PyDict>(py).expect("verify dict");
+            dict.get_item("valid").unwrap().unwrap().extract().unwrap()
+        };
+        assert!(valid(&receipt));
+
+        receipt["rows"][0]["code"] = serde_json::json!(42);
+        assert!(!valid(&receipt));
+    });
+}
+
+#[test]
+fn validate_lists_unknown_keys_only_when_strict() {
+    with_py(|py| {
+        let repo = make_repo("fn main() {}\n");
+        let path = repo.path().to_string_lossy().to_string();
+        let args = serde_json::json!({ "paths": [path] }).to_string();
+        let scanned: serde_json::Value =
+            serde_json::from_str(&tokmd_core::ffi::run_json("lang", &args)).unwrap();
+        let mut receipt = scanned["data"].clone();
+        receipt["totl"] = serde_json::json!(1);
+
+        let unknown = |strict: bool| -> Vec<String> {
+            let result = validate(py, &receipt.to_string(), strict).expect("validate");
+            let dict = result.cast_bound::<PyDict>(py).expect("validate dict");
+            dict.get_item("unknown_fields")
+                .unwrap()
+                .unwrap()
+                .extract()
+                .unwrap()
+        };
+        assert!(unknown(false).is_empty());
+        assert_eq!(unknown(true), ["/totl"]);
+    });
+}
+
+#[test]
+fn available_formats_match_core_build() {
+    with_py(|py| {
+        let formats: Vec<String> = available_formats(py)
+            .expect("available_formats should succeed")
+            .extract(py)
+            .expect("list of strings");
+        let expected: Vec<String> =
+            serde_json::to_value(tokmd_core::analysis_facade::available_formats())
+                .and_then(serde_json::from_value)
+                .expect("format names");
+        assert_eq!(formats, expected);
+        assert!(formats.iter().any(|f| f == "md"));
+        // OBJ and MIDI come and go together with the `fun` feature.
+        assert_eq!(
+            formats.iter().any(|f| f == "obj"),
+            formats.iter().any(|f| f == "midi")
+        );
+    });
+}
+
+#[test]
+fn capabilities_match_core_build() {
+    with_py(|py| {
+        let caps = capabilities(py).expect("capabilities should succeed");
+        let caps = caps.bind(py);
+        let modes: Vec<String> = caps
+            .get_item("modes")
+            .and_then(|m| m.extract())
+            .expect("modes list");
+        assert!(modes.iter().any(|m| m == "lang"));
+        assert!(modes.iter().any(|m| m == "export"));
+        let fun: bool = caps
+            .get_item("features")
+            .and_then(|f| f.get_item("fun"))
+            .and_then(|f| f.extract())
+            .expect("fun flag");
+        let core: serde_json::Value =
+            serde_json::from_str(&tokmd_core::ffi::capabilities()).expect("core json");
+        assert_eq!(fun, core["features"]["fun"]);
+    });
+}
+
 // ========================================================================
 // Compile-check stubs: verify the core API surface that bindings depend on
 // ========================================================================
@@ -537,6 +627,15 @@ fn core_schema_version_matches_binding() {
     assert_eq!(core_sv, binding_sv, "binding must delegate to core");
 }
 
+#[test]
+fn is_compatible_rejects_future_and_negative_versions() {
+    assert_eq!(min_schema_version(), tokmd_core::ffi::min_schema_version());
+    assert!(is_compatible(i64::from(schema_version())));
+    assert!(is_compatible(i64::from(min_schema_version())));
+    assert!(!is_compatible(i64::from(schema_version()) + 1));
+    assert!(!is_compatible(-1));
+}
+
 #[test]
 fn core_run_json_returns_valid_json_for_all_modes() {
     let modes = ["lang", "module", "export", "analyze", "diff", "version"];
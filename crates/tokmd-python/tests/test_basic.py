@@ -21,6 +21,29 @@ def test_version():
     assert "." in v  # Should look like semver
 
 
+def test_available_formats():
+    """Test available_formats lists analysis formats for this build."""
+    import tokmd
+
+    formats = tokmd.available_formats()
+    assert isinstance(formats, list)
+    assert "md" in formats
+    assert "json" in formats
+
+
+def test_capabilities():
+    """Test capabilities describes modes, formats, and features."""
+    import tokmd
+
+    caps = tokmd.capabilities()
+    assert isinstance(caps, dict)
+    for mode in ("lang", "module", "export", "diff", "verify", "version"):
+        assert mode in caps["modes"]
+    assert caps["formats"]["analysis"] == tokmd.available_formats()
+    assert caps["schema_versions"]["core"] == tokmd.schema_version()
+    assert isinstance(caps["features"]["fun"], bool)
+
+
 def test_schema_version():
     """Test schema_version returns an integer."""
     import tokmd
@@ -30,6 +53,18 @@ def test_schema_version():
     assert sv >= 1
 
 
+def test_is_compatible():
+    """Test is_compatible accepts the current version and rejects newer ones."""
+    import tokmd
+
+    assert tokmd.MIN_SCHEMA_VERSION == tokmd.min_schema_version()
+    assert 1 <= tokmd.MIN_SCHEMA_VERSION <= tokmd.SCHEMA_VERSION
+    assert tokmd.is_compatible(tokmd.SCHEMA_VERSION)
+    assert tokmd.is_compatible(tokmd.MIN_SCHEMA_VERSION)
+    assert not tokmd.is_compatible(tokmd.SCHEMA_VERSION + 1000)
+    assert not tokmd.is_compatible(0)
+
+
 def test_module_attributes():
     """Test module has expected attributes."""
     import tokmd
@@ -144,6 +179,32 @@ def test_diff_requires_paths():
     assert result["mode"] == "diff"
 
 
+def test_verify_detects_tampered_receipt():
+    """Test verify accepts a fresh receipt and flags an edited one."""
+    import tokmd
+
+    receipt = tokmd.export(paths=["src"], format="json")
+    assert tokmd.verify(json.dumps(receipt))["valid"] is True
+
+    receipt["rows"][0]["code"] += 1
+    result = tokmd.verify(json.dumps(receipt))
+    assert result["valid"] is False
+    assert "mismatch" in result["mismatch"]
+
+
+def test_validate_reports_unknown_keys_when_strict():
+    """Test validate ignores extra keys by default and lists them when strict."""
+    import tokmd
+
+    receipt = tokmd.lang(paths=["src"], format="json")
+    receipt["totl"] = 1
+
+    assert tokmd.validate(json.dumps(receipt))["valid"] is True
+    result = tokmd.validate(json.dumps(receipt), strict=True)
+    assert result["valid"] is False
+    assert result["unknown_fields"] == ["/totl"]
+
+
 class TestLangOptions:
     """Test various lang function options."""
 
@@ -0,0 +1,322 @@
+//! Persistent per-file scan cache, from `ScanOptions::scan_cache`.
+//!
+//! Counting lines is the expensive part of a scan. With a cache directory
+//! set, the scanner walks the roots the way tokei would, reuses the stored
+//! counts of every file whose modification time and size are unchanged, and
+//! hands only the remaining files to tokei. The cache is a single JSON file
+//! holding the files seen by the last scan; it is discarded whole when the
+//! tokmd version or a counting option changes. Counts are cached as tokei
+//! reports them, before logical line counting or comment markers are applied.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+use ignore::WalkBuilder;
+use ignore::overrides::OverrideBuilder;
+use serde::{Deserialize, Serialize};
+use tokei::{CodeStats, Config, LanguageType, Languages, Report};
+
+/// Default scan cache directory, relative to the working directory.
+pub const SCAN_CACHE_DIR: &str = ".tokmd/cache/scan";
+
+const CACHE_FILE: &str = "files.json";
+
+/// How many files a cached scan reused and how many it counted afresh.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ScanCacheStats {
+    pub hits: usize,
+    pub misses: usize,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct CacheFile {
+    version: String,
+    files: BTreeMap<String, CachedFile>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CachedFile {
+    language: String,
+    modified_ns: u64,
+    size: u64,
+    stats: CachedStats,
+}
+
+/// `tokei::CodeStats` with blobs keyed by language name, which (unlike
+/// `LanguageType`'s serde form) round-trips through `FromStr`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CachedStats {
+    blanks: usize,
+    code: usize,
+    comments: usize,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    blobs: BTreeMap<String, CachedStats>,
+}
+
+impl CachedStats {
+    fn from_code_stats(stats: &CodeStats) -> Self {
+        Self {
+            blanks: stats.blanks,
+            code: stats.code,
+            comments: stats.comments,
+            blobs: stats
+                .blobs
+                .iter()
+                .map(|(language, blob)| (language.name().to_string(), Self::from_code_stats(blob)))
+                .collect(),
+        }
+    }
+
+    fn to_code_stats(&self) -> Option<CodeStats> {
+        let mut stats = CodeStats::new();
+        stats.blanks = self.blanks;
+        stats.code = self.code;
+        stats.comments = self.comments;
+        for (language, blob) in &self.blobs {
+            stats
+                .blobs
+                .insert(language.parse().ok()?, blob.to_code_stats()?);
+        }
+        Some(stats)
+    }
+}
+
+/// Scan cache loaded for one scan; written back by [`ScanCache::save`].
+pub(crate) struct ScanCache {
+    dir: PathBuf,
+    version: String,
+    previous: BTreeMap<String, CachedFile>,
+    current: BTreeMap<String, CachedFile>,
+    stats: ScanCacheStats,
+}
+
+impl ScanCache {
+    /// Load the cache in `dir`. A missing, unreadable or outdated cache
+    /// starts empty.
+    pub(crate) fn load(dir: &Path, cfg: &Config) -> Self {
+        let version = cache_version(cfg);
+        let previous = fs::read_to_string(dir.join(CACHE_FILE))
+            .ok()
+            .and_then(|text| serde_json::from_str::<CacheFile>(&text).ok())
+            .filter(|file| file.version == version)
+            .map(|file| file.files)
+            .unwrap_or_default();
+        Self {
+            dir: dir.to_path_buf(),
+            version,
+            previous,
+            current: BTreeMap::new(),
+            stats: ScanCacheStats::default(),
+        }
+    }
+
+    /// Count `paths` like `Languages::get_statistics`, reusing cached counts
+    /// for unchanged files.
+    pub(crate) fn count(&mut self, paths: &[PathBuf], ignored: &[&str], cfg: &Config) -> Languages {
+        let mut languages = Languages::new();
+        let mut stamps = BTreeMap::new();
+        let mut misses = Vec::new();
+
+        for (path, language) in walk_files(paths, ignored, cfg) {
+            let stamp = file_stamp(&path);
+            let key = path.to_str().map(str::to_string);
+            let cached = key
+                .as_ref()
+                .zip(stamp)
+                .and_then(|(key, stamp)| self.lookup(key, language, stamp));
+            match cached {
+                Some((entry, stats)) => {
+                    self.stats.hits += 1;
+                    self.current.insert(key.unwrap_or_default(), entry);
+                    let mut report = Report::new(path);
+                    report.stats = stats;
+                    languages.entry(language).or_default().add_report(report);
+                }
+                None => {
+                    self.stats.misses += 1;
+                    if let (Some(key), Some(stamp)) = (key, stamp) {
+                        stamps.insert(path.clone(), (key, stamp));
+                    }
+                    misses.push(path);
+                }
+            }
+        }
+
+        if !misses.is_empty() {
+            let mut fresh = Languages::new();
+            fresh.get_statistics(&misses, ignored, cfg);
+            for (language_type, language) in std::mem::take(&mut *fresh) {
+                let target = languages.entry(language_type).or_default();
+                target.inaccurate |= language.inaccurate;
+                for report in language.reports {
+                    if let Some((key, (modified_ns, size))) = stamps.remove(&report.name) {
+                        let entry = CachedFile {
+                            language: language_type.name().to_string(),
+                            modified_ns,
+                            size,
+                            stats: CachedStats::from_code_stats(&report.stats),
+                        };
+                        self.current.insert(key, entry);
+                    }
+                    target.add_report(report);
+                }
+            }
+        }
+
+        for language in languages.values_mut() {
+            language.total();
+        }
+        languages
+    }
+
+    fn lookup(
+        &self,
+        key: &str,
+        language: LanguageType,
+        (modified_ns, size): (u64, u64),
+    ) -> Option<(CachedFile, CodeStats)> {
+        let entry = self.previous.get(key)?;
+        if entry.modified_ns != modified_ns
+            || entry.size != size
+            || entry.language != language.name()
+        {
+            return None;
+        }
+        Some((entry.clone(), entry.stats.to_code_stats()?))
+    }
+
+    /// Write the files seen by this scan back to the cache and return the
+    /// hit counts. The cache is best-effort: a write failure leaves the
+    /// next scan to count everything again.
+    pub(crate) fn save(self) -> ScanCacheStats {
+        let file = CacheFile {
+            version: self.version,
+            files: self.current,
+        };
+        if let Ok(json) = serde_json::to_string(&file)
+            && fs::create_dir_all(&self.dir).is_ok()
+        {
+            let tmp = self.dir.join(format!("{CACHE_FILE}.tmp"));
+            if fs::write(&tmp, json).is_ok() {
+                let _ = fs::rename(&tmp, self.dir.join(CACHE_FILE));
+            }
+        }
+        self.stats
+    }
+}
+
+/// Everything that changes what tokei counts for an unchanged file.
+fn cache_version(cfg: &Config) -> String {
+    format!(
+        "tokmd-scan {}; doc strings as comments: {}",
+        env!("CARGO_PKG_VERSION"),
+        cfg.treat_doc_strings_as_comments.unwrap_or(false)
+    )
+}
+
+/// Modification time (nanoseconds since the epoch) and size of `path`.
+fn file_stamp(path: &Path) -> Option<(u64, u64)> {
+    let metadata = fs::metadata(path).ok()?;
+    let modified = metadata.modified().ok()?.duration_since(UNIX_EPOCH).ok()?;
+    Some((u64::try_from(modified.as_nanos()).ok()?, metadata.len()))
+}
+
+/// Files tokei would count under `paths`, with their languages. Mirrors the
+/// walker settings of `tokei::Languages::get_statistics`.
+fn walk_files(paths: &[PathBuf], ignored: &[&str], cfg: &Config) -> Vec<(PathBuf, LanguageType)> {
+    let Some((first, rest)) = paths.split_first() else {
+        return Vec::new();
+    };
+    let mut walker = WalkBuilder::new(first);
+    for path in rest {
+        walker.add(path);
+    }
+
+    if !ignored.is_empty() {
+        let mut overrides = OverrideBuilder::new(".");
+        for pattern in ignored {
+            let _ = overrides.add(&format!("!{pattern}"));
+        }
+        if let Ok(overrides) = overrides.build() {
+            walker.overrides(overrides);
+        }
+    }
+
+    let ignore = !cfg.no_ignore.unwrap_or(false);
+    let ignore_dot = ignore && !cfg.no_ignore_dot.unwrap_or(false);
+    let ignore_vcs = ignore && !cfg.no_ignore_vcs.unwrap_or(false);
+    if ignore_dot {
+        walker.add_custom_ignore_filename(".tokeignore");
+    }
+    walker
+        .git_exclude(ignore_vcs)
+        .git_global(ignore_vcs)
+        .git_ignore(ignore_vcs)
+        .hidden(!cfg.hidden.unwrap_or(false))
+        .ignore(ignore_dot)
+        .parents(ignore && !cfg.no_ignore_parent.unwrap_or(false));
+
+    walker
+        .build()
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_type().is_some_and(|kind| kind.is_file()))
+        .filter_map(|entry| {
+            let language = LanguageType::from_path(entry.path(), cfg)?;
+            let wanted = cfg
+                .types
+                .as_ref()
+                .is_none_or(|types| types.contains(&language));
+            wanted.then(|| (entry.into_path(), language))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cached_stats_round_trip_embedded_blobs() {
+        let mut blob = CodeStats::new();
+        blob.code = 3;
+        let mut stats = CodeStats::new();
+        stats.code = 10;
+        stats.comments = 2;
+        stats.blanks = 1;
+        stats.blobs.insert(LanguageType::Cpp, blob);
+
+        let cached = CachedStats::from_code_stats(&stats);
+        assert_eq!(cached.blobs.keys().collect::<Vec<_>>(), vec!["C++"]);
+        assert_eq!(cached.to_code_stats(), Some(stats));
+    }
+
+    #[test]
+    fn outdated_cache_version_starts_empty() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = CacheFile {
+            version: "tokmd-scan 0.0.0".to_string(),
+            files: BTreeMap::from([(
+                "/src/lib.rs".to_string(),
+                CachedFile {
+                    language: "Rust".to_string(),
+                    modified_ns: 1,
+                    size: 1,
+                    stats: CachedStats::from_code_stats(&CodeStats::new()),
+                },
+            )]),
+        };
+        fs::write(
+            dir.path().join(CACHE_FILE),
+            serde_json::to_string(&file).unwrap(),
+        )
+        .unwrap();
+
+        assert!(
+            ScanCache::load(dir.path(), &Config::default())
+                .previous
+                .is_empty()
+        );
+    }
+}
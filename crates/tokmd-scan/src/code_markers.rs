@@ -0,0 +1,66 @@
+//! Comment markers counted as code, from `ScanOptions::code_comment_markers`.
+//!
+//! Directives such as `// @ts-ignore`, `# noqa` or `//go:generate` are
+//! comments to tokei but change how the code around them builds or lints.
+//! When markers are configured, the scanner re-reads each file and moves
+//! every line that starts with one (after indentation) from `comments` to
+//! `code`. Code embedded in other files is left alone.
+
+use std::fs;
+
+use tokei::Languages;
+use tokmd_settings::ScanOptions;
+
+/// Move comment lines starting with a configured marker into `code`.
+/// Reports must still carry the absolute paths tokei scanned.
+pub(crate) fn apply_code_comment_markers(languages: &mut Languages, args: &ScanOptions) {
+    let markers: Vec<&str> = args
+        .code_comment_markers
+        .iter()
+        .map(|marker| marker.trim())
+        .filter(|marker| !marker.is_empty())
+        .collect();
+    if markers.is_empty() {
+        return;
+    }
+    for language in languages.values_mut() {
+        for report in &mut language.reports {
+            // An unreadable file keeps the counts tokei gave it.
+            let Ok(bytes) = fs::read(&report.name) else {
+                continue;
+            };
+            let moved = count_marker_lines(&String::from_utf8_lossy(&bytes), &markers)
+                .min(report.stats.comments);
+            report.stats.comments -= moved;
+            report.stats.code += moved;
+            language.comments = language.comments.saturating_sub(moved);
+            language.code += moved;
+        }
+    }
+}
+
+/// Number of lines whose first non-blank text starts with one of `markers`.
+fn count_marker_lines(text: &str, markers: &[&str]) -> usize {
+    text.lines()
+        .map(str::trim_start)
+        .filter(|line| markers.iter().any(|marker| line.starts_with(marker)))
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_lines_starting_with_a_marker_match() {
+        let text = "// @ts-ignore\nconst a = 1; // @ts-ignore\n// plain note\n    // @ts-ignore\n";
+        assert_eq!(count_marker_lines(text, &["// @ts-ignore"]), 2);
+        assert_eq!(count_marker_lines(text, &[]), 0);
+    }
+
+    #[test]
+    fn any_of_several_markers_matches() {
+        let text = "//go:generate stringer\n# noqa\n# comment\n";
+        assert_eq!(count_marker_lines(text, &["//go:generate", "# noqa"]), 2);
+    }
+}
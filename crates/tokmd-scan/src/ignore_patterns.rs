@@ -4,13 +4,22 @@
 //! Relative excludes therefore need canonical-root variants in addition to the
 //! caller-provided and globbed forms so excludes keep matching after root
 //! normalization.
+//!
+//! `tokei` only reads `.gitignore`, `.ignore` and `.tokeignore`, so the
+//! tokmd-specific ignore files (`.tokmdignore` and `ScanOptions::ignore_file`)
+//! are read here and passed on as root-anchored excludes. Only the files
+//! directly in each scan root are read; copies in subdirectories are not.
 
 use std::collections::BTreeSet;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use tokmd_settings::ScanOptions;
 
 use crate::path::{ValidatedRoot, normalize_slashes};
+use crate::roots::validated_scan_roots;
+
+/// Ignore file read from each scan root on top of the VCS and tokei ones.
+pub const TOKMD_IGNORE_FILE: &str = ".tokmdignore";
 
 pub(crate) fn ignored_patterns(args: &ScanOptions, roots: &[ValidatedRoot]) -> Vec<String> {
     let mut patterns = BTreeSet::new();
@@ -37,9 +46,100 @@ pub(crate) fn ignored_patterns(args: &ScanOptions, roots: &[ValidatedRoot]) -> V
         }
     }
 
+    for root in roots {
+        patterns.extend(ignore_file_patterns(args, root));
+    }
+
     patterns.into_iter().collect()
 }
 
+/// Patterns from the tokmd ignore files at `root`, anchored to that root.
+///
+/// Lines keep their gitignore meaning relative to the root: a pattern with a
+/// leading or inner `/` matches from the root, any other matches at any
+/// depth. Negations (`!pattern`) cannot be expressed as excludes and are
+/// skipped; [`skipped_ignore_negations`] lists them so callers can warn.
+/// `--no-ignore` and `--no-ignore-dot` turn the files off.
+fn ignore_file_patterns(args: &ScanOptions, root: &ValidatedRoot) -> Vec<String> {
+    let canonical = normalize_slashes(&root.canonical().to_string_lossy());
+    let anchor = format!("**/{}", escape_glob(canonical.trim_matches('/')));
+
+    let mut patterns = Vec::new();
+    for (_, content) in ignore_files(args, root) {
+        for line in content.lines() {
+            let line = line.trim_end();
+            if line.is_empty() || line.starts_with('#') || line.starts_with('!') {
+                continue;
+            }
+            let (anchored, body) = match line.strip_prefix('/') {
+                Some(rest) => (true, rest),
+                None => (line.trim_end_matches('/').contains('/'), line),
+            };
+            if body.is_empty() {
+                continue;
+            }
+            patterns.push(if anchored {
+                format!("{anchor}/{body}")
+            } else {
+                format!("{anchor}/**/{body}")
+            });
+        }
+    }
+    patterns
+}
+
+/// `!pattern` lines in the tokmd ignore files at the roots of `paths`, as
+/// `file:line: !pattern`.
+///
+/// Excludes cannot re-include a path, so the scan skips these lines and the
+/// paths they name stay excluded. Roots that fail validation are left to the
+/// scan to report.
+pub fn skipped_ignore_negations(paths: &[PathBuf], args: &ScanOptions) -> Vec<String> {
+    let Ok(roots) = validated_scan_roots(paths) else {
+        return Vec::new();
+    };
+    let mut skipped = Vec::new();
+    for root in &roots {
+        for (file, content) in ignore_files(args, root) {
+            for (index, line) in content.lines().enumerate() {
+                let line = line.trim_end();
+                if line.starts_with('!') {
+                    skipped.push(format!("{}:{}: {line}", file.display(), index + 1));
+                }
+            }
+        }
+    }
+    skipped
+}
+
+/// The tokmd ignore files present at `root` with their contents, unless
+/// `--no-ignore` or `--no-ignore-dot` turned them off.
+fn ignore_files(args: &ScanOptions, root: &ValidatedRoot) -> Vec<(PathBuf, String)> {
+    if args.no_ignore || args.no_ignore_dot || !root.canonical().is_dir() {
+        return Vec::new();
+    }
+    std::iter::once(TOKMD_IGNORE_FILE)
+        .chain(args.ignore_file.as_deref())
+        .filter_map(|name| {
+            let file = root.canonical().join(name);
+            let content = std::fs::read_to_string(&file).ok()?;
+            Some((file, content))
+        })
+        .collect()
+}
+
+/// Escape glob metacharacters so a literal path can prefix a pattern.
+fn escape_glob(literal: &str) -> String {
+    let mut escaped = String::with_capacity(literal.len());
+    for ch in literal.chars() {
+        if matches!(ch, '*' | '?' | '[' | ']' | '{' | '}' | '\\') {
+            escaped.push('\\');
+        }
+        escaped.push(ch);
+    }
+    escaped
+}
+
 fn is_absolute_pattern(pattern: &str) -> bool {
     let path = Path::new(pattern);
     path.is_absolute()
@@ -104,4 +204,81 @@ mod tests {
 
         assert_eq!(patterns, vec![absolute]);
     }
+
+    #[test]
+    fn ignore_file_patterns_anchor_lines_to_the_root() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join(TOKMD_IGNORE_FILE),
+            "# comment\n\ngenerated/\n/build\ndocs/api\n!keep.rs\n",
+        )
+        .unwrap();
+        let root = ValidatedRoot::new(dir.path()).unwrap();
+        let canonical = normalize_slashes(&root.canonical().to_string_lossy());
+        let anchor = format!("**/{}", escape_glob(canonical.trim_matches('/')));
+
+        let patterns = ignore_file_patterns(&ScanOptions::default(), &root);
+
+        assert_eq!(
+            patterns,
+            vec![
+                format!("{anchor}/**/generated/"),
+                format!("{anchor}/build"),
+                format!("{anchor}/docs/api"),
+            ]
+        );
+    }
+
+    #[test]
+    fn ignore_file_patterns_read_the_configured_file_too() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join(TOKMD_IGNORE_FILE), "a.rs\n").unwrap();
+        std::fs::write(dir.path().join(".locignore"), "b.rs\n").unwrap();
+        let root = ValidatedRoot::new(dir.path()).unwrap();
+        let options = ScanOptions {
+            ignore_file: Some(".locignore".to_string()),
+            ..ScanOptions::default()
+        };
+
+        let patterns = ignore_file_patterns(&options, &root);
+        assert_eq!(patterns.len(), 2);
+        assert!(patterns[0].ends_with("/**/a.rs"));
+        assert!(patterns[1].ends_with("/**/b.rs"));
+
+        let options = ScanOptions {
+            no_ignore_dot: true,
+            ..options
+        };
+        assert!(ignore_file_patterns(&options, &root).is_empty());
+    }
+
+    #[test]
+    fn skipped_ignore_negations_name_the_file_and_line() -> anyhow::Result<()> {
+        let dir = tempfile::tempdir()?;
+        std::fs::write(
+            dir.path().join(TOKMD_IGNORE_FILE),
+            "generated/\n!generated/keep.rs\n",
+        )?;
+        let root = ValidatedRoot::new(dir.path())?;
+        let file = root.canonical().join(TOKMD_IGNORE_FILE);
+
+        let skipped =
+            skipped_ignore_negations(&[dir.path().to_path_buf()], &ScanOptions::default());
+        assert_eq!(
+            skipped,
+            vec![format!("{}:2: !generated/keep.rs", file.display())]
+        );
+
+        let options = ScanOptions {
+            no_ignore: true,
+            ..ScanOptions::default()
+        };
+        assert!(skipped_ignore_negations(&[dir.path().to_path_buf()], &options).is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn escape_glob_escapes_metacharacters() {
+        assert_eq!(escape_glob("tmp/[x]*?{y}"), r"tmp/\[x\]\*\?\{y\}");
+    }
 }
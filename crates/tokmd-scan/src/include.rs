@@ -0,0 +1,101 @@
+//! Include-only globs, from `ScanOptions::include`.
+//!
+//! `tokei` prefixes every pattern it is given with `!`, so its overrides can
+//! only exclude. When include globs are set, the scanner drops every report
+//! whose path, relative to its scan root, matches none of them, and re-totals
+//! each language from what is left. Ignore files and excludes have already
+//! been applied by then, so they still win.
+
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use ignore::overrides::{Override, OverrideBuilder};
+use tokei::Languages;
+use tokmd_settings::ScanOptions;
+
+use crate::path::ValidatedRoot;
+
+/// Keep only the reports matching one of `args.include`.
+/// Reports must already be rebased onto the caller's `roots`.
+pub(crate) fn apply_include_globs(
+    languages: &mut Languages,
+    args: &ScanOptions,
+    roots: &[ValidatedRoot],
+) -> Result<()> {
+    if args.include.is_empty() {
+        return Ok(());
+    }
+    let include = include_override(&args.include)?;
+    let keep = |path: &Path| is_included(&include, path, roots);
+
+    for language in languages.values_mut() {
+        language.reports.retain(|report| keep(&report.name));
+        for reports in language.children.values_mut() {
+            reports.retain(|report| keep(&report.name));
+        }
+        language.children.retain(|_, reports| !reports.is_empty());
+        language.total();
+    }
+    languages.retain(|_, language| !language.reports.is_empty() || !language.children.is_empty());
+    Ok(())
+}
+
+fn include_override(patterns: &[String]) -> Result<Override> {
+    let mut builder = OverrideBuilder::new(".");
+    for pattern in patterns {
+        builder
+            .add(pattern)
+            .with_context(|| format!("invalid include glob: {pattern}"))?;
+    }
+    builder.build().context("failed to compile include globs")
+}
+
+/// Whether `path` matches an include glob relative to the deepest root
+/// containing it. A root that is itself a file was named explicitly and is
+/// always kept, as are paths outside every root.
+fn is_included(include: &Override, path: &Path, roots: &[ValidatedRoot]) -> bool {
+    let relative = roots
+        .iter()
+        .filter_map(|root| path.strip_prefix(root.input()).ok())
+        .min_by_key(|relative| relative.components().count());
+    match relative {
+        Some(relative) if !relative.as_os_str().is_empty() => {
+            include.matched(relative, false).is_whitelist()
+        }
+        _ => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matches(patterns: &[&str], path: &str) -> bool {
+        let patterns: Vec<String> = patterns.iter().map(|pattern| pattern.to_string()).collect();
+        include_override(&patterns)
+            .unwrap()
+            .matched(path, false)
+            .is_whitelist()
+    }
+
+    #[test]
+    fn include_globs_use_gitignore_anchoring() {
+        assert!(matches(&["src/**/*.rs"], "src/lib.rs"));
+        assert!(matches(&["src/**/*.rs"], "src/a/b.rs"));
+        assert!(!matches(&["src/**/*.rs"], "tests/src/lib.rs"));
+        assert!(!matches(&["src/**/*.rs"], "src/main.py"));
+        assert!(matches(&["*.py"], "tools/gen.py"));
+    }
+
+    #[test]
+    fn any_include_glob_admits_a_file() {
+        assert!(matches(&["src/**/*.rs", "*.py"], "tools/gen.py"));
+        assert!(!matches(&["src/**/*.rs", "*.py"], "README.md"));
+    }
+
+    #[test]
+    fn invalid_include_glob_is_an_error() {
+        let err = include_override(&["src/[".to_string()]).unwrap_err();
+        assert!(err.to_string().contains("invalid include glob: src/["));
+    }
+}
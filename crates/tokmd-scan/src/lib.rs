@@ -15,16 +15,21 @@
 //! * Receipt construction
 
 use anyhow::Result;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use tokei::{Config, Languages};
 
+use crate::cache::ScanCache;
+use crate::code_markers::apply_code_comment_markers;
 use crate::ignore_patterns::ignored_patterns;
 pub use crate::in_memory::{
     InMemoryFile, MaterializedScan, normalize_in_memory_paths, scan_in_memory, scan_snapshot,
 };
 #[cfg(feature = "archive-zip")]
 pub use crate::in_memory::{inputs_from_zip_bytes, scan_snapshot_from_zip};
+use crate::include::apply_include_globs;
+use crate::logical::apply_line_count_mode;
 use crate::roots::{rebase_report_paths, validated_scan_roots};
+use crate::symlinks::linked_dir_roots;
 // Re-export the archive admission types so `scan_snapshot_from_zip` callers can
 // build limits and match errors without a separate `tokmd-io-port` import.
 #[cfg(feature = "archive-zip")]
@@ -34,6 +39,18 @@ use tokmd_types::ConfigMode;
 
 /// Scans a set of paths and computes line counts for each language found.
 ///
+/// Symlinked directories are skipped unless `args.symlink_policy` follows
+/// them; followed directories are reported under the link's path.
+///
+/// With `args.line_count_mode` set to logical, `code` counts statements
+/// instead of lines for languages that have a statement rule. Comment lines
+/// starting with one of `args.code_comment_markers` count as code.
+///
+/// Besides `.gitignore`, `.ignore` and `.tokeignore`, patterns in a
+/// `.tokmdignore` file (and in `args.ignore_file`, when set) at each scan
+/// root are excluded. When `args.include` is set, only files matching one of
+/// its globs (relative to their scan root) are counted.
+///
 /// # Examples
 ///
 /// ```
@@ -62,7 +79,24 @@ use tokmd_types::ConfigMode;
 /// # }
 /// ```
 pub fn scan(paths: &[PathBuf], args: &ScanOptions) -> Result<Languages> {
+    scan_with_cache_stats(paths, args).map(|(languages, _)| languages)
+}
+
+/// [`scan`], also reporting how many files `args.scan_cache` let it reuse.
+///
+/// With `args.scan_cache` set to a directory, files whose modification time
+/// and size match the cache skip re-counting, and the cache is rewritten with
+/// this scan's files. Without it, every file is counted and the stats are
+/// zero.
+pub fn scan_with_cache_stats(
+    paths: &[PathBuf],
+    args: &ScanOptions,
+) -> Result<(Languages, ScanCacheStats)> {
     let cfg = config_from_scan_options(args);
+    let mut cache = args
+        .scan_cache
+        .as_deref()
+        .map(|dir| ScanCache::load(Path::new(dir), &cfg));
     let roots = validated_scan_roots(paths)?;
     let ignores = ignored_patterns(args, &roots);
     let ignore_refs: Vec<_> = ignores.iter().map(String::as_str).collect();
@@ -71,11 +105,44 @@ pub fn scan(paths: &[PathBuf], args: &ScanOptions) -> Result<Languages> {
         .map(|root| root.canonical().to_path_buf())
         .collect();
 
-    let mut languages = Languages::new();
-    languages.get_statistics(&scan_paths, &ignore_refs, &cfg);
+    let mut languages = count(&scan_paths, &ignore_refs, &cfg, cache.as_mut());
+    apply_line_count_mode(&mut languages, args);
+    apply_code_comment_markers(&mut languages, args);
     rebase_report_paths(&mut languages, &roots);
 
-    Ok(languages)
+    // Each followed link is scanned and rebased on its own, so a target that
+    // is also reached directly keeps both paths instead of being relabeled.
+    for linked_root in linked_dir_roots(&roots, args) {
+        let linked_paths = [linked_root.canonical().to_path_buf()];
+        let linked = std::slice::from_ref(&linked_root);
+        let ignores = ignored_patterns(args, linked);
+        let ignore_refs: Vec<_> = ignores.iter().map(String::as_str).collect();
+        let mut extra = count(&linked_paths, &ignore_refs, &cfg, cache.as_mut());
+        apply_line_count_mode(&mut extra, args);
+        apply_code_comment_markers(&mut extra, args);
+        rebase_report_paths(&mut extra, linked);
+        languages += std::mem::take(&mut *extra);
+    }
+
+    apply_include_globs(&mut languages, args, &roots)?;
+    let stats = cache.map(ScanCache::save).unwrap_or_default();
+    Ok((languages, stats))
+}
+
+fn count(
+    paths: &[PathBuf],
+    ignored: &[&str],
+    cfg: &Config,
+    cache: Option<&mut ScanCache>,
+) -> Languages {
+    match cache {
+        Some(cache) => cache.count(paths, ignored, cfg),
+        None => {
+            let mut languages = Languages::new();
+            languages.get_statistics(paths, ignored, cfg);
+            languages
+        }
+    }
 }
 
 /// Build the `tokei` config used for a scan from clap-free `ScanOptions`.
@@ -131,6 +198,14 @@ mod tests {
             no_ignore_dot: false,
             no_ignore_vcs: false,
             treat_doc_strings_as_comments: false,
+            token_model: None,
+            hash_algo: tokmd_types::HashAlgo::Blake3,
+            symlink_policy: Default::default(),
+            line_count_mode: Default::default(),
+            code_comment_markers: Vec::new(),
+            ignore_file: None,
+            include: Vec::new(),
+            scan_cache: None,
         }
     }
 
@@ -206,6 +281,99 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn scan_logical_mode_counts_statements_on_one_line() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        fs::write(
+            dir.path().join("lib.rs"),
+            "fn f() { let a = 1; let b = 2; let c = 3; }\n",
+        )?;
+        let paths = vec![dir.path().to_path_buf()];
+
+        let code = |line_count_mode| -> Result<usize> {
+            let args = ScanOptions {
+                line_count_mode,
+                ..default_scan_options()
+            };
+            let languages = scan(&paths, &args)?;
+            let rust = languages.get(&tokei::LanguageType::Rust).unwrap();
+            assert_eq!(rust.reports[0].stats.code, rust.code);
+            Ok(rust.code)
+        };
+
+        assert_eq!(code(tokmd_types::LineCountMode::Physical)?, 1);
+        assert_eq!(code(tokmd_types::LineCountMode::Logical)?, 3);
+        Ok(())
+    }
+
+    #[test]
+    fn scan_code_comment_markers_count_directives_as_code() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        fs::write(
+            dir.path().join("main.rs"),
+            "//@generate stubs\n// plain note\nfn main() {}\n",
+        )?;
+        let args = ScanOptions {
+            code_comment_markers: vec!["//@generate".to_string()],
+            ..default_scan_options()
+        };
+
+        let languages = scan(&[dir.path().to_path_buf()], &args)?;
+        let rust = languages.get(&tokei::LanguageType::Rust).unwrap();
+
+        assert_eq!((rust.code, rust.comments), (2, 1));
+        assert_eq!(rust.reports[0].stats.code, 2);
+        assert_eq!(rust.reports[0].stats.comments, 1);
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn scan_symlink_policy_controls_linked_directory_files() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let root = dir.path().join("repo");
+        let shared = dir.path().join("shared");
+        fs::create_dir_all(root.join("src"))?;
+        fs::create_dir_all(&shared)?;
+        fs::write(root.join("src/lib.rs"), "pub fn lib() {}\n")?;
+        fs::write(shared.join("util.rs"), "pub fn util() {}\n")?;
+        std::os::unix::fs::symlink(&shared, root.join("vendor"))?;
+        std::os::unix::fs::symlink(root.join("src"), root.join("alias"))?;
+        std::os::unix::fs::symlink(&root, root.join("src/loop"))?;
+
+        let rust_files = |policy| -> Result<Vec<String>> {
+            let args = ScanOptions {
+                symlink_policy: policy,
+                ..default_scan_options()
+            };
+            let languages = scan(std::slice::from_ref(&root), &args)?;
+            let mut names: Vec<String> = languages
+                .get(&tokei::LanguageType::Rust)
+                .map(|rust| {
+                    rust.reports
+                        .iter()
+                        .filter_map(|r| r.name.strip_prefix(&root).ok())
+                        .map(|p| p.to_string_lossy().replace('\\', "/"))
+                        .collect()
+                })
+                .unwrap_or_default();
+            names.sort();
+            Ok(names)
+        };
+
+        use tokmd_types::SymlinkPolicy;
+        assert_eq!(rust_files(SymlinkPolicy::Ignore)?, ["src/lib.rs"]);
+        assert_eq!(
+            rust_files(SymlinkPolicy::Follow)?,
+            ["alias/lib.rs", "src/lib.rs", "vendor/util.rs"]
+        );
+        assert_eq!(
+            rust_files(SymlinkPolicy::FollowNoLoop)?,
+            ["src/lib.rs", "vendor/util.rs"]
+        );
+        Ok(())
+    }
+
     #[test]
     fn scan_rebases_embedded_child_report_paths_to_input_root() -> Result<()> {
         let args = default_scan_options();
@@ -275,6 +443,152 @@ mod tests {
         Ok(())
     }
 
+    fn rust_report_paths(languages: &Languages) -> Vec<String> {
+        let mut paths: Vec<_> = languages
+            .get(&tokei::LanguageType::Rust)
+            .map(|rust| {
+                rust.reports
+                    .iter()
+                    .map(|report| normalize_slashes(&report.name.to_string_lossy()))
+                    .collect()
+            })
+            .unwrap_or_default();
+        paths.sort();
+        paths
+    }
+
+    #[test]
+    fn scan_honors_tokmdignore_alongside_gitignore() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let root = dir.path();
+        std::process::Command::new("git")
+            .arg("init")
+            .current_dir(root)
+            .output()?;
+        fs::create_dir_all(root.join("generated"))?;
+        fs::create_dir_all(root.join("src/generated"))?;
+        fs::write(root.join(".gitignore"), "vcs_ignored.rs\n")?;
+        fs::write(root.join(".tokmdignore"), "# generated code\ngenerated/\n")?;
+        fs::write(root.join("kept.rs"), "fn kept() {}\n")?;
+        fs::write(root.join("vcs_ignored.rs"), "fn vcs() {}\n")?;
+        fs::write(root.join("generated/gen.rs"), "fn generated() {}\n")?;
+        fs::write(root.join("src/generated/deep.rs"), "fn deep() {}\n")?;
+
+        let paths = rust_report_paths(&scan(&[root.to_path_buf()], &default_scan_options())?);
+
+        assert_eq!(paths.len(), 1, "{paths:?}");
+        assert!(paths[0].ends_with("kept.rs"), "{paths:?}");
+        Ok(())
+    }
+
+    #[test]
+    fn scan_include_globs_count_only_matching_files() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let root = dir.path();
+        fs::create_dir_all(root.join("src/nested"))?;
+        fs::create_dir_all(root.join("tests"))?;
+        fs::write(root.join("src/lib.rs"), "pub fn lib() {}\n")?;
+        fs::write(root.join("src/nested/mod.rs"), "pub fn nested() {}\n")?;
+        fs::write(root.join("src/gen.py"), "print('hi')\n")?;
+        fs::write(root.join("tests/it.rs"), "fn it() {}\n")?;
+        fs::write(root.join("build.rs"), "fn main() {}\n")?;
+
+        let mut args = default_scan_options();
+        args.include = vec!["src/**/*.rs".to_string()];
+        let languages = scan(&[root.to_path_buf()], &args)?;
+
+        assert_eq!(
+            languages.keys().copied().collect::<Vec<_>>(),
+            vec![tokei::LanguageType::Rust]
+        );
+        let paths = rust_report_paths(&languages);
+        assert_eq!(paths.len(), 2, "{paths:?}");
+        assert!(paths[0].ends_with("src/lib.rs"), "{paths:?}");
+        assert!(paths[1].ends_with("src/nested/mod.rs"), "{paths:?}");
+        assert_eq!(languages[&tokei::LanguageType::Rust].code, 2);
+        Ok(())
+    }
+
+    #[test]
+    fn scan_include_globs_still_honor_excludes() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let root = dir.path();
+        fs::create_dir_all(root.join("src/generated"))?;
+        fs::write(root.join("src/lib.rs"), "pub fn lib() {}\n")?;
+        fs::write(root.join("src/generated/out.rs"), "pub fn out() {}\n")?;
+
+        let mut args = default_scan_options();
+        args.include = vec!["src/**/*.rs".to_string()];
+        args.excluded = vec!["generated".to_string()];
+        let paths = rust_report_paths(&scan(&[root.to_path_buf()], &args)?);
+
+        assert_eq!(paths.len(), 1, "{paths:?}");
+        assert!(paths[0].ends_with("src/lib.rs"), "{paths:?}");
+        Ok(())
+    }
+
+    #[test]
+    fn scan_cache_reuses_counts_of_unchanged_files() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let cache_dir = tempfile::tempdir()?;
+        let root = dir.path();
+        fs::create_dir_all(root.join("src"))?;
+        fs::write(root.join("src/lib.rs"), "pub fn lib() {}\n// note\n")?;
+        fs::write(root.join("src/main.rs"), "fn main() {}\n\nfn other() {}\n")?;
+
+        let mut args = default_scan_options();
+        args.scan_cache = Some(cache_dir.path().to_string_lossy().into_owned());
+        let paths = [root.to_path_buf()];
+
+        let (first, stats) = scan_with_cache_stats(&paths, &args)?;
+        assert_eq!(stats, ScanCacheStats { hits: 0, misses: 2 });
+        let (second, stats) = scan_with_cache_stats(&paths, &args)?;
+        assert_eq!(stats, ScanCacheStats { hits: 2, misses: 0 });
+
+        let rust = |languages: &Languages| {
+            let rust = &languages[&tokei::LanguageType::Rust];
+            (rust.code, rust.comments, rust.blanks)
+        };
+        assert_eq!(rust_report_paths(&first), rust_report_paths(&second));
+        assert_eq!(rust(&first), rust(&second));
+        assert_eq!(rust(&second), (3, 1, 1));
+
+        fs::write(
+            root.join("src/lib.rs"),
+            "pub fn lib() {}\npub fn more() {}\n",
+        )?;
+        let (third, stats) = scan_with_cache_stats(&paths, &args)?;
+        assert_eq!(stats, ScanCacheStats { hits: 1, misses: 1 });
+        assert_eq!(rust(&third), (4, 0, 1));
+        Ok(())
+    }
+
+    #[test]
+    fn scan_reads_the_configured_ignore_file_unless_dot_ignores_are_off() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let root = dir.path();
+        fs::create_dir_all(root.join("sub"))?;
+        fs::write(root.join(".locignore"), "/legacy.rs\n!sub/legacy.rs\n")?;
+        fs::write(root.join("kept.rs"), "fn kept() {}\n")?;
+        fs::write(root.join("legacy.rs"), "fn legacy() {}\n")?;
+        fs::write(root.join("sub/legacy.rs"), "fn nested() {}\n")?;
+
+        let mut args = default_scan_options();
+        args.ignore_file = Some(".locignore".to_string());
+        let paths = rust_report_paths(&scan(&[root.to_path_buf()], &args)?);
+        // Only the root-anchored file is dropped; the negation is skipped.
+        assert_eq!(paths.len(), 2, "{paths:?}");
+        assert!(paths[0].ends_with("kept.rs"), "{paths:?}");
+        assert!(paths[1].ends_with("sub/legacy.rs"), "{paths:?}");
+
+        args.no_ignore_dot = true;
+        assert_eq!(
+            rust_report_paths(&scan(&[root.to_path_buf()], &args)?).len(),
+            3
+        );
+        Ok(())
+    }
+
     // ========================
     // Config Flag Tests
     // ========================
@@ -353,6 +667,14 @@ mod tests {
             no_ignore_dot: true,
             no_ignore_vcs: true,
             treat_doc_strings_as_comments: true,
+            token_model: None,
+            hash_algo: tokmd_types::HashAlgo::Blake3,
+            symlink_policy: Default::default(),
+            line_count_mode: Default::default(),
+            code_comment_markers: Vec::new(),
+            ignore_file: None,
+            include: Vec::new(),
+            scan_cache: None,
         };
         let paths = vec![test_path()];
         // Should handle all flags without panicking
@@ -377,16 +699,23 @@ mod tests {
     }
 }
 
+mod cache;
+mod code_markers;
 pub mod exclude;
 mod ignore_patterns;
 mod in_memory;
+mod include;
+mod logical;
 pub mod math;
 pub mod path;
 mod roots;
+mod symlinks;
 pub mod tokeignore;
 pub mod walk;
 
+pub use cache::{SCAN_CACHE_DIR, ScanCacheStats};
 pub use exclude::{add_exclude_pattern, has_exclude_pattern, normalize_exclude_pattern};
+pub use ignore_patterns::{TOKMD_IGNORE_FILE, skipped_ignore_negations};
 pub use math::{gini_coefficient, percentile, round_f64, safe_ratio};
 pub use path::{
     canonicalize_bounded_path, normalize_bounded_rel_path, normalize_rel_path, normalize_slashes,
@@ -0,0 +1,282 @@
+//! Logical line counting for [`LineCountMode::Logical`].
+//!
+//! tokei only counts physical lines. In logical mode the scanner re-reads
+//! each file whose language has a statement rule and replaces its `code`
+//! count with the number of statements: `;` terminators in C-family
+//! languages, statement-ending newlines (or `;`) in Python. Comments and
+//! string contents never count. Languages without a rule, and code embedded
+//! in other files, keep their physical counts.
+
+use std::fs;
+use std::iter::Peekable;
+use std::str::Chars;
+
+use tokei::{LanguageType, Languages};
+use tokmd_settings::ScanOptions;
+use tokmd_types::LineCountMode;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Rule {
+    /// Statements end at `;`. `char_literals` marks languages where `'`
+    /// opens a character literal (or a Rust lifetime) rather than a string.
+    Semicolon { char_literals: bool },
+    /// Statements end at a newline outside brackets, or at `;`.
+    Newline,
+}
+
+fn rule_for(lang: LanguageType) -> Option<Rule> {
+    match lang {
+        LanguageType::C
+        | LanguageType::CHeader
+        | LanguageType::Cpp
+        | LanguageType::CppHeader
+        | LanguageType::CSharp
+        | LanguageType::Java
+        | LanguageType::Rust => Some(Rule::Semicolon {
+            char_literals: true,
+        }),
+        LanguageType::JavaScript
+        | LanguageType::Jsx
+        | LanguageType::TypeScript
+        | LanguageType::Tsx
+        | LanguageType::Php => Some(Rule::Semicolon {
+            char_literals: false,
+        }),
+        LanguageType::Python => Some(Rule::Newline),
+        _ => None,
+    }
+}
+
+/// Replace physical `code` counts with logical ones when `args` asks for
+/// logical mode. Reports must still carry the absolute paths tokei scanned.
+pub(crate) fn apply_line_count_mode(languages: &mut Languages, args: &ScanOptions) {
+    if args.line_count_mode != LineCountMode::Logical {
+        return;
+    }
+    for (lang_type, language) in languages.iter_mut() {
+        let Some(rule) = rule_for(*lang_type) else {
+            continue;
+        };
+        for report in &mut language.reports {
+            // An unreadable file keeps the count tokei gave it.
+            let Ok(bytes) = fs::read(&report.name) else {
+                continue;
+            };
+            let logical = count_logical(rule, &String::from_utf8_lossy(&bytes));
+            language.code = (language.code + logical).saturating_sub(report.stats.code);
+            report.stats.code = logical;
+        }
+    }
+}
+
+fn count_logical(rule: Rule, text: &str) -> usize {
+    match rule {
+        Rule::Semicolon { char_literals } => count_semicolons(text, char_literals),
+        Rule::Newline => count_python_statements(text),
+    }
+}
+
+/// Count `;` terminators outside comments, strings and character literals.
+fn count_semicolons(text: &str, char_literals: bool) -> usize {
+    let mut chars = text.chars().peekable();
+    let mut count = 0;
+    while let Some(c) = chars.next() {
+        match c {
+            '/' if chars.peek() == Some(&'/') => skip_to_newline(&mut chars),
+            '/' if chars.next_if_eq(&'*').is_some() => skip_block_comment(&mut chars),
+            '"' => skip_quoted(&mut chars, '"'),
+            '`' if !char_literals => skip_quoted(&mut chars, '`'),
+            '\'' if !char_literals => skip_quoted(&mut chars, '\''),
+            '\'' => skip_char_literal(&mut chars),
+            ';' => count += 1,
+            _ => {}
+        }
+    }
+    count
+}
+
+/// Count Python statements: a newline ends one when the line held code and
+/// no bracket or backslash continues it; `;` separates statements on a line.
+fn count_python_statements(text: &str) -> usize {
+    let mut chars = text.chars().peekable();
+    let mut count = 0;
+    let mut depth = 0usize;
+    let mut has_code = false;
+    while let Some(c) = chars.next() {
+        match c {
+            '#' => skip_to_newline(&mut chars),
+            quote @ ('"' | '\'') => {
+                has_code = true;
+                let mut ahead = chars.clone();
+                if ahead.next() == Some(quote) && ahead.next() == Some(quote) {
+                    chars = ahead;
+                    skip_triple_quoted(&mut chars, quote);
+                } else {
+                    skip_line_quoted(&mut chars, quote);
+                }
+            }
+            '\\' if chars.next_if_eq(&'\n').is_some() => {}
+            '(' | '[' | '{' => {
+                has_code = true;
+                depth += 1;
+            }
+            ')' | ']' | '}' => {
+                has_code = true;
+                depth = depth.saturating_sub(1);
+            }
+            ';' => {
+                if has_code {
+                    count += 1;
+                    has_code = false;
+                }
+            }
+            '\n' => {
+                if has_code && depth == 0 {
+                    count += 1;
+                    has_code = false;
+                }
+            }
+            c if c.is_whitespace() => {}
+            _ => has_code = true,
+        }
+    }
+    count + usize::from(has_code)
+}
+
+/// Advance to the newline ending the current line (left unconsumed), or the
+/// end of input.
+fn skip_to_newline(chars: &mut Peekable<Chars<'_>>) {
+    while chars.next_if(|&c| c != '\n').is_some() {}
+}
+
+/// Consume a block comment body through its closing `*/`.
+fn skip_block_comment(chars: &mut Peekable<Chars<'_>>) {
+    while let Some(c) = chars.next() {
+        if c == '*' && chars.next_if_eq(&'/').is_some() {
+            return;
+        }
+    }
+}
+
+/// Consume a string body through the unescaped `quote` closing it. The
+/// string may span lines.
+fn skip_quoted(chars: &mut Peekable<Chars<'_>>, quote: char) {
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                chars.next();
+            }
+            c if c == quote => return,
+            _ => {}
+        }
+    }
+}
+
+/// Like [`skip_quoted`], but an unterminated string stops at the newline.
+fn skip_line_quoted(chars: &mut Peekable<Chars<'_>>, quote: char) {
+    while let Some(c) = chars.next_if(|&c| c != '\n') {
+        match c {
+            '\\' => {
+                chars.next();
+            }
+            c if c == quote => return,
+            _ => {}
+        }
+    }
+}
+
+/// Consume a triple-quoted string body through the three `quote`s closing it.
+fn skip_triple_quoted(chars: &mut Peekable<Chars<'_>>, quote: char) {
+    let mut run = 0;
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            chars.next();
+            run = 0;
+        } else if c == quote {
+            run += 1;
+            if run == 3 {
+                return;
+            }
+        } else {
+            run = 0;
+        }
+    }
+}
+
+/// Skip the rest of a character literal such as `'a'` or `'\n'` whose
+/// opening `'` was just consumed. A `'` that does not start one (a Rust
+/// lifetime) is stepped over on its own.
+fn skip_char_literal(chars: &mut Peekable<Chars<'_>>) {
+    if chars.peek() == Some(&'\\') {
+        skip_line_quoted(chars, '\'');
+        return;
+    }
+    let mut ahead = chars.clone();
+    if ahead.next().is_some() && ahead.next() == Some('\'') {
+        *chars = ahead;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rust(text: &str) -> usize {
+        count_logical(rule_for(LanguageType::Rust).unwrap(), text)
+    }
+
+    fn python(text: &str) -> usize {
+        count_logical(rule_for(LanguageType::Python).unwrap(), text)
+    }
+
+    #[test]
+    fn rust_line_with_several_statements_counts_each() {
+        assert_eq!(rust("let a = 1; let b = 2; let c = 3;\n"), 3);
+    }
+
+    #[test]
+    fn semicolons_in_comments_strings_and_chars_are_ignored() {
+        let text = "// a; b;\n/* c; d; */\nlet s = \"e; \\\"f;\";\nlet c = ';';\nlet e = '\\'';\n";
+        assert_eq!(rust(text), 3);
+    }
+
+    #[test]
+    fn rust_lifetimes_do_not_open_char_literals() {
+        assert_eq!(rust("fn f<'a>(x: &'a str) -> &'a str { x; x }\n"), 1);
+    }
+
+    #[test]
+    fn javascript_single_quotes_and_templates_are_strings() {
+        let rule = rule_for(LanguageType::JavaScript).unwrap();
+        assert_eq!(
+            count_logical(rule, "const a = 'x;y'; const b = `p;\nq`;\n"),
+            2
+        );
+    }
+
+    #[test]
+    fn python_counts_statement_ending_newlines() {
+        let text = "import os\n\n# note; not code\nx = 1; y = 2\n";
+        assert_eq!(python(text), 3);
+    }
+
+    #[test]
+    fn python_brackets_continuations_and_strings_join_lines() {
+        let text = "total = (1 +\n         2)\nname = 'a' \\\n    'b'\ndoc = \"\"\"one\ntwo\"\"\"\nlast = 3";
+        assert_eq!(python(text), 4);
+    }
+
+    #[test]
+    fn unterminated_comments_strings_and_escapes_stop_at_end_of_input() {
+        assert_eq!(rust("a; /* b;"), 1);
+        assert_eq!(rust("a; \"b;\\"), 1);
+        assert_eq!(rust("a; '\\"), 1);
+        assert_eq!(python("x = 1\ns = \"\"\"open"), 2);
+    }
+
+    #[test]
+    fn languages_without_a_rule_keep_physical_counts() {
+        assert_eq!(rule_for(LanguageType::Markdown), None);
+        assert_eq!(rule_for(LanguageType::Toml), None);
+    }
+}
@@ -0,0 +1,191 @@
+//! Symlinked-directory discovery for [`SymlinkPolicy`].
+//!
+//! tokei never descends into symlinked directories, which is the `Ignore`
+//! policy. The following policies find each linked directory up front and
+//! scan it as an extra root whose reports are rebased onto the link's own
+//! path, so a file reached through `vendor/lib -> ../shared` is reported as
+//! `vendor/lib/...`.
+//!
+//! Discovery walks with `ignore`'s loop detection, so a link back into one of
+//! its own ancestors is never followed under either policy.
+
+use std::path::{Path, PathBuf};
+
+use ignore::WalkBuilder;
+use ignore::overrides::OverrideBuilder;
+use tokmd_settings::ScanOptions;
+use tokmd_types::SymlinkPolicy;
+
+use crate::ignore_patterns::ignored_patterns;
+use crate::path::ValidatedRoot;
+
+/// Extra scan roots for the symlinked directories under `roots`, in walk
+/// order. Empty for [`SymlinkPolicy::Ignore`].
+pub(crate) fn linked_dir_roots(roots: &[ValidatedRoot], args: &ScanOptions) -> Vec<ValidatedRoot> {
+    if args.symlink_policy == SymlinkPolicy::Ignore {
+        return Vec::new();
+    }
+
+    let mut covered: Vec<PathBuf> = roots.iter().map(|r| r.canonical().to_path_buf()).collect();
+    let mut linked = Vec::new();
+    for root in roots {
+        for link in symlinked_dirs(root, args) {
+            let Ok(extra) = ValidatedRoot::new(&link) else {
+                continue;
+            };
+            if args.symlink_policy == SymlinkPolicy::FollowNoLoop
+                && overlaps_any(extra.canonical(), &covered)
+            {
+                continue;
+            }
+            covered.push(extra.canonical().to_path_buf());
+            linked.push(extra);
+        }
+    }
+    linked
+}
+
+/// Caller-facing paths of every symlinked directory under `root`, honoring
+/// the scan's hidden-file, ignore-file, and exclude settings.
+fn symlinked_dirs(root: &ValidatedRoot, args: &ScanOptions) -> Vec<PathBuf> {
+    let ignore_files = !args.no_ignore;
+    let mut builder = WalkBuilder::new(root.canonical());
+    builder
+        .hidden(!args.hidden)
+        .ignore(ignore_files && !args.no_ignore_dot)
+        .parents(ignore_files && !args.no_ignore_parent)
+        .git_ignore(ignore_files && !args.no_ignore_vcs)
+        .git_exclude(ignore_files && !args.no_ignore_vcs)
+        .git_global(ignore_files && !args.no_ignore_vcs)
+        .follow_links(true);
+    if let Some(overrides) = exclude_overrides(root, args) {
+        builder.overrides(overrides);
+    }
+
+    builder
+        .build()
+        // Loop errors land here too: the walker refuses to descend into them.
+        .filter_map(Result::ok)
+        .filter(|entry| entry.path_is_symlink() && entry.file_type().is_some_and(|t| t.is_dir()))
+        .filter_map(|entry| {
+            let relative = entry.path().strip_prefix(root.canonical()).ok()?;
+            Some(root.input().join(relative))
+        })
+        .collect()
+}
+
+/// Excludes as `ignore` overrides, mirroring how tokei applies them.
+fn exclude_overrides(
+    root: &ValidatedRoot,
+    args: &ScanOptions,
+) -> Option<ignore::overrides::Override> {
+    if args.excluded.is_empty() {
+        return None;
+    }
+    let mut overrides = OverrideBuilder::new(root.canonical());
+    for pattern in ignored_patterns(args, std::slice::from_ref(root)) {
+        overrides.add(&format!("!{pattern}")).ok()?;
+    }
+    overrides.build().ok()
+}
+
+/// True when `target` lies inside, or contains, an already-scanned directory.
+fn overlaps_any(target: &Path, covered: &[PathBuf]) -> bool {
+    covered
+        .iter()
+        .any(|dir| target.starts_with(dir) || dir.starts_with(target))
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use std::fs;
+    use std::os::unix::fs::symlink;
+
+    use super::*;
+
+    fn options(policy: SymlinkPolicy) -> ScanOptions {
+        ScanOptions {
+            symlink_policy: policy,
+            ..ScanOptions::default()
+        }
+    }
+
+    fn linked_names(root: &Path, policy: SymlinkPolicy) -> Vec<String> {
+        let roots = [ValidatedRoot::new(root).unwrap()];
+        let mut names: Vec<String> = linked_dir_roots(&roots, &options(policy))
+            .iter()
+            .map(|r| {
+                r.input()
+                    .strip_prefix(root)
+                    .unwrap()
+                    .to_string_lossy()
+                    .replace('\\', "/")
+            })
+            .collect();
+        names.sort();
+        names
+    }
+
+    /// `repo/src` holds code, `shared` sits outside the repo, and the repo
+    /// links to both: `repo/vendor -> shared`, `repo/alias -> repo/src`.
+    fn fixture() -> (tempfile::TempDir, PathBuf) {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = dir.path().join("repo");
+        let shared = dir.path().join("shared");
+        fs::create_dir_all(repo.join("src")).unwrap();
+        fs::create_dir_all(&shared).unwrap();
+        fs::write(repo.join("src/lib.rs"), "fn a() {}\n").unwrap();
+        fs::write(shared.join("util.rs"), "fn b() {}\n").unwrap();
+        symlink(&shared, repo.join("vendor")).unwrap();
+        symlink(repo.join("src"), repo.join("alias")).unwrap();
+        (dir, repo)
+    }
+
+    #[test]
+    fn ignore_policy_follows_nothing() {
+        let (_dir, repo) = fixture();
+        assert!(linked_names(&repo, SymlinkPolicy::Ignore).is_empty());
+    }
+
+    #[test]
+    fn follow_policy_follows_every_directory_link() {
+        let (_dir, repo) = fixture();
+        assert_eq!(
+            linked_names(&repo, SymlinkPolicy::Follow),
+            ["alias", "vendor"]
+        );
+    }
+
+    #[test]
+    fn follow_no_loop_skips_links_into_scanned_directories() {
+        let (_dir, repo) = fixture();
+        assert_eq!(linked_names(&repo, SymlinkPolicy::FollowNoLoop), ["vendor"]);
+    }
+
+    #[test]
+    fn self_referential_link_terminates() {
+        let (_dir, repo) = fixture();
+        symlink(&repo, repo.join("src/loop")).unwrap();
+
+        assert_eq!(linked_names(&repo, SymlinkPolicy::FollowNoLoop), ["vendor"]);
+        // The walker's loop detection never descends into `src/loop`, so even
+        // plain `Follow` finishes with a finite set.
+        let followed = linked_names(&repo, SymlinkPolicy::Follow);
+        assert!(followed.contains(&"vendor".to_string()), "{followed:?}");
+        assert!(
+            !followed.iter().any(|name| name.contains("loop/")),
+            "{followed:?}"
+        );
+    }
+
+    #[test]
+    fn excluded_links_are_not_followed() {
+        let (_dir, repo) = fixture();
+        let roots = [ValidatedRoot::new(&repo).unwrap()];
+        let args = ScanOptions {
+            excluded: vec!["vendor".to_string()],
+            ..options(SymlinkPolicy::FollowNoLoop)
+        };
+        assert!(linked_dir_roots(&roots, &args).is_empty());
+    }
+}
@@ -27,6 +27,14 @@ fn default_opts() -> ScanOptions {
         no_ignore_dot: false,
         no_ignore_vcs: false,
         treat_doc_strings_as_comments: false,
+        token_model: None,
+        hash_algo: tokmd_types::HashAlgo::Blake3,
+        symlink_policy: Default::default(),
+        line_count_mode: Default::default(),
+        code_comment_markers: Vec::new(),
+        ignore_file: None,
+        include: Vec::new(),
+        scan_cache: None,
     }
 }
 
@@ -622,6 +630,14 @@ fn all_flags_combined_does_not_panic() -> Result<()> {
         no_ignore_dot: true,
         no_ignore_vcs: true,
         treat_doc_strings_as_comments: true,
+        token_model: None,
+        hash_algo: tokmd_types::HashAlgo::Blake3,
+        symlink_policy: Default::default(),
+        line_count_mode: Default::default(),
+        code_comment_markers: Vec::new(),
+        ignore_file: None,
+        include: Vec::new(),
+        scan_cache: None,
     };
     let result = scan(&[dir.path().to_path_buf()], &opts);
     assert!(result.is_ok());
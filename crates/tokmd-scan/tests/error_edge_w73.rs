@@ -19,6 +19,14 @@ fn default_options() -> ScanOptions {
         no_ignore_dot: false,
         no_ignore_vcs: false,
         treat_doc_strings_as_comments: false,
+        token_model: None,
+        hash_algo: tokmd_types::HashAlgo::Blake3,
+        symlink_policy: Default::default(),
+        line_count_mode: Default::default(),
+        code_comment_markers: Vec::new(),
+        ignore_file: None,
+        include: Vec::new(),
+        scan_cache: None,
     }
 }
 
@@ -113,6 +121,14 @@ fn scan_with_all_no_ignore_flags_does_not_panic() {
         no_ignore_dot: true,
         no_ignore_vcs: true,
         treat_doc_strings_as_comments: true,
+        token_model: None,
+        hash_algo: tokmd_types::HashAlgo::Blake3,
+        symlink_policy: Default::default(),
+        line_count_mode: Default::default(),
+        code_comment_markers: Vec::new(),
+        ignore_file: None,
+        include: Vec::new(),
+        scan_cache: None,
     };
     let result = scan(&[dir.path().to_path_buf()], &opts);
     assert!(result.is_ok());
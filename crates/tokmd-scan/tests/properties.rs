@@ -100,6 +100,14 @@ fn arb_global_args() -> impl Strategy<Value = ScanOptions> {
                 no_ignore_dot,
                 no_ignore_vcs,
                 treat_doc_strings_as_comments,
+                token_model: None,
+                hash_algo: tokmd_types::HashAlgo::Blake3,
+                symlink_policy: Default::default(),
+                line_count_mode: Default::default(),
+                code_comment_markers: Vec::new(),
+                ignore_file: None,
+                include: Vec::new(),
+                scan_cache: None,
             },
         )
 }
@@ -331,6 +339,14 @@ proptest! {
             no_ignore_dot: true,
             no_ignore_vcs: true,
             treat_doc_strings_as_comments: true,
+            token_model: None,
+            hash_algo: tokmd_types::HashAlgo::Blake3,
+            symlink_policy: Default::default(),
+            line_count_mode: Default::default(),
+            code_comment_markers: Vec::new(),
+            ignore_file: None,
+            include: Vec::new(),
+            scan_cache: None,
         };
 
         // Build config
@@ -366,6 +382,14 @@ proptest! {
             no_ignore_dot: false,
             no_ignore_vcs: false,
             treat_doc_strings_as_comments: false,
+            token_model: None,
+            hash_algo: tokmd_types::HashAlgo::Blake3,
+            symlink_policy: Default::default(),
+            line_count_mode: Default::default(),
+            code_comment_markers: Vec::new(),
+            ignore_file: None,
+            include: Vec::new(),
+            scan_cache: None,
         };
 
         // With all flags false, config remains at defaults
@@ -390,6 +414,14 @@ proptest! {
             no_ignore_dot: false,
             no_ignore_vcs: false,
             treat_doc_strings_as_comments: false,
+            token_model: None,
+            hash_algo: tokmd_types::HashAlgo::Blake3,
+            symlink_policy: Default::default(),
+            line_count_mode: Default::default(),
+            code_comment_markers: Vec::new(),
+            ignore_file: None,
+            include: Vec::new(),
+            scan_cache: None,
         };
 
         let mut cfg = tokei::Config::default();
@@ -518,6 +550,14 @@ proptest! {
             no_ignore_dot: false,
             no_ignore_vcs: false,
             treat_doc_strings_as_comments: false,
+            token_model: None,
+            hash_algo: tokmd_types::HashAlgo::Blake3,
+            symlink_policy: Default::default(),
+            line_count_mode: Default::default(),
+            code_comment_markers: Vec::new(),
+            ignore_file: None,
+            include: Vec::new(),
+            scan_cache: None,
         };
 
         let ignores: Vec<&str> = args.excluded.iter().map(|s| s.as_str()).collect();
@@ -55,6 +55,14 @@ fn arb_scan_options() -> impl Strategy<Value = ScanOptions> {
                     no_ignore_dot,
                     no_ignore_vcs,
                     treat_doc_strings_as_comments,
+                    token_model: None,
+                    hash_algo: tokmd_types::HashAlgo::Blake3,
+                    symlink_policy: Default::default(),
+                    line_count_mode: Default::default(),
+                    code_comment_markers: Vec::new(),
+                    ignore_file: None,
+                    include: Vec::new(),
+                    scan_cache: None,
                 }
             },
         )
@@ -173,6 +181,14 @@ proptest! {
             no_ignore_dot,
             no_ignore_vcs: false,
             treat_doc_strings_as_comments: false,
+            token_model: None,
+            hash_algo: tokmd_types::HashAlgo::Blake3,
+            symlink_policy: Default::default(),
+            line_count_mode: Default::default(),
+            code_comment_markers: Vec::new(),
+            ignore_file: None,
+            include: Vec::new(),
+            scan_cache: None,
         };
         let cfg = build_config(&args);
         prop_assert_eq!(cfg.hidden.unwrap_or(false), hidden);
@@ -193,6 +209,14 @@ proptest! {
             no_ignore_dot: false,
             no_ignore_vcs: false,
             treat_doc_strings_as_comments: treat_doc,
+            token_model: None,
+            hash_algo: tokmd_types::HashAlgo::Blake3,
+            symlink_policy: Default::default(),
+            line_count_mode: Default::default(),
+            code_comment_markers: Vec::new(),
+            ignore_file: None,
+            include: Vec::new(),
+            scan_cache: None,
         };
         let cfg = build_config(&args);
         prop_assert_eq!(cfg.treat_doc_strings_as_comments.unwrap_or(false), treat_doc);
@@ -218,6 +242,14 @@ proptest! {
             no_ignore_dot: false,
             no_ignore_vcs: false,
             treat_doc_strings_as_comments: false,
+            token_model: None,
+            hash_algo: tokmd_types::HashAlgo::Blake3,
+            symlink_policy: Default::default(),
+            line_count_mode: Default::default(),
+            code_comment_markers: Vec::new(),
+            ignore_file: None,
+            include: Vec::new(),
+            scan_cache: None,
         };
         let ignores: Vec<&str> = args.excluded.iter().map(|s| s.as_str()).collect();
         prop_assert_eq!(ignores.len(), n);
@@ -35,6 +35,14 @@ fn default_scan_options() -> ScanOptions {
         no_ignore_dot: false,
         no_ignore_vcs: false,
         treat_doc_strings_as_comments: false,
+        token_model: None,
+        hash_algo: tokmd_types::HashAlgo::Blake3,
+        symlink_policy: Default::default(),
+        line_count_mode: Default::default(),
+        code_comment_markers: Vec::new(),
+        ignore_file: None,
+        include: Vec::new(),
+        scan_cache: None,
     }
 }
 
@@ -90,6 +98,14 @@ proptest! {
             no_ignore_dot: false,
             no_ignore_vcs: false,
             treat_doc_strings_as_comments: false,
+            token_model: None,
+            hash_algo: tokmd_types::HashAlgo::Blake3,
+            symlink_policy: Default::default(),
+            line_count_mode: Default::default(),
+            code_comment_markers: Vec::new(),
+            ignore_file: None,
+            include: Vec::new(),
+            scan_cache: None,
         };
         let paths = vec![test_path()];
         let result = scan(&paths, &args).unwrap();
@@ -146,6 +162,14 @@ proptest! {
             no_ignore_dot,
             no_ignore_vcs,
             treat_doc_strings_as_comments: treat_doc,
+            token_model: None,
+            hash_algo: tokmd_types::HashAlgo::Blake3,
+            symlink_policy: Default::default(),
+            line_count_mode: Default::default(),
+            code_comment_markers: Vec::new(),
+            ignore_file: None,
+            include: Vec::new(),
+            scan_cache: None,
         };
         let paths = vec![test_path()];
         // Should never panic regardless of flag combination
@@ -26,6 +26,14 @@ fn default_opts() -> ScanOptions {
         no_ignore_dot: false,
         no_ignore_vcs: false,
         treat_doc_strings_as_comments: false,
+        token_model: None,
+        hash_algo: tokmd_types::HashAlgo::Blake3,
+        symlink_policy: Default::default(),
+        line_count_mode: Default::default(),
+        code_comment_markers: Vec::new(),
+        ignore_file: None,
+        include: Vec::new(),
+        scan_cache: None,
     }
 }
 
@@ -356,6 +364,14 @@ fn scan_with_all_flags_combined() -> Result<()> {
         no_ignore_dot: true,
         no_ignore_vcs: true,
         treat_doc_strings_as_comments: true,
+        token_model: None,
+        hash_algo: tokmd_types::HashAlgo::Blake3,
+        symlink_policy: Default::default(),
+        line_count_mode: Default::default(),
+        code_comment_markers: Vec::new(),
+        ignore_file: None,
+        include: Vec::new(),
+        scan_cache: None,
     };
     let langs = scan(&[crate_src()], &opts)?;
     assert!(!langs.is_empty());
@@ -695,6 +711,14 @@ mod properties {
                         no_ignore_dot,
                         no_ignore_vcs,
                         treat_doc_strings_as_comments: doc_comments,
+                        token_model: None,
+                        hash_algo: tokmd_types::HashAlgo::Blake3,
+                        symlink_policy: Default::default(),
+                        line_count_mode: Default::default(),
+                        code_comment_markers: Vec::new(),
+                        ignore_file: None,
+                        include: Vec::new(),
+                        scan_cache: None,
                     }
                 },
             )
@@ -1,7 +1,10 @@
 //! Clap-free settings for tokmd command modes.
 
 use serde::{Deserialize, Serialize};
-use tokmd_types::{ChildIncludeMode, ChildrenMode, ExportFormat, RedactMode};
+use tokmd_types::{
+    ChildIncludeMode, ChildrenMode, ExportFilters, ExportFormat, ExportSortKey, RedactMode,
+    SortDirection,
+};
 
 /// Settings for language summary (`tokmd lang`).
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -60,6 +63,11 @@ pub struct ModuleSettings {
     /// Redaction mode for output.
     #[serde(default)]
     pub redact: Option<RedactMode>,
+
+    /// Relabel modules whose files sit below a single-child directory chain
+    /// with the deepest directory of that chain.
+    #[serde(default)]
+    pub collapse_single_child: bool,
 }
 
 fn default_module_roots() -> Vec<String> {
@@ -82,6 +90,7 @@ impl Default for ModuleSettings {
             module_depth: default_module_depth(),
             children: default_child_include_mode(),
             redact: None,
+            collapse_single_child: false,
         }
     }
 }
@@ -109,6 +118,18 @@ pub struct ExportSettings {
     #[serde(default)]
     pub min_code: usize,
 
+    /// Per-metric row bounds, applied together with `min_code`.
+    #[serde(default, flatten)]
+    pub filters: ExportFilters,
+
+    /// Sort column, applied before `max_rows` (`None` = code).
+    #[serde(default)]
+    pub sort_by: Option<ExportSortKey>,
+
+    /// Sort direction (`None` = the column's default direction).
+    #[serde(default)]
+    pub sort_dir: Option<SortDirection>,
+
     /// Stop after emitting N rows (0 = unlimited).
     #[serde(default)]
     pub max_rows: usize,
@@ -124,6 +145,10 @@ pub struct ExportSettings {
     /// Strip this prefix from paths.
     #[serde(default)]
     pub strip_prefix: Option<String>,
+
+    /// Attach summary stats to the meta record (requires `meta`).
+    #[serde(default)]
+    pub summary: bool,
 }
 
 fn default_redact_mode() -> RedactMode {
@@ -146,10 +171,14 @@ impl Default for ExportSettings {
             module_depth: default_module_depth(),
             children: default_child_include_mode(),
             min_code: 0,
+            filters: ExportFilters::default(),
+            sort_by: None,
+            sort_dir: None,
             max_rows: 0,
             redact: RedactMode::None,
             meta: true,
             strip_prefix: None,
+            summary: false,
         }
     }
 }
@@ -181,6 +210,11 @@ pub struct AnalyzeSettings {
     #[serde(default)]
     pub max_file_bytes: Option<u64>,
 
+    /// Exclude files larger than this many bytes from every metric; they are
+    /// listed as oversized files instead.
+    #[serde(default)]
+    pub oversized_file_bytes: Option<u64>,
+
     /// Limit commits scanned for git metrics.
     #[serde(default)]
     pub max_commits: Option<usize>,
@@ -189,6 +223,15 @@ pub struct AnalyzeSettings {
     #[serde(default)]
     pub max_commit_files: Option<usize>,
 
+    /// Keep at most this many bytes of each commit message for intent
+    /// classification.
+    #[serde(default)]
+    pub max_commit_message_bytes: Option<usize>,
+
+    /// Strip a leading byte-order mark before content analysis.
+    #[serde(default)]
+    pub strip_bom: bool,
+
     /// Import graph granularity.
     #[serde(default = "default_granularity")]
     pub granularity: String,
@@ -239,8 +282,11 @@ impl Default for AnalyzeSettings {
             max_files: None,
             max_bytes: None,
             max_file_bytes: None,
+            oversized_file_bytes: None,
             max_commits: None,
             max_commit_files: None,
+            max_commit_message_bytes: None,
+            strip_bom: false,
             granularity: default_granularity(),
             effort_model: None,
             effort_layer: None,
@@ -271,6 +317,49 @@ pub struct CockpitSettings {
     /// Optional baseline file path for trend comparison.
     #[serde(default)]
     pub baseline: Option<String>,
+
+    /// Extra glob patterns excluded from the determinism gate's source hash.
+    #[serde(default)]
+    pub determinism_exclude: Vec<String>,
+
+    /// Coverage report paths (globs, repo-relative) the coverage gates try
+    /// before their built-in locations.
+    #[serde(default)]
+    pub coverage_artifacts: Vec<String>,
+
+    /// Project line coverage ratio (0.0-1.0) at or above which the project
+    /// coverage gate passes. Defaults to 0.80.
+    #[serde(default)]
+    pub project_coverage_pass: Option<f64>,
+
+    /// Project line coverage ratio (0.0-1.0) at or above which the project
+    /// coverage gate warns rather than fails. Defaults to 0.50.
+    #[serde(default)]
+    pub project_coverage_warn: Option<f64>,
+
+    /// Globs of changed files the mutation and complexity gates consider even
+    /// where the built-in rules skip them (e.g. `tests/**`).
+    #[serde(default)]
+    pub rust_source_include: Vec<String>,
+
+    /// Globs of changed files the mutation and complexity gates ignore.
+    #[serde(default)]
+    pub rust_source_exclude: Vec<String>,
+
+    /// Commits an ancestor's mutation CI artifact may trail HEAD and still be
+    /// used (marked stale). 0 accepts only artifacts from HEAD itself.
+    #[serde(default)]
+    pub artifact_staleness_window: usize,
+
+    /// Lowest advisory severity the supply-chain gate reports: "low",
+    /// "medium", "high", or "critical". Unset reports every advisory.
+    #[serde(default)]
+    pub supply_chain_min_severity: Option<String>,
+
+    /// Path to a TOML allowlist of accepted supply-chain advisories.
+    /// Unexpired entries are acknowledged instead of failing the gate.
+    #[serde(default)]
+    pub advisory_allowlist: Option<String>,
 }
 
 fn default_cockpit_base() -> String {
@@ -292,6 +381,15 @@ impl Default for CockpitSettings {
             head: default_cockpit_head(),
             range_mode: default_cockpit_range_mode(),
             baseline: None,
+            determinism_exclude: Vec::new(),
+            coverage_artifacts: Vec::new(),
+            project_coverage_pass: None,
+            project_coverage_warn: None,
+            rust_source_include: Vec::new(),
+            rust_source_exclude: Vec::new(),
+            artifact_staleness_window: 0,
+            supply_chain_min_severity: None,
+            advisory_allowlist: None,
         }
     }
 }
@@ -33,6 +33,10 @@ pub struct TomlConfig {
     /// Named view profiles (e.g., [view.llm], [view.ci]).
     #[serde(default)]
     pub view: BTreeMap<String, ViewProfile>,
+
+    /// Named analysis presets (e.g., [preset.security]).
+    #[serde(default)]
+    pub preset: BTreeMap<String, PresetConfig>,
 }
 
 /// Scan settings shared by all commands.
@@ -94,7 +98,7 @@ pub struct ExportConfig {
     /// Redaction mode: "none", "paths", or "all".
     pub redact: Option<String>,
 
-    /// Output format: "jsonl", "csv", "json", "cyclonedx".
+    /// Output format: "jsonl", "csv", "json", "cyclonedx", "paths", "paths0".
     pub format: Option<String>,
 
     /// Children handling: "collapse" or "separate".
@@ -155,6 +159,55 @@ pub struct AnalyzeConfig {
 
     /// Monte Carlo seed for effort estimation.
     pub effort_mc_seed: Option<u64>,
+
+    /// Corporate fingerprint: parent domains that absorb their subdomains.
+    pub domain_parents: Option<Vec<String>>,
+
+    /// Corporate fingerprint: domains kept as their own row even when they
+    /// are personal-mail providers.
+    pub domain_allow: Option<Vec<String>>,
+
+    /// Corporate fingerprint: domains left out entirely.
+    pub domain_deny: Option<Vec<String>>,
+}
+
+/// A user-defined analysis preset, selected with `tokmd analyze --preset <name>`.
+///
+/// Unset fields fall back to the `base` preset; command-line flags still win.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PresetConfig {
+    /// Built-in preset supplying the defaults (default: the built-in of the
+    /// same name, else "receipt").
+    pub base: Option<String>,
+
+    /// Analysis sections to compute, replacing the base preset's set
+    /// (e.g., ["entropy", "license", "deps"]).
+    pub sections: Option<Vec<String>>,
+
+    /// Force git metrics on/off.
+    pub git: Option<bool>,
+
+    /// Context window size for utilization analysis.
+    pub window: Option<usize>,
+
+    /// Max files for asset/deps/content scans.
+    pub max_files: Option<usize>,
+
+    /// Max total bytes for content scans.
+    pub max_bytes: Option<u64>,
+
+    /// Max bytes per file for content scans.
+    pub max_file_bytes: Option<u64>,
+
+    /// Max commits for git metrics.
+    pub max_commits: Option<usize>,
+
+    /// Max files per commit for git metrics.
+    pub max_commit_files: Option<usize>,
+
+    /// Minimum documented ratio for the public API surface.
+    pub min_doc_ratio: Option<f64>,
 }
 
 /// Context command settings.
@@ -26,13 +26,16 @@ pub use commands::{
 };
 pub use config::{
     AnalyzeConfig, BadgeConfig, ContextConfig, ExportConfig, GateConfig, GateRule, ModuleConfig,
-    RatchetRuleConfig, ScanConfig, TomlConfig, ViewProfile,
+    PresetConfig, RatchetRuleConfig, ScanConfig, TomlConfig, ViewProfile,
 };
 pub use profile::{Profile, UserConfig};
 pub use scan::{ScanOptions, ScanSettings};
 
 // Re-export types from tokmd_types for convenience.
-pub use tokmd_types::{ChildIncludeMode, ChildrenMode, ConfigMode, ExportFormat, RedactMode};
+pub use tokmd_types::{
+    AvgMeasure, ChildIncludeMode, ChildrenMode, ConfigMode, ExportFilters, ExportFormat,
+    ExportSortKey, HashAlgo, LineCountMode, RedactMode, SortDirection, SymlinkPolicy,
+};
 
 /// Result type alias for TOML parsing errors.
 pub type TomlResult<T> = Result<T, toml::de::Error>;
@@ -1,7 +1,7 @@
 //! Shared scan settings independent of clap parsing.
 
 use serde::{Deserialize, Serialize};
-use tokmd_types::ConfigMode;
+use tokmd_types::{ConfigMode, HashAlgo, LineCountMode, SymlinkPolicy};
 
 /// Scan options shared by all commands that invoke the scanner.
 ///
@@ -30,7 +30,7 @@ pub struct ScanOptions {
     #[serde(default)]
     pub no_ignore_parent: bool,
 
-    /// Don't respect .ignore and .tokeignore files.
+    /// Don't respect .ignore, .tokeignore and .tokmdignore files.
     #[serde(default)]
     pub no_ignore_dot: bool,
 
@@ -41,6 +41,42 @@ pub struct ScanOptions {
     /// Treat doc strings as comments.
     #[serde(default)]
     pub treat_doc_strings_as_comments: bool,
+
+    /// Token model name recorded in receipts (`None` = `heuristic`).
+    #[serde(default)]
+    pub token_model: Option<String>,
+
+    /// Digest algorithm for receipt integrity and determinism hashes.
+    #[serde(default)]
+    pub hash_algo: HashAlgo,
+
+    /// How symlinked directories are treated while scanning.
+    #[serde(default)]
+    pub symlink_policy: SymlinkPolicy,
+
+    /// Whether `code` counts physical lines or logical statements.
+    #[serde(default)]
+    pub line_count_mode: LineCountMode,
+
+    /// Comment prefixes (e.g. `//go:generate`, `# noqa`) whose lines count
+    /// as code instead of comments.
+    #[serde(default)]
+    pub code_comment_markers: Vec<String>,
+
+    /// Extra ignore file name read from each scan root, in addition to
+    /// `.tokmdignore` (e.g. `.locignore`).
+    #[serde(default)]
+    pub ignore_file: Option<String>,
+
+    /// Glob patterns a file must match to be counted; empty counts every
+    /// file. Ignore rules and `excluded` still apply.
+    #[serde(default)]
+    pub include: Vec<String>,
+
+    /// Directory of the persistent per-file scan cache (e.g.
+    /// `.tokmd/cache/scan`); `None` counts every file afresh.
+    #[serde(default)]
+    pub scan_cache: Option<String>,
 }
 
 /// Global scan settings shared by all operations.
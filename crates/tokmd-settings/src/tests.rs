@@ -46,6 +46,14 @@ fn serde_roundtrip_scan_options() {
         no_ignore_dot: false,
         no_ignore_vcs: true,
         treat_doc_strings_as_comments: true,
+        token_model: None,
+        hash_algo: tokmd_types::HashAlgo::Blake3,
+        symlink_policy: Default::default(),
+        line_count_mode: Default::default(),
+        code_comment_markers: Vec::new(),
+        ignore_file: None,
+        include: Vec::new(),
+        scan_cache: None,
     };
     let json = serde_json::to_string(&opts).unwrap();
     let back: ScanOptions = serde_json::from_str(&json).unwrap();
@@ -121,6 +129,15 @@ fn serde_roundtrip_cockpit_settings_with_baseline() {
         head: "feature".into(),
         range_mode: "three-dot".into(),
         baseline: Some("baseline.json".into()),
+        determinism_exclude: vec![],
+        coverage_artifacts: vec![],
+        project_coverage_pass: None,
+        project_coverage_warn: None,
+        rust_source_include: vec![],
+        rust_source_exclude: vec![],
+        artifact_staleness_window: 0,
+        supply_chain_min_severity: None,
+        advisory_allowlist: None,
     };
     let json = serde_json::to_string(&s).unwrap();
     let back: CockpitSettings = serde_json::from_str(&json).unwrap();
@@ -157,6 +174,38 @@ top = 10
     assert_eq!(llm.top, Some(10));
 }
 
+#[test]
+fn toml_parse_preset_tables() -> Result<(), Box<dyn std::error::Error>> {
+    let toml_str = r#"
+[preset.security]
+sections = ["entropy", "license", "deps"]
+max_file_bytes = 65536
+
+[preset.quick]
+base = "health"
+git = false
+"#;
+    let config = TomlConfig::parse(toml_str)?;
+    let security = config.preset.get("security").ok_or("security preset")?;
+    assert_eq!(
+        security.sections.as_deref(),
+        Some(
+            &[
+                "entropy".to_string(),
+                "license".to_string(),
+                "deps".to_string()
+            ][..]
+        )
+    );
+    assert_eq!(security.max_file_bytes, Some(65536));
+    assert_eq!(security.base, None);
+    let quick = config.preset.get("quick").ok_or("quick preset")?;
+    assert_eq!(quick.base.as_deref(), Some("health"));
+    assert_eq!(quick.git, Some(false));
+    assert_eq!(quick.sections, None);
+    Ok(())
+}
+
 #[test]
 fn toml_from_file_roundtrip() {
     let toml_content = r#"
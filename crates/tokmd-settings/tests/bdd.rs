@@ -183,6 +183,14 @@ mod json_roundtrip {
             no_ignore_dot: true,
             no_ignore_vcs: true,
             treat_doc_strings_as_comments: true,
+            token_model: None,
+            hash_algo: tokmd_types::HashAlgo::Blake3,
+            symlink_policy: Default::default(),
+            line_count_mode: Default::default(),
+            code_comment_markers: Vec::new(),
+            ignore_file: None,
+            include: Vec::new(),
+            scan_cache: None,
         };
         // When: serialized to JSON and deserialized back
         let json = serde_json::to_string(&opts).unwrap();
@@ -224,6 +232,7 @@ mod json_roundtrip {
             module_depth: 4,
             children: ChildIncludeMode::ParentsOnly,
             redact: Some(RedactMode::Paths),
+            collapse_single_child: false,
         };
         // When: round-tripped
         let json = serde_json::to_string(&s).unwrap();
@@ -249,6 +258,10 @@ mod json_roundtrip {
             redact: RedactMode::Paths,
             meta: false,
             strip_prefix: Some("project/".into()),
+            filters: Default::default(),
+            sort_by: None,
+            sort_dir: None,
+            summary: false,
         };
         // When: round-tripped
         let json = serde_json::to_string(&s).unwrap();
@@ -299,6 +312,15 @@ mod json_roundtrip {
             head: "feature/new-api".into(),
             range_mode: "three-dot".into(),
             baseline: Some("baselines/v2.json".into()),
+            determinism_exclude: vec![],
+            coverage_artifacts: vec![],
+            project_coverage_pass: None,
+            project_coverage_warn: None,
+            rust_source_include: vec![],
+            rust_source_exclude: vec![],
+            artifact_staleness_window: 0,
+            supply_chain_min_severity: None,
+            advisory_allowlist: None,
         };
         // When: round-tripped
         let json = serde_json::to_string(&s).unwrap();
@@ -553,6 +575,26 @@ format = "json"
         assert_eq!(config.analyze.granularity, Some("file".into()));
     }
 
+    #[test]
+    fn analyze_section_with_domain_grouping() {
+        // Given: TOML with corporate fingerprint domain lists
+        let toml = r#"
+[analyze]
+domain_parents = ["acme.com"]
+domain_allow = ["proton.me"]
+domain_deny = ["gmail.com", "yahoo.com"]
+"#;
+        // When: parsed
+        let config = TomlConfig::parse(toml).unwrap();
+        // Then: the lists are kept in order
+        assert_eq!(config.analyze.domain_parents, Some(vec!["acme.com".into()]));
+        assert_eq!(config.analyze.domain_allow, Some(vec!["proton.me".into()]));
+        assert_eq!(
+            config.analyze.domain_deny,
+            Some(vec!["gmail.com".into(), "yahoo.com".into()])
+        );
+    }
+
     #[test]
     fn context_section() {
         // Given: TOML with context settings
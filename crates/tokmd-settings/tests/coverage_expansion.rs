@@ -245,6 +245,10 @@ fn export_settings_fully_populated_roundtrip() {
         redact: RedactMode::All,
         meta: false,
         strip_prefix: Some("project/".into()),
+        filters: Default::default(),
+        sort_by: None,
+        sort_dir: None,
+        summary: false,
     };
     let json = serde_json::to_string(&s).unwrap();
     let back: ExportSettings = serde_json::from_str(&json).unwrap();
@@ -383,6 +387,15 @@ fn cockpit_settings_custom_range_mode_roundtrip() {
         head: "feature/new-api".into(),
         range_mode: "three-dot".into(),
         baseline: Some("baselines/v2.json".into()),
+        determinism_exclude: vec![],
+        coverage_artifacts: vec![],
+        project_coverage_pass: None,
+        project_coverage_warn: None,
+        rust_source_include: vec![],
+        rust_source_exclude: vec![],
+        artifact_staleness_window: 0,
+        supply_chain_min_severity: None,
+        advisory_allowlist: None,
     };
     let json = serde_json::to_string(&s).unwrap();
     let back: CockpitSettings = serde_json::from_str(&json).unwrap();
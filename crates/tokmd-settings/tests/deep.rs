@@ -167,6 +167,14 @@ fn scan_settings_flatten_roundtrip() {
             no_ignore_dot: true,
             no_ignore_vcs: false,
             treat_doc_strings_as_comments: true,
+            token_model: None,
+            hash_algo: tokmd_types::HashAlgo::Blake3,
+            symlink_policy: Default::default(),
+            line_count_mode: Default::default(),
+            code_comment_markers: Vec::new(),
+            ignore_file: None,
+            include: Vec::new(),
+            scan_cache: None,
         },
     };
     let json = serde_json::to_string(&s).unwrap();
@@ -740,6 +748,7 @@ fn module_settings_custom_roots_roundtrip() {
         module_depth: 4,
         children: ChildIncludeMode::ParentsOnly,
         redact: Some(RedactMode::Paths),
+        collapse_single_child: false,
     };
     let json = serde_json::to_string(&ms).unwrap();
     let back: ModuleSettings = serde_json::from_str(&json).unwrap();
@@ -775,6 +784,15 @@ fn cockpit_settings_with_baseline() {
         head: "feature/new".into(),
         range_mode: "three-dot".into(),
         baseline: Some("baseline.json".into()),
+        determinism_exclude: vec![],
+        coverage_artifacts: vec![],
+        project_coverage_pass: None,
+        project_coverage_warn: None,
+        rust_source_include: vec![],
+        rust_source_exclude: vec![],
+        artifact_staleness_window: 0,
+        supply_chain_min_severity: None,
+        advisory_allowlist: None,
     };
     let json = serde_json::to_string(&c).unwrap();
     let back: CockpitSettings = serde_json::from_str(&json).unwrap();
@@ -27,6 +27,14 @@ fn scan_options_all_fields_set() {
         no_ignore_dot: true,
         no_ignore_vcs: true,
         treat_doc_strings_as_comments: true,
+        token_model: None,
+        hash_algo: tokmd_types::HashAlgo::Blake3,
+        symlink_policy: Default::default(),
+        line_count_mode: Default::default(),
+        code_comment_markers: Vec::new(),
+        ignore_file: None,
+        include: Vec::new(),
+        scan_cache: None,
     };
     assert_eq!(opts.excluded.len(), 2);
     assert!(opts.hidden);
@@ -102,6 +110,7 @@ fn module_settings_custom_roundtrip() {
         module_depth: 3,
         children: ChildIncludeMode::ParentsOnly,
         redact: Some(RedactMode::All),
+        collapse_single_child: false,
     };
     let json = serde_json::to_string(&ms).unwrap();
     let back: ModuleSettings = serde_json::from_str(&json).unwrap();
@@ -142,6 +151,10 @@ fn export_settings_custom_roundtrip() {
         redact: RedactMode::Paths,
         meta: false,
         strip_prefix: Some("/home/user/project".into()),
+        filters: Default::default(),
+        sort_by: None,
+        sort_dir: None,
+        summary: false,
     };
     let json = serde_json::to_string(&es).unwrap();
     let back: ExportSettings = serde_json::from_str(&json).unwrap();
@@ -213,6 +226,15 @@ fn cockpit_settings_custom_roundtrip() {
         head: "feature/xyz".to_string(),
         range_mode: "three-dot".to_string(),
         baseline: Some("baseline.json".to_string()),
+        determinism_exclude: vec![],
+        coverage_artifacts: vec![],
+        project_coverage_pass: None,
+        project_coverage_warn: None,
+        rust_source_include: vec![],
+        rust_source_exclude: vec![],
+        artifact_staleness_window: 0,
+        supply_chain_min_severity: None,
+        advisory_allowlist: None,
     };
     let json = serde_json::to_string(&c).unwrap();
     let back: CockpitSettings = serde_json::from_str(&json).unwrap();
@@ -280,6 +302,14 @@ fn scan_settings_flatten_roundtrip() {
             no_ignore_dot: true,
             no_ignore_vcs: false,
             treat_doc_strings_as_comments: true,
+            token_model: None,
+            hash_algo: tokmd_types::HashAlgo::Blake3,
+            symlink_policy: Default::default(),
+            line_count_mode: Default::default(),
+            code_comment_markers: Vec::new(),
+            ignore_file: None,
+            include: Vec::new(),
+            scan_cache: None,
         },
     };
     let json = serde_json::to_string(&s).unwrap();
@@ -576,6 +606,14 @@ proptest! {
             no_ignore_dot,
             no_ignore_vcs,
             treat_doc_strings_as_comments: treat_doc,
+            token_model: None,
+            hash_algo: tokmd_types::HashAlgo::Blake3,
+            symlink_policy: Default::default(),
+            line_count_mode: Default::default(),
+            code_comment_markers: Vec::new(),
+            ignore_file: None,
+            include: Vec::new(),
+            scan_cache: None,
         };
         let json = serde_json::to_string(&opts).unwrap();
         let back: ScanOptions = serde_json::from_str(&json).unwrap();
@@ -118,6 +118,14 @@ fn serde_roundtrip_scan_options_full() {
         no_ignore_dot: true,
         no_ignore_vcs: true,
         treat_doc_strings_as_comments: true,
+        token_model: None,
+        hash_algo: tokmd_types::HashAlgo::Blake3,
+        symlink_policy: Default::default(),
+        line_count_mode: Default::default(),
+        code_comment_markers: Vec::new(),
+        ignore_file: None,
+        include: Vec::new(),
+        scan_cache: None,
     };
     let json = serde_json::to_string(&o).unwrap();
     let back: ScanOptions = serde_json::from_str(&json).unwrap();
@@ -152,6 +160,7 @@ fn serde_roundtrip_module_settings() {
         module_depth: 4,
         children: ChildIncludeMode::Separate,
         redact: Some(RedactMode::All),
+        collapse_single_child: false,
     };
     let json = serde_json::to_string(&s).unwrap();
     let back: ModuleSettings = serde_json::from_str(&json).unwrap();
@@ -209,6 +218,15 @@ fn serde_roundtrip_cockpit_with_baseline() {
         head: "feature-branch".into(),
         range_mode: "three-dot".into(),
         baseline: Some("baseline.json".into()),
+        determinism_exclude: vec![],
+        coverage_artifacts: vec![],
+        project_coverage_pass: None,
+        project_coverage_warn: None,
+        rust_source_include: vec![],
+        rust_source_exclude: vec![],
+        artifact_staleness_window: 0,
+        supply_chain_min_severity: None,
+        advisory_allowlist: None,
     };
     let json = serde_json::to_string(&s).unwrap();
     let back: CockpitSettings = serde_json::from_str(&json).unwrap();
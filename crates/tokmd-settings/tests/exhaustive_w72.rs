@@ -30,6 +30,14 @@ fn scan_options_serde_roundtrip() {
         no_ignore_dot: true,
         no_ignore_vcs: true,
         treat_doc_strings_as_comments: true,
+        token_model: None,
+        hash_algo: tokmd_types::HashAlgo::Blake3,
+        symlink_policy: Default::default(),
+        line_count_mode: Default::default(),
+        code_comment_markers: Vec::new(),
+        ignore_file: None,
+        include: Vec::new(),
+        scan_cache: None,
     };
     let json = serde_json::to_string(&opts).unwrap();
     let back: ScanOptions = serde_json::from_str(&json).unwrap();
@@ -78,6 +78,14 @@ proptest! {
             no_ignore_dot,
             no_ignore_vcs,
             treat_doc_strings_as_comments: treat_doc,
+            token_model: None,
+            hash_algo: tokmd_types::HashAlgo::Blake3,
+            symlink_policy: Default::default(),
+            line_count_mode: Default::default(),
+            code_comment_markers: Vec::new(),
+            ignore_file: None,
+            include: Vec::new(),
+            scan_cache: None,
         };
         let json = serde_json::to_string(&opts).unwrap();
         let back: ScanOptions = serde_json::from_str(&json).unwrap();
@@ -161,6 +169,7 @@ proptest! {
             module_depth: depth,
             children,
             redact: redact_opt,
+            collapse_single_child: false,
         };
         let json = serde_json::to_string(&s).unwrap();
         let back: ModuleSettings = serde_json::from_str(&json).unwrap();
@@ -192,6 +201,10 @@ proptest! {
         let s = ExportSettings {
             format, module_roots: roots.clone(), module_depth: depth,
             children, min_code, max_rows, redact, meta, strip_prefix: strip.clone(),
+            filters: Default::default(),
+            sort_by: None,
+            sort_dir: None,
+            summary: false,
         };
         let json = serde_json::to_string(&s).unwrap();
         let back: ExportSettings = serde_json::from_str(&json).unwrap();
@@ -256,7 +269,7 @@ proptest! {
         baseline_val in arb_safe_string(),
     ) {
         let baseline = if has_baseline { Some(baseline_val.clone()) } else { Option::None };
-        let s = CockpitSettings { base: base.clone(), head: head.clone(), range_mode: range_mode.clone(), baseline: baseline.clone() };
+        let s = CockpitSettings { base: base.clone(), head: head.clone(), range_mode: range_mode.clone(), baseline: baseline.clone(), determinism_exclude: vec![], coverage_artifacts: vec![], project_coverage_pass: None, project_coverage_warn: None, rust_source_include: vec![], rust_source_exclude: vec![], artifact_staleness_window: 0, supply_chain_min_severity: None, advisory_allowlist: None };
         let json = serde_json::to_string(&s).unwrap();
         let back: CockpitSettings = serde_json::from_str(&json).unwrap();
         prop_assert_eq!(&back.base, &base);
@@ -35,6 +35,14 @@ proptest! {
             no_ignore_dot,
             no_ignore_vcs,
             treat_doc_strings_as_comments: treat_doc,
+            token_model: None,
+            hash_algo: tokmd_types::HashAlgo::Blake3,
+            symlink_policy: Default::default(),
+            line_count_mode: Default::default(),
+            code_comment_markers: Vec::new(),
+            ignore_file: None,
+            include: Vec::new(),
+            scan_cache: None,
         };
         let json = serde_json::to_string(&opts).unwrap();
         let parsed: ScanOptions = serde_json::from_str(&json).unwrap();
@@ -195,6 +203,7 @@ proptest! {
             module_depth: depth,
             children: ChildIncludeMode::Separate,
             redact: None,
+            collapse_single_child: false,
         };
         let json = serde_json::to_string(&s).unwrap();
         let parsed: ModuleSettings = serde_json::from_str(&json).unwrap();
@@ -244,6 +253,10 @@ proptest! {
             redact: RedactMode::None,
             meta,
             strip_prefix: None,
+            filters: Default::default(),
+            sort_by: None,
+            sort_dir: None,
+            summary: false,
         };
         let json = serde_json::to_string(&s).unwrap();
         let parsed: ExportSettings = serde_json::from_str(&json).unwrap();
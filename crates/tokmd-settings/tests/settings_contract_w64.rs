@@ -47,6 +47,14 @@ fn scan_options_all_flags_true() {
         no_ignore_dot: true,
         no_ignore_vcs: true,
         treat_doc_strings_as_comments: true,
+        token_model: None,
+        hash_algo: tokmd_types::HashAlgo::Blake3,
+        symlink_policy: Default::default(),
+        line_count_mode: Default::default(),
+        code_comment_markers: Vec::new(),
+        ignore_file: None,
+        include: Vec::new(),
+        scan_cache: None,
     };
     assert!(opts.hidden);
     assert!(opts.no_ignore);
@@ -232,6 +240,15 @@ fn cockpit_settings_three_dot() {
         head: "feature-branch".to_string(),
         range_mode: "three-dot".to_string(),
         baseline: Some("baseline.json".to_string()),
+        determinism_exclude: vec![],
+        coverage_artifacts: vec![],
+        project_coverage_pass: None,
+        project_coverage_warn: None,
+        rust_source_include: vec![],
+        rust_source_exclude: vec![],
+        artifact_staleness_window: 0,
+        supply_chain_min_severity: None,
+        advisory_allowlist: None,
     };
     assert_eq!(s.range_mode, "three-dot");
     assert!(s.baseline.is_some());
@@ -273,6 +290,14 @@ fn serde_roundtrip_scan_options() {
         no_ignore_dot: false,
         no_ignore_vcs: true,
         treat_doc_strings_as_comments: true,
+        token_model: None,
+        hash_algo: tokmd_types::HashAlgo::Blake3,
+        symlink_policy: Default::default(),
+        line_count_mode: Default::default(),
+        code_comment_markers: Vec::new(),
+        ignore_file: None,
+        include: Vec::new(),
+        scan_cache: None,
     };
     let json = serde_json::to_string(&opts).unwrap();
     let back: ScanOptions = serde_json::from_str(&json).unwrap();
@@ -325,6 +350,7 @@ fn serde_roundtrip_module_settings() {
         module_depth: 4,
         children: ChildIncludeMode::ParentsOnly,
         redact: Some(RedactMode::Paths),
+        collapse_single_child: false,
     };
     let json = serde_json::to_string(&s).unwrap();
     let back: ModuleSettings = serde_json::from_str(&json).unwrap();
@@ -381,6 +407,15 @@ fn serde_roundtrip_cockpit_settings() {
         head: "feature".to_string(),
         range_mode: "three-dot".to_string(),
         baseline: Some("bl.json".to_string()),
+        determinism_exclude: vec![],
+        coverage_artifacts: vec![],
+        project_coverage_pass: None,
+        project_coverage_warn: None,
+        rust_source_include: vec![],
+        rust_source_exclude: vec![],
+        artifact_staleness_window: 0,
+        supply_chain_min_severity: None,
+        advisory_allowlist: None,
     };
     let json = serde_json::to_string(&s).unwrap();
     let back: CockpitSettings = serde_json::from_str(&json).unwrap();
@@ -37,6 +37,14 @@ fn scan_options_all_flags_true_roundtrip() {
         no_ignore_dot: true,
         no_ignore_vcs: true,
         treat_doc_strings_as_comments: true,
+        token_model: None,
+        hash_algo: tokmd_types::HashAlgo::Blake3,
+        symlink_policy: Default::default(),
+        line_count_mode: Default::default(),
+        code_comment_markers: Vec::new(),
+        ignore_file: None,
+        include: Vec::new(),
+        scan_cache: None,
     };
     let json = serde_json::to_string(&opts).unwrap();
     let back: ScanOptions = serde_json::from_str(&json).unwrap();
@@ -152,6 +160,7 @@ fn module_settings_custom_roundtrip() {
         module_depth: 5,
         children: ChildIncludeMode::ParentsOnly,
         redact: Some(RedactMode::Paths),
+        collapse_single_child: false,
     };
     let json = serde_json::to_string(&s).unwrap();
     let back: ModuleSettings = serde_json::from_str(&json).unwrap();
@@ -235,6 +244,10 @@ fn export_settings_full_roundtrip() {
         redact: RedactMode::All,
         meta: false,
         strip_prefix: Some("/home/user/project".into()),
+        filters: Default::default(),
+        sort_by: None,
+        sort_dir: None,
+        summary: false,
     };
     let json = serde_json::to_string(&s).unwrap();
     let back: ExportSettings = serde_json::from_str(&json).unwrap();
@@ -312,6 +325,15 @@ fn cockpit_settings_custom_roundtrip() {
         head: "feature-branch".into(),
         range_mode: "three-dot".into(),
         baseline: Some("path/to/baseline.json".into()),
+        determinism_exclude: vec![],
+        coverage_artifacts: vec![],
+        project_coverage_pass: None,
+        project_coverage_warn: None,
+        rust_source_include: vec![],
+        rust_source_exclude: vec![],
+        artifact_staleness_window: 0,
+        supply_chain_min_severity: None,
+        advisory_allowlist: None,
     };
     let json = serde_json::to_string(&s).unwrap();
     let back: CockpitSettings = serde_json::from_str(&json).unwrap();
@@ -127,6 +127,7 @@ fn roundtrip_module_settings_json() {
         module_depth: 4,
         children: ChildIncludeMode::ParentsOnly,
         redact: Some(RedactMode::Paths),
+        collapse_single_child: false,
     };
     let json = serde_json::to_string(&s).unwrap();
     let back: ModuleSettings = serde_json::from_str(&json).unwrap();
@@ -147,6 +148,10 @@ fn roundtrip_export_settings_all_fields() {
         redact: RedactMode::Paths,
         meta: false,
         strip_prefix: Some("/repo/".into()),
+        filters: Default::default(),
+        sort_by: None,
+        sort_dir: None,
+        summary: false,
     };
     let json = serde_json::to_string(&s).unwrap();
     let back: ExportSettings = serde_json::from_str(&json).unwrap();
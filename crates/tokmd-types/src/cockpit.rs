@@ -4,15 +4,17 @@
 //! They are extracted here (Tier 0) so that lower-tier crates like `tokmd-cockpit`
 //! and `tokmd-core` can reference them without depending on the CLI binary.
 
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
 
 mod evidence;
 
 pub use evidence::{
-    BreakingChange, CliSubGate, CommitMatch, ComplexityGate, ContractDiffGate, DeterminismGate,
-    DiffCoverageGate, Evidence, EvidenceSource, GateMeta, GateStatus, HighComplexityFile,
-    MutationGate, MutationSurvivor, SchemaSubGate, ScopeCoverage, SemverSubGate, SupplyChainGate,
-    UncoveredHunk, Vulnerability,
+    AcknowledgedVulnerability, BranchCoverage, BreakingChange, CliSubGate, CommitMatch,
+    ComplexityGate, ContractDiffGate, DeterminismGate, DiffCoverageGate, Evidence, EvidenceSource,
+    GateMeta, GateStatus, HighComplexityFile, MutationGate, MutationSurvivor, ProjectCoverageGate,
+    SchemaSubGate, ScopeCoverage, SemverSubGate, SupplyChainGate, UncoveredHunk, Vulnerability,
 };
 
 /// Cockpit receipt schema version.
@@ -126,6 +128,16 @@ pub struct Risk {
     pub level: RiskLevel,
     /// Risk score (0-100).
     pub score: u32,
+    /// Distinct CODEOWNERS owners across the changed files; absent when the
+    /// repository has no CODEOWNERS file.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owners_touched: Option<usize>,
+    /// Changed files that no CODEOWNERS rule assigns an owner.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub unowned_files: Vec<String>,
+    /// CODEOWNERS owners of each touched hotspot, keyed by path.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub hotspot_owners: BTreeMap<String, Vec<String>>,
 }
 
 /// Risk level classification.
@@ -160,7 +172,7 @@ pub struct Contracts {
 }
 
 /// Review plan item.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ReviewItem {
     pub path: String,
     pub reason: String,
@@ -171,6 +183,13 @@ pub struct ReviewItem {
     /// Lines changed in this file.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub lines_changed: Option<usize>,
+    /// Estimated minutes to review this file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub review_minutes: Option<u32>,
+    /// CODEOWNERS owners of this file; empty when no rule assigns one
+    /// (unowned), absent when the repository has no CODEOWNERS file.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owners: Option<Vec<String>>,
 }
 
 // =============================================================================
@@ -283,6 +302,9 @@ mod tests {
                 bus_factor_warnings: vec![],
                 level: RiskLevel::Low,
                 score: 10,
+                owners_touched: None,
+                unowned_files: vec![],
+                hotspot_owners: Default::default(),
             },
             contracts: Contracts {
                 api_changed: false,
@@ -292,6 +314,7 @@ mod tests {
             },
             evidence: Evidence {
                 overall_status: GateStatus::Pass,
+                project_coverage: None,
                 mutation: MutationGate {
                     meta: GateMeta {
                         status: GateStatus::Pass,
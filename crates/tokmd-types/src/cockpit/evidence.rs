@@ -28,6 +28,9 @@ pub struct Evidence {
     /// Complexity gate (optional).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub complexity: Option<ComplexityGate>,
+    /// Whole-project coverage gate (optional).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_coverage: Option<ProjectCoverageGate>,
 }
 
 /// Status of a gate check.
@@ -119,15 +122,59 @@ pub struct DiffCoverageGate {
     pub lines_added: usize,
     pub lines_covered: usize,
     pub coverage_pct: f64,
+    /// Repo-relative path of the coverage report the gate read.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub artifact: Option<String>,
+    /// Branch coverage of added lines; absent when the report has no branch
+    /// data for them.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub branch_coverage: Option<BranchCoverage>,
     pub uncovered_hunks: Vec<UncoveredHunk>,
 }
 
+/// Branch coverage of the lines a diff added, from LCOV `BRDA` records.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BranchCoverage {
+    /// Status from the branch band, independent of the line-coverage status.
+    pub status: GateStatus,
+    pub branches_added: usize,
+    pub branches_covered: usize,
+    pub coverage_pct: f64,
+}
+
+/// Whole-project coverage gate results, read from the same coverage report as
+/// the diff-coverage gate but counting every file in it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectCoverageGate {
+    #[serde(flatten)]
+    pub meta: GateMeta,
+    /// Source files in the coverage report.
+    pub files: usize,
+    pub lines_found: usize,
+    pub lines_hit: usize,
+    pub coverage_pct: f64,
+    /// Branches in the report; absent when it has no branch data.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub branches_found: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub branches_hit: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub branch_coverage_pct: Option<f64>,
+    /// Repo-relative path of the coverage report the gate read.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub artifact: Option<String>,
+}
+
 /// Uncovered hunk in diff coverage.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UncoveredHunk {
     pub file: String,
     pub start_line: usize,
     pub end_line: usize,
+    /// Source lines of the hunk from the working tree, capped in length.
+    /// Only present when snippets were requested and paths are not redacted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snippet: Option<Vec<String>>,
 }
 
 /// Contract diff gate results (compound gate).
@@ -184,10 +231,26 @@ pub struct SupplyChainGate {
     pub meta: GateMeta,
     pub vulnerabilities: Vec<Vulnerability>,
     pub denied: Vec<String>,
+    /// Allowlisted advisories; reported, but never fail the gate.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub acknowledged: Vec<AcknowledgedVulnerability>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub advisory_db_version: Option<String>,
 }
 
+/// Vulnerability accepted through an unexpired advisory allowlist entry.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AcknowledgedVulnerability {
+    #[serde(flatten)]
+    pub vulnerability: Vulnerability,
+    /// Why the advisory was accepted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    /// Last day (`YYYY-MM-DD`) the acceptance holds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires: Option<String>,
+}
+
 /// Vulnerability from cargo-audit.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Vulnerability {
@@ -195,6 +258,12 @@ pub struct Vulnerability {
     pub package: String,
     pub severity: String,
     pub title: String,
+    /// Advisory page, e.g. `https://rustsec.org/advisories/RUSTSEC-2024-0001`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// Version requirements that fix the advisory, e.g. `>=1.2.3`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub patched: Vec<String>,
 }
 
 /// Determinism gate results.
@@ -208,6 +277,9 @@ pub struct DeterminismGate {
     pub actual_hash: Option<String>,
     pub algo: String,
     pub differences: Vec<String>,
+    /// Glob patterns excluded from the source hash walk.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub excludes: Vec<String>,
 }
 
 /// Complexity gate results.
@@ -493,10 +565,13 @@ mod tests {
             lines_added: 100,
             lines_covered: 70,
             coverage_pct: 0.7,
+            artifact: None,
+            branch_coverage: None,
             uncovered_hunks: vec![UncoveredHunk {
                 file: "src/x.rs".into(),
                 start_line: 10,
                 end_line: 20,
+                snippet: None,
             }],
         };
         let value = serde_json::to_value(&g).unwrap();
@@ -516,6 +591,7 @@ mod tests {
             file: "f".into(),
             start_line: 5,
             end_line: 7,
+            snippet: None,
         };
         let json = serde_json::to_string(&h).unwrap();
         let back: UncoveredHunk = serde_json::from_str(&json).unwrap();
@@ -599,8 +675,11 @@ mod tests {
                 package: "libfoo".into(),
                 severity: "high".into(),
                 title: "use after free".into(),
+                url: None,
+                patched: vec![],
             }],
             denied: vec!["GPL-3.0".into()],
+            acknowledged: vec![],
             advisory_db_version: Some("2025-05-01".into()),
         };
         let json = serde_json::to_string(&g).unwrap();
@@ -619,10 +698,34 @@ mod tests {
             meta: sample_meta(),
             vulnerabilities: vec![],
             denied: vec![],
+            acknowledged: vec![],
             advisory_db_version: None,
         };
         let value = serde_json::to_value(&g).unwrap();
         assert!(value.get("advisory_db_version").is_none());
+        assert!(value.get("acknowledged").is_none());
+    }
+
+    #[test]
+    fn acknowledged_vulnerability_flattens_the_advisory() {
+        let ack = AcknowledgedVulnerability {
+            vulnerability: Vulnerability {
+                id: "RUSTSEC-2024-0001".into(),
+                package: "libfoo".into(),
+                severity: "high".into(),
+                title: "no fix yet".into(),
+                url: None,
+                patched: vec![],
+            },
+            reason: Some("not reachable".into()),
+            expires: Some("2027-01-31".into()),
+        };
+        let value = serde_json::to_value(&ack).unwrap();
+        assert_eq!(value["id"], "RUSTSEC-2024-0001");
+        assert_eq!(value["reason"], "not reachable");
+        assert_eq!(value["expires"], "2027-01-31");
+        let back: AcknowledgedVulnerability = serde_json::from_value(value).unwrap();
+        assert_eq!(back.vulnerability.package, "libfoo");
     }
 
     // ── DeterminismGate ─────────────────────────────────────────────
@@ -634,6 +737,7 @@ mod tests {
             actual_hash: Some("bbbb".into()),
             algo: "sha256".into(),
             differences: vec!["file1.txt: bytes differ".into()],
+            excludes: vec![],
         };
         let json = serde_json::to_string(&g).unwrap();
         let value: serde_json::Value = serde_json::from_str(&json).unwrap();
@@ -653,6 +757,7 @@ mod tests {
             actual_hash: None,
             algo: "blake3".into(),
             differences: vec![],
+            excludes: vec![],
         };
         let value = serde_json::to_value(&g).unwrap();
         assert!(value.get("expected_hash").is_none());
@@ -720,6 +825,7 @@ mod tests {
     fn evidence_only_mutation_required() {
         let e = Evidence {
             overall_status: GateStatus::Pass,
+            project_coverage: None,
             mutation: sample_mutation_gate(),
             diff_coverage: None,
             contracts: None,
@@ -746,12 +852,15 @@ mod tests {
     fn evidence_with_all_gates_serde_roundtrip() {
         let e = Evidence {
             overall_status: GateStatus::Fail,
+            project_coverage: None,
             mutation: sample_mutation_gate(),
             diff_coverage: Some(DiffCoverageGate {
                 meta: sample_meta(),
                 lines_added: 1,
                 lines_covered: 1,
                 coverage_pct: 1.0,
+                artifact: None,
+                branch_coverage: None,
                 uncovered_hunks: vec![],
             }),
             contracts: Some(ContractDiffGate {
@@ -765,6 +874,7 @@ mod tests {
                 meta: sample_meta(),
                 vulnerabilities: vec![],
                 denied: vec![],
+                acknowledged: vec![],
                 advisory_db_version: None,
             }),
             determinism: Some(DeterminismGate {
@@ -773,6 +883,7 @@ mod tests {
                 actual_hash: None,
                 algo: "sha256".into(),
                 differences: vec![],
+                excludes: vec![],
             }),
             complexity: Some(ComplexityGate {
                 meta: sample_meta(),
@@ -87,6 +87,45 @@ pub struct DiffReceipt {
     pub to_source: String,
     pub diff_rows: Vec<DiffRow>,
     pub totals: DiffTotals,
+    /// Public API changes, when both sides carry an analysis receipt with an
+    /// API surface report.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub api_surface: Option<ApiSurfaceDiff>,
+}
+
+/// Public API items added or removed between two API surface reports.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ApiSurfaceDiff {
+    /// Public item count in the base report.
+    pub base_public_items: usize,
+    /// Public item count in the head report.
+    pub head_public_items: usize,
+    /// Public items present in head but not base, by module.
+    pub added: Vec<ModuleApiChange>,
+    /// Public items present in base but not head, by module (breaking).
+    pub removed: Vec<ModuleApiChange>,
+    /// Whether either report's symbol inventory was cut off at its cap. The
+    /// diff is then incomplete: `removed` is left empty when the head was
+    /// capped and `added` when the base was, rather than listing items the
+    /// cap merely hid.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub truncated: bool,
+}
+
+impl ApiSurfaceDiff {
+    /// Whether any public item was removed.
+    pub fn has_breaking_changes(&self) -> bool {
+        !self.removed.is_empty()
+    }
+}
+
+/// Public items added to or removed from one module.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ModuleApiChange {
+    /// Module path.
+    pub module: String,
+    /// Item identifiers (`"<kind> <name>"`), sorted.
+    pub items: Vec<String>,
 }
 
 #[cfg(test)]
@@ -243,6 +282,7 @@ mod tests {
             to_source: "new.json".into(),
             diff_rows: vec![sample_diff_row()],
             totals: sample_diff_totals(),
+            api_surface: None,
         };
 
         let json = serde_json::to_string(&receipt).expect("serialize");
@@ -292,6 +332,7 @@ mod tests {
             to_source: "b".into(),
             diff_rows: rows.clone(),
             totals: DiffTotals::default(),
+            api_surface: None,
         };
         let json = serde_json::to_string(&receipt).expect("serialize");
         let back: DiffReceipt = serde_json::from_str(&json).expect("deserialize");
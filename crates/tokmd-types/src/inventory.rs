@@ -8,6 +8,8 @@ use std::path::PathBuf;
 
 use serde::{Deserialize, Serialize};
 
+use crate::Warning;
+
 /// A small totals struct shared by summary outputs.
 ///
 /// # Examples
@@ -22,6 +24,9 @@ use serde::{Deserialize, Serialize};
 ///     bytes: 40000,
 ///     tokens: 10000,
 ///     avg_lines: 150,
+///     median_lines: None,
+///     p50_lines: None,
+///     p90_lines: None,
 /// };
 /// assert_eq!(totals.code, 1000);
 /// ```
@@ -33,6 +38,15 @@ pub struct Totals {
     pub bytes: usize,
     pub tokens: usize,
     pub avg_lines: usize,
+    /// Median lines per file; present only when the median measure is requested.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub median_lines: Option<usize>,
+    /// 50th percentile (nearest rank) of lines per file; present only when percentiles are requested.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub p50_lines: Option<usize>,
+    /// 90th percentile (nearest rank) of lines per file; present only when percentiles are requested.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub p90_lines: Option<usize>,
 }
 
 /// A single language row in the lang summary.
@@ -50,6 +64,10 @@ pub struct Totals {
 ///     bytes: 180_000,
 ///     tokens: 45_000,
 ///     avg_lines: 154,
+///     median_lines: None,
+///     p50_lines: None,
+///     p90_lines: None,
+///     file_rows: vec![],
 /// };
 /// assert_eq!(row.lang, "Rust");
 /// assert_eq!(row.files, 42);
@@ -63,6 +81,18 @@ pub struct LangRow {
     pub bytes: usize,
     pub tokens: usize,
     pub avg_lines: usize,
+    /// Median lines per file; present only when the median measure is requested.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub median_lines: Option<usize>,
+    /// 50th percentile (nearest rank) of lines per file; present only when percentiles are requested.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub p50_lines: Option<usize>,
+    /// 90th percentile (nearest rank) of lines per file; present only when percentiles are requested.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub p90_lines: Option<usize>,
+    /// Contributing file rows; present only when the files detail is requested.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub file_rows: Vec<FileRow>,
 }
 
 /// A report detailing language statistics.
@@ -82,6 +112,10 @@ pub struct LangRow {
 ///             bytes: 180_000,
 ///             tokens: 45_000,
 ///             avg_lines: 154,
+///             median_lines: None,
+///             p50_lines: None,
+///             p90_lines: None,
+///             file_rows: vec![],
 ///         }
 ///     ],
 ///     total: Totals {
@@ -91,6 +125,9 @@ pub struct LangRow {
 ///         bytes: 180_000,
 ///         tokens: 45_000,
 ///         avg_lines: 154,
+///         median_lines: None,
+///         p50_lines: None,
+///         p90_lines: None,
 ///     },
 ///     with_files: false,
 ///     children: ChildrenMode::Collapse,
@@ -123,6 +160,11 @@ pub struct LangReport {
 ///     bytes: 32_000,
 ///     tokens: 8_000,
 ///     avg_lines: 366,
+///     median_lines: None,
+///     p50_lines: None,
+///     p90_lines: None,
+///     file_rows: vec![],
+///     children: vec![],
 /// };
 /// assert_eq!(row.module, "crates/tokmd-types");
 /// assert_eq!(row.code, 800);
@@ -136,6 +178,34 @@ pub struct ModuleRow {
     pub bytes: usize,
     pub tokens: usize,
     pub avg_lines: usize,
+    /// Median lines per file; present only when the median measure is requested.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub median_lines: Option<usize>,
+    /// 50th percentile (nearest rank) of lines per file; present only when percentiles are requested.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub p50_lines: Option<usize>,
+    /// 90th percentile (nearest rank) of lines per file; present only when percentiles are requested.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub p90_lines: Option<usize>,
+    /// Contributing file rows; present only when the files detail is requested.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub file_rows: Vec<FileRow>,
+    /// Embedded languages counted in this module, most code first; present
+    /// only with [`ChildIncludeMode::Nested`].
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub children: Vec<ModuleChildRow>,
+}
+
+/// The part of a module row contributed by one embedded language.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ModuleChildRow {
+    pub lang: String,
+    pub code: usize,
+    pub lines: usize,
+    /// Files in the module embedding this language.
+    pub files: usize,
+    pub bytes: usize,
+    pub tokens: usize,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -155,6 +225,28 @@ pub enum FileKind {
     Child,
 }
 
+/// What a file is for, judged from its path: hand-written source, tests,
+/// generated output, or vendored third-party code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FileRole {
+    Source,
+    Test,
+    Generated,
+    Vendored,
+}
+
+impl FileRole {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            FileRole::Source => "source",
+            FileRole::Test => "test",
+            FileRole::Generated => "generated",
+            FileRole::Vendored => "vendored",
+        }
+    }
+}
+
 /// A single file row in the export inventory.
 ///
 /// # Examples
@@ -173,6 +265,9 @@ pub enum FileKind {
 ///     lines: 170,
 ///     bytes: 4_800,
 ///     tokens: 1_200,
+///     last_modified_ms: None,
+///     age_days: None,
+///     role: None,
 /// };
 /// assert_eq!(row.path, "src/main.rs");
 /// assert_eq!(row.kind, FileKind::Parent);
@@ -190,6 +285,17 @@ pub struct FileRow {
     pub lines: usize,
     pub bytes: usize,
     pub tokens: usize,
+    /// Unix milliseconds of the newest commit touching the file. Set only when
+    /// git age was requested and git has seen the path.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_modified_ms: Option<u64>,
+    /// Whole days between that commit and the export.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub age_days: Option<u64>,
+    /// Source/test/generated/vendored classification. Set only when role
+    /// classification was requested.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub role: Option<FileRole>,
 }
 
 /// Detailed export data containing individual file statistics.
@@ -212,6 +318,9 @@ pub struct FileRow {
 ///             lines: 170,
 ///             bytes: 4_800,
 ///             tokens: 1_200,
+///             last_modified_ms: None,
+///             age_days: None,
+///             role: None,
 ///         }
 ///     ],
 ///     module_roots: vec![],
@@ -228,6 +337,34 @@ pub struct ExportData {
     pub children: ChildIncludeMode,
 }
 
+/// Quick aggregate stats over the exported rows.
+///
+/// Per-language rows and totals come from the language report builder with
+/// the median and p50/p90 size figures filled in, so they read the same as
+/// `tokmd lang --avg both --percentiles` over the same files.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ExportSummary {
+    pub by_lang: Vec<LangRow>,
+    /// Per file extension, most tokens first. Finer-grained than `by_lang`:
+    /// `.ts` and `.tsx` are both TypeScript but get separate rows here.
+    #[serde(default)]
+    pub by_ext: Vec<ExtensionRow>,
+    pub total: Totals,
+}
+
+/// File, byte, and token counts for one file extension.
+///
+/// `ext` is lowercased and has no leading dot (`rs`, `toml`); files without
+/// an extension are grouped under `(none)`. Only parent rows count, since
+/// embedded-language child rows carry no bytes or tokens of their own.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ExtensionRow {
+    pub ext: String,
+    pub files: usize,
+    pub bytes: usize,
+    pub tokens: usize,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RunReceipt {
     pub schema_version: u32,
@@ -296,6 +433,28 @@ pub struct ScanArgs {
     pub no_ignore_dot: bool,
     pub no_ignore_vcs: bool,
     pub treat_doc_strings_as_comments: bool,
+    /// Tokenizer model the receipt's token counts assume.
+    #[serde(default = "crate::default_token_model")]
+    pub token_model: String,
+    /// Digest algorithm for integrity hashes; omitted when blake3.
+    #[serde(default, skip_serializing_if = "HashAlgo::is_default")]
+    pub hash_algo: HashAlgo,
+    /// How symlinked directories were treated; omitted when ignored.
+    #[serde(default, skip_serializing_if = "SymlinkPolicy::is_default")]
+    pub symlink_policy: SymlinkPolicy,
+    /// How the `code` metric counts lines; omitted when physical.
+    #[serde(default, skip_serializing_if = "LineCountMode::is_default")]
+    pub line_count_mode: LineCountMode,
+    /// Comment prefixes whose lines were counted as code; omitted when none.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub code_comment_markers: Vec<String>,
+    /// Extra ignore file name read alongside `.tokmdignore`; omitted when none.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ignore_file: Option<String>,
+    /// Globs restricting the scan to matching files; hashed when paths are
+    /// redacted and omitted when none.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub include: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -313,9 +472,13 @@ pub struct LangReceipt {
     pub tool: ToolInfo,
     pub mode: String, // "lang"
     pub status: ScanStatus,
-    pub warnings: Vec<String>,
+    pub warnings: Vec<Warning>,
     pub scan: ScanArgs,
     pub args: LangArgsMeta,
+    /// Hash over the receipt's serialized rows; absent in receipts written
+    /// before integrity was recorded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integrity: Option<IntegrityReport>,
     #[serde(flatten)]
     pub report: LangReport,
 }
@@ -327,6 +490,8 @@ pub struct ModuleArgsMeta {
     pub module_depth: usize,
     pub children: ChildIncludeMode,
     pub top: usize,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub collapse_single_child: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -336,9 +501,13 @@ pub struct ModuleReceipt {
     pub tool: ToolInfo,
     pub mode: String, // "module"
     pub status: ScanStatus,
-    pub warnings: Vec<String>,
+    pub warnings: Vec<Warning>,
     pub scan: ScanArgs,
     pub args: ModuleArgsMeta,
+    /// Hash over the receipt's serialized rows; absent in receipts written
+    /// before integrity was recorded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integrity: Option<IntegrityReport>,
     #[serde(flatten)]
     pub report: ModuleReport,
 }
@@ -350,6 +519,12 @@ pub struct ExportArgsMeta {
     pub module_depth: usize,
     pub children: ChildIncludeMode,
     pub min_code: usize,
+    #[serde(flatten)]
+    pub filters: ExportFilters,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_by: Option<ExportSortKey>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_dir: Option<SortDirection>,
     pub max_rows: usize,
     pub redact: RedactMode,
     pub strip_prefix: Option<String>,
@@ -365,13 +540,108 @@ pub struct ExportReceipt {
     pub tool: ToolInfo,
     pub mode: String, // "export"
     pub status: ScanStatus,
-    pub warnings: Vec<String>,
+    pub warnings: Vec<Warning>,
     pub scan: ScanArgs,
     pub args: ExportArgsMeta,
+    /// Hash over the receipt's serialized rows; absent in receipts written
+    /// before integrity was recorded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integrity: Option<IntegrityReport>,
+    /// Aggregate stats over the rows, present when a summary was requested.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub summary: Option<ExportSummary>,
     #[serde(flatten)]
     pub data: ExportData,
 }
 
+/// Content hash of a receipt's rows.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IntegrityReport {
+    /// Hash algorithm (`"blake3"` or `"sha256"`).
+    pub algo: String,
+    /// Lowercase hex digest.
+    pub hash: String,
+    /// Number of hashed entries.
+    pub entries: usize,
+}
+
+/// Digest algorithm for integrity and determinism hashes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HashAlgo {
+    /// BLAKE3 (256-bit).
+    #[default]
+    Blake3,
+    /// SHA-256, for compliance regimes that mandate it.
+    Sha256,
+}
+
+impl HashAlgo {
+    /// Name recorded in `algo` fields.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tokmd_types::HashAlgo;
+    ///
+    /// assert_eq!(HashAlgo::default().as_str(), "blake3");
+    /// assert_eq!(HashAlgo::Sha256.as_str(), "sha256");
+    /// ```
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Blake3 => "blake3",
+            Self::Sha256 => "sha256",
+        }
+    }
+
+    /// True for the default algorithm (serde `skip_serializing_if` helper).
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// How the scanner treats symlinked directories.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum SymlinkPolicy {
+    /// Do not descend into symlinked directories.
+    #[default]
+    Ignore,
+    /// Scan every symlinked directory, even when its files are also reached
+    /// another way. Links back into their own ancestors are never followed.
+    Follow,
+    /// Scan a symlinked directory only when its target is not already being
+    /// scanned, so no file is counted twice.
+    FollowNoLoop,
+}
+
+impl SymlinkPolicy {
+    /// True for the default policy (serde `skip_serializing_if` helper).
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// How the scanner counts lines of code.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum LineCountMode {
+    /// Count physical lines that contain code.
+    #[default]
+    Physical,
+    /// Count statements: `;` terminators in C-family languages and
+    /// statement-ending newlines in Python. Languages without a rule keep
+    /// their physical counts.
+    Logical,
+}
+
+impl LineCountMode {
+    /// True for the default mode (serde `skip_serializing_if` helper).
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LangArgs {
     pub paths: Vec<PathBuf>,
@@ -379,6 +649,12 @@ pub struct LangArgs {
     pub top: usize,
     pub files: bool,
     pub children: ChildrenMode,
+    #[serde(default)]
+    pub avg: AvgMeasure,
+    #[serde(default)]
+    pub percentiles: bool,
+    #[serde(default)]
+    pub with_files_detail: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -389,6 +665,83 @@ pub struct ModuleArgs {
     pub module_roots: Vec<String>,
     pub module_depth: usize,
     pub children: ChildIncludeMode,
+    #[serde(default)]
+    pub collapse_single_child: bool,
+    #[serde(default)]
+    pub avg: AvgMeasure,
+    #[serde(default)]
+    pub percentiles: bool,
+    #[serde(default)]
+    pub with_files_detail: bool,
+}
+
+/// Per-metric bounds on export rows.
+///
+/// Bounds are inclusive and combine with each other and with `min_code` (all
+/// must hold). They are applied before `max_rows` truncation.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExportFilters {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_code: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_lines: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_lines: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_bytes: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_bytes: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_tokens: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_tokens: Option<usize>,
+}
+
+impl ExportFilters {
+    /// True when no bound is set.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// True when `row` satisfies every set bound.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tokmd_types::{ExportFilters, FileKind, FileRow};
+    ///
+    /// let row = FileRow {
+    ///     path: "src/lib.rs".into(),
+    ///     module: "src".into(),
+    ///     lang: "Rust".into(),
+    ///     kind: FileKind::Parent,
+    ///     code: 40,
+    ///     comments: 5,
+    ///     blanks: 5,
+    ///     lines: 50,
+    ///     bytes: 1200,
+    ///     tokens: 300,
+    ///     last_modified_ms: None,
+    ///     age_days: None,
+    ///     role: None,
+    /// };
+    /// let filters = ExportFilters {
+    ///     min_tokens: Some(300),
+    ///     max_bytes: Some(1000),
+    ///     ..Default::default()
+    /// };
+    /// assert!(!filters.matches(&row));
+    /// assert!(ExportFilters::default().matches(&row));
+    /// ```
+    pub fn matches(&self, row: &FileRow) -> bool {
+        let within = |value: usize, min: Option<usize>, max: Option<usize>| {
+            min.is_none_or(|min| value >= min) && max.is_none_or(|max| value <= max)
+        };
+        within(row.code, None, self.max_code)
+            && within(row.lines, self.min_lines, self.max_lines)
+            && within(row.bytes, self.min_bytes, self.max_bytes)
+            && within(row.tokens, self.min_tokens, self.max_tokens)
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -400,10 +753,21 @@ pub struct ExportArgs {
     pub module_depth: usize,
     pub children: ChildIncludeMode,
     pub min_code: usize,
+    #[serde(default)]
+    pub filters: ExportFilters,
+    /// Column to sort rows by before `max_rows` truncation; `None` sorts by code.
+    #[serde(default)]
+    pub sort_by: Option<ExportSortKey>,
+    /// Sort direction; `None` uses the key's default direction.
+    #[serde(default)]
+    pub sort_dir: Option<SortDirection>,
     pub max_rows: usize,
     pub redact: RedactMode,
     pub meta: bool,
     pub strip_prefix: Option<PathBuf>,
+    /// Include an [`ExportSummary`] in the meta record (JSON / JSONL only).
+    #[serde(default)]
+    pub summary: bool,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -428,6 +792,55 @@ pub enum ExportFormat {
     Json,
     /// CycloneDX 1.6 JSON SBOM format.
     Cyclonedx,
+    /// File paths only, one per line.
+    Paths,
+    /// NUL-terminated file paths only (for `xargs -0`).
+    Paths0,
+}
+
+/// Column to order export rows by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum ExportSortKey {
+    /// Lines of code (the default order).
+    #[default]
+    Code,
+    /// Total lines.
+    Lines,
+    /// Comment lines.
+    Comments,
+    /// Blank lines.
+    Blanks,
+    /// File size in bytes.
+    Bytes,
+    /// Estimated tokens.
+    Tokens,
+    /// File path.
+    Path,
+    /// Module key.
+    Module,
+    /// Language name.
+    Lang,
+}
+
+impl ExportSortKey {
+    /// Direction used when none is given: largest first for metrics,
+    /// alphabetical for text columns.
+    pub fn default_direction(self) -> SortDirection {
+        match self {
+            Self::Path | Self::Module | Self::Lang => SortDirection::Asc,
+            _ => SortDirection::Desc,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum SortDirection {
+    /// Smallest / alphabetically first row first.
+    Asc,
+    /// Largest / alphabetically last row first.
+    Desc,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
@@ -440,6 +853,33 @@ pub enum ConfigMode {
     None,
 }
 
+/// Per-file line measure shown next to row totals.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum AvgMeasure {
+    /// Mean lines per file (`avg_lines`).
+    #[default]
+    Mean,
+    /// Median lines per file (`median_lines`), less skewed by outliers.
+    Median,
+    /// Both mean and median.
+    Both,
+}
+
+impl AvgMeasure {
+    /// Whether the mean column is shown.
+    #[must_use]
+    pub fn includes_mean(self) -> bool {
+        matches!(self, Self::Mean | Self::Both)
+    }
+
+    /// Whether the median is computed and shown.
+    #[must_use]
+    pub fn includes_median(self) -> bool {
+        matches!(self, Self::Median | Self::Both)
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 pub enum ChildrenMode {
@@ -456,6 +896,9 @@ pub enum ChildIncludeMode {
     Separate,
     /// Ignore embedded languages.
     ParentsOnly,
+    /// Include embedded languages as separate contributions, and list each
+    /// module's embedded languages under it.
+    Nested,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -476,8 +919,14 @@ pub enum AnalysisFormat {
     Json,
     Jsonld,
     Xml,
+    /// One row per file from the derived top offenders.
+    Csv,
     Svg,
+    /// SVG grid of headline metrics, colored by threshold.
+    Scorecard,
     Mermaid,
+    /// Mermaid `gitGraph` of recent commits tagged by intent.
+    Gitgraph,
     Obj,
     Midi,
     Tree,
@@ -496,6 +945,9 @@ mod tests {
             bytes: 5_000,
             tokens: 250,
             avg_lines: 20,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         }
     }
 
@@ -508,6 +960,10 @@ mod tests {
             bytes: 3_000,
             tokens: 200,
             avg_lines: 30,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         }
     }
 
@@ -520,6 +976,11 @@ mod tests {
             bytes: 2_500,
             tokens: 160,
             avg_lines: 30,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
+            children: Vec::new(),
         }
     }
 
@@ -535,6 +996,9 @@ mod tests {
             lines: 65,
             bytes: 2_000,
             tokens: 100,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         }
     }
 
@@ -550,6 +1014,13 @@ mod tests {
             no_ignore_dot: false,
             no_ignore_vcs: false,
             treat_doc_strings_as_comments: false,
+            token_model: "heuristic".to_string(),
+            hash_algo: HashAlgo::Blake3,
+            symlink_policy: Default::default(),
+            line_count_mode: Default::default(),
+            code_comment_markers: Vec::new(),
+            ignore_file: None,
+            include: Vec::new(),
         }
     }
 
@@ -765,7 +1236,11 @@ mod tests {
             serde_json::to_string(&ChildIncludeMode::ParentsOnly).unwrap(),
             "\"parents-only\""
         );
-        for variant in [ChildIncludeMode::Separate, ChildIncludeMode::ParentsOnly] {
+        for variant in [
+            ChildIncludeMode::Separate,
+            ChildIncludeMode::ParentsOnly,
+            ChildIncludeMode::Nested,
+        ] {
             let json = serde_json::to_string(&variant).unwrap();
             let back: ChildIncludeMode = serde_json::from_str(&json).unwrap();
             assert_eq!(back, variant);
@@ -819,6 +1294,40 @@ mod tests {
         );
     }
 
+    #[test]
+    fn hash_algo_default_is_blake3_and_serializes_as_algo_label() {
+        assert_eq!(HashAlgo::default(), HashAlgo::Blake3);
+        for algo in [HashAlgo::Blake3, HashAlgo::Sha256] {
+            assert_eq!(
+                serde_json::to_string(&algo).unwrap(),
+                format!("\"{}\"", algo.as_str())
+            );
+        }
+    }
+
+    #[test]
+    fn symlink_policy_defaults_to_ignore_and_uses_kebab_case() {
+        assert_eq!(SymlinkPolicy::default(), SymlinkPolicy::Ignore);
+        assert_eq!(
+            serde_json::to_string(&SymlinkPolicy::FollowNoLoop).unwrap(),
+            "\"follow-no-loop\""
+        );
+        let back: SymlinkPolicy = serde_json::from_str("\"follow\"").unwrap();
+        assert_eq!(back, SymlinkPolicy::Follow);
+    }
+
+    #[test]
+    fn line_count_mode_defaults_to_physical_and_uses_kebab_case() {
+        assert_eq!(LineCountMode::default(), LineCountMode::Physical);
+        assert!(LineCountMode::Physical.is_default());
+        assert_eq!(
+            serde_json::to_string(&LineCountMode::Logical).unwrap(),
+            "\"logical\""
+        );
+        let back: LineCountMode = serde_json::from_str("\"physical\"").unwrap();
+        assert_eq!(back, LineCountMode::Physical);
+    }
+
     // ── ToolInfo ─────────────────────────────────────────────────────
     #[test]
     fn tool_info_default_serde() {
@@ -870,6 +1379,16 @@ mod tests {
         assert_eq!(value["excluded_redacted"], true);
     }
 
+    #[test]
+    fn scan_args_token_model_defaults_for_legacy_receipts() {
+        let mut value = serde_json::to_value(sample_scan_args()).unwrap();
+        assert_eq!(value["token_model"], "heuristic");
+
+        value.as_object_mut().unwrap().remove("token_model");
+        let back: ScanArgs = serde_json::from_value(value).unwrap();
+        assert_eq!(back.token_model, crate::DEFAULT_TOKEN_MODEL);
+    }
+
     // ── Receipts ─────────────────────────────────────────────────────
     #[test]
     fn lang_receipt_flattens_report_fields() {
@@ -880,7 +1399,10 @@ mod tests {
             mode: "lang".into(),
             status: ScanStatus::Complete,
             warnings: vec![],
-            scan: sample_scan_args(),
+            scan: ScanArgs {
+                token_model: "cl100k_base".into(),
+                ..sample_scan_args()
+            },
             args: LangArgsMeta {
                 format: "md".into(),
                 top: 10,
@@ -894,6 +1416,7 @@ mod tests {
                 children: ChildrenMode::Separate,
                 top: 10,
             },
+            integrity: None,
         };
         let value = serde_json::to_value(&receipt).unwrap();
         // Envelope fields
@@ -921,6 +1444,7 @@ mod tests {
         let back: LangReceipt = serde_json::from_str(&json).unwrap();
         assert_eq!(back.mode, "lang");
         assert_eq!(back.report.rows.len(), 1);
+        assert_eq!(back.scan.token_model, "cl100k_base");
     }
 
     #[test]
@@ -939,6 +1463,7 @@ mod tests {
                 module_depth: 3,
                 children: ChildIncludeMode::Separate,
                 top: 20,
+                collapse_single_child: false,
             },
             report: ModuleReport {
                 rows: vec![sample_module_row()],
@@ -948,6 +1473,7 @@ mod tests {
                 children: ChildIncludeMode::Separate,
                 top: 20,
             },
+            integrity: None,
         };
         let json = serde_json::to_string(&receipt).unwrap();
         let value: serde_json::Value = serde_json::from_str(&json).unwrap();
@@ -969,7 +1495,7 @@ mod tests {
         let back: ModuleReceipt = serde_json::from_str(&json).unwrap();
         assert_eq!(back.mode, "module");
         assert_eq!(back.report.module_depth, 3);
-        assert_eq!(back.warnings, vec!["something".to_string()]);
+        assert_eq!(back.warnings, vec![Warning::from("something")]);
     }
 
     #[test]
@@ -981,7 +1507,10 @@ mod tests {
             mode: "export".into(),
             status: ScanStatus::Complete,
             warnings: vec![],
-            scan: sample_scan_args(),
+            scan: ScanArgs {
+                token_model: "cl100k_base".into(),
+                ..sample_scan_args()
+            },
             args: ExportArgsMeta {
                 format: ExportFormat::Json,
                 module_roots: vec![],
@@ -992,6 +1521,9 @@ mod tests {
                 redact: RedactMode::None,
                 strip_prefix: None,
                 strip_prefix_redacted: false,
+                filters: Default::default(),
+                sort_by: None,
+                sort_dir: None,
             },
             data: ExportData {
                 rows: vec![sample_file_row()],
@@ -999,6 +1531,8 @@ mod tests {
                 module_depth: 1,
                 children: ChildIncludeMode::Separate,
             },
+            integrity: None,
+            summary: None,
         };
         let json = serde_json::to_string(&receipt).unwrap();
         let value: serde_json::Value = serde_json::from_str(&json).unwrap();
@@ -1012,6 +1546,7 @@ mod tests {
         let back: ExportReceipt = serde_json::from_str(&json).unwrap();
         assert_eq!(back.mode, "export");
         assert_eq!(back.data.rows.len(), 1);
+        assert_eq!(back.scan.token_model, "cl100k_base");
     }
 
     #[test]
@@ -1026,6 +1561,9 @@ mod tests {
             redact: RedactMode::None,
             strip_prefix: None,
             strip_prefix_redacted: false,
+            filters: Default::default(),
+            sort_by: None,
+            sort_dir: None,
         };
         let value = serde_json::to_value(&meta).unwrap();
         assert!(value.get("strip_prefix_redacted").is_none());
@@ -1043,6 +1581,9 @@ mod tests {
             redact: RedactMode::None,
             strip_prefix: Some("abc".into()),
             strip_prefix_redacted: true,
+            filters: Default::default(),
+            sort_by: None,
+            sort_dir: None,
         };
         let value = serde_json::to_value(&meta).unwrap();
         assert_eq!(value["strip_prefix_redacted"], true);
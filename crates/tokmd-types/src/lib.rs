@@ -36,6 +36,7 @@ mod evidence_packet;
 mod inventory;
 mod packet_siblings;
 mod tokmd_packets;
+mod warning;
 
 pub use context::{
     ArtifactEntry, ArtifactHash, CONTEXT_BUNDLE_SCHEMA_VERSION, CONTEXT_SCHEMA_VERSION,
@@ -45,17 +46,18 @@ pub use context::{
     HandoffManifest, InclusionPolicy, PolicyExcludedFile, SmartExcludedFile, TokenAudit,
     TokenEstimationMeta,
 };
-pub use diff::{DiffReceipt, DiffRow, DiffTotals};
+pub use diff::{ApiSurfaceDiff, DiffReceipt, DiffRow, DiffTotals, ModuleApiChange};
 pub use evidence_packet::{
     EVIDENCE_PACKET_SCHEMA, EvidencePacketArtifacts, EvidencePacketManifest,
     EvidencePacketReviewPriorityItem, EvidencePacketStatus,
 };
 pub use inventory::{
-    AnalysisFormat, ChildIncludeMode, ChildrenMode, CommitIntentKind, ConfigMode, ExportArgs,
-    ExportArgsMeta, ExportData, ExportFormat, ExportReceipt, FileKind, FileRow, LangArgs,
-    LangArgsMeta, LangReceipt, LangReport, LangRow, ModuleArgs, ModuleArgsMeta, ModuleReceipt,
-    ModuleReport, ModuleRow, RedactMode, RunReceipt, ScanArgs, ScanStatus, TableFormat, ToolInfo,
-    Totals,
+    AnalysisFormat, AvgMeasure, ChildIncludeMode, ChildrenMode, CommitIntentKind, ConfigMode,
+    ExportArgs, ExportArgsMeta, ExportData, ExportFilters, ExportFormat, ExportReceipt,
+    ExportSortKey, ExportSummary, ExtensionRow, FileKind, FileRole, FileRow, HashAlgo,
+    IntegrityReport, LangArgs, LangArgsMeta, LangReceipt, LangReport, LangRow, LineCountMode,
+    ModuleArgs, ModuleArgsMeta, ModuleChildRow, ModuleReceipt, ModuleReport, ModuleRow, RedactMode,
+    RunReceipt, ScanArgs, ScanStatus, SortDirection, SymlinkPolicy, TableFormat, ToolInfo, Totals,
 };
 pub use packet_siblings::{
     CARDS_FILE, CardsFile, MANUAL_CANDIDATES_FILE, MANUAL_CANDIDATES_SCHEMA, ManualCandidateRecord,
@@ -65,6 +67,11 @@ pub use tokmd_packets::{
     BUN_UB_PACKET_PRESETS, PacketPresetInput, TOKMD_PACKETS_SCHEMA, TokmdPacketsManifest,
     TokmdPacketsProducer,
 };
+pub use warning::{
+    BOM_STRIPPED, BYTE_BUDGET_EXCEEDED, ENRICHER_FAILED, FEATURE_DISABLED, NO_HOST_ROOT,
+    NON_PORTABLE_PATH, NOT_A_GIT_REPO, RUNTIME_BUDGET_EXCEEDED, THRESHOLD_UNMET, TRUNCATED_FILE,
+    UNCLASSIFIED, Warning, WarningSeverity,
+};
 
 #[cfg(test)]
 pub(crate) use context::is_default_policy;
@@ -74,9 +81,25 @@ pub(crate) use context::is_default_policy;
 /// # Examples
 ///
 /// ```
-/// assert_eq!(tokmd_types::SCHEMA_VERSION, 2);
+/// assert_eq!(tokmd_types::SCHEMA_VERSION, 3);
 /// ```
-pub const SCHEMA_VERSION: u32 = 2;
+pub const SCHEMA_VERSION: u32 = 3;
+
+/// Token model recorded in receipts when none is configured: the built-in
+/// estimate of one token per four bytes.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(tokmd_types::DEFAULT_TOKEN_MODEL, "heuristic");
+/// ```
+pub const DEFAULT_TOKEN_MODEL: &str = "heuristic";
+
+/// Serde default for `token_model` fields, so receipts written before the
+/// field existed deserialize as [`DEFAULT_TOKEN_MODEL`].
+pub fn default_token_model() -> String {
+    DEFAULT_TOKEN_MODEL.to_string()
+}
 
 #[cfg(test)]
 mod tests {
@@ -85,7 +108,7 @@ mod tests {
     // ── Schema version constants ──────────────────────────────────────
     #[test]
     fn schema_version_constants() {
-        assert_eq!(SCHEMA_VERSION, 2);
+        assert_eq!(SCHEMA_VERSION, 3);
         assert_eq!(HANDOFF_SCHEMA_VERSION, 5);
         assert_eq!(CONTEXT_BUNDLE_SCHEMA_VERSION, 2);
         assert_eq!(CONTEXT_SCHEMA_VERSION, 4);
@@ -142,6 +165,8 @@ mod tests {
             ExportFormat::Jsonl,
             ExportFormat::Json,
             ExportFormat::Cyclonedx,
+            ExportFormat::Paths,
+            ExportFormat::Paths0,
         ] {
             let json = serde_json::to_string(&variant).unwrap();
             let back: ExportFormat = serde_json::from_str(&json).unwrap();
@@ -274,8 +299,11 @@ mod tests {
             AnalysisFormat::Json,
             AnalysisFormat::Jsonld,
             AnalysisFormat::Xml,
+            AnalysisFormat::Csv,
             AnalysisFormat::Svg,
+            AnalysisFormat::Scorecard,
             AnalysisFormat::Mermaid,
+            AnalysisFormat::Gitgraph,
             AnalysisFormat::Obj,
             AnalysisFormat::Midi,
             AnalysisFormat::Tree,
@@ -324,6 +352,9 @@ mod tests {
             bytes: 5000,
             tokens: 250,
             avg_lines: 20,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         };
         let json = serde_json::to_string(&t).unwrap();
         let back: Totals = serde_json::from_str(&json).unwrap();
@@ -340,6 +371,10 @@ mod tests {
             bytes: 3000,
             tokens: 200,
             avg_lines: 30,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         };
         let json = serde_json::to_string(&r).unwrap();
         let back: LangRow = serde_json::from_str(&json).unwrap();
@@ -504,6 +539,9 @@ mod tests {
             lines: 65,
             bytes: 2000,
             tokens: 100,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         };
         let json = serde_json::to_string(&r).unwrap();
         let back: FileRow = serde_json::from_str(&json).unwrap();
@@ -0,0 +1,210 @@
+//! Structured receipt warnings.
+//!
+//! Receipts record problems that did not stop the run (bounded scans,
+//! disabled enrichers, non-portable paths) as [`Warning`]s: a stable
+//! machine-readable `code`, a [`WarningSeverity`], the human message, and the
+//! path concerned when there is one. CI can gate on codes; renderers that
+//! want a single line use the `Display` form.
+//!
+//! Receipts written before warnings were structured stored bare strings.
+//! Those still deserialize, as [`UNCLASSIFIED`] warnings with the string as
+//! their message.
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// Code of warnings read from receipts that stored plain strings.
+pub const UNCLASSIFIED: &str = "unclassified";
+/// A file was only partly read because it exceeded a per-file byte limit.
+pub const TRUNCATED_FILE: &str = "truncated_file";
+/// A scan stopped before covering every eligible file because a total byte
+/// budget ran out.
+pub const BYTE_BUDGET_EXCEEDED: &str = "byte_budget_exceeded";
+/// A wall-clock runtime budget ran out; later phases were skipped and the
+/// one running was cut short.
+pub const RUNTIME_BUDGET_EXCEEDED: &str = "runtime_budget_exceeded";
+/// An analysis step failed; its section of the receipt is missing.
+pub const ENRICHER_FAILED: &str = "enricher_failed";
+/// An analysis step was requested but this build lacks the feature for it.
+pub const FEATURE_DISABLED: &str = "feature_disabled";
+/// File- or git-backed analysis was skipped because the input has no host
+/// directory (in-memory or archive inputs).
+pub const NO_HOST_ROOT: &str = "no_host_root";
+/// Git-backed analysis was skipped because the root is not a git repository.
+pub const NOT_A_GIT_REPO: &str = "not_a_git_repo";
+/// A path would break a checkout on Windows.
+pub const NON_PORTABLE_PATH: &str = "non_portable_path";
+/// A UTF-8 byte order mark was stripped before content analysis.
+pub const BOM_STRIPPED: &str = "bom_stripped";
+/// A measured ratio or threshold was not met.
+pub const THRESHOLD_UNMET: &str = "threshold_unmet";
+
+/// How much a warning matters.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WarningSeverity {
+    /// Expected for this input or build; nothing to fix.
+    Info,
+    /// Results are partial or questionable.
+    #[default]
+    Warning,
+    /// A requested result could not be produced.
+    Error,
+}
+
+impl WarningSeverity {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            WarningSeverity::Info => "info",
+            WarningSeverity::Warning => "warning",
+            WarningSeverity::Error => "error",
+        }
+    }
+}
+
+/// A problem recorded in a receipt without failing the run.
+///
+/// # Examples
+///
+/// ```
+/// use tokmd_types::{TRUNCATED_FILE, Warning, WarningSeverity};
+///
+/// let warning = Warning::new(
+///     TRUNCATED_FILE,
+///     WarningSeverity::Warning,
+///     "read the first 128 KiB only",
+/// )
+/// .with_path("src/generated.rs");
+/// assert_eq!(warning.code, "truncated_file");
+/// assert_eq!(warning.to_string(), "read the first 128 KiB only (src/generated.rs)");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "WarningRepr")]
+pub struct Warning {
+    /// Stable snake_case identifier, e.g. [`TRUNCATED_FILE`].
+    pub code: String,
+    pub severity: WarningSeverity,
+    /// Human-readable description.
+    pub message: String,
+    /// Path the warning is about, when it concerns a single file.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+}
+
+impl Warning {
+    pub fn new(
+        code: impl Into<String>,
+        severity: WarningSeverity,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            code: code.into(),
+            severity,
+            message: message.into(),
+            path: None,
+        }
+    }
+
+    pub fn with_path(mut self, path: impl Into<String>) -> Self {
+        self.path = Some(path.into());
+        self
+    }
+}
+
+/// The message, followed by the path in parentheses when there is one.
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.path {
+            Some(path) => write!(f, "{} ({path})", self.message),
+            None => f.write_str(&self.message),
+        }
+    }
+}
+
+/// A bare message becomes an [`UNCLASSIFIED`] warning.
+impl From<String> for Warning {
+    fn from(message: String) -> Self {
+        Self::new(UNCLASSIFIED, WarningSeverity::Warning, message)
+    }
+}
+
+impl From<&str> for Warning {
+    fn from(message: &str) -> Self {
+        Self::from(message.to_string())
+    }
+}
+
+/// Accepts both the structured form and the plain strings older receipts
+/// stored.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum WarningRepr {
+    Text(String),
+    Structured {
+        code: String,
+        #[serde(default)]
+        severity: WarningSeverity,
+        message: String,
+        #[serde(default)]
+        path: Option<String>,
+    },
+}
+
+impl From<WarningRepr> for Warning {
+    fn from(repr: WarningRepr) -> Self {
+        match repr {
+            WarningRepr::Text(message) => Self::from(message),
+            WarningRepr::Structured {
+                code,
+                severity,
+                message,
+                path,
+            } => Self {
+                code,
+                severity,
+                message,
+                path,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn structured_warning_round_trips() {
+        let warning = Warning::new(TRUNCATED_FILE, WarningSeverity::Warning, "clipped")
+            .with_path("src/big.rs");
+        let json = serde_json::to_value(&warning).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "code": "truncated_file",
+                "severity": "warning",
+                "message": "clipped",
+                "path": "src/big.rs",
+            })
+        );
+        assert_eq!(serde_json::from_value::<Warning>(json).unwrap(), warning);
+    }
+
+    #[test]
+    fn plain_string_reads_as_unclassified() {
+        let warning: Warning = serde_json::from_str("\"git scan failed\"").unwrap();
+        assert_eq!(warning.code, UNCLASSIFIED);
+        assert_eq!(warning.severity, WarningSeverity::Warning);
+        assert_eq!(warning.message, "git scan failed");
+        assert_eq!(warning.path, None);
+    }
+
+    #[test]
+    fn pathless_warning_omits_path() {
+        let warning = Warning::new(FEATURE_DISABLED, WarningSeverity::Info, "no git");
+        let json = serde_json::to_string(&warning).unwrap();
+        assert!(!json.contains("path"));
+        assert_eq!(warning.to_string(), "no git");
+    }
+}
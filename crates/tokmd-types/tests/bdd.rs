@@ -24,7 +24,7 @@ use tokmd_types::{
 fn schema_version_constants_are_correct() {
     // Given the documented schema versions
     // Then each constant matches its documented value
-    assert_eq!(SCHEMA_VERSION, 2, "Core receipt schema version");
+    assert_eq!(SCHEMA_VERSION, 3, "Core receipt schema version");
     assert_eq!(COCKPIT_SCHEMA_VERSION, 3, "Cockpit schema version");
     assert_eq!(HANDOFF_SCHEMA_VERSION, 5, "Handoff schema version");
     assert_eq!(CONTEXT_SCHEMA_VERSION, 4, "Context schema version");
@@ -49,6 +49,10 @@ fn lang_row_construction_and_field_access() {
         bytes: 200_000,
         tokens: 50_000,
         avg_lines: 166,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
+        file_rows: Vec::new(),
     };
 
     // Then all fields are accessible and correct
@@ -72,6 +76,10 @@ fn lang_row_serialized_fields_match_expected() {
         bytes: 4096,
         tokens: 1024,
         avg_lines: 50,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
+        file_rows: Vec::new(),
     };
 
     // When serialized to JSON
@@ -98,6 +106,10 @@ fn lang_row_equality() {
         bytes: 4,
         tokens: 5,
         avg_lines: 6,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
+        file_rows: Vec::new(),
     };
     let b = a.clone();
 
@@ -119,6 +131,11 @@ fn module_row_construction_and_field_access() {
         bytes: 32_000,
         tokens: 8_000,
         avg_lines: 240,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
+        file_rows: Vec::new(),
+        children: Vec::new(),
     };
 
     assert_eq!(row.module, "src/parser");
@@ -136,6 +153,11 @@ fn module_row_serde_roundtrip() {
         bytes: 50_000,
         tokens: 12_500,
         avg_lines: 150,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
+        file_rows: Vec::new(),
+        children: Vec::new(),
     };
 
     let json = serde_json::to_string(&row).unwrap();
@@ -160,6 +182,9 @@ fn file_row_construction_with_parent_kind() {
         lines: 250,
         bytes: 8_000,
         tokens: 2_000,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     };
 
     assert_eq!(row.kind, FileKind::Parent);
@@ -181,6 +206,9 @@ fn file_row_construction_with_child_kind() {
         lines: 65,
         bytes: 2_000,
         tokens: 500,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     };
 
     assert_eq!(row.kind, FileKind::Child);
@@ -199,6 +227,9 @@ fn file_row_serde_roundtrip() {
         lines: 135,
         bytes: 5_000,
         tokens: 1_250,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     };
 
     let json = serde_json::to_string(&row).unwrap();
@@ -224,6 +255,9 @@ fn export_row_json_field_order_is_stable() {
         lines: 15,
         bytes: 400,
         tokens: 100,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     };
 
     // When serialized
@@ -376,6 +410,9 @@ fn totals_construction_and_equality() {
         bytes: 50_000,
         tokens: 12_500,
         avg_lines: 75,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
     };
     let b = a.clone();
     assert_eq!(a, b);
@@ -39,6 +39,13 @@ fn make_scan_args() -> ScanArgs {
         no_ignore_dot: false,
         no_ignore_vcs: false,
         treat_doc_strings_as_comments: false,
+        token_model: "heuristic".to_string(),
+        hash_algo: tokmd_types::HashAlgo::Blake3,
+        symlink_policy: Default::default(),
+        line_count_mode: Default::default(),
+        code_comment_markers: Vec::new(),
+        ignore_file: None,
+        include: Vec::new(),
     }
 }
 
@@ -50,6 +57,9 @@ fn make_totals() -> Totals {
         bytes: 5000,
         tokens: 250,
         avg_lines: 20,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
     }
 }
 
@@ -62,6 +72,10 @@ fn make_lang_row(lang: &str, code: usize) -> LangRow {
         bytes: code * 50,
         tokens: code * 5,
         avg_lines: code * 2,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
+        file_rows: Vec::new(),
     }
 }
 
@@ -74,6 +88,11 @@ fn make_module_row(module: &str, code: usize) -> ModuleRow {
         bytes: code * 50,
         tokens: code * 5,
         avg_lines: code * 2,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
+        file_rows: Vec::new(),
+        children: Vec::new(),
     }
 }
 
@@ -89,6 +108,9 @@ fn make_file_row() -> FileRow {
         lines: 130,
         bytes: 4000,
         tokens: 500,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
@@ -116,6 +138,7 @@ fn make_diff_row(lang: &str, old_code: usize, new_code: usize) -> DiffRow {
 fn make_evidence() -> Evidence {
     Evidence {
         overall_status: GateStatus::Pass,
+        project_coverage: None,
         mutation: MutationGate {
             meta: GateMeta {
                 status: GateStatus::Pass,
@@ -317,6 +340,7 @@ fn given_lang_receipt_when_constructed_then_schema_version_present() {
             children: ChildrenMode::Collapse,
             top: 10,
         },
+        integrity: None,
     };
     assert_eq!(receipt.schema_version, SCHEMA_VERSION);
     assert_eq!(receipt.mode, "lang");
@@ -331,7 +355,7 @@ fn given_lang_receipt_when_serialized_then_json_roundtrips() {
         tool: ToolInfo::current(),
         mode: "lang".to_string(),
         status: ScanStatus::Complete,
-        warnings: vec!["test warning".to_string()],
+        warnings: vec!["test warning".into()],
         scan: make_scan_args(),
         args: LangArgsMeta {
             format: "json".to_string(),
@@ -346,11 +370,15 @@ fn given_lang_receipt_when_serialized_then_json_roundtrips() {
             children: ChildrenMode::Separate,
             top: 5,
         },
+        integrity: None,
     };
     let json = serde_json::to_string(&receipt).unwrap();
     let back: LangReceipt = serde_json::from_str(&json).unwrap();
     assert_eq!(back.schema_version, SCHEMA_VERSION);
-    assert_eq!(back.warnings, vec!["test warning"]);
+    assert_eq!(
+        back.warnings,
+        vec![tokmd_types::Warning::from("test warning")]
+    );
 }
 
 #[test]
@@ -369,6 +397,7 @@ fn given_module_receipt_when_constructed_then_schema_version_present() {
             module_depth: 1,
             children: ChildIncludeMode::Separate,
             top: 10,
+            collapse_single_child: false,
         },
         report: ModuleReport {
             rows: vec![make_module_row("src", 200)],
@@ -378,6 +407,7 @@ fn given_module_receipt_when_constructed_then_schema_version_present() {
             children: ChildIncludeMode::Separate,
             top: 10,
         },
+        integrity: None,
     };
     assert_eq!(receipt.schema_version, SCHEMA_VERSION);
     assert_eq!(receipt.mode, "module");
@@ -399,6 +429,7 @@ fn given_module_receipt_when_serialized_then_json_roundtrips() {
             module_depth: 2,
             children: ChildIncludeMode::ParentsOnly,
             top: 0,
+            collapse_single_child: false,
         },
         report: ModuleReport {
             rows: vec![],
@@ -408,6 +439,7 @@ fn given_module_receipt_when_serialized_then_json_roundtrips() {
             children: ChildIncludeMode::ParentsOnly,
             top: 0,
         },
+        integrity: None,
     };
     let json = serde_json::to_string(&receipt).unwrap();
     let back: ModuleReceipt = serde_json::from_str(&json).unwrap();
@@ -434,6 +466,9 @@ fn given_export_receipt_when_constructed_then_schema_version_present() {
             redact: RedactMode::None,
             strip_prefix: None,
             strip_prefix_redacted: false,
+            filters: Default::default(),
+            sort_by: None,
+            sort_dir: None,
         },
         data: ExportData {
             rows: vec![make_file_row()],
@@ -441,6 +476,8 @@ fn given_export_receipt_when_constructed_then_schema_version_present() {
             module_depth: 1,
             children: ChildIncludeMode::Separate,
         },
+        integrity: None,
+        summary: None,
     };
     assert_eq!(receipt.schema_version, SCHEMA_VERSION);
     assert_eq!(receipt.mode, "export");
@@ -467,6 +504,9 @@ fn given_export_receipt_when_serialized_then_json_roundtrips() {
             redact: RedactMode::Paths,
             strip_prefix: Some("/home/user".to_string()),
             strip_prefix_redacted: true,
+            filters: Default::default(),
+            sort_by: None,
+            sort_dir: None,
         },
         data: ExportData {
             rows: vec![],
@@ -474,6 +514,8 @@ fn given_export_receipt_when_serialized_then_json_roundtrips() {
             module_depth: 1,
             children: ChildIncludeMode::Separate,
         },
+        integrity: None,
+        summary: None,
     };
     let json = serde_json::to_string(&receipt).unwrap();
     let back: ExportReceipt = serde_json::from_str(&json).unwrap();
@@ -552,6 +594,7 @@ fn given_diff_receipt_when_constructed_then_contains_metadata() {
         to_source: "v2.0".to_string(),
         diff_rows: vec![make_diff_row("Rust", 100, 150)],
         totals: DiffTotals::default(),
+        api_surface: None,
     };
     assert_eq!(receipt.schema_version, SCHEMA_VERSION);
     assert_eq!(receipt.from_source, "v1.0");
@@ -570,6 +613,7 @@ fn given_diff_receipt_when_serialized_then_json_roundtrips() {
         to_source: "b.json".to_string(),
         diff_rows: vec![],
         totals: DiffTotals::default(),
+        api_surface: None,
     };
     let json = serde_json::to_string(&receipt).unwrap();
     let back: DiffReceipt = serde_json::from_str(&json).unwrap();
@@ -697,7 +741,7 @@ fn given_schema_versions_when_checked_then_all_positive_nonzero() {
 
 #[test]
 fn given_schema_versions_when_checked_then_match_documented_values() {
-    assert_eq!(SCHEMA_VERSION, 2);
+    assert_eq!(SCHEMA_VERSION, 3);
     assert_eq!(COCKPIT_SCHEMA_VERSION, 3);
     assert_eq!(HANDOFF_SCHEMA_VERSION, 5);
     assert_eq!(CONTEXT_SCHEMA_VERSION, 4);
@@ -779,6 +823,9 @@ fn given_cockpit_receipt_when_constructed_then_schema_version_matches() {
             bus_factor_warnings: vec![],
             level: RiskLevel::Low,
             score: 5,
+            owners_touched: None,
+            unowned_files: vec![],
+            hotspot_owners: Default::default(),
         },
         contracts: Contracts {
             api_changed: false,
@@ -833,6 +880,9 @@ fn given_cockpit_receipt_when_serialized_then_json_roundtrips() {
             bus_factor_warnings: vec![],
             level: RiskLevel::Critical,
             score: 100,
+            owners_touched: None,
+            unowned_files: vec![],
+            hotspot_owners: Default::default(),
         },
         contracts: Contracts {
             api_changed: true,
@@ -847,6 +897,8 @@ fn given_cockpit_receipt_when_serialized_then_json_roundtrips() {
             priority: 1,
             complexity: Some(4),
             lines_changed: Some(100),
+            review_minutes: None,
+            owners: None,
         }],
         trend: None,
     };
@@ -894,6 +946,9 @@ fn given_cockpit_receipt_with_trend_when_serialized_then_trend_present() {
             bus_factor_warnings: vec![],
             level: RiskLevel::Medium,
             score: 50,
+            owners_touched: None,
+            unowned_files: vec![],
+            hotspot_owners: Default::default(),
         },
         contracts: Contracts {
             api_changed: false,
@@ -1266,6 +1321,10 @@ fn given_lang_row_when_empty_string_lang_then_ok() {
         bytes: 0,
         tokens: 0,
         avg_lines: 0,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
+        file_rows: Vec::new(),
     };
     let json = serde_json::to_string(&row).unwrap();
     let back: LangRow = serde_json::from_str(&json).unwrap();
@@ -1283,6 +1342,11 @@ fn given_module_row_when_empty_module_name_then_ok() {
         bytes: 0,
         tokens: 0,
         avg_lines: 0,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
+        file_rows: Vec::new(),
+        children: Vec::new(),
     };
     let json = serde_json::to_string(&row).unwrap();
     let back: ModuleRow = serde_json::from_str(&json).unwrap();
@@ -1299,6 +1363,10 @@ fn given_lang_row_when_zero_values_then_serializes() {
         bytes: 0,
         tokens: 0,
         avg_lines: 0,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
+        file_rows: Vec::new(),
     };
     let json = serde_json::to_string(&row).unwrap();
     assert!(json.contains("\"code\":0"));
@@ -1314,6 +1382,10 @@ fn given_lang_row_when_max_usize_values_then_serializes() {
         bytes: usize::MAX,
         tokens: usize::MAX,
         avg_lines: usize::MAX,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
+        file_rows: Vec::new(),
     };
     let json = serde_json::to_string(&row).unwrap();
     let back: LangRow = serde_json::from_str(&json).unwrap();
@@ -1358,6 +1430,9 @@ fn given_file_row_when_unicode_path_then_roundtrips() {
         lines: 13,
         bytes: 500,
         tokens: 50,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     };
     let json = serde_json::to_string(&row).unwrap();
     let back: FileRow = serde_json::from_str(&json).unwrap();
@@ -1737,6 +1812,13 @@ fn given_scan_args_with_all_flags_true_when_serialized_then_roundtrips() {
         no_ignore_dot: true,
         no_ignore_vcs: true,
         treat_doc_strings_as_comments: true,
+        token_model: "heuristic".to_string(),
+        hash_algo: tokmd_types::HashAlgo::Blake3,
+        symlink_policy: Default::default(),
+        line_count_mode: Default::default(),
+        code_comment_markers: Vec::new(),
+        ignore_file: None,
+        include: Vec::new(),
     };
     let json = serde_json::to_string(&args).unwrap();
     let back: ScanArgs = serde_json::from_str(&json).unwrap();
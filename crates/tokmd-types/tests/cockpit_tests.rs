@@ -48,6 +48,7 @@ fn sample_mutation_gate(status: GateStatus) -> MutationGate {
 fn sample_evidence(overall: GateStatus) -> Evidence {
     Evidence {
         overall_status: overall,
+        project_coverage: None,
         mutation: sample_mutation_gate(overall),
         diff_coverage: None,
         contracts: None,
@@ -93,6 +94,9 @@ fn sample_cockpit_receipt() -> CockpitReceipt {
             bus_factor_warnings: vec![],
             level: RiskLevel::Medium,
             score: 45,
+            owners_touched: None,
+            unowned_files: vec![],
+            hotspot_owners: Default::default(),
         },
         contracts: Contracts {
             api_changed: false,
@@ -107,6 +111,8 @@ fn sample_cockpit_receipt() -> CockpitReceipt {
             priority: 1,
             complexity: Some(3),
             lines_changed: Some(85),
+            review_minutes: None,
+            owners: None,
         }],
         trend: None,
     }
@@ -350,10 +356,13 @@ fn given_diff_coverage_gate_then_coverage_pct_accessible() {
         lines_added: 100,
         lines_covered: 75,
         coverage_pct: 75.0,
+        artifact: None,
+        branch_coverage: None,
         uncovered_hunks: vec![UncoveredHunk {
             file: "src/new.rs".to_string(),
             start_line: 10,
             end_line: 20,
+            snippet: None,
         }],
     };
 
@@ -369,6 +378,8 @@ fn given_diff_coverage_gate_when_roundtripped_then_preserved() {
         lines_added: 50,
         lines_covered: 50,
         coverage_pct: 100.0,
+        artifact: None,
+        branch_coverage: None,
         uncovered_hunks: vec![],
     };
     let json_str = serde_json::to_string(&gate).unwrap();
@@ -386,8 +397,11 @@ fn given_supply_chain_gate_with_vulnerabilities_then_accessible() {
             package: "risky-crate".to_string(),
             severity: "high".to_string(),
             title: "Memory safety issue".to_string(),
+            url: None,
+            patched: vec![],
         }],
         denied: vec!["banned-license".to_string()],
+        acknowledged: vec![],
         advisory_db_version: Some("2024-01-01".to_string()),
     };
 
@@ -404,6 +418,7 @@ fn given_determinism_gate_with_mismatch_then_differences_listed() {
         actual_hash: Some("actual_def".to_string()),
         algo: "blake3".to_string(),
         differences: vec!["row ordering changed".to_string()],
+        excludes: vec![],
     };
 
     assert_eq!(gate.algo, "blake3");
@@ -470,12 +485,15 @@ fn given_contract_diff_gate_with_semver_break_then_failures_nonzero() {
 fn given_evidence_with_all_gates_when_serialized_then_all_present() {
     let evidence = Evidence {
         overall_status: GateStatus::Warn,
+        project_coverage: None,
         mutation: sample_mutation_gate(GateStatus::Pass),
         diff_coverage: Some(DiffCoverageGate {
             meta: sample_gate_meta(GateStatus::Pass),
             lines_added: 100,
             lines_covered: 95,
             coverage_pct: 95.0,
+            artifact: None,
+            branch_coverage: None,
             uncovered_hunks: vec![],
         }),
         contracts: Some(ContractDiffGate {
@@ -489,6 +507,7 @@ fn given_evidence_with_all_gates_when_serialized_then_all_present() {
             meta: sample_gate_meta(GateStatus::Pass),
             vulnerabilities: vec![],
             denied: vec![],
+            acknowledged: vec![],
             advisory_db_version: None,
         }),
         determinism: Some(DeterminismGate {
@@ -497,6 +516,7 @@ fn given_evidence_with_all_gates_when_serialized_then_all_present() {
             actual_hash: None,
             algo: "blake3".to_string(),
             differences: vec![],
+            excludes: vec![],
         }),
         complexity: Some(ComplexityGate {
             meta: sample_gate_meta(GateStatus::Pass),
@@ -614,6 +634,8 @@ fn given_review_items_when_sorted_by_priority_then_lowest_first() {
             priority: 3,
             complexity: None,
             lines_changed: None,
+            review_minutes: None,
+            owners: None,
         },
         ReviewItem {
             path: "a.rs".to_string(),
@@ -621,6 +643,8 @@ fn given_review_items_when_sorted_by_priority_then_lowest_first() {
             priority: 1,
             complexity: Some(5),
             lines_changed: Some(200),
+            review_minutes: None,
+            owners: None,
         },
         ReviewItem {
             path: "b.rs".to_string(),
@@ -628,6 +652,8 @@ fn given_review_items_when_sorted_by_priority_then_lowest_first() {
             priority: 2,
             complexity: Some(3),
             lines_changed: Some(50),
+            review_minutes: None,
+            owners: None,
         },
     ];
 
@@ -649,6 +675,8 @@ fn given_review_item_with_optional_fields_omitted_then_json_clean() {
         priority: 1,
         complexity: None,
         lines_changed: None,
+        review_minutes: None,
+        owners: None,
     };
     let json = serde_json::to_string(&item).unwrap();
     assert!(!json.contains("\"complexity\""));
@@ -727,6 +755,7 @@ fn snapshot_cockpit_receipt_minimal() {
 fn snapshot_cockpit_evidence_all_gates() {
     let evidence = Evidence {
         overall_status: GateStatus::Warn,
+        project_coverage: None,
         mutation: MutationGate {
             meta: sample_gate_meta(GateStatus::Warn),
             survivors: vec![MutationSurvivor {
@@ -743,6 +772,8 @@ fn snapshot_cockpit_evidence_all_gates() {
             lines_added: 100,
             lines_covered: 95,
             coverage_pct: 95.0,
+            artifact: None,
+            branch_coverage: None,
             uncovered_hunks: vec![],
         }),
         contracts: None,
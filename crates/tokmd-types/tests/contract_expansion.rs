@@ -37,6 +37,13 @@ fn sample_scan_args() -> ScanArgs {
         no_ignore_dot: false,
         no_ignore_vcs: false,
         treat_doc_strings_as_comments: false,
+        token_model: "heuristic".to_string(),
+        hash_algo: tokmd_types::HashAlgo::Blake3,
+        symlink_policy: Default::default(),
+        line_count_mode: Default::default(),
+        code_comment_markers: Vec::new(),
+        ignore_file: None,
+        include: Vec::new(),
     }
 }
 
@@ -48,6 +55,9 @@ fn sample_totals() -> Totals {
         bytes: 50_000,
         tokens: 12_500,
         avg_lines: 150,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
     }
 }
 
@@ -60,6 +70,10 @@ fn sample_lang_row() -> LangRow {
         bytes: 50_000,
         tokens: 12_500,
         avg_lines: 150,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
+        file_rows: Vec::new(),
     }
 }
 
@@ -90,6 +104,7 @@ fn given_lang_receipt_when_constructed_then_schema_version_matches_constant() {
             children: ChildrenMode::Collapse,
             top: 0,
         },
+        integrity: None,
     };
 
     assert_eq!(receipt.schema_version, SCHEMA_VERSION);
@@ -105,7 +120,7 @@ fn given_lang_receipt_when_roundtripped_then_all_fields_preserved() {
         tool: sample_tool_info(),
         mode: "lang".to_string(),
         status: ScanStatus::Complete,
-        warnings: vec!["test warning".to_string()],
+        warnings: vec!["test warning".into()],
         scan: sample_scan_args(),
         args: LangArgsMeta {
             format: "json".to_string(),
@@ -120,6 +135,7 @@ fn given_lang_receipt_when_roundtripped_then_all_fields_preserved() {
             children: ChildrenMode::Separate,
             top: 5,
         },
+        integrity: None,
     };
 
     let json = serde_json::to_string(&receipt).unwrap();
@@ -127,7 +143,10 @@ fn given_lang_receipt_when_roundtripped_then_all_fields_preserved() {
 
     assert_eq!(back.schema_version, SCHEMA_VERSION);
     assert_eq!(back.generated_at_ms, 1700000000000);
-    assert_eq!(back.warnings, vec!["test warning"]);
+    assert_eq!(
+        back.warnings,
+        vec![tokmd_types::Warning::from("test warning")]
+    );
     assert_eq!(back.args.top, 5);
     assert!(back.args.with_files);
     assert_eq!(back.report.rows[0].lang, "Rust");
@@ -154,6 +173,7 @@ fn given_module_receipt_when_roundtripped_then_module_rows_preserved() {
             module_depth: 2,
             children: ChildIncludeMode::Separate,
             top: 0,
+            collapse_single_child: false,
         },
         report: ModuleReport {
             rows: vec![
@@ -165,6 +185,11 @@ fn given_module_receipt_when_roundtripped_then_module_rows_preserved() {
                     bytes: 20_000,
                     tokens: 5_000,
                     avg_lines: 140,
+                    median_lines: None,
+                    p50_lines: None,
+                    p90_lines: None,
+                    file_rows: Vec::new(),
+                    children: Vec::new(),
                 },
                 ModuleRow {
                     module: "crates/types".to_string(),
@@ -174,6 +199,11 @@ fn given_module_receipt_when_roundtripped_then_module_rows_preserved() {
                     bytes: 12_000,
                     tokens: 3_000,
                     avg_lines: 133,
+                    median_lines: None,
+                    p50_lines: None,
+                    p90_lines: None,
+                    file_rows: Vec::new(),
+                    children: Vec::new(),
                 },
             ],
             total: sample_totals(),
@@ -182,6 +212,7 @@ fn given_module_receipt_when_roundtripped_then_module_rows_preserved() {
             children: ChildIncludeMode::Separate,
             top: 0,
         },
+        integrity: None,
     };
 
     let json = serde_json::to_string(&receipt).unwrap();
@@ -218,6 +249,9 @@ fn given_export_receipt_with_file_rows_when_roundtripped_then_preserved() {
             redact: RedactMode::Paths,
             strip_prefix: Some("/home/user".to_string()),
             strip_prefix_redacted: false,
+            filters: Default::default(),
+            sort_by: None,
+            sort_dir: None,
         },
         data: ExportData {
             rows: vec![FileRow {
@@ -231,11 +265,16 @@ fn given_export_receipt_with_file_rows_when_roundtripped_then_preserved() {
                 lines: 250,
                 bytes: 8_000,
                 tokens: 2_000,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             }],
             module_roots: vec![],
             module_depth: 1,
             children: ChildIncludeMode::ParentsOnly,
         },
+        integrity: None,
+        summary: None,
     };
 
     let json = serde_json::to_string(&receipt).unwrap();
@@ -289,6 +328,13 @@ fn given_scan_args_with_all_flags_true_when_roundtripped_then_preserved() {
         no_ignore_dot: true,
         no_ignore_vcs: true,
         treat_doc_strings_as_comments: true,
+        token_model: "heuristic".to_string(),
+        hash_algo: tokmd_types::HashAlgo::Blake3,
+        symlink_policy: Default::default(),
+        line_count_mode: Default::default(),
+        code_comment_markers: Vec::new(),
+        ignore_file: None,
+        include: Vec::new(),
     };
 
     let json = serde_json::to_string(&args).unwrap();
@@ -526,7 +572,7 @@ fn given_handoff_intelligence_with_all_fields_when_roundtripped_then_preserved()
 #[test]
 fn given_schema_versions_then_each_family_has_independent_version() {
     // Core receipts
-    assert_eq!(SCHEMA_VERSION, 2);
+    assert_eq!(SCHEMA_VERSION, 3);
     // Handoff
     assert_eq!(HANDOFF_SCHEMA_VERSION, 5);
     // Context
@@ -630,6 +676,13 @@ proptest! {
             no_ignore_dot,
             no_ignore_vcs,
             treat_doc_strings_as_comments,
+            token_model: "heuristic".to_string(),
+            hash_algo: tokmd_types::HashAlgo::Blake3,
+            symlink_policy: Default::default(),
+            line_count_mode: Default::default(),
+            code_comment_markers: Vec::new(),
+            ignore_file: None,
+            include: Vec::new(),
         };
 
         let json = serde_json::to_string(&args).unwrap();
@@ -25,6 +25,13 @@ fn make_scan_args() -> ScanArgs {
         no_ignore_dot: false,
         no_ignore_vcs: false,
         treat_doc_strings_as_comments: false,
+        token_model: "heuristic".to_string(),
+        hash_algo: tokmd_types::HashAlgo::Blake3,
+        symlink_policy: Default::default(),
+        line_count_mode: Default::default(),
+        code_comment_markers: Vec::new(),
+        ignore_file: None,
+        include: Vec::new(),
     }
 }
 
@@ -36,6 +43,9 @@ fn make_totals(code: usize) -> Totals {
         bytes: code * 40,
         tokens: code * 10,
         avg_lines: if code > 0 { (code + code / 2) / 5 } else { 0 },
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
     }
 }
 
@@ -48,6 +58,10 @@ fn make_lang_row(name: &str, code: usize) -> LangRow {
         bytes: code * 40,
         tokens: code * 10,
         avg_lines: (code + 50) / 3,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
+        file_rows: Vec::new(),
     }
 }
 
@@ -71,7 +85,7 @@ fn schema_version_constants_are_positive_u32() {
 
 #[test]
 fn schema_version_exact_values() {
-    assert_eq!(SCHEMA_VERSION, 2);
+    assert_eq!(SCHEMA_VERSION, 3);
     assert_eq!(HANDOFF_SCHEMA_VERSION, 5);
     assert_eq!(CONTEXT_BUNDLE_SCHEMA_VERSION, 2);
     assert_eq!(CONTEXT_SCHEMA_VERSION, 4);
@@ -90,7 +104,7 @@ fn lang_receipt_serde_roundtrip() {
         tool: ToolInfo::current(),
         mode: "lang".to_string(),
         status: ScanStatus::Complete,
-        warnings: vec!["test warning".to_string()],
+        warnings: vec!["test warning".into()],
         scan: make_scan_args(),
         args: LangArgsMeta {
             format: "json".to_string(),
@@ -105,6 +119,7 @@ fn lang_receipt_serde_roundtrip() {
             children: ChildrenMode::Collapse,
             top: 10,
         },
+        integrity: None,
     };
 
     let json = serde_json::to_string(&receipt).unwrap();
@@ -137,6 +152,7 @@ fn module_receipt_serde_roundtrip() {
             module_depth: 2,
             children: ChildIncludeMode::Separate,
             top: 5,
+            collapse_single_child: false,
         },
         report: ModuleReport {
             rows: vec![ModuleRow {
@@ -147,6 +163,11 @@ fn module_receipt_serde_roundtrip() {
                 bytes: 25000,
                 tokens: 6250,
                 avg_lines: 233,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
+                children: Vec::new(),
             }],
             total: make_totals(500),
             module_roots: vec!["crates".to_string()],
@@ -154,6 +175,7 @@ fn module_receipt_serde_roundtrip() {
             children: ChildIncludeMode::Separate,
             top: 5,
         },
+        integrity: None,
     };
 
     let json = serde_json::to_string(&receipt).unwrap();
@@ -186,6 +208,9 @@ fn export_receipt_serde_roundtrip() {
             redact: RedactMode::None,
             strip_prefix: None,
             strip_prefix_redacted: false,
+            filters: Default::default(),
+            sort_by: None,
+            sort_dir: None,
         },
         data: ExportData {
             rows: vec![FileRow {
@@ -199,11 +224,16 @@ fn export_receipt_serde_roundtrip() {
                 lines: 130,
                 bytes: 5000,
                 tokens: 1250,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             }],
             module_roots: vec![],
             module_depth: 1,
             children: ChildIncludeMode::ParentsOnly,
         },
+        integrity: None,
+        summary: None,
     };
 
     let json = serde_json::to_string(&receipt).unwrap();
@@ -260,6 +290,7 @@ fn diff_receipt_serde_roundtrip() {
             new_tokens: 2500,
             delta_tokens: 1250,
         },
+        api_surface: None,
     };
 
     let json = serde_json::to_string(&receipt).unwrap();
@@ -403,6 +434,9 @@ fn cockpit_receipt_serde_roundtrip() {
             bus_factor_warnings: vec![],
             level: RiskLevel::Low,
             score: 20,
+            owners_touched: None,
+            unowned_files: vec![],
+            hotspot_owners: Default::default(),
         },
         contracts: Contracts {
             api_changed: false,
@@ -412,6 +446,7 @@ fn cockpit_receipt_serde_roundtrip() {
         },
         evidence: Evidence {
             overall_status: GateStatus::Pass,
+            project_coverage: None,
             mutation: MutationGate {
                 meta: GateMeta {
                     status: GateStatus::Skipped,
@@ -800,6 +835,10 @@ fn lang_row_all_fields_serialize() {
         bytes: 20000,
         tokens: 5000,
         avg_lines: 58,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
+        file_rows: Vec::new(),
     };
     let v: Value = serde_json::to_value(&row).unwrap();
     assert_eq!(v["lang"], "Python");
@@ -825,6 +864,11 @@ fn module_row_all_fields_serialize() {
         bytes: 15000,
         tokens: 3750,
         avg_lines: 80,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
+        file_rows: Vec::new(),
+        children: Vec::new(),
     };
     let v: Value = serde_json::to_value(&row).unwrap();
     assert_eq!(v["module"], "crates/core");
@@ -849,6 +893,9 @@ fn file_row_all_fields_serialize() {
         lines: 250,
         bytes: 8000,
         tokens: 2000,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     };
     let v: Value = serde_json::to_value(&row).unwrap();
     assert_eq!(v["path"], "src/lib.rs");
@@ -876,6 +923,9 @@ fn totals_zero_roundtrip() {
         bytes: 0,
         tokens: 0,
         avg_lines: 0,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
     };
     let json = serde_json::to_string(&t).unwrap();
     let back: Totals = serde_json::from_str(&json).unwrap();
@@ -895,6 +945,9 @@ fn totals_realistic_roundtrip() {
         bytes: 2_000_000,
         tokens: 500_000,
         avg_lines: 375,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
     };
     let json = serde_json::to_string(&t).unwrap();
     let back: Totals = serde_json::from_str(&json).unwrap();
@@ -1010,6 +1063,7 @@ fn empty_lang_receipt() {
             children: ChildrenMode::Collapse,
             top: 0,
         },
+        integrity: None,
     };
     let json = serde_json::to_string(&receipt).unwrap();
     let back: LangReceipt = serde_json::from_str(&json).unwrap();
@@ -1037,6 +1091,9 @@ fn empty_export_receipt() {
             redact: RedactMode::None,
             strip_prefix: None,
             strip_prefix_redacted: false,
+            filters: Default::default(),
+            sort_by: None,
+            sort_dir: None,
         },
         data: ExportData {
             rows: vec![],
@@ -1044,6 +1101,8 @@ fn empty_export_receipt() {
             module_depth: 1,
             children: ChildIncludeMode::Separate,
         },
+        integrity: None,
+        summary: None,
     };
     let json = serde_json::to_string(&receipt).unwrap();
     let back: ExportReceipt = serde_json::from_str(&json).unwrap();
@@ -1063,6 +1122,9 @@ fn very_large_line_counts_roundtrip() {
         bytes: usize::MAX / 2,
         tokens: usize::MAX / 2,
         avg_lines: usize::MAX / 2,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
     };
     let json = serde_json::to_string(&t).unwrap();
     let back: Totals = serde_json::from_str(&json).unwrap();
@@ -1182,6 +1244,7 @@ fn lang_receipt_report_is_flattened() {
             children: ChildrenMode::Collapse,
             top: 0,
         },
+        integrity: None,
     };
     let v: Value = serde_json::to_value(receipt).unwrap();
     assert!(v.get("rows").is_some(), "rows flattened to top level");
@@ -1203,7 +1266,7 @@ proptest! {
         tokens in 0..50_000_000usize,
         avg_lines in 0..10_000usize,
     ) {
-        let t = Totals { code, lines, files, bytes, tokens, avg_lines };
+        let t = Totals { code, lines, files, bytes, tokens, avg_lines, median_lines: None, p50_lines: None, p90_lines: None };
         let json = serde_json::to_string(&t).unwrap();
         let back: Totals = serde_json::from_str(&json).unwrap();
         prop_assert_eq!(back, t);
@@ -1227,6 +1290,10 @@ proptest! {
             bytes,
             tokens,
             avg_lines: avg,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         };
         let json = serde_json::to_string(&row).unwrap();
         let back: LangRow = serde_json::from_str(&json).unwrap();
@@ -1280,6 +1347,9 @@ proptest! {
             lines: code + comments + blanks,
             bytes: (code + comments + blanks) * 40,
             tokens: code * 10,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         };
         let json = serde_json::to_string(&row).unwrap();
         let back: FileRow = serde_json::from_str(&json).unwrap();
@@ -9,8 +9,8 @@ use tokmd_types::*;
 // =============================================================================
 
 #[test]
-fn schema_version_is_2() {
-    assert_eq!(SCHEMA_VERSION, 2);
+fn schema_version_is_3() {
+    assert_eq!(SCHEMA_VERSION, 3);
 }
 
 #[test]
@@ -49,6 +49,13 @@ fn make_scan_args() -> ScanArgs {
         no_ignore_dot: false,
         no_ignore_vcs: false,
         treat_doc_strings_as_comments: false,
+        token_model: "heuristic".to_string(),
+        hash_algo: tokmd_types::HashAlgo::Blake3,
+        symlink_policy: Default::default(),
+        line_count_mode: Default::default(),
+        code_comment_markers: Vec::new(),
+        ignore_file: None,
+        include: Vec::new(),
     }
 }
 
@@ -60,7 +67,7 @@ fn lang_receipt_roundtrip() {
         tool: ToolInfo::current(),
         mode: "lang".to_string(),
         status: ScanStatus::Complete,
-        warnings: vec!["warn1".to_string()],
+        warnings: vec!["warn1".into()],
         scan: make_scan_args(),
         args: LangArgsMeta {
             format: "json".to_string(),
@@ -77,6 +84,10 @@ fn lang_receipt_roundtrip() {
                 bytes: 50000,
                 tokens: 12500,
                 avg_lines: 150,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             }],
             total: Totals {
                 code: 1000,
@@ -85,11 +96,15 @@ fn lang_receipt_roundtrip() {
                 bytes: 50000,
                 tokens: 12500,
                 avg_lines: 150,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
             },
             with_files: true,
             children: ChildrenMode::Collapse,
             top: 10,
         },
+        integrity: None,
     };
 
     let json = serde_json::to_string(&receipt).unwrap();
@@ -126,11 +141,15 @@ fn lang_receipt_json_has_flattened_report() {
                 bytes: 0,
                 tokens: 0,
                 avg_lines: 0,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
             },
             with_files: false,
             children: ChildrenMode::Collapse,
             top: 0,
         },
+        integrity: None,
     };
 
     let v: Value = serde_json::to_value(receipt).unwrap();
@@ -162,6 +181,7 @@ fn module_receipt_roundtrip() {
             module_depth: 2,
             children: ChildIncludeMode::Separate,
             top: 5,
+            collapse_single_child: false,
         },
         report: ModuleReport {
             rows: vec![ModuleRow {
@@ -172,6 +192,11 @@ fn module_receipt_roundtrip() {
                 bytes: 25000,
                 tokens: 6250,
                 avg_lines: 233,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
+                children: Vec::new(),
             }],
             total: Totals {
                 code: 500,
@@ -180,12 +205,16 @@ fn module_receipt_roundtrip() {
                 bytes: 25000,
                 tokens: 6250,
                 avg_lines: 233,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
             },
             module_roots: vec!["src".to_string()],
             module_depth: 2,
             children: ChildIncludeMode::Separate,
             top: 5,
         },
+        integrity: None,
     };
 
     let json = serde_json::to_string(&receipt).unwrap();
@@ -218,6 +247,9 @@ fn export_receipt_roundtrip() {
             redact: RedactMode::None,
             strip_prefix: None,
             strip_prefix_redacted: false,
+            filters: Default::default(),
+            sort_by: None,
+            sort_dir: None,
         },
         data: ExportData {
             rows: vec![FileRow {
@@ -231,11 +263,16 @@ fn export_receipt_roundtrip() {
                 lines: 130,
                 bytes: 5000,
                 tokens: 1250,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             }],
             module_roots: vec![],
             module_depth: 1,
             children: ChildIncludeMode::ParentsOnly,
         },
+        integrity: None,
+        summary: None,
     };
 
     let json = serde_json::to_string(&receipt).unwrap();
@@ -294,6 +331,7 @@ fn diff_receipt_roundtrip() {
             new_tokens: 2500,
             delta_tokens: 1250,
         },
+        api_surface: None,
     };
 
     let json = serde_json::to_string(&receipt).unwrap();
@@ -322,6 +360,13 @@ fn scan_args_excluded_redacted_skips_when_false() {
         no_ignore_dot: false,
         no_ignore_vcs: false,
         treat_doc_strings_as_comments: false,
+        token_model: "heuristic".to_string(),
+        hash_algo: tokmd_types::HashAlgo::Blake3,
+        symlink_policy: Default::default(),
+        line_count_mode: Default::default(),
+        code_comment_markers: Vec::new(),
+        ignore_file: None,
+        include: Vec::new(),
     };
 
     let json = serde_json::to_string(&args).unwrap();
@@ -345,6 +390,13 @@ fn scan_args_excluded_redacted_present_when_true() {
         no_ignore_dot: false,
         no_ignore_vcs: false,
         treat_doc_strings_as_comments: false,
+        token_model: "heuristic".to_string(),
+        hash_algo: tokmd_types::HashAlgo::Blake3,
+        symlink_policy: Default::default(),
+        line_count_mode: Default::default(),
+        code_comment_markers: Vec::new(),
+        ignore_file: None,
+        include: Vec::new(),
     };
 
     let json = serde_json::to_string(&args).unwrap();
@@ -678,6 +730,9 @@ fn cockpit_receipt_full_roundtrip_with_trend() {
             bus_factor_warnings: vec![],
             level: RiskLevel::Low,
             score: 5,
+            owners_touched: None,
+            unowned_files: vec![],
+            hotspot_owners: Default::default(),
         },
         contracts: Contracts {
             api_changed: false,
@@ -687,6 +742,7 @@ fn cockpit_receipt_full_roundtrip_with_trend() {
         },
         evidence: Evidence {
             overall_status: GateStatus::Pass,
+            project_coverage: None,
             mutation: MutationGate {
                 meta: GateMeta {
                     status: GateStatus::Skipped,
@@ -719,6 +775,8 @@ fn cockpit_receipt_full_roundtrip_with_trend() {
             priority: 2,
             complexity: Some(1),
             lines_changed: Some(20),
+            review_minutes: None,
+            owners: None,
         }],
         trend: Some(TrendComparison {
             baseline_available: true,
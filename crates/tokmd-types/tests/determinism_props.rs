@@ -38,6 +38,10 @@ fn arb_lang_row() -> impl Strategy<Value = LangRow> {
                 bytes,
                 tokens,
                 avg_lines,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
         )
 }
@@ -61,6 +65,11 @@ fn arb_module_row() -> impl Strategy<Value = ModuleRow> {
                 bytes,
                 tokens,
                 avg_lines,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
+                children: Vec::new(),
             },
         )
 }
@@ -90,6 +99,9 @@ fn arb_file_row() -> impl Strategy<Value = FileRow> {
                 lines,
                 bytes,
                 tokens,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             },
         )
 }
@@ -429,7 +441,7 @@ proptest! {
 
 #[test]
 fn schema_versions_are_stable() {
-    assert_eq!(SCHEMA_VERSION, 2);
+    assert_eq!(SCHEMA_VERSION, 3);
     assert_eq!(HANDOFF_SCHEMA_VERSION, 5);
     assert_eq!(CONTEXT_BUNDLE_SCHEMA_VERSION, 2);
     assert_eq!(CONTEXT_SCHEMA_VERSION, 4);
@@ -474,7 +486,7 @@ proptest! {
         tokens in 0usize..1_000_000,
         avg_lines in 0usize..1_000,
     ) {
-        let totals = Totals { code, lines, files, bytes, tokens, avg_lines };
+        let totals = Totals { code, lines, files, bytes, tokens, avg_lines, median_lines: None, p50_lines: None, p90_lines: None };
         let json1 = serde_json::to_string(&totals).expect("serialize");
         let back: Totals = serde_json::from_str(&json1).expect("deserialize");
         let json2 = serde_json::to_string(&back).expect("re-serialize");
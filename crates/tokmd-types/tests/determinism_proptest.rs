@@ -37,6 +37,10 @@ fn arb_lang_row() -> impl Strategy<Value = LangRow> {
                 bytes,
                 tokens,
                 avg_lines,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
         )
 }
@@ -64,6 +68,10 @@ fn arb_lang_rows_with_ties() -> impl Strategy<Value = Vec<LangRow>> {
                         bytes,
                         tokens,
                         avg_lines,
+                        median_lines: None,
+                        p50_lines: None,
+                        p90_lines: None,
+                        file_rows: Vec::new(),
                     }
                 }),
             3..30,
@@ -90,6 +98,11 @@ fn arb_module_row() -> impl Strategy<Value = ModuleRow> {
                 bytes,
                 tokens,
                 avg_lines,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
+                children: Vec::new(),
             },
         )
 }
@@ -119,6 +132,9 @@ fn arb_file_row() -> impl Strategy<Value = FileRow> {
                 lines,
                 bytes,
                 tokens,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             },
         )
 }
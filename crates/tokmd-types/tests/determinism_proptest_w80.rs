@@ -82,6 +82,9 @@ proptest! {
     ) {
         let row = FileRow {
             path, module, lang, kind, code, comments, blanks, lines, bytes, tokens,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         };
         let json1 = serde_json::to_string(&row).expect("serialize");
         let back: FileRow = serde_json::from_str(&json1).expect("deserialize");
@@ -101,6 +104,10 @@ proptest! {
     ) {
         let row = LangRow {
             lang, code, lines, files, bytes, tokens, avg_lines,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         };
         let json1 = serde_json::to_string(&row).expect("serialize");
         let back: LangRow = serde_json::from_str(&json1).expect("deserialize");
@@ -120,6 +127,11 @@ proptest! {
     ) {
         let row = ModuleRow {
             module, code, lines, files, bytes, tokens, avg_lines,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
+            children: Vec::new(),
         };
         let json1 = serde_json::to_string(&row).expect("serialize");
         let back: ModuleRow = serde_json::from_str(&json1).expect("deserialize");
@@ -16,6 +16,10 @@ fn sample_lang_row(lang: &str, code: usize) -> LangRow {
         bytes: code * 4,
         tokens: code,
         avg_lines: (code + 50) / 3,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
+        file_rows: Vec::new(),
     }
 }
 
@@ -28,6 +32,11 @@ fn sample_module_row(module: &str, code: usize) -> ModuleRow {
         bytes: code * 4,
         tokens: code,
         avg_lines: (code + 40) / 2,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
+        file_rows: Vec::new(),
+        children: Vec::new(),
     }
 }
 
@@ -43,6 +52,9 @@ fn sample_file_row(path: &str, lang: &str, code: usize) -> FileRow {
         lines: code + 15,
         bytes: code * 4,
         tokens: code,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
@@ -54,6 +66,9 @@ fn sample_totals(code: usize) -> Totals {
         bytes: code * 4,
         tokens: code,
         avg_lines: (code + 100) / 5,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
     }
 }
 
@@ -256,6 +271,7 @@ fn diff_receipt_serialization_is_deterministic() {
             sample_diff_row("Rust", 100, 200),
         ],
         totals: DiffTotals::default(),
+        api_surface: None,
     };
     let json1 = serde_json::to_string(&receipt).unwrap();
     let json2 = serde_json::to_string(&receipt).unwrap();
@@ -306,7 +322,7 @@ fn scan_status_serialization_is_stable() {
 
 #[test]
 fn schema_version_constants_are_pinned() {
-    assert_eq!(SCHEMA_VERSION, 2);
+    assert_eq!(SCHEMA_VERSION, 3);
     assert_eq!(HANDOFF_SCHEMA_VERSION, 5);
     assert_eq!(CONTEXT_SCHEMA_VERSION, 4);
     assert_eq!(CONTEXT_BUNDLE_SCHEMA_VERSION, 2);
@@ -358,6 +374,10 @@ proptest! {
             bytes: code * 4,
             tokens: code,
             avg_lines: lines.checked_div(files).unwrap_or(0),
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         };
         let json1 = serde_json::to_string(&row).unwrap();
         let rt: LangRow = serde_json::from_str(&json1).unwrap();
@@ -445,6 +465,9 @@ proptest! {
             bytes: code * 4,
             tokens: code,
             avg_lines: lines / files,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         };
         let json1 = serde_json::to_string(&t).unwrap();
         let rt: Totals = serde_json::from_str(&json1).unwrap();
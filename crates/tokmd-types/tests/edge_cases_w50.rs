@@ -55,6 +55,10 @@ fn lang_row_u64_max_code_no_overflow() {
         bytes: 0,
         tokens: 0,
         avg_lines: usize::MAX,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
+        file_rows: Vec::new(),
     };
     let json = serde_json::to_string(&row).unwrap();
     let back: LangRow = serde_json::from_str(&json).unwrap();
@@ -72,6 +76,10 @@ fn lang_row_all_zeros() {
         bytes: 0,
         tokens: 0,
         avg_lines: 0,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
+        file_rows: Vec::new(),
     };
     let json = serde_json::to_string(&row).unwrap();
     let back: LangRow = serde_json::from_str(&json).unwrap();
@@ -93,6 +101,9 @@ fn lang_report_empty_rows() {
             bytes: 0,
             tokens: 0,
             avg_lines: 0,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: false,
         children: ChildrenMode::Collapse,
@@ -115,6 +126,9 @@ fn module_report_empty_rows() {
             bytes: 0,
             tokens: 0,
             avg_lines: 0,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         module_roots: vec![],
         module_depth: 1,
@@ -156,6 +170,9 @@ fn file_row_path_with_spaces() {
         lines: 13,
         bytes: 100,
         tokens: 25,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     };
     let json = serde_json::to_string(&row).unwrap();
     let back: FileRow = serde_json::from_str(&json).unwrap();
@@ -175,6 +192,9 @@ fn file_row_path_with_unicode() {
         lines: 5,
         bytes: 50,
         tokens: 12,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     };
     let json = serde_json::to_string(&row).unwrap();
     let back: FileRow = serde_json::from_str(&json).unwrap();
@@ -195,6 +215,9 @@ fn file_row_path_with_dots() {
         lines: 0,
         bytes: 0,
         tokens: 0,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     };
     let json = serde_json::to_string(&row).unwrap();
     let back: FileRow = serde_json::from_str(&json).unwrap();
@@ -287,6 +310,10 @@ fn multiple_lang_rows_serialize_independently() {
         bytes: 4000,
         tokens: 1000,
         avg_lines: 30,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
+        file_rows: Vec::new(),
     };
     let row_b = LangRow {
         lang: "Python".to_string(),
@@ -296,6 +323,10 @@ fn multiple_lang_rows_serialize_independently() {
         bytes: 8000,
         tokens: 2000,
         avg_lines: 30,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
+        file_rows: Vec::new(),
     };
     let json_a = serde_json::to_string(&row_a).unwrap();
     let json_b = serde_json::to_string(&row_b).unwrap();
@@ -317,6 +348,11 @@ fn multiple_module_rows_serialize_independently() {
         bytes: 2000,
         tokens: 500,
         avg_lines: 23,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
+        file_rows: Vec::new(),
+        children: Vec::new(),
     };
     let row_b = ModuleRow {
         module: "crates/bar".to_string(),
@@ -326,6 +362,11 @@ fn multiple_module_rows_serialize_independently() {
         bytes: 3200,
         tokens: 800,
         avg_lines: 25,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
+        file_rows: Vec::new(),
+        children: Vec::new(),
     };
     let json_a = serde_json::to_string(&row_a).unwrap();
     let json_b = serde_json::to_string(&row_b).unwrap();
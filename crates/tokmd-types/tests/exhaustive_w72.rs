@@ -35,6 +35,9 @@ fn totals_construct_and_serialize() {
         bytes: 500,
         tokens: 50,
         avg_lines: 6,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
     };
     let json = serde_json::to_string(&t).unwrap();
     let back: Totals = serde_json::from_str(&json).unwrap();
@@ -51,6 +54,10 @@ fn lang_row_construct_and_serialize() {
         bytes: 1200,
         tokens: 100,
         avg_lines: 30,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
+        file_rows: Vec::new(),
     };
     let json = serde_json::to_string(&r).unwrap();
     let back: LangRow = serde_json::from_str(&json).unwrap();
@@ -67,6 +74,11 @@ fn module_row_construct_and_serialize() {
         bytes: 20000,
         tokens: 5000,
         avg_lines: 140,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
+        file_rows: Vec::new(),
+        children: Vec::new(),
     };
     let json = serde_json::to_string(&r).unwrap();
     let back: ModuleRow = serde_json::from_str(&json).unwrap();
@@ -86,6 +98,9 @@ fn file_row_construct_and_serialize() {
         lines: 65,
         bytes: 2000,
         tokens: 100,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     };
     let json = serde_json::to_string(&r).unwrap();
     let back: FileRow = serde_json::from_str(&json).unwrap();
@@ -156,6 +171,13 @@ fn scan_args_construct_and_serialize() {
         no_ignore_dot: false,
         no_ignore_vcs: false,
         treat_doc_strings_as_comments: false,
+        token_model: "heuristic".to_string(),
+        hash_algo: tokmd_types::HashAlgo::Blake3,
+        symlink_policy: Default::default(),
+        line_count_mode: Default::default(),
+        code_comment_markers: Vec::new(),
+        ignore_file: None,
+        include: Vec::new(),
     };
     let json = serde_json::to_string(&sa).unwrap();
     let back: ScanArgs = serde_json::from_str(&json).unwrap();
@@ -454,6 +476,13 @@ fn make_scan_args() -> ScanArgs {
         no_ignore_dot: false,
         no_ignore_vcs: false,
         treat_doc_strings_as_comments: false,
+        token_model: "heuristic".to_string(),
+        hash_algo: tokmd_types::HashAlgo::Blake3,
+        symlink_policy: Default::default(),
+        line_count_mode: Default::default(),
+        code_comment_markers: Vec::new(),
+        ignore_file: None,
+        include: Vec::new(),
     }
 }
 
@@ -482,11 +511,15 @@ fn lang_receipt_has_schema_and_tool() {
                 bytes: 0,
                 tokens: 0,
                 avg_lines: 0,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
             },
             with_files: false,
             children: ChildrenMode::Collapse,
             top: 0,
         },
+        integrity: None,
     };
     let json = serde_json::to_string(&receipt).unwrap();
     let v: serde_json::Value = serde_json::from_str(&json).unwrap();
@@ -506,6 +539,7 @@ fn diff_receipt_has_schema_and_tool() {
         to_source: "b.json".into(),
         diff_rows: vec![],
         totals: DiffTotals::default(),
+        api_surface: None,
     };
     let json = serde_json::to_string(&receipt).unwrap();
     let v: serde_json::Value = serde_json::from_str(&json).unwrap();
@@ -15,7 +15,7 @@ use tokmd_types::{
 
 #[test]
 fn schema_version_constants_correct() {
-    assert_eq!(SCHEMA_VERSION, 2, "core schema");
+    assert_eq!(SCHEMA_VERSION, 3, "core schema");
     assert_eq!(HANDOFF_SCHEMA_VERSION, 5, "handoff schema");
     assert_eq!(CONTEXT_BUNDLE_SCHEMA_VERSION, 2, "context bundle schema");
     assert_eq!(CONTEXT_SCHEMA_VERSION, 4, "context schema");
@@ -83,6 +83,10 @@ fn lang_row_sort_by_code_desc() {
             bytes: 4000,
             tokens: 1000,
             avg_lines: 75,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         },
         LangRow {
             lang: "Rust".to_string(),
@@ -92,6 +96,10 @@ fn lang_row_sort_by_code_desc() {
             bytes: 20000,
             tokens: 5000,
             avg_lines: 140,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         },
     ];
 
@@ -118,6 +126,9 @@ fn totals_equality() {
         bytes: 4000,
         tokens: 1000,
         avg_lines: 30,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
     };
     let b = a.clone();
     assert_eq!(a, b);
@@ -146,6 +157,9 @@ fn file_row_equality() {
         lines: 130,
         bytes: 4000,
         tokens: 1000,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     };
     let r2 = r1.clone();
     assert_eq!(r1, r2);
@@ -221,6 +235,10 @@ fn lang_row_serde_roundtrip() {
         bytes: 20000,
         tokens: 5000,
         avg_lines: 140,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
+        file_rows: Vec::new(),
     };
     let json = serde_json::to_string(&row).unwrap();
     let back: LangRow = serde_json::from_str(&json).unwrap();
@@ -33,6 +33,9 @@ fn arb_totals() -> impl Strategy<Value = Totals> {
             bytes,
             tokens,
             avg_lines,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         })
 }
 
@@ -55,6 +58,10 @@ fn arb_lang_row() -> impl Strategy<Value = LangRow> {
                 bytes,
                 tokens,
                 avg_lines,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
         )
 }
@@ -78,6 +85,11 @@ fn arb_module_row() -> impl Strategy<Value = ModuleRow> {
                 bytes,
                 tokens,
                 avg_lines,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
+                children: Vec::new(),
             },
         )
 }
@@ -111,6 +123,9 @@ fn arb_file_row() -> impl Strategy<Value = FileRow> {
                 lines,
                 bytes,
                 tokens,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             },
         )
 }
@@ -287,6 +302,9 @@ proptest! {
             bytes: 0,
             tokens: 0,
             avg_lines: 0,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         };
         let json = serde_json::to_string(&zero).expect("serialize");
         let parsed: Totals = serde_json::from_str(&json).expect("deserialize");
@@ -303,6 +321,9 @@ proptest! {
             bytes: 1_000_000_000,
             tokens: 100_000_000,
             avg_lines: 200,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         };
         let json = serde_json::to_string(&large).expect("serialize");
         let parsed: Totals = serde_json::from_str(&json).expect("deserialize");
@@ -327,6 +348,10 @@ proptest! {
             bytes,
             tokens,
             avg_lines,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         };
         let json = serde_json::to_string(&row).expect("serialize");
         let parsed: LangRow = serde_json::from_str(&json).expect("deserialize");
@@ -157,7 +157,7 @@ proptest! {
         tokens in 0usize..10_000_000,
         avg_lines in 0usize..5000,
     ) {
-        let totals = Totals { code, lines, files, bytes, tokens, avg_lines };
+        let totals = Totals { code, lines, files, bytes, tokens, avg_lines, median_lines: None, p50_lines: None, p90_lines: None };
         let json = serde_json::to_string(&totals).unwrap();
         let parsed: Totals = serde_json::from_str(&json).unwrap();
         prop_assert_eq!(totals, parsed);
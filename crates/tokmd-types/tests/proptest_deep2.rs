@@ -274,7 +274,7 @@ proptest! {
         tokens in 0usize..10_000_000,
         avg_lines in 0usize..5000,
     ) {
-        let totals = Totals { code, lines, files, bytes, tokens, avg_lines };
+        let totals = Totals { code, lines, files, bytes, tokens, avg_lines, median_lines: None, p50_lines: None, p90_lines: None };
         let json1 = serde_json::to_string(&totals).unwrap();
         let back: Totals = serde_json::from_str(&json1).unwrap();
         let json2 = serde_json::to_string(&back).unwrap();
@@ -299,7 +299,7 @@ proptest! {
         tokens in 0usize..500_000,
         avg_lines in 0usize..500,
     ) {
-        let row = LangRow { lang, code, lines, files, bytes, tokens, avg_lines };
+        let row = LangRow { lang, code, lines, files, bytes, tokens, avg_lines, median_lines: None, p50_lines: None, p90_lines: None, file_rows: Vec::new() };
         let json1 = serde_json::to_string(&row).unwrap();
         let back: LangRow = serde_json::from_str(&json1).unwrap();
         let json2 = serde_json::to_string(&back).unwrap();
@@ -316,7 +316,7 @@ proptest! {
         tokens in 0usize..500_000,
         avg_lines in 0usize..500,
     ) {
-        let row = ModuleRow { module, code, lines, files, bytes, tokens, avg_lines };
+        let row = ModuleRow { module, code, lines, files, bytes, tokens, avg_lines, median_lines: None, p50_lines: None, p90_lines: None, file_rows: Vec::new(), children: Vec::new() };
         let json1 = serde_json::to_string(&row).unwrap();
         let back: ModuleRow = serde_json::from_str(&json1).unwrap();
         let json2 = serde_json::to_string(&back).unwrap();
@@ -339,6 +339,9 @@ proptest! {
             lines: code + comments + blanks,
             bytes: code * 10,
             tokens: code / 4,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         };
         let json1 = serde_json::to_string(&row).unwrap();
         let back: FileRow = serde_json::from_str(&json1).unwrap();
@@ -37,6 +37,10 @@ fn arb_lang_row() -> impl Strategy<Value = LangRow> {
             bytes,
             tokens,
             avg_lines: avg,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         })
 }
 
@@ -59,6 +63,11 @@ fn arb_module_row() -> impl Strategy<Value = ModuleRow> {
                 bytes,
                 tokens,
                 avg_lines: avg,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
+                children: Vec::new(),
             },
         )
 }
@@ -87,6 +96,9 @@ fn arb_file_row() -> impl Strategy<Value = FileRow> {
             lines: code + comments + blanks,
             bytes: code * 10,
             tokens: code / 4,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         })
 }
 
@@ -213,7 +225,7 @@ proptest! {
         tokens in 0usize..10_000_000,
         avg_lines in 0usize..5000,
     ) {
-        let totals = Totals { code, lines, files, bytes, tokens, avg_lines };
+        let totals = Totals { code, lines, files, bytes, tokens, avg_lines, median_lines: None, p50_lines: None, p90_lines: None };
         let json = serde_json::to_string(&totals).unwrap();
         let parsed: Totals = serde_json::from_str(&json).unwrap();
         prop_assert_eq!(totals, parsed);
@@ -614,6 +626,9 @@ proptest! {
             bytes: rows.iter().map(|r| r.bytes).sum(),
             tokens: rows.iter().map(|r| r.tokens).sum(),
             avg_lines: 0,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         };
         prop_assert_eq!(total.code, expected_code);
         prop_assert_eq!(total.files, expected_files);
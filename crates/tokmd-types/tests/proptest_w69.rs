@@ -29,7 +29,7 @@ proptest! {
         avg_lines in 0usize..5000,
         lang in "[A-Za-z]{1,20}",
     ) {
-        let row = LangRow { lang, code, lines, files, bytes, tokens, avg_lines };
+        let row = LangRow { lang, code, lines, files, bytes, tokens, avg_lines, median_lines: None, p50_lines: None, p90_lines: None, file_rows: Vec::new() };
         let json = serde_json::to_string(&row).unwrap();
         let parsed: LangRow = serde_json::from_str(&json).unwrap();
         prop_assert_eq!(row, parsed);
@@ -53,7 +53,7 @@ proptest! {
         avg_lines in 0usize..5000,
         module in "[a-z/]{1,30}",
     ) {
-        let row = ModuleRow { module, code, lines, files, bytes, tokens, avg_lines };
+        let row = ModuleRow { module, code, lines, files, bytes, tokens, avg_lines, median_lines: None, p50_lines: None, p90_lines: None, file_rows: Vec::new(), children: Vec::new() };
         let json = serde_json::to_string(&row).unwrap();
         let parsed: ModuleRow = serde_json::from_str(&json).unwrap();
         prop_assert_eq!(row, parsed);
@@ -84,6 +84,9 @@ proptest! {
             lang: "Rust".into(),
             kind,
             code, comments, blanks, lines, bytes, tokens,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         };
         let json = serde_json::to_string(&row).unwrap();
         let parsed: FileRow = serde_json::from_str(&json).unwrap();
@@ -112,6 +115,9 @@ proptest! {
             kind: FileKind::Parent,
             code, comments, blanks, lines,
             bytes: 0, tokens: 0,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         };
         prop_assert_eq!(row.lines, row.code + row.comments + row.blanks);
     }
@@ -139,6 +145,9 @@ proptest! {
             code, comments, blanks,
             lines: code + comments + blanks,
             bytes: 0, tokens: 0,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         };
         let json = serde_json::to_string(&row).unwrap();
         let parsed: FileRow = serde_json::from_str(&json).unwrap();
@@ -163,9 +172,9 @@ proptest! {
         c_code in 0usize..100_000,
     ) {
         let rows = vec![
-            LangRow { lang: "Rust".into(), code: a_code, lines: a_code, files: 1, bytes: a_code * 10, tokens: a_code / 4, avg_lines: a_code },
-            LangRow { lang: "Go".into(), code: b_code, lines: b_code, files: 1, bytes: b_code * 10, tokens: b_code / 4, avg_lines: b_code },
-            LangRow { lang: "Python".into(), code: c_code, lines: c_code, files: 1, bytes: c_code * 10, tokens: c_code / 4, avg_lines: c_code },
+            LangRow { lang: "Rust".into(), code: a_code, lines: a_code, files: 1, bytes: a_code * 10, tokens: a_code / 4, avg_lines: a_code, median_lines: None, p50_lines: None, p90_lines: None, file_rows: Vec::new() },
+            LangRow { lang: "Go".into(), code: b_code, lines: b_code, files: 1, bytes: b_code * 10, tokens: b_code / 4, avg_lines: b_code, median_lines: None, p50_lines: None, p90_lines: None, file_rows: Vec::new() },
+            LangRow { lang: "Python".into(), code: c_code, lines: c_code, files: 1, bytes: c_code * 10, tokens: c_code / 4, avg_lines: c_code, median_lines: None, p50_lines: None, p90_lines: None, file_rows: Vec::new() },
         ];
         let total_code: usize = rows.iter().map(|r| r.code).sum();
         let total_lines: usize = rows.iter().map(|r| r.lines).sum();
@@ -179,6 +188,9 @@ proptest! {
             bytes: total_bytes,
             tokens: total_tokens,
             avg_lines: total_lines.checked_div(total_files).unwrap_or(0),
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         };
         let report = LangReport {
             rows: rows.clone(),
@@ -205,8 +217,8 @@ proptest! {
         b_code in 0usize..100_000,
     ) {
         let rows = vec![
-            ModuleRow { module: "crates/a".into(), code: a_code, lines: a_code, files: 1, bytes: a_code * 10, tokens: a_code / 4, avg_lines: a_code },
-            ModuleRow { module: "crates/b".into(), code: b_code, lines: b_code, files: 1, bytes: b_code * 10, tokens: b_code / 4, avg_lines: b_code },
+            ModuleRow { module: "crates/a".into(), code: a_code, lines: a_code, files: 1, bytes: a_code * 10, tokens: a_code / 4, avg_lines: a_code, median_lines: None, p50_lines: None, p90_lines: None, file_rows: Vec::new(), children: Vec::new() },
+            ModuleRow { module: "crates/b".into(), code: b_code, lines: b_code, files: 1, bytes: b_code * 10, tokens: b_code / 4, avg_lines: b_code, median_lines: None, p50_lines: None, p90_lines: None, file_rows: Vec::new(), children: Vec::new() },
         ];
         let total_code: usize = rows.iter().map(|r| r.code).sum();
         let total_lines: usize = rows.iter().map(|r| r.lines).sum();
@@ -220,6 +232,9 @@ proptest! {
             bytes: total_bytes,
             tokens: total_tokens,
             avg_lines: total_lines.checked_div(total_files).unwrap_or(0),
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         };
         let report = ModuleReport {
             rows: rows.clone(),
@@ -246,10 +261,10 @@ proptest! {
         a in 0usize..100_000,
         b in 0usize..100_000,
     ) {
-        let rows = [LangRow { lang: "A".into(), code: a, lines: a, files: 1, bytes: 0, tokens: 0, avg_lines: a },
-            LangRow { lang: "B".into(), code: b, lines: b, files: 1, bytes: 0, tokens: 0, avg_lines: b }];
+        let rows = [LangRow { lang: "A".into(), code: a, lines: a, files: 1, bytes: 0, tokens: 0, avg_lines: a, median_lines: None, p50_lines: None, p90_lines: None, file_rows: Vec::new() },
+            LangRow { lang: "B".into(), code: b, lines: b, files: 1, bytes: 0, tokens: 0, avg_lines: b, median_lines: None, p50_lines: None, p90_lines: None, file_rows: Vec::new() }];
         let total_lines: usize = rows.iter().map(|r| r.lines).sum();
-        let total = Totals { code: a + b, lines: total_lines, files: 2, bytes: 0, tokens: 0, avg_lines: total_lines / 2 };
+        let total = Totals { code: a + b, lines: total_lines, files: 2, bytes: 0, tokens: 0, avg_lines: total_lines / 2, median_lines: None, p50_lines: None, p90_lines: None };
         prop_assert_eq!(total.lines, rows.iter().map(|r| r.lines).sum::<usize>());
     }
 }
@@ -266,9 +281,9 @@ proptest! {
         a_files in 1usize..100,
         b_files in 1usize..100,
     ) {
-        let rows = [ModuleRow { module: "a".into(), code: 100, lines: 100, files: a_files, bytes: 0, tokens: 0, avg_lines: 100 / a_files },
-            ModuleRow { module: "b".into(), code: 200, lines: 200, files: b_files, bytes: 0, tokens: 0, avg_lines: 200 / b_files }];
-        let total = Totals { code: 300, lines: 300, files: a_files + b_files, bytes: 0, tokens: 0, avg_lines: 300 / (a_files + b_files) };
+        let rows = [ModuleRow { module: "a".into(), code: 100, lines: 100, files: a_files, bytes: 0, tokens: 0, avg_lines: 100 / a_files, median_lines: None, p50_lines: None, p90_lines: None, file_rows: Vec::new(), children: Vec::new() },
+            ModuleRow { module: "b".into(), code: 200, lines: 200, files: b_files, bytes: 0, tokens: 0, avg_lines: 200 / b_files, median_lines: None, p50_lines: None, p90_lines: None, file_rows: Vec::new(), children: Vec::new() }];
+        let total = Totals { code: 300, lines: 300, files: a_files + b_files, bytes: 0, tokens: 0, avg_lines: 300 / (a_files + b_files), median_lines: None, p50_lines: None, p90_lines: None };
         prop_assert_eq!(total.files, rows.iter().map(|r| r.files).sum::<usize>());
     }
 }
@@ -282,7 +297,7 @@ proptest! {
 
     #[test]
     fn schema_version_constant_values(_dummy in 0..1u8) {
-        prop_assert_eq!(SCHEMA_VERSION, 2u32);
+        prop_assert_eq!(SCHEMA_VERSION, 3u32);
         prop_assert_eq!(COCKPIT_SCHEMA_VERSION, 3u32);
         prop_assert_eq!(HANDOFF_SCHEMA_VERSION, 5u32);
         prop_assert_eq!(CONTEXT_SCHEMA_VERSION, 4u32);
@@ -302,7 +317,7 @@ proptest! {
         code in 0usize..usize::MAX / 2,
         lines in 0usize..usize::MAX / 2,
     ) {
-        let totals = Totals { code, lines, files: 1, bytes: 0, tokens: 0, avg_lines: lines };
+        let totals = Totals { code, lines, files: 1, bytes: 0, tokens: 0, avg_lines: lines, median_lines: None, p50_lines: None, p90_lines: None };
         let json = serde_json::to_string(&totals).unwrap();
         let parsed: Totals = serde_json::from_str(&json).unwrap();
         prop_assert_eq!(totals, parsed);
@@ -669,6 +684,10 @@ proptest! {
         let row = LangRow {
             lang: "Rust".into(), code, lines, files: 1,
             bytes: code * 4, tokens: code / 4, avg_lines: lines,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         };
         let json = serde_json::to_string(&row).unwrap();
         let v: serde_json::Value = serde_json::from_str(&json).unwrap();
@@ -24,6 +24,9 @@ fn sample_totals() -> Totals {
         bytes: 5000,
         tokens: 1000,
         avg_lines: 30,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
     }
 }
 
@@ -46,6 +49,13 @@ fn sample_scan_args() -> ScanArgs {
         no_ignore_dot: false,
         no_ignore_vcs: false,
         treat_doc_strings_as_comments: false,
+        token_model: "heuristic".to_string(),
+        hash_algo: tokmd_types::HashAlgo::Blake3,
+        symlink_policy: Default::default(),
+        line_count_mode: Default::default(),
+        code_comment_markers: Vec::new(),
+        ignore_file: None,
+        include: Vec::new(),
     }
 }
 
@@ -73,12 +83,17 @@ fn sample_lang_receipt() -> LangReceipt {
                 bytes: 5000,
                 tokens: 1000,
                 avg_lines: 30,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             }],
             total: sample_totals(),
             with_files: true,
             children: ChildrenMode::Collapse,
             top: 0,
         },
+        integrity: None,
     }
 }
 
@@ -97,6 +112,7 @@ fn sample_module_receipt() -> ModuleReceipt {
             module_depth: 1,
             children: ChildIncludeMode::Separate,
             top: 0,
+            collapse_single_child: false,
         },
         report: ModuleReport {
             rows: vec![ModuleRow {
@@ -107,6 +123,11 @@ fn sample_module_receipt() -> ModuleReceipt {
                 bytes: 5000,
                 tokens: 1000,
                 avg_lines: 30,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
+                children: Vec::new(),
             }],
             total: sample_totals(),
             module_roots: vec![],
@@ -114,6 +135,7 @@ fn sample_module_receipt() -> ModuleReceipt {
             children: ChildIncludeMode::Separate,
             top: 0,
         },
+        integrity: None,
     }
 }
 
@@ -136,6 +158,9 @@ fn sample_export_receipt() -> ExportReceipt {
             redact: RedactMode::None,
             strip_prefix: None,
             strip_prefix_redacted: false,
+            filters: Default::default(),
+            sort_by: None,
+            sort_dir: None,
         },
         data: ExportData {
             rows: vec![FileRow {
@@ -149,11 +174,16 @@ fn sample_export_receipt() -> ExportReceipt {
                 lines: 150,
                 bytes: 5000,
                 tokens: 1000,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             }],
             module_roots: vec![],
             module_depth: 1,
             children: ChildIncludeMode::Separate,
         },
+        integrity: None,
+        summary: None,
     }
 }
 
@@ -167,6 +197,7 @@ fn sample_diff_receipt() -> DiffReceipt {
         to_source: "b.json".into(),
         diff_rows: vec![],
         totals: DiffTotals::default(),
+        api_surface: None,
     }
 }
 
@@ -226,7 +257,7 @@ fn context_bundle_schema_version_is_positive() {
 
 #[test]
 fn schema_version_matches_documented_value() {
-    assert_eq!(SCHEMA_VERSION, 2);
+    assert_eq!(SCHEMA_VERSION, 3);
     assert_eq!(COCKPIT_SCHEMA_VERSION, 3);
     assert_eq!(HANDOFF_SCHEMA_VERSION, 5);
     assert_eq!(CONTEXT_SCHEMA_VERSION, 4);
@@ -30,6 +30,13 @@ fn sample_scan_args() -> ScanArgs {
         no_ignore_dot: false,
         no_ignore_vcs: false,
         treat_doc_strings_as_comments: false,
+        token_model: "heuristic".to_string(),
+        hash_algo: tokmd_types::HashAlgo::Blake3,
+        symlink_policy: Default::default(),
+        line_count_mode: Default::default(),
+        code_comment_markers: Vec::new(),
+        ignore_file: None,
+        include: Vec::new(),
     }
 }
 
@@ -41,6 +48,9 @@ fn sample_totals() -> Totals {
         bytes: 4000,
         tokens: 1000,
         avg_lines: 30,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
     }
 }
 
@@ -68,12 +78,17 @@ fn sample_lang_receipt() -> LangReceipt {
                 bytes: 4000,
                 tokens: 1000,
                 avg_lines: 30,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             }],
             total: sample_totals(),
             with_files: false,
             children: ChildrenMode::Collapse,
             top: 0,
         },
+        integrity: None,
     }
 }
 
@@ -92,6 +107,7 @@ fn sample_module_receipt() -> ModuleReceipt {
             module_depth: 2,
             children: ChildIncludeMode::Separate,
             top: 0,
+            collapse_single_child: false,
         },
         report: ModuleReport {
             rows: vec![ModuleRow {
@@ -102,6 +118,11 @@ fn sample_module_receipt() -> ModuleReceipt {
                 bytes: 4000,
                 tokens: 1000,
                 avg_lines: 30,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
+                children: Vec::new(),
             }],
             total: sample_totals(),
             module_roots: vec!["crates".into()],
@@ -109,6 +130,7 @@ fn sample_module_receipt() -> ModuleReceipt {
             children: ChildIncludeMode::Separate,
             top: 0,
         },
+        integrity: None,
     }
 }
 
@@ -131,6 +153,9 @@ fn sample_export_receipt() -> ExportReceipt {
             redact: RedactMode::None,
             strip_prefix: None,
             strip_prefix_redacted: false,
+            filters: Default::default(),
+            sort_by: None,
+            sort_dir: None,
         },
         data: ExportData {
             rows: vec![FileRow {
@@ -144,11 +169,16 @@ fn sample_export_receipt() -> ExportReceipt {
                 lines: 150,
                 bytes: 4000,
                 tokens: 1000,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             }],
             module_roots: vec!["crates".into()],
             module_depth: 2,
             children: ChildIncludeMode::Separate,
         },
+        integrity: None,
+        summary: None,
     }
 }
 
@@ -162,7 +192,7 @@ fn schema_version_is_positive() {
 
 #[test]
 fn schema_version_pinned() {
-    assert_eq!(SCHEMA_VERSION, 2);
+    assert_eq!(SCHEMA_VERSION, 3);
 }
 
 #[test]
@@ -248,6 +278,7 @@ fn diff_receipt_json_roundtrip() {
         to_source: "v2.0".into(),
         diff_rows: vec![],
         totals: DiffTotals::default(),
+        api_surface: None,
     };
     let json = serde_json::to_string(&receipt).unwrap();
     let back: DiffReceipt = serde_json::from_str(&json).unwrap();
@@ -354,6 +385,10 @@ mod properties {
                         bytes: 400,
                         tokens: 100,
                         avg_lines: 15,
+                        median_lines: None,
+                        p50_lines: None,
+                        p90_lines: None,
+                        file_rows: Vec::new(),
                     })
                     .collect();
                 let total = Totals {
@@ -363,6 +398,9 @@ mod properties {
                     bytes: rows.iter().map(|r| r.bytes).sum(),
                     tokens: rows.iter().map(|r| r.tokens).sum(),
                     avg_lines: 15,
+                    median_lines: None,
+                    p50_lines: None,
+                    p90_lines: None,
                 };
                 LangReceipt {
                     schema_version: SCHEMA_VERSION,
@@ -385,6 +423,13 @@ mod properties {
                         no_ignore_dot: false,
                         no_ignore_vcs: false,
                         treat_doc_strings_as_comments: false,
+                        token_model: "heuristic".to_string(),
+                        hash_algo: tokmd_types::HashAlgo::Blake3,
+                        symlink_policy: Default::default(),
+                        line_count_mode: Default::default(),
+                        code_comment_markers: Vec::new(),
+                        ignore_file: None,
+                        include: Vec::new(),
                     },
                     args: LangArgsMeta {
                         format: "json".into(),
@@ -399,6 +444,7 @@ mod properties {
                         children: ChildrenMode::Collapse,
                         top: 0,
                     },
+                    integrity: None,
                 }
             })
     }
@@ -23,6 +23,13 @@ fn make_scan_args() -> ScanArgs {
         no_ignore_dot: false,
         no_ignore_vcs: false,
         treat_doc_strings_as_comments: false,
+        token_model: "heuristic".to_string(),
+        hash_algo: tokmd_types::HashAlgo::Blake3,
+        symlink_policy: Default::default(),
+        line_count_mode: Default::default(),
+        code_comment_markers: Vec::new(),
+        ignore_file: None,
+        include: Vec::new(),
     }
 }
 
@@ -34,6 +41,9 @@ fn make_totals() -> Totals {
         bytes: 4000,
         tokens: 1000,
         avg_lines: 30,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
     }
 }
 
@@ -61,12 +71,17 @@ fn make_lang_receipt() -> LangReceipt {
                 bytes: 4000,
                 tokens: 1000,
                 avg_lines: 30,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             }],
             total: make_totals(),
             with_files: false,
             children: ChildrenMode::Collapse,
             top: 10,
         },
+        integrity: None,
     }
 }
 
@@ -85,6 +100,7 @@ fn make_module_receipt() -> ModuleReceipt {
             module_depth: 1,
             children: ChildIncludeMode::Separate,
             top: 10,
+            collapse_single_child: false,
         },
         report: ModuleReport {
             rows: vec![ModuleRow {
@@ -95,6 +111,11 @@ fn make_module_receipt() -> ModuleReceipt {
                 bytes: 4000,
                 tokens: 1000,
                 avg_lines: 30,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
+                children: Vec::new(),
             }],
             total: make_totals(),
             module_roots: vec![],
@@ -102,6 +123,7 @@ fn make_module_receipt() -> ModuleReceipt {
             children: ChildIncludeMode::Separate,
             top: 10,
         },
+        integrity: None,
     }
 }
 
@@ -124,6 +146,9 @@ fn make_export_receipt() -> ExportReceipt {
             redact: RedactMode::None,
             strip_prefix: None,
             strip_prefix_redacted: false,
+            filters: Default::default(),
+            sort_by: None,
+            sort_dir: None,
         },
         data: ExportData {
             rows: vec![FileRow {
@@ -137,11 +162,16 @@ fn make_export_receipt() -> ExportReceipt {
                 lines: 150,
                 bytes: 4000,
                 tokens: 1000,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             }],
             module_roots: vec![],
             module_depth: 1,
             children: ChildIncludeMode::Separate,
         },
+        integrity: None,
+        summary: None,
     }
 }
 
@@ -155,6 +185,7 @@ fn make_diff_receipt() -> DiffReceipt {
         to_source: "new.json".into(),
         diff_rows: vec![],
         totals: DiffTotals::default(),
+        api_surface: None,
     }
 }
 
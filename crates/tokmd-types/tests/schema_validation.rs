@@ -36,6 +36,13 @@ fn sample_scan_args() -> ScanArgs {
         no_ignore_dot: false,
         no_ignore_vcs: false,
         treat_doc_strings_as_comments: false,
+        token_model: "heuristic".to_string(),
+        hash_algo: tokmd_types::HashAlgo::Blake3,
+        symlink_policy: Default::default(),
+        line_count_mode: Default::default(),
+        code_comment_markers: Vec::new(),
+        ignore_file: None,
+        include: Vec::new(),
     }
 }
 
@@ -47,6 +54,9 @@ fn sample_totals() -> Totals {
         bytes: 50000,
         tokens: 12500,
         avg_lines: 150,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
     }
 }
 
@@ -74,12 +84,17 @@ fn sample_lang_receipt() -> LangReceipt {
                 bytes: 50000,
                 tokens: 12500,
                 avg_lines: 150,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             }],
             total: sample_totals(),
             with_files: false,
             children: ChildrenMode::Collapse,
             top: 0,
         },
+        integrity: None,
     }
 }
 
@@ -98,6 +113,7 @@ fn sample_module_receipt() -> ModuleReceipt {
             module_depth: 1,
             children: ChildIncludeMode::Separate,
             top: 0,
+            collapse_single_child: false,
         },
         report: ModuleReport {
             rows: vec![ModuleRow {
@@ -108,6 +124,11 @@ fn sample_module_receipt() -> ModuleReceipt {
                 bytes: 50000,
                 tokens: 12500,
                 avg_lines: 150,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
+                children: Vec::new(),
             }],
             total: sample_totals(),
             module_roots: vec![],
@@ -115,6 +136,7 @@ fn sample_module_receipt() -> ModuleReceipt {
             children: ChildIncludeMode::Separate,
             top: 0,
         },
+        integrity: None,
     }
 }
 
@@ -137,6 +159,9 @@ fn sample_export_receipt() -> ExportReceipt {
             redact: RedactMode::None,
             strip_prefix: None,
             strip_prefix_redacted: false,
+            filters: Default::default(),
+            sort_by: None,
+            sort_dir: None,
         },
         data: ExportData {
             rows: vec![],
@@ -144,6 +169,8 @@ fn sample_export_receipt() -> ExportReceipt {
             module_depth: 1,
             children: ChildIncludeMode::Separate,
         },
+        integrity: None,
+        summary: None,
     }
 }
 
@@ -157,6 +184,7 @@ fn sample_diff_receipt() -> DiffReceipt {
         to_source: "v2.0.0".to_string(),
         diff_rows: vec![],
         totals: DiffTotals::default(),
+        api_surface: None,
     }
 }
 
@@ -167,7 +195,7 @@ fn sample_diff_receipt() -> DiffReceipt {
 #[test]
 fn schema_version_constants_match_expected_values() {
     assert_eq!(
-        SCHEMA_VERSION, 2,
+        SCHEMA_VERSION, 3,
         "SCHEMA_VERSION changed — update docs/SCHEMA.md and docs/schema.json"
     );
     assert_eq!(
@@ -406,6 +406,9 @@ fn totals_json_deterministic() {
         bytes: 5000,
         tokens: 250,
         avg_lines: 20,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
     };
     let a = serde_json::to_string(&t).unwrap();
     let b = serde_json::to_string(&t).unwrap();
@@ -422,6 +425,10 @@ fn lang_row_json_deterministic() {
         bytes: 3000,
         tokens: 200,
         avg_lines: 30,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
+        file_rows: Vec::new(),
     };
     let a = serde_json::to_string(&r).unwrap();
     let b = serde_json::to_string(&r).unwrap();
@@ -462,6 +469,9 @@ fn file_row_json_deterministic() {
         lines: 65,
         bytes: 2000,
         tokens: 100,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     };
     let a = serde_json::to_string(&r).unwrap();
     let b = serde_json::to_string(&r).unwrap();
@@ -481,6 +491,9 @@ fn roundtrip_totals() {
         bytes: 1500,
         tokens: 200,
         avg_lines: 33,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
     };
     let json = serde_json::to_string(&orig).unwrap();
     let back: Totals = serde_json::from_str(&json).unwrap();
@@ -497,6 +510,11 @@ fn roundtrip_module_row() {
         bytes: 40000,
         tokens: 5000,
         avg_lines: 75,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
+        file_rows: Vec::new(),
+        children: Vec::new(),
     };
     let json = serde_json::to_string(&orig).unwrap();
     let back: ModuleRow = serde_json::from_str(&json).unwrap();
@@ -703,6 +721,10 @@ fn jsonl_lang_rows_parse_line_by_line() {
             bytes: 3000,
             tokens: 200,
             avg_lines: 30,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         },
         LangRow {
             lang: "Python".into(),
@@ -712,6 +734,10 @@ fn jsonl_lang_rows_parse_line_by_line() {
             bytes: 6000,
             tokens: 400,
             avg_lines: 37,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         },
         LangRow {
             lang: "Go".into(),
@@ -721,6 +747,10 @@ fn jsonl_lang_rows_parse_line_by_line() {
             bytes: 1500,
             tokens: 100,
             avg_lines: 35,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         },
     ];
 
@@ -757,6 +787,9 @@ fn jsonl_file_rows_parse_line_by_line() {
             lines: 65,
             bytes: 2000,
             tokens: 100,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         },
         FileRow {
             path: "src/lib.rs".into(),
@@ -769,6 +802,9 @@ fn jsonl_file_rows_parse_line_by_line() {
             lines: 250,
             bytes: 8000,
             tokens: 500,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         },
     ];
 
@@ -801,6 +837,9 @@ fn totals_json_field_values_match() {
         bytes: 1500,
         tokens: 200,
         avg_lines: 33,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
     };
     let v: Value = serde_json::to_value(t).unwrap();
     assert_eq!(v["code"], json!(42));
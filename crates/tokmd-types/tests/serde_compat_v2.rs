@@ -647,6 +647,9 @@ fn file_row_roundtrip_with_file_kind_rename() {
         lines: 130,
         bytes: 5200,
         tokens: 1300,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     };
     let json = serde_json::to_value(&row).unwrap();
     assert_eq!(json["kind"], "parent");
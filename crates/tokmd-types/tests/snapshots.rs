@@ -25,6 +25,10 @@ fn snapshot_lang_row() {
         bytes: 200_000,
         tokens: 50_000,
         avg_lines: 166,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
+        file_rows: Vec::new(),
     };
     insta::assert_json_snapshot!("lang_row", row);
 }
@@ -39,6 +43,11 @@ fn snapshot_module_row() {
         bytes: 48_000,
         tokens: 12_000,
         avg_lines: 225,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
+        file_rows: Vec::new(),
+        children: Vec::new(),
     };
     insta::assert_json_snapshot!("module_row", row);
 }
@@ -56,6 +65,9 @@ fn snapshot_file_row_parent() {
         lines: 390,
         bytes: 12_000,
         tokens: 3_000,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     };
     insta::assert_json_snapshot!("file_row_parent", row);
 }
@@ -73,6 +85,9 @@ fn snapshot_file_row_child() {
         lines: 27,
         bytes: 800,
         tokens: 200,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     };
     insta::assert_json_snapshot!("file_row_child", row);
 }
@@ -86,6 +101,9 @@ fn snapshot_totals() {
         bytes: 500_000,
         tokens: 125_000,
         avg_lines: 150,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
     };
     insta::assert_json_snapshot!("totals", totals);
 }
@@ -372,6 +390,8 @@ fn snapshot_review_item() {
         priority: 1,
         complexity: Some(4),
         lines_changed: Some(150),
+        review_minutes: None,
+        owners: None,
     };
     insta::assert_json_snapshot!("review_item", item);
 }
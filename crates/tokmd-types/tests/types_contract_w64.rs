@@ -24,7 +24,7 @@ fn schema_version_at_least_minimum() {
 
 #[test]
 fn schema_version_exact_values() {
-    assert_eq!(SCHEMA_VERSION, 2);
+    assert_eq!(SCHEMA_VERSION, 3);
     assert_eq!(HANDOFF_SCHEMA_VERSION, 5);
     assert_eq!(CONTEXT_SCHEMA_VERSION, 4);
     assert_eq!(CONTEXT_BUNDLE_SCHEMA_VERSION, 2);
@@ -44,6 +44,9 @@ fn totals_construction() {
         bytes: 4000,
         tokens: 250,
         avg_lines: 30,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
     };
     assert_eq!(t.code, 100);
     assert_eq!(t.lines, 150);
@@ -63,6 +66,10 @@ fn lang_row_construction() {
         bytes: 180_000,
         tokens: 45_000,
         avg_lines: 154,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
+        file_rows: Vec::new(),
     };
     assert_eq!(row.lang, "Rust");
     assert_eq!(row.files, 42);
@@ -78,6 +85,11 @@ fn module_row_construction() {
         bytes: 32_000,
         tokens: 8_000,
         avg_lines: 366,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
+        file_rows: Vec::new(),
+        children: Vec::new(),
     };
     assert_eq!(row.module, "crates/tokmd-types");
     assert_eq!(row.code, 800);
@@ -96,6 +108,9 @@ fn file_row_construction() {
         lines: 170,
         bytes: 4_800,
         tokens: 1_200,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     };
     assert_eq!(row.path, "src/main.rs");
     assert_eq!(row.kind, FileKind::Parent);
@@ -316,6 +331,9 @@ fn totals_serde_roundtrip() {
         bytes: 100_000,
         tokens: 25_000,
         avg_lines: 57,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
     };
     let json = serde_json::to_string(&t).unwrap();
     let back: Totals = serde_json::from_str(&json).unwrap();
@@ -332,6 +350,10 @@ fn lang_row_serde_roundtrip() {
         bytes: 90_000,
         tokens: 30_000,
         avg_lines: 200,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
+        file_rows: Vec::new(),
     };
     let json = serde_json::to_string(&row).unwrap();
     let back: LangRow = serde_json::from_str(&json).unwrap();
@@ -348,6 +370,11 @@ fn module_row_serde_roundtrip() {
         bytes: 16_000,
         tokens: 4_000,
         avg_lines: 75,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
+        file_rows: Vec::new(),
+        children: Vec::new(),
     };
     let json = serde_json::to_string(&row).unwrap();
     let back: ModuleRow = serde_json::from_str(&json).unwrap();
@@ -367,6 +394,9 @@ fn file_row_serde_roundtrip() {
         lines: 380,
         bytes: 12_000,
         tokens: 3_000,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     };
     let json = serde_json::to_string(&row).unwrap();
     let back: FileRow = serde_json::from_str(&json).unwrap();
@@ -424,6 +454,9 @@ fn totals_json_deterministic() {
         bytes: 100,
         tokens: 25,
         avg_lines: 10,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
     };
     let json1 = serde_json::to_string(&t).unwrap();
     let json2 = serde_json::to_string(&t).unwrap();
@@ -440,6 +473,10 @@ fn lang_row_json_field_order_stable() {
         bytes: 10,
         tokens: 3,
         avg_lines: 2,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
+        file_rows: Vec::new(),
     };
     let json = serde_json::to_string(&row).unwrap();
     // "lang" should appear before "code" in serde output (field declaration order)
@@ -629,6 +666,13 @@ fn lang_receipt_envelope_fields() {
             no_ignore_dot: false,
             no_ignore_vcs: false,
             treat_doc_strings_as_comments: false,
+            token_model: "heuristic".to_string(),
+            hash_algo: tokmd_types::HashAlgo::Blake3,
+            symlink_policy: Default::default(),
+            line_count_mode: Default::default(),
+            code_comment_markers: Vec::new(),
+            ignore_file: None,
+            include: Vec::new(),
         },
         args: LangArgsMeta {
             format: "md".to_string(),
@@ -645,11 +689,15 @@ fn lang_receipt_envelope_fields() {
                 bytes: 0,
                 tokens: 0,
                 avg_lines: 0,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
             },
             with_files: false,
             children: ChildrenMode::Collapse,
             top: 0,
         },
+        integrity: None,
     };
     assert_eq!(receipt.schema_version, SCHEMA_VERSION);
     assert_eq!(receipt.mode, "lang");
@@ -782,6 +830,10 @@ fn property_totals_sum_of_lang_rows() {
             bytes: 40000,
             tokens: 10000,
             avg_lines: 150,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         },
         LangRow {
             lang: "Python".to_string(),
@@ -791,6 +843,10 @@ fn property_totals_sum_of_lang_rows() {
             bytes: 15000,
             tokens: 5000,
             avg_lines: 140,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         },
         LangRow {
             lang: "Go".to_string(),
@@ -800,6 +856,10 @@ fn property_totals_sum_of_lang_rows() {
             bytes: 10000,
             tokens: 3000,
             avg_lines: 133,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
+            file_rows: Vec::new(),
         },
     ];
 
@@ -817,6 +877,9 @@ fn property_totals_sum_of_lang_rows() {
         bytes: total_bytes,
         tokens: total_tokens,
         avg_lines: avg,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
     };
 
     assert_eq!(totals.code, 1800);
@@ -888,6 +951,9 @@ fn bdd_given_file_rows_when_summing_then_totals_match() {
             lines: 130,
             bytes: 5000,
             tokens: 1000,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         },
         FileRow {
             path: "src/b.rs".to_string(),
@@ -900,6 +966,9 @@ fn bdd_given_file_rows_when_summing_then_totals_match() {
             lines: 260,
             bytes: 8000,
             tokens: 2000,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         },
     ];
 
@@ -936,6 +1005,9 @@ fn edge_zero_valued_totals() {
         bytes: 0,
         tokens: 0,
         avg_lines: 0,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
     };
     let json = serde_json::to_string(&t).unwrap();
     let back: Totals = serde_json::from_str(&json).unwrap();
@@ -951,6 +1023,9 @@ fn edge_max_usize_values() {
         bytes: usize::MAX,
         tokens: usize::MAX,
         avg_lines: usize::MAX,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
     };
     let json = serde_json::to_string(&t).unwrap();
     let back: Totals = serde_json::from_str(&json).unwrap();
@@ -967,6 +1042,10 @@ fn edge_empty_string_lang() {
         bytes: 0,
         tokens: 0,
         avg_lines: 0,
+        median_lines: None,
+        p50_lines: None,
+        p90_lines: None,
+        file_rows: Vec::new(),
     };
     let json = serde_json::to_string(&row).unwrap();
     let back: LangRow = serde_json::from_str(&json).unwrap();
@@ -986,6 +1065,9 @@ fn edge_unicode_in_path() {
         lines: 13,
         bytes: 500,
         tokens: 100,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     };
     let json = serde_json::to_string(&row).unwrap();
     let back: FileRow = serde_json::from_str(&json).unwrap();
@@ -1039,6 +1121,13 @@ fn edge_empty_warnings_vec() {
             no_ignore_dot: false,
             no_ignore_vcs: false,
             treat_doc_strings_as_comments: false,
+            token_model: "heuristic".to_string(),
+            hash_algo: tokmd_types::HashAlgo::Blake3,
+            symlink_policy: Default::default(),
+            line_count_mode: Default::default(),
+            code_comment_markers: Vec::new(),
+            ignore_file: None,
+            include: Vec::new(),
         },
         args: LangArgsMeta {
             format: "json".to_string(),
@@ -1055,11 +1144,15 @@ fn edge_empty_warnings_vec() {
                 bytes: 0,
                 tokens: 0,
                 avg_lines: 0,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
             },
             with_files: false,
             children: ChildrenMode::Collapse,
             top: 0,
         },
+        integrity: None,
     };
     assert!(receipt.warnings.is_empty());
     let json = serde_json::to_string(&receipt).unwrap();
@@ -1201,6 +1294,9 @@ fn cockpit_receipt_minimal_roundtrip() {
             bus_factor_warnings: vec![],
             level: RiskLevel::Low,
             score: 10,
+            owners_touched: None,
+            unowned_files: vec![],
+            hotspot_owners: Default::default(),
         },
         contracts: Contracts {
             api_changed: false,
@@ -1210,6 +1306,7 @@ fn cockpit_receipt_minimal_roundtrip() {
         },
         evidence: Evidence {
             overall_status: GateStatus::Pass,
+            project_coverage: None,
             mutation: MutationGate {
                 meta: GateMeta {
                     status: GateStatus::Pass,
@@ -1253,6 +1350,8 @@ fn review_item_with_optional_fields() {
         priority: 1,
         complexity: Some(4),
         lines_changed: Some(200),
+        review_minutes: None,
+        owners: None,
     };
     let json = serde_json::to_string(&item).unwrap();
     let back: ReviewItem = serde_json::from_str(&json).unwrap();
@@ -1268,6 +1367,8 @@ fn review_item_without_optional_fields() {
         priority: 3,
         complexity: None,
         lines_changed: None,
+        review_minutes: None,
+        owners: None,
     };
     let json = serde_json::to_string(&item).unwrap();
     assert!(!json.contains("\"complexity\""));
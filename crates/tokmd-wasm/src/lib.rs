@@ -534,7 +534,7 @@ mod tests {
                 .as_array()
                 .expect("warnings array")
                 .iter()
-                .filter_map(Value::as_str)
+                .filter_map(|warning| warning["message"].as_str())
                 .any(|warning| warning.contains("no host root") && warning.contains("file-backed"))
         );
         assert!(
@@ -542,7 +542,7 @@ mod tests {
                 .as_array()
                 .expect("warnings array")
                 .iter()
-                .filter_map(Value::as_str)
+                .filter_map(|warning| warning["message"].as_str())
                 .any(|warning| warning.contains("no host root") && warning.contains("git"))
         );
     }
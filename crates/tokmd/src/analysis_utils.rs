@@ -19,12 +19,15 @@ use tokmd_analysis as analysis;
 use tokmd_analysis_types as analysis_types;
 /// Re-exported from tokmd-core facade to maintain tier boundary compliance.
 /// See ADR-001 for the architectural rationale.
-use tokmd_core::analysis_facade::{RenderedOutput, render};
+use tokmd_core::analysis_facade::{
+    AnalysisRenderOptions, RenderedOutput, render, render_with, write_api_items_jsonl,
+};
 
 pub(crate) fn child_include_to_string(mode: tokmd_types::ChildIncludeMode) -> String {
     match mode {
         tokmd_types::ChildIncludeMode::Separate => "separate".to_string(),
         tokmd_types::ChildIncludeMode::ParentsOnly => "parents-only".to_string(),
+        tokmd_types::ChildIncludeMode::Nested => "nested".to_string(),
     }
 }
 
@@ -38,8 +41,11 @@ pub(crate) fn format_to_string(format: tokmd_types::AnalysisFormat) -> String {
         tokmd_types::AnalysisFormat::Json => "json".to_string(),
         tokmd_types::AnalysisFormat::Jsonld => "jsonld".to_string(),
         tokmd_types::AnalysisFormat::Xml => "xml".to_string(),
+        tokmd_types::AnalysisFormat::Csv => "csv".to_string(),
         tokmd_types::AnalysisFormat::Svg => "svg".to_string(),
+        tokmd_types::AnalysisFormat::Scorecard => "scorecard".to_string(),
         tokmd_types::AnalysisFormat::Mermaid => "mermaid".to_string(),
+        tokmd_types::AnalysisFormat::Gitgraph => "gitgraph".to_string(),
         tokmd_types::AnalysisFormat::Obj => "obj".to_string(),
         tokmd_types::AnalysisFormat::Midi => "midi".to_string(),
         tokmd_types::AnalysisFormat::Tree => "tree".to_string(),
@@ -85,8 +91,11 @@ fn analysis_output_filename(format: tokmd_types::AnalysisFormat) -> &'static str
         tokmd_types::AnalysisFormat::Json => "analysis.json",
         tokmd_types::AnalysisFormat::Jsonld => "analysis.jsonld",
         tokmd_types::AnalysisFormat::Xml => "analysis.xml",
+        tokmd_types::AnalysisFormat::Csv => "analysis.csv",
         tokmd_types::AnalysisFormat::Svg => "analysis.svg",
+        tokmd_types::AnalysisFormat::Scorecard => "analysis.scorecard.svg",
         tokmd_types::AnalysisFormat::Mermaid => "analysis.mmd",
+        tokmd_types::AnalysisFormat::Gitgraph => "analysis.gitgraph.mmd",
         tokmd_types::AnalysisFormat::Obj => "analysis.obj",
         tokmd_types::AnalysisFormat::Midi => "analysis.mid",
         tokmd_types::AnalysisFormat::Tree => "analysis.tree.txt",
@@ -98,8 +107,9 @@ pub(crate) fn write_analysis_output(
     receipt: &analysis_types::AnalysisReceipt,
     output_dir: &Path,
     format: tokmd_types::AnalysisFormat,
+    options: &AnalysisRenderOptions,
 ) -> Result<()> {
-    let rendered = render(receipt, format)?;
+    let rendered = render_with(receipt, format, options)?;
     let out_path = output_dir.join(analysis_output_filename(format));
     match rendered {
         RenderedOutput::Text(text) => {
@@ -112,6 +122,22 @@ pub(crate) fn write_analysis_output(
     Ok(())
 }
 
+/// Write `api_items.jsonl` next to the analysis output when the receipt
+/// carries a detailed API item list.
+pub(crate) fn write_api_items_output(
+    receipt: &analysis_types::AnalysisReceipt,
+    output_dir: &Path,
+) -> Result<()> {
+    let Some(report) = receipt.api_surface.as_ref().filter(|r| r.items.is_some()) else {
+        return Ok(());
+    };
+    let mut out =
+        std::io::BufWriter::new(std::fs::File::create(output_dir.join("api_items.jsonl"))?);
+    write_api_items_jsonl(&mut out, report)?;
+    std::io::Write::flush(&mut out)?;
+    Ok(())
+}
+
 /// Render an analysis receipt and write it to an explicit file path.
 ///
 /// Unlike [`write_analysis_output`], the caller controls the exact file name.
@@ -137,8 +163,9 @@ pub(crate) fn write_analysis_to_path(
 pub(crate) fn write_analysis_stdout(
     receipt: &analysis_types::AnalysisReceipt,
     format: tokmd_types::AnalysisFormat,
+    options: &AnalysisRenderOptions,
 ) -> Result<()> {
-    let rendered = render(receipt, format)?;
+    let rendered = render_with(receipt, format, options)?;
     match rendered {
         RenderedOutput::Text(text) => {
             print!("{}", text);
@@ -178,6 +205,14 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_child_include_to_string_nested() {
+        assert_eq!(
+            child_include_to_string(tokmd_types::ChildIncludeMode::Nested),
+            "nested"
+        );
+    }
+
     #[test]
     fn test_preset_to_string_all_variants() {
         assert_eq!(preset_to_string(cli::AnalysisPreset::Receipt), "receipt");
@@ -207,11 +242,20 @@ mod tests {
             "jsonld"
         );
         assert_eq!(format_to_string(tokmd_types::AnalysisFormat::Xml), "xml");
+        assert_eq!(format_to_string(tokmd_types::AnalysisFormat::Csv), "csv");
         assert_eq!(format_to_string(tokmd_types::AnalysisFormat::Svg), "svg");
+        assert_eq!(
+            format_to_string(tokmd_types::AnalysisFormat::Scorecard),
+            "scorecard"
+        );
         assert_eq!(
             format_to_string(tokmd_types::AnalysisFormat::Mermaid),
             "mermaid"
         );
+        assert_eq!(
+            format_to_string(tokmd_types::AnalysisFormat::Gitgraph),
+            "gitgraph"
+        );
         assert_eq!(format_to_string(tokmd_types::AnalysisFormat::Obj), "obj");
         assert_eq!(format_to_string(tokmd_types::AnalysisFormat::Midi), "midi");
         assert_eq!(format_to_string(tokmd_types::AnalysisFormat::Tree), "tree");
@@ -313,14 +357,26 @@ mod tests {
             analysis_output_filename(tokmd_types::AnalysisFormat::Xml),
             "analysis.xml"
         );
+        assert_eq!(
+            analysis_output_filename(tokmd_types::AnalysisFormat::Csv),
+            "analysis.csv"
+        );
         assert_eq!(
             analysis_output_filename(tokmd_types::AnalysisFormat::Svg),
             "analysis.svg"
         );
+        assert_eq!(
+            analysis_output_filename(tokmd_types::AnalysisFormat::Scorecard),
+            "analysis.scorecard.svg"
+        );
         assert_eq!(
             analysis_output_filename(tokmd_types::AnalysisFormat::Mermaid),
             "analysis.mmd"
         );
+        assert_eq!(
+            analysis_output_filename(tokmd_types::AnalysisFormat::Gitgraph),
+            "analysis.gitgraph.mmd"
+        );
         assert_eq!(
             analysis_output_filename(tokmd_types::AnalysisFormat::Obj),
             "analysis.obj"
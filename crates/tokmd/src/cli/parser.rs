@@ -44,10 +44,13 @@ mod value_enums;
 
 pub use analysis::{
     AnalysisPreset, CliAnalyzeArgs, EffortLayer, EffortModelKind, ImportGranularity, NearDupScope,
+    SvgBadgeMetric, TreemapMetric,
 };
 pub use badge::{BadgeArgs, BadgeMetric};
 pub use check_ignore::CliCheckIgnoreArgs;
-pub use cockpit::{BaselineArgs, CockpitArgs, CockpitFormat, DiffRangeMode};
+pub use cockpit::{
+    AdvisorySeverity, BaselineArgs, CockpitArgs, CockpitFormat, DiffRangeMode, ReviewGroupBy,
+};
 pub use commands::Commands;
 pub use completions::{CompletionsArgs, Shell};
 pub use context::{
@@ -55,7 +58,7 @@ pub use context::{
 };
 pub use diff::{ColorMode, DiffArgs, DiffFormat};
 pub use evidence_packet::EvidencePacketArgs;
-pub use export::CliExportArgs;
+pub use export::{CliExportArgs, CliExportFilters};
 pub use gate::{CliGateArgs, GateFormat};
 pub use global::GlobalArgs;
 pub use init::{InitArgs, InitProfile};
@@ -72,8 +75,8 @@ pub use sensor::{SensorArgs, SensorFormat};
 pub use syntax::SyntaxArgs;
 pub use tools::ToolsArgs;
 pub use value_enums::{
-    AnalysisFormat, ChildIncludeMode, ChildrenMode, ConfigMode, ExportFormat, RedactMode,
-    TableFormat,
+    AnalysisFormat, AvgMeasure, ChildIncludeMode, ChildrenMode, ConfigMode, ExportFormat,
+    ExportSortKey, HashAlgo, LineCountMode, RedactMode, SortDirection, SymlinkPolicy, TableFormat,
 };
 
 /// tokmd — code awareness for AI contexts
@@ -122,7 +125,8 @@ pub struct Cli {
 
 pub use tokmd_settings::{
     AnalyzeConfig, BadgeConfig, ContextConfig, ExportConfig, GateConfig, GateRule, ModuleConfig,
-    Profile, RatchetRuleConfig, ScanConfig, TomlConfig, TomlResult, UserConfig, ViewProfile,
+    PresetConfig, Profile, RatchetRuleConfig, ScanConfig, TomlConfig, TomlResult, UserConfig,
+    ViewProfile,
 };
 
 #[cfg(test)]
@@ -1,8 +1,12 @@
+use std::ffi::OsStr;
 use std::path::PathBuf;
 
+use clap::builder::{PossibleValue, StringValueParser, TypedValueParser};
 use clap::{Args, ValueEnum};
 use serde::{Deserialize, Serialize};
 
+use tokmd_core::analysis_facade::DecimalMetric;
+
 use super::AnalysisFormat;
 
 #[derive(Args, Debug, Clone)]
@@ -14,9 +18,18 @@ pub struct CliAnalyzeArgs {
     #[arg(value_name = "INPUT", default_value = ".")]
     pub inputs: Vec<PathBuf>,
 
-    /// Analysis preset to run [default: receipt].
-    #[arg(long, value_enum)]
-    pub preset: Option<AnalysisPreset>,
+    /// Analysis preset to run: a built-in or a `[preset.<name>]` table from
+    /// tokmd.toml [default: receipt].
+    #[arg(long, value_parser = PresetNameParser)]
+    pub preset: Option<String>,
+
+    /// Compute only these sections of the preset (e.g. dup,complexity,near_dup). Repeatable.
+    #[arg(long, value_name = "SECTION", value_delimiter = ',')]
+    pub include_sections: Vec<String>,
+
+    /// Skip these sections even when the preset computes them (e.g. git,dup). Repeatable.
+    #[arg(long, value_name = "SECTION", value_delimiter = ',')]
+    pub exclude_sections: Vec<String>,
 
     /// Output format [default: md].
     #[arg(long, value_enum)]
@@ -35,6 +48,9 @@ pub struct CliAnalyzeArgs {
     pub no_git: bool,
 
     /// Output directory for analysis artifacts.
+    ///
+    /// `{date}`, `{time}`, `{format}`, and `{repo}` in the path are expanded
+    /// (e.g. `reports/{date}-{repo}`).
     #[arg(long)]
     pub output_dir: Option<PathBuf>,
 
@@ -50,6 +66,10 @@ pub struct CliAnalyzeArgs {
     #[arg(long)]
     pub max_file_bytes: Option<u64>,
 
+    /// Exclude files larger than this many bytes from all metrics; list them as oversized files.
+    #[arg(long)]
+    pub oversized_file_bytes: Option<u64>,
+
     /// Limit how many commits are scanned for git metrics.
     #[arg(long)]
     pub max_commits: Option<usize>,
@@ -58,6 +78,22 @@ pub struct CliAnalyzeArgs {
     #[arg(long)]
     pub max_commit_files: Option<usize>,
 
+    /// Keep at most this many bytes of each commit message for intent classification [default: 4096].
+    #[arg(long)]
+    pub max_commit_message_bytes: Option<usize>,
+
+    /// Strip a leading byte-order mark (transcoding UTF-16 to UTF-8) before content analysis.
+    #[arg(long)]
+    pub strip_bom: bool,
+
+    /// Analyze only this share of files (e.g. 0.1), chosen deterministically by path hash; totals are extrapolated.
+    #[arg(long, value_name = "RATE", value_parser = super::validate::budget_fraction)]
+    pub sample: Option<f64>,
+
+    /// Stop after this many milliseconds of wall-clock time (counting the scan) and emit what is done; skipped phases are listed in a warning.
+    #[arg(long, value_name = "MS")]
+    pub max_runtime_ms: Option<u64>,
+
     /// Import graph granularity [default: module].
     #[arg(long, value_enum)]
     pub granularity: Option<ImportGranularity>,
@@ -94,6 +130,10 @@ pub struct CliAnalyzeArgs {
     #[arg(long)]
     pub detail_functions: bool,
 
+    /// Include the public API item list in the API surface report (writes api_items.jsonl with --output-dir).
+    #[arg(long)]
+    pub detail_api_items: bool,
+
     /// Enable near-duplicate file detection (opt-in).
     #[arg(long)]
     pub near_dup: bool,
@@ -118,11 +158,129 @@ pub struct CliAnalyzeArgs {
     #[arg(long, value_name = "GLOB")]
     pub near_dup_exclude: Vec<String>,
 
+    /// Minimum documented ratio (0.0-1.0) for public API items. Unmet thresholds add a warning.
+    #[arg(long, value_name = "RATIO", value_parser = super::validate::unit_ratio)]
+    pub min_doc_ratio: Option<f64>,
+
+    /// Minimum documented ratio for one language's public API items. Repeatable.
+    #[arg(long, value_name = "LANG=RATIO", value_parser = super::validate::lang_ratio)]
+    pub min_doc_ratio_lang: Vec<(String, f64)>,
+
+    /// Exit nonzero when an API documented-ratio threshold is not met. Needs
+    /// --min-doc-ratio or --min-doc-ratio-lang and the API surface section.
+    #[arg(long)]
+    pub fail_on_doc_ratio: bool,
+
+    /// List source files whose first lines lack this license header (e.g.
+    /// "SPDX-License-Identifier: MIT"). Each line of a multi-line header must appear.
+    #[arg(long, value_name = "TEXT")]
+    pub license_header: Option<String>,
+
+    /// Count commit domains under this parent in the corporate fingerprint
+    /// (e.g. `acme.com` absorbs `eng.acme.com`). Repeatable.
+    #[arg(long, value_name = "DOMAIN")]
+    pub domain_parent: Vec<String>,
+
+    /// Keep this domain as its own corporate-fingerprint row even if it is a
+    /// known personal-mail provider. Repeatable.
+    #[arg(long, value_name = "DOMAIN")]
+    pub domain_allow: Vec<String>,
+
+    /// Leave this domain and its subdomains out of the corporate fingerprint
+    /// (e.g. `gmail.com`). Repeatable.
+    #[arg(long, value_name = "DOMAIN")]
+    pub domain_deny: Vec<String>,
+
+    /// Project each module's predictive churn this many days ahead, with an
+    /// uncertainty band.
+    #[arg(long, value_name = "DAYS", value_parser = clap::value_parser!(u32).range(1..))]
+    pub forecast_days: Option<u32>,
+
+    /// LCOV report (e.g. `coverage/lcov.info`) whose per-file line coverage
+    /// joins complexity and churn in the risk surface.
+    #[arg(long, value_name = "LCOV")]
+    pub coverage: Option<PathBuf>,
+
+    /// Print what changed since the last `--delta` run (files added, removed,
+    /// grown, shrunk) to stderr, then update `.tokmd/last-analysis.json`.
+    #[arg(long)]
+    pub delta: bool,
+
+    /// Record wall-clock milliseconds per analysis phase (scan, complexity, git, ...) in the receipt.
+    #[arg(long)]
+    pub timings: bool,
+
+    /// Embed the full receipt JSON in the HTML report (`<script id="tokmd-receipt">`) for later extraction.
+    #[arg(long)]
+    pub embed_receipt: bool,
+
+    /// Apply the HTML report's print stylesheet on screen too (light theme, page breaks, no search box).
+    #[arg(long)]
+    pub print_layout: bool,
+
+    /// Files listed in the HTML report's table and treemap (0 = all) [default: 100].
+    #[arg(long, value_name = "N")]
+    pub html_max_rows: Option<usize>,
+
+    /// Per-file count that sizes the HTML report's treemap cells [default: lines].
+    #[arg(long, value_enum, value_name = "METRIC")]
+    pub treemap_metric: Option<TreemapMetric>,
+
+    /// Color for one language's HTML treemap cells and badges, e.g. `MyDSL=#ff8800`. Repeatable.
+    #[arg(long, value_name = "LANG=COLOR", value_parser = super::validate::lang_color)]
+    pub lang_color: Vec<(String, String)>,
+
+    /// Fold chains of single-subdirectory directories into one node in the
+    /// `--format tree` output (e.g. `src/a/b/c`).
+    #[arg(long)]
+    pub collapse_single_child: bool,
+
+    /// Append code/comment/blank line counts to every node of the `--format tree` output.
+    #[arg(long)]
+    pub line_breakdown: bool,
+
+    /// Value shown on the `--format svg` badge [default: context-fit, or tokens without a window].
+    #[arg(long, value_enum, value_name = "METRIC")]
+    pub badge_metric: Option<SvgBadgeMetric>,
+
+    /// Decimals shown for one report metric, e.g. `gini=6` or `percent=2`. Repeatable.
+    #[arg(long, value_name = "METRIC=N", value_parser = super::validate::metric_precision)]
+    pub precision: Vec<(DecimalMetric, usize)>,
+
     /// Explain a metric or finding key and exit.
     #[arg(long, value_name = "KEY")]
     pub explain: Option<String>,
 }
 
+/// Parses `--preset` names.
+///
+/// Configured presets are only known once tokmd.toml is loaded, so any name
+/// is accepted here and checked when the preset is resolved; the built-ins
+/// are still listed in help and completions.
+#[derive(Debug, Clone, Copy)]
+pub struct PresetNameParser;
+
+impl TypedValueParser for PresetNameParser {
+    type Value = String;
+
+    fn parse_ref(
+        &self,
+        cmd: &clap::Command,
+        arg: Option<&clap::Arg>,
+        value: &OsStr,
+    ) -> Result<String, clap::Error> {
+        StringValueParser::new().parse_ref(cmd, arg, value)
+    }
+
+    fn possible_values(&self) -> Option<Box<dyn Iterator<Item = PossibleValue> + '_>> {
+        Some(Box::new(
+            AnalysisPreset::value_variants()
+                .iter()
+                .filter_map(ValueEnum::to_possible_value),
+        ))
+    }
+}
+
 #[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 pub enum AnalysisPreset {
@@ -176,6 +334,36 @@ pub enum NearDupScope {
     Global,
 }
 
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum TreemapMetric {
+    /// Total lines.
+    Lines,
+    /// Lines of code.
+    Code,
+    /// Estimated tokens.
+    Tokens,
+    /// File size in bytes.
+    Bytes,
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum SvgBadgeMetric {
+    /// Files scanned.
+    Files,
+    /// Total lines.
+    Lines,
+    /// Lines of code.
+    Code,
+    /// Estimated tokens.
+    Tokens,
+    /// Documentation density.
+    DocDensity,
+    /// Share of the context window used (needs --window).
+    ContextFit,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
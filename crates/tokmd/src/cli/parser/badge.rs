@@ -12,7 +12,7 @@ use super::AnalysisPreset;
 
 #[derive(Args, Debug, Clone)]
 #[command(
-    after_help = "Examples:\n  tokmd badge --metric lines\n  tokmd badge --metric hotspot --preset risk --output badge.svg"
+    after_help = "Examples:\n  tokmd badge --metric lines\n  tokmd badge --metric hotspot --preset risk --output badge.svg\n  tokmd badge --metric doc --target 10 --output docs-badge.svg"
 )]
 pub struct BadgeArgs {
     /// Inputs to analyze (run dir, receipt.json, export.jsonl, or paths).
@@ -23,6 +23,10 @@ pub struct BadgeArgs {
     #[arg(long, value_enum)]
     pub metric: BadgeMetric,
 
+    /// Doc-density target in percent for `--metric doc`: green at or above, red below.
+    #[arg(long, value_name = "PCT")]
+    pub target: Option<f64>,
+
     /// Optional analysis preset to use for the badge.
     #[arg(long, value_enum)]
     pub preset: Option<AnalysisPreset>,
@@ -24,6 +24,14 @@ pub struct CockpitArgs {
     #[arg(long, value_name = "PATH")]
     pub output: Option<PathBuf>,
 
+    /// Also write the JSON receipt to this file, whatever `--format` is.
+    #[arg(long, value_name = "PATH")]
+    pub json_output: Option<PathBuf>,
+
+    /// Also write the Markdown report (as `--format md`) to this file.
+    #[arg(long, value_name = "PATH")]
+    pub md_output: Option<PathBuf>,
+
     /// Write cockpit artifacts (`cockpit.json`, `report.json`, `comment.md`) to directory.
     #[arg(long, value_name = "DIR")]
     pub artifacts_dir: Option<PathBuf>,
@@ -35,10 +43,88 @@ pub struct CockpitArgs {
     /// Path to baseline receipt for trend comparison.
     ///
     /// When provided, cockpit will compute delta metrics showing how
-    /// the current state compares to the baseline.
+    /// the current state compares to the baseline. Without it, cockpit
+    /// compares against its previous run over the same base and head refs,
+    /// cached in `.git/tokmd/cockpit-last.json`.
     #[arg(long, value_name = "PATH")]
     pub baseline: Option<PathBuf>,
 
+    /// Glob excluded from the determinism gate's source hash (repeatable).
+    ///
+    /// Use for generated files that legitimately change between runs, e.g.
+    /// `--determinism-exclude "**/generated/**"`. `.git/**`, `.tokmd/**`, and
+    /// `target/**` are always excluded.
+    #[arg(long = "determinism-exclude", value_name = "GLOB")]
+    pub determinism_exclude: Vec<String>,
+
+    /// Coverage report path or glob checked by the coverage gates (repeatable).
+    ///
+    /// Tried in order before the built-in locations (`coverage/lcov.info`,
+    /// `target/coverage/lcov.info`, `lcov.info`, ...), e.g.
+    /// `--coverage-artifact "target/nextest/coverage/lcov.info"`. Matches are
+    /// read as LCOV whatever their file name.
+    #[arg(long = "coverage-artifact", value_name = "GLOB")]
+    pub coverage_artifact: Vec<String>,
+
+    /// Project line coverage ratio (0.0-1.0) at which the project coverage gate passes.
+    ///
+    /// Defaults to 0.80. The gate totals every file in the coverage report,
+    /// not just the lines the diff touched.
+    #[arg(long, value_name = "RATIO")]
+    pub project_coverage_pass: Option<f64>,
+
+    /// Project line coverage ratio (0.0-1.0) below which the project coverage gate fails.
+    ///
+    /// Defaults to 0.50; coverage between this and the pass ratio warns.
+    #[arg(long, value_name = "RATIO")]
+    pub project_coverage_warn: Option<f64>,
+
+    /// Include the source lines of uncovered diff hunks.
+    ///
+    /// Read from the working tree and capped at 8 lines for each of the first
+    /// 5 hunks. Skipped when the active config redacts paths.
+    #[arg(long)]
+    pub uncovered_snippets: bool,
+
+    /// Glob of changed files the mutation and complexity gates consider (repeatable).
+    ///
+    /// Brings files the built-in rules skip into scope, e.g.
+    /// `--rust-source-include "tests/**"` to gate integration tests. The
+    /// mutation gate only considers `.rs` files.
+    #[arg(long = "rust-source-include", value_name = "GLOB")]
+    pub rust_source_include: Vec<String>,
+
+    /// Glob of changed files the mutation and complexity gates ignore (repeatable).
+    ///
+    /// Applied on top of the built-in rules (test directories, `*_test.*`,
+    /// fuzz targets) and wins over `--rust-source-include`.
+    #[arg(long = "rust-source-exclude", value_name = "GLOB")]
+    pub rust_source_exclude: Vec<String>,
+
+    /// Commits an ancestor's mutation CI artifact may trail HEAD and still be used.
+    ///
+    /// Defaults to 0: only an artifact from HEAD itself counts. In rebasing
+    /// workflows a larger window keeps an ancestor's artifact, marked stale.
+    #[arg(long, value_name = "COMMITS", default_value_t = 0)]
+    pub artifact_staleness_window: usize,
+
+    /// Lowest advisory severity the supply-chain gate reports.
+    ///
+    /// Advisories below it are dropped before the gate status is set, e.g.
+    /// `--supply-chain-min-severity medium` hides low-severity noise.
+    /// Advisories without a severity are always reported.
+    #[arg(long, value_enum, value_name = "LEVEL")]
+    pub supply_chain_min_severity: Option<AdvisorySeverity>,
+
+    /// TOML allowlist of accepted supply-chain advisories.
+    ///
+    /// Each `[[advisory]]` entry has an `id` and optional `reason` and
+    /// `expires` (`YYYY-MM-DD`). Allowlisted advisories are reported as
+    /// acknowledged and do not fail the gate; once an entry expires, its
+    /// advisory fails the gate again.
+    #[arg(long, value_name = "PATH")]
+    pub advisory_allowlist: Option<PathBuf>,
+
     /// Import required proof-run summary evidence into review packets.
     #[arg(long, value_name = "PATH")]
     pub proof_run_summary: Option<PathBuf>,
@@ -63,6 +149,18 @@ pub struct CockpitArgs {
     #[arg(long, value_name = "PATH")]
     pub doc_artifacts_check: Option<PathBuf>,
 
+    /// Group the Markdown review plan into sections with per-group subtotals.
+    #[arg(long, value_enum, value_name = "GROUP")]
+    pub review_group_by: Option<ReviewGroupBy>,
+
+    /// Module roots for `--review-group-by module` (see `tokmd module`).
+    #[arg(long, value_delimiter = ',', requires = "review_group_by")]
+    pub module_roots: Option<Vec<String>>,
+
+    /// Module depth for `--review-group-by module` (see `tokmd module`).
+    #[arg(long, requires = "review_group_by")]
+    pub module_depth: Option<usize>,
+
     /// Diff range syntax: two-dot (default) or three-dot.
     #[arg(long, value_enum, default_value_t = DiffRangeMode::TwoDot)]
     pub diff_range: DiffRangeMode,
@@ -110,6 +208,24 @@ pub enum CockpitFormat {
     Comment,
     /// Section-based output for PR template filling.
     Sections,
+    /// JSON Lines: one record per gate plus a summary, for log ingestion.
+    Jsonl,
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ReviewGroupBy {
+    /// Module key of each path, as in `tokmd module`.
+    Module,
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum AdvisorySeverity {
+    Low,
+    Medium,
+    High,
+    Critical,
 }
 
 #[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
@@ -7,7 +7,7 @@ use std::path::PathBuf;
 
 use clap::Args;
 
-use super::{ChildIncludeMode, ExportFormat, RedactMode};
+use super::{ChildIncludeMode, ExportFormat, ExportSortKey, RedactMode, SortDirection};
 
 #[derive(Args, Debug, Clone)]
 #[command(
@@ -23,6 +23,9 @@ pub struct CliExportArgs {
     pub format: Option<ExportFormat>,
 
     /// Write output to this file instead of stdout.
+    ///
+    /// `{date}`, `{time}`, `{format}`, and `{repo}` in the path are expanded
+    /// (e.g. `reports/{date}-{repo}.json`); missing directories are created.
     #[arg(long, value_name = "PATH", visible_alias = "out")]
     pub output: Option<PathBuf>,
 
@@ -42,6 +45,17 @@ pub struct CliExportArgs {
     #[arg(long)]
     pub min_code: Option<usize>,
 
+    #[command(flatten)]
+    pub filters: CliExportFilters,
+
+    /// Sort rows by this column before `--max-rows` [default: code].
+    #[arg(long, value_enum, value_name = "KEY")]
+    pub sort_by: Option<ExportSortKey>,
+
+    /// Sort direction [default: desc for metrics, asc for path/module/lang].
+    #[arg(long, value_enum, value_name = "DIR")]
+    pub sort_dir: Option<SortDirection>,
+
     /// Stop after emitting N rows (0 = unlimited) [default: 0].
     #[arg(long)]
     pub max_rows: Option<usize>,
@@ -50,6 +64,10 @@ pub struct CliExportArgs {
     #[arg(long, action = clap::ArgAction::Set)]
     pub meta: Option<bool>,
 
+    /// Attach summary stats (totals, per-language and per-extension counts, percentiles) to the meta record.
+    #[arg(long)]
+    pub summary: bool,
+
     /// Redact paths (and optionally module names) for safer copy/paste into LLMs [default: none].
     #[arg(long, value_enum)]
     pub redact: Option<RedactMode>,
@@ -57,4 +75,59 @@ pub struct CliExportArgs {
     /// Strip this prefix from paths before output (helps when paths are absolute).
     #[arg(long, value_name = "PATH")]
     pub strip_prefix: Option<PathBuf>,
+
+    /// Add `last_modified_ms` and `age_days` to each row from the newest git commit touching the file.
+    #[arg(long)]
+    pub git_age: bool,
+
+    /// Add a `role` to each row: source, test, generated, or vendored, judged from the path.
+    #[arg(long)]
+    pub classify: bool,
+}
+
+/// Per-metric row bounds for `tokmd export`. Bounds are inclusive and combine
+/// with `--min-code`; rows must satisfy all of them.
+#[derive(Args, Debug, Clone, Copy, Default)]
+pub struct CliExportFilters {
+    /// Drop rows with more than N code lines.
+    #[arg(long, value_name = "N")]
+    pub max_code: Option<usize>,
+
+    /// Drop rows with fewer than N total lines.
+    #[arg(long, value_name = "N")]
+    pub min_lines: Option<usize>,
+
+    /// Drop rows with more than N total lines.
+    #[arg(long, value_name = "N")]
+    pub max_lines: Option<usize>,
+
+    /// Drop rows smaller than N bytes.
+    #[arg(long, value_name = "N")]
+    pub min_bytes: Option<usize>,
+
+    /// Drop rows larger than N bytes.
+    #[arg(long, value_name = "N")]
+    pub max_bytes: Option<usize>,
+
+    /// Drop rows with fewer than N estimated tokens.
+    #[arg(long, value_name = "N")]
+    pub min_tokens: Option<usize>,
+
+    /// Drop rows with more than N estimated tokens.
+    #[arg(long, value_name = "N")]
+    pub max_tokens: Option<usize>,
+}
+
+impl From<CliExportFilters> for tokmd_types::ExportFilters {
+    fn from(value: CliExportFilters) -> Self {
+        Self {
+            max_code: value.max_code,
+            min_lines: value.min_lines,
+            max_lines: value.max_lines,
+            min_bytes: value.min_bytes,
+            max_bytes: value.max_bytes,
+            min_tokens: value.min_tokens,
+            max_tokens: value.max_tokens,
+        }
+    }
 }
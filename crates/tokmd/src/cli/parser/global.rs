@@ -5,7 +5,7 @@
 
 use clap::Args;
 
-use super::ConfigMode;
+use super::{ConfigMode, HashAlgo, LineCountMode, SymlinkPolicy};
 
 #[derive(Args, Debug, Clone, Default)]
 pub struct GlobalArgs {
@@ -40,7 +40,7 @@ pub struct GlobalArgs {
     #[arg(long)]
     pub no_ignore_parent: bool,
 
-    /// Don't respect .ignore and .tokeignore files (including in parent directories).
+    /// Don't respect .ignore, .tokeignore and .tokmdignore files (including in parent directories).
     #[arg(long)]
     pub no_ignore_dot: bool,
 
@@ -52,12 +52,62 @@ pub struct GlobalArgs {
     #[arg(long)]
     pub treat_doc_strings_as_comments: bool,
 
+    /// Token model name recorded in receipts [default: heuristic].
+    #[arg(long, value_name = "NAME")]
+    pub token_model: Option<String>,
+
+    /// Digest for receipt integrity and determinism hashes.
+    #[arg(long, value_enum, value_name = "ALGO", default_value_t = HashAlgo::Blake3)]
+    pub hash_algo: HashAlgo,
+
+    /// How to treat symlinked directories while scanning.
+    #[arg(long, value_enum, value_name = "POLICY", default_value_t = SymlinkPolicy::Ignore)]
+    pub symlink_policy: SymlinkPolicy,
+
+    /// Count physical lines or logical statements for the code metric.
+    #[arg(long, value_enum, value_name = "MODE", default_value_t = LineCountMode::Physical)]
+    pub line_count_mode: LineCountMode,
+
+    /// Count comment lines starting with this marker as code. Repeatable.
+    ///
+    /// Examples:
+    ///   --code-comment-marker "//go:generate"
+    ///   --code-comment-marker "# noqa"
+    #[arg(long = "code-comment-marker", value_name = "MARKER")]
+    pub code_comment_markers: Vec<String>,
+
+    /// Also read ignore patterns from a file with this name at each scan root.
+    ///
+    /// Read in addition to `.tokmdignore`, which is always honored, e.g.
+    /// `--ignore-file .locignore`.
+    #[arg(long, value_name = "NAME")]
+    pub ignore_file: Option<String>,
+
+    /// Only count files matching these patterns (gitignore syntax). Repeatable.
+    ///
+    /// Ignore files and --exclude still apply. Patterns are matched against
+    /// paths relative to each scan root.
+    ///
+    /// Examples:
+    ///   --include "src/**/*.rs"
+    ///   --include "*.py"
+    #[arg(long = "include", value_name = "PATTERN")]
+    pub include: Vec<String>,
+
+    /// Reuse counts of unchanged files from `.tokmd/cache/scan/`.
+    ///
+    /// Files whose modification time and size match the cache skip
+    /// re-counting; the cache is rewritten after each scan and discarded when
+    /// the tokmd version changes.
+    #[arg(long)]
+    pub scan_cache: bool,
+
     /// Verbose output (repeat for more detail).
     #[arg(short = 'v', long = "verbose", action = clap::ArgAction::Count)]
     pub verbose: u8,
 
     /// Disable progress spinners.
-    #[arg(long, global = true)]
+    #[arg(long, global = true, visible_alias = "quiet")]
     pub no_progress: bool,
 }
 
@@ -72,6 +122,14 @@ impl From<&GlobalArgs> for tokmd_settings::ScanOptions {
             no_ignore_dot: g.no_ignore_dot,
             no_ignore_vcs: g.no_ignore_vcs,
             treat_doc_strings_as_comments: g.treat_doc_strings_as_comments,
+            token_model: g.token_model.clone(),
+            hash_algo: g.hash_algo.into(),
+            symlink_policy: g.symlink_policy.into(),
+            line_count_mode: g.line_count_mode.into(),
+            code_comment_markers: g.code_comment_markers.clone(),
+            ignore_file: g.ignore_file.clone(),
+            include: g.include.clone(),
+            scan_cache: g.scan_cache.then(|| tokmd_scan::SCAN_CACHE_DIR.to_string()),
         }
     }
 }
@@ -109,6 +167,14 @@ mod tests {
             treat_doc_strings_as_comments: true,
             verbose: 0,
             no_progress: false,
+            token_model: None,
+            hash_algo: HashAlgo::Blake3,
+            symlink_policy: SymlinkPolicy::Ignore,
+            line_count_mode: LineCountMode::Physical,
+            code_comment_markers: vec![],
+            ignore_file: None,
+            include: vec![],
+            scan_cache: false,
         };
         let opts: tokmd_settings::ScanOptions = (&g).into();
         assert_eq!(opts.excluded, vec!["target"]);
@@ -130,4 +196,81 @@ mod tests {
         assert_eq!(opts.excluded, vec!["vendor"]);
         assert!(!opts.hidden);
     }
+
+    #[test]
+    fn global_args_hash_algo_reaches_scan_options() {
+        let g = GlobalArgs {
+            hash_algo: HashAlgo::Sha256,
+            ..GlobalArgs::default()
+        };
+        let opts: tokmd_settings::ScanOptions = g.into();
+        assert_eq!(opts.hash_algo, tokmd_types::HashAlgo::Sha256);
+        assert_eq!(
+            tokmd_settings::ScanOptions::from(GlobalArgs::default()).hash_algo,
+            tokmd_types::HashAlgo::Blake3
+        );
+    }
+
+    #[test]
+    fn global_args_symlink_policy_reaches_scan_options() {
+        let g = GlobalArgs {
+            symlink_policy: SymlinkPolicy::FollowNoLoop,
+            ..GlobalArgs::default()
+        };
+        let opts: tokmd_settings::ScanOptions = g.into();
+        assert_eq!(
+            opts.symlink_policy,
+            tokmd_types::SymlinkPolicy::FollowNoLoop
+        );
+        assert!(
+            tokmd_settings::ScanOptions::from(GlobalArgs::default())
+                .symlink_policy
+                .is_default()
+        );
+    }
+
+    #[test]
+    fn global_args_line_count_mode_reaches_scan_options() {
+        let g = GlobalArgs {
+            line_count_mode: LineCountMode::Logical,
+            ..GlobalArgs::default()
+        };
+        let opts: tokmd_settings::ScanOptions = g.into();
+        assert_eq!(opts.line_count_mode, tokmd_types::LineCountMode::Logical);
+        assert!(
+            tokmd_settings::ScanOptions::from(GlobalArgs::default())
+                .line_count_mode
+                .is_default()
+        );
+    }
+
+    #[test]
+    fn global_args_code_comment_markers_reach_scan_options() {
+        let g = GlobalArgs {
+            code_comment_markers: vec!["# noqa".into()],
+            ..GlobalArgs::default()
+        };
+        let opts: tokmd_settings::ScanOptions = g.into();
+        assert_eq!(opts.code_comment_markers, vec!["# noqa"]);
+    }
+
+    #[test]
+    fn global_args_ignore_file_reaches_scan_options() {
+        let g = GlobalArgs {
+            ignore_file: Some(".locignore".into()),
+            ..GlobalArgs::default()
+        };
+        let opts: tokmd_settings::ScanOptions = g.into();
+        assert_eq!(opts.ignore_file.as_deref(), Some(".locignore"));
+    }
+
+    #[test]
+    fn global_args_include_reaches_scan_options() {
+        let g = GlobalArgs {
+            include: vec!["src/**/*.rs".into()],
+            ..GlobalArgs::default()
+        };
+        let opts: tokmd_settings::ScanOptions = g.into();
+        assert_eq!(opts.include, vec!["src/**/*.rs"]);
+    }
 }
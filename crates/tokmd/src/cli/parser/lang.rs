@@ -7,7 +7,7 @@ use std::path::PathBuf;
 
 use clap::Args;
 
-use super::{ChildrenMode, TableFormat};
+use super::{AvgMeasure, ChildrenMode, TableFormat};
 
 #[derive(Args, Debug, Clone, Default)]
 #[command(
@@ -34,4 +34,22 @@ pub struct CliLangArgs {
     /// How to handle embedded languages (tokei "children" / blobs) [default: collapse].
     #[arg(long, value_enum)]
     pub children: Option<ChildrenMode>,
+
+    /// Per-file line measure shown with `--files` [default: mean].
+    #[arg(long, value_enum)]
+    pub avg: Option<AvgMeasure>,
+
+    /// Add p50/p90 lines-per-file columns (shown with `--files`).
+    #[arg(long)]
+    pub percentiles: bool,
+
+    /// Embed each language's contributing file rows in the JSON receipt (large; JSON only).
+    #[arg(long)]
+    pub with_files_detail: bool,
+
+    /// Also append a dated totals row (files, code, comments, lines, tokens, bytes, doc%)
+    /// to this history file. Tab-separated unless the path ends in `.csv`; the header is
+    /// written only when the file is new.
+    #[arg(long, value_name = "PATH")]
+    pub append_history: Option<PathBuf>,
 }
@@ -7,7 +7,7 @@ use std::path::PathBuf;
 
 use clap::Args;
 
-use super::{ChildIncludeMode, TableFormat};
+use super::{AvgMeasure, ChildIncludeMode, TableFormat};
 
 #[derive(Args, Debug, Clone)]
 #[command(
@@ -31,6 +31,8 @@ pub struct CliModuleArgs {
     ///
     /// If a file path starts with one of these roots, the module key will include
     /// `module_depth` segments. Otherwise, the module key is the top-level directory.
+    /// Write a root as `name=depth` (e.g. `crates=2,apps=1`) to override the depth for that root.
+    /// Roots may be globs over leading directories (e.g. `packages/*/src`); the deepest match wins.
     #[arg(long, value_delimiter = ',')]
     pub module_roots: Option<Vec<String>>,
 
@@ -45,4 +47,21 @@ pub struct CliModuleArgs {
     /// Whether to include embedded languages (tokei "children" / blobs) in module totals [default: separate].
     #[arg(long, value_enum)]
     pub children: Option<ChildIncludeMode>,
+
+    /// Relabel each module with the deepest single-child directory chain its files share
+    /// (e.g. `src` becomes `src/a/b/c` when every file lives under `src/a/b/c`).
+    #[arg(long)]
+    pub collapse_single_child: bool,
+
+    /// Per-file line measure shown in the table [default: mean].
+    #[arg(long, value_enum)]
+    pub avg: Option<AvgMeasure>,
+
+    /// Add p50/p90 lines-per-file columns.
+    #[arg(long)]
+    pub percentiles: bool,
+
+    /// Embed each module's contributing file rows in the JSON receipt (large; JSON only).
+    #[arg(long)]
+    pub with_files_detail: bool,
 }
@@ -23,6 +23,9 @@ pub struct RunArgs {
     pub output_dir: Option<PathBuf>,
 
     /// Tag or name for this run.
+    ///
+    /// Without one the run directory is named `run-<ms>` from the wall clock,
+    /// even when `SOURCE_DATE_EPOCH` pins receipt timestamps.
     #[arg(long)]
     pub name: Option<String>,
 
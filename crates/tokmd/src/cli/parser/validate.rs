@@ -6,11 +6,13 @@
 //! all. Validating at parse time turns those silent footguns into a clear,
 //! actionable error before any work begins.
 
+use tokmd_core::analysis_facade::DecimalMetric;
+
 /// Parse a budget fraction in the half-open range `(0.0, 1.0]`.
 ///
-/// Used by `--max-file-pct`. A non-finite value, a value at or below `0.0`, or a
-/// value above `1.0` is rejected so the caller learns immediately instead of
-/// receiving an empty or nonsensical selection.
+/// Used by `--max-file-pct` and `--sample`. A non-finite value, a value at or
+/// below `0.0`, or a value above `1.0` is rejected so the caller learns
+/// immediately instead of receiving an empty or nonsensical selection.
 pub(crate) fn budget_fraction(raw: &str) -> Result<f64, String> {
     let value: f64 = raw
         .parse()
@@ -23,6 +25,91 @@ pub(crate) fn budget_fraction(raw: &str) -> Result<f64, String> {
     Ok(value)
 }
 
+/// Parse a ratio in the closed range `[0.0, 1.0]`.
+///
+/// Used by `--min-doc-ratio`, where `0.0` (accept anything) and `1.0` (every
+/// public item documented) are both meaningful thresholds.
+pub(crate) fn unit_ratio(raw: &str) -> Result<f64, String> {
+    let value: f64 = raw
+        .parse()
+        .map_err(|_| format!("`{raw}` is not a valid number"))?;
+    if !value.is_finite() || !(0.0..=1.0).contains(&value) {
+        return Err(format!(
+            "`{raw}` is out of range; expected a ratio between 0.0 and 1.0"
+        ));
+    }
+    Ok(value)
+}
+
+/// Parse a `LANG=RATIO` pair, validating the ratio like [`unit_ratio`].
+///
+/// Used by `--min-doc-ratio-lang`.
+pub(crate) fn lang_ratio(raw: &str) -> Result<(String, f64), String> {
+    let (lang, ratio) = raw
+        .split_once('=')
+        .ok_or_else(|| format!("`{raw}` is not in LANG=RATIO form"))?;
+    let lang = lang.trim();
+    if lang.is_empty() {
+        return Err(format!("`{raw}` is missing a language name"));
+    }
+    Ok((lang.to_string(), unit_ratio(ratio.trim())?))
+}
+
+/// Parse a `LANG=COLOR` pair for the HTML report's language colors.
+///
+/// Used by `--lang-color`. The color must be `#` with 3, 4, 6, or 8 hex
+/// digits, or a CSS color name such as `teal`; the renderer drops anything
+/// else, so it is rejected here instead of being silently ignored.
+pub(crate) fn lang_color(raw: &str) -> Result<(String, String), String> {
+    let (lang, color) = raw
+        .split_once('=')
+        .ok_or_else(|| format!("`{raw}` is not in LANG=COLOR form"))?;
+    let (lang, color) = (lang.trim(), color.trim());
+    if lang.is_empty() {
+        return Err(format!("`{raw}` is missing a language name"));
+    }
+    let valid = match color.strip_prefix('#') {
+        Some(hex) => {
+            matches!(hex.len(), 3 | 4 | 6 | 8) && hex.chars().all(|c| c.is_ascii_hexdigit())
+        }
+        None => (1..=32).contains(&color.len()) && color.chars().all(|c| c.is_ascii_alphabetic()),
+    };
+    if !valid {
+        return Err(format!(
+            "`{color}` is not a color; expected #rgb, #rrggbb, #rrggbbaa, or a name like teal"
+        ));
+    }
+    Ok((lang.to_string(), color.to_string()))
+}
+
+/// Most decimals `--precision` accepts.
+const MAX_PRECISION: usize = 12;
+
+/// Parse a `METRIC=N` pair for per-metric display precision.
+///
+/// Used by `--precision`. Unknown metric names are rejected with the list of
+/// valid ones, and `N` is capped at 12 since `f64` carries no more meaningful
+/// digits for report-sized values.
+pub(crate) fn metric_precision(raw: &str) -> Result<(DecimalMetric, usize), String> {
+    let (metric, decimals) = raw
+        .split_once('=')
+        .ok_or_else(|| format!("`{raw}` is not in METRIC=N form"))?;
+    let metric = metric.trim().parse::<DecimalMetric>().map_err(|err| {
+        let names: Vec<_> = DecimalMetric::ALL.iter().map(|m| m.as_str()).collect();
+        format!("{err}; expected one of: {}", names.join(", "))
+    })?;
+    let decimals = decimals.trim();
+    let decimals: usize = decimals
+        .parse()
+        .map_err(|_| format!("`{decimals}` is not a valid whole number"))?;
+    if decimals > MAX_PRECISION {
+        return Err(format!(
+            "`{decimals}` is out of range; expected at most {MAX_PRECISION} decimals"
+        ));
+    }
+    Ok((metric, decimals))
+}
+
 /// Parse a count that must be at least `1`.
 ///
 /// Used by flags such as `--max-commits`, `--max-commit-files`, and
@@ -62,6 +149,54 @@ mod tests {
         assert!(budget_fraction("abc").is_err());
     }
 
+    #[test]
+    fn unit_ratio_accepts_closed_range() {
+        assert_eq!(unit_ratio("0").unwrap(), 0.0);
+        assert_eq!(unit_ratio("0.8").unwrap(), 0.8);
+        assert_eq!(unit_ratio("1").unwrap(), 1.0);
+        assert!(unit_ratio("1.01").is_err());
+        assert!(unit_ratio("-0.1").is_err());
+        assert!(unit_ratio("NaN").is_err());
+    }
+
+    #[test]
+    fn lang_ratio_parses_pairs() {
+        assert_eq!(lang_ratio("Rust=0.9").unwrap(), ("Rust".to_string(), 0.9));
+        assert!(lang_ratio("Rust").is_err());
+        assert!(lang_ratio("=0.5").is_err());
+        assert!(lang_ratio("Rust=2").is_err());
+    }
+
+    #[test]
+    fn lang_color_parses_pairs() {
+        assert_eq!(
+            lang_color("My DSL = #ff8800").unwrap(),
+            ("My DSL".to_string(), "#ff8800".to_string())
+        );
+        assert_eq!(lang_color("Rust=teal").unwrap().1, "teal");
+        assert!(lang_color("Rust").is_err());
+        assert!(lang_color("=#fff").is_err());
+        assert!(lang_color("Rust=#ff88").is_ok());
+        assert!(lang_color("Rust=#ff8").is_ok());
+        assert!(lang_color("Rust=#ff88000").is_err());
+        assert!(lang_color("Rust=red;}").is_err());
+    }
+
+    #[test]
+    fn metric_precision_parses_pairs() {
+        assert_eq!(
+            metric_precision("gini = 6").unwrap(),
+            (DecimalMetric::Gini, 6)
+        );
+        assert_eq!(metric_precision("percent=0").unwrap().1, 0);
+        assert!(metric_precision("gini").is_err());
+        assert!(metric_precision("gini=-1").is_err());
+        assert!(metric_precision("gini=13").is_err());
+        let err = metric_precision("median=2").unwrap_err();
+        assert!(err.contains("unknown metric 'median'"), "{err}");
+        assert!(err.contains("todo_density"), "{err}");
+    }
+
     #[test]
     fn positive_usize_accepts_one_and_above() {
         assert_eq!(positive_usize("1").unwrap(), 1);
@@ -48,6 +48,10 @@ pub enum ExportFormat {
     Json,
     /// CycloneDX 1.6 JSON SBOM format.
     Cyclonedx,
+    /// File paths only, one per line.
+    Paths,
+    /// NUL-terminated file paths only (for `xargs -0`).
+    Paths0,
 }
 
 impl From<ExportFormat> for tokmd_types::ExportFormat {
@@ -57,6 +61,8 @@ impl From<ExportFormat> for tokmd_types::ExportFormat {
             ExportFormat::Jsonl => Self::Jsonl,
             ExportFormat::Json => Self::Json,
             ExportFormat::Cyclonedx => Self::Cyclonedx,
+            ExportFormat::Paths => Self::Paths,
+            ExportFormat::Paths0 => Self::Paths0,
         }
     }
 }
@@ -68,6 +74,65 @@ impl From<tokmd_types::ExportFormat> for ExportFormat {
             tokmd_types::ExportFormat::Jsonl => Self::Jsonl,
             tokmd_types::ExportFormat::Json => Self::Json,
             tokmd_types::ExportFormat::Cyclonedx => Self::Cyclonedx,
+            tokmd_types::ExportFormat::Paths => Self::Paths,
+            tokmd_types::ExportFormat::Paths0 => Self::Paths0,
+        }
+    }
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ExportSortKey {
+    /// Lines of code.
+    Code,
+    /// Total lines.
+    Lines,
+    /// Comment lines.
+    Comments,
+    /// Blank lines.
+    Blanks,
+    /// File size in bytes.
+    Bytes,
+    /// Estimated tokens.
+    Tokens,
+    /// File path.
+    Path,
+    /// Module key.
+    Module,
+    /// Language name.
+    Lang,
+}
+
+impl From<ExportSortKey> for tokmd_types::ExportSortKey {
+    fn from(value: ExportSortKey) -> Self {
+        match value {
+            ExportSortKey::Code => Self::Code,
+            ExportSortKey::Lines => Self::Lines,
+            ExportSortKey::Comments => Self::Comments,
+            ExportSortKey::Blanks => Self::Blanks,
+            ExportSortKey::Bytes => Self::Bytes,
+            ExportSortKey::Tokens => Self::Tokens,
+            ExportSortKey::Path => Self::Path,
+            ExportSortKey::Module => Self::Module,
+            ExportSortKey::Lang => Self::Lang,
+        }
+    }
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum SortDirection {
+    /// Ascending.
+    Asc,
+    /// Descending.
+    Desc,
+}
+
+impl From<SortDirection> for tokmd_types::SortDirection {
+    fn from(value: SortDirection) -> Self {
+        match value {
+            SortDirection::Asc => Self::Asc,
+            SortDirection::Desc => Self::Desc,
         }
     }
 }
@@ -100,6 +165,94 @@ impl From<tokmd_types::ConfigMode> for ConfigMode {
     }
 }
 
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum HashAlgo {
+    /// BLAKE3.
+    #[default]
+    Blake3,
+    /// SHA-256.
+    Sha256,
+}
+
+impl From<HashAlgo> for tokmd_types::HashAlgo {
+    fn from(value: HashAlgo) -> Self {
+        match value {
+            HashAlgo::Blake3 => Self::Blake3,
+            HashAlgo::Sha256 => Self::Sha256,
+        }
+    }
+}
+
+impl From<tokmd_types::HashAlgo> for HashAlgo {
+    fn from(value: tokmd_types::HashAlgo) -> Self {
+        match value {
+            tokmd_types::HashAlgo::Blake3 => Self::Blake3,
+            tokmd_types::HashAlgo::Sha256 => Self::Sha256,
+        }
+    }
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum SymlinkPolicy {
+    /// Skip symlinked directories.
+    #[default]
+    Ignore,
+    /// Scan every symlinked directory, even if its files are counted twice.
+    Follow,
+    /// Scan symlinked directories whose target is not already scanned.
+    FollowNoLoop,
+}
+
+impl From<SymlinkPolicy> for tokmd_types::SymlinkPolicy {
+    fn from(value: SymlinkPolicy) -> Self {
+        match value {
+            SymlinkPolicy::Ignore => Self::Ignore,
+            SymlinkPolicy::Follow => Self::Follow,
+            SymlinkPolicy::FollowNoLoop => Self::FollowNoLoop,
+        }
+    }
+}
+
+impl From<tokmd_types::SymlinkPolicy> for SymlinkPolicy {
+    fn from(value: tokmd_types::SymlinkPolicy) -> Self {
+        match value {
+            tokmd_types::SymlinkPolicy::Ignore => Self::Ignore,
+            tokmd_types::SymlinkPolicy::Follow => Self::Follow,
+            tokmd_types::SymlinkPolicy::FollowNoLoop => Self::FollowNoLoop,
+        }
+    }
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum LineCountMode {
+    /// Count physical lines of code.
+    #[default]
+    Physical,
+    /// Count statements in languages with a logical-line rule.
+    Logical,
+}
+
+impl From<LineCountMode> for tokmd_types::LineCountMode {
+    fn from(value: LineCountMode) -> Self {
+        match value {
+            LineCountMode::Physical => Self::Physical,
+            LineCountMode::Logical => Self::Logical,
+        }
+    }
+}
+
+impl From<tokmd_types::LineCountMode> for LineCountMode {
+    fn from(value: tokmd_types::LineCountMode) -> Self {
+        match value {
+            tokmd_types::LineCountMode::Physical => Self::Physical,
+            tokmd_types::LineCountMode::Logical => Self::Logical,
+        }
+    }
+}
+
 #[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 pub enum ChildrenMode {
@@ -127,6 +280,37 @@ impl From<tokmd_types::ChildrenMode> for ChildrenMode {
     }
 }
 
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum AvgMeasure {
+    /// Mean lines per file.
+    Mean,
+    /// Median lines per file (robust to generated or vendored outliers).
+    Median,
+    /// Show both mean and median columns.
+    Both,
+}
+
+impl From<AvgMeasure> for tokmd_types::AvgMeasure {
+    fn from(value: AvgMeasure) -> Self {
+        match value {
+            AvgMeasure::Mean => Self::Mean,
+            AvgMeasure::Median => Self::Median,
+            AvgMeasure::Both => Self::Both,
+        }
+    }
+}
+
+impl From<tokmd_types::AvgMeasure> for AvgMeasure {
+    fn from(value: tokmd_types::AvgMeasure) -> Self {
+        match value {
+            tokmd_types::AvgMeasure::Mean => Self::Mean,
+            tokmd_types::AvgMeasure::Median => Self::Median,
+            tokmd_types::AvgMeasure::Both => Self::Both,
+        }
+    }
+}
+
 #[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 pub enum ChildIncludeMode {
@@ -134,6 +318,8 @@ pub enum ChildIncludeMode {
     Separate,
     /// Ignore embedded languages.
     ParentsOnly,
+    /// Include embedded languages, and list them under each module.
+    Nested,
 }
 
 impl From<ChildIncludeMode> for tokmd_types::ChildIncludeMode {
@@ -141,6 +327,7 @@ impl From<ChildIncludeMode> for tokmd_types::ChildIncludeMode {
         match value {
             ChildIncludeMode::Separate => Self::Separate,
             ChildIncludeMode::ParentsOnly => Self::ParentsOnly,
+            ChildIncludeMode::Nested => Self::Nested,
         }
     }
 }
@@ -150,6 +337,7 @@ impl From<tokmd_types::ChildIncludeMode> for ChildIncludeMode {
         match value {
             tokmd_types::ChildIncludeMode::Separate => Self::Separate,
             tokmd_types::ChildIncludeMode::ParentsOnly => Self::ParentsOnly,
+            tokmd_types::ChildIncludeMode::Nested => Self::Nested,
         }
     }
 }
@@ -192,8 +380,14 @@ pub enum AnalysisFormat {
     Json,
     Jsonld,
     Xml,
+    /// One CSV row per file from the derived top offenders.
+    Csv,
     Svg,
+    /// SVG grid of headline metrics colored by threshold, for READMEs.
+    Scorecard,
     Mermaid,
+    /// Mermaid gitGraph of recent commits by intent (needs git data).
+    Gitgraph,
     Obj,
     Midi,
     Tree,
@@ -207,8 +401,11 @@ impl From<AnalysisFormat> for tokmd_types::AnalysisFormat {
             AnalysisFormat::Json => Self::Json,
             AnalysisFormat::Jsonld => Self::Jsonld,
             AnalysisFormat::Xml => Self::Xml,
+            AnalysisFormat::Csv => Self::Csv,
             AnalysisFormat::Svg => Self::Svg,
+            AnalysisFormat::Scorecard => Self::Scorecard,
             AnalysisFormat::Mermaid => Self::Mermaid,
+            AnalysisFormat::Gitgraph => Self::Gitgraph,
             AnalysisFormat::Obj => Self::Obj,
             AnalysisFormat::Midi => Self::Midi,
             AnalysisFormat::Tree => Self::Tree,
@@ -224,8 +421,11 @@ impl From<tokmd_types::AnalysisFormat> for AnalysisFormat {
             tokmd_types::AnalysisFormat::Json => Self::Json,
             tokmd_types::AnalysisFormat::Jsonld => Self::Jsonld,
             tokmd_types::AnalysisFormat::Xml => Self::Xml,
+            tokmd_types::AnalysisFormat::Csv => Self::Csv,
             tokmd_types::AnalysisFormat::Svg => Self::Svg,
+            tokmd_types::AnalysisFormat::Scorecard => Self::Scorecard,
             tokmd_types::AnalysisFormat::Mermaid => Self::Mermaid,
+            tokmd_types::AnalysisFormat::Gitgraph => Self::Gitgraph,
             tokmd_types::AnalysisFormat::Obj => Self::Obj,
             tokmd_types::AnalysisFormat::Midi => Self::Midi,
             tokmd_types::AnalysisFormat::Tree => Self::Tree,
@@ -1,16 +1,29 @@
+use std::collections::BTreeMap;
 use std::path::Path;
+use std::time::Instant;
 
 use crate::cli;
 use anyhow::{Context, Result, bail};
 use tokmd_analysis as analysis;
 use tokmd_analysis_types as analysis_types;
+use tokmd_core::analysis_facade::{
+    AnalysisRenderOptions, NumberFormat, SvgBadgeMetric, TreemapMetric,
+};
 
 use crate::analysis_explain;
 use crate::analysis_utils;
+use crate::config::ResolvedConfig;
 use crate::export_bundle;
 use crate::progress::Progress;
 
-pub(crate) fn handle(args: cli::CliAnalyzeArgs, global: &cli::GlobalArgs) -> Result<()> {
+mod delta;
+mod preset;
+
+pub(crate) fn handle(
+    mut args: cli::CliAnalyzeArgs,
+    global: &cli::GlobalArgs,
+    resolved: &ResolvedConfig,
+) -> Result<()> {
     if let Some(key) = args.explain.as_deref() {
         let normalized = key.trim().to_ascii_lowercase();
         if normalized == "list" || normalized == "all" || normalized == "keys" {
@@ -33,31 +46,149 @@ pub(crate) fn handle(args: cli::CliAnalyzeArgs, global: &cli::GlobalArgs) -> Res
         .format
         .map(Into::into)
         .unwrap_or(tokmd_types::AnalysisFormat::Md);
-    let receipt = build_receipt(&args, global)?;
+    let presets = resolved.toml.map(|toml| &toml.preset);
+    if let Some(toml) = resolved.toml {
+        add_configured_domains(&mut args, &toml.analyze);
+    }
+    if args.fail_on_doc_ratio {
+        let effective = preset::resolve(args.preset.as_deref(), presets)?.apply_to(&args);
+        if effective.min_doc_ratio.is_none() && effective.min_doc_ratio_lang.is_empty() {
+            bail!(
+                "--fail-on-doc-ratio needs a threshold: pass --min-doc-ratio or --min-doc-ratio-lang"
+            );
+        }
+    }
+    let (receipt, rows) = build_receipt_and_rows(&args, global, presets)?;
+    if args.license_header.is_some() && receipt.license.is_none() {
+        eprintln!(
+            "Warning: --license-header needs the license section (e.g. --preset security or deep); no header check was run"
+        );
+    }
+    if args.coverage.is_some() && (receipt.complexity.is_none() || receipt.git.is_none()) {
+        eprintln!(
+            "Warning: --coverage feeds the risk surface, which needs complexity and git history (e.g. --preset risk or deep); coverage was not used"
+        );
+    }
+    if format != tokmd_types::AnalysisFormat::Html {
+        if args.embed_receipt {
+            eprintln!("Warning: --embed-receipt only applies to --format html");
+        }
+        if args.print_layout {
+            eprintln!("Warning: --print-layout only applies to --format html");
+        }
+        if args.html_max_rows.is_some() {
+            eprintln!("Warning: --html-max-rows only applies to --format html");
+        }
+        if args.treemap_metric.is_some() {
+            eprintln!("Warning: --treemap-metric only applies to --format html");
+        }
+        if !args.lang_color.is_empty() {
+            eprintln!("Warning: --lang-color only applies to --format html");
+        }
+    }
+    if format != tokmd_types::AnalysisFormat::Tree {
+        if args.collapse_single_child {
+            eprintln!("Warning: --collapse-single-child only applies to --format tree");
+        }
+        if args.line_breakdown {
+            eprintln!("Warning: --line-breakdown only applies to --format tree");
+        }
+    }
+    if format != tokmd_types::AnalysisFormat::Svg && args.badge_metric.is_some() {
+        eprintln!("Warning: --badge-metric only applies to --format svg");
+    }
+    let render_options = AnalysisRenderOptions {
+        embed_receipt: args.embed_receipt,
+        print_layout: args.print_layout,
+        html_max_rows: args.html_max_rows,
+        treemap_metric: treemap_metric(args.treemap_metric),
+        lang_colors: args.lang_color.iter().cloned().collect(),
+        svg_badge_metric: svg_badge_metric(args.badge_metric),
+        numbers: NumberFormat {
+            precision: args.precision.iter().copied().collect(),
+            ..NumberFormat::default()
+        },
+        ..Default::default()
+    };
 
-    if let Some(output_dir) = args.output_dir {
+    if let Some(template) = args.output_dir.as_deref() {
+        let root = args.inputs.first().map_or(Path::new("."), |p| p.as_path());
+        let vars = tokmd_format::OutputPathVars::now(
+            &analysis_utils::format_to_string(format),
+            &tokmd_format::repo_name(root),
+        );
+        let output_dir = tokmd_format::expand_output_path(template, &vars);
         std::fs::create_dir_all(&output_dir)
             .context("Failed to create analysis output directory")?;
-        analysis_utils::write_analysis_output(&receipt, &output_dir, format)?;
+        analysis_utils::write_analysis_output(&receipt, &output_dir, format, &render_options)?;
+        analysis_utils::write_api_items_output(&receipt, &output_dir)?;
     } else {
-        analysis_utils::write_analysis_stdout(&receipt, format)?;
+        analysis_utils::write_analysis_stdout(&receipt, format, &render_options)?;
+    }
+
+    if args.delta {
+        let summary = delta::report_and_update(
+            Path::new(delta::DEFAULT_CACHE_PATH),
+            delta::scan_scope(&args.inputs),
+            &rows,
+            receipt.generated_at_ms,
+        )?;
+        eprint!("{summary}");
+    }
+
+    if args.fail_on_doc_ratio {
+        let Some(api_surface) = receipt.api_surface.as_ref() else {
+            bail!(
+                "--fail-on-doc-ratio needs the API surface section (e.g. --preset receipt or deep), which this run did not produce; no documentation check was run"
+            );
+        };
+        if let Some(violation) = api_surface.doc_violations.first() {
+            bail!(
+                "API documented ratio {:.4} ({}) is below the required {:.4}",
+                violation.documented_ratio,
+                violation.scope,
+                violation.threshold
+            );
+        }
     }
 
     Ok(())
 }
 
+/// Append `[analyze]` domain grouping from `tokmd.toml` to the flag values.
+fn add_configured_domains(args: &mut cli::CliAnalyzeArgs, config: &cli::AnalyzeConfig) {
+    let extend = |flags: &mut Vec<String>, configured: &Option<Vec<String>>| {
+        flags.extend(configured.iter().flatten().cloned());
+    };
+    extend(&mut args.domain_parent, &config.domain_parents);
+    extend(&mut args.domain_allow, &config.domain_allow);
+    extend(&mut args.domain_deny, &config.domain_deny);
+}
+
 /// Build an analysis receipt from CLI arguments without emitting it.
 ///
 /// This is the shared analysis core used by `tokmd analyze` and by the
 /// `tokmd packet generate` orchestrator, which renders the same receipt to
-/// several artifact files.
+/// several artifact files. Only built-in presets are available here.
 pub(crate) fn build_receipt(
     args: &cli::CliAnalyzeArgs,
     global: &cli::GlobalArgs,
 ) -> Result<analysis_types::AnalysisReceipt> {
+    build_receipt_and_rows(args, global, None).map(|(receipt, _)| receipt)
+}
+
+/// Build the receipt and, when `--delta` is set, keep a copy of the analyzed
+/// file rows for the delta cache (empty otherwise).
+fn build_receipt_and_rows(
+    args: &cli::CliAnalyzeArgs,
+    global: &cli::GlobalArgs,
+    presets: Option<&BTreeMap<String, tokmd_settings::PresetConfig>>,
+) -> Result<(analysis_types::AnalysisReceipt, Vec<tokmd_types::FileRow>)> {
     let progress = Progress::new(!global.no_progress);
 
-    let preset = args.preset.unwrap_or(cli::AnalysisPreset::Receipt);
+    let resolved_preset = preset::resolve(args.preset.as_deref(), presets)?;
+    let args = &resolved_preset.apply_to(args);
+    let preset = resolved_preset.base;
     let format = args
         .format
         .map(Into::into)
@@ -71,8 +202,12 @@ pub(crate) fn build_receipt(
     };
     let granularity = args.granularity.unwrap_or(cli::ImportGranularity::Module);
 
+    // Started before loading so the scan counts against the budget.
+    let runtime_budget = args.max_runtime_ms.map(analysis::RuntimeBudget::start);
     progress.set_message("Loading export data...");
+    let scan_started = args.timings.then(Instant::now);
     let bundle = export_bundle::load_export_from_inputs(&args.inputs, global)?;
+    let scan_ms = scan_started.map(|started| started.elapsed().as_millis() as u64);
     let source = analysis_types::AnalysisSource {
         inputs: args
             .inputs
@@ -87,9 +222,10 @@ pub(crate) fn build_receipt(
         module_roots: bundle.meta.module_roots.clone(),
         module_depth: bundle.meta.module_depth,
         children: analysis_utils::child_include_to_string(bundle.meta.children),
+        token_model: bundle.meta.token_model.clone(),
     };
     let args_meta = analysis_types::AnalysisArgsMeta {
-        preset: analysis_utils::preset_to_string(preset),
+        preset: resolved_preset.name.clone(),
         format: analysis_utils::format_to_string(format),
         window_tokens: args.window,
         git: git_flag,
@@ -99,6 +235,14 @@ pub(crate) fn build_receipt(
         max_commits: args.max_commits,
         max_commit_files: args.max_commit_files,
         import_granularity: analysis_utils::granularity_to_string(granularity),
+        oversized_file_bytes: args.oversized_file_bytes,
+        max_commit_message_bytes: args.max_commit_message_bytes,
+        strip_bom: args.strip_bom,
+        preset_sections: resolved_preset.recorded_sections(),
+        include_sections: args.include_sections.clone(),
+        exclude_sections: args.exclude_sections.clone(),
+        sample_rate: args.sample,
+        max_runtime_ms: args.max_runtime_ms,
     };
     let near_dup_scope = match args.near_dup_scope {
         Some(cli::NearDupScope::Module) | None => analysis::NearDupScope::Module,
@@ -116,6 +260,9 @@ pub(crate) fn build_receipt(
 
     let request = analysis::AnalysisRequest {
         preset: analysis_utils::map_preset(preset),
+        plan: resolved_preset.plan,
+        include_sections: args.include_sections.clone(),
+        exclude_sections: args.exclude_sections.clone(),
         args: args_meta,
         limits: analysis::AnalysisLimits {
             max_files: args.max_files,
@@ -123,11 +270,21 @@ pub(crate) fn build_receipt(
             max_file_bytes: args.max_file_bytes,
             max_commits: args.max_commits,
             max_commit_files: args.max_commit_files,
+            oversized_file_bytes: args.oversized_file_bytes,
+            max_commit_message_bytes: args.max_commit_message_bytes,
+            strip_bom: args.strip_bom,
+            sample_rate: args.sample,
+            runtime_budget,
         },
         window_tokens: args.window,
         git: git_flag,
         import_granularity: analysis_utils::map_granularity(granularity),
+        tree: analysis::AnalysisTreeOptions {
+            collapse_single_child: args.collapse_single_child,
+            line_breakdown: args.line_breakdown,
+        },
         detail_functions: args.detail_functions,
+        detail_api_items: args.detail_api_items,
         near_dup: args.near_dup,
         near_dup_threshold: args.near_dup_threshold,
         near_dup_max_files: args.near_dup_max_files,
@@ -135,6 +292,27 @@ pub(crate) fn build_receipt(
         near_dup_max_pairs: Some(args.near_dup_max_pairs),
         near_dup_exclude: args.near_dup_exclude.clone(),
         effort,
+        api_doc_thresholds: analysis::ApiDocThresholds {
+            min_documented_ratio: args.min_doc_ratio,
+            min_documented_ratio_by_lang: args.min_doc_ratio_lang.iter().cloned().collect(),
+        },
+        license_header: args.license_header.clone(),
+        domain_grouping: analysis_types::DomainGrouping {
+            parents: args.domain_parent.clone(),
+            allow: args.domain_allow.clone(),
+            deny: args.domain_deny.clone(),
+        },
+        forecast_days: args.forecast_days,
+        coverage: args.coverage.clone(),
+        hash_algo: global.hash_algo.into(),
+        timings: args.timings,
+        progress: Some(phase_progress(&progress)),
+        extensions: Vec::new(),
+    };
+    let rows = if args.delta {
+        bundle.export.rows.clone()
+    } else {
+        Vec::new()
     };
     let ctx = analysis::AnalysisContext {
         export: bundle.export,
@@ -142,11 +320,28 @@ pub(crate) fn build_receipt(
         source,
     };
     progress.set_message("Running analysis...");
-    let receipt = analysis::analyze(ctx, request)?;
+    let mut receipt = analysis::analyze(ctx, request)?;
+    // Loading the export (scanning, for path inputs) happens before the
+    // analysis pipeline, so its phase is added here.
+    if let (Some(timings), Some(scan_ms)) = (receipt.timings.as_mut(), scan_ms) {
+        timings.insert("scan".to_string(), scan_ms);
+    }
 
     progress.finish_and_clear();
 
-    Ok(receipt)
+    Ok((receipt, rows))
+}
+
+/// Drive the spinner's step bar from the analysis phases.
+fn phase_progress(progress: &Progress) -> analysis::ProgressHook {
+    let steps = progress.steps();
+    analysis::ProgressHook::new(move |phase: &analysis::AnalysisProgress| {
+        let msg = match phase.files {
+            Some(files) => format!("Analyzed {} ({files} files)", phase.phase),
+            None => format!("Analyzed {}", phase.phase),
+        };
+        steps.set(phase.completed as u64, phase.total as u64, msg);
+    })
 }
 
 fn parse_effort_request(
@@ -244,6 +439,26 @@ fn map_effort_model(model: cli::EffortModelKind) -> Result<analysis::EffortModel
     }
 }
 
+fn treemap_metric(metric: Option<cli::TreemapMetric>) -> TreemapMetric {
+    match metric {
+        Some(cli::TreemapMetric::Lines) | None => TreemapMetric::Lines,
+        Some(cli::TreemapMetric::Code) => TreemapMetric::Code,
+        Some(cli::TreemapMetric::Tokens) => TreemapMetric::Tokens,
+        Some(cli::TreemapMetric::Bytes) => TreemapMetric::Bytes,
+    }
+}
+
+fn svg_badge_metric(metric: Option<cli::SvgBadgeMetric>) -> SvgBadgeMetric {
+    match metric {
+        Some(cli::SvgBadgeMetric::ContextFit) | None => SvgBadgeMetric::ContextFit,
+        Some(cli::SvgBadgeMetric::Files) => SvgBadgeMetric::Files,
+        Some(cli::SvgBadgeMetric::Lines) => SvgBadgeMetric::Lines,
+        Some(cli::SvgBadgeMetric::Code) => SvgBadgeMetric::Code,
+        Some(cli::SvgBadgeMetric::Tokens) => SvgBadgeMetric::Tokens,
+        Some(cli::SvgBadgeMetric::DocDensity) => SvgBadgeMetric::DocDensity,
+    }
+}
+
 fn map_effort_layer(layer: cli::EffortLayer) -> analysis::EffortLayer {
     match layer {
         cli::EffortLayer::Headline => analysis::EffortLayer::Headline,
@@ -0,0 +1,210 @@
+//! Last-run cache for `tokmd analyze --delta`.
+//!
+//! The cache keeps the analyzed file rows from the previous `--delta` run so
+//! the next run can report which files changed without the caller managing
+//! two receipts. A missing, unreadable, or incompatible cache, or one recorded
+//! for other scanned paths, is treated as a first run and simply overwritten.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use tokmd_format::{compute_file_deltas, render_file_delta_summary};
+use tokmd_types::{FileKind, FileRow};
+
+/// Cache location, relative to the working directory.
+pub(super) const DEFAULT_CACHE_PATH: &str = ".tokmd/last-analysis.json";
+
+const CACHE_VERSION: u32 = 2;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct LastAnalysis {
+    version: u32,
+    generated_at_ms: u128,
+    /// Scanned paths, as returned by [`scan_scope`].
+    scope: Vec<String>,
+    rows: Vec<FileRow>,
+}
+
+/// The analyzed `inputs` as sorted absolute paths, so runs over different
+/// roots never diff against each other. Paths that cannot be resolved are
+/// kept as given.
+pub(super) fn scan_scope(inputs: &[PathBuf]) -> Vec<String> {
+    let mut scope: Vec<String> = inputs
+        .iter()
+        .map(|input| {
+            input
+                .canonicalize()
+                .unwrap_or_else(|_| input.clone())
+                .display()
+                .to_string()
+        })
+        .collect();
+    scope.sort();
+    scope.dedup();
+    scope
+}
+
+/// Diff `rows` against the cached run over the same `scope`, rewrite the
+/// cache, and return the text summary to print.
+pub(super) fn report_and_update(
+    cache_path: &Path,
+    scope: Vec<String>,
+    rows: &[FileRow],
+    generated_at_ms: u128,
+) -> Result<String> {
+    let rows: Vec<FileRow> = rows
+        .iter()
+        .filter(|row| row.kind == FileKind::Parent)
+        .cloned()
+        .collect();
+
+    let summary = match load(cache_path) {
+        Some(previous) if previous.scope == scope => {
+            render_file_delta_summary(&compute_file_deltas(&previous.rows, &rows))
+        }
+        Some(_) => format!(
+            "Delta since last analysis: previous run covered other paths; recorded this run in {}\n",
+            cache_path.display()
+        ),
+        None => format!(
+            "Delta since last analysis: no previous run cached; recorded this run in {}\n",
+            cache_path.display()
+        ),
+    };
+
+    store(
+        cache_path,
+        &LastAnalysis {
+            version: CACHE_VERSION,
+            generated_at_ms,
+            scope,
+            rows,
+        },
+    )?;
+    Ok(summary)
+}
+
+fn load(path: &Path) -> Option<LastAnalysis> {
+    let content = std::fs::read_to_string(path).ok()?;
+    let cached: LastAnalysis = serde_json::from_str(&content).ok()?;
+    (cached.version == CACHE_VERSION).then_some(cached)
+}
+
+fn store(path: &Path, cache: &LastAnalysis) -> Result<()> {
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create {}", parent.display()))?;
+    }
+    let json = serde_json::to_string(cache)?;
+    std::fs::write(path, json)
+        .with_context(|| format!("Failed to write analysis cache {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn row(path: &str, code: usize) -> FileRow {
+        FileRow {
+            path: path.to_string(),
+            module: "src".to_string(),
+            lang: "Rust".to_string(),
+            kind: FileKind::Parent,
+            code,
+            comments: 0,
+            blanks: 0,
+            lines: code,
+            bytes: code * 10,
+            tokens: code * 3,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
+        }
+    }
+
+    fn scope(root: &str) -> Vec<String> {
+        vec![root.to_string()]
+    }
+
+    #[test]
+    fn first_run_records_cache_then_second_run_reports_changes() -> Result<()> {
+        let dir = tempdir()?;
+        let cache = dir.path().join(".tokmd/last-analysis.json");
+
+        let first = report_and_update(&cache, scope("/repo"), &[row("src/a.rs", 10)], 1)?;
+        assert!(first.contains("no previous run cached"), "{first}");
+        assert!(cache.exists());
+
+        let grown = [row("src/a.rs", 15), row("src/b.rs", 2)];
+        let second = report_and_update(&cache, scope("/repo"), &grown, 2)?;
+        assert!(second.contains("1 added, 0 removed, 1 grown"), "{second}");
+        assert!(second.contains("+ src/b.rs"), "{second}");
+        assert!(second.contains("~ src/a.rs 10 -> 15 (+5)"), "{second}");
+
+        let third = report_and_update(&cache, scope("/repo"), &grown, 3)?;
+        assert_eq!(third, "Delta since last analysis: no file changes\n");
+        Ok(())
+    }
+
+    #[test]
+    fn cache_for_other_paths_is_treated_as_first_run() -> Result<()> {
+        let dir = tempdir()?;
+        let cache = dir.path().join("last-analysis.json");
+
+        report_and_update(&cache, scope("/repo/src"), &[row("src/a.rs", 10)], 1)?;
+        let other = report_and_update(&cache, scope("/repo/tests"), &[row("tests/t.rs", 4)], 2)?;
+        assert!(
+            other.contains("previous run covered other paths"),
+            "{other}"
+        );
+
+        let again = report_and_update(&cache, scope("/repo/tests"), &[row("tests/t.rs", 4)], 3)?;
+        assert_eq!(again, "Delta since last analysis: no file changes\n");
+        Ok(())
+    }
+
+    #[test]
+    fn scope_is_absolute_sorted_and_deduplicated() -> Result<()> {
+        let dir = tempdir()?;
+        let (a, b) = (dir.path().join("a"), dir.path().join("b"));
+        std::fs::create_dir_all(&a)?;
+        std::fs::create_dir_all(&b)?;
+
+        let scope = scan_scope(&[b.clone(), a.clone(), b.join("..").join("b")]);
+        let expected = [a.canonicalize()?, b.canonicalize()?].map(|p| p.display().to_string());
+        assert_eq!(scope, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn corrupt_cache_is_treated_as_first_run() -> Result<()> {
+        let dir = tempdir()?;
+        let cache = dir.path().join("last-analysis.json");
+        std::fs::write(&cache, "{not json")?;
+
+        let summary = report_and_update(&cache, scope("/repo"), &[row("src/a.rs", 10)], 1)?;
+        assert!(summary.contains("no previous run cached"), "{summary}");
+
+        let rewritten: LastAnalysis = serde_json::from_str(&std::fs::read_to_string(&cache)?)?;
+        assert_eq!(rewritten.version, CACHE_VERSION);
+        assert_eq!(rewritten.scope, scope("/repo"));
+        assert_eq!(rewritten.rows.len(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn incompatible_cache_version_is_treated_as_first_run() -> Result<()> {
+        let dir = tempdir()?;
+        let cache = dir.path().join("last-analysis.json");
+        std::fs::write(
+            &cache,
+            r#"{"version":999,"generated_at_ms":0,"scope":["/repo"],"rows":[]}"#,
+        )?;
+
+        let summary = report_and_update(&cache, scope("/repo"), &[], 1)?;
+        assert!(summary.contains("no previous run cached"), "{summary}");
+        Ok(())
+    }
+}
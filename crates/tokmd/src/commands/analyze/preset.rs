@@ -0,0 +1,239 @@
+//! `--preset` resolution: built-in presets plus `[preset.<name>]` tables
+//! from tokmd.toml.
+
+use std::collections::BTreeMap;
+
+use anyhow::{Result, anyhow};
+use clap::ValueEnum;
+use tokmd_analysis::PresetPlan;
+use tokmd_settings::PresetConfig;
+
+use crate::cli;
+
+/// The preset an analyze run resolved to.
+#[derive(Debug, Clone)]
+pub(super) struct ResolvedPreset<'a> {
+    /// The requested name, recorded as the receipt's `args.preset`.
+    pub name: String,
+    /// The built-in preset itself, or the one a configured preset builds on.
+    pub base: cli::AnalysisPreset,
+    /// Sections replacing the base preset's, when the table lists them.
+    pub plan: Option<PresetPlan>,
+    /// The `[preset.<name>]` table, when the name is configured.
+    pub config: Option<&'a PresetConfig>,
+}
+
+/// Resolve `name` (default "receipt"), preferring a configured table over a
+/// built-in of the same name.
+pub(super) fn resolve<'a>(
+    name: Option<&str>,
+    presets: Option<&'a BTreeMap<String, PresetConfig>>,
+) -> Result<ResolvedPreset<'a>> {
+    let name = name.unwrap_or("receipt");
+    let Some(config) = presets.and_then(|presets| presets.get(name)) else {
+        let base = builtin(name).ok_or_else(|| {
+            anyhow!(
+                "invalid value '{name}' for '--preset': not a built-in preset or a \
+                 [preset.{name}] table in tokmd.toml"
+            )
+        })?;
+        return Ok(ResolvedPreset {
+            name: name.to_string(),
+            base,
+            plan: None,
+            config: None,
+        });
+    };
+
+    let base_name = match config.base.as_deref() {
+        Some(base) => base,
+        None if builtin(name).is_some() => name,
+        None => "receipt",
+    };
+    let base = builtin(base_name).ok_or_else(|| {
+        anyhow!("invalid value '{base_name}' for 'base' in [preset.{name}]: not a built-in preset")
+    })?;
+    let plan = config
+        .sections
+        .as_deref()
+        .map(PresetPlan::from_sections)
+        .transpose()
+        .map_err(|err| anyhow!("[preset.{name}]: {err}"))?;
+    Ok(ResolvedPreset {
+        name: name.to_string(),
+        base,
+        plan,
+        config: Some(config),
+    })
+}
+
+impl ResolvedPreset<'_> {
+    /// `args` with unset options filled from the configured table; flags
+    /// given on the command line win.
+    pub(super) fn apply_to(&self, args: &cli::CliAnalyzeArgs) -> cli::CliAnalyzeArgs {
+        let mut args = args.clone();
+        let Some(config) = self.config else {
+            return args;
+        };
+        if !args.git && !args.no_git {
+            match config.git {
+                Some(true) => args.git = true,
+                Some(false) => args.no_git = true,
+                None => {}
+            }
+        }
+        args.window = args.window.or(config.window);
+        args.max_files = args.max_files.or(config.max_files);
+        args.max_bytes = args.max_bytes.or(config.max_bytes);
+        args.max_file_bytes = args.max_file_bytes.or(config.max_file_bytes);
+        args.max_commits = args.max_commits.or(config.max_commits);
+        args.max_commit_files = args.max_commit_files.or(config.max_commit_files);
+        args.min_doc_ratio = args.min_doc_ratio.or(config.min_doc_ratio);
+        args
+    }
+
+    /// Sections to record in the receipt for a configured preset.
+    pub(super) fn recorded_sections(&self) -> Option<Vec<String>> {
+        self.config?;
+        let plan = self.plan.unwrap_or_else(|| {
+            tokmd_analysis::preset_plan_for(crate::analysis_utils::map_preset(self.base))
+        });
+        Some(plan.sections().into_iter().map(String::from).collect())
+    }
+}
+
+fn builtin(name: &str) -> Option<cli::AnalysisPreset> {
+    cli::AnalysisPreset::from_str(name, false).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn analyze_args(argv: &[&str]) -> cli::CliAnalyzeArgs {
+        use clap::Parser;
+
+        let argv = ["tokmd", "analyze"].into_iter().chain(argv.iter().copied());
+        match cli::Cli::try_parse_from(argv).unwrap().command.unwrap() {
+            cli::Commands::Analyze(args) => args,
+            other => panic!("unexpected command: {other:?}"),
+        }
+    }
+
+    fn presets(toml: &str) -> BTreeMap<String, PresetConfig> {
+        tokmd_settings::TomlConfig::parse(toml)
+            .expect("parse config")
+            .preset
+    }
+
+    #[test]
+    fn builtin_names_resolve_without_config() {
+        let resolved = resolve(Some("bun-ub"), None).unwrap();
+        assert_eq!(resolved.base, cli::AnalysisPreset::BunUb);
+        assert!(resolved.plan.is_none());
+        assert!(resolved.recorded_sections().is_none());
+        assert_eq!(
+            resolve(None, None).unwrap().base,
+            cli::AnalysisPreset::Receipt
+        );
+    }
+
+    #[test]
+    fn unknown_names_are_invalid_values() {
+        let err = resolve(Some("banana"), None).unwrap_err().to_string();
+        assert!(err.contains("invalid value 'banana'"), "{err}");
+        assert!(err.contains("[preset.banana]"), "{err}");
+    }
+
+    #[test]
+    fn custom_preset_enables_exactly_the_configured_sections() {
+        let presets = presets(
+            r#"
+[preset.audit]
+sections = ["license", "entropy", "deps"]
+"#,
+        );
+        let resolved = resolve(Some("audit"), Some(&presets)).unwrap();
+        assert_eq!(resolved.name, "audit");
+        assert_eq!(resolved.base, cli::AnalysisPreset::Receipt);
+        let plan = resolved.plan.expect("configured sections");
+        assert_eq!(plan.sections(), vec!["deps", "entropy", "license"]);
+        assert!(!plan.git && !plan.complexity && !plan.dup);
+        assert_eq!(
+            resolved.recorded_sections(),
+            Some(vec![
+                "deps".to_string(),
+                "entropy".to_string(),
+                "license".to_string()
+            ])
+        );
+    }
+
+    #[test]
+    fn table_overrides_builtin_of_the_same_name() {
+        let presets = presets(
+            r#"
+[preset.security]
+max_file_bytes = 4096
+"#,
+        );
+        let resolved = resolve(Some("security"), Some(&presets)).unwrap();
+        assert_eq!(resolved.base, cli::AnalysisPreset::Security);
+        assert!(resolved.plan.is_none());
+        let sections = resolved.recorded_sections().expect("configured preset");
+        assert!(sections.contains(&"entropy".to_string()));
+        assert!(sections.contains(&"license".to_string()));
+    }
+
+    #[test]
+    fn configured_settings_fill_unset_flags_only() {
+        let presets = presets(
+            r#"
+[preset.quick]
+base = "health"
+git = false
+max_files = 50
+max_file_bytes = 4096
+"#,
+        );
+        let resolved = resolve(Some("quick"), Some(&presets)).unwrap();
+        assert_eq!(resolved.base, cli::AnalysisPreset::Health);
+
+        let args = resolved.apply_to(&analyze_args(&["--preset", "quick", "--max-files", "7"]));
+        assert_eq!(args.max_files, Some(7));
+        assert_eq!(args.max_file_bytes, Some(4096));
+        assert!(args.no_git && !args.git);
+
+        let args = resolved.apply_to(&analyze_args(&["--git"]));
+        assert!(args.git && !args.no_git);
+    }
+
+    #[test]
+    fn any_preset_name_parses() {
+        let args = analyze_args(&["--preset", "team-audit"]);
+        assert_eq!(args.preset.as_deref(), Some("team-audit"));
+    }
+
+    #[test]
+    fn invalid_base_and_sections_name_the_table() {
+        let presets = presets(
+            r#"
+[preset.a]
+base = "nope"
+
+[preset.b]
+sections = ["secrets"]
+"#,
+        );
+        let err = resolve(Some("a"), Some(&presets)).unwrap_err().to_string();
+        assert!(
+            err.contains("'nope'") && err.contains("[preset.a]"),
+            "{err}"
+        );
+        let err = resolve(Some("b"), Some(&presets)).unwrap_err().to_string();
+        assert!(
+            err.contains("'secrets'") && err.contains("[preset.b]"),
+            "{err}"
+        );
+    }
+}
@@ -8,6 +8,9 @@ use crate::export_bundle;
 
 pub(crate) fn handle(args: cli::BadgeArgs, global: &cli::GlobalArgs) -> Result<()> {
     let metric = args.metric;
+    if args.target.is_some() && metric != cli::BadgeMetric::Doc {
+        anyhow::bail!("--target applies only to --metric doc");
+    }
     let mut preset = args.preset.unwrap_or(cli::AnalysisPreset::Receipt);
     if metric == cli::BadgeMetric::Hotspot && args.preset.is_none() {
         preset = cli::AnalysisPreset::Risk;
@@ -37,6 +40,7 @@ pub(crate) fn handle(args: cli::BadgeArgs, global: &cli::GlobalArgs) -> Result<(
         module_roots: bundle.meta.module_roots.clone(),
         module_depth: bundle.meta.module_depth,
         children: analysis_utils::child_include_to_string(bundle.meta.children),
+        token_model: bundle.meta.token_model.clone(),
     };
     let args_meta = tokmd_analysis_types::AnalysisArgsMeta {
         preset: analysis_utils::preset_to_string(preset),
@@ -49,6 +53,14 @@ pub(crate) fn handle(args: cli::BadgeArgs, global: &cli::GlobalArgs) -> Result<(
         max_commits: args.max_commits,
         max_commit_files: args.max_commit_files,
         import_granularity: "module".to_string(),
+        oversized_file_bytes: None,
+        max_commit_message_bytes: None,
+        strip_bom: false,
+        preset_sections: None,
+        include_sections: vec![],
+        exclude_sections: vec![],
+        sample_rate: None,
+        max_runtime_ms: None,
     };
     let request = analysis::AnalysisRequest {
         preset: analysis_utils::map_preset(preset),
@@ -59,11 +71,18 @@ pub(crate) fn handle(args: cli::BadgeArgs, global: &cli::GlobalArgs) -> Result<(
             max_file_bytes: None,
             max_commits: args.max_commits,
             max_commit_files: args.max_commit_files,
+            oversized_file_bytes: None,
+            max_commit_message_bytes: None,
+            strip_bom: false,
+            sample_rate: None,
+            runtime_budget: None,
         },
         window_tokens: None,
         git: git_flag,
         import_granularity: analysis::ImportGranularity::Module,
+        tree: Default::default(),
         detail_functions: false,
+        detail_api_items: false,
         near_dup: false,
         near_dup_threshold: 0.80,
         near_dup_max_files: 2000,
@@ -71,6 +90,18 @@ pub(crate) fn handle(args: cli::BadgeArgs, global: &cli::GlobalArgs) -> Result<(
         near_dup_max_pairs: None,
         near_dup_exclude: Vec::new(),
         effort: None,
+        api_doc_thresholds: Default::default(),
+        license_header: None,
+        domain_grouping: Default::default(),
+        forecast_days: None,
+        coverage: None,
+        hash_algo: global.hash_algo.into(),
+        timings: false,
+        plan: None,
+        include_sections: vec![],
+        exclude_sections: vec![],
+        progress: None,
+        extensions: Vec::new(),
     };
     let ctx = analysis::AnalysisContext {
         export: bundle.export,
@@ -113,8 +144,10 @@ pub(crate) fn handle(args: cli::BadgeArgs, global: &cli::GlobalArgs) -> Result<(
             .unwrap_or_else(|| "n/a".to_string()),
     };
 
-    let label = badge_metric_label(metric);
-    let svg = badge_svg(label, &value);
+    let svg = match args.target {
+        Some(target) => tokmd_format::analysis::render_doc_density_svg(&receipt, target),
+        None => badge_svg(badge_metric_label(metric), &value),
+    };
 
     if let Some(output) = args.output {
         std::fs::write(output, svg)?;
@@ -54,6 +54,7 @@ pub(crate) fn handle(args: BaselineArgs, global: &GlobalArgs) -> Result<()> {
         module_roots: bundle.meta.module_roots.clone(),
         module_depth: bundle.meta.module_depth,
         children: analysis_utils::child_include_to_string(bundle.meta.children),
+        token_model: bundle.meta.token_model.clone(),
     };
 
     let args_meta = AnalysisArgsMeta {
@@ -67,6 +68,14 @@ pub(crate) fn handle(args: BaselineArgs, global: &GlobalArgs) -> Result<()> {
         max_commits: None,
         max_commit_files: None,
         import_granularity: "module".to_string(),
+        oversized_file_bytes: None,
+        max_commit_message_bytes: None,
+        strip_bom: false,
+        preset_sections: None,
+        include_sections: vec![],
+        exclude_sections: vec![],
+        sample_rate: None,
+        max_runtime_ms: None,
     };
 
     // Run analysis with "health" preset (includes complexity)
@@ -78,7 +87,9 @@ pub(crate) fn handle(args: BaselineArgs, global: &GlobalArgs) -> Result<()> {
         window_tokens: None,
         git: None,
         import_granularity: analysis::ImportGranularity::Module,
+        tree: Default::default(),
         detail_functions: false,
+        detail_api_items: false,
         near_dup: false,
         near_dup_threshold: 0.80,
         near_dup_max_files: 2000,
@@ -86,6 +97,18 @@ pub(crate) fn handle(args: BaselineArgs, global: &GlobalArgs) -> Result<()> {
         near_dup_max_pairs: None,
         near_dup_exclude: Vec::new(),
         effort: None,
+        api_doc_thresholds: Default::default(),
+        license_header: None,
+        domain_grouping: Default::default(),
+        forecast_days: None,
+        coverage: None,
+        hash_algo: global.hash_algo.into(),
+        timings: false,
+        plan: None,
+        include_sections: vec![],
+        exclude_sections: vec![],
+        progress: None,
+        extensions: Vec::new(),
     };
 
     let ctx = analysis::AnalysisContext {
@@ -107,7 +130,11 @@ pub(crate) fn handle(args: BaselineArgs, global: &GlobalArgs) -> Result<()> {
     #[cfg(feature = "git")]
     if args.determinism {
         progress.set_message("Computing determinism hashes...");
-        baseline.determinism = Some(compute_determinism_baseline(&scan_root, &file_paths)?);
+        baseline.determinism = Some(compute_determinism_baseline(
+            &scan_root,
+            &file_paths,
+            global.hash_algo.into(),
+        )?);
     }
     #[cfg(not(feature = "git"))]
     if args.determinism {
@@ -160,15 +187,19 @@ pub(crate) fn handle(args: BaselineArgs, global: &GlobalArgs) -> Result<()> {
 
 /// Compute a determinism baseline from export file paths.
 ///
-/// Hashes all source files and optionally `Cargo.lock` to create a
-/// reproducibility fingerprint.
+/// Hashes all source files and optionally `Cargo.lock` with `algo` to create
+/// a reproducibility fingerprint.
 #[cfg(feature = "git")]
-fn compute_determinism_baseline(root: &Path, file_paths: &[String]) -> Result<DeterminismBaseline> {
+fn compute_determinism_baseline(
+    root: &Path,
+    file_paths: &[String],
+    algo: tokmd_types::HashAlgo,
+) -> Result<DeterminismBaseline> {
     let path_refs: Vec<&str> = file_paths.iter().map(|s| s.as_str()).collect();
-    let source_hash = determinism::hash_files_from_paths(root, &path_refs)?;
-    let cargo_lock_hash = determinism::hash_cargo_lock(root)?;
+    let source_hash = determinism::hash_files_from_paths_with(root, &path_refs, algo)?;
+    let cargo_lock_hash = determinism::hash_cargo_lock_with(root, algo)?;
 
-    let generated_at = time::OffsetDateTime::now_utc()
+    let generated_at = tokmd_format::now_utc()
         .format(&time::format_description::well_known::Rfc3339)
         .unwrap_or_default();
 
@@ -178,6 +209,7 @@ fn compute_determinism_baseline(root: &Path, file_paths: &[String]) -> Result<De
         build_hash: String::new(),
         source_hash,
         cargo_lock_hash,
+        algo,
     })
 }
 
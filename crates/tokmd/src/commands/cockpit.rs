@@ -9,6 +9,7 @@ use std::io::Write;
 use std::path::{Path, PathBuf};
 
 use crate::cli;
+use crate::config::ResolvedConfig;
 #[cfg(feature = "git")]
 use crate::progress::Progress;
 #[cfg(feature = "git")]
@@ -24,11 +25,16 @@ pub use tokmd_types::cockpit::*;
 pub use tokmd_cockpit::compute_cockpit;
 
 /// Handle the cockpit command.
-pub(crate) fn handle(args: cli::CockpitArgs, global: &cli::GlobalArgs) -> Result<()> {
+pub(crate) fn handle(
+    args: cli::CockpitArgs,
+    global: &cli::GlobalArgs,
+    resolved: &ResolvedConfig,
+) -> Result<()> {
     #[cfg(not(feature = "git"))]
     {
         let _ = &args; // Silence unused warning
         let _ = global;
+        let _ = resolved;
         bail!("The cockpit command requires the 'git' feature. Rebuild with --features git");
     }
 
@@ -66,6 +72,34 @@ pub(crate) fn handle(args: cli::CockpitArgs, global: &cli::GlobalArgs) -> Result
                 )
             })?;
 
+        let defaults = tokmd_cockpit::ProjectCoverageThresholds::default();
+        let options = tokmd_cockpit::GateOptions {
+            determinism_excludes: args.determinism_exclude.clone(),
+            coverage_artifacts: args.coverage_artifact.clone(),
+            project_coverage: tokmd_cockpit::ProjectCoverageThresholds {
+                line_pass: args.project_coverage_pass.unwrap_or(defaults.line_pass),
+                line_warn: args.project_coverage_warn.unwrap_or(defaults.line_warn),
+                ..defaults
+            },
+            rust_source: tokmd_cockpit::RustSourceScope {
+                include: args.rust_source_include.clone(),
+                exclude: args.rust_source_exclude.clone(),
+            },
+            artifact_staleness_window: args.artifact_staleness_window,
+            supply_chain_min_severity: args.supply_chain_min_severity.map(
+                |severity| match severity {
+                    cli::AdvisorySeverity::Low => tokmd_cockpit::AdvisorySeverity::Low,
+                    cli::AdvisorySeverity::Medium => tokmd_cockpit::AdvisorySeverity::Medium,
+                    cli::AdvisorySeverity::High => tokmd_cockpit::AdvisorySeverity::High,
+                    cli::AdvisorySeverity::Critical => tokmd_cockpit::AdvisorySeverity::Critical,
+                },
+            ),
+            advisory_allowlist: match &args.advisory_allowlist {
+                Some(path) => tokmd_cockpit::AdvisoryAllowlist::load(path)?,
+                None => tokmd_cockpit::AdvisoryAllowlist::default(),
+            },
+        };
+
         progress.set_message("Computing cockpit metrics (git diff + scan)...");
         let mut receipt = tokmd_cockpit::compute_cockpit(
             &repo_root,
@@ -73,22 +107,50 @@ pub(crate) fn handle(args: cli::CockpitArgs, global: &cli::GlobalArgs) -> Result
             &args.head,
             range_mode,
             args.baseline.as_deref(),
+            &options,
         )?;
 
-        // Load baseline and compute trend if provided
-        if let Some(baseline_path) = &args.baseline {
-            progress.set_message("Computing trend comparison...");
-            receipt.trend = Some(tokmd_cockpit::load_and_compute_trend(
+        // Show the untested source under each uncovered hunk when asked, unless
+        // the active config redacts paths
+        if args.uncovered_snippets
+            && let Some(gate) = receipt.evidence.diff_coverage.as_mut()
+        {
+            let redact = crate::config::parse_redact_mode(resolved.redact())
+                .unwrap_or(tokmd_types::RedactMode::None);
+            tokmd_cockpit::attach_hunk_snippets(
+                &repo_root,
+                gate,
+                &tokmd_cockpit::HunkSnippetLimits::default(),
+                redact,
+            );
+        }
+
+        // Load baseline and compute trend if provided; otherwise trend against
+        // the previous run over the same refs, cached in the git directory
+        progress.set_message("Computing trend comparison...");
+        let git_dir = tokmd_git::git_dir(&repo_root);
+        receipt.trend = match (&args.baseline, &git_dir) {
+            (Some(baseline_path), _) => Some(tokmd_cockpit::load_and_compute_trend(
                 baseline_path,
                 &receipt,
-            )?);
-        }
+            )?),
+            (None, Some(git_dir)) => tokmd_cockpit::trend_since_last_run(git_dir, &receipt)?,
+            (None, None) => None,
+        };
 
         // Clear the spinner before any stdout/file output so progress noise on
         // stderr never interleaves with the machine-readable receipt.
         progress.set_message("Rendering report...");
         progress.finish_and_clear();
 
+        // Cache this run as the next run's baseline; a read-only checkout only
+        // loses the automatic trend
+        if let Some(git_dir) = &git_dir
+            && let Err(err) = tokmd_cockpit::store_last_run(git_dir, &receipt)
+        {
+            eprintln!("warning: cockpit last-run cache not updated: {err:#}");
+        }
+
         // In sensor mode, write envelope to artifacts_dir
         if args.sensor_mode {
             let artifacts_dir = args
@@ -112,9 +174,10 @@ pub(crate) fn handle(args: cli::CockpitArgs, global: &cli::GlobalArgs) -> Result
         // Standard (non-sensor) mode
         let output = match args.format {
             cli::CockpitFormat::Json => tokmd_cockpit::render::render_json(&receipt)?,
-            cli::CockpitFormat::Md => tokmd_cockpit::render::render_markdown(&receipt),
+            cli::CockpitFormat::Md => render_markdown(&receipt, &args),
             cli::CockpitFormat::Comment => tokmd_cockpit::render::render_comment_md(&receipt),
             cli::CockpitFormat::Sections => tokmd_cockpit::render::render_sections(&receipt),
+            cli::CockpitFormat::Jsonl => tokmd_cockpit::render::render_cockpit_jsonl(&receipt),
         };
 
         if let Some(artifacts_dir) = &args.artifacts_dir {
@@ -132,11 +195,16 @@ pub(crate) fn handle(args: cli::CockpitArgs, global: &cli::GlobalArgs) -> Result
             )?;
         }
 
+        // Side outputs render the same receipt, so JSON and Markdown agree
+        if let Some(json_path) = &args.json_output {
+            write_output_file(json_path, &tokmd_cockpit::render::render_json(&receipt)?)?;
+        }
+        if let Some(md_path) = &args.md_output {
+            write_output_file(md_path, &render_markdown(&receipt, &args))?;
+        }
+
         if let Some(output_path) = &args.output {
-            let mut file = std::fs::File::create(output_path).with_context(|| {
-                format!("Failed to create output file: {}", output_path.display())
-            })?;
-            file.write_all(output.as_bytes())?;
+            write_output_file(output_path, &output)?;
         } else {
             print!("{}", output);
         }
@@ -145,6 +213,34 @@ pub(crate) fn handle(args: cli::CockpitArgs, global: &cli::GlobalArgs) -> Result
     }
 }
 
+/// Render the `md` report, grouping the review plan when `--review-group-by` is set.
+#[cfg(feature = "git")]
+fn render_markdown(receipt: &CockpitReceipt, args: &cli::CockpitArgs) -> String {
+    match args.review_group_by {
+        None => tokmd_cockpit::render::render_markdown(receipt),
+        Some(cli::ReviewGroupBy::Module) => {
+            let defaults = tokmd_cockpit::ReviewPlanGrouping::default();
+            let grouping = tokmd_cockpit::ReviewPlanGrouping {
+                module_roots: args.module_roots.clone().unwrap_or(defaults.module_roots),
+                module_depth: args.module_depth.unwrap_or(defaults.module_depth),
+            };
+            tokmd_cockpit::render::render_markdown_grouped(
+                receipt,
+                &tokmd_format::StatusStyle::default(),
+                &grouping,
+            )
+        }
+    }
+}
+
+#[cfg(feature = "git")]
+fn write_output_file(path: &Path, content: &str) -> Result<()> {
+    let mut file = std::fs::File::create(path)
+        .with_context(|| format!("Failed to create output file: {}", path.display()))?;
+    file.write_all(content.as_bytes())?;
+    Ok(())
+}
+
 #[cfg(feature = "git")]
 fn load_doc_artifacts_evidence_input(
     args: &cli::CockpitArgs,
@@ -297,7 +393,7 @@ mod tests {
         let baseline = tmp.path().join("baseline.json");
         fs::write(&baseline, "{")?;
 
-        let err = match compute_determinism_gate(tmp.path(), Some(&baseline)) {
+        let err = match compute_determinism_gate(tmp.path(), Some(&baseline), &[]) {
             Ok(_) => {
                 return Err(anyhow::anyhow!(
                     "invalid JSON should not silently skip determinism gate"
@@ -316,7 +412,7 @@ mod tests {
         let baseline = tmp.path().join("baseline.json");
         fs::write(&baseline, r#"{"mode":"cockpit"}"#)?;
 
-        let gate = compute_determinism_gate(tmp.path(), Some(&baseline))?;
+        let gate = compute_determinism_gate(tmp.path(), Some(&baseline), &[])?;
         assert!(gate.is_none());
         Ok(())
     }
@@ -328,7 +424,7 @@ mod tests {
         let baseline = tmp.path().join("baseline.json");
         fs::write(&baseline, r#"{"mode":"lang"}"#)?;
 
-        let err = match compute_determinism_gate(tmp.path(), Some(&baseline)) {
+        let err = match compute_determinism_gate(tmp.path(), Some(&baseline), &[]) {
             Ok(_) => {
                 return Err(anyhow::anyhow!(
                     "non-baseline JSON should be a configuration error"
@@ -49,6 +49,7 @@ pub(crate) fn handle(args: cli::CliContextArgs, global: &cli::GlobalArgs) -> Res
         &mut excluded_paths,
     );
     let scan_opts = tokmd_settings::ScanOptions::from(&scan_args);
+    super::warn_skipped_ignore_negations(&paths, &scan_opts);
     let languages = scan::scan(&paths, &scan_opts)?;
     let module_roots = args.module_roots.clone().unwrap_or_default();
     let module_depth = args.module_depth.unwrap_or(2);
@@ -1,6 +1,8 @@
 use crate::cli;
 use crate::progress::Progress;
 use anyhow::{Context, Result, bail};
+use tokmd_analysis_types::{AnalysisReceipt, ApiSurfaceDiff, ApiSurfaceReport};
+use tokmd_format::analysis::{diff_api_surface, render_api_surface_diff_md};
 use tokmd_format::{
     DiffColorMode, DiffRenderOptions, compute_diff_rows, compute_diff_totals, create_diff_receipt,
     render_diff_md_with_options,
@@ -35,6 +37,7 @@ pub(crate) fn handle(args: cli::DiffArgs, global: &cli::GlobalArgs) -> Result<()
     progress.set_message("Computing diff...");
     let diff_rows = compute_diff_rows(&from_report, &to_report);
     let totals = compute_diff_totals(&diff_rows);
+    let api_surface = resolve_api_surface_diff(&from, &to);
 
     // Clear the stderr spinner before the report is written to stdout.
     progress.finish_and_clear();
@@ -50,9 +53,13 @@ pub(crate) fn handle(args: cli::DiffArgs, global: &cli::GlobalArgs) -> Result<()
                 "{}",
                 render_diff_md_with_options(&from, &to, &diff_rows, &totals, render_options)
             );
+            if let Some(api_surface) = api_surface.filter(|_| !args.compact) {
+                print!("\n{}", render_api_surface_diff_md(&api_surface));
+            }
         }
         cli::DiffFormat::Json => {
-            let receipt = create_diff_receipt(&from, &to, diff_rows, totals);
+            let mut receipt = create_diff_receipt(&from, &to, diff_rows, totals);
+            receipt.api_surface = api_surface;
             println!("{}", serde_json::to_string(&receipt)?);
         }
     }
@@ -159,10 +166,55 @@ fn load_lang_report_from_path(path: &Path) -> Result<LangReport> {
     let content = std::fs::read_to_string(&lang_path)
         .with_context(|| format!("Failed to read {}", lang_path.display()))?;
     let receipt: tokmd_types::LangReceipt =
-        serde_json::from_str(&content).context("Failed to parse lang receipt")?;
+        tokmd_format::parse_receipt(&content).context("Failed to parse lang receipt")?;
     Ok(receipt.report)
 }
 
+/// Public API changes between two run directories, when both hold an
+/// `analysis.json` with an API surface report. Git refs and bare lang
+/// receipts have none.
+fn resolve_api_surface_diff(from: &str, to: &str) -> Option<ApiSurfaceDiff> {
+    let load = |input: &str| {
+        load_api_surface(Path::new(input)).unwrap_or_else(|err| {
+            eprintln!("Warning: skipping API surface diff: {err:#}");
+            None
+        })
+    };
+    let base = load(from)?;
+    let head = load(to)?;
+    Some(diff_api_surface(&base, &head))
+}
+
+/// The API surface report from a run directory's `analysis.json`, or `None`
+/// when the input is not a run directory or it has no analysis receipt.
+fn load_api_surface(path: &Path) -> Result<Option<ApiSurfaceReport>> {
+    let run_dir = if path.is_dir() {
+        path
+    } else if path
+        .file_name()
+        .is_some_and(|name| name == "receipt.json" || name == "lang.json")
+    {
+        match path.parent() {
+            Some(parent) => parent,
+            None => return Ok(None),
+        }
+    } else {
+        return Ok(None);
+    };
+
+    let analysis_path = run_dir.join("analysis.json");
+    let content = match std::fs::read_to_string(&analysis_path) {
+        Ok(content) => content,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(err) => {
+            return Err(err).with_context(|| format!("Failed to read {}", analysis_path.display()));
+        }
+    };
+    let receipt: AnalysisReceipt = serde_json::from_str(&content)
+        .with_context(|| format!("Failed to parse {}", analysis_path.display()))?;
+    Ok(receipt.api_surface)
+}
+
 #[cfg(not(feature = "git"))]
 fn lang_report_from_git_ref(_revision: &str, _global: &cli::GlobalArgs) -> Result<LangReport> {
     bail!("Git support is disabled in this build. Cannot diff git refs.");
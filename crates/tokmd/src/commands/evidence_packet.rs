@@ -6,7 +6,7 @@ use anyhow::{Context, Result, bail};
 use serde_json::Value;
 use tokmd_types::{
     EVIDENCE_PACKET_SCHEMA, EvidencePacketArtifacts, EvidencePacketManifest,
-    EvidencePacketReviewPriorityItem, EvidencePacketStatus,
+    EvidencePacketReviewPriorityItem, EvidencePacketStatus, Warning,
 };
 
 use crate::cli;
@@ -293,10 +293,16 @@ fn inspect_analyze_json(
     match json.get("warnings").and_then(Value::as_array) {
         Some(items) => {
             for item in items {
-                match item.as_str() {
-                    Some(warning) if !warning.is_empty() => push_unique(warnings, warning),
-                    Some(_) => {}
-                    None => push_unique(errors, "analyze.json warnings contains non-string values"),
+                // Structured since analysis schema v10; older receipts hold
+                // plain strings, which `Warning` also accepts.
+                match serde_json::from_value::<Warning>(item.clone()) {
+                    Ok(warning) if !warning.message.is_empty() => {
+                        push_unique(warnings, &warning.to_string())
+                    }
+                    Ok(_) => {}
+                    Err(_) => {
+                        push_unique(errors, "analyze.json warnings contains malformed values")
+                    }
                 }
             }
         }
@@ -18,19 +18,34 @@ pub(crate) fn handle(
 
     let progress = Progress::new(!global.no_progress);
     progress.set_message("Scanning codebase...");
+    super::warn_skipped_ignore_negations(&args.paths, &scan_opts);
     let languages = scan::scan(&args.paths, &scan_opts)?;
 
     progress.set_message("Building file inventory...");
     let strip_prefix = args.strip_prefix.as_deref();
-    let export = model::create_export_data(
+    let mut export = model::create_export_data_with_options(
         &languages,
         &args.module_roots,
         args.module_depth,
         args.children,
         strip_prefix,
-        args.min_code,
-        args.max_rows,
+        &model::ExportRowOptions {
+            min_code: args.min_code,
+            filters: args.filters,
+            sort_by: args.sort_by,
+            sort_dir: args.sort_dir,
+            max_rows: args.max_rows,
+        },
     );
+    if cli_args.classify {
+        for row in &mut export.rows {
+            row.role = Some(tokmd_analysis_types::file_role(&row.path));
+        }
+    }
+    if cli_args.git_age {
+        progress.set_message("Reading git history...");
+        add_git_ages(&mut export, &args)?;
+    }
     // Clear the stderr spinner before machine-readable output is written so the
     // inventory on stdout stays clean.
     progress.finish_and_clear();
@@ -38,3 +53,45 @@ pub(crate) fn handle(
     format::write_export(&export, &scan_opts, &args)?;
     Ok(())
 }
+
+/// Annotate rows with the newest commit touching each file (`--git-age`).
+#[cfg(feature = "git")]
+fn add_git_ages(
+    export: &mut tokmd_types::ExportData,
+    args: &tokmd_types::ExportArgs,
+) -> Result<()> {
+    let scan_root = args
+        .paths
+        .first()
+        .map_or(std::path::Path::new("."), std::path::PathBuf::as_path);
+    let Some(repo_root) = tokmd_git::repo_root(scan_root) else {
+        eprintln!("Warning: --git-age ignored: not inside a git repository");
+        return Ok(());
+    };
+    let repo_root = repo_root.canonicalize().unwrap_or(repo_root);
+    let cwd = std::env::current_dir()?;
+    let cwd = cwd.canonicalize().unwrap_or(cwd);
+    // Row paths are relative to the working directory, minus any stripped prefix.
+    let base = match args.strip_prefix.as_deref() {
+        Some(prefix) => cwd.join(prefix),
+        None => cwd,
+    };
+    let paths = tokmd_git::row_repo_paths(&export.rows, &repo_root, &base);
+    let last_modified = tokmd_git::last_modified(&repo_root, &paths)?;
+    tokmd_git::annotate_ages(
+        &mut export.rows,
+        &repo_root,
+        &base,
+        &last_modified,
+        tokmd_analysis_types::now_ms(),
+    );
+    Ok(())
+}
+
+#[cfg(not(feature = "git"))]
+fn add_git_ages(
+    _export: &mut tokmd_types::ExportData,
+    _args: &tokmd_types::ExportArgs,
+) -> Result<()> {
+    anyhow::bail!("--git-age requires the 'git' feature. Rebuild with --features git");
+}
@@ -52,6 +52,7 @@ fn compute_receipt(
         module_roots: bundle.meta.module_roots.clone(),
         module_depth: bundle.meta.module_depth,
         children: analysis_utils::child_include_to_string(bundle.meta.children),
+        token_model: bundle.meta.token_model.clone(),
     };
 
     let args_meta = analysis_types::AnalysisArgsMeta {
@@ -65,6 +66,14 @@ fn compute_receipt(
         max_commits: None,
         max_commit_files: None,
         import_granularity: "module".to_string(),
+        oversized_file_bytes: None,
+        max_commit_message_bytes: None,
+        strip_bom: false,
+        preset_sections: None,
+        include_sections: vec![],
+        exclude_sections: vec![],
+        sample_rate: None,
+        max_runtime_ms: None,
     };
 
     let request = analysis::AnalysisRequest {
@@ -74,7 +83,9 @@ fn compute_receipt(
         window_tokens: None,
         git: None,
         import_granularity: analysis::ImportGranularity::Module,
+        tree: Default::default(),
         detail_functions: false,
+        detail_api_items: false,
         near_dup: false,
         near_dup_threshold: 0.80,
         near_dup_max_files: 2000,
@@ -82,6 +93,18 @@ fn compute_receipt(
         near_dup_max_pairs: None,
         near_dup_exclude: Vec::new(),
         effort: None,
+        api_doc_thresholds: Default::default(),
+        license_header: None,
+        domain_grouping: Default::default(),
+        forecast_days: None,
+        coverage: None,
+        hash_algo: global.hash_algo.into(),
+        timings: false,
+        plan: None,
+        include_sections: vec![],
+        exclude_sections: vec![],
+        progress: None,
+        extensions: Vec::new(),
     };
 
     let ctx = analysis::AnalysisContext {
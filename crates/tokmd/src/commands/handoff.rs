@@ -8,7 +8,6 @@
 
 use std::fs;
 use std::path::{Path, PathBuf};
-use std::time::{SystemTime, UNIX_EPOCH};
 
 use crate::cli;
 use anyhow::{Context, Result, bail};
@@ -69,6 +68,7 @@ pub(crate) fn handle(args: cli::HandoffArgs, global: &cli::GlobalArgs) -> Result
     let mut scan_args = global.clone();
     let excluded_paths = exclude_output_dir(&root, &args.out_dir, &mut scan_args);
     let scan_opts = tokmd_settings::ScanOptions::from(&scan_args);
+    super::warn_skipped_ignore_negations(&paths, &scan_opts);
     let languages = scan::scan(&paths, &scan_opts)?;
     let module_roots = args.module_roots.clone().unwrap_or_default();
     let module_depth = args.module_depth.unwrap_or(2);
@@ -142,10 +142,7 @@ pub(crate) fn handle(args: cli::HandoffArgs, global: &cli::GlobalArgs) -> Result
         )
     })?;
 
-    let timestamp = SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .unwrap_or_default()
-        .as_millis();
+    let timestamp = tokmd_analysis_types::now_ms();
 
     let mut payloads = write_payloads(
         &args.out_dir,
@@ -344,6 +341,9 @@ mod tests {
                 lines: 10,
                 bytes: 100,
                 tokens: 20,
+                last_modified_ms: None,
+                age_days: None,
+                role: None,
             }],
             module_roots: vec![],
             module_depth: 2,
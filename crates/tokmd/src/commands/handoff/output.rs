@@ -941,6 +941,9 @@ mod tests {
                     lines: 1,
                     bytes: 10,
                     tokens: 3,
+                    last_modified_ms: None,
+                    age_days: None,
+                    role: None,
                 },
                 FileRow {
                     path: "src/lib.rs:Markdown".to_string(),
@@ -953,6 +956,9 @@ mod tests {
                     lines: 99,
                     bytes: 99,
                     tokens: 99,
+                    last_modified_ms: None,
+                    age_days: None,
+                    role: None,
                 },
             ],
             module_roots: vec![],
@@ -18,11 +18,42 @@ pub(crate) fn handle(
 
     let progress = Progress::new(!global.no_progress);
     progress.set_message("Scanning codebase...");
+    super::warn_skipped_ignore_negations(&args.paths, &scan_opts);
     let languages = scan::scan(&args.paths, &scan_opts)?;
-    let report = model::create_lang_report(&languages, args.top, args.files, args.children);
+    let file_rows = model::collect_file_rows(
+        &languages,
+        &[],
+        1,
+        tokmd_types::ChildIncludeMode::Separate,
+        None,
+    );
+    let report = model::create_lang_report_from_rows_with_detail(
+        &file_rows,
+        args.top,
+        args.files,
+        args.children,
+        model::RowDetail {
+            avg: args.avg,
+            percentiles: args.percentiles,
+            file_rows: args.with_files_detail,
+        },
+    );
     // Clear the stderr spinner before the report is written to stdout.
     progress.finish_and_clear();
 
     format::print_lang_report(&report, &scan_opts, &args)?;
+
+    if let Some(path) = &cli_args.append_history {
+        let snapshot = format::SummarySnapshot {
+            date: format::OutputPathVars::now("tsv", "").date,
+            files: report.total.files,
+            code: report.total.code,
+            comments: file_rows.iter().map(|row| row.comments).sum(),
+            lines: report.total.lines,
+            tokens: report.total.tokens,
+            bytes: report.total.bytes,
+        };
+        format::append_summary_row(path, &snapshot)?;
+    }
     Ok(())
 }
@@ -32,6 +32,16 @@ use anyhow::Result;
 
 use crate::config::ResolvedConfig;
 
+/// Warn about tokmd ignore-file negations, which the scan cannot honor.
+pub(crate) fn warn_skipped_ignore_negations(
+    paths: &[std::path::PathBuf],
+    scan_opts: &tokmd_settings::ScanOptions,
+) {
+    for line in tokmd_scan::skipped_ignore_negations(paths, scan_opts) {
+        eprintln!("Warning: {line}: negations are not supported; the path stays excluded");
+    }
+}
+
 pub(crate) fn dispatch(cli: cli::Cli, resolved: &ResolvedConfig) -> Result<()> {
     let global = &cli.global;
     match cli.command.unwrap_or(cli::Commands::Lang(cli.lang.clone())) {
@@ -43,7 +53,7 @@ pub(crate) fn dispatch(cli: cli::Cli, resolved: &ResolvedConfig) -> Result<()> {
         cli::Commands::Module(args) => module::handle(args, global, resolved),
         cli::Commands::Export(args) => export::handle(args, global, resolved),
         #[cfg(feature = "analysis")]
-        cli::Commands::Analyze(args) => analyze::handle(args, global),
+        cli::Commands::Analyze(args) => analyze::handle(args, global, resolved),
         #[cfg(feature = "analysis")]
         cli::Commands::Badge(args) => badge::handle(args, global),
         cli::Commands::Init(args) => init::handle(args),
@@ -52,7 +62,7 @@ pub(crate) fn dispatch(cli: cli::Cli, resolved: &ResolvedConfig) -> Result<()> {
         cli::Commands::Tools(args) => tools::handle(args),
         #[cfg(feature = "analysis")]
         cli::Commands::Gate(args) => gate::handle(args, global, resolved),
-        cli::Commands::Cockpit(args) => cockpit::handle(args, global),
+        cli::Commands::Cockpit(args) => cockpit::handle(args, global, resolved),
         #[cfg(feature = "analysis")]
         cli::Commands::Baseline(args) => baseline::handle(args, global),
         cli::Commands::Handoff(args) => handoff::handle(args, global),
@@ -29,21 +29,30 @@ pub(crate) fn handle(
 
     let progress = Progress::new(!global.no_progress);
     progress.set_message("Scanning codebase...");
+    super::warn_skipped_ignore_negations(&args.paths, &scan_opts);
     let languages = scan::scan(&args.paths, &scan_opts)?;
     let strip_prefix = single_scan_root_strip_prefix(&args.paths);
-    let file_rows = model::collect_file_rows(
+    let mut file_rows = model::collect_file_rows(
         &languages,
         &args.module_roots,
         args.module_depth,
         args.children,
         strip_prefix,
     );
-    let report = model::create_module_report_from_rows(
+    if args.collapse_single_child {
+        model::collapse_single_child_modules(&mut file_rows);
+    }
+    let report = model::create_module_report_from_rows_with_detail(
         &file_rows,
         &args.module_roots,
         args.module_depth,
         args.children,
         args.top,
+        model::RowDetail {
+            avg: args.avg,
+            percentiles: args.percentiles,
+            file_rows: args.with_files_detail,
+        },
     );
     // Clear the stderr spinner before the report is written to stdout.
     progress.finish_and_clear();
@@ -120,7 +120,9 @@ fn analyze_args(args: &cli::PacketGenerateArgs) -> cli::CliAnalyzeArgs {
     );
     cli::CliAnalyzeArgs {
         inputs: args.paths.clone(),
-        preset: Some(args.preset),
+        preset: Some(analysis_utils::preset_to_string(args.preset)),
+        include_sections: Vec::new(),
+        exclude_sections: Vec::new(),
         // The receipt is rendered to both formats; record JSON in the
         // machine-readable artifact's metadata.
         format: Some(cli::AnalysisFormat::Json),
@@ -142,13 +144,39 @@ fn analyze_args(args: &cli::PacketGenerateArgs) -> cli::CliAnalyzeArgs {
         mc_iterations: None,
         mc_seed: None,
         detail_functions: false,
+        detail_api_items: false,
         near_dup: false,
         near_dup_threshold: 0.80,
         near_dup_max_files: 2000,
         near_dup_scope: None,
         near_dup_max_pairs: 10000,
         near_dup_exclude: Vec::new(),
+        min_doc_ratio: None,
+        min_doc_ratio_lang: Vec::new(),
+        fail_on_doc_ratio: false,
+        license_header: None,
+        domain_parent: Vec::new(),
+        domain_allow: Vec::new(),
+        domain_deny: Vec::new(),
+        forecast_days: None,
+        coverage: None,
+        delta: false,
+        timings: false,
+        embed_receipt: false,
+        print_layout: false,
+        html_max_rows: None,
+        treemap_metric: None,
+        lang_color: Vec::new(),
+        collapse_single_child: false,
+        line_breakdown: false,
+        badge_metric: None,
+        precision: Vec::new(),
         explain: None,
+        oversized_file_bytes: None,
+        max_commit_message_bytes: None,
+        strip_bom: false,
+        sample: None,
+        max_runtime_ms: None,
     }
 }
 
@@ -18,6 +18,7 @@ pub(crate) fn handle(args: cli::RunArgs, global: &cli::GlobalArgs) -> Result<()>
     // 1. Scan once
     progress.set_message("Scanning codebase...");
     let scan_opts = ScanOptions::from(global);
+    super::warn_skipped_ignore_negations(&args.paths, &scan_opts);
     let languages = scan::scan(&args.paths, &scan_opts)?;
 
     // 2. Determine output directory
@@ -25,7 +26,9 @@ pub(crate) fn handle(args: cli::RunArgs, global: &cli::GlobalArgs) -> Result<()>
         std::fs::create_dir_all(&d).context("Failed to create output directory")?;
         d
     } else {
-        let run_id = args.name.unwrap_or_else(|| format!("run-{}", now_ms()));
+        let run_id = args
+            .name
+            .unwrap_or_else(|| format!("run-{}", wall_clock_ms()));
         let local_runs = PathBuf::from(".runs/tokmd").join(&run_id);
 
         // Try repo-local first, fall back to OS state dir if creation fails
@@ -93,6 +96,7 @@ pub(crate) fn handle(args: cli::RunArgs, global: &cli::GlobalArgs) -> Result<()>
         module_roots: vec!["crates".to_string(), "packages".to_string()],
         module_depth: 2,
         children: tokmd_types::ChildIncludeMode::Separate,
+        collapse_single_child: false,
     };
     format::write_module_json_to_file(
         &module_path,
@@ -115,6 +119,9 @@ pub(crate) fn handle(args: cli::RunArgs, global: &cli::GlobalArgs) -> Result<()>
         redact: redact_mode,
         strip_prefix: None,
         strip_prefix_redacted: false,
+        filters: Default::default(),
+        sort_by: None,
+        sort_dir: None,
     };
     format::write_export_jsonl_to_file(&export_path, &export_data, &scan_args, &export_args_meta)
         .context("Failed to write export.jsonl")?;
@@ -150,6 +157,7 @@ pub(crate) fn handle(args: cli::RunArgs, global: &cli::GlobalArgs) -> Result<()>
             module_roots: export_data.module_roots.clone(),
             module_depth: export_data.module_depth,
             children: analysis_utils::child_include_to_string(export_data.children),
+            token_model: scan_args.token_model.clone(),
         };
         let args_meta = analysis_types::AnalysisArgsMeta {
             preset: analysis_utils::preset_to_string(preset),
@@ -162,6 +170,14 @@ pub(crate) fn handle(args: cli::RunArgs, global: &cli::GlobalArgs) -> Result<()>
             max_commits: None,
             max_commit_files: None,
             import_granularity: "module".to_string(),
+            oversized_file_bytes: None,
+            max_commit_message_bytes: None,
+            strip_bom: false,
+            preset_sections: None,
+            include_sections: vec![],
+            exclude_sections: vec![],
+            sample_rate: None,
+            max_runtime_ms: None,
         };
         let request = analysis::AnalysisRequest {
             preset: analysis_utils::map_preset(preset),
@@ -170,7 +186,9 @@ pub(crate) fn handle(args: cli::RunArgs, global: &cli::GlobalArgs) -> Result<()>
             window_tokens: None,
             git: None,
             import_granularity: analysis::ImportGranularity::Module,
+            tree: Default::default(),
             detail_functions: false,
+            detail_api_items: false,
             near_dup: false,
             near_dup_threshold: 0.80,
             near_dup_max_files: 2000,
@@ -178,6 +196,18 @@ pub(crate) fn handle(args: cli::RunArgs, global: &cli::GlobalArgs) -> Result<()>
             near_dup_max_pairs: None,
             near_dup_exclude: Vec::new(),
             effort: None,
+            api_doc_thresholds: Default::default(),
+            license_header: None,
+            domain_grouping: Default::default(),
+            forecast_days: None,
+            coverage: None,
+            hash_algo: scan_opts.hash_algo,
+            timings: false,
+            plan: None,
+            include_sections: vec![],
+            exclude_sections: vec![],
+            progress: None,
+            extensions: Vec::new(),
         };
         let ctx = analysis::AnalysisContext {
             export: export_data.clone(),
@@ -190,11 +220,13 @@ pub(crate) fn handle(args: cli::RunArgs, global: &cli::GlobalArgs) -> Result<()>
             &receipt,
             &output_dir,
             tokmd_types::AnalysisFormat::Md,
+            &Default::default(),
         )?;
         analysis_utils::write_analysis_output(
             &receipt,
             &output_dir,
             tokmd_types::AnalysisFormat::Json,
+            &Default::default(),
         )?;
     }
 
@@ -202,6 +234,13 @@ pub(crate) fn handle(args: cli::RunArgs, global: &cli::GlobalArgs) -> Result<()>
 }
 
 fn now_ms() -> u128 {
+    tokmd_analysis_types::now_ms()
+}
+
+/// Real wall-clock milliseconds. Run directory names use this rather than
+/// [`now_ms`], which `SOURCE_DATE_EPOCH` pins, so pinned runs don't all share
+/// one directory.
+fn wall_clock_ms() -> u128 {
     std::time::SystemTime::now()
         .duration_since(std::time::UNIX_EPOCH)
         .unwrap_or_default()
@@ -73,6 +73,7 @@ pub(crate) fn handle(args: cli::SensorArgs, global: &cli::GlobalArgs) -> Result<
             &args.head,
             range_mode,
             None,
+            &Default::default(),
         )?;
 
         // Build the sensor report envelope
@@ -118,7 +119,7 @@ fn build_summary(receipt: &super::cockpit::CockpitReceipt, base: &str, head: &st
 
 #[cfg(feature = "git")]
 fn now_iso8601() -> String {
-    time::OffsetDateTime::now_utc()
+    tokmd_format::now_utc()
         .format(&time::format_description::well_known::Rfc3339)
         .unwrap_or_else(|_| "1970-01-01T00:00:00Z".to_string())
 }
@@ -168,6 +169,9 @@ mod tests {
                 bus_factor_warnings: vec![],
                 level: RiskLevel::High,
                 score: 80,
+                owners_touched: None,
+                unowned_files: vec![],
+                hotspot_owners: Default::default(),
             },
             contracts: super::super::cockpit::Contracts {
                 api_changed: false,
@@ -227,6 +227,7 @@ mod tests {
     fn base_evidence() -> Evidence {
         Evidence {
             overall_status: GateStatus::Warn,
+            project_coverage: None,
             mutation: sample_mutation_gate(GateStatus::Warn),
             diff_coverage: None,
             contracts: None,
@@ -249,6 +250,9 @@ mod tests {
             bus_factor_warnings: vec!["src/owner.rs".to_string()],
             level: RiskLevel::Medium,
             score: 50,
+            owners_touched: None,
+            unowned_files: vec![],
+            hotspot_owners: Default::default(),
         };
 
         emit_risk_findings(&mut report, &risk);
@@ -346,10 +350,13 @@ mod tests {
             lines_added: 20,
             lines_covered: 5,
             coverage_pct: 0.25,
+            artifact: None,
+            branch_coverage: None,
             uncovered_hunks: vec![UncoveredHunk {
                 file: "src/lib.rs".to_string(),
                 start_line: 1,
                 end_line: 3,
+                snippet: None,
             }],
         });
         evidence.complexity = Some(ComplexityGate {
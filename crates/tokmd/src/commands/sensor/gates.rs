@@ -106,6 +106,7 @@ pub(crate) mod test_support {
     pub(crate) fn base_evidence() -> Evidence {
         Evidence {
             overall_status: GateStatus::Warn,
+            project_coverage: None,
             mutation: sample_mutation_gate(GateStatus::Warn),
             diff_coverage: None,
             contracts: None,
@@ -141,10 +142,13 @@ mod tests {
             lines_added: 10,
             lines_covered: 5,
             coverage_pct: 0.5,
+            artifact: None,
+            branch_coverage: None,
             uncovered_hunks: vec![UncoveredHunk {
                 file: "src/lib.rs".to_string(),
                 start_line: 1,
                 end_line: 3,
+                snippet: None,
             }],
         });
         evidence.contracts = Some(ContractDiffGate {
@@ -158,6 +162,7 @@ mod tests {
             meta: test_support::sample_meta(GateStatus::Pass),
             vulnerabilities: vec![],
             denied: vec![],
+            acknowledged: vec![],
             advisory_db_version: None,
         });
         evidence.determinism = Some(DeterminismGate {
@@ -166,6 +171,7 @@ mod tests {
             actual_hash: Some("def".to_string()),
             algo: "blake3".to_string(),
             differences: vec!["target/app".to_string()],
+            excludes: vec![],
         });
         evidence.complexity = Some(ComplexityGate {
             meta: test_support::sample_meta(GateStatus::Fail),
@@ -5,6 +5,7 @@ use tokmd_settings::{Profile, TomlConfig, UserConfig, ViewProfile};
 pub mod explain;
 mod resolve;
 
+pub(crate) use resolve::parse_redact_mode;
 pub use resolve::{
     resolve_export, resolve_export_with_config, resolve_lang, resolve_lang_with_config,
     resolve_module, resolve_module_with_config,
@@ -8,3 +8,4 @@ mod parse;
 pub use export::{resolve_export, resolve_export_with_config};
 pub use lang::{resolve_lang, resolve_lang_with_config};
 pub use module::{resolve_module, resolve_module_with_config};
+pub(crate) use parse::parse_redact_mode;
@@ -24,10 +24,16 @@ use super::parse::{parse_child_include_mode, parse_export_format, parse_redact_m
 ///     module_depth: None,
 ///     children: None,
 ///     min_code: None,
+///     filters: Default::default(),
+///     sort_by: None,
+///     sort_dir: None,
 ///     max_rows: None,
 ///     redact: None,
 ///     meta: None,
 ///     strip_prefix: None,
+///     summary: false,
+///     git_age: false,
+///     classify: false,
 /// };
 /// let profile = Profile::default();
 ///
@@ -68,6 +74,9 @@ pub fn resolve_export(
             .min_code
             .or(profile.and_then(|p| p.min_code))
             .unwrap_or(0),
+        filters: cli_args.filters.into(),
+        sort_by: cli_args.sort_by.map(Into::into),
+        sort_dir: cli_args.sort_dir.map(Into::into),
         max_rows: cli_args
             .max_rows
             .or(profile.and_then(|p| p.max_rows))
@@ -82,6 +91,7 @@ pub fn resolve_export(
             .or(profile.and_then(|p| p.meta))
             .unwrap_or(true),
         strip_prefix: cli_args.strip_prefix.clone(),
+        summary: cli_args.summary,
     }
 }
 
@@ -113,10 +123,16 @@ pub fn resolve_export(
 ///     module_depth: None,
 ///     children: None,
 ///     min_code: None,
+///     filters: Default::default(),
+///     sort_by: None,
+///     sort_dir: None,
 ///     max_rows: None,
 ///     redact: None,
 ///     meta: None,
 ///     strip_prefix: None,
+///     summary: false,
+///     git_age: false,
+///     classify: false,
 /// };
 /// let export_args_1 = resolve_export_with_config(&cli_args_empty, &resolved);
 /// assert_eq!(export_args_1.format, ExportFormat::Csv);
@@ -130,10 +146,16 @@ pub fn resolve_export(
 ///     module_depth: None,
 ///     children: None,
 ///     min_code: None,
+///     filters: Default::default(),
+///     sort_by: None,
+///     sort_dir: None,
 ///     max_rows: None,
 ///     redact: None,
 ///     meta: None,
 ///     strip_prefix: None,
+///     summary: false,
+///     git_age: false,
+///     classify: false,
 /// };
 /// let export_args_2 = resolve_export_with_config(&cli_args_override, &resolved);
 /// assert_eq!(export_args_2.format, ExportFormat::Jsonl);
@@ -172,6 +194,9 @@ pub fn resolve_export_with_config(
             })
             .unwrap_or(tokmd_types::ChildIncludeMode::Separate),
         min_code: cli_args.min_code.or(resolved.min_code()).unwrap_or(0),
+        filters: cli_args.filters.into(),
+        sort_by: cli_args.sort_by.map(Into::into),
+        sort_dir: cli_args.sort_dir.map(Into::into),
         max_rows: cli_args.max_rows.or(resolved.max_rows()).unwrap_or(0),
         redact: cli_args
             .redact
@@ -180,5 +205,6 @@ pub fn resolve_export_with_config(
             .unwrap_or(tokmd_types::RedactMode::None),
         meta: cli_args.meta.or(resolved.meta()).unwrap_or(true),
         strip_prefix: cli_args.strip_prefix.clone(),
+        summary: cli_args.summary,
     }
 }
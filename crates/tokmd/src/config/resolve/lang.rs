@@ -22,6 +22,10 @@ use super::parse::{parse_children_mode, parse_table_format};
 ///     top: None,
 ///     files: false,
 ///     children: None,
+///     avg: None,
+///     percentiles: false,
+///     with_files_detail: false,
+///     append_history: None,
 /// };
 /// let profile = Profile::default();
 ///
@@ -55,6 +59,9 @@ pub fn resolve_lang(
             .map(Into::into)
             .or_else(|| parse_children_mode(profile.and_then(|p| p.children.as_deref())))
             .unwrap_or(tokmd_types::ChildrenMode::Collapse),
+        avg: cli_args.avg.map(Into::into).unwrap_or_default(),
+        percentiles: cli_args.percentiles,
+        with_files_detail: cli_args.with_files_detail,
     }
 }
 
@@ -83,6 +90,10 @@ pub fn resolve_lang(
 ///     top: None,
 ///     files: false,
 ///     children: None,
+///     avg: None,
+///     percentiles: false,
+///     with_files_detail: false,
+///     append_history: None,
 /// };
 /// let lang_args_1 = resolve_lang_with_config(&cli_args_empty, &resolved);
 /// assert_eq!(lang_args_1.top, 10);
@@ -94,6 +105,10 @@ pub fn resolve_lang(
 ///     top: Some(5),
 ///     files: false,
 ///     children: None,
+///     avg: None,
+///     percentiles: false,
+///     with_files_detail: false,
+///     append_history: None,
 /// };
 /// let lang_args_2 = resolve_lang_with_config(&cli_args_override, &resolved);
 /// assert_eq!(lang_args_2.top, 5);
@@ -119,5 +134,8 @@ pub fn resolve_lang_with_config(
             .map(Into::into)
             .or_else(|| parse_children_mode(resolved.children()))
             .unwrap_or(tokmd_types::ChildrenMode::Collapse),
+        avg: cli_args.avg.map(Into::into).unwrap_or_default(),
+        percentiles: cli_args.percentiles,
+        with_files_detail: cli_args.with_files_detail,
     }
 }
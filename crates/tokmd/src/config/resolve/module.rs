@@ -23,6 +23,10 @@ use super::parse::{parse_child_include_mode, parse_table_format};
 ///     module_roots: None,
 ///     module_depth: None,
 ///     children: None,
+///     collapse_single_child: false,
+///     avg: None,
+///     percentiles: false,
+///     with_files_detail: false,
 /// };
 /// let profile = Profile::default();
 ///
@@ -66,6 +70,10 @@ pub fn resolve_module(
             .map(Into::into)
             .or_else(|| parse_child_include_mode(profile.and_then(|p| p.children.as_deref())))
             .unwrap_or(tokmd_types::ChildIncludeMode::Separate),
+        collapse_single_child: cli_args.collapse_single_child,
+        avg: cli_args.avg.map(Into::into).unwrap_or_default(),
+        percentiles: cli_args.percentiles,
+        with_files_detail: cli_args.with_files_detail,
     }
 }
 
@@ -95,6 +103,10 @@ pub fn resolve_module(
 ///     module_roots: None,
 ///     module_depth: None,
 ///     children: None,
+///     collapse_single_child: false,
+///     avg: None,
+///     percentiles: false,
+///     with_files_detail: false,
 /// };
 /// let module_args_1 = resolve_module_with_config(&cli_args_empty, &resolved);
 /// assert_eq!(module_args_1.module_depth, 4);
@@ -107,6 +119,10 @@ pub fn resolve_module(
 ///     module_roots: None,
 ///     module_depth: Some(1),
 ///     children: None,
+///     collapse_single_child: false,
+///     avg: None,
+///     percentiles: false,
+///     with_files_detail: false,
 /// };
 /// let module_args_2 = resolve_module_with_config(&cli_args_override, &resolved);
 /// assert_eq!(module_args_2.module_depth, 1);
@@ -144,5 +160,9 @@ pub fn resolve_module_with_config(
             .map(Into::into)
             .or_else(|| parse_child_include_mode(resolved.children()))
             .unwrap_or(tokmd_types::ChildIncludeMode::Separate),
+        collapse_single_child: cli_args.collapse_single_child,
+        avg: cli_args.avg.map(Into::into).unwrap_or_default(),
+        percentiles: cli_args.percentiles,
+        with_files_detail: cli_args.with_files_detail,
     }
 }
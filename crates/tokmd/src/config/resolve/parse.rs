@@ -28,7 +28,7 @@ pub(super) fn parse_export_format(value: Option<&str>) -> Option<tokmd_types::Ex
         .map(Into::into)
 }
 
-pub(super) fn parse_redact_mode(value: Option<&str>) -> Option<tokmd_types::RedactMode> {
+pub(crate) fn parse_redact_mode(value: Option<&str>) -> Option<tokmd_types::RedactMode> {
     value
         .and_then(|s| cli::RedactMode::from_str(s, true).ok())
         .map(Into::into)
@@ -3,7 +3,6 @@
 use std::fs::OpenOptions;
 use std::io::Write;
 use std::path::Path;
-use std::time::{SystemTime, UNIX_EPOCH};
 
 use anyhow::{Context, Result};
 use tokmd_types::{
@@ -85,10 +84,7 @@ pub(crate) fn append_context_log_record(
 ) -> Result<()> {
     let log_record = ContextLogRecord {
         schema_version: SCHEMA_VERSION,
-        generated_at_ms: SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap_or_default()
-            .as_millis(),
+        generated_at_ms: tokmd_analysis_types::now_ms(),
         tool: ToolInfo::current(),
         budget_tokens: budget,
         used_tokens,
@@ -158,10 +154,7 @@ fn format_json_output(
     let token_estimation = tokmd_types::TokenEstimationMeta::from_bytes(total_file_bytes, 4.0);
     let receipt = ContextReceipt {
         schema_version: CONTEXT_SCHEMA_VERSION,
-        generated_at_ms: SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap_or_default()
-            .as_millis(),
+        generated_at_ms: tokmd_analysis_types::now_ms(),
         tool: ToolInfo::current(),
         mode: "context".to_string(),
         budget_tokens: budget,
@@ -128,6 +128,9 @@ mod tests {
             lines,
             bytes: tokens,
             tokens,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         }
     }
 
@@ -13,6 +13,9 @@ fn make_test_row(path: &str, module: &str, lang: &str, tokens: usize, code: usiz
         lines: code,
         bytes: code * 10,
         tokens,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
@@ -28,6 +31,9 @@ fn make_child_row(path: &str, module: &str, lang: &str, tokens: usize, code: usi
         lines: code,
         bytes: code * 10,
         tokens,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     }
 }
 
@@ -329,6 +335,9 @@ fn test_normalize_path_with_backslash() {
         lines: 50,
         bytes: 500,
         tokens: 100,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     };
 
     // Create git scores with forward slash path
@@ -624,6 +633,9 @@ fn test_to_context_row_preserves_all_fields() {
         lines: 57,
         bytes: 1234,
         tokens: 99,
+        last_modified_ms: None,
+        age_days: None,
+        role: None,
     };
 
     let ctx = to_context_row(&row, ValueMetric::Code, None);
@@ -1333,6 +1345,9 @@ fn test_generated_over_cap_excluded_by_policy() {
             lines: 10,
             bytes: 500_000,
             tokens: 117_000,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         },
     ];
     let result = select_files_with_options(
@@ -12,6 +12,7 @@ pub(crate) struct ExportMetaLite {
     pub(crate) module_roots: Vec<String>,
     pub(crate) module_depth: usize,
     pub(crate) children: tokmd_types::ChildIncludeMode,
+    pub(crate) token_model: String,
 }
 
 impl Default for ExportMetaLite {
@@ -23,6 +24,7 @@ impl Default for ExportMetaLite {
             module_roots: vec!["crates".into(), "packages".into(), "src".into()],
             module_depth: 2,
             children: tokmd_types::ChildIncludeMode::Separate,
+            token_model: tokmd_types::default_token_model(),
         }
     }
 }
@@ -82,8 +84,15 @@ pub(crate) fn load_export_from_inputs(
 
 fn scan_export_from_paths(paths: &[PathBuf], global: &cli::GlobalArgs) -> Result<ExportBundle> {
     let scan_opts = tokmd_settings::ScanOptions::from(global);
+    crate::commands::warn_skipped_ignore_negations(paths, &scan_opts);
     let languages = scan::scan(paths, &scan_opts)?;
-    let meta = ExportMetaLite::default();
+    let meta = ExportMetaLite {
+        token_model: scan_opts
+            .token_model
+            .clone()
+            .unwrap_or_else(tokmd_types::default_token_model),
+        ..ExportMetaLite::default()
+    };
     let export = model::create_export_data(
         &languages,
         &meta.module_roots,
@@ -202,6 +211,13 @@ fn load_export_jsonl_content(content: &str) -> Result<(tokmd_types::ExportData,
                 meta.module_depth = parsed.module_depth;
                 meta.children = parsed.children;
             }
+            if let Some(model) = value
+                .get("scan")
+                .and_then(|scan| scan.get("token_model"))
+                .and_then(|v| v.as_str())
+            {
+                meta.token_model = model.to_string();
+            }
             continue;
         }
 
@@ -221,16 +237,23 @@ fn load_export_jsonl_content(content: &str) -> Result<(tokmd_types::ExportData,
 }
 
 fn load_export_json_content(content: &str) -> Result<(tokmd_types::ExportData, ExportMetaLite)> {
-    // Try ExportReceipt wrapper first
-    if let Ok(receipt) = serde_json::from_str::<tokmd_types::ExportReceipt>(content) {
-        let meta = ExportMetaLite {
-            schema_version: Some(receipt.schema_version),
-            generated_at_ms: Some(receipt.generated_at_ms),
-            module_roots: receipt.args.module_roots.clone(),
-            module_depth: receipt.args.module_depth,
-            children: receipt.args.children,
-        };
-        return Ok((receipt.data, meta));
+    // Try ExportReceipt wrapper first, upgrading older schemas
+    match tokmd_format::parse_receipt::<tokmd_types::ExportReceipt>(content) {
+        Ok(receipt) => {
+            let meta = ExportMetaLite {
+                schema_version: Some(receipt.schema_version),
+                generated_at_ms: Some(receipt.generated_at_ms),
+                module_roots: receipt.args.module_roots.clone(),
+                module_depth: receipt.args.module_depth,
+                children: receipt.args.children,
+                token_model: receipt.scan.token_model.clone(),
+            };
+            return Ok((receipt.data, meta));
+        }
+        Err(err) if err.is::<tokmd_format::UnsupportedSchemaVersion>() => {
+            return Err(err.context("Failed to parse export receipt"));
+        }
+        Err(_) => {}
     }
 
     // Fallback to raw list of rows
@@ -271,6 +294,9 @@ mod tests {
             lines: 10,
             bytes: 120,
             tokens: 50,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         }
     }
 
@@ -285,6 +311,9 @@ mod tests {
             redact: RedactMode::None,
             strip_prefix: None,
             strip_prefix_redacted: false,
+            filters: Default::default(),
+            sort_by: None,
+            sort_dir: None,
         }
     }
 
@@ -300,6 +329,13 @@ mod tests {
             no_ignore_dot: false,
             no_ignore_vcs: false,
             treat_doc_strings_as_comments: false,
+            token_model: "heuristic".to_string(),
+            hash_algo: tokmd_types::HashAlgo::Blake3,
+            symlink_policy: Default::default(),
+            line_count_mode: Default::default(),
+            code_comment_markers: Vec::new(),
+            ignore_file: None,
+            include: Vec::new(),
         }
     }
 
@@ -325,6 +361,25 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn load_export_jsonl_content_reads_token_model_from_scan_meta() -> anyhow::Result<()> {
+        let scan = ScanArgs {
+            token_model: "cl100k_base".to_string(),
+            ..sample_scan_args()
+        };
+        let meta_line = serde_json::json!({
+            "type": "meta",
+            "scan": scan,
+            "args": sample_args_meta(),
+        });
+        let (_, meta) = load_export_jsonl_content(&format!("{meta_line}\n"))?;
+        assert_eq!(meta.token_model, "cl100k_base");
+
+        let (_, legacy) = load_export_jsonl_content("{\"type\":\"meta\"}\n")?;
+        assert_eq!(legacy.token_model, "heuristic");
+        Ok(())
+    }
+
     #[test]
     fn load_export_json_content_with_receipt() -> anyhow::Result<()> {
         let row = sample_row();
@@ -347,11 +402,13 @@ mod tests {
                 module_depth: args.module_depth,
                 children: args.children,
             },
+            integrity: None,
+            summary: None,
         };
         let content = serde_json::to_string(&receipt)?;
 
         let (export, meta) = load_export_json_content(&content)?;
-        assert_eq!(meta.schema_version, Some(2));
+        assert_eq!(meta.schema_version, Some(tokmd_types::SCHEMA_VERSION));
         assert_eq!(meta.generated_at_ms, Some(42));
         assert_eq!(meta.module_roots, vec!["src".to_string()]);
         assert_eq!(export.rows.len(), 1);
@@ -371,6 +428,50 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn load_export_json_content_migrates_v1_receipt() -> anyhow::Result<()> {
+        let args = sample_args_meta();
+        let receipt = ExportReceipt {
+            schema_version: 2,
+            generated_at_ms: 42,
+            tool: ToolInfo {
+                name: "tokmd".to_string(),
+                version: "0.0.0".to_string(),
+            },
+            mode: "export".to_string(),
+            status: ScanStatus::Complete,
+            warnings: Vec::new(),
+            scan: sample_scan_args(),
+            args: args.clone(),
+            data: ExportData {
+                rows: vec![sample_row()],
+                module_roots: args.module_roots.clone(),
+                module_depth: args.module_depth,
+                children: args.children,
+            },
+            integrity: None,
+            summary: None,
+        };
+        // Rewrite it as schema v1, which recorded no bytes or tokens.
+        let mut value = serde_json::to_value(&receipt)?;
+        value["schema_version"] = 1.into();
+        for row in value["rows"].as_array_mut().unwrap() {
+            let row = row.as_object_mut().unwrap();
+            row.remove("bytes");
+            row.remove("tokens");
+        }
+
+        let (export, meta) = load_export_json_content(&value.to_string())?;
+        assert_eq!(meta.schema_version, Some(tokmd_types::SCHEMA_VERSION));
+        assert_eq!(export.rows[0].path, "src/main.rs");
+        assert_eq!(export.rows[0].bytes, 0);
+
+        value["schema_version"] = 99.into();
+        let err = load_export_json_content(&value.to_string()).unwrap_err();
+        assert!(format!("{err:#}").contains("newer than this tokmd supports"));
+        Ok(())
+    }
+
     #[test]
     fn load_export_from_inputs_prefers_receipt_in_dir() -> anyhow::Result<()> {
         let dir = tempdir()?;
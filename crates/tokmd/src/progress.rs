@@ -74,6 +74,16 @@ mod ui_impl {
     use indicatif::{ProgressBar, ProgressStyle};
     use std::time::Duration;
 
+    #[cfg(any(test, feature = "analysis"))]
+    const ETA_TEMPLATE: &str =
+        "{spinner:.cyan} [{elapsed_precise}] [{bar:40.cyan/blue}] {pos}/{len} ({eta}) {msg}";
+
+    /// The step-bar style, or indicatif's plain bar if the template is rejected.
+    #[cfg(any(test, feature = "analysis"))]
+    fn eta_style() -> ProgressStyle {
+        ProgressStyle::with_template(ETA_TEMPLATE).unwrap_or_else(|_| ProgressStyle::default_bar())
+    }
+
     /// A progress indicator that wraps indicatif.
     pub struct Progress {
         bar: Option<ProgressBar>,
@@ -115,6 +125,14 @@ mod ui_impl {
             }
         }
 
+        /// A shareable handle that turns the spinner into a step bar.
+        #[cfg(feature = "analysis")]
+        pub fn steps(&self) -> Steps {
+            Steps {
+                bar: self.bar.clone(),
+            }
+        }
+
         /// Finish and clear the spinner.
         pub fn finish_and_clear(&self) {
             emit_progress_event("finish", "done");
@@ -124,6 +142,31 @@ mod ui_impl {
         }
     }
 
+    /// Step counter for a [`Progress`] spinner, usable from other threads.
+    #[cfg(feature = "analysis")]
+    #[derive(Clone)]
+    pub struct Steps {
+        bar: Option<ProgressBar>,
+    }
+
+    #[cfg(feature = "analysis")]
+    impl Steps {
+        /// Show `position` of `total` steps. The first call switches the
+        /// spinner to a bar with an ETA. Does nothing when the spinner is
+        /// hidden, and emits no progress events.
+        pub fn set(&self, position: u64, total: u64, msg: impl Into<String>) {
+            let Some(bar) = &self.bar else {
+                return;
+            };
+            if bar.length().is_none() {
+                bar.set_style(eta_style());
+            }
+            bar.set_length(total);
+            bar.set_position(position);
+            bar.set_message(msg.into());
+        }
+    }
+
     impl Drop for Progress {
         fn drop(&mut self) {
             if let Some(bar) = &self.bar {
@@ -146,12 +189,7 @@ mod ui_impl {
 
             let bar = if should_show {
                 let pb = indicatif::ProgressBar::new(total);
-                pb.set_style(
-                    ProgressStyle::with_template(
-                        "{spinner:.cyan} [{elapsed_precise}] [{bar:40.cyan/blue}] {pos}/{len} ({eta}) {msg}",
-                    )
-                    .expect("progress template is static and must be valid"),
-                );
+                pb.set_style(eta_style());
                 pb.set_message(message.to_string());
                 pb.enable_steady_tick(Duration::from_millis(100));
                 Some(pb)
@@ -244,12 +282,29 @@ mod ui_impl {
             emit_progress_event("update", &msg);
         }
 
+        /// A step counter handle (no-op without `ui` feature).
+        #[cfg(feature = "analysis")]
+        pub fn steps(&self) -> Steps {
+            Steps
+        }
+
         /// Finish and clear the spinner (no-op without `ui` feature).
         pub fn finish_and_clear(&self) {
             emit_progress_event("finish", "done");
         }
     }
 
+    /// A no-op step counter when the `ui` feature is disabled.
+    #[cfg(feature = "analysis")]
+    #[derive(Clone)]
+    pub struct Steps;
+
+    #[cfg(feature = "analysis")]
+    impl Steps {
+        /// Show step progress (no-op without `ui` feature).
+        pub fn set(&self, _position: u64, _total: u64, _msg: impl Into<String>) {}
+    }
+
     /// A no-op progress bar when `ui` feature is disabled.
     #[cfg(test)]
     pub struct ProgressBarWithEta;
@@ -305,6 +360,16 @@ mod tests {
         progress.finish_and_clear();
     }
 
+    #[cfg(feature = "analysis")]
+    #[test]
+    fn steps_do_not_panic_when_disabled() {
+        let progress = Progress::new(false);
+        let steps = progress.steps();
+        steps.set(1, 4, "derived");
+        steps.clone().set(4, 4, "done");
+        progress.finish_and_clear();
+    }
+
     #[test]
     fn progress_bar_methods_do_not_panic_when_disabled() {
         let progress = ProgressBarWithEta::new(false, 10, "scan");
@@ -35,7 +35,7 @@ fn analyze_receipt_preset_json_smoke() {
     let json: Value = serde_json::from_str(&stdout).expect("invalid JSON output");
 
     assert_eq!(json["mode"], "analysis");
-    assert_eq!(json["schema_version"], 9);
+    assert_eq!(json["schema_version"], 10);
     assert!(json["generated_at_ms"].is_number());
 
     // A couple of stable "shape" checks
@@ -100,9 +100,10 @@ fn analyze_marks_default_capped_complexity_as_partial() {
     let warnings = json["warnings"].as_array().expect("warnings array");
     assert!(
         warnings.iter().any(|warning| {
-            warning
-                .as_str()
-                .is_some_and(|text| text.contains("complexity scan bounded"))
+            warning["code"] == "truncated_file"
+                && warning["message"]
+                    .as_str()
+                    .is_some_and(|text| text.contains("complexity scan bounded"))
         }),
         "{warnings:?}"
     );
@@ -687,6 +688,98 @@ fn analyze_topics_preset_returns_topic_cloud() {
     assert!(!overall.is_empty());
 }
 
+#[test]
+fn analyze_delta_reports_changes_since_previous_run() {
+    let dir = tempdir().expect("should create temp dir");
+    let src_dir = dir.path().join("src");
+    std::fs::create_dir_all(&src_dir).expect("create src dir");
+    std::fs::create_dir_all(dir.path().join(".git")).expect("create .git marker");
+    std::fs::write(src_dir.join("lib.rs"), "pub fn a() {}\n").expect("write lib.rs");
+    std::fs::write(src_dir.join("old.rs"), "pub fn old() {}\n").expect("write old.rs");
+
+    let run = || {
+        let output = Command::new(env!("CARGO_BIN_EXE_tokmd"))
+            .current_dir(dir.path())
+            .arg("--no-progress")
+            .arg("analyze")
+            .arg("src")
+            .arg("--format")
+            .arg("json")
+            .arg("--no-git")
+            .arg("--delta")
+            .output()
+            .expect("failed to execute tokmd analyze --delta");
+        assert!(
+            output.status.success(),
+            "tokmd analyze --delta failed: {:?}\nstderr: {}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr)
+        );
+        serde_json::from_slice::<Value>(&output.stdout).expect("stdout stays valid JSON");
+        String::from_utf8(output.stderr).expect("invalid UTF-8")
+    };
+
+    let first = run();
+    assert!(first.contains("no previous run cached"), "{first}");
+    assert!(dir.path().join(".tokmd/last-analysis.json").exists());
+
+    std::fs::write(
+        src_dir.join("lib.rs"),
+        "pub fn a() {}\npub fn b() {}\npub fn c() {}\n",
+    )
+    .expect("grow lib.rs");
+    std::fs::remove_file(src_dir.join("old.rs")).expect("remove old.rs");
+    std::fs::write(src_dir.join("new.rs"), "pub fn new() {}\n").expect("write new.rs");
+
+    let second = run();
+    assert!(
+        second.contains("1 added, 1 removed, 1 grown, 0 shrunk"),
+        "{second}"
+    );
+    assert!(second.contains("+ src/new.rs"), "{second}");
+    assert!(second.contains("- src/old.rs"), "{second}");
+    assert!(second.contains("~ src/lib.rs 1 -> 3 (+2)"), "{second}");
+}
+
+/// Run `tokmd analyze .` on the fixture with `args`; returns whether it
+/// succeeded and its stderr.
+fn analyze_with(args: &[&str]) -> anyhow::Result<(bool, String)> {
+    let output = tokmd_cmd().arg("analyze").arg(".").args(args).output()?;
+    Ok((output.status.success(), String::from_utf8(output.stderr)?))
+}
+
+#[test]
+fn analyze_fail_on_doc_ratio_without_threshold_fails() -> anyhow::Result<()> {
+    let (success, stderr) = analyze_with(&["--fail-on-doc-ratio"])?;
+
+    assert!(!success);
+    assert!(stderr.contains("--min-doc-ratio"), "{stderr}");
+    Ok(())
+}
+
+#[test]
+fn analyze_fail_on_doc_ratio_without_api_surface_fails() -> anyhow::Result<()> {
+    let (success, stderr) = analyze_with(&[
+        "--preset",
+        "topics",
+        "--min-doc-ratio",
+        "0",
+        "--fail-on-doc-ratio",
+    ])?;
+
+    assert!(!success);
+    assert!(stderr.contains("API surface section"), "{stderr}");
+    Ok(())
+}
+
+#[test]
+fn analyze_fail_on_doc_ratio_passes_met_threshold() -> anyhow::Result<()> {
+    let (success, stderr) = analyze_with(&["--min-doc-ratio", "0", "--fail-on-doc-ratio"])?;
+
+    assert!(success, "stderr: {stderr}");
+    Ok(())
+}
+
 #[cfg(unix)]
 fn create_file_symlink(src: &std::path::Path, dst: &std::path::Path) -> std::io::Result<()> {
     std::os::unix::fs::symlink(src, dst)
@@ -44,3 +44,31 @@ fn badge_writes_to_file() -> Result<(), Box<dyn std::error::Error>> {
     assert!(content.contains("bytes"));
     Ok(())
 }
+
+#[test]
+fn badge_doc_target_colors_the_value() {
+    // No fixture reaches 100% doc density, so an unreachable target warns.
+    let mut cmd = tokmd_cmd();
+    cmd.args(["badge", "--metric", "doc", "--target", "100"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("fill=\"#e05d44\""))
+        .stdout(predicate::str::contains("%<"));
+
+    let mut cmd = tokmd_cmd();
+    cmd.args(["badge", "--metric", "doc", "--target", "0"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("fill=\"#4c1\""));
+}
+
+#[test]
+fn badge_target_requires_doc_metric() {
+    let mut cmd = tokmd_cmd();
+    cmd.args(["badge", "--metric", "lines", "--target", "10"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--target applies only to --metric doc",
+        ));
+}
@@ -65,8 +65,8 @@ fn given_project_when_analyze_json_then_has_schema_version() {
     )
     .expect("should decode stdout as UTF-8");
     assert_eq!(
-        json["schema_version"], 9,
-        "analysis schema_version should be 9"
+        json["schema_version"], 10,
+        "analysis schema_version should be 10"
     );
     assert!(
         json["generated_at_ms"].is_number(),
@@ -1173,6 +1173,76 @@ fn test_cockpit_output_file() {
     let _: serde_json::Value = serde_json::from_str(&content).expect("valid JSON in file");
 }
 
+#[test]
+fn test_cockpit_json_and_md_outputs_in_one_run() {
+    // Given: A git repository with a feature branch
+    // When: User runs `tokmd cockpit --json-output cockpit.json --md-output cockpit.md`
+    // Then: Both files are written from the receipt printed to stdout
+    let Some(dir) = basic_cockpit_repo() else {
+        return;
+    };
+    let json_file = dir.path().join("out/cockpit.json");
+    let md_file = dir.path().join("out/cockpit.md");
+    std::fs::create_dir_all(dir.path().join("out")).unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_tokmd"))
+        .current_dir(dir.path())
+        .arg("cockpit")
+        .arg("--base")
+        .arg("main")
+        .arg("--json-output")
+        .arg(&json_file)
+        .arg("--md-output")
+        .arg(&md_file)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let stdout_receipt: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let file_json = std::fs::read_to_string(&json_file).expect("JSON output should exist");
+    let file_receipt: tokmd_cockpit::CockpitReceipt =
+        serde_json::from_str(&file_json).expect("JSON output should be a cockpit receipt");
+    assert_eq!(serde_json::to_value(&file_receipt).unwrap(), stdout_receipt);
+
+    let md = std::fs::read_to_string(&md_file).expect("Markdown output should exist");
+    assert!(md.contains("## Glass Cockpit"), "{md}");
+    assert!(md.contains(&format!("{}/100", file_receipt.code_health.score)));
+}
+
+#[test]
+fn test_cockpit_caches_last_run_in_git_dir_for_same_refs() -> Result<(), Box<dyn std::error::Error>>
+{
+    // Given: A git repository with a feature branch
+    // When: User runs `tokmd cockpit --base main` twice, then with another head
+    // Then: The cache lives under `.git/tokmd`, the second run trends against
+    //       the first, and a run over other refs does not
+    let Some(dir) = basic_cockpit_repo() else {
+        return Ok(());
+    };
+    let run = |head: &str| -> Result<serde_json::Value, Box<dyn std::error::Error>> {
+        let output = Command::new(env!("CARGO_BIN_EXE_tokmd"))
+            .current_dir(dir.path())
+            .args([
+                "cockpit", "--base", "main", "--head", head, "--format", "json",
+            ])
+            .output()?;
+        assert!(output.status.success());
+        Ok(serde_json::from_slice(&output.stdout)?)
+    };
+
+    let first = run("HEAD")?;
+    assert!(first.get("trend").is_none(), "{first}");
+    assert!(dir.path().join(".git/tokmd/cockpit-last.json").is_file());
+    assert!(!dir.path().join(".tokmd").exists());
+
+    let second = run("HEAD")?;
+    assert_eq!(second["trend"]["baseline_available"], true, "{second}");
+
+    let other_head = run("main")?;
+    assert!(other_head.get("trend").is_none(), "{other_head}");
+    Ok(())
+}
+
 #[test]
 fn test_cockpit_artifacts_dir() {
     // Given: A git repository with a main branch and a test branch with code changes
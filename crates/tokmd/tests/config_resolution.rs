@@ -93,6 +93,12 @@ fn test_resolve_export_cli_overrides_profile() {
         redact: None,
         meta: None,
         strip_prefix: None,
+        filters: Default::default(),
+        sort_by: None,
+        sort_dir: None,
+        summary: false,
+        git_age: false,
+        classify: false,
     };
 
     let profile = Profile {
@@ -119,6 +125,10 @@ fn test_resolve_module_profile_overrides_default() {
         module_roots: None,
         module_depth: None,
         children: None,
+        collapse_single_child: false,
+        avg: None,
+        percentiles: false,
+        with_files_detail: false,
     };
 
     let profile = Profile {
@@ -145,6 +155,10 @@ fn test_resolve_module_cli_overrides_profile_scalars() {
         module_roots: None,
         module_depth: None,
         children: None,
+        collapse_single_child: false,
+        avg: None,
+        percentiles: false,
+        with_files_detail: false,
     };
 
     let profile = Profile {
@@ -178,6 +192,12 @@ fn test_resolve_export_with_config() {
         redact: None,
         meta: None,
         strip_prefix: None,
+        filters: Default::default(),
+        sort_by: None,
+        sort_dir: None,
+        summary: false,
+        git_age: false,
+        classify: false,
     };
 
     let toml = TomlConfig {
@@ -216,6 +236,12 @@ fn test_resolve_export_profile_overrides_default_format() {
         redact: None,
         meta: None,
         strip_prefix: None,
+        filters: Default::default(),
+        sort_by: None,
+        sort_dir: None,
+        summary: false,
+        git_age: false,
+        classify: false,
     };
 
     let profile = Profile {
@@ -241,6 +267,10 @@ fn test_resolve_module_with_config() {
         module_roots: None,
         module_depth: None,
         children: None,
+        collapse_single_child: false,
+        avg: None,
+        percentiles: false,
+        with_files_detail: false,
     };
 
     let toml = TomlConfig {
@@ -280,6 +310,12 @@ fn test_resolve_export_no_args_no_profile() {
         redact: None,
         meta: None,
         strip_prefix: None,
+        filters: Default::default(),
+        sort_by: None,
+        sort_dir: None,
+        summary: false,
+        git_age: false,
+        classify: false,
     };
     let resolved = resolve_export(&cli, None);
 
@@ -307,6 +343,10 @@ fn test_resolve_module_no_args_no_profile() {
         module_roots: None,
         module_depth: None,
         children: None,
+        collapse_single_child: false,
+        avg: None,
+        percentiles: false,
+        with_files_detail: false,
     };
     let resolved = resolve_module(&cli, None);
 
@@ -333,6 +373,10 @@ fn test_resolve_lang_with_config_precedence() {
         files: false,
         paths: None,
         children: Some(CliChildrenMode::Separate),
+        avg: None,
+        percentiles: false,
+        with_files_detail: false,
+        append_history: None,
     };
 
     let view = ViewProfile {
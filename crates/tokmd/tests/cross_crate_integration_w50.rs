@@ -10,7 +10,7 @@ use tokmd_model::{create_export_data, create_lang_report, create_module_report};
 use tokmd_scan::scan;
 use tokmd_settings::{ScanOptions, TomlConfig};
 use tokmd_types::{
-    ChildIncludeMode, ChildrenMode, ConfigMode, ExportArgs, ExportData, ExportFormat,
+    AvgMeasure, ChildIncludeMode, ChildrenMode, ConfigMode, ExportArgs, ExportData, ExportFormat,
     ExportReceipt, LangArgs, LangReceipt, LangReport, ModuleReceipt, RedactMode, SCHEMA_VERSION,
     TableFormat,
 };
@@ -61,6 +61,9 @@ fn default_lang_args(fmt: TableFormat) -> LangArgs {
         top: 0,
         files: false,
         children: ChildrenMode::Collapse,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     }
 }
 
@@ -77,6 +80,10 @@ fn default_export_args() -> ExportArgs {
         redact: RedactMode::None,
         meta: false,
         strip_prefix: None,
+        filters: Default::default(),
+        sort_by: None,
+        sort_dir: None,
+        summary: false,
     }
 }
 
@@ -362,6 +369,13 @@ fn receipt_lang_serialize_has_schema_version() {
             no_ignore_dot: false,
             no_ignore_vcs: false,
             treat_doc_strings_as_comments: false,
+            token_model: "heuristic".to_string(),
+            hash_algo: tokmd_types::HashAlgo::Blake3,
+            symlink_policy: Default::default(),
+            line_count_mode: Default::default(),
+            code_comment_markers: Vec::new(),
+            ignore_file: None,
+            include: Vec::new(),
         },
         args: tokmd_types::LangArgsMeta {
             format: "md".into(),
@@ -378,11 +392,15 @@ fn receipt_lang_serialize_has_schema_version() {
                 bytes: 0,
                 tokens: 0,
                 avg_lines: 0,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
             },
             with_files: false,
             children: ChildrenMode::Collapse,
             top: 0,
         },
+        integrity: None,
     };
     let json = serde_json::to_value(&receipt).unwrap();
     assert_eq!(json["schema_version"], SCHEMA_VERSION);
@@ -409,6 +427,13 @@ fn receipt_lang_flatten_rows_at_top_level() {
             no_ignore_dot: false,
             no_ignore_vcs: false,
             treat_doc_strings_as_comments: false,
+            token_model: "heuristic".to_string(),
+            hash_algo: tokmd_types::HashAlgo::Blake3,
+            symlink_policy: Default::default(),
+            line_count_mode: Default::default(),
+            code_comment_markers: Vec::new(),
+            ignore_file: None,
+            include: Vec::new(),
         },
         args: tokmd_types::LangArgsMeta {
             format: "json".into(),
@@ -425,6 +450,10 @@ fn receipt_lang_flatten_rows_at_top_level() {
                 bytes: 3000,
                 tokens: 500,
                 avg_lines: 60,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             }],
             total: tokmd_types::Totals {
                 code: 100,
@@ -433,11 +462,15 @@ fn receipt_lang_flatten_rows_at_top_level() {
                 bytes: 3000,
                 tokens: 500,
                 avg_lines: 60,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
             },
             with_files: false,
             children: ChildrenMode::Collapse,
             top: 0,
         },
+        integrity: None,
     };
     let json = serde_json::to_value(&receipt).unwrap();
     // #[serde(flatten)] puts rows at top level, not nested under "report"
@@ -469,6 +502,13 @@ fn receipt_module_flatten_rows_at_top_level() {
             no_ignore_dot: false,
             no_ignore_vcs: false,
             treat_doc_strings_as_comments: false,
+            token_model: "heuristic".to_string(),
+            hash_algo: tokmd_types::HashAlgo::Blake3,
+            symlink_policy: Default::default(),
+            line_count_mode: Default::default(),
+            code_comment_markers: Vec::new(),
+            ignore_file: None,
+            include: Vec::new(),
         },
         args: tokmd_types::ModuleArgsMeta {
             format: "json".into(),
@@ -476,6 +516,7 @@ fn receipt_module_flatten_rows_at_top_level() {
             module_depth: 2,
             children: ChildIncludeMode::Separate,
             top: 0,
+            collapse_single_child: false,
         },
         report: tokmd_types::ModuleReport {
             rows: vec![],
@@ -486,12 +527,16 @@ fn receipt_module_flatten_rows_at_top_level() {
                 bytes: 0,
                 tokens: 0,
                 avg_lines: 0,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
             },
             module_roots: vec![],
             module_depth: 2,
             children: ChildIncludeMode::Separate,
             top: 0,
         },
+        integrity: None,
     };
     let json = serde_json::to_value(&receipt).unwrap();
     assert!(
@@ -521,6 +566,13 @@ fn receipt_export_flatten_data_at_top_level() {
             no_ignore_dot: false,
             no_ignore_vcs: false,
             treat_doc_strings_as_comments: false,
+            token_model: "heuristic".to_string(),
+            hash_algo: tokmd_types::HashAlgo::Blake3,
+            symlink_policy: Default::default(),
+            line_count_mode: Default::default(),
+            code_comment_markers: Vec::new(),
+            ignore_file: None,
+            include: Vec::new(),
         },
         args: tokmd_types::ExportArgsMeta {
             format: ExportFormat::Csv,
@@ -532,6 +584,9 @@ fn receipt_export_flatten_data_at_top_level() {
             redact: RedactMode::None,
             strip_prefix: None,
             strip_prefix_redacted: false,
+            filters: Default::default(),
+            sort_by: None,
+            sort_dir: None,
         },
         data: ExportData {
             rows: vec![],
@@ -539,6 +594,8 @@ fn receipt_export_flatten_data_at_top_level() {
             module_depth: 2,
             children: ChildIncludeMode::Separate,
         },
+        integrity: None,
+        summary: None,
     };
     let json = serde_json::to_value(&receipt).unwrap();
     assert!(
@@ -14,7 +14,7 @@ use tokmd_model::{
 use tokmd_scan::scan;
 use tokmd_settings::ScanOptions;
 use tokmd_types::{
-    ChildIncludeMode, ChildrenMode, ConfigMode, ExportArgs, ExportData, ExportFormat,
+    AvgMeasure, ChildIncludeMode, ChildrenMode, ConfigMode, ExportArgs, ExportData, ExportFormat,
     ExportReceipt, FileKind, LangArgs, LangReceipt, LangReport, ModuleArgs, ModuleReceipt,
     ModuleReport, RedactMode, SCHEMA_VERSION, TableFormat,
 };
@@ -95,6 +95,9 @@ fn make_lang_args(dir: &std::path::Path, fmt: TableFormat) -> LangArgs {
         top: 0,
         files: true,
         children: ChildrenMode::Collapse,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     }
 }
 
@@ -106,6 +109,10 @@ fn make_module_args(dir: &std::path::Path, fmt: TableFormat) -> ModuleArgs {
         module_roots: vec![],
         module_depth: 1,
         children: ChildIncludeMode::Separate,
+        collapse_single_child: false,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     }
 }
 
@@ -122,6 +129,10 @@ fn make_export_args(dir: &std::path::Path) -> ExportArgs {
         redact: RedactMode::None,
         meta: true,
         strip_prefix: Some(dir.to_path_buf()),
+        filters: Default::default(),
+        sort_by: None,
+        sort_dir: None,
+        summary: false,
     }
 }
 
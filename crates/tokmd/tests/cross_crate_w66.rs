@@ -8,8 +8,9 @@ use tokmd_model::{create_lang_report, create_module_report};
 use tokmd_scan::scan;
 use tokmd_settings::ScanOptions;
 use tokmd_types::{
-    ChildIncludeMode, ChildrenMode, ConfigMode, LangArgs, LangReceipt, LangReport, LangRow,
-    ModuleArgs, ModuleReceipt, ModuleReport, ModuleRow, SCHEMA_VERSION, TableFormat, Totals,
+    AvgMeasure, ChildIncludeMode, ChildrenMode, ConfigMode, LangArgs, LangReceipt, LangReport,
+    LangRow, ModuleArgs, ModuleReceipt, ModuleReport, ModuleRow, SCHEMA_VERSION, TableFormat,
+    Totals,
 };
 
 fn make_project() -> TempDir {
@@ -65,6 +66,10 @@ fn synthetic_lang_report() -> LangReport {
                 bytes: 20_000,
                 tokens: 5_000,
                 avg_lines: 65,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
             LangRow {
                 lang: "Python".to_string(),
@@ -74,6 +79,10 @@ fn synthetic_lang_report() -> LangReport {
                 bytes: 12_000,
                 tokens: 3_000,
                 avg_lines: 80,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
             },
         ],
         total: Totals {
@@ -83,6 +92,9 @@ fn synthetic_lang_report() -> LangReport {
             bytes: 32_000,
             tokens: 8_000,
             avg_lines: 70,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         with_files: true,
         children: ChildrenMode::Collapse,
@@ -101,6 +113,11 @@ fn synthetic_module_report() -> ModuleReport {
                 bytes: 20_000,
                 tokens: 5_000,
                 avg_lines: 65,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
+                children: Vec::new(),
             },
             ModuleRow {
                 module: "lib".to_string(),
@@ -110,6 +127,11 @@ fn synthetic_module_report() -> ModuleReport {
                 bytes: 12_000,
                 tokens: 3_000,
                 avg_lines: 80,
+                median_lines: None,
+                p50_lines: None,
+                p90_lines: None,
+                file_rows: Vec::new(),
+                children: Vec::new(),
             },
         ],
         total: Totals {
@@ -119,6 +141,9 @@ fn synthetic_module_report() -> ModuleReport {
             bytes: 32_000,
             tokens: 8_000,
             avg_lines: 70,
+            median_lines: None,
+            p50_lines: None,
+            p90_lines: None,
         },
         module_roots: vec![],
         module_depth: 1,
@@ -134,6 +159,9 @@ fn lang_args(dir: &std::path::Path, fmt: TableFormat) -> LangArgs {
         top: 0,
         files: true,
         children: ChildrenMode::Collapse,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     }
 }
 
@@ -145,6 +173,10 @@ fn module_args(dir: &std::path::Path, fmt: TableFormat) -> ModuleArgs {
         module_roots: vec![],
         module_depth: 1,
         children: ChildIncludeMode::Separate,
+        collapse_single_child: false,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     }
 }
 
@@ -190,6 +222,9 @@ fn synthetic_lang_md_produces_valid_table() {
         top: 0,
         files: true,
         children: ChildrenMode::Collapse,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     };
     let mut buf = Vec::new();
     tokmd_format::write_lang_report_to(&mut buf, &report, &opts(), &args).unwrap();
@@ -239,6 +274,10 @@ fn synthetic_module_tsv_roundtrip_values() {
         module_roots: vec![],
         module_depth: 1,
         children: ChildIncludeMode::Separate,
+        collapse_single_child: false,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     };
     let mut buf = Vec::new();
     tokmd_format::write_module_report_to(&mut buf, &report, &opts(), &args).unwrap();
@@ -93,7 +93,7 @@ fn run_receipt_has_schema_version() {
 
     let v: serde_json::Value =
         serde_json::from_str(&fs::read_to_string(out.join("receipt.json")).unwrap()).unwrap();
-    assert_eq!(v["schema_version"], 2, "schema_version should be 2");
+    assert_eq!(v["schema_version"], 3, "schema_version should be 3");
 }
 
 #[test]
@@ -180,7 +180,7 @@ fn run_on_empty_dir_produces_valid_receipt() {
 
     let v: serde_json::Value =
         serde_json::from_str(&fs::read_to_string(out.join("receipt.json")).unwrap()).unwrap();
-    assert_eq!(v["schema_version"], 2);
+    assert_eq!(v["schema_version"], 3);
 }
 
 #[test]
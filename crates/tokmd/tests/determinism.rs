@@ -304,7 +304,7 @@ fn lang_receipt_has_required_envelope_fields() {
     assert!(json.get("tool").is_some(), "missing tool");
     assert!(json.get("mode").is_some(), "missing mode");
     assert_eq!(json["mode"], "lang");
-    assert_eq!(json["schema_version"], 2);
+    assert_eq!(json["schema_version"], 3);
 }
 
 #[test]
@@ -319,7 +319,7 @@ fn module_receipt_has_required_envelope_fields() {
         "missing schema_version"
     );
     assert_eq!(json["mode"], "module");
-    assert_eq!(json["schema_version"], 2);
+    assert_eq!(json["schema_version"], 3);
 }
 
 #[test]
@@ -334,7 +334,7 @@ fn export_json_receipt_has_required_envelope_fields() {
         "missing schema_version"
     );
     assert_eq!(json["mode"], "export");
-    assert_eq!(json["schema_version"], 2);
+    assert_eq!(json["schema_version"], 3);
 }
 
 // ---------------------------------------------------------------------------
@@ -388,7 +388,7 @@ fn export_modules_use_forward_slashes() {
 
 #[test]
 fn schema_version_constants_match_expected() {
-    assert_eq!(tokmd_types::SCHEMA_VERSION, 2, "core schema version");
+    assert_eq!(tokmd_types::SCHEMA_VERSION, 3, "core schema version");
     assert_eq!(
         tokmd_types::HANDOFF_SCHEMA_VERSION,
         5,
@@ -411,7 +411,7 @@ fn schema_version_constants_match_expected() {
     );
     assert_eq!(
         tokmd_analysis_types::ANALYSIS_SCHEMA_VERSION,
-        9,
+        10,
         "analysis schema version"
     );
 }
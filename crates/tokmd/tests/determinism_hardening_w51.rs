@@ -460,7 +460,7 @@ fn w51_lang_totals_equal_row_sums() {
 // ===========================================================================
 
 #[test]
-fn w51_core_receipts_schema_version_is_2() {
+fn w51_core_receipts_schema_version_is_3() {
     for (cmd, mode_label) in [
         (vec!["lang", "--format", "json"], "lang"),
         (vec!["module", "--format", "json"], "module"),
@@ -470,14 +470,14 @@ fn w51_core_receipts_schema_version_is_2() {
         assert!(o.status.success(), "{mode_label} command failed");
         let json: Value = serde_json::from_slice(&o.stdout).expect("valid JSON");
         assert_eq!(
-            json["schema_version"], 2,
-            "{mode_label} schema_version must be 2"
+            json["schema_version"], 3,
+            "{mode_label} schema_version must be 3"
         );
     }
 }
 
 #[test]
-fn w51_analyze_schema_version_is_9() {
+fn w51_analyze_schema_version_is_10() {
     let o = tokmd_cmd()
         .args(["analyze", ".", "--preset", "receipt", "--format", "json"])
         .output()
@@ -485,8 +485,8 @@ fn w51_analyze_schema_version_is_9() {
     assert!(o.status.success(), "analyze command failed");
     let json: Value = serde_json::from_slice(&o.stdout).expect("valid JSON");
     assert_eq!(
-        json["schema_version"], 9,
-        "analysis schema_version must be 9"
+        json["schema_version"], 10,
+        "analysis schema_version must be 10"
     );
 }
 
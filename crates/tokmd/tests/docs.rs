@@ -531,27 +531,11 @@ fn recipe_handoff_no_git() {
 #[cfg(feature = "git")]
 #[test]
 fn recipe_sensor_json() {
-    // Skip if the fixtures directory is not inside a git repository
-    // (e.g., in the Nix build sandbox where .git is absent)
-    let fixtures = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
-        .join("tests")
-        .join("data");
-    let in_git = std::process::Command::new("git")
-        .arg("-C")
-        .arg(&fixtures)
-        .arg("rev-parse")
-        .arg("--show-toplevel")
-        .output()
-        .map(|o| o.status.success())
-        .unwrap_or(false);
-    if !in_git {
-        eprintln!("Skipping recipe_sensor_json: not inside a git repository");
-        return;
-    }
-
+    // Run in a throwaway repository so the recipe never touches this checkout
+    let git_fixture = git_recipe_fixture();
     let tmp = tempfile::tempdir().unwrap();
     let report_path = tmp.path().join("report.json");
-    tokmd()
+    tokmd_in(git_fixture.path())
         .env("TOKMD_GIT_BASE_REF", "HEAD")
         .arg("sensor")
         .arg("--format")
@@ -730,22 +714,11 @@ fn recipe_diff() {
 #[test]
 #[cfg(feature = "git")]
 fn recipe_cockpit_format() {
-    let fixtures = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
-        .join("tests")
-        .join("data");
-    let in_git = std::process::Command::new("git")
-        .arg("-C")
-        .arg(&fixtures)
-        .arg("rev-parse")
-        .arg("--show-toplevel")
-        .output()
-        .map(|o| o.status.success())
-        .unwrap_or(false);
-    if !in_git {
-        return;
-    }
+    // Run in a throwaway repository: cockpit caches its last run in the
+    // repository's git directory
+    let git_fixture = git_recipe_fixture();
 
-    tokmd()
+    tokmd_in(git_fixture.path())
         .env("TOKMD_GIT_BASE_REF", "HEAD")
         .arg("cockpit")
         .arg("--format")
@@ -22,8 +22,8 @@ use tokmd_model::{create_export_data, create_lang_report, create_module_report};
 use tokmd_scan::scan;
 use tokmd_settings::{ScanOptions, TomlConfig};
 use tokmd_types::{
-    ChildIncludeMode, ChildrenMode, ConfigMode, DiffRow, ExportArgs, ExportFormat, LangArgs,
-    ModuleArgs, RedactMode, SCHEMA_VERSION, TableFormat,
+    AvgMeasure, ChildIncludeMode, ChildrenMode, ConfigMode, DiffRow, ExportArgs, ExportFormat,
+    LangArgs, ModuleArgs, RedactMode, SCHEMA_VERSION, TableFormat,
 };
 
 // ===========================================================================
@@ -81,6 +81,9 @@ fn default_lang_args(fmt: TableFormat) -> LangArgs {
         top: 0,
         files: false,
         children: ChildrenMode::Collapse,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     }
 }
 
@@ -92,6 +95,10 @@ fn default_module_args(fmt: TableFormat) -> ModuleArgs {
         module_roots: vec![],
         module_depth: 2,
         children: ChildIncludeMode::Separate,
+        collapse_single_child: false,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     }
 }
 
@@ -108,6 +115,10 @@ fn default_export_args(fmt: ExportFormat) -> ExportArgs {
         redact: RedactMode::None,
         meta: false,
         strip_prefix: None,
+        filters: Default::default(),
+        sort_by: None,
+        sort_dir: None,
+        summary: false,
     }
 }
 
@@ -479,7 +490,7 @@ fn cli_export_json_full_pipeline() {
 #[test]
 fn cli_analyze_receipt_json_pipeline() {
     let json = run_json(&["analyze", "--preset", "receipt", "--format", "json"]);
-    assert_eq!(json["schema_version"].as_u64().unwrap(), 9);
+    assert_eq!(json["schema_version"].as_u64().unwrap(), 10);
     assert_eq!(json["mode"].as_str().unwrap(), "analysis");
     assert!(
         json["derived"].is_object(),
@@ -490,7 +501,7 @@ fn cli_analyze_receipt_json_pipeline() {
 #[test]
 fn cli_analyze_health_json_pipeline() {
     let json = run_json(&["analyze", "--preset", "health", "--format", "json"]);
-    assert_eq!(json["schema_version"].as_u64().unwrap(), 9);
+    assert_eq!(json["schema_version"].as_u64().unwrap(), 10);
     assert_eq!(json["mode"].as_str().unwrap(), "analysis");
     assert!(json["derived"].is_object());
 }
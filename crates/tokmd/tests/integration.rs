@@ -24,7 +24,13 @@ fn normalize_snapshot(output: &str) -> String {
     // Normalize tool.version -> 0.0.0
     let re_ver =
         regex::Regex::new(r#"("tool":\{"name":"tokmd","version":")[^"]+"#).expect("valid regex");
-    re_ver.replace_all(&s, r#"${1}0.0.0"#).to_string()
+    let s = re_ver.replace_all(&s, r#"${1}0.0.0"#).to_string();
+
+    // Normalize receipt integrity hash
+    let re_hash = regex::Regex::new(r#""hash":"[0-9a-f]{64}""#).expect("valid regex");
+    re_hash
+        .replace_all(&s, r#""hash":"<INTEGRITY_HASH>""#)
+        .to_string()
 }
 
 #[test]
@@ -35,6 +41,34 @@ fn test_default_lang_output() {
         .stdout(predicate::str::contains("|Rust|"));
 }
 
+#[test]
+fn test_lang_append_history_accumulates_rows() -> Result<()> {
+    let dir = tempdir()?;
+    let history = dir.path().join("history.tsv");
+
+    for _ in 0..2 {
+        tokmd_cmd()
+            .arg("lang")
+            .arg("--append-history")
+            .arg(&history)
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("|Rust|"));
+    }
+
+    let content = std::fs::read_to_string(&history)?;
+    let lines: Vec<&str> = content.lines().collect();
+    assert_eq!(lines.len(), 3);
+    assert_eq!(
+        lines[0],
+        "Date\tFiles\tCode\tComments\tLines\tTokens\tBytes\tDoc%"
+    );
+    // Same tree, same totals; only the date could differ across midnight.
+    let totals = |line: &str| line.split_once('\t').map(|(_, rest)| rest.to_string());
+    assert_eq!(totals(lines[1]), totals(lines[2]));
+    Ok(())
+}
+
 #[test]
 fn test_module_output() {
     let mut cmd = tokmd_cmd();
@@ -77,6 +111,26 @@ fn test_ignore_file_respected() {
         .stdout(predicate::str::contains("ignored.rs").not());
 }
 
+#[test]
+fn test_tokmdignore_negation_warns_and_stays_excluded() -> Result<()> {
+    let dir = tempdir()?;
+    std::fs::create_dir(dir.path().join("gen"))?;
+    std::fs::write(dir.path().join("gen/keep.rs"), "fn keep() {}")?;
+    std::fs::write(dir.path().join("main.rs"), "fn main() {}")?;
+    std::fs::write(dir.path().join(".tokmdignore"), "gen/\n!gen/keep.rs\n")?;
+
+    let mut cmd = Command::new(env!("CARGO_BIN_EXE_tokmd"));
+    cmd.current_dir(dir.path())
+        .arg("export")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("main.rs"))
+        .stdout(predicate::str::contains("keep.rs").not())
+        .stderr(predicate::str::contains(".tokmdignore:2: !gen/keep.rs"))
+        .stderr(predicate::str::contains("negations are not supported"));
+    Ok(())
+}
+
 #[test]
 fn test_ignore_vcs_explicit() {
     // Given: 'hidden_by_git.rs' is in .gitignore
@@ -231,6 +285,41 @@ fn test_export_format_json() {
         .stdout(predicate::str::contains(r#""rows":["#));
 }
 
+#[test]
+fn test_export_format_paths_applies_filters() -> Result<()> {
+    // Given: Standard files
+    // When: We export paths only with --min-code and --max-rows
+    // Then: output is bare paths, largest first, filtered and truncated
+    let mut cmd = tokmd_cmd();
+    let output = cmd
+        .arg("export")
+        .arg("--format")
+        .arg("paths")
+        .arg("--min-code")
+        .arg("4")
+        .arg("--max-rows")
+        .arg("2")
+        .output()?;
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout)?;
+    assert_eq!(stdout, "large.rs\nCargo.toml\n");
+    Ok(())
+}
+
+#[test]
+fn test_export_format_paths0_is_nul_terminated() -> Result<()> {
+    let mut cmd = tokmd_cmd();
+    let output = cmd.arg("export").arg("--format").arg("paths0").output()?;
+    assert!(output.status.success());
+
+    assert!(!output.stdout.contains(&b'\n'));
+    let records: Vec<&[u8]> = output.stdout.split(|b| *b == 0).collect();
+    assert_eq!(records.last(), Some(&&b""[..]));
+    assert!(records.contains(&&b"space file.rs"[..]));
+    Ok(())
+}
+
 #[test]
 fn test_init_creates_file() -> Result<()> {
     // Given: An empty temporary directory
@@ -1361,6 +1450,116 @@ fn test_analyze_html_format() {
         stdout.contains("<script>"),
         "HTML should have embedded scripts"
     );
+    assert!(
+        !stdout.contains("id=\"tokmd-receipt\""),
+        "receipt should only be embedded on request"
+    );
+}
+
+#[test]
+fn test_analyze_html_embed_receipt() {
+    const OPEN: &str = "<script type=\"application/json\" id=\"tokmd-receipt\">";
+    let output = tokmd_cmd()
+        .args(["analyze", ".", "--preset", "receipt", "--format", "html"])
+        .arg("--embed-receipt")
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "html analyze failed");
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let start = stdout.find(OPEN).expect("receipt script tag") + OPEN.len();
+    let end = start + stdout[start..].find("</script>").unwrap();
+    let receipt: serde_json::Value = serde_json::from_str(&stdout[start..end]).unwrap();
+    assert_eq!(receipt["mode"], "analysis");
+    assert!(receipt["derived"]["totals"]["files"].as_u64().unwrap() > 0);
+}
+
+#[test]
+fn test_analyze_html_print_layout() {
+    let default = tokmd_cmd()
+        .args(["analyze", ".", "--preset", "receipt", "--format", "html"])
+        .output()
+        .unwrap();
+    assert!(
+        String::from_utf8(default.stdout)
+            .unwrap()
+            .contains("@media print {")
+    );
+
+    let forced = tokmd_cmd()
+        .args(["analyze", ".", "--preset", "receipt", "--format", "html"])
+        .arg("--print-layout")
+        .output()
+        .unwrap();
+    assert!(forced.status.success(), "html analyze failed");
+    assert!(
+        String::from_utf8(forced.stdout)
+            .unwrap()
+            .contains("@media all {")
+    );
+}
+
+#[test]
+fn test_analyze_html_max_rows() {
+    let output = tokmd_cmd()
+        .args(["analyze", ".", "--preset", "receipt", "--format", "html"])
+        .args(["--html-max-rows", "2"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "html analyze failed");
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(stdout.matches("<tr><td").count(), 2);
+}
+
+#[test]
+fn test_analyze_html_treemap_metric() {
+    let output = tokmd_cmd()
+        .args(["analyze", ".", "--preset", "receipt", "--format", "html"])
+        .args(["--treemap-metric", "tokens"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "html analyze failed");
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("const SIZE_BY = 'tokens';"));
+    assert!(stdout.contains(r#""size":"#));
+}
+
+#[test]
+fn test_analyze_svg_badge_metric() {
+    let output = tokmd_cmd()
+        .args(["analyze", ".", "--preset", "receipt", "--format", "svg"])
+        .args(["--badge-metric", "files"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "svg analyze failed");
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains(">files</text>"), "{stdout}");
+}
+
+#[test]
+fn test_analyze_html_lang_color() {
+    let output = tokmd_cmd()
+        .args(["analyze", ".", "--preset", "receipt", "--format", "html"])
+        .args(["--lang-color", "Rust=#ff8800"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "html analyze failed");
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains(r##"Object.assign(LANG_COLORS, {"Rust":"#ff8800"});"##));
+    assert!(stdout.contains(r#"style="background: #ff8800">Rust<"#));
+
+    tokmd_cmd()
+        .args([
+            "analyze",
+            ".",
+            "--format",
+            "html",
+            "--lang-color",
+            "Rust=red;",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("is not a color"));
 }
 
 // --- Check-ignore Tests ---
@@ -1720,3 +1919,27 @@ fn test_context_out_and_bundle_dir_conflict() {
         .failure()
         .stderr(predicate::str::contains("cannot be used with"));
 }
+
+#[test]
+fn test_analyze_license_header_lists_noncompliant_files() -> Result<()> {
+    let dir = tempdir()?;
+    std::fs::write(
+        dir.path().join("with.rs"),
+        "// SPDX-License-Identifier: MIT\nfn with() {}\n",
+    )?;
+    std::fs::write(dir.path().join("without.rs"), "fn without() {}\n")?;
+
+    let output = Command::new(env!("CARGO_BIN_EXE_tokmd"))
+        .current_dir(dir.path())
+        .args(["analyze", ".", "--preset", "security", "--format", "json"])
+        .args(["--license-header", "SPDX-License-Identifier: MIT"])
+        .output()?;
+    assert!(output.status.success());
+
+    let receipt: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    let headers = &receipt["license"]["header_compliance"];
+    assert_eq!(headers["checked"], 2);
+    assert_eq!(headers["compliant"], 1);
+    assert_eq!(headers["missing"], serde_json::json!(["without.rs"]));
+    Ok(())
+}
@@ -17,7 +17,8 @@ use tokmd_model::create_lang_report;
 use tokmd_scan::scan;
 use tokmd_settings::ScanOptions;
 use tokmd_types::{
-    ChildrenMode, ConfigMode, LangArgs, LangReceipt, ModuleReceipt, SCHEMA_VERSION, TableFormat,
+    AvgMeasure, ChildrenMode, ConfigMode, LangArgs, LangReceipt, ModuleReceipt, SCHEMA_VERSION,
+    TableFormat,
 };
 
 // ---------------------------------------------------------------------------
@@ -114,6 +115,9 @@ fn w70_scan_model_format_md_contains_table_header() {
         top: 0,
         files: true,
         children: ChildrenMode::Collapse,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     };
     let mut buf = Vec::new();
     tokmd_format::write_lang_report_to(&mut buf, &report, &scan_opts(), &args).unwrap();
@@ -133,6 +137,9 @@ fn w70_scan_model_format_md_includes_all_detected_languages() {
         top: 0,
         files: true,
         children: ChildrenMode::Collapse,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     };
     let mut buf = Vec::new();
     tokmd_format::write_lang_report_to(&mut buf, &report, &scan_opts(), &args).unwrap();
@@ -153,6 +160,9 @@ fn w70_scan_model_format_json_roundtrips_totals() {
         top: 0,
         files: true,
         children: ChildrenMode::Collapse,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     };
     let mut buf = Vec::new();
     tokmd_format::write_lang_report_to(&mut buf, &report, &scan_opts(), &args).unwrap();
@@ -328,6 +338,9 @@ fn w70_lib_lang_receipt_schema_version_matches() {
         top: 0,
         files: true,
         children: ChildrenMode::Collapse,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     };
     let mut buf = Vec::new();
     tokmd_format::write_lang_report_to(&mut buf, &report, &scan_opts(), &args).unwrap();
@@ -677,6 +690,9 @@ fn w70_sort_order_consistent_between_md_and_json() {
         top: 0,
         files: true,
         children: ChildrenMode::Collapse,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     };
     let mut jbuf = Vec::new();
     tokmd_format::write_lang_report_to(&mut jbuf, &report, &scan_opts(), &json_args).unwrap();
@@ -694,6 +710,9 @@ fn w70_sort_order_consistent_between_md_and_json() {
         top: 0,
         files: true,
         children: ChildrenMode::Collapse,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     };
     let mut mbuf = Vec::new();
     tokmd_format::write_lang_report_to(&mut mbuf, &report, &scan_opts(), &md_args).unwrap();
@@ -728,6 +747,9 @@ fn w70_sort_order_consistent_between_tsv_and_json() {
         top: 0,
         files: true,
         children: ChildrenMode::Collapse,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     };
     let mut jbuf = Vec::new();
     tokmd_format::write_lang_report_to(&mut jbuf, &report, &scan_opts(), &json_args).unwrap();
@@ -745,6 +767,9 @@ fn w70_sort_order_consistent_between_tsv_and_json() {
         top: 0,
         files: true,
         children: ChildrenMode::Collapse,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     };
     let mut tbuf = Vec::new();
     tokmd_format::write_lang_report_to(&mut tbuf, &report, &scan_opts(), &tsv_args).unwrap();
@@ -12,8 +12,8 @@ use assert_cmd::Command;
 use serde_json::Value;
 
 // Schema version constants mirrored from the crate sources.
-const CORE_SCHEMA_VERSION: u32 = 2;
-const ANALYSIS_SCHEMA_VERSION: u32 = 9;
+const CORE_SCHEMA_VERSION: u32 = 3;
+const ANALYSIS_SCHEMA_VERSION: u32 = 10;
 
 // ── helpers ──────────────────────────────────────────────────────────────
 
@@ -17,8 +17,8 @@ use tokmd_model::{
 use tokmd_scan::scan;
 use tokmd_settings::ScanOptions;
 use tokmd_types::{
-    CONTEXT_BUNDLE_SCHEMA_VERSION, CONTEXT_SCHEMA_VERSION, ChildIncludeMode, ChildrenMode,
-    ConfigMode, HANDOFF_SCHEMA_VERSION, LangArgs, SCHEMA_VERSION, TableFormat,
+    AvgMeasure, CONTEXT_BUNDLE_SCHEMA_VERSION, CONTEXT_SCHEMA_VERSION, ChildIncludeMode,
+    ChildrenMode, ConfigMode, HANDOFF_SCHEMA_VERSION, LangArgs, SCHEMA_VERSION, TableFormat,
 };
 
 use std::path::{Path, PathBuf};
@@ -76,16 +76,16 @@ fn run_stdout(args: &[&str]) -> String {
 // ===========================================================================
 
 #[test]
-fn schema_version_is_2() {
-    assert_eq!(SCHEMA_VERSION, 2, "core SCHEMA_VERSION must be 2");
+fn schema_version_is_3() {
+    assert_eq!(SCHEMA_VERSION, 3, "core SCHEMA_VERSION must be 3");
 }
 
 #[test]
-fn analysis_schema_version_is_9() {
+fn analysis_schema_version_is_10() {
     assert_eq!(
         tokmd_analysis_types::ANALYSIS_SCHEMA_VERSION,
-        9,
-        "ANALYSIS_SCHEMA_VERSION must be 9"
+        10,
+        "ANALYSIS_SCHEMA_VERSION must be 10"
     );
 }
 
@@ -264,6 +264,9 @@ fn format_write_functions_accept_writers() {
         top: 0,
         files: false,
         children: ChildrenMode::Collapse,
+        avg: AvgMeasure::Mean,
+        percentiles: false,
+        with_files_detail: false,
     };
     let mut buf: Vec<u8> = Vec::new();
     let result = write_lang_report_to(&mut buf, &report, &default_scan_options(), &args);
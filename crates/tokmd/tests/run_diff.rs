@@ -935,3 +935,90 @@ fn test_diff_git_refs() {
         .stdout(predicate::str::contains("## Diff:"))
         .stdout(predicate::str::contains("Rust"));
 }
+
+#[test]
+#[cfg(all(feature = "content", feature = "walk"))]
+fn test_diff_runs_with_analysis_reports_api_surface_changes() {
+    let dir = tempdir().unwrap();
+    let project = dir.path().join("project");
+    let src_dir = project.join("src");
+    fs::create_dir_all(&src_dir).unwrap();
+
+    let run = |source: &str, run_dir: &std::path::Path| {
+        fs::write(src_dir.join("lib.rs"), source).unwrap();
+        let mut cmd: Command = cargo_bin_cmd!("tokmd");
+        cmd.current_dir(&project)
+            .arg("run")
+            .arg("--analysis")
+            .arg("receipt")
+            .arg("--output-dir")
+            .arg(run_dir.to_str().unwrap())
+            .arg(".")
+            .assert()
+            .success();
+        assert!(run_dir.join("analysis.json").exists());
+    };
+    let base = dir.path().join("base");
+    let head = dir.path().join("head");
+    run("pub fn parse() {}\npub fn legacy() {}\n", &base);
+    run("pub fn parse() {}\npub fn render() {}\n", &head);
+
+    let mut cmd: Command = cargo_bin_cmd!("tokmd");
+    cmd.arg("diff")
+        .arg(base.to_str().unwrap())
+        .arg(head.to_str().unwrap())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("## API surface diff"))
+        .stdout(predicate::str::contains("|src|- `fn legacy`|"))
+        .stdout(predicate::str::contains("|src|+ `fn render`|"));
+
+    let mut cmd: Command = cargo_bin_cmd!("tokmd");
+    let output = cmd
+        .arg("diff")
+        .arg("--format")
+        .arg("json")
+        .arg(base.join("receipt.json").to_str().unwrap())
+        .arg(head.join("receipt.json").to_str().unwrap())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let receipt: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(
+        receipt["api_surface"]["removed"][0]["items"][0],
+        "fn legacy"
+    );
+    assert_eq!(receipt["api_surface"]["added"][0]["items"][0], "fn render");
+}
+
+#[test]
+fn test_diff_warns_and_skips_api_surface_on_unreadable_analysis()
+-> Result<(), Box<dyn std::error::Error>> {
+    let dir = tempdir()?;
+    let base = dir.path().join("base");
+    let head = dir.path().join("head");
+    for run_dir in [&base, &head] {
+        let mut cmd: Command = cargo_bin_cmd!("tokmd");
+        cmd.current_dir(common::fixture_root())
+            .arg("run")
+            .arg("--output-dir")
+            .arg(run_dir)
+            .arg(".")
+            .assert()
+            .success();
+    }
+    fs::write(base.join("analysis.json"), "{ not json")?;
+
+    let mut cmd: Command = cargo_bin_cmd!("tokmd");
+    cmd.arg("diff")
+        .arg(&base)
+        .arg(&head)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("## Diff:"))
+        .stdout(predicate::str::contains("## API surface diff").not())
+        .stderr(predicate::str::contains(
+            "Warning: skipping API surface diff: Failed to parse",
+        ));
+    Ok(())
+}
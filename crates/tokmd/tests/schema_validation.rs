@@ -461,8 +461,8 @@ fn test_schema_version_matches_constant() -> Result<()> {
         lang_version
             .as_u64()
             .context("schema_version should be integer")?,
-        2,
-        "LangReceipt schema_version should be 2"
+        3,
+        "LangReceipt schema_version should be 3"
     );
 
     // Check ModuleReceipt schema_version const
@@ -472,8 +472,8 @@ fn test_schema_version_matches_constant() -> Result<()> {
         module_version
             .as_u64()
             .context("schema_version should be integer")?,
-        2,
-        "ModuleReceipt schema_version should be 2"
+        3,
+        "ModuleReceipt schema_version should be 3"
     );
 
     // Check ExportReceipt schema_version const
@@ -483,8 +483,8 @@ fn test_schema_version_matches_constant() -> Result<()> {
         export_version
             .as_u64()
             .context("schema_version should be integer")?,
-        2,
-        "ExportReceipt schema_version should be 2"
+        3,
+        "ExportReceipt schema_version should be 3"
     );
 
     // Check ExportMeta schema_version const
@@ -494,8 +494,8 @@ fn test_schema_version_matches_constant() -> Result<()> {
         meta_version
             .as_u64()
             .context("schema_version should be integer")?,
-        2,
-        "ExportMeta schema_version should be 2"
+        3,
+        "ExportMeta schema_version should be 3"
     );
 
     // Check AnalysisReceipt schema_version const
@@ -505,8 +505,8 @@ fn test_schema_version_matches_constant() -> Result<()> {
         analysis_version
             .as_u64()
             .context("schema_version should be integer")?,
-        9,
-        "AnalysisReceipt schema_version should be 9"
+        10,
+        "AnalysisReceipt schema_version should be 10"
     );
 
     // Check CockpitReceipt schema_version const
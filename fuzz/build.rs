@@ -1,4 +1,7 @@
 fn main() {
     println!("cargo::rustc-check-cfg=cfg(fuzzing)");
+    // `fuzz_entropy` includes tokmd-analysis's content io module, which gates
+    // legacy-encoding support on a feature this crate does not have.
+    println!("cargo::rustc-check-cfg=cfg(feature, values(\"encoding\"))");
     println!("cargo:rustc-cfg=fuzzing");
 }
@@ -55,6 +55,9 @@ fn build_export_data(payload: &[u8]) -> ExportData {
             lines,
             bytes,
             tokens,
+            last_modified_ms: None,
+            age_days: None,
+            role: None,
         });
     }
 